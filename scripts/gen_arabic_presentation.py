@@ -0,0 +1,106 @@
+#!/usr/bin/env python3
+"""Generates src/maps/arabic_presentation.rs from Unicode data.
+
+Every character in the Arabic Presentation Forms-A (U+FB50-U+FDFF) and
+Arabic Presentation Forms-B (U+FE70-U+FEFF) blocks with a compatibility
+decomposition is mapped to its NFKC form, i.e. its compatibility decomposition
+with base letters recomposed.
+
+The Unicode data used is the one bundled with the running Python interpreter.
+
+Usage: python3 scripts/gen_arabic_presentation.py
+"""
+
+import os
+import re
+import unicodedata
+
+from unicode_version import UNICODE_VERSION, check_unicodedata
+
+BLOCKS = [(0xFB50, 0xFDFF), (0xFE70, 0xFEFF)]
+
+OUTPUT_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    os.pardir,
+    "src",
+    "maps",
+    "arabic_presentation.rs",
+)
+
+HEADER = """\
+//! Arabic presentation form decomposition map.
+//!
+//! Maps all characters in the Arabic Presentation Forms-A (U+FB50-U+FDFF) and
+//! Arabic Presentation Forms-B (U+FE70-U+FEFF) blocks to their base letter
+//! sequences (i.e. their NFKC forms). Ligatures such as ﻻ and ﷲ are expanded
+//! to multiple letters.
+//!
+//! ```rust
+//! use charmap::maps::arabic_presentation::DECOMPOSE_PRESENTATION_FORMS;
+//! use charmap::*;
+//!
+//! let mapper =
+//!     CharMapper::new(&DECOMPOSE_PRESENTATION_FORMS, CharMapAction::Pass);
+//!
+//! let decomposed: String = "ﻻ ﷲ ﺑﺴﻢ".map_chars(&mapper).collect();
+//! assert_eq!(decomposed, "لا الله بسم");
+//! ```
+//!
+//! This file is generated by `scripts/gen_arabic_presentation.py` from
+//! Unicode {version} data. Do not edit it manually.
+
+use crate::actionmap::{{assert_sorted, CharMapAction}};
+
+/// Map decomposing Arabic presentation forms to base letter sequences.
+#[rustfmt::skip]
+pub static DECOMPOSE_PRESENTATION_FORMS: [(char, CharMapAction); {count}] = [
+"""
+
+FOOTER = """\
+];
+const _: () = assert_sorted(&DECOMPOSE_PRESENTATION_FORMS);
+"""
+
+
+def escape(c):
+    if c != " " and unicodedata.category(c) in ("Mn", "Cf", "Zs"):
+        return "\\u{%04X}" % ord(c)
+    return c
+
+
+def short_name(c):
+    return re.sub(r"^ARABIC (LETTER |LIGATURE )?", "", unicodedata.name(c))
+
+
+def main():
+    check_unicodedata()
+
+    entries = []
+
+    for start, end in BLOCKS:
+        for code in range(start, end + 1):
+            c = chr(code)
+            decomposed = unicodedata.normalize("NFKC", c)
+
+            if decomposed == c:
+                continue
+
+            if len(decomposed) == 1:
+                action = "CharMapAction::SubChar('%s')" % escape(decomposed)
+            else:
+                action = 'CharMapAction::SubStr("%s")' % "".join(
+                    escape(d) for d in decomposed
+                )
+
+            entries.append(
+                "    ('\\u{%04X}', %s), // %s\n" % (code, action, short_name(c))
+            )
+
+    with open(OUTPUT_PATH, "w", encoding="utf-8") as f:
+        f.write(HEADER.format(version=UNICODE_VERSION, count=len(entries)))
+        f.writelines(entries)
+        f.write(FOOTER)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,142 @@
+#!/usr/bin/env python3
+"""Generates src/maps/ascii_folding.rs from Lucene's ASCIIFoldingFilter.
+
+The folding rules are parsed from the `fold_non_ascii_char` function of
+tantivy's Rust port of Lucene's ASCIIFoldingFilter
+(`src/tokenizer/ascii_folding_filter.rs` in the tantivy crate), which lists the
+rules as a single `match` expression.
+
+Lucene does not fold letterlike temperature symbols, so these are added from
+`SUPPLEMENT`.
+
+Usage: python3 scripts/gen_ascii_folding.py path/to/ascii_folding_filter.rs
+"""
+
+import os
+import re
+import sys
+import unicodedata
+
+from unicode_version import check_unicodedata
+
+OUTPUT_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    os.pardir,
+    "src",
+    "maps",
+    "ascii_folding.rs",
+)
+
+HEADER = """\
+//! ASCII folding map equivalent to Lucene's `ASCIIFoldingFilter`.
+//!
+//! Maps alphabetic, numeric, and symbolic characters outside the Basic Latin
+//! block to their ASCII equivalents, if one exists. Some characters are
+//! expanded to multiple ASCII characters (e.g. ß to "ss" and ﬁ to "fi").
+//! Characters without an ASCII equivalent are not mapped.
+//!
+//! In addition to Lucene's rules, the degree Celsius (℃) and degree
+//! Fahrenheit (℉) signs are folded to "oC" and "oF" respectively.
+//!
+//! ```rust
+//! use charmap::maps::ascii_folding::ASCII_FOLDING;
+//! use charmap::*;
+//!
+//! let mapper = CharMapper::new(&ASCII_FOLDING, CharMapAction::Pass);
+//!
+//! let folded: String = "Æsir straße ﬁnal café 25℃".map_chars(&mapper).collect();
+//! assert_eq!(folded, "AEsir strasse final cafe 25oC");
+//! ```
+//!
+//! This file is generated by `scripts/gen_ascii_folding.py`. Do not edit it
+//! manually.
+
+use crate::actionmap::{{assert_sorted, CharMapAction}};
+
+/// Map folding characters to their ASCII equivalents.
+#[rustfmt::skip]
+pub static ASCII_FOLDING: [(char, CharMapAction); {count}] = [
+"""
+
+FOOTER = """\
+];
+const _: () = assert_sorted(&ASCII_FOLDING);
+"""
+
+# Foldings missing from Lucene's ASCIIFoldingFilter.
+SUPPLEMENT = {
+    0x2103: "oC",  # DEGREE CELSIUS
+    0x2109: "oF",  # DEGREE FAHRENHEIT
+}
+
+CHAR_RE = re.compile(r"'\\u\{([0-9A-Fa-f]+)\}'")
+FOLDED_RE = re.compile(r'=> Some\("((?:[^"\\]|\\.)*)"\)')
+
+
+def unescape(s):
+    return re.sub(r"\\(.)", r"\1", s)
+
+
+def escape(c, quote):
+    if c in ("\\", quote):
+        return "\\" + c
+    return c
+
+
+def parse(path):
+    with open(path, encoding="utf-8") as f:
+        source = f.read()
+
+    start = source.index("fn fold_non_ascii_char")
+    end = source.index("_ => None", start)
+
+    foldings = {}
+    pending = []
+
+    for line in source[start:end].splitlines():
+        pending.extend(int(code, 16) for code in CHAR_RE.findall(line))
+        match = FOLDED_RE.search(line)
+
+        if match:
+            folded = unescape(match.group(1))
+            for code in pending:
+                assert code not in foldings, "duplicate U+%04X" % code
+                foldings[code] = folded
+            pending = []
+
+    assert not pending, "unterminated match arm"
+
+    return foldings
+
+
+def main():
+    check_unicodedata()
+
+    foldings = parse(sys.argv[1])
+    entries = []
+
+    for code, folded in SUPPLEMENT.items():
+        assert code not in foldings, "supplemented U+%04X" % code
+        foldings[code] = folded
+
+    for code in sorted(foldings):
+        folded = foldings[code]
+
+        if len(folded) == 1:
+            action = "CharMapAction::SubChar('%s')" % escape(folded, "'")
+        else:
+            action = 'CharMapAction::SubStr("%s")' % "".join(
+                escape(c, '"') for c in folded
+            )
+
+        name = unicodedata.name(chr(code), "U+%04X" % code)
+        entries.append("    ('\\u{%04X}', %s), // %s\n" % (code, action, name))
+
+    with open(OUTPUT_PATH, "w", encoding="utf-8") as f:
+        f.write(HEADER.format(count=len(entries)))
+        f.writelines(entries)
+        f.write(FOOTER)
+
+
+if __name__ == "__main__":
+    main()
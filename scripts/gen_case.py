@@ -0,0 +1,443 @@
+#!/usr/bin/env python3
+"""Generates src/maps/case.rs from Unicode data.
+
+* LOWERCASE, UPPERCASE, and TITLECASE map every character to its full case
+  mapping (UnicodeData.txt and the unconditional mappings of
+  SpecialCasing.txt), e.g. ß to SS and İ to i̇.
+* CASE_FOLD maps every character to its full case folding (CaseFolding.txt
+  statuses C and F), e.g. ß to ss.
+* CASE_IGNORABLE lists the ranges of characters with the Case_Ignorable
+  property, which is used to apply the Final_Sigma condition and to titlecase
+  words.
+
+The Unicode data used is the one bundled with the running Python interpreter.
+
+Usage: python3 scripts/gen_case.py
+"""
+
+import os
+import sys
+import unicodedata
+
+from unicode_version import UNICODE_VERSION, check_unicodedata
+
+OUTPUT_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    os.pardir,
+    "src",
+    "maps",
+    "case.rs",
+)
+
+# Characters with Word_Break=MidLetter, MidNumLet, or Single_Quote, which are
+# case-ignorable in addition to the general categories below.
+WORD_BREAK_IGNORABLE = [
+    0x0027, 0x002E, 0x003A, 0x00B7, 0x0387, 0x055F, 0x05F4, 0x2018, 0x2019,
+    0x2024, 0x2027, 0xFE13, 0xFE52, 0xFE55, 0xFF07, 0xFF0E, 0xFF1A,
+]
+IGNORABLE_CATEGORIES = ("Mn", "Me", "Cf", "Lm", "Sk")
+
+ESCAPED_CATEGORIES = ("Mn", "Mc", "Me", "Cf", "Zs", "Cc")
+
+HEADER = """\
+//! Unicode case mapping maps.
+//!
+//! * [`LOWERCASE`], [`UPPERCASE`], and [`TITLECASE`] map characters to their
+//!   full case mappings, including multi-character mappings (e.g. ß `SS`, ŉ
+//!   `ʼN`, and İ `i̇`).
+//! * [`CASE_FOLD`] maps characters to their full case foldings (e.g. ß `ss`),
+//!   which should be used for caseless matching.
+//! * [`TURKIC_LOWERCASE`], [`TURKIC_UPPERCASE`], and [`TURKIC_CASE_FOLD`]
+//!   tailor the maps above for Turkish and Azeri (e.g. I `ı` and i `İ`), and
+//!   should be combined with them using pairs (e.g. `(&TURKIC_LOWERCASE,
+//!   &LOWERCASE)`).
+//!
+//! [`CaseMapper`] selects the right maps for a given [`Case`] and optional
+//! Turkic tailoring. Used as an [`ActionMap`], it maps each character
+//! independently, so rules that depend on the surrounding characters are not
+//! applied:
+//!
+//! * Final sigma: Σ is lowercased to ς at the end of a word.
+//! * Titlecasing: only the first letter of each word is titlecased, and the
+//!   rest of the word is lowercased. Words are runs of letters and digits,
+//!   which may contain case-ignorable characters (e.g. `they're`).
+//! * Turkic: I followed by U+0307 COMBINING DOT ABOVE is lowercased to i.
+//!
+//! [`CaseMapper::case_chars`] applies these rules as well.
+//!
+//! ```rust
+//! use charmap::maps::case::{{Case, CaseMapper}};
+//! use charmap::*;
+//!
+//! let upper = CaseMapper::new(Case::Upper);
+//! let mapper = CharMapper::new(&upper, CharMapAction::Pass);
+//!
+//! let mapped: String = "Straße".map_chars(&mapper).collect();
+//! assert_eq!(mapped, "STRASSE");
+//!
+//! let lower = CaseMapper::new(Case::Lower).turkic(true);
+//! let mapped: String = lower.case_chars("DİYARBAKIR".chars()).collect();
+//! assert_eq!(mapped, "diyarbakır");
+//!
+//! let title = CaseMapper::new(Case::Title);
+//! let mapped: String = title.case_chars("ΟΔΥΣΣΕΥΣ and ǆungla".chars()).collect();
+//! assert_eq!(mapped, "Οδυσσευς And ǅungla");
+//! ```
+//!
+//! This file is generated by `scripts/gen_case.py` from Unicode {version}
+//! data. Do not edit it manually.
+
+use core::cmp::Ordering;
+use core::iter;
+use core::{{option, str::Chars}};
+
+use crate::actionmap::{{assert_sorted, ActionMap, CharMapAction}};
+"""
+
+TABLE_HEADER = """
+/// {doc}
+#[rustfmt::skip]
+pub static {name}: [(char, CharMapAction); {count}] = [
+"""
+
+TABLE_FOOTER = """\
+];
+const _: () = assert_sorted(&{name});
+"""
+
+RANGES_HEADER = """
+// Ranges of characters with the Case_Ignorable property.
+#[rustfmt::skip]
+static CASE_IGNORABLE: [(char, char); {count}] = [
+"""
+
+RANGES_FOOTER = """\
+];
+"""
+
+FOOTER = r"""
+/// Map tailoring [`LOWERCASE`] for Turkish and Azeri.
+pub static TURKIC_LOWERCASE: [(char, CharMapAction); 2] = [
+    ('\u{0049}', CharMapAction::SubChar('\u{0131}')), // LATIN CAPITAL LETTER I
+    ('\u{0130}', CharMapAction::SubChar('\u{0069}')), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+];
+const _: () = assert_sorted(&TURKIC_LOWERCASE);
+
+/// Map tailoring [`UPPERCASE`] and [`TITLECASE`] for Turkish and Azeri.
+pub static TURKIC_UPPERCASE: [(char, CharMapAction); 1] = [
+    ('\u{0069}', CharMapAction::SubChar('\u{0130}')), // LATIN SMALL LETTER I
+];
+
+/// Map tailoring [`CASE_FOLD`] for Turkish and Azeri.
+pub static TURKIC_CASE_FOLD: [(char, CharMapAction); 2] = [
+    ('\u{0049}', CharMapAction::SubChar('\u{0131}')), // LATIN CAPITAL LETTER I
+    ('\u{0130}', CharMapAction::SubChar('\u{0069}')), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+];
+const _: () = assert_sorted(&TURKIC_CASE_FOLD);
+
+/// A case mapping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Lowercase, e.g. `"Straße"` to `"straße"`.
+    Lower,
+    /// Uppercase, e.g. `"Straße"` to `"STRASSE"`.
+    Upper,
+    /// Titlecase, e.g. `"straße"` to `"Straße"`.
+    Title,
+    /// Case folding, e.g. `"Straße"` to `"strasse"`.
+    Fold,
+}
+
+/// An [`ActionMap`] mapping characters to a given [`Case`], optionally with
+/// Turkish and Azeri tailoring.
+///
+/// Used as an [`ActionMap`], a [`CaseMapper`] applies the maps of its case,
+/// ignoring context-dependent rules (e.g. [`Case::Title`] titlecases every
+/// character). Use [`CaseMapper::case_chars`] to apply them as well.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CaseMapper {
+    case: Case,
+    turkic: bool,
+}
+
+impl CaseMapper {
+    /// Creates a new [`CaseMapper`] for a given [`Case`] without Turkic
+    /// tailoring.
+    #[inline]
+    pub const fn new(case: Case) -> Self {
+        CaseMapper { case, turkic: false }
+    }
+
+    /// Toggles the Turkish and Azeri tailoring.
+    #[inline]
+    pub const fn turkic(mut self, enabled: bool) -> Self {
+        self.turkic = enabled;
+        self
+    }
+
+    /// Returns the [`Case`] of this [`CaseMapper`].
+    #[inline]
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    /// Returns an iterator that maps the case of characters from a given
+    /// character iterator, applying context-dependent rules as well.
+    ///
+    /// The character iterator needs to be cloneable since the final sigma
+    /// rule looks ahead past case-ignorable characters (e.g. `'` and
+    /// combining marks).
+    #[inline]
+    pub fn case_chars<I>(&self, text_chars: I) -> CasedChars<I>
+    where
+        I: Iterator<Item = char> + Clone,
+    {
+        CasedChars {
+            mapper: *self,
+            text_chars,
+            after_cased: false,
+            in_word: false,
+            out_chars: None.into_iter().chain("".chars()),
+        }
+    }
+
+    fn case_action(
+        &self,
+        case: Case,
+        c: char,
+    ) -> Option<CharMapAction<'static>> {
+        let (tailoring, actions): (&'static [_], &'static [_]) = match case {
+            Case::Lower => (&TURKIC_LOWERCASE, &LOWERCASE),
+            Case::Upper => (&TURKIC_UPPERCASE, &UPPERCASE),
+            Case::Title => (&TURKIC_UPPERCASE, &TITLECASE),
+            Case::Fold => (&TURKIC_CASE_FOLD, &CASE_FOLD),
+        };
+
+        if self.turkic {
+            if let Some(action) = <[_] as ActionMap>::map_char(tailoring, c) {
+                return Some(action);
+            }
+        }
+
+        <[_] as ActionMap>::map_char(actions, c)
+    }
+}
+
+impl ActionMap for CaseMapper {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.case_action(self.case, c)
+    }
+}
+
+/// Iterator returned by [`CaseMapper::case_chars`].
+#[derive(Clone)]
+pub struct CasedChars<I>
+where
+    I: Iterator<Item = char> + Clone,
+{
+    mapper: CaseMapper,
+    text_chars: I,
+    // Whether the last character that is not case-ignorable is cased, and
+    // whether it is alphanumeric.
+    after_cased: bool,
+    in_word: bool,
+    out_chars: iter::Chain<option::IntoIter<char>, Chars<'static>>,
+}
+
+impl<I> CasedChars<I>
+where
+    I: Iterator<Item = char> + Clone,
+{
+    fn action(&mut self, c: char) -> Option<CharMapAction<'static>> {
+        // Titlecasing only applies to the first cased letter of each word.
+        let case = match self.mapper.case {
+            Case::Title if self.in_word => Case::Lower,
+            case => case,
+        };
+
+        if case == Case::Lower {
+            if c == '\u{03A3}' && self.after_cased && !self.before_cased() {
+                return Some(CharMapAction::SubChar('\u{03C2}'));
+            }
+
+            if self.mapper.turkic
+                && c == '\u{0049}'
+                && self.text_chars.clone().next() == Some('\u{0307}')
+            {
+                self.text_chars.next();
+                return Some(CharMapAction::SubChar('\u{0069}'));
+            }
+        }
+
+        self.mapper.case_action(case, c)
+    }
+
+    // Returns whether the next character that is not case-ignorable is cased.
+    fn before_cased(&self) -> bool {
+        self.text_chars
+            .clone()
+            .find(|&c| !is_case_ignorable(c))
+            .is_some_and(is_cased)
+    }
+}
+
+impl<I> Iterator for CasedChars<I>
+where
+    I: Iterator<Item = char> + Clone,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.out_chars.next() {
+                return Some(c);
+            }
+
+            let c = self.text_chars.next()?;
+            let (d, s) = match self.action(c) {
+                None | Some(CharMapAction::Pass) => (Some(c), ""),
+                Some(CharMapAction::Delete) => (None, ""),
+                Some(CharMapAction::SubChar(d)) => (Some(d), ""),
+                Some(CharMapAction::SubStr(s)) => (None, s),
+            };
+
+            if !is_case_ignorable(c) {
+                self.after_cased = is_cased(c);
+                self.in_word = c.is_alphanumeric();
+            }
+
+            self.out_chars = d.into_iter().chain(s.chars());
+        }
+    }
+}
+
+// Returns whether a given character has the Cased property.
+#[inline]
+fn is_cased(c: char) -> bool {
+    c.is_lowercase()
+        || c.is_uppercase()
+        || matches!(
+            c,
+            '\u{01C5}'
+                | '\u{01C8}'
+                | '\u{01CB}'
+                | '\u{01F2}'
+                | '\u{1F88}'..='\u{1F8F}'
+                | '\u{1F98}'..='\u{1F9F}'
+                | '\u{1FA8}'..='\u{1FAF}'
+                | '\u{1FBC}'
+                | '\u{1FCC}'
+                | '\u{1FFC}'
+        )
+}
+
+// Returns whether a given character has the Case_Ignorable property.
+#[inline]
+fn is_case_ignorable(c: char) -> bool {
+    CASE_IGNORABLE
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+"""
+
+
+def escape(c, quote):
+    if c in (quote, "\\"):
+        return "\\" + c
+    if unicodedata.category(c) in ESCAPED_CATEGORIES:
+        return "\\u{%04X}" % ord(c)
+    return c
+
+
+def action(s):
+    if len(s) == 1:
+        return "CharMapAction::SubChar('%s')" % escape(s, "'")
+    return 'CharMapAction::SubStr("%s")' % "".join(escape(c, '"') for c in s)
+
+
+def chars():
+    for code in range(sys.maxunicode + 1):
+        c = chr(code)
+        if unicodedata.category(c) not in ("Cs", "Cn"):
+            yield c
+
+
+def mapping(f):
+    table = {}
+    for c in chars():
+        mapped = f(c)
+        if mapped != c:
+            table[c] = mapped
+    return table
+
+
+def case_ignorable():
+    codes = [
+        ord(c)
+        for c in chars()
+        if unicodedata.category(c) in IGNORABLE_CATEGORIES
+        or ord(c) in WORD_BREAK_IGNORABLE
+    ]
+
+    ranges = []
+    for code in codes:
+        if ranges and ranges[-1][1] == code - 1:
+            ranges[-1][1] = code
+        else:
+            ranges.append([code, code])
+    return ranges
+
+
+def write_table(f, name, doc, table):
+    f.write(TABLE_HEADER.format(doc=doc, name=name, count=len(table)))
+
+    for c, s in sorted(table.items()):
+        f.write(
+            "    ('\\u{%04X}', %s), // %s\n"
+            % (ord(c), action(s), unicodedata.name(c))
+        )
+
+    f.write(TABLE_FOOTER.format(name=name))
+
+
+def main():
+    check_unicodedata()
+
+    tables = [
+        ("LOWERCASE", "Map from characters to their full lowercase mappings.",
+         mapping(str.lower)),
+        ("UPPERCASE", "Map from characters to their full uppercase mappings.",
+         mapping(str.upper)),
+        ("TITLECASE", "Map from characters to their full titlecase mappings.",
+         mapping(str.title)),
+        ("CASE_FOLD", "Map from characters to their full case foldings.",
+         mapping(str.casefold)),
+    ]
+    ranges = case_ignorable()
+
+    with open(OUTPUT_PATH, "w", encoding="utf-8") as f:
+        f.write(HEADER.format(version=UNICODE_VERSION))
+
+        for name, doc, table in tables:
+            write_table(f, name, doc, table)
+
+        f.write(FOOTER)
+
+        f.write(RANGES_HEADER.format(count=len(ranges)))
+        for start, end in ranges:
+            f.write("    ('\\u{%04X}', '\\u{%04X}'),\n" % (start, end))
+        f.write(RANGES_FOOTER)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,141 @@
+#!/usr/bin/env python3
+"""Generates src/maps/confusables.rs from Unicode confusables data.
+
+Every character in the first column of confusables.txt (UTS #39) is mapped to
+its prototype in the second column.
+
+The data file can be downloaded from
+https://www.unicode.org/Public/security/14.0.0/confusables.txt.
+
+Usage: python3 scripts/gen_confusables.py path/to/confusables.txt
+"""
+
+import os
+import re
+import sys
+import unicodedata
+
+from unicode_version import UNICODE_VERSION, check_version
+
+OUTPUT_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    os.pardir,
+    "src",
+    "maps",
+    "confusables.rs",
+)
+
+LINE_RE = re.compile(r"^([0-9A-F]+) ;\t((?:[0-9A-F]+ )*[0-9A-F]+) ;\t\w+")
+VERSION_RE = re.compile(r"^#\s*Version:\s*(\S+)")
+
+HEADER = """\
+//! Confusable character (homoglyph) map from UTS #39.
+//!
+//! Maps visually confusable characters to their prototypes (e.g. Cyrillic а
+//! to Latin `a`, Greek ο to Latin `o`, and mathematical bold 𝐀 to `A`). Some
+//! characters are mapped to multiple characters (e.g. ǆ to `dž`), and some
+//! ASCII characters are mapped as well (e.g. `m` to `rn` and `1` to `l`).
+//!
+//! The map is intended to be applied to NFD text. [`skeleton`] does so and
+//! computes the UTS #39 skeleton of a string, and [`is_confusable`] compares
+//! the skeletons of two strings.
+//!
+//! ```rust
+//! use charmap::maps::confusables::{{is_confusable, skeleton}};
+//!
+//! assert_eq!(skeleton("gооglе"), "google");
+//! assert!(is_confusable("𝐩𝐚𝐲𝐩𝐚𝐥", "paypal"));
+//! assert!(!is_confusable("paypal", "paypai"));
+//! ```
+//!
+//! This file is generated by `scripts/gen_confusables.py` from Unicode
+//! {version} `confusables.txt`. Do not edit it manually.
+
+#[cfg(feature = "std")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::actionmap::{{assert_sorted, CharMapAction}};
+#[cfg(feature = "std")]
+use crate::charmapper::{{CharMapper, MapCharsIter}};
+
+/// Map from confusable characters to their prototypes.
+#[rustfmt::skip]
+pub static CONFUSABLES: [(char, CharMapAction); {count}] = [
+"""
+
+FOOTER = """\
+];
+const _: () = assert_sorted(&CONFUSABLES);
+
+/// Returns the UTS #39 skeleton of a given string, i.e. its NFD form with
+/// every confusable character replaced by its prototype, normalized to NFD
+/// again.
+#[cfg(feature = "std")]
+pub fn skeleton(s: &str) -> String {
+    let mapper = CharMapper::new(&CONFUSABLES, CharMapAction::Pass);
+
+    s.nfd().map_chars(&mapper).nfd().collect()
+}
+
+/// Returns whether two given strings are confusable, i.e. whether they have
+/// the same [`skeleton`].
+#[cfg(feature = "std")]
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    skeleton(a) == skeleton(b)
+}
+"""
+
+
+def escape(c, quote):
+    if c in (quote, "\\"):
+        return "\\" + c
+    if " " <= c <= "~":
+        return c
+    return "\\u{%04X}" % ord(c)
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(__doc__.strip().splitlines()[-1])
+
+    version = None
+    entries = []
+
+    with open(sys.argv[1], encoding="utf-8-sig") as f:
+        for line in f:
+            m = VERSION_RE.match(line)
+            if m and version is None:
+                version = m.group(1)
+                continue
+
+            m = LINE_RE.match(line)
+            if not m:
+                continue
+
+            c = chr(int(m.group(1), 16))
+            prototype = "".join(chr(int(p, 16)) for p in m.group(2).split())
+            entries.append((c, prototype))
+
+    check_version(version, sys.argv[1])
+    entries.sort()
+
+    with open(OUTPUT_PATH, "w", encoding="utf-8") as f:
+        f.write(HEADER.format(version=UNICODE_VERSION, count=len(entries)))
+
+        for c, prototype in entries:
+            if len(prototype) == 1:
+                action = "CharMapAction::SubChar('%s')" % escape(prototype, "'")
+            else:
+                action = 'CharMapAction::SubStr("%s")' % "".join(
+                    escape(p, '"') for p in prototype
+                )
+
+            name = unicodedata.name(c, "")
+            comment = " // %s" % name if name else ""
+            f.write("    ('\\u{%04X}', %s),%s\n" % (ord(c), action, comment))
+
+        f.write(FOOTER)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,189 @@
+#!/usr/bin/env python3
+"""Generates src/maps/greek/tables.rs from Unicode data.
+
+* STRIP_ACCENTS maps every letter in the Greek and Coptic block (U+0370-U+03FF)
+  whose canonical decomposition is a base letter followed by tonos and/or
+  dialytika to that base letter, and deletes the tonos and dialytika marks
+  themselves.
+* FOLD_POLYTONIC maps every letter in the Greek Extended block (U+1F00-U+1FFF)
+  to the base letter of its canonical decomposition, and deletes the spacing
+  and combining polytonic diacritics.
+* ELOT_743 transliterates the base letters of the Greek alphabet to Latin
+  according to ELOT 743, along with every accented letter covered by the two
+  maps above.
+
+The context-dependent ELOT 743 rules (`Romanizer`) are written by hand in
+src/maps/greek/mod.rs.
+
+The Unicode data used is the one bundled with the running Python interpreter.
+
+Usage: python3 scripts/gen_greek.py
+"""
+
+import os
+import unicodedata
+
+from unicode_version import UNICODE_VERSION, check_unicodedata
+
+GREEK_AND_COPTIC = (0x0370, 0x03FF)
+GREEK_EXTENDED = (0x1F00, 0x1FFF)
+
+# Tonos and dialytika, as combining and spacing marks.
+MONOTONIC_MARKS = ["\u0301", "\u0308", "\u0344", "\u0384", "\u0385"]
+
+# Combining polytonic diacritics: varia, psili, dasia, perispomeni, koronis,
+# and ypogegrammeni.
+POLYTONIC_MARKS = ["\u0300", "\u0313", "\u0314", "\u0342", "\u0343", "\u0345"]
+
+ELOT_743 = {
+    "α": "a", "β": "v", "γ": "g", "δ": "d", "ε": "e", "ζ": "z", "η": "i",
+    "θ": "th", "ι": "i", "κ": "k", "λ": "l", "μ": "m", "ν": "n", "ξ": "x",
+    "ο": "o", "π": "p", "ρ": "r", "σ": "s", "ς": "s", "τ": "t", "υ": "y",
+    "φ": "f", "χ": "ch", "ψ": "ps", "ω": "o",
+}
+
+# Greek question mark and ano teleia.
+ELOT_743_PUNCTUATION = {"\u037E": "?", "\u0387": ";"}
+
+OUTPUT_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    os.pardir,
+    "src",
+    "maps",
+    "greek",
+    "tables.rs",
+)
+
+HEADER = """\
+// This file is generated by `scripts/gen_greek.py` from Unicode {version}
+// data. Do not edit it manually.
+
+use crate::actionmap::{{assert_sorted, CharMapAction}};
+"""
+
+TABLE_HEADER = """
+/// {doc}
+#[rustfmt::skip]
+pub static {name}: [(char, CharMapAction); {count}] = [
+"""
+
+TABLE_FOOTER = """\
+];
+const _: () = assert_sorted(&{name});
+"""
+
+
+def escape(c):
+    if unicodedata.category(c) in ("Mn", "Sk"):
+        return "\\u{%04X}" % ord(c)
+    return c
+
+
+def short_name(c):
+    return unicodedata.name(c).replace("GREEK ", "")
+
+
+def action(s):
+    if s == "":
+        return "CharMapAction::Delete"
+    if len(s) == 1:
+        return "CharMapAction::SubChar('%s')" % escape(s)
+    return 'CharMapAction::SubStr("%s")' % "".join(escape(c) for c in s)
+
+
+def block(start, end):
+    for code in range(start, end + 1):
+        c = chr(code)
+        if unicodedata.category(c) != "Cn":
+            yield c
+
+
+def strip_accents():
+    table = {c: "" for c in MONOTONIC_MARKS}
+
+    for c in block(*GREEK_AND_COPTIC):
+        decomposed = unicodedata.normalize("NFD", c)
+        base, marks = decomposed[0], decomposed[1:]
+
+        if (
+            marks
+            and unicodedata.category(base).startswith("L")
+            and all(m in MONOTONIC_MARKS for m in marks)
+        ):
+            table[c] = base
+
+    return table
+
+
+def fold_polytonic():
+    table = {c: "" for c in POLYTONIC_MARKS}
+
+    for c in block(*GREEK_EXTENDED):
+        if unicodedata.category(c) == "Sk":
+            table[c] = ""
+            continue
+
+        base = unicodedata.normalize("NFD", c)[0]
+        assert unicodedata.category(base).startswith("L"), c
+        table[c] = base
+
+    return table
+
+
+def elot_743(strip, fold):
+    table = dict(ELOT_743_PUNCTUATION)
+
+    for lower, latin in ELOT_743.items():
+        table[lower] = latin
+        if lower != "ς":
+            table[lower.upper()] = latin[0].upper() + latin[1:]
+
+    for c, base in list(strip.items()) + list(fold.items()):
+        if base == "" or base in table:
+            table[c] = table.get(base, "")
+
+    return table
+
+
+def write_table(f, name, doc, table):
+    f.write(TABLE_HEADER.format(doc=doc, name=name, count=len(table)))
+
+    for c, s in sorted(table.items()):
+        f.write(
+            "    ('\\u{%04X}', %s), // %s\n" % (ord(c), action(s), short_name(c))
+        )
+
+    f.write(TABLE_FOOTER.format(name=name))
+
+
+def main():
+    check_unicodedata()
+
+    strip = strip_accents()
+    fold = fold_polytonic()
+    elot = elot_743(strip, fold)
+
+    with open(OUTPUT_PATH, "w", encoding="utf-8") as f:
+        f.write(HEADER.format(version=UNICODE_VERSION))
+        write_table(
+            f,
+            "STRIP_ACCENTS",
+            "Map removing tonos and dialytika from Greek letters.",
+            strip,
+        )
+        write_table(
+            f,
+            "FOLD_POLYTONIC",
+            "Map folding polytonic Greek letters to their base letters.",
+            fold,
+        )
+        write_table(
+            f,
+            "ELOT_743",
+            "Greek to Latin ELOT 743 transliteration map (context-free form).",
+            elot,
+        )
+
+
+if __name__ == "__main__":
+    main()
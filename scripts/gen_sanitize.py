@@ -0,0 +1,87 @@
+#!/usr/bin/env python3
+"""Generates src/maps/sanitize/tables.rs from Unicode emoji data.
+
+EXTENDED_PICTOGRAPHIC lists the ranges of characters with the
+Extended_Pictographic property (emoji-data.txt, UTS #51), merging adjacent
+ranges. It is used to keep ZWJs inside emoji sequences.
+
+The data file can be downloaded from
+https://www.unicode.org/Public/14.0.0/ucd/emoji/emoji-data.txt.
+
+Usage: python3 scripts/gen_sanitize.py path/to/emoji-data.txt
+"""
+
+import os
+import re
+import sys
+
+from unicode_version import UNICODE_VERSION, check_version
+
+OUTPUT_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    os.pardir,
+    "src",
+    "maps",
+    "sanitize",
+    "tables.rs",
+)
+
+LINE_RE = re.compile(
+    r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*Extended_Pictographic\s*(#|$)"
+)
+VERSION_RE = re.compile(r"^#.*\bEmoji Version (\d+\.\d+)")
+
+HEADER = """\
+// This file is generated by `scripts/gen_sanitize.py` from Unicode {version}
+// `emoji-data.txt`. Do not edit it manually.
+
+// Ranges of characters with the Extended_Pictographic property.
+#[rustfmt::skip]
+pub(super) static EXTENDED_PICTOGRAPHIC: [(char, char); {count}] = [
+"""
+
+FOOTER = """\
+];
+"""
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(__doc__.strip().splitlines()[-1])
+
+    version = None
+    ranges = []
+
+    with open(sys.argv[1], encoding="utf-8-sig") as f:
+        for line in f:
+            m = VERSION_RE.match(line)
+            if m and version is None:
+                version = m.group(1) + ".0"
+                continue
+
+            m = LINE_RE.match(line)
+            if not m:
+                continue
+
+            start = int(m.group(1), 16)
+            end = int(m.group(2) or m.group(1), 16)
+            ranges.append((start, end))
+
+    check_version(version, sys.argv[1])
+
+    merged = []
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1] = (merged[-1][0], max(merged[-1][1], end))
+        else:
+            merged.append((start, end))
+
+    with open(OUTPUT_PATH, "w", encoding="utf-8") as f:
+        f.write(HEADER.format(version=UNICODE_VERSION, count=len(merged)))
+        for start, end in merged:
+            f.write("    ('\\u{%04X}', '\\u{%04X}'),\n" % (start, end))
+        f.write(FOOTER)
+
+
+if __name__ == "__main__":
+    main()
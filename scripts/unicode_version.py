@@ -0,0 +1,24 @@
+"""The Unicode version that all generated tables are pinned to.
+
+Scripts using the Unicode data bundled with the running Python interpreter
+check that it is of this version, and scripts reading Unicode data files check
+that the files are of this version, so that all maps agree on the assigned
+characters and their properties.
+"""
+
+import sys
+import unicodedata
+
+UNICODE_VERSION = "14.0.0"
+
+
+def check_version(version, source):
+    if version != UNICODE_VERSION:
+        sys.exit(
+            "%s is from Unicode %s, but the tables are pinned to Unicode %s"
+            % (source, version, UNICODE_VERSION)
+        )
+
+
+def check_unicodedata():
+    check_version(unicodedata.unidata_version, "Python's unicodedata")
@@ -1,6 +1,6 @@
 /// An enum representing an action to be taken by a
 /// [`CharMapper`](super::CharMapper) when it sees a certain [`char`].
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CharMapAction<'a> {
     /// Output the character as is.
     Pass,
@@ -14,49 +14,409 @@ pub enum CharMapAction<'a> {
 
 /// A trait that should be implemented by any struct providing char-to-action
 /// mappings for [`CharMapper`](super::CharMapper).
+///
+/// Actions are returned by value so that maps which compute their actions on
+/// the fly (e.g. closures wrapped in [`FnMap`]) can implement this trait as
+/// well.
+///
+/// [`FnMap`] only accepts closures returning `Option<CharMapAction<'static>>`,
+/// so they cannot return a [`CharMapAction::SubStr`] borrowing data they
+/// captured (e.g. a `String`). Such maps can implement this trait directly
+/// instead:
+///
+/// ```rust
+/// use charmap::*;
+///
+/// struct DigitSub(String);
+///
+/// impl ActionMap for DigitSub {
+///     fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+///         if c.is_ascii_digit() {
+///             Some(CharMapAction::SubStr(&self.0))
+///         } else {
+///             None
+///         }
+///     }
+/// }
+///
+/// let actions = DigitSub(String::from("<d>"));
+/// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+///
+/// let mapped: String = "a1".map_chars(&mapper).collect();
+/// assert_eq!(mapped, "a<d>");
+/// ```
+///
+/// References, `Box`es, [`Rc`](std::rc::Rc)s, and [`Arc`](std::sync::Arc)s
+/// of action maps (including trait objects) are action maps themselves, so
+/// they can be used interchangeably.
 pub trait ActionMap {
     /// Map a character to its respective CharMapAction.
-    fn map_char(&'_ self, c: char) -> Option<&'_ CharMapAction<'_>>;
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>>;
+
+    /// Returns an iterator over the characters this map has actions for, or
+    /// `None` if they cannot be listed (e.g. for closures).
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        None
+    }
 }
 
+/// Boxed character iterator returned by [`ActionMap::keys`].
+#[cfg(feature = "std")]
+pub type Keys<'a> = std::boxed::Box<dyn Iterator<Item = char> + 'a>;
+
 // ====== ActionMap implementations for most commonly used maps ====== //
 
 #[cfg(feature = "std")]
 impl ActionMap for std::collections::HashMap<char, CharMapAction<'_>> {
     #[inline]
-    fn map_char(&'_ self, c: char) -> Option<&'_ CharMapAction<'_>> {
-        self.get(&c)
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.get(&c).copied()
+    }
+
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(Box::new(self.keys().copied()))
     }
 }
 
 #[cfg(feature = "std")]
 impl ActionMap for std::collections::BTreeMap<char, CharMapAction<'_>> {
     #[inline]
-    fn map_char(&'_ self, c: char) -> Option<&'_ CharMapAction<'_>> {
-        self.get(&c)
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.get(&c).copied()
+    }
+
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(Box::new(self.keys().copied()))
     }
 }
 
 #[cfg(feature = "hashbrown")]
 impl ActionMap for hashbrown::HashMap<char, CharMapAction<'_>> {
     #[inline]
-    fn map_char(&'_ self, c: char) -> Option<&'_ CharMapAction<'_>> {
-        self.get(&c)
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.get(&c).copied()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(Box::new(self.keys().copied()))
     }
 }
 
 #[cfg(feature = "phf")]
 impl ActionMap for phf::Map<char, CharMapAction<'_>> {
     #[inline]
-    fn map_char(&'_ self, c: char) -> Option<&'_ CharMapAction<'_>> {
-        self.get(&c)
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.get(&c).copied()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(Box::new(self.keys().copied()))
     }
 }
 
 #[cfg(feature = "phf")]
 impl ActionMap for phf::OrderedMap<char, CharMapAction<'_>> {
     #[inline]
-    fn map_char(&'_ self, c: char) -> Option<&'_ CharMapAction<'_>> {
-        self.get(&c)
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.get(&c).copied()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(Box::new(self.keys().copied()))
+    }
+}
+
+// ====== ActionMap implementations for sorted slices and arrays ====== //
+
+// NOTE: Slices and arrays are looked up using binary search and must therefore
+// be sorted by character. Use `assert_sorted` to check this at compile time.
+impl ActionMap for [(char, CharMapAction<'_>)] {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        match self.binary_search_by_key(&c, |(k, _)| *k) {
+            Ok(idx) => Some(self[idx].1),
+            Err(_) => None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(Box::new(self.iter().map(|(c, _)| *c)))
+    }
+}
+
+impl<const N: usize> ActionMap for [(char, CharMapAction<'_>); N] {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.as_slice().map_char(c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        self.as_slice().keys()
+    }
+}
+
+/// Panics if the given slice of char-action pairs is not sorted by character
+/// or contains duplicate characters.
+///
+/// Since this is a `const fn`, it can be used to reject misordered tables at
+/// compile time:
+///
+/// ```rust
+/// use charmap::{assert_sorted, CharMapAction};
+///
+/// const ACTIONS: [(char, CharMapAction); 2] =
+///     [('a', CharMapAction::Delete), ('b', CharMapAction::SubChar('c'))];
+/// const _: () = assert_sorted(&ACTIONS);
+/// ```
+///
+/// ```rust,compile_fail
+/// use charmap::{assert_sorted, CharMapAction};
+///
+/// const ACTIONS: [(char, CharMapAction); 2] =
+///     [('b', CharMapAction::SubChar('c')), ('a', CharMapAction::Delete)];
+/// const _: () = assert_sorted(&ACTIONS);
+/// ```
+pub const fn assert_sorted(actions: &[(char, CharMapAction<'_>)]) {
+    let mut i = 1;
+
+    while i < actions.len() {
+        let prev = actions[i - 1].0 as u32;
+        let curr = actions[i].0 as u32;
+
+        if prev == curr {
+            panic!("action map contains duplicate characters");
+        } else if prev > curr {
+            panic!("action map is not sorted by character");
+        }
+
+        i += 1;
+    }
+}
+
+// ====== ActionMap implementations for combined maps ====== //
+
+// NOTE: Pairs of maps act as a single map where the first map takes
+// precedence. Larger combinations can be built by nesting pairs.
+impl<A, B> ActionMap for (A, B)
+where
+    A: ActionMap,
+    B: ActionMap,
+{
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.0.map_char(c).or_else(|| self.1.map_char(c))
+    }
+
+    // Keys of the second map that are shadowed by the first are only listed
+    // once.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        let first = self.0.keys()?;
+        let second = self.1.keys()?;
+
+        Some(Box::new(
+            first.chain(second.filter(|c| self.0.map_char(*c).is_none())),
+        ))
+    }
+}
+
+// ====== ActionMap implementations for closures and pointer types ====== //
+
+/// A wrapper that turns a closure (or function) into an [`ActionMap`].
+///
+/// The wrapped closure is restricted to returning `'static` actions since the
+/// returned action needs to outlive any borrow of the closure itself.
+///
+/// ```rust
+/// use charmap::*;
+///
+/// let actions = FnMap(|c: char| {
+///     if c.is_ascii_digit() {
+///         Some(CharMapAction::SubChar('#'))
+///     } else {
+///         None
+///     }
+/// });
+/// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+///
+/// let mapped: String = "a1b2".map_chars(&mapper).collect();
+/// assert_eq!(mapped, "a#b#");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FnMap<F>(pub F);
+
+impl<F> ActionMap for FnMap<F>
+where
+    F: Fn(char) -> Option<CharMapAction<'static>>,
+{
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        (self.0)(c)
+    }
+}
+
+impl<M> ActionMap for &M
+where
+    M: ActionMap + ?Sized,
+{
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        (**self).map_char(c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        (**self).keys()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> ActionMap for std::boxed::Box<M>
+where
+    M: ActionMap + ?Sized,
+{
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        (**self).map_char(c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        (**self).keys()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> ActionMap for std::rc::Rc<M>
+where
+    M: ActionMap + ?Sized,
+{
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        (**self).map_char(c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        (**self).keys()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> ActionMap for std::sync::Arc<M>
+where
+    M: ActionMap + ?Sized,
+{
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        (**self).map_char(c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        (**self).keys()
+    }
+}
+
+// ====== Per-rule toggles for bundled maps ====== //
+
+// A sorted action table with static actions.
+#[cfg(any(
+    feature = "arabic",
+    feature = "hebrew",
+    feature = "punctuation",
+    feature = "sanitize"
+))]
+pub(crate) type ActionTable = &'static [(char, CharMapAction<'static>)];
+
+// Toggles for bundled maps made up of several action tables that can be
+// enabled and disabled individually (e.g. `maps::arabic::Normalizer`). The
+// tables themselves are passed in on lookup, and enabled tables are looked up
+// in order, so earlier tables take precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg(any(
+    feature = "arabic",
+    feature = "hebrew",
+    feature = "punctuation",
+    feature = "sanitize"
+))]
+pub(crate) struct Toggles<const N: usize>([bool; N]);
+
+#[cfg(any(
+    feature = "arabic",
+    feature = "hebrew",
+    feature = "punctuation",
+    feature = "sanitize"
+))]
+impl<const N: usize> Toggles<N> {
+    pub(crate) const ALL: Self = Toggles([true; N]);
+    pub(crate) const NONE: Self = Toggles([false; N]);
+
+    #[inline]
+    pub(crate) const fn set(mut self, rule: usize, enabled: bool) -> Self {
+        self.0[rule] = enabled;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn map_char(
+        &self,
+        tables: &[ActionTable; N],
+        c: char,
+    ) -> Option<CharMapAction<'static>> {
+        tables
+            .iter()
+            .copied()
+            .zip(self.0)
+            .filter(|(_, enabled)| *enabled)
+            .find_map(|(table, _)| table.map_char(c))
+    }
+
+    // Keys shadowed by an earlier enabled table are only listed once.
+    #[cfg(feature = "std")]
+    pub(crate) fn keys(
+        self,
+        tables: &'static [ActionTable; N],
+    ) -> Keys<'static> {
+        Box::new(
+            tables
+                .iter()
+                .enumerate()
+                .filter(move |(rule, _)| self.0[*rule])
+                .flat_map(move |(rule, table)| {
+                    let earlier = Toggles(self.0).until(rule);
+
+                    table.iter().map(|(c, _)| *c).filter(move |c| {
+                        earlier.map_char(tables, *c).is_none()
+                    })
+                }),
+        )
+    }
+
+    // Returns these toggles with all rules from a given rule onwards disabled.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn until(mut self, rule: usize) -> Self {
+        self.0[rule..].fill(false);
+        self
     }
 }
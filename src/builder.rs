@@ -0,0 +1,482 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+use std::collections::HashMap;
+#[cfg(feature = "general_category")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "general_category")]
+use unicode_properties::{
+    GeneralCategory, GeneralCategoryGroup, UnicodeGeneralCategory,
+};
+
+use super::actionmap::{ActionMap, CharMapAction, Keys};
+use super::charmapper::CharMapper;
+
+// Maximum span of characters for which a dense lookup table is used.
+const DENSE_MAX_SPAN: u32 = 1024;
+
+/// Builder used to fluently construct a [`CharMap`].
+///
+/// Rules can be added in any order and may overlap as long as they agree on
+/// the action to take for the overlapping characters. Conflicting rules are
+/// reported by [`CharMapBuilder::build`].
+///
+/// ```rust
+/// use charmap::*;
+///
+/// let charmap = CharMapBuilder::new()
+///     .delete(['!', '?'])
+///     .delete_range('0'..='9')
+///     .sub_char('a', 'A')
+///     .sub_str('ß', "ss")
+///     .pass_matching(char::is_whitespace)
+///     .default(CharMapAction::SubChar('_'))
+///     .build()
+///     .unwrap();
+///
+/// let mapped_str: String = "a1 bß!".map_chars(&charmap.mapper()).collect();
+///
+/// assert_eq!(mapped_str, "A _ss");
+/// ```
+#[derive(Clone, Debug)]
+pub struct CharMapBuilder<'a> {
+    rules: Vec<Rule<'a>>,
+    default: CharMapAction<'a>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Rule<'a> {
+    start: char,
+    end: char,
+    action: CharMapAction<'a>,
+    order: usize,
+}
+
+impl<'a> CharMapBuilder<'a> {
+    /// Creates a new empty [`CharMapBuilder`] whose default action is
+    /// [`CharMapAction::Pass`].
+    #[inline]
+    pub fn new() -> Self {
+        CharMapBuilder { rules: Vec::new(), default: CharMapAction::Pass }
+    }
+
+    /// Maps a given character to a given action.
+    pub fn action(self, c: char, action: CharMapAction<'a>) -> Self {
+        self.action_range(c..=c, action)
+    }
+
+    /// Maps all characters in a given range to a given action.
+    pub fn action_range(
+        mut self,
+        range: RangeInclusive<char>,
+        action: CharMapAction<'a>,
+    ) -> Self {
+        let (start, end) = range.into_inner();
+
+        if start <= end {
+            let order = self.rules.len();
+            self.rules.push(Rule { start, end, action, order });
+        }
+
+        self
+    }
+
+    /// Maps all characters for which a given predicate returns `true` to a
+    /// given action (e.g. `char::is_whitespace`).
+    ///
+    /// Note that this calls the predicate on every Unicode scalar value, so
+    /// prefer [`CharMapBuilder::action_range`] where possible.
+    pub fn action_matching<P>(
+        mut self,
+        predicate: P,
+        action: CharMapAction<'a>,
+    ) -> Self
+    where
+        P: Fn(char) -> bool,
+    {
+        let mut start = None;
+        let mut end = '\0';
+
+        for c in '\0'..=char::MAX {
+            if predicate(c) {
+                if start.is_none() {
+                    start = Some(c);
+                }
+                end = c;
+            } else if let Some(s) = start.take() {
+                self = self.action_range(s..=end, action);
+            }
+        }
+
+        if let Some(s) = start {
+            self = self.action_range(s..=end, action);
+        }
+
+        self
+    }
+
+    /// Maps all characters of a given Unicode general category to a given
+    /// action.
+    #[cfg(feature = "general_category")]
+    pub fn action_category(
+        self,
+        category: GeneralCategory,
+        action: CharMapAction<'a>,
+    ) -> Self {
+        self.action_categories(|c| c.general_category() == category, action)
+    }
+
+    /// Maps all characters of a given group of Unicode general categories
+    /// (e.g. all letters) to a given action.
+    #[cfg(feature = "general_category")]
+    pub fn action_category_group(
+        self,
+        group: GeneralCategoryGroup,
+        action: CharMapAction<'a>,
+    ) -> Self {
+        self.action_categories(|c| c.general_category_group() == group, action)
+    }
+
+    // Maps all characters of the category ranges whose first character
+    // satisfies a given predicate to a given action. Since all characters of a
+    // range share the same general category, this only calls the predicate
+    // once per range.
+    #[cfg(feature = "general_category")]
+    fn action_categories<P>(
+        mut self,
+        predicate: P,
+        action: CharMapAction<'a>,
+    ) -> Self
+    where
+        P: Fn(char) -> bool,
+    {
+        let mut pending: Option<(char, char)> = None;
+
+        // Category ranges are contiguous, so consecutive matching ranges can
+        // be merged into a single rule.
+        for &(start, end) in category_ranges() {
+            if predicate(start) {
+                pending = Some((pending.map_or(start, |(s, _)| s), end));
+            } else if let Some((s, e)) = pending.take() {
+                self = self.action_range(s..=e, action);
+            }
+        }
+
+        if let Some((s, e)) = pending {
+            self = self.action_range(s..=e, action);
+        }
+
+        self
+    }
+
+    /// Deletes all given characters.
+    pub fn delete<I>(self, chars: I) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        chars.into_iter().fold(self, |b, c| b.action(c, CharMapAction::Delete))
+    }
+
+    /// Deletes all characters in a given range.
+    pub fn delete_range(self, range: RangeInclusive<char>) -> Self {
+        self.action_range(range, CharMapAction::Delete)
+    }
+
+    /// Deletes all characters for which a given predicate returns `true`.
+    pub fn delete_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(char) -> bool,
+    {
+        self.action_matching(predicate, CharMapAction::Delete)
+    }
+
+    /// Deletes all characters of a given Unicode general category.
+    #[cfg(feature = "general_category")]
+    pub fn delete_category(self, category: GeneralCategory) -> Self {
+        self.action_category(category, CharMapAction::Delete)
+    }
+
+    /// Deletes all characters of a given group of Unicode general categories.
+    #[cfg(feature = "general_category")]
+    pub fn delete_category_group(self, group: GeneralCategoryGroup) -> Self {
+        self.action_category_group(group, CharMapAction::Delete)
+    }
+
+    /// Outputs all given characters as they are.
+    pub fn pass<I>(self, chars: I) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        chars.into_iter().fold(self, |b, c| b.action(c, CharMapAction::Pass))
+    }
+
+    /// Outputs all characters in a given range as they are.
+    pub fn pass_range(self, range: RangeInclusive<char>) -> Self {
+        self.action_range(range, CharMapAction::Pass)
+    }
+
+    /// Outputs all characters for which a given predicate returns `true` as
+    /// they are.
+    pub fn pass_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(char) -> bool,
+    {
+        self.action_matching(predicate, CharMapAction::Pass)
+    }
+
+    /// Outputs all characters of a given Unicode general category as they
+    /// are.
+    #[cfg(feature = "general_category")]
+    pub fn pass_category(self, category: GeneralCategory) -> Self {
+        self.action_category(category, CharMapAction::Pass)
+    }
+
+    /// Outputs all characters of a given group of Unicode general categories
+    /// as they are.
+    #[cfg(feature = "general_category")]
+    pub fn pass_category_group(self, group: GeneralCategoryGroup) -> Self {
+        self.action_category_group(group, CharMapAction::Pass)
+    }
+
+    /// Substitutes a given character with another character.
+    pub fn sub_char(self, from: char, to: char) -> Self {
+        self.action(from, CharMapAction::SubChar(to))
+    }
+
+    /// Substitutes a given character with a string.
+    pub fn sub_str(self, from: char, to: &'a str) -> Self {
+        self.action(from, CharMapAction::SubStr(to))
+    }
+
+    /// Sets the action to take for characters not matched by any rule.
+    pub fn default(mut self, action: CharMapAction<'a>) -> Self {
+        self.default = action;
+        self
+    }
+
+    /// Builds a [`CharMap`] from the given rules, choosing the most suitable
+    /// lookup structure for them.
+    ///
+    /// Returns a [`ConflictError`] if two rules map the same character to
+    /// different actions.
+    pub fn build(mut self) -> Result<CharMap<'a>, ConflictError<'a>> {
+        self.rules.sort_by_key(|r| r.start);
+
+        // Merge rules into sorted, disjoint ranges, keeping track of the end
+        // and order of the rules making up the last range so that conflicts
+        // can be attributed to the rules that actually overlap.
+        let mut ranges: Vec<Rule> = Vec::with_capacity(self.rules.len());
+        let mut members: Vec<(char, usize)> = Vec::new();
+
+        for rule in self.rules {
+            match ranges.last_mut() {
+                Some(last) if rule.start <= last.end => {
+                    if rule.action != last.action {
+                        let order = members
+                            .iter()
+                            .filter(|(end, _)| *end >= rule.start)
+                            .map(|(_, order)| *order)
+                            .min()
+                            .unwrap_or(last.order);
+
+                        let (first, second) = if order < rule.order {
+                            (last.action, rule.action)
+                        } else {
+                            (rule.action, last.action)
+                        };
+
+                        return Err(ConflictError {
+                            c: rule.start,
+                            first,
+                            second,
+                        });
+                    }
+
+                    last.end = last.end.max(rule.end);
+                    members.push((rule.end, rule.order));
+                }
+                Some(last)
+                    if rule.action == last.action
+                        && rule.start as u32 == last.end as u32 + 1 =>
+                {
+                    last.end = rule.end;
+                    members.push((rule.end, rule.order));
+                }
+                _ => {
+                    members.clear();
+                    members.push((rule.end, rule.order));
+                    ranges.push(rule);
+                }
+            }
+        }
+
+        let actions = match (ranges.first(), ranges.last()) {
+            (Some(first), Some(last)) => {
+                let span = last.end as u32 - first.start as u32 + 1;
+                let n_chars: u32 = ranges
+                    .iter()
+                    .map(|r| r.end as u32 - r.start as u32 + 1)
+                    .sum();
+
+                if span <= DENSE_MAX_SPAN {
+                    let offset = first.start as u32;
+                    let mut table = Vec::new();
+                    table.resize(span as usize, None);
+
+                    for r in ranges {
+                        for c in r.start..=r.end {
+                            table[(c as u32 - offset) as usize] =
+                                Some(r.action);
+                        }
+                    }
+
+                    Actions::Dense { offset, table }
+                } else if n_chars as usize <= 2 * ranges.len() {
+                    Actions::Hash(
+                        ranges
+                            .iter()
+                            .flat_map(|r| {
+                                (r.start..=r.end).map(|c| (c, r.action))
+                            })
+                            .collect(),
+                    )
+                } else {
+                    Actions::Ranges(
+                        ranges
+                            .iter()
+                            .map(|r| (r.start, r.end, r.action))
+                            .collect(),
+                    )
+                }
+            }
+            _ => Actions::Empty,
+        };
+
+        Ok(CharMap { actions, default: self.default })
+    }
+}
+
+// Returns the ranges of characters sharing the same general category, covering
+// all Unicode scalar values. These are computed once on first use.
+#[cfg(feature = "general_category")]
+fn category_ranges() -> &'static [(char, char)] {
+    static RANGES: OnceLock<Vec<(char, char)>> = OnceLock::new();
+
+    RANGES.get_or_init(|| {
+        let mut ranges: Vec<(char, char)> = Vec::new();
+        let mut last = None;
+
+        for c in '\0'..=char::MAX {
+            let category = c.general_category();
+
+            match ranges.last_mut() {
+                Some((_, end)) if last == Some(category) => *end = c,
+                _ => ranges.push((c, c)),
+            }
+
+            last = Some(category);
+        }
+
+        ranges
+    })
+}
+
+impl Default for CharMapBuilder<'_> {
+    #[inline]
+    fn default() -> Self {
+        CharMapBuilder::new()
+    }
+}
+
+/// An [`ActionMap`] built by [`CharMapBuilder`], along with the default action
+/// given to the builder.
+#[derive(Clone, Debug)]
+pub struct CharMap<'a> {
+    actions: Actions<'a>,
+    default: CharMapAction<'a>,
+}
+
+#[derive(Clone, Debug)]
+enum Actions<'a> {
+    Empty,
+    Dense { offset: u32, table: Vec<Option<CharMapAction<'a>>> },
+    Ranges(Vec<(char, char, CharMapAction<'a>)>),
+    Hash(HashMap<char, CharMapAction<'a>>),
+}
+
+impl<'a> CharMap<'a> {
+    /// Returns the action to take for characters not in this map.
+    #[inline]
+    pub fn default_action(&self) -> CharMapAction<'a> {
+        self.default
+    }
+
+    /// Returns a [`CharMapper`] using this map and its default action.
+    #[inline]
+    pub fn mapper(&self) -> CharMapper<'_, Self> {
+        CharMapper::new(self, self.default)
+    }
+}
+
+impl ActionMap for CharMap<'_> {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        match &self.actions {
+            Actions::Empty => None,
+            Actions::Dense { offset, table } => {
+                let idx = (c as u32).checked_sub(*offset)? as usize;
+                table.get(idx).copied().flatten()
+            }
+            Actions::Ranges(ranges) => {
+                let idx = ranges.partition_point(|(start, _, _)| *start <= c);
+
+                match idx.checked_sub(1).map(|i| ranges[i]) {
+                    Some((_, end, action)) if c <= end => Some(action),
+                    _ => None,
+                }
+            }
+            Actions::Hash(map) => map.get(&c).copied(),
+        }
+    }
+
+    fn keys(&self) -> Option<Keys<'_>> {
+        let keys: Keys = match &self.actions {
+            Actions::Empty => Box::new(core::iter::empty()),
+            Actions::Dense { offset, table } => {
+                Box::new(table.iter().enumerate().filter_map(|(i, a)| {
+                    a.and_then(|_| char::from_u32(*offset + i as u32))
+                }))
+            }
+            Actions::Ranges(ranges) => Box::new(
+                ranges.iter().flat_map(|(start, end, _)| *start..=*end),
+            ),
+            Actions::Hash(map) => Box::new(map.keys().copied()),
+        };
+
+        Some(keys)
+    }
+}
+
+/// Error returned by [`CharMapBuilder::build`] when two rules map the same
+/// character to different actions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConflictError<'a> {
+    /// The first character both rules apply to.
+    pub c: char,
+    /// The action of the rule that was added first.
+    pub first: CharMapAction<'a>,
+    /// The action of the rule that was added second.
+    pub second: CharMapAction<'a>,
+}
+
+impl fmt::Display for ConflictError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "conflicting actions for {:?} (U+{:04X}): {:?} and {:?}",
+            self.c, self.c as u32, self.first, self.second
+        )
+    }
+}
+
+impl std::error::Error for ConflictError<'_> {}
@@ -1,32 +1,133 @@
 use core::{option, str::Chars};
 
 use super::actionmap::{ActionMap, CharMapAction};
+#[cfg(feature = "normalization")]
+pub(crate) use super::normalization::MaybeNormalized;
+#[cfg(feature = "normalization")]
+use super::normalization::NormalForm;
+use super::trace::{TracedChar, TracedOutput};
+
+// Without normalization support, characters are never normalized.
+#[cfg(not(feature = "normalization"))]
+pub(crate) type MaybeNormalized<I> = I;
 
 /// Primary struct used for character mapping.
 pub struct CharMapper<'a, M>
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
 {
     actionmap: &'a M,
     default: CharMapAction<'a>,
+    pub(crate) max_repeats: MaxRepeats<'a>,
+    #[cfg(feature = "normalization")]
+    pub(crate) normal_form: Option<NormalForm>,
+}
+
+// The maximum number of times an output character may be repeated.
+#[derive(Clone, Copy)]
+pub(crate) enum MaxRepeats<'a> {
+    Unlimited,
+    All(usize),
+    By(&'a dyn Fn(char) -> Option<usize>),
+}
+
+impl MaxRepeats<'_> {
+    // Returns the maximum number of times a given output character may be
+    // repeated.
+    #[inline]
+    pub(crate) fn of(&self, c: char) -> Option<usize> {
+        match self {
+            MaxRepeats::Unlimited => None,
+            MaxRepeats::All(max) => Some(*max),
+            MaxRepeats::By(max) => max(c),
+        }
+    }
 }
 
 impl<'a, M> CharMapper<'a, M>
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
 {
     /// Creates a new [`CharMapper`] with a given [`ActionMap`] and a default
     /// action to take if a character is not in the given [`ActionMap`].
     #[inline]
     pub fn new(actionmap: &'a M, default: CharMapAction<'a>) -> Self {
-        CharMapper { actionmap, default }
+        CharMapper {
+            actionmap,
+            default,
+            max_repeats: MaxRepeats::Unlimited,
+            #[cfg(feature = "normalization")]
+            normal_form: None,
+        }
+    }
+
+    /// Limits runs of the same output character to at most `max` characters
+    /// (e.g. "soooo" becomes "soo" with a maximum of 2).
+    ///
+    /// Runs are counted after mapping, so characters mapped to the same
+    /// character count towards the same run. The limit applies to all output
+    /// of this [`CharMapper`], including [`TracedChar::output`] and
+    /// [`Stats`](crate::Stats). A maximum of 0 removes output characters
+    /// altogether.
+    ///
+    /// ```rust
+    /// use charmap::*;
+    ///
+    /// let actions = [('O', CharMapAction::SubChar('o'))];
+    /// let mapper = CharMapper::new(&actions, CharMapAction::Pass).max_repeats(2);
+    ///
+    /// let mapped: String = "sOoOOoo!!!".map_chars(&mapper).collect();
+    /// assert_eq!(mapped, "soo!!");
+    /// ```
+    #[inline]
+    pub fn max_repeats(mut self, max: usize) -> Self {
+        self.max_repeats = MaxRepeats::All(max);
+        self
+    }
+
+    /// Limits runs of the same output character to a maximum that depends on
+    /// the character. Characters for which `max` returns `None` are not
+    /// limited, and characters for which it returns `Some(0)` are removed.
+    ///
+    /// ```rust
+    /// use charmap::*;
+    ///
+    /// let max = |c: char| match c {
+    ///     '!' | '?' => Some(1),
+    ///     c if c.is_alphabetic() => Some(2),
+    ///     _ => None,
+    /// };
+    /// let mapper = CharMapper::new(&[], CharMapAction::Pass).max_repeats_by(&max);
+    ///
+    /// let mapped: String = "هههههه 1000!!!".map_chars(&mapper).collect();
+    /// assert_eq!(mapped, "هه 1000!");
+    /// ```
+    #[inline]
+    pub fn max_repeats_by(
+        mut self,
+        max: &'a dyn Fn(char) -> Option<usize>,
+    ) -> Self {
+        self.max_repeats = MaxRepeats::By(max);
+        self
+    }
+
+    /// Returns the [`ActionMap`] used by this [`CharMapper`].
+    #[inline]
+    pub fn actionmap(&self) -> &'a M {
+        self.actionmap
+    }
+
+    /// Returns the action taken for characters not in the [`ActionMap`].
+    #[inline]
+    pub fn default_action(&self) -> CharMapAction<'a> {
+        self.default
     }
 
     /// Returns the [`CharMapAction`] asscociated with a given character.
     #[inline]
-    pub fn get_action(&'a self, c: char) -> &'a CharMapAction<'a> {
+    pub fn get_action(&self, c: char) -> CharMapAction<'a> {
         match self.actionmap.map_char(c) {
-            None => &self.default,
+            None => self.default,
             Some(action) => action,
         }
     }
@@ -40,6 +141,28 @@ where
     {
         MappedChars::new(self, text_chars)
     }
+
+    // Normalizes characters to the normal form of this `CharMapper`, if any.
+    #[cfg(feature = "normalization")]
+    #[inline]
+    pub(crate) fn normalize_chars<I>(
+        &self,
+        text_chars: I,
+    ) -> MaybeNormalized<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        MaybeNormalized::new(text_chars, self.normal_form)
+    }
+
+    #[cfg(not(feature = "normalization"))]
+    #[inline]
+    pub(crate) fn normalize_chars<I>(&self, text_chars: I) -> I
+    where
+        I: Iterator<Item = char>,
+    {
+        text_chars
+    }
 }
 
 /// Character iterator returned by
@@ -48,18 +171,15 @@ where
 #[derive(Clone)]
 pub struct MappedChars<'a, M, I>
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
     I: Iterator<Item = char>,
 {
-    charmapper: &'a CharMapper<'a, M>,
-    text_chars: I,
-    sub_chars: Chars<'a>,
-    in_sub: bool,
+    mapping: MaybeNormalized<Mapping<'a, M, MaybeNormalized<I>, ()>>,
 }
 
 impl<'a, M, I> MappedChars<'a, M, I>
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
     I: Iterator<Item = char>,
 {
     #[inline]
@@ -67,60 +187,104 @@ where
     where
         I: Iterator<Item = char>,
     {
-        MappedChars {
+        let text_chars = charmapper.normalize_chars(text_chars);
+        let mapping = Mapping::new(charmapper, text_chars, ());
+
+        MappedChars { mapping: charmapper.normalize_chars(mapping) }
+    }
+}
+
+impl<M, I> Iterator for MappedChars<'_, M, I>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.mapping.next()
+    }
+}
+
+// Receives the traced input characters and the output characters of a
+// `Mapping`, e.g. to collect statistics.
+pub(crate) trait Recorder {
+    fn record_input(&mut self, traced: &TracedChar<'_>);
+    fn record_output(&mut self, c: char);
+}
+
+impl Recorder for () {
+    #[inline]
+    fn record_input(&mut self, _traced: &TracedChar<'_>) {}
+
+    #[inline]
+    fn record_output(&mut self, _c: char) {}
+}
+
+// The mapping logic shared by all iterators mapping characters, such as
+// `MappedChars` and `StatsChars`.
+#[derive(Clone)]
+pub(crate) struct Mapping<'a, M, I, R>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+{
+    charmapper: &'a CharMapper<'a, M>,
+    text_chars: I,
+    pos: usize,
+    // The output of the last input character.
+    out_chars: TracedOutput<'a>,
+    recorder: R,
+}
+
+impl<'a, M, I, R> Mapping<'a, M, I, R>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+    R: Recorder,
+{
+    #[inline]
+    pub(crate) fn new(
+        charmapper: &'a CharMapper<'a, M>,
+        text_chars: I,
+        recorder: R,
+    ) -> Self {
+        Mapping {
             charmapper,
             text_chars,
-            sub_chars: "".chars(),
-            in_sub: false,
+            pos: 0,
+            out_chars: TracedOutput::empty(charmapper.max_repeats),
+            recorder,
         }
     }
 }
 
-impl<'a, M, I> Iterator for MappedChars<'a, M, I>
+impl<M, I, R> Iterator for Mapping<'_, M, I, R>
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
     I: Iterator<Item = char>,
+    R: Recorder,
 {
     type Item = char;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        // Check if we're currently outputing characters from sub string.
-        if self.in_sub {
-            match self.sub_chars.next() {
-                Some(c) => return Some(c),
-                None => self.in_sub = false,
-            }
-        }
-
-        // We need to keep looping through the input until the we got a
-        // non-delete action.
         loop {
-            let next_char = self.text_chars.next();
-
-            match next_char {
-                Some(c) => {
-                    match self.charmapper.get_action(c) {
-                        CharMapAction::Pass => return Some(c),
-                        CharMapAction::Delete => continue,
-                        CharMapAction::SubChar(d) => return Some(*d),
-                        CharMapAction::SubStr(sub_str) => {
-                            self.in_sub = true;
-                            self.sub_chars = sub_str.chars();
-                            // Can't unwrap directly because sub string could
-                            // be empty.
-                            match self.sub_chars.next() {
-                                Some(c) => return Some(c),
-                                None => {
-                                    self.in_sub = false;
-                                    continue;
-                                }
-                            }
-                        }
-                    }
-                }
-                None => return None,
+            if let Some(c) = self.out_chars.next() {
+                self.recorder.record_output(c);
+                return Some(c);
             }
+
+            // We need to keep looping through the input until we get an
+            // output character.
+            let c = self.text_chars.next()?;
+            let traced =
+                self.charmapper.trace_char(self.pos, c, self.out_chars.run());
+
+            self.pos += 1;
+            self.recorder.record_input(&traced);
+            self.out_chars = traced.output();
         }
     }
 }
@@ -129,7 +293,7 @@ where
 /// [`char`] to charmap their output.
 pub trait MapCharsIter<'a, M, I: Iterator<Item = char>>
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
 {
     fn map_chars(self, mapper: &'a CharMapper<'a, M>)
         -> MappedChars<'a, M, I>;
@@ -137,7 +301,7 @@ where
 
 impl<'a, M> MapCharsIter<'a, M, Chars<'a>> for &'a str
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
 {
     #[inline]
     fn map_chars(
@@ -153,7 +317,7 @@ where
 
 impl<'a, M> MapCharsIter<'a, M, option::IntoIter<char>> for char
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
 {
     #[inline]
     fn map_chars(
@@ -169,7 +333,7 @@ where
 
 impl<'a, M, I: Iterator<Item = char>> MapCharsIter<'a, M, I> for I
 where
-    M: ActionMap,
+    M: ActionMap + ?Sized,
 {
     #[inline]
     fn map_chars(
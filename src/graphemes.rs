@@ -0,0 +1,235 @@
+use core::str::Chars;
+
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
+
+use super::actionmap::{ActionMap, CharMapAction};
+use super::charmapper::CharMapper;
+use super::trace::TracedOutput;
+
+/// What happens to the characters attached to the base (i.e. first) character
+/// of a grapheme cluster, such as combining marks, when the base character is
+/// deleted or substituted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MarkPolicy {
+    /// Attached characters are mapped individually, as if the text was not
+    /// segmented into grapheme clusters.
+    Keep,
+    /// Attached characters are deleted along with their base character, and
+    /// mapped individually if it is substituted.
+    DeleteWithBase,
+    /// Attached characters are deleted whenever their base character is
+    /// deleted or substituted.
+    Drop,
+}
+
+/// A trait that should be implemented by any struct providing
+/// cluster-to-action mappings for
+/// [`CharMapper::map_graphemes`](super::CharMapper::map_graphemes).
+pub trait ClusterActionMap {
+    /// Map a grapheme cluster to its respective CharMapAction.
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>>;
+}
+
+#[cfg(feature = "std")]
+impl ClusterActionMap for std::collections::HashMap<&str, CharMapAction<'_>> {
+    #[inline]
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>> {
+        self.get(cluster).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ClusterActionMap for std::collections::BTreeMap<&str, CharMapAction<'_>> {
+    #[inline]
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>> {
+        self.get(cluster).copied()
+    }
+}
+
+// NOTE: Slices and arrays are looked up using binary search and must therefore
+// be sorted by cluster.
+impl ClusterActionMap for [(&str, CharMapAction<'_>)] {
+    #[inline]
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>> {
+        match self.binary_search_by(|(k, _)| (*k).cmp(cluster)) {
+            Ok(idx) => Some(self[idx].1),
+            Err(_) => None,
+        }
+    }
+}
+
+impl<const N: usize> ClusterActionMap for [(&str, CharMapAction<'_>); N] {
+    #[inline]
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>> {
+        self.as_slice().map_cluster(cluster)
+    }
+}
+
+// NOTE: Pairs of maps act as a single map where the first map takes
+// precedence.
+impl<A, B> ClusterActionMap for (A, B)
+where
+    A: ClusterActionMap,
+    B: ClusterActionMap,
+{
+    #[inline]
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>> {
+        self.0.map_cluster(cluster).or_else(|| self.1.map_cluster(cluster))
+    }
+}
+
+// NOTE: Closures are restricted to returning `'static` actions since the
+// returned action needs to outlive any borrow of the closure itself.
+impl<F> ClusterActionMap for F
+where
+    F: Fn(&str) -> Option<CharMapAction<'static>>,
+{
+    #[inline]
+    fn map_cluster(&'_ self, cluster: &str) -> Option<CharMapAction<'_>> {
+        self(cluster)
+    }
+}
+
+impl<'a, M> CharMapper<'a, M>
+where
+    M: ActionMap + ?Sized,
+{
+    /// Returns an iterator that maps the extended grapheme clusters (see
+    /// [UAX #29](https://www.unicode.org/reports/tr29/)) of a given string.
+    ///
+    /// Each cluster is first looked up as a whole in `clusters`. If it is not
+    /// found, the action for its base (i.e. first) character is taken from
+    /// this [`CharMapper`], and `policy` decides what happens to the rest of
+    /// the cluster. Note that `"\r\n"` is always mapped character by
+    /// character.
+    ///
+    /// Runs of output characters are limited as with
+    /// [`CharMapper::max_repeats`], including the output of clusters. Since
+    /// clusters are borrowed from `text`, the text is not normalized, even if
+    /// this [`CharMapper`] normalizes text when mapping characters.
+    ///
+    /// ```rust
+    /// use charmap::*;
+    ///
+    /// let actions = [('e', CharMapAction::SubChar('3')), ('x', CharMapAction::Delete)];
+    /// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+    /// let clusters = [("x\u{0301}", CharMapAction::SubStr("[x]"))];
+    ///
+    /// let text = "e\u{0301}x x\u{0301} x\u{0302}";
+    ///
+    /// // Mapping individual characters leaves orphaned combining marks.
+    /// let mapped: String = text.map_chars(&mapper).collect();
+    /// assert_eq!(mapped, "3\u{0301} \u{0301} \u{0302}");
+    ///
+    /// let mapped: String =
+    ///     mapper.map_graphemes(text, &clusters, MarkPolicy::Drop).collect();
+    /// assert_eq!(mapped, "3 [x] ");
+    ///
+    /// let mapped: String =
+    ///     mapper.map_graphemes(text, &[], MarkPolicy::DeleteWithBase).collect();
+    /// assert_eq!(mapped, "3\u{0301}  ");
+    /// ```
+    #[inline]
+    pub fn map_graphemes<C>(
+        &'a self,
+        text: &'a str,
+        clusters: &'a C,
+        policy: MarkPolicy,
+    ) -> MappedGraphemes<'a, M, C>
+    where
+        C: ClusterActionMap + ?Sized,
+    {
+        MappedGraphemes {
+            charmapper: self,
+            clusters,
+            policy,
+            graphemes: text.graphemes(true),
+            pos: 0,
+            out_chars: TracedOutput::empty(self.max_repeats),
+            rest_chars: "".chars(),
+        }
+    }
+}
+
+/// Character iterator returned by
+/// [`CharMapper::map_graphemes`](super::CharMapper::map_graphemes).
+#[derive(Clone)]
+pub struct MappedGraphemes<'a, M, C>
+where
+    M: ActionMap + ?Sized,
+    C: ClusterActionMap + ?Sized,
+{
+    charmapper: &'a CharMapper<'a, M>,
+    clusters: &'a C,
+    policy: MarkPolicy,
+    graphemes: Graphemes<'a>,
+    pos: usize,
+    // The output of the last action, and the rest of the current cluster to
+    // be mapped character by character.
+    out_chars: TracedOutput<'a>,
+    rest_chars: Chars<'a>,
+}
+
+impl<'a, M, C> Iterator for MappedGraphemes<'a, M, C>
+where
+    M: ActionMap + ?Sized,
+    C: ClusterActionMap + ?Sized,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.out_chars.next() {
+                return Some(c);
+            }
+
+            let run = self.out_chars.run();
+
+            if let Some(c) = self.rest_chars.next() {
+                let traced = self.charmapper.trace_char(self.pos, c, run);
+
+                self.pos += 1;
+                self.out_chars = traced.output();
+                continue;
+            }
+
+            let cluster = self.graphemes.next()?;
+
+            if let Some(action) = self.clusters.map_cluster(cluster) {
+                let (c, s) = match action {
+                    CharMapAction::Pass => (None, cluster),
+                    CharMapAction::Delete => (None, ""),
+                    CharMapAction::SubChar(d) => (Some(d), ""),
+                    CharMapAction::SubStr(s) => (None, s),
+                };
+
+                self.pos += cluster.chars().count();
+                self.out_chars =
+                    TracedOutput::new(c, s, run, self.charmapper.max_repeats);
+                continue;
+            }
+
+            let mut chars = cluster.chars();
+            let Some(base) = chars.next() else { continue };
+            let traced = self.charmapper.trace_char(self.pos, base, run);
+            let action = traced.action;
+
+            self.pos += 1;
+            let keep_rest = match (self.policy, action) {
+                _ if cluster == "\r\n" => true,
+                (_, CharMapAction::Pass) | (MarkPolicy::Keep, _) => true,
+                (MarkPolicy::DeleteWithBase, CharMapAction::Delete) => false,
+                (MarkPolicy::DeleteWithBase, _) => true,
+                (MarkPolicy::Drop, _) => false,
+            };
+
+            self.out_chars = traced.output();
+            if keep_rest {
+                self.rest_chars = chars;
+            } else {
+                self.pos += chars.count();
+            }
+        }
+    }
+}
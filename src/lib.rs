@@ -45,6 +45,11 @@
 //! charmap = {version = "0.2", default-features = false, features = ["phf"]}
 //! ```
 //!
+//! Sorted slices and arrays of `(char, CharMapAction)` pairs can always be
+//! used as action maps, even without any features enabled. Use
+//! [`assert_sorted`] in a `const` context to make sure such tables are sorted
+//! at compile time.
+//!
 //! ## Example
 //!
 //! Below is an example of how to use `charmap` with libstd's
@@ -103,9 +108,82 @@
 //! // Output should be: --LLLLLLo---o-LLL-
 //! println!("{}", mapped_str);
 //! ```
+//!
+//! Closures (and functions) can be used as action maps as well by wrapping
+//! them in [`FnMap`]. References, `Box`es, [`Rc`](std::rc::Rc)s, and
+//! [`Arc`](std::sync::Arc)s of action maps (including trait objects) are
+//! action maps too:
+//!
+//! ```rust
+//! use charmap::*;
+//!
+//! // Replace all ASCII digits with '#'.
+//! let actions = FnMap(|c: char| {
+//!     if c.is_ascii_digit() {
+//!         Some(CharMapAction::SubChar('#'))
+//!     } else {
+//!         None
+//!     }
+//! });
+//!
+//! let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+//! let mapped_str: String = "Call 555-0123".map_chars(&mapper).collect();
+//!
+//! assert_eq!(mapped_str, "Call ###-####");
+//! ```
+//!
+//! Finally, [`CharMapBuilder`] can be used to fluently build action maps from
+//! individual characters, character ranges, and character predicates, as well
+//! as Unicode general categories if the `"general_category"` feature is set.
+//!
+//! ## Bundled maps
+//!
+//! `charmap` ships with a number of ready-made action maps in the [`maps`]
+//! module, each enabled by a feature of the same name. For example, to use
+//! the Buckwalter transliteration maps, add the following to your
+//! `Cargo.toml`:
+//!
+//! ```toml
+//! [dependencies]
+//! charmap = {version = "0.2", features = ["buckwalter"]}
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 mod actionmap;
+#[cfg(feature = "std")]
+mod builder;
 mod charmapper;
+#[cfg(feature = "graphemes")]
+mod graphemes;
+pub mod maps;
+#[cfg(feature = "normalization")]
+mod normalization;
+#[cfg(feature = "std")]
+mod stats;
+mod trace;
+#[cfg(feature = "std")]
+mod whitespace;
 
-pub use crate::actionmap::{ActionMap, CharMapAction};
+#[cfg(feature = "std")]
+pub use crate::actionmap::Keys;
+pub use crate::actionmap::{assert_sorted, ActionMap, CharMapAction, FnMap};
+#[cfg(feature = "std")]
+pub use crate::builder::{CharMap, CharMapBuilder, ConflictError};
 pub use crate::charmapper::{CharMapper, MapCharsIter, MappedChars};
+#[cfg(feature = "graphemes")]
+pub use crate::graphemes::{ClusterActionMap, MappedGraphemes, MarkPolicy};
+#[cfg(feature = "normalization")]
+pub use crate::normalization::{NormalForm, NormalizeCharsIter, Normalized};
+#[cfg(feature = "std")]
+pub use crate::stats::{Stats, StatsChars};
+pub use crate::trace::{
+    ActionSource, TraceTable, TracedChar, TracedChars, TracedOutput,
+};
+#[cfg(feature = "std")]
+pub use crate::whitespace::{
+    NormalizeWhitespaceIter, NormalizedWhitespace, Spaces,
+    WhitespaceNormalizer,
+};
+#[cfg(feature = "general_category")]
+pub use unicode_properties::{GeneralCategory, GeneralCategoryGroup};
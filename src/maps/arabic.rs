@@ -0,0 +1,166 @@
+//! Arabic orthographic normalization maps.
+//!
+//! The maps below each cover one orthographic variation, such as the
+//! different forms of alef or the optional short vowel marks. Use a single
+//! map, nest several in pairs (e.g. `(&NORMALIZE_ALEF, &REMOVE_TATWEEL)`), or
+//! use [`Normalizer`] and switch rules on and off as needed.
+//!
+//! ```rust
+//! use charmap::maps::arabic::Normalizer;
+//! use charmap::*;
+//!
+//! let normalizer = Normalizer::ALL.teh_marbuta(false);
+//! let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+//!
+//! let normalized: String = "إِلَى المَدْرَسَـــةِ".map_chars(&mapper).collect();
+//! assert_eq!(normalized, "الي المدرسة");
+//! ```
+
+#[cfg(feature = "std")]
+use crate::actionmap::Keys;
+use crate::actionmap::{
+    assert_sorted, ActionMap, ActionTable, CharMapAction, Toggles,
+};
+
+/// Map normalizing alef variants (أ إ آ ٱ) to bare alef (ا).
+pub static NORMALIZE_ALEF: [(char, CharMapAction); 4] = [
+    ('\u{0622}', CharMapAction::SubChar('\u{0627}')), // ALEF WITH MADDA ABOVE
+    ('\u{0623}', CharMapAction::SubChar('\u{0627}')), // ALEF WITH HAMZA ABOVE
+    ('\u{0625}', CharMapAction::SubChar('\u{0627}')), // ALEF WITH HAMZA BELOW
+    ('\u{0671}', CharMapAction::SubChar('\u{0627}')), // ALEF WASLA
+];
+const _: () = assert_sorted(&NORMALIZE_ALEF);
+
+/// Map normalizing alef maksura (ى) to yeh (ي).
+pub static NORMALIZE_ALEF_MAKSURA: [(char, CharMapAction); 1] = [
+    ('\u{0649}', CharMapAction::SubChar('\u{064A}')), // ALEF MAKSURA
+];
+
+/// Map normalizing ta marbuta (ة) to heh (ه).
+pub static NORMALIZE_TEH_MARBUTA: [(char, CharMapAction); 1] = [
+    ('\u{0629}', CharMapAction::SubChar('\u{0647}')), // TEH MARBUTA
+];
+
+/// Map removing tatweel (ـ).
+pub static REMOVE_TATWEEL: [(char, CharMapAction); 1] = [
+    ('\u{0640}', CharMapAction::Delete), // TATWEEL
+];
+
+/// Map removing diacritics (short vowels, tanween, shadda, sukun, and dagger
+/// alef).
+pub static REMOVE_DIACRITICS: [(char, CharMapAction); 9] = [
+    ('\u{064B}', CharMapAction::Delete), // FATHATAN
+    ('\u{064C}', CharMapAction::Delete), // DAMMATAN
+    ('\u{064D}', CharMapAction::Delete), // KASRATAN
+    ('\u{064E}', CharMapAction::Delete), // FATHA
+    ('\u{064F}', CharMapAction::Delete), // DAMMA
+    ('\u{0650}', CharMapAction::Delete), // KASRA
+    ('\u{0651}', CharMapAction::Delete), // SHADDA
+    ('\u{0652}', CharMapAction::Delete), // SUKUN
+    ('\u{0670}', CharMapAction::Delete), // SUPERSCRIPT ALEF
+];
+const _: () = assert_sorted(&REMOVE_DIACRITICS);
+
+/// Map removing combining maddah and hamza above and below.
+///
+/// These marks only appear in decomposed (NFD) text, where they are part of
+/// letters such as آ (ا + U+0653) and ؤ (و + U+0654). Removing them turns
+/// these letters into their bare forms, while their precomposed forms are
+/// left as they are, so text should be decomposed first for consistent
+/// results.
+pub static REMOVE_COMBINING_HAMZA: [(char, CharMapAction); 3] = [
+    ('\u{0653}', CharMapAction::Delete), // MADDAH ABOVE
+    ('\u{0654}', CharMapAction::Delete), // HAMZA ABOVE
+    ('\u{0655}', CharMapAction::Delete), // HAMZA BELOW
+];
+const _: () = assert_sorted(&REMOVE_COMBINING_HAMZA);
+
+// Tables of the rules applied by `Normalizer`, indexed by the constants below.
+static RULES: [ActionTable; 6] = [
+    &NORMALIZE_ALEF,
+    &NORMALIZE_ALEF_MAKSURA,
+    &NORMALIZE_TEH_MARBUTA,
+    &REMOVE_TATWEEL,
+    &REMOVE_DIACRITICS,
+    &REMOVE_COMBINING_HAMZA,
+];
+
+const ALEF: usize = 0;
+const ALEF_MAKSURA: usize = 1;
+const TEH_MARBUTA: usize = 2;
+const TATWEEL: usize = 3;
+const DIACRITICS: usize = 4;
+const COMBINING_HAMZA: usize = 5;
+
+/// An [`ActionMap`] applying a configurable set of Arabic normalization rules.
+///
+/// The default [`Normalizer`] enables all rules except
+/// [`REMOVE_COMBINING_HAMZA`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Normalizer {
+    rules: Toggles<6>,
+}
+
+impl Normalizer {
+    /// A [`Normalizer`] with all rules enabled.
+    pub const ALL: Normalizer = Normalizer { rules: Toggles::ALL };
+
+    /// A [`Normalizer`] with all rules disabled.
+    pub const NONE: Normalizer = Normalizer { rules: Toggles::NONE };
+
+    /// Toggles [`NORMALIZE_ALEF`].
+    #[inline]
+    pub const fn alef(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(ALEF, enabled) }
+    }
+
+    /// Toggles [`NORMALIZE_ALEF_MAKSURA`].
+    #[inline]
+    pub const fn alef_maksura(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(ALEF_MAKSURA, enabled) }
+    }
+
+    /// Toggles [`NORMALIZE_TEH_MARBUTA`].
+    #[inline]
+    pub const fn teh_marbuta(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(TEH_MARBUTA, enabled) }
+    }
+
+    /// Toggles [`REMOVE_TATWEEL`].
+    #[inline]
+    pub const fn tatweel(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(TATWEEL, enabled) }
+    }
+
+    /// Toggles [`REMOVE_DIACRITICS`].
+    #[inline]
+    pub const fn diacritics(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(DIACRITICS, enabled) }
+    }
+
+    /// Toggles [`REMOVE_COMBINING_HAMZA`].
+    #[inline]
+    pub const fn combining_hamza(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(COMBINING_HAMZA, enabled) }
+    }
+}
+
+impl Default for Normalizer {
+    #[inline]
+    fn default() -> Self {
+        Normalizer::ALL.combining_hamza(false)
+    }
+}
+
+impl ActionMap for Normalizer {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.rules.map_char(&RULES, c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(self.rules.keys(&RULES))
+    }
+}
@@ -0,0 +1,759 @@
+//! Arabic presentation form decomposition map.
+//!
+//! Maps all characters in the Arabic Presentation Forms-A (U+FB50-U+FDFF) and
+//! Arabic Presentation Forms-B (U+FE70-U+FEFF) blocks to their base letter
+//! sequences (i.e. their NFKC forms). Ligatures such as ﻻ and ﷲ are expanded
+//! to multiple letters.
+//!
+//! ```rust
+//! use charmap::maps::arabic_presentation::DECOMPOSE_PRESENTATION_FORMS;
+//! use charmap::*;
+//!
+//! let mapper =
+//!     CharMapper::new(&DECOMPOSE_PRESENTATION_FORMS, CharMapAction::Pass);
+//!
+//! let decomposed: String = "ﻻ ﷲ ﺑﺴﻢ".map_chars(&mapper).collect();
+//! assert_eq!(decomposed, "لا الله بسم");
+//! ```
+//!
+//! This file is generated by `scripts/gen_arabic_presentation.py` from
+//! Unicode 14.0.0 data. Do not edit it manually.
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+
+/// Map decomposing Arabic presentation forms to base letter sequences.
+#[rustfmt::skip]
+pub static DECOMPOSE_PRESENTATION_FORMS: [(char, CharMapAction); 731] = [
+    ('\u{FB50}', CharMapAction::SubChar('ٱ')), // ALEF WASLA ISOLATED FORM
+    ('\u{FB51}', CharMapAction::SubChar('ٱ')), // ALEF WASLA FINAL FORM
+    ('\u{FB52}', CharMapAction::SubChar('ٻ')), // BEEH ISOLATED FORM
+    ('\u{FB53}', CharMapAction::SubChar('ٻ')), // BEEH FINAL FORM
+    ('\u{FB54}', CharMapAction::SubChar('ٻ')), // BEEH INITIAL FORM
+    ('\u{FB55}', CharMapAction::SubChar('ٻ')), // BEEH MEDIAL FORM
+    ('\u{FB56}', CharMapAction::SubChar('پ')), // PEH ISOLATED FORM
+    ('\u{FB57}', CharMapAction::SubChar('پ')), // PEH FINAL FORM
+    ('\u{FB58}', CharMapAction::SubChar('پ')), // PEH INITIAL FORM
+    ('\u{FB59}', CharMapAction::SubChar('پ')), // PEH MEDIAL FORM
+    ('\u{FB5A}', CharMapAction::SubChar('ڀ')), // BEHEH ISOLATED FORM
+    ('\u{FB5B}', CharMapAction::SubChar('ڀ')), // BEHEH FINAL FORM
+    ('\u{FB5C}', CharMapAction::SubChar('ڀ')), // BEHEH INITIAL FORM
+    ('\u{FB5D}', CharMapAction::SubChar('ڀ')), // BEHEH MEDIAL FORM
+    ('\u{FB5E}', CharMapAction::SubChar('ٺ')), // TTEHEH ISOLATED FORM
+    ('\u{FB5F}', CharMapAction::SubChar('ٺ')), // TTEHEH FINAL FORM
+    ('\u{FB60}', CharMapAction::SubChar('ٺ')), // TTEHEH INITIAL FORM
+    ('\u{FB61}', CharMapAction::SubChar('ٺ')), // TTEHEH MEDIAL FORM
+    ('\u{FB62}', CharMapAction::SubChar('ٿ')), // TEHEH ISOLATED FORM
+    ('\u{FB63}', CharMapAction::SubChar('ٿ')), // TEHEH FINAL FORM
+    ('\u{FB64}', CharMapAction::SubChar('ٿ')), // TEHEH INITIAL FORM
+    ('\u{FB65}', CharMapAction::SubChar('ٿ')), // TEHEH MEDIAL FORM
+    ('\u{FB66}', CharMapAction::SubChar('ٹ')), // TTEH ISOLATED FORM
+    ('\u{FB67}', CharMapAction::SubChar('ٹ')), // TTEH FINAL FORM
+    ('\u{FB68}', CharMapAction::SubChar('ٹ')), // TTEH INITIAL FORM
+    ('\u{FB69}', CharMapAction::SubChar('ٹ')), // TTEH MEDIAL FORM
+    ('\u{FB6A}', CharMapAction::SubChar('ڤ')), // VEH ISOLATED FORM
+    ('\u{FB6B}', CharMapAction::SubChar('ڤ')), // VEH FINAL FORM
+    ('\u{FB6C}', CharMapAction::SubChar('ڤ')), // VEH INITIAL FORM
+    ('\u{FB6D}', CharMapAction::SubChar('ڤ')), // VEH MEDIAL FORM
+    ('\u{FB6E}', CharMapAction::SubChar('ڦ')), // PEHEH ISOLATED FORM
+    ('\u{FB6F}', CharMapAction::SubChar('ڦ')), // PEHEH FINAL FORM
+    ('\u{FB70}', CharMapAction::SubChar('ڦ')), // PEHEH INITIAL FORM
+    ('\u{FB71}', CharMapAction::SubChar('ڦ')), // PEHEH MEDIAL FORM
+    ('\u{FB72}', CharMapAction::SubChar('ڄ')), // DYEH ISOLATED FORM
+    ('\u{FB73}', CharMapAction::SubChar('ڄ')), // DYEH FINAL FORM
+    ('\u{FB74}', CharMapAction::SubChar('ڄ')), // DYEH INITIAL FORM
+    ('\u{FB75}', CharMapAction::SubChar('ڄ')), // DYEH MEDIAL FORM
+    ('\u{FB76}', CharMapAction::SubChar('ڃ')), // NYEH ISOLATED FORM
+    ('\u{FB77}', CharMapAction::SubChar('ڃ')), // NYEH FINAL FORM
+    ('\u{FB78}', CharMapAction::SubChar('ڃ')), // NYEH INITIAL FORM
+    ('\u{FB79}', CharMapAction::SubChar('ڃ')), // NYEH MEDIAL FORM
+    ('\u{FB7A}', CharMapAction::SubChar('چ')), // TCHEH ISOLATED FORM
+    ('\u{FB7B}', CharMapAction::SubChar('چ')), // TCHEH FINAL FORM
+    ('\u{FB7C}', CharMapAction::SubChar('چ')), // TCHEH INITIAL FORM
+    ('\u{FB7D}', CharMapAction::SubChar('چ')), // TCHEH MEDIAL FORM
+    ('\u{FB7E}', CharMapAction::SubChar('ڇ')), // TCHEHEH ISOLATED FORM
+    ('\u{FB7F}', CharMapAction::SubChar('ڇ')), // TCHEHEH FINAL FORM
+    ('\u{FB80}', CharMapAction::SubChar('ڇ')), // TCHEHEH INITIAL FORM
+    ('\u{FB81}', CharMapAction::SubChar('ڇ')), // TCHEHEH MEDIAL FORM
+    ('\u{FB82}', CharMapAction::SubChar('ڍ')), // DDAHAL ISOLATED FORM
+    ('\u{FB83}', CharMapAction::SubChar('ڍ')), // DDAHAL FINAL FORM
+    ('\u{FB84}', CharMapAction::SubChar('ڌ')), // DAHAL ISOLATED FORM
+    ('\u{FB85}', CharMapAction::SubChar('ڌ')), // DAHAL FINAL FORM
+    ('\u{FB86}', CharMapAction::SubChar('ڎ')), // DUL ISOLATED FORM
+    ('\u{FB87}', CharMapAction::SubChar('ڎ')), // DUL FINAL FORM
+    ('\u{FB88}', CharMapAction::SubChar('ڈ')), // DDAL ISOLATED FORM
+    ('\u{FB89}', CharMapAction::SubChar('ڈ')), // DDAL FINAL FORM
+    ('\u{FB8A}', CharMapAction::SubChar('ژ')), // JEH ISOLATED FORM
+    ('\u{FB8B}', CharMapAction::SubChar('ژ')), // JEH FINAL FORM
+    ('\u{FB8C}', CharMapAction::SubChar('ڑ')), // RREH ISOLATED FORM
+    ('\u{FB8D}', CharMapAction::SubChar('ڑ')), // RREH FINAL FORM
+    ('\u{FB8E}', CharMapAction::SubChar('ک')), // KEHEH ISOLATED FORM
+    ('\u{FB8F}', CharMapAction::SubChar('ک')), // KEHEH FINAL FORM
+    ('\u{FB90}', CharMapAction::SubChar('ک')), // KEHEH INITIAL FORM
+    ('\u{FB91}', CharMapAction::SubChar('ک')), // KEHEH MEDIAL FORM
+    ('\u{FB92}', CharMapAction::SubChar('گ')), // GAF ISOLATED FORM
+    ('\u{FB93}', CharMapAction::SubChar('گ')), // GAF FINAL FORM
+    ('\u{FB94}', CharMapAction::SubChar('گ')), // GAF INITIAL FORM
+    ('\u{FB95}', CharMapAction::SubChar('گ')), // GAF MEDIAL FORM
+    ('\u{FB96}', CharMapAction::SubChar('ڳ')), // GUEH ISOLATED FORM
+    ('\u{FB97}', CharMapAction::SubChar('ڳ')), // GUEH FINAL FORM
+    ('\u{FB98}', CharMapAction::SubChar('ڳ')), // GUEH INITIAL FORM
+    ('\u{FB99}', CharMapAction::SubChar('ڳ')), // GUEH MEDIAL FORM
+    ('\u{FB9A}', CharMapAction::SubChar('ڱ')), // NGOEH ISOLATED FORM
+    ('\u{FB9B}', CharMapAction::SubChar('ڱ')), // NGOEH FINAL FORM
+    ('\u{FB9C}', CharMapAction::SubChar('ڱ')), // NGOEH INITIAL FORM
+    ('\u{FB9D}', CharMapAction::SubChar('ڱ')), // NGOEH MEDIAL FORM
+    ('\u{FB9E}', CharMapAction::SubChar('ں')), // NOON GHUNNA ISOLATED FORM
+    ('\u{FB9F}', CharMapAction::SubChar('ں')), // NOON GHUNNA FINAL FORM
+    ('\u{FBA0}', CharMapAction::SubChar('ڻ')), // RNOON ISOLATED FORM
+    ('\u{FBA1}', CharMapAction::SubChar('ڻ')), // RNOON FINAL FORM
+    ('\u{FBA2}', CharMapAction::SubChar('ڻ')), // RNOON INITIAL FORM
+    ('\u{FBA3}', CharMapAction::SubChar('ڻ')), // RNOON MEDIAL FORM
+    ('\u{FBA4}', CharMapAction::SubChar('ۀ')), // HEH WITH YEH ABOVE ISOLATED FORM
+    ('\u{FBA5}', CharMapAction::SubChar('ۀ')), // HEH WITH YEH ABOVE FINAL FORM
+    ('\u{FBA6}', CharMapAction::SubChar('ہ')), // HEH GOAL ISOLATED FORM
+    ('\u{FBA7}', CharMapAction::SubChar('ہ')), // HEH GOAL FINAL FORM
+    ('\u{FBA8}', CharMapAction::SubChar('ہ')), // HEH GOAL INITIAL FORM
+    ('\u{FBA9}', CharMapAction::SubChar('ہ')), // HEH GOAL MEDIAL FORM
+    ('\u{FBAA}', CharMapAction::SubChar('ھ')), // HEH DOACHASHMEE ISOLATED FORM
+    ('\u{FBAB}', CharMapAction::SubChar('ھ')), // HEH DOACHASHMEE FINAL FORM
+    ('\u{FBAC}', CharMapAction::SubChar('ھ')), // HEH DOACHASHMEE INITIAL FORM
+    ('\u{FBAD}', CharMapAction::SubChar('ھ')), // HEH DOACHASHMEE MEDIAL FORM
+    ('\u{FBAE}', CharMapAction::SubChar('ے')), // YEH BARREE ISOLATED FORM
+    ('\u{FBAF}', CharMapAction::SubChar('ے')), // YEH BARREE FINAL FORM
+    ('\u{FBB0}', CharMapAction::SubChar('ۓ')), // YEH BARREE WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FBB1}', CharMapAction::SubChar('ۓ')), // YEH BARREE WITH HAMZA ABOVE FINAL FORM
+    ('\u{FBD3}', CharMapAction::SubChar('ڭ')), // NG ISOLATED FORM
+    ('\u{FBD4}', CharMapAction::SubChar('ڭ')), // NG FINAL FORM
+    ('\u{FBD5}', CharMapAction::SubChar('ڭ')), // NG INITIAL FORM
+    ('\u{FBD6}', CharMapAction::SubChar('ڭ')), // NG MEDIAL FORM
+    ('\u{FBD7}', CharMapAction::SubChar('ۇ')), // U ISOLATED FORM
+    ('\u{FBD8}', CharMapAction::SubChar('ۇ')), // U FINAL FORM
+    ('\u{FBD9}', CharMapAction::SubChar('ۆ')), // OE ISOLATED FORM
+    ('\u{FBDA}', CharMapAction::SubChar('ۆ')), // OE FINAL FORM
+    ('\u{FBDB}', CharMapAction::SubChar('ۈ')), // YU ISOLATED FORM
+    ('\u{FBDC}', CharMapAction::SubChar('ۈ')), // YU FINAL FORM
+    ('\u{FBDD}', CharMapAction::SubStr("ۇٴ")), // U WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FBDE}', CharMapAction::SubChar('ۋ')), // VE ISOLATED FORM
+    ('\u{FBDF}', CharMapAction::SubChar('ۋ')), // VE FINAL FORM
+    ('\u{FBE0}', CharMapAction::SubChar('ۅ')), // KIRGHIZ OE ISOLATED FORM
+    ('\u{FBE1}', CharMapAction::SubChar('ۅ')), // KIRGHIZ OE FINAL FORM
+    ('\u{FBE2}', CharMapAction::SubChar('ۉ')), // KIRGHIZ YU ISOLATED FORM
+    ('\u{FBE3}', CharMapAction::SubChar('ۉ')), // KIRGHIZ YU FINAL FORM
+    ('\u{FBE4}', CharMapAction::SubChar('ې')), // E ISOLATED FORM
+    ('\u{FBE5}', CharMapAction::SubChar('ې')), // E FINAL FORM
+    ('\u{FBE6}', CharMapAction::SubChar('ې')), // E INITIAL FORM
+    ('\u{FBE7}', CharMapAction::SubChar('ې')), // E MEDIAL FORM
+    ('\u{FBE8}', CharMapAction::SubChar('ى')), // UIGHUR KAZAKH KIRGHIZ ALEF MAKSURA INITIAL FORM
+    ('\u{FBE9}', CharMapAction::SubChar('ى')), // UIGHUR KAZAKH KIRGHIZ ALEF MAKSURA MEDIAL FORM
+    ('\u{FBEA}', CharMapAction::SubStr("ئا")), // YEH WITH HAMZA ABOVE WITH ALEF ISOLATED FORM
+    ('\u{FBEB}', CharMapAction::SubStr("ئا")), // YEH WITH HAMZA ABOVE WITH ALEF FINAL FORM
+    ('\u{FBEC}', CharMapAction::SubStr("ئە")), // YEH WITH HAMZA ABOVE WITH AE ISOLATED FORM
+    ('\u{FBED}', CharMapAction::SubStr("ئە")), // YEH WITH HAMZA ABOVE WITH AE FINAL FORM
+    ('\u{FBEE}', CharMapAction::SubStr("ئو")), // YEH WITH HAMZA ABOVE WITH WAW ISOLATED FORM
+    ('\u{FBEF}', CharMapAction::SubStr("ئو")), // YEH WITH HAMZA ABOVE WITH WAW FINAL FORM
+    ('\u{FBF0}', CharMapAction::SubStr("ئۇ")), // YEH WITH HAMZA ABOVE WITH U ISOLATED FORM
+    ('\u{FBF1}', CharMapAction::SubStr("ئۇ")), // YEH WITH HAMZA ABOVE WITH U FINAL FORM
+    ('\u{FBF2}', CharMapAction::SubStr("ئۆ")), // YEH WITH HAMZA ABOVE WITH OE ISOLATED FORM
+    ('\u{FBF3}', CharMapAction::SubStr("ئۆ")), // YEH WITH HAMZA ABOVE WITH OE FINAL FORM
+    ('\u{FBF4}', CharMapAction::SubStr("ئۈ")), // YEH WITH HAMZA ABOVE WITH YU ISOLATED FORM
+    ('\u{FBF5}', CharMapAction::SubStr("ئۈ")), // YEH WITH HAMZA ABOVE WITH YU FINAL FORM
+    ('\u{FBF6}', CharMapAction::SubStr("ئې")), // YEH WITH HAMZA ABOVE WITH E ISOLATED FORM
+    ('\u{FBF7}', CharMapAction::SubStr("ئې")), // YEH WITH HAMZA ABOVE WITH E FINAL FORM
+    ('\u{FBF8}', CharMapAction::SubStr("ئې")), // YEH WITH HAMZA ABOVE WITH E INITIAL FORM
+    ('\u{FBF9}', CharMapAction::SubStr("ئى")), // UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FBFA}', CharMapAction::SubStr("ئى")), // UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA FINAL FORM
+    ('\u{FBFB}', CharMapAction::SubStr("ئى")), // UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA INITIAL FORM
+    ('\u{FBFC}', CharMapAction::SubChar('ی')), // FARSI YEH ISOLATED FORM
+    ('\u{FBFD}', CharMapAction::SubChar('ی')), // FARSI YEH FINAL FORM
+    ('\u{FBFE}', CharMapAction::SubChar('ی')), // FARSI YEH INITIAL FORM
+    ('\u{FBFF}', CharMapAction::SubChar('ی')), // FARSI YEH MEDIAL FORM
+    ('\u{FC00}', CharMapAction::SubStr("ئج")), // YEH WITH HAMZA ABOVE WITH JEEM ISOLATED FORM
+    ('\u{FC01}', CharMapAction::SubStr("ئح")), // YEH WITH HAMZA ABOVE WITH HAH ISOLATED FORM
+    ('\u{FC02}', CharMapAction::SubStr("ئم")), // YEH WITH HAMZA ABOVE WITH MEEM ISOLATED FORM
+    ('\u{FC03}', CharMapAction::SubStr("ئى")), // YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC04}', CharMapAction::SubStr("ئي")), // YEH WITH HAMZA ABOVE WITH YEH ISOLATED FORM
+    ('\u{FC05}', CharMapAction::SubStr("بج")), // BEH WITH JEEM ISOLATED FORM
+    ('\u{FC06}', CharMapAction::SubStr("بح")), // BEH WITH HAH ISOLATED FORM
+    ('\u{FC07}', CharMapAction::SubStr("بخ")), // BEH WITH KHAH ISOLATED FORM
+    ('\u{FC08}', CharMapAction::SubStr("بم")), // BEH WITH MEEM ISOLATED FORM
+    ('\u{FC09}', CharMapAction::SubStr("بى")), // BEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC0A}', CharMapAction::SubStr("بي")), // BEH WITH YEH ISOLATED FORM
+    ('\u{FC0B}', CharMapAction::SubStr("تج")), // TEH WITH JEEM ISOLATED FORM
+    ('\u{FC0C}', CharMapAction::SubStr("تح")), // TEH WITH HAH ISOLATED FORM
+    ('\u{FC0D}', CharMapAction::SubStr("تخ")), // TEH WITH KHAH ISOLATED FORM
+    ('\u{FC0E}', CharMapAction::SubStr("تم")), // TEH WITH MEEM ISOLATED FORM
+    ('\u{FC0F}', CharMapAction::SubStr("تى")), // TEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC10}', CharMapAction::SubStr("تي")), // TEH WITH YEH ISOLATED FORM
+    ('\u{FC11}', CharMapAction::SubStr("ثج")), // THEH WITH JEEM ISOLATED FORM
+    ('\u{FC12}', CharMapAction::SubStr("ثم")), // THEH WITH MEEM ISOLATED FORM
+    ('\u{FC13}', CharMapAction::SubStr("ثى")), // THEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC14}', CharMapAction::SubStr("ثي")), // THEH WITH YEH ISOLATED FORM
+    ('\u{FC15}', CharMapAction::SubStr("جح")), // JEEM WITH HAH ISOLATED FORM
+    ('\u{FC16}', CharMapAction::SubStr("جم")), // JEEM WITH MEEM ISOLATED FORM
+    ('\u{FC17}', CharMapAction::SubStr("حج")), // HAH WITH JEEM ISOLATED FORM
+    ('\u{FC18}', CharMapAction::SubStr("حم")), // HAH WITH MEEM ISOLATED FORM
+    ('\u{FC19}', CharMapAction::SubStr("خج")), // KHAH WITH JEEM ISOLATED FORM
+    ('\u{FC1A}', CharMapAction::SubStr("خح")), // KHAH WITH HAH ISOLATED FORM
+    ('\u{FC1B}', CharMapAction::SubStr("خم")), // KHAH WITH MEEM ISOLATED FORM
+    ('\u{FC1C}', CharMapAction::SubStr("سج")), // SEEN WITH JEEM ISOLATED FORM
+    ('\u{FC1D}', CharMapAction::SubStr("سح")), // SEEN WITH HAH ISOLATED FORM
+    ('\u{FC1E}', CharMapAction::SubStr("سخ")), // SEEN WITH KHAH ISOLATED FORM
+    ('\u{FC1F}', CharMapAction::SubStr("سم")), // SEEN WITH MEEM ISOLATED FORM
+    ('\u{FC20}', CharMapAction::SubStr("صح")), // SAD WITH HAH ISOLATED FORM
+    ('\u{FC21}', CharMapAction::SubStr("صم")), // SAD WITH MEEM ISOLATED FORM
+    ('\u{FC22}', CharMapAction::SubStr("ضج")), // DAD WITH JEEM ISOLATED FORM
+    ('\u{FC23}', CharMapAction::SubStr("ضح")), // DAD WITH HAH ISOLATED FORM
+    ('\u{FC24}', CharMapAction::SubStr("ضخ")), // DAD WITH KHAH ISOLATED FORM
+    ('\u{FC25}', CharMapAction::SubStr("ضم")), // DAD WITH MEEM ISOLATED FORM
+    ('\u{FC26}', CharMapAction::SubStr("طح")), // TAH WITH HAH ISOLATED FORM
+    ('\u{FC27}', CharMapAction::SubStr("طم")), // TAH WITH MEEM ISOLATED FORM
+    ('\u{FC28}', CharMapAction::SubStr("ظم")), // ZAH WITH MEEM ISOLATED FORM
+    ('\u{FC29}', CharMapAction::SubStr("عج")), // AIN WITH JEEM ISOLATED FORM
+    ('\u{FC2A}', CharMapAction::SubStr("عم")), // AIN WITH MEEM ISOLATED FORM
+    ('\u{FC2B}', CharMapAction::SubStr("غج")), // GHAIN WITH JEEM ISOLATED FORM
+    ('\u{FC2C}', CharMapAction::SubStr("غم")), // GHAIN WITH MEEM ISOLATED FORM
+    ('\u{FC2D}', CharMapAction::SubStr("فج")), // FEH WITH JEEM ISOLATED FORM
+    ('\u{FC2E}', CharMapAction::SubStr("فح")), // FEH WITH HAH ISOLATED FORM
+    ('\u{FC2F}', CharMapAction::SubStr("فخ")), // FEH WITH KHAH ISOLATED FORM
+    ('\u{FC30}', CharMapAction::SubStr("فم")), // FEH WITH MEEM ISOLATED FORM
+    ('\u{FC31}', CharMapAction::SubStr("فى")), // FEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC32}', CharMapAction::SubStr("في")), // FEH WITH YEH ISOLATED FORM
+    ('\u{FC33}', CharMapAction::SubStr("قح")), // QAF WITH HAH ISOLATED FORM
+    ('\u{FC34}', CharMapAction::SubStr("قم")), // QAF WITH MEEM ISOLATED FORM
+    ('\u{FC35}', CharMapAction::SubStr("قى")), // QAF WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC36}', CharMapAction::SubStr("قي")), // QAF WITH YEH ISOLATED FORM
+    ('\u{FC37}', CharMapAction::SubStr("كا")), // KAF WITH ALEF ISOLATED FORM
+    ('\u{FC38}', CharMapAction::SubStr("كج")), // KAF WITH JEEM ISOLATED FORM
+    ('\u{FC39}', CharMapAction::SubStr("كح")), // KAF WITH HAH ISOLATED FORM
+    ('\u{FC3A}', CharMapAction::SubStr("كخ")), // KAF WITH KHAH ISOLATED FORM
+    ('\u{FC3B}', CharMapAction::SubStr("كل")), // KAF WITH LAM ISOLATED FORM
+    ('\u{FC3C}', CharMapAction::SubStr("كم")), // KAF WITH MEEM ISOLATED FORM
+    ('\u{FC3D}', CharMapAction::SubStr("كى")), // KAF WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC3E}', CharMapAction::SubStr("كي")), // KAF WITH YEH ISOLATED FORM
+    ('\u{FC3F}', CharMapAction::SubStr("لج")), // LAM WITH JEEM ISOLATED FORM
+    ('\u{FC40}', CharMapAction::SubStr("لح")), // LAM WITH HAH ISOLATED FORM
+    ('\u{FC41}', CharMapAction::SubStr("لخ")), // LAM WITH KHAH ISOLATED FORM
+    ('\u{FC42}', CharMapAction::SubStr("لم")), // LAM WITH MEEM ISOLATED FORM
+    ('\u{FC43}', CharMapAction::SubStr("لى")), // LAM WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC44}', CharMapAction::SubStr("لي")), // LAM WITH YEH ISOLATED FORM
+    ('\u{FC45}', CharMapAction::SubStr("مج")), // MEEM WITH JEEM ISOLATED FORM
+    ('\u{FC46}', CharMapAction::SubStr("مح")), // MEEM WITH HAH ISOLATED FORM
+    ('\u{FC47}', CharMapAction::SubStr("مخ")), // MEEM WITH KHAH ISOLATED FORM
+    ('\u{FC48}', CharMapAction::SubStr("مم")), // MEEM WITH MEEM ISOLATED FORM
+    ('\u{FC49}', CharMapAction::SubStr("مى")), // MEEM WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC4A}', CharMapAction::SubStr("مي")), // MEEM WITH YEH ISOLATED FORM
+    ('\u{FC4B}', CharMapAction::SubStr("نج")), // NOON WITH JEEM ISOLATED FORM
+    ('\u{FC4C}', CharMapAction::SubStr("نح")), // NOON WITH HAH ISOLATED FORM
+    ('\u{FC4D}', CharMapAction::SubStr("نخ")), // NOON WITH KHAH ISOLATED FORM
+    ('\u{FC4E}', CharMapAction::SubStr("نم")), // NOON WITH MEEM ISOLATED FORM
+    ('\u{FC4F}', CharMapAction::SubStr("نى")), // NOON WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC50}', CharMapAction::SubStr("ني")), // NOON WITH YEH ISOLATED FORM
+    ('\u{FC51}', CharMapAction::SubStr("هج")), // HEH WITH JEEM ISOLATED FORM
+    ('\u{FC52}', CharMapAction::SubStr("هم")), // HEH WITH MEEM ISOLATED FORM
+    ('\u{FC53}', CharMapAction::SubStr("هى")), // HEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC54}', CharMapAction::SubStr("هي")), // HEH WITH YEH ISOLATED FORM
+    ('\u{FC55}', CharMapAction::SubStr("يج")), // YEH WITH JEEM ISOLATED FORM
+    ('\u{FC56}', CharMapAction::SubStr("يح")), // YEH WITH HAH ISOLATED FORM
+    ('\u{FC57}', CharMapAction::SubStr("يخ")), // YEH WITH KHAH ISOLATED FORM
+    ('\u{FC58}', CharMapAction::SubStr("يم")), // YEH WITH MEEM ISOLATED FORM
+    ('\u{FC59}', CharMapAction::SubStr("يى")), // YEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC5A}', CharMapAction::SubStr("يي")), // YEH WITH YEH ISOLATED FORM
+    ('\u{FC5B}', CharMapAction::SubStr("ذ\u{0670}")), // THAL WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC5C}', CharMapAction::SubStr("ر\u{0670}")), // REH WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC5D}', CharMapAction::SubStr("ى\u{0670}")), // ALEF MAKSURA WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC5E}', CharMapAction::SubStr(" \u{064C}\u{0651}")), // SHADDA WITH DAMMATAN ISOLATED FORM
+    ('\u{FC5F}', CharMapAction::SubStr(" \u{064D}\u{0651}")), // SHADDA WITH KASRATAN ISOLATED FORM
+    ('\u{FC60}', CharMapAction::SubStr(" \u{064E}\u{0651}")), // SHADDA WITH FATHA ISOLATED FORM
+    ('\u{FC61}', CharMapAction::SubStr(" \u{064F}\u{0651}")), // SHADDA WITH DAMMA ISOLATED FORM
+    ('\u{FC62}', CharMapAction::SubStr(" \u{0650}\u{0651}")), // SHADDA WITH KASRA ISOLATED FORM
+    ('\u{FC63}', CharMapAction::SubStr(" \u{0651}\u{0670}")), // SHADDA WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC64}', CharMapAction::SubStr("ئر")), // YEH WITH HAMZA ABOVE WITH REH FINAL FORM
+    ('\u{FC65}', CharMapAction::SubStr("ئز")), // YEH WITH HAMZA ABOVE WITH ZAIN FINAL FORM
+    ('\u{FC66}', CharMapAction::SubStr("ئم")), // YEH WITH HAMZA ABOVE WITH MEEM FINAL FORM
+    ('\u{FC67}', CharMapAction::SubStr("ئن")), // YEH WITH HAMZA ABOVE WITH NOON FINAL FORM
+    ('\u{FC68}', CharMapAction::SubStr("ئى")), // YEH WITH HAMZA ABOVE WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC69}', CharMapAction::SubStr("ئي")), // YEH WITH HAMZA ABOVE WITH YEH FINAL FORM
+    ('\u{FC6A}', CharMapAction::SubStr("بر")), // BEH WITH REH FINAL FORM
+    ('\u{FC6B}', CharMapAction::SubStr("بز")), // BEH WITH ZAIN FINAL FORM
+    ('\u{FC6C}', CharMapAction::SubStr("بم")), // BEH WITH MEEM FINAL FORM
+    ('\u{FC6D}', CharMapAction::SubStr("بن")), // BEH WITH NOON FINAL FORM
+    ('\u{FC6E}', CharMapAction::SubStr("بى")), // BEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC6F}', CharMapAction::SubStr("بي")), // BEH WITH YEH FINAL FORM
+    ('\u{FC70}', CharMapAction::SubStr("تر")), // TEH WITH REH FINAL FORM
+    ('\u{FC71}', CharMapAction::SubStr("تز")), // TEH WITH ZAIN FINAL FORM
+    ('\u{FC72}', CharMapAction::SubStr("تم")), // TEH WITH MEEM FINAL FORM
+    ('\u{FC73}', CharMapAction::SubStr("تن")), // TEH WITH NOON FINAL FORM
+    ('\u{FC74}', CharMapAction::SubStr("تى")), // TEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC75}', CharMapAction::SubStr("تي")), // TEH WITH YEH FINAL FORM
+    ('\u{FC76}', CharMapAction::SubStr("ثر")), // THEH WITH REH FINAL FORM
+    ('\u{FC77}', CharMapAction::SubStr("ثز")), // THEH WITH ZAIN FINAL FORM
+    ('\u{FC78}', CharMapAction::SubStr("ثم")), // THEH WITH MEEM FINAL FORM
+    ('\u{FC79}', CharMapAction::SubStr("ثن")), // THEH WITH NOON FINAL FORM
+    ('\u{FC7A}', CharMapAction::SubStr("ثى")), // THEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC7B}', CharMapAction::SubStr("ثي")), // THEH WITH YEH FINAL FORM
+    ('\u{FC7C}', CharMapAction::SubStr("فى")), // FEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC7D}', CharMapAction::SubStr("في")), // FEH WITH YEH FINAL FORM
+    ('\u{FC7E}', CharMapAction::SubStr("قى")), // QAF WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC7F}', CharMapAction::SubStr("قي")), // QAF WITH YEH FINAL FORM
+    ('\u{FC80}', CharMapAction::SubStr("كا")), // KAF WITH ALEF FINAL FORM
+    ('\u{FC81}', CharMapAction::SubStr("كل")), // KAF WITH LAM FINAL FORM
+    ('\u{FC82}', CharMapAction::SubStr("كم")), // KAF WITH MEEM FINAL FORM
+    ('\u{FC83}', CharMapAction::SubStr("كى")), // KAF WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC84}', CharMapAction::SubStr("كي")), // KAF WITH YEH FINAL FORM
+    ('\u{FC85}', CharMapAction::SubStr("لم")), // LAM WITH MEEM FINAL FORM
+    ('\u{FC86}', CharMapAction::SubStr("لى")), // LAM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC87}', CharMapAction::SubStr("لي")), // LAM WITH YEH FINAL FORM
+    ('\u{FC88}', CharMapAction::SubStr("ما")), // MEEM WITH ALEF FINAL FORM
+    ('\u{FC89}', CharMapAction::SubStr("مم")), // MEEM WITH MEEM FINAL FORM
+    ('\u{FC8A}', CharMapAction::SubStr("نر")), // NOON WITH REH FINAL FORM
+    ('\u{FC8B}', CharMapAction::SubStr("نز")), // NOON WITH ZAIN FINAL FORM
+    ('\u{FC8C}', CharMapAction::SubStr("نم")), // NOON WITH MEEM FINAL FORM
+    ('\u{FC8D}', CharMapAction::SubStr("نن")), // NOON WITH NOON FINAL FORM
+    ('\u{FC8E}', CharMapAction::SubStr("نى")), // NOON WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC8F}', CharMapAction::SubStr("ني")), // NOON WITH YEH FINAL FORM
+    ('\u{FC90}', CharMapAction::SubStr("ى\u{0670}")), // ALEF MAKSURA WITH SUPERSCRIPT ALEF FINAL FORM
+    ('\u{FC91}', CharMapAction::SubStr("ير")), // YEH WITH REH FINAL FORM
+    ('\u{FC92}', CharMapAction::SubStr("يز")), // YEH WITH ZAIN FINAL FORM
+    ('\u{FC93}', CharMapAction::SubStr("يم")), // YEH WITH MEEM FINAL FORM
+    ('\u{FC94}', CharMapAction::SubStr("ين")), // YEH WITH NOON FINAL FORM
+    ('\u{FC95}', CharMapAction::SubStr("يى")), // YEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC96}', CharMapAction::SubStr("يي")), // YEH WITH YEH FINAL FORM
+    ('\u{FC97}', CharMapAction::SubStr("ئج")), // YEH WITH HAMZA ABOVE WITH JEEM INITIAL FORM
+    ('\u{FC98}', CharMapAction::SubStr("ئح")), // YEH WITH HAMZA ABOVE WITH HAH INITIAL FORM
+    ('\u{FC99}', CharMapAction::SubStr("ئخ")), // YEH WITH HAMZA ABOVE WITH KHAH INITIAL FORM
+    ('\u{FC9A}', CharMapAction::SubStr("ئم")), // YEH WITH HAMZA ABOVE WITH MEEM INITIAL FORM
+    ('\u{FC9B}', CharMapAction::SubStr("ئه")), // YEH WITH HAMZA ABOVE WITH HEH INITIAL FORM
+    ('\u{FC9C}', CharMapAction::SubStr("بج")), // BEH WITH JEEM INITIAL FORM
+    ('\u{FC9D}', CharMapAction::SubStr("بح")), // BEH WITH HAH INITIAL FORM
+    ('\u{FC9E}', CharMapAction::SubStr("بخ")), // BEH WITH KHAH INITIAL FORM
+    ('\u{FC9F}', CharMapAction::SubStr("بم")), // BEH WITH MEEM INITIAL FORM
+    ('\u{FCA0}', CharMapAction::SubStr("به")), // BEH WITH HEH INITIAL FORM
+    ('\u{FCA1}', CharMapAction::SubStr("تج")), // TEH WITH JEEM INITIAL FORM
+    ('\u{FCA2}', CharMapAction::SubStr("تح")), // TEH WITH HAH INITIAL FORM
+    ('\u{FCA3}', CharMapAction::SubStr("تخ")), // TEH WITH KHAH INITIAL FORM
+    ('\u{FCA4}', CharMapAction::SubStr("تم")), // TEH WITH MEEM INITIAL FORM
+    ('\u{FCA5}', CharMapAction::SubStr("ته")), // TEH WITH HEH INITIAL FORM
+    ('\u{FCA6}', CharMapAction::SubStr("ثم")), // THEH WITH MEEM INITIAL FORM
+    ('\u{FCA7}', CharMapAction::SubStr("جح")), // JEEM WITH HAH INITIAL FORM
+    ('\u{FCA8}', CharMapAction::SubStr("جم")), // JEEM WITH MEEM INITIAL FORM
+    ('\u{FCA9}', CharMapAction::SubStr("حج")), // HAH WITH JEEM INITIAL FORM
+    ('\u{FCAA}', CharMapAction::SubStr("حم")), // HAH WITH MEEM INITIAL FORM
+    ('\u{FCAB}', CharMapAction::SubStr("خج")), // KHAH WITH JEEM INITIAL FORM
+    ('\u{FCAC}', CharMapAction::SubStr("خم")), // KHAH WITH MEEM INITIAL FORM
+    ('\u{FCAD}', CharMapAction::SubStr("سج")), // SEEN WITH JEEM INITIAL FORM
+    ('\u{FCAE}', CharMapAction::SubStr("سح")), // SEEN WITH HAH INITIAL FORM
+    ('\u{FCAF}', CharMapAction::SubStr("سخ")), // SEEN WITH KHAH INITIAL FORM
+    ('\u{FCB0}', CharMapAction::SubStr("سم")), // SEEN WITH MEEM INITIAL FORM
+    ('\u{FCB1}', CharMapAction::SubStr("صح")), // SAD WITH HAH INITIAL FORM
+    ('\u{FCB2}', CharMapAction::SubStr("صخ")), // SAD WITH KHAH INITIAL FORM
+    ('\u{FCB3}', CharMapAction::SubStr("صم")), // SAD WITH MEEM INITIAL FORM
+    ('\u{FCB4}', CharMapAction::SubStr("ضج")), // DAD WITH JEEM INITIAL FORM
+    ('\u{FCB5}', CharMapAction::SubStr("ضح")), // DAD WITH HAH INITIAL FORM
+    ('\u{FCB6}', CharMapAction::SubStr("ضخ")), // DAD WITH KHAH INITIAL FORM
+    ('\u{FCB7}', CharMapAction::SubStr("ضم")), // DAD WITH MEEM INITIAL FORM
+    ('\u{FCB8}', CharMapAction::SubStr("طح")), // TAH WITH HAH INITIAL FORM
+    ('\u{FCB9}', CharMapAction::SubStr("ظم")), // ZAH WITH MEEM INITIAL FORM
+    ('\u{FCBA}', CharMapAction::SubStr("عج")), // AIN WITH JEEM INITIAL FORM
+    ('\u{FCBB}', CharMapAction::SubStr("عم")), // AIN WITH MEEM INITIAL FORM
+    ('\u{FCBC}', CharMapAction::SubStr("غج")), // GHAIN WITH JEEM INITIAL FORM
+    ('\u{FCBD}', CharMapAction::SubStr("غم")), // GHAIN WITH MEEM INITIAL FORM
+    ('\u{FCBE}', CharMapAction::SubStr("فج")), // FEH WITH JEEM INITIAL FORM
+    ('\u{FCBF}', CharMapAction::SubStr("فح")), // FEH WITH HAH INITIAL FORM
+    ('\u{FCC0}', CharMapAction::SubStr("فخ")), // FEH WITH KHAH INITIAL FORM
+    ('\u{FCC1}', CharMapAction::SubStr("فم")), // FEH WITH MEEM INITIAL FORM
+    ('\u{FCC2}', CharMapAction::SubStr("قح")), // QAF WITH HAH INITIAL FORM
+    ('\u{FCC3}', CharMapAction::SubStr("قم")), // QAF WITH MEEM INITIAL FORM
+    ('\u{FCC4}', CharMapAction::SubStr("كج")), // KAF WITH JEEM INITIAL FORM
+    ('\u{FCC5}', CharMapAction::SubStr("كح")), // KAF WITH HAH INITIAL FORM
+    ('\u{FCC6}', CharMapAction::SubStr("كخ")), // KAF WITH KHAH INITIAL FORM
+    ('\u{FCC7}', CharMapAction::SubStr("كل")), // KAF WITH LAM INITIAL FORM
+    ('\u{FCC8}', CharMapAction::SubStr("كم")), // KAF WITH MEEM INITIAL FORM
+    ('\u{FCC9}', CharMapAction::SubStr("لج")), // LAM WITH JEEM INITIAL FORM
+    ('\u{FCCA}', CharMapAction::SubStr("لح")), // LAM WITH HAH INITIAL FORM
+    ('\u{FCCB}', CharMapAction::SubStr("لخ")), // LAM WITH KHAH INITIAL FORM
+    ('\u{FCCC}', CharMapAction::SubStr("لم")), // LAM WITH MEEM INITIAL FORM
+    ('\u{FCCD}', CharMapAction::SubStr("له")), // LAM WITH HEH INITIAL FORM
+    ('\u{FCCE}', CharMapAction::SubStr("مج")), // MEEM WITH JEEM INITIAL FORM
+    ('\u{FCCF}', CharMapAction::SubStr("مح")), // MEEM WITH HAH INITIAL FORM
+    ('\u{FCD0}', CharMapAction::SubStr("مخ")), // MEEM WITH KHAH INITIAL FORM
+    ('\u{FCD1}', CharMapAction::SubStr("مم")), // MEEM WITH MEEM INITIAL FORM
+    ('\u{FCD2}', CharMapAction::SubStr("نج")), // NOON WITH JEEM INITIAL FORM
+    ('\u{FCD3}', CharMapAction::SubStr("نح")), // NOON WITH HAH INITIAL FORM
+    ('\u{FCD4}', CharMapAction::SubStr("نخ")), // NOON WITH KHAH INITIAL FORM
+    ('\u{FCD5}', CharMapAction::SubStr("نم")), // NOON WITH MEEM INITIAL FORM
+    ('\u{FCD6}', CharMapAction::SubStr("نه")), // NOON WITH HEH INITIAL FORM
+    ('\u{FCD7}', CharMapAction::SubStr("هج")), // HEH WITH JEEM INITIAL FORM
+    ('\u{FCD8}', CharMapAction::SubStr("هم")), // HEH WITH MEEM INITIAL FORM
+    ('\u{FCD9}', CharMapAction::SubStr("ه\u{0670}")), // HEH WITH SUPERSCRIPT ALEF INITIAL FORM
+    ('\u{FCDA}', CharMapAction::SubStr("يج")), // YEH WITH JEEM INITIAL FORM
+    ('\u{FCDB}', CharMapAction::SubStr("يح")), // YEH WITH HAH INITIAL FORM
+    ('\u{FCDC}', CharMapAction::SubStr("يخ")), // YEH WITH KHAH INITIAL FORM
+    ('\u{FCDD}', CharMapAction::SubStr("يم")), // YEH WITH MEEM INITIAL FORM
+    ('\u{FCDE}', CharMapAction::SubStr("يه")), // YEH WITH HEH INITIAL FORM
+    ('\u{FCDF}', CharMapAction::SubStr("ئم")), // YEH WITH HAMZA ABOVE WITH MEEM MEDIAL FORM
+    ('\u{FCE0}', CharMapAction::SubStr("ئه")), // YEH WITH HAMZA ABOVE WITH HEH MEDIAL FORM
+    ('\u{FCE1}', CharMapAction::SubStr("بم")), // BEH WITH MEEM MEDIAL FORM
+    ('\u{FCE2}', CharMapAction::SubStr("به")), // BEH WITH HEH MEDIAL FORM
+    ('\u{FCE3}', CharMapAction::SubStr("تم")), // TEH WITH MEEM MEDIAL FORM
+    ('\u{FCE4}', CharMapAction::SubStr("ته")), // TEH WITH HEH MEDIAL FORM
+    ('\u{FCE5}', CharMapAction::SubStr("ثم")), // THEH WITH MEEM MEDIAL FORM
+    ('\u{FCE6}', CharMapAction::SubStr("ثه")), // THEH WITH HEH MEDIAL FORM
+    ('\u{FCE7}', CharMapAction::SubStr("سم")), // SEEN WITH MEEM MEDIAL FORM
+    ('\u{FCE8}', CharMapAction::SubStr("سه")), // SEEN WITH HEH MEDIAL FORM
+    ('\u{FCE9}', CharMapAction::SubStr("شم")), // SHEEN WITH MEEM MEDIAL FORM
+    ('\u{FCEA}', CharMapAction::SubStr("شه")), // SHEEN WITH HEH MEDIAL FORM
+    ('\u{FCEB}', CharMapAction::SubStr("كل")), // KAF WITH LAM MEDIAL FORM
+    ('\u{FCEC}', CharMapAction::SubStr("كم")), // KAF WITH MEEM MEDIAL FORM
+    ('\u{FCED}', CharMapAction::SubStr("لم")), // LAM WITH MEEM MEDIAL FORM
+    ('\u{FCEE}', CharMapAction::SubStr("نم")), // NOON WITH MEEM MEDIAL FORM
+    ('\u{FCEF}', CharMapAction::SubStr("نه")), // NOON WITH HEH MEDIAL FORM
+    ('\u{FCF0}', CharMapAction::SubStr("يم")), // YEH WITH MEEM MEDIAL FORM
+    ('\u{FCF1}', CharMapAction::SubStr("يه")), // YEH WITH HEH MEDIAL FORM
+    ('\u{FCF2}', CharMapAction::SubStr("ـ\u{064E}\u{0651}")), // SHADDA WITH FATHA MEDIAL FORM
+    ('\u{FCF3}', CharMapAction::SubStr("ـ\u{064F}\u{0651}")), // SHADDA WITH DAMMA MEDIAL FORM
+    ('\u{FCF4}', CharMapAction::SubStr("ـ\u{0650}\u{0651}")), // SHADDA WITH KASRA MEDIAL FORM
+    ('\u{FCF5}', CharMapAction::SubStr("طى")), // TAH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCF6}', CharMapAction::SubStr("طي")), // TAH WITH YEH ISOLATED FORM
+    ('\u{FCF7}', CharMapAction::SubStr("عى")), // AIN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCF8}', CharMapAction::SubStr("عي")), // AIN WITH YEH ISOLATED FORM
+    ('\u{FCF9}', CharMapAction::SubStr("غى")), // GHAIN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCFA}', CharMapAction::SubStr("غي")), // GHAIN WITH YEH ISOLATED FORM
+    ('\u{FCFB}', CharMapAction::SubStr("سى")), // SEEN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCFC}', CharMapAction::SubStr("سي")), // SEEN WITH YEH ISOLATED FORM
+    ('\u{FCFD}', CharMapAction::SubStr("شى")), // SHEEN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCFE}', CharMapAction::SubStr("شي")), // SHEEN WITH YEH ISOLATED FORM
+    ('\u{FCFF}', CharMapAction::SubStr("حى")), // HAH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD00}', CharMapAction::SubStr("حي")), // HAH WITH YEH ISOLATED FORM
+    ('\u{FD01}', CharMapAction::SubStr("جى")), // JEEM WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD02}', CharMapAction::SubStr("جي")), // JEEM WITH YEH ISOLATED FORM
+    ('\u{FD03}', CharMapAction::SubStr("خى")), // KHAH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD04}', CharMapAction::SubStr("خي")), // KHAH WITH YEH ISOLATED FORM
+    ('\u{FD05}', CharMapAction::SubStr("صى")), // SAD WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD06}', CharMapAction::SubStr("صي")), // SAD WITH YEH ISOLATED FORM
+    ('\u{FD07}', CharMapAction::SubStr("ضى")), // DAD WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD08}', CharMapAction::SubStr("ضي")), // DAD WITH YEH ISOLATED FORM
+    ('\u{FD09}', CharMapAction::SubStr("شج")), // SHEEN WITH JEEM ISOLATED FORM
+    ('\u{FD0A}', CharMapAction::SubStr("شح")), // SHEEN WITH HAH ISOLATED FORM
+    ('\u{FD0B}', CharMapAction::SubStr("شخ")), // SHEEN WITH KHAH ISOLATED FORM
+    ('\u{FD0C}', CharMapAction::SubStr("شم")), // SHEEN WITH MEEM ISOLATED FORM
+    ('\u{FD0D}', CharMapAction::SubStr("شر")), // SHEEN WITH REH ISOLATED FORM
+    ('\u{FD0E}', CharMapAction::SubStr("سر")), // SEEN WITH REH ISOLATED FORM
+    ('\u{FD0F}', CharMapAction::SubStr("صر")), // SAD WITH REH ISOLATED FORM
+    ('\u{FD10}', CharMapAction::SubStr("ضر")), // DAD WITH REH ISOLATED FORM
+    ('\u{FD11}', CharMapAction::SubStr("طى")), // TAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD12}', CharMapAction::SubStr("طي")), // TAH WITH YEH FINAL FORM
+    ('\u{FD13}', CharMapAction::SubStr("عى")), // AIN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD14}', CharMapAction::SubStr("عي")), // AIN WITH YEH FINAL FORM
+    ('\u{FD15}', CharMapAction::SubStr("غى")), // GHAIN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD16}', CharMapAction::SubStr("غي")), // GHAIN WITH YEH FINAL FORM
+    ('\u{FD17}', CharMapAction::SubStr("سى")), // SEEN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD18}', CharMapAction::SubStr("سي")), // SEEN WITH YEH FINAL FORM
+    ('\u{FD19}', CharMapAction::SubStr("شى")), // SHEEN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD1A}', CharMapAction::SubStr("شي")), // SHEEN WITH YEH FINAL FORM
+    ('\u{FD1B}', CharMapAction::SubStr("حى")), // HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD1C}', CharMapAction::SubStr("حي")), // HAH WITH YEH FINAL FORM
+    ('\u{FD1D}', CharMapAction::SubStr("جى")), // JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD1E}', CharMapAction::SubStr("جي")), // JEEM WITH YEH FINAL FORM
+    ('\u{FD1F}', CharMapAction::SubStr("خى")), // KHAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD20}', CharMapAction::SubStr("خي")), // KHAH WITH YEH FINAL FORM
+    ('\u{FD21}', CharMapAction::SubStr("صى")), // SAD WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD22}', CharMapAction::SubStr("صي")), // SAD WITH YEH FINAL FORM
+    ('\u{FD23}', CharMapAction::SubStr("ضى")), // DAD WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD24}', CharMapAction::SubStr("ضي")), // DAD WITH YEH FINAL FORM
+    ('\u{FD25}', CharMapAction::SubStr("شج")), // SHEEN WITH JEEM FINAL FORM
+    ('\u{FD26}', CharMapAction::SubStr("شح")), // SHEEN WITH HAH FINAL FORM
+    ('\u{FD27}', CharMapAction::SubStr("شخ")), // SHEEN WITH KHAH FINAL FORM
+    ('\u{FD28}', CharMapAction::SubStr("شم")), // SHEEN WITH MEEM FINAL FORM
+    ('\u{FD29}', CharMapAction::SubStr("شر")), // SHEEN WITH REH FINAL FORM
+    ('\u{FD2A}', CharMapAction::SubStr("سر")), // SEEN WITH REH FINAL FORM
+    ('\u{FD2B}', CharMapAction::SubStr("صر")), // SAD WITH REH FINAL FORM
+    ('\u{FD2C}', CharMapAction::SubStr("ضر")), // DAD WITH REH FINAL FORM
+    ('\u{FD2D}', CharMapAction::SubStr("شج")), // SHEEN WITH JEEM INITIAL FORM
+    ('\u{FD2E}', CharMapAction::SubStr("شح")), // SHEEN WITH HAH INITIAL FORM
+    ('\u{FD2F}', CharMapAction::SubStr("شخ")), // SHEEN WITH KHAH INITIAL FORM
+    ('\u{FD30}', CharMapAction::SubStr("شم")), // SHEEN WITH MEEM INITIAL FORM
+    ('\u{FD31}', CharMapAction::SubStr("سه")), // SEEN WITH HEH INITIAL FORM
+    ('\u{FD32}', CharMapAction::SubStr("شه")), // SHEEN WITH HEH INITIAL FORM
+    ('\u{FD33}', CharMapAction::SubStr("طم")), // TAH WITH MEEM INITIAL FORM
+    ('\u{FD34}', CharMapAction::SubStr("سج")), // SEEN WITH JEEM MEDIAL FORM
+    ('\u{FD35}', CharMapAction::SubStr("سح")), // SEEN WITH HAH MEDIAL FORM
+    ('\u{FD36}', CharMapAction::SubStr("سخ")), // SEEN WITH KHAH MEDIAL FORM
+    ('\u{FD37}', CharMapAction::SubStr("شج")), // SHEEN WITH JEEM MEDIAL FORM
+    ('\u{FD38}', CharMapAction::SubStr("شح")), // SHEEN WITH HAH MEDIAL FORM
+    ('\u{FD39}', CharMapAction::SubStr("شخ")), // SHEEN WITH KHAH MEDIAL FORM
+    ('\u{FD3A}', CharMapAction::SubStr("طم")), // TAH WITH MEEM MEDIAL FORM
+    ('\u{FD3B}', CharMapAction::SubStr("ظم")), // ZAH WITH MEEM MEDIAL FORM
+    ('\u{FD3C}', CharMapAction::SubStr("ا\u{064B}")), // ALEF WITH FATHATAN FINAL FORM
+    ('\u{FD3D}', CharMapAction::SubStr("ا\u{064B}")), // ALEF WITH FATHATAN ISOLATED FORM
+    ('\u{FD50}', CharMapAction::SubStr("تجم")), // TEH WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FD51}', CharMapAction::SubStr("تحج")), // TEH WITH HAH WITH JEEM FINAL FORM
+    ('\u{FD52}', CharMapAction::SubStr("تحج")), // TEH WITH HAH WITH JEEM INITIAL FORM
+    ('\u{FD53}', CharMapAction::SubStr("تحم")), // TEH WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD54}', CharMapAction::SubStr("تخم")), // TEH WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD55}', CharMapAction::SubStr("تمج")), // TEH WITH MEEM WITH JEEM INITIAL FORM
+    ('\u{FD56}', CharMapAction::SubStr("تمح")), // TEH WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD57}', CharMapAction::SubStr("تمخ")), // TEH WITH MEEM WITH KHAH INITIAL FORM
+    ('\u{FD58}', CharMapAction::SubStr("جمح")), // JEEM WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD59}', CharMapAction::SubStr("جمح")), // JEEM WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD5A}', CharMapAction::SubStr("حمي")), // HAH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD5B}', CharMapAction::SubStr("حمى")), // HAH WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD5C}', CharMapAction::SubStr("سحج")), // SEEN WITH HAH WITH JEEM INITIAL FORM
+    ('\u{FD5D}', CharMapAction::SubStr("سجح")), // SEEN WITH JEEM WITH HAH INITIAL FORM
+    ('\u{FD5E}', CharMapAction::SubStr("سجى")), // SEEN WITH JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD5F}', CharMapAction::SubStr("سمح")), // SEEN WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD60}', CharMapAction::SubStr("سمح")), // SEEN WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD61}', CharMapAction::SubStr("سمج")), // SEEN WITH MEEM WITH JEEM INITIAL FORM
+    ('\u{FD62}', CharMapAction::SubStr("سمم")), // SEEN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD63}', CharMapAction::SubStr("سمم")), // SEEN WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD64}', CharMapAction::SubStr("صحح")), // SAD WITH HAH WITH HAH FINAL FORM
+    ('\u{FD65}', CharMapAction::SubStr("صحح")), // SAD WITH HAH WITH HAH INITIAL FORM
+    ('\u{FD66}', CharMapAction::SubStr("صمم")), // SAD WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD67}', CharMapAction::SubStr("شحم")), // SHEEN WITH HAH WITH MEEM FINAL FORM
+    ('\u{FD68}', CharMapAction::SubStr("شحم")), // SHEEN WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD69}', CharMapAction::SubStr("شجي")), // SHEEN WITH JEEM WITH YEH FINAL FORM
+    ('\u{FD6A}', CharMapAction::SubStr("شمخ")), // SHEEN WITH MEEM WITH KHAH FINAL FORM
+    ('\u{FD6B}', CharMapAction::SubStr("شمخ")), // SHEEN WITH MEEM WITH KHAH INITIAL FORM
+    ('\u{FD6C}', CharMapAction::SubStr("شمم")), // SHEEN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD6D}', CharMapAction::SubStr("شمم")), // SHEEN WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD6E}', CharMapAction::SubStr("ضحى")), // DAD WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD6F}', CharMapAction::SubStr("ضخم")), // DAD WITH KHAH WITH MEEM FINAL FORM
+    ('\u{FD70}', CharMapAction::SubStr("ضخم")), // DAD WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD71}', CharMapAction::SubStr("طمح")), // TAH WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD72}', CharMapAction::SubStr("طمح")), // TAH WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD73}', CharMapAction::SubStr("طمم")), // TAH WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD74}', CharMapAction::SubStr("طمي")), // TAH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD75}', CharMapAction::SubStr("عجم")), // AIN WITH JEEM WITH MEEM FINAL FORM
+    ('\u{FD76}', CharMapAction::SubStr("عمم")), // AIN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD77}', CharMapAction::SubStr("عمم")), // AIN WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD78}', CharMapAction::SubStr("عمى")), // AIN WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD79}', CharMapAction::SubStr("غمم")), // GHAIN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD7A}', CharMapAction::SubStr("غمي")), // GHAIN WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD7B}', CharMapAction::SubStr("غمى")), // GHAIN WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD7C}', CharMapAction::SubStr("فخم")), // FEH WITH KHAH WITH MEEM FINAL FORM
+    ('\u{FD7D}', CharMapAction::SubStr("فخم")), // FEH WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD7E}', CharMapAction::SubStr("قمح")), // QAF WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD7F}', CharMapAction::SubStr("قمم")), // QAF WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD80}', CharMapAction::SubStr("لحم")), // LAM WITH HAH WITH MEEM FINAL FORM
+    ('\u{FD81}', CharMapAction::SubStr("لحي")), // LAM WITH HAH WITH YEH FINAL FORM
+    ('\u{FD82}', CharMapAction::SubStr("لحى")), // LAM WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD83}', CharMapAction::SubStr("لجج")), // LAM WITH JEEM WITH JEEM INITIAL FORM
+    ('\u{FD84}', CharMapAction::SubStr("لجج")), // LAM WITH JEEM WITH JEEM FINAL FORM
+    ('\u{FD85}', CharMapAction::SubStr("لخم")), // LAM WITH KHAH WITH MEEM FINAL FORM
+    ('\u{FD86}', CharMapAction::SubStr("لخم")), // LAM WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD87}', CharMapAction::SubStr("لمح")), // LAM WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD88}', CharMapAction::SubStr("لمح")), // LAM WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD89}', CharMapAction::SubStr("محج")), // MEEM WITH HAH WITH JEEM INITIAL FORM
+    ('\u{FD8A}', CharMapAction::SubStr("محم")), // MEEM WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD8B}', CharMapAction::SubStr("محي")), // MEEM WITH HAH WITH YEH FINAL FORM
+    ('\u{FD8C}', CharMapAction::SubStr("مجح")), // MEEM WITH JEEM WITH HAH INITIAL FORM
+    ('\u{FD8D}', CharMapAction::SubStr("مجم")), // MEEM WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FD8E}', CharMapAction::SubStr("مخج")), // MEEM WITH KHAH WITH JEEM INITIAL FORM
+    ('\u{FD8F}', CharMapAction::SubStr("مخم")), // MEEM WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD92}', CharMapAction::SubStr("مجخ")), // MEEM WITH JEEM WITH KHAH INITIAL FORM
+    ('\u{FD93}', CharMapAction::SubStr("همج")), // HEH WITH MEEM WITH JEEM INITIAL FORM
+    ('\u{FD94}', CharMapAction::SubStr("همم")), // HEH WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD95}', CharMapAction::SubStr("نحم")), // NOON WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD96}', CharMapAction::SubStr("نحى")), // NOON WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD97}', CharMapAction::SubStr("نجم")), // NOON WITH JEEM WITH MEEM FINAL FORM
+    ('\u{FD98}', CharMapAction::SubStr("نجم")), // NOON WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FD99}', CharMapAction::SubStr("نجى")), // NOON WITH JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD9A}', CharMapAction::SubStr("نمي")), // NOON WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD9B}', CharMapAction::SubStr("نمى")), // NOON WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD9C}', CharMapAction::SubStr("يمم")), // YEH WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD9D}', CharMapAction::SubStr("يمم")), // YEH WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD9E}', CharMapAction::SubStr("بخي")), // BEH WITH KHAH WITH YEH FINAL FORM
+    ('\u{FD9F}', CharMapAction::SubStr("تجي")), // TEH WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDA0}', CharMapAction::SubStr("تجى")), // TEH WITH JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA1}', CharMapAction::SubStr("تخي")), // TEH WITH KHAH WITH YEH FINAL FORM
+    ('\u{FDA2}', CharMapAction::SubStr("تخى")), // TEH WITH KHAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA3}', CharMapAction::SubStr("تمي")), // TEH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDA4}', CharMapAction::SubStr("تمى")), // TEH WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA5}', CharMapAction::SubStr("جمي")), // JEEM WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDA6}', CharMapAction::SubStr("جحى")), // JEEM WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA7}', CharMapAction::SubStr("جمى")), // JEEM WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA8}', CharMapAction::SubStr("سخى")), // SEEN WITH KHAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA9}', CharMapAction::SubStr("صحي")), // SAD WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAA}', CharMapAction::SubStr("شحي")), // SHEEN WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAB}', CharMapAction::SubStr("ضحي")), // DAD WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAC}', CharMapAction::SubStr("لجي")), // LAM WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDAD}', CharMapAction::SubStr("لمي")), // LAM WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDAE}', CharMapAction::SubStr("يحي")), // YEH WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAF}', CharMapAction::SubStr("يجي")), // YEH WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDB0}', CharMapAction::SubStr("يمي")), // YEH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB1}', CharMapAction::SubStr("ممي")), // MEEM WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB2}', CharMapAction::SubStr("قمي")), // QAF WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB3}', CharMapAction::SubStr("نحي")), // NOON WITH HAH WITH YEH FINAL FORM
+    ('\u{FDB4}', CharMapAction::SubStr("قمح")), // QAF WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FDB5}', CharMapAction::SubStr("لحم")), // LAM WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FDB6}', CharMapAction::SubStr("عمي")), // AIN WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB7}', CharMapAction::SubStr("كمي")), // KAF WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB8}', CharMapAction::SubStr("نجح")), // NOON WITH JEEM WITH HAH INITIAL FORM
+    ('\u{FDB9}', CharMapAction::SubStr("مخي")), // MEEM WITH KHAH WITH YEH FINAL FORM
+    ('\u{FDBA}', CharMapAction::SubStr("لجم")), // LAM WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FDBB}', CharMapAction::SubStr("كمم")), // KAF WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FDBC}', CharMapAction::SubStr("لجم")), // LAM WITH JEEM WITH MEEM FINAL FORM
+    ('\u{FDBD}', CharMapAction::SubStr("نجح")), // NOON WITH JEEM WITH HAH FINAL FORM
+    ('\u{FDBE}', CharMapAction::SubStr("جحي")), // JEEM WITH HAH WITH YEH FINAL FORM
+    ('\u{FDBF}', CharMapAction::SubStr("حجي")), // HAH WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDC0}', CharMapAction::SubStr("مجي")), // MEEM WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDC1}', CharMapAction::SubStr("فمي")), // FEH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDC2}', CharMapAction::SubStr("بحي")), // BEH WITH HAH WITH YEH FINAL FORM
+    ('\u{FDC3}', CharMapAction::SubStr("كمم")), // KAF WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FDC4}', CharMapAction::SubStr("عجم")), // AIN WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FDC5}', CharMapAction::SubStr("صمم")), // SAD WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FDC6}', CharMapAction::SubStr("سخي")), // SEEN WITH KHAH WITH YEH FINAL FORM
+    ('\u{FDC7}', CharMapAction::SubStr("نجي")), // NOON WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDF0}', CharMapAction::SubStr("صلے")), // SALLA USED AS KORANIC STOP SIGN ISOLATED FORM
+    ('\u{FDF1}', CharMapAction::SubStr("قلے")), // QALA USED AS KORANIC STOP SIGN ISOLATED FORM
+    ('\u{FDF2}', CharMapAction::SubStr("الله")), // ALLAH ISOLATED FORM
+    ('\u{FDF3}', CharMapAction::SubStr("اكبر")), // AKBAR ISOLATED FORM
+    ('\u{FDF4}', CharMapAction::SubStr("محمد")), // MOHAMMAD ISOLATED FORM
+    ('\u{FDF5}', CharMapAction::SubStr("صلعم")), // SALAM ISOLATED FORM
+    ('\u{FDF6}', CharMapAction::SubStr("رسول")), // RASOUL ISOLATED FORM
+    ('\u{FDF7}', CharMapAction::SubStr("عليه")), // ALAYHE ISOLATED FORM
+    ('\u{FDF8}', CharMapAction::SubStr("وسلم")), // WASALLAM ISOLATED FORM
+    ('\u{FDF9}', CharMapAction::SubStr("صلى")), // SALLA ISOLATED FORM
+    ('\u{FDFA}', CharMapAction::SubStr("صلى الله عليه وسلم")), // SALLALLAHOU ALAYHE WASALLAM
+    ('\u{FDFB}', CharMapAction::SubStr("جل جلاله")), // JALLAJALALOUHOU
+    ('\u{FDFC}', CharMapAction::SubStr("ریال")), // RIAL SIGN
+    ('\u{FE70}', CharMapAction::SubStr(" \u{064B}")), // FATHATAN ISOLATED FORM
+    ('\u{FE71}', CharMapAction::SubStr("ـ\u{064B}")), // TATWEEL WITH FATHATAN ABOVE
+    ('\u{FE72}', CharMapAction::SubStr(" \u{064C}")), // DAMMATAN ISOLATED FORM
+    ('\u{FE74}', CharMapAction::SubStr(" \u{064D}")), // KASRATAN ISOLATED FORM
+    ('\u{FE76}', CharMapAction::SubStr(" \u{064E}")), // FATHA ISOLATED FORM
+    ('\u{FE77}', CharMapAction::SubStr("ـ\u{064E}")), // FATHA MEDIAL FORM
+    ('\u{FE78}', CharMapAction::SubStr(" \u{064F}")), // DAMMA ISOLATED FORM
+    ('\u{FE79}', CharMapAction::SubStr("ـ\u{064F}")), // DAMMA MEDIAL FORM
+    ('\u{FE7A}', CharMapAction::SubStr(" \u{0650}")), // KASRA ISOLATED FORM
+    ('\u{FE7B}', CharMapAction::SubStr("ـ\u{0650}")), // KASRA MEDIAL FORM
+    ('\u{FE7C}', CharMapAction::SubStr(" \u{0651}")), // SHADDA ISOLATED FORM
+    ('\u{FE7D}', CharMapAction::SubStr("ـ\u{0651}")), // SHADDA MEDIAL FORM
+    ('\u{FE7E}', CharMapAction::SubStr(" \u{0652}")), // SUKUN ISOLATED FORM
+    ('\u{FE7F}', CharMapAction::SubStr("ـ\u{0652}")), // SUKUN MEDIAL FORM
+    ('\u{FE80}', CharMapAction::SubChar('ء')), // HAMZA ISOLATED FORM
+    ('\u{FE81}', CharMapAction::SubChar('آ')), // ALEF WITH MADDA ABOVE ISOLATED FORM
+    ('\u{FE82}', CharMapAction::SubChar('آ')), // ALEF WITH MADDA ABOVE FINAL FORM
+    ('\u{FE83}', CharMapAction::SubChar('أ')), // ALEF WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FE84}', CharMapAction::SubChar('أ')), // ALEF WITH HAMZA ABOVE FINAL FORM
+    ('\u{FE85}', CharMapAction::SubChar('ؤ')), // WAW WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FE86}', CharMapAction::SubChar('ؤ')), // WAW WITH HAMZA ABOVE FINAL FORM
+    ('\u{FE87}', CharMapAction::SubChar('إ')), // ALEF WITH HAMZA BELOW ISOLATED FORM
+    ('\u{FE88}', CharMapAction::SubChar('إ')), // ALEF WITH HAMZA BELOW FINAL FORM
+    ('\u{FE89}', CharMapAction::SubChar('ئ')), // YEH WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FE8A}', CharMapAction::SubChar('ئ')), // YEH WITH HAMZA ABOVE FINAL FORM
+    ('\u{FE8B}', CharMapAction::SubChar('ئ')), // YEH WITH HAMZA ABOVE INITIAL FORM
+    ('\u{FE8C}', CharMapAction::SubChar('ئ')), // YEH WITH HAMZA ABOVE MEDIAL FORM
+    ('\u{FE8D}', CharMapAction::SubChar('ا')), // ALEF ISOLATED FORM
+    ('\u{FE8E}', CharMapAction::SubChar('ا')), // ALEF FINAL FORM
+    ('\u{FE8F}', CharMapAction::SubChar('ب')), // BEH ISOLATED FORM
+    ('\u{FE90}', CharMapAction::SubChar('ب')), // BEH FINAL FORM
+    ('\u{FE91}', CharMapAction::SubChar('ب')), // BEH INITIAL FORM
+    ('\u{FE92}', CharMapAction::SubChar('ب')), // BEH MEDIAL FORM
+    ('\u{FE93}', CharMapAction::SubChar('ة')), // TEH MARBUTA ISOLATED FORM
+    ('\u{FE94}', CharMapAction::SubChar('ة')), // TEH MARBUTA FINAL FORM
+    ('\u{FE95}', CharMapAction::SubChar('ت')), // TEH ISOLATED FORM
+    ('\u{FE96}', CharMapAction::SubChar('ت')), // TEH FINAL FORM
+    ('\u{FE97}', CharMapAction::SubChar('ت')), // TEH INITIAL FORM
+    ('\u{FE98}', CharMapAction::SubChar('ت')), // TEH MEDIAL FORM
+    ('\u{FE99}', CharMapAction::SubChar('ث')), // THEH ISOLATED FORM
+    ('\u{FE9A}', CharMapAction::SubChar('ث')), // THEH FINAL FORM
+    ('\u{FE9B}', CharMapAction::SubChar('ث')), // THEH INITIAL FORM
+    ('\u{FE9C}', CharMapAction::SubChar('ث')), // THEH MEDIAL FORM
+    ('\u{FE9D}', CharMapAction::SubChar('ج')), // JEEM ISOLATED FORM
+    ('\u{FE9E}', CharMapAction::SubChar('ج')), // JEEM FINAL FORM
+    ('\u{FE9F}', CharMapAction::SubChar('ج')), // JEEM INITIAL FORM
+    ('\u{FEA0}', CharMapAction::SubChar('ج')), // JEEM MEDIAL FORM
+    ('\u{FEA1}', CharMapAction::SubChar('ح')), // HAH ISOLATED FORM
+    ('\u{FEA2}', CharMapAction::SubChar('ح')), // HAH FINAL FORM
+    ('\u{FEA3}', CharMapAction::SubChar('ح')), // HAH INITIAL FORM
+    ('\u{FEA4}', CharMapAction::SubChar('ح')), // HAH MEDIAL FORM
+    ('\u{FEA5}', CharMapAction::SubChar('خ')), // KHAH ISOLATED FORM
+    ('\u{FEA6}', CharMapAction::SubChar('خ')), // KHAH FINAL FORM
+    ('\u{FEA7}', CharMapAction::SubChar('خ')), // KHAH INITIAL FORM
+    ('\u{FEA8}', CharMapAction::SubChar('خ')), // KHAH MEDIAL FORM
+    ('\u{FEA9}', CharMapAction::SubChar('د')), // DAL ISOLATED FORM
+    ('\u{FEAA}', CharMapAction::SubChar('د')), // DAL FINAL FORM
+    ('\u{FEAB}', CharMapAction::SubChar('ذ')), // THAL ISOLATED FORM
+    ('\u{FEAC}', CharMapAction::SubChar('ذ')), // THAL FINAL FORM
+    ('\u{FEAD}', CharMapAction::SubChar('ر')), // REH ISOLATED FORM
+    ('\u{FEAE}', CharMapAction::SubChar('ر')), // REH FINAL FORM
+    ('\u{FEAF}', CharMapAction::SubChar('ز')), // ZAIN ISOLATED FORM
+    ('\u{FEB0}', CharMapAction::SubChar('ز')), // ZAIN FINAL FORM
+    ('\u{FEB1}', CharMapAction::SubChar('س')), // SEEN ISOLATED FORM
+    ('\u{FEB2}', CharMapAction::SubChar('س')), // SEEN FINAL FORM
+    ('\u{FEB3}', CharMapAction::SubChar('س')), // SEEN INITIAL FORM
+    ('\u{FEB4}', CharMapAction::SubChar('س')), // SEEN MEDIAL FORM
+    ('\u{FEB5}', CharMapAction::SubChar('ش')), // SHEEN ISOLATED FORM
+    ('\u{FEB6}', CharMapAction::SubChar('ش')), // SHEEN FINAL FORM
+    ('\u{FEB7}', CharMapAction::SubChar('ش')), // SHEEN INITIAL FORM
+    ('\u{FEB8}', CharMapAction::SubChar('ش')), // SHEEN MEDIAL FORM
+    ('\u{FEB9}', CharMapAction::SubChar('ص')), // SAD ISOLATED FORM
+    ('\u{FEBA}', CharMapAction::SubChar('ص')), // SAD FINAL FORM
+    ('\u{FEBB}', CharMapAction::SubChar('ص')), // SAD INITIAL FORM
+    ('\u{FEBC}', CharMapAction::SubChar('ص')), // SAD MEDIAL FORM
+    ('\u{FEBD}', CharMapAction::SubChar('ض')), // DAD ISOLATED FORM
+    ('\u{FEBE}', CharMapAction::SubChar('ض')), // DAD FINAL FORM
+    ('\u{FEBF}', CharMapAction::SubChar('ض')), // DAD INITIAL FORM
+    ('\u{FEC0}', CharMapAction::SubChar('ض')), // DAD MEDIAL FORM
+    ('\u{FEC1}', CharMapAction::SubChar('ط')), // TAH ISOLATED FORM
+    ('\u{FEC2}', CharMapAction::SubChar('ط')), // TAH FINAL FORM
+    ('\u{FEC3}', CharMapAction::SubChar('ط')), // TAH INITIAL FORM
+    ('\u{FEC4}', CharMapAction::SubChar('ط')), // TAH MEDIAL FORM
+    ('\u{FEC5}', CharMapAction::SubChar('ظ')), // ZAH ISOLATED FORM
+    ('\u{FEC6}', CharMapAction::SubChar('ظ')), // ZAH FINAL FORM
+    ('\u{FEC7}', CharMapAction::SubChar('ظ')), // ZAH INITIAL FORM
+    ('\u{FEC8}', CharMapAction::SubChar('ظ')), // ZAH MEDIAL FORM
+    ('\u{FEC9}', CharMapAction::SubChar('ع')), // AIN ISOLATED FORM
+    ('\u{FECA}', CharMapAction::SubChar('ع')), // AIN FINAL FORM
+    ('\u{FECB}', CharMapAction::SubChar('ع')), // AIN INITIAL FORM
+    ('\u{FECC}', CharMapAction::SubChar('ع')), // AIN MEDIAL FORM
+    ('\u{FECD}', CharMapAction::SubChar('غ')), // GHAIN ISOLATED FORM
+    ('\u{FECE}', CharMapAction::SubChar('غ')), // GHAIN FINAL FORM
+    ('\u{FECF}', CharMapAction::SubChar('غ')), // GHAIN INITIAL FORM
+    ('\u{FED0}', CharMapAction::SubChar('غ')), // GHAIN MEDIAL FORM
+    ('\u{FED1}', CharMapAction::SubChar('ف')), // FEH ISOLATED FORM
+    ('\u{FED2}', CharMapAction::SubChar('ف')), // FEH FINAL FORM
+    ('\u{FED3}', CharMapAction::SubChar('ف')), // FEH INITIAL FORM
+    ('\u{FED4}', CharMapAction::SubChar('ف')), // FEH MEDIAL FORM
+    ('\u{FED5}', CharMapAction::SubChar('ق')), // QAF ISOLATED FORM
+    ('\u{FED6}', CharMapAction::SubChar('ق')), // QAF FINAL FORM
+    ('\u{FED7}', CharMapAction::SubChar('ق')), // QAF INITIAL FORM
+    ('\u{FED8}', CharMapAction::SubChar('ق')), // QAF MEDIAL FORM
+    ('\u{FED9}', CharMapAction::SubChar('ك')), // KAF ISOLATED FORM
+    ('\u{FEDA}', CharMapAction::SubChar('ك')), // KAF FINAL FORM
+    ('\u{FEDB}', CharMapAction::SubChar('ك')), // KAF INITIAL FORM
+    ('\u{FEDC}', CharMapAction::SubChar('ك')), // KAF MEDIAL FORM
+    ('\u{FEDD}', CharMapAction::SubChar('ل')), // LAM ISOLATED FORM
+    ('\u{FEDE}', CharMapAction::SubChar('ل')), // LAM FINAL FORM
+    ('\u{FEDF}', CharMapAction::SubChar('ل')), // LAM INITIAL FORM
+    ('\u{FEE0}', CharMapAction::SubChar('ل')), // LAM MEDIAL FORM
+    ('\u{FEE1}', CharMapAction::SubChar('م')), // MEEM ISOLATED FORM
+    ('\u{FEE2}', CharMapAction::SubChar('م')), // MEEM FINAL FORM
+    ('\u{FEE3}', CharMapAction::SubChar('م')), // MEEM INITIAL FORM
+    ('\u{FEE4}', CharMapAction::SubChar('م')), // MEEM MEDIAL FORM
+    ('\u{FEE5}', CharMapAction::SubChar('ن')), // NOON ISOLATED FORM
+    ('\u{FEE6}', CharMapAction::SubChar('ن')), // NOON FINAL FORM
+    ('\u{FEE7}', CharMapAction::SubChar('ن')), // NOON INITIAL FORM
+    ('\u{FEE8}', CharMapAction::SubChar('ن')), // NOON MEDIAL FORM
+    ('\u{FEE9}', CharMapAction::SubChar('ه')), // HEH ISOLATED FORM
+    ('\u{FEEA}', CharMapAction::SubChar('ه')), // HEH FINAL FORM
+    ('\u{FEEB}', CharMapAction::SubChar('ه')), // HEH INITIAL FORM
+    ('\u{FEEC}', CharMapAction::SubChar('ه')), // HEH MEDIAL FORM
+    ('\u{FEED}', CharMapAction::SubChar('و')), // WAW ISOLATED FORM
+    ('\u{FEEE}', CharMapAction::SubChar('و')), // WAW FINAL FORM
+    ('\u{FEEF}', CharMapAction::SubChar('ى')), // ALEF MAKSURA ISOLATED FORM
+    ('\u{FEF0}', CharMapAction::SubChar('ى')), // ALEF MAKSURA FINAL FORM
+    ('\u{FEF1}', CharMapAction::SubChar('ي')), // YEH ISOLATED FORM
+    ('\u{FEF2}', CharMapAction::SubChar('ي')), // YEH FINAL FORM
+    ('\u{FEF3}', CharMapAction::SubChar('ي')), // YEH INITIAL FORM
+    ('\u{FEF4}', CharMapAction::SubChar('ي')), // YEH MEDIAL FORM
+    ('\u{FEF5}', CharMapAction::SubStr("لآ")), // LAM WITH ALEF WITH MADDA ABOVE ISOLATED FORM
+    ('\u{FEF6}', CharMapAction::SubStr("لآ")), // LAM WITH ALEF WITH MADDA ABOVE FINAL FORM
+    ('\u{FEF7}', CharMapAction::SubStr("لأ")), // LAM WITH ALEF WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FEF8}', CharMapAction::SubStr("لأ")), // LAM WITH ALEF WITH HAMZA ABOVE FINAL FORM
+    ('\u{FEF9}', CharMapAction::SubStr("لإ")), // LAM WITH ALEF WITH HAMZA BELOW ISOLATED FORM
+    ('\u{FEFA}', CharMapAction::SubStr("لإ")), // LAM WITH ALEF WITH HAMZA BELOW FINAL FORM
+    ('\u{FEFB}', CharMapAction::SubStr("لا")), // LAM WITH ALEF ISOLATED FORM
+    ('\u{FEFC}', CharMapAction::SubStr("لا")), // LAM WITH ALEF FINAL FORM
+];
+const _: () = assert_sorted(&DECOMPOSE_PRESENTATION_FORMS);
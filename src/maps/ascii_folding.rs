@@ -0,0 +1,1274 @@
+//! ASCII folding map equivalent to Lucene's `ASCIIFoldingFilter`.
+//!
+//! Maps alphabetic, numeric, and symbolic characters outside the Basic Latin
+//! block to their ASCII equivalents, if one exists. Some characters are
+//! expanded to multiple ASCII characters (e.g. ß to "ss" and ﬁ to "fi").
+//! Characters without an ASCII equivalent are not mapped.
+//!
+//! In addition to Lucene's rules, the degree Celsius (℃) and degree
+//! Fahrenheit (℉) signs are folded to "oC" and "oF" respectively.
+//!
+//! ```rust
+//! use charmap::maps::ascii_folding::ASCII_FOLDING;
+//! use charmap::*;
+//!
+//! let mapper = CharMapper::new(&ASCII_FOLDING, CharMapAction::Pass);
+//!
+//! let folded: String = "Æsir straße ﬁnal café 25℃".map_chars(&mapper).collect();
+//! assert_eq!(folded, "AEsir strasse final cafe 25oC");
+//! ```
+//!
+//! This file is generated by `scripts/gen_ascii_folding.py`. Do not edit it
+//! manually.
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+
+/// Map folding characters to their ASCII equivalents.
+#[rustfmt::skip]
+pub static ASCII_FOLDING: [(char, CharMapAction); 1244] = [
+    ('\u{00AB}', CharMapAction::SubChar('"')), // LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00B2}', CharMapAction::SubChar('2')), // SUPERSCRIPT TWO
+    ('\u{00B3}', CharMapAction::SubChar('3')), // SUPERSCRIPT THREE
+    ('\u{00B9}', CharMapAction::SubChar('1')), // SUPERSCRIPT ONE
+    ('\u{00BB}', CharMapAction::SubChar('"')), // RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00C0}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH GRAVE
+    ('\u{00C1}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH ACUTE
+    ('\u{00C2}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX
+    ('\u{00C3}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH TILDE
+    ('\u{00C4}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH DIAERESIS
+    ('\u{00C5}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH RING ABOVE
+    ('\u{00C6}', CharMapAction::SubStr("AE")), // LATIN CAPITAL LETTER AE
+    ('\u{00C7}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH CEDILLA
+    ('\u{00C8}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH GRAVE
+    ('\u{00C9}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH ACUTE
+    ('\u{00CA}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX
+    ('\u{00CB}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH DIAERESIS
+    ('\u{00CC}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH GRAVE
+    ('\u{00CD}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH ACUTE
+    ('\u{00CE}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH CIRCUMFLEX
+    ('\u{00CF}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH DIAERESIS
+    ('\u{00D0}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER ETH
+    ('\u{00D1}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH TILDE
+    ('\u{00D2}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH GRAVE
+    ('\u{00D3}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH ACUTE
+    ('\u{00D4}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX
+    ('\u{00D5}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH TILDE
+    ('\u{00D6}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DIAERESIS
+    ('\u{00D8}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00D9}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH GRAVE
+    ('\u{00DA}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH ACUTE
+    ('\u{00DB}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH CIRCUMFLEX
+    ('\u{00DC}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DIAERESIS
+    ('\u{00DD}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH ACUTE
+    ('\u{00DE}', CharMapAction::SubStr("TH")), // LATIN CAPITAL LETTER THORN
+    ('\u{00DF}', CharMapAction::SubStr("ss")), // LATIN SMALL LETTER SHARP S
+    ('\u{00E0}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH GRAVE
+    ('\u{00E1}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH ACUTE
+    ('\u{00E2}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CIRCUMFLEX
+    ('\u{00E3}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH TILDE
+    ('\u{00E4}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH DIAERESIS
+    ('\u{00E5}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH RING ABOVE
+    ('\u{00E6}', CharMapAction::SubStr("ae")), // LATIN SMALL LETTER AE
+    ('\u{00E7}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH CEDILLA
+    ('\u{00E8}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH GRAVE
+    ('\u{00E9}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH ACUTE
+    ('\u{00EA}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX
+    ('\u{00EB}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH DIAERESIS
+    ('\u{00EC}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH GRAVE
+    ('\u{00ED}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH ACUTE
+    ('\u{00EE}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH CIRCUMFLEX
+    ('\u{00EF}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH DIAERESIS
+    ('\u{00F0}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER ETH
+    ('\u{00F1}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH TILDE
+    ('\u{00F2}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH GRAVE
+    ('\u{00F3}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH ACUTE
+    ('\u{00F4}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CIRCUMFLEX
+    ('\u{00F5}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH TILDE
+    ('\u{00F6}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DIAERESIS
+    ('\u{00F8}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH STROKE
+    ('\u{00F9}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH GRAVE
+    ('\u{00FA}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH ACUTE
+    ('\u{00FB}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH CIRCUMFLEX
+    ('\u{00FC}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DIAERESIS
+    ('\u{00FD}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH ACUTE
+    ('\u{00FE}', CharMapAction::SubStr("th")), // LATIN SMALL LETTER THORN
+    ('\u{00FF}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH DIAERESIS
+    ('\u{0100}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH MACRON
+    ('\u{0101}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH MACRON
+    ('\u{0102}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH BREVE
+    ('\u{0103}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH BREVE
+    ('\u{0104}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH OGONEK
+    ('\u{0105}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH OGONEK
+    ('\u{0106}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH ACUTE
+    ('\u{0107}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH ACUTE
+    ('\u{0108}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH CIRCUMFLEX
+    ('\u{0109}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH CIRCUMFLEX
+    ('\u{010A}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH DOT ABOVE
+    ('\u{010B}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH DOT ABOVE
+    ('\u{010C}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH CARON
+    ('\u{010D}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH CARON
+    ('\u{010E}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH CARON
+    ('\u{010F}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH CARON
+    ('\u{0110}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH STROKE
+    ('\u{0111}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH STROKE
+    ('\u{0112}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH MACRON
+    ('\u{0113}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH MACRON
+    ('\u{0114}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH BREVE
+    ('\u{0115}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH BREVE
+    ('\u{0116}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH DOT ABOVE
+    ('\u{0117}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH DOT ABOVE
+    ('\u{0118}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH OGONEK
+    ('\u{0119}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH OGONEK
+    ('\u{011A}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CARON
+    ('\u{011B}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CARON
+    ('\u{011C}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH CIRCUMFLEX
+    ('\u{011D}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH CIRCUMFLEX
+    ('\u{011E}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH BREVE
+    ('\u{011F}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH BREVE
+    ('\u{0120}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH DOT ABOVE
+    ('\u{0121}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH DOT ABOVE
+    ('\u{0122}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH CEDILLA
+    ('\u{0123}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH CEDILLA
+    ('\u{0124}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH CIRCUMFLEX
+    ('\u{0125}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH CIRCUMFLEX
+    ('\u{0126}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH STROKE
+    ('\u{0127}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH STROKE
+    ('\u{0128}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH TILDE
+    ('\u{0129}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH TILDE
+    ('\u{012A}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH MACRON
+    ('\u{012B}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH MACRON
+    ('\u{012C}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH BREVE
+    ('\u{012D}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH BREVE
+    ('\u{012E}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH OGONEK
+    ('\u{012F}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH OGONEK
+    ('\u{0130}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+    ('\u{0131}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER DOTLESS I
+    ('\u{0132}', CharMapAction::SubStr("IJ")), // LATIN CAPITAL LIGATURE IJ
+    ('\u{0133}', CharMapAction::SubStr("ij")), // LATIN SMALL LIGATURE IJ
+    ('\u{0134}', CharMapAction::SubChar('J')), // LATIN CAPITAL LETTER J WITH CIRCUMFLEX
+    ('\u{0135}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER J WITH CIRCUMFLEX
+    ('\u{0136}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH CEDILLA
+    ('\u{0137}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH CEDILLA
+    ('\u{0138}', CharMapAction::SubChar('q')), // LATIN SMALL LETTER KRA
+    ('\u{0139}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH ACUTE
+    ('\u{013A}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH ACUTE
+    ('\u{013B}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH CEDILLA
+    ('\u{013C}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH CEDILLA
+    ('\u{013D}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH CARON
+    ('\u{013E}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH CARON
+    ('\u{013F}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH MIDDLE DOT
+    ('\u{0140}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH MIDDLE DOT
+    ('\u{0141}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH STROKE
+    ('\u{0142}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH STROKE
+    ('\u{0143}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH ACUTE
+    ('\u{0144}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH ACUTE
+    ('\u{0145}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH CEDILLA
+    ('\u{0146}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH CEDILLA
+    ('\u{0147}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH CARON
+    ('\u{0148}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH CARON
+    ('\u{0149}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N PRECEDED BY APOSTROPHE
+    ('\u{014A}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER ENG
+    ('\u{014B}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER ENG
+    ('\u{014C}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH MACRON
+    ('\u{014D}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH MACRON
+    ('\u{014E}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH BREVE
+    ('\u{014F}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH BREVE
+    ('\u{0150}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DOUBLE ACUTE
+    ('\u{0151}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DOUBLE ACUTE
+    ('\u{0152}', CharMapAction::SubStr("OE")), // LATIN CAPITAL LIGATURE OE
+    ('\u{0153}', CharMapAction::SubStr("oe")), // LATIN SMALL LIGATURE OE
+    ('\u{0154}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH ACUTE
+    ('\u{0155}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH ACUTE
+    ('\u{0156}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH CEDILLA
+    ('\u{0157}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH CEDILLA
+    ('\u{0158}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH CARON
+    ('\u{0159}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH CARON
+    ('\u{015A}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH ACUTE
+    ('\u{015B}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH ACUTE
+    ('\u{015C}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH CIRCUMFLEX
+    ('\u{015D}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH CIRCUMFLEX
+    ('\u{015E}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH CEDILLA
+    ('\u{015F}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH CEDILLA
+    ('\u{0160}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH CARON
+    ('\u{0161}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH CARON
+    ('\u{0162}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH CEDILLA
+    ('\u{0163}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH CEDILLA
+    ('\u{0164}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH CARON
+    ('\u{0165}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH CARON
+    ('\u{0166}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH STROKE
+    ('\u{0167}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH STROKE
+    ('\u{0168}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH TILDE
+    ('\u{0169}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH TILDE
+    ('\u{016A}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH MACRON
+    ('\u{016B}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH MACRON
+    ('\u{016C}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH BREVE
+    ('\u{016D}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH BREVE
+    ('\u{016E}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH RING ABOVE
+    ('\u{016F}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH RING ABOVE
+    ('\u{0170}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DOUBLE ACUTE
+    ('\u{0171}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DOUBLE ACUTE
+    ('\u{0172}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH OGONEK
+    ('\u{0173}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH OGONEK
+    ('\u{0174}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH CIRCUMFLEX
+    ('\u{0175}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH CIRCUMFLEX
+    ('\u{0176}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH CIRCUMFLEX
+    ('\u{0177}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH CIRCUMFLEX
+    ('\u{0178}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH DIAERESIS
+    ('\u{0179}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH ACUTE
+    ('\u{017A}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH ACUTE
+    ('\u{017B}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH DOT ABOVE
+    ('\u{017C}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH DOT ABOVE
+    ('\u{017D}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH CARON
+    ('\u{017E}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH CARON
+    ('\u{017F}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER LONG S
+    ('\u{0180}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH STROKE
+    ('\u{0181}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER B WITH HOOK
+    ('\u{0182}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER B WITH TOPBAR
+    ('\u{0183}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH TOPBAR
+    ('\u{0186}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER OPEN O
+    ('\u{0187}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH HOOK
+    ('\u{0188}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH HOOK
+    ('\u{0189}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER AFRICAN D
+    ('\u{018A}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH HOOK
+    ('\u{018B}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH TOPBAR
+    ('\u{018C}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH TOPBAR
+    ('\u{018E}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER REVERSED E
+    ('\u{018F}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER SCHWA
+    ('\u{0190}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER OPEN E
+    ('\u{0191}', CharMapAction::SubChar('F')), // LATIN CAPITAL LETTER F WITH HOOK
+    ('\u{0192}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER F WITH HOOK
+    ('\u{0193}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH HOOK
+    ('\u{0195}', CharMapAction::SubStr("hv")), // LATIN SMALL LETTER HV
+    ('\u{0196}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER IOTA
+    ('\u{0197}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH STROKE
+    ('\u{0198}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH HOOK
+    ('\u{0199}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH HOOK
+    ('\u{019A}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH BAR
+    ('\u{019C}', CharMapAction::SubChar('M')), // LATIN CAPITAL LETTER TURNED M
+    ('\u{019D}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH LEFT HOOK
+    ('\u{019E}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH LONG RIGHT LEG
+    ('\u{019F}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH MIDDLE TILDE
+    ('\u{01A0}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HORN
+    ('\u{01A1}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HORN
+    ('\u{01A4}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH HOOK
+    ('\u{01A5}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH HOOK
+    ('\u{01AB}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH PALATAL HOOK
+    ('\u{01AC}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH HOOK
+    ('\u{01AD}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH HOOK
+    ('\u{01AE}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH RETROFLEX HOOK
+    ('\u{01AF}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HORN
+    ('\u{01B0}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HORN
+    ('\u{01B2}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER V WITH HOOK
+    ('\u{01B3}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH HOOK
+    ('\u{01B4}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH HOOK
+    ('\u{01B5}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH STROKE
+    ('\u{01B6}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH STROKE
+    ('\u{01BF}', CharMapAction::SubChar('w')), // LATIN LETTER WYNN
+    ('\u{01C4}', CharMapAction::SubStr("DZ")), // LATIN CAPITAL LETTER DZ WITH CARON
+    ('\u{01C5}', CharMapAction::SubStr("Dz")), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON
+    ('\u{01C6}', CharMapAction::SubStr("dz")), // LATIN SMALL LETTER DZ WITH CARON
+    ('\u{01C7}', CharMapAction::SubStr("LJ")), // LATIN CAPITAL LETTER LJ
+    ('\u{01C8}', CharMapAction::SubStr("Lj")), // LATIN CAPITAL LETTER L WITH SMALL LETTER J
+    ('\u{01C9}', CharMapAction::SubStr("lj")), // LATIN SMALL LETTER LJ
+    ('\u{01CA}', CharMapAction::SubStr("NJ")), // LATIN CAPITAL LETTER NJ
+    ('\u{01CB}', CharMapAction::SubStr("Nj")), // LATIN CAPITAL LETTER N WITH SMALL LETTER J
+    ('\u{01CC}', CharMapAction::SubStr("nj")), // LATIN SMALL LETTER NJ
+    ('\u{01CD}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CARON
+    ('\u{01CE}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CARON
+    ('\u{01CF}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH CARON
+    ('\u{01D0}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH CARON
+    ('\u{01D1}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CARON
+    ('\u{01D2}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CARON
+    ('\u{01D3}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH CARON
+    ('\u{01D4}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH CARON
+    ('\u{01D5}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON
+    ('\u{01D6}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DIAERESIS AND MACRON
+    ('\u{01D7}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE
+    ('\u{01D8}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE
+    ('\u{01D9}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON
+    ('\u{01DA}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DIAERESIS AND CARON
+    ('\u{01DB}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE
+    ('\u{01DC}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE
+    ('\u{01DD}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER TURNED E
+    ('\u{01DE}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON
+    ('\u{01DF}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH DIAERESIS AND MACRON
+    ('\u{01E0}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON
+    ('\u{01E1}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON
+    ('\u{01E2}', CharMapAction::SubStr("AE")), // LATIN CAPITAL LETTER AE WITH MACRON
+    ('\u{01E3}', CharMapAction::SubStr("ae")), // LATIN SMALL LETTER AE WITH MACRON
+    ('\u{01E4}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH STROKE
+    ('\u{01E5}', CharMapAction::SubChar('G')), // LATIN SMALL LETTER G WITH STROKE
+    ('\u{01E6}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH CARON
+    ('\u{01E7}', CharMapAction::SubChar('G')), // LATIN SMALL LETTER G WITH CARON
+    ('\u{01E8}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH CARON
+    ('\u{01E9}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH CARON
+    ('\u{01EA}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH OGONEK
+    ('\u{01EB}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH OGONEK
+    ('\u{01EC}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH OGONEK AND MACRON
+    ('\u{01ED}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH OGONEK AND MACRON
+    ('\u{01F0}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER J WITH CARON
+    ('\u{01F1}', CharMapAction::SubStr("DZ")), // LATIN CAPITAL LETTER DZ
+    ('\u{01F2}', CharMapAction::SubStr("Dz")), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z
+    ('\u{01F3}', CharMapAction::SubStr("dz")), // LATIN SMALL LETTER DZ
+    ('\u{01F4}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH ACUTE
+    ('\u{01F5}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH ACUTE
+    ('\u{01F6}', CharMapAction::SubStr("HV")), // LATIN CAPITAL LETTER HWAIR
+    ('\u{01F7}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER WYNN
+    ('\u{01F8}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH GRAVE
+    ('\u{01F9}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH GRAVE
+    ('\u{01FA}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE
+    ('\u{01FB}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE
+    ('\u{01FC}', CharMapAction::SubStr("AE")), // LATIN CAPITAL LETTER AE WITH ACUTE
+    ('\u{01FD}', CharMapAction::SubStr("ae")), // LATIN SMALL LETTER AE WITH ACUTE
+    ('\u{01FE}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH STROKE AND ACUTE
+    ('\u{01FF}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH STROKE AND ACUTE
+    ('\u{0200}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH DOUBLE GRAVE
+    ('\u{0201}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH DOUBLE GRAVE
+    ('\u{0202}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH INVERTED BREVE
+    ('\u{0203}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH INVERTED BREVE
+    ('\u{0204}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH DOUBLE GRAVE
+    ('\u{0205}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH DOUBLE GRAVE
+    ('\u{0206}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH INVERTED BREVE
+    ('\u{0207}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH INVERTED BREVE
+    ('\u{0208}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH DOUBLE GRAVE
+    ('\u{0209}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH DOUBLE GRAVE
+    ('\u{020A}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH INVERTED BREVE
+    ('\u{020B}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH INVERTED BREVE
+    ('\u{020C}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DOUBLE GRAVE
+    ('\u{020D}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DOUBLE GRAVE
+    ('\u{020E}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH INVERTED BREVE
+    ('\u{020F}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH INVERTED BREVE
+    ('\u{0210}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH DOUBLE GRAVE
+    ('\u{0211}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH DOUBLE GRAVE
+    ('\u{0212}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH INVERTED BREVE
+    ('\u{0213}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH INVERTED BREVE
+    ('\u{0214}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DOUBLE GRAVE
+    ('\u{0215}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DOUBLE GRAVE
+    ('\u{0216}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH INVERTED BREVE
+    ('\u{0217}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH INVERTED BREVE
+    ('\u{0218}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH COMMA BELOW
+    ('\u{0219}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH COMMA BELOW
+    ('\u{021A}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH COMMA BELOW
+    ('\u{021B}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH COMMA BELOW
+    ('\u{021C}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER YOGH
+    ('\u{021D}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER YOGH
+    ('\u{021E}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH CARON
+    ('\u{021F}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH CARON
+    ('\u{0220}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH LONG RIGHT LEG
+    ('\u{0221}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH CURL
+    ('\u{0222}', CharMapAction::SubStr("OU")), // LATIN CAPITAL LETTER OU
+    ('\u{0223}', CharMapAction::SubStr("ou")), // LATIN SMALL LETTER OU
+    ('\u{0224}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH HOOK
+    ('\u{0225}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH HOOK
+    ('\u{0226}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH DOT ABOVE
+    ('\u{0227}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH DOT ABOVE
+    ('\u{0228}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CEDILLA
+    ('\u{0229}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CEDILLA
+    ('\u{022A}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON
+    ('\u{022B}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DIAERESIS AND MACRON
+    ('\u{022C}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH TILDE AND MACRON
+    ('\u{022D}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH TILDE AND MACRON
+    ('\u{022E}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DOT ABOVE
+    ('\u{022F}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DOT ABOVE
+    ('\u{0230}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON
+    ('\u{0231}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON
+    ('\u{0232}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH MACRON
+    ('\u{0233}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH MACRON
+    ('\u{0234}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH CURL
+    ('\u{0235}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH CURL
+    ('\u{0236}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH CURL
+    ('\u{0237}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER DOTLESS J
+    ('\u{0238}', CharMapAction::SubStr("db")), // LATIN SMALL LETTER DB DIGRAPH
+    ('\u{0239}', CharMapAction::SubStr("qp")), // LATIN SMALL LETTER QP DIGRAPH
+    ('\u{023A}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH STROKE
+    ('\u{023B}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH STROKE
+    ('\u{023C}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH STROKE
+    ('\u{023D}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH BAR
+    ('\u{023E}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH DIAGONAL STROKE
+    ('\u{023F}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH SWASH TAIL
+    ('\u{0240}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH SWASH TAIL
+    ('\u{0243}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER B WITH STROKE
+    ('\u{0244}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U BAR
+    ('\u{0245}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER TURNED V
+    ('\u{0246}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH STROKE
+    ('\u{0247}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH STROKE
+    ('\u{0248}', CharMapAction::SubChar('J')), // LATIN CAPITAL LETTER J WITH STROKE
+    ('\u{0249}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER J WITH STROKE
+    ('\u{024A}', CharMapAction::SubChar('Q')), // LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL
+    ('\u{024B}', CharMapAction::SubChar('q')), // LATIN SMALL LETTER Q WITH HOOK TAIL
+    ('\u{024C}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH STROKE
+    ('\u{024D}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH STROKE
+    ('\u{024E}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH STROKE
+    ('\u{024F}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH STROKE
+    ('\u{0250}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER TURNED A
+    ('\u{0253}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH HOOK
+    ('\u{0254}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER OPEN O
+    ('\u{0255}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH CURL
+    ('\u{0256}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH TAIL
+    ('\u{0257}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH HOOK
+    ('\u{0258}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER REVERSED E
+    ('\u{0259}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER SCHWA
+    ('\u{025A}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER SCHWA WITH HOOK
+    ('\u{025B}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER OPEN E
+    ('\u{025C}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER REVERSED OPEN E
+    ('\u{025D}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER REVERSED OPEN E WITH HOOK
+    ('\u{025E}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER CLOSED REVERSED OPEN E
+    ('\u{025F}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER DOTLESS J WITH STROKE
+    ('\u{0260}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH HOOK
+    ('\u{0261}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER SCRIPT G
+    ('\u{0262}', CharMapAction::SubChar('G')), // LATIN LETTER SMALL CAPITAL G
+    ('\u{0265}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER TURNED H
+    ('\u{0266}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH HOOK
+    ('\u{0268}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH STROKE
+    ('\u{026A}', CharMapAction::SubChar('I')), // LATIN LETTER SMALL CAPITAL I
+    ('\u{026B}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH MIDDLE TILDE
+    ('\u{026C}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH BELT
+    ('\u{026D}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH RETROFLEX HOOK
+    ('\u{026F}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER TURNED M
+    ('\u{0270}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER TURNED M WITH LONG LEG
+    ('\u{0271}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER M WITH HOOK
+    ('\u{0272}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH LEFT HOOK
+    ('\u{0273}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH RETROFLEX HOOK
+    ('\u{0274}', CharMapAction::SubChar('N')), // LATIN LETTER SMALL CAPITAL N
+    ('\u{0275}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER BARRED O
+    ('\u{0276}', CharMapAction::SubStr("OE")), // LATIN LETTER SMALL CAPITAL OE
+    ('\u{027C}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH LONG LEG
+    ('\u{027D}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH TAIL
+    ('\u{027E}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH FISHHOOK
+    ('\u{027F}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER REVERSED R WITH FISHHOOK
+    ('\u{0280}', CharMapAction::SubChar('R')), // LATIN LETTER SMALL CAPITAL R
+    ('\u{0281}', CharMapAction::SubChar('R')), // LATIN LETTER SMALL CAPITAL INVERTED R
+    ('\u{0282}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH HOOK
+    ('\u{0284}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER DOTLESS J WITH STROKE AND HOOK
+    ('\u{0287}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER TURNED T
+    ('\u{0288}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH RETROFLEX HOOK
+    ('\u{0289}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U BAR
+    ('\u{028B}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH HOOK
+    ('\u{028C}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER TURNED V
+    ('\u{028D}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER TURNED W
+    ('\u{028E}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER TURNED Y
+    ('\u{028F}', CharMapAction::SubChar('Y')), // LATIN LETTER SMALL CAPITAL Y
+    ('\u{0290}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH RETROFLEX HOOK
+    ('\u{0291}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH CURL
+    ('\u{0297}', CharMapAction::SubChar('C')), // LATIN LETTER STRETCHED C
+    ('\u{0299}', CharMapAction::SubChar('B')), // LATIN LETTER SMALL CAPITAL B
+    ('\u{029A}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER CLOSED OPEN E
+    ('\u{029B}', CharMapAction::SubChar('G')), // LATIN LETTER SMALL CAPITAL G WITH HOOK
+    ('\u{029C}', CharMapAction::SubChar('H')), // LATIN LETTER SMALL CAPITAL H
+    ('\u{029D}', CharMapAction::SubChar('j')), // LATIN SMALL LETTER J WITH CROSSED-TAIL
+    ('\u{029E}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER TURNED K
+    ('\u{029F}', CharMapAction::SubChar('L')), // LATIN LETTER SMALL CAPITAL L
+    ('\u{02A0}', CharMapAction::SubChar('q')), // LATIN SMALL LETTER Q WITH HOOK
+    ('\u{02A3}', CharMapAction::SubStr("dz")), // LATIN SMALL LETTER DZ DIGRAPH
+    ('\u{02A5}', CharMapAction::SubStr("dz")), // LATIN SMALL LETTER DZ DIGRAPH WITH CURL
+    ('\u{02A6}', CharMapAction::SubStr("ts")), // LATIN SMALL LETTER TS DIGRAPH
+    ('\u{02A8}', CharMapAction::SubStr("tc")), // LATIN SMALL LETTER TC DIGRAPH WITH CURL
+    ('\u{02AA}', CharMapAction::SubStr("ls")), // LATIN SMALL LETTER LS DIGRAPH
+    ('\u{02AB}', CharMapAction::SubStr("lz")), // LATIN SMALL LETTER LZ DIGRAPH
+    ('\u{02AE}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER TURNED H WITH FISHHOOK
+    ('\u{02AF}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER TURNED H WITH FISHHOOK AND TAIL
+    ('\u{1D00}', CharMapAction::SubChar('A')), // LATIN LETTER SMALL CAPITAL A
+    ('\u{1D01}', CharMapAction::SubStr("AE")), // LATIN LETTER SMALL CAPITAL AE
+    ('\u{1D02}', CharMapAction::SubStr("ae")), // LATIN SMALL LETTER TURNED AE
+    ('\u{1D03}', CharMapAction::SubChar('B')), // LATIN LETTER SMALL CAPITAL BARRED B
+    ('\u{1D04}', CharMapAction::SubChar('C')), // LATIN LETTER SMALL CAPITAL C
+    ('\u{1D05}', CharMapAction::SubChar('D')), // LATIN LETTER SMALL CAPITAL D
+    ('\u{1D06}', CharMapAction::SubChar('D')), // LATIN LETTER SMALL CAPITAL ETH
+    ('\u{1D07}', CharMapAction::SubChar('E')), // LATIN LETTER SMALL CAPITAL E
+    ('\u{1D08}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER TURNED OPEN E
+    ('\u{1D09}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER TURNED I
+    ('\u{1D0A}', CharMapAction::SubChar('J')), // LATIN LETTER SMALL CAPITAL J
+    ('\u{1D0B}', CharMapAction::SubChar('K')), // LATIN LETTER SMALL CAPITAL K
+    ('\u{1D0C}', CharMapAction::SubChar('L')), // LATIN LETTER SMALL CAPITAL L WITH STROKE
+    ('\u{1D0D}', CharMapAction::SubChar('M')), // LATIN LETTER SMALL CAPITAL M
+    ('\u{1D0E}', CharMapAction::SubChar('N')), // LATIN LETTER SMALL CAPITAL REVERSED N
+    ('\u{1D0F}', CharMapAction::SubChar('O')), // LATIN LETTER SMALL CAPITAL O
+    ('\u{1D10}', CharMapAction::SubChar('O')), // LATIN LETTER SMALL CAPITAL OPEN O
+    ('\u{1D14}', CharMapAction::SubStr("oe")), // LATIN SMALL LETTER TURNED OE
+    ('\u{1D15}', CharMapAction::SubStr("OU")), // LATIN LETTER SMALL CAPITAL OU
+    ('\u{1D16}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER TOP HALF O
+    ('\u{1D17}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER BOTTOM HALF O
+    ('\u{1D18}', CharMapAction::SubChar('P')), // LATIN LETTER SMALL CAPITAL P
+    ('\u{1D19}', CharMapAction::SubChar('R')), // LATIN LETTER SMALL CAPITAL REVERSED R
+    ('\u{1D1A}', CharMapAction::SubChar('R')), // LATIN LETTER SMALL CAPITAL TURNED R
+    ('\u{1D1B}', CharMapAction::SubChar('T')), // LATIN LETTER SMALL CAPITAL T
+    ('\u{1D1C}', CharMapAction::SubChar('U')), // LATIN LETTER SMALL CAPITAL U
+    ('\u{1D20}', CharMapAction::SubChar('V')), // LATIN LETTER SMALL CAPITAL V
+    ('\u{1D21}', CharMapAction::SubChar('W')), // LATIN LETTER SMALL CAPITAL W
+    ('\u{1D22}', CharMapAction::SubChar('Z')), // LATIN LETTER SMALL CAPITAL Z
+    ('\u{1D62}', CharMapAction::SubChar('i')), // LATIN SUBSCRIPT SMALL LETTER I
+    ('\u{1D63}', CharMapAction::SubChar('r')), // LATIN SUBSCRIPT SMALL LETTER R
+    ('\u{1D64}', CharMapAction::SubChar('u')), // LATIN SUBSCRIPT SMALL LETTER U
+    ('\u{1D65}', CharMapAction::SubChar('v')), // LATIN SUBSCRIPT SMALL LETTER V
+    ('\u{1D6B}', CharMapAction::SubStr("ue")), // LATIN SMALL LETTER UE
+    ('\u{1D6C}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH MIDDLE TILDE
+    ('\u{1D6D}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH MIDDLE TILDE
+    ('\u{1D6E}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER F WITH MIDDLE TILDE
+    ('\u{1D6F}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER M WITH MIDDLE TILDE
+    ('\u{1D70}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH MIDDLE TILDE
+    ('\u{1D71}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH MIDDLE TILDE
+    ('\u{1D72}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH MIDDLE TILDE
+    ('\u{1D73}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH FISHHOOK AND MIDDLE TILDE
+    ('\u{1D74}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH MIDDLE TILDE
+    ('\u{1D75}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH MIDDLE TILDE
+    ('\u{1D76}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH MIDDLE TILDE
+    ('\u{1D77}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER TURNED G
+    ('\u{1D79}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER INSULAR G
+    ('\u{1D7A}', CharMapAction::SubStr("th")), // LATIN SMALL LETTER TH WITH STRIKETHROUGH
+    ('\u{1D7B}', CharMapAction::SubChar('I')), // LATIN SMALL CAPITAL LETTER I WITH STROKE
+    ('\u{1D7C}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER IOTA WITH STROKE
+    ('\u{1D7D}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH STROKE
+    ('\u{1D7E}', CharMapAction::SubChar('U')), // LATIN SMALL CAPITAL LETTER U WITH STROKE
+    ('\u{1D80}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH PALATAL HOOK
+    ('\u{1D81}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH PALATAL HOOK
+    ('\u{1D82}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER F WITH PALATAL HOOK
+    ('\u{1D83}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH PALATAL HOOK
+    ('\u{1D84}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH PALATAL HOOK
+    ('\u{1D85}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH PALATAL HOOK
+    ('\u{1D86}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER M WITH PALATAL HOOK
+    ('\u{1D87}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH PALATAL HOOK
+    ('\u{1D88}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH PALATAL HOOK
+    ('\u{1D89}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH PALATAL HOOK
+    ('\u{1D8A}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH PALATAL HOOK
+    ('\u{1D8C}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH PALATAL HOOK
+    ('\u{1D8D}', CharMapAction::SubChar('x')), // LATIN SMALL LETTER X WITH PALATAL HOOK
+    ('\u{1D8E}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH PALATAL HOOK
+    ('\u{1D8F}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH RETROFLEX HOOK
+    ('\u{1D91}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH HOOK AND TAIL
+    ('\u{1D92}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH RETROFLEX HOOK
+    ('\u{1D93}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER OPEN E WITH RETROFLEX HOOK
+    ('\u{1D94}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER REVERSED OPEN E WITH RETROFLEX HOOK
+    ('\u{1D95}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER SCHWA WITH RETROFLEX HOOK
+    ('\u{1D96}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH RETROFLEX HOOK
+    ('\u{1D97}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER OPEN O WITH RETROFLEX HOOK
+    ('\u{1D99}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH RETROFLEX HOOK
+    ('\u{1E00}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH RING BELOW
+    ('\u{1E01}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH RING BELOW
+    ('\u{1E02}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER B WITH DOT ABOVE
+    ('\u{1E03}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH DOT ABOVE
+    ('\u{1E04}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER B WITH DOT BELOW
+    ('\u{1E05}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH DOT BELOW
+    ('\u{1E06}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER B WITH LINE BELOW
+    ('\u{1E07}', CharMapAction::SubChar('b')), // LATIN SMALL LETTER B WITH LINE BELOW
+    ('\u{1E08}', CharMapAction::SubChar('C')), // LATIN CAPITAL LETTER C WITH CEDILLA AND ACUTE
+    ('\u{1E09}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER C WITH CEDILLA AND ACUTE
+    ('\u{1E0A}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH DOT ABOVE
+    ('\u{1E0B}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH DOT ABOVE
+    ('\u{1E0C}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH DOT BELOW
+    ('\u{1E0D}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH DOT BELOW
+    ('\u{1E0E}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH LINE BELOW
+    ('\u{1E0F}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH LINE BELOW
+    ('\u{1E10}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH CEDILLA
+    ('\u{1E11}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH CEDILLA
+    ('\u{1E12}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER D WITH CIRCUMFLEX BELOW
+    ('\u{1E13}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER D WITH CIRCUMFLEX BELOW
+    ('\u{1E14}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH MACRON AND GRAVE
+    ('\u{1E15}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH MACRON AND GRAVE
+    ('\u{1E16}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH MACRON AND ACUTE
+    ('\u{1E17}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH MACRON AND ACUTE
+    ('\u{1E18}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX BELOW
+    ('\u{1E19}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX BELOW
+    ('\u{1E1A}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH TILDE BELOW
+    ('\u{1E1B}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH TILDE BELOW
+    ('\u{1E1C}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CEDILLA AND BREVE
+    ('\u{1E1D}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CEDILLA AND BREVE
+    ('\u{1E1E}', CharMapAction::SubChar('F')), // LATIN CAPITAL LETTER F WITH DOT ABOVE
+    ('\u{1E1F}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER F WITH DOT ABOVE
+    ('\u{1E20}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER G WITH MACRON
+    ('\u{1E21}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH MACRON
+    ('\u{1E22}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH DOT ABOVE
+    ('\u{1E23}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH DOT ABOVE
+    ('\u{1E24}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH DOT BELOW
+    ('\u{1E25}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH DOT BELOW
+    ('\u{1E26}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH DIAERESIS
+    ('\u{1E27}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH DIAERESIS
+    ('\u{1E28}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH CEDILLA
+    ('\u{1E29}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH CEDILLA
+    ('\u{1E2A}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH BREVE BELOW
+    ('\u{1E2B}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH BREVE BELOW
+    ('\u{1E2C}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH TILDE BELOW
+    ('\u{1E2D}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH TILDE BELOW
+    ('\u{1E2E}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH DIAERESIS AND ACUTE
+    ('\u{1E2F}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH DIAERESIS AND ACUTE
+    ('\u{1E30}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH ACUTE
+    ('\u{1E31}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH ACUTE
+    ('\u{1E32}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH DOT BELOW
+    ('\u{1E33}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH DOT BELOW
+    ('\u{1E34}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH LINE BELOW
+    ('\u{1E35}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH LINE BELOW
+    ('\u{1E36}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH DOT BELOW
+    ('\u{1E37}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH DOT BELOW
+    ('\u{1E38}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH DOT BELOW AND MACRON
+    ('\u{1E39}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH DOT BELOW AND MACRON
+    ('\u{1E3A}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH LINE BELOW
+    ('\u{1E3B}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH LINE BELOW
+    ('\u{1E3C}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH CIRCUMFLEX BELOW
+    ('\u{1E3D}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH CIRCUMFLEX BELOW
+    ('\u{1E3E}', CharMapAction::SubChar('M')), // LATIN CAPITAL LETTER M WITH ACUTE
+    ('\u{1E3F}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER M WITH ACUTE
+    ('\u{1E40}', CharMapAction::SubChar('M')), // LATIN CAPITAL LETTER M WITH DOT ABOVE
+    ('\u{1E41}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER M WITH DOT ABOVE
+    ('\u{1E42}', CharMapAction::SubChar('M')), // LATIN CAPITAL LETTER M WITH DOT BELOW
+    ('\u{1E43}', CharMapAction::SubChar('m')), // LATIN SMALL LETTER M WITH DOT BELOW
+    ('\u{1E44}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH DOT ABOVE
+    ('\u{1E45}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH DOT ABOVE
+    ('\u{1E46}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH DOT BELOW
+    ('\u{1E47}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH DOT BELOW
+    ('\u{1E48}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH LINE BELOW
+    ('\u{1E49}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH LINE BELOW
+    ('\u{1E4A}', CharMapAction::SubChar('N')), // LATIN CAPITAL LETTER N WITH CIRCUMFLEX BELOW
+    ('\u{1E4B}', CharMapAction::SubChar('n')), // LATIN SMALL LETTER N WITH CIRCUMFLEX BELOW
+    ('\u{1E4C}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH TILDE AND ACUTE
+    ('\u{1E4D}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH TILDE AND ACUTE
+    ('\u{1E4E}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH TILDE AND DIAERESIS
+    ('\u{1E4F}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH TILDE AND DIAERESIS
+    ('\u{1E50}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH MACRON AND GRAVE
+    ('\u{1E51}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH MACRON AND GRAVE
+    ('\u{1E52}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH MACRON AND ACUTE
+    ('\u{1E53}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH MACRON AND ACUTE
+    ('\u{1E54}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH ACUTE
+    ('\u{1E55}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH ACUTE
+    ('\u{1E56}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH DOT ABOVE
+    ('\u{1E57}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH DOT ABOVE
+    ('\u{1E58}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH DOT ABOVE
+    ('\u{1E59}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH DOT ABOVE
+    ('\u{1E5A}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH DOT BELOW
+    ('\u{1E5B}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH DOT BELOW
+    ('\u{1E5C}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH DOT BELOW AND MACRON
+    ('\u{1E5D}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH DOT BELOW AND MACRON
+    ('\u{1E5E}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH LINE BELOW
+    ('\u{1E5F}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITH LINE BELOW
+    ('\u{1E60}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH DOT ABOVE
+    ('\u{1E61}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH DOT ABOVE
+    ('\u{1E62}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH DOT BELOW
+    ('\u{1E63}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH DOT BELOW
+    ('\u{1E64}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH ACUTE AND DOT ABOVE
+    ('\u{1E65}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH ACUTE AND DOT ABOVE
+    ('\u{1E66}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH CARON AND DOT ABOVE
+    ('\u{1E67}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH CARON AND DOT ABOVE
+    ('\u{1E68}', CharMapAction::SubChar('S')), // LATIN CAPITAL LETTER S WITH DOT BELOW AND DOT ABOVE
+    ('\u{1E69}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE
+    ('\u{1E6A}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH DOT ABOVE
+    ('\u{1E6B}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH DOT ABOVE
+    ('\u{1E6C}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH DOT BELOW
+    ('\u{1E6D}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH DOT BELOW
+    ('\u{1E6E}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH LINE BELOW
+    ('\u{1E6F}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH LINE BELOW
+    ('\u{1E70}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER T WITH CIRCUMFLEX BELOW
+    ('\u{1E71}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH CIRCUMFLEX BELOW
+    ('\u{1E72}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DIAERESIS BELOW
+    ('\u{1E73}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DIAERESIS BELOW
+    ('\u{1E74}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH TILDE BELOW
+    ('\u{1E75}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH TILDE BELOW
+    ('\u{1E76}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH CIRCUMFLEX BELOW
+    ('\u{1E77}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH CIRCUMFLEX BELOW
+    ('\u{1E78}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH TILDE AND ACUTE
+    ('\u{1E79}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH TILDE AND ACUTE
+    ('\u{1E7A}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH MACRON AND DIAERESIS
+    ('\u{1E7B}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH MACRON AND DIAERESIS
+    ('\u{1E7C}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER V WITH TILDE
+    ('\u{1E7D}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH TILDE
+    ('\u{1E7E}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER V WITH DOT BELOW
+    ('\u{1E7F}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH DOT BELOW
+    ('\u{1E80}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH GRAVE
+    ('\u{1E81}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH GRAVE
+    ('\u{1E82}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH ACUTE
+    ('\u{1E83}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH ACUTE
+    ('\u{1E84}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH DIAERESIS
+    ('\u{1E85}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH DIAERESIS
+    ('\u{1E86}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH DOT ABOVE
+    ('\u{1E87}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH DOT ABOVE
+    ('\u{1E88}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH DOT BELOW
+    ('\u{1E89}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH DOT BELOW
+    ('\u{1E8A}', CharMapAction::SubChar('X')), // LATIN CAPITAL LETTER X WITH DOT ABOVE
+    ('\u{1E8B}', CharMapAction::SubChar('x')), // LATIN SMALL LETTER X WITH DOT ABOVE
+    ('\u{1E8C}', CharMapAction::SubChar('X')), // LATIN CAPITAL LETTER X WITH DIAERESIS
+    ('\u{1E8D}', CharMapAction::SubChar('x')), // LATIN SMALL LETTER X WITH DIAERESIS
+    ('\u{1E8E}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH DOT ABOVE
+    ('\u{1E8F}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH DOT ABOVE
+    ('\u{1E90}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH CIRCUMFLEX
+    ('\u{1E91}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH CIRCUMFLEX
+    ('\u{1E92}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH DOT BELOW
+    ('\u{1E93}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH DOT BELOW
+    ('\u{1E94}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH LINE BELOW
+    ('\u{1E95}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH LINE BELOW
+    ('\u{1E96}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH LINE BELOW
+    ('\u{1E97}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH DIAERESIS
+    ('\u{1E98}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH RING ABOVE
+    ('\u{1E99}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH RING ABOVE
+    ('\u{1E9A}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH RIGHT HALF RING
+    ('\u{1E9B}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER LONG S WITH DOT ABOVE
+    ('\u{1E9C}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER LONG S WITH DIAGONAL STROKE
+    ('\u{1E9D}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER LONG S WITH HIGH STROKE
+    ('\u{1E9E}', CharMapAction::SubStr("SS")), // LATIN CAPITAL LETTER SHARP S
+    ('\u{1EA0}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH DOT BELOW
+    ('\u{1EA1}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH DOT BELOW
+    ('\u{1EA2}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH HOOK ABOVE
+    ('\u{1EA3}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH HOOK ABOVE
+    ('\u{1EA4}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EA5}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EA6}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EA7}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EA8}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EA9}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EAA}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND TILDE
+    ('\u{1EAB}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND TILDE
+    ('\u{1EAC}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EAD}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EAE}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH BREVE AND ACUTE
+    ('\u{1EAF}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH BREVE AND ACUTE
+    ('\u{1EB0}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH BREVE AND GRAVE
+    ('\u{1EB1}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH BREVE AND GRAVE
+    ('\u{1EB2}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH BREVE AND HOOK ABOVE
+    ('\u{1EB3}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH BREVE AND HOOK ABOVE
+    ('\u{1EB4}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH BREVE AND TILDE
+    ('\u{1EB5}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH BREVE AND TILDE
+    ('\u{1EB6}', CharMapAction::SubChar('A')), // LATIN CAPITAL LETTER A WITH BREVE AND DOT BELOW
+    ('\u{1EB7}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH BREVE AND DOT BELOW
+    ('\u{1EB8}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH DOT BELOW
+    ('\u{1EB9}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH DOT BELOW
+    ('\u{1EBA}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH HOOK ABOVE
+    ('\u{1EBB}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH HOOK ABOVE
+    ('\u{1EBC}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH TILDE
+    ('\u{1EBD}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH TILDE
+    ('\u{1EBE}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EBF}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EC0}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EC1}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EC2}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EC3}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EC4}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND TILDE
+    ('\u{1EC5}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND TILDE
+    ('\u{1EC6}', CharMapAction::SubChar('E')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EC7}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EC8}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH HOOK ABOVE
+    ('\u{1EC9}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH HOOK ABOVE
+    ('\u{1ECA}', CharMapAction::SubChar('I')), // LATIN CAPITAL LETTER I WITH DOT BELOW
+    ('\u{1ECB}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER I WITH DOT BELOW
+    ('\u{1ECC}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH DOT BELOW
+    ('\u{1ECD}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH DOT BELOW
+    ('\u{1ECE}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HOOK ABOVE
+    ('\u{1ECF}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HOOK ABOVE
+    ('\u{1ED0}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND ACUTE
+    ('\u{1ED1}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND ACUTE
+    ('\u{1ED2}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND GRAVE
+    ('\u{1ED3}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND GRAVE
+    ('\u{1ED4}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1ED5}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1ED6}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND TILDE
+    ('\u{1ED7}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND TILDE
+    ('\u{1ED8}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1ED9}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EDA}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HORN AND ACUTE
+    ('\u{1EDB}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HORN AND ACUTE
+    ('\u{1EDC}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HORN AND GRAVE
+    ('\u{1EDD}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HORN AND GRAVE
+    ('\u{1EDE}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HORN AND HOOK ABOVE
+    ('\u{1EDF}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HORN AND HOOK ABOVE
+    ('\u{1EE0}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HORN AND TILDE
+    ('\u{1EE1}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HORN AND TILDE
+    ('\u{1EE2}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH HORN AND DOT BELOW
+    ('\u{1EE3}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH HORN AND DOT BELOW
+    ('\u{1EE4}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH DOT BELOW
+    ('\u{1EE5}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH DOT BELOW
+    ('\u{1EE6}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HOOK ABOVE
+    ('\u{1EE7}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HOOK ABOVE
+    ('\u{1EE8}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HORN AND ACUTE
+    ('\u{1EE9}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HORN AND ACUTE
+    ('\u{1EEA}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HORN AND GRAVE
+    ('\u{1EEB}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HORN AND GRAVE
+    ('\u{1EEC}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HORN AND HOOK ABOVE
+    ('\u{1EED}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HORN AND HOOK ABOVE
+    ('\u{1EEE}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HORN AND TILDE
+    ('\u{1EEF}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HORN AND TILDE
+    ('\u{1EF0}', CharMapAction::SubChar('U')), // LATIN CAPITAL LETTER U WITH HORN AND DOT BELOW
+    ('\u{1EF1}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH HORN AND DOT BELOW
+    ('\u{1EF2}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH GRAVE
+    ('\u{1EF3}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH GRAVE
+    ('\u{1EF4}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH DOT BELOW
+    ('\u{1EF5}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH DOT BELOW
+    ('\u{1EF6}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH HOOK ABOVE
+    ('\u{1EF7}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH HOOK ABOVE
+    ('\u{1EF8}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH TILDE
+    ('\u{1EF9}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH TILDE
+    ('\u{1EFA}', CharMapAction::SubStr("LL")), // LATIN CAPITAL LETTER MIDDLE-WELSH LL
+    ('\u{1EFB}', CharMapAction::SubStr("ll")), // LATIN SMALL LETTER MIDDLE-WELSH LL
+    ('\u{1EFC}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER MIDDLE-WELSH V
+    ('\u{1EFE}', CharMapAction::SubChar('Y')), // LATIN CAPITAL LETTER Y WITH LOOP
+    ('\u{1EFF}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH LOOP
+    ('\u{2010}', CharMapAction::SubChar('-')), // HYPHEN
+    ('\u{2011}', CharMapAction::SubChar('-')), // NON-BREAKING HYPHEN
+    ('\u{2012}', CharMapAction::SubChar('-')), // FIGURE DASH
+    ('\u{2013}', CharMapAction::SubChar('-')), // EN DASH
+    ('\u{2014}', CharMapAction::SubChar('-')), // EM DASH
+    ('\u{2018}', CharMapAction::SubChar('\'')), // LEFT SINGLE QUOTATION MARK
+    ('\u{2019}', CharMapAction::SubChar('\'')), // RIGHT SINGLE QUOTATION MARK
+    ('\u{201A}', CharMapAction::SubChar('\'')), // SINGLE LOW-9 QUOTATION MARK
+    ('\u{201B}', CharMapAction::SubChar('\'')), // SINGLE HIGH-REVERSED-9 QUOTATION MARK
+    ('\u{201C}', CharMapAction::SubChar('"')), // LEFT DOUBLE QUOTATION MARK
+    ('\u{201D}', CharMapAction::SubChar('"')), // RIGHT DOUBLE QUOTATION MARK
+    ('\u{201E}', CharMapAction::SubChar('"')), // DOUBLE LOW-9 QUOTATION MARK
+    ('\u{2032}', CharMapAction::SubChar('\'')), // PRIME
+    ('\u{2033}', CharMapAction::SubChar('"')), // DOUBLE PRIME
+    ('\u{2035}', CharMapAction::SubChar('\'')), // REVERSED PRIME
+    ('\u{2036}', CharMapAction::SubChar('"')), // REVERSED DOUBLE PRIME
+    ('\u{2038}', CharMapAction::SubChar('^')), // CARET
+    ('\u{2039}', CharMapAction::SubChar('\'')), // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+    ('\u{203A}', CharMapAction::SubChar('\'')), // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+    ('\u{203C}', CharMapAction::SubStr("!!")), // DOUBLE EXCLAMATION MARK
+    ('\u{2044}', CharMapAction::SubChar('/')), // FRACTION SLASH
+    ('\u{2045}', CharMapAction::SubChar('[')), // LEFT SQUARE BRACKET WITH QUILL
+    ('\u{2046}', CharMapAction::SubChar(']')), // RIGHT SQUARE BRACKET WITH QUILL
+    ('\u{2047}', CharMapAction::SubStr("??")), // DOUBLE QUESTION MARK
+    ('\u{2048}', CharMapAction::SubStr("?!")), // QUESTION EXCLAMATION MARK
+    ('\u{2049}', CharMapAction::SubStr("!?")), // EXCLAMATION QUESTION MARK
+    ('\u{204E}', CharMapAction::SubChar('*')), // LOW ASTERISK
+    ('\u{204F}', CharMapAction::SubChar(';')), // REVERSED SEMICOLON
+    ('\u{2052}', CharMapAction::SubChar('%')), // COMMERCIAL MINUS SIGN
+    ('\u{2053}', CharMapAction::SubChar('~')), // SWUNG DASH
+    ('\u{2070}', CharMapAction::SubChar('0')), // SUPERSCRIPT ZERO
+    ('\u{2071}', CharMapAction::SubChar('i')), // SUPERSCRIPT LATIN SMALL LETTER I
+    ('\u{2074}', CharMapAction::SubChar('4')), // SUPERSCRIPT FOUR
+    ('\u{2075}', CharMapAction::SubChar('5')), // SUPERSCRIPT FIVE
+    ('\u{2076}', CharMapAction::SubChar('6')), // SUPERSCRIPT SIX
+    ('\u{2077}', CharMapAction::SubChar('7')), // SUPERSCRIPT SEVEN
+    ('\u{2078}', CharMapAction::SubChar('8')), // SUPERSCRIPT EIGHT
+    ('\u{2079}', CharMapAction::SubChar('9')), // SUPERSCRIPT NINE
+    ('\u{207A}', CharMapAction::SubChar('+')), // SUPERSCRIPT PLUS SIGN
+    ('\u{207B}', CharMapAction::SubChar('-')), // SUPERSCRIPT MINUS
+    ('\u{207C}', CharMapAction::SubChar('=')), // SUPERSCRIPT EQUALS SIGN
+    ('\u{207D}', CharMapAction::SubChar('(')), // SUPERSCRIPT LEFT PARENTHESIS
+    ('\u{207E}', CharMapAction::SubChar(')')), // SUPERSCRIPT RIGHT PARENTHESIS
+    ('\u{207F}', CharMapAction::SubChar('n')), // SUPERSCRIPT LATIN SMALL LETTER N
+    ('\u{2080}', CharMapAction::SubChar('0')), // SUBSCRIPT ZERO
+    ('\u{2081}', CharMapAction::SubChar('1')), // SUBSCRIPT ONE
+    ('\u{2082}', CharMapAction::SubChar('2')), // SUBSCRIPT TWO
+    ('\u{2083}', CharMapAction::SubChar('3')), // SUBSCRIPT THREE
+    ('\u{2084}', CharMapAction::SubChar('4')), // SUBSCRIPT FOUR
+    ('\u{2085}', CharMapAction::SubChar('5')), // SUBSCRIPT FIVE
+    ('\u{2086}', CharMapAction::SubChar('6')), // SUBSCRIPT SIX
+    ('\u{2087}', CharMapAction::SubChar('7')), // SUBSCRIPT SEVEN
+    ('\u{2088}', CharMapAction::SubChar('8')), // SUBSCRIPT EIGHT
+    ('\u{2089}', CharMapAction::SubChar('9')), // SUBSCRIPT NINE
+    ('\u{208A}', CharMapAction::SubChar('+')), // SUBSCRIPT PLUS SIGN
+    ('\u{208B}', CharMapAction::SubChar('-')), // SUBSCRIPT MINUS
+    ('\u{208C}', CharMapAction::SubChar('=')), // SUBSCRIPT EQUALS SIGN
+    ('\u{208D}', CharMapAction::SubChar('(')), // SUBSCRIPT LEFT PARENTHESIS
+    ('\u{208E}', CharMapAction::SubChar(')')), // SUBSCRIPT RIGHT PARENTHESIS
+    ('\u{2090}', CharMapAction::SubChar('a')), // LATIN SUBSCRIPT SMALL LETTER A
+    ('\u{2091}', CharMapAction::SubChar('e')), // LATIN SUBSCRIPT SMALL LETTER E
+    ('\u{2092}', CharMapAction::SubChar('o')), // LATIN SUBSCRIPT SMALL LETTER O
+    ('\u{2093}', CharMapAction::SubChar('x')), // LATIN SUBSCRIPT SMALL LETTER X
+    ('\u{2094}', CharMapAction::SubChar('a')), // LATIN SUBSCRIPT SMALL LETTER SCHWA
+    ('\u{2103}', CharMapAction::SubStr("oC")), // DEGREE CELSIUS
+    ('\u{2109}', CharMapAction::SubStr("oF")), // DEGREE FAHRENHEIT
+    ('\u{2184}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER REVERSED C
+    ('\u{2460}', CharMapAction::SubChar('1')), // CIRCLED DIGIT ONE
+    ('\u{2461}', CharMapAction::SubChar('2')), // CIRCLED DIGIT TWO
+    ('\u{2462}', CharMapAction::SubChar('3')), // CIRCLED DIGIT THREE
+    ('\u{2463}', CharMapAction::SubChar('4')), // CIRCLED DIGIT FOUR
+    ('\u{2464}', CharMapAction::SubChar('5')), // CIRCLED DIGIT FIVE
+    ('\u{2465}', CharMapAction::SubChar('6')), // CIRCLED DIGIT SIX
+    ('\u{2466}', CharMapAction::SubChar('7')), // CIRCLED DIGIT SEVEN
+    ('\u{2467}', CharMapAction::SubChar('8')), // CIRCLED DIGIT EIGHT
+    ('\u{2468}', CharMapAction::SubChar('9')), // CIRCLED DIGIT NINE
+    ('\u{2469}', CharMapAction::SubStr("10")), // CIRCLED NUMBER TEN
+    ('\u{246A}', CharMapAction::SubStr("11")), // CIRCLED NUMBER ELEVEN
+    ('\u{246B}', CharMapAction::SubStr("12")), // CIRCLED NUMBER TWELVE
+    ('\u{246C}', CharMapAction::SubStr("13")), // CIRCLED NUMBER THIRTEEN
+    ('\u{246D}', CharMapAction::SubStr("14")), // CIRCLED NUMBER FOURTEEN
+    ('\u{246E}', CharMapAction::SubStr("15")), // CIRCLED NUMBER FIFTEEN
+    ('\u{246F}', CharMapAction::SubStr("16")), // CIRCLED NUMBER SIXTEEN
+    ('\u{2470}', CharMapAction::SubStr("17")), // CIRCLED NUMBER SEVENTEEN
+    ('\u{2471}', CharMapAction::SubStr("18")), // CIRCLED NUMBER EIGHTEEN
+    ('\u{2472}', CharMapAction::SubStr("19")), // CIRCLED NUMBER NINETEEN
+    ('\u{2473}', CharMapAction::SubStr("20")), // CIRCLED NUMBER TWENTY
+    ('\u{2474}', CharMapAction::SubStr("(1)")), // PARENTHESIZED DIGIT ONE
+    ('\u{2475}', CharMapAction::SubStr("(2)")), // PARENTHESIZED DIGIT TWO
+    ('\u{2476}', CharMapAction::SubStr("(3)")), // PARENTHESIZED DIGIT THREE
+    ('\u{2477}', CharMapAction::SubStr("(4)")), // PARENTHESIZED DIGIT FOUR
+    ('\u{2478}', CharMapAction::SubStr("(5)")), // PARENTHESIZED DIGIT FIVE
+    ('\u{2479}', CharMapAction::SubStr("(6)")), // PARENTHESIZED DIGIT SIX
+    ('\u{247A}', CharMapAction::SubStr("(7)")), // PARENTHESIZED DIGIT SEVEN
+    ('\u{247B}', CharMapAction::SubStr("(8)")), // PARENTHESIZED DIGIT EIGHT
+    ('\u{247C}', CharMapAction::SubStr("(9)")), // PARENTHESIZED DIGIT NINE
+    ('\u{247D}', CharMapAction::SubStr("(10)")), // PARENTHESIZED NUMBER TEN
+    ('\u{247E}', CharMapAction::SubStr("(11)")), // PARENTHESIZED NUMBER ELEVEN
+    ('\u{247F}', CharMapAction::SubStr("(12)")), // PARENTHESIZED NUMBER TWELVE
+    ('\u{2480}', CharMapAction::SubStr("(13)")), // PARENTHESIZED NUMBER THIRTEEN
+    ('\u{2481}', CharMapAction::SubStr("(14)")), // PARENTHESIZED NUMBER FOURTEEN
+    ('\u{2482}', CharMapAction::SubStr("(15)")), // PARENTHESIZED NUMBER FIFTEEN
+    ('\u{2483}', CharMapAction::SubStr("(16)")), // PARENTHESIZED NUMBER SIXTEEN
+    ('\u{2484}', CharMapAction::SubStr("(17)")), // PARENTHESIZED NUMBER SEVENTEEN
+    ('\u{2485}', CharMapAction::SubStr("(18)")), // PARENTHESIZED NUMBER EIGHTEEN
+    ('\u{2486}', CharMapAction::SubStr("(19)")), // PARENTHESIZED NUMBER NINETEEN
+    ('\u{2487}', CharMapAction::SubStr("(20)")), // PARENTHESIZED NUMBER TWENTY
+    ('\u{2488}', CharMapAction::SubStr("1.")), // DIGIT ONE FULL STOP
+    ('\u{2489}', CharMapAction::SubStr("2.")), // DIGIT TWO FULL STOP
+    ('\u{248A}', CharMapAction::SubStr("3.")), // DIGIT THREE FULL STOP
+    ('\u{248B}', CharMapAction::SubStr("4.")), // DIGIT FOUR FULL STOP
+    ('\u{248C}', CharMapAction::SubStr("5.")), // DIGIT FIVE FULL STOP
+    ('\u{248D}', CharMapAction::SubStr("6.")), // DIGIT SIX FULL STOP
+    ('\u{248E}', CharMapAction::SubStr("7.")), // DIGIT SEVEN FULL STOP
+    ('\u{248F}', CharMapAction::SubStr("8.")), // DIGIT EIGHT FULL STOP
+    ('\u{2490}', CharMapAction::SubStr("9.")), // DIGIT NINE FULL STOP
+    ('\u{2491}', CharMapAction::SubStr("10.")), // NUMBER TEN FULL STOP
+    ('\u{2492}', CharMapAction::SubStr("11.")), // NUMBER ELEVEN FULL STOP
+    ('\u{2493}', CharMapAction::SubStr("12.")), // NUMBER TWELVE FULL STOP
+    ('\u{2494}', CharMapAction::SubStr("13.")), // NUMBER THIRTEEN FULL STOP
+    ('\u{2495}', CharMapAction::SubStr("14.")), // NUMBER FOURTEEN FULL STOP
+    ('\u{2496}', CharMapAction::SubStr("15.")), // NUMBER FIFTEEN FULL STOP
+    ('\u{2497}', CharMapAction::SubStr("16.")), // NUMBER SIXTEEN FULL STOP
+    ('\u{2498}', CharMapAction::SubStr("17.")), // NUMBER SEVENTEEN FULL STOP
+    ('\u{2499}', CharMapAction::SubStr("18.")), // NUMBER EIGHTEEN FULL STOP
+    ('\u{249A}', CharMapAction::SubStr("19.")), // NUMBER NINETEEN FULL STOP
+    ('\u{249B}', CharMapAction::SubStr("20.")), // NUMBER TWENTY FULL STOP
+    ('\u{249C}', CharMapAction::SubStr("(a)")), // PARENTHESIZED LATIN SMALL LETTER A
+    ('\u{249D}', CharMapAction::SubStr("(b)")), // PARENTHESIZED LATIN SMALL LETTER B
+    ('\u{249E}', CharMapAction::SubStr("(c)")), // PARENTHESIZED LATIN SMALL LETTER C
+    ('\u{249F}', CharMapAction::SubStr("(d)")), // PARENTHESIZED LATIN SMALL LETTER D
+    ('\u{24A0}', CharMapAction::SubStr("(e)")), // PARENTHESIZED LATIN SMALL LETTER E
+    ('\u{24A1}', CharMapAction::SubStr("(f)")), // PARENTHESIZED LATIN SMALL LETTER F
+    ('\u{24A2}', CharMapAction::SubStr("(g)")), // PARENTHESIZED LATIN SMALL LETTER G
+    ('\u{24A3}', CharMapAction::SubStr("(h)")), // PARENTHESIZED LATIN SMALL LETTER H
+    ('\u{24A4}', CharMapAction::SubStr("(i)")), // PARENTHESIZED LATIN SMALL LETTER I
+    ('\u{24A5}', CharMapAction::SubStr("(j)")), // PARENTHESIZED LATIN SMALL LETTER J
+    ('\u{24A6}', CharMapAction::SubStr("(k)")), // PARENTHESIZED LATIN SMALL LETTER K
+    ('\u{24A7}', CharMapAction::SubStr("(l)")), // PARENTHESIZED LATIN SMALL LETTER L
+    ('\u{24A8}', CharMapAction::SubStr("(m)")), // PARENTHESIZED LATIN SMALL LETTER M
+    ('\u{24A9}', CharMapAction::SubStr("(n)")), // PARENTHESIZED LATIN SMALL LETTER N
+    ('\u{24AA}', CharMapAction::SubStr("(o)")), // PARENTHESIZED LATIN SMALL LETTER O
+    ('\u{24AB}', CharMapAction::SubStr("(p)")), // PARENTHESIZED LATIN SMALL LETTER P
+    ('\u{24AC}', CharMapAction::SubStr("(q)")), // PARENTHESIZED LATIN SMALL LETTER Q
+    ('\u{24AD}', CharMapAction::SubStr("(r)")), // PARENTHESIZED LATIN SMALL LETTER R
+    ('\u{24AE}', CharMapAction::SubStr("(s)")), // PARENTHESIZED LATIN SMALL LETTER S
+    ('\u{24AF}', CharMapAction::SubStr("(t)")), // PARENTHESIZED LATIN SMALL LETTER T
+    ('\u{24B0}', CharMapAction::SubStr("(u)")), // PARENTHESIZED LATIN SMALL LETTER U
+    ('\u{24B1}', CharMapAction::SubStr("(v)")), // PARENTHESIZED LATIN SMALL LETTER V
+    ('\u{24B2}', CharMapAction::SubStr("(w)")), // PARENTHESIZED LATIN SMALL LETTER W
+    ('\u{24B3}', CharMapAction::SubStr("(x)")), // PARENTHESIZED LATIN SMALL LETTER X
+    ('\u{24B4}', CharMapAction::SubStr("(y)")), // PARENTHESIZED LATIN SMALL LETTER Y
+    ('\u{24B5}', CharMapAction::SubStr("(z)")), // PARENTHESIZED LATIN SMALL LETTER Z
+    ('\u{24B6}', CharMapAction::SubChar('A')), // CIRCLED LATIN CAPITAL LETTER A
+    ('\u{24B7}', CharMapAction::SubChar('B')), // CIRCLED LATIN CAPITAL LETTER B
+    ('\u{24B8}', CharMapAction::SubChar('C')), // CIRCLED LATIN CAPITAL LETTER C
+    ('\u{24B9}', CharMapAction::SubChar('D')), // CIRCLED LATIN CAPITAL LETTER D
+    ('\u{24BA}', CharMapAction::SubChar('E')), // CIRCLED LATIN CAPITAL LETTER E
+    ('\u{24BB}', CharMapAction::SubChar('F')), // CIRCLED LATIN CAPITAL LETTER F
+    ('\u{24BC}', CharMapAction::SubChar('G')), // CIRCLED LATIN CAPITAL LETTER G
+    ('\u{24BD}', CharMapAction::SubChar('H')), // CIRCLED LATIN CAPITAL LETTER H
+    ('\u{24BE}', CharMapAction::SubChar('I')), // CIRCLED LATIN CAPITAL LETTER I
+    ('\u{24BF}', CharMapAction::SubChar('J')), // CIRCLED LATIN CAPITAL LETTER J
+    ('\u{24C0}', CharMapAction::SubChar('K')), // CIRCLED LATIN CAPITAL LETTER K
+    ('\u{24C1}', CharMapAction::SubChar('L')), // CIRCLED LATIN CAPITAL LETTER L
+    ('\u{24C2}', CharMapAction::SubChar('M')), // CIRCLED LATIN CAPITAL LETTER M
+    ('\u{24C3}', CharMapAction::SubChar('N')), // CIRCLED LATIN CAPITAL LETTER N
+    ('\u{24C4}', CharMapAction::SubChar('O')), // CIRCLED LATIN CAPITAL LETTER O
+    ('\u{24C5}', CharMapAction::SubChar('P')), // CIRCLED LATIN CAPITAL LETTER P
+    ('\u{24C6}', CharMapAction::SubChar('Q')), // CIRCLED LATIN CAPITAL LETTER Q
+    ('\u{24C7}', CharMapAction::SubChar('R')), // CIRCLED LATIN CAPITAL LETTER R
+    ('\u{24C8}', CharMapAction::SubChar('S')), // CIRCLED LATIN CAPITAL LETTER S
+    ('\u{24C9}', CharMapAction::SubChar('T')), // CIRCLED LATIN CAPITAL LETTER T
+    ('\u{24CA}', CharMapAction::SubChar('U')), // CIRCLED LATIN CAPITAL LETTER U
+    ('\u{24CB}', CharMapAction::SubChar('V')), // CIRCLED LATIN CAPITAL LETTER V
+    ('\u{24CC}', CharMapAction::SubChar('W')), // CIRCLED LATIN CAPITAL LETTER W
+    ('\u{24CD}', CharMapAction::SubChar('X')), // CIRCLED LATIN CAPITAL LETTER X
+    ('\u{24CE}', CharMapAction::SubChar('Y')), // CIRCLED LATIN CAPITAL LETTER Y
+    ('\u{24CF}', CharMapAction::SubChar('Z')), // CIRCLED LATIN CAPITAL LETTER Z
+    ('\u{24D0}', CharMapAction::SubChar('a')), // CIRCLED LATIN SMALL LETTER A
+    ('\u{24D1}', CharMapAction::SubChar('b')), // CIRCLED LATIN SMALL LETTER B
+    ('\u{24D2}', CharMapAction::SubChar('c')), // CIRCLED LATIN SMALL LETTER C
+    ('\u{24D3}', CharMapAction::SubChar('d')), // CIRCLED LATIN SMALL LETTER D
+    ('\u{24D4}', CharMapAction::SubChar('e')), // CIRCLED LATIN SMALL LETTER E
+    ('\u{24D5}', CharMapAction::SubChar('f')), // CIRCLED LATIN SMALL LETTER F
+    ('\u{24D6}', CharMapAction::SubChar('g')), // CIRCLED LATIN SMALL LETTER G
+    ('\u{24D7}', CharMapAction::SubChar('h')), // CIRCLED LATIN SMALL LETTER H
+    ('\u{24D8}', CharMapAction::SubChar('i')), // CIRCLED LATIN SMALL LETTER I
+    ('\u{24D9}', CharMapAction::SubChar('j')), // CIRCLED LATIN SMALL LETTER J
+    ('\u{24DA}', CharMapAction::SubChar('k')), // CIRCLED LATIN SMALL LETTER K
+    ('\u{24DB}', CharMapAction::SubChar('l')), // CIRCLED LATIN SMALL LETTER L
+    ('\u{24DC}', CharMapAction::SubChar('m')), // CIRCLED LATIN SMALL LETTER M
+    ('\u{24DD}', CharMapAction::SubChar('n')), // CIRCLED LATIN SMALL LETTER N
+    ('\u{24DE}', CharMapAction::SubChar('o')), // CIRCLED LATIN SMALL LETTER O
+    ('\u{24DF}', CharMapAction::SubChar('p')), // CIRCLED LATIN SMALL LETTER P
+    ('\u{24E0}', CharMapAction::SubChar('q')), // CIRCLED LATIN SMALL LETTER Q
+    ('\u{24E1}', CharMapAction::SubChar('r')), // CIRCLED LATIN SMALL LETTER R
+    ('\u{24E2}', CharMapAction::SubChar('s')), // CIRCLED LATIN SMALL LETTER S
+    ('\u{24E3}', CharMapAction::SubChar('t')), // CIRCLED LATIN SMALL LETTER T
+    ('\u{24E4}', CharMapAction::SubChar('u')), // CIRCLED LATIN SMALL LETTER U
+    ('\u{24E5}', CharMapAction::SubChar('v')), // CIRCLED LATIN SMALL LETTER V
+    ('\u{24E6}', CharMapAction::SubChar('w')), // CIRCLED LATIN SMALL LETTER W
+    ('\u{24E7}', CharMapAction::SubChar('x')), // CIRCLED LATIN SMALL LETTER X
+    ('\u{24E8}', CharMapAction::SubChar('y')), // CIRCLED LATIN SMALL LETTER Y
+    ('\u{24E9}', CharMapAction::SubChar('z')), // CIRCLED LATIN SMALL LETTER Z
+    ('\u{24EA}', CharMapAction::SubChar('0')), // CIRCLED DIGIT ZERO
+    ('\u{24EB}', CharMapAction::SubStr("11")), // NEGATIVE CIRCLED NUMBER ELEVEN
+    ('\u{24EC}', CharMapAction::SubStr("12")), // NEGATIVE CIRCLED NUMBER TWELVE
+    ('\u{24ED}', CharMapAction::SubStr("13")), // NEGATIVE CIRCLED NUMBER THIRTEEN
+    ('\u{24EE}', CharMapAction::SubStr("14")), // NEGATIVE CIRCLED NUMBER FOURTEEN
+    ('\u{24EF}', CharMapAction::SubStr("15")), // NEGATIVE CIRCLED NUMBER FIFTEEN
+    ('\u{24F0}', CharMapAction::SubStr("16")), // NEGATIVE CIRCLED NUMBER SIXTEEN
+    ('\u{24F1}', CharMapAction::SubStr("17")), // NEGATIVE CIRCLED NUMBER SEVENTEEN
+    ('\u{24F2}', CharMapAction::SubStr("18")), // NEGATIVE CIRCLED NUMBER EIGHTEEN
+    ('\u{24F3}', CharMapAction::SubStr("19")), // NEGATIVE CIRCLED NUMBER NINETEEN
+    ('\u{24F4}', CharMapAction::SubStr("20")), // NEGATIVE CIRCLED NUMBER TWENTY
+    ('\u{24F5}', CharMapAction::SubChar('1')), // DOUBLE CIRCLED DIGIT ONE
+    ('\u{24F6}', CharMapAction::SubChar('2')), // DOUBLE CIRCLED DIGIT TWO
+    ('\u{24F7}', CharMapAction::SubChar('3')), // DOUBLE CIRCLED DIGIT THREE
+    ('\u{24F8}', CharMapAction::SubChar('4')), // DOUBLE CIRCLED DIGIT FOUR
+    ('\u{24F9}', CharMapAction::SubChar('5')), // DOUBLE CIRCLED DIGIT FIVE
+    ('\u{24FA}', CharMapAction::SubChar('6')), // DOUBLE CIRCLED DIGIT SIX
+    ('\u{24FB}', CharMapAction::SubChar('7')), // DOUBLE CIRCLED DIGIT SEVEN
+    ('\u{24FC}', CharMapAction::SubChar('8')), // DOUBLE CIRCLED DIGIT EIGHT
+    ('\u{24FD}', CharMapAction::SubChar('9')), // DOUBLE CIRCLED DIGIT NINE
+    ('\u{24FE}', CharMapAction::SubStr("10")), // DOUBLE CIRCLED NUMBER TEN
+    ('\u{24FF}', CharMapAction::SubChar('0')), // NEGATIVE CIRCLED DIGIT ZERO
+    ('\u{275B}', CharMapAction::SubChar('\'')), // HEAVY SINGLE TURNED COMMA QUOTATION MARK ORNAMENT
+    ('\u{275C}', CharMapAction::SubChar('\'')), // HEAVY SINGLE COMMA QUOTATION MARK ORNAMENT
+    ('\u{275D}', CharMapAction::SubChar('"')), // HEAVY DOUBLE TURNED COMMA QUOTATION MARK ORNAMENT
+    ('\u{275E}', CharMapAction::SubChar('"')), // HEAVY DOUBLE COMMA QUOTATION MARK ORNAMENT
+    ('\u{2768}', CharMapAction::SubChar('(')), // MEDIUM LEFT PARENTHESIS ORNAMENT
+    ('\u{2769}', CharMapAction::SubChar(')')), // MEDIUM RIGHT PARENTHESIS ORNAMENT
+    ('\u{276A}', CharMapAction::SubChar('(')), // MEDIUM FLATTENED LEFT PARENTHESIS ORNAMENT
+    ('\u{276B}', CharMapAction::SubChar(')')), // MEDIUM FLATTENED RIGHT PARENTHESIS ORNAMENT
+    ('\u{276C}', CharMapAction::SubChar('<')), // MEDIUM LEFT-POINTING ANGLE BRACKET ORNAMENT
+    ('\u{276D}', CharMapAction::SubChar('>')), // MEDIUM RIGHT-POINTING ANGLE BRACKET ORNAMENT
+    ('\u{276E}', CharMapAction::SubChar('"')), // HEAVY LEFT-POINTING ANGLE QUOTATION MARK ORNAMENT
+    ('\u{276F}', CharMapAction::SubChar('"')), // HEAVY RIGHT-POINTING ANGLE QUOTATION MARK ORNAMENT
+    ('\u{2770}', CharMapAction::SubChar('<')), // HEAVY LEFT-POINTING ANGLE BRACKET ORNAMENT
+    ('\u{2771}', CharMapAction::SubChar('>')), // HEAVY RIGHT-POINTING ANGLE BRACKET ORNAMENT
+    ('\u{2772}', CharMapAction::SubChar('[')), // LIGHT LEFT TORTOISE SHELL BRACKET ORNAMENT
+    ('\u{2773}', CharMapAction::SubChar(']')), // LIGHT RIGHT TORTOISE SHELL BRACKET ORNAMENT
+    ('\u{2774}', CharMapAction::SubChar('{')), // MEDIUM LEFT CURLY BRACKET ORNAMENT
+    ('\u{2775}', CharMapAction::SubChar('}')), // MEDIUM RIGHT CURLY BRACKET ORNAMENT
+    ('\u{2776}', CharMapAction::SubChar('1')), // DINGBAT NEGATIVE CIRCLED DIGIT ONE
+    ('\u{2777}', CharMapAction::SubChar('2')), // DINGBAT NEGATIVE CIRCLED DIGIT TWO
+    ('\u{2778}', CharMapAction::SubChar('3')), // DINGBAT NEGATIVE CIRCLED DIGIT THREE
+    ('\u{2779}', CharMapAction::SubChar('4')), // DINGBAT NEGATIVE CIRCLED DIGIT FOUR
+    ('\u{277A}', CharMapAction::SubChar('5')), // DINGBAT NEGATIVE CIRCLED DIGIT FIVE
+    ('\u{277B}', CharMapAction::SubChar('6')), // DINGBAT NEGATIVE CIRCLED DIGIT SIX
+    ('\u{277C}', CharMapAction::SubChar('7')), // DINGBAT NEGATIVE CIRCLED DIGIT SEVEN
+    ('\u{277D}', CharMapAction::SubChar('8')), // DINGBAT NEGATIVE CIRCLED DIGIT EIGHT
+    ('\u{277E}', CharMapAction::SubChar('9')), // DINGBAT NEGATIVE CIRCLED DIGIT NINE
+    ('\u{277F}', CharMapAction::SubStr("10")), // DINGBAT NEGATIVE CIRCLED NUMBER TEN
+    ('\u{2780}', CharMapAction::SubChar('1')), // DINGBAT CIRCLED SANS-SERIF DIGIT ONE
+    ('\u{2781}', CharMapAction::SubChar('2')), // DINGBAT CIRCLED SANS-SERIF DIGIT TWO
+    ('\u{2782}', CharMapAction::SubChar('3')), // DINGBAT CIRCLED SANS-SERIF DIGIT THREE
+    ('\u{2783}', CharMapAction::SubChar('4')), // DINGBAT CIRCLED SANS-SERIF DIGIT FOUR
+    ('\u{2784}', CharMapAction::SubChar('5')), // DINGBAT CIRCLED SANS-SERIF DIGIT FIVE
+    ('\u{2785}', CharMapAction::SubChar('6')), // DINGBAT CIRCLED SANS-SERIF DIGIT SIX
+    ('\u{2786}', CharMapAction::SubChar('7')), // DINGBAT CIRCLED SANS-SERIF DIGIT SEVEN
+    ('\u{2787}', CharMapAction::SubChar('8')), // DINGBAT CIRCLED SANS-SERIF DIGIT EIGHT
+    ('\u{2788}', CharMapAction::SubChar('9')), // DINGBAT CIRCLED SANS-SERIF DIGIT NINE
+    ('\u{2789}', CharMapAction::SubStr("10")), // DINGBAT CIRCLED SANS-SERIF NUMBER TEN
+    ('\u{278A}', CharMapAction::SubChar('1')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT ONE
+    ('\u{278B}', CharMapAction::SubChar('2')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT TWO
+    ('\u{278C}', CharMapAction::SubChar('3')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT THREE
+    ('\u{278D}', CharMapAction::SubChar('4')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT FOUR
+    ('\u{278E}', CharMapAction::SubChar('5')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT FIVE
+    ('\u{278F}', CharMapAction::SubChar('6')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT SIX
+    ('\u{2790}', CharMapAction::SubChar('7')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT SEVEN
+    ('\u{2791}', CharMapAction::SubChar('8')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT EIGHT
+    ('\u{2792}', CharMapAction::SubChar('9')), // DINGBAT NEGATIVE CIRCLED SANS-SERIF DIGIT NINE
+    ('\u{2793}', CharMapAction::SubStr("10")), // DINGBAT NEGATIVE CIRCLED SANS-SERIF NUMBER TEN
+    ('\u{2C60}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH DOUBLE BAR
+    ('\u{2C61}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH DOUBLE BAR
+    ('\u{2C62}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH MIDDLE TILDE
+    ('\u{2C63}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH STROKE
+    ('\u{2C64}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R WITH TAIL
+    ('\u{2C65}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER A WITH STROKE
+    ('\u{2C66}', CharMapAction::SubChar('t')), // LATIN SMALL LETTER T WITH DIAGONAL STROKE
+    ('\u{2C67}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER H WITH DESCENDER
+    ('\u{2C68}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER H WITH DESCENDER
+    ('\u{2C69}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH DESCENDER
+    ('\u{2C6A}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH DESCENDER
+    ('\u{2C6B}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER Z WITH DESCENDER
+    ('\u{2C6C}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER Z WITH DESCENDER
+    ('\u{2C6E}', CharMapAction::SubChar('M')), // LATIN CAPITAL LETTER M WITH HOOK
+    ('\u{2C6F}', CharMapAction::SubChar('a')), // LATIN CAPITAL LETTER TURNED A
+    ('\u{2C71}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH RIGHT HOOK
+    ('\u{2C72}', CharMapAction::SubChar('W')), // LATIN CAPITAL LETTER W WITH HOOK
+    ('\u{2C73}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER W WITH HOOK
+    ('\u{2C74}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH CURL
+    ('\u{2C75}', CharMapAction::SubChar('H')), // LATIN CAPITAL LETTER HALF H
+    ('\u{2C76}', CharMapAction::SubChar('h')), // LATIN SMALL LETTER HALF H
+    ('\u{2C78}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER E WITH NOTCH
+    ('\u{2C7A}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH LOW RING INSIDE
+    ('\u{2C7B}', CharMapAction::SubChar('E')), // LATIN LETTER SMALL CAPITAL TURNED E
+    ('\u{2C7C}', CharMapAction::SubChar('j')), // LATIN SUBSCRIPT SMALL LETTER J
+    ('\u{2E28}', CharMapAction::SubStr("((")), // LEFT DOUBLE PARENTHESIS
+    ('\u{2E29}', CharMapAction::SubStr("))")), // RIGHT DOUBLE PARENTHESIS
+    ('\u{A728}', CharMapAction::SubStr("TZ")), // LATIN CAPITAL LETTER TZ
+    ('\u{A729}', CharMapAction::SubStr("tz")), // LATIN SMALL LETTER TZ
+    ('\u{A730}', CharMapAction::SubChar('F')), // LATIN LETTER SMALL CAPITAL F
+    ('\u{A731}', CharMapAction::SubChar('S')), // LATIN LETTER SMALL CAPITAL S
+    ('\u{A732}', CharMapAction::SubStr("AA")), // LATIN CAPITAL LETTER AA
+    ('\u{A733}', CharMapAction::SubStr("aa")), // LATIN SMALL LETTER AA
+    ('\u{A734}', CharMapAction::SubStr("AO")), // LATIN CAPITAL LETTER AO
+    ('\u{A735}', CharMapAction::SubStr("ao")), // LATIN SMALL LETTER AO
+    ('\u{A736}', CharMapAction::SubStr("AU")), // LATIN CAPITAL LETTER AU
+    ('\u{A737}', CharMapAction::SubStr("au")), // LATIN SMALL LETTER AU
+    ('\u{A738}', CharMapAction::SubStr("AV")), // LATIN CAPITAL LETTER AV
+    ('\u{A739}', CharMapAction::SubStr("av")), // LATIN SMALL LETTER AV
+    ('\u{A73A}', CharMapAction::SubStr("AV")), // LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73B}', CharMapAction::SubStr("av")), // LATIN SMALL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73C}', CharMapAction::SubStr("AY")), // LATIN CAPITAL LETTER AY
+    ('\u{A73D}', CharMapAction::SubStr("ay")), // LATIN SMALL LETTER AY
+    ('\u{A73E}', CharMapAction::SubChar('c')), // LATIN CAPITAL LETTER REVERSED C WITH DOT
+    ('\u{A73F}', CharMapAction::SubChar('c')), // LATIN SMALL LETTER REVERSED C WITH DOT
+    ('\u{A740}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH STROKE
+    ('\u{A741}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH STROKE
+    ('\u{A742}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH DIAGONAL STROKE
+    ('\u{A743}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH DIAGONAL STROKE
+    ('\u{A744}', CharMapAction::SubChar('K')), // LATIN CAPITAL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A745}', CharMapAction::SubChar('k')), // LATIN SMALL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A746}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER BROKEN L
+    ('\u{A747}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER BROKEN L
+    ('\u{A748}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER L WITH HIGH STROKE
+    ('\u{A749}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER L WITH HIGH STROKE
+    ('\u{A74A}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74B}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74C}', CharMapAction::SubChar('O')), // LATIN CAPITAL LETTER O WITH LOOP
+    ('\u{A74D}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER O WITH LOOP
+    ('\u{A74E}', CharMapAction::SubStr("OO")), // LATIN CAPITAL LETTER OO
+    ('\u{A74F}', CharMapAction::SubStr("oo")), // LATIN SMALL LETTER OO
+    ('\u{A750}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A751}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A752}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH FLOURISH
+    ('\u{A753}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH FLOURISH
+    ('\u{A754}', CharMapAction::SubChar('P')), // LATIN CAPITAL LETTER P WITH SQUIRREL TAIL
+    ('\u{A755}', CharMapAction::SubChar('p')), // LATIN SMALL LETTER P WITH SQUIRREL TAIL
+    ('\u{A756}', CharMapAction::SubChar('Q')), // LATIN CAPITAL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A757}', CharMapAction::SubChar('q')), // LATIN SMALL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A758}', CharMapAction::SubChar('Q')), // LATIN CAPITAL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A759}', CharMapAction::SubChar('q')), // LATIN SMALL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A75A}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER R ROTUNDA
+    ('\u{A75B}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R ROTUNDA
+    ('\u{A75E}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER V WITH DIAGONAL STROKE
+    ('\u{A75F}', CharMapAction::SubChar('v')), // LATIN SMALL LETTER V WITH DIAGONAL STROKE
+    ('\u{A760}', CharMapAction::SubStr("VY")), // LATIN CAPITAL LETTER VY
+    ('\u{A761}', CharMapAction::SubStr("vy")), // LATIN SMALL LETTER VY
+    ('\u{A762}', CharMapAction::SubChar('Z')), // LATIN CAPITAL LETTER VISIGOTHIC Z
+    ('\u{A763}', CharMapAction::SubChar('z')), // LATIN SMALL LETTER VISIGOTHIC Z
+    ('\u{A766}', CharMapAction::SubStr("TH")), // LATIN CAPITAL LETTER THORN WITH STROKE THROUGH DESCENDER
+    ('\u{A767}', CharMapAction::SubStr("th")), // LATIN SMALL LETTER THORN WITH STROKE THROUGH DESCENDER
+    ('\u{A768}', CharMapAction::SubChar('V')), // LATIN CAPITAL LETTER VEND
+    ('\u{A779}', CharMapAction::SubChar('D')), // LATIN CAPITAL LETTER INSULAR D
+    ('\u{A77A}', CharMapAction::SubChar('d')), // LATIN SMALL LETTER INSULAR D
+    ('\u{A77B}', CharMapAction::SubChar('F')), // LATIN CAPITAL LETTER INSULAR F
+    ('\u{A77C}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER INSULAR F
+    ('\u{A77D}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER INSULAR G
+    ('\u{A77E}', CharMapAction::SubChar('G')), // LATIN CAPITAL LETTER TURNED INSULAR G
+    ('\u{A77F}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER TURNED INSULAR G
+    ('\u{A780}', CharMapAction::SubChar('L')), // LATIN CAPITAL LETTER TURNED L
+    ('\u{A781}', CharMapAction::SubChar('l')), // LATIN SMALL LETTER TURNED L
+    ('\u{A782}', CharMapAction::SubChar('R')), // LATIN CAPITAL LETTER INSULAR R
+    ('\u{A783}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER INSULAR R
+    ('\u{A784}', CharMapAction::SubChar('s')), // LATIN CAPITAL LETTER INSULAR S
+    ('\u{A785}', CharMapAction::SubChar('S')), // LATIN SMALL LETTER INSULAR S
+    ('\u{A786}', CharMapAction::SubChar('T')), // LATIN CAPITAL LETTER INSULAR T
+    ('\u{A7FB}', CharMapAction::SubChar('F')), // LATIN EPIGRAPHIC LETTER REVERSED F
+    ('\u{A7FC}', CharMapAction::SubChar('p')), // LATIN EPIGRAPHIC LETTER REVERSED P
+    ('\u{A7FD}', CharMapAction::SubChar('M')), // LATIN EPIGRAPHIC LETTER INVERTED M
+    ('\u{A7FE}', CharMapAction::SubChar('I')), // LATIN EPIGRAPHIC LETTER I LONGA
+    ('\u{A7FF}', CharMapAction::SubChar('M')), // LATIN EPIGRAPHIC LETTER ARCHAIC M
+    ('\u{FB00}', CharMapAction::SubStr("ff")), // LATIN SMALL LIGATURE FF
+    ('\u{FB01}', CharMapAction::SubStr("fi")), // LATIN SMALL LIGATURE FI
+    ('\u{FB02}', CharMapAction::SubStr("fl")), // LATIN SMALL LIGATURE FL
+    ('\u{FB03}', CharMapAction::SubStr("ffi")), // LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', CharMapAction::SubStr("ffl")), // LATIN SMALL LIGATURE FFL
+    ('\u{FB06}', CharMapAction::SubStr("st")), // LATIN SMALL LIGATURE ST
+    ('\u{FF01}', CharMapAction::SubChar('!')), // FULLWIDTH EXCLAMATION MARK
+    ('\u{FF02}', CharMapAction::SubChar('"')), // FULLWIDTH QUOTATION MARK
+    ('\u{FF03}', CharMapAction::SubChar('#')), // FULLWIDTH NUMBER SIGN
+    ('\u{FF04}', CharMapAction::SubChar('$')), // FULLWIDTH DOLLAR SIGN
+    ('\u{FF05}', CharMapAction::SubChar('%')), // FULLWIDTH PERCENT SIGN
+    ('\u{FF06}', CharMapAction::SubChar('&')), // FULLWIDTH AMPERSAND
+    ('\u{FF07}', CharMapAction::SubChar('\'')), // FULLWIDTH APOSTROPHE
+    ('\u{FF08}', CharMapAction::SubChar('(')), // FULLWIDTH LEFT PARENTHESIS
+    ('\u{FF09}', CharMapAction::SubChar(')')), // FULLWIDTH RIGHT PARENTHESIS
+    ('\u{FF0A}', CharMapAction::SubChar('*')), // FULLWIDTH ASTERISK
+    ('\u{FF0B}', CharMapAction::SubChar('+')), // FULLWIDTH PLUS SIGN
+    ('\u{FF0C}', CharMapAction::SubChar(',')), // FULLWIDTH COMMA
+    ('\u{FF0D}', CharMapAction::SubChar('-')), // FULLWIDTH HYPHEN-MINUS
+    ('\u{FF0E}', CharMapAction::SubChar('.')), // FULLWIDTH FULL STOP
+    ('\u{FF0F}', CharMapAction::SubChar('/')), // FULLWIDTH SOLIDUS
+    ('\u{FF10}', CharMapAction::SubChar('0')), // FULLWIDTH DIGIT ZERO
+    ('\u{FF11}', CharMapAction::SubChar('1')), // FULLWIDTH DIGIT ONE
+    ('\u{FF12}', CharMapAction::SubChar('2')), // FULLWIDTH DIGIT TWO
+    ('\u{FF13}', CharMapAction::SubChar('3')), // FULLWIDTH DIGIT THREE
+    ('\u{FF14}', CharMapAction::SubChar('4')), // FULLWIDTH DIGIT FOUR
+    ('\u{FF15}', CharMapAction::SubChar('5')), // FULLWIDTH DIGIT FIVE
+    ('\u{FF16}', CharMapAction::SubChar('6')), // FULLWIDTH DIGIT SIX
+    ('\u{FF17}', CharMapAction::SubChar('7')), // FULLWIDTH DIGIT SEVEN
+    ('\u{FF18}', CharMapAction::SubChar('8')), // FULLWIDTH DIGIT EIGHT
+    ('\u{FF19}', CharMapAction::SubChar('9')), // FULLWIDTH DIGIT NINE
+    ('\u{FF1A}', CharMapAction::SubChar(':')), // FULLWIDTH COLON
+    ('\u{FF1B}', CharMapAction::SubChar(';')), // FULLWIDTH SEMICOLON
+    ('\u{FF1C}', CharMapAction::SubChar('<')), // FULLWIDTH LESS-THAN SIGN
+    ('\u{FF1D}', CharMapAction::SubChar('=')), // FULLWIDTH EQUALS SIGN
+    ('\u{FF1E}', CharMapAction::SubChar('>')), // FULLWIDTH GREATER-THAN SIGN
+    ('\u{FF1F}', CharMapAction::SubChar('?')), // FULLWIDTH QUESTION MARK
+    ('\u{FF20}', CharMapAction::SubChar('@')), // FULLWIDTH COMMERCIAL AT
+    ('\u{FF21}', CharMapAction::SubChar('A')), // FULLWIDTH LATIN CAPITAL LETTER A
+    ('\u{FF22}', CharMapAction::SubChar('B')), // FULLWIDTH LATIN CAPITAL LETTER B
+    ('\u{FF23}', CharMapAction::SubChar('C')), // FULLWIDTH LATIN CAPITAL LETTER C
+    ('\u{FF24}', CharMapAction::SubChar('D')), // FULLWIDTH LATIN CAPITAL LETTER D
+    ('\u{FF25}', CharMapAction::SubChar('E')), // FULLWIDTH LATIN CAPITAL LETTER E
+    ('\u{FF26}', CharMapAction::SubChar('F')), // FULLWIDTH LATIN CAPITAL LETTER F
+    ('\u{FF27}', CharMapAction::SubChar('G')), // FULLWIDTH LATIN CAPITAL LETTER G
+    ('\u{FF28}', CharMapAction::SubChar('H')), // FULLWIDTH LATIN CAPITAL LETTER H
+    ('\u{FF29}', CharMapAction::SubChar('I')), // FULLWIDTH LATIN CAPITAL LETTER I
+    ('\u{FF2A}', CharMapAction::SubChar('J')), // FULLWIDTH LATIN CAPITAL LETTER J
+    ('\u{FF2B}', CharMapAction::SubChar('K')), // FULLWIDTH LATIN CAPITAL LETTER K
+    ('\u{FF2C}', CharMapAction::SubChar('L')), // FULLWIDTH LATIN CAPITAL LETTER L
+    ('\u{FF2D}', CharMapAction::SubChar('M')), // FULLWIDTH LATIN CAPITAL LETTER M
+    ('\u{FF2E}', CharMapAction::SubChar('N')), // FULLWIDTH LATIN CAPITAL LETTER N
+    ('\u{FF2F}', CharMapAction::SubChar('O')), // FULLWIDTH LATIN CAPITAL LETTER O
+    ('\u{FF30}', CharMapAction::SubChar('P')), // FULLWIDTH LATIN CAPITAL LETTER P
+    ('\u{FF31}', CharMapAction::SubChar('Q')), // FULLWIDTH LATIN CAPITAL LETTER Q
+    ('\u{FF32}', CharMapAction::SubChar('R')), // FULLWIDTH LATIN CAPITAL LETTER R
+    ('\u{FF33}', CharMapAction::SubChar('S')), // FULLWIDTH LATIN CAPITAL LETTER S
+    ('\u{FF34}', CharMapAction::SubChar('T')), // FULLWIDTH LATIN CAPITAL LETTER T
+    ('\u{FF35}', CharMapAction::SubChar('U')), // FULLWIDTH LATIN CAPITAL LETTER U
+    ('\u{FF36}', CharMapAction::SubChar('V')), // FULLWIDTH LATIN CAPITAL LETTER V
+    ('\u{FF37}', CharMapAction::SubChar('W')), // FULLWIDTH LATIN CAPITAL LETTER W
+    ('\u{FF38}', CharMapAction::SubChar('X')), // FULLWIDTH LATIN CAPITAL LETTER X
+    ('\u{FF39}', CharMapAction::SubChar('Y')), // FULLWIDTH LATIN CAPITAL LETTER Y
+    ('\u{FF3A}', CharMapAction::SubChar('Z')), // FULLWIDTH LATIN CAPITAL LETTER Z
+    ('\u{FF3B}', CharMapAction::SubChar('[')), // FULLWIDTH LEFT SQUARE BRACKET
+    ('\u{FF3C}', CharMapAction::SubChar('\\')), // FULLWIDTH REVERSE SOLIDUS
+    ('\u{FF3D}', CharMapAction::SubChar(']')), // FULLWIDTH RIGHT SQUARE BRACKET
+    ('\u{FF3E}', CharMapAction::SubChar('^')), // FULLWIDTH CIRCUMFLEX ACCENT
+    ('\u{FF3F}', CharMapAction::SubChar('_')), // FULLWIDTH LOW LINE
+    ('\u{FF41}', CharMapAction::SubChar('a')), // FULLWIDTH LATIN SMALL LETTER A
+    ('\u{FF42}', CharMapAction::SubChar('b')), // FULLWIDTH LATIN SMALL LETTER B
+    ('\u{FF43}', CharMapAction::SubChar('c')), // FULLWIDTH LATIN SMALL LETTER C
+    ('\u{FF44}', CharMapAction::SubChar('d')), // FULLWIDTH LATIN SMALL LETTER D
+    ('\u{FF45}', CharMapAction::SubChar('e')), // FULLWIDTH LATIN SMALL LETTER E
+    ('\u{FF46}', CharMapAction::SubChar('f')), // FULLWIDTH LATIN SMALL LETTER F
+    ('\u{FF47}', CharMapAction::SubChar('g')), // FULLWIDTH LATIN SMALL LETTER G
+    ('\u{FF48}', CharMapAction::SubChar('h')), // FULLWIDTH LATIN SMALL LETTER H
+    ('\u{FF49}', CharMapAction::SubChar('i')), // FULLWIDTH LATIN SMALL LETTER I
+    ('\u{FF4A}', CharMapAction::SubChar('j')), // FULLWIDTH LATIN SMALL LETTER J
+    ('\u{FF4B}', CharMapAction::SubChar('k')), // FULLWIDTH LATIN SMALL LETTER K
+    ('\u{FF4C}', CharMapAction::SubChar('l')), // FULLWIDTH LATIN SMALL LETTER L
+    ('\u{FF4D}', CharMapAction::SubChar('m')), // FULLWIDTH LATIN SMALL LETTER M
+    ('\u{FF4E}', CharMapAction::SubChar('n')), // FULLWIDTH LATIN SMALL LETTER N
+    ('\u{FF4F}', CharMapAction::SubChar('o')), // FULLWIDTH LATIN SMALL LETTER O
+    ('\u{FF50}', CharMapAction::SubChar('p')), // FULLWIDTH LATIN SMALL LETTER P
+    ('\u{FF51}', CharMapAction::SubChar('q')), // FULLWIDTH LATIN SMALL LETTER Q
+    ('\u{FF52}', CharMapAction::SubChar('r')), // FULLWIDTH LATIN SMALL LETTER R
+    ('\u{FF53}', CharMapAction::SubChar('s')), // FULLWIDTH LATIN SMALL LETTER S
+    ('\u{FF54}', CharMapAction::SubChar('t')), // FULLWIDTH LATIN SMALL LETTER T
+    ('\u{FF55}', CharMapAction::SubChar('u')), // FULLWIDTH LATIN SMALL LETTER U
+    ('\u{FF56}', CharMapAction::SubChar('v')), // FULLWIDTH LATIN SMALL LETTER V
+    ('\u{FF57}', CharMapAction::SubChar('w')), // FULLWIDTH LATIN SMALL LETTER W
+    ('\u{FF58}', CharMapAction::SubChar('x')), // FULLWIDTH LATIN SMALL LETTER X
+    ('\u{FF59}', CharMapAction::SubChar('y')), // FULLWIDTH LATIN SMALL LETTER Y
+    ('\u{FF5A}', CharMapAction::SubChar('z')), // FULLWIDTH LATIN SMALL LETTER Z
+    ('\u{FF5B}', CharMapAction::SubChar('{')), // FULLWIDTH LEFT CURLY BRACKET
+    ('\u{FF5D}', CharMapAction::SubChar('}')), // FULLWIDTH RIGHT CURLY BRACKET
+    ('\u{FF5E}', CharMapAction::SubChar('~')), // FULLWIDTH TILDE
+];
+const _: () = assert_sorted(&ASCII_FOLDING);
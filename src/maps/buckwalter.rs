@@ -0,0 +1,362 @@
+//! Arabic transliteration maps for the
+//! [Buckwalter](https://en.wikipedia.org/wiki/Buckwalter_transliteration)
+//! transliteration scheme and its Safe and XML variants.
+//!
+//! All maps cover the Arabic letters (including hamza variants, alef wasla,
+//! and tatweel), the diacritics (including tanween, shadda, sukun, and the
+//! dagger alef), and the letters of the extended Buckwalter scheme (پ, چ, ڤ,
+//! and گ). The Safe Buckwalter variant replaces all non-alphanumeric symbols
+//! (except `~` and `_`) with letters, while the XML variant only replaces `<`,
+//! `>`, and `&`.
+//!
+//! ```rust
+//! use charmap::maps::buckwalter::{ARABIC_TO_BW, BW_TO_ARABIC};
+//! use charmap::*;
+//!
+//! let to_bw = CharMapper::new(&ARABIC_TO_BW, CharMapAction::Pass);
+//! let from_bw = CharMapper::new(&BW_TO_ARABIC, CharMapAction::Pass);
+//!
+//! let bw: String = "كَتَبَ".map_chars(&to_bw).collect();
+//! assert_eq!(bw, "kataba");
+//!
+//! let arabic: String = bw.chars().map_chars(&from_bw).collect();
+//! assert_eq!(arabic, "كَتَبَ");
+//! ```
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+
+/// Arabic to Buckwalter transliteration map.
+pub static ARABIC_TO_BW: [(char, CharMapAction); 51] = [
+    ('\u{0621}', CharMapAction::SubChar('\'')), // HAMZA
+    ('\u{0622}', CharMapAction::SubChar('|')),  // ALEF WITH MADDA ABOVE
+    ('\u{0623}', CharMapAction::SubChar('>')),  // ALEF WITH HAMZA ABOVE
+    ('\u{0624}', CharMapAction::SubChar('&')),  // WAW WITH HAMZA ABOVE
+    ('\u{0625}', CharMapAction::SubChar('<')),  // ALEF WITH HAMZA BELOW
+    ('\u{0626}', CharMapAction::SubChar('}')),  // YEH WITH HAMZA ABOVE
+    ('\u{0627}', CharMapAction::SubChar('A')),  // ALEF
+    ('\u{0628}', CharMapAction::SubChar('b')),  // BEH
+    ('\u{0629}', CharMapAction::SubChar('p')),  // TEH MARBUTA
+    ('\u{062A}', CharMapAction::SubChar('t')),  // TEH
+    ('\u{062B}', CharMapAction::SubChar('v')),  // THEH
+    ('\u{062C}', CharMapAction::SubChar('j')),  // JEEM
+    ('\u{062D}', CharMapAction::SubChar('H')),  // HAH
+    ('\u{062E}', CharMapAction::SubChar('x')),  // KHAH
+    ('\u{062F}', CharMapAction::SubChar('d')),  // DAL
+    ('\u{0630}', CharMapAction::SubChar('*')),  // THAL
+    ('\u{0631}', CharMapAction::SubChar('r')),  // REH
+    ('\u{0632}', CharMapAction::SubChar('z')),  // ZAIN
+    ('\u{0633}', CharMapAction::SubChar('s')),  // SEEN
+    ('\u{0634}', CharMapAction::SubChar('$')),  // SHEEN
+    ('\u{0635}', CharMapAction::SubChar('S')),  // SAD
+    ('\u{0636}', CharMapAction::SubChar('D')),  // DAD
+    ('\u{0637}', CharMapAction::SubChar('T')),  // TAH
+    ('\u{0638}', CharMapAction::SubChar('Z')),  // ZAH
+    ('\u{0639}', CharMapAction::SubChar('E')),  // AIN
+    ('\u{063A}', CharMapAction::SubChar('g')),  // GHAIN
+    ('\u{0640}', CharMapAction::SubChar('_')),  // TATWEEL
+    ('\u{0641}', CharMapAction::SubChar('f')),  // FEH
+    ('\u{0642}', CharMapAction::SubChar('q')),  // QAF
+    ('\u{0643}', CharMapAction::SubChar('k')),  // KAF
+    ('\u{0644}', CharMapAction::SubChar('l')),  // LAM
+    ('\u{0645}', CharMapAction::SubChar('m')),  // MEEM
+    ('\u{0646}', CharMapAction::SubChar('n')),  // NOON
+    ('\u{0647}', CharMapAction::SubChar('h')),  // HEH
+    ('\u{0648}', CharMapAction::SubChar('w')),  // WAW
+    ('\u{0649}', CharMapAction::SubChar('Y')),  // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('y')),  // YEH
+    ('\u{064B}', CharMapAction::SubChar('F')),  // FATHATAN
+    ('\u{064C}', CharMapAction::SubChar('N')),  // DAMMATAN
+    ('\u{064D}', CharMapAction::SubChar('K')),  // KASRATAN
+    ('\u{064E}', CharMapAction::SubChar('a')),  // FATHA
+    ('\u{064F}', CharMapAction::SubChar('u')),  // DAMMA
+    ('\u{0650}', CharMapAction::SubChar('i')),  // KASRA
+    ('\u{0651}', CharMapAction::SubChar('~')),  // SHADDA
+    ('\u{0652}', CharMapAction::SubChar('o')),  // SUKUN
+    ('\u{0670}', CharMapAction::SubChar('`')),  // SUPERSCRIPT ALEF
+    ('\u{0671}', CharMapAction::SubChar('{')),  // ALEF WASLA
+    ('\u{067E}', CharMapAction::SubChar('P')),  // PEH
+    ('\u{0686}', CharMapAction::SubChar('J')),  // TCHEH
+    ('\u{06A4}', CharMapAction::SubChar('V')),  // VEH
+    ('\u{06AF}', CharMapAction::SubChar('G')),  // GAF
+];
+const _: () = assert_sorted(&ARABIC_TO_BW);
+
+/// Buckwalter to Arabic transliteration map.
+pub static BW_TO_ARABIC: [(char, CharMapAction); 51] = [
+    ('$', CharMapAction::SubChar('\u{0634}')), // SHEEN
+    ('&', CharMapAction::SubChar('\u{0624}')), // WAW WITH HAMZA ABOVE
+    ('\'', CharMapAction::SubChar('\u{0621}')), // HAMZA
+    ('*', CharMapAction::SubChar('\u{0630}')), // THAL
+    ('<', CharMapAction::SubChar('\u{0625}')), // ALEF WITH HAMZA BELOW
+    ('>', CharMapAction::SubChar('\u{0623}')), // ALEF WITH HAMZA ABOVE
+    ('A', CharMapAction::SubChar('\u{0627}')), // ALEF
+    ('D', CharMapAction::SubChar('\u{0636}')), // DAD
+    ('E', CharMapAction::SubChar('\u{0639}')), // AIN
+    ('F', CharMapAction::SubChar('\u{064B}')), // FATHATAN
+    ('G', CharMapAction::SubChar('\u{06AF}')), // GAF
+    ('H', CharMapAction::SubChar('\u{062D}')), // HAH
+    ('J', CharMapAction::SubChar('\u{0686}')), // TCHEH
+    ('K', CharMapAction::SubChar('\u{064D}')), // KASRATAN
+    ('N', CharMapAction::SubChar('\u{064C}')), // DAMMATAN
+    ('P', CharMapAction::SubChar('\u{067E}')), // PEH
+    ('S', CharMapAction::SubChar('\u{0635}')), // SAD
+    ('T', CharMapAction::SubChar('\u{0637}')), // TAH
+    ('V', CharMapAction::SubChar('\u{06A4}')), // VEH
+    ('Y', CharMapAction::SubChar('\u{0649}')), // ALEF MAKSURA
+    ('Z', CharMapAction::SubChar('\u{0638}')), // ZAH
+    ('_', CharMapAction::SubChar('\u{0640}')), // TATWEEL
+    ('`', CharMapAction::SubChar('\u{0670}')), // SUPERSCRIPT ALEF
+    ('a', CharMapAction::SubChar('\u{064E}')), // FATHA
+    ('b', CharMapAction::SubChar('\u{0628}')), // BEH
+    ('d', CharMapAction::SubChar('\u{062F}')), // DAL
+    ('f', CharMapAction::SubChar('\u{0641}')), // FEH
+    ('g', CharMapAction::SubChar('\u{063A}')), // GHAIN
+    ('h', CharMapAction::SubChar('\u{0647}')), // HEH
+    ('i', CharMapAction::SubChar('\u{0650}')), // KASRA
+    ('j', CharMapAction::SubChar('\u{062C}')), // JEEM
+    ('k', CharMapAction::SubChar('\u{0643}')), // KAF
+    ('l', CharMapAction::SubChar('\u{0644}')), // LAM
+    ('m', CharMapAction::SubChar('\u{0645}')), // MEEM
+    ('n', CharMapAction::SubChar('\u{0646}')), // NOON
+    ('o', CharMapAction::SubChar('\u{0652}')), // SUKUN
+    ('p', CharMapAction::SubChar('\u{0629}')), // TEH MARBUTA
+    ('q', CharMapAction::SubChar('\u{0642}')), // QAF
+    ('r', CharMapAction::SubChar('\u{0631}')), // REH
+    ('s', CharMapAction::SubChar('\u{0633}')), // SEEN
+    ('t', CharMapAction::SubChar('\u{062A}')), // TEH
+    ('u', CharMapAction::SubChar('\u{064F}')), // DAMMA
+    ('v', CharMapAction::SubChar('\u{062B}')), // THEH
+    ('w', CharMapAction::SubChar('\u{0648}')), // WAW
+    ('x', CharMapAction::SubChar('\u{062E}')), // KHAH
+    ('y', CharMapAction::SubChar('\u{064A}')), // YEH
+    ('z', CharMapAction::SubChar('\u{0632}')), // ZAIN
+    ('{', CharMapAction::SubChar('\u{0671}')), // ALEF WASLA
+    ('|', CharMapAction::SubChar('\u{0622}')), // ALEF WITH MADDA ABOVE
+    ('}', CharMapAction::SubChar('\u{0626}')), // YEH WITH HAMZA ABOVE
+    ('~', CharMapAction::SubChar('\u{0651}')), // SHADDA
+];
+const _: () = assert_sorted(&BW_TO_ARABIC);
+
+/// Arabic to Safe Buckwalter transliteration map.
+pub static ARABIC_TO_SAFE_BW: [(char, CharMapAction); 51] = [
+    ('\u{0621}', CharMapAction::SubChar('C')), // HAMZA
+    ('\u{0622}', CharMapAction::SubChar('M')), // ALEF WITH MADDA ABOVE
+    ('\u{0623}', CharMapAction::SubChar('O')), // ALEF WITH HAMZA ABOVE
+    ('\u{0624}', CharMapAction::SubChar('W')), // WAW WITH HAMZA ABOVE
+    ('\u{0625}', CharMapAction::SubChar('I')), // ALEF WITH HAMZA BELOW
+    ('\u{0626}', CharMapAction::SubChar('Q')), // YEH WITH HAMZA ABOVE
+    ('\u{0627}', CharMapAction::SubChar('A')), // ALEF
+    ('\u{0628}', CharMapAction::SubChar('b')), // BEH
+    ('\u{0629}', CharMapAction::SubChar('p')), // TEH MARBUTA
+    ('\u{062A}', CharMapAction::SubChar('t')), // TEH
+    ('\u{062B}', CharMapAction::SubChar('v')), // THEH
+    ('\u{062C}', CharMapAction::SubChar('j')), // JEEM
+    ('\u{062D}', CharMapAction::SubChar('H')), // HAH
+    ('\u{062E}', CharMapAction::SubChar('x')), // KHAH
+    ('\u{062F}', CharMapAction::SubChar('d')), // DAL
+    ('\u{0630}', CharMapAction::SubChar('V')), // THAL
+    ('\u{0631}', CharMapAction::SubChar('r')), // REH
+    ('\u{0632}', CharMapAction::SubChar('z')), // ZAIN
+    ('\u{0633}', CharMapAction::SubChar('s')), // SEEN
+    ('\u{0634}', CharMapAction::SubChar('c')), // SHEEN
+    ('\u{0635}', CharMapAction::SubChar('S')), // SAD
+    ('\u{0636}', CharMapAction::SubChar('D')), // DAD
+    ('\u{0637}', CharMapAction::SubChar('T')), // TAH
+    ('\u{0638}', CharMapAction::SubChar('Z')), // ZAH
+    ('\u{0639}', CharMapAction::SubChar('E')), // AIN
+    ('\u{063A}', CharMapAction::SubChar('g')), // GHAIN
+    ('\u{0640}', CharMapAction::SubChar('_')), // TATWEEL
+    ('\u{0641}', CharMapAction::SubChar('f')), // FEH
+    ('\u{0642}', CharMapAction::SubChar('q')), // QAF
+    ('\u{0643}', CharMapAction::SubChar('k')), // KAF
+    ('\u{0644}', CharMapAction::SubChar('l')), // LAM
+    ('\u{0645}', CharMapAction::SubChar('m')), // MEEM
+    ('\u{0646}', CharMapAction::SubChar('n')), // NOON
+    ('\u{0647}', CharMapAction::SubChar('h')), // HEH
+    ('\u{0648}', CharMapAction::SubChar('w')), // WAW
+    ('\u{0649}', CharMapAction::SubChar('Y')), // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('y')), // YEH
+    ('\u{064B}', CharMapAction::SubChar('F')), // FATHATAN
+    ('\u{064C}', CharMapAction::SubChar('N')), // DAMMATAN
+    ('\u{064D}', CharMapAction::SubChar('K')), // KASRATAN
+    ('\u{064E}', CharMapAction::SubChar('a')), // FATHA
+    ('\u{064F}', CharMapAction::SubChar('u')), // DAMMA
+    ('\u{0650}', CharMapAction::SubChar('i')), // KASRA
+    ('\u{0651}', CharMapAction::SubChar('~')), // SHADDA
+    ('\u{0652}', CharMapAction::SubChar('o')), // SUKUN
+    ('\u{0670}', CharMapAction::SubChar('e')), // SUPERSCRIPT ALEF
+    ('\u{0671}', CharMapAction::SubChar('L')), // ALEF WASLA
+    ('\u{067E}', CharMapAction::SubChar('P')), // PEH
+    ('\u{0686}', CharMapAction::SubChar('J')), // TCHEH
+    ('\u{06A4}', CharMapAction::SubChar('B')), // VEH
+    ('\u{06AF}', CharMapAction::SubChar('G')), // GAF
+];
+const _: () = assert_sorted(&ARABIC_TO_SAFE_BW);
+
+/// Safe Buckwalter to Arabic transliteration map.
+pub static SAFE_BW_TO_ARABIC: [(char, CharMapAction); 51] = [
+    ('A', CharMapAction::SubChar('\u{0627}')), // ALEF
+    ('B', CharMapAction::SubChar('\u{06A4}')), // VEH
+    ('C', CharMapAction::SubChar('\u{0621}')), // HAMZA
+    ('D', CharMapAction::SubChar('\u{0636}')), // DAD
+    ('E', CharMapAction::SubChar('\u{0639}')), // AIN
+    ('F', CharMapAction::SubChar('\u{064B}')), // FATHATAN
+    ('G', CharMapAction::SubChar('\u{06AF}')), // GAF
+    ('H', CharMapAction::SubChar('\u{062D}')), // HAH
+    ('I', CharMapAction::SubChar('\u{0625}')), // ALEF WITH HAMZA BELOW
+    ('J', CharMapAction::SubChar('\u{0686}')), // TCHEH
+    ('K', CharMapAction::SubChar('\u{064D}')), // KASRATAN
+    ('L', CharMapAction::SubChar('\u{0671}')), // ALEF WASLA
+    ('M', CharMapAction::SubChar('\u{0622}')), // ALEF WITH MADDA ABOVE
+    ('N', CharMapAction::SubChar('\u{064C}')), // DAMMATAN
+    ('O', CharMapAction::SubChar('\u{0623}')), // ALEF WITH HAMZA ABOVE
+    ('P', CharMapAction::SubChar('\u{067E}')), // PEH
+    ('Q', CharMapAction::SubChar('\u{0626}')), // YEH WITH HAMZA ABOVE
+    ('S', CharMapAction::SubChar('\u{0635}')), // SAD
+    ('T', CharMapAction::SubChar('\u{0637}')), // TAH
+    ('V', CharMapAction::SubChar('\u{0630}')), // THAL
+    ('W', CharMapAction::SubChar('\u{0624}')), // WAW WITH HAMZA ABOVE
+    ('Y', CharMapAction::SubChar('\u{0649}')), // ALEF MAKSURA
+    ('Z', CharMapAction::SubChar('\u{0638}')), // ZAH
+    ('_', CharMapAction::SubChar('\u{0640}')), // TATWEEL
+    ('a', CharMapAction::SubChar('\u{064E}')), // FATHA
+    ('b', CharMapAction::SubChar('\u{0628}')), // BEH
+    ('c', CharMapAction::SubChar('\u{0634}')), // SHEEN
+    ('d', CharMapAction::SubChar('\u{062F}')), // DAL
+    ('e', CharMapAction::SubChar('\u{0670}')), // SUPERSCRIPT ALEF
+    ('f', CharMapAction::SubChar('\u{0641}')), // FEH
+    ('g', CharMapAction::SubChar('\u{063A}')), // GHAIN
+    ('h', CharMapAction::SubChar('\u{0647}')), // HEH
+    ('i', CharMapAction::SubChar('\u{0650}')), // KASRA
+    ('j', CharMapAction::SubChar('\u{062C}')), // JEEM
+    ('k', CharMapAction::SubChar('\u{0643}')), // KAF
+    ('l', CharMapAction::SubChar('\u{0644}')), // LAM
+    ('m', CharMapAction::SubChar('\u{0645}')), // MEEM
+    ('n', CharMapAction::SubChar('\u{0646}')), // NOON
+    ('o', CharMapAction::SubChar('\u{0652}')), // SUKUN
+    ('p', CharMapAction::SubChar('\u{0629}')), // TEH MARBUTA
+    ('q', CharMapAction::SubChar('\u{0642}')), // QAF
+    ('r', CharMapAction::SubChar('\u{0631}')), // REH
+    ('s', CharMapAction::SubChar('\u{0633}')), // SEEN
+    ('t', CharMapAction::SubChar('\u{062A}')), // TEH
+    ('u', CharMapAction::SubChar('\u{064F}')), // DAMMA
+    ('v', CharMapAction::SubChar('\u{062B}')), // THEH
+    ('w', CharMapAction::SubChar('\u{0648}')), // WAW
+    ('x', CharMapAction::SubChar('\u{062E}')), // KHAH
+    ('y', CharMapAction::SubChar('\u{064A}')), // YEH
+    ('z', CharMapAction::SubChar('\u{0632}')), // ZAIN
+    ('~', CharMapAction::SubChar('\u{0651}')), // SHADDA
+];
+const _: () = assert_sorted(&SAFE_BW_TO_ARABIC);
+
+/// Arabic to XML Buckwalter transliteration map.
+pub static ARABIC_TO_XML_BW: [(char, CharMapAction); 51] = [
+    ('\u{0621}', CharMapAction::SubChar('\'')), // HAMZA
+    ('\u{0622}', CharMapAction::SubChar('|')),  // ALEF WITH MADDA ABOVE
+    ('\u{0623}', CharMapAction::SubChar('O')),  // ALEF WITH HAMZA ABOVE
+    ('\u{0624}', CharMapAction::SubChar('W')),  // WAW WITH HAMZA ABOVE
+    ('\u{0625}', CharMapAction::SubChar('I')),  // ALEF WITH HAMZA BELOW
+    ('\u{0626}', CharMapAction::SubChar('}')),  // YEH WITH HAMZA ABOVE
+    ('\u{0627}', CharMapAction::SubChar('A')),  // ALEF
+    ('\u{0628}', CharMapAction::SubChar('b')),  // BEH
+    ('\u{0629}', CharMapAction::SubChar('p')),  // TEH MARBUTA
+    ('\u{062A}', CharMapAction::SubChar('t')),  // TEH
+    ('\u{062B}', CharMapAction::SubChar('v')),  // THEH
+    ('\u{062C}', CharMapAction::SubChar('j')),  // JEEM
+    ('\u{062D}', CharMapAction::SubChar('H')),  // HAH
+    ('\u{062E}', CharMapAction::SubChar('x')),  // KHAH
+    ('\u{062F}', CharMapAction::SubChar('d')),  // DAL
+    ('\u{0630}', CharMapAction::SubChar('*')),  // THAL
+    ('\u{0631}', CharMapAction::SubChar('r')),  // REH
+    ('\u{0632}', CharMapAction::SubChar('z')),  // ZAIN
+    ('\u{0633}', CharMapAction::SubChar('s')),  // SEEN
+    ('\u{0634}', CharMapAction::SubChar('$')),  // SHEEN
+    ('\u{0635}', CharMapAction::SubChar('S')),  // SAD
+    ('\u{0636}', CharMapAction::SubChar('D')),  // DAD
+    ('\u{0637}', CharMapAction::SubChar('T')),  // TAH
+    ('\u{0638}', CharMapAction::SubChar('Z')),  // ZAH
+    ('\u{0639}', CharMapAction::SubChar('E')),  // AIN
+    ('\u{063A}', CharMapAction::SubChar('g')),  // GHAIN
+    ('\u{0640}', CharMapAction::SubChar('_')),  // TATWEEL
+    ('\u{0641}', CharMapAction::SubChar('f')),  // FEH
+    ('\u{0642}', CharMapAction::SubChar('q')),  // QAF
+    ('\u{0643}', CharMapAction::SubChar('k')),  // KAF
+    ('\u{0644}', CharMapAction::SubChar('l')),  // LAM
+    ('\u{0645}', CharMapAction::SubChar('m')),  // MEEM
+    ('\u{0646}', CharMapAction::SubChar('n')),  // NOON
+    ('\u{0647}', CharMapAction::SubChar('h')),  // HEH
+    ('\u{0648}', CharMapAction::SubChar('w')),  // WAW
+    ('\u{0649}', CharMapAction::SubChar('Y')),  // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('y')),  // YEH
+    ('\u{064B}', CharMapAction::SubChar('F')),  // FATHATAN
+    ('\u{064C}', CharMapAction::SubChar('N')),  // DAMMATAN
+    ('\u{064D}', CharMapAction::SubChar('K')),  // KASRATAN
+    ('\u{064E}', CharMapAction::SubChar('a')),  // FATHA
+    ('\u{064F}', CharMapAction::SubChar('u')),  // DAMMA
+    ('\u{0650}', CharMapAction::SubChar('i')),  // KASRA
+    ('\u{0651}', CharMapAction::SubChar('~')),  // SHADDA
+    ('\u{0652}', CharMapAction::SubChar('o')),  // SUKUN
+    ('\u{0670}', CharMapAction::SubChar('`')),  // SUPERSCRIPT ALEF
+    ('\u{0671}', CharMapAction::SubChar('{')),  // ALEF WASLA
+    ('\u{067E}', CharMapAction::SubChar('P')),  // PEH
+    ('\u{0686}', CharMapAction::SubChar('J')),  // TCHEH
+    ('\u{06A4}', CharMapAction::SubChar('V')),  // VEH
+    ('\u{06AF}', CharMapAction::SubChar('G')),  // GAF
+];
+const _: () = assert_sorted(&ARABIC_TO_XML_BW);
+
+/// XML Buckwalter to Arabic transliteration map.
+pub static XML_BW_TO_ARABIC: [(char, CharMapAction); 51] = [
+    ('$', CharMapAction::SubChar('\u{0634}')), // SHEEN
+    ('\'', CharMapAction::SubChar('\u{0621}')), // HAMZA
+    ('*', CharMapAction::SubChar('\u{0630}')), // THAL
+    ('A', CharMapAction::SubChar('\u{0627}')), // ALEF
+    ('D', CharMapAction::SubChar('\u{0636}')), // DAD
+    ('E', CharMapAction::SubChar('\u{0639}')), // AIN
+    ('F', CharMapAction::SubChar('\u{064B}')), // FATHATAN
+    ('G', CharMapAction::SubChar('\u{06AF}')), // GAF
+    ('H', CharMapAction::SubChar('\u{062D}')), // HAH
+    ('I', CharMapAction::SubChar('\u{0625}')), // ALEF WITH HAMZA BELOW
+    ('J', CharMapAction::SubChar('\u{0686}')), // TCHEH
+    ('K', CharMapAction::SubChar('\u{064D}')), // KASRATAN
+    ('N', CharMapAction::SubChar('\u{064C}')), // DAMMATAN
+    ('O', CharMapAction::SubChar('\u{0623}')), // ALEF WITH HAMZA ABOVE
+    ('P', CharMapAction::SubChar('\u{067E}')), // PEH
+    ('S', CharMapAction::SubChar('\u{0635}')), // SAD
+    ('T', CharMapAction::SubChar('\u{0637}')), // TAH
+    ('V', CharMapAction::SubChar('\u{06A4}')), // VEH
+    ('W', CharMapAction::SubChar('\u{0624}')), // WAW WITH HAMZA ABOVE
+    ('Y', CharMapAction::SubChar('\u{0649}')), // ALEF MAKSURA
+    ('Z', CharMapAction::SubChar('\u{0638}')), // ZAH
+    ('_', CharMapAction::SubChar('\u{0640}')), // TATWEEL
+    ('`', CharMapAction::SubChar('\u{0670}')), // SUPERSCRIPT ALEF
+    ('a', CharMapAction::SubChar('\u{064E}')), // FATHA
+    ('b', CharMapAction::SubChar('\u{0628}')), // BEH
+    ('d', CharMapAction::SubChar('\u{062F}')), // DAL
+    ('f', CharMapAction::SubChar('\u{0641}')), // FEH
+    ('g', CharMapAction::SubChar('\u{063A}')), // GHAIN
+    ('h', CharMapAction::SubChar('\u{0647}')), // HEH
+    ('i', CharMapAction::SubChar('\u{0650}')), // KASRA
+    ('j', CharMapAction::SubChar('\u{062C}')), // JEEM
+    ('k', CharMapAction::SubChar('\u{0643}')), // KAF
+    ('l', CharMapAction::SubChar('\u{0644}')), // LAM
+    ('m', CharMapAction::SubChar('\u{0645}')), // MEEM
+    ('n', CharMapAction::SubChar('\u{0646}')), // NOON
+    ('o', CharMapAction::SubChar('\u{0652}')), // SUKUN
+    ('p', CharMapAction::SubChar('\u{0629}')), // TEH MARBUTA
+    ('q', CharMapAction::SubChar('\u{0642}')), // QAF
+    ('r', CharMapAction::SubChar('\u{0631}')), // REH
+    ('s', CharMapAction::SubChar('\u{0633}')), // SEEN
+    ('t', CharMapAction::SubChar('\u{062A}')), // TEH
+    ('u', CharMapAction::SubChar('\u{064F}')), // DAMMA
+    ('v', CharMapAction::SubChar('\u{062B}')), // THEH
+    ('w', CharMapAction::SubChar('\u{0648}')), // WAW
+    ('x', CharMapAction::SubChar('\u{062E}')), // KHAH
+    ('y', CharMapAction::SubChar('\u{064A}')), // YEH
+    ('z', CharMapAction::SubChar('\u{0632}')), // ZAIN
+    ('{', CharMapAction::SubChar('\u{0671}')), // ALEF WASLA
+    ('|', CharMapAction::SubChar('\u{0622}')), // ALEF WITH MADDA ABOVE
+    ('}', CharMapAction::SubChar('\u{0626}')), // YEH WITH HAMZA ABOVE
+    ('~', CharMapAction::SubChar('\u{0651}')), // SHADDA
+];
+const _: () = assert_sorted(&XML_BW_TO_ARABIC);
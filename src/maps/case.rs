@@ -0,0 +1,6681 @@
+//! Unicode case mapping maps.
+//!
+//! * [`LOWERCASE`], [`UPPERCASE`], and [`TITLECASE`] map characters to their
+//!   full case mappings, including multi-character mappings (e.g. ß `SS`, ŉ
+//!   `ʼN`, and İ `i̇`).
+//! * [`CASE_FOLD`] maps characters to their full case foldings (e.g. ß `ss`),
+//!   which should be used for caseless matching.
+//! * [`TURKIC_LOWERCASE`], [`TURKIC_UPPERCASE`], and [`TURKIC_CASE_FOLD`]
+//!   tailor the maps above for Turkish and Azeri (e.g. I `ı` and i `İ`), and
+//!   should be combined with them using pairs (e.g. `(&TURKIC_LOWERCASE,
+//!   &LOWERCASE)`).
+//!
+//! [`CaseMapper`] selects the right maps for a given [`Case`] and optional
+//! Turkic tailoring. Used as an [`ActionMap`], it maps each character
+//! independently, so rules that depend on the surrounding characters are not
+//! applied:
+//!
+//! * Final sigma: Σ is lowercased to ς at the end of a word.
+//! * Titlecasing: only the first letter of each word is titlecased, and the
+//!   rest of the word is lowercased. Words are runs of letters and digits,
+//!   which may contain case-ignorable characters (e.g. `they're`).
+//! * Turkic: I followed by U+0307 COMBINING DOT ABOVE is lowercased to i.
+//!
+//! [`CaseMapper::case_chars`] applies these rules as well.
+//!
+//! ```rust
+//! use charmap::maps::case::{Case, CaseMapper};
+//! use charmap::*;
+//!
+//! let upper = CaseMapper::new(Case::Upper);
+//! let mapper = CharMapper::new(&upper, CharMapAction::Pass);
+//!
+//! let mapped: String = "Straße".map_chars(&mapper).collect();
+//! assert_eq!(mapped, "STRASSE");
+//!
+//! let lower = CaseMapper::new(Case::Lower).turkic(true);
+//! let mapped: String = lower.case_chars("DİYARBAKIR".chars()).collect();
+//! assert_eq!(mapped, "diyarbakır");
+//!
+//! let title = CaseMapper::new(Case::Title);
+//! let mapped: String = title.case_chars("ΟΔΥΣΣΕΥΣ and ǆungla".chars()).collect();
+//! assert_eq!(mapped, "Οδυσσευς And ǅungla");
+//! ```
+//!
+//! This file is generated by `scripts/gen_case.py` from Unicode 14.0.0
+//! data. Do not edit it manually.
+
+use core::cmp::Ordering;
+use core::iter;
+use core::{option, str::Chars};
+
+use crate::actionmap::{assert_sorted, ActionMap, CharMapAction};
+
+/// Map from characters to their full lowercase mappings.
+#[rustfmt::skip]
+pub static LOWERCASE: [(char, CharMapAction); 1433] = [
+    ('\u{0041}', CharMapAction::SubChar('a')), // LATIN CAPITAL LETTER A
+    ('\u{0042}', CharMapAction::SubChar('b')), // LATIN CAPITAL LETTER B
+    ('\u{0043}', CharMapAction::SubChar('c')), // LATIN CAPITAL LETTER C
+    ('\u{0044}', CharMapAction::SubChar('d')), // LATIN CAPITAL LETTER D
+    ('\u{0045}', CharMapAction::SubChar('e')), // LATIN CAPITAL LETTER E
+    ('\u{0046}', CharMapAction::SubChar('f')), // LATIN CAPITAL LETTER F
+    ('\u{0047}', CharMapAction::SubChar('g')), // LATIN CAPITAL LETTER G
+    ('\u{0048}', CharMapAction::SubChar('h')), // LATIN CAPITAL LETTER H
+    ('\u{0049}', CharMapAction::SubChar('i')), // LATIN CAPITAL LETTER I
+    ('\u{004A}', CharMapAction::SubChar('j')), // LATIN CAPITAL LETTER J
+    ('\u{004B}', CharMapAction::SubChar('k')), // LATIN CAPITAL LETTER K
+    ('\u{004C}', CharMapAction::SubChar('l')), // LATIN CAPITAL LETTER L
+    ('\u{004D}', CharMapAction::SubChar('m')), // LATIN CAPITAL LETTER M
+    ('\u{004E}', CharMapAction::SubChar('n')), // LATIN CAPITAL LETTER N
+    ('\u{004F}', CharMapAction::SubChar('o')), // LATIN CAPITAL LETTER O
+    ('\u{0050}', CharMapAction::SubChar('p')), // LATIN CAPITAL LETTER P
+    ('\u{0051}', CharMapAction::SubChar('q')), // LATIN CAPITAL LETTER Q
+    ('\u{0052}', CharMapAction::SubChar('r')), // LATIN CAPITAL LETTER R
+    ('\u{0053}', CharMapAction::SubChar('s')), // LATIN CAPITAL LETTER S
+    ('\u{0054}', CharMapAction::SubChar('t')), // LATIN CAPITAL LETTER T
+    ('\u{0055}', CharMapAction::SubChar('u')), // LATIN CAPITAL LETTER U
+    ('\u{0056}', CharMapAction::SubChar('v')), // LATIN CAPITAL LETTER V
+    ('\u{0057}', CharMapAction::SubChar('w')), // LATIN CAPITAL LETTER W
+    ('\u{0058}', CharMapAction::SubChar('x')), // LATIN CAPITAL LETTER X
+    ('\u{0059}', CharMapAction::SubChar('y')), // LATIN CAPITAL LETTER Y
+    ('\u{005A}', CharMapAction::SubChar('z')), // LATIN CAPITAL LETTER Z
+    ('\u{00C0}', CharMapAction::SubChar('à')), // LATIN CAPITAL LETTER A WITH GRAVE
+    ('\u{00C1}', CharMapAction::SubChar('á')), // LATIN CAPITAL LETTER A WITH ACUTE
+    ('\u{00C2}', CharMapAction::SubChar('â')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX
+    ('\u{00C3}', CharMapAction::SubChar('ã')), // LATIN CAPITAL LETTER A WITH TILDE
+    ('\u{00C4}', CharMapAction::SubChar('ä')), // LATIN CAPITAL LETTER A WITH DIAERESIS
+    ('\u{00C5}', CharMapAction::SubChar('å')), // LATIN CAPITAL LETTER A WITH RING ABOVE
+    ('\u{00C6}', CharMapAction::SubChar('æ')), // LATIN CAPITAL LETTER AE
+    ('\u{00C7}', CharMapAction::SubChar('ç')), // LATIN CAPITAL LETTER C WITH CEDILLA
+    ('\u{00C8}', CharMapAction::SubChar('è')), // LATIN CAPITAL LETTER E WITH GRAVE
+    ('\u{00C9}', CharMapAction::SubChar('é')), // LATIN CAPITAL LETTER E WITH ACUTE
+    ('\u{00CA}', CharMapAction::SubChar('ê')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX
+    ('\u{00CB}', CharMapAction::SubChar('ë')), // LATIN CAPITAL LETTER E WITH DIAERESIS
+    ('\u{00CC}', CharMapAction::SubChar('ì')), // LATIN CAPITAL LETTER I WITH GRAVE
+    ('\u{00CD}', CharMapAction::SubChar('í')), // LATIN CAPITAL LETTER I WITH ACUTE
+    ('\u{00CE}', CharMapAction::SubChar('î')), // LATIN CAPITAL LETTER I WITH CIRCUMFLEX
+    ('\u{00CF}', CharMapAction::SubChar('ï')), // LATIN CAPITAL LETTER I WITH DIAERESIS
+    ('\u{00D0}', CharMapAction::SubChar('ð')), // LATIN CAPITAL LETTER ETH
+    ('\u{00D1}', CharMapAction::SubChar('ñ')), // LATIN CAPITAL LETTER N WITH TILDE
+    ('\u{00D2}', CharMapAction::SubChar('ò')), // LATIN CAPITAL LETTER O WITH GRAVE
+    ('\u{00D3}', CharMapAction::SubChar('ó')), // LATIN CAPITAL LETTER O WITH ACUTE
+    ('\u{00D4}', CharMapAction::SubChar('ô')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX
+    ('\u{00D5}', CharMapAction::SubChar('õ')), // LATIN CAPITAL LETTER O WITH TILDE
+    ('\u{00D6}', CharMapAction::SubChar('ö')), // LATIN CAPITAL LETTER O WITH DIAERESIS
+    ('\u{00D8}', CharMapAction::SubChar('ø')), // LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00D9}', CharMapAction::SubChar('ù')), // LATIN CAPITAL LETTER U WITH GRAVE
+    ('\u{00DA}', CharMapAction::SubChar('ú')), // LATIN CAPITAL LETTER U WITH ACUTE
+    ('\u{00DB}', CharMapAction::SubChar('û')), // LATIN CAPITAL LETTER U WITH CIRCUMFLEX
+    ('\u{00DC}', CharMapAction::SubChar('ü')), // LATIN CAPITAL LETTER U WITH DIAERESIS
+    ('\u{00DD}', CharMapAction::SubChar('ý')), // LATIN CAPITAL LETTER Y WITH ACUTE
+    ('\u{00DE}', CharMapAction::SubChar('þ')), // LATIN CAPITAL LETTER THORN
+    ('\u{0100}', CharMapAction::SubChar('ā')), // LATIN CAPITAL LETTER A WITH MACRON
+    ('\u{0102}', CharMapAction::SubChar('ă')), // LATIN CAPITAL LETTER A WITH BREVE
+    ('\u{0104}', CharMapAction::SubChar('ą')), // LATIN CAPITAL LETTER A WITH OGONEK
+    ('\u{0106}', CharMapAction::SubChar('ć')), // LATIN CAPITAL LETTER C WITH ACUTE
+    ('\u{0108}', CharMapAction::SubChar('ĉ')), // LATIN CAPITAL LETTER C WITH CIRCUMFLEX
+    ('\u{010A}', CharMapAction::SubChar('ċ')), // LATIN CAPITAL LETTER C WITH DOT ABOVE
+    ('\u{010C}', CharMapAction::SubChar('č')), // LATIN CAPITAL LETTER C WITH CARON
+    ('\u{010E}', CharMapAction::SubChar('ď')), // LATIN CAPITAL LETTER D WITH CARON
+    ('\u{0110}', CharMapAction::SubChar('đ')), // LATIN CAPITAL LETTER D WITH STROKE
+    ('\u{0112}', CharMapAction::SubChar('ē')), // LATIN CAPITAL LETTER E WITH MACRON
+    ('\u{0114}', CharMapAction::SubChar('ĕ')), // LATIN CAPITAL LETTER E WITH BREVE
+    ('\u{0116}', CharMapAction::SubChar('ė')), // LATIN CAPITAL LETTER E WITH DOT ABOVE
+    ('\u{0118}', CharMapAction::SubChar('ę')), // LATIN CAPITAL LETTER E WITH OGONEK
+    ('\u{011A}', CharMapAction::SubChar('ě')), // LATIN CAPITAL LETTER E WITH CARON
+    ('\u{011C}', CharMapAction::SubChar('ĝ')), // LATIN CAPITAL LETTER G WITH CIRCUMFLEX
+    ('\u{011E}', CharMapAction::SubChar('ğ')), // LATIN CAPITAL LETTER G WITH BREVE
+    ('\u{0120}', CharMapAction::SubChar('ġ')), // LATIN CAPITAL LETTER G WITH DOT ABOVE
+    ('\u{0122}', CharMapAction::SubChar('ģ')), // LATIN CAPITAL LETTER G WITH CEDILLA
+    ('\u{0124}', CharMapAction::SubChar('ĥ')), // LATIN CAPITAL LETTER H WITH CIRCUMFLEX
+    ('\u{0126}', CharMapAction::SubChar('ħ')), // LATIN CAPITAL LETTER H WITH STROKE
+    ('\u{0128}', CharMapAction::SubChar('ĩ')), // LATIN CAPITAL LETTER I WITH TILDE
+    ('\u{012A}', CharMapAction::SubChar('ī')), // LATIN CAPITAL LETTER I WITH MACRON
+    ('\u{012C}', CharMapAction::SubChar('ĭ')), // LATIN CAPITAL LETTER I WITH BREVE
+    ('\u{012E}', CharMapAction::SubChar('į')), // LATIN CAPITAL LETTER I WITH OGONEK
+    ('\u{0130}', CharMapAction::SubStr("i\u{0307}")), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+    ('\u{0132}', CharMapAction::SubChar('ĳ')), // LATIN CAPITAL LIGATURE IJ
+    ('\u{0134}', CharMapAction::SubChar('ĵ')), // LATIN CAPITAL LETTER J WITH CIRCUMFLEX
+    ('\u{0136}', CharMapAction::SubChar('ķ')), // LATIN CAPITAL LETTER K WITH CEDILLA
+    ('\u{0139}', CharMapAction::SubChar('ĺ')), // LATIN CAPITAL LETTER L WITH ACUTE
+    ('\u{013B}', CharMapAction::SubChar('ļ')), // LATIN CAPITAL LETTER L WITH CEDILLA
+    ('\u{013D}', CharMapAction::SubChar('ľ')), // LATIN CAPITAL LETTER L WITH CARON
+    ('\u{013F}', CharMapAction::SubChar('ŀ')), // LATIN CAPITAL LETTER L WITH MIDDLE DOT
+    ('\u{0141}', CharMapAction::SubChar('ł')), // LATIN CAPITAL LETTER L WITH STROKE
+    ('\u{0143}', CharMapAction::SubChar('ń')), // LATIN CAPITAL LETTER N WITH ACUTE
+    ('\u{0145}', CharMapAction::SubChar('ņ')), // LATIN CAPITAL LETTER N WITH CEDILLA
+    ('\u{0147}', CharMapAction::SubChar('ň')), // LATIN CAPITAL LETTER N WITH CARON
+    ('\u{014A}', CharMapAction::SubChar('ŋ')), // LATIN CAPITAL LETTER ENG
+    ('\u{014C}', CharMapAction::SubChar('ō')), // LATIN CAPITAL LETTER O WITH MACRON
+    ('\u{014E}', CharMapAction::SubChar('ŏ')), // LATIN CAPITAL LETTER O WITH BREVE
+    ('\u{0150}', CharMapAction::SubChar('ő')), // LATIN CAPITAL LETTER O WITH DOUBLE ACUTE
+    ('\u{0152}', CharMapAction::SubChar('œ')), // LATIN CAPITAL LIGATURE OE
+    ('\u{0154}', CharMapAction::SubChar('ŕ')), // LATIN CAPITAL LETTER R WITH ACUTE
+    ('\u{0156}', CharMapAction::SubChar('ŗ')), // LATIN CAPITAL LETTER R WITH CEDILLA
+    ('\u{0158}', CharMapAction::SubChar('ř')), // LATIN CAPITAL LETTER R WITH CARON
+    ('\u{015A}', CharMapAction::SubChar('ś')), // LATIN CAPITAL LETTER S WITH ACUTE
+    ('\u{015C}', CharMapAction::SubChar('ŝ')), // LATIN CAPITAL LETTER S WITH CIRCUMFLEX
+    ('\u{015E}', CharMapAction::SubChar('ş')), // LATIN CAPITAL LETTER S WITH CEDILLA
+    ('\u{0160}', CharMapAction::SubChar('š')), // LATIN CAPITAL LETTER S WITH CARON
+    ('\u{0162}', CharMapAction::SubChar('ţ')), // LATIN CAPITAL LETTER T WITH CEDILLA
+    ('\u{0164}', CharMapAction::SubChar('ť')), // LATIN CAPITAL LETTER T WITH CARON
+    ('\u{0166}', CharMapAction::SubChar('ŧ')), // LATIN CAPITAL LETTER T WITH STROKE
+    ('\u{0168}', CharMapAction::SubChar('ũ')), // LATIN CAPITAL LETTER U WITH TILDE
+    ('\u{016A}', CharMapAction::SubChar('ū')), // LATIN CAPITAL LETTER U WITH MACRON
+    ('\u{016C}', CharMapAction::SubChar('ŭ')), // LATIN CAPITAL LETTER U WITH BREVE
+    ('\u{016E}', CharMapAction::SubChar('ů')), // LATIN CAPITAL LETTER U WITH RING ABOVE
+    ('\u{0170}', CharMapAction::SubChar('ű')), // LATIN CAPITAL LETTER U WITH DOUBLE ACUTE
+    ('\u{0172}', CharMapAction::SubChar('ų')), // LATIN CAPITAL LETTER U WITH OGONEK
+    ('\u{0174}', CharMapAction::SubChar('ŵ')), // LATIN CAPITAL LETTER W WITH CIRCUMFLEX
+    ('\u{0176}', CharMapAction::SubChar('ŷ')), // LATIN CAPITAL LETTER Y WITH CIRCUMFLEX
+    ('\u{0178}', CharMapAction::SubChar('ÿ')), // LATIN CAPITAL LETTER Y WITH DIAERESIS
+    ('\u{0179}', CharMapAction::SubChar('ź')), // LATIN CAPITAL LETTER Z WITH ACUTE
+    ('\u{017B}', CharMapAction::SubChar('ż')), // LATIN CAPITAL LETTER Z WITH DOT ABOVE
+    ('\u{017D}', CharMapAction::SubChar('ž')), // LATIN CAPITAL LETTER Z WITH CARON
+    ('\u{0181}', CharMapAction::SubChar('ɓ')), // LATIN CAPITAL LETTER B WITH HOOK
+    ('\u{0182}', CharMapAction::SubChar('ƃ')), // LATIN CAPITAL LETTER B WITH TOPBAR
+    ('\u{0184}', CharMapAction::SubChar('ƅ')), // LATIN CAPITAL LETTER TONE SIX
+    ('\u{0186}', CharMapAction::SubChar('ɔ')), // LATIN CAPITAL LETTER OPEN O
+    ('\u{0187}', CharMapAction::SubChar('ƈ')), // LATIN CAPITAL LETTER C WITH HOOK
+    ('\u{0189}', CharMapAction::SubChar('ɖ')), // LATIN CAPITAL LETTER AFRICAN D
+    ('\u{018A}', CharMapAction::SubChar('ɗ')), // LATIN CAPITAL LETTER D WITH HOOK
+    ('\u{018B}', CharMapAction::SubChar('ƌ')), // LATIN CAPITAL LETTER D WITH TOPBAR
+    ('\u{018E}', CharMapAction::SubChar('ǝ')), // LATIN CAPITAL LETTER REVERSED E
+    ('\u{018F}', CharMapAction::SubChar('ə')), // LATIN CAPITAL LETTER SCHWA
+    ('\u{0190}', CharMapAction::SubChar('ɛ')), // LATIN CAPITAL LETTER OPEN E
+    ('\u{0191}', CharMapAction::SubChar('ƒ')), // LATIN CAPITAL LETTER F WITH HOOK
+    ('\u{0193}', CharMapAction::SubChar('ɠ')), // LATIN CAPITAL LETTER G WITH HOOK
+    ('\u{0194}', CharMapAction::SubChar('ɣ')), // LATIN CAPITAL LETTER GAMMA
+    ('\u{0196}', CharMapAction::SubChar('ɩ')), // LATIN CAPITAL LETTER IOTA
+    ('\u{0197}', CharMapAction::SubChar('ɨ')), // LATIN CAPITAL LETTER I WITH STROKE
+    ('\u{0198}', CharMapAction::SubChar('ƙ')), // LATIN CAPITAL LETTER K WITH HOOK
+    ('\u{019C}', CharMapAction::SubChar('ɯ')), // LATIN CAPITAL LETTER TURNED M
+    ('\u{019D}', CharMapAction::SubChar('ɲ')), // LATIN CAPITAL LETTER N WITH LEFT HOOK
+    ('\u{019F}', CharMapAction::SubChar('ɵ')), // LATIN CAPITAL LETTER O WITH MIDDLE TILDE
+    ('\u{01A0}', CharMapAction::SubChar('ơ')), // LATIN CAPITAL LETTER O WITH HORN
+    ('\u{01A2}', CharMapAction::SubChar('ƣ')), // LATIN CAPITAL LETTER OI
+    ('\u{01A4}', CharMapAction::SubChar('ƥ')), // LATIN CAPITAL LETTER P WITH HOOK
+    ('\u{01A6}', CharMapAction::SubChar('ʀ')), // LATIN LETTER YR
+    ('\u{01A7}', CharMapAction::SubChar('ƨ')), // LATIN CAPITAL LETTER TONE TWO
+    ('\u{01A9}', CharMapAction::SubChar('ʃ')), // LATIN CAPITAL LETTER ESH
+    ('\u{01AC}', CharMapAction::SubChar('ƭ')), // LATIN CAPITAL LETTER T WITH HOOK
+    ('\u{01AE}', CharMapAction::SubChar('ʈ')), // LATIN CAPITAL LETTER T WITH RETROFLEX HOOK
+    ('\u{01AF}', CharMapAction::SubChar('ư')), // LATIN CAPITAL LETTER U WITH HORN
+    ('\u{01B1}', CharMapAction::SubChar('ʊ')), // LATIN CAPITAL LETTER UPSILON
+    ('\u{01B2}', CharMapAction::SubChar('ʋ')), // LATIN CAPITAL LETTER V WITH HOOK
+    ('\u{01B3}', CharMapAction::SubChar('ƴ')), // LATIN CAPITAL LETTER Y WITH HOOK
+    ('\u{01B5}', CharMapAction::SubChar('ƶ')), // LATIN CAPITAL LETTER Z WITH STROKE
+    ('\u{01B7}', CharMapAction::SubChar('ʒ')), // LATIN CAPITAL LETTER EZH
+    ('\u{01B8}', CharMapAction::SubChar('ƹ')), // LATIN CAPITAL LETTER EZH REVERSED
+    ('\u{01BC}', CharMapAction::SubChar('ƽ')), // LATIN CAPITAL LETTER TONE FIVE
+    ('\u{01C4}', CharMapAction::SubChar('ǆ')), // LATIN CAPITAL LETTER DZ WITH CARON
+    ('\u{01C5}', CharMapAction::SubChar('ǆ')), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON
+    ('\u{01C7}', CharMapAction::SubChar('ǉ')), // LATIN CAPITAL LETTER LJ
+    ('\u{01C8}', CharMapAction::SubChar('ǉ')), // LATIN CAPITAL LETTER L WITH SMALL LETTER J
+    ('\u{01CA}', CharMapAction::SubChar('ǌ')), // LATIN CAPITAL LETTER NJ
+    ('\u{01CB}', CharMapAction::SubChar('ǌ')), // LATIN CAPITAL LETTER N WITH SMALL LETTER J
+    ('\u{01CD}', CharMapAction::SubChar('ǎ')), // LATIN CAPITAL LETTER A WITH CARON
+    ('\u{01CF}', CharMapAction::SubChar('ǐ')), // LATIN CAPITAL LETTER I WITH CARON
+    ('\u{01D1}', CharMapAction::SubChar('ǒ')), // LATIN CAPITAL LETTER O WITH CARON
+    ('\u{01D3}', CharMapAction::SubChar('ǔ')), // LATIN CAPITAL LETTER U WITH CARON
+    ('\u{01D5}', CharMapAction::SubChar('ǖ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON
+    ('\u{01D7}', CharMapAction::SubChar('ǘ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE
+    ('\u{01D9}', CharMapAction::SubChar('ǚ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON
+    ('\u{01DB}', CharMapAction::SubChar('ǜ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE
+    ('\u{01DE}', CharMapAction::SubChar('ǟ')), // LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON
+    ('\u{01E0}', CharMapAction::SubChar('ǡ')), // LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON
+    ('\u{01E2}', CharMapAction::SubChar('ǣ')), // LATIN CAPITAL LETTER AE WITH MACRON
+    ('\u{01E4}', CharMapAction::SubChar('ǥ')), // LATIN CAPITAL LETTER G WITH STROKE
+    ('\u{01E6}', CharMapAction::SubChar('ǧ')), // LATIN CAPITAL LETTER G WITH CARON
+    ('\u{01E8}', CharMapAction::SubChar('ǩ')), // LATIN CAPITAL LETTER K WITH CARON
+    ('\u{01EA}', CharMapAction::SubChar('ǫ')), // LATIN CAPITAL LETTER O WITH OGONEK
+    ('\u{01EC}', CharMapAction::SubChar('ǭ')), // LATIN CAPITAL LETTER O WITH OGONEK AND MACRON
+    ('\u{01EE}', CharMapAction::SubChar('ǯ')), // LATIN CAPITAL LETTER EZH WITH CARON
+    ('\u{01F1}', CharMapAction::SubChar('ǳ')), // LATIN CAPITAL LETTER DZ
+    ('\u{01F2}', CharMapAction::SubChar('ǳ')), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z
+    ('\u{01F4}', CharMapAction::SubChar('ǵ')), // LATIN CAPITAL LETTER G WITH ACUTE
+    ('\u{01F6}', CharMapAction::SubChar('ƕ')), // LATIN CAPITAL LETTER HWAIR
+    ('\u{01F7}', CharMapAction::SubChar('ƿ')), // LATIN CAPITAL LETTER WYNN
+    ('\u{01F8}', CharMapAction::SubChar('ǹ')), // LATIN CAPITAL LETTER N WITH GRAVE
+    ('\u{01FA}', CharMapAction::SubChar('ǻ')), // LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE
+    ('\u{01FC}', CharMapAction::SubChar('ǽ')), // LATIN CAPITAL LETTER AE WITH ACUTE
+    ('\u{01FE}', CharMapAction::SubChar('ǿ')), // LATIN CAPITAL LETTER O WITH STROKE AND ACUTE
+    ('\u{0200}', CharMapAction::SubChar('ȁ')), // LATIN CAPITAL LETTER A WITH DOUBLE GRAVE
+    ('\u{0202}', CharMapAction::SubChar('ȃ')), // LATIN CAPITAL LETTER A WITH INVERTED BREVE
+    ('\u{0204}', CharMapAction::SubChar('ȅ')), // LATIN CAPITAL LETTER E WITH DOUBLE GRAVE
+    ('\u{0206}', CharMapAction::SubChar('ȇ')), // LATIN CAPITAL LETTER E WITH INVERTED BREVE
+    ('\u{0208}', CharMapAction::SubChar('ȉ')), // LATIN CAPITAL LETTER I WITH DOUBLE GRAVE
+    ('\u{020A}', CharMapAction::SubChar('ȋ')), // LATIN CAPITAL LETTER I WITH INVERTED BREVE
+    ('\u{020C}', CharMapAction::SubChar('ȍ')), // LATIN CAPITAL LETTER O WITH DOUBLE GRAVE
+    ('\u{020E}', CharMapAction::SubChar('ȏ')), // LATIN CAPITAL LETTER O WITH INVERTED BREVE
+    ('\u{0210}', CharMapAction::SubChar('ȑ')), // LATIN CAPITAL LETTER R WITH DOUBLE GRAVE
+    ('\u{0212}', CharMapAction::SubChar('ȓ')), // LATIN CAPITAL LETTER R WITH INVERTED BREVE
+    ('\u{0214}', CharMapAction::SubChar('ȕ')), // LATIN CAPITAL LETTER U WITH DOUBLE GRAVE
+    ('\u{0216}', CharMapAction::SubChar('ȗ')), // LATIN CAPITAL LETTER U WITH INVERTED BREVE
+    ('\u{0218}', CharMapAction::SubChar('ș')), // LATIN CAPITAL LETTER S WITH COMMA BELOW
+    ('\u{021A}', CharMapAction::SubChar('ț')), // LATIN CAPITAL LETTER T WITH COMMA BELOW
+    ('\u{021C}', CharMapAction::SubChar('ȝ')), // LATIN CAPITAL LETTER YOGH
+    ('\u{021E}', CharMapAction::SubChar('ȟ')), // LATIN CAPITAL LETTER H WITH CARON
+    ('\u{0220}', CharMapAction::SubChar('ƞ')), // LATIN CAPITAL LETTER N WITH LONG RIGHT LEG
+    ('\u{0222}', CharMapAction::SubChar('ȣ')), // LATIN CAPITAL LETTER OU
+    ('\u{0224}', CharMapAction::SubChar('ȥ')), // LATIN CAPITAL LETTER Z WITH HOOK
+    ('\u{0226}', CharMapAction::SubChar('ȧ')), // LATIN CAPITAL LETTER A WITH DOT ABOVE
+    ('\u{0228}', CharMapAction::SubChar('ȩ')), // LATIN CAPITAL LETTER E WITH CEDILLA
+    ('\u{022A}', CharMapAction::SubChar('ȫ')), // LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON
+    ('\u{022C}', CharMapAction::SubChar('ȭ')), // LATIN CAPITAL LETTER O WITH TILDE AND MACRON
+    ('\u{022E}', CharMapAction::SubChar('ȯ')), // LATIN CAPITAL LETTER O WITH DOT ABOVE
+    ('\u{0230}', CharMapAction::SubChar('ȱ')), // LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON
+    ('\u{0232}', CharMapAction::SubChar('ȳ')), // LATIN CAPITAL LETTER Y WITH MACRON
+    ('\u{023A}', CharMapAction::SubChar('ⱥ')), // LATIN CAPITAL LETTER A WITH STROKE
+    ('\u{023B}', CharMapAction::SubChar('ȼ')), // LATIN CAPITAL LETTER C WITH STROKE
+    ('\u{023D}', CharMapAction::SubChar('ƚ')), // LATIN CAPITAL LETTER L WITH BAR
+    ('\u{023E}', CharMapAction::SubChar('ⱦ')), // LATIN CAPITAL LETTER T WITH DIAGONAL STROKE
+    ('\u{0241}', CharMapAction::SubChar('ɂ')), // LATIN CAPITAL LETTER GLOTTAL STOP
+    ('\u{0243}', CharMapAction::SubChar('ƀ')), // LATIN CAPITAL LETTER B WITH STROKE
+    ('\u{0244}', CharMapAction::SubChar('ʉ')), // LATIN CAPITAL LETTER U BAR
+    ('\u{0245}', CharMapAction::SubChar('ʌ')), // LATIN CAPITAL LETTER TURNED V
+    ('\u{0246}', CharMapAction::SubChar('ɇ')), // LATIN CAPITAL LETTER E WITH STROKE
+    ('\u{0248}', CharMapAction::SubChar('ɉ')), // LATIN CAPITAL LETTER J WITH STROKE
+    ('\u{024A}', CharMapAction::SubChar('ɋ')), // LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL
+    ('\u{024C}', CharMapAction::SubChar('ɍ')), // LATIN CAPITAL LETTER R WITH STROKE
+    ('\u{024E}', CharMapAction::SubChar('ɏ')), // LATIN CAPITAL LETTER Y WITH STROKE
+    ('\u{0370}', CharMapAction::SubChar('ͱ')), // GREEK CAPITAL LETTER HETA
+    ('\u{0372}', CharMapAction::SubChar('ͳ')), // GREEK CAPITAL LETTER ARCHAIC SAMPI
+    ('\u{0376}', CharMapAction::SubChar('ͷ')), // GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA
+    ('\u{037F}', CharMapAction::SubChar('ϳ')), // GREEK CAPITAL LETTER YOT
+    ('\u{0386}', CharMapAction::SubChar('ά')), // GREEK CAPITAL LETTER ALPHA WITH TONOS
+    ('\u{0388}', CharMapAction::SubChar('έ')), // GREEK CAPITAL LETTER EPSILON WITH TONOS
+    ('\u{0389}', CharMapAction::SubChar('ή')), // GREEK CAPITAL LETTER ETA WITH TONOS
+    ('\u{038A}', CharMapAction::SubChar('ί')), // GREEK CAPITAL LETTER IOTA WITH TONOS
+    ('\u{038C}', CharMapAction::SubChar('ό')), // GREEK CAPITAL LETTER OMICRON WITH TONOS
+    ('\u{038E}', CharMapAction::SubChar('ύ')), // GREEK CAPITAL LETTER UPSILON WITH TONOS
+    ('\u{038F}', CharMapAction::SubChar('ώ')), // GREEK CAPITAL LETTER OMEGA WITH TONOS
+    ('\u{0391}', CharMapAction::SubChar('α')), // GREEK CAPITAL LETTER ALPHA
+    ('\u{0392}', CharMapAction::SubChar('β')), // GREEK CAPITAL LETTER BETA
+    ('\u{0393}', CharMapAction::SubChar('γ')), // GREEK CAPITAL LETTER GAMMA
+    ('\u{0394}', CharMapAction::SubChar('δ')), // GREEK CAPITAL LETTER DELTA
+    ('\u{0395}', CharMapAction::SubChar('ε')), // GREEK CAPITAL LETTER EPSILON
+    ('\u{0396}', CharMapAction::SubChar('ζ')), // GREEK CAPITAL LETTER ZETA
+    ('\u{0397}', CharMapAction::SubChar('η')), // GREEK CAPITAL LETTER ETA
+    ('\u{0398}', CharMapAction::SubChar('θ')), // GREEK CAPITAL LETTER THETA
+    ('\u{0399}', CharMapAction::SubChar('ι')), // GREEK CAPITAL LETTER IOTA
+    ('\u{039A}', CharMapAction::SubChar('κ')), // GREEK CAPITAL LETTER KAPPA
+    ('\u{039B}', CharMapAction::SubChar('λ')), // GREEK CAPITAL LETTER LAMDA
+    ('\u{039C}', CharMapAction::SubChar('μ')), // GREEK CAPITAL LETTER MU
+    ('\u{039D}', CharMapAction::SubChar('ν')), // GREEK CAPITAL LETTER NU
+    ('\u{039E}', CharMapAction::SubChar('ξ')), // GREEK CAPITAL LETTER XI
+    ('\u{039F}', CharMapAction::SubChar('ο')), // GREEK CAPITAL LETTER OMICRON
+    ('\u{03A0}', CharMapAction::SubChar('π')), // GREEK CAPITAL LETTER PI
+    ('\u{03A1}', CharMapAction::SubChar('ρ')), // GREEK CAPITAL LETTER RHO
+    ('\u{03A3}', CharMapAction::SubChar('σ')), // GREEK CAPITAL LETTER SIGMA
+    ('\u{03A4}', CharMapAction::SubChar('τ')), // GREEK CAPITAL LETTER TAU
+    ('\u{03A5}', CharMapAction::SubChar('υ')), // GREEK CAPITAL LETTER UPSILON
+    ('\u{03A6}', CharMapAction::SubChar('φ')), // GREEK CAPITAL LETTER PHI
+    ('\u{03A7}', CharMapAction::SubChar('χ')), // GREEK CAPITAL LETTER CHI
+    ('\u{03A8}', CharMapAction::SubChar('ψ')), // GREEK CAPITAL LETTER PSI
+    ('\u{03A9}', CharMapAction::SubChar('ω')), // GREEK CAPITAL LETTER OMEGA
+    ('\u{03AA}', CharMapAction::SubChar('ϊ')), // GREEK CAPITAL LETTER IOTA WITH DIALYTIKA
+    ('\u{03AB}', CharMapAction::SubChar('ϋ')), // GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03CF}', CharMapAction::SubChar('ϗ')), // GREEK CAPITAL KAI SYMBOL
+    ('\u{03D8}', CharMapAction::SubChar('ϙ')), // GREEK LETTER ARCHAIC KOPPA
+    ('\u{03DA}', CharMapAction::SubChar('ϛ')), // GREEK LETTER STIGMA
+    ('\u{03DC}', CharMapAction::SubChar('ϝ')), // GREEK LETTER DIGAMMA
+    ('\u{03DE}', CharMapAction::SubChar('ϟ')), // GREEK LETTER KOPPA
+    ('\u{03E0}', CharMapAction::SubChar('ϡ')), // GREEK LETTER SAMPI
+    ('\u{03E2}', CharMapAction::SubChar('ϣ')), // COPTIC CAPITAL LETTER SHEI
+    ('\u{03E4}', CharMapAction::SubChar('ϥ')), // COPTIC CAPITAL LETTER FEI
+    ('\u{03E6}', CharMapAction::SubChar('ϧ')), // COPTIC CAPITAL LETTER KHEI
+    ('\u{03E8}', CharMapAction::SubChar('ϩ')), // COPTIC CAPITAL LETTER HORI
+    ('\u{03EA}', CharMapAction::SubChar('ϫ')), // COPTIC CAPITAL LETTER GANGIA
+    ('\u{03EC}', CharMapAction::SubChar('ϭ')), // COPTIC CAPITAL LETTER SHIMA
+    ('\u{03EE}', CharMapAction::SubChar('ϯ')), // COPTIC CAPITAL LETTER DEI
+    ('\u{03F4}', CharMapAction::SubChar('θ')), // GREEK CAPITAL THETA SYMBOL
+    ('\u{03F7}', CharMapAction::SubChar('ϸ')), // GREEK CAPITAL LETTER SHO
+    ('\u{03F9}', CharMapAction::SubChar('ϲ')), // GREEK CAPITAL LUNATE SIGMA SYMBOL
+    ('\u{03FA}', CharMapAction::SubChar('ϻ')), // GREEK CAPITAL LETTER SAN
+    ('\u{03FD}', CharMapAction::SubChar('ͻ')), // GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL
+    ('\u{03FE}', CharMapAction::SubChar('ͼ')), // GREEK CAPITAL DOTTED LUNATE SIGMA SYMBOL
+    ('\u{03FF}', CharMapAction::SubChar('ͽ')), // GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL
+    ('\u{0400}', CharMapAction::SubChar('ѐ')), // CYRILLIC CAPITAL LETTER IE WITH GRAVE
+    ('\u{0401}', CharMapAction::SubChar('ё')), // CYRILLIC CAPITAL LETTER IO
+    ('\u{0402}', CharMapAction::SubChar('ђ')), // CYRILLIC CAPITAL LETTER DJE
+    ('\u{0403}', CharMapAction::SubChar('ѓ')), // CYRILLIC CAPITAL LETTER GJE
+    ('\u{0404}', CharMapAction::SubChar('є')), // CYRILLIC CAPITAL LETTER UKRAINIAN IE
+    ('\u{0405}', CharMapAction::SubChar('ѕ')), // CYRILLIC CAPITAL LETTER DZE
+    ('\u{0406}', CharMapAction::SubChar('і')), // CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0407}', CharMapAction::SubChar('ї')), // CYRILLIC CAPITAL LETTER YI
+    ('\u{0408}', CharMapAction::SubChar('ј')), // CYRILLIC CAPITAL LETTER JE
+    ('\u{0409}', CharMapAction::SubChar('љ')), // CYRILLIC CAPITAL LETTER LJE
+    ('\u{040A}', CharMapAction::SubChar('њ')), // CYRILLIC CAPITAL LETTER NJE
+    ('\u{040B}', CharMapAction::SubChar('ћ')), // CYRILLIC CAPITAL LETTER TSHE
+    ('\u{040C}', CharMapAction::SubChar('ќ')), // CYRILLIC CAPITAL LETTER KJE
+    ('\u{040D}', CharMapAction::SubChar('ѝ')), // CYRILLIC CAPITAL LETTER I WITH GRAVE
+    ('\u{040E}', CharMapAction::SubChar('ў')), // CYRILLIC CAPITAL LETTER SHORT U
+    ('\u{040F}', CharMapAction::SubChar('џ')), // CYRILLIC CAPITAL LETTER DZHE
+    ('\u{0410}', CharMapAction::SubChar('а')), // CYRILLIC CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('б')), // CYRILLIC CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('в')), // CYRILLIC CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('г')), // CYRILLIC CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('д')), // CYRILLIC CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('е')), // CYRILLIC CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubChar('ж')), // CYRILLIC CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('з')), // CYRILLIC CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('и')), // CYRILLIC CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('й')), // CYRILLIC CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('к')), // CYRILLIC CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('л')), // CYRILLIC CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('м')), // CYRILLIC CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('н')), // CYRILLIC CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('о')), // CYRILLIC CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('п')), // CYRILLIC CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('р')), // CYRILLIC CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('с')), // CYRILLIC CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('т')), // CYRILLIC CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('у')), // CYRILLIC CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('ф')), // CYRILLIC CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('х')), // CYRILLIC CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubChar('ц')), // CYRILLIC CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubChar('ч')), // CYRILLIC CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubChar('ш')), // CYRILLIC CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubChar('щ')), // CYRILLIC CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubChar('ъ')), // CYRILLIC CAPITAL LETTER HARD SIGN
+    ('\u{042B}', CharMapAction::SubChar('ы')), // CYRILLIC CAPITAL LETTER YERU
+    ('\u{042C}', CharMapAction::SubChar('ь')), // CYRILLIC CAPITAL LETTER SOFT SIGN
+    ('\u{042D}', CharMapAction::SubChar('э')), // CYRILLIC CAPITAL LETTER E
+    ('\u{042E}', CharMapAction::SubChar('ю')), // CYRILLIC CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubChar('я')), // CYRILLIC CAPITAL LETTER YA
+    ('\u{0460}', CharMapAction::SubChar('ѡ')), // CYRILLIC CAPITAL LETTER OMEGA
+    ('\u{0462}', CharMapAction::SubChar('ѣ')), // CYRILLIC CAPITAL LETTER YAT
+    ('\u{0464}', CharMapAction::SubChar('ѥ')), // CYRILLIC CAPITAL LETTER IOTIFIED E
+    ('\u{0466}', CharMapAction::SubChar('ѧ')), // CYRILLIC CAPITAL LETTER LITTLE YUS
+    ('\u{0468}', CharMapAction::SubChar('ѩ')), // CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS
+    ('\u{046A}', CharMapAction::SubChar('ѫ')), // CYRILLIC CAPITAL LETTER BIG YUS
+    ('\u{046C}', CharMapAction::SubChar('ѭ')), // CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS
+    ('\u{046E}', CharMapAction::SubChar('ѯ')), // CYRILLIC CAPITAL LETTER KSI
+    ('\u{0470}', CharMapAction::SubChar('ѱ')), // CYRILLIC CAPITAL LETTER PSI
+    ('\u{0472}', CharMapAction::SubChar('ѳ')), // CYRILLIC CAPITAL LETTER FITA
+    ('\u{0474}', CharMapAction::SubChar('ѵ')), // CYRILLIC CAPITAL LETTER IZHITSA
+    ('\u{0476}', CharMapAction::SubChar('ѷ')), // CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT
+    ('\u{0478}', CharMapAction::SubChar('ѹ')), // CYRILLIC CAPITAL LETTER UK
+    ('\u{047A}', CharMapAction::SubChar('ѻ')), // CYRILLIC CAPITAL LETTER ROUND OMEGA
+    ('\u{047C}', CharMapAction::SubChar('ѽ')), // CYRILLIC CAPITAL LETTER OMEGA WITH TITLO
+    ('\u{047E}', CharMapAction::SubChar('ѿ')), // CYRILLIC CAPITAL LETTER OT
+    ('\u{0480}', CharMapAction::SubChar('ҁ')), // CYRILLIC CAPITAL LETTER KOPPA
+    ('\u{048A}', CharMapAction::SubChar('ҋ')), // CYRILLIC CAPITAL LETTER SHORT I WITH TAIL
+    ('\u{048C}', CharMapAction::SubChar('ҍ')), // CYRILLIC CAPITAL LETTER SEMISOFT SIGN
+    ('\u{048E}', CharMapAction::SubChar('ҏ')), // CYRILLIC CAPITAL LETTER ER WITH TICK
+    ('\u{0490}', CharMapAction::SubChar('ґ')), // CYRILLIC CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0492}', CharMapAction::SubChar('ғ')), // CYRILLIC CAPITAL LETTER GHE WITH STROKE
+    ('\u{0494}', CharMapAction::SubChar('ҕ')), // CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK
+    ('\u{0496}', CharMapAction::SubChar('җ')), // CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER
+    ('\u{0498}', CharMapAction::SubChar('ҙ')), // CYRILLIC CAPITAL LETTER ZE WITH DESCENDER
+    ('\u{049A}', CharMapAction::SubChar('қ')), // CYRILLIC CAPITAL LETTER KA WITH DESCENDER
+    ('\u{049C}', CharMapAction::SubChar('ҝ')), // CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE
+    ('\u{049E}', CharMapAction::SubChar('ҟ')), // CYRILLIC CAPITAL LETTER KA WITH STROKE
+    ('\u{04A0}', CharMapAction::SubChar('ҡ')), // CYRILLIC CAPITAL LETTER BASHKIR KA
+    ('\u{04A2}', CharMapAction::SubChar('ң')), // CYRILLIC CAPITAL LETTER EN WITH DESCENDER
+    ('\u{04A4}', CharMapAction::SubChar('ҥ')), // CYRILLIC CAPITAL LIGATURE EN GHE
+    ('\u{04A6}', CharMapAction::SubChar('ҧ')), // CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK
+    ('\u{04A8}', CharMapAction::SubChar('ҩ')), // CYRILLIC CAPITAL LETTER ABKHASIAN HA
+    ('\u{04AA}', CharMapAction::SubChar('ҫ')), // CYRILLIC CAPITAL LETTER ES WITH DESCENDER
+    ('\u{04AC}', CharMapAction::SubChar('ҭ')), // CYRILLIC CAPITAL LETTER TE WITH DESCENDER
+    ('\u{04AE}', CharMapAction::SubChar('ү')), // CYRILLIC CAPITAL LETTER STRAIGHT U
+    ('\u{04B0}', CharMapAction::SubChar('ұ')), // CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B2}', CharMapAction::SubChar('ҳ')), // CYRILLIC CAPITAL LETTER HA WITH DESCENDER
+    ('\u{04B4}', CharMapAction::SubChar('ҵ')), // CYRILLIC CAPITAL LIGATURE TE TSE
+    ('\u{04B6}', CharMapAction::SubChar('ҷ')), // CYRILLIC CAPITAL LETTER CHE WITH DESCENDER
+    ('\u{04B8}', CharMapAction::SubChar('ҹ')), // CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE
+    ('\u{04BA}', CharMapAction::SubChar('һ')), // CYRILLIC CAPITAL LETTER SHHA
+    ('\u{04BC}', CharMapAction::SubChar('ҽ')), // CYRILLIC CAPITAL LETTER ABKHASIAN CHE
+    ('\u{04BE}', CharMapAction::SubChar('ҿ')), // CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER
+    ('\u{04C0}', CharMapAction::SubChar('ӏ')), // CYRILLIC LETTER PALOCHKA
+    ('\u{04C1}', CharMapAction::SubChar('ӂ')), // CYRILLIC CAPITAL LETTER ZHE WITH BREVE
+    ('\u{04C3}', CharMapAction::SubChar('ӄ')), // CYRILLIC CAPITAL LETTER KA WITH HOOK
+    ('\u{04C5}', CharMapAction::SubChar('ӆ')), // CYRILLIC CAPITAL LETTER EL WITH TAIL
+    ('\u{04C7}', CharMapAction::SubChar('ӈ')), // CYRILLIC CAPITAL LETTER EN WITH HOOK
+    ('\u{04C9}', CharMapAction::SubChar('ӊ')), // CYRILLIC CAPITAL LETTER EN WITH TAIL
+    ('\u{04CB}', CharMapAction::SubChar('ӌ')), // CYRILLIC CAPITAL LETTER KHAKASSIAN CHE
+    ('\u{04CD}', CharMapAction::SubChar('ӎ')), // CYRILLIC CAPITAL LETTER EM WITH TAIL
+    ('\u{04D0}', CharMapAction::SubChar('ӑ')), // CYRILLIC CAPITAL LETTER A WITH BREVE
+    ('\u{04D2}', CharMapAction::SubChar('ӓ')), // CYRILLIC CAPITAL LETTER A WITH DIAERESIS
+    ('\u{04D4}', CharMapAction::SubChar('ӕ')), // CYRILLIC CAPITAL LIGATURE A IE
+    ('\u{04D6}', CharMapAction::SubChar('ӗ')), // CYRILLIC CAPITAL LETTER IE WITH BREVE
+    ('\u{04D8}', CharMapAction::SubChar('ә')), // CYRILLIC CAPITAL LETTER SCHWA
+    ('\u{04DA}', CharMapAction::SubChar('ӛ')), // CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS
+    ('\u{04DC}', CharMapAction::SubChar('ӝ')), // CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS
+    ('\u{04DE}', CharMapAction::SubChar('ӟ')), // CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS
+    ('\u{04E0}', CharMapAction::SubChar('ӡ')), // CYRILLIC CAPITAL LETTER ABKHASIAN DZE
+    ('\u{04E2}', CharMapAction::SubChar('ӣ')), // CYRILLIC CAPITAL LETTER I WITH MACRON
+    ('\u{04E4}', CharMapAction::SubChar('ӥ')), // CYRILLIC CAPITAL LETTER I WITH DIAERESIS
+    ('\u{04E6}', CharMapAction::SubChar('ӧ')), // CYRILLIC CAPITAL LETTER O WITH DIAERESIS
+    ('\u{04E8}', CharMapAction::SubChar('ө')), // CYRILLIC CAPITAL LETTER BARRED O
+    ('\u{04EA}', CharMapAction::SubChar('ӫ')), // CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS
+    ('\u{04EC}', CharMapAction::SubChar('ӭ')), // CYRILLIC CAPITAL LETTER E WITH DIAERESIS
+    ('\u{04EE}', CharMapAction::SubChar('ӯ')), // CYRILLIC CAPITAL LETTER U WITH MACRON
+    ('\u{04F0}', CharMapAction::SubChar('ӱ')), // CYRILLIC CAPITAL LETTER U WITH DIAERESIS
+    ('\u{04F2}', CharMapAction::SubChar('ӳ')), // CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE
+    ('\u{04F4}', CharMapAction::SubChar('ӵ')), // CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS
+    ('\u{04F6}', CharMapAction::SubChar('ӷ')), // CYRILLIC CAPITAL LETTER GHE WITH DESCENDER
+    ('\u{04F8}', CharMapAction::SubChar('ӹ')), // CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS
+    ('\u{04FA}', CharMapAction::SubChar('ӻ')), // CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK
+    ('\u{04FC}', CharMapAction::SubChar('ӽ')), // CYRILLIC CAPITAL LETTER HA WITH HOOK
+    ('\u{04FE}', CharMapAction::SubChar('ӿ')), // CYRILLIC CAPITAL LETTER HA WITH STROKE
+    ('\u{0500}', CharMapAction::SubChar('ԁ')), // CYRILLIC CAPITAL LETTER KOMI DE
+    ('\u{0502}', CharMapAction::SubChar('ԃ')), // CYRILLIC CAPITAL LETTER KOMI DJE
+    ('\u{0504}', CharMapAction::SubChar('ԅ')), // CYRILLIC CAPITAL LETTER KOMI ZJE
+    ('\u{0506}', CharMapAction::SubChar('ԇ')), // CYRILLIC CAPITAL LETTER KOMI DZJE
+    ('\u{0508}', CharMapAction::SubChar('ԉ')), // CYRILLIC CAPITAL LETTER KOMI LJE
+    ('\u{050A}', CharMapAction::SubChar('ԋ')), // CYRILLIC CAPITAL LETTER KOMI NJE
+    ('\u{050C}', CharMapAction::SubChar('ԍ')), // CYRILLIC CAPITAL LETTER KOMI SJE
+    ('\u{050E}', CharMapAction::SubChar('ԏ')), // CYRILLIC CAPITAL LETTER KOMI TJE
+    ('\u{0510}', CharMapAction::SubChar('ԑ')), // CYRILLIC CAPITAL LETTER REVERSED ZE
+    ('\u{0512}', CharMapAction::SubChar('ԓ')), // CYRILLIC CAPITAL LETTER EL WITH HOOK
+    ('\u{0514}', CharMapAction::SubChar('ԕ')), // CYRILLIC CAPITAL LETTER LHA
+    ('\u{0516}', CharMapAction::SubChar('ԗ')), // CYRILLIC CAPITAL LETTER RHA
+    ('\u{0518}', CharMapAction::SubChar('ԙ')), // CYRILLIC CAPITAL LETTER YAE
+    ('\u{051A}', CharMapAction::SubChar('ԛ')), // CYRILLIC CAPITAL LETTER QA
+    ('\u{051C}', CharMapAction::SubChar('ԝ')), // CYRILLIC CAPITAL LETTER WE
+    ('\u{051E}', CharMapAction::SubChar('ԟ')), // CYRILLIC CAPITAL LETTER ALEUT KA
+    ('\u{0520}', CharMapAction::SubChar('ԡ')), // CYRILLIC CAPITAL LETTER EL WITH MIDDLE HOOK
+    ('\u{0522}', CharMapAction::SubChar('ԣ')), // CYRILLIC CAPITAL LETTER EN WITH MIDDLE HOOK
+    ('\u{0524}', CharMapAction::SubChar('ԥ')), // CYRILLIC CAPITAL LETTER PE WITH DESCENDER
+    ('\u{0526}', CharMapAction::SubChar('ԧ')), // CYRILLIC CAPITAL LETTER SHHA WITH DESCENDER
+    ('\u{0528}', CharMapAction::SubChar('ԩ')), // CYRILLIC CAPITAL LETTER EN WITH LEFT HOOK
+    ('\u{052A}', CharMapAction::SubChar('ԫ')), // CYRILLIC CAPITAL LETTER DZZHE
+    ('\u{052C}', CharMapAction::SubChar('ԭ')), // CYRILLIC CAPITAL LETTER DCHE
+    ('\u{052E}', CharMapAction::SubChar('ԯ')), // CYRILLIC CAPITAL LETTER EL WITH DESCENDER
+    ('\u{0531}', CharMapAction::SubChar('ա')), // ARMENIAN CAPITAL LETTER AYB
+    ('\u{0532}', CharMapAction::SubChar('բ')), // ARMENIAN CAPITAL LETTER BEN
+    ('\u{0533}', CharMapAction::SubChar('գ')), // ARMENIAN CAPITAL LETTER GIM
+    ('\u{0534}', CharMapAction::SubChar('դ')), // ARMENIAN CAPITAL LETTER DA
+    ('\u{0535}', CharMapAction::SubChar('ե')), // ARMENIAN CAPITAL LETTER ECH
+    ('\u{0536}', CharMapAction::SubChar('զ')), // ARMENIAN CAPITAL LETTER ZA
+    ('\u{0537}', CharMapAction::SubChar('է')), // ARMENIAN CAPITAL LETTER EH
+    ('\u{0538}', CharMapAction::SubChar('ը')), // ARMENIAN CAPITAL LETTER ET
+    ('\u{0539}', CharMapAction::SubChar('թ')), // ARMENIAN CAPITAL LETTER TO
+    ('\u{053A}', CharMapAction::SubChar('ժ')), // ARMENIAN CAPITAL LETTER ZHE
+    ('\u{053B}', CharMapAction::SubChar('ի')), // ARMENIAN CAPITAL LETTER INI
+    ('\u{053C}', CharMapAction::SubChar('լ')), // ARMENIAN CAPITAL LETTER LIWN
+    ('\u{053D}', CharMapAction::SubChar('խ')), // ARMENIAN CAPITAL LETTER XEH
+    ('\u{053E}', CharMapAction::SubChar('ծ')), // ARMENIAN CAPITAL LETTER CA
+    ('\u{053F}', CharMapAction::SubChar('կ')), // ARMENIAN CAPITAL LETTER KEN
+    ('\u{0540}', CharMapAction::SubChar('հ')), // ARMENIAN CAPITAL LETTER HO
+    ('\u{0541}', CharMapAction::SubChar('ձ')), // ARMENIAN CAPITAL LETTER JA
+    ('\u{0542}', CharMapAction::SubChar('ղ')), // ARMENIAN CAPITAL LETTER GHAD
+    ('\u{0543}', CharMapAction::SubChar('ճ')), // ARMENIAN CAPITAL LETTER CHEH
+    ('\u{0544}', CharMapAction::SubChar('մ')), // ARMENIAN CAPITAL LETTER MEN
+    ('\u{0545}', CharMapAction::SubChar('յ')), // ARMENIAN CAPITAL LETTER YI
+    ('\u{0546}', CharMapAction::SubChar('ն')), // ARMENIAN CAPITAL LETTER NOW
+    ('\u{0547}', CharMapAction::SubChar('շ')), // ARMENIAN CAPITAL LETTER SHA
+    ('\u{0548}', CharMapAction::SubChar('ո')), // ARMENIAN CAPITAL LETTER VO
+    ('\u{0549}', CharMapAction::SubChar('չ')), // ARMENIAN CAPITAL LETTER CHA
+    ('\u{054A}', CharMapAction::SubChar('պ')), // ARMENIAN CAPITAL LETTER PEH
+    ('\u{054B}', CharMapAction::SubChar('ջ')), // ARMENIAN CAPITAL LETTER JHEH
+    ('\u{054C}', CharMapAction::SubChar('ռ')), // ARMENIAN CAPITAL LETTER RA
+    ('\u{054D}', CharMapAction::SubChar('ս')), // ARMENIAN CAPITAL LETTER SEH
+    ('\u{054E}', CharMapAction::SubChar('վ')), // ARMENIAN CAPITAL LETTER VEW
+    ('\u{054F}', CharMapAction::SubChar('տ')), // ARMENIAN CAPITAL LETTER TIWN
+    ('\u{0550}', CharMapAction::SubChar('ր')), // ARMENIAN CAPITAL LETTER REH
+    ('\u{0551}', CharMapAction::SubChar('ց')), // ARMENIAN CAPITAL LETTER CO
+    ('\u{0552}', CharMapAction::SubChar('ւ')), // ARMENIAN CAPITAL LETTER YIWN
+    ('\u{0553}', CharMapAction::SubChar('փ')), // ARMENIAN CAPITAL LETTER PIWR
+    ('\u{0554}', CharMapAction::SubChar('ք')), // ARMENIAN CAPITAL LETTER KEH
+    ('\u{0555}', CharMapAction::SubChar('օ')), // ARMENIAN CAPITAL LETTER OH
+    ('\u{0556}', CharMapAction::SubChar('ֆ')), // ARMENIAN CAPITAL LETTER FEH
+    ('\u{10A0}', CharMapAction::SubChar('ⴀ')), // GEORGIAN CAPITAL LETTER AN
+    ('\u{10A1}', CharMapAction::SubChar('ⴁ')), // GEORGIAN CAPITAL LETTER BAN
+    ('\u{10A2}', CharMapAction::SubChar('ⴂ')), // GEORGIAN CAPITAL LETTER GAN
+    ('\u{10A3}', CharMapAction::SubChar('ⴃ')), // GEORGIAN CAPITAL LETTER DON
+    ('\u{10A4}', CharMapAction::SubChar('ⴄ')), // GEORGIAN CAPITAL LETTER EN
+    ('\u{10A5}', CharMapAction::SubChar('ⴅ')), // GEORGIAN CAPITAL LETTER VIN
+    ('\u{10A6}', CharMapAction::SubChar('ⴆ')), // GEORGIAN CAPITAL LETTER ZEN
+    ('\u{10A7}', CharMapAction::SubChar('ⴇ')), // GEORGIAN CAPITAL LETTER TAN
+    ('\u{10A8}', CharMapAction::SubChar('ⴈ')), // GEORGIAN CAPITAL LETTER IN
+    ('\u{10A9}', CharMapAction::SubChar('ⴉ')), // GEORGIAN CAPITAL LETTER KAN
+    ('\u{10AA}', CharMapAction::SubChar('ⴊ')), // GEORGIAN CAPITAL LETTER LAS
+    ('\u{10AB}', CharMapAction::SubChar('ⴋ')), // GEORGIAN CAPITAL LETTER MAN
+    ('\u{10AC}', CharMapAction::SubChar('ⴌ')), // GEORGIAN CAPITAL LETTER NAR
+    ('\u{10AD}', CharMapAction::SubChar('ⴍ')), // GEORGIAN CAPITAL LETTER ON
+    ('\u{10AE}', CharMapAction::SubChar('ⴎ')), // GEORGIAN CAPITAL LETTER PAR
+    ('\u{10AF}', CharMapAction::SubChar('ⴏ')), // GEORGIAN CAPITAL LETTER ZHAR
+    ('\u{10B0}', CharMapAction::SubChar('ⴐ')), // GEORGIAN CAPITAL LETTER RAE
+    ('\u{10B1}', CharMapAction::SubChar('ⴑ')), // GEORGIAN CAPITAL LETTER SAN
+    ('\u{10B2}', CharMapAction::SubChar('ⴒ')), // GEORGIAN CAPITAL LETTER TAR
+    ('\u{10B3}', CharMapAction::SubChar('ⴓ')), // GEORGIAN CAPITAL LETTER UN
+    ('\u{10B4}', CharMapAction::SubChar('ⴔ')), // GEORGIAN CAPITAL LETTER PHAR
+    ('\u{10B5}', CharMapAction::SubChar('ⴕ')), // GEORGIAN CAPITAL LETTER KHAR
+    ('\u{10B6}', CharMapAction::SubChar('ⴖ')), // GEORGIAN CAPITAL LETTER GHAN
+    ('\u{10B7}', CharMapAction::SubChar('ⴗ')), // GEORGIAN CAPITAL LETTER QAR
+    ('\u{10B8}', CharMapAction::SubChar('ⴘ')), // GEORGIAN CAPITAL LETTER SHIN
+    ('\u{10B9}', CharMapAction::SubChar('ⴙ')), // GEORGIAN CAPITAL LETTER CHIN
+    ('\u{10BA}', CharMapAction::SubChar('ⴚ')), // GEORGIAN CAPITAL LETTER CAN
+    ('\u{10BB}', CharMapAction::SubChar('ⴛ')), // GEORGIAN CAPITAL LETTER JIL
+    ('\u{10BC}', CharMapAction::SubChar('ⴜ')), // GEORGIAN CAPITAL LETTER CIL
+    ('\u{10BD}', CharMapAction::SubChar('ⴝ')), // GEORGIAN CAPITAL LETTER CHAR
+    ('\u{10BE}', CharMapAction::SubChar('ⴞ')), // GEORGIAN CAPITAL LETTER XAN
+    ('\u{10BF}', CharMapAction::SubChar('ⴟ')), // GEORGIAN CAPITAL LETTER JHAN
+    ('\u{10C0}', CharMapAction::SubChar('ⴠ')), // GEORGIAN CAPITAL LETTER HAE
+    ('\u{10C1}', CharMapAction::SubChar('ⴡ')), // GEORGIAN CAPITAL LETTER HE
+    ('\u{10C2}', CharMapAction::SubChar('ⴢ')), // GEORGIAN CAPITAL LETTER HIE
+    ('\u{10C3}', CharMapAction::SubChar('ⴣ')), // GEORGIAN CAPITAL LETTER WE
+    ('\u{10C4}', CharMapAction::SubChar('ⴤ')), // GEORGIAN CAPITAL LETTER HAR
+    ('\u{10C5}', CharMapAction::SubChar('ⴥ')), // GEORGIAN CAPITAL LETTER HOE
+    ('\u{10C7}', CharMapAction::SubChar('ⴧ')), // GEORGIAN CAPITAL LETTER YN
+    ('\u{10CD}', CharMapAction::SubChar('ⴭ')), // GEORGIAN CAPITAL LETTER AEN
+    ('\u{13A0}', CharMapAction::SubChar('ꭰ')), // CHEROKEE LETTER A
+    ('\u{13A1}', CharMapAction::SubChar('ꭱ')), // CHEROKEE LETTER E
+    ('\u{13A2}', CharMapAction::SubChar('ꭲ')), // CHEROKEE LETTER I
+    ('\u{13A3}', CharMapAction::SubChar('ꭳ')), // CHEROKEE LETTER O
+    ('\u{13A4}', CharMapAction::SubChar('ꭴ')), // CHEROKEE LETTER U
+    ('\u{13A5}', CharMapAction::SubChar('ꭵ')), // CHEROKEE LETTER V
+    ('\u{13A6}', CharMapAction::SubChar('ꭶ')), // CHEROKEE LETTER GA
+    ('\u{13A7}', CharMapAction::SubChar('ꭷ')), // CHEROKEE LETTER KA
+    ('\u{13A8}', CharMapAction::SubChar('ꭸ')), // CHEROKEE LETTER GE
+    ('\u{13A9}', CharMapAction::SubChar('ꭹ')), // CHEROKEE LETTER GI
+    ('\u{13AA}', CharMapAction::SubChar('ꭺ')), // CHEROKEE LETTER GO
+    ('\u{13AB}', CharMapAction::SubChar('ꭻ')), // CHEROKEE LETTER GU
+    ('\u{13AC}', CharMapAction::SubChar('ꭼ')), // CHEROKEE LETTER GV
+    ('\u{13AD}', CharMapAction::SubChar('ꭽ')), // CHEROKEE LETTER HA
+    ('\u{13AE}', CharMapAction::SubChar('ꭾ')), // CHEROKEE LETTER HE
+    ('\u{13AF}', CharMapAction::SubChar('ꭿ')), // CHEROKEE LETTER HI
+    ('\u{13B0}', CharMapAction::SubChar('ꮀ')), // CHEROKEE LETTER HO
+    ('\u{13B1}', CharMapAction::SubChar('ꮁ')), // CHEROKEE LETTER HU
+    ('\u{13B2}', CharMapAction::SubChar('ꮂ')), // CHEROKEE LETTER HV
+    ('\u{13B3}', CharMapAction::SubChar('ꮃ')), // CHEROKEE LETTER LA
+    ('\u{13B4}', CharMapAction::SubChar('ꮄ')), // CHEROKEE LETTER LE
+    ('\u{13B5}', CharMapAction::SubChar('ꮅ')), // CHEROKEE LETTER LI
+    ('\u{13B6}', CharMapAction::SubChar('ꮆ')), // CHEROKEE LETTER LO
+    ('\u{13B7}', CharMapAction::SubChar('ꮇ')), // CHEROKEE LETTER LU
+    ('\u{13B8}', CharMapAction::SubChar('ꮈ')), // CHEROKEE LETTER LV
+    ('\u{13B9}', CharMapAction::SubChar('ꮉ')), // CHEROKEE LETTER MA
+    ('\u{13BA}', CharMapAction::SubChar('ꮊ')), // CHEROKEE LETTER ME
+    ('\u{13BB}', CharMapAction::SubChar('ꮋ')), // CHEROKEE LETTER MI
+    ('\u{13BC}', CharMapAction::SubChar('ꮌ')), // CHEROKEE LETTER MO
+    ('\u{13BD}', CharMapAction::SubChar('ꮍ')), // CHEROKEE LETTER MU
+    ('\u{13BE}', CharMapAction::SubChar('ꮎ')), // CHEROKEE LETTER NA
+    ('\u{13BF}', CharMapAction::SubChar('ꮏ')), // CHEROKEE LETTER HNA
+    ('\u{13C0}', CharMapAction::SubChar('ꮐ')), // CHEROKEE LETTER NAH
+    ('\u{13C1}', CharMapAction::SubChar('ꮑ')), // CHEROKEE LETTER NE
+    ('\u{13C2}', CharMapAction::SubChar('ꮒ')), // CHEROKEE LETTER NI
+    ('\u{13C3}', CharMapAction::SubChar('ꮓ')), // CHEROKEE LETTER NO
+    ('\u{13C4}', CharMapAction::SubChar('ꮔ')), // CHEROKEE LETTER NU
+    ('\u{13C5}', CharMapAction::SubChar('ꮕ')), // CHEROKEE LETTER NV
+    ('\u{13C6}', CharMapAction::SubChar('ꮖ')), // CHEROKEE LETTER QUA
+    ('\u{13C7}', CharMapAction::SubChar('ꮗ')), // CHEROKEE LETTER QUE
+    ('\u{13C8}', CharMapAction::SubChar('ꮘ')), // CHEROKEE LETTER QUI
+    ('\u{13C9}', CharMapAction::SubChar('ꮙ')), // CHEROKEE LETTER QUO
+    ('\u{13CA}', CharMapAction::SubChar('ꮚ')), // CHEROKEE LETTER QUU
+    ('\u{13CB}', CharMapAction::SubChar('ꮛ')), // CHEROKEE LETTER QUV
+    ('\u{13CC}', CharMapAction::SubChar('ꮜ')), // CHEROKEE LETTER SA
+    ('\u{13CD}', CharMapAction::SubChar('ꮝ')), // CHEROKEE LETTER S
+    ('\u{13CE}', CharMapAction::SubChar('ꮞ')), // CHEROKEE LETTER SE
+    ('\u{13CF}', CharMapAction::SubChar('ꮟ')), // CHEROKEE LETTER SI
+    ('\u{13D0}', CharMapAction::SubChar('ꮠ')), // CHEROKEE LETTER SO
+    ('\u{13D1}', CharMapAction::SubChar('ꮡ')), // CHEROKEE LETTER SU
+    ('\u{13D2}', CharMapAction::SubChar('ꮢ')), // CHEROKEE LETTER SV
+    ('\u{13D3}', CharMapAction::SubChar('ꮣ')), // CHEROKEE LETTER DA
+    ('\u{13D4}', CharMapAction::SubChar('ꮤ')), // CHEROKEE LETTER TA
+    ('\u{13D5}', CharMapAction::SubChar('ꮥ')), // CHEROKEE LETTER DE
+    ('\u{13D6}', CharMapAction::SubChar('ꮦ')), // CHEROKEE LETTER TE
+    ('\u{13D7}', CharMapAction::SubChar('ꮧ')), // CHEROKEE LETTER DI
+    ('\u{13D8}', CharMapAction::SubChar('ꮨ')), // CHEROKEE LETTER TI
+    ('\u{13D9}', CharMapAction::SubChar('ꮩ')), // CHEROKEE LETTER DO
+    ('\u{13DA}', CharMapAction::SubChar('ꮪ')), // CHEROKEE LETTER DU
+    ('\u{13DB}', CharMapAction::SubChar('ꮫ')), // CHEROKEE LETTER DV
+    ('\u{13DC}', CharMapAction::SubChar('ꮬ')), // CHEROKEE LETTER DLA
+    ('\u{13DD}', CharMapAction::SubChar('ꮭ')), // CHEROKEE LETTER TLA
+    ('\u{13DE}', CharMapAction::SubChar('ꮮ')), // CHEROKEE LETTER TLE
+    ('\u{13DF}', CharMapAction::SubChar('ꮯ')), // CHEROKEE LETTER TLI
+    ('\u{13E0}', CharMapAction::SubChar('ꮰ')), // CHEROKEE LETTER TLO
+    ('\u{13E1}', CharMapAction::SubChar('ꮱ')), // CHEROKEE LETTER TLU
+    ('\u{13E2}', CharMapAction::SubChar('ꮲ')), // CHEROKEE LETTER TLV
+    ('\u{13E3}', CharMapAction::SubChar('ꮳ')), // CHEROKEE LETTER TSA
+    ('\u{13E4}', CharMapAction::SubChar('ꮴ')), // CHEROKEE LETTER TSE
+    ('\u{13E5}', CharMapAction::SubChar('ꮵ')), // CHEROKEE LETTER TSI
+    ('\u{13E6}', CharMapAction::SubChar('ꮶ')), // CHEROKEE LETTER TSO
+    ('\u{13E7}', CharMapAction::SubChar('ꮷ')), // CHEROKEE LETTER TSU
+    ('\u{13E8}', CharMapAction::SubChar('ꮸ')), // CHEROKEE LETTER TSV
+    ('\u{13E9}', CharMapAction::SubChar('ꮹ')), // CHEROKEE LETTER WA
+    ('\u{13EA}', CharMapAction::SubChar('ꮺ')), // CHEROKEE LETTER WE
+    ('\u{13EB}', CharMapAction::SubChar('ꮻ')), // CHEROKEE LETTER WI
+    ('\u{13EC}', CharMapAction::SubChar('ꮼ')), // CHEROKEE LETTER WO
+    ('\u{13ED}', CharMapAction::SubChar('ꮽ')), // CHEROKEE LETTER WU
+    ('\u{13EE}', CharMapAction::SubChar('ꮾ')), // CHEROKEE LETTER WV
+    ('\u{13EF}', CharMapAction::SubChar('ꮿ')), // CHEROKEE LETTER YA
+    ('\u{13F0}', CharMapAction::SubChar('ᏸ')), // CHEROKEE LETTER YE
+    ('\u{13F1}', CharMapAction::SubChar('ᏹ')), // CHEROKEE LETTER YI
+    ('\u{13F2}', CharMapAction::SubChar('ᏺ')), // CHEROKEE LETTER YO
+    ('\u{13F3}', CharMapAction::SubChar('ᏻ')), // CHEROKEE LETTER YU
+    ('\u{13F4}', CharMapAction::SubChar('ᏼ')), // CHEROKEE LETTER YV
+    ('\u{13F5}', CharMapAction::SubChar('ᏽ')), // CHEROKEE LETTER MV
+    ('\u{1C90}', CharMapAction::SubChar('ა')), // GEORGIAN MTAVRULI CAPITAL LETTER AN
+    ('\u{1C91}', CharMapAction::SubChar('ბ')), // GEORGIAN MTAVRULI CAPITAL LETTER BAN
+    ('\u{1C92}', CharMapAction::SubChar('გ')), // GEORGIAN MTAVRULI CAPITAL LETTER GAN
+    ('\u{1C93}', CharMapAction::SubChar('დ')), // GEORGIAN MTAVRULI CAPITAL LETTER DON
+    ('\u{1C94}', CharMapAction::SubChar('ე')), // GEORGIAN MTAVRULI CAPITAL LETTER EN
+    ('\u{1C95}', CharMapAction::SubChar('ვ')), // GEORGIAN MTAVRULI CAPITAL LETTER VIN
+    ('\u{1C96}', CharMapAction::SubChar('ზ')), // GEORGIAN MTAVRULI CAPITAL LETTER ZEN
+    ('\u{1C97}', CharMapAction::SubChar('თ')), // GEORGIAN MTAVRULI CAPITAL LETTER TAN
+    ('\u{1C98}', CharMapAction::SubChar('ი')), // GEORGIAN MTAVRULI CAPITAL LETTER IN
+    ('\u{1C99}', CharMapAction::SubChar('კ')), // GEORGIAN MTAVRULI CAPITAL LETTER KAN
+    ('\u{1C9A}', CharMapAction::SubChar('ლ')), // GEORGIAN MTAVRULI CAPITAL LETTER LAS
+    ('\u{1C9B}', CharMapAction::SubChar('მ')), // GEORGIAN MTAVRULI CAPITAL LETTER MAN
+    ('\u{1C9C}', CharMapAction::SubChar('ნ')), // GEORGIAN MTAVRULI CAPITAL LETTER NAR
+    ('\u{1C9D}', CharMapAction::SubChar('ო')), // GEORGIAN MTAVRULI CAPITAL LETTER ON
+    ('\u{1C9E}', CharMapAction::SubChar('პ')), // GEORGIAN MTAVRULI CAPITAL LETTER PAR
+    ('\u{1C9F}', CharMapAction::SubChar('ჟ')), // GEORGIAN MTAVRULI CAPITAL LETTER ZHAR
+    ('\u{1CA0}', CharMapAction::SubChar('რ')), // GEORGIAN MTAVRULI CAPITAL LETTER RAE
+    ('\u{1CA1}', CharMapAction::SubChar('ს')), // GEORGIAN MTAVRULI CAPITAL LETTER SAN
+    ('\u{1CA2}', CharMapAction::SubChar('ტ')), // GEORGIAN MTAVRULI CAPITAL LETTER TAR
+    ('\u{1CA3}', CharMapAction::SubChar('უ')), // GEORGIAN MTAVRULI CAPITAL LETTER UN
+    ('\u{1CA4}', CharMapAction::SubChar('ფ')), // GEORGIAN MTAVRULI CAPITAL LETTER PHAR
+    ('\u{1CA5}', CharMapAction::SubChar('ქ')), // GEORGIAN MTAVRULI CAPITAL LETTER KHAR
+    ('\u{1CA6}', CharMapAction::SubChar('ღ')), // GEORGIAN MTAVRULI CAPITAL LETTER GHAN
+    ('\u{1CA7}', CharMapAction::SubChar('ყ')), // GEORGIAN MTAVRULI CAPITAL LETTER QAR
+    ('\u{1CA8}', CharMapAction::SubChar('შ')), // GEORGIAN MTAVRULI CAPITAL LETTER SHIN
+    ('\u{1CA9}', CharMapAction::SubChar('ჩ')), // GEORGIAN MTAVRULI CAPITAL LETTER CHIN
+    ('\u{1CAA}', CharMapAction::SubChar('ც')), // GEORGIAN MTAVRULI CAPITAL LETTER CAN
+    ('\u{1CAB}', CharMapAction::SubChar('ძ')), // GEORGIAN MTAVRULI CAPITAL LETTER JIL
+    ('\u{1CAC}', CharMapAction::SubChar('წ')), // GEORGIAN MTAVRULI CAPITAL LETTER CIL
+    ('\u{1CAD}', CharMapAction::SubChar('ჭ')), // GEORGIAN MTAVRULI CAPITAL LETTER CHAR
+    ('\u{1CAE}', CharMapAction::SubChar('ხ')), // GEORGIAN MTAVRULI CAPITAL LETTER XAN
+    ('\u{1CAF}', CharMapAction::SubChar('ჯ')), // GEORGIAN MTAVRULI CAPITAL LETTER JHAN
+    ('\u{1CB0}', CharMapAction::SubChar('ჰ')), // GEORGIAN MTAVRULI CAPITAL LETTER HAE
+    ('\u{1CB1}', CharMapAction::SubChar('ჱ')), // GEORGIAN MTAVRULI CAPITAL LETTER HE
+    ('\u{1CB2}', CharMapAction::SubChar('ჲ')), // GEORGIAN MTAVRULI CAPITAL LETTER HIE
+    ('\u{1CB3}', CharMapAction::SubChar('ჳ')), // GEORGIAN MTAVRULI CAPITAL LETTER WE
+    ('\u{1CB4}', CharMapAction::SubChar('ჴ')), // GEORGIAN MTAVRULI CAPITAL LETTER HAR
+    ('\u{1CB5}', CharMapAction::SubChar('ჵ')), // GEORGIAN MTAVRULI CAPITAL LETTER HOE
+    ('\u{1CB6}', CharMapAction::SubChar('ჶ')), // GEORGIAN MTAVRULI CAPITAL LETTER FI
+    ('\u{1CB7}', CharMapAction::SubChar('ჷ')), // GEORGIAN MTAVRULI CAPITAL LETTER YN
+    ('\u{1CB8}', CharMapAction::SubChar('ჸ')), // GEORGIAN MTAVRULI CAPITAL LETTER ELIFI
+    ('\u{1CB9}', CharMapAction::SubChar('ჹ')), // GEORGIAN MTAVRULI CAPITAL LETTER TURNED GAN
+    ('\u{1CBA}', CharMapAction::SubChar('ჺ')), // GEORGIAN MTAVRULI CAPITAL LETTER AIN
+    ('\u{1CBD}', CharMapAction::SubChar('ჽ')), // GEORGIAN MTAVRULI CAPITAL LETTER AEN
+    ('\u{1CBE}', CharMapAction::SubChar('ჾ')), // GEORGIAN MTAVRULI CAPITAL LETTER HARD SIGN
+    ('\u{1CBF}', CharMapAction::SubChar('ჿ')), // GEORGIAN MTAVRULI CAPITAL LETTER LABIAL SIGN
+    ('\u{1E00}', CharMapAction::SubChar('ḁ')), // LATIN CAPITAL LETTER A WITH RING BELOW
+    ('\u{1E02}', CharMapAction::SubChar('ḃ')), // LATIN CAPITAL LETTER B WITH DOT ABOVE
+    ('\u{1E04}', CharMapAction::SubChar('ḅ')), // LATIN CAPITAL LETTER B WITH DOT BELOW
+    ('\u{1E06}', CharMapAction::SubChar('ḇ')), // LATIN CAPITAL LETTER B WITH LINE BELOW
+    ('\u{1E08}', CharMapAction::SubChar('ḉ')), // LATIN CAPITAL LETTER C WITH CEDILLA AND ACUTE
+    ('\u{1E0A}', CharMapAction::SubChar('ḋ')), // LATIN CAPITAL LETTER D WITH DOT ABOVE
+    ('\u{1E0C}', CharMapAction::SubChar('ḍ')), // LATIN CAPITAL LETTER D WITH DOT BELOW
+    ('\u{1E0E}', CharMapAction::SubChar('ḏ')), // LATIN CAPITAL LETTER D WITH LINE BELOW
+    ('\u{1E10}', CharMapAction::SubChar('ḑ')), // LATIN CAPITAL LETTER D WITH CEDILLA
+    ('\u{1E12}', CharMapAction::SubChar('ḓ')), // LATIN CAPITAL LETTER D WITH CIRCUMFLEX BELOW
+    ('\u{1E14}', CharMapAction::SubChar('ḕ')), // LATIN CAPITAL LETTER E WITH MACRON AND GRAVE
+    ('\u{1E16}', CharMapAction::SubChar('ḗ')), // LATIN CAPITAL LETTER E WITH MACRON AND ACUTE
+    ('\u{1E18}', CharMapAction::SubChar('ḙ')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX BELOW
+    ('\u{1E1A}', CharMapAction::SubChar('ḛ')), // LATIN CAPITAL LETTER E WITH TILDE BELOW
+    ('\u{1E1C}', CharMapAction::SubChar('ḝ')), // LATIN CAPITAL LETTER E WITH CEDILLA AND BREVE
+    ('\u{1E1E}', CharMapAction::SubChar('ḟ')), // LATIN CAPITAL LETTER F WITH DOT ABOVE
+    ('\u{1E20}', CharMapAction::SubChar('ḡ')), // LATIN CAPITAL LETTER G WITH MACRON
+    ('\u{1E22}', CharMapAction::SubChar('ḣ')), // LATIN CAPITAL LETTER H WITH DOT ABOVE
+    ('\u{1E24}', CharMapAction::SubChar('ḥ')), // LATIN CAPITAL LETTER H WITH DOT BELOW
+    ('\u{1E26}', CharMapAction::SubChar('ḧ')), // LATIN CAPITAL LETTER H WITH DIAERESIS
+    ('\u{1E28}', CharMapAction::SubChar('ḩ')), // LATIN CAPITAL LETTER H WITH CEDILLA
+    ('\u{1E2A}', CharMapAction::SubChar('ḫ')), // LATIN CAPITAL LETTER H WITH BREVE BELOW
+    ('\u{1E2C}', CharMapAction::SubChar('ḭ')), // LATIN CAPITAL LETTER I WITH TILDE BELOW
+    ('\u{1E2E}', CharMapAction::SubChar('ḯ')), // LATIN CAPITAL LETTER I WITH DIAERESIS AND ACUTE
+    ('\u{1E30}', CharMapAction::SubChar('ḱ')), // LATIN CAPITAL LETTER K WITH ACUTE
+    ('\u{1E32}', CharMapAction::SubChar('ḳ')), // LATIN CAPITAL LETTER K WITH DOT BELOW
+    ('\u{1E34}', CharMapAction::SubChar('ḵ')), // LATIN CAPITAL LETTER K WITH LINE BELOW
+    ('\u{1E36}', CharMapAction::SubChar('ḷ')), // LATIN CAPITAL LETTER L WITH DOT BELOW
+    ('\u{1E38}', CharMapAction::SubChar('ḹ')), // LATIN CAPITAL LETTER L WITH DOT BELOW AND MACRON
+    ('\u{1E3A}', CharMapAction::SubChar('ḻ')), // LATIN CAPITAL LETTER L WITH LINE BELOW
+    ('\u{1E3C}', CharMapAction::SubChar('ḽ')), // LATIN CAPITAL LETTER L WITH CIRCUMFLEX BELOW
+    ('\u{1E3E}', CharMapAction::SubChar('ḿ')), // LATIN CAPITAL LETTER M WITH ACUTE
+    ('\u{1E40}', CharMapAction::SubChar('ṁ')), // LATIN CAPITAL LETTER M WITH DOT ABOVE
+    ('\u{1E42}', CharMapAction::SubChar('ṃ')), // LATIN CAPITAL LETTER M WITH DOT BELOW
+    ('\u{1E44}', CharMapAction::SubChar('ṅ')), // LATIN CAPITAL LETTER N WITH DOT ABOVE
+    ('\u{1E46}', CharMapAction::SubChar('ṇ')), // LATIN CAPITAL LETTER N WITH DOT BELOW
+    ('\u{1E48}', CharMapAction::SubChar('ṉ')), // LATIN CAPITAL LETTER N WITH LINE BELOW
+    ('\u{1E4A}', CharMapAction::SubChar('ṋ')), // LATIN CAPITAL LETTER N WITH CIRCUMFLEX BELOW
+    ('\u{1E4C}', CharMapAction::SubChar('ṍ')), // LATIN CAPITAL LETTER O WITH TILDE AND ACUTE
+    ('\u{1E4E}', CharMapAction::SubChar('ṏ')), // LATIN CAPITAL LETTER O WITH TILDE AND DIAERESIS
+    ('\u{1E50}', CharMapAction::SubChar('ṑ')), // LATIN CAPITAL LETTER O WITH MACRON AND GRAVE
+    ('\u{1E52}', CharMapAction::SubChar('ṓ')), // LATIN CAPITAL LETTER O WITH MACRON AND ACUTE
+    ('\u{1E54}', CharMapAction::SubChar('ṕ')), // LATIN CAPITAL LETTER P WITH ACUTE
+    ('\u{1E56}', CharMapAction::SubChar('ṗ')), // LATIN CAPITAL LETTER P WITH DOT ABOVE
+    ('\u{1E58}', CharMapAction::SubChar('ṙ')), // LATIN CAPITAL LETTER R WITH DOT ABOVE
+    ('\u{1E5A}', CharMapAction::SubChar('ṛ')), // LATIN CAPITAL LETTER R WITH DOT BELOW
+    ('\u{1E5C}', CharMapAction::SubChar('ṝ')), // LATIN CAPITAL LETTER R WITH DOT BELOW AND MACRON
+    ('\u{1E5E}', CharMapAction::SubChar('ṟ')), // LATIN CAPITAL LETTER R WITH LINE BELOW
+    ('\u{1E60}', CharMapAction::SubChar('ṡ')), // LATIN CAPITAL LETTER S WITH DOT ABOVE
+    ('\u{1E62}', CharMapAction::SubChar('ṣ')), // LATIN CAPITAL LETTER S WITH DOT BELOW
+    ('\u{1E64}', CharMapAction::SubChar('ṥ')), // LATIN CAPITAL LETTER S WITH ACUTE AND DOT ABOVE
+    ('\u{1E66}', CharMapAction::SubChar('ṧ')), // LATIN CAPITAL LETTER S WITH CARON AND DOT ABOVE
+    ('\u{1E68}', CharMapAction::SubChar('ṩ')), // LATIN CAPITAL LETTER S WITH DOT BELOW AND DOT ABOVE
+    ('\u{1E6A}', CharMapAction::SubChar('ṫ')), // LATIN CAPITAL LETTER T WITH DOT ABOVE
+    ('\u{1E6C}', CharMapAction::SubChar('ṭ')), // LATIN CAPITAL LETTER T WITH DOT BELOW
+    ('\u{1E6E}', CharMapAction::SubChar('ṯ')), // LATIN CAPITAL LETTER T WITH LINE BELOW
+    ('\u{1E70}', CharMapAction::SubChar('ṱ')), // LATIN CAPITAL LETTER T WITH CIRCUMFLEX BELOW
+    ('\u{1E72}', CharMapAction::SubChar('ṳ')), // LATIN CAPITAL LETTER U WITH DIAERESIS BELOW
+    ('\u{1E74}', CharMapAction::SubChar('ṵ')), // LATIN CAPITAL LETTER U WITH TILDE BELOW
+    ('\u{1E76}', CharMapAction::SubChar('ṷ')), // LATIN CAPITAL LETTER U WITH CIRCUMFLEX BELOW
+    ('\u{1E78}', CharMapAction::SubChar('ṹ')), // LATIN CAPITAL LETTER U WITH TILDE AND ACUTE
+    ('\u{1E7A}', CharMapAction::SubChar('ṻ')), // LATIN CAPITAL LETTER U WITH MACRON AND DIAERESIS
+    ('\u{1E7C}', CharMapAction::SubChar('ṽ')), // LATIN CAPITAL LETTER V WITH TILDE
+    ('\u{1E7E}', CharMapAction::SubChar('ṿ')), // LATIN CAPITAL LETTER V WITH DOT BELOW
+    ('\u{1E80}', CharMapAction::SubChar('ẁ')), // LATIN CAPITAL LETTER W WITH GRAVE
+    ('\u{1E82}', CharMapAction::SubChar('ẃ')), // LATIN CAPITAL LETTER W WITH ACUTE
+    ('\u{1E84}', CharMapAction::SubChar('ẅ')), // LATIN CAPITAL LETTER W WITH DIAERESIS
+    ('\u{1E86}', CharMapAction::SubChar('ẇ')), // LATIN CAPITAL LETTER W WITH DOT ABOVE
+    ('\u{1E88}', CharMapAction::SubChar('ẉ')), // LATIN CAPITAL LETTER W WITH DOT BELOW
+    ('\u{1E8A}', CharMapAction::SubChar('ẋ')), // LATIN CAPITAL LETTER X WITH DOT ABOVE
+    ('\u{1E8C}', CharMapAction::SubChar('ẍ')), // LATIN CAPITAL LETTER X WITH DIAERESIS
+    ('\u{1E8E}', CharMapAction::SubChar('ẏ')), // LATIN CAPITAL LETTER Y WITH DOT ABOVE
+    ('\u{1E90}', CharMapAction::SubChar('ẑ')), // LATIN CAPITAL LETTER Z WITH CIRCUMFLEX
+    ('\u{1E92}', CharMapAction::SubChar('ẓ')), // LATIN CAPITAL LETTER Z WITH DOT BELOW
+    ('\u{1E94}', CharMapAction::SubChar('ẕ')), // LATIN CAPITAL LETTER Z WITH LINE BELOW
+    ('\u{1E9E}', CharMapAction::SubChar('ß')), // LATIN CAPITAL LETTER SHARP S
+    ('\u{1EA0}', CharMapAction::SubChar('ạ')), // LATIN CAPITAL LETTER A WITH DOT BELOW
+    ('\u{1EA2}', CharMapAction::SubChar('ả')), // LATIN CAPITAL LETTER A WITH HOOK ABOVE
+    ('\u{1EA4}', CharMapAction::SubChar('ấ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EA6}', CharMapAction::SubChar('ầ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EA8}', CharMapAction::SubChar('ẩ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EAA}', CharMapAction::SubChar('ẫ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND TILDE
+    ('\u{1EAC}', CharMapAction::SubChar('ậ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EAE}', CharMapAction::SubChar('ắ')), // LATIN CAPITAL LETTER A WITH BREVE AND ACUTE
+    ('\u{1EB0}', CharMapAction::SubChar('ằ')), // LATIN CAPITAL LETTER A WITH BREVE AND GRAVE
+    ('\u{1EB2}', CharMapAction::SubChar('ẳ')), // LATIN CAPITAL LETTER A WITH BREVE AND HOOK ABOVE
+    ('\u{1EB4}', CharMapAction::SubChar('ẵ')), // LATIN CAPITAL LETTER A WITH BREVE AND TILDE
+    ('\u{1EB6}', CharMapAction::SubChar('ặ')), // LATIN CAPITAL LETTER A WITH BREVE AND DOT BELOW
+    ('\u{1EB8}', CharMapAction::SubChar('ẹ')), // LATIN CAPITAL LETTER E WITH DOT BELOW
+    ('\u{1EBA}', CharMapAction::SubChar('ẻ')), // LATIN CAPITAL LETTER E WITH HOOK ABOVE
+    ('\u{1EBC}', CharMapAction::SubChar('ẽ')), // LATIN CAPITAL LETTER E WITH TILDE
+    ('\u{1EBE}', CharMapAction::SubChar('ế')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EC0}', CharMapAction::SubChar('ề')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EC2}', CharMapAction::SubChar('ể')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EC4}', CharMapAction::SubChar('ễ')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND TILDE
+    ('\u{1EC6}', CharMapAction::SubChar('ệ')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EC8}', CharMapAction::SubChar('ỉ')), // LATIN CAPITAL LETTER I WITH HOOK ABOVE
+    ('\u{1ECA}', CharMapAction::SubChar('ị')), // LATIN CAPITAL LETTER I WITH DOT BELOW
+    ('\u{1ECC}', CharMapAction::SubChar('ọ')), // LATIN CAPITAL LETTER O WITH DOT BELOW
+    ('\u{1ECE}', CharMapAction::SubChar('ỏ')), // LATIN CAPITAL LETTER O WITH HOOK ABOVE
+    ('\u{1ED0}', CharMapAction::SubChar('ố')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND ACUTE
+    ('\u{1ED2}', CharMapAction::SubChar('ồ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND GRAVE
+    ('\u{1ED4}', CharMapAction::SubChar('ổ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1ED6}', CharMapAction::SubChar('ỗ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND TILDE
+    ('\u{1ED8}', CharMapAction::SubChar('ộ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EDA}', CharMapAction::SubChar('ớ')), // LATIN CAPITAL LETTER O WITH HORN AND ACUTE
+    ('\u{1EDC}', CharMapAction::SubChar('ờ')), // LATIN CAPITAL LETTER O WITH HORN AND GRAVE
+    ('\u{1EDE}', CharMapAction::SubChar('ở')), // LATIN CAPITAL LETTER O WITH HORN AND HOOK ABOVE
+    ('\u{1EE0}', CharMapAction::SubChar('ỡ')), // LATIN CAPITAL LETTER O WITH HORN AND TILDE
+    ('\u{1EE2}', CharMapAction::SubChar('ợ')), // LATIN CAPITAL LETTER O WITH HORN AND DOT BELOW
+    ('\u{1EE4}', CharMapAction::SubChar('ụ')), // LATIN CAPITAL LETTER U WITH DOT BELOW
+    ('\u{1EE6}', CharMapAction::SubChar('ủ')), // LATIN CAPITAL LETTER U WITH HOOK ABOVE
+    ('\u{1EE8}', CharMapAction::SubChar('ứ')), // LATIN CAPITAL LETTER U WITH HORN AND ACUTE
+    ('\u{1EEA}', CharMapAction::SubChar('ừ')), // LATIN CAPITAL LETTER U WITH HORN AND GRAVE
+    ('\u{1EEC}', CharMapAction::SubChar('ử')), // LATIN CAPITAL LETTER U WITH HORN AND HOOK ABOVE
+    ('\u{1EEE}', CharMapAction::SubChar('ữ')), // LATIN CAPITAL LETTER U WITH HORN AND TILDE
+    ('\u{1EF0}', CharMapAction::SubChar('ự')), // LATIN CAPITAL LETTER U WITH HORN AND DOT BELOW
+    ('\u{1EF2}', CharMapAction::SubChar('ỳ')), // LATIN CAPITAL LETTER Y WITH GRAVE
+    ('\u{1EF4}', CharMapAction::SubChar('ỵ')), // LATIN CAPITAL LETTER Y WITH DOT BELOW
+    ('\u{1EF6}', CharMapAction::SubChar('ỷ')), // LATIN CAPITAL LETTER Y WITH HOOK ABOVE
+    ('\u{1EF8}', CharMapAction::SubChar('ỹ')), // LATIN CAPITAL LETTER Y WITH TILDE
+    ('\u{1EFA}', CharMapAction::SubChar('ỻ')), // LATIN CAPITAL LETTER MIDDLE-WELSH LL
+    ('\u{1EFC}', CharMapAction::SubChar('ỽ')), // LATIN CAPITAL LETTER MIDDLE-WELSH V
+    ('\u{1EFE}', CharMapAction::SubChar('ỿ')), // LATIN CAPITAL LETTER Y WITH LOOP
+    ('\u{1F08}', CharMapAction::SubChar('ἀ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI
+    ('\u{1F09}', CharMapAction::SubChar('ἁ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA
+    ('\u{1F0A}', CharMapAction::SubChar('ἂ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F0B}', CharMapAction::SubChar('ἃ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F0C}', CharMapAction::SubChar('ἄ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F0D}', CharMapAction::SubChar('ἅ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F0E}', CharMapAction::SubChar('ἆ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F0F}', CharMapAction::SubChar('ἇ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F18}', CharMapAction::SubChar('ἐ')), // GREEK CAPITAL LETTER EPSILON WITH PSILI
+    ('\u{1F19}', CharMapAction::SubChar('ἑ')), // GREEK CAPITAL LETTER EPSILON WITH DASIA
+    ('\u{1F1A}', CharMapAction::SubChar('ἒ')), // GREEK CAPITAL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F1B}', CharMapAction::SubChar('ἓ')), // GREEK CAPITAL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F1C}', CharMapAction::SubChar('ἔ')), // GREEK CAPITAL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F1D}', CharMapAction::SubChar('ἕ')), // GREEK CAPITAL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F28}', CharMapAction::SubChar('ἠ')), // GREEK CAPITAL LETTER ETA WITH PSILI
+    ('\u{1F29}', CharMapAction::SubChar('ἡ')), // GREEK CAPITAL LETTER ETA WITH DASIA
+    ('\u{1F2A}', CharMapAction::SubChar('ἢ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F2B}', CharMapAction::SubChar('ἣ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F2C}', CharMapAction::SubChar('ἤ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F2D}', CharMapAction::SubChar('ἥ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F2E}', CharMapAction::SubChar('ἦ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F2F}', CharMapAction::SubChar('ἧ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F38}', CharMapAction::SubChar('ἰ')), // GREEK CAPITAL LETTER IOTA WITH PSILI
+    ('\u{1F39}', CharMapAction::SubChar('ἱ')), // GREEK CAPITAL LETTER IOTA WITH DASIA
+    ('\u{1F3A}', CharMapAction::SubChar('ἲ')), // GREEK CAPITAL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F3B}', CharMapAction::SubChar('ἳ')), // GREEK CAPITAL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F3C}', CharMapAction::SubChar('ἴ')), // GREEK CAPITAL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F3D}', CharMapAction::SubChar('ἵ')), // GREEK CAPITAL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F3E}', CharMapAction::SubChar('ἶ')), // GREEK CAPITAL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F3F}', CharMapAction::SubChar('ἷ')), // GREEK CAPITAL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F48}', CharMapAction::SubChar('ὀ')), // GREEK CAPITAL LETTER OMICRON WITH PSILI
+    ('\u{1F49}', CharMapAction::SubChar('ὁ')), // GREEK CAPITAL LETTER OMICRON WITH DASIA
+    ('\u{1F4A}', CharMapAction::SubChar('ὂ')), // GREEK CAPITAL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F4B}', CharMapAction::SubChar('ὃ')), // GREEK CAPITAL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F4C}', CharMapAction::SubChar('ὄ')), // GREEK CAPITAL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F4D}', CharMapAction::SubChar('ὅ')), // GREEK CAPITAL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F59}', CharMapAction::SubChar('ὑ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA
+    ('\u{1F5B}', CharMapAction::SubChar('ὓ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F5D}', CharMapAction::SubChar('ὕ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F5F}', CharMapAction::SubChar('ὗ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F68}', CharMapAction::SubChar('ὠ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI
+    ('\u{1F69}', CharMapAction::SubChar('ὡ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA
+    ('\u{1F6A}', CharMapAction::SubChar('ὢ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F6B}', CharMapAction::SubChar('ὣ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F6C}', CharMapAction::SubChar('ὤ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F6D}', CharMapAction::SubChar('ὥ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F6E}', CharMapAction::SubChar('ὦ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F6F}', CharMapAction::SubChar('ὧ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F88}', CharMapAction::SubChar('ᾀ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F89}', CharMapAction::SubChar('ᾁ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F8A}', CharMapAction::SubChar('ᾂ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8B}', CharMapAction::SubChar('ᾃ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8C}', CharMapAction::SubChar('ᾄ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8D}', CharMapAction::SubChar('ᾅ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8E}', CharMapAction::SubChar('ᾆ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F8F}', CharMapAction::SubChar('ᾇ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F98}', CharMapAction::SubChar('ᾐ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F99}', CharMapAction::SubChar('ᾑ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F9A}', CharMapAction::SubChar('ᾒ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9B}', CharMapAction::SubChar('ᾓ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9C}', CharMapAction::SubChar('ᾔ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9D}', CharMapAction::SubChar('ᾕ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9E}', CharMapAction::SubChar('ᾖ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F9F}', CharMapAction::SubChar('ᾗ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FA8}', CharMapAction::SubChar('ᾠ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1FA9}', CharMapAction::SubChar('ᾡ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1FAA}', CharMapAction::SubChar('ᾢ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAB}', CharMapAction::SubChar('ᾣ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAC}', CharMapAction::SubChar('ᾤ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAD}', CharMapAction::SubChar('ᾥ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAE}', CharMapAction::SubChar('ᾦ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FAF}', CharMapAction::SubChar('ᾧ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FB8}', CharMapAction::SubChar('ᾰ')), // GREEK CAPITAL LETTER ALPHA WITH VRACHY
+    ('\u{1FB9}', CharMapAction::SubChar('ᾱ')), // GREEK CAPITAL LETTER ALPHA WITH MACRON
+    ('\u{1FBA}', CharMapAction::SubChar('ὰ')), // GREEK CAPITAL LETTER ALPHA WITH VARIA
+    ('\u{1FBB}', CharMapAction::SubChar('ά')), // GREEK CAPITAL LETTER ALPHA WITH OXIA
+    ('\u{1FBC}', CharMapAction::SubChar('ᾳ')), // GREEK CAPITAL LETTER ALPHA WITH PROSGEGRAMMENI
+    ('\u{1FC8}', CharMapAction::SubChar('ὲ')), // GREEK CAPITAL LETTER EPSILON WITH VARIA
+    ('\u{1FC9}', CharMapAction::SubChar('έ')), // GREEK CAPITAL LETTER EPSILON WITH OXIA
+    ('\u{1FCA}', CharMapAction::SubChar('ὴ')), // GREEK CAPITAL LETTER ETA WITH VARIA
+    ('\u{1FCB}', CharMapAction::SubChar('ή')), // GREEK CAPITAL LETTER ETA WITH OXIA
+    ('\u{1FCC}', CharMapAction::SubChar('ῃ')), // GREEK CAPITAL LETTER ETA WITH PROSGEGRAMMENI
+    ('\u{1FD8}', CharMapAction::SubChar('ῐ')), // GREEK CAPITAL LETTER IOTA WITH VRACHY
+    ('\u{1FD9}', CharMapAction::SubChar('ῑ')), // GREEK CAPITAL LETTER IOTA WITH MACRON
+    ('\u{1FDA}', CharMapAction::SubChar('ὶ')), // GREEK CAPITAL LETTER IOTA WITH VARIA
+    ('\u{1FDB}', CharMapAction::SubChar('ί')), // GREEK CAPITAL LETTER IOTA WITH OXIA
+    ('\u{1FE8}', CharMapAction::SubChar('ῠ')), // GREEK CAPITAL LETTER UPSILON WITH VRACHY
+    ('\u{1FE9}', CharMapAction::SubChar('ῡ')), // GREEK CAPITAL LETTER UPSILON WITH MACRON
+    ('\u{1FEA}', CharMapAction::SubChar('ὺ')), // GREEK CAPITAL LETTER UPSILON WITH VARIA
+    ('\u{1FEB}', CharMapAction::SubChar('ύ')), // GREEK CAPITAL LETTER UPSILON WITH OXIA
+    ('\u{1FEC}', CharMapAction::SubChar('ῥ')), // GREEK CAPITAL LETTER RHO WITH DASIA
+    ('\u{1FF8}', CharMapAction::SubChar('ὸ')), // GREEK CAPITAL LETTER OMICRON WITH VARIA
+    ('\u{1FF9}', CharMapAction::SubChar('ό')), // GREEK CAPITAL LETTER OMICRON WITH OXIA
+    ('\u{1FFA}', CharMapAction::SubChar('ὼ')), // GREEK CAPITAL LETTER OMEGA WITH VARIA
+    ('\u{1FFB}', CharMapAction::SubChar('ώ')), // GREEK CAPITAL LETTER OMEGA WITH OXIA
+    ('\u{1FFC}', CharMapAction::SubChar('ῳ')), // GREEK CAPITAL LETTER OMEGA WITH PROSGEGRAMMENI
+    ('\u{2126}', CharMapAction::SubChar('ω')), // OHM SIGN
+    ('\u{212A}', CharMapAction::SubChar('k')), // KELVIN SIGN
+    ('\u{212B}', CharMapAction::SubChar('å')), // ANGSTROM SIGN
+    ('\u{2132}', CharMapAction::SubChar('ⅎ')), // TURNED CAPITAL F
+    ('\u{2160}', CharMapAction::SubChar('ⅰ')), // ROMAN NUMERAL ONE
+    ('\u{2161}', CharMapAction::SubChar('ⅱ')), // ROMAN NUMERAL TWO
+    ('\u{2162}', CharMapAction::SubChar('ⅲ')), // ROMAN NUMERAL THREE
+    ('\u{2163}', CharMapAction::SubChar('ⅳ')), // ROMAN NUMERAL FOUR
+    ('\u{2164}', CharMapAction::SubChar('ⅴ')), // ROMAN NUMERAL FIVE
+    ('\u{2165}', CharMapAction::SubChar('ⅵ')), // ROMAN NUMERAL SIX
+    ('\u{2166}', CharMapAction::SubChar('ⅶ')), // ROMAN NUMERAL SEVEN
+    ('\u{2167}', CharMapAction::SubChar('ⅷ')), // ROMAN NUMERAL EIGHT
+    ('\u{2168}', CharMapAction::SubChar('ⅸ')), // ROMAN NUMERAL NINE
+    ('\u{2169}', CharMapAction::SubChar('ⅹ')), // ROMAN NUMERAL TEN
+    ('\u{216A}', CharMapAction::SubChar('ⅺ')), // ROMAN NUMERAL ELEVEN
+    ('\u{216B}', CharMapAction::SubChar('ⅻ')), // ROMAN NUMERAL TWELVE
+    ('\u{216C}', CharMapAction::SubChar('ⅼ')), // ROMAN NUMERAL FIFTY
+    ('\u{216D}', CharMapAction::SubChar('ⅽ')), // ROMAN NUMERAL ONE HUNDRED
+    ('\u{216E}', CharMapAction::SubChar('ⅾ')), // ROMAN NUMERAL FIVE HUNDRED
+    ('\u{216F}', CharMapAction::SubChar('ⅿ')), // ROMAN NUMERAL ONE THOUSAND
+    ('\u{2183}', CharMapAction::SubChar('ↄ')), // ROMAN NUMERAL REVERSED ONE HUNDRED
+    ('\u{24B6}', CharMapAction::SubChar('ⓐ')), // CIRCLED LATIN CAPITAL LETTER A
+    ('\u{24B7}', CharMapAction::SubChar('ⓑ')), // CIRCLED LATIN CAPITAL LETTER B
+    ('\u{24B8}', CharMapAction::SubChar('ⓒ')), // CIRCLED LATIN CAPITAL LETTER C
+    ('\u{24B9}', CharMapAction::SubChar('ⓓ')), // CIRCLED LATIN CAPITAL LETTER D
+    ('\u{24BA}', CharMapAction::SubChar('ⓔ')), // CIRCLED LATIN CAPITAL LETTER E
+    ('\u{24BB}', CharMapAction::SubChar('ⓕ')), // CIRCLED LATIN CAPITAL LETTER F
+    ('\u{24BC}', CharMapAction::SubChar('ⓖ')), // CIRCLED LATIN CAPITAL LETTER G
+    ('\u{24BD}', CharMapAction::SubChar('ⓗ')), // CIRCLED LATIN CAPITAL LETTER H
+    ('\u{24BE}', CharMapAction::SubChar('ⓘ')), // CIRCLED LATIN CAPITAL LETTER I
+    ('\u{24BF}', CharMapAction::SubChar('ⓙ')), // CIRCLED LATIN CAPITAL LETTER J
+    ('\u{24C0}', CharMapAction::SubChar('ⓚ')), // CIRCLED LATIN CAPITAL LETTER K
+    ('\u{24C1}', CharMapAction::SubChar('ⓛ')), // CIRCLED LATIN CAPITAL LETTER L
+    ('\u{24C2}', CharMapAction::SubChar('ⓜ')), // CIRCLED LATIN CAPITAL LETTER M
+    ('\u{24C3}', CharMapAction::SubChar('ⓝ')), // CIRCLED LATIN CAPITAL LETTER N
+    ('\u{24C4}', CharMapAction::SubChar('ⓞ')), // CIRCLED LATIN CAPITAL LETTER O
+    ('\u{24C5}', CharMapAction::SubChar('ⓟ')), // CIRCLED LATIN CAPITAL LETTER P
+    ('\u{24C6}', CharMapAction::SubChar('ⓠ')), // CIRCLED LATIN CAPITAL LETTER Q
+    ('\u{24C7}', CharMapAction::SubChar('ⓡ')), // CIRCLED LATIN CAPITAL LETTER R
+    ('\u{24C8}', CharMapAction::SubChar('ⓢ')), // CIRCLED LATIN CAPITAL LETTER S
+    ('\u{24C9}', CharMapAction::SubChar('ⓣ')), // CIRCLED LATIN CAPITAL LETTER T
+    ('\u{24CA}', CharMapAction::SubChar('ⓤ')), // CIRCLED LATIN CAPITAL LETTER U
+    ('\u{24CB}', CharMapAction::SubChar('ⓥ')), // CIRCLED LATIN CAPITAL LETTER V
+    ('\u{24CC}', CharMapAction::SubChar('ⓦ')), // CIRCLED LATIN CAPITAL LETTER W
+    ('\u{24CD}', CharMapAction::SubChar('ⓧ')), // CIRCLED LATIN CAPITAL LETTER X
+    ('\u{24CE}', CharMapAction::SubChar('ⓨ')), // CIRCLED LATIN CAPITAL LETTER Y
+    ('\u{24CF}', CharMapAction::SubChar('ⓩ')), // CIRCLED LATIN CAPITAL LETTER Z
+    ('\u{2C00}', CharMapAction::SubChar('ⰰ')), // GLAGOLITIC CAPITAL LETTER AZU
+    ('\u{2C01}', CharMapAction::SubChar('ⰱ')), // GLAGOLITIC CAPITAL LETTER BUKY
+    ('\u{2C02}', CharMapAction::SubChar('ⰲ')), // GLAGOLITIC CAPITAL LETTER VEDE
+    ('\u{2C03}', CharMapAction::SubChar('ⰳ')), // GLAGOLITIC CAPITAL LETTER GLAGOLI
+    ('\u{2C04}', CharMapAction::SubChar('ⰴ')), // GLAGOLITIC CAPITAL LETTER DOBRO
+    ('\u{2C05}', CharMapAction::SubChar('ⰵ')), // GLAGOLITIC CAPITAL LETTER YESTU
+    ('\u{2C06}', CharMapAction::SubChar('ⰶ')), // GLAGOLITIC CAPITAL LETTER ZHIVETE
+    ('\u{2C07}', CharMapAction::SubChar('ⰷ')), // GLAGOLITIC CAPITAL LETTER DZELO
+    ('\u{2C08}', CharMapAction::SubChar('ⰸ')), // GLAGOLITIC CAPITAL LETTER ZEMLJA
+    ('\u{2C09}', CharMapAction::SubChar('ⰹ')), // GLAGOLITIC CAPITAL LETTER IZHE
+    ('\u{2C0A}', CharMapAction::SubChar('ⰺ')), // GLAGOLITIC CAPITAL LETTER INITIAL IZHE
+    ('\u{2C0B}', CharMapAction::SubChar('ⰻ')), // GLAGOLITIC CAPITAL LETTER I
+    ('\u{2C0C}', CharMapAction::SubChar('ⰼ')), // GLAGOLITIC CAPITAL LETTER DJERVI
+    ('\u{2C0D}', CharMapAction::SubChar('ⰽ')), // GLAGOLITIC CAPITAL LETTER KAKO
+    ('\u{2C0E}', CharMapAction::SubChar('ⰾ')), // GLAGOLITIC CAPITAL LETTER LJUDIJE
+    ('\u{2C0F}', CharMapAction::SubChar('ⰿ')), // GLAGOLITIC CAPITAL LETTER MYSLITE
+    ('\u{2C10}', CharMapAction::SubChar('ⱀ')), // GLAGOLITIC CAPITAL LETTER NASHI
+    ('\u{2C11}', CharMapAction::SubChar('ⱁ')), // GLAGOLITIC CAPITAL LETTER ONU
+    ('\u{2C12}', CharMapAction::SubChar('ⱂ')), // GLAGOLITIC CAPITAL LETTER POKOJI
+    ('\u{2C13}', CharMapAction::SubChar('ⱃ')), // GLAGOLITIC CAPITAL LETTER RITSI
+    ('\u{2C14}', CharMapAction::SubChar('ⱄ')), // GLAGOLITIC CAPITAL LETTER SLOVO
+    ('\u{2C15}', CharMapAction::SubChar('ⱅ')), // GLAGOLITIC CAPITAL LETTER TVRIDO
+    ('\u{2C16}', CharMapAction::SubChar('ⱆ')), // GLAGOLITIC CAPITAL LETTER UKU
+    ('\u{2C17}', CharMapAction::SubChar('ⱇ')), // GLAGOLITIC CAPITAL LETTER FRITU
+    ('\u{2C18}', CharMapAction::SubChar('ⱈ')), // GLAGOLITIC CAPITAL LETTER HERU
+    ('\u{2C19}', CharMapAction::SubChar('ⱉ')), // GLAGOLITIC CAPITAL LETTER OTU
+    ('\u{2C1A}', CharMapAction::SubChar('ⱊ')), // GLAGOLITIC CAPITAL LETTER PE
+    ('\u{2C1B}', CharMapAction::SubChar('ⱋ')), // GLAGOLITIC CAPITAL LETTER SHTA
+    ('\u{2C1C}', CharMapAction::SubChar('ⱌ')), // GLAGOLITIC CAPITAL LETTER TSI
+    ('\u{2C1D}', CharMapAction::SubChar('ⱍ')), // GLAGOLITIC CAPITAL LETTER CHRIVI
+    ('\u{2C1E}', CharMapAction::SubChar('ⱎ')), // GLAGOLITIC CAPITAL LETTER SHA
+    ('\u{2C1F}', CharMapAction::SubChar('ⱏ')), // GLAGOLITIC CAPITAL LETTER YERU
+    ('\u{2C20}', CharMapAction::SubChar('ⱐ')), // GLAGOLITIC CAPITAL LETTER YERI
+    ('\u{2C21}', CharMapAction::SubChar('ⱑ')), // GLAGOLITIC CAPITAL LETTER YATI
+    ('\u{2C22}', CharMapAction::SubChar('ⱒ')), // GLAGOLITIC CAPITAL LETTER SPIDERY HA
+    ('\u{2C23}', CharMapAction::SubChar('ⱓ')), // GLAGOLITIC CAPITAL LETTER YU
+    ('\u{2C24}', CharMapAction::SubChar('ⱔ')), // GLAGOLITIC CAPITAL LETTER SMALL YUS
+    ('\u{2C25}', CharMapAction::SubChar('ⱕ')), // GLAGOLITIC CAPITAL LETTER SMALL YUS WITH TAIL
+    ('\u{2C26}', CharMapAction::SubChar('ⱖ')), // GLAGOLITIC CAPITAL LETTER YO
+    ('\u{2C27}', CharMapAction::SubChar('ⱗ')), // GLAGOLITIC CAPITAL LETTER IOTATED SMALL YUS
+    ('\u{2C28}', CharMapAction::SubChar('ⱘ')), // GLAGOLITIC CAPITAL LETTER BIG YUS
+    ('\u{2C29}', CharMapAction::SubChar('ⱙ')), // GLAGOLITIC CAPITAL LETTER IOTATED BIG YUS
+    ('\u{2C2A}', CharMapAction::SubChar('ⱚ')), // GLAGOLITIC CAPITAL LETTER FITA
+    ('\u{2C2B}', CharMapAction::SubChar('ⱛ')), // GLAGOLITIC CAPITAL LETTER IZHITSA
+    ('\u{2C2C}', CharMapAction::SubChar('ⱜ')), // GLAGOLITIC CAPITAL LETTER SHTAPIC
+    ('\u{2C2D}', CharMapAction::SubChar('ⱝ')), // GLAGOLITIC CAPITAL LETTER TROKUTASTI A
+    ('\u{2C2E}', CharMapAction::SubChar('ⱞ')), // GLAGOLITIC CAPITAL LETTER LATINATE MYSLITE
+    ('\u{2C2F}', CharMapAction::SubChar('ⱟ')), // GLAGOLITIC CAPITAL LETTER CAUDATE CHRIVI
+    ('\u{2C60}', CharMapAction::SubChar('ⱡ')), // LATIN CAPITAL LETTER L WITH DOUBLE BAR
+    ('\u{2C62}', CharMapAction::SubChar('ɫ')), // LATIN CAPITAL LETTER L WITH MIDDLE TILDE
+    ('\u{2C63}', CharMapAction::SubChar('ᵽ')), // LATIN CAPITAL LETTER P WITH STROKE
+    ('\u{2C64}', CharMapAction::SubChar('ɽ')), // LATIN CAPITAL LETTER R WITH TAIL
+    ('\u{2C67}', CharMapAction::SubChar('ⱨ')), // LATIN CAPITAL LETTER H WITH DESCENDER
+    ('\u{2C69}', CharMapAction::SubChar('ⱪ')), // LATIN CAPITAL LETTER K WITH DESCENDER
+    ('\u{2C6B}', CharMapAction::SubChar('ⱬ')), // LATIN CAPITAL LETTER Z WITH DESCENDER
+    ('\u{2C6D}', CharMapAction::SubChar('ɑ')), // LATIN CAPITAL LETTER ALPHA
+    ('\u{2C6E}', CharMapAction::SubChar('ɱ')), // LATIN CAPITAL LETTER M WITH HOOK
+    ('\u{2C6F}', CharMapAction::SubChar('ɐ')), // LATIN CAPITAL LETTER TURNED A
+    ('\u{2C70}', CharMapAction::SubChar('ɒ')), // LATIN CAPITAL LETTER TURNED ALPHA
+    ('\u{2C72}', CharMapAction::SubChar('ⱳ')), // LATIN CAPITAL LETTER W WITH HOOK
+    ('\u{2C75}', CharMapAction::SubChar('ⱶ')), // LATIN CAPITAL LETTER HALF H
+    ('\u{2C7E}', CharMapAction::SubChar('ȿ')), // LATIN CAPITAL LETTER S WITH SWASH TAIL
+    ('\u{2C7F}', CharMapAction::SubChar('ɀ')), // LATIN CAPITAL LETTER Z WITH SWASH TAIL
+    ('\u{2C80}', CharMapAction::SubChar('ⲁ')), // COPTIC CAPITAL LETTER ALFA
+    ('\u{2C82}', CharMapAction::SubChar('ⲃ')), // COPTIC CAPITAL LETTER VIDA
+    ('\u{2C84}', CharMapAction::SubChar('ⲅ')), // COPTIC CAPITAL LETTER GAMMA
+    ('\u{2C86}', CharMapAction::SubChar('ⲇ')), // COPTIC CAPITAL LETTER DALDA
+    ('\u{2C88}', CharMapAction::SubChar('ⲉ')), // COPTIC CAPITAL LETTER EIE
+    ('\u{2C8A}', CharMapAction::SubChar('ⲋ')), // COPTIC CAPITAL LETTER SOU
+    ('\u{2C8C}', CharMapAction::SubChar('ⲍ')), // COPTIC CAPITAL LETTER ZATA
+    ('\u{2C8E}', CharMapAction::SubChar('ⲏ')), // COPTIC CAPITAL LETTER HATE
+    ('\u{2C90}', CharMapAction::SubChar('ⲑ')), // COPTIC CAPITAL LETTER THETHE
+    ('\u{2C92}', CharMapAction::SubChar('ⲓ')), // COPTIC CAPITAL LETTER IAUDA
+    ('\u{2C94}', CharMapAction::SubChar('ⲕ')), // COPTIC CAPITAL LETTER KAPA
+    ('\u{2C96}', CharMapAction::SubChar('ⲗ')), // COPTIC CAPITAL LETTER LAULA
+    ('\u{2C98}', CharMapAction::SubChar('ⲙ')), // COPTIC CAPITAL LETTER MI
+    ('\u{2C9A}', CharMapAction::SubChar('ⲛ')), // COPTIC CAPITAL LETTER NI
+    ('\u{2C9C}', CharMapAction::SubChar('ⲝ')), // COPTIC CAPITAL LETTER KSI
+    ('\u{2C9E}', CharMapAction::SubChar('ⲟ')), // COPTIC CAPITAL LETTER O
+    ('\u{2CA0}', CharMapAction::SubChar('ⲡ')), // COPTIC CAPITAL LETTER PI
+    ('\u{2CA2}', CharMapAction::SubChar('ⲣ')), // COPTIC CAPITAL LETTER RO
+    ('\u{2CA4}', CharMapAction::SubChar('ⲥ')), // COPTIC CAPITAL LETTER SIMA
+    ('\u{2CA6}', CharMapAction::SubChar('ⲧ')), // COPTIC CAPITAL LETTER TAU
+    ('\u{2CA8}', CharMapAction::SubChar('ⲩ')), // COPTIC CAPITAL LETTER UA
+    ('\u{2CAA}', CharMapAction::SubChar('ⲫ')), // COPTIC CAPITAL LETTER FI
+    ('\u{2CAC}', CharMapAction::SubChar('ⲭ')), // COPTIC CAPITAL LETTER KHI
+    ('\u{2CAE}', CharMapAction::SubChar('ⲯ')), // COPTIC CAPITAL LETTER PSI
+    ('\u{2CB0}', CharMapAction::SubChar('ⲱ')), // COPTIC CAPITAL LETTER OOU
+    ('\u{2CB2}', CharMapAction::SubChar('ⲳ')), // COPTIC CAPITAL LETTER DIALECT-P ALEF
+    ('\u{2CB4}', CharMapAction::SubChar('ⲵ')), // COPTIC CAPITAL LETTER OLD COPTIC AIN
+    ('\u{2CB6}', CharMapAction::SubChar('ⲷ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC EIE
+    ('\u{2CB8}', CharMapAction::SubChar('ⲹ')), // COPTIC CAPITAL LETTER DIALECT-P KAPA
+    ('\u{2CBA}', CharMapAction::SubChar('ⲻ')), // COPTIC CAPITAL LETTER DIALECT-P NI
+    ('\u{2CBC}', CharMapAction::SubChar('ⲽ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC NI
+    ('\u{2CBE}', CharMapAction::SubChar('ⲿ')), // COPTIC CAPITAL LETTER OLD COPTIC OOU
+    ('\u{2CC0}', CharMapAction::SubChar('ⳁ')), // COPTIC CAPITAL LETTER SAMPI
+    ('\u{2CC2}', CharMapAction::SubChar('ⳃ')), // COPTIC CAPITAL LETTER CROSSED SHEI
+    ('\u{2CC4}', CharMapAction::SubChar('ⳅ')), // COPTIC CAPITAL LETTER OLD COPTIC SHEI
+    ('\u{2CC6}', CharMapAction::SubChar('ⳇ')), // COPTIC CAPITAL LETTER OLD COPTIC ESH
+    ('\u{2CC8}', CharMapAction::SubChar('ⳉ')), // COPTIC CAPITAL LETTER AKHMIMIC KHEI
+    ('\u{2CCA}', CharMapAction::SubChar('ⳋ')), // COPTIC CAPITAL LETTER DIALECT-P HORI
+    ('\u{2CCC}', CharMapAction::SubChar('ⳍ')), // COPTIC CAPITAL LETTER OLD COPTIC HORI
+    ('\u{2CCE}', CharMapAction::SubChar('ⳏ')), // COPTIC CAPITAL LETTER OLD COPTIC HA
+    ('\u{2CD0}', CharMapAction::SubChar('ⳑ')), // COPTIC CAPITAL LETTER L-SHAPED HA
+    ('\u{2CD2}', CharMapAction::SubChar('ⳓ')), // COPTIC CAPITAL LETTER OLD COPTIC HEI
+    ('\u{2CD4}', CharMapAction::SubChar('ⳕ')), // COPTIC CAPITAL LETTER OLD COPTIC HAT
+    ('\u{2CD6}', CharMapAction::SubChar('ⳗ')), // COPTIC CAPITAL LETTER OLD COPTIC GANGIA
+    ('\u{2CD8}', CharMapAction::SubChar('ⳙ')), // COPTIC CAPITAL LETTER OLD COPTIC DJA
+    ('\u{2CDA}', CharMapAction::SubChar('ⳛ')), // COPTIC CAPITAL LETTER OLD COPTIC SHIMA
+    ('\u{2CDC}', CharMapAction::SubChar('ⳝ')), // COPTIC CAPITAL LETTER OLD NUBIAN SHIMA
+    ('\u{2CDE}', CharMapAction::SubChar('ⳟ')), // COPTIC CAPITAL LETTER OLD NUBIAN NGI
+    ('\u{2CE0}', CharMapAction::SubChar('ⳡ')), // COPTIC CAPITAL LETTER OLD NUBIAN NYI
+    ('\u{2CE2}', CharMapAction::SubChar('ⳣ')), // COPTIC CAPITAL LETTER OLD NUBIAN WAU
+    ('\u{2CEB}', CharMapAction::SubChar('ⳬ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC SHEI
+    ('\u{2CED}', CharMapAction::SubChar('ⳮ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC GANGIA
+    ('\u{2CF2}', CharMapAction::SubChar('ⳳ')), // COPTIC CAPITAL LETTER BOHAIRIC KHEI
+    ('\u{A640}', CharMapAction::SubChar('ꙁ')), // CYRILLIC CAPITAL LETTER ZEMLYA
+    ('\u{A642}', CharMapAction::SubChar('ꙃ')), // CYRILLIC CAPITAL LETTER DZELO
+    ('\u{A644}', CharMapAction::SubChar('ꙅ')), // CYRILLIC CAPITAL LETTER REVERSED DZE
+    ('\u{A646}', CharMapAction::SubChar('ꙇ')), // CYRILLIC CAPITAL LETTER IOTA
+    ('\u{A648}', CharMapAction::SubChar('ꙉ')), // CYRILLIC CAPITAL LETTER DJERV
+    ('\u{A64A}', CharMapAction::SubChar('ꙋ')), // CYRILLIC CAPITAL LETTER MONOGRAPH UK
+    ('\u{A64C}', CharMapAction::SubChar('ꙍ')), // CYRILLIC CAPITAL LETTER BROAD OMEGA
+    ('\u{A64E}', CharMapAction::SubChar('ꙏ')), // CYRILLIC CAPITAL LETTER NEUTRAL YER
+    ('\u{A650}', CharMapAction::SubChar('ꙑ')), // CYRILLIC CAPITAL LETTER YERU WITH BACK YER
+    ('\u{A652}', CharMapAction::SubChar('ꙓ')), // CYRILLIC CAPITAL LETTER IOTIFIED YAT
+    ('\u{A654}', CharMapAction::SubChar('ꙕ')), // CYRILLIC CAPITAL LETTER REVERSED YU
+    ('\u{A656}', CharMapAction::SubChar('ꙗ')), // CYRILLIC CAPITAL LETTER IOTIFIED A
+    ('\u{A658}', CharMapAction::SubChar('ꙙ')), // CYRILLIC CAPITAL LETTER CLOSED LITTLE YUS
+    ('\u{A65A}', CharMapAction::SubChar('ꙛ')), // CYRILLIC CAPITAL LETTER BLENDED YUS
+    ('\u{A65C}', CharMapAction::SubChar('ꙝ')), // CYRILLIC CAPITAL LETTER IOTIFIED CLOSED LITTLE YUS
+    ('\u{A65E}', CharMapAction::SubChar('ꙟ')), // CYRILLIC CAPITAL LETTER YN
+    ('\u{A660}', CharMapAction::SubChar('ꙡ')), // CYRILLIC CAPITAL LETTER REVERSED TSE
+    ('\u{A662}', CharMapAction::SubChar('ꙣ')), // CYRILLIC CAPITAL LETTER SOFT DE
+    ('\u{A664}', CharMapAction::SubChar('ꙥ')), // CYRILLIC CAPITAL LETTER SOFT EL
+    ('\u{A666}', CharMapAction::SubChar('ꙧ')), // CYRILLIC CAPITAL LETTER SOFT EM
+    ('\u{A668}', CharMapAction::SubChar('ꙩ')), // CYRILLIC CAPITAL LETTER MONOCULAR O
+    ('\u{A66A}', CharMapAction::SubChar('ꙫ')), // CYRILLIC CAPITAL LETTER BINOCULAR O
+    ('\u{A66C}', CharMapAction::SubChar('ꙭ')), // CYRILLIC CAPITAL LETTER DOUBLE MONOCULAR O
+    ('\u{A680}', CharMapAction::SubChar('ꚁ')), // CYRILLIC CAPITAL LETTER DWE
+    ('\u{A682}', CharMapAction::SubChar('ꚃ')), // CYRILLIC CAPITAL LETTER DZWE
+    ('\u{A684}', CharMapAction::SubChar('ꚅ')), // CYRILLIC CAPITAL LETTER ZHWE
+    ('\u{A686}', CharMapAction::SubChar('ꚇ')), // CYRILLIC CAPITAL LETTER CCHE
+    ('\u{A688}', CharMapAction::SubChar('ꚉ')), // CYRILLIC CAPITAL LETTER DZZE
+    ('\u{A68A}', CharMapAction::SubChar('ꚋ')), // CYRILLIC CAPITAL LETTER TE WITH MIDDLE HOOK
+    ('\u{A68C}', CharMapAction::SubChar('ꚍ')), // CYRILLIC CAPITAL LETTER TWE
+    ('\u{A68E}', CharMapAction::SubChar('ꚏ')), // CYRILLIC CAPITAL LETTER TSWE
+    ('\u{A690}', CharMapAction::SubChar('ꚑ')), // CYRILLIC CAPITAL LETTER TSSE
+    ('\u{A692}', CharMapAction::SubChar('ꚓ')), // CYRILLIC CAPITAL LETTER TCHE
+    ('\u{A694}', CharMapAction::SubChar('ꚕ')), // CYRILLIC CAPITAL LETTER HWE
+    ('\u{A696}', CharMapAction::SubChar('ꚗ')), // CYRILLIC CAPITAL LETTER SHWE
+    ('\u{A698}', CharMapAction::SubChar('ꚙ')), // CYRILLIC CAPITAL LETTER DOUBLE O
+    ('\u{A69A}', CharMapAction::SubChar('ꚛ')), // CYRILLIC CAPITAL LETTER CROSSED O
+    ('\u{A722}', CharMapAction::SubChar('ꜣ')), // LATIN CAPITAL LETTER EGYPTOLOGICAL ALEF
+    ('\u{A724}', CharMapAction::SubChar('ꜥ')), // LATIN CAPITAL LETTER EGYPTOLOGICAL AIN
+    ('\u{A726}', CharMapAction::SubChar('ꜧ')), // LATIN CAPITAL LETTER HENG
+    ('\u{A728}', CharMapAction::SubChar('ꜩ')), // LATIN CAPITAL LETTER TZ
+    ('\u{A72A}', CharMapAction::SubChar('ꜫ')), // LATIN CAPITAL LETTER TRESILLO
+    ('\u{A72C}', CharMapAction::SubChar('ꜭ')), // LATIN CAPITAL LETTER CUATRILLO
+    ('\u{A72E}', CharMapAction::SubChar('ꜯ')), // LATIN CAPITAL LETTER CUATRILLO WITH COMMA
+    ('\u{A732}', CharMapAction::SubChar('ꜳ')), // LATIN CAPITAL LETTER AA
+    ('\u{A734}', CharMapAction::SubChar('ꜵ')), // LATIN CAPITAL LETTER AO
+    ('\u{A736}', CharMapAction::SubChar('ꜷ')), // LATIN CAPITAL LETTER AU
+    ('\u{A738}', CharMapAction::SubChar('ꜹ')), // LATIN CAPITAL LETTER AV
+    ('\u{A73A}', CharMapAction::SubChar('ꜻ')), // LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73C}', CharMapAction::SubChar('ꜽ')), // LATIN CAPITAL LETTER AY
+    ('\u{A73E}', CharMapAction::SubChar('ꜿ')), // LATIN CAPITAL LETTER REVERSED C WITH DOT
+    ('\u{A740}', CharMapAction::SubChar('ꝁ')), // LATIN CAPITAL LETTER K WITH STROKE
+    ('\u{A742}', CharMapAction::SubChar('ꝃ')), // LATIN CAPITAL LETTER K WITH DIAGONAL STROKE
+    ('\u{A744}', CharMapAction::SubChar('ꝅ')), // LATIN CAPITAL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A746}', CharMapAction::SubChar('ꝇ')), // LATIN CAPITAL LETTER BROKEN L
+    ('\u{A748}', CharMapAction::SubChar('ꝉ')), // LATIN CAPITAL LETTER L WITH HIGH STROKE
+    ('\u{A74A}', CharMapAction::SubChar('ꝋ')), // LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74C}', CharMapAction::SubChar('ꝍ')), // LATIN CAPITAL LETTER O WITH LOOP
+    ('\u{A74E}', CharMapAction::SubChar('ꝏ')), // LATIN CAPITAL LETTER OO
+    ('\u{A750}', CharMapAction::SubChar('ꝑ')), // LATIN CAPITAL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A752}', CharMapAction::SubChar('ꝓ')), // LATIN CAPITAL LETTER P WITH FLOURISH
+    ('\u{A754}', CharMapAction::SubChar('ꝕ')), // LATIN CAPITAL LETTER P WITH SQUIRREL TAIL
+    ('\u{A756}', CharMapAction::SubChar('ꝗ')), // LATIN CAPITAL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A758}', CharMapAction::SubChar('ꝙ')), // LATIN CAPITAL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A75A}', CharMapAction::SubChar('ꝛ')), // LATIN CAPITAL LETTER R ROTUNDA
+    ('\u{A75C}', CharMapAction::SubChar('ꝝ')), // LATIN CAPITAL LETTER RUM ROTUNDA
+    ('\u{A75E}', CharMapAction::SubChar('ꝟ')), // LATIN CAPITAL LETTER V WITH DIAGONAL STROKE
+    ('\u{A760}', CharMapAction::SubChar('ꝡ')), // LATIN CAPITAL LETTER VY
+    ('\u{A762}', CharMapAction::SubChar('ꝣ')), // LATIN CAPITAL LETTER VISIGOTHIC Z
+    ('\u{A764}', CharMapAction::SubChar('ꝥ')), // LATIN CAPITAL LETTER THORN WITH STROKE
+    ('\u{A766}', CharMapAction::SubChar('ꝧ')), // LATIN CAPITAL LETTER THORN WITH STROKE THROUGH DESCENDER
+    ('\u{A768}', CharMapAction::SubChar('ꝩ')), // LATIN CAPITAL LETTER VEND
+    ('\u{A76A}', CharMapAction::SubChar('ꝫ')), // LATIN CAPITAL LETTER ET
+    ('\u{A76C}', CharMapAction::SubChar('ꝭ')), // LATIN CAPITAL LETTER IS
+    ('\u{A76E}', CharMapAction::SubChar('ꝯ')), // LATIN CAPITAL LETTER CON
+    ('\u{A779}', CharMapAction::SubChar('ꝺ')), // LATIN CAPITAL LETTER INSULAR D
+    ('\u{A77B}', CharMapAction::SubChar('ꝼ')), // LATIN CAPITAL LETTER INSULAR F
+    ('\u{A77D}', CharMapAction::SubChar('ᵹ')), // LATIN CAPITAL LETTER INSULAR G
+    ('\u{A77E}', CharMapAction::SubChar('ꝿ')), // LATIN CAPITAL LETTER TURNED INSULAR G
+    ('\u{A780}', CharMapAction::SubChar('ꞁ')), // LATIN CAPITAL LETTER TURNED L
+    ('\u{A782}', CharMapAction::SubChar('ꞃ')), // LATIN CAPITAL LETTER INSULAR R
+    ('\u{A784}', CharMapAction::SubChar('ꞅ')), // LATIN CAPITAL LETTER INSULAR S
+    ('\u{A786}', CharMapAction::SubChar('ꞇ')), // LATIN CAPITAL LETTER INSULAR T
+    ('\u{A78B}', CharMapAction::SubChar('ꞌ')), // LATIN CAPITAL LETTER SALTILLO
+    ('\u{A78D}', CharMapAction::SubChar('ɥ')), // LATIN CAPITAL LETTER TURNED H
+    ('\u{A790}', CharMapAction::SubChar('ꞑ')), // LATIN CAPITAL LETTER N WITH DESCENDER
+    ('\u{A792}', CharMapAction::SubChar('ꞓ')), // LATIN CAPITAL LETTER C WITH BAR
+    ('\u{A796}', CharMapAction::SubChar('ꞗ')), // LATIN CAPITAL LETTER B WITH FLOURISH
+    ('\u{A798}', CharMapAction::SubChar('ꞙ')), // LATIN CAPITAL LETTER F WITH STROKE
+    ('\u{A79A}', CharMapAction::SubChar('ꞛ')), // LATIN CAPITAL LETTER VOLAPUK AE
+    ('\u{A79C}', CharMapAction::SubChar('ꞝ')), // LATIN CAPITAL LETTER VOLAPUK OE
+    ('\u{A79E}', CharMapAction::SubChar('ꞟ')), // LATIN CAPITAL LETTER VOLAPUK UE
+    ('\u{A7A0}', CharMapAction::SubChar('ꞡ')), // LATIN CAPITAL LETTER G WITH OBLIQUE STROKE
+    ('\u{A7A2}', CharMapAction::SubChar('ꞣ')), // LATIN CAPITAL LETTER K WITH OBLIQUE STROKE
+    ('\u{A7A4}', CharMapAction::SubChar('ꞥ')), // LATIN CAPITAL LETTER N WITH OBLIQUE STROKE
+    ('\u{A7A6}', CharMapAction::SubChar('ꞧ')), // LATIN CAPITAL LETTER R WITH OBLIQUE STROKE
+    ('\u{A7A8}', CharMapAction::SubChar('ꞩ')), // LATIN CAPITAL LETTER S WITH OBLIQUE STROKE
+    ('\u{A7AA}', CharMapAction::SubChar('ɦ')), // LATIN CAPITAL LETTER H WITH HOOK
+    ('\u{A7AB}', CharMapAction::SubChar('ɜ')), // LATIN CAPITAL LETTER REVERSED OPEN E
+    ('\u{A7AC}', CharMapAction::SubChar('ɡ')), // LATIN CAPITAL LETTER SCRIPT G
+    ('\u{A7AD}', CharMapAction::SubChar('ɬ')), // LATIN CAPITAL LETTER L WITH BELT
+    ('\u{A7AE}', CharMapAction::SubChar('ɪ')), // LATIN CAPITAL LETTER SMALL CAPITAL I
+    ('\u{A7B0}', CharMapAction::SubChar('ʞ')), // LATIN CAPITAL LETTER TURNED K
+    ('\u{A7B1}', CharMapAction::SubChar('ʇ')), // LATIN CAPITAL LETTER TURNED T
+    ('\u{A7B2}', CharMapAction::SubChar('ʝ')), // LATIN CAPITAL LETTER J WITH CROSSED-TAIL
+    ('\u{A7B3}', CharMapAction::SubChar('ꭓ')), // LATIN CAPITAL LETTER CHI
+    ('\u{A7B4}', CharMapAction::SubChar('ꞵ')), // LATIN CAPITAL LETTER BETA
+    ('\u{A7B6}', CharMapAction::SubChar('ꞷ')), // LATIN CAPITAL LETTER OMEGA
+    ('\u{A7B8}', CharMapAction::SubChar('ꞹ')), // LATIN CAPITAL LETTER U WITH STROKE
+    ('\u{A7BA}', CharMapAction::SubChar('ꞻ')), // LATIN CAPITAL LETTER GLOTTAL A
+    ('\u{A7BC}', CharMapAction::SubChar('ꞽ')), // LATIN CAPITAL LETTER GLOTTAL I
+    ('\u{A7BE}', CharMapAction::SubChar('ꞿ')), // LATIN CAPITAL LETTER GLOTTAL U
+    ('\u{A7C0}', CharMapAction::SubChar('ꟁ')), // LATIN CAPITAL LETTER OLD POLISH O
+    ('\u{A7C2}', CharMapAction::SubChar('ꟃ')), // LATIN CAPITAL LETTER ANGLICANA W
+    ('\u{A7C4}', CharMapAction::SubChar('ꞔ')), // LATIN CAPITAL LETTER C WITH PALATAL HOOK
+    ('\u{A7C5}', CharMapAction::SubChar('ʂ')), // LATIN CAPITAL LETTER S WITH HOOK
+    ('\u{A7C6}', CharMapAction::SubChar('ᶎ')), // LATIN CAPITAL LETTER Z WITH PALATAL HOOK
+    ('\u{A7C7}', CharMapAction::SubChar('ꟈ')), // LATIN CAPITAL LETTER D WITH SHORT STROKE OVERLAY
+    ('\u{A7C9}', CharMapAction::SubChar('ꟊ')), // LATIN CAPITAL LETTER S WITH SHORT STROKE OVERLAY
+    ('\u{A7D0}', CharMapAction::SubChar('ꟑ')), // LATIN CAPITAL LETTER CLOSED INSULAR G
+    ('\u{A7D6}', CharMapAction::SubChar('ꟗ')), // LATIN CAPITAL LETTER MIDDLE SCOTS S
+    ('\u{A7D8}', CharMapAction::SubChar('ꟙ')), // LATIN CAPITAL LETTER SIGMOID S
+    ('\u{A7F5}', CharMapAction::SubChar('ꟶ')), // LATIN CAPITAL LETTER REVERSED HALF H
+    ('\u{FF21}', CharMapAction::SubChar('ａ')), // FULLWIDTH LATIN CAPITAL LETTER A
+    ('\u{FF22}', CharMapAction::SubChar('ｂ')), // FULLWIDTH LATIN CAPITAL LETTER B
+    ('\u{FF23}', CharMapAction::SubChar('ｃ')), // FULLWIDTH LATIN CAPITAL LETTER C
+    ('\u{FF24}', CharMapAction::SubChar('ｄ')), // FULLWIDTH LATIN CAPITAL LETTER D
+    ('\u{FF25}', CharMapAction::SubChar('ｅ')), // FULLWIDTH LATIN CAPITAL LETTER E
+    ('\u{FF26}', CharMapAction::SubChar('ｆ')), // FULLWIDTH LATIN CAPITAL LETTER F
+    ('\u{FF27}', CharMapAction::SubChar('ｇ')), // FULLWIDTH LATIN CAPITAL LETTER G
+    ('\u{FF28}', CharMapAction::SubChar('ｈ')), // FULLWIDTH LATIN CAPITAL LETTER H
+    ('\u{FF29}', CharMapAction::SubChar('ｉ')), // FULLWIDTH LATIN CAPITAL LETTER I
+    ('\u{FF2A}', CharMapAction::SubChar('ｊ')), // FULLWIDTH LATIN CAPITAL LETTER J
+    ('\u{FF2B}', CharMapAction::SubChar('ｋ')), // FULLWIDTH LATIN CAPITAL LETTER K
+    ('\u{FF2C}', CharMapAction::SubChar('ｌ')), // FULLWIDTH LATIN CAPITAL LETTER L
+    ('\u{FF2D}', CharMapAction::SubChar('ｍ')), // FULLWIDTH LATIN CAPITAL LETTER M
+    ('\u{FF2E}', CharMapAction::SubChar('ｎ')), // FULLWIDTH LATIN CAPITAL LETTER N
+    ('\u{FF2F}', CharMapAction::SubChar('ｏ')), // FULLWIDTH LATIN CAPITAL LETTER O
+    ('\u{FF30}', CharMapAction::SubChar('ｐ')), // FULLWIDTH LATIN CAPITAL LETTER P
+    ('\u{FF31}', CharMapAction::SubChar('ｑ')), // FULLWIDTH LATIN CAPITAL LETTER Q
+    ('\u{FF32}', CharMapAction::SubChar('ｒ')), // FULLWIDTH LATIN CAPITAL LETTER R
+    ('\u{FF33}', CharMapAction::SubChar('ｓ')), // FULLWIDTH LATIN CAPITAL LETTER S
+    ('\u{FF34}', CharMapAction::SubChar('ｔ')), // FULLWIDTH LATIN CAPITAL LETTER T
+    ('\u{FF35}', CharMapAction::SubChar('ｕ')), // FULLWIDTH LATIN CAPITAL LETTER U
+    ('\u{FF36}', CharMapAction::SubChar('ｖ')), // FULLWIDTH LATIN CAPITAL LETTER V
+    ('\u{FF37}', CharMapAction::SubChar('ｗ')), // FULLWIDTH LATIN CAPITAL LETTER W
+    ('\u{FF38}', CharMapAction::SubChar('ｘ')), // FULLWIDTH LATIN CAPITAL LETTER X
+    ('\u{FF39}', CharMapAction::SubChar('ｙ')), // FULLWIDTH LATIN CAPITAL LETTER Y
+    ('\u{FF3A}', CharMapAction::SubChar('ｚ')), // FULLWIDTH LATIN CAPITAL LETTER Z
+    ('\u{10400}', CharMapAction::SubChar('𐐨')), // DESERET CAPITAL LETTER LONG I
+    ('\u{10401}', CharMapAction::SubChar('𐐩')), // DESERET CAPITAL LETTER LONG E
+    ('\u{10402}', CharMapAction::SubChar('𐐪')), // DESERET CAPITAL LETTER LONG A
+    ('\u{10403}', CharMapAction::SubChar('𐐫')), // DESERET CAPITAL LETTER LONG AH
+    ('\u{10404}', CharMapAction::SubChar('𐐬')), // DESERET CAPITAL LETTER LONG O
+    ('\u{10405}', CharMapAction::SubChar('𐐭')), // DESERET CAPITAL LETTER LONG OO
+    ('\u{10406}', CharMapAction::SubChar('𐐮')), // DESERET CAPITAL LETTER SHORT I
+    ('\u{10407}', CharMapAction::SubChar('𐐯')), // DESERET CAPITAL LETTER SHORT E
+    ('\u{10408}', CharMapAction::SubChar('𐐰')), // DESERET CAPITAL LETTER SHORT A
+    ('\u{10409}', CharMapAction::SubChar('𐐱')), // DESERET CAPITAL LETTER SHORT AH
+    ('\u{1040A}', CharMapAction::SubChar('𐐲')), // DESERET CAPITAL LETTER SHORT O
+    ('\u{1040B}', CharMapAction::SubChar('𐐳')), // DESERET CAPITAL LETTER SHORT OO
+    ('\u{1040C}', CharMapAction::SubChar('𐐴')), // DESERET CAPITAL LETTER AY
+    ('\u{1040D}', CharMapAction::SubChar('𐐵')), // DESERET CAPITAL LETTER OW
+    ('\u{1040E}', CharMapAction::SubChar('𐐶')), // DESERET CAPITAL LETTER WU
+    ('\u{1040F}', CharMapAction::SubChar('𐐷')), // DESERET CAPITAL LETTER YEE
+    ('\u{10410}', CharMapAction::SubChar('𐐸')), // DESERET CAPITAL LETTER H
+    ('\u{10411}', CharMapAction::SubChar('𐐹')), // DESERET CAPITAL LETTER PEE
+    ('\u{10412}', CharMapAction::SubChar('𐐺')), // DESERET CAPITAL LETTER BEE
+    ('\u{10413}', CharMapAction::SubChar('𐐻')), // DESERET CAPITAL LETTER TEE
+    ('\u{10414}', CharMapAction::SubChar('𐐼')), // DESERET CAPITAL LETTER DEE
+    ('\u{10415}', CharMapAction::SubChar('𐐽')), // DESERET CAPITAL LETTER CHEE
+    ('\u{10416}', CharMapAction::SubChar('𐐾')), // DESERET CAPITAL LETTER JEE
+    ('\u{10417}', CharMapAction::SubChar('𐐿')), // DESERET CAPITAL LETTER KAY
+    ('\u{10418}', CharMapAction::SubChar('𐑀')), // DESERET CAPITAL LETTER GAY
+    ('\u{10419}', CharMapAction::SubChar('𐑁')), // DESERET CAPITAL LETTER EF
+    ('\u{1041A}', CharMapAction::SubChar('𐑂')), // DESERET CAPITAL LETTER VEE
+    ('\u{1041B}', CharMapAction::SubChar('𐑃')), // DESERET CAPITAL LETTER ETH
+    ('\u{1041C}', CharMapAction::SubChar('𐑄')), // DESERET CAPITAL LETTER THEE
+    ('\u{1041D}', CharMapAction::SubChar('𐑅')), // DESERET CAPITAL LETTER ES
+    ('\u{1041E}', CharMapAction::SubChar('𐑆')), // DESERET CAPITAL LETTER ZEE
+    ('\u{1041F}', CharMapAction::SubChar('𐑇')), // DESERET CAPITAL LETTER ESH
+    ('\u{10420}', CharMapAction::SubChar('𐑈')), // DESERET CAPITAL LETTER ZHEE
+    ('\u{10421}', CharMapAction::SubChar('𐑉')), // DESERET CAPITAL LETTER ER
+    ('\u{10422}', CharMapAction::SubChar('𐑊')), // DESERET CAPITAL LETTER EL
+    ('\u{10423}', CharMapAction::SubChar('𐑋')), // DESERET CAPITAL LETTER EM
+    ('\u{10424}', CharMapAction::SubChar('𐑌')), // DESERET CAPITAL LETTER EN
+    ('\u{10425}', CharMapAction::SubChar('𐑍')), // DESERET CAPITAL LETTER ENG
+    ('\u{10426}', CharMapAction::SubChar('𐑎')), // DESERET CAPITAL LETTER OI
+    ('\u{10427}', CharMapAction::SubChar('𐑏')), // DESERET CAPITAL LETTER EW
+    ('\u{104B0}', CharMapAction::SubChar('𐓘')), // OSAGE CAPITAL LETTER A
+    ('\u{104B1}', CharMapAction::SubChar('𐓙')), // OSAGE CAPITAL LETTER AI
+    ('\u{104B2}', CharMapAction::SubChar('𐓚')), // OSAGE CAPITAL LETTER AIN
+    ('\u{104B3}', CharMapAction::SubChar('𐓛')), // OSAGE CAPITAL LETTER AH
+    ('\u{104B4}', CharMapAction::SubChar('𐓜')), // OSAGE CAPITAL LETTER BRA
+    ('\u{104B5}', CharMapAction::SubChar('𐓝')), // OSAGE CAPITAL LETTER CHA
+    ('\u{104B6}', CharMapAction::SubChar('𐓞')), // OSAGE CAPITAL LETTER EHCHA
+    ('\u{104B7}', CharMapAction::SubChar('𐓟')), // OSAGE CAPITAL LETTER E
+    ('\u{104B8}', CharMapAction::SubChar('𐓠')), // OSAGE CAPITAL LETTER EIN
+    ('\u{104B9}', CharMapAction::SubChar('𐓡')), // OSAGE CAPITAL LETTER HA
+    ('\u{104BA}', CharMapAction::SubChar('𐓢')), // OSAGE CAPITAL LETTER HYA
+    ('\u{104BB}', CharMapAction::SubChar('𐓣')), // OSAGE CAPITAL LETTER I
+    ('\u{104BC}', CharMapAction::SubChar('𐓤')), // OSAGE CAPITAL LETTER KA
+    ('\u{104BD}', CharMapAction::SubChar('𐓥')), // OSAGE CAPITAL LETTER EHKA
+    ('\u{104BE}', CharMapAction::SubChar('𐓦')), // OSAGE CAPITAL LETTER KYA
+    ('\u{104BF}', CharMapAction::SubChar('𐓧')), // OSAGE CAPITAL LETTER LA
+    ('\u{104C0}', CharMapAction::SubChar('𐓨')), // OSAGE CAPITAL LETTER MA
+    ('\u{104C1}', CharMapAction::SubChar('𐓩')), // OSAGE CAPITAL LETTER NA
+    ('\u{104C2}', CharMapAction::SubChar('𐓪')), // OSAGE CAPITAL LETTER O
+    ('\u{104C3}', CharMapAction::SubChar('𐓫')), // OSAGE CAPITAL LETTER OIN
+    ('\u{104C4}', CharMapAction::SubChar('𐓬')), // OSAGE CAPITAL LETTER PA
+    ('\u{104C5}', CharMapAction::SubChar('𐓭')), // OSAGE CAPITAL LETTER EHPA
+    ('\u{104C6}', CharMapAction::SubChar('𐓮')), // OSAGE CAPITAL LETTER SA
+    ('\u{104C7}', CharMapAction::SubChar('𐓯')), // OSAGE CAPITAL LETTER SHA
+    ('\u{104C8}', CharMapAction::SubChar('𐓰')), // OSAGE CAPITAL LETTER TA
+    ('\u{104C9}', CharMapAction::SubChar('𐓱')), // OSAGE CAPITAL LETTER EHTA
+    ('\u{104CA}', CharMapAction::SubChar('𐓲')), // OSAGE CAPITAL LETTER TSA
+    ('\u{104CB}', CharMapAction::SubChar('𐓳')), // OSAGE CAPITAL LETTER EHTSA
+    ('\u{104CC}', CharMapAction::SubChar('𐓴')), // OSAGE CAPITAL LETTER TSHA
+    ('\u{104CD}', CharMapAction::SubChar('𐓵')), // OSAGE CAPITAL LETTER DHA
+    ('\u{104CE}', CharMapAction::SubChar('𐓶')), // OSAGE CAPITAL LETTER U
+    ('\u{104CF}', CharMapAction::SubChar('𐓷')), // OSAGE CAPITAL LETTER WA
+    ('\u{104D0}', CharMapAction::SubChar('𐓸')), // OSAGE CAPITAL LETTER KHA
+    ('\u{104D1}', CharMapAction::SubChar('𐓹')), // OSAGE CAPITAL LETTER GHA
+    ('\u{104D2}', CharMapAction::SubChar('𐓺')), // OSAGE CAPITAL LETTER ZA
+    ('\u{104D3}', CharMapAction::SubChar('𐓻')), // OSAGE CAPITAL LETTER ZHA
+    ('\u{10570}', CharMapAction::SubChar('𐖗')), // VITHKUQI CAPITAL LETTER A
+    ('\u{10571}', CharMapAction::SubChar('𐖘')), // VITHKUQI CAPITAL LETTER BBE
+    ('\u{10572}', CharMapAction::SubChar('𐖙')), // VITHKUQI CAPITAL LETTER BE
+    ('\u{10573}', CharMapAction::SubChar('𐖚')), // VITHKUQI CAPITAL LETTER CE
+    ('\u{10574}', CharMapAction::SubChar('𐖛')), // VITHKUQI CAPITAL LETTER CHE
+    ('\u{10575}', CharMapAction::SubChar('𐖜')), // VITHKUQI CAPITAL LETTER DE
+    ('\u{10576}', CharMapAction::SubChar('𐖝')), // VITHKUQI CAPITAL LETTER DHE
+    ('\u{10577}', CharMapAction::SubChar('𐖞')), // VITHKUQI CAPITAL LETTER EI
+    ('\u{10578}', CharMapAction::SubChar('𐖟')), // VITHKUQI CAPITAL LETTER E
+    ('\u{10579}', CharMapAction::SubChar('𐖠')), // VITHKUQI CAPITAL LETTER FE
+    ('\u{1057A}', CharMapAction::SubChar('𐖡')), // VITHKUQI CAPITAL LETTER GA
+    ('\u{1057C}', CharMapAction::SubChar('𐖣')), // VITHKUQI CAPITAL LETTER HA
+    ('\u{1057D}', CharMapAction::SubChar('𐖤')), // VITHKUQI CAPITAL LETTER HHA
+    ('\u{1057E}', CharMapAction::SubChar('𐖥')), // VITHKUQI CAPITAL LETTER I
+    ('\u{1057F}', CharMapAction::SubChar('𐖦')), // VITHKUQI CAPITAL LETTER IJE
+    ('\u{10580}', CharMapAction::SubChar('𐖧')), // VITHKUQI CAPITAL LETTER JE
+    ('\u{10581}', CharMapAction::SubChar('𐖨')), // VITHKUQI CAPITAL LETTER KA
+    ('\u{10582}', CharMapAction::SubChar('𐖩')), // VITHKUQI CAPITAL LETTER LA
+    ('\u{10583}', CharMapAction::SubChar('𐖪')), // VITHKUQI CAPITAL LETTER LLA
+    ('\u{10584}', CharMapAction::SubChar('𐖫')), // VITHKUQI CAPITAL LETTER ME
+    ('\u{10585}', CharMapAction::SubChar('𐖬')), // VITHKUQI CAPITAL LETTER NE
+    ('\u{10586}', CharMapAction::SubChar('𐖭')), // VITHKUQI CAPITAL LETTER NJE
+    ('\u{10587}', CharMapAction::SubChar('𐖮')), // VITHKUQI CAPITAL LETTER O
+    ('\u{10588}', CharMapAction::SubChar('𐖯')), // VITHKUQI CAPITAL LETTER PE
+    ('\u{10589}', CharMapAction::SubChar('𐖰')), // VITHKUQI CAPITAL LETTER QA
+    ('\u{1058A}', CharMapAction::SubChar('𐖱')), // VITHKUQI CAPITAL LETTER RE
+    ('\u{1058C}', CharMapAction::SubChar('𐖳')), // VITHKUQI CAPITAL LETTER SE
+    ('\u{1058D}', CharMapAction::SubChar('𐖴')), // VITHKUQI CAPITAL LETTER SHE
+    ('\u{1058E}', CharMapAction::SubChar('𐖵')), // VITHKUQI CAPITAL LETTER TE
+    ('\u{1058F}', CharMapAction::SubChar('𐖶')), // VITHKUQI CAPITAL LETTER THE
+    ('\u{10590}', CharMapAction::SubChar('𐖷')), // VITHKUQI CAPITAL LETTER U
+    ('\u{10591}', CharMapAction::SubChar('𐖸')), // VITHKUQI CAPITAL LETTER VE
+    ('\u{10592}', CharMapAction::SubChar('𐖹')), // VITHKUQI CAPITAL LETTER XE
+    ('\u{10594}', CharMapAction::SubChar('𐖻')), // VITHKUQI CAPITAL LETTER Y
+    ('\u{10595}', CharMapAction::SubChar('𐖼')), // VITHKUQI CAPITAL LETTER ZE
+    ('\u{10C80}', CharMapAction::SubChar('𐳀')), // OLD HUNGARIAN CAPITAL LETTER A
+    ('\u{10C81}', CharMapAction::SubChar('𐳁')), // OLD HUNGARIAN CAPITAL LETTER AA
+    ('\u{10C82}', CharMapAction::SubChar('𐳂')), // OLD HUNGARIAN CAPITAL LETTER EB
+    ('\u{10C83}', CharMapAction::SubChar('𐳃')), // OLD HUNGARIAN CAPITAL LETTER AMB
+    ('\u{10C84}', CharMapAction::SubChar('𐳄')), // OLD HUNGARIAN CAPITAL LETTER EC
+    ('\u{10C85}', CharMapAction::SubChar('𐳅')), // OLD HUNGARIAN CAPITAL LETTER ENC
+    ('\u{10C86}', CharMapAction::SubChar('𐳆')), // OLD HUNGARIAN CAPITAL LETTER ECS
+    ('\u{10C87}', CharMapAction::SubChar('𐳇')), // OLD HUNGARIAN CAPITAL LETTER ED
+    ('\u{10C88}', CharMapAction::SubChar('𐳈')), // OLD HUNGARIAN CAPITAL LETTER AND
+    ('\u{10C89}', CharMapAction::SubChar('𐳉')), // OLD HUNGARIAN CAPITAL LETTER E
+    ('\u{10C8A}', CharMapAction::SubChar('𐳊')), // OLD HUNGARIAN CAPITAL LETTER CLOSE E
+    ('\u{10C8B}', CharMapAction::SubChar('𐳋')), // OLD HUNGARIAN CAPITAL LETTER EE
+    ('\u{10C8C}', CharMapAction::SubChar('𐳌')), // OLD HUNGARIAN CAPITAL LETTER EF
+    ('\u{10C8D}', CharMapAction::SubChar('𐳍')), // OLD HUNGARIAN CAPITAL LETTER EG
+    ('\u{10C8E}', CharMapAction::SubChar('𐳎')), // OLD HUNGARIAN CAPITAL LETTER EGY
+    ('\u{10C8F}', CharMapAction::SubChar('𐳏')), // OLD HUNGARIAN CAPITAL LETTER EH
+    ('\u{10C90}', CharMapAction::SubChar('𐳐')), // OLD HUNGARIAN CAPITAL LETTER I
+    ('\u{10C91}', CharMapAction::SubChar('𐳑')), // OLD HUNGARIAN CAPITAL LETTER II
+    ('\u{10C92}', CharMapAction::SubChar('𐳒')), // OLD HUNGARIAN CAPITAL LETTER EJ
+    ('\u{10C93}', CharMapAction::SubChar('𐳓')), // OLD HUNGARIAN CAPITAL LETTER EK
+    ('\u{10C94}', CharMapAction::SubChar('𐳔')), // OLD HUNGARIAN CAPITAL LETTER AK
+    ('\u{10C95}', CharMapAction::SubChar('𐳕')), // OLD HUNGARIAN CAPITAL LETTER UNK
+    ('\u{10C96}', CharMapAction::SubChar('𐳖')), // OLD HUNGARIAN CAPITAL LETTER EL
+    ('\u{10C97}', CharMapAction::SubChar('𐳗')), // OLD HUNGARIAN CAPITAL LETTER ELY
+    ('\u{10C98}', CharMapAction::SubChar('𐳘')), // OLD HUNGARIAN CAPITAL LETTER EM
+    ('\u{10C99}', CharMapAction::SubChar('𐳙')), // OLD HUNGARIAN CAPITAL LETTER EN
+    ('\u{10C9A}', CharMapAction::SubChar('𐳚')), // OLD HUNGARIAN CAPITAL LETTER ENY
+    ('\u{10C9B}', CharMapAction::SubChar('𐳛')), // OLD HUNGARIAN CAPITAL LETTER O
+    ('\u{10C9C}', CharMapAction::SubChar('𐳜')), // OLD HUNGARIAN CAPITAL LETTER OO
+    ('\u{10C9D}', CharMapAction::SubChar('𐳝')), // OLD HUNGARIAN CAPITAL LETTER NIKOLSBURG OE
+    ('\u{10C9E}', CharMapAction::SubChar('𐳞')), // OLD HUNGARIAN CAPITAL LETTER RUDIMENTA OE
+    ('\u{10C9F}', CharMapAction::SubChar('𐳟')), // OLD HUNGARIAN CAPITAL LETTER OEE
+    ('\u{10CA0}', CharMapAction::SubChar('𐳠')), // OLD HUNGARIAN CAPITAL LETTER EP
+    ('\u{10CA1}', CharMapAction::SubChar('𐳡')), // OLD HUNGARIAN CAPITAL LETTER EMP
+    ('\u{10CA2}', CharMapAction::SubChar('𐳢')), // OLD HUNGARIAN CAPITAL LETTER ER
+    ('\u{10CA3}', CharMapAction::SubChar('𐳣')), // OLD HUNGARIAN CAPITAL LETTER SHORT ER
+    ('\u{10CA4}', CharMapAction::SubChar('𐳤')), // OLD HUNGARIAN CAPITAL LETTER ES
+    ('\u{10CA5}', CharMapAction::SubChar('𐳥')), // OLD HUNGARIAN CAPITAL LETTER ESZ
+    ('\u{10CA6}', CharMapAction::SubChar('𐳦')), // OLD HUNGARIAN CAPITAL LETTER ET
+    ('\u{10CA7}', CharMapAction::SubChar('𐳧')), // OLD HUNGARIAN CAPITAL LETTER ENT
+    ('\u{10CA8}', CharMapAction::SubChar('𐳨')), // OLD HUNGARIAN CAPITAL LETTER ETY
+    ('\u{10CA9}', CharMapAction::SubChar('𐳩')), // OLD HUNGARIAN CAPITAL LETTER ECH
+    ('\u{10CAA}', CharMapAction::SubChar('𐳪')), // OLD HUNGARIAN CAPITAL LETTER U
+    ('\u{10CAB}', CharMapAction::SubChar('𐳫')), // OLD HUNGARIAN CAPITAL LETTER UU
+    ('\u{10CAC}', CharMapAction::SubChar('𐳬')), // OLD HUNGARIAN CAPITAL LETTER NIKOLSBURG UE
+    ('\u{10CAD}', CharMapAction::SubChar('𐳭')), // OLD HUNGARIAN CAPITAL LETTER RUDIMENTA UE
+    ('\u{10CAE}', CharMapAction::SubChar('𐳮')), // OLD HUNGARIAN CAPITAL LETTER EV
+    ('\u{10CAF}', CharMapAction::SubChar('𐳯')), // OLD HUNGARIAN CAPITAL LETTER EZ
+    ('\u{10CB0}', CharMapAction::SubChar('𐳰')), // OLD HUNGARIAN CAPITAL LETTER EZS
+    ('\u{10CB1}', CharMapAction::SubChar('𐳱')), // OLD HUNGARIAN CAPITAL LETTER ENT-SHAPED SIGN
+    ('\u{10CB2}', CharMapAction::SubChar('𐳲')), // OLD HUNGARIAN CAPITAL LETTER US
+    ('\u{118A0}', CharMapAction::SubChar('𑣀')), // WARANG CITI CAPITAL LETTER NGAA
+    ('\u{118A1}', CharMapAction::SubChar('𑣁')), // WARANG CITI CAPITAL LETTER A
+    ('\u{118A2}', CharMapAction::SubChar('𑣂')), // WARANG CITI CAPITAL LETTER WI
+    ('\u{118A3}', CharMapAction::SubChar('𑣃')), // WARANG CITI CAPITAL LETTER YU
+    ('\u{118A4}', CharMapAction::SubChar('𑣄')), // WARANG CITI CAPITAL LETTER YA
+    ('\u{118A5}', CharMapAction::SubChar('𑣅')), // WARANG CITI CAPITAL LETTER YO
+    ('\u{118A6}', CharMapAction::SubChar('𑣆')), // WARANG CITI CAPITAL LETTER II
+    ('\u{118A7}', CharMapAction::SubChar('𑣇')), // WARANG CITI CAPITAL LETTER UU
+    ('\u{118A8}', CharMapAction::SubChar('𑣈')), // WARANG CITI CAPITAL LETTER E
+    ('\u{118A9}', CharMapAction::SubChar('𑣉')), // WARANG CITI CAPITAL LETTER O
+    ('\u{118AA}', CharMapAction::SubChar('𑣊')), // WARANG CITI CAPITAL LETTER ANG
+    ('\u{118AB}', CharMapAction::SubChar('𑣋')), // WARANG CITI CAPITAL LETTER GA
+    ('\u{118AC}', CharMapAction::SubChar('𑣌')), // WARANG CITI CAPITAL LETTER KO
+    ('\u{118AD}', CharMapAction::SubChar('𑣍')), // WARANG CITI CAPITAL LETTER ENY
+    ('\u{118AE}', CharMapAction::SubChar('𑣎')), // WARANG CITI CAPITAL LETTER YUJ
+    ('\u{118AF}', CharMapAction::SubChar('𑣏')), // WARANG CITI CAPITAL LETTER UC
+    ('\u{118B0}', CharMapAction::SubChar('𑣐')), // WARANG CITI CAPITAL LETTER ENN
+    ('\u{118B1}', CharMapAction::SubChar('𑣑')), // WARANG CITI CAPITAL LETTER ODD
+    ('\u{118B2}', CharMapAction::SubChar('𑣒')), // WARANG CITI CAPITAL LETTER TTE
+    ('\u{118B3}', CharMapAction::SubChar('𑣓')), // WARANG CITI CAPITAL LETTER NUNG
+    ('\u{118B4}', CharMapAction::SubChar('𑣔')), // WARANG CITI CAPITAL LETTER DA
+    ('\u{118B5}', CharMapAction::SubChar('𑣕')), // WARANG CITI CAPITAL LETTER AT
+    ('\u{118B6}', CharMapAction::SubChar('𑣖')), // WARANG CITI CAPITAL LETTER AM
+    ('\u{118B7}', CharMapAction::SubChar('𑣗')), // WARANG CITI CAPITAL LETTER BU
+    ('\u{118B8}', CharMapAction::SubChar('𑣘')), // WARANG CITI CAPITAL LETTER PU
+    ('\u{118B9}', CharMapAction::SubChar('𑣙')), // WARANG CITI CAPITAL LETTER HIYO
+    ('\u{118BA}', CharMapAction::SubChar('𑣚')), // WARANG CITI CAPITAL LETTER HOLO
+    ('\u{118BB}', CharMapAction::SubChar('𑣛')), // WARANG CITI CAPITAL LETTER HORR
+    ('\u{118BC}', CharMapAction::SubChar('𑣜')), // WARANG CITI CAPITAL LETTER HAR
+    ('\u{118BD}', CharMapAction::SubChar('𑣝')), // WARANG CITI CAPITAL LETTER SSUU
+    ('\u{118BE}', CharMapAction::SubChar('𑣞')), // WARANG CITI CAPITAL LETTER SII
+    ('\u{118BF}', CharMapAction::SubChar('𑣟')), // WARANG CITI CAPITAL LETTER VIYO
+    ('\u{16E40}', CharMapAction::SubChar('𖹠')), // MEDEFAIDRIN CAPITAL LETTER M
+    ('\u{16E41}', CharMapAction::SubChar('𖹡')), // MEDEFAIDRIN CAPITAL LETTER S
+    ('\u{16E42}', CharMapAction::SubChar('𖹢')), // MEDEFAIDRIN CAPITAL LETTER V
+    ('\u{16E43}', CharMapAction::SubChar('𖹣')), // MEDEFAIDRIN CAPITAL LETTER W
+    ('\u{16E44}', CharMapAction::SubChar('𖹤')), // MEDEFAIDRIN CAPITAL LETTER ATIU
+    ('\u{16E45}', CharMapAction::SubChar('𖹥')), // MEDEFAIDRIN CAPITAL LETTER Z
+    ('\u{16E46}', CharMapAction::SubChar('𖹦')), // MEDEFAIDRIN CAPITAL LETTER KP
+    ('\u{16E47}', CharMapAction::SubChar('𖹧')), // MEDEFAIDRIN CAPITAL LETTER P
+    ('\u{16E48}', CharMapAction::SubChar('𖹨')), // MEDEFAIDRIN CAPITAL LETTER T
+    ('\u{16E49}', CharMapAction::SubChar('𖹩')), // MEDEFAIDRIN CAPITAL LETTER G
+    ('\u{16E4A}', CharMapAction::SubChar('𖹪')), // MEDEFAIDRIN CAPITAL LETTER F
+    ('\u{16E4B}', CharMapAction::SubChar('𖹫')), // MEDEFAIDRIN CAPITAL LETTER I
+    ('\u{16E4C}', CharMapAction::SubChar('𖹬')), // MEDEFAIDRIN CAPITAL LETTER K
+    ('\u{16E4D}', CharMapAction::SubChar('𖹭')), // MEDEFAIDRIN CAPITAL LETTER A
+    ('\u{16E4E}', CharMapAction::SubChar('𖹮')), // MEDEFAIDRIN CAPITAL LETTER J
+    ('\u{16E4F}', CharMapAction::SubChar('𖹯')), // MEDEFAIDRIN CAPITAL LETTER E
+    ('\u{16E50}', CharMapAction::SubChar('𖹰')), // MEDEFAIDRIN CAPITAL LETTER B
+    ('\u{16E51}', CharMapAction::SubChar('𖹱')), // MEDEFAIDRIN CAPITAL LETTER C
+    ('\u{16E52}', CharMapAction::SubChar('𖹲')), // MEDEFAIDRIN CAPITAL LETTER U
+    ('\u{16E53}', CharMapAction::SubChar('𖹳')), // MEDEFAIDRIN CAPITAL LETTER YU
+    ('\u{16E54}', CharMapAction::SubChar('𖹴')), // MEDEFAIDRIN CAPITAL LETTER L
+    ('\u{16E55}', CharMapAction::SubChar('𖹵')), // MEDEFAIDRIN CAPITAL LETTER Q
+    ('\u{16E56}', CharMapAction::SubChar('𖹶')), // MEDEFAIDRIN CAPITAL LETTER HP
+    ('\u{16E57}', CharMapAction::SubChar('𖹷')), // MEDEFAIDRIN CAPITAL LETTER NY
+    ('\u{16E58}', CharMapAction::SubChar('𖹸')), // MEDEFAIDRIN CAPITAL LETTER X
+    ('\u{16E59}', CharMapAction::SubChar('𖹹')), // MEDEFAIDRIN CAPITAL LETTER D
+    ('\u{16E5A}', CharMapAction::SubChar('𖹺')), // MEDEFAIDRIN CAPITAL LETTER OE
+    ('\u{16E5B}', CharMapAction::SubChar('𖹻')), // MEDEFAIDRIN CAPITAL LETTER N
+    ('\u{16E5C}', CharMapAction::SubChar('𖹼')), // MEDEFAIDRIN CAPITAL LETTER R
+    ('\u{16E5D}', CharMapAction::SubChar('𖹽')), // MEDEFAIDRIN CAPITAL LETTER O
+    ('\u{16E5E}', CharMapAction::SubChar('𖹾')), // MEDEFAIDRIN CAPITAL LETTER AI
+    ('\u{16E5F}', CharMapAction::SubChar('𖹿')), // MEDEFAIDRIN CAPITAL LETTER Y
+    ('\u{1E900}', CharMapAction::SubChar('𞤢')), // ADLAM CAPITAL LETTER ALIF
+    ('\u{1E901}', CharMapAction::SubChar('𞤣')), // ADLAM CAPITAL LETTER DAALI
+    ('\u{1E902}', CharMapAction::SubChar('𞤤')), // ADLAM CAPITAL LETTER LAAM
+    ('\u{1E903}', CharMapAction::SubChar('𞤥')), // ADLAM CAPITAL LETTER MIIM
+    ('\u{1E904}', CharMapAction::SubChar('𞤦')), // ADLAM CAPITAL LETTER BA
+    ('\u{1E905}', CharMapAction::SubChar('𞤧')), // ADLAM CAPITAL LETTER SINNYIIYHE
+    ('\u{1E906}', CharMapAction::SubChar('𞤨')), // ADLAM CAPITAL LETTER PE
+    ('\u{1E907}', CharMapAction::SubChar('𞤩')), // ADLAM CAPITAL LETTER BHE
+    ('\u{1E908}', CharMapAction::SubChar('𞤪')), // ADLAM CAPITAL LETTER RA
+    ('\u{1E909}', CharMapAction::SubChar('𞤫')), // ADLAM CAPITAL LETTER E
+    ('\u{1E90A}', CharMapAction::SubChar('𞤬')), // ADLAM CAPITAL LETTER FA
+    ('\u{1E90B}', CharMapAction::SubChar('𞤭')), // ADLAM CAPITAL LETTER I
+    ('\u{1E90C}', CharMapAction::SubChar('𞤮')), // ADLAM CAPITAL LETTER O
+    ('\u{1E90D}', CharMapAction::SubChar('𞤯')), // ADLAM CAPITAL LETTER DHA
+    ('\u{1E90E}', CharMapAction::SubChar('𞤰')), // ADLAM CAPITAL LETTER YHE
+    ('\u{1E90F}', CharMapAction::SubChar('𞤱')), // ADLAM CAPITAL LETTER WAW
+    ('\u{1E910}', CharMapAction::SubChar('𞤲')), // ADLAM CAPITAL LETTER NUN
+    ('\u{1E911}', CharMapAction::SubChar('𞤳')), // ADLAM CAPITAL LETTER KAF
+    ('\u{1E912}', CharMapAction::SubChar('𞤴')), // ADLAM CAPITAL LETTER YA
+    ('\u{1E913}', CharMapAction::SubChar('𞤵')), // ADLAM CAPITAL LETTER U
+    ('\u{1E914}', CharMapAction::SubChar('𞤶')), // ADLAM CAPITAL LETTER JIIM
+    ('\u{1E915}', CharMapAction::SubChar('𞤷')), // ADLAM CAPITAL LETTER CHI
+    ('\u{1E916}', CharMapAction::SubChar('𞤸')), // ADLAM CAPITAL LETTER HA
+    ('\u{1E917}', CharMapAction::SubChar('𞤹')), // ADLAM CAPITAL LETTER QAAF
+    ('\u{1E918}', CharMapAction::SubChar('𞤺')), // ADLAM CAPITAL LETTER GA
+    ('\u{1E919}', CharMapAction::SubChar('𞤻')), // ADLAM CAPITAL LETTER NYA
+    ('\u{1E91A}', CharMapAction::SubChar('𞤼')), // ADLAM CAPITAL LETTER TU
+    ('\u{1E91B}', CharMapAction::SubChar('𞤽')), // ADLAM CAPITAL LETTER NHA
+    ('\u{1E91C}', CharMapAction::SubChar('𞤾')), // ADLAM CAPITAL LETTER VA
+    ('\u{1E91D}', CharMapAction::SubChar('𞤿')), // ADLAM CAPITAL LETTER KHA
+    ('\u{1E91E}', CharMapAction::SubChar('𞥀')), // ADLAM CAPITAL LETTER GBE
+    ('\u{1E91F}', CharMapAction::SubChar('𞥁')), // ADLAM CAPITAL LETTER ZAL
+    ('\u{1E920}', CharMapAction::SubChar('𞥂')), // ADLAM CAPITAL LETTER KPO
+    ('\u{1E921}', CharMapAction::SubChar('𞥃')), // ADLAM CAPITAL LETTER SHA
+];
+const _: () = assert_sorted(&LOWERCASE);
+
+/// Map from characters to their full uppercase mappings.
+#[rustfmt::skip]
+pub static UPPERCASE: [(char, CharMapAction); 1525] = [
+    ('\u{0061}', CharMapAction::SubChar('A')), // LATIN SMALL LETTER A
+    ('\u{0062}', CharMapAction::SubChar('B')), // LATIN SMALL LETTER B
+    ('\u{0063}', CharMapAction::SubChar('C')), // LATIN SMALL LETTER C
+    ('\u{0064}', CharMapAction::SubChar('D')), // LATIN SMALL LETTER D
+    ('\u{0065}', CharMapAction::SubChar('E')), // LATIN SMALL LETTER E
+    ('\u{0066}', CharMapAction::SubChar('F')), // LATIN SMALL LETTER F
+    ('\u{0067}', CharMapAction::SubChar('G')), // LATIN SMALL LETTER G
+    ('\u{0068}', CharMapAction::SubChar('H')), // LATIN SMALL LETTER H
+    ('\u{0069}', CharMapAction::SubChar('I')), // LATIN SMALL LETTER I
+    ('\u{006A}', CharMapAction::SubChar('J')), // LATIN SMALL LETTER J
+    ('\u{006B}', CharMapAction::SubChar('K')), // LATIN SMALL LETTER K
+    ('\u{006C}', CharMapAction::SubChar('L')), // LATIN SMALL LETTER L
+    ('\u{006D}', CharMapAction::SubChar('M')), // LATIN SMALL LETTER M
+    ('\u{006E}', CharMapAction::SubChar('N')), // LATIN SMALL LETTER N
+    ('\u{006F}', CharMapAction::SubChar('O')), // LATIN SMALL LETTER O
+    ('\u{0070}', CharMapAction::SubChar('P')), // LATIN SMALL LETTER P
+    ('\u{0071}', CharMapAction::SubChar('Q')), // LATIN SMALL LETTER Q
+    ('\u{0072}', CharMapAction::SubChar('R')), // LATIN SMALL LETTER R
+    ('\u{0073}', CharMapAction::SubChar('S')), // LATIN SMALL LETTER S
+    ('\u{0074}', CharMapAction::SubChar('T')), // LATIN SMALL LETTER T
+    ('\u{0075}', CharMapAction::SubChar('U')), // LATIN SMALL LETTER U
+    ('\u{0076}', CharMapAction::SubChar('V')), // LATIN SMALL LETTER V
+    ('\u{0077}', CharMapAction::SubChar('W')), // LATIN SMALL LETTER W
+    ('\u{0078}', CharMapAction::SubChar('X')), // LATIN SMALL LETTER X
+    ('\u{0079}', CharMapAction::SubChar('Y')), // LATIN SMALL LETTER Y
+    ('\u{007A}', CharMapAction::SubChar('Z')), // LATIN SMALL LETTER Z
+    ('\u{00B5}', CharMapAction::SubChar('Μ')), // MICRO SIGN
+    ('\u{00DF}', CharMapAction::SubStr("SS")), // LATIN SMALL LETTER SHARP S
+    ('\u{00E0}', CharMapAction::SubChar('À')), // LATIN SMALL LETTER A WITH GRAVE
+    ('\u{00E1}', CharMapAction::SubChar('Á')), // LATIN SMALL LETTER A WITH ACUTE
+    ('\u{00E2}', CharMapAction::SubChar('Â')), // LATIN SMALL LETTER A WITH CIRCUMFLEX
+    ('\u{00E3}', CharMapAction::SubChar('Ã')), // LATIN SMALL LETTER A WITH TILDE
+    ('\u{00E4}', CharMapAction::SubChar('Ä')), // LATIN SMALL LETTER A WITH DIAERESIS
+    ('\u{00E5}', CharMapAction::SubChar('Å')), // LATIN SMALL LETTER A WITH RING ABOVE
+    ('\u{00E6}', CharMapAction::SubChar('Æ')), // LATIN SMALL LETTER AE
+    ('\u{00E7}', CharMapAction::SubChar('Ç')), // LATIN SMALL LETTER C WITH CEDILLA
+    ('\u{00E8}', CharMapAction::SubChar('È')), // LATIN SMALL LETTER E WITH GRAVE
+    ('\u{00E9}', CharMapAction::SubChar('É')), // LATIN SMALL LETTER E WITH ACUTE
+    ('\u{00EA}', CharMapAction::SubChar('Ê')), // LATIN SMALL LETTER E WITH CIRCUMFLEX
+    ('\u{00EB}', CharMapAction::SubChar('Ë')), // LATIN SMALL LETTER E WITH DIAERESIS
+    ('\u{00EC}', CharMapAction::SubChar('Ì')), // LATIN SMALL LETTER I WITH GRAVE
+    ('\u{00ED}', CharMapAction::SubChar('Í')), // LATIN SMALL LETTER I WITH ACUTE
+    ('\u{00EE}', CharMapAction::SubChar('Î')), // LATIN SMALL LETTER I WITH CIRCUMFLEX
+    ('\u{00EF}', CharMapAction::SubChar('Ï')), // LATIN SMALL LETTER I WITH DIAERESIS
+    ('\u{00F0}', CharMapAction::SubChar('Ð')), // LATIN SMALL LETTER ETH
+    ('\u{00F1}', CharMapAction::SubChar('Ñ')), // LATIN SMALL LETTER N WITH TILDE
+    ('\u{00F2}', CharMapAction::SubChar('Ò')), // LATIN SMALL LETTER O WITH GRAVE
+    ('\u{00F3}', CharMapAction::SubChar('Ó')), // LATIN SMALL LETTER O WITH ACUTE
+    ('\u{00F4}', CharMapAction::SubChar('Ô')), // LATIN SMALL LETTER O WITH CIRCUMFLEX
+    ('\u{00F5}', CharMapAction::SubChar('Õ')), // LATIN SMALL LETTER O WITH TILDE
+    ('\u{00F6}', CharMapAction::SubChar('Ö')), // LATIN SMALL LETTER O WITH DIAERESIS
+    ('\u{00F8}', CharMapAction::SubChar('Ø')), // LATIN SMALL LETTER O WITH STROKE
+    ('\u{00F9}', CharMapAction::SubChar('Ù')), // LATIN SMALL LETTER U WITH GRAVE
+    ('\u{00FA}', CharMapAction::SubChar('Ú')), // LATIN SMALL LETTER U WITH ACUTE
+    ('\u{00FB}', CharMapAction::SubChar('Û')), // LATIN SMALL LETTER U WITH CIRCUMFLEX
+    ('\u{00FC}', CharMapAction::SubChar('Ü')), // LATIN SMALL LETTER U WITH DIAERESIS
+    ('\u{00FD}', CharMapAction::SubChar('Ý')), // LATIN SMALL LETTER Y WITH ACUTE
+    ('\u{00FE}', CharMapAction::SubChar('Þ')), // LATIN SMALL LETTER THORN
+    ('\u{00FF}', CharMapAction::SubChar('Ÿ')), // LATIN SMALL LETTER Y WITH DIAERESIS
+    ('\u{0101}', CharMapAction::SubChar('Ā')), // LATIN SMALL LETTER A WITH MACRON
+    ('\u{0103}', CharMapAction::SubChar('Ă')), // LATIN SMALL LETTER A WITH BREVE
+    ('\u{0105}', CharMapAction::SubChar('Ą')), // LATIN SMALL LETTER A WITH OGONEK
+    ('\u{0107}', CharMapAction::SubChar('Ć')), // LATIN SMALL LETTER C WITH ACUTE
+    ('\u{0109}', CharMapAction::SubChar('Ĉ')), // LATIN SMALL LETTER C WITH CIRCUMFLEX
+    ('\u{010B}', CharMapAction::SubChar('Ċ')), // LATIN SMALL LETTER C WITH DOT ABOVE
+    ('\u{010D}', CharMapAction::SubChar('Č')), // LATIN SMALL LETTER C WITH CARON
+    ('\u{010F}', CharMapAction::SubChar('Ď')), // LATIN SMALL LETTER D WITH CARON
+    ('\u{0111}', CharMapAction::SubChar('Đ')), // LATIN SMALL LETTER D WITH STROKE
+    ('\u{0113}', CharMapAction::SubChar('Ē')), // LATIN SMALL LETTER E WITH MACRON
+    ('\u{0115}', CharMapAction::SubChar('Ĕ')), // LATIN SMALL LETTER E WITH BREVE
+    ('\u{0117}', CharMapAction::SubChar('Ė')), // LATIN SMALL LETTER E WITH DOT ABOVE
+    ('\u{0119}', CharMapAction::SubChar('Ę')), // LATIN SMALL LETTER E WITH OGONEK
+    ('\u{011B}', CharMapAction::SubChar('Ě')), // LATIN SMALL LETTER E WITH CARON
+    ('\u{011D}', CharMapAction::SubChar('Ĝ')), // LATIN SMALL LETTER G WITH CIRCUMFLEX
+    ('\u{011F}', CharMapAction::SubChar('Ğ')), // LATIN SMALL LETTER G WITH BREVE
+    ('\u{0121}', CharMapAction::SubChar('Ġ')), // LATIN SMALL LETTER G WITH DOT ABOVE
+    ('\u{0123}', CharMapAction::SubChar('Ģ')), // LATIN SMALL LETTER G WITH CEDILLA
+    ('\u{0125}', CharMapAction::SubChar('Ĥ')), // LATIN SMALL LETTER H WITH CIRCUMFLEX
+    ('\u{0127}', CharMapAction::SubChar('Ħ')), // LATIN SMALL LETTER H WITH STROKE
+    ('\u{0129}', CharMapAction::SubChar('Ĩ')), // LATIN SMALL LETTER I WITH TILDE
+    ('\u{012B}', CharMapAction::SubChar('Ī')), // LATIN SMALL LETTER I WITH MACRON
+    ('\u{012D}', CharMapAction::SubChar('Ĭ')), // LATIN SMALL LETTER I WITH BREVE
+    ('\u{012F}', CharMapAction::SubChar('Į')), // LATIN SMALL LETTER I WITH OGONEK
+    ('\u{0131}', CharMapAction::SubChar('I')), // LATIN SMALL LETTER DOTLESS I
+    ('\u{0133}', CharMapAction::SubChar('Ĳ')), // LATIN SMALL LIGATURE IJ
+    ('\u{0135}', CharMapAction::SubChar('Ĵ')), // LATIN SMALL LETTER J WITH CIRCUMFLEX
+    ('\u{0137}', CharMapAction::SubChar('Ķ')), // LATIN SMALL LETTER K WITH CEDILLA
+    ('\u{013A}', CharMapAction::SubChar('Ĺ')), // LATIN SMALL LETTER L WITH ACUTE
+    ('\u{013C}', CharMapAction::SubChar('Ļ')), // LATIN SMALL LETTER L WITH CEDILLA
+    ('\u{013E}', CharMapAction::SubChar('Ľ')), // LATIN SMALL LETTER L WITH CARON
+    ('\u{0140}', CharMapAction::SubChar('Ŀ')), // LATIN SMALL LETTER L WITH MIDDLE DOT
+    ('\u{0142}', CharMapAction::SubChar('Ł')), // LATIN SMALL LETTER L WITH STROKE
+    ('\u{0144}', CharMapAction::SubChar('Ń')), // LATIN SMALL LETTER N WITH ACUTE
+    ('\u{0146}', CharMapAction::SubChar('Ņ')), // LATIN SMALL LETTER N WITH CEDILLA
+    ('\u{0148}', CharMapAction::SubChar('Ň')), // LATIN SMALL LETTER N WITH CARON
+    ('\u{0149}', CharMapAction::SubStr("ʼN")), // LATIN SMALL LETTER N PRECEDED BY APOSTROPHE
+    ('\u{014B}', CharMapAction::SubChar('Ŋ')), // LATIN SMALL LETTER ENG
+    ('\u{014D}', CharMapAction::SubChar('Ō')), // LATIN SMALL LETTER O WITH MACRON
+    ('\u{014F}', CharMapAction::SubChar('Ŏ')), // LATIN SMALL LETTER O WITH BREVE
+    ('\u{0151}', CharMapAction::SubChar('Ő')), // LATIN SMALL LETTER O WITH DOUBLE ACUTE
+    ('\u{0153}', CharMapAction::SubChar('Œ')), // LATIN SMALL LIGATURE OE
+    ('\u{0155}', CharMapAction::SubChar('Ŕ')), // LATIN SMALL LETTER R WITH ACUTE
+    ('\u{0157}', CharMapAction::SubChar('Ŗ')), // LATIN SMALL LETTER R WITH CEDILLA
+    ('\u{0159}', CharMapAction::SubChar('Ř')), // LATIN SMALL LETTER R WITH CARON
+    ('\u{015B}', CharMapAction::SubChar('Ś')), // LATIN SMALL LETTER S WITH ACUTE
+    ('\u{015D}', CharMapAction::SubChar('Ŝ')), // LATIN SMALL LETTER S WITH CIRCUMFLEX
+    ('\u{015F}', CharMapAction::SubChar('Ş')), // LATIN SMALL LETTER S WITH CEDILLA
+    ('\u{0161}', CharMapAction::SubChar('Š')), // LATIN SMALL LETTER S WITH CARON
+    ('\u{0163}', CharMapAction::SubChar('Ţ')), // LATIN SMALL LETTER T WITH CEDILLA
+    ('\u{0165}', CharMapAction::SubChar('Ť')), // LATIN SMALL LETTER T WITH CARON
+    ('\u{0167}', CharMapAction::SubChar('Ŧ')), // LATIN SMALL LETTER T WITH STROKE
+    ('\u{0169}', CharMapAction::SubChar('Ũ')), // LATIN SMALL LETTER U WITH TILDE
+    ('\u{016B}', CharMapAction::SubChar('Ū')), // LATIN SMALL LETTER U WITH MACRON
+    ('\u{016D}', CharMapAction::SubChar('Ŭ')), // LATIN SMALL LETTER U WITH BREVE
+    ('\u{016F}', CharMapAction::SubChar('Ů')), // LATIN SMALL LETTER U WITH RING ABOVE
+    ('\u{0171}', CharMapAction::SubChar('Ű')), // LATIN SMALL LETTER U WITH DOUBLE ACUTE
+    ('\u{0173}', CharMapAction::SubChar('Ų')), // LATIN SMALL LETTER U WITH OGONEK
+    ('\u{0175}', CharMapAction::SubChar('Ŵ')), // LATIN SMALL LETTER W WITH CIRCUMFLEX
+    ('\u{0177}', CharMapAction::SubChar('Ŷ')), // LATIN SMALL LETTER Y WITH CIRCUMFLEX
+    ('\u{017A}', CharMapAction::SubChar('Ź')), // LATIN SMALL LETTER Z WITH ACUTE
+    ('\u{017C}', CharMapAction::SubChar('Ż')), // LATIN SMALL LETTER Z WITH DOT ABOVE
+    ('\u{017E}', CharMapAction::SubChar('Ž')), // LATIN SMALL LETTER Z WITH CARON
+    ('\u{017F}', CharMapAction::SubChar('S')), // LATIN SMALL LETTER LONG S
+    ('\u{0180}', CharMapAction::SubChar('Ƀ')), // LATIN SMALL LETTER B WITH STROKE
+    ('\u{0183}', CharMapAction::SubChar('Ƃ')), // LATIN SMALL LETTER B WITH TOPBAR
+    ('\u{0185}', CharMapAction::SubChar('Ƅ')), // LATIN SMALL LETTER TONE SIX
+    ('\u{0188}', CharMapAction::SubChar('Ƈ')), // LATIN SMALL LETTER C WITH HOOK
+    ('\u{018C}', CharMapAction::SubChar('Ƌ')), // LATIN SMALL LETTER D WITH TOPBAR
+    ('\u{0192}', CharMapAction::SubChar('Ƒ')), // LATIN SMALL LETTER F WITH HOOK
+    ('\u{0195}', CharMapAction::SubChar('Ƕ')), // LATIN SMALL LETTER HV
+    ('\u{0199}', CharMapAction::SubChar('Ƙ')), // LATIN SMALL LETTER K WITH HOOK
+    ('\u{019A}', CharMapAction::SubChar('Ƚ')), // LATIN SMALL LETTER L WITH BAR
+    ('\u{019E}', CharMapAction::SubChar('Ƞ')), // LATIN SMALL LETTER N WITH LONG RIGHT LEG
+    ('\u{01A1}', CharMapAction::SubChar('Ơ')), // LATIN SMALL LETTER O WITH HORN
+    ('\u{01A3}', CharMapAction::SubChar('Ƣ')), // LATIN SMALL LETTER OI
+    ('\u{01A5}', CharMapAction::SubChar('Ƥ')), // LATIN SMALL LETTER P WITH HOOK
+    ('\u{01A8}', CharMapAction::SubChar('Ƨ')), // LATIN SMALL LETTER TONE TWO
+    ('\u{01AD}', CharMapAction::SubChar('Ƭ')), // LATIN SMALL LETTER T WITH HOOK
+    ('\u{01B0}', CharMapAction::SubChar('Ư')), // LATIN SMALL LETTER U WITH HORN
+    ('\u{01B4}', CharMapAction::SubChar('Ƴ')), // LATIN SMALL LETTER Y WITH HOOK
+    ('\u{01B6}', CharMapAction::SubChar('Ƶ')), // LATIN SMALL LETTER Z WITH STROKE
+    ('\u{01B9}', CharMapAction::SubChar('Ƹ')), // LATIN SMALL LETTER EZH REVERSED
+    ('\u{01BD}', CharMapAction::SubChar('Ƽ')), // LATIN SMALL LETTER TONE FIVE
+    ('\u{01BF}', CharMapAction::SubChar('Ƿ')), // LATIN LETTER WYNN
+    ('\u{01C5}', CharMapAction::SubChar('Ǆ')), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON
+    ('\u{01C6}', CharMapAction::SubChar('Ǆ')), // LATIN SMALL LETTER DZ WITH CARON
+    ('\u{01C8}', CharMapAction::SubChar('Ǉ')), // LATIN CAPITAL LETTER L WITH SMALL LETTER J
+    ('\u{01C9}', CharMapAction::SubChar('Ǉ')), // LATIN SMALL LETTER LJ
+    ('\u{01CB}', CharMapAction::SubChar('Ǌ')), // LATIN CAPITAL LETTER N WITH SMALL LETTER J
+    ('\u{01CC}', CharMapAction::SubChar('Ǌ')), // LATIN SMALL LETTER NJ
+    ('\u{01CE}', CharMapAction::SubChar('Ǎ')), // LATIN SMALL LETTER A WITH CARON
+    ('\u{01D0}', CharMapAction::SubChar('Ǐ')), // LATIN SMALL LETTER I WITH CARON
+    ('\u{01D2}', CharMapAction::SubChar('Ǒ')), // LATIN SMALL LETTER O WITH CARON
+    ('\u{01D4}', CharMapAction::SubChar('Ǔ')), // LATIN SMALL LETTER U WITH CARON
+    ('\u{01D6}', CharMapAction::SubChar('Ǖ')), // LATIN SMALL LETTER U WITH DIAERESIS AND MACRON
+    ('\u{01D8}', CharMapAction::SubChar('Ǘ')), // LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE
+    ('\u{01DA}', CharMapAction::SubChar('Ǚ')), // LATIN SMALL LETTER U WITH DIAERESIS AND CARON
+    ('\u{01DC}', CharMapAction::SubChar('Ǜ')), // LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE
+    ('\u{01DD}', CharMapAction::SubChar('Ǝ')), // LATIN SMALL LETTER TURNED E
+    ('\u{01DF}', CharMapAction::SubChar('Ǟ')), // LATIN SMALL LETTER A WITH DIAERESIS AND MACRON
+    ('\u{01E1}', CharMapAction::SubChar('Ǡ')), // LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON
+    ('\u{01E3}', CharMapAction::SubChar('Ǣ')), // LATIN SMALL LETTER AE WITH MACRON
+    ('\u{01E5}', CharMapAction::SubChar('Ǥ')), // LATIN SMALL LETTER G WITH STROKE
+    ('\u{01E7}', CharMapAction::SubChar('Ǧ')), // LATIN SMALL LETTER G WITH CARON
+    ('\u{01E9}', CharMapAction::SubChar('Ǩ')), // LATIN SMALL LETTER K WITH CARON
+    ('\u{01EB}', CharMapAction::SubChar('Ǫ')), // LATIN SMALL LETTER O WITH OGONEK
+    ('\u{01ED}', CharMapAction::SubChar('Ǭ')), // LATIN SMALL LETTER O WITH OGONEK AND MACRON
+    ('\u{01EF}', CharMapAction::SubChar('Ǯ')), // LATIN SMALL LETTER EZH WITH CARON
+    ('\u{01F0}', CharMapAction::SubStr("J\u{030C}")), // LATIN SMALL LETTER J WITH CARON
+    ('\u{01F2}', CharMapAction::SubChar('Ǳ')), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z
+    ('\u{01F3}', CharMapAction::SubChar('Ǳ')), // LATIN SMALL LETTER DZ
+    ('\u{01F5}', CharMapAction::SubChar('Ǵ')), // LATIN SMALL LETTER G WITH ACUTE
+    ('\u{01F9}', CharMapAction::SubChar('Ǹ')), // LATIN SMALL LETTER N WITH GRAVE
+    ('\u{01FB}', CharMapAction::SubChar('Ǻ')), // LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE
+    ('\u{01FD}', CharMapAction::SubChar('Ǽ')), // LATIN SMALL LETTER AE WITH ACUTE
+    ('\u{01FF}', CharMapAction::SubChar('Ǿ')), // LATIN SMALL LETTER O WITH STROKE AND ACUTE
+    ('\u{0201}', CharMapAction::SubChar('Ȁ')), // LATIN SMALL LETTER A WITH DOUBLE GRAVE
+    ('\u{0203}', CharMapAction::SubChar('Ȃ')), // LATIN SMALL LETTER A WITH INVERTED BREVE
+    ('\u{0205}', CharMapAction::SubChar('Ȅ')), // LATIN SMALL LETTER E WITH DOUBLE GRAVE
+    ('\u{0207}', CharMapAction::SubChar('Ȇ')), // LATIN SMALL LETTER E WITH INVERTED BREVE
+    ('\u{0209}', CharMapAction::SubChar('Ȉ')), // LATIN SMALL LETTER I WITH DOUBLE GRAVE
+    ('\u{020B}', CharMapAction::SubChar('Ȋ')), // LATIN SMALL LETTER I WITH INVERTED BREVE
+    ('\u{020D}', CharMapAction::SubChar('Ȍ')), // LATIN SMALL LETTER O WITH DOUBLE GRAVE
+    ('\u{020F}', CharMapAction::SubChar('Ȏ')), // LATIN SMALL LETTER O WITH INVERTED BREVE
+    ('\u{0211}', CharMapAction::SubChar('Ȑ')), // LATIN SMALL LETTER R WITH DOUBLE GRAVE
+    ('\u{0213}', CharMapAction::SubChar('Ȓ')), // LATIN SMALL LETTER R WITH INVERTED BREVE
+    ('\u{0215}', CharMapAction::SubChar('Ȕ')), // LATIN SMALL LETTER U WITH DOUBLE GRAVE
+    ('\u{0217}', CharMapAction::SubChar('Ȗ')), // LATIN SMALL LETTER U WITH INVERTED BREVE
+    ('\u{0219}', CharMapAction::SubChar('Ș')), // LATIN SMALL LETTER S WITH COMMA BELOW
+    ('\u{021B}', CharMapAction::SubChar('Ț')), // LATIN SMALL LETTER T WITH COMMA BELOW
+    ('\u{021D}', CharMapAction::SubChar('Ȝ')), // LATIN SMALL LETTER YOGH
+    ('\u{021F}', CharMapAction::SubChar('Ȟ')), // LATIN SMALL LETTER H WITH CARON
+    ('\u{0223}', CharMapAction::SubChar('Ȣ')), // LATIN SMALL LETTER OU
+    ('\u{0225}', CharMapAction::SubChar('Ȥ')), // LATIN SMALL LETTER Z WITH HOOK
+    ('\u{0227}', CharMapAction::SubChar('Ȧ')), // LATIN SMALL LETTER A WITH DOT ABOVE
+    ('\u{0229}', CharMapAction::SubChar('Ȩ')), // LATIN SMALL LETTER E WITH CEDILLA
+    ('\u{022B}', CharMapAction::SubChar('Ȫ')), // LATIN SMALL LETTER O WITH DIAERESIS AND MACRON
+    ('\u{022D}', CharMapAction::SubChar('Ȭ')), // LATIN SMALL LETTER O WITH TILDE AND MACRON
+    ('\u{022F}', CharMapAction::SubChar('Ȯ')), // LATIN SMALL LETTER O WITH DOT ABOVE
+    ('\u{0231}', CharMapAction::SubChar('Ȱ')), // LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON
+    ('\u{0233}', CharMapAction::SubChar('Ȳ')), // LATIN SMALL LETTER Y WITH MACRON
+    ('\u{023C}', CharMapAction::SubChar('Ȼ')), // LATIN SMALL LETTER C WITH STROKE
+    ('\u{023F}', CharMapAction::SubChar('Ȿ')), // LATIN SMALL LETTER S WITH SWASH TAIL
+    ('\u{0240}', CharMapAction::SubChar('Ɀ')), // LATIN SMALL LETTER Z WITH SWASH TAIL
+    ('\u{0242}', CharMapAction::SubChar('Ɂ')), // LATIN SMALL LETTER GLOTTAL STOP
+    ('\u{0247}', CharMapAction::SubChar('Ɇ')), // LATIN SMALL LETTER E WITH STROKE
+    ('\u{0249}', CharMapAction::SubChar('Ɉ')), // LATIN SMALL LETTER J WITH STROKE
+    ('\u{024B}', CharMapAction::SubChar('Ɋ')), // LATIN SMALL LETTER Q WITH HOOK TAIL
+    ('\u{024D}', CharMapAction::SubChar('Ɍ')), // LATIN SMALL LETTER R WITH STROKE
+    ('\u{024F}', CharMapAction::SubChar('Ɏ')), // LATIN SMALL LETTER Y WITH STROKE
+    ('\u{0250}', CharMapAction::SubChar('Ɐ')), // LATIN SMALL LETTER TURNED A
+    ('\u{0251}', CharMapAction::SubChar('Ɑ')), // LATIN SMALL LETTER ALPHA
+    ('\u{0252}', CharMapAction::SubChar('Ɒ')), // LATIN SMALL LETTER TURNED ALPHA
+    ('\u{0253}', CharMapAction::SubChar('Ɓ')), // LATIN SMALL LETTER B WITH HOOK
+    ('\u{0254}', CharMapAction::SubChar('Ɔ')), // LATIN SMALL LETTER OPEN O
+    ('\u{0256}', CharMapAction::SubChar('Ɖ')), // LATIN SMALL LETTER D WITH TAIL
+    ('\u{0257}', CharMapAction::SubChar('Ɗ')), // LATIN SMALL LETTER D WITH HOOK
+    ('\u{0259}', CharMapAction::SubChar('Ə')), // LATIN SMALL LETTER SCHWA
+    ('\u{025B}', CharMapAction::SubChar('Ɛ')), // LATIN SMALL LETTER OPEN E
+    ('\u{025C}', CharMapAction::SubChar('Ɜ')), // LATIN SMALL LETTER REVERSED OPEN E
+    ('\u{0260}', CharMapAction::SubChar('Ɠ')), // LATIN SMALL LETTER G WITH HOOK
+    ('\u{0261}', CharMapAction::SubChar('Ɡ')), // LATIN SMALL LETTER SCRIPT G
+    ('\u{0263}', CharMapAction::SubChar('Ɣ')), // LATIN SMALL LETTER GAMMA
+    ('\u{0265}', CharMapAction::SubChar('Ɥ')), // LATIN SMALL LETTER TURNED H
+    ('\u{0266}', CharMapAction::SubChar('Ɦ')), // LATIN SMALL LETTER H WITH HOOK
+    ('\u{0268}', CharMapAction::SubChar('Ɨ')), // LATIN SMALL LETTER I WITH STROKE
+    ('\u{0269}', CharMapAction::SubChar('Ɩ')), // LATIN SMALL LETTER IOTA
+    ('\u{026A}', CharMapAction::SubChar('Ɪ')), // LATIN LETTER SMALL CAPITAL I
+    ('\u{026B}', CharMapAction::SubChar('Ɫ')), // LATIN SMALL LETTER L WITH MIDDLE TILDE
+    ('\u{026C}', CharMapAction::SubChar('Ɬ')), // LATIN SMALL LETTER L WITH BELT
+    ('\u{026F}', CharMapAction::SubChar('Ɯ')), // LATIN SMALL LETTER TURNED M
+    ('\u{0271}', CharMapAction::SubChar('Ɱ')), // LATIN SMALL LETTER M WITH HOOK
+    ('\u{0272}', CharMapAction::SubChar('Ɲ')), // LATIN SMALL LETTER N WITH LEFT HOOK
+    ('\u{0275}', CharMapAction::SubChar('Ɵ')), // LATIN SMALL LETTER BARRED O
+    ('\u{027D}', CharMapAction::SubChar('Ɽ')), // LATIN SMALL LETTER R WITH TAIL
+    ('\u{0280}', CharMapAction::SubChar('Ʀ')), // LATIN LETTER SMALL CAPITAL R
+    ('\u{0282}', CharMapAction::SubChar('Ʂ')), // LATIN SMALL LETTER S WITH HOOK
+    ('\u{0283}', CharMapAction::SubChar('Ʃ')), // LATIN SMALL LETTER ESH
+    ('\u{0287}', CharMapAction::SubChar('Ʇ')), // LATIN SMALL LETTER TURNED T
+    ('\u{0288}', CharMapAction::SubChar('Ʈ')), // LATIN SMALL LETTER T WITH RETROFLEX HOOK
+    ('\u{0289}', CharMapAction::SubChar('Ʉ')), // LATIN SMALL LETTER U BAR
+    ('\u{028A}', CharMapAction::SubChar('Ʊ')), // LATIN SMALL LETTER UPSILON
+    ('\u{028B}', CharMapAction::SubChar('Ʋ')), // LATIN SMALL LETTER V WITH HOOK
+    ('\u{028C}', CharMapAction::SubChar('Ʌ')), // LATIN SMALL LETTER TURNED V
+    ('\u{0292}', CharMapAction::SubChar('Ʒ')), // LATIN SMALL LETTER EZH
+    ('\u{029D}', CharMapAction::SubChar('Ʝ')), // LATIN SMALL LETTER J WITH CROSSED-TAIL
+    ('\u{029E}', CharMapAction::SubChar('Ʞ')), // LATIN SMALL LETTER TURNED K
+    ('\u{0345}', CharMapAction::SubChar('Ι')), // COMBINING GREEK YPOGEGRAMMENI
+    ('\u{0371}', CharMapAction::SubChar('Ͱ')), // GREEK SMALL LETTER HETA
+    ('\u{0373}', CharMapAction::SubChar('Ͳ')), // GREEK SMALL LETTER ARCHAIC SAMPI
+    ('\u{0377}', CharMapAction::SubChar('Ͷ')), // GREEK SMALL LETTER PAMPHYLIAN DIGAMMA
+    ('\u{037B}', CharMapAction::SubChar('Ͻ')), // GREEK SMALL REVERSED LUNATE SIGMA SYMBOL
+    ('\u{037C}', CharMapAction::SubChar('Ͼ')), // GREEK SMALL DOTTED LUNATE SIGMA SYMBOL
+    ('\u{037D}', CharMapAction::SubChar('Ͽ')), // GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL
+    ('\u{0390}', CharMapAction::SubStr("Ι\u{0308}\u{0301}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS
+    ('\u{03AC}', CharMapAction::SubChar('Ά')), // GREEK SMALL LETTER ALPHA WITH TONOS
+    ('\u{03AD}', CharMapAction::SubChar('Έ')), // GREEK SMALL LETTER EPSILON WITH TONOS
+    ('\u{03AE}', CharMapAction::SubChar('Ή')), // GREEK SMALL LETTER ETA WITH TONOS
+    ('\u{03AF}', CharMapAction::SubChar('Ί')), // GREEK SMALL LETTER IOTA WITH TONOS
+    ('\u{03B0}', CharMapAction::SubStr("Υ\u{0308}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS
+    ('\u{03B1}', CharMapAction::SubChar('Α')), // GREEK SMALL LETTER ALPHA
+    ('\u{03B2}', CharMapAction::SubChar('Β')), // GREEK SMALL LETTER BETA
+    ('\u{03B3}', CharMapAction::SubChar('Γ')), // GREEK SMALL LETTER GAMMA
+    ('\u{03B4}', CharMapAction::SubChar('Δ')), // GREEK SMALL LETTER DELTA
+    ('\u{03B5}', CharMapAction::SubChar('Ε')), // GREEK SMALL LETTER EPSILON
+    ('\u{03B6}', CharMapAction::SubChar('Ζ')), // GREEK SMALL LETTER ZETA
+    ('\u{03B7}', CharMapAction::SubChar('Η')), // GREEK SMALL LETTER ETA
+    ('\u{03B8}', CharMapAction::SubChar('Θ')), // GREEK SMALL LETTER THETA
+    ('\u{03B9}', CharMapAction::SubChar('Ι')), // GREEK SMALL LETTER IOTA
+    ('\u{03BA}', CharMapAction::SubChar('Κ')), // GREEK SMALL LETTER KAPPA
+    ('\u{03BB}', CharMapAction::SubChar('Λ')), // GREEK SMALL LETTER LAMDA
+    ('\u{03BC}', CharMapAction::SubChar('Μ')), // GREEK SMALL LETTER MU
+    ('\u{03BD}', CharMapAction::SubChar('Ν')), // GREEK SMALL LETTER NU
+    ('\u{03BE}', CharMapAction::SubChar('Ξ')), // GREEK SMALL LETTER XI
+    ('\u{03BF}', CharMapAction::SubChar('Ο')), // GREEK SMALL LETTER OMICRON
+    ('\u{03C0}', CharMapAction::SubChar('Π')), // GREEK SMALL LETTER PI
+    ('\u{03C1}', CharMapAction::SubChar('Ρ')), // GREEK SMALL LETTER RHO
+    ('\u{03C2}', CharMapAction::SubChar('Σ')), // GREEK SMALL LETTER FINAL SIGMA
+    ('\u{03C3}', CharMapAction::SubChar('Σ')), // GREEK SMALL LETTER SIGMA
+    ('\u{03C4}', CharMapAction::SubChar('Τ')), // GREEK SMALL LETTER TAU
+    ('\u{03C5}', CharMapAction::SubChar('Υ')), // GREEK SMALL LETTER UPSILON
+    ('\u{03C6}', CharMapAction::SubChar('Φ')), // GREEK SMALL LETTER PHI
+    ('\u{03C7}', CharMapAction::SubChar('Χ')), // GREEK SMALL LETTER CHI
+    ('\u{03C8}', CharMapAction::SubChar('Ψ')), // GREEK SMALL LETTER PSI
+    ('\u{03C9}', CharMapAction::SubChar('Ω')), // GREEK SMALL LETTER OMEGA
+    ('\u{03CA}', CharMapAction::SubChar('Ϊ')), // GREEK SMALL LETTER IOTA WITH DIALYTIKA
+    ('\u{03CB}', CharMapAction::SubChar('Ϋ')), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03CC}', CharMapAction::SubChar('Ό')), // GREEK SMALL LETTER OMICRON WITH TONOS
+    ('\u{03CD}', CharMapAction::SubChar('Ύ')), // GREEK SMALL LETTER UPSILON WITH TONOS
+    ('\u{03CE}', CharMapAction::SubChar('Ώ')), // GREEK SMALL LETTER OMEGA WITH TONOS
+    ('\u{03D0}', CharMapAction::SubChar('Β')), // GREEK BETA SYMBOL
+    ('\u{03D1}', CharMapAction::SubChar('Θ')), // GREEK THETA SYMBOL
+    ('\u{03D5}', CharMapAction::SubChar('Φ')), // GREEK PHI SYMBOL
+    ('\u{03D6}', CharMapAction::SubChar('Π')), // GREEK PI SYMBOL
+    ('\u{03D7}', CharMapAction::SubChar('Ϗ')), // GREEK KAI SYMBOL
+    ('\u{03D9}', CharMapAction::SubChar('Ϙ')), // GREEK SMALL LETTER ARCHAIC KOPPA
+    ('\u{03DB}', CharMapAction::SubChar('Ϛ')), // GREEK SMALL LETTER STIGMA
+    ('\u{03DD}', CharMapAction::SubChar('Ϝ')), // GREEK SMALL LETTER DIGAMMA
+    ('\u{03DF}', CharMapAction::SubChar('Ϟ')), // GREEK SMALL LETTER KOPPA
+    ('\u{03E1}', CharMapAction::SubChar('Ϡ')), // GREEK SMALL LETTER SAMPI
+    ('\u{03E3}', CharMapAction::SubChar('Ϣ')), // COPTIC SMALL LETTER SHEI
+    ('\u{03E5}', CharMapAction::SubChar('Ϥ')), // COPTIC SMALL LETTER FEI
+    ('\u{03E7}', CharMapAction::SubChar('Ϧ')), // COPTIC SMALL LETTER KHEI
+    ('\u{03E9}', CharMapAction::SubChar('Ϩ')), // COPTIC SMALL LETTER HORI
+    ('\u{03EB}', CharMapAction::SubChar('Ϫ')), // COPTIC SMALL LETTER GANGIA
+    ('\u{03ED}', CharMapAction::SubChar('Ϭ')), // COPTIC SMALL LETTER SHIMA
+    ('\u{03EF}', CharMapAction::SubChar('Ϯ')), // COPTIC SMALL LETTER DEI
+    ('\u{03F0}', CharMapAction::SubChar('Κ')), // GREEK KAPPA SYMBOL
+    ('\u{03F1}', CharMapAction::SubChar('Ρ')), // GREEK RHO SYMBOL
+    ('\u{03F2}', CharMapAction::SubChar('Ϲ')), // GREEK LUNATE SIGMA SYMBOL
+    ('\u{03F3}', CharMapAction::SubChar('Ϳ')), // GREEK LETTER YOT
+    ('\u{03F5}', CharMapAction::SubChar('Ε')), // GREEK LUNATE EPSILON SYMBOL
+    ('\u{03F8}', CharMapAction::SubChar('Ϸ')), // GREEK SMALL LETTER SHO
+    ('\u{03FB}', CharMapAction::SubChar('Ϻ')), // GREEK SMALL LETTER SAN
+    ('\u{0430}', CharMapAction::SubChar('А')), // CYRILLIC SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('Б')), // CYRILLIC SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('В')), // CYRILLIC SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('Г')), // CYRILLIC SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('Д')), // CYRILLIC SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('Е')), // CYRILLIC SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubChar('Ж')), // CYRILLIC SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('З')), // CYRILLIC SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('И')), // CYRILLIC SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('Й')), // CYRILLIC SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('К')), // CYRILLIC SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('Л')), // CYRILLIC SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('М')), // CYRILLIC SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('Н')), // CYRILLIC SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('О')), // CYRILLIC SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('П')), // CYRILLIC SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('Р')), // CYRILLIC SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('С')), // CYRILLIC SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('Т')), // CYRILLIC SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('У')), // CYRILLIC SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('Ф')), // CYRILLIC SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('Х')), // CYRILLIC SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubChar('Ц')), // CYRILLIC SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubChar('Ч')), // CYRILLIC SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubChar('Ш')), // CYRILLIC SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubChar('Щ')), // CYRILLIC SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubChar('Ъ')), // CYRILLIC SMALL LETTER HARD SIGN
+    ('\u{044B}', CharMapAction::SubChar('Ы')), // CYRILLIC SMALL LETTER YERU
+    ('\u{044C}', CharMapAction::SubChar('Ь')), // CYRILLIC SMALL LETTER SOFT SIGN
+    ('\u{044D}', CharMapAction::SubChar('Э')), // CYRILLIC SMALL LETTER E
+    ('\u{044E}', CharMapAction::SubChar('Ю')), // CYRILLIC SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubChar('Я')), // CYRILLIC SMALL LETTER YA
+    ('\u{0450}', CharMapAction::SubChar('Ѐ')), // CYRILLIC SMALL LETTER IE WITH GRAVE
+    ('\u{0451}', CharMapAction::SubChar('Ё')), // CYRILLIC SMALL LETTER IO
+    ('\u{0452}', CharMapAction::SubChar('Ђ')), // CYRILLIC SMALL LETTER DJE
+    ('\u{0453}', CharMapAction::SubChar('Ѓ')), // CYRILLIC SMALL LETTER GJE
+    ('\u{0454}', CharMapAction::SubChar('Є')), // CYRILLIC SMALL LETTER UKRAINIAN IE
+    ('\u{0455}', CharMapAction::SubChar('Ѕ')), // CYRILLIC SMALL LETTER DZE
+    ('\u{0456}', CharMapAction::SubChar('І')), // CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0457}', CharMapAction::SubChar('Ї')), // CYRILLIC SMALL LETTER YI
+    ('\u{0458}', CharMapAction::SubChar('Ј')), // CYRILLIC SMALL LETTER JE
+    ('\u{0459}', CharMapAction::SubChar('Љ')), // CYRILLIC SMALL LETTER LJE
+    ('\u{045A}', CharMapAction::SubChar('Њ')), // CYRILLIC SMALL LETTER NJE
+    ('\u{045B}', CharMapAction::SubChar('Ћ')), // CYRILLIC SMALL LETTER TSHE
+    ('\u{045C}', CharMapAction::SubChar('Ќ')), // CYRILLIC SMALL LETTER KJE
+    ('\u{045D}', CharMapAction::SubChar('Ѝ')), // CYRILLIC SMALL LETTER I WITH GRAVE
+    ('\u{045E}', CharMapAction::SubChar('Ў')), // CYRILLIC SMALL LETTER SHORT U
+    ('\u{045F}', CharMapAction::SubChar('Џ')), // CYRILLIC SMALL LETTER DZHE
+    ('\u{0461}', CharMapAction::SubChar('Ѡ')), // CYRILLIC SMALL LETTER OMEGA
+    ('\u{0463}', CharMapAction::SubChar('Ѣ')), // CYRILLIC SMALL LETTER YAT
+    ('\u{0465}', CharMapAction::SubChar('Ѥ')), // CYRILLIC SMALL LETTER IOTIFIED E
+    ('\u{0467}', CharMapAction::SubChar('Ѧ')), // CYRILLIC SMALL LETTER LITTLE YUS
+    ('\u{0469}', CharMapAction::SubChar('Ѩ')), // CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS
+    ('\u{046B}', CharMapAction::SubChar('Ѫ')), // CYRILLIC SMALL LETTER BIG YUS
+    ('\u{046D}', CharMapAction::SubChar('Ѭ')), // CYRILLIC SMALL LETTER IOTIFIED BIG YUS
+    ('\u{046F}', CharMapAction::SubChar('Ѯ')), // CYRILLIC SMALL LETTER KSI
+    ('\u{0471}', CharMapAction::SubChar('Ѱ')), // CYRILLIC SMALL LETTER PSI
+    ('\u{0473}', CharMapAction::SubChar('Ѳ')), // CYRILLIC SMALL LETTER FITA
+    ('\u{0475}', CharMapAction::SubChar('Ѵ')), // CYRILLIC SMALL LETTER IZHITSA
+    ('\u{0477}', CharMapAction::SubChar('Ѷ')), // CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT
+    ('\u{0479}', CharMapAction::SubChar('Ѹ')), // CYRILLIC SMALL LETTER UK
+    ('\u{047B}', CharMapAction::SubChar('Ѻ')), // CYRILLIC SMALL LETTER ROUND OMEGA
+    ('\u{047D}', CharMapAction::SubChar('Ѽ')), // CYRILLIC SMALL LETTER OMEGA WITH TITLO
+    ('\u{047F}', CharMapAction::SubChar('Ѿ')), // CYRILLIC SMALL LETTER OT
+    ('\u{0481}', CharMapAction::SubChar('Ҁ')), // CYRILLIC SMALL LETTER KOPPA
+    ('\u{048B}', CharMapAction::SubChar('Ҋ')), // CYRILLIC SMALL LETTER SHORT I WITH TAIL
+    ('\u{048D}', CharMapAction::SubChar('Ҍ')), // CYRILLIC SMALL LETTER SEMISOFT SIGN
+    ('\u{048F}', CharMapAction::SubChar('Ҏ')), // CYRILLIC SMALL LETTER ER WITH TICK
+    ('\u{0491}', CharMapAction::SubChar('Ґ')), // CYRILLIC SMALL LETTER GHE WITH UPTURN
+    ('\u{0493}', CharMapAction::SubChar('Ғ')), // CYRILLIC SMALL LETTER GHE WITH STROKE
+    ('\u{0495}', CharMapAction::SubChar('Ҕ')), // CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK
+    ('\u{0497}', CharMapAction::SubChar('Җ')), // CYRILLIC SMALL LETTER ZHE WITH DESCENDER
+    ('\u{0499}', CharMapAction::SubChar('Ҙ')), // CYRILLIC SMALL LETTER ZE WITH DESCENDER
+    ('\u{049B}', CharMapAction::SubChar('Қ')), // CYRILLIC SMALL LETTER KA WITH DESCENDER
+    ('\u{049D}', CharMapAction::SubChar('Ҝ')), // CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE
+    ('\u{049F}', CharMapAction::SubChar('Ҟ')), // CYRILLIC SMALL LETTER KA WITH STROKE
+    ('\u{04A1}', CharMapAction::SubChar('Ҡ')), // CYRILLIC SMALL LETTER BASHKIR KA
+    ('\u{04A3}', CharMapAction::SubChar('Ң')), // CYRILLIC SMALL LETTER EN WITH DESCENDER
+    ('\u{04A5}', CharMapAction::SubChar('Ҥ')), // CYRILLIC SMALL LIGATURE EN GHE
+    ('\u{04A7}', CharMapAction::SubChar('Ҧ')), // CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK
+    ('\u{04A9}', CharMapAction::SubChar('Ҩ')), // CYRILLIC SMALL LETTER ABKHASIAN HA
+    ('\u{04AB}', CharMapAction::SubChar('Ҫ')), // CYRILLIC SMALL LETTER ES WITH DESCENDER
+    ('\u{04AD}', CharMapAction::SubChar('Ҭ')), // CYRILLIC SMALL LETTER TE WITH DESCENDER
+    ('\u{04AF}', CharMapAction::SubChar('Ү')), // CYRILLIC SMALL LETTER STRAIGHT U
+    ('\u{04B1}', CharMapAction::SubChar('Ұ')), // CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B3}', CharMapAction::SubChar('Ҳ')), // CYRILLIC SMALL LETTER HA WITH DESCENDER
+    ('\u{04B5}', CharMapAction::SubChar('Ҵ')), // CYRILLIC SMALL LIGATURE TE TSE
+    ('\u{04B7}', CharMapAction::SubChar('Ҷ')), // CYRILLIC SMALL LETTER CHE WITH DESCENDER
+    ('\u{04B9}', CharMapAction::SubChar('Ҹ')), // CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE
+    ('\u{04BB}', CharMapAction::SubChar('Һ')), // CYRILLIC SMALL LETTER SHHA
+    ('\u{04BD}', CharMapAction::SubChar('Ҽ')), // CYRILLIC SMALL LETTER ABKHASIAN CHE
+    ('\u{04BF}', CharMapAction::SubChar('Ҿ')), // CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER
+    ('\u{04C2}', CharMapAction::SubChar('Ӂ')), // CYRILLIC SMALL LETTER ZHE WITH BREVE
+    ('\u{04C4}', CharMapAction::SubChar('Ӄ')), // CYRILLIC SMALL LETTER KA WITH HOOK
+    ('\u{04C6}', CharMapAction::SubChar('Ӆ')), // CYRILLIC SMALL LETTER EL WITH TAIL
+    ('\u{04C8}', CharMapAction::SubChar('Ӈ')), // CYRILLIC SMALL LETTER EN WITH HOOK
+    ('\u{04CA}', CharMapAction::SubChar('Ӊ')), // CYRILLIC SMALL LETTER EN WITH TAIL
+    ('\u{04CC}', CharMapAction::SubChar('Ӌ')), // CYRILLIC SMALL LETTER KHAKASSIAN CHE
+    ('\u{04CE}', CharMapAction::SubChar('Ӎ')), // CYRILLIC SMALL LETTER EM WITH TAIL
+    ('\u{04CF}', CharMapAction::SubChar('Ӏ')), // CYRILLIC SMALL LETTER PALOCHKA
+    ('\u{04D1}', CharMapAction::SubChar('Ӑ')), // CYRILLIC SMALL LETTER A WITH BREVE
+    ('\u{04D3}', CharMapAction::SubChar('Ӓ')), // CYRILLIC SMALL LETTER A WITH DIAERESIS
+    ('\u{04D5}', CharMapAction::SubChar('Ӕ')), // CYRILLIC SMALL LIGATURE A IE
+    ('\u{04D7}', CharMapAction::SubChar('Ӗ')), // CYRILLIC SMALL LETTER IE WITH BREVE
+    ('\u{04D9}', CharMapAction::SubChar('Ә')), // CYRILLIC SMALL LETTER SCHWA
+    ('\u{04DB}', CharMapAction::SubChar('Ӛ')), // CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS
+    ('\u{04DD}', CharMapAction::SubChar('Ӝ')), // CYRILLIC SMALL LETTER ZHE WITH DIAERESIS
+    ('\u{04DF}', CharMapAction::SubChar('Ӟ')), // CYRILLIC SMALL LETTER ZE WITH DIAERESIS
+    ('\u{04E1}', CharMapAction::SubChar('Ӡ')), // CYRILLIC SMALL LETTER ABKHASIAN DZE
+    ('\u{04E3}', CharMapAction::SubChar('Ӣ')), // CYRILLIC SMALL LETTER I WITH MACRON
+    ('\u{04E5}', CharMapAction::SubChar('Ӥ')), // CYRILLIC SMALL LETTER I WITH DIAERESIS
+    ('\u{04E7}', CharMapAction::SubChar('Ӧ')), // CYRILLIC SMALL LETTER O WITH DIAERESIS
+    ('\u{04E9}', CharMapAction::SubChar('Ө')), // CYRILLIC SMALL LETTER BARRED O
+    ('\u{04EB}', CharMapAction::SubChar('Ӫ')), // CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS
+    ('\u{04ED}', CharMapAction::SubChar('Ӭ')), // CYRILLIC SMALL LETTER E WITH DIAERESIS
+    ('\u{04EF}', CharMapAction::SubChar('Ӯ')), // CYRILLIC SMALL LETTER U WITH MACRON
+    ('\u{04F1}', CharMapAction::SubChar('Ӱ')), // CYRILLIC SMALL LETTER U WITH DIAERESIS
+    ('\u{04F3}', CharMapAction::SubChar('Ӳ')), // CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE
+    ('\u{04F5}', CharMapAction::SubChar('Ӵ')), // CYRILLIC SMALL LETTER CHE WITH DIAERESIS
+    ('\u{04F7}', CharMapAction::SubChar('Ӷ')), // CYRILLIC SMALL LETTER GHE WITH DESCENDER
+    ('\u{04F9}', CharMapAction::SubChar('Ӹ')), // CYRILLIC SMALL LETTER YERU WITH DIAERESIS
+    ('\u{04FB}', CharMapAction::SubChar('Ӻ')), // CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK
+    ('\u{04FD}', CharMapAction::SubChar('Ӽ')), // CYRILLIC SMALL LETTER HA WITH HOOK
+    ('\u{04FF}', CharMapAction::SubChar('Ӿ')), // CYRILLIC SMALL LETTER HA WITH STROKE
+    ('\u{0501}', CharMapAction::SubChar('Ԁ')), // CYRILLIC SMALL LETTER KOMI DE
+    ('\u{0503}', CharMapAction::SubChar('Ԃ')), // CYRILLIC SMALL LETTER KOMI DJE
+    ('\u{0505}', CharMapAction::SubChar('Ԅ')), // CYRILLIC SMALL LETTER KOMI ZJE
+    ('\u{0507}', CharMapAction::SubChar('Ԇ')), // CYRILLIC SMALL LETTER KOMI DZJE
+    ('\u{0509}', CharMapAction::SubChar('Ԉ')), // CYRILLIC SMALL LETTER KOMI LJE
+    ('\u{050B}', CharMapAction::SubChar('Ԋ')), // CYRILLIC SMALL LETTER KOMI NJE
+    ('\u{050D}', CharMapAction::SubChar('Ԍ')), // CYRILLIC SMALL LETTER KOMI SJE
+    ('\u{050F}', CharMapAction::SubChar('Ԏ')), // CYRILLIC SMALL LETTER KOMI TJE
+    ('\u{0511}', CharMapAction::SubChar('Ԑ')), // CYRILLIC SMALL LETTER REVERSED ZE
+    ('\u{0513}', CharMapAction::SubChar('Ԓ')), // CYRILLIC SMALL LETTER EL WITH HOOK
+    ('\u{0515}', CharMapAction::SubChar('Ԕ')), // CYRILLIC SMALL LETTER LHA
+    ('\u{0517}', CharMapAction::SubChar('Ԗ')), // CYRILLIC SMALL LETTER RHA
+    ('\u{0519}', CharMapAction::SubChar('Ԙ')), // CYRILLIC SMALL LETTER YAE
+    ('\u{051B}', CharMapAction::SubChar('Ԛ')), // CYRILLIC SMALL LETTER QA
+    ('\u{051D}', CharMapAction::SubChar('Ԝ')), // CYRILLIC SMALL LETTER WE
+    ('\u{051F}', CharMapAction::SubChar('Ԟ')), // CYRILLIC SMALL LETTER ALEUT KA
+    ('\u{0521}', CharMapAction::SubChar('Ԡ')), // CYRILLIC SMALL LETTER EL WITH MIDDLE HOOK
+    ('\u{0523}', CharMapAction::SubChar('Ԣ')), // CYRILLIC SMALL LETTER EN WITH MIDDLE HOOK
+    ('\u{0525}', CharMapAction::SubChar('Ԥ')), // CYRILLIC SMALL LETTER PE WITH DESCENDER
+    ('\u{0527}', CharMapAction::SubChar('Ԧ')), // CYRILLIC SMALL LETTER SHHA WITH DESCENDER
+    ('\u{0529}', CharMapAction::SubChar('Ԩ')), // CYRILLIC SMALL LETTER EN WITH LEFT HOOK
+    ('\u{052B}', CharMapAction::SubChar('Ԫ')), // CYRILLIC SMALL LETTER DZZHE
+    ('\u{052D}', CharMapAction::SubChar('Ԭ')), // CYRILLIC SMALL LETTER DCHE
+    ('\u{052F}', CharMapAction::SubChar('Ԯ')), // CYRILLIC SMALL LETTER EL WITH DESCENDER
+    ('\u{0561}', CharMapAction::SubChar('Ա')), // ARMENIAN SMALL LETTER AYB
+    ('\u{0562}', CharMapAction::SubChar('Բ')), // ARMENIAN SMALL LETTER BEN
+    ('\u{0563}', CharMapAction::SubChar('Գ')), // ARMENIAN SMALL LETTER GIM
+    ('\u{0564}', CharMapAction::SubChar('Դ')), // ARMENIAN SMALL LETTER DA
+    ('\u{0565}', CharMapAction::SubChar('Ե')), // ARMENIAN SMALL LETTER ECH
+    ('\u{0566}', CharMapAction::SubChar('Զ')), // ARMENIAN SMALL LETTER ZA
+    ('\u{0567}', CharMapAction::SubChar('Է')), // ARMENIAN SMALL LETTER EH
+    ('\u{0568}', CharMapAction::SubChar('Ը')), // ARMENIAN SMALL LETTER ET
+    ('\u{0569}', CharMapAction::SubChar('Թ')), // ARMENIAN SMALL LETTER TO
+    ('\u{056A}', CharMapAction::SubChar('Ժ')), // ARMENIAN SMALL LETTER ZHE
+    ('\u{056B}', CharMapAction::SubChar('Ի')), // ARMENIAN SMALL LETTER INI
+    ('\u{056C}', CharMapAction::SubChar('Լ')), // ARMENIAN SMALL LETTER LIWN
+    ('\u{056D}', CharMapAction::SubChar('Խ')), // ARMENIAN SMALL LETTER XEH
+    ('\u{056E}', CharMapAction::SubChar('Ծ')), // ARMENIAN SMALL LETTER CA
+    ('\u{056F}', CharMapAction::SubChar('Կ')), // ARMENIAN SMALL LETTER KEN
+    ('\u{0570}', CharMapAction::SubChar('Հ')), // ARMENIAN SMALL LETTER HO
+    ('\u{0571}', CharMapAction::SubChar('Ձ')), // ARMENIAN SMALL LETTER JA
+    ('\u{0572}', CharMapAction::SubChar('Ղ')), // ARMENIAN SMALL LETTER GHAD
+    ('\u{0573}', CharMapAction::SubChar('Ճ')), // ARMENIAN SMALL LETTER CHEH
+    ('\u{0574}', CharMapAction::SubChar('Մ')), // ARMENIAN SMALL LETTER MEN
+    ('\u{0575}', CharMapAction::SubChar('Յ')), // ARMENIAN SMALL LETTER YI
+    ('\u{0576}', CharMapAction::SubChar('Ն')), // ARMENIAN SMALL LETTER NOW
+    ('\u{0577}', CharMapAction::SubChar('Շ')), // ARMENIAN SMALL LETTER SHA
+    ('\u{0578}', CharMapAction::SubChar('Ո')), // ARMENIAN SMALL LETTER VO
+    ('\u{0579}', CharMapAction::SubChar('Չ')), // ARMENIAN SMALL LETTER CHA
+    ('\u{057A}', CharMapAction::SubChar('Պ')), // ARMENIAN SMALL LETTER PEH
+    ('\u{057B}', CharMapAction::SubChar('Ջ')), // ARMENIAN SMALL LETTER JHEH
+    ('\u{057C}', CharMapAction::SubChar('Ռ')), // ARMENIAN SMALL LETTER RA
+    ('\u{057D}', CharMapAction::SubChar('Ս')), // ARMENIAN SMALL LETTER SEH
+    ('\u{057E}', CharMapAction::SubChar('Վ')), // ARMENIAN SMALL LETTER VEW
+    ('\u{057F}', CharMapAction::SubChar('Տ')), // ARMENIAN SMALL LETTER TIWN
+    ('\u{0580}', CharMapAction::SubChar('Ր')), // ARMENIAN SMALL LETTER REH
+    ('\u{0581}', CharMapAction::SubChar('Ց')), // ARMENIAN SMALL LETTER CO
+    ('\u{0582}', CharMapAction::SubChar('Ւ')), // ARMENIAN SMALL LETTER YIWN
+    ('\u{0583}', CharMapAction::SubChar('Փ')), // ARMENIAN SMALL LETTER PIWR
+    ('\u{0584}', CharMapAction::SubChar('Ք')), // ARMENIAN SMALL LETTER KEH
+    ('\u{0585}', CharMapAction::SubChar('Օ')), // ARMENIAN SMALL LETTER OH
+    ('\u{0586}', CharMapAction::SubChar('Ֆ')), // ARMENIAN SMALL LETTER FEH
+    ('\u{0587}', CharMapAction::SubStr("ԵՒ")), // ARMENIAN SMALL LIGATURE ECH YIWN
+    ('\u{10D0}', CharMapAction::SubChar('Ა')), // GEORGIAN LETTER AN
+    ('\u{10D1}', CharMapAction::SubChar('Ბ')), // GEORGIAN LETTER BAN
+    ('\u{10D2}', CharMapAction::SubChar('Გ')), // GEORGIAN LETTER GAN
+    ('\u{10D3}', CharMapAction::SubChar('Დ')), // GEORGIAN LETTER DON
+    ('\u{10D4}', CharMapAction::SubChar('Ე')), // GEORGIAN LETTER EN
+    ('\u{10D5}', CharMapAction::SubChar('Ვ')), // GEORGIAN LETTER VIN
+    ('\u{10D6}', CharMapAction::SubChar('Ზ')), // GEORGIAN LETTER ZEN
+    ('\u{10D7}', CharMapAction::SubChar('Თ')), // GEORGIAN LETTER TAN
+    ('\u{10D8}', CharMapAction::SubChar('Ი')), // GEORGIAN LETTER IN
+    ('\u{10D9}', CharMapAction::SubChar('Კ')), // GEORGIAN LETTER KAN
+    ('\u{10DA}', CharMapAction::SubChar('Ლ')), // GEORGIAN LETTER LAS
+    ('\u{10DB}', CharMapAction::SubChar('Მ')), // GEORGIAN LETTER MAN
+    ('\u{10DC}', CharMapAction::SubChar('Ნ')), // GEORGIAN LETTER NAR
+    ('\u{10DD}', CharMapAction::SubChar('Ო')), // GEORGIAN LETTER ON
+    ('\u{10DE}', CharMapAction::SubChar('Პ')), // GEORGIAN LETTER PAR
+    ('\u{10DF}', CharMapAction::SubChar('Ჟ')), // GEORGIAN LETTER ZHAR
+    ('\u{10E0}', CharMapAction::SubChar('Რ')), // GEORGIAN LETTER RAE
+    ('\u{10E1}', CharMapAction::SubChar('Ს')), // GEORGIAN LETTER SAN
+    ('\u{10E2}', CharMapAction::SubChar('Ტ')), // GEORGIAN LETTER TAR
+    ('\u{10E3}', CharMapAction::SubChar('Უ')), // GEORGIAN LETTER UN
+    ('\u{10E4}', CharMapAction::SubChar('Ფ')), // GEORGIAN LETTER PHAR
+    ('\u{10E5}', CharMapAction::SubChar('Ქ')), // GEORGIAN LETTER KHAR
+    ('\u{10E6}', CharMapAction::SubChar('Ღ')), // GEORGIAN LETTER GHAN
+    ('\u{10E7}', CharMapAction::SubChar('Ყ')), // GEORGIAN LETTER QAR
+    ('\u{10E8}', CharMapAction::SubChar('Შ')), // GEORGIAN LETTER SHIN
+    ('\u{10E9}', CharMapAction::SubChar('Ჩ')), // GEORGIAN LETTER CHIN
+    ('\u{10EA}', CharMapAction::SubChar('Ც')), // GEORGIAN LETTER CAN
+    ('\u{10EB}', CharMapAction::SubChar('Ძ')), // GEORGIAN LETTER JIL
+    ('\u{10EC}', CharMapAction::SubChar('Წ')), // GEORGIAN LETTER CIL
+    ('\u{10ED}', CharMapAction::SubChar('Ჭ')), // GEORGIAN LETTER CHAR
+    ('\u{10EE}', CharMapAction::SubChar('Ხ')), // GEORGIAN LETTER XAN
+    ('\u{10EF}', CharMapAction::SubChar('Ჯ')), // GEORGIAN LETTER JHAN
+    ('\u{10F0}', CharMapAction::SubChar('Ჰ')), // GEORGIAN LETTER HAE
+    ('\u{10F1}', CharMapAction::SubChar('Ჱ')), // GEORGIAN LETTER HE
+    ('\u{10F2}', CharMapAction::SubChar('Ჲ')), // GEORGIAN LETTER HIE
+    ('\u{10F3}', CharMapAction::SubChar('Ჳ')), // GEORGIAN LETTER WE
+    ('\u{10F4}', CharMapAction::SubChar('Ჴ')), // GEORGIAN LETTER HAR
+    ('\u{10F5}', CharMapAction::SubChar('Ჵ')), // GEORGIAN LETTER HOE
+    ('\u{10F6}', CharMapAction::SubChar('Ჶ')), // GEORGIAN LETTER FI
+    ('\u{10F7}', CharMapAction::SubChar('Ჷ')), // GEORGIAN LETTER YN
+    ('\u{10F8}', CharMapAction::SubChar('Ჸ')), // GEORGIAN LETTER ELIFI
+    ('\u{10F9}', CharMapAction::SubChar('Ჹ')), // GEORGIAN LETTER TURNED GAN
+    ('\u{10FA}', CharMapAction::SubChar('Ჺ')), // GEORGIAN LETTER AIN
+    ('\u{10FD}', CharMapAction::SubChar('Ჽ')), // GEORGIAN LETTER AEN
+    ('\u{10FE}', CharMapAction::SubChar('Ჾ')), // GEORGIAN LETTER HARD SIGN
+    ('\u{10FF}', CharMapAction::SubChar('Ჿ')), // GEORGIAN LETTER LABIAL SIGN
+    ('\u{13F8}', CharMapAction::SubChar('Ᏸ')), // CHEROKEE SMALL LETTER YE
+    ('\u{13F9}', CharMapAction::SubChar('Ᏹ')), // CHEROKEE SMALL LETTER YI
+    ('\u{13FA}', CharMapAction::SubChar('Ᏺ')), // CHEROKEE SMALL LETTER YO
+    ('\u{13FB}', CharMapAction::SubChar('Ᏻ')), // CHEROKEE SMALL LETTER YU
+    ('\u{13FC}', CharMapAction::SubChar('Ᏼ')), // CHEROKEE SMALL LETTER YV
+    ('\u{13FD}', CharMapAction::SubChar('Ᏽ')), // CHEROKEE SMALL LETTER MV
+    ('\u{1C80}', CharMapAction::SubChar('В')), // CYRILLIC SMALL LETTER ROUNDED VE
+    ('\u{1C81}', CharMapAction::SubChar('Д')), // CYRILLIC SMALL LETTER LONG-LEGGED DE
+    ('\u{1C82}', CharMapAction::SubChar('О')), // CYRILLIC SMALL LETTER NARROW O
+    ('\u{1C83}', CharMapAction::SubChar('С')), // CYRILLIC SMALL LETTER WIDE ES
+    ('\u{1C84}', CharMapAction::SubChar('Т')), // CYRILLIC SMALL LETTER TALL TE
+    ('\u{1C85}', CharMapAction::SubChar('Т')), // CYRILLIC SMALL LETTER THREE-LEGGED TE
+    ('\u{1C86}', CharMapAction::SubChar('Ъ')), // CYRILLIC SMALL LETTER TALL HARD SIGN
+    ('\u{1C87}', CharMapAction::SubChar('Ѣ')), // CYRILLIC SMALL LETTER TALL YAT
+    ('\u{1C88}', CharMapAction::SubChar('Ꙋ')), // CYRILLIC SMALL LETTER UNBLENDED UK
+    ('\u{1D79}', CharMapAction::SubChar('Ᵹ')), // LATIN SMALL LETTER INSULAR G
+    ('\u{1D7D}', CharMapAction::SubChar('Ᵽ')), // LATIN SMALL LETTER P WITH STROKE
+    ('\u{1D8E}', CharMapAction::SubChar('Ᶎ')), // LATIN SMALL LETTER Z WITH PALATAL HOOK
+    ('\u{1E01}', CharMapAction::SubChar('Ḁ')), // LATIN SMALL LETTER A WITH RING BELOW
+    ('\u{1E03}', CharMapAction::SubChar('Ḃ')), // LATIN SMALL LETTER B WITH DOT ABOVE
+    ('\u{1E05}', CharMapAction::SubChar('Ḅ')), // LATIN SMALL LETTER B WITH DOT BELOW
+    ('\u{1E07}', CharMapAction::SubChar('Ḇ')), // LATIN SMALL LETTER B WITH LINE BELOW
+    ('\u{1E09}', CharMapAction::SubChar('Ḉ')), // LATIN SMALL LETTER C WITH CEDILLA AND ACUTE
+    ('\u{1E0B}', CharMapAction::SubChar('Ḋ')), // LATIN SMALL LETTER D WITH DOT ABOVE
+    ('\u{1E0D}', CharMapAction::SubChar('Ḍ')), // LATIN SMALL LETTER D WITH DOT BELOW
+    ('\u{1E0F}', CharMapAction::SubChar('Ḏ')), // LATIN SMALL LETTER D WITH LINE BELOW
+    ('\u{1E11}', CharMapAction::SubChar('Ḑ')), // LATIN SMALL LETTER D WITH CEDILLA
+    ('\u{1E13}', CharMapAction::SubChar('Ḓ')), // LATIN SMALL LETTER D WITH CIRCUMFLEX BELOW
+    ('\u{1E15}', CharMapAction::SubChar('Ḕ')), // LATIN SMALL LETTER E WITH MACRON AND GRAVE
+    ('\u{1E17}', CharMapAction::SubChar('Ḗ')), // LATIN SMALL LETTER E WITH MACRON AND ACUTE
+    ('\u{1E19}', CharMapAction::SubChar('Ḙ')), // LATIN SMALL LETTER E WITH CIRCUMFLEX BELOW
+    ('\u{1E1B}', CharMapAction::SubChar('Ḛ')), // LATIN SMALL LETTER E WITH TILDE BELOW
+    ('\u{1E1D}', CharMapAction::SubChar('Ḝ')), // LATIN SMALL LETTER E WITH CEDILLA AND BREVE
+    ('\u{1E1F}', CharMapAction::SubChar('Ḟ')), // LATIN SMALL LETTER F WITH DOT ABOVE
+    ('\u{1E21}', CharMapAction::SubChar('Ḡ')), // LATIN SMALL LETTER G WITH MACRON
+    ('\u{1E23}', CharMapAction::SubChar('Ḣ')), // LATIN SMALL LETTER H WITH DOT ABOVE
+    ('\u{1E25}', CharMapAction::SubChar('Ḥ')), // LATIN SMALL LETTER H WITH DOT BELOW
+    ('\u{1E27}', CharMapAction::SubChar('Ḧ')), // LATIN SMALL LETTER H WITH DIAERESIS
+    ('\u{1E29}', CharMapAction::SubChar('Ḩ')), // LATIN SMALL LETTER H WITH CEDILLA
+    ('\u{1E2B}', CharMapAction::SubChar('Ḫ')), // LATIN SMALL LETTER H WITH BREVE BELOW
+    ('\u{1E2D}', CharMapAction::SubChar('Ḭ')), // LATIN SMALL LETTER I WITH TILDE BELOW
+    ('\u{1E2F}', CharMapAction::SubChar('Ḯ')), // LATIN SMALL LETTER I WITH DIAERESIS AND ACUTE
+    ('\u{1E31}', CharMapAction::SubChar('Ḱ')), // LATIN SMALL LETTER K WITH ACUTE
+    ('\u{1E33}', CharMapAction::SubChar('Ḳ')), // LATIN SMALL LETTER K WITH DOT BELOW
+    ('\u{1E35}', CharMapAction::SubChar('Ḵ')), // LATIN SMALL LETTER K WITH LINE BELOW
+    ('\u{1E37}', CharMapAction::SubChar('Ḷ')), // LATIN SMALL LETTER L WITH DOT BELOW
+    ('\u{1E39}', CharMapAction::SubChar('Ḹ')), // LATIN SMALL LETTER L WITH DOT BELOW AND MACRON
+    ('\u{1E3B}', CharMapAction::SubChar('Ḻ')), // LATIN SMALL LETTER L WITH LINE BELOW
+    ('\u{1E3D}', CharMapAction::SubChar('Ḽ')), // LATIN SMALL LETTER L WITH CIRCUMFLEX BELOW
+    ('\u{1E3F}', CharMapAction::SubChar('Ḿ')), // LATIN SMALL LETTER M WITH ACUTE
+    ('\u{1E41}', CharMapAction::SubChar('Ṁ')), // LATIN SMALL LETTER M WITH DOT ABOVE
+    ('\u{1E43}', CharMapAction::SubChar('Ṃ')), // LATIN SMALL LETTER M WITH DOT BELOW
+    ('\u{1E45}', CharMapAction::SubChar('Ṅ')), // LATIN SMALL LETTER N WITH DOT ABOVE
+    ('\u{1E47}', CharMapAction::SubChar('Ṇ')), // LATIN SMALL LETTER N WITH DOT BELOW
+    ('\u{1E49}', CharMapAction::SubChar('Ṉ')), // LATIN SMALL LETTER N WITH LINE BELOW
+    ('\u{1E4B}', CharMapAction::SubChar('Ṋ')), // LATIN SMALL LETTER N WITH CIRCUMFLEX BELOW
+    ('\u{1E4D}', CharMapAction::SubChar('Ṍ')), // LATIN SMALL LETTER O WITH TILDE AND ACUTE
+    ('\u{1E4F}', CharMapAction::SubChar('Ṏ')), // LATIN SMALL LETTER O WITH TILDE AND DIAERESIS
+    ('\u{1E51}', CharMapAction::SubChar('Ṑ')), // LATIN SMALL LETTER O WITH MACRON AND GRAVE
+    ('\u{1E53}', CharMapAction::SubChar('Ṓ')), // LATIN SMALL LETTER O WITH MACRON AND ACUTE
+    ('\u{1E55}', CharMapAction::SubChar('Ṕ')), // LATIN SMALL LETTER P WITH ACUTE
+    ('\u{1E57}', CharMapAction::SubChar('Ṗ')), // LATIN SMALL LETTER P WITH DOT ABOVE
+    ('\u{1E59}', CharMapAction::SubChar('Ṙ')), // LATIN SMALL LETTER R WITH DOT ABOVE
+    ('\u{1E5B}', CharMapAction::SubChar('Ṛ')), // LATIN SMALL LETTER R WITH DOT BELOW
+    ('\u{1E5D}', CharMapAction::SubChar('Ṝ')), // LATIN SMALL LETTER R WITH DOT BELOW AND MACRON
+    ('\u{1E5F}', CharMapAction::SubChar('Ṟ')), // LATIN SMALL LETTER R WITH LINE BELOW
+    ('\u{1E61}', CharMapAction::SubChar('Ṡ')), // LATIN SMALL LETTER S WITH DOT ABOVE
+    ('\u{1E63}', CharMapAction::SubChar('Ṣ')), // LATIN SMALL LETTER S WITH DOT BELOW
+    ('\u{1E65}', CharMapAction::SubChar('Ṥ')), // LATIN SMALL LETTER S WITH ACUTE AND DOT ABOVE
+    ('\u{1E67}', CharMapAction::SubChar('Ṧ')), // LATIN SMALL LETTER S WITH CARON AND DOT ABOVE
+    ('\u{1E69}', CharMapAction::SubChar('Ṩ')), // LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE
+    ('\u{1E6B}', CharMapAction::SubChar('Ṫ')), // LATIN SMALL LETTER T WITH DOT ABOVE
+    ('\u{1E6D}', CharMapAction::SubChar('Ṭ')), // LATIN SMALL LETTER T WITH DOT BELOW
+    ('\u{1E6F}', CharMapAction::SubChar('Ṯ')), // LATIN SMALL LETTER T WITH LINE BELOW
+    ('\u{1E71}', CharMapAction::SubChar('Ṱ')), // LATIN SMALL LETTER T WITH CIRCUMFLEX BELOW
+    ('\u{1E73}', CharMapAction::SubChar('Ṳ')), // LATIN SMALL LETTER U WITH DIAERESIS BELOW
+    ('\u{1E75}', CharMapAction::SubChar('Ṵ')), // LATIN SMALL LETTER U WITH TILDE BELOW
+    ('\u{1E77}', CharMapAction::SubChar('Ṷ')), // LATIN SMALL LETTER U WITH CIRCUMFLEX BELOW
+    ('\u{1E79}', CharMapAction::SubChar('Ṹ')), // LATIN SMALL LETTER U WITH TILDE AND ACUTE
+    ('\u{1E7B}', CharMapAction::SubChar('Ṻ')), // LATIN SMALL LETTER U WITH MACRON AND DIAERESIS
+    ('\u{1E7D}', CharMapAction::SubChar('Ṽ')), // LATIN SMALL LETTER V WITH TILDE
+    ('\u{1E7F}', CharMapAction::SubChar('Ṿ')), // LATIN SMALL LETTER V WITH DOT BELOW
+    ('\u{1E81}', CharMapAction::SubChar('Ẁ')), // LATIN SMALL LETTER W WITH GRAVE
+    ('\u{1E83}', CharMapAction::SubChar('Ẃ')), // LATIN SMALL LETTER W WITH ACUTE
+    ('\u{1E85}', CharMapAction::SubChar('Ẅ')), // LATIN SMALL LETTER W WITH DIAERESIS
+    ('\u{1E87}', CharMapAction::SubChar('Ẇ')), // LATIN SMALL LETTER W WITH DOT ABOVE
+    ('\u{1E89}', CharMapAction::SubChar('Ẉ')), // LATIN SMALL LETTER W WITH DOT BELOW
+    ('\u{1E8B}', CharMapAction::SubChar('Ẋ')), // LATIN SMALL LETTER X WITH DOT ABOVE
+    ('\u{1E8D}', CharMapAction::SubChar('Ẍ')), // LATIN SMALL LETTER X WITH DIAERESIS
+    ('\u{1E8F}', CharMapAction::SubChar('Ẏ')), // LATIN SMALL LETTER Y WITH DOT ABOVE
+    ('\u{1E91}', CharMapAction::SubChar('Ẑ')), // LATIN SMALL LETTER Z WITH CIRCUMFLEX
+    ('\u{1E93}', CharMapAction::SubChar('Ẓ')), // LATIN SMALL LETTER Z WITH DOT BELOW
+    ('\u{1E95}', CharMapAction::SubChar('Ẕ')), // LATIN SMALL LETTER Z WITH LINE BELOW
+    ('\u{1E96}', CharMapAction::SubStr("H\u{0331}")), // LATIN SMALL LETTER H WITH LINE BELOW
+    ('\u{1E97}', CharMapAction::SubStr("T\u{0308}")), // LATIN SMALL LETTER T WITH DIAERESIS
+    ('\u{1E98}', CharMapAction::SubStr("W\u{030A}")), // LATIN SMALL LETTER W WITH RING ABOVE
+    ('\u{1E99}', CharMapAction::SubStr("Y\u{030A}")), // LATIN SMALL LETTER Y WITH RING ABOVE
+    ('\u{1E9A}', CharMapAction::SubStr("Aʾ")), // LATIN SMALL LETTER A WITH RIGHT HALF RING
+    ('\u{1E9B}', CharMapAction::SubChar('Ṡ')), // LATIN SMALL LETTER LONG S WITH DOT ABOVE
+    ('\u{1EA1}', CharMapAction::SubChar('Ạ')), // LATIN SMALL LETTER A WITH DOT BELOW
+    ('\u{1EA3}', CharMapAction::SubChar('Ả')), // LATIN SMALL LETTER A WITH HOOK ABOVE
+    ('\u{1EA5}', CharMapAction::SubChar('Ấ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EA7}', CharMapAction::SubChar('Ầ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EA9}', CharMapAction::SubChar('Ẩ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EAB}', CharMapAction::SubChar('Ẫ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND TILDE
+    ('\u{1EAD}', CharMapAction::SubChar('Ậ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EAF}', CharMapAction::SubChar('Ắ')), // LATIN SMALL LETTER A WITH BREVE AND ACUTE
+    ('\u{1EB1}', CharMapAction::SubChar('Ằ')), // LATIN SMALL LETTER A WITH BREVE AND GRAVE
+    ('\u{1EB3}', CharMapAction::SubChar('Ẳ')), // LATIN SMALL LETTER A WITH BREVE AND HOOK ABOVE
+    ('\u{1EB5}', CharMapAction::SubChar('Ẵ')), // LATIN SMALL LETTER A WITH BREVE AND TILDE
+    ('\u{1EB7}', CharMapAction::SubChar('Ặ')), // LATIN SMALL LETTER A WITH BREVE AND DOT BELOW
+    ('\u{1EB9}', CharMapAction::SubChar('Ẹ')), // LATIN SMALL LETTER E WITH DOT BELOW
+    ('\u{1EBB}', CharMapAction::SubChar('Ẻ')), // LATIN SMALL LETTER E WITH HOOK ABOVE
+    ('\u{1EBD}', CharMapAction::SubChar('Ẽ')), // LATIN SMALL LETTER E WITH TILDE
+    ('\u{1EBF}', CharMapAction::SubChar('Ế')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EC1}', CharMapAction::SubChar('Ề')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EC3}', CharMapAction::SubChar('Ể')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EC5}', CharMapAction::SubChar('Ễ')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND TILDE
+    ('\u{1EC7}', CharMapAction::SubChar('Ệ')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EC9}', CharMapAction::SubChar('Ỉ')), // LATIN SMALL LETTER I WITH HOOK ABOVE
+    ('\u{1ECB}', CharMapAction::SubChar('Ị')), // LATIN SMALL LETTER I WITH DOT BELOW
+    ('\u{1ECD}', CharMapAction::SubChar('Ọ')), // LATIN SMALL LETTER O WITH DOT BELOW
+    ('\u{1ECF}', CharMapAction::SubChar('Ỏ')), // LATIN SMALL LETTER O WITH HOOK ABOVE
+    ('\u{1ED1}', CharMapAction::SubChar('Ố')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND ACUTE
+    ('\u{1ED3}', CharMapAction::SubChar('Ồ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND GRAVE
+    ('\u{1ED5}', CharMapAction::SubChar('Ổ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1ED7}', CharMapAction::SubChar('Ỗ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND TILDE
+    ('\u{1ED9}', CharMapAction::SubChar('Ộ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EDB}', CharMapAction::SubChar('Ớ')), // LATIN SMALL LETTER O WITH HORN AND ACUTE
+    ('\u{1EDD}', CharMapAction::SubChar('Ờ')), // LATIN SMALL LETTER O WITH HORN AND GRAVE
+    ('\u{1EDF}', CharMapAction::SubChar('Ở')), // LATIN SMALL LETTER O WITH HORN AND HOOK ABOVE
+    ('\u{1EE1}', CharMapAction::SubChar('Ỡ')), // LATIN SMALL LETTER O WITH HORN AND TILDE
+    ('\u{1EE3}', CharMapAction::SubChar('Ợ')), // LATIN SMALL LETTER O WITH HORN AND DOT BELOW
+    ('\u{1EE5}', CharMapAction::SubChar('Ụ')), // LATIN SMALL LETTER U WITH DOT BELOW
+    ('\u{1EE7}', CharMapAction::SubChar('Ủ')), // LATIN SMALL LETTER U WITH HOOK ABOVE
+    ('\u{1EE9}', CharMapAction::SubChar('Ứ')), // LATIN SMALL LETTER U WITH HORN AND ACUTE
+    ('\u{1EEB}', CharMapAction::SubChar('Ừ')), // LATIN SMALL LETTER U WITH HORN AND GRAVE
+    ('\u{1EED}', CharMapAction::SubChar('Ử')), // LATIN SMALL LETTER U WITH HORN AND HOOK ABOVE
+    ('\u{1EEF}', CharMapAction::SubChar('Ữ')), // LATIN SMALL LETTER U WITH HORN AND TILDE
+    ('\u{1EF1}', CharMapAction::SubChar('Ự')), // LATIN SMALL LETTER U WITH HORN AND DOT BELOW
+    ('\u{1EF3}', CharMapAction::SubChar('Ỳ')), // LATIN SMALL LETTER Y WITH GRAVE
+    ('\u{1EF5}', CharMapAction::SubChar('Ỵ')), // LATIN SMALL LETTER Y WITH DOT BELOW
+    ('\u{1EF7}', CharMapAction::SubChar('Ỷ')), // LATIN SMALL LETTER Y WITH HOOK ABOVE
+    ('\u{1EF9}', CharMapAction::SubChar('Ỹ')), // LATIN SMALL LETTER Y WITH TILDE
+    ('\u{1EFB}', CharMapAction::SubChar('Ỻ')), // LATIN SMALL LETTER MIDDLE-WELSH LL
+    ('\u{1EFD}', CharMapAction::SubChar('Ỽ')), // LATIN SMALL LETTER MIDDLE-WELSH V
+    ('\u{1EFF}', CharMapAction::SubChar('Ỿ')), // LATIN SMALL LETTER Y WITH LOOP
+    ('\u{1F00}', CharMapAction::SubChar('Ἀ')), // GREEK SMALL LETTER ALPHA WITH PSILI
+    ('\u{1F01}', CharMapAction::SubChar('Ἁ')), // GREEK SMALL LETTER ALPHA WITH DASIA
+    ('\u{1F02}', CharMapAction::SubChar('Ἂ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F03}', CharMapAction::SubChar('Ἃ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F04}', CharMapAction::SubChar('Ἄ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F05}', CharMapAction::SubChar('Ἅ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F06}', CharMapAction::SubChar('Ἆ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F07}', CharMapAction::SubChar('Ἇ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F10}', CharMapAction::SubChar('Ἐ')), // GREEK SMALL LETTER EPSILON WITH PSILI
+    ('\u{1F11}', CharMapAction::SubChar('Ἑ')), // GREEK SMALL LETTER EPSILON WITH DASIA
+    ('\u{1F12}', CharMapAction::SubChar('Ἒ')), // GREEK SMALL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F13}', CharMapAction::SubChar('Ἓ')), // GREEK SMALL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F14}', CharMapAction::SubChar('Ἔ')), // GREEK SMALL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F15}', CharMapAction::SubChar('Ἕ')), // GREEK SMALL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F20}', CharMapAction::SubChar('Ἠ')), // GREEK SMALL LETTER ETA WITH PSILI
+    ('\u{1F21}', CharMapAction::SubChar('Ἡ')), // GREEK SMALL LETTER ETA WITH DASIA
+    ('\u{1F22}', CharMapAction::SubChar('Ἢ')), // GREEK SMALL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F23}', CharMapAction::SubChar('Ἣ')), // GREEK SMALL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F24}', CharMapAction::SubChar('Ἤ')), // GREEK SMALL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F25}', CharMapAction::SubChar('Ἥ')), // GREEK SMALL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F26}', CharMapAction::SubChar('Ἦ')), // GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F27}', CharMapAction::SubChar('Ἧ')), // GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F30}', CharMapAction::SubChar('Ἰ')), // GREEK SMALL LETTER IOTA WITH PSILI
+    ('\u{1F31}', CharMapAction::SubChar('Ἱ')), // GREEK SMALL LETTER IOTA WITH DASIA
+    ('\u{1F32}', CharMapAction::SubChar('Ἲ')), // GREEK SMALL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F33}', CharMapAction::SubChar('Ἳ')), // GREEK SMALL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F34}', CharMapAction::SubChar('Ἴ')), // GREEK SMALL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F35}', CharMapAction::SubChar('Ἵ')), // GREEK SMALL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F36}', CharMapAction::SubChar('Ἶ')), // GREEK SMALL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F37}', CharMapAction::SubChar('Ἷ')), // GREEK SMALL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F40}', CharMapAction::SubChar('Ὀ')), // GREEK SMALL LETTER OMICRON WITH PSILI
+    ('\u{1F41}', CharMapAction::SubChar('Ὁ')), // GREEK SMALL LETTER OMICRON WITH DASIA
+    ('\u{1F42}', CharMapAction::SubChar('Ὂ')), // GREEK SMALL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F43}', CharMapAction::SubChar('Ὃ')), // GREEK SMALL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F44}', CharMapAction::SubChar('Ὄ')), // GREEK SMALL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F45}', CharMapAction::SubChar('Ὅ')), // GREEK SMALL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F50}', CharMapAction::SubStr("Υ\u{0313}")), // GREEK SMALL LETTER UPSILON WITH PSILI
+    ('\u{1F51}', CharMapAction::SubChar('Ὑ')), // GREEK SMALL LETTER UPSILON WITH DASIA
+    ('\u{1F52}', CharMapAction::SubStr("Υ\u{0313}\u{0300}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND VARIA
+    ('\u{1F53}', CharMapAction::SubChar('Ὓ')), // GREEK SMALL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F54}', CharMapAction::SubStr("Υ\u{0313}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND OXIA
+    ('\u{1F55}', CharMapAction::SubChar('Ὕ')), // GREEK SMALL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F56}', CharMapAction::SubStr("Υ\u{0313}\u{0342}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND PERISPOMENI
+    ('\u{1F57}', CharMapAction::SubChar('Ὗ')), // GREEK SMALL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F60}', CharMapAction::SubChar('Ὠ')), // GREEK SMALL LETTER OMEGA WITH PSILI
+    ('\u{1F61}', CharMapAction::SubChar('Ὡ')), // GREEK SMALL LETTER OMEGA WITH DASIA
+    ('\u{1F62}', CharMapAction::SubChar('Ὢ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F63}', CharMapAction::SubChar('Ὣ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F64}', CharMapAction::SubChar('Ὤ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F65}', CharMapAction::SubChar('Ὥ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F66}', CharMapAction::SubChar('Ὦ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F67}', CharMapAction::SubChar('Ὧ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F70}', CharMapAction::SubChar('Ὰ')), // GREEK SMALL LETTER ALPHA WITH VARIA
+    ('\u{1F71}', CharMapAction::SubChar('Ά')), // GREEK SMALL LETTER ALPHA WITH OXIA
+    ('\u{1F72}', CharMapAction::SubChar('Ὲ')), // GREEK SMALL LETTER EPSILON WITH VARIA
+    ('\u{1F73}', CharMapAction::SubChar('Έ')), // GREEK SMALL LETTER EPSILON WITH OXIA
+    ('\u{1F74}', CharMapAction::SubChar('Ὴ')), // GREEK SMALL LETTER ETA WITH VARIA
+    ('\u{1F75}', CharMapAction::SubChar('Ή')), // GREEK SMALL LETTER ETA WITH OXIA
+    ('\u{1F76}', CharMapAction::SubChar('Ὶ')), // GREEK SMALL LETTER IOTA WITH VARIA
+    ('\u{1F77}', CharMapAction::SubChar('Ί')), // GREEK SMALL LETTER IOTA WITH OXIA
+    ('\u{1F78}', CharMapAction::SubChar('Ὸ')), // GREEK SMALL LETTER OMICRON WITH VARIA
+    ('\u{1F79}', CharMapAction::SubChar('Ό')), // GREEK SMALL LETTER OMICRON WITH OXIA
+    ('\u{1F7A}', CharMapAction::SubChar('Ὺ')), // GREEK SMALL LETTER UPSILON WITH VARIA
+    ('\u{1F7B}', CharMapAction::SubChar('Ύ')), // GREEK SMALL LETTER UPSILON WITH OXIA
+    ('\u{1F7C}', CharMapAction::SubChar('Ὼ')), // GREEK SMALL LETTER OMEGA WITH VARIA
+    ('\u{1F7D}', CharMapAction::SubChar('Ώ')), // GREEK SMALL LETTER OMEGA WITH OXIA
+    ('\u{1F80}', CharMapAction::SubStr("ἈΙ")), // GREEK SMALL LETTER ALPHA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F81}', CharMapAction::SubStr("ἉΙ")), // GREEK SMALL LETTER ALPHA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F82}', CharMapAction::SubStr("ἊΙ")), // GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F83}', CharMapAction::SubStr("ἋΙ")), // GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F84}', CharMapAction::SubStr("ἌΙ")), // GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F85}', CharMapAction::SubStr("ἍΙ")), // GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F86}', CharMapAction::SubStr("ἎΙ")), // GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F87}', CharMapAction::SubStr("ἏΙ")), // GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F88}', CharMapAction::SubStr("ἈΙ")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F89}', CharMapAction::SubStr("ἉΙ")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F8A}', CharMapAction::SubStr("ἊΙ")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8B}', CharMapAction::SubStr("ἋΙ")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8C}', CharMapAction::SubStr("ἌΙ")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8D}', CharMapAction::SubStr("ἍΙ")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8E}', CharMapAction::SubStr("ἎΙ")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F8F}', CharMapAction::SubStr("ἏΙ")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F90}', CharMapAction::SubStr("ἨΙ")), // GREEK SMALL LETTER ETA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F91}', CharMapAction::SubStr("ἩΙ")), // GREEK SMALL LETTER ETA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F92}', CharMapAction::SubStr("ἪΙ")), // GREEK SMALL LETTER ETA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F93}', CharMapAction::SubStr("ἫΙ")), // GREEK SMALL LETTER ETA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F94}', CharMapAction::SubStr("ἬΙ")), // GREEK SMALL LETTER ETA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F95}', CharMapAction::SubStr("ἭΙ")), // GREEK SMALL LETTER ETA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F96}', CharMapAction::SubStr("ἮΙ")), // GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F97}', CharMapAction::SubStr("ἯΙ")), // GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F98}', CharMapAction::SubStr("ἨΙ")), // GREEK CAPITAL LETTER ETA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F99}', CharMapAction::SubStr("ἩΙ")), // GREEK CAPITAL LETTER ETA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F9A}', CharMapAction::SubStr("ἪΙ")), // GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9B}', CharMapAction::SubStr("ἫΙ")), // GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9C}', CharMapAction::SubStr("ἬΙ")), // GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9D}', CharMapAction::SubStr("ἭΙ")), // GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9E}', CharMapAction::SubStr("ἮΙ")), // GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F9F}', CharMapAction::SubStr("ἯΙ")), // GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FA0}', CharMapAction::SubStr("ὨΙ")), // GREEK SMALL LETTER OMEGA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1FA1}', CharMapAction::SubStr("ὩΙ")), // GREEK SMALL LETTER OMEGA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1FA2}', CharMapAction::SubStr("ὪΙ")), // GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA3}', CharMapAction::SubStr("ὫΙ")), // GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA4}', CharMapAction::SubStr("ὬΙ")), // GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA5}', CharMapAction::SubStr("ὭΙ")), // GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA6}', CharMapAction::SubStr("ὮΙ")), // GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA7}', CharMapAction::SubStr("ὯΙ")), // GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA8}', CharMapAction::SubStr("ὨΙ")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1FA9}', CharMapAction::SubStr("ὩΙ")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1FAA}', CharMapAction::SubStr("ὪΙ")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAB}', CharMapAction::SubStr("ὫΙ")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAC}', CharMapAction::SubStr("ὬΙ")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAD}', CharMapAction::SubStr("ὭΙ")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAE}', CharMapAction::SubStr("ὮΙ")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FAF}', CharMapAction::SubStr("ὯΙ")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FB0}', CharMapAction::SubChar('Ᾰ')), // GREEK SMALL LETTER ALPHA WITH VRACHY
+    ('\u{1FB1}', CharMapAction::SubChar('Ᾱ')), // GREEK SMALL LETTER ALPHA WITH MACRON
+    ('\u{1FB2}', CharMapAction::SubStr("ᾺΙ")), // GREEK SMALL LETTER ALPHA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FB3}', CharMapAction::SubStr("ΑΙ")), // GREEK SMALL LETTER ALPHA WITH YPOGEGRAMMENI
+    ('\u{1FB4}', CharMapAction::SubStr("ΆΙ")), // GREEK SMALL LETTER ALPHA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FB6}', CharMapAction::SubStr("Α\u{0342}")), // GREEK SMALL LETTER ALPHA WITH PERISPOMENI
+    ('\u{1FB7}', CharMapAction::SubStr("Α\u{0342}Ι")), // GREEK SMALL LETTER ALPHA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FBC}', CharMapAction::SubStr("ΑΙ")), // GREEK CAPITAL LETTER ALPHA WITH PROSGEGRAMMENI
+    ('\u{1FBE}', CharMapAction::SubChar('Ι')), // GREEK PROSGEGRAMMENI
+    ('\u{1FC2}', CharMapAction::SubStr("ῊΙ")), // GREEK SMALL LETTER ETA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FC3}', CharMapAction::SubStr("ΗΙ")), // GREEK SMALL LETTER ETA WITH YPOGEGRAMMENI
+    ('\u{1FC4}', CharMapAction::SubStr("ΉΙ")), // GREEK SMALL LETTER ETA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FC6}', CharMapAction::SubStr("Η\u{0342}")), // GREEK SMALL LETTER ETA WITH PERISPOMENI
+    ('\u{1FC7}', CharMapAction::SubStr("Η\u{0342}Ι")), // GREEK SMALL LETTER ETA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FCC}', CharMapAction::SubStr("ΗΙ")), // GREEK CAPITAL LETTER ETA WITH PROSGEGRAMMENI
+    ('\u{1FD0}', CharMapAction::SubChar('Ῐ')), // GREEK SMALL LETTER IOTA WITH VRACHY
+    ('\u{1FD1}', CharMapAction::SubChar('Ῑ')), // GREEK SMALL LETTER IOTA WITH MACRON
+    ('\u{1FD2}', CharMapAction::SubStr("Ι\u{0308}\u{0300}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND VARIA
+    ('\u{1FD3}', CharMapAction::SubStr("Ι\u{0308}\u{0301}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND OXIA
+    ('\u{1FD6}', CharMapAction::SubStr("Ι\u{0342}")), // GREEK SMALL LETTER IOTA WITH PERISPOMENI
+    ('\u{1FD7}', CharMapAction::SubStr("Ι\u{0308}\u{0342}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FE0}', CharMapAction::SubChar('Ῠ')), // GREEK SMALL LETTER UPSILON WITH VRACHY
+    ('\u{1FE1}', CharMapAction::SubChar('Ῡ')), // GREEK SMALL LETTER UPSILON WITH MACRON
+    ('\u{1FE2}', CharMapAction::SubStr("Υ\u{0308}\u{0300}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND VARIA
+    ('\u{1FE3}', CharMapAction::SubStr("Υ\u{0308}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND OXIA
+    ('\u{1FE4}', CharMapAction::SubStr("Ρ\u{0313}")), // GREEK SMALL LETTER RHO WITH PSILI
+    ('\u{1FE5}', CharMapAction::SubChar('Ῥ')), // GREEK SMALL LETTER RHO WITH DASIA
+    ('\u{1FE6}', CharMapAction::SubStr("Υ\u{0342}")), // GREEK SMALL LETTER UPSILON WITH PERISPOMENI
+    ('\u{1FE7}', CharMapAction::SubStr("Υ\u{0308}\u{0342}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FF2}', CharMapAction::SubStr("ῺΙ")), // GREEK SMALL LETTER OMEGA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FF3}', CharMapAction::SubStr("ΩΙ")), // GREEK SMALL LETTER OMEGA WITH YPOGEGRAMMENI
+    ('\u{1FF4}', CharMapAction::SubStr("ΏΙ")), // GREEK SMALL LETTER OMEGA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FF6}', CharMapAction::SubStr("Ω\u{0342}")), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI
+    ('\u{1FF7}', CharMapAction::SubStr("Ω\u{0342}Ι")), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FFC}', CharMapAction::SubStr("ΩΙ")), // GREEK CAPITAL LETTER OMEGA WITH PROSGEGRAMMENI
+    ('\u{214E}', CharMapAction::SubChar('Ⅎ')), // TURNED SMALL F
+    ('\u{2170}', CharMapAction::SubChar('Ⅰ')), // SMALL ROMAN NUMERAL ONE
+    ('\u{2171}', CharMapAction::SubChar('Ⅱ')), // SMALL ROMAN NUMERAL TWO
+    ('\u{2172}', CharMapAction::SubChar('Ⅲ')), // SMALL ROMAN NUMERAL THREE
+    ('\u{2173}', CharMapAction::SubChar('Ⅳ')), // SMALL ROMAN NUMERAL FOUR
+    ('\u{2174}', CharMapAction::SubChar('Ⅴ')), // SMALL ROMAN NUMERAL FIVE
+    ('\u{2175}', CharMapAction::SubChar('Ⅵ')), // SMALL ROMAN NUMERAL SIX
+    ('\u{2176}', CharMapAction::SubChar('Ⅶ')), // SMALL ROMAN NUMERAL SEVEN
+    ('\u{2177}', CharMapAction::SubChar('Ⅷ')), // SMALL ROMAN NUMERAL EIGHT
+    ('\u{2178}', CharMapAction::SubChar('Ⅸ')), // SMALL ROMAN NUMERAL NINE
+    ('\u{2179}', CharMapAction::SubChar('Ⅹ')), // SMALL ROMAN NUMERAL TEN
+    ('\u{217A}', CharMapAction::SubChar('Ⅺ')), // SMALL ROMAN NUMERAL ELEVEN
+    ('\u{217B}', CharMapAction::SubChar('Ⅻ')), // SMALL ROMAN NUMERAL TWELVE
+    ('\u{217C}', CharMapAction::SubChar('Ⅼ')), // SMALL ROMAN NUMERAL FIFTY
+    ('\u{217D}', CharMapAction::SubChar('Ⅽ')), // SMALL ROMAN NUMERAL ONE HUNDRED
+    ('\u{217E}', CharMapAction::SubChar('Ⅾ')), // SMALL ROMAN NUMERAL FIVE HUNDRED
+    ('\u{217F}', CharMapAction::SubChar('Ⅿ')), // SMALL ROMAN NUMERAL ONE THOUSAND
+    ('\u{2184}', CharMapAction::SubChar('Ↄ')), // LATIN SMALL LETTER REVERSED C
+    ('\u{24D0}', CharMapAction::SubChar('Ⓐ')), // CIRCLED LATIN SMALL LETTER A
+    ('\u{24D1}', CharMapAction::SubChar('Ⓑ')), // CIRCLED LATIN SMALL LETTER B
+    ('\u{24D2}', CharMapAction::SubChar('Ⓒ')), // CIRCLED LATIN SMALL LETTER C
+    ('\u{24D3}', CharMapAction::SubChar('Ⓓ')), // CIRCLED LATIN SMALL LETTER D
+    ('\u{24D4}', CharMapAction::SubChar('Ⓔ')), // CIRCLED LATIN SMALL LETTER E
+    ('\u{24D5}', CharMapAction::SubChar('Ⓕ')), // CIRCLED LATIN SMALL LETTER F
+    ('\u{24D6}', CharMapAction::SubChar('Ⓖ')), // CIRCLED LATIN SMALL LETTER G
+    ('\u{24D7}', CharMapAction::SubChar('Ⓗ')), // CIRCLED LATIN SMALL LETTER H
+    ('\u{24D8}', CharMapAction::SubChar('Ⓘ')), // CIRCLED LATIN SMALL LETTER I
+    ('\u{24D9}', CharMapAction::SubChar('Ⓙ')), // CIRCLED LATIN SMALL LETTER J
+    ('\u{24DA}', CharMapAction::SubChar('Ⓚ')), // CIRCLED LATIN SMALL LETTER K
+    ('\u{24DB}', CharMapAction::SubChar('Ⓛ')), // CIRCLED LATIN SMALL LETTER L
+    ('\u{24DC}', CharMapAction::SubChar('Ⓜ')), // CIRCLED LATIN SMALL LETTER M
+    ('\u{24DD}', CharMapAction::SubChar('Ⓝ')), // CIRCLED LATIN SMALL LETTER N
+    ('\u{24DE}', CharMapAction::SubChar('Ⓞ')), // CIRCLED LATIN SMALL LETTER O
+    ('\u{24DF}', CharMapAction::SubChar('Ⓟ')), // CIRCLED LATIN SMALL LETTER P
+    ('\u{24E0}', CharMapAction::SubChar('Ⓠ')), // CIRCLED LATIN SMALL LETTER Q
+    ('\u{24E1}', CharMapAction::SubChar('Ⓡ')), // CIRCLED LATIN SMALL LETTER R
+    ('\u{24E2}', CharMapAction::SubChar('Ⓢ')), // CIRCLED LATIN SMALL LETTER S
+    ('\u{24E3}', CharMapAction::SubChar('Ⓣ')), // CIRCLED LATIN SMALL LETTER T
+    ('\u{24E4}', CharMapAction::SubChar('Ⓤ')), // CIRCLED LATIN SMALL LETTER U
+    ('\u{24E5}', CharMapAction::SubChar('Ⓥ')), // CIRCLED LATIN SMALL LETTER V
+    ('\u{24E6}', CharMapAction::SubChar('Ⓦ')), // CIRCLED LATIN SMALL LETTER W
+    ('\u{24E7}', CharMapAction::SubChar('Ⓧ')), // CIRCLED LATIN SMALL LETTER X
+    ('\u{24E8}', CharMapAction::SubChar('Ⓨ')), // CIRCLED LATIN SMALL LETTER Y
+    ('\u{24E9}', CharMapAction::SubChar('Ⓩ')), // CIRCLED LATIN SMALL LETTER Z
+    ('\u{2C30}', CharMapAction::SubChar('Ⰰ')), // GLAGOLITIC SMALL LETTER AZU
+    ('\u{2C31}', CharMapAction::SubChar('Ⰱ')), // GLAGOLITIC SMALL LETTER BUKY
+    ('\u{2C32}', CharMapAction::SubChar('Ⰲ')), // GLAGOLITIC SMALL LETTER VEDE
+    ('\u{2C33}', CharMapAction::SubChar('Ⰳ')), // GLAGOLITIC SMALL LETTER GLAGOLI
+    ('\u{2C34}', CharMapAction::SubChar('Ⰴ')), // GLAGOLITIC SMALL LETTER DOBRO
+    ('\u{2C35}', CharMapAction::SubChar('Ⰵ')), // GLAGOLITIC SMALL LETTER YESTU
+    ('\u{2C36}', CharMapAction::SubChar('Ⰶ')), // GLAGOLITIC SMALL LETTER ZHIVETE
+    ('\u{2C37}', CharMapAction::SubChar('Ⰷ')), // GLAGOLITIC SMALL LETTER DZELO
+    ('\u{2C38}', CharMapAction::SubChar('Ⰸ')), // GLAGOLITIC SMALL LETTER ZEMLJA
+    ('\u{2C39}', CharMapAction::SubChar('Ⰹ')), // GLAGOLITIC SMALL LETTER IZHE
+    ('\u{2C3A}', CharMapAction::SubChar('Ⰺ')), // GLAGOLITIC SMALL LETTER INITIAL IZHE
+    ('\u{2C3B}', CharMapAction::SubChar('Ⰻ')), // GLAGOLITIC SMALL LETTER I
+    ('\u{2C3C}', CharMapAction::SubChar('Ⰼ')), // GLAGOLITIC SMALL LETTER DJERVI
+    ('\u{2C3D}', CharMapAction::SubChar('Ⰽ')), // GLAGOLITIC SMALL LETTER KAKO
+    ('\u{2C3E}', CharMapAction::SubChar('Ⰾ')), // GLAGOLITIC SMALL LETTER LJUDIJE
+    ('\u{2C3F}', CharMapAction::SubChar('Ⰿ')), // GLAGOLITIC SMALL LETTER MYSLITE
+    ('\u{2C40}', CharMapAction::SubChar('Ⱀ')), // GLAGOLITIC SMALL LETTER NASHI
+    ('\u{2C41}', CharMapAction::SubChar('Ⱁ')), // GLAGOLITIC SMALL LETTER ONU
+    ('\u{2C42}', CharMapAction::SubChar('Ⱂ')), // GLAGOLITIC SMALL LETTER POKOJI
+    ('\u{2C43}', CharMapAction::SubChar('Ⱃ')), // GLAGOLITIC SMALL LETTER RITSI
+    ('\u{2C44}', CharMapAction::SubChar('Ⱄ')), // GLAGOLITIC SMALL LETTER SLOVO
+    ('\u{2C45}', CharMapAction::SubChar('Ⱅ')), // GLAGOLITIC SMALL LETTER TVRIDO
+    ('\u{2C46}', CharMapAction::SubChar('Ⱆ')), // GLAGOLITIC SMALL LETTER UKU
+    ('\u{2C47}', CharMapAction::SubChar('Ⱇ')), // GLAGOLITIC SMALL LETTER FRITU
+    ('\u{2C48}', CharMapAction::SubChar('Ⱈ')), // GLAGOLITIC SMALL LETTER HERU
+    ('\u{2C49}', CharMapAction::SubChar('Ⱉ')), // GLAGOLITIC SMALL LETTER OTU
+    ('\u{2C4A}', CharMapAction::SubChar('Ⱊ')), // GLAGOLITIC SMALL LETTER PE
+    ('\u{2C4B}', CharMapAction::SubChar('Ⱋ')), // GLAGOLITIC SMALL LETTER SHTA
+    ('\u{2C4C}', CharMapAction::SubChar('Ⱌ')), // GLAGOLITIC SMALL LETTER TSI
+    ('\u{2C4D}', CharMapAction::SubChar('Ⱍ')), // GLAGOLITIC SMALL LETTER CHRIVI
+    ('\u{2C4E}', CharMapAction::SubChar('Ⱎ')), // GLAGOLITIC SMALL LETTER SHA
+    ('\u{2C4F}', CharMapAction::SubChar('Ⱏ')), // GLAGOLITIC SMALL LETTER YERU
+    ('\u{2C50}', CharMapAction::SubChar('Ⱐ')), // GLAGOLITIC SMALL LETTER YERI
+    ('\u{2C51}', CharMapAction::SubChar('Ⱑ')), // GLAGOLITIC SMALL LETTER YATI
+    ('\u{2C52}', CharMapAction::SubChar('Ⱒ')), // GLAGOLITIC SMALL LETTER SPIDERY HA
+    ('\u{2C53}', CharMapAction::SubChar('Ⱓ')), // GLAGOLITIC SMALL LETTER YU
+    ('\u{2C54}', CharMapAction::SubChar('Ⱔ')), // GLAGOLITIC SMALL LETTER SMALL YUS
+    ('\u{2C55}', CharMapAction::SubChar('Ⱕ')), // GLAGOLITIC SMALL LETTER SMALL YUS WITH TAIL
+    ('\u{2C56}', CharMapAction::SubChar('Ⱖ')), // GLAGOLITIC SMALL LETTER YO
+    ('\u{2C57}', CharMapAction::SubChar('Ⱗ')), // GLAGOLITIC SMALL LETTER IOTATED SMALL YUS
+    ('\u{2C58}', CharMapAction::SubChar('Ⱘ')), // GLAGOLITIC SMALL LETTER BIG YUS
+    ('\u{2C59}', CharMapAction::SubChar('Ⱙ')), // GLAGOLITIC SMALL LETTER IOTATED BIG YUS
+    ('\u{2C5A}', CharMapAction::SubChar('Ⱚ')), // GLAGOLITIC SMALL LETTER FITA
+    ('\u{2C5B}', CharMapAction::SubChar('Ⱛ')), // GLAGOLITIC SMALL LETTER IZHITSA
+    ('\u{2C5C}', CharMapAction::SubChar('Ⱜ')), // GLAGOLITIC SMALL LETTER SHTAPIC
+    ('\u{2C5D}', CharMapAction::SubChar('Ⱝ')), // GLAGOLITIC SMALL LETTER TROKUTASTI A
+    ('\u{2C5E}', CharMapAction::SubChar('Ⱞ')), // GLAGOLITIC SMALL LETTER LATINATE MYSLITE
+    ('\u{2C5F}', CharMapAction::SubChar('Ⱟ')), // GLAGOLITIC SMALL LETTER CAUDATE CHRIVI
+    ('\u{2C61}', CharMapAction::SubChar('Ⱡ')), // LATIN SMALL LETTER L WITH DOUBLE BAR
+    ('\u{2C65}', CharMapAction::SubChar('Ⱥ')), // LATIN SMALL LETTER A WITH STROKE
+    ('\u{2C66}', CharMapAction::SubChar('Ⱦ')), // LATIN SMALL LETTER T WITH DIAGONAL STROKE
+    ('\u{2C68}', CharMapAction::SubChar('Ⱨ')), // LATIN SMALL LETTER H WITH DESCENDER
+    ('\u{2C6A}', CharMapAction::SubChar('Ⱪ')), // LATIN SMALL LETTER K WITH DESCENDER
+    ('\u{2C6C}', CharMapAction::SubChar('Ⱬ')), // LATIN SMALL LETTER Z WITH DESCENDER
+    ('\u{2C73}', CharMapAction::SubChar('Ⱳ')), // LATIN SMALL LETTER W WITH HOOK
+    ('\u{2C76}', CharMapAction::SubChar('Ⱶ')), // LATIN SMALL LETTER HALF H
+    ('\u{2C81}', CharMapAction::SubChar('Ⲁ')), // COPTIC SMALL LETTER ALFA
+    ('\u{2C83}', CharMapAction::SubChar('Ⲃ')), // COPTIC SMALL LETTER VIDA
+    ('\u{2C85}', CharMapAction::SubChar('Ⲅ')), // COPTIC SMALL LETTER GAMMA
+    ('\u{2C87}', CharMapAction::SubChar('Ⲇ')), // COPTIC SMALL LETTER DALDA
+    ('\u{2C89}', CharMapAction::SubChar('Ⲉ')), // COPTIC SMALL LETTER EIE
+    ('\u{2C8B}', CharMapAction::SubChar('Ⲋ')), // COPTIC SMALL LETTER SOU
+    ('\u{2C8D}', CharMapAction::SubChar('Ⲍ')), // COPTIC SMALL LETTER ZATA
+    ('\u{2C8F}', CharMapAction::SubChar('Ⲏ')), // COPTIC SMALL LETTER HATE
+    ('\u{2C91}', CharMapAction::SubChar('Ⲑ')), // COPTIC SMALL LETTER THETHE
+    ('\u{2C93}', CharMapAction::SubChar('Ⲓ')), // COPTIC SMALL LETTER IAUDA
+    ('\u{2C95}', CharMapAction::SubChar('Ⲕ')), // COPTIC SMALL LETTER KAPA
+    ('\u{2C97}', CharMapAction::SubChar('Ⲗ')), // COPTIC SMALL LETTER LAULA
+    ('\u{2C99}', CharMapAction::SubChar('Ⲙ')), // COPTIC SMALL LETTER MI
+    ('\u{2C9B}', CharMapAction::SubChar('Ⲛ')), // COPTIC SMALL LETTER NI
+    ('\u{2C9D}', CharMapAction::SubChar('Ⲝ')), // COPTIC SMALL LETTER KSI
+    ('\u{2C9F}', CharMapAction::SubChar('Ⲟ')), // COPTIC SMALL LETTER O
+    ('\u{2CA1}', CharMapAction::SubChar('Ⲡ')), // COPTIC SMALL LETTER PI
+    ('\u{2CA3}', CharMapAction::SubChar('Ⲣ')), // COPTIC SMALL LETTER RO
+    ('\u{2CA5}', CharMapAction::SubChar('Ⲥ')), // COPTIC SMALL LETTER SIMA
+    ('\u{2CA7}', CharMapAction::SubChar('Ⲧ')), // COPTIC SMALL LETTER TAU
+    ('\u{2CA9}', CharMapAction::SubChar('Ⲩ')), // COPTIC SMALL LETTER UA
+    ('\u{2CAB}', CharMapAction::SubChar('Ⲫ')), // COPTIC SMALL LETTER FI
+    ('\u{2CAD}', CharMapAction::SubChar('Ⲭ')), // COPTIC SMALL LETTER KHI
+    ('\u{2CAF}', CharMapAction::SubChar('Ⲯ')), // COPTIC SMALL LETTER PSI
+    ('\u{2CB1}', CharMapAction::SubChar('Ⲱ')), // COPTIC SMALL LETTER OOU
+    ('\u{2CB3}', CharMapAction::SubChar('Ⲳ')), // COPTIC SMALL LETTER DIALECT-P ALEF
+    ('\u{2CB5}', CharMapAction::SubChar('Ⲵ')), // COPTIC SMALL LETTER OLD COPTIC AIN
+    ('\u{2CB7}', CharMapAction::SubChar('Ⲷ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC EIE
+    ('\u{2CB9}', CharMapAction::SubChar('Ⲹ')), // COPTIC SMALL LETTER DIALECT-P KAPA
+    ('\u{2CBB}', CharMapAction::SubChar('Ⲻ')), // COPTIC SMALL LETTER DIALECT-P NI
+    ('\u{2CBD}', CharMapAction::SubChar('Ⲽ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC NI
+    ('\u{2CBF}', CharMapAction::SubChar('Ⲿ')), // COPTIC SMALL LETTER OLD COPTIC OOU
+    ('\u{2CC1}', CharMapAction::SubChar('Ⳁ')), // COPTIC SMALL LETTER SAMPI
+    ('\u{2CC3}', CharMapAction::SubChar('Ⳃ')), // COPTIC SMALL LETTER CROSSED SHEI
+    ('\u{2CC5}', CharMapAction::SubChar('Ⳅ')), // COPTIC SMALL LETTER OLD COPTIC SHEI
+    ('\u{2CC7}', CharMapAction::SubChar('Ⳇ')), // COPTIC SMALL LETTER OLD COPTIC ESH
+    ('\u{2CC9}', CharMapAction::SubChar('Ⳉ')), // COPTIC SMALL LETTER AKHMIMIC KHEI
+    ('\u{2CCB}', CharMapAction::SubChar('Ⳋ')), // COPTIC SMALL LETTER DIALECT-P HORI
+    ('\u{2CCD}', CharMapAction::SubChar('Ⳍ')), // COPTIC SMALL LETTER OLD COPTIC HORI
+    ('\u{2CCF}', CharMapAction::SubChar('Ⳏ')), // COPTIC SMALL LETTER OLD COPTIC HA
+    ('\u{2CD1}', CharMapAction::SubChar('Ⳑ')), // COPTIC SMALL LETTER L-SHAPED HA
+    ('\u{2CD3}', CharMapAction::SubChar('Ⳓ')), // COPTIC SMALL LETTER OLD COPTIC HEI
+    ('\u{2CD5}', CharMapAction::SubChar('Ⳕ')), // COPTIC SMALL LETTER OLD COPTIC HAT
+    ('\u{2CD7}', CharMapAction::SubChar('Ⳗ')), // COPTIC SMALL LETTER OLD COPTIC GANGIA
+    ('\u{2CD9}', CharMapAction::SubChar('Ⳙ')), // COPTIC SMALL LETTER OLD COPTIC DJA
+    ('\u{2CDB}', CharMapAction::SubChar('Ⳛ')), // COPTIC SMALL LETTER OLD COPTIC SHIMA
+    ('\u{2CDD}', CharMapAction::SubChar('Ⳝ')), // COPTIC SMALL LETTER OLD NUBIAN SHIMA
+    ('\u{2CDF}', CharMapAction::SubChar('Ⳟ')), // COPTIC SMALL LETTER OLD NUBIAN NGI
+    ('\u{2CE1}', CharMapAction::SubChar('Ⳡ')), // COPTIC SMALL LETTER OLD NUBIAN NYI
+    ('\u{2CE3}', CharMapAction::SubChar('Ⳣ')), // COPTIC SMALL LETTER OLD NUBIAN WAU
+    ('\u{2CEC}', CharMapAction::SubChar('Ⳬ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC SHEI
+    ('\u{2CEE}', CharMapAction::SubChar('Ⳮ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC GANGIA
+    ('\u{2CF3}', CharMapAction::SubChar('Ⳳ')), // COPTIC SMALL LETTER BOHAIRIC KHEI
+    ('\u{2D00}', CharMapAction::SubChar('Ⴀ')), // GEORGIAN SMALL LETTER AN
+    ('\u{2D01}', CharMapAction::SubChar('Ⴁ')), // GEORGIAN SMALL LETTER BAN
+    ('\u{2D02}', CharMapAction::SubChar('Ⴂ')), // GEORGIAN SMALL LETTER GAN
+    ('\u{2D03}', CharMapAction::SubChar('Ⴃ')), // GEORGIAN SMALL LETTER DON
+    ('\u{2D04}', CharMapAction::SubChar('Ⴄ')), // GEORGIAN SMALL LETTER EN
+    ('\u{2D05}', CharMapAction::SubChar('Ⴅ')), // GEORGIAN SMALL LETTER VIN
+    ('\u{2D06}', CharMapAction::SubChar('Ⴆ')), // GEORGIAN SMALL LETTER ZEN
+    ('\u{2D07}', CharMapAction::SubChar('Ⴇ')), // GEORGIAN SMALL LETTER TAN
+    ('\u{2D08}', CharMapAction::SubChar('Ⴈ')), // GEORGIAN SMALL LETTER IN
+    ('\u{2D09}', CharMapAction::SubChar('Ⴉ')), // GEORGIAN SMALL LETTER KAN
+    ('\u{2D0A}', CharMapAction::SubChar('Ⴊ')), // GEORGIAN SMALL LETTER LAS
+    ('\u{2D0B}', CharMapAction::SubChar('Ⴋ')), // GEORGIAN SMALL LETTER MAN
+    ('\u{2D0C}', CharMapAction::SubChar('Ⴌ')), // GEORGIAN SMALL LETTER NAR
+    ('\u{2D0D}', CharMapAction::SubChar('Ⴍ')), // GEORGIAN SMALL LETTER ON
+    ('\u{2D0E}', CharMapAction::SubChar('Ⴎ')), // GEORGIAN SMALL LETTER PAR
+    ('\u{2D0F}', CharMapAction::SubChar('Ⴏ')), // GEORGIAN SMALL LETTER ZHAR
+    ('\u{2D10}', CharMapAction::SubChar('Ⴐ')), // GEORGIAN SMALL LETTER RAE
+    ('\u{2D11}', CharMapAction::SubChar('Ⴑ')), // GEORGIAN SMALL LETTER SAN
+    ('\u{2D12}', CharMapAction::SubChar('Ⴒ')), // GEORGIAN SMALL LETTER TAR
+    ('\u{2D13}', CharMapAction::SubChar('Ⴓ')), // GEORGIAN SMALL LETTER UN
+    ('\u{2D14}', CharMapAction::SubChar('Ⴔ')), // GEORGIAN SMALL LETTER PHAR
+    ('\u{2D15}', CharMapAction::SubChar('Ⴕ')), // GEORGIAN SMALL LETTER KHAR
+    ('\u{2D16}', CharMapAction::SubChar('Ⴖ')), // GEORGIAN SMALL LETTER GHAN
+    ('\u{2D17}', CharMapAction::SubChar('Ⴗ')), // GEORGIAN SMALL LETTER QAR
+    ('\u{2D18}', CharMapAction::SubChar('Ⴘ')), // GEORGIAN SMALL LETTER SHIN
+    ('\u{2D19}', CharMapAction::SubChar('Ⴙ')), // GEORGIAN SMALL LETTER CHIN
+    ('\u{2D1A}', CharMapAction::SubChar('Ⴚ')), // GEORGIAN SMALL LETTER CAN
+    ('\u{2D1B}', CharMapAction::SubChar('Ⴛ')), // GEORGIAN SMALL LETTER JIL
+    ('\u{2D1C}', CharMapAction::SubChar('Ⴜ')), // GEORGIAN SMALL LETTER CIL
+    ('\u{2D1D}', CharMapAction::SubChar('Ⴝ')), // GEORGIAN SMALL LETTER CHAR
+    ('\u{2D1E}', CharMapAction::SubChar('Ⴞ')), // GEORGIAN SMALL LETTER XAN
+    ('\u{2D1F}', CharMapAction::SubChar('Ⴟ')), // GEORGIAN SMALL LETTER JHAN
+    ('\u{2D20}', CharMapAction::SubChar('Ⴠ')), // GEORGIAN SMALL LETTER HAE
+    ('\u{2D21}', CharMapAction::SubChar('Ⴡ')), // GEORGIAN SMALL LETTER HE
+    ('\u{2D22}', CharMapAction::SubChar('Ⴢ')), // GEORGIAN SMALL LETTER HIE
+    ('\u{2D23}', CharMapAction::SubChar('Ⴣ')), // GEORGIAN SMALL LETTER WE
+    ('\u{2D24}', CharMapAction::SubChar('Ⴤ')), // GEORGIAN SMALL LETTER HAR
+    ('\u{2D25}', CharMapAction::SubChar('Ⴥ')), // GEORGIAN SMALL LETTER HOE
+    ('\u{2D27}', CharMapAction::SubChar('Ⴧ')), // GEORGIAN SMALL LETTER YN
+    ('\u{2D2D}', CharMapAction::SubChar('Ⴭ')), // GEORGIAN SMALL LETTER AEN
+    ('\u{A641}', CharMapAction::SubChar('Ꙁ')), // CYRILLIC SMALL LETTER ZEMLYA
+    ('\u{A643}', CharMapAction::SubChar('Ꙃ')), // CYRILLIC SMALL LETTER DZELO
+    ('\u{A645}', CharMapAction::SubChar('Ꙅ')), // CYRILLIC SMALL LETTER REVERSED DZE
+    ('\u{A647}', CharMapAction::SubChar('Ꙇ')), // CYRILLIC SMALL LETTER IOTA
+    ('\u{A649}', CharMapAction::SubChar('Ꙉ')), // CYRILLIC SMALL LETTER DJERV
+    ('\u{A64B}', CharMapAction::SubChar('Ꙋ')), // CYRILLIC SMALL LETTER MONOGRAPH UK
+    ('\u{A64D}', CharMapAction::SubChar('Ꙍ')), // CYRILLIC SMALL LETTER BROAD OMEGA
+    ('\u{A64F}', CharMapAction::SubChar('Ꙏ')), // CYRILLIC SMALL LETTER NEUTRAL YER
+    ('\u{A651}', CharMapAction::SubChar('Ꙑ')), // CYRILLIC SMALL LETTER YERU WITH BACK YER
+    ('\u{A653}', CharMapAction::SubChar('Ꙓ')), // CYRILLIC SMALL LETTER IOTIFIED YAT
+    ('\u{A655}', CharMapAction::SubChar('Ꙕ')), // CYRILLIC SMALL LETTER REVERSED YU
+    ('\u{A657}', CharMapAction::SubChar('Ꙗ')), // CYRILLIC SMALL LETTER IOTIFIED A
+    ('\u{A659}', CharMapAction::SubChar('Ꙙ')), // CYRILLIC SMALL LETTER CLOSED LITTLE YUS
+    ('\u{A65B}', CharMapAction::SubChar('Ꙛ')), // CYRILLIC SMALL LETTER BLENDED YUS
+    ('\u{A65D}', CharMapAction::SubChar('Ꙝ')), // CYRILLIC SMALL LETTER IOTIFIED CLOSED LITTLE YUS
+    ('\u{A65F}', CharMapAction::SubChar('Ꙟ')), // CYRILLIC SMALL LETTER YN
+    ('\u{A661}', CharMapAction::SubChar('Ꙡ')), // CYRILLIC SMALL LETTER REVERSED TSE
+    ('\u{A663}', CharMapAction::SubChar('Ꙣ')), // CYRILLIC SMALL LETTER SOFT DE
+    ('\u{A665}', CharMapAction::SubChar('Ꙥ')), // CYRILLIC SMALL LETTER SOFT EL
+    ('\u{A667}', CharMapAction::SubChar('Ꙧ')), // CYRILLIC SMALL LETTER SOFT EM
+    ('\u{A669}', CharMapAction::SubChar('Ꙩ')), // CYRILLIC SMALL LETTER MONOCULAR O
+    ('\u{A66B}', CharMapAction::SubChar('Ꙫ')), // CYRILLIC SMALL LETTER BINOCULAR O
+    ('\u{A66D}', CharMapAction::SubChar('Ꙭ')), // CYRILLIC SMALL LETTER DOUBLE MONOCULAR O
+    ('\u{A681}', CharMapAction::SubChar('Ꚁ')), // CYRILLIC SMALL LETTER DWE
+    ('\u{A683}', CharMapAction::SubChar('Ꚃ')), // CYRILLIC SMALL LETTER DZWE
+    ('\u{A685}', CharMapAction::SubChar('Ꚅ')), // CYRILLIC SMALL LETTER ZHWE
+    ('\u{A687}', CharMapAction::SubChar('Ꚇ')), // CYRILLIC SMALL LETTER CCHE
+    ('\u{A689}', CharMapAction::SubChar('Ꚉ')), // CYRILLIC SMALL LETTER DZZE
+    ('\u{A68B}', CharMapAction::SubChar('Ꚋ')), // CYRILLIC SMALL LETTER TE WITH MIDDLE HOOK
+    ('\u{A68D}', CharMapAction::SubChar('Ꚍ')), // CYRILLIC SMALL LETTER TWE
+    ('\u{A68F}', CharMapAction::SubChar('Ꚏ')), // CYRILLIC SMALL LETTER TSWE
+    ('\u{A691}', CharMapAction::SubChar('Ꚑ')), // CYRILLIC SMALL LETTER TSSE
+    ('\u{A693}', CharMapAction::SubChar('Ꚓ')), // CYRILLIC SMALL LETTER TCHE
+    ('\u{A695}', CharMapAction::SubChar('Ꚕ')), // CYRILLIC SMALL LETTER HWE
+    ('\u{A697}', CharMapAction::SubChar('Ꚗ')), // CYRILLIC SMALL LETTER SHWE
+    ('\u{A699}', CharMapAction::SubChar('Ꚙ')), // CYRILLIC SMALL LETTER DOUBLE O
+    ('\u{A69B}', CharMapAction::SubChar('Ꚛ')), // CYRILLIC SMALL LETTER CROSSED O
+    ('\u{A723}', CharMapAction::SubChar('Ꜣ')), // LATIN SMALL LETTER EGYPTOLOGICAL ALEF
+    ('\u{A725}', CharMapAction::SubChar('Ꜥ')), // LATIN SMALL LETTER EGYPTOLOGICAL AIN
+    ('\u{A727}', CharMapAction::SubChar('Ꜧ')), // LATIN SMALL LETTER HENG
+    ('\u{A729}', CharMapAction::SubChar('Ꜩ')), // LATIN SMALL LETTER TZ
+    ('\u{A72B}', CharMapAction::SubChar('Ꜫ')), // LATIN SMALL LETTER TRESILLO
+    ('\u{A72D}', CharMapAction::SubChar('Ꜭ')), // LATIN SMALL LETTER CUATRILLO
+    ('\u{A72F}', CharMapAction::SubChar('Ꜯ')), // LATIN SMALL LETTER CUATRILLO WITH COMMA
+    ('\u{A733}', CharMapAction::SubChar('Ꜳ')), // LATIN SMALL LETTER AA
+    ('\u{A735}', CharMapAction::SubChar('Ꜵ')), // LATIN SMALL LETTER AO
+    ('\u{A737}', CharMapAction::SubChar('Ꜷ')), // LATIN SMALL LETTER AU
+    ('\u{A739}', CharMapAction::SubChar('Ꜹ')), // LATIN SMALL LETTER AV
+    ('\u{A73B}', CharMapAction::SubChar('Ꜻ')), // LATIN SMALL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73D}', CharMapAction::SubChar('Ꜽ')), // LATIN SMALL LETTER AY
+    ('\u{A73F}', CharMapAction::SubChar('Ꜿ')), // LATIN SMALL LETTER REVERSED C WITH DOT
+    ('\u{A741}', CharMapAction::SubChar('Ꝁ')), // LATIN SMALL LETTER K WITH STROKE
+    ('\u{A743}', CharMapAction::SubChar('Ꝃ')), // LATIN SMALL LETTER K WITH DIAGONAL STROKE
+    ('\u{A745}', CharMapAction::SubChar('Ꝅ')), // LATIN SMALL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A747}', CharMapAction::SubChar('Ꝇ')), // LATIN SMALL LETTER BROKEN L
+    ('\u{A749}', CharMapAction::SubChar('Ꝉ')), // LATIN SMALL LETTER L WITH HIGH STROKE
+    ('\u{A74B}', CharMapAction::SubChar('Ꝋ')), // LATIN SMALL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74D}', CharMapAction::SubChar('Ꝍ')), // LATIN SMALL LETTER O WITH LOOP
+    ('\u{A74F}', CharMapAction::SubChar('Ꝏ')), // LATIN SMALL LETTER OO
+    ('\u{A751}', CharMapAction::SubChar('Ꝑ')), // LATIN SMALL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A753}', CharMapAction::SubChar('Ꝓ')), // LATIN SMALL LETTER P WITH FLOURISH
+    ('\u{A755}', CharMapAction::SubChar('Ꝕ')), // LATIN SMALL LETTER P WITH SQUIRREL TAIL
+    ('\u{A757}', CharMapAction::SubChar('Ꝗ')), // LATIN SMALL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A759}', CharMapAction::SubChar('Ꝙ')), // LATIN SMALL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A75B}', CharMapAction::SubChar('Ꝛ')), // LATIN SMALL LETTER R ROTUNDA
+    ('\u{A75D}', CharMapAction::SubChar('Ꝝ')), // LATIN SMALL LETTER RUM ROTUNDA
+    ('\u{A75F}', CharMapAction::SubChar('Ꝟ')), // LATIN SMALL LETTER V WITH DIAGONAL STROKE
+    ('\u{A761}', CharMapAction::SubChar('Ꝡ')), // LATIN SMALL LETTER VY
+    ('\u{A763}', CharMapAction::SubChar('Ꝣ')), // LATIN SMALL LETTER VISIGOTHIC Z
+    ('\u{A765}', CharMapAction::SubChar('Ꝥ')), // LATIN SMALL LETTER THORN WITH STROKE
+    ('\u{A767}', CharMapAction::SubChar('Ꝧ')), // LATIN SMALL LETTER THORN WITH STROKE THROUGH DESCENDER
+    ('\u{A769}', CharMapAction::SubChar('Ꝩ')), // LATIN SMALL LETTER VEND
+    ('\u{A76B}', CharMapAction::SubChar('Ꝫ')), // LATIN SMALL LETTER ET
+    ('\u{A76D}', CharMapAction::SubChar('Ꝭ')), // LATIN SMALL LETTER IS
+    ('\u{A76F}', CharMapAction::SubChar('Ꝯ')), // LATIN SMALL LETTER CON
+    ('\u{A77A}', CharMapAction::SubChar('Ꝺ')), // LATIN SMALL LETTER INSULAR D
+    ('\u{A77C}', CharMapAction::SubChar('Ꝼ')), // LATIN SMALL LETTER INSULAR F
+    ('\u{A77F}', CharMapAction::SubChar('Ꝿ')), // LATIN SMALL LETTER TURNED INSULAR G
+    ('\u{A781}', CharMapAction::SubChar('Ꞁ')), // LATIN SMALL LETTER TURNED L
+    ('\u{A783}', CharMapAction::SubChar('Ꞃ')), // LATIN SMALL LETTER INSULAR R
+    ('\u{A785}', CharMapAction::SubChar('Ꞅ')), // LATIN SMALL LETTER INSULAR S
+    ('\u{A787}', CharMapAction::SubChar('Ꞇ')), // LATIN SMALL LETTER INSULAR T
+    ('\u{A78C}', CharMapAction::SubChar('Ꞌ')), // LATIN SMALL LETTER SALTILLO
+    ('\u{A791}', CharMapAction::SubChar('Ꞑ')), // LATIN SMALL LETTER N WITH DESCENDER
+    ('\u{A793}', CharMapAction::SubChar('Ꞓ')), // LATIN SMALL LETTER C WITH BAR
+    ('\u{A794}', CharMapAction::SubChar('Ꞔ')), // LATIN SMALL LETTER C WITH PALATAL HOOK
+    ('\u{A797}', CharMapAction::SubChar('Ꞗ')), // LATIN SMALL LETTER B WITH FLOURISH
+    ('\u{A799}', CharMapAction::SubChar('Ꞙ')), // LATIN SMALL LETTER F WITH STROKE
+    ('\u{A79B}', CharMapAction::SubChar('Ꞛ')), // LATIN SMALL LETTER VOLAPUK AE
+    ('\u{A79D}', CharMapAction::SubChar('Ꞝ')), // LATIN SMALL LETTER VOLAPUK OE
+    ('\u{A79F}', CharMapAction::SubChar('Ꞟ')), // LATIN SMALL LETTER VOLAPUK UE
+    ('\u{A7A1}', CharMapAction::SubChar('Ꞡ')), // LATIN SMALL LETTER G WITH OBLIQUE STROKE
+    ('\u{A7A3}', CharMapAction::SubChar('Ꞣ')), // LATIN SMALL LETTER K WITH OBLIQUE STROKE
+    ('\u{A7A5}', CharMapAction::SubChar('Ꞥ')), // LATIN SMALL LETTER N WITH OBLIQUE STROKE
+    ('\u{A7A7}', CharMapAction::SubChar('Ꞧ')), // LATIN SMALL LETTER R WITH OBLIQUE STROKE
+    ('\u{A7A9}', CharMapAction::SubChar('Ꞩ')), // LATIN SMALL LETTER S WITH OBLIQUE STROKE
+    ('\u{A7B5}', CharMapAction::SubChar('Ꞵ')), // LATIN SMALL LETTER BETA
+    ('\u{A7B7}', CharMapAction::SubChar('Ꞷ')), // LATIN SMALL LETTER OMEGA
+    ('\u{A7B9}', CharMapAction::SubChar('Ꞹ')), // LATIN SMALL LETTER U WITH STROKE
+    ('\u{A7BB}', CharMapAction::SubChar('Ꞻ')), // LATIN SMALL LETTER GLOTTAL A
+    ('\u{A7BD}', CharMapAction::SubChar('Ꞽ')), // LATIN SMALL LETTER GLOTTAL I
+    ('\u{A7BF}', CharMapAction::SubChar('Ꞿ')), // LATIN SMALL LETTER GLOTTAL U
+    ('\u{A7C1}', CharMapAction::SubChar('Ꟁ')), // LATIN SMALL LETTER OLD POLISH O
+    ('\u{A7C3}', CharMapAction::SubChar('Ꟃ')), // LATIN SMALL LETTER ANGLICANA W
+    ('\u{A7C8}', CharMapAction::SubChar('Ꟈ')), // LATIN SMALL LETTER D WITH SHORT STROKE OVERLAY
+    ('\u{A7CA}', CharMapAction::SubChar('Ꟊ')), // LATIN SMALL LETTER S WITH SHORT STROKE OVERLAY
+    ('\u{A7D1}', CharMapAction::SubChar('Ꟑ')), // LATIN SMALL LETTER CLOSED INSULAR G
+    ('\u{A7D7}', CharMapAction::SubChar('Ꟗ')), // LATIN SMALL LETTER MIDDLE SCOTS S
+    ('\u{A7D9}', CharMapAction::SubChar('Ꟙ')), // LATIN SMALL LETTER SIGMOID S
+    ('\u{A7F6}', CharMapAction::SubChar('Ꟶ')), // LATIN SMALL LETTER REVERSED HALF H
+    ('\u{AB53}', CharMapAction::SubChar('Ꭓ')), // LATIN SMALL LETTER CHI
+    ('\u{AB70}', CharMapAction::SubChar('Ꭰ')), // CHEROKEE SMALL LETTER A
+    ('\u{AB71}', CharMapAction::SubChar('Ꭱ')), // CHEROKEE SMALL LETTER E
+    ('\u{AB72}', CharMapAction::SubChar('Ꭲ')), // CHEROKEE SMALL LETTER I
+    ('\u{AB73}', CharMapAction::SubChar('Ꭳ')), // CHEROKEE SMALL LETTER O
+    ('\u{AB74}', CharMapAction::SubChar('Ꭴ')), // CHEROKEE SMALL LETTER U
+    ('\u{AB75}', CharMapAction::SubChar('Ꭵ')), // CHEROKEE SMALL LETTER V
+    ('\u{AB76}', CharMapAction::SubChar('Ꭶ')), // CHEROKEE SMALL LETTER GA
+    ('\u{AB77}', CharMapAction::SubChar('Ꭷ')), // CHEROKEE SMALL LETTER KA
+    ('\u{AB78}', CharMapAction::SubChar('Ꭸ')), // CHEROKEE SMALL LETTER GE
+    ('\u{AB79}', CharMapAction::SubChar('Ꭹ')), // CHEROKEE SMALL LETTER GI
+    ('\u{AB7A}', CharMapAction::SubChar('Ꭺ')), // CHEROKEE SMALL LETTER GO
+    ('\u{AB7B}', CharMapAction::SubChar('Ꭻ')), // CHEROKEE SMALL LETTER GU
+    ('\u{AB7C}', CharMapAction::SubChar('Ꭼ')), // CHEROKEE SMALL LETTER GV
+    ('\u{AB7D}', CharMapAction::SubChar('Ꭽ')), // CHEROKEE SMALL LETTER HA
+    ('\u{AB7E}', CharMapAction::SubChar('Ꭾ')), // CHEROKEE SMALL LETTER HE
+    ('\u{AB7F}', CharMapAction::SubChar('Ꭿ')), // CHEROKEE SMALL LETTER HI
+    ('\u{AB80}', CharMapAction::SubChar('Ꮀ')), // CHEROKEE SMALL LETTER HO
+    ('\u{AB81}', CharMapAction::SubChar('Ꮁ')), // CHEROKEE SMALL LETTER HU
+    ('\u{AB82}', CharMapAction::SubChar('Ꮂ')), // CHEROKEE SMALL LETTER HV
+    ('\u{AB83}', CharMapAction::SubChar('Ꮃ')), // CHEROKEE SMALL LETTER LA
+    ('\u{AB84}', CharMapAction::SubChar('Ꮄ')), // CHEROKEE SMALL LETTER LE
+    ('\u{AB85}', CharMapAction::SubChar('Ꮅ')), // CHEROKEE SMALL LETTER LI
+    ('\u{AB86}', CharMapAction::SubChar('Ꮆ')), // CHEROKEE SMALL LETTER LO
+    ('\u{AB87}', CharMapAction::SubChar('Ꮇ')), // CHEROKEE SMALL LETTER LU
+    ('\u{AB88}', CharMapAction::SubChar('Ꮈ')), // CHEROKEE SMALL LETTER LV
+    ('\u{AB89}', CharMapAction::SubChar('Ꮉ')), // CHEROKEE SMALL LETTER MA
+    ('\u{AB8A}', CharMapAction::SubChar('Ꮊ')), // CHEROKEE SMALL LETTER ME
+    ('\u{AB8B}', CharMapAction::SubChar('Ꮋ')), // CHEROKEE SMALL LETTER MI
+    ('\u{AB8C}', CharMapAction::SubChar('Ꮌ')), // CHEROKEE SMALL LETTER MO
+    ('\u{AB8D}', CharMapAction::SubChar('Ꮍ')), // CHEROKEE SMALL LETTER MU
+    ('\u{AB8E}', CharMapAction::SubChar('Ꮎ')), // CHEROKEE SMALL LETTER NA
+    ('\u{AB8F}', CharMapAction::SubChar('Ꮏ')), // CHEROKEE SMALL LETTER HNA
+    ('\u{AB90}', CharMapAction::SubChar('Ꮐ')), // CHEROKEE SMALL LETTER NAH
+    ('\u{AB91}', CharMapAction::SubChar('Ꮑ')), // CHEROKEE SMALL LETTER NE
+    ('\u{AB92}', CharMapAction::SubChar('Ꮒ')), // CHEROKEE SMALL LETTER NI
+    ('\u{AB93}', CharMapAction::SubChar('Ꮓ')), // CHEROKEE SMALL LETTER NO
+    ('\u{AB94}', CharMapAction::SubChar('Ꮔ')), // CHEROKEE SMALL LETTER NU
+    ('\u{AB95}', CharMapAction::SubChar('Ꮕ')), // CHEROKEE SMALL LETTER NV
+    ('\u{AB96}', CharMapAction::SubChar('Ꮖ')), // CHEROKEE SMALL LETTER QUA
+    ('\u{AB97}', CharMapAction::SubChar('Ꮗ')), // CHEROKEE SMALL LETTER QUE
+    ('\u{AB98}', CharMapAction::SubChar('Ꮘ')), // CHEROKEE SMALL LETTER QUI
+    ('\u{AB99}', CharMapAction::SubChar('Ꮙ')), // CHEROKEE SMALL LETTER QUO
+    ('\u{AB9A}', CharMapAction::SubChar('Ꮚ')), // CHEROKEE SMALL LETTER QUU
+    ('\u{AB9B}', CharMapAction::SubChar('Ꮛ')), // CHEROKEE SMALL LETTER QUV
+    ('\u{AB9C}', CharMapAction::SubChar('Ꮜ')), // CHEROKEE SMALL LETTER SA
+    ('\u{AB9D}', CharMapAction::SubChar('Ꮝ')), // CHEROKEE SMALL LETTER S
+    ('\u{AB9E}', CharMapAction::SubChar('Ꮞ')), // CHEROKEE SMALL LETTER SE
+    ('\u{AB9F}', CharMapAction::SubChar('Ꮟ')), // CHEROKEE SMALL LETTER SI
+    ('\u{ABA0}', CharMapAction::SubChar('Ꮠ')), // CHEROKEE SMALL LETTER SO
+    ('\u{ABA1}', CharMapAction::SubChar('Ꮡ')), // CHEROKEE SMALL LETTER SU
+    ('\u{ABA2}', CharMapAction::SubChar('Ꮢ')), // CHEROKEE SMALL LETTER SV
+    ('\u{ABA3}', CharMapAction::SubChar('Ꮣ')), // CHEROKEE SMALL LETTER DA
+    ('\u{ABA4}', CharMapAction::SubChar('Ꮤ')), // CHEROKEE SMALL LETTER TA
+    ('\u{ABA5}', CharMapAction::SubChar('Ꮥ')), // CHEROKEE SMALL LETTER DE
+    ('\u{ABA6}', CharMapAction::SubChar('Ꮦ')), // CHEROKEE SMALL LETTER TE
+    ('\u{ABA7}', CharMapAction::SubChar('Ꮧ')), // CHEROKEE SMALL LETTER DI
+    ('\u{ABA8}', CharMapAction::SubChar('Ꮨ')), // CHEROKEE SMALL LETTER TI
+    ('\u{ABA9}', CharMapAction::SubChar('Ꮩ')), // CHEROKEE SMALL LETTER DO
+    ('\u{ABAA}', CharMapAction::SubChar('Ꮪ')), // CHEROKEE SMALL LETTER DU
+    ('\u{ABAB}', CharMapAction::SubChar('Ꮫ')), // CHEROKEE SMALL LETTER DV
+    ('\u{ABAC}', CharMapAction::SubChar('Ꮬ')), // CHEROKEE SMALL LETTER DLA
+    ('\u{ABAD}', CharMapAction::SubChar('Ꮭ')), // CHEROKEE SMALL LETTER TLA
+    ('\u{ABAE}', CharMapAction::SubChar('Ꮮ')), // CHEROKEE SMALL LETTER TLE
+    ('\u{ABAF}', CharMapAction::SubChar('Ꮯ')), // CHEROKEE SMALL LETTER TLI
+    ('\u{ABB0}', CharMapAction::SubChar('Ꮰ')), // CHEROKEE SMALL LETTER TLO
+    ('\u{ABB1}', CharMapAction::SubChar('Ꮱ')), // CHEROKEE SMALL LETTER TLU
+    ('\u{ABB2}', CharMapAction::SubChar('Ꮲ')), // CHEROKEE SMALL LETTER TLV
+    ('\u{ABB3}', CharMapAction::SubChar('Ꮳ')), // CHEROKEE SMALL LETTER TSA
+    ('\u{ABB4}', CharMapAction::SubChar('Ꮴ')), // CHEROKEE SMALL LETTER TSE
+    ('\u{ABB5}', CharMapAction::SubChar('Ꮵ')), // CHEROKEE SMALL LETTER TSI
+    ('\u{ABB6}', CharMapAction::SubChar('Ꮶ')), // CHEROKEE SMALL LETTER TSO
+    ('\u{ABB7}', CharMapAction::SubChar('Ꮷ')), // CHEROKEE SMALL LETTER TSU
+    ('\u{ABB8}', CharMapAction::SubChar('Ꮸ')), // CHEROKEE SMALL LETTER TSV
+    ('\u{ABB9}', CharMapAction::SubChar('Ꮹ')), // CHEROKEE SMALL LETTER WA
+    ('\u{ABBA}', CharMapAction::SubChar('Ꮺ')), // CHEROKEE SMALL LETTER WE
+    ('\u{ABBB}', CharMapAction::SubChar('Ꮻ')), // CHEROKEE SMALL LETTER WI
+    ('\u{ABBC}', CharMapAction::SubChar('Ꮼ')), // CHEROKEE SMALL LETTER WO
+    ('\u{ABBD}', CharMapAction::SubChar('Ꮽ')), // CHEROKEE SMALL LETTER WU
+    ('\u{ABBE}', CharMapAction::SubChar('Ꮾ')), // CHEROKEE SMALL LETTER WV
+    ('\u{ABBF}', CharMapAction::SubChar('Ꮿ')), // CHEROKEE SMALL LETTER YA
+    ('\u{FB00}', CharMapAction::SubStr("FF")), // LATIN SMALL LIGATURE FF
+    ('\u{FB01}', CharMapAction::SubStr("FI")), // LATIN SMALL LIGATURE FI
+    ('\u{FB02}', CharMapAction::SubStr("FL")), // LATIN SMALL LIGATURE FL
+    ('\u{FB03}', CharMapAction::SubStr("FFI")), // LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', CharMapAction::SubStr("FFL")), // LATIN SMALL LIGATURE FFL
+    ('\u{FB05}', CharMapAction::SubStr("ST")), // LATIN SMALL LIGATURE LONG S T
+    ('\u{FB06}', CharMapAction::SubStr("ST")), // LATIN SMALL LIGATURE ST
+    ('\u{FB13}', CharMapAction::SubStr("ՄՆ")), // ARMENIAN SMALL LIGATURE MEN NOW
+    ('\u{FB14}', CharMapAction::SubStr("ՄԵ")), // ARMENIAN SMALL LIGATURE MEN ECH
+    ('\u{FB15}', CharMapAction::SubStr("ՄԻ")), // ARMENIAN SMALL LIGATURE MEN INI
+    ('\u{FB16}', CharMapAction::SubStr("ՎՆ")), // ARMENIAN SMALL LIGATURE VEW NOW
+    ('\u{FB17}', CharMapAction::SubStr("ՄԽ")), // ARMENIAN SMALL LIGATURE MEN XEH
+    ('\u{FF41}', CharMapAction::SubChar('Ａ')), // FULLWIDTH LATIN SMALL LETTER A
+    ('\u{FF42}', CharMapAction::SubChar('Ｂ')), // FULLWIDTH LATIN SMALL LETTER B
+    ('\u{FF43}', CharMapAction::SubChar('Ｃ')), // FULLWIDTH LATIN SMALL LETTER C
+    ('\u{FF44}', CharMapAction::SubChar('Ｄ')), // FULLWIDTH LATIN SMALL LETTER D
+    ('\u{FF45}', CharMapAction::SubChar('Ｅ')), // FULLWIDTH LATIN SMALL LETTER E
+    ('\u{FF46}', CharMapAction::SubChar('Ｆ')), // FULLWIDTH LATIN SMALL LETTER F
+    ('\u{FF47}', CharMapAction::SubChar('Ｇ')), // FULLWIDTH LATIN SMALL LETTER G
+    ('\u{FF48}', CharMapAction::SubChar('Ｈ')), // FULLWIDTH LATIN SMALL LETTER H
+    ('\u{FF49}', CharMapAction::SubChar('Ｉ')), // FULLWIDTH LATIN SMALL LETTER I
+    ('\u{FF4A}', CharMapAction::SubChar('Ｊ')), // FULLWIDTH LATIN SMALL LETTER J
+    ('\u{FF4B}', CharMapAction::SubChar('Ｋ')), // FULLWIDTH LATIN SMALL LETTER K
+    ('\u{FF4C}', CharMapAction::SubChar('Ｌ')), // FULLWIDTH LATIN SMALL LETTER L
+    ('\u{FF4D}', CharMapAction::SubChar('Ｍ')), // FULLWIDTH LATIN SMALL LETTER M
+    ('\u{FF4E}', CharMapAction::SubChar('Ｎ')), // FULLWIDTH LATIN SMALL LETTER N
+    ('\u{FF4F}', CharMapAction::SubChar('Ｏ')), // FULLWIDTH LATIN SMALL LETTER O
+    ('\u{FF50}', CharMapAction::SubChar('Ｐ')), // FULLWIDTH LATIN SMALL LETTER P
+    ('\u{FF51}', CharMapAction::SubChar('Ｑ')), // FULLWIDTH LATIN SMALL LETTER Q
+    ('\u{FF52}', CharMapAction::SubChar('Ｒ')), // FULLWIDTH LATIN SMALL LETTER R
+    ('\u{FF53}', CharMapAction::SubChar('Ｓ')), // FULLWIDTH LATIN SMALL LETTER S
+    ('\u{FF54}', CharMapAction::SubChar('Ｔ')), // FULLWIDTH LATIN SMALL LETTER T
+    ('\u{FF55}', CharMapAction::SubChar('Ｕ')), // FULLWIDTH LATIN SMALL LETTER U
+    ('\u{FF56}', CharMapAction::SubChar('Ｖ')), // FULLWIDTH LATIN SMALL LETTER V
+    ('\u{FF57}', CharMapAction::SubChar('Ｗ')), // FULLWIDTH LATIN SMALL LETTER W
+    ('\u{FF58}', CharMapAction::SubChar('Ｘ')), // FULLWIDTH LATIN SMALL LETTER X
+    ('\u{FF59}', CharMapAction::SubChar('Ｙ')), // FULLWIDTH LATIN SMALL LETTER Y
+    ('\u{FF5A}', CharMapAction::SubChar('Ｚ')), // FULLWIDTH LATIN SMALL LETTER Z
+    ('\u{10428}', CharMapAction::SubChar('𐐀')), // DESERET SMALL LETTER LONG I
+    ('\u{10429}', CharMapAction::SubChar('𐐁')), // DESERET SMALL LETTER LONG E
+    ('\u{1042A}', CharMapAction::SubChar('𐐂')), // DESERET SMALL LETTER LONG A
+    ('\u{1042B}', CharMapAction::SubChar('𐐃')), // DESERET SMALL LETTER LONG AH
+    ('\u{1042C}', CharMapAction::SubChar('𐐄')), // DESERET SMALL LETTER LONG O
+    ('\u{1042D}', CharMapAction::SubChar('𐐅')), // DESERET SMALL LETTER LONG OO
+    ('\u{1042E}', CharMapAction::SubChar('𐐆')), // DESERET SMALL LETTER SHORT I
+    ('\u{1042F}', CharMapAction::SubChar('𐐇')), // DESERET SMALL LETTER SHORT E
+    ('\u{10430}', CharMapAction::SubChar('𐐈')), // DESERET SMALL LETTER SHORT A
+    ('\u{10431}', CharMapAction::SubChar('𐐉')), // DESERET SMALL LETTER SHORT AH
+    ('\u{10432}', CharMapAction::SubChar('𐐊')), // DESERET SMALL LETTER SHORT O
+    ('\u{10433}', CharMapAction::SubChar('𐐋')), // DESERET SMALL LETTER SHORT OO
+    ('\u{10434}', CharMapAction::SubChar('𐐌')), // DESERET SMALL LETTER AY
+    ('\u{10435}', CharMapAction::SubChar('𐐍')), // DESERET SMALL LETTER OW
+    ('\u{10436}', CharMapAction::SubChar('𐐎')), // DESERET SMALL LETTER WU
+    ('\u{10437}', CharMapAction::SubChar('𐐏')), // DESERET SMALL LETTER YEE
+    ('\u{10438}', CharMapAction::SubChar('𐐐')), // DESERET SMALL LETTER H
+    ('\u{10439}', CharMapAction::SubChar('𐐑')), // DESERET SMALL LETTER PEE
+    ('\u{1043A}', CharMapAction::SubChar('𐐒')), // DESERET SMALL LETTER BEE
+    ('\u{1043B}', CharMapAction::SubChar('𐐓')), // DESERET SMALL LETTER TEE
+    ('\u{1043C}', CharMapAction::SubChar('𐐔')), // DESERET SMALL LETTER DEE
+    ('\u{1043D}', CharMapAction::SubChar('𐐕')), // DESERET SMALL LETTER CHEE
+    ('\u{1043E}', CharMapAction::SubChar('𐐖')), // DESERET SMALL LETTER JEE
+    ('\u{1043F}', CharMapAction::SubChar('𐐗')), // DESERET SMALL LETTER KAY
+    ('\u{10440}', CharMapAction::SubChar('𐐘')), // DESERET SMALL LETTER GAY
+    ('\u{10441}', CharMapAction::SubChar('𐐙')), // DESERET SMALL LETTER EF
+    ('\u{10442}', CharMapAction::SubChar('𐐚')), // DESERET SMALL LETTER VEE
+    ('\u{10443}', CharMapAction::SubChar('𐐛')), // DESERET SMALL LETTER ETH
+    ('\u{10444}', CharMapAction::SubChar('𐐜')), // DESERET SMALL LETTER THEE
+    ('\u{10445}', CharMapAction::SubChar('𐐝')), // DESERET SMALL LETTER ES
+    ('\u{10446}', CharMapAction::SubChar('𐐞')), // DESERET SMALL LETTER ZEE
+    ('\u{10447}', CharMapAction::SubChar('𐐟')), // DESERET SMALL LETTER ESH
+    ('\u{10448}', CharMapAction::SubChar('𐐠')), // DESERET SMALL LETTER ZHEE
+    ('\u{10449}', CharMapAction::SubChar('𐐡')), // DESERET SMALL LETTER ER
+    ('\u{1044A}', CharMapAction::SubChar('𐐢')), // DESERET SMALL LETTER EL
+    ('\u{1044B}', CharMapAction::SubChar('𐐣')), // DESERET SMALL LETTER EM
+    ('\u{1044C}', CharMapAction::SubChar('𐐤')), // DESERET SMALL LETTER EN
+    ('\u{1044D}', CharMapAction::SubChar('𐐥')), // DESERET SMALL LETTER ENG
+    ('\u{1044E}', CharMapAction::SubChar('𐐦')), // DESERET SMALL LETTER OI
+    ('\u{1044F}', CharMapAction::SubChar('𐐧')), // DESERET SMALL LETTER EW
+    ('\u{104D8}', CharMapAction::SubChar('𐒰')), // OSAGE SMALL LETTER A
+    ('\u{104D9}', CharMapAction::SubChar('𐒱')), // OSAGE SMALL LETTER AI
+    ('\u{104DA}', CharMapAction::SubChar('𐒲')), // OSAGE SMALL LETTER AIN
+    ('\u{104DB}', CharMapAction::SubChar('𐒳')), // OSAGE SMALL LETTER AH
+    ('\u{104DC}', CharMapAction::SubChar('𐒴')), // OSAGE SMALL LETTER BRA
+    ('\u{104DD}', CharMapAction::SubChar('𐒵')), // OSAGE SMALL LETTER CHA
+    ('\u{104DE}', CharMapAction::SubChar('𐒶')), // OSAGE SMALL LETTER EHCHA
+    ('\u{104DF}', CharMapAction::SubChar('𐒷')), // OSAGE SMALL LETTER E
+    ('\u{104E0}', CharMapAction::SubChar('𐒸')), // OSAGE SMALL LETTER EIN
+    ('\u{104E1}', CharMapAction::SubChar('𐒹')), // OSAGE SMALL LETTER HA
+    ('\u{104E2}', CharMapAction::SubChar('𐒺')), // OSAGE SMALL LETTER HYA
+    ('\u{104E3}', CharMapAction::SubChar('𐒻')), // OSAGE SMALL LETTER I
+    ('\u{104E4}', CharMapAction::SubChar('𐒼')), // OSAGE SMALL LETTER KA
+    ('\u{104E5}', CharMapAction::SubChar('𐒽')), // OSAGE SMALL LETTER EHKA
+    ('\u{104E6}', CharMapAction::SubChar('𐒾')), // OSAGE SMALL LETTER KYA
+    ('\u{104E7}', CharMapAction::SubChar('𐒿')), // OSAGE SMALL LETTER LA
+    ('\u{104E8}', CharMapAction::SubChar('𐓀')), // OSAGE SMALL LETTER MA
+    ('\u{104E9}', CharMapAction::SubChar('𐓁')), // OSAGE SMALL LETTER NA
+    ('\u{104EA}', CharMapAction::SubChar('𐓂')), // OSAGE SMALL LETTER O
+    ('\u{104EB}', CharMapAction::SubChar('𐓃')), // OSAGE SMALL LETTER OIN
+    ('\u{104EC}', CharMapAction::SubChar('𐓄')), // OSAGE SMALL LETTER PA
+    ('\u{104ED}', CharMapAction::SubChar('𐓅')), // OSAGE SMALL LETTER EHPA
+    ('\u{104EE}', CharMapAction::SubChar('𐓆')), // OSAGE SMALL LETTER SA
+    ('\u{104EF}', CharMapAction::SubChar('𐓇')), // OSAGE SMALL LETTER SHA
+    ('\u{104F0}', CharMapAction::SubChar('𐓈')), // OSAGE SMALL LETTER TA
+    ('\u{104F1}', CharMapAction::SubChar('𐓉')), // OSAGE SMALL LETTER EHTA
+    ('\u{104F2}', CharMapAction::SubChar('𐓊')), // OSAGE SMALL LETTER TSA
+    ('\u{104F3}', CharMapAction::SubChar('𐓋')), // OSAGE SMALL LETTER EHTSA
+    ('\u{104F4}', CharMapAction::SubChar('𐓌')), // OSAGE SMALL LETTER TSHA
+    ('\u{104F5}', CharMapAction::SubChar('𐓍')), // OSAGE SMALL LETTER DHA
+    ('\u{104F6}', CharMapAction::SubChar('𐓎')), // OSAGE SMALL LETTER U
+    ('\u{104F7}', CharMapAction::SubChar('𐓏')), // OSAGE SMALL LETTER WA
+    ('\u{104F8}', CharMapAction::SubChar('𐓐')), // OSAGE SMALL LETTER KHA
+    ('\u{104F9}', CharMapAction::SubChar('𐓑')), // OSAGE SMALL LETTER GHA
+    ('\u{104FA}', CharMapAction::SubChar('𐓒')), // OSAGE SMALL LETTER ZA
+    ('\u{104FB}', CharMapAction::SubChar('𐓓')), // OSAGE SMALL LETTER ZHA
+    ('\u{10597}', CharMapAction::SubChar('𐕰')), // VITHKUQI SMALL LETTER A
+    ('\u{10598}', CharMapAction::SubChar('𐕱')), // VITHKUQI SMALL LETTER BBE
+    ('\u{10599}', CharMapAction::SubChar('𐕲')), // VITHKUQI SMALL LETTER BE
+    ('\u{1059A}', CharMapAction::SubChar('𐕳')), // VITHKUQI SMALL LETTER CE
+    ('\u{1059B}', CharMapAction::SubChar('𐕴')), // VITHKUQI SMALL LETTER CHE
+    ('\u{1059C}', CharMapAction::SubChar('𐕵')), // VITHKUQI SMALL LETTER DE
+    ('\u{1059D}', CharMapAction::SubChar('𐕶')), // VITHKUQI SMALL LETTER DHE
+    ('\u{1059E}', CharMapAction::SubChar('𐕷')), // VITHKUQI SMALL LETTER EI
+    ('\u{1059F}', CharMapAction::SubChar('𐕸')), // VITHKUQI SMALL LETTER E
+    ('\u{105A0}', CharMapAction::SubChar('𐕹')), // VITHKUQI SMALL LETTER FE
+    ('\u{105A1}', CharMapAction::SubChar('𐕺')), // VITHKUQI SMALL LETTER GA
+    ('\u{105A3}', CharMapAction::SubChar('𐕼')), // VITHKUQI SMALL LETTER HA
+    ('\u{105A4}', CharMapAction::SubChar('𐕽')), // VITHKUQI SMALL LETTER HHA
+    ('\u{105A5}', CharMapAction::SubChar('𐕾')), // VITHKUQI SMALL LETTER I
+    ('\u{105A6}', CharMapAction::SubChar('𐕿')), // VITHKUQI SMALL LETTER IJE
+    ('\u{105A7}', CharMapAction::SubChar('𐖀')), // VITHKUQI SMALL LETTER JE
+    ('\u{105A8}', CharMapAction::SubChar('𐖁')), // VITHKUQI SMALL LETTER KA
+    ('\u{105A9}', CharMapAction::SubChar('𐖂')), // VITHKUQI SMALL LETTER LA
+    ('\u{105AA}', CharMapAction::SubChar('𐖃')), // VITHKUQI SMALL LETTER LLA
+    ('\u{105AB}', CharMapAction::SubChar('𐖄')), // VITHKUQI SMALL LETTER ME
+    ('\u{105AC}', CharMapAction::SubChar('𐖅')), // VITHKUQI SMALL LETTER NE
+    ('\u{105AD}', CharMapAction::SubChar('𐖆')), // VITHKUQI SMALL LETTER NJE
+    ('\u{105AE}', CharMapAction::SubChar('𐖇')), // VITHKUQI SMALL LETTER O
+    ('\u{105AF}', CharMapAction::SubChar('𐖈')), // VITHKUQI SMALL LETTER PE
+    ('\u{105B0}', CharMapAction::SubChar('𐖉')), // VITHKUQI SMALL LETTER QA
+    ('\u{105B1}', CharMapAction::SubChar('𐖊')), // VITHKUQI SMALL LETTER RE
+    ('\u{105B3}', CharMapAction::SubChar('𐖌')), // VITHKUQI SMALL LETTER SE
+    ('\u{105B4}', CharMapAction::SubChar('𐖍')), // VITHKUQI SMALL LETTER SHE
+    ('\u{105B5}', CharMapAction::SubChar('𐖎')), // VITHKUQI SMALL LETTER TE
+    ('\u{105B6}', CharMapAction::SubChar('𐖏')), // VITHKUQI SMALL LETTER THE
+    ('\u{105B7}', CharMapAction::SubChar('𐖐')), // VITHKUQI SMALL LETTER U
+    ('\u{105B8}', CharMapAction::SubChar('𐖑')), // VITHKUQI SMALL LETTER VE
+    ('\u{105B9}', CharMapAction::SubChar('𐖒')), // VITHKUQI SMALL LETTER XE
+    ('\u{105BB}', CharMapAction::SubChar('𐖔')), // VITHKUQI SMALL LETTER Y
+    ('\u{105BC}', CharMapAction::SubChar('𐖕')), // VITHKUQI SMALL LETTER ZE
+    ('\u{10CC0}', CharMapAction::SubChar('𐲀')), // OLD HUNGARIAN SMALL LETTER A
+    ('\u{10CC1}', CharMapAction::SubChar('𐲁')), // OLD HUNGARIAN SMALL LETTER AA
+    ('\u{10CC2}', CharMapAction::SubChar('𐲂')), // OLD HUNGARIAN SMALL LETTER EB
+    ('\u{10CC3}', CharMapAction::SubChar('𐲃')), // OLD HUNGARIAN SMALL LETTER AMB
+    ('\u{10CC4}', CharMapAction::SubChar('𐲄')), // OLD HUNGARIAN SMALL LETTER EC
+    ('\u{10CC5}', CharMapAction::SubChar('𐲅')), // OLD HUNGARIAN SMALL LETTER ENC
+    ('\u{10CC6}', CharMapAction::SubChar('𐲆')), // OLD HUNGARIAN SMALL LETTER ECS
+    ('\u{10CC7}', CharMapAction::SubChar('𐲇')), // OLD HUNGARIAN SMALL LETTER ED
+    ('\u{10CC8}', CharMapAction::SubChar('𐲈')), // OLD HUNGARIAN SMALL LETTER AND
+    ('\u{10CC9}', CharMapAction::SubChar('𐲉')), // OLD HUNGARIAN SMALL LETTER E
+    ('\u{10CCA}', CharMapAction::SubChar('𐲊')), // OLD HUNGARIAN SMALL LETTER CLOSE E
+    ('\u{10CCB}', CharMapAction::SubChar('𐲋')), // OLD HUNGARIAN SMALL LETTER EE
+    ('\u{10CCC}', CharMapAction::SubChar('𐲌')), // OLD HUNGARIAN SMALL LETTER EF
+    ('\u{10CCD}', CharMapAction::SubChar('𐲍')), // OLD HUNGARIAN SMALL LETTER EG
+    ('\u{10CCE}', CharMapAction::SubChar('𐲎')), // OLD HUNGARIAN SMALL LETTER EGY
+    ('\u{10CCF}', CharMapAction::SubChar('𐲏')), // OLD HUNGARIAN SMALL LETTER EH
+    ('\u{10CD0}', CharMapAction::SubChar('𐲐')), // OLD HUNGARIAN SMALL LETTER I
+    ('\u{10CD1}', CharMapAction::SubChar('𐲑')), // OLD HUNGARIAN SMALL LETTER II
+    ('\u{10CD2}', CharMapAction::SubChar('𐲒')), // OLD HUNGARIAN SMALL LETTER EJ
+    ('\u{10CD3}', CharMapAction::SubChar('𐲓')), // OLD HUNGARIAN SMALL LETTER EK
+    ('\u{10CD4}', CharMapAction::SubChar('𐲔')), // OLD HUNGARIAN SMALL LETTER AK
+    ('\u{10CD5}', CharMapAction::SubChar('𐲕')), // OLD HUNGARIAN SMALL LETTER UNK
+    ('\u{10CD6}', CharMapAction::SubChar('𐲖')), // OLD HUNGARIAN SMALL LETTER EL
+    ('\u{10CD7}', CharMapAction::SubChar('𐲗')), // OLD HUNGARIAN SMALL LETTER ELY
+    ('\u{10CD8}', CharMapAction::SubChar('𐲘')), // OLD HUNGARIAN SMALL LETTER EM
+    ('\u{10CD9}', CharMapAction::SubChar('𐲙')), // OLD HUNGARIAN SMALL LETTER EN
+    ('\u{10CDA}', CharMapAction::SubChar('𐲚')), // OLD HUNGARIAN SMALL LETTER ENY
+    ('\u{10CDB}', CharMapAction::SubChar('𐲛')), // OLD HUNGARIAN SMALL LETTER O
+    ('\u{10CDC}', CharMapAction::SubChar('𐲜')), // OLD HUNGARIAN SMALL LETTER OO
+    ('\u{10CDD}', CharMapAction::SubChar('𐲝')), // OLD HUNGARIAN SMALL LETTER NIKOLSBURG OE
+    ('\u{10CDE}', CharMapAction::SubChar('𐲞')), // OLD HUNGARIAN SMALL LETTER RUDIMENTA OE
+    ('\u{10CDF}', CharMapAction::SubChar('𐲟')), // OLD HUNGARIAN SMALL LETTER OEE
+    ('\u{10CE0}', CharMapAction::SubChar('𐲠')), // OLD HUNGARIAN SMALL LETTER EP
+    ('\u{10CE1}', CharMapAction::SubChar('𐲡')), // OLD HUNGARIAN SMALL LETTER EMP
+    ('\u{10CE2}', CharMapAction::SubChar('𐲢')), // OLD HUNGARIAN SMALL LETTER ER
+    ('\u{10CE3}', CharMapAction::SubChar('𐲣')), // OLD HUNGARIAN SMALL LETTER SHORT ER
+    ('\u{10CE4}', CharMapAction::SubChar('𐲤')), // OLD HUNGARIAN SMALL LETTER ES
+    ('\u{10CE5}', CharMapAction::SubChar('𐲥')), // OLD HUNGARIAN SMALL LETTER ESZ
+    ('\u{10CE6}', CharMapAction::SubChar('𐲦')), // OLD HUNGARIAN SMALL LETTER ET
+    ('\u{10CE7}', CharMapAction::SubChar('𐲧')), // OLD HUNGARIAN SMALL LETTER ENT
+    ('\u{10CE8}', CharMapAction::SubChar('𐲨')), // OLD HUNGARIAN SMALL LETTER ETY
+    ('\u{10CE9}', CharMapAction::SubChar('𐲩')), // OLD HUNGARIAN SMALL LETTER ECH
+    ('\u{10CEA}', CharMapAction::SubChar('𐲪')), // OLD HUNGARIAN SMALL LETTER U
+    ('\u{10CEB}', CharMapAction::SubChar('𐲫')), // OLD HUNGARIAN SMALL LETTER UU
+    ('\u{10CEC}', CharMapAction::SubChar('𐲬')), // OLD HUNGARIAN SMALL LETTER NIKOLSBURG UE
+    ('\u{10CED}', CharMapAction::SubChar('𐲭')), // OLD HUNGARIAN SMALL LETTER RUDIMENTA UE
+    ('\u{10CEE}', CharMapAction::SubChar('𐲮')), // OLD HUNGARIAN SMALL LETTER EV
+    ('\u{10CEF}', CharMapAction::SubChar('𐲯')), // OLD HUNGARIAN SMALL LETTER EZ
+    ('\u{10CF0}', CharMapAction::SubChar('𐲰')), // OLD HUNGARIAN SMALL LETTER EZS
+    ('\u{10CF1}', CharMapAction::SubChar('𐲱')), // OLD HUNGARIAN SMALL LETTER ENT-SHAPED SIGN
+    ('\u{10CF2}', CharMapAction::SubChar('𐲲')), // OLD HUNGARIAN SMALL LETTER US
+    ('\u{118C0}', CharMapAction::SubChar('𑢠')), // WARANG CITI SMALL LETTER NGAA
+    ('\u{118C1}', CharMapAction::SubChar('𑢡')), // WARANG CITI SMALL LETTER A
+    ('\u{118C2}', CharMapAction::SubChar('𑢢')), // WARANG CITI SMALL LETTER WI
+    ('\u{118C3}', CharMapAction::SubChar('𑢣')), // WARANG CITI SMALL LETTER YU
+    ('\u{118C4}', CharMapAction::SubChar('𑢤')), // WARANG CITI SMALL LETTER YA
+    ('\u{118C5}', CharMapAction::SubChar('𑢥')), // WARANG CITI SMALL LETTER YO
+    ('\u{118C6}', CharMapAction::SubChar('𑢦')), // WARANG CITI SMALL LETTER II
+    ('\u{118C7}', CharMapAction::SubChar('𑢧')), // WARANG CITI SMALL LETTER UU
+    ('\u{118C8}', CharMapAction::SubChar('𑢨')), // WARANG CITI SMALL LETTER E
+    ('\u{118C9}', CharMapAction::SubChar('𑢩')), // WARANG CITI SMALL LETTER O
+    ('\u{118CA}', CharMapAction::SubChar('𑢪')), // WARANG CITI SMALL LETTER ANG
+    ('\u{118CB}', CharMapAction::SubChar('𑢫')), // WARANG CITI SMALL LETTER GA
+    ('\u{118CC}', CharMapAction::SubChar('𑢬')), // WARANG CITI SMALL LETTER KO
+    ('\u{118CD}', CharMapAction::SubChar('𑢭')), // WARANG CITI SMALL LETTER ENY
+    ('\u{118CE}', CharMapAction::SubChar('𑢮')), // WARANG CITI SMALL LETTER YUJ
+    ('\u{118CF}', CharMapAction::SubChar('𑢯')), // WARANG CITI SMALL LETTER UC
+    ('\u{118D0}', CharMapAction::SubChar('𑢰')), // WARANG CITI SMALL LETTER ENN
+    ('\u{118D1}', CharMapAction::SubChar('𑢱')), // WARANG CITI SMALL LETTER ODD
+    ('\u{118D2}', CharMapAction::SubChar('𑢲')), // WARANG CITI SMALL LETTER TTE
+    ('\u{118D3}', CharMapAction::SubChar('𑢳')), // WARANG CITI SMALL LETTER NUNG
+    ('\u{118D4}', CharMapAction::SubChar('𑢴')), // WARANG CITI SMALL LETTER DA
+    ('\u{118D5}', CharMapAction::SubChar('𑢵')), // WARANG CITI SMALL LETTER AT
+    ('\u{118D6}', CharMapAction::SubChar('𑢶')), // WARANG CITI SMALL LETTER AM
+    ('\u{118D7}', CharMapAction::SubChar('𑢷')), // WARANG CITI SMALL LETTER BU
+    ('\u{118D8}', CharMapAction::SubChar('𑢸')), // WARANG CITI SMALL LETTER PU
+    ('\u{118D9}', CharMapAction::SubChar('𑢹')), // WARANG CITI SMALL LETTER HIYO
+    ('\u{118DA}', CharMapAction::SubChar('𑢺')), // WARANG CITI SMALL LETTER HOLO
+    ('\u{118DB}', CharMapAction::SubChar('𑢻')), // WARANG CITI SMALL LETTER HORR
+    ('\u{118DC}', CharMapAction::SubChar('𑢼')), // WARANG CITI SMALL LETTER HAR
+    ('\u{118DD}', CharMapAction::SubChar('𑢽')), // WARANG CITI SMALL LETTER SSUU
+    ('\u{118DE}', CharMapAction::SubChar('𑢾')), // WARANG CITI SMALL LETTER SII
+    ('\u{118DF}', CharMapAction::SubChar('𑢿')), // WARANG CITI SMALL LETTER VIYO
+    ('\u{16E60}', CharMapAction::SubChar('𖹀')), // MEDEFAIDRIN SMALL LETTER M
+    ('\u{16E61}', CharMapAction::SubChar('𖹁')), // MEDEFAIDRIN SMALL LETTER S
+    ('\u{16E62}', CharMapAction::SubChar('𖹂')), // MEDEFAIDRIN SMALL LETTER V
+    ('\u{16E63}', CharMapAction::SubChar('𖹃')), // MEDEFAIDRIN SMALL LETTER W
+    ('\u{16E64}', CharMapAction::SubChar('𖹄')), // MEDEFAIDRIN SMALL LETTER ATIU
+    ('\u{16E65}', CharMapAction::SubChar('𖹅')), // MEDEFAIDRIN SMALL LETTER Z
+    ('\u{16E66}', CharMapAction::SubChar('𖹆')), // MEDEFAIDRIN SMALL LETTER KP
+    ('\u{16E67}', CharMapAction::SubChar('𖹇')), // MEDEFAIDRIN SMALL LETTER P
+    ('\u{16E68}', CharMapAction::SubChar('𖹈')), // MEDEFAIDRIN SMALL LETTER T
+    ('\u{16E69}', CharMapAction::SubChar('𖹉')), // MEDEFAIDRIN SMALL LETTER G
+    ('\u{16E6A}', CharMapAction::SubChar('𖹊')), // MEDEFAIDRIN SMALL LETTER F
+    ('\u{16E6B}', CharMapAction::SubChar('𖹋')), // MEDEFAIDRIN SMALL LETTER I
+    ('\u{16E6C}', CharMapAction::SubChar('𖹌')), // MEDEFAIDRIN SMALL LETTER K
+    ('\u{16E6D}', CharMapAction::SubChar('𖹍')), // MEDEFAIDRIN SMALL LETTER A
+    ('\u{16E6E}', CharMapAction::SubChar('𖹎')), // MEDEFAIDRIN SMALL LETTER J
+    ('\u{16E6F}', CharMapAction::SubChar('𖹏')), // MEDEFAIDRIN SMALL LETTER E
+    ('\u{16E70}', CharMapAction::SubChar('𖹐')), // MEDEFAIDRIN SMALL LETTER B
+    ('\u{16E71}', CharMapAction::SubChar('𖹑')), // MEDEFAIDRIN SMALL LETTER C
+    ('\u{16E72}', CharMapAction::SubChar('𖹒')), // MEDEFAIDRIN SMALL LETTER U
+    ('\u{16E73}', CharMapAction::SubChar('𖹓')), // MEDEFAIDRIN SMALL LETTER YU
+    ('\u{16E74}', CharMapAction::SubChar('𖹔')), // MEDEFAIDRIN SMALL LETTER L
+    ('\u{16E75}', CharMapAction::SubChar('𖹕')), // MEDEFAIDRIN SMALL LETTER Q
+    ('\u{16E76}', CharMapAction::SubChar('𖹖')), // MEDEFAIDRIN SMALL LETTER HP
+    ('\u{16E77}', CharMapAction::SubChar('𖹗')), // MEDEFAIDRIN SMALL LETTER NY
+    ('\u{16E78}', CharMapAction::SubChar('𖹘')), // MEDEFAIDRIN SMALL LETTER X
+    ('\u{16E79}', CharMapAction::SubChar('𖹙')), // MEDEFAIDRIN SMALL LETTER D
+    ('\u{16E7A}', CharMapAction::SubChar('𖹚')), // MEDEFAIDRIN SMALL LETTER OE
+    ('\u{16E7B}', CharMapAction::SubChar('𖹛')), // MEDEFAIDRIN SMALL LETTER N
+    ('\u{16E7C}', CharMapAction::SubChar('𖹜')), // MEDEFAIDRIN SMALL LETTER R
+    ('\u{16E7D}', CharMapAction::SubChar('𖹝')), // MEDEFAIDRIN SMALL LETTER O
+    ('\u{16E7E}', CharMapAction::SubChar('𖹞')), // MEDEFAIDRIN SMALL LETTER AI
+    ('\u{16E7F}', CharMapAction::SubChar('𖹟')), // MEDEFAIDRIN SMALL LETTER Y
+    ('\u{1E922}', CharMapAction::SubChar('𞤀')), // ADLAM SMALL LETTER ALIF
+    ('\u{1E923}', CharMapAction::SubChar('𞤁')), // ADLAM SMALL LETTER DAALI
+    ('\u{1E924}', CharMapAction::SubChar('𞤂')), // ADLAM SMALL LETTER LAAM
+    ('\u{1E925}', CharMapAction::SubChar('𞤃')), // ADLAM SMALL LETTER MIIM
+    ('\u{1E926}', CharMapAction::SubChar('𞤄')), // ADLAM SMALL LETTER BA
+    ('\u{1E927}', CharMapAction::SubChar('𞤅')), // ADLAM SMALL LETTER SINNYIIYHE
+    ('\u{1E928}', CharMapAction::SubChar('𞤆')), // ADLAM SMALL LETTER PE
+    ('\u{1E929}', CharMapAction::SubChar('𞤇')), // ADLAM SMALL LETTER BHE
+    ('\u{1E92A}', CharMapAction::SubChar('𞤈')), // ADLAM SMALL LETTER RA
+    ('\u{1E92B}', CharMapAction::SubChar('𞤉')), // ADLAM SMALL LETTER E
+    ('\u{1E92C}', CharMapAction::SubChar('𞤊')), // ADLAM SMALL LETTER FA
+    ('\u{1E92D}', CharMapAction::SubChar('𞤋')), // ADLAM SMALL LETTER I
+    ('\u{1E92E}', CharMapAction::SubChar('𞤌')), // ADLAM SMALL LETTER O
+    ('\u{1E92F}', CharMapAction::SubChar('𞤍')), // ADLAM SMALL LETTER DHA
+    ('\u{1E930}', CharMapAction::SubChar('𞤎')), // ADLAM SMALL LETTER YHE
+    ('\u{1E931}', CharMapAction::SubChar('𞤏')), // ADLAM SMALL LETTER WAW
+    ('\u{1E932}', CharMapAction::SubChar('𞤐')), // ADLAM SMALL LETTER NUN
+    ('\u{1E933}', CharMapAction::SubChar('𞤑')), // ADLAM SMALL LETTER KAF
+    ('\u{1E934}', CharMapAction::SubChar('𞤒')), // ADLAM SMALL LETTER YA
+    ('\u{1E935}', CharMapAction::SubChar('𞤓')), // ADLAM SMALL LETTER U
+    ('\u{1E936}', CharMapAction::SubChar('𞤔')), // ADLAM SMALL LETTER JIIM
+    ('\u{1E937}', CharMapAction::SubChar('𞤕')), // ADLAM SMALL LETTER CHI
+    ('\u{1E938}', CharMapAction::SubChar('𞤖')), // ADLAM SMALL LETTER HA
+    ('\u{1E939}', CharMapAction::SubChar('𞤗')), // ADLAM SMALL LETTER QAAF
+    ('\u{1E93A}', CharMapAction::SubChar('𞤘')), // ADLAM SMALL LETTER GA
+    ('\u{1E93B}', CharMapAction::SubChar('𞤙')), // ADLAM SMALL LETTER NYA
+    ('\u{1E93C}', CharMapAction::SubChar('𞤚')), // ADLAM SMALL LETTER TU
+    ('\u{1E93D}', CharMapAction::SubChar('𞤛')), // ADLAM SMALL LETTER NHA
+    ('\u{1E93E}', CharMapAction::SubChar('𞤜')), // ADLAM SMALL LETTER VA
+    ('\u{1E93F}', CharMapAction::SubChar('𞤝')), // ADLAM SMALL LETTER KHA
+    ('\u{1E940}', CharMapAction::SubChar('𞤞')), // ADLAM SMALL LETTER GBE
+    ('\u{1E941}', CharMapAction::SubChar('𞤟')), // ADLAM SMALL LETTER ZAL
+    ('\u{1E942}', CharMapAction::SubChar('𞤠')), // ADLAM SMALL LETTER KPO
+    ('\u{1E943}', CharMapAction::SubChar('𞤡')), // ADLAM SMALL LETTER SHA
+];
+const _: () = assert_sorted(&UPPERCASE);
+
+/// Map from characters to their full titlecase mappings.
+#[rustfmt::skip]
+pub static TITLECASE: [(char, CharMapAction); 1452] = [
+    ('\u{0061}', CharMapAction::SubChar('A')), // LATIN SMALL LETTER A
+    ('\u{0062}', CharMapAction::SubChar('B')), // LATIN SMALL LETTER B
+    ('\u{0063}', CharMapAction::SubChar('C')), // LATIN SMALL LETTER C
+    ('\u{0064}', CharMapAction::SubChar('D')), // LATIN SMALL LETTER D
+    ('\u{0065}', CharMapAction::SubChar('E')), // LATIN SMALL LETTER E
+    ('\u{0066}', CharMapAction::SubChar('F')), // LATIN SMALL LETTER F
+    ('\u{0067}', CharMapAction::SubChar('G')), // LATIN SMALL LETTER G
+    ('\u{0068}', CharMapAction::SubChar('H')), // LATIN SMALL LETTER H
+    ('\u{0069}', CharMapAction::SubChar('I')), // LATIN SMALL LETTER I
+    ('\u{006A}', CharMapAction::SubChar('J')), // LATIN SMALL LETTER J
+    ('\u{006B}', CharMapAction::SubChar('K')), // LATIN SMALL LETTER K
+    ('\u{006C}', CharMapAction::SubChar('L')), // LATIN SMALL LETTER L
+    ('\u{006D}', CharMapAction::SubChar('M')), // LATIN SMALL LETTER M
+    ('\u{006E}', CharMapAction::SubChar('N')), // LATIN SMALL LETTER N
+    ('\u{006F}', CharMapAction::SubChar('O')), // LATIN SMALL LETTER O
+    ('\u{0070}', CharMapAction::SubChar('P')), // LATIN SMALL LETTER P
+    ('\u{0071}', CharMapAction::SubChar('Q')), // LATIN SMALL LETTER Q
+    ('\u{0072}', CharMapAction::SubChar('R')), // LATIN SMALL LETTER R
+    ('\u{0073}', CharMapAction::SubChar('S')), // LATIN SMALL LETTER S
+    ('\u{0074}', CharMapAction::SubChar('T')), // LATIN SMALL LETTER T
+    ('\u{0075}', CharMapAction::SubChar('U')), // LATIN SMALL LETTER U
+    ('\u{0076}', CharMapAction::SubChar('V')), // LATIN SMALL LETTER V
+    ('\u{0077}', CharMapAction::SubChar('W')), // LATIN SMALL LETTER W
+    ('\u{0078}', CharMapAction::SubChar('X')), // LATIN SMALL LETTER X
+    ('\u{0079}', CharMapAction::SubChar('Y')), // LATIN SMALL LETTER Y
+    ('\u{007A}', CharMapAction::SubChar('Z')), // LATIN SMALL LETTER Z
+    ('\u{00B5}', CharMapAction::SubChar('Μ')), // MICRO SIGN
+    ('\u{00DF}', CharMapAction::SubStr("Ss")), // LATIN SMALL LETTER SHARP S
+    ('\u{00E0}', CharMapAction::SubChar('À')), // LATIN SMALL LETTER A WITH GRAVE
+    ('\u{00E1}', CharMapAction::SubChar('Á')), // LATIN SMALL LETTER A WITH ACUTE
+    ('\u{00E2}', CharMapAction::SubChar('Â')), // LATIN SMALL LETTER A WITH CIRCUMFLEX
+    ('\u{00E3}', CharMapAction::SubChar('Ã')), // LATIN SMALL LETTER A WITH TILDE
+    ('\u{00E4}', CharMapAction::SubChar('Ä')), // LATIN SMALL LETTER A WITH DIAERESIS
+    ('\u{00E5}', CharMapAction::SubChar('Å')), // LATIN SMALL LETTER A WITH RING ABOVE
+    ('\u{00E6}', CharMapAction::SubChar('Æ')), // LATIN SMALL LETTER AE
+    ('\u{00E7}', CharMapAction::SubChar('Ç')), // LATIN SMALL LETTER C WITH CEDILLA
+    ('\u{00E8}', CharMapAction::SubChar('È')), // LATIN SMALL LETTER E WITH GRAVE
+    ('\u{00E9}', CharMapAction::SubChar('É')), // LATIN SMALL LETTER E WITH ACUTE
+    ('\u{00EA}', CharMapAction::SubChar('Ê')), // LATIN SMALL LETTER E WITH CIRCUMFLEX
+    ('\u{00EB}', CharMapAction::SubChar('Ë')), // LATIN SMALL LETTER E WITH DIAERESIS
+    ('\u{00EC}', CharMapAction::SubChar('Ì')), // LATIN SMALL LETTER I WITH GRAVE
+    ('\u{00ED}', CharMapAction::SubChar('Í')), // LATIN SMALL LETTER I WITH ACUTE
+    ('\u{00EE}', CharMapAction::SubChar('Î')), // LATIN SMALL LETTER I WITH CIRCUMFLEX
+    ('\u{00EF}', CharMapAction::SubChar('Ï')), // LATIN SMALL LETTER I WITH DIAERESIS
+    ('\u{00F0}', CharMapAction::SubChar('Ð')), // LATIN SMALL LETTER ETH
+    ('\u{00F1}', CharMapAction::SubChar('Ñ')), // LATIN SMALL LETTER N WITH TILDE
+    ('\u{00F2}', CharMapAction::SubChar('Ò')), // LATIN SMALL LETTER O WITH GRAVE
+    ('\u{00F3}', CharMapAction::SubChar('Ó')), // LATIN SMALL LETTER O WITH ACUTE
+    ('\u{00F4}', CharMapAction::SubChar('Ô')), // LATIN SMALL LETTER O WITH CIRCUMFLEX
+    ('\u{00F5}', CharMapAction::SubChar('Õ')), // LATIN SMALL LETTER O WITH TILDE
+    ('\u{00F6}', CharMapAction::SubChar('Ö')), // LATIN SMALL LETTER O WITH DIAERESIS
+    ('\u{00F8}', CharMapAction::SubChar('Ø')), // LATIN SMALL LETTER O WITH STROKE
+    ('\u{00F9}', CharMapAction::SubChar('Ù')), // LATIN SMALL LETTER U WITH GRAVE
+    ('\u{00FA}', CharMapAction::SubChar('Ú')), // LATIN SMALL LETTER U WITH ACUTE
+    ('\u{00FB}', CharMapAction::SubChar('Û')), // LATIN SMALL LETTER U WITH CIRCUMFLEX
+    ('\u{00FC}', CharMapAction::SubChar('Ü')), // LATIN SMALL LETTER U WITH DIAERESIS
+    ('\u{00FD}', CharMapAction::SubChar('Ý')), // LATIN SMALL LETTER Y WITH ACUTE
+    ('\u{00FE}', CharMapAction::SubChar('Þ')), // LATIN SMALL LETTER THORN
+    ('\u{00FF}', CharMapAction::SubChar('Ÿ')), // LATIN SMALL LETTER Y WITH DIAERESIS
+    ('\u{0101}', CharMapAction::SubChar('Ā')), // LATIN SMALL LETTER A WITH MACRON
+    ('\u{0103}', CharMapAction::SubChar('Ă')), // LATIN SMALL LETTER A WITH BREVE
+    ('\u{0105}', CharMapAction::SubChar('Ą')), // LATIN SMALL LETTER A WITH OGONEK
+    ('\u{0107}', CharMapAction::SubChar('Ć')), // LATIN SMALL LETTER C WITH ACUTE
+    ('\u{0109}', CharMapAction::SubChar('Ĉ')), // LATIN SMALL LETTER C WITH CIRCUMFLEX
+    ('\u{010B}', CharMapAction::SubChar('Ċ')), // LATIN SMALL LETTER C WITH DOT ABOVE
+    ('\u{010D}', CharMapAction::SubChar('Č')), // LATIN SMALL LETTER C WITH CARON
+    ('\u{010F}', CharMapAction::SubChar('Ď')), // LATIN SMALL LETTER D WITH CARON
+    ('\u{0111}', CharMapAction::SubChar('Đ')), // LATIN SMALL LETTER D WITH STROKE
+    ('\u{0113}', CharMapAction::SubChar('Ē')), // LATIN SMALL LETTER E WITH MACRON
+    ('\u{0115}', CharMapAction::SubChar('Ĕ')), // LATIN SMALL LETTER E WITH BREVE
+    ('\u{0117}', CharMapAction::SubChar('Ė')), // LATIN SMALL LETTER E WITH DOT ABOVE
+    ('\u{0119}', CharMapAction::SubChar('Ę')), // LATIN SMALL LETTER E WITH OGONEK
+    ('\u{011B}', CharMapAction::SubChar('Ě')), // LATIN SMALL LETTER E WITH CARON
+    ('\u{011D}', CharMapAction::SubChar('Ĝ')), // LATIN SMALL LETTER G WITH CIRCUMFLEX
+    ('\u{011F}', CharMapAction::SubChar('Ğ')), // LATIN SMALL LETTER G WITH BREVE
+    ('\u{0121}', CharMapAction::SubChar('Ġ')), // LATIN SMALL LETTER G WITH DOT ABOVE
+    ('\u{0123}', CharMapAction::SubChar('Ģ')), // LATIN SMALL LETTER G WITH CEDILLA
+    ('\u{0125}', CharMapAction::SubChar('Ĥ')), // LATIN SMALL LETTER H WITH CIRCUMFLEX
+    ('\u{0127}', CharMapAction::SubChar('Ħ')), // LATIN SMALL LETTER H WITH STROKE
+    ('\u{0129}', CharMapAction::SubChar('Ĩ')), // LATIN SMALL LETTER I WITH TILDE
+    ('\u{012B}', CharMapAction::SubChar('Ī')), // LATIN SMALL LETTER I WITH MACRON
+    ('\u{012D}', CharMapAction::SubChar('Ĭ')), // LATIN SMALL LETTER I WITH BREVE
+    ('\u{012F}', CharMapAction::SubChar('Į')), // LATIN SMALL LETTER I WITH OGONEK
+    ('\u{0131}', CharMapAction::SubChar('I')), // LATIN SMALL LETTER DOTLESS I
+    ('\u{0133}', CharMapAction::SubChar('Ĳ')), // LATIN SMALL LIGATURE IJ
+    ('\u{0135}', CharMapAction::SubChar('Ĵ')), // LATIN SMALL LETTER J WITH CIRCUMFLEX
+    ('\u{0137}', CharMapAction::SubChar('Ķ')), // LATIN SMALL LETTER K WITH CEDILLA
+    ('\u{013A}', CharMapAction::SubChar('Ĺ')), // LATIN SMALL LETTER L WITH ACUTE
+    ('\u{013C}', CharMapAction::SubChar('Ļ')), // LATIN SMALL LETTER L WITH CEDILLA
+    ('\u{013E}', CharMapAction::SubChar('Ľ')), // LATIN SMALL LETTER L WITH CARON
+    ('\u{0140}', CharMapAction::SubChar('Ŀ')), // LATIN SMALL LETTER L WITH MIDDLE DOT
+    ('\u{0142}', CharMapAction::SubChar('Ł')), // LATIN SMALL LETTER L WITH STROKE
+    ('\u{0144}', CharMapAction::SubChar('Ń')), // LATIN SMALL LETTER N WITH ACUTE
+    ('\u{0146}', CharMapAction::SubChar('Ņ')), // LATIN SMALL LETTER N WITH CEDILLA
+    ('\u{0148}', CharMapAction::SubChar('Ň')), // LATIN SMALL LETTER N WITH CARON
+    ('\u{0149}', CharMapAction::SubStr("ʼN")), // LATIN SMALL LETTER N PRECEDED BY APOSTROPHE
+    ('\u{014B}', CharMapAction::SubChar('Ŋ')), // LATIN SMALL LETTER ENG
+    ('\u{014D}', CharMapAction::SubChar('Ō')), // LATIN SMALL LETTER O WITH MACRON
+    ('\u{014F}', CharMapAction::SubChar('Ŏ')), // LATIN SMALL LETTER O WITH BREVE
+    ('\u{0151}', CharMapAction::SubChar('Ő')), // LATIN SMALL LETTER O WITH DOUBLE ACUTE
+    ('\u{0153}', CharMapAction::SubChar('Œ')), // LATIN SMALL LIGATURE OE
+    ('\u{0155}', CharMapAction::SubChar('Ŕ')), // LATIN SMALL LETTER R WITH ACUTE
+    ('\u{0157}', CharMapAction::SubChar('Ŗ')), // LATIN SMALL LETTER R WITH CEDILLA
+    ('\u{0159}', CharMapAction::SubChar('Ř')), // LATIN SMALL LETTER R WITH CARON
+    ('\u{015B}', CharMapAction::SubChar('Ś')), // LATIN SMALL LETTER S WITH ACUTE
+    ('\u{015D}', CharMapAction::SubChar('Ŝ')), // LATIN SMALL LETTER S WITH CIRCUMFLEX
+    ('\u{015F}', CharMapAction::SubChar('Ş')), // LATIN SMALL LETTER S WITH CEDILLA
+    ('\u{0161}', CharMapAction::SubChar('Š')), // LATIN SMALL LETTER S WITH CARON
+    ('\u{0163}', CharMapAction::SubChar('Ţ')), // LATIN SMALL LETTER T WITH CEDILLA
+    ('\u{0165}', CharMapAction::SubChar('Ť')), // LATIN SMALL LETTER T WITH CARON
+    ('\u{0167}', CharMapAction::SubChar('Ŧ')), // LATIN SMALL LETTER T WITH STROKE
+    ('\u{0169}', CharMapAction::SubChar('Ũ')), // LATIN SMALL LETTER U WITH TILDE
+    ('\u{016B}', CharMapAction::SubChar('Ū')), // LATIN SMALL LETTER U WITH MACRON
+    ('\u{016D}', CharMapAction::SubChar('Ŭ')), // LATIN SMALL LETTER U WITH BREVE
+    ('\u{016F}', CharMapAction::SubChar('Ů')), // LATIN SMALL LETTER U WITH RING ABOVE
+    ('\u{0171}', CharMapAction::SubChar('Ű')), // LATIN SMALL LETTER U WITH DOUBLE ACUTE
+    ('\u{0173}', CharMapAction::SubChar('Ų')), // LATIN SMALL LETTER U WITH OGONEK
+    ('\u{0175}', CharMapAction::SubChar('Ŵ')), // LATIN SMALL LETTER W WITH CIRCUMFLEX
+    ('\u{0177}', CharMapAction::SubChar('Ŷ')), // LATIN SMALL LETTER Y WITH CIRCUMFLEX
+    ('\u{017A}', CharMapAction::SubChar('Ź')), // LATIN SMALL LETTER Z WITH ACUTE
+    ('\u{017C}', CharMapAction::SubChar('Ż')), // LATIN SMALL LETTER Z WITH DOT ABOVE
+    ('\u{017E}', CharMapAction::SubChar('Ž')), // LATIN SMALL LETTER Z WITH CARON
+    ('\u{017F}', CharMapAction::SubChar('S')), // LATIN SMALL LETTER LONG S
+    ('\u{0180}', CharMapAction::SubChar('Ƀ')), // LATIN SMALL LETTER B WITH STROKE
+    ('\u{0183}', CharMapAction::SubChar('Ƃ')), // LATIN SMALL LETTER B WITH TOPBAR
+    ('\u{0185}', CharMapAction::SubChar('Ƅ')), // LATIN SMALL LETTER TONE SIX
+    ('\u{0188}', CharMapAction::SubChar('Ƈ')), // LATIN SMALL LETTER C WITH HOOK
+    ('\u{018C}', CharMapAction::SubChar('Ƌ')), // LATIN SMALL LETTER D WITH TOPBAR
+    ('\u{0192}', CharMapAction::SubChar('Ƒ')), // LATIN SMALL LETTER F WITH HOOK
+    ('\u{0195}', CharMapAction::SubChar('Ƕ')), // LATIN SMALL LETTER HV
+    ('\u{0199}', CharMapAction::SubChar('Ƙ')), // LATIN SMALL LETTER K WITH HOOK
+    ('\u{019A}', CharMapAction::SubChar('Ƚ')), // LATIN SMALL LETTER L WITH BAR
+    ('\u{019E}', CharMapAction::SubChar('Ƞ')), // LATIN SMALL LETTER N WITH LONG RIGHT LEG
+    ('\u{01A1}', CharMapAction::SubChar('Ơ')), // LATIN SMALL LETTER O WITH HORN
+    ('\u{01A3}', CharMapAction::SubChar('Ƣ')), // LATIN SMALL LETTER OI
+    ('\u{01A5}', CharMapAction::SubChar('Ƥ')), // LATIN SMALL LETTER P WITH HOOK
+    ('\u{01A8}', CharMapAction::SubChar('Ƨ')), // LATIN SMALL LETTER TONE TWO
+    ('\u{01AD}', CharMapAction::SubChar('Ƭ')), // LATIN SMALL LETTER T WITH HOOK
+    ('\u{01B0}', CharMapAction::SubChar('Ư')), // LATIN SMALL LETTER U WITH HORN
+    ('\u{01B4}', CharMapAction::SubChar('Ƴ')), // LATIN SMALL LETTER Y WITH HOOK
+    ('\u{01B6}', CharMapAction::SubChar('Ƶ')), // LATIN SMALL LETTER Z WITH STROKE
+    ('\u{01B9}', CharMapAction::SubChar('Ƹ')), // LATIN SMALL LETTER EZH REVERSED
+    ('\u{01BD}', CharMapAction::SubChar('Ƽ')), // LATIN SMALL LETTER TONE FIVE
+    ('\u{01BF}', CharMapAction::SubChar('Ƿ')), // LATIN LETTER WYNN
+    ('\u{01C4}', CharMapAction::SubChar('ǅ')), // LATIN CAPITAL LETTER DZ WITH CARON
+    ('\u{01C6}', CharMapAction::SubChar('ǅ')), // LATIN SMALL LETTER DZ WITH CARON
+    ('\u{01C7}', CharMapAction::SubChar('ǈ')), // LATIN CAPITAL LETTER LJ
+    ('\u{01C9}', CharMapAction::SubChar('ǈ')), // LATIN SMALL LETTER LJ
+    ('\u{01CA}', CharMapAction::SubChar('ǋ')), // LATIN CAPITAL LETTER NJ
+    ('\u{01CC}', CharMapAction::SubChar('ǋ')), // LATIN SMALL LETTER NJ
+    ('\u{01CE}', CharMapAction::SubChar('Ǎ')), // LATIN SMALL LETTER A WITH CARON
+    ('\u{01D0}', CharMapAction::SubChar('Ǐ')), // LATIN SMALL LETTER I WITH CARON
+    ('\u{01D2}', CharMapAction::SubChar('Ǒ')), // LATIN SMALL LETTER O WITH CARON
+    ('\u{01D4}', CharMapAction::SubChar('Ǔ')), // LATIN SMALL LETTER U WITH CARON
+    ('\u{01D6}', CharMapAction::SubChar('Ǖ')), // LATIN SMALL LETTER U WITH DIAERESIS AND MACRON
+    ('\u{01D8}', CharMapAction::SubChar('Ǘ')), // LATIN SMALL LETTER U WITH DIAERESIS AND ACUTE
+    ('\u{01DA}', CharMapAction::SubChar('Ǚ')), // LATIN SMALL LETTER U WITH DIAERESIS AND CARON
+    ('\u{01DC}', CharMapAction::SubChar('Ǜ')), // LATIN SMALL LETTER U WITH DIAERESIS AND GRAVE
+    ('\u{01DD}', CharMapAction::SubChar('Ǝ')), // LATIN SMALL LETTER TURNED E
+    ('\u{01DF}', CharMapAction::SubChar('Ǟ')), // LATIN SMALL LETTER A WITH DIAERESIS AND MACRON
+    ('\u{01E1}', CharMapAction::SubChar('Ǡ')), // LATIN SMALL LETTER A WITH DOT ABOVE AND MACRON
+    ('\u{01E3}', CharMapAction::SubChar('Ǣ')), // LATIN SMALL LETTER AE WITH MACRON
+    ('\u{01E5}', CharMapAction::SubChar('Ǥ')), // LATIN SMALL LETTER G WITH STROKE
+    ('\u{01E7}', CharMapAction::SubChar('Ǧ')), // LATIN SMALL LETTER G WITH CARON
+    ('\u{01E9}', CharMapAction::SubChar('Ǩ')), // LATIN SMALL LETTER K WITH CARON
+    ('\u{01EB}', CharMapAction::SubChar('Ǫ')), // LATIN SMALL LETTER O WITH OGONEK
+    ('\u{01ED}', CharMapAction::SubChar('Ǭ')), // LATIN SMALL LETTER O WITH OGONEK AND MACRON
+    ('\u{01EF}', CharMapAction::SubChar('Ǯ')), // LATIN SMALL LETTER EZH WITH CARON
+    ('\u{01F0}', CharMapAction::SubStr("J\u{030C}")), // LATIN SMALL LETTER J WITH CARON
+    ('\u{01F1}', CharMapAction::SubChar('ǲ')), // LATIN CAPITAL LETTER DZ
+    ('\u{01F3}', CharMapAction::SubChar('ǲ')), // LATIN SMALL LETTER DZ
+    ('\u{01F5}', CharMapAction::SubChar('Ǵ')), // LATIN SMALL LETTER G WITH ACUTE
+    ('\u{01F9}', CharMapAction::SubChar('Ǹ')), // LATIN SMALL LETTER N WITH GRAVE
+    ('\u{01FB}', CharMapAction::SubChar('Ǻ')), // LATIN SMALL LETTER A WITH RING ABOVE AND ACUTE
+    ('\u{01FD}', CharMapAction::SubChar('Ǽ')), // LATIN SMALL LETTER AE WITH ACUTE
+    ('\u{01FF}', CharMapAction::SubChar('Ǿ')), // LATIN SMALL LETTER O WITH STROKE AND ACUTE
+    ('\u{0201}', CharMapAction::SubChar('Ȁ')), // LATIN SMALL LETTER A WITH DOUBLE GRAVE
+    ('\u{0203}', CharMapAction::SubChar('Ȃ')), // LATIN SMALL LETTER A WITH INVERTED BREVE
+    ('\u{0205}', CharMapAction::SubChar('Ȅ')), // LATIN SMALL LETTER E WITH DOUBLE GRAVE
+    ('\u{0207}', CharMapAction::SubChar('Ȇ')), // LATIN SMALL LETTER E WITH INVERTED BREVE
+    ('\u{0209}', CharMapAction::SubChar('Ȉ')), // LATIN SMALL LETTER I WITH DOUBLE GRAVE
+    ('\u{020B}', CharMapAction::SubChar('Ȋ')), // LATIN SMALL LETTER I WITH INVERTED BREVE
+    ('\u{020D}', CharMapAction::SubChar('Ȍ')), // LATIN SMALL LETTER O WITH DOUBLE GRAVE
+    ('\u{020F}', CharMapAction::SubChar('Ȏ')), // LATIN SMALL LETTER O WITH INVERTED BREVE
+    ('\u{0211}', CharMapAction::SubChar('Ȑ')), // LATIN SMALL LETTER R WITH DOUBLE GRAVE
+    ('\u{0213}', CharMapAction::SubChar('Ȓ')), // LATIN SMALL LETTER R WITH INVERTED BREVE
+    ('\u{0215}', CharMapAction::SubChar('Ȕ')), // LATIN SMALL LETTER U WITH DOUBLE GRAVE
+    ('\u{0217}', CharMapAction::SubChar('Ȗ')), // LATIN SMALL LETTER U WITH INVERTED BREVE
+    ('\u{0219}', CharMapAction::SubChar('Ș')), // LATIN SMALL LETTER S WITH COMMA BELOW
+    ('\u{021B}', CharMapAction::SubChar('Ț')), // LATIN SMALL LETTER T WITH COMMA BELOW
+    ('\u{021D}', CharMapAction::SubChar('Ȝ')), // LATIN SMALL LETTER YOGH
+    ('\u{021F}', CharMapAction::SubChar('Ȟ')), // LATIN SMALL LETTER H WITH CARON
+    ('\u{0223}', CharMapAction::SubChar('Ȣ')), // LATIN SMALL LETTER OU
+    ('\u{0225}', CharMapAction::SubChar('Ȥ')), // LATIN SMALL LETTER Z WITH HOOK
+    ('\u{0227}', CharMapAction::SubChar('Ȧ')), // LATIN SMALL LETTER A WITH DOT ABOVE
+    ('\u{0229}', CharMapAction::SubChar('Ȩ')), // LATIN SMALL LETTER E WITH CEDILLA
+    ('\u{022B}', CharMapAction::SubChar('Ȫ')), // LATIN SMALL LETTER O WITH DIAERESIS AND MACRON
+    ('\u{022D}', CharMapAction::SubChar('Ȭ')), // LATIN SMALL LETTER O WITH TILDE AND MACRON
+    ('\u{022F}', CharMapAction::SubChar('Ȯ')), // LATIN SMALL LETTER O WITH DOT ABOVE
+    ('\u{0231}', CharMapAction::SubChar('Ȱ')), // LATIN SMALL LETTER O WITH DOT ABOVE AND MACRON
+    ('\u{0233}', CharMapAction::SubChar('Ȳ')), // LATIN SMALL LETTER Y WITH MACRON
+    ('\u{023C}', CharMapAction::SubChar('Ȼ')), // LATIN SMALL LETTER C WITH STROKE
+    ('\u{023F}', CharMapAction::SubChar('Ȿ')), // LATIN SMALL LETTER S WITH SWASH TAIL
+    ('\u{0240}', CharMapAction::SubChar('Ɀ')), // LATIN SMALL LETTER Z WITH SWASH TAIL
+    ('\u{0242}', CharMapAction::SubChar('Ɂ')), // LATIN SMALL LETTER GLOTTAL STOP
+    ('\u{0247}', CharMapAction::SubChar('Ɇ')), // LATIN SMALL LETTER E WITH STROKE
+    ('\u{0249}', CharMapAction::SubChar('Ɉ')), // LATIN SMALL LETTER J WITH STROKE
+    ('\u{024B}', CharMapAction::SubChar('Ɋ')), // LATIN SMALL LETTER Q WITH HOOK TAIL
+    ('\u{024D}', CharMapAction::SubChar('Ɍ')), // LATIN SMALL LETTER R WITH STROKE
+    ('\u{024F}', CharMapAction::SubChar('Ɏ')), // LATIN SMALL LETTER Y WITH STROKE
+    ('\u{0250}', CharMapAction::SubChar('Ɐ')), // LATIN SMALL LETTER TURNED A
+    ('\u{0251}', CharMapAction::SubChar('Ɑ')), // LATIN SMALL LETTER ALPHA
+    ('\u{0252}', CharMapAction::SubChar('Ɒ')), // LATIN SMALL LETTER TURNED ALPHA
+    ('\u{0253}', CharMapAction::SubChar('Ɓ')), // LATIN SMALL LETTER B WITH HOOK
+    ('\u{0254}', CharMapAction::SubChar('Ɔ')), // LATIN SMALL LETTER OPEN O
+    ('\u{0256}', CharMapAction::SubChar('Ɖ')), // LATIN SMALL LETTER D WITH TAIL
+    ('\u{0257}', CharMapAction::SubChar('Ɗ')), // LATIN SMALL LETTER D WITH HOOK
+    ('\u{0259}', CharMapAction::SubChar('Ə')), // LATIN SMALL LETTER SCHWA
+    ('\u{025B}', CharMapAction::SubChar('Ɛ')), // LATIN SMALL LETTER OPEN E
+    ('\u{025C}', CharMapAction::SubChar('Ɜ')), // LATIN SMALL LETTER REVERSED OPEN E
+    ('\u{0260}', CharMapAction::SubChar('Ɠ')), // LATIN SMALL LETTER G WITH HOOK
+    ('\u{0261}', CharMapAction::SubChar('Ɡ')), // LATIN SMALL LETTER SCRIPT G
+    ('\u{0263}', CharMapAction::SubChar('Ɣ')), // LATIN SMALL LETTER GAMMA
+    ('\u{0265}', CharMapAction::SubChar('Ɥ')), // LATIN SMALL LETTER TURNED H
+    ('\u{0266}', CharMapAction::SubChar('Ɦ')), // LATIN SMALL LETTER H WITH HOOK
+    ('\u{0268}', CharMapAction::SubChar('Ɨ')), // LATIN SMALL LETTER I WITH STROKE
+    ('\u{0269}', CharMapAction::SubChar('Ɩ')), // LATIN SMALL LETTER IOTA
+    ('\u{026A}', CharMapAction::SubChar('Ɪ')), // LATIN LETTER SMALL CAPITAL I
+    ('\u{026B}', CharMapAction::SubChar('Ɫ')), // LATIN SMALL LETTER L WITH MIDDLE TILDE
+    ('\u{026C}', CharMapAction::SubChar('Ɬ')), // LATIN SMALL LETTER L WITH BELT
+    ('\u{026F}', CharMapAction::SubChar('Ɯ')), // LATIN SMALL LETTER TURNED M
+    ('\u{0271}', CharMapAction::SubChar('Ɱ')), // LATIN SMALL LETTER M WITH HOOK
+    ('\u{0272}', CharMapAction::SubChar('Ɲ')), // LATIN SMALL LETTER N WITH LEFT HOOK
+    ('\u{0275}', CharMapAction::SubChar('Ɵ')), // LATIN SMALL LETTER BARRED O
+    ('\u{027D}', CharMapAction::SubChar('Ɽ')), // LATIN SMALL LETTER R WITH TAIL
+    ('\u{0280}', CharMapAction::SubChar('Ʀ')), // LATIN LETTER SMALL CAPITAL R
+    ('\u{0282}', CharMapAction::SubChar('Ʂ')), // LATIN SMALL LETTER S WITH HOOK
+    ('\u{0283}', CharMapAction::SubChar('Ʃ')), // LATIN SMALL LETTER ESH
+    ('\u{0287}', CharMapAction::SubChar('Ʇ')), // LATIN SMALL LETTER TURNED T
+    ('\u{0288}', CharMapAction::SubChar('Ʈ')), // LATIN SMALL LETTER T WITH RETROFLEX HOOK
+    ('\u{0289}', CharMapAction::SubChar('Ʉ')), // LATIN SMALL LETTER U BAR
+    ('\u{028A}', CharMapAction::SubChar('Ʊ')), // LATIN SMALL LETTER UPSILON
+    ('\u{028B}', CharMapAction::SubChar('Ʋ')), // LATIN SMALL LETTER V WITH HOOK
+    ('\u{028C}', CharMapAction::SubChar('Ʌ')), // LATIN SMALL LETTER TURNED V
+    ('\u{0292}', CharMapAction::SubChar('Ʒ')), // LATIN SMALL LETTER EZH
+    ('\u{029D}', CharMapAction::SubChar('Ʝ')), // LATIN SMALL LETTER J WITH CROSSED-TAIL
+    ('\u{029E}', CharMapAction::SubChar('Ʞ')), // LATIN SMALL LETTER TURNED K
+    ('\u{0345}', CharMapAction::SubChar('Ι')), // COMBINING GREEK YPOGEGRAMMENI
+    ('\u{0371}', CharMapAction::SubChar('Ͱ')), // GREEK SMALL LETTER HETA
+    ('\u{0373}', CharMapAction::SubChar('Ͳ')), // GREEK SMALL LETTER ARCHAIC SAMPI
+    ('\u{0377}', CharMapAction::SubChar('Ͷ')), // GREEK SMALL LETTER PAMPHYLIAN DIGAMMA
+    ('\u{037B}', CharMapAction::SubChar('Ͻ')), // GREEK SMALL REVERSED LUNATE SIGMA SYMBOL
+    ('\u{037C}', CharMapAction::SubChar('Ͼ')), // GREEK SMALL DOTTED LUNATE SIGMA SYMBOL
+    ('\u{037D}', CharMapAction::SubChar('Ͽ')), // GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL
+    ('\u{0390}', CharMapAction::SubStr("Ι\u{0308}\u{0301}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS
+    ('\u{03AC}', CharMapAction::SubChar('Ά')), // GREEK SMALL LETTER ALPHA WITH TONOS
+    ('\u{03AD}', CharMapAction::SubChar('Έ')), // GREEK SMALL LETTER EPSILON WITH TONOS
+    ('\u{03AE}', CharMapAction::SubChar('Ή')), // GREEK SMALL LETTER ETA WITH TONOS
+    ('\u{03AF}', CharMapAction::SubChar('Ί')), // GREEK SMALL LETTER IOTA WITH TONOS
+    ('\u{03B0}', CharMapAction::SubStr("Υ\u{0308}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS
+    ('\u{03B1}', CharMapAction::SubChar('Α')), // GREEK SMALL LETTER ALPHA
+    ('\u{03B2}', CharMapAction::SubChar('Β')), // GREEK SMALL LETTER BETA
+    ('\u{03B3}', CharMapAction::SubChar('Γ')), // GREEK SMALL LETTER GAMMA
+    ('\u{03B4}', CharMapAction::SubChar('Δ')), // GREEK SMALL LETTER DELTA
+    ('\u{03B5}', CharMapAction::SubChar('Ε')), // GREEK SMALL LETTER EPSILON
+    ('\u{03B6}', CharMapAction::SubChar('Ζ')), // GREEK SMALL LETTER ZETA
+    ('\u{03B7}', CharMapAction::SubChar('Η')), // GREEK SMALL LETTER ETA
+    ('\u{03B8}', CharMapAction::SubChar('Θ')), // GREEK SMALL LETTER THETA
+    ('\u{03B9}', CharMapAction::SubChar('Ι')), // GREEK SMALL LETTER IOTA
+    ('\u{03BA}', CharMapAction::SubChar('Κ')), // GREEK SMALL LETTER KAPPA
+    ('\u{03BB}', CharMapAction::SubChar('Λ')), // GREEK SMALL LETTER LAMDA
+    ('\u{03BC}', CharMapAction::SubChar('Μ')), // GREEK SMALL LETTER MU
+    ('\u{03BD}', CharMapAction::SubChar('Ν')), // GREEK SMALL LETTER NU
+    ('\u{03BE}', CharMapAction::SubChar('Ξ')), // GREEK SMALL LETTER XI
+    ('\u{03BF}', CharMapAction::SubChar('Ο')), // GREEK SMALL LETTER OMICRON
+    ('\u{03C0}', CharMapAction::SubChar('Π')), // GREEK SMALL LETTER PI
+    ('\u{03C1}', CharMapAction::SubChar('Ρ')), // GREEK SMALL LETTER RHO
+    ('\u{03C2}', CharMapAction::SubChar('Σ')), // GREEK SMALL LETTER FINAL SIGMA
+    ('\u{03C3}', CharMapAction::SubChar('Σ')), // GREEK SMALL LETTER SIGMA
+    ('\u{03C4}', CharMapAction::SubChar('Τ')), // GREEK SMALL LETTER TAU
+    ('\u{03C5}', CharMapAction::SubChar('Υ')), // GREEK SMALL LETTER UPSILON
+    ('\u{03C6}', CharMapAction::SubChar('Φ')), // GREEK SMALL LETTER PHI
+    ('\u{03C7}', CharMapAction::SubChar('Χ')), // GREEK SMALL LETTER CHI
+    ('\u{03C8}', CharMapAction::SubChar('Ψ')), // GREEK SMALL LETTER PSI
+    ('\u{03C9}', CharMapAction::SubChar('Ω')), // GREEK SMALL LETTER OMEGA
+    ('\u{03CA}', CharMapAction::SubChar('Ϊ')), // GREEK SMALL LETTER IOTA WITH DIALYTIKA
+    ('\u{03CB}', CharMapAction::SubChar('Ϋ')), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03CC}', CharMapAction::SubChar('Ό')), // GREEK SMALL LETTER OMICRON WITH TONOS
+    ('\u{03CD}', CharMapAction::SubChar('Ύ')), // GREEK SMALL LETTER UPSILON WITH TONOS
+    ('\u{03CE}', CharMapAction::SubChar('Ώ')), // GREEK SMALL LETTER OMEGA WITH TONOS
+    ('\u{03D0}', CharMapAction::SubChar('Β')), // GREEK BETA SYMBOL
+    ('\u{03D1}', CharMapAction::SubChar('Θ')), // GREEK THETA SYMBOL
+    ('\u{03D5}', CharMapAction::SubChar('Φ')), // GREEK PHI SYMBOL
+    ('\u{03D6}', CharMapAction::SubChar('Π')), // GREEK PI SYMBOL
+    ('\u{03D7}', CharMapAction::SubChar('Ϗ')), // GREEK KAI SYMBOL
+    ('\u{03D9}', CharMapAction::SubChar('Ϙ')), // GREEK SMALL LETTER ARCHAIC KOPPA
+    ('\u{03DB}', CharMapAction::SubChar('Ϛ')), // GREEK SMALL LETTER STIGMA
+    ('\u{03DD}', CharMapAction::SubChar('Ϝ')), // GREEK SMALL LETTER DIGAMMA
+    ('\u{03DF}', CharMapAction::SubChar('Ϟ')), // GREEK SMALL LETTER KOPPA
+    ('\u{03E1}', CharMapAction::SubChar('Ϡ')), // GREEK SMALL LETTER SAMPI
+    ('\u{03E3}', CharMapAction::SubChar('Ϣ')), // COPTIC SMALL LETTER SHEI
+    ('\u{03E5}', CharMapAction::SubChar('Ϥ')), // COPTIC SMALL LETTER FEI
+    ('\u{03E7}', CharMapAction::SubChar('Ϧ')), // COPTIC SMALL LETTER KHEI
+    ('\u{03E9}', CharMapAction::SubChar('Ϩ')), // COPTIC SMALL LETTER HORI
+    ('\u{03EB}', CharMapAction::SubChar('Ϫ')), // COPTIC SMALL LETTER GANGIA
+    ('\u{03ED}', CharMapAction::SubChar('Ϭ')), // COPTIC SMALL LETTER SHIMA
+    ('\u{03EF}', CharMapAction::SubChar('Ϯ')), // COPTIC SMALL LETTER DEI
+    ('\u{03F0}', CharMapAction::SubChar('Κ')), // GREEK KAPPA SYMBOL
+    ('\u{03F1}', CharMapAction::SubChar('Ρ')), // GREEK RHO SYMBOL
+    ('\u{03F2}', CharMapAction::SubChar('Ϲ')), // GREEK LUNATE SIGMA SYMBOL
+    ('\u{03F3}', CharMapAction::SubChar('Ϳ')), // GREEK LETTER YOT
+    ('\u{03F5}', CharMapAction::SubChar('Ε')), // GREEK LUNATE EPSILON SYMBOL
+    ('\u{03F8}', CharMapAction::SubChar('Ϸ')), // GREEK SMALL LETTER SHO
+    ('\u{03FB}', CharMapAction::SubChar('Ϻ')), // GREEK SMALL LETTER SAN
+    ('\u{0430}', CharMapAction::SubChar('А')), // CYRILLIC SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('Б')), // CYRILLIC SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('В')), // CYRILLIC SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('Г')), // CYRILLIC SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('Д')), // CYRILLIC SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('Е')), // CYRILLIC SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubChar('Ж')), // CYRILLIC SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('З')), // CYRILLIC SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('И')), // CYRILLIC SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('Й')), // CYRILLIC SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('К')), // CYRILLIC SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('Л')), // CYRILLIC SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('М')), // CYRILLIC SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('Н')), // CYRILLIC SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('О')), // CYRILLIC SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('П')), // CYRILLIC SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('Р')), // CYRILLIC SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('С')), // CYRILLIC SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('Т')), // CYRILLIC SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('У')), // CYRILLIC SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('Ф')), // CYRILLIC SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('Х')), // CYRILLIC SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubChar('Ц')), // CYRILLIC SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubChar('Ч')), // CYRILLIC SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubChar('Ш')), // CYRILLIC SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubChar('Щ')), // CYRILLIC SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubChar('Ъ')), // CYRILLIC SMALL LETTER HARD SIGN
+    ('\u{044B}', CharMapAction::SubChar('Ы')), // CYRILLIC SMALL LETTER YERU
+    ('\u{044C}', CharMapAction::SubChar('Ь')), // CYRILLIC SMALL LETTER SOFT SIGN
+    ('\u{044D}', CharMapAction::SubChar('Э')), // CYRILLIC SMALL LETTER E
+    ('\u{044E}', CharMapAction::SubChar('Ю')), // CYRILLIC SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubChar('Я')), // CYRILLIC SMALL LETTER YA
+    ('\u{0450}', CharMapAction::SubChar('Ѐ')), // CYRILLIC SMALL LETTER IE WITH GRAVE
+    ('\u{0451}', CharMapAction::SubChar('Ё')), // CYRILLIC SMALL LETTER IO
+    ('\u{0452}', CharMapAction::SubChar('Ђ')), // CYRILLIC SMALL LETTER DJE
+    ('\u{0453}', CharMapAction::SubChar('Ѓ')), // CYRILLIC SMALL LETTER GJE
+    ('\u{0454}', CharMapAction::SubChar('Є')), // CYRILLIC SMALL LETTER UKRAINIAN IE
+    ('\u{0455}', CharMapAction::SubChar('Ѕ')), // CYRILLIC SMALL LETTER DZE
+    ('\u{0456}', CharMapAction::SubChar('І')), // CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0457}', CharMapAction::SubChar('Ї')), // CYRILLIC SMALL LETTER YI
+    ('\u{0458}', CharMapAction::SubChar('Ј')), // CYRILLIC SMALL LETTER JE
+    ('\u{0459}', CharMapAction::SubChar('Љ')), // CYRILLIC SMALL LETTER LJE
+    ('\u{045A}', CharMapAction::SubChar('Њ')), // CYRILLIC SMALL LETTER NJE
+    ('\u{045B}', CharMapAction::SubChar('Ћ')), // CYRILLIC SMALL LETTER TSHE
+    ('\u{045C}', CharMapAction::SubChar('Ќ')), // CYRILLIC SMALL LETTER KJE
+    ('\u{045D}', CharMapAction::SubChar('Ѝ')), // CYRILLIC SMALL LETTER I WITH GRAVE
+    ('\u{045E}', CharMapAction::SubChar('Ў')), // CYRILLIC SMALL LETTER SHORT U
+    ('\u{045F}', CharMapAction::SubChar('Џ')), // CYRILLIC SMALL LETTER DZHE
+    ('\u{0461}', CharMapAction::SubChar('Ѡ')), // CYRILLIC SMALL LETTER OMEGA
+    ('\u{0463}', CharMapAction::SubChar('Ѣ')), // CYRILLIC SMALL LETTER YAT
+    ('\u{0465}', CharMapAction::SubChar('Ѥ')), // CYRILLIC SMALL LETTER IOTIFIED E
+    ('\u{0467}', CharMapAction::SubChar('Ѧ')), // CYRILLIC SMALL LETTER LITTLE YUS
+    ('\u{0469}', CharMapAction::SubChar('Ѩ')), // CYRILLIC SMALL LETTER IOTIFIED LITTLE YUS
+    ('\u{046B}', CharMapAction::SubChar('Ѫ')), // CYRILLIC SMALL LETTER BIG YUS
+    ('\u{046D}', CharMapAction::SubChar('Ѭ')), // CYRILLIC SMALL LETTER IOTIFIED BIG YUS
+    ('\u{046F}', CharMapAction::SubChar('Ѯ')), // CYRILLIC SMALL LETTER KSI
+    ('\u{0471}', CharMapAction::SubChar('Ѱ')), // CYRILLIC SMALL LETTER PSI
+    ('\u{0473}', CharMapAction::SubChar('Ѳ')), // CYRILLIC SMALL LETTER FITA
+    ('\u{0475}', CharMapAction::SubChar('Ѵ')), // CYRILLIC SMALL LETTER IZHITSA
+    ('\u{0477}', CharMapAction::SubChar('Ѷ')), // CYRILLIC SMALL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT
+    ('\u{0479}', CharMapAction::SubChar('Ѹ')), // CYRILLIC SMALL LETTER UK
+    ('\u{047B}', CharMapAction::SubChar('Ѻ')), // CYRILLIC SMALL LETTER ROUND OMEGA
+    ('\u{047D}', CharMapAction::SubChar('Ѽ')), // CYRILLIC SMALL LETTER OMEGA WITH TITLO
+    ('\u{047F}', CharMapAction::SubChar('Ѿ')), // CYRILLIC SMALL LETTER OT
+    ('\u{0481}', CharMapAction::SubChar('Ҁ')), // CYRILLIC SMALL LETTER KOPPA
+    ('\u{048B}', CharMapAction::SubChar('Ҋ')), // CYRILLIC SMALL LETTER SHORT I WITH TAIL
+    ('\u{048D}', CharMapAction::SubChar('Ҍ')), // CYRILLIC SMALL LETTER SEMISOFT SIGN
+    ('\u{048F}', CharMapAction::SubChar('Ҏ')), // CYRILLIC SMALL LETTER ER WITH TICK
+    ('\u{0491}', CharMapAction::SubChar('Ґ')), // CYRILLIC SMALL LETTER GHE WITH UPTURN
+    ('\u{0493}', CharMapAction::SubChar('Ғ')), // CYRILLIC SMALL LETTER GHE WITH STROKE
+    ('\u{0495}', CharMapAction::SubChar('Ҕ')), // CYRILLIC SMALL LETTER GHE WITH MIDDLE HOOK
+    ('\u{0497}', CharMapAction::SubChar('Җ')), // CYRILLIC SMALL LETTER ZHE WITH DESCENDER
+    ('\u{0499}', CharMapAction::SubChar('Ҙ')), // CYRILLIC SMALL LETTER ZE WITH DESCENDER
+    ('\u{049B}', CharMapAction::SubChar('Қ')), // CYRILLIC SMALL LETTER KA WITH DESCENDER
+    ('\u{049D}', CharMapAction::SubChar('Ҝ')), // CYRILLIC SMALL LETTER KA WITH VERTICAL STROKE
+    ('\u{049F}', CharMapAction::SubChar('Ҟ')), // CYRILLIC SMALL LETTER KA WITH STROKE
+    ('\u{04A1}', CharMapAction::SubChar('Ҡ')), // CYRILLIC SMALL LETTER BASHKIR KA
+    ('\u{04A3}', CharMapAction::SubChar('Ң')), // CYRILLIC SMALL LETTER EN WITH DESCENDER
+    ('\u{04A5}', CharMapAction::SubChar('Ҥ')), // CYRILLIC SMALL LIGATURE EN GHE
+    ('\u{04A7}', CharMapAction::SubChar('Ҧ')), // CYRILLIC SMALL LETTER PE WITH MIDDLE HOOK
+    ('\u{04A9}', CharMapAction::SubChar('Ҩ')), // CYRILLIC SMALL LETTER ABKHASIAN HA
+    ('\u{04AB}', CharMapAction::SubChar('Ҫ')), // CYRILLIC SMALL LETTER ES WITH DESCENDER
+    ('\u{04AD}', CharMapAction::SubChar('Ҭ')), // CYRILLIC SMALL LETTER TE WITH DESCENDER
+    ('\u{04AF}', CharMapAction::SubChar('Ү')), // CYRILLIC SMALL LETTER STRAIGHT U
+    ('\u{04B1}', CharMapAction::SubChar('Ұ')), // CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B3}', CharMapAction::SubChar('Ҳ')), // CYRILLIC SMALL LETTER HA WITH DESCENDER
+    ('\u{04B5}', CharMapAction::SubChar('Ҵ')), // CYRILLIC SMALL LIGATURE TE TSE
+    ('\u{04B7}', CharMapAction::SubChar('Ҷ')), // CYRILLIC SMALL LETTER CHE WITH DESCENDER
+    ('\u{04B9}', CharMapAction::SubChar('Ҹ')), // CYRILLIC SMALL LETTER CHE WITH VERTICAL STROKE
+    ('\u{04BB}', CharMapAction::SubChar('Һ')), // CYRILLIC SMALL LETTER SHHA
+    ('\u{04BD}', CharMapAction::SubChar('Ҽ')), // CYRILLIC SMALL LETTER ABKHASIAN CHE
+    ('\u{04BF}', CharMapAction::SubChar('Ҿ')), // CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER
+    ('\u{04C2}', CharMapAction::SubChar('Ӂ')), // CYRILLIC SMALL LETTER ZHE WITH BREVE
+    ('\u{04C4}', CharMapAction::SubChar('Ӄ')), // CYRILLIC SMALL LETTER KA WITH HOOK
+    ('\u{04C6}', CharMapAction::SubChar('Ӆ')), // CYRILLIC SMALL LETTER EL WITH TAIL
+    ('\u{04C8}', CharMapAction::SubChar('Ӈ')), // CYRILLIC SMALL LETTER EN WITH HOOK
+    ('\u{04CA}', CharMapAction::SubChar('Ӊ')), // CYRILLIC SMALL LETTER EN WITH TAIL
+    ('\u{04CC}', CharMapAction::SubChar('Ӌ')), // CYRILLIC SMALL LETTER KHAKASSIAN CHE
+    ('\u{04CE}', CharMapAction::SubChar('Ӎ')), // CYRILLIC SMALL LETTER EM WITH TAIL
+    ('\u{04CF}', CharMapAction::SubChar('Ӏ')), // CYRILLIC SMALL LETTER PALOCHKA
+    ('\u{04D1}', CharMapAction::SubChar('Ӑ')), // CYRILLIC SMALL LETTER A WITH BREVE
+    ('\u{04D3}', CharMapAction::SubChar('Ӓ')), // CYRILLIC SMALL LETTER A WITH DIAERESIS
+    ('\u{04D5}', CharMapAction::SubChar('Ӕ')), // CYRILLIC SMALL LIGATURE A IE
+    ('\u{04D7}', CharMapAction::SubChar('Ӗ')), // CYRILLIC SMALL LETTER IE WITH BREVE
+    ('\u{04D9}', CharMapAction::SubChar('Ә')), // CYRILLIC SMALL LETTER SCHWA
+    ('\u{04DB}', CharMapAction::SubChar('Ӛ')), // CYRILLIC SMALL LETTER SCHWA WITH DIAERESIS
+    ('\u{04DD}', CharMapAction::SubChar('Ӝ')), // CYRILLIC SMALL LETTER ZHE WITH DIAERESIS
+    ('\u{04DF}', CharMapAction::SubChar('Ӟ')), // CYRILLIC SMALL LETTER ZE WITH DIAERESIS
+    ('\u{04E1}', CharMapAction::SubChar('Ӡ')), // CYRILLIC SMALL LETTER ABKHASIAN DZE
+    ('\u{04E3}', CharMapAction::SubChar('Ӣ')), // CYRILLIC SMALL LETTER I WITH MACRON
+    ('\u{04E5}', CharMapAction::SubChar('Ӥ')), // CYRILLIC SMALL LETTER I WITH DIAERESIS
+    ('\u{04E7}', CharMapAction::SubChar('Ӧ')), // CYRILLIC SMALL LETTER O WITH DIAERESIS
+    ('\u{04E9}', CharMapAction::SubChar('Ө')), // CYRILLIC SMALL LETTER BARRED O
+    ('\u{04EB}', CharMapAction::SubChar('Ӫ')), // CYRILLIC SMALL LETTER BARRED O WITH DIAERESIS
+    ('\u{04ED}', CharMapAction::SubChar('Ӭ')), // CYRILLIC SMALL LETTER E WITH DIAERESIS
+    ('\u{04EF}', CharMapAction::SubChar('Ӯ')), // CYRILLIC SMALL LETTER U WITH MACRON
+    ('\u{04F1}', CharMapAction::SubChar('Ӱ')), // CYRILLIC SMALL LETTER U WITH DIAERESIS
+    ('\u{04F3}', CharMapAction::SubChar('Ӳ')), // CYRILLIC SMALL LETTER U WITH DOUBLE ACUTE
+    ('\u{04F5}', CharMapAction::SubChar('Ӵ')), // CYRILLIC SMALL LETTER CHE WITH DIAERESIS
+    ('\u{04F7}', CharMapAction::SubChar('Ӷ')), // CYRILLIC SMALL LETTER GHE WITH DESCENDER
+    ('\u{04F9}', CharMapAction::SubChar('Ӹ')), // CYRILLIC SMALL LETTER YERU WITH DIAERESIS
+    ('\u{04FB}', CharMapAction::SubChar('Ӻ')), // CYRILLIC SMALL LETTER GHE WITH STROKE AND HOOK
+    ('\u{04FD}', CharMapAction::SubChar('Ӽ')), // CYRILLIC SMALL LETTER HA WITH HOOK
+    ('\u{04FF}', CharMapAction::SubChar('Ӿ')), // CYRILLIC SMALL LETTER HA WITH STROKE
+    ('\u{0501}', CharMapAction::SubChar('Ԁ')), // CYRILLIC SMALL LETTER KOMI DE
+    ('\u{0503}', CharMapAction::SubChar('Ԃ')), // CYRILLIC SMALL LETTER KOMI DJE
+    ('\u{0505}', CharMapAction::SubChar('Ԅ')), // CYRILLIC SMALL LETTER KOMI ZJE
+    ('\u{0507}', CharMapAction::SubChar('Ԇ')), // CYRILLIC SMALL LETTER KOMI DZJE
+    ('\u{0509}', CharMapAction::SubChar('Ԉ')), // CYRILLIC SMALL LETTER KOMI LJE
+    ('\u{050B}', CharMapAction::SubChar('Ԋ')), // CYRILLIC SMALL LETTER KOMI NJE
+    ('\u{050D}', CharMapAction::SubChar('Ԍ')), // CYRILLIC SMALL LETTER KOMI SJE
+    ('\u{050F}', CharMapAction::SubChar('Ԏ')), // CYRILLIC SMALL LETTER KOMI TJE
+    ('\u{0511}', CharMapAction::SubChar('Ԑ')), // CYRILLIC SMALL LETTER REVERSED ZE
+    ('\u{0513}', CharMapAction::SubChar('Ԓ')), // CYRILLIC SMALL LETTER EL WITH HOOK
+    ('\u{0515}', CharMapAction::SubChar('Ԕ')), // CYRILLIC SMALL LETTER LHA
+    ('\u{0517}', CharMapAction::SubChar('Ԗ')), // CYRILLIC SMALL LETTER RHA
+    ('\u{0519}', CharMapAction::SubChar('Ԙ')), // CYRILLIC SMALL LETTER YAE
+    ('\u{051B}', CharMapAction::SubChar('Ԛ')), // CYRILLIC SMALL LETTER QA
+    ('\u{051D}', CharMapAction::SubChar('Ԝ')), // CYRILLIC SMALL LETTER WE
+    ('\u{051F}', CharMapAction::SubChar('Ԟ')), // CYRILLIC SMALL LETTER ALEUT KA
+    ('\u{0521}', CharMapAction::SubChar('Ԡ')), // CYRILLIC SMALL LETTER EL WITH MIDDLE HOOK
+    ('\u{0523}', CharMapAction::SubChar('Ԣ')), // CYRILLIC SMALL LETTER EN WITH MIDDLE HOOK
+    ('\u{0525}', CharMapAction::SubChar('Ԥ')), // CYRILLIC SMALL LETTER PE WITH DESCENDER
+    ('\u{0527}', CharMapAction::SubChar('Ԧ')), // CYRILLIC SMALL LETTER SHHA WITH DESCENDER
+    ('\u{0529}', CharMapAction::SubChar('Ԩ')), // CYRILLIC SMALL LETTER EN WITH LEFT HOOK
+    ('\u{052B}', CharMapAction::SubChar('Ԫ')), // CYRILLIC SMALL LETTER DZZHE
+    ('\u{052D}', CharMapAction::SubChar('Ԭ')), // CYRILLIC SMALL LETTER DCHE
+    ('\u{052F}', CharMapAction::SubChar('Ԯ')), // CYRILLIC SMALL LETTER EL WITH DESCENDER
+    ('\u{0561}', CharMapAction::SubChar('Ա')), // ARMENIAN SMALL LETTER AYB
+    ('\u{0562}', CharMapAction::SubChar('Բ')), // ARMENIAN SMALL LETTER BEN
+    ('\u{0563}', CharMapAction::SubChar('Գ')), // ARMENIAN SMALL LETTER GIM
+    ('\u{0564}', CharMapAction::SubChar('Դ')), // ARMENIAN SMALL LETTER DA
+    ('\u{0565}', CharMapAction::SubChar('Ե')), // ARMENIAN SMALL LETTER ECH
+    ('\u{0566}', CharMapAction::SubChar('Զ')), // ARMENIAN SMALL LETTER ZA
+    ('\u{0567}', CharMapAction::SubChar('Է')), // ARMENIAN SMALL LETTER EH
+    ('\u{0568}', CharMapAction::SubChar('Ը')), // ARMENIAN SMALL LETTER ET
+    ('\u{0569}', CharMapAction::SubChar('Թ')), // ARMENIAN SMALL LETTER TO
+    ('\u{056A}', CharMapAction::SubChar('Ժ')), // ARMENIAN SMALL LETTER ZHE
+    ('\u{056B}', CharMapAction::SubChar('Ի')), // ARMENIAN SMALL LETTER INI
+    ('\u{056C}', CharMapAction::SubChar('Լ')), // ARMENIAN SMALL LETTER LIWN
+    ('\u{056D}', CharMapAction::SubChar('Խ')), // ARMENIAN SMALL LETTER XEH
+    ('\u{056E}', CharMapAction::SubChar('Ծ')), // ARMENIAN SMALL LETTER CA
+    ('\u{056F}', CharMapAction::SubChar('Կ')), // ARMENIAN SMALL LETTER KEN
+    ('\u{0570}', CharMapAction::SubChar('Հ')), // ARMENIAN SMALL LETTER HO
+    ('\u{0571}', CharMapAction::SubChar('Ձ')), // ARMENIAN SMALL LETTER JA
+    ('\u{0572}', CharMapAction::SubChar('Ղ')), // ARMENIAN SMALL LETTER GHAD
+    ('\u{0573}', CharMapAction::SubChar('Ճ')), // ARMENIAN SMALL LETTER CHEH
+    ('\u{0574}', CharMapAction::SubChar('Մ')), // ARMENIAN SMALL LETTER MEN
+    ('\u{0575}', CharMapAction::SubChar('Յ')), // ARMENIAN SMALL LETTER YI
+    ('\u{0576}', CharMapAction::SubChar('Ն')), // ARMENIAN SMALL LETTER NOW
+    ('\u{0577}', CharMapAction::SubChar('Շ')), // ARMENIAN SMALL LETTER SHA
+    ('\u{0578}', CharMapAction::SubChar('Ո')), // ARMENIAN SMALL LETTER VO
+    ('\u{0579}', CharMapAction::SubChar('Չ')), // ARMENIAN SMALL LETTER CHA
+    ('\u{057A}', CharMapAction::SubChar('Պ')), // ARMENIAN SMALL LETTER PEH
+    ('\u{057B}', CharMapAction::SubChar('Ջ')), // ARMENIAN SMALL LETTER JHEH
+    ('\u{057C}', CharMapAction::SubChar('Ռ')), // ARMENIAN SMALL LETTER RA
+    ('\u{057D}', CharMapAction::SubChar('Ս')), // ARMENIAN SMALL LETTER SEH
+    ('\u{057E}', CharMapAction::SubChar('Վ')), // ARMENIAN SMALL LETTER VEW
+    ('\u{057F}', CharMapAction::SubChar('Տ')), // ARMENIAN SMALL LETTER TIWN
+    ('\u{0580}', CharMapAction::SubChar('Ր')), // ARMENIAN SMALL LETTER REH
+    ('\u{0581}', CharMapAction::SubChar('Ց')), // ARMENIAN SMALL LETTER CO
+    ('\u{0582}', CharMapAction::SubChar('Ւ')), // ARMENIAN SMALL LETTER YIWN
+    ('\u{0583}', CharMapAction::SubChar('Փ')), // ARMENIAN SMALL LETTER PIWR
+    ('\u{0584}', CharMapAction::SubChar('Ք')), // ARMENIAN SMALL LETTER KEH
+    ('\u{0585}', CharMapAction::SubChar('Օ')), // ARMENIAN SMALL LETTER OH
+    ('\u{0586}', CharMapAction::SubChar('Ֆ')), // ARMENIAN SMALL LETTER FEH
+    ('\u{0587}', CharMapAction::SubStr("Եւ")), // ARMENIAN SMALL LIGATURE ECH YIWN
+    ('\u{13F8}', CharMapAction::SubChar('Ᏸ')), // CHEROKEE SMALL LETTER YE
+    ('\u{13F9}', CharMapAction::SubChar('Ᏹ')), // CHEROKEE SMALL LETTER YI
+    ('\u{13FA}', CharMapAction::SubChar('Ᏺ')), // CHEROKEE SMALL LETTER YO
+    ('\u{13FB}', CharMapAction::SubChar('Ᏻ')), // CHEROKEE SMALL LETTER YU
+    ('\u{13FC}', CharMapAction::SubChar('Ᏼ')), // CHEROKEE SMALL LETTER YV
+    ('\u{13FD}', CharMapAction::SubChar('Ᏽ')), // CHEROKEE SMALL LETTER MV
+    ('\u{1C80}', CharMapAction::SubChar('В')), // CYRILLIC SMALL LETTER ROUNDED VE
+    ('\u{1C81}', CharMapAction::SubChar('Д')), // CYRILLIC SMALL LETTER LONG-LEGGED DE
+    ('\u{1C82}', CharMapAction::SubChar('О')), // CYRILLIC SMALL LETTER NARROW O
+    ('\u{1C83}', CharMapAction::SubChar('С')), // CYRILLIC SMALL LETTER WIDE ES
+    ('\u{1C84}', CharMapAction::SubChar('Т')), // CYRILLIC SMALL LETTER TALL TE
+    ('\u{1C85}', CharMapAction::SubChar('Т')), // CYRILLIC SMALL LETTER THREE-LEGGED TE
+    ('\u{1C86}', CharMapAction::SubChar('Ъ')), // CYRILLIC SMALL LETTER TALL HARD SIGN
+    ('\u{1C87}', CharMapAction::SubChar('Ѣ')), // CYRILLIC SMALL LETTER TALL YAT
+    ('\u{1C88}', CharMapAction::SubChar('Ꙋ')), // CYRILLIC SMALL LETTER UNBLENDED UK
+    ('\u{1D79}', CharMapAction::SubChar('Ᵹ')), // LATIN SMALL LETTER INSULAR G
+    ('\u{1D7D}', CharMapAction::SubChar('Ᵽ')), // LATIN SMALL LETTER P WITH STROKE
+    ('\u{1D8E}', CharMapAction::SubChar('Ᶎ')), // LATIN SMALL LETTER Z WITH PALATAL HOOK
+    ('\u{1E01}', CharMapAction::SubChar('Ḁ')), // LATIN SMALL LETTER A WITH RING BELOW
+    ('\u{1E03}', CharMapAction::SubChar('Ḃ')), // LATIN SMALL LETTER B WITH DOT ABOVE
+    ('\u{1E05}', CharMapAction::SubChar('Ḅ')), // LATIN SMALL LETTER B WITH DOT BELOW
+    ('\u{1E07}', CharMapAction::SubChar('Ḇ')), // LATIN SMALL LETTER B WITH LINE BELOW
+    ('\u{1E09}', CharMapAction::SubChar('Ḉ')), // LATIN SMALL LETTER C WITH CEDILLA AND ACUTE
+    ('\u{1E0B}', CharMapAction::SubChar('Ḋ')), // LATIN SMALL LETTER D WITH DOT ABOVE
+    ('\u{1E0D}', CharMapAction::SubChar('Ḍ')), // LATIN SMALL LETTER D WITH DOT BELOW
+    ('\u{1E0F}', CharMapAction::SubChar('Ḏ')), // LATIN SMALL LETTER D WITH LINE BELOW
+    ('\u{1E11}', CharMapAction::SubChar('Ḑ')), // LATIN SMALL LETTER D WITH CEDILLA
+    ('\u{1E13}', CharMapAction::SubChar('Ḓ')), // LATIN SMALL LETTER D WITH CIRCUMFLEX BELOW
+    ('\u{1E15}', CharMapAction::SubChar('Ḕ')), // LATIN SMALL LETTER E WITH MACRON AND GRAVE
+    ('\u{1E17}', CharMapAction::SubChar('Ḗ')), // LATIN SMALL LETTER E WITH MACRON AND ACUTE
+    ('\u{1E19}', CharMapAction::SubChar('Ḙ')), // LATIN SMALL LETTER E WITH CIRCUMFLEX BELOW
+    ('\u{1E1B}', CharMapAction::SubChar('Ḛ')), // LATIN SMALL LETTER E WITH TILDE BELOW
+    ('\u{1E1D}', CharMapAction::SubChar('Ḝ')), // LATIN SMALL LETTER E WITH CEDILLA AND BREVE
+    ('\u{1E1F}', CharMapAction::SubChar('Ḟ')), // LATIN SMALL LETTER F WITH DOT ABOVE
+    ('\u{1E21}', CharMapAction::SubChar('Ḡ')), // LATIN SMALL LETTER G WITH MACRON
+    ('\u{1E23}', CharMapAction::SubChar('Ḣ')), // LATIN SMALL LETTER H WITH DOT ABOVE
+    ('\u{1E25}', CharMapAction::SubChar('Ḥ')), // LATIN SMALL LETTER H WITH DOT BELOW
+    ('\u{1E27}', CharMapAction::SubChar('Ḧ')), // LATIN SMALL LETTER H WITH DIAERESIS
+    ('\u{1E29}', CharMapAction::SubChar('Ḩ')), // LATIN SMALL LETTER H WITH CEDILLA
+    ('\u{1E2B}', CharMapAction::SubChar('Ḫ')), // LATIN SMALL LETTER H WITH BREVE BELOW
+    ('\u{1E2D}', CharMapAction::SubChar('Ḭ')), // LATIN SMALL LETTER I WITH TILDE BELOW
+    ('\u{1E2F}', CharMapAction::SubChar('Ḯ')), // LATIN SMALL LETTER I WITH DIAERESIS AND ACUTE
+    ('\u{1E31}', CharMapAction::SubChar('Ḱ')), // LATIN SMALL LETTER K WITH ACUTE
+    ('\u{1E33}', CharMapAction::SubChar('Ḳ')), // LATIN SMALL LETTER K WITH DOT BELOW
+    ('\u{1E35}', CharMapAction::SubChar('Ḵ')), // LATIN SMALL LETTER K WITH LINE BELOW
+    ('\u{1E37}', CharMapAction::SubChar('Ḷ')), // LATIN SMALL LETTER L WITH DOT BELOW
+    ('\u{1E39}', CharMapAction::SubChar('Ḹ')), // LATIN SMALL LETTER L WITH DOT BELOW AND MACRON
+    ('\u{1E3B}', CharMapAction::SubChar('Ḻ')), // LATIN SMALL LETTER L WITH LINE BELOW
+    ('\u{1E3D}', CharMapAction::SubChar('Ḽ')), // LATIN SMALL LETTER L WITH CIRCUMFLEX BELOW
+    ('\u{1E3F}', CharMapAction::SubChar('Ḿ')), // LATIN SMALL LETTER M WITH ACUTE
+    ('\u{1E41}', CharMapAction::SubChar('Ṁ')), // LATIN SMALL LETTER M WITH DOT ABOVE
+    ('\u{1E43}', CharMapAction::SubChar('Ṃ')), // LATIN SMALL LETTER M WITH DOT BELOW
+    ('\u{1E45}', CharMapAction::SubChar('Ṅ')), // LATIN SMALL LETTER N WITH DOT ABOVE
+    ('\u{1E47}', CharMapAction::SubChar('Ṇ')), // LATIN SMALL LETTER N WITH DOT BELOW
+    ('\u{1E49}', CharMapAction::SubChar('Ṉ')), // LATIN SMALL LETTER N WITH LINE BELOW
+    ('\u{1E4B}', CharMapAction::SubChar('Ṋ')), // LATIN SMALL LETTER N WITH CIRCUMFLEX BELOW
+    ('\u{1E4D}', CharMapAction::SubChar('Ṍ')), // LATIN SMALL LETTER O WITH TILDE AND ACUTE
+    ('\u{1E4F}', CharMapAction::SubChar('Ṏ')), // LATIN SMALL LETTER O WITH TILDE AND DIAERESIS
+    ('\u{1E51}', CharMapAction::SubChar('Ṑ')), // LATIN SMALL LETTER O WITH MACRON AND GRAVE
+    ('\u{1E53}', CharMapAction::SubChar('Ṓ')), // LATIN SMALL LETTER O WITH MACRON AND ACUTE
+    ('\u{1E55}', CharMapAction::SubChar('Ṕ')), // LATIN SMALL LETTER P WITH ACUTE
+    ('\u{1E57}', CharMapAction::SubChar('Ṗ')), // LATIN SMALL LETTER P WITH DOT ABOVE
+    ('\u{1E59}', CharMapAction::SubChar('Ṙ')), // LATIN SMALL LETTER R WITH DOT ABOVE
+    ('\u{1E5B}', CharMapAction::SubChar('Ṛ')), // LATIN SMALL LETTER R WITH DOT BELOW
+    ('\u{1E5D}', CharMapAction::SubChar('Ṝ')), // LATIN SMALL LETTER R WITH DOT BELOW AND MACRON
+    ('\u{1E5F}', CharMapAction::SubChar('Ṟ')), // LATIN SMALL LETTER R WITH LINE BELOW
+    ('\u{1E61}', CharMapAction::SubChar('Ṡ')), // LATIN SMALL LETTER S WITH DOT ABOVE
+    ('\u{1E63}', CharMapAction::SubChar('Ṣ')), // LATIN SMALL LETTER S WITH DOT BELOW
+    ('\u{1E65}', CharMapAction::SubChar('Ṥ')), // LATIN SMALL LETTER S WITH ACUTE AND DOT ABOVE
+    ('\u{1E67}', CharMapAction::SubChar('Ṧ')), // LATIN SMALL LETTER S WITH CARON AND DOT ABOVE
+    ('\u{1E69}', CharMapAction::SubChar('Ṩ')), // LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE
+    ('\u{1E6B}', CharMapAction::SubChar('Ṫ')), // LATIN SMALL LETTER T WITH DOT ABOVE
+    ('\u{1E6D}', CharMapAction::SubChar('Ṭ')), // LATIN SMALL LETTER T WITH DOT BELOW
+    ('\u{1E6F}', CharMapAction::SubChar('Ṯ')), // LATIN SMALL LETTER T WITH LINE BELOW
+    ('\u{1E71}', CharMapAction::SubChar('Ṱ')), // LATIN SMALL LETTER T WITH CIRCUMFLEX BELOW
+    ('\u{1E73}', CharMapAction::SubChar('Ṳ')), // LATIN SMALL LETTER U WITH DIAERESIS BELOW
+    ('\u{1E75}', CharMapAction::SubChar('Ṵ')), // LATIN SMALL LETTER U WITH TILDE BELOW
+    ('\u{1E77}', CharMapAction::SubChar('Ṷ')), // LATIN SMALL LETTER U WITH CIRCUMFLEX BELOW
+    ('\u{1E79}', CharMapAction::SubChar('Ṹ')), // LATIN SMALL LETTER U WITH TILDE AND ACUTE
+    ('\u{1E7B}', CharMapAction::SubChar('Ṻ')), // LATIN SMALL LETTER U WITH MACRON AND DIAERESIS
+    ('\u{1E7D}', CharMapAction::SubChar('Ṽ')), // LATIN SMALL LETTER V WITH TILDE
+    ('\u{1E7F}', CharMapAction::SubChar('Ṿ')), // LATIN SMALL LETTER V WITH DOT BELOW
+    ('\u{1E81}', CharMapAction::SubChar('Ẁ')), // LATIN SMALL LETTER W WITH GRAVE
+    ('\u{1E83}', CharMapAction::SubChar('Ẃ')), // LATIN SMALL LETTER W WITH ACUTE
+    ('\u{1E85}', CharMapAction::SubChar('Ẅ')), // LATIN SMALL LETTER W WITH DIAERESIS
+    ('\u{1E87}', CharMapAction::SubChar('Ẇ')), // LATIN SMALL LETTER W WITH DOT ABOVE
+    ('\u{1E89}', CharMapAction::SubChar('Ẉ')), // LATIN SMALL LETTER W WITH DOT BELOW
+    ('\u{1E8B}', CharMapAction::SubChar('Ẋ')), // LATIN SMALL LETTER X WITH DOT ABOVE
+    ('\u{1E8D}', CharMapAction::SubChar('Ẍ')), // LATIN SMALL LETTER X WITH DIAERESIS
+    ('\u{1E8F}', CharMapAction::SubChar('Ẏ')), // LATIN SMALL LETTER Y WITH DOT ABOVE
+    ('\u{1E91}', CharMapAction::SubChar('Ẑ')), // LATIN SMALL LETTER Z WITH CIRCUMFLEX
+    ('\u{1E93}', CharMapAction::SubChar('Ẓ')), // LATIN SMALL LETTER Z WITH DOT BELOW
+    ('\u{1E95}', CharMapAction::SubChar('Ẕ')), // LATIN SMALL LETTER Z WITH LINE BELOW
+    ('\u{1E96}', CharMapAction::SubStr("H\u{0331}")), // LATIN SMALL LETTER H WITH LINE BELOW
+    ('\u{1E97}', CharMapAction::SubStr("T\u{0308}")), // LATIN SMALL LETTER T WITH DIAERESIS
+    ('\u{1E98}', CharMapAction::SubStr("W\u{030A}")), // LATIN SMALL LETTER W WITH RING ABOVE
+    ('\u{1E99}', CharMapAction::SubStr("Y\u{030A}")), // LATIN SMALL LETTER Y WITH RING ABOVE
+    ('\u{1E9A}', CharMapAction::SubStr("Aʾ")), // LATIN SMALL LETTER A WITH RIGHT HALF RING
+    ('\u{1E9B}', CharMapAction::SubChar('Ṡ')), // LATIN SMALL LETTER LONG S WITH DOT ABOVE
+    ('\u{1EA1}', CharMapAction::SubChar('Ạ')), // LATIN SMALL LETTER A WITH DOT BELOW
+    ('\u{1EA3}', CharMapAction::SubChar('Ả')), // LATIN SMALL LETTER A WITH HOOK ABOVE
+    ('\u{1EA5}', CharMapAction::SubChar('Ấ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EA7}', CharMapAction::SubChar('Ầ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EA9}', CharMapAction::SubChar('Ẩ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EAB}', CharMapAction::SubChar('Ẫ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND TILDE
+    ('\u{1EAD}', CharMapAction::SubChar('Ậ')), // LATIN SMALL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EAF}', CharMapAction::SubChar('Ắ')), // LATIN SMALL LETTER A WITH BREVE AND ACUTE
+    ('\u{1EB1}', CharMapAction::SubChar('Ằ')), // LATIN SMALL LETTER A WITH BREVE AND GRAVE
+    ('\u{1EB3}', CharMapAction::SubChar('Ẳ')), // LATIN SMALL LETTER A WITH BREVE AND HOOK ABOVE
+    ('\u{1EB5}', CharMapAction::SubChar('Ẵ')), // LATIN SMALL LETTER A WITH BREVE AND TILDE
+    ('\u{1EB7}', CharMapAction::SubChar('Ặ')), // LATIN SMALL LETTER A WITH BREVE AND DOT BELOW
+    ('\u{1EB9}', CharMapAction::SubChar('Ẹ')), // LATIN SMALL LETTER E WITH DOT BELOW
+    ('\u{1EBB}', CharMapAction::SubChar('Ẻ')), // LATIN SMALL LETTER E WITH HOOK ABOVE
+    ('\u{1EBD}', CharMapAction::SubChar('Ẽ')), // LATIN SMALL LETTER E WITH TILDE
+    ('\u{1EBF}', CharMapAction::SubChar('Ế')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EC1}', CharMapAction::SubChar('Ề')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EC3}', CharMapAction::SubChar('Ể')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EC5}', CharMapAction::SubChar('Ễ')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND TILDE
+    ('\u{1EC7}', CharMapAction::SubChar('Ệ')), // LATIN SMALL LETTER E WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EC9}', CharMapAction::SubChar('Ỉ')), // LATIN SMALL LETTER I WITH HOOK ABOVE
+    ('\u{1ECB}', CharMapAction::SubChar('Ị')), // LATIN SMALL LETTER I WITH DOT BELOW
+    ('\u{1ECD}', CharMapAction::SubChar('Ọ')), // LATIN SMALL LETTER O WITH DOT BELOW
+    ('\u{1ECF}', CharMapAction::SubChar('Ỏ')), // LATIN SMALL LETTER O WITH HOOK ABOVE
+    ('\u{1ED1}', CharMapAction::SubChar('Ố')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND ACUTE
+    ('\u{1ED3}', CharMapAction::SubChar('Ồ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND GRAVE
+    ('\u{1ED5}', CharMapAction::SubChar('Ổ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1ED7}', CharMapAction::SubChar('Ỗ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND TILDE
+    ('\u{1ED9}', CharMapAction::SubChar('Ộ')), // LATIN SMALL LETTER O WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EDB}', CharMapAction::SubChar('Ớ')), // LATIN SMALL LETTER O WITH HORN AND ACUTE
+    ('\u{1EDD}', CharMapAction::SubChar('Ờ')), // LATIN SMALL LETTER O WITH HORN AND GRAVE
+    ('\u{1EDF}', CharMapAction::SubChar('Ở')), // LATIN SMALL LETTER O WITH HORN AND HOOK ABOVE
+    ('\u{1EE1}', CharMapAction::SubChar('Ỡ')), // LATIN SMALL LETTER O WITH HORN AND TILDE
+    ('\u{1EE3}', CharMapAction::SubChar('Ợ')), // LATIN SMALL LETTER O WITH HORN AND DOT BELOW
+    ('\u{1EE5}', CharMapAction::SubChar('Ụ')), // LATIN SMALL LETTER U WITH DOT BELOW
+    ('\u{1EE7}', CharMapAction::SubChar('Ủ')), // LATIN SMALL LETTER U WITH HOOK ABOVE
+    ('\u{1EE9}', CharMapAction::SubChar('Ứ')), // LATIN SMALL LETTER U WITH HORN AND ACUTE
+    ('\u{1EEB}', CharMapAction::SubChar('Ừ')), // LATIN SMALL LETTER U WITH HORN AND GRAVE
+    ('\u{1EED}', CharMapAction::SubChar('Ử')), // LATIN SMALL LETTER U WITH HORN AND HOOK ABOVE
+    ('\u{1EEF}', CharMapAction::SubChar('Ữ')), // LATIN SMALL LETTER U WITH HORN AND TILDE
+    ('\u{1EF1}', CharMapAction::SubChar('Ự')), // LATIN SMALL LETTER U WITH HORN AND DOT BELOW
+    ('\u{1EF3}', CharMapAction::SubChar('Ỳ')), // LATIN SMALL LETTER Y WITH GRAVE
+    ('\u{1EF5}', CharMapAction::SubChar('Ỵ')), // LATIN SMALL LETTER Y WITH DOT BELOW
+    ('\u{1EF7}', CharMapAction::SubChar('Ỷ')), // LATIN SMALL LETTER Y WITH HOOK ABOVE
+    ('\u{1EF9}', CharMapAction::SubChar('Ỹ')), // LATIN SMALL LETTER Y WITH TILDE
+    ('\u{1EFB}', CharMapAction::SubChar('Ỻ')), // LATIN SMALL LETTER MIDDLE-WELSH LL
+    ('\u{1EFD}', CharMapAction::SubChar('Ỽ')), // LATIN SMALL LETTER MIDDLE-WELSH V
+    ('\u{1EFF}', CharMapAction::SubChar('Ỿ')), // LATIN SMALL LETTER Y WITH LOOP
+    ('\u{1F00}', CharMapAction::SubChar('Ἀ')), // GREEK SMALL LETTER ALPHA WITH PSILI
+    ('\u{1F01}', CharMapAction::SubChar('Ἁ')), // GREEK SMALL LETTER ALPHA WITH DASIA
+    ('\u{1F02}', CharMapAction::SubChar('Ἂ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F03}', CharMapAction::SubChar('Ἃ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F04}', CharMapAction::SubChar('Ἄ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F05}', CharMapAction::SubChar('Ἅ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F06}', CharMapAction::SubChar('Ἆ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F07}', CharMapAction::SubChar('Ἇ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F10}', CharMapAction::SubChar('Ἐ')), // GREEK SMALL LETTER EPSILON WITH PSILI
+    ('\u{1F11}', CharMapAction::SubChar('Ἑ')), // GREEK SMALL LETTER EPSILON WITH DASIA
+    ('\u{1F12}', CharMapAction::SubChar('Ἒ')), // GREEK SMALL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F13}', CharMapAction::SubChar('Ἓ')), // GREEK SMALL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F14}', CharMapAction::SubChar('Ἔ')), // GREEK SMALL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F15}', CharMapAction::SubChar('Ἕ')), // GREEK SMALL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F20}', CharMapAction::SubChar('Ἠ')), // GREEK SMALL LETTER ETA WITH PSILI
+    ('\u{1F21}', CharMapAction::SubChar('Ἡ')), // GREEK SMALL LETTER ETA WITH DASIA
+    ('\u{1F22}', CharMapAction::SubChar('Ἢ')), // GREEK SMALL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F23}', CharMapAction::SubChar('Ἣ')), // GREEK SMALL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F24}', CharMapAction::SubChar('Ἤ')), // GREEK SMALL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F25}', CharMapAction::SubChar('Ἥ')), // GREEK SMALL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F26}', CharMapAction::SubChar('Ἦ')), // GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F27}', CharMapAction::SubChar('Ἧ')), // GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F30}', CharMapAction::SubChar('Ἰ')), // GREEK SMALL LETTER IOTA WITH PSILI
+    ('\u{1F31}', CharMapAction::SubChar('Ἱ')), // GREEK SMALL LETTER IOTA WITH DASIA
+    ('\u{1F32}', CharMapAction::SubChar('Ἲ')), // GREEK SMALL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F33}', CharMapAction::SubChar('Ἳ')), // GREEK SMALL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F34}', CharMapAction::SubChar('Ἴ')), // GREEK SMALL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F35}', CharMapAction::SubChar('Ἵ')), // GREEK SMALL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F36}', CharMapAction::SubChar('Ἶ')), // GREEK SMALL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F37}', CharMapAction::SubChar('Ἷ')), // GREEK SMALL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F40}', CharMapAction::SubChar('Ὀ')), // GREEK SMALL LETTER OMICRON WITH PSILI
+    ('\u{1F41}', CharMapAction::SubChar('Ὁ')), // GREEK SMALL LETTER OMICRON WITH DASIA
+    ('\u{1F42}', CharMapAction::SubChar('Ὂ')), // GREEK SMALL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F43}', CharMapAction::SubChar('Ὃ')), // GREEK SMALL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F44}', CharMapAction::SubChar('Ὄ')), // GREEK SMALL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F45}', CharMapAction::SubChar('Ὅ')), // GREEK SMALL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F50}', CharMapAction::SubStr("Υ\u{0313}")), // GREEK SMALL LETTER UPSILON WITH PSILI
+    ('\u{1F51}', CharMapAction::SubChar('Ὑ')), // GREEK SMALL LETTER UPSILON WITH DASIA
+    ('\u{1F52}', CharMapAction::SubStr("Υ\u{0313}\u{0300}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND VARIA
+    ('\u{1F53}', CharMapAction::SubChar('Ὓ')), // GREEK SMALL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F54}', CharMapAction::SubStr("Υ\u{0313}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND OXIA
+    ('\u{1F55}', CharMapAction::SubChar('Ὕ')), // GREEK SMALL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F56}', CharMapAction::SubStr("Υ\u{0313}\u{0342}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND PERISPOMENI
+    ('\u{1F57}', CharMapAction::SubChar('Ὗ')), // GREEK SMALL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F60}', CharMapAction::SubChar('Ὠ')), // GREEK SMALL LETTER OMEGA WITH PSILI
+    ('\u{1F61}', CharMapAction::SubChar('Ὡ')), // GREEK SMALL LETTER OMEGA WITH DASIA
+    ('\u{1F62}', CharMapAction::SubChar('Ὢ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F63}', CharMapAction::SubChar('Ὣ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F64}', CharMapAction::SubChar('Ὤ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F65}', CharMapAction::SubChar('Ὥ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F66}', CharMapAction::SubChar('Ὦ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F67}', CharMapAction::SubChar('Ὧ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F70}', CharMapAction::SubChar('Ὰ')), // GREEK SMALL LETTER ALPHA WITH VARIA
+    ('\u{1F71}', CharMapAction::SubChar('Ά')), // GREEK SMALL LETTER ALPHA WITH OXIA
+    ('\u{1F72}', CharMapAction::SubChar('Ὲ')), // GREEK SMALL LETTER EPSILON WITH VARIA
+    ('\u{1F73}', CharMapAction::SubChar('Έ')), // GREEK SMALL LETTER EPSILON WITH OXIA
+    ('\u{1F74}', CharMapAction::SubChar('Ὴ')), // GREEK SMALL LETTER ETA WITH VARIA
+    ('\u{1F75}', CharMapAction::SubChar('Ή')), // GREEK SMALL LETTER ETA WITH OXIA
+    ('\u{1F76}', CharMapAction::SubChar('Ὶ')), // GREEK SMALL LETTER IOTA WITH VARIA
+    ('\u{1F77}', CharMapAction::SubChar('Ί')), // GREEK SMALL LETTER IOTA WITH OXIA
+    ('\u{1F78}', CharMapAction::SubChar('Ὸ')), // GREEK SMALL LETTER OMICRON WITH VARIA
+    ('\u{1F79}', CharMapAction::SubChar('Ό')), // GREEK SMALL LETTER OMICRON WITH OXIA
+    ('\u{1F7A}', CharMapAction::SubChar('Ὺ')), // GREEK SMALL LETTER UPSILON WITH VARIA
+    ('\u{1F7B}', CharMapAction::SubChar('Ύ')), // GREEK SMALL LETTER UPSILON WITH OXIA
+    ('\u{1F7C}', CharMapAction::SubChar('Ὼ')), // GREEK SMALL LETTER OMEGA WITH VARIA
+    ('\u{1F7D}', CharMapAction::SubChar('Ώ')), // GREEK SMALL LETTER OMEGA WITH OXIA
+    ('\u{1F80}', CharMapAction::SubChar('ᾈ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F81}', CharMapAction::SubChar('ᾉ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F82}', CharMapAction::SubChar('ᾊ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F83}', CharMapAction::SubChar('ᾋ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F84}', CharMapAction::SubChar('ᾌ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F85}', CharMapAction::SubChar('ᾍ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F86}', CharMapAction::SubChar('ᾎ')), // GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F87}', CharMapAction::SubChar('ᾏ')), // GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F90}', CharMapAction::SubChar('ᾘ')), // GREEK SMALL LETTER ETA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F91}', CharMapAction::SubChar('ᾙ')), // GREEK SMALL LETTER ETA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F92}', CharMapAction::SubChar('ᾚ')), // GREEK SMALL LETTER ETA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F93}', CharMapAction::SubChar('ᾛ')), // GREEK SMALL LETTER ETA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F94}', CharMapAction::SubChar('ᾜ')), // GREEK SMALL LETTER ETA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F95}', CharMapAction::SubChar('ᾝ')), // GREEK SMALL LETTER ETA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F96}', CharMapAction::SubChar('ᾞ')), // GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F97}', CharMapAction::SubChar('ᾟ')), // GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA0}', CharMapAction::SubChar('ᾨ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1FA1}', CharMapAction::SubChar('ᾩ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1FA2}', CharMapAction::SubChar('ᾪ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA3}', CharMapAction::SubChar('ᾫ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA4}', CharMapAction::SubChar('ᾬ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA5}', CharMapAction::SubChar('ᾭ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA6}', CharMapAction::SubChar('ᾮ')), // GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA7}', CharMapAction::SubChar('ᾯ')), // GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FB0}', CharMapAction::SubChar('Ᾰ')), // GREEK SMALL LETTER ALPHA WITH VRACHY
+    ('\u{1FB1}', CharMapAction::SubChar('Ᾱ')), // GREEK SMALL LETTER ALPHA WITH MACRON
+    ('\u{1FB2}', CharMapAction::SubStr("Ὰ\u{0345}")), // GREEK SMALL LETTER ALPHA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FB3}', CharMapAction::SubChar('ᾼ')), // GREEK SMALL LETTER ALPHA WITH YPOGEGRAMMENI
+    ('\u{1FB4}', CharMapAction::SubStr("Ά\u{0345}")), // GREEK SMALL LETTER ALPHA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FB6}', CharMapAction::SubStr("Α\u{0342}")), // GREEK SMALL LETTER ALPHA WITH PERISPOMENI
+    ('\u{1FB7}', CharMapAction::SubStr("Α\u{0342}\u{0345}")), // GREEK SMALL LETTER ALPHA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FBE}', CharMapAction::SubChar('Ι')), // GREEK PROSGEGRAMMENI
+    ('\u{1FC2}', CharMapAction::SubStr("Ὴ\u{0345}")), // GREEK SMALL LETTER ETA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FC3}', CharMapAction::SubChar('ῌ')), // GREEK SMALL LETTER ETA WITH YPOGEGRAMMENI
+    ('\u{1FC4}', CharMapAction::SubStr("Ή\u{0345}")), // GREEK SMALL LETTER ETA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FC6}', CharMapAction::SubStr("Η\u{0342}")), // GREEK SMALL LETTER ETA WITH PERISPOMENI
+    ('\u{1FC7}', CharMapAction::SubStr("Η\u{0342}\u{0345}")), // GREEK SMALL LETTER ETA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FD0}', CharMapAction::SubChar('Ῐ')), // GREEK SMALL LETTER IOTA WITH VRACHY
+    ('\u{1FD1}', CharMapAction::SubChar('Ῑ')), // GREEK SMALL LETTER IOTA WITH MACRON
+    ('\u{1FD2}', CharMapAction::SubStr("Ι\u{0308}\u{0300}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND VARIA
+    ('\u{1FD3}', CharMapAction::SubStr("Ι\u{0308}\u{0301}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND OXIA
+    ('\u{1FD6}', CharMapAction::SubStr("Ι\u{0342}")), // GREEK SMALL LETTER IOTA WITH PERISPOMENI
+    ('\u{1FD7}', CharMapAction::SubStr("Ι\u{0308}\u{0342}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FE0}', CharMapAction::SubChar('Ῠ')), // GREEK SMALL LETTER UPSILON WITH VRACHY
+    ('\u{1FE1}', CharMapAction::SubChar('Ῡ')), // GREEK SMALL LETTER UPSILON WITH MACRON
+    ('\u{1FE2}', CharMapAction::SubStr("Υ\u{0308}\u{0300}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND VARIA
+    ('\u{1FE3}', CharMapAction::SubStr("Υ\u{0308}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND OXIA
+    ('\u{1FE4}', CharMapAction::SubStr("Ρ\u{0313}")), // GREEK SMALL LETTER RHO WITH PSILI
+    ('\u{1FE5}', CharMapAction::SubChar('Ῥ')), // GREEK SMALL LETTER RHO WITH DASIA
+    ('\u{1FE6}', CharMapAction::SubStr("Υ\u{0342}")), // GREEK SMALL LETTER UPSILON WITH PERISPOMENI
+    ('\u{1FE7}', CharMapAction::SubStr("Υ\u{0308}\u{0342}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FF2}', CharMapAction::SubStr("Ὼ\u{0345}")), // GREEK SMALL LETTER OMEGA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FF3}', CharMapAction::SubChar('ῼ')), // GREEK SMALL LETTER OMEGA WITH YPOGEGRAMMENI
+    ('\u{1FF4}', CharMapAction::SubStr("Ώ\u{0345}")), // GREEK SMALL LETTER OMEGA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FF6}', CharMapAction::SubStr("Ω\u{0342}")), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI
+    ('\u{1FF7}', CharMapAction::SubStr("Ω\u{0342}\u{0345}")), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{214E}', CharMapAction::SubChar('Ⅎ')), // TURNED SMALL F
+    ('\u{2170}', CharMapAction::SubChar('Ⅰ')), // SMALL ROMAN NUMERAL ONE
+    ('\u{2171}', CharMapAction::SubChar('Ⅱ')), // SMALL ROMAN NUMERAL TWO
+    ('\u{2172}', CharMapAction::SubChar('Ⅲ')), // SMALL ROMAN NUMERAL THREE
+    ('\u{2173}', CharMapAction::SubChar('Ⅳ')), // SMALL ROMAN NUMERAL FOUR
+    ('\u{2174}', CharMapAction::SubChar('Ⅴ')), // SMALL ROMAN NUMERAL FIVE
+    ('\u{2175}', CharMapAction::SubChar('Ⅵ')), // SMALL ROMAN NUMERAL SIX
+    ('\u{2176}', CharMapAction::SubChar('Ⅶ')), // SMALL ROMAN NUMERAL SEVEN
+    ('\u{2177}', CharMapAction::SubChar('Ⅷ')), // SMALL ROMAN NUMERAL EIGHT
+    ('\u{2178}', CharMapAction::SubChar('Ⅸ')), // SMALL ROMAN NUMERAL NINE
+    ('\u{2179}', CharMapAction::SubChar('Ⅹ')), // SMALL ROMAN NUMERAL TEN
+    ('\u{217A}', CharMapAction::SubChar('Ⅺ')), // SMALL ROMAN NUMERAL ELEVEN
+    ('\u{217B}', CharMapAction::SubChar('Ⅻ')), // SMALL ROMAN NUMERAL TWELVE
+    ('\u{217C}', CharMapAction::SubChar('Ⅼ')), // SMALL ROMAN NUMERAL FIFTY
+    ('\u{217D}', CharMapAction::SubChar('Ⅽ')), // SMALL ROMAN NUMERAL ONE HUNDRED
+    ('\u{217E}', CharMapAction::SubChar('Ⅾ')), // SMALL ROMAN NUMERAL FIVE HUNDRED
+    ('\u{217F}', CharMapAction::SubChar('Ⅿ')), // SMALL ROMAN NUMERAL ONE THOUSAND
+    ('\u{2184}', CharMapAction::SubChar('Ↄ')), // LATIN SMALL LETTER REVERSED C
+    ('\u{24D0}', CharMapAction::SubChar('Ⓐ')), // CIRCLED LATIN SMALL LETTER A
+    ('\u{24D1}', CharMapAction::SubChar('Ⓑ')), // CIRCLED LATIN SMALL LETTER B
+    ('\u{24D2}', CharMapAction::SubChar('Ⓒ')), // CIRCLED LATIN SMALL LETTER C
+    ('\u{24D3}', CharMapAction::SubChar('Ⓓ')), // CIRCLED LATIN SMALL LETTER D
+    ('\u{24D4}', CharMapAction::SubChar('Ⓔ')), // CIRCLED LATIN SMALL LETTER E
+    ('\u{24D5}', CharMapAction::SubChar('Ⓕ')), // CIRCLED LATIN SMALL LETTER F
+    ('\u{24D6}', CharMapAction::SubChar('Ⓖ')), // CIRCLED LATIN SMALL LETTER G
+    ('\u{24D7}', CharMapAction::SubChar('Ⓗ')), // CIRCLED LATIN SMALL LETTER H
+    ('\u{24D8}', CharMapAction::SubChar('Ⓘ')), // CIRCLED LATIN SMALL LETTER I
+    ('\u{24D9}', CharMapAction::SubChar('Ⓙ')), // CIRCLED LATIN SMALL LETTER J
+    ('\u{24DA}', CharMapAction::SubChar('Ⓚ')), // CIRCLED LATIN SMALL LETTER K
+    ('\u{24DB}', CharMapAction::SubChar('Ⓛ')), // CIRCLED LATIN SMALL LETTER L
+    ('\u{24DC}', CharMapAction::SubChar('Ⓜ')), // CIRCLED LATIN SMALL LETTER M
+    ('\u{24DD}', CharMapAction::SubChar('Ⓝ')), // CIRCLED LATIN SMALL LETTER N
+    ('\u{24DE}', CharMapAction::SubChar('Ⓞ')), // CIRCLED LATIN SMALL LETTER O
+    ('\u{24DF}', CharMapAction::SubChar('Ⓟ')), // CIRCLED LATIN SMALL LETTER P
+    ('\u{24E0}', CharMapAction::SubChar('Ⓠ')), // CIRCLED LATIN SMALL LETTER Q
+    ('\u{24E1}', CharMapAction::SubChar('Ⓡ')), // CIRCLED LATIN SMALL LETTER R
+    ('\u{24E2}', CharMapAction::SubChar('Ⓢ')), // CIRCLED LATIN SMALL LETTER S
+    ('\u{24E3}', CharMapAction::SubChar('Ⓣ')), // CIRCLED LATIN SMALL LETTER T
+    ('\u{24E4}', CharMapAction::SubChar('Ⓤ')), // CIRCLED LATIN SMALL LETTER U
+    ('\u{24E5}', CharMapAction::SubChar('Ⓥ')), // CIRCLED LATIN SMALL LETTER V
+    ('\u{24E6}', CharMapAction::SubChar('Ⓦ')), // CIRCLED LATIN SMALL LETTER W
+    ('\u{24E7}', CharMapAction::SubChar('Ⓧ')), // CIRCLED LATIN SMALL LETTER X
+    ('\u{24E8}', CharMapAction::SubChar('Ⓨ')), // CIRCLED LATIN SMALL LETTER Y
+    ('\u{24E9}', CharMapAction::SubChar('Ⓩ')), // CIRCLED LATIN SMALL LETTER Z
+    ('\u{2C30}', CharMapAction::SubChar('Ⰰ')), // GLAGOLITIC SMALL LETTER AZU
+    ('\u{2C31}', CharMapAction::SubChar('Ⰱ')), // GLAGOLITIC SMALL LETTER BUKY
+    ('\u{2C32}', CharMapAction::SubChar('Ⰲ')), // GLAGOLITIC SMALL LETTER VEDE
+    ('\u{2C33}', CharMapAction::SubChar('Ⰳ')), // GLAGOLITIC SMALL LETTER GLAGOLI
+    ('\u{2C34}', CharMapAction::SubChar('Ⰴ')), // GLAGOLITIC SMALL LETTER DOBRO
+    ('\u{2C35}', CharMapAction::SubChar('Ⰵ')), // GLAGOLITIC SMALL LETTER YESTU
+    ('\u{2C36}', CharMapAction::SubChar('Ⰶ')), // GLAGOLITIC SMALL LETTER ZHIVETE
+    ('\u{2C37}', CharMapAction::SubChar('Ⰷ')), // GLAGOLITIC SMALL LETTER DZELO
+    ('\u{2C38}', CharMapAction::SubChar('Ⰸ')), // GLAGOLITIC SMALL LETTER ZEMLJA
+    ('\u{2C39}', CharMapAction::SubChar('Ⰹ')), // GLAGOLITIC SMALL LETTER IZHE
+    ('\u{2C3A}', CharMapAction::SubChar('Ⰺ')), // GLAGOLITIC SMALL LETTER INITIAL IZHE
+    ('\u{2C3B}', CharMapAction::SubChar('Ⰻ')), // GLAGOLITIC SMALL LETTER I
+    ('\u{2C3C}', CharMapAction::SubChar('Ⰼ')), // GLAGOLITIC SMALL LETTER DJERVI
+    ('\u{2C3D}', CharMapAction::SubChar('Ⰽ')), // GLAGOLITIC SMALL LETTER KAKO
+    ('\u{2C3E}', CharMapAction::SubChar('Ⰾ')), // GLAGOLITIC SMALL LETTER LJUDIJE
+    ('\u{2C3F}', CharMapAction::SubChar('Ⰿ')), // GLAGOLITIC SMALL LETTER MYSLITE
+    ('\u{2C40}', CharMapAction::SubChar('Ⱀ')), // GLAGOLITIC SMALL LETTER NASHI
+    ('\u{2C41}', CharMapAction::SubChar('Ⱁ')), // GLAGOLITIC SMALL LETTER ONU
+    ('\u{2C42}', CharMapAction::SubChar('Ⱂ')), // GLAGOLITIC SMALL LETTER POKOJI
+    ('\u{2C43}', CharMapAction::SubChar('Ⱃ')), // GLAGOLITIC SMALL LETTER RITSI
+    ('\u{2C44}', CharMapAction::SubChar('Ⱄ')), // GLAGOLITIC SMALL LETTER SLOVO
+    ('\u{2C45}', CharMapAction::SubChar('Ⱅ')), // GLAGOLITIC SMALL LETTER TVRIDO
+    ('\u{2C46}', CharMapAction::SubChar('Ⱆ')), // GLAGOLITIC SMALL LETTER UKU
+    ('\u{2C47}', CharMapAction::SubChar('Ⱇ')), // GLAGOLITIC SMALL LETTER FRITU
+    ('\u{2C48}', CharMapAction::SubChar('Ⱈ')), // GLAGOLITIC SMALL LETTER HERU
+    ('\u{2C49}', CharMapAction::SubChar('Ⱉ')), // GLAGOLITIC SMALL LETTER OTU
+    ('\u{2C4A}', CharMapAction::SubChar('Ⱊ')), // GLAGOLITIC SMALL LETTER PE
+    ('\u{2C4B}', CharMapAction::SubChar('Ⱋ')), // GLAGOLITIC SMALL LETTER SHTA
+    ('\u{2C4C}', CharMapAction::SubChar('Ⱌ')), // GLAGOLITIC SMALL LETTER TSI
+    ('\u{2C4D}', CharMapAction::SubChar('Ⱍ')), // GLAGOLITIC SMALL LETTER CHRIVI
+    ('\u{2C4E}', CharMapAction::SubChar('Ⱎ')), // GLAGOLITIC SMALL LETTER SHA
+    ('\u{2C4F}', CharMapAction::SubChar('Ⱏ')), // GLAGOLITIC SMALL LETTER YERU
+    ('\u{2C50}', CharMapAction::SubChar('Ⱐ')), // GLAGOLITIC SMALL LETTER YERI
+    ('\u{2C51}', CharMapAction::SubChar('Ⱑ')), // GLAGOLITIC SMALL LETTER YATI
+    ('\u{2C52}', CharMapAction::SubChar('Ⱒ')), // GLAGOLITIC SMALL LETTER SPIDERY HA
+    ('\u{2C53}', CharMapAction::SubChar('Ⱓ')), // GLAGOLITIC SMALL LETTER YU
+    ('\u{2C54}', CharMapAction::SubChar('Ⱔ')), // GLAGOLITIC SMALL LETTER SMALL YUS
+    ('\u{2C55}', CharMapAction::SubChar('Ⱕ')), // GLAGOLITIC SMALL LETTER SMALL YUS WITH TAIL
+    ('\u{2C56}', CharMapAction::SubChar('Ⱖ')), // GLAGOLITIC SMALL LETTER YO
+    ('\u{2C57}', CharMapAction::SubChar('Ⱗ')), // GLAGOLITIC SMALL LETTER IOTATED SMALL YUS
+    ('\u{2C58}', CharMapAction::SubChar('Ⱘ')), // GLAGOLITIC SMALL LETTER BIG YUS
+    ('\u{2C59}', CharMapAction::SubChar('Ⱙ')), // GLAGOLITIC SMALL LETTER IOTATED BIG YUS
+    ('\u{2C5A}', CharMapAction::SubChar('Ⱚ')), // GLAGOLITIC SMALL LETTER FITA
+    ('\u{2C5B}', CharMapAction::SubChar('Ⱛ')), // GLAGOLITIC SMALL LETTER IZHITSA
+    ('\u{2C5C}', CharMapAction::SubChar('Ⱜ')), // GLAGOLITIC SMALL LETTER SHTAPIC
+    ('\u{2C5D}', CharMapAction::SubChar('Ⱝ')), // GLAGOLITIC SMALL LETTER TROKUTASTI A
+    ('\u{2C5E}', CharMapAction::SubChar('Ⱞ')), // GLAGOLITIC SMALL LETTER LATINATE MYSLITE
+    ('\u{2C5F}', CharMapAction::SubChar('Ⱟ')), // GLAGOLITIC SMALL LETTER CAUDATE CHRIVI
+    ('\u{2C61}', CharMapAction::SubChar('Ⱡ')), // LATIN SMALL LETTER L WITH DOUBLE BAR
+    ('\u{2C65}', CharMapAction::SubChar('Ⱥ')), // LATIN SMALL LETTER A WITH STROKE
+    ('\u{2C66}', CharMapAction::SubChar('Ⱦ')), // LATIN SMALL LETTER T WITH DIAGONAL STROKE
+    ('\u{2C68}', CharMapAction::SubChar('Ⱨ')), // LATIN SMALL LETTER H WITH DESCENDER
+    ('\u{2C6A}', CharMapAction::SubChar('Ⱪ')), // LATIN SMALL LETTER K WITH DESCENDER
+    ('\u{2C6C}', CharMapAction::SubChar('Ⱬ')), // LATIN SMALL LETTER Z WITH DESCENDER
+    ('\u{2C73}', CharMapAction::SubChar('Ⱳ')), // LATIN SMALL LETTER W WITH HOOK
+    ('\u{2C76}', CharMapAction::SubChar('Ⱶ')), // LATIN SMALL LETTER HALF H
+    ('\u{2C81}', CharMapAction::SubChar('Ⲁ')), // COPTIC SMALL LETTER ALFA
+    ('\u{2C83}', CharMapAction::SubChar('Ⲃ')), // COPTIC SMALL LETTER VIDA
+    ('\u{2C85}', CharMapAction::SubChar('Ⲅ')), // COPTIC SMALL LETTER GAMMA
+    ('\u{2C87}', CharMapAction::SubChar('Ⲇ')), // COPTIC SMALL LETTER DALDA
+    ('\u{2C89}', CharMapAction::SubChar('Ⲉ')), // COPTIC SMALL LETTER EIE
+    ('\u{2C8B}', CharMapAction::SubChar('Ⲋ')), // COPTIC SMALL LETTER SOU
+    ('\u{2C8D}', CharMapAction::SubChar('Ⲍ')), // COPTIC SMALL LETTER ZATA
+    ('\u{2C8F}', CharMapAction::SubChar('Ⲏ')), // COPTIC SMALL LETTER HATE
+    ('\u{2C91}', CharMapAction::SubChar('Ⲑ')), // COPTIC SMALL LETTER THETHE
+    ('\u{2C93}', CharMapAction::SubChar('Ⲓ')), // COPTIC SMALL LETTER IAUDA
+    ('\u{2C95}', CharMapAction::SubChar('Ⲕ')), // COPTIC SMALL LETTER KAPA
+    ('\u{2C97}', CharMapAction::SubChar('Ⲗ')), // COPTIC SMALL LETTER LAULA
+    ('\u{2C99}', CharMapAction::SubChar('Ⲙ')), // COPTIC SMALL LETTER MI
+    ('\u{2C9B}', CharMapAction::SubChar('Ⲛ')), // COPTIC SMALL LETTER NI
+    ('\u{2C9D}', CharMapAction::SubChar('Ⲝ')), // COPTIC SMALL LETTER KSI
+    ('\u{2C9F}', CharMapAction::SubChar('Ⲟ')), // COPTIC SMALL LETTER O
+    ('\u{2CA1}', CharMapAction::SubChar('Ⲡ')), // COPTIC SMALL LETTER PI
+    ('\u{2CA3}', CharMapAction::SubChar('Ⲣ')), // COPTIC SMALL LETTER RO
+    ('\u{2CA5}', CharMapAction::SubChar('Ⲥ')), // COPTIC SMALL LETTER SIMA
+    ('\u{2CA7}', CharMapAction::SubChar('Ⲧ')), // COPTIC SMALL LETTER TAU
+    ('\u{2CA9}', CharMapAction::SubChar('Ⲩ')), // COPTIC SMALL LETTER UA
+    ('\u{2CAB}', CharMapAction::SubChar('Ⲫ')), // COPTIC SMALL LETTER FI
+    ('\u{2CAD}', CharMapAction::SubChar('Ⲭ')), // COPTIC SMALL LETTER KHI
+    ('\u{2CAF}', CharMapAction::SubChar('Ⲯ')), // COPTIC SMALL LETTER PSI
+    ('\u{2CB1}', CharMapAction::SubChar('Ⲱ')), // COPTIC SMALL LETTER OOU
+    ('\u{2CB3}', CharMapAction::SubChar('Ⲳ')), // COPTIC SMALL LETTER DIALECT-P ALEF
+    ('\u{2CB5}', CharMapAction::SubChar('Ⲵ')), // COPTIC SMALL LETTER OLD COPTIC AIN
+    ('\u{2CB7}', CharMapAction::SubChar('Ⲷ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC EIE
+    ('\u{2CB9}', CharMapAction::SubChar('Ⲹ')), // COPTIC SMALL LETTER DIALECT-P KAPA
+    ('\u{2CBB}', CharMapAction::SubChar('Ⲻ')), // COPTIC SMALL LETTER DIALECT-P NI
+    ('\u{2CBD}', CharMapAction::SubChar('Ⲽ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC NI
+    ('\u{2CBF}', CharMapAction::SubChar('Ⲿ')), // COPTIC SMALL LETTER OLD COPTIC OOU
+    ('\u{2CC1}', CharMapAction::SubChar('Ⳁ')), // COPTIC SMALL LETTER SAMPI
+    ('\u{2CC3}', CharMapAction::SubChar('Ⳃ')), // COPTIC SMALL LETTER CROSSED SHEI
+    ('\u{2CC5}', CharMapAction::SubChar('Ⳅ')), // COPTIC SMALL LETTER OLD COPTIC SHEI
+    ('\u{2CC7}', CharMapAction::SubChar('Ⳇ')), // COPTIC SMALL LETTER OLD COPTIC ESH
+    ('\u{2CC9}', CharMapAction::SubChar('Ⳉ')), // COPTIC SMALL LETTER AKHMIMIC KHEI
+    ('\u{2CCB}', CharMapAction::SubChar('Ⳋ')), // COPTIC SMALL LETTER DIALECT-P HORI
+    ('\u{2CCD}', CharMapAction::SubChar('Ⳍ')), // COPTIC SMALL LETTER OLD COPTIC HORI
+    ('\u{2CCF}', CharMapAction::SubChar('Ⳏ')), // COPTIC SMALL LETTER OLD COPTIC HA
+    ('\u{2CD1}', CharMapAction::SubChar('Ⳑ')), // COPTIC SMALL LETTER L-SHAPED HA
+    ('\u{2CD3}', CharMapAction::SubChar('Ⳓ')), // COPTIC SMALL LETTER OLD COPTIC HEI
+    ('\u{2CD5}', CharMapAction::SubChar('Ⳕ')), // COPTIC SMALL LETTER OLD COPTIC HAT
+    ('\u{2CD7}', CharMapAction::SubChar('Ⳗ')), // COPTIC SMALL LETTER OLD COPTIC GANGIA
+    ('\u{2CD9}', CharMapAction::SubChar('Ⳙ')), // COPTIC SMALL LETTER OLD COPTIC DJA
+    ('\u{2CDB}', CharMapAction::SubChar('Ⳛ')), // COPTIC SMALL LETTER OLD COPTIC SHIMA
+    ('\u{2CDD}', CharMapAction::SubChar('Ⳝ')), // COPTIC SMALL LETTER OLD NUBIAN SHIMA
+    ('\u{2CDF}', CharMapAction::SubChar('Ⳟ')), // COPTIC SMALL LETTER OLD NUBIAN NGI
+    ('\u{2CE1}', CharMapAction::SubChar('Ⳡ')), // COPTIC SMALL LETTER OLD NUBIAN NYI
+    ('\u{2CE3}', CharMapAction::SubChar('Ⳣ')), // COPTIC SMALL LETTER OLD NUBIAN WAU
+    ('\u{2CEC}', CharMapAction::SubChar('Ⳬ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC SHEI
+    ('\u{2CEE}', CharMapAction::SubChar('Ⳮ')), // COPTIC SMALL LETTER CRYPTOGRAMMIC GANGIA
+    ('\u{2CF3}', CharMapAction::SubChar('Ⳳ')), // COPTIC SMALL LETTER BOHAIRIC KHEI
+    ('\u{2D00}', CharMapAction::SubChar('Ⴀ')), // GEORGIAN SMALL LETTER AN
+    ('\u{2D01}', CharMapAction::SubChar('Ⴁ')), // GEORGIAN SMALL LETTER BAN
+    ('\u{2D02}', CharMapAction::SubChar('Ⴂ')), // GEORGIAN SMALL LETTER GAN
+    ('\u{2D03}', CharMapAction::SubChar('Ⴃ')), // GEORGIAN SMALL LETTER DON
+    ('\u{2D04}', CharMapAction::SubChar('Ⴄ')), // GEORGIAN SMALL LETTER EN
+    ('\u{2D05}', CharMapAction::SubChar('Ⴅ')), // GEORGIAN SMALL LETTER VIN
+    ('\u{2D06}', CharMapAction::SubChar('Ⴆ')), // GEORGIAN SMALL LETTER ZEN
+    ('\u{2D07}', CharMapAction::SubChar('Ⴇ')), // GEORGIAN SMALL LETTER TAN
+    ('\u{2D08}', CharMapAction::SubChar('Ⴈ')), // GEORGIAN SMALL LETTER IN
+    ('\u{2D09}', CharMapAction::SubChar('Ⴉ')), // GEORGIAN SMALL LETTER KAN
+    ('\u{2D0A}', CharMapAction::SubChar('Ⴊ')), // GEORGIAN SMALL LETTER LAS
+    ('\u{2D0B}', CharMapAction::SubChar('Ⴋ')), // GEORGIAN SMALL LETTER MAN
+    ('\u{2D0C}', CharMapAction::SubChar('Ⴌ')), // GEORGIAN SMALL LETTER NAR
+    ('\u{2D0D}', CharMapAction::SubChar('Ⴍ')), // GEORGIAN SMALL LETTER ON
+    ('\u{2D0E}', CharMapAction::SubChar('Ⴎ')), // GEORGIAN SMALL LETTER PAR
+    ('\u{2D0F}', CharMapAction::SubChar('Ⴏ')), // GEORGIAN SMALL LETTER ZHAR
+    ('\u{2D10}', CharMapAction::SubChar('Ⴐ')), // GEORGIAN SMALL LETTER RAE
+    ('\u{2D11}', CharMapAction::SubChar('Ⴑ')), // GEORGIAN SMALL LETTER SAN
+    ('\u{2D12}', CharMapAction::SubChar('Ⴒ')), // GEORGIAN SMALL LETTER TAR
+    ('\u{2D13}', CharMapAction::SubChar('Ⴓ')), // GEORGIAN SMALL LETTER UN
+    ('\u{2D14}', CharMapAction::SubChar('Ⴔ')), // GEORGIAN SMALL LETTER PHAR
+    ('\u{2D15}', CharMapAction::SubChar('Ⴕ')), // GEORGIAN SMALL LETTER KHAR
+    ('\u{2D16}', CharMapAction::SubChar('Ⴖ')), // GEORGIAN SMALL LETTER GHAN
+    ('\u{2D17}', CharMapAction::SubChar('Ⴗ')), // GEORGIAN SMALL LETTER QAR
+    ('\u{2D18}', CharMapAction::SubChar('Ⴘ')), // GEORGIAN SMALL LETTER SHIN
+    ('\u{2D19}', CharMapAction::SubChar('Ⴙ')), // GEORGIAN SMALL LETTER CHIN
+    ('\u{2D1A}', CharMapAction::SubChar('Ⴚ')), // GEORGIAN SMALL LETTER CAN
+    ('\u{2D1B}', CharMapAction::SubChar('Ⴛ')), // GEORGIAN SMALL LETTER JIL
+    ('\u{2D1C}', CharMapAction::SubChar('Ⴜ')), // GEORGIAN SMALL LETTER CIL
+    ('\u{2D1D}', CharMapAction::SubChar('Ⴝ')), // GEORGIAN SMALL LETTER CHAR
+    ('\u{2D1E}', CharMapAction::SubChar('Ⴞ')), // GEORGIAN SMALL LETTER XAN
+    ('\u{2D1F}', CharMapAction::SubChar('Ⴟ')), // GEORGIAN SMALL LETTER JHAN
+    ('\u{2D20}', CharMapAction::SubChar('Ⴠ')), // GEORGIAN SMALL LETTER HAE
+    ('\u{2D21}', CharMapAction::SubChar('Ⴡ')), // GEORGIAN SMALL LETTER HE
+    ('\u{2D22}', CharMapAction::SubChar('Ⴢ')), // GEORGIAN SMALL LETTER HIE
+    ('\u{2D23}', CharMapAction::SubChar('Ⴣ')), // GEORGIAN SMALL LETTER WE
+    ('\u{2D24}', CharMapAction::SubChar('Ⴤ')), // GEORGIAN SMALL LETTER HAR
+    ('\u{2D25}', CharMapAction::SubChar('Ⴥ')), // GEORGIAN SMALL LETTER HOE
+    ('\u{2D27}', CharMapAction::SubChar('Ⴧ')), // GEORGIAN SMALL LETTER YN
+    ('\u{2D2D}', CharMapAction::SubChar('Ⴭ')), // GEORGIAN SMALL LETTER AEN
+    ('\u{A641}', CharMapAction::SubChar('Ꙁ')), // CYRILLIC SMALL LETTER ZEMLYA
+    ('\u{A643}', CharMapAction::SubChar('Ꙃ')), // CYRILLIC SMALL LETTER DZELO
+    ('\u{A645}', CharMapAction::SubChar('Ꙅ')), // CYRILLIC SMALL LETTER REVERSED DZE
+    ('\u{A647}', CharMapAction::SubChar('Ꙇ')), // CYRILLIC SMALL LETTER IOTA
+    ('\u{A649}', CharMapAction::SubChar('Ꙉ')), // CYRILLIC SMALL LETTER DJERV
+    ('\u{A64B}', CharMapAction::SubChar('Ꙋ')), // CYRILLIC SMALL LETTER MONOGRAPH UK
+    ('\u{A64D}', CharMapAction::SubChar('Ꙍ')), // CYRILLIC SMALL LETTER BROAD OMEGA
+    ('\u{A64F}', CharMapAction::SubChar('Ꙏ')), // CYRILLIC SMALL LETTER NEUTRAL YER
+    ('\u{A651}', CharMapAction::SubChar('Ꙑ')), // CYRILLIC SMALL LETTER YERU WITH BACK YER
+    ('\u{A653}', CharMapAction::SubChar('Ꙓ')), // CYRILLIC SMALL LETTER IOTIFIED YAT
+    ('\u{A655}', CharMapAction::SubChar('Ꙕ')), // CYRILLIC SMALL LETTER REVERSED YU
+    ('\u{A657}', CharMapAction::SubChar('Ꙗ')), // CYRILLIC SMALL LETTER IOTIFIED A
+    ('\u{A659}', CharMapAction::SubChar('Ꙙ')), // CYRILLIC SMALL LETTER CLOSED LITTLE YUS
+    ('\u{A65B}', CharMapAction::SubChar('Ꙛ')), // CYRILLIC SMALL LETTER BLENDED YUS
+    ('\u{A65D}', CharMapAction::SubChar('Ꙝ')), // CYRILLIC SMALL LETTER IOTIFIED CLOSED LITTLE YUS
+    ('\u{A65F}', CharMapAction::SubChar('Ꙟ')), // CYRILLIC SMALL LETTER YN
+    ('\u{A661}', CharMapAction::SubChar('Ꙡ')), // CYRILLIC SMALL LETTER REVERSED TSE
+    ('\u{A663}', CharMapAction::SubChar('Ꙣ')), // CYRILLIC SMALL LETTER SOFT DE
+    ('\u{A665}', CharMapAction::SubChar('Ꙥ')), // CYRILLIC SMALL LETTER SOFT EL
+    ('\u{A667}', CharMapAction::SubChar('Ꙧ')), // CYRILLIC SMALL LETTER SOFT EM
+    ('\u{A669}', CharMapAction::SubChar('Ꙩ')), // CYRILLIC SMALL LETTER MONOCULAR O
+    ('\u{A66B}', CharMapAction::SubChar('Ꙫ')), // CYRILLIC SMALL LETTER BINOCULAR O
+    ('\u{A66D}', CharMapAction::SubChar('Ꙭ')), // CYRILLIC SMALL LETTER DOUBLE MONOCULAR O
+    ('\u{A681}', CharMapAction::SubChar('Ꚁ')), // CYRILLIC SMALL LETTER DWE
+    ('\u{A683}', CharMapAction::SubChar('Ꚃ')), // CYRILLIC SMALL LETTER DZWE
+    ('\u{A685}', CharMapAction::SubChar('Ꚅ')), // CYRILLIC SMALL LETTER ZHWE
+    ('\u{A687}', CharMapAction::SubChar('Ꚇ')), // CYRILLIC SMALL LETTER CCHE
+    ('\u{A689}', CharMapAction::SubChar('Ꚉ')), // CYRILLIC SMALL LETTER DZZE
+    ('\u{A68B}', CharMapAction::SubChar('Ꚋ')), // CYRILLIC SMALL LETTER TE WITH MIDDLE HOOK
+    ('\u{A68D}', CharMapAction::SubChar('Ꚍ')), // CYRILLIC SMALL LETTER TWE
+    ('\u{A68F}', CharMapAction::SubChar('Ꚏ')), // CYRILLIC SMALL LETTER TSWE
+    ('\u{A691}', CharMapAction::SubChar('Ꚑ')), // CYRILLIC SMALL LETTER TSSE
+    ('\u{A693}', CharMapAction::SubChar('Ꚓ')), // CYRILLIC SMALL LETTER TCHE
+    ('\u{A695}', CharMapAction::SubChar('Ꚕ')), // CYRILLIC SMALL LETTER HWE
+    ('\u{A697}', CharMapAction::SubChar('Ꚗ')), // CYRILLIC SMALL LETTER SHWE
+    ('\u{A699}', CharMapAction::SubChar('Ꚙ')), // CYRILLIC SMALL LETTER DOUBLE O
+    ('\u{A69B}', CharMapAction::SubChar('Ꚛ')), // CYRILLIC SMALL LETTER CROSSED O
+    ('\u{A723}', CharMapAction::SubChar('Ꜣ')), // LATIN SMALL LETTER EGYPTOLOGICAL ALEF
+    ('\u{A725}', CharMapAction::SubChar('Ꜥ')), // LATIN SMALL LETTER EGYPTOLOGICAL AIN
+    ('\u{A727}', CharMapAction::SubChar('Ꜧ')), // LATIN SMALL LETTER HENG
+    ('\u{A729}', CharMapAction::SubChar('Ꜩ')), // LATIN SMALL LETTER TZ
+    ('\u{A72B}', CharMapAction::SubChar('Ꜫ')), // LATIN SMALL LETTER TRESILLO
+    ('\u{A72D}', CharMapAction::SubChar('Ꜭ')), // LATIN SMALL LETTER CUATRILLO
+    ('\u{A72F}', CharMapAction::SubChar('Ꜯ')), // LATIN SMALL LETTER CUATRILLO WITH COMMA
+    ('\u{A733}', CharMapAction::SubChar('Ꜳ')), // LATIN SMALL LETTER AA
+    ('\u{A735}', CharMapAction::SubChar('Ꜵ')), // LATIN SMALL LETTER AO
+    ('\u{A737}', CharMapAction::SubChar('Ꜷ')), // LATIN SMALL LETTER AU
+    ('\u{A739}', CharMapAction::SubChar('Ꜹ')), // LATIN SMALL LETTER AV
+    ('\u{A73B}', CharMapAction::SubChar('Ꜻ')), // LATIN SMALL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73D}', CharMapAction::SubChar('Ꜽ')), // LATIN SMALL LETTER AY
+    ('\u{A73F}', CharMapAction::SubChar('Ꜿ')), // LATIN SMALL LETTER REVERSED C WITH DOT
+    ('\u{A741}', CharMapAction::SubChar('Ꝁ')), // LATIN SMALL LETTER K WITH STROKE
+    ('\u{A743}', CharMapAction::SubChar('Ꝃ')), // LATIN SMALL LETTER K WITH DIAGONAL STROKE
+    ('\u{A745}', CharMapAction::SubChar('Ꝅ')), // LATIN SMALL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A747}', CharMapAction::SubChar('Ꝇ')), // LATIN SMALL LETTER BROKEN L
+    ('\u{A749}', CharMapAction::SubChar('Ꝉ')), // LATIN SMALL LETTER L WITH HIGH STROKE
+    ('\u{A74B}', CharMapAction::SubChar('Ꝋ')), // LATIN SMALL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74D}', CharMapAction::SubChar('Ꝍ')), // LATIN SMALL LETTER O WITH LOOP
+    ('\u{A74F}', CharMapAction::SubChar('Ꝏ')), // LATIN SMALL LETTER OO
+    ('\u{A751}', CharMapAction::SubChar('Ꝑ')), // LATIN SMALL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A753}', CharMapAction::SubChar('Ꝓ')), // LATIN SMALL LETTER P WITH FLOURISH
+    ('\u{A755}', CharMapAction::SubChar('Ꝕ')), // LATIN SMALL LETTER P WITH SQUIRREL TAIL
+    ('\u{A757}', CharMapAction::SubChar('Ꝗ')), // LATIN SMALL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A759}', CharMapAction::SubChar('Ꝙ')), // LATIN SMALL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A75B}', CharMapAction::SubChar('Ꝛ')), // LATIN SMALL LETTER R ROTUNDA
+    ('\u{A75D}', CharMapAction::SubChar('Ꝝ')), // LATIN SMALL LETTER RUM ROTUNDA
+    ('\u{A75F}', CharMapAction::SubChar('Ꝟ')), // LATIN SMALL LETTER V WITH DIAGONAL STROKE
+    ('\u{A761}', CharMapAction::SubChar('Ꝡ')), // LATIN SMALL LETTER VY
+    ('\u{A763}', CharMapAction::SubChar('Ꝣ')), // LATIN SMALL LETTER VISIGOTHIC Z
+    ('\u{A765}', CharMapAction::SubChar('Ꝥ')), // LATIN SMALL LETTER THORN WITH STROKE
+    ('\u{A767}', CharMapAction::SubChar('Ꝧ')), // LATIN SMALL LETTER THORN WITH STROKE THROUGH DESCENDER
+    ('\u{A769}', CharMapAction::SubChar('Ꝩ')), // LATIN SMALL LETTER VEND
+    ('\u{A76B}', CharMapAction::SubChar('Ꝫ')), // LATIN SMALL LETTER ET
+    ('\u{A76D}', CharMapAction::SubChar('Ꝭ')), // LATIN SMALL LETTER IS
+    ('\u{A76F}', CharMapAction::SubChar('Ꝯ')), // LATIN SMALL LETTER CON
+    ('\u{A77A}', CharMapAction::SubChar('Ꝺ')), // LATIN SMALL LETTER INSULAR D
+    ('\u{A77C}', CharMapAction::SubChar('Ꝼ')), // LATIN SMALL LETTER INSULAR F
+    ('\u{A77F}', CharMapAction::SubChar('Ꝿ')), // LATIN SMALL LETTER TURNED INSULAR G
+    ('\u{A781}', CharMapAction::SubChar('Ꞁ')), // LATIN SMALL LETTER TURNED L
+    ('\u{A783}', CharMapAction::SubChar('Ꞃ')), // LATIN SMALL LETTER INSULAR R
+    ('\u{A785}', CharMapAction::SubChar('Ꞅ')), // LATIN SMALL LETTER INSULAR S
+    ('\u{A787}', CharMapAction::SubChar('Ꞇ')), // LATIN SMALL LETTER INSULAR T
+    ('\u{A78C}', CharMapAction::SubChar('Ꞌ')), // LATIN SMALL LETTER SALTILLO
+    ('\u{A791}', CharMapAction::SubChar('Ꞑ')), // LATIN SMALL LETTER N WITH DESCENDER
+    ('\u{A793}', CharMapAction::SubChar('Ꞓ')), // LATIN SMALL LETTER C WITH BAR
+    ('\u{A794}', CharMapAction::SubChar('Ꞔ')), // LATIN SMALL LETTER C WITH PALATAL HOOK
+    ('\u{A797}', CharMapAction::SubChar('Ꞗ')), // LATIN SMALL LETTER B WITH FLOURISH
+    ('\u{A799}', CharMapAction::SubChar('Ꞙ')), // LATIN SMALL LETTER F WITH STROKE
+    ('\u{A79B}', CharMapAction::SubChar('Ꞛ')), // LATIN SMALL LETTER VOLAPUK AE
+    ('\u{A79D}', CharMapAction::SubChar('Ꞝ')), // LATIN SMALL LETTER VOLAPUK OE
+    ('\u{A79F}', CharMapAction::SubChar('Ꞟ')), // LATIN SMALL LETTER VOLAPUK UE
+    ('\u{A7A1}', CharMapAction::SubChar('Ꞡ')), // LATIN SMALL LETTER G WITH OBLIQUE STROKE
+    ('\u{A7A3}', CharMapAction::SubChar('Ꞣ')), // LATIN SMALL LETTER K WITH OBLIQUE STROKE
+    ('\u{A7A5}', CharMapAction::SubChar('Ꞥ')), // LATIN SMALL LETTER N WITH OBLIQUE STROKE
+    ('\u{A7A7}', CharMapAction::SubChar('Ꞧ')), // LATIN SMALL LETTER R WITH OBLIQUE STROKE
+    ('\u{A7A9}', CharMapAction::SubChar('Ꞩ')), // LATIN SMALL LETTER S WITH OBLIQUE STROKE
+    ('\u{A7B5}', CharMapAction::SubChar('Ꞵ')), // LATIN SMALL LETTER BETA
+    ('\u{A7B7}', CharMapAction::SubChar('Ꞷ')), // LATIN SMALL LETTER OMEGA
+    ('\u{A7B9}', CharMapAction::SubChar('Ꞹ')), // LATIN SMALL LETTER U WITH STROKE
+    ('\u{A7BB}', CharMapAction::SubChar('Ꞻ')), // LATIN SMALL LETTER GLOTTAL A
+    ('\u{A7BD}', CharMapAction::SubChar('Ꞽ')), // LATIN SMALL LETTER GLOTTAL I
+    ('\u{A7BF}', CharMapAction::SubChar('Ꞿ')), // LATIN SMALL LETTER GLOTTAL U
+    ('\u{A7C1}', CharMapAction::SubChar('Ꟁ')), // LATIN SMALL LETTER OLD POLISH O
+    ('\u{A7C3}', CharMapAction::SubChar('Ꟃ')), // LATIN SMALL LETTER ANGLICANA W
+    ('\u{A7C8}', CharMapAction::SubChar('Ꟈ')), // LATIN SMALL LETTER D WITH SHORT STROKE OVERLAY
+    ('\u{A7CA}', CharMapAction::SubChar('Ꟊ')), // LATIN SMALL LETTER S WITH SHORT STROKE OVERLAY
+    ('\u{A7D1}', CharMapAction::SubChar('Ꟑ')), // LATIN SMALL LETTER CLOSED INSULAR G
+    ('\u{A7D7}', CharMapAction::SubChar('Ꟗ')), // LATIN SMALL LETTER MIDDLE SCOTS S
+    ('\u{A7D9}', CharMapAction::SubChar('Ꟙ')), // LATIN SMALL LETTER SIGMOID S
+    ('\u{A7F6}', CharMapAction::SubChar('Ꟶ')), // LATIN SMALL LETTER REVERSED HALF H
+    ('\u{AB53}', CharMapAction::SubChar('Ꭓ')), // LATIN SMALL LETTER CHI
+    ('\u{AB70}', CharMapAction::SubChar('Ꭰ')), // CHEROKEE SMALL LETTER A
+    ('\u{AB71}', CharMapAction::SubChar('Ꭱ')), // CHEROKEE SMALL LETTER E
+    ('\u{AB72}', CharMapAction::SubChar('Ꭲ')), // CHEROKEE SMALL LETTER I
+    ('\u{AB73}', CharMapAction::SubChar('Ꭳ')), // CHEROKEE SMALL LETTER O
+    ('\u{AB74}', CharMapAction::SubChar('Ꭴ')), // CHEROKEE SMALL LETTER U
+    ('\u{AB75}', CharMapAction::SubChar('Ꭵ')), // CHEROKEE SMALL LETTER V
+    ('\u{AB76}', CharMapAction::SubChar('Ꭶ')), // CHEROKEE SMALL LETTER GA
+    ('\u{AB77}', CharMapAction::SubChar('Ꭷ')), // CHEROKEE SMALL LETTER KA
+    ('\u{AB78}', CharMapAction::SubChar('Ꭸ')), // CHEROKEE SMALL LETTER GE
+    ('\u{AB79}', CharMapAction::SubChar('Ꭹ')), // CHEROKEE SMALL LETTER GI
+    ('\u{AB7A}', CharMapAction::SubChar('Ꭺ')), // CHEROKEE SMALL LETTER GO
+    ('\u{AB7B}', CharMapAction::SubChar('Ꭻ')), // CHEROKEE SMALL LETTER GU
+    ('\u{AB7C}', CharMapAction::SubChar('Ꭼ')), // CHEROKEE SMALL LETTER GV
+    ('\u{AB7D}', CharMapAction::SubChar('Ꭽ')), // CHEROKEE SMALL LETTER HA
+    ('\u{AB7E}', CharMapAction::SubChar('Ꭾ')), // CHEROKEE SMALL LETTER HE
+    ('\u{AB7F}', CharMapAction::SubChar('Ꭿ')), // CHEROKEE SMALL LETTER HI
+    ('\u{AB80}', CharMapAction::SubChar('Ꮀ')), // CHEROKEE SMALL LETTER HO
+    ('\u{AB81}', CharMapAction::SubChar('Ꮁ')), // CHEROKEE SMALL LETTER HU
+    ('\u{AB82}', CharMapAction::SubChar('Ꮂ')), // CHEROKEE SMALL LETTER HV
+    ('\u{AB83}', CharMapAction::SubChar('Ꮃ')), // CHEROKEE SMALL LETTER LA
+    ('\u{AB84}', CharMapAction::SubChar('Ꮄ')), // CHEROKEE SMALL LETTER LE
+    ('\u{AB85}', CharMapAction::SubChar('Ꮅ')), // CHEROKEE SMALL LETTER LI
+    ('\u{AB86}', CharMapAction::SubChar('Ꮆ')), // CHEROKEE SMALL LETTER LO
+    ('\u{AB87}', CharMapAction::SubChar('Ꮇ')), // CHEROKEE SMALL LETTER LU
+    ('\u{AB88}', CharMapAction::SubChar('Ꮈ')), // CHEROKEE SMALL LETTER LV
+    ('\u{AB89}', CharMapAction::SubChar('Ꮉ')), // CHEROKEE SMALL LETTER MA
+    ('\u{AB8A}', CharMapAction::SubChar('Ꮊ')), // CHEROKEE SMALL LETTER ME
+    ('\u{AB8B}', CharMapAction::SubChar('Ꮋ')), // CHEROKEE SMALL LETTER MI
+    ('\u{AB8C}', CharMapAction::SubChar('Ꮌ')), // CHEROKEE SMALL LETTER MO
+    ('\u{AB8D}', CharMapAction::SubChar('Ꮍ')), // CHEROKEE SMALL LETTER MU
+    ('\u{AB8E}', CharMapAction::SubChar('Ꮎ')), // CHEROKEE SMALL LETTER NA
+    ('\u{AB8F}', CharMapAction::SubChar('Ꮏ')), // CHEROKEE SMALL LETTER HNA
+    ('\u{AB90}', CharMapAction::SubChar('Ꮐ')), // CHEROKEE SMALL LETTER NAH
+    ('\u{AB91}', CharMapAction::SubChar('Ꮑ')), // CHEROKEE SMALL LETTER NE
+    ('\u{AB92}', CharMapAction::SubChar('Ꮒ')), // CHEROKEE SMALL LETTER NI
+    ('\u{AB93}', CharMapAction::SubChar('Ꮓ')), // CHEROKEE SMALL LETTER NO
+    ('\u{AB94}', CharMapAction::SubChar('Ꮔ')), // CHEROKEE SMALL LETTER NU
+    ('\u{AB95}', CharMapAction::SubChar('Ꮕ')), // CHEROKEE SMALL LETTER NV
+    ('\u{AB96}', CharMapAction::SubChar('Ꮖ')), // CHEROKEE SMALL LETTER QUA
+    ('\u{AB97}', CharMapAction::SubChar('Ꮗ')), // CHEROKEE SMALL LETTER QUE
+    ('\u{AB98}', CharMapAction::SubChar('Ꮘ')), // CHEROKEE SMALL LETTER QUI
+    ('\u{AB99}', CharMapAction::SubChar('Ꮙ')), // CHEROKEE SMALL LETTER QUO
+    ('\u{AB9A}', CharMapAction::SubChar('Ꮚ')), // CHEROKEE SMALL LETTER QUU
+    ('\u{AB9B}', CharMapAction::SubChar('Ꮛ')), // CHEROKEE SMALL LETTER QUV
+    ('\u{AB9C}', CharMapAction::SubChar('Ꮜ')), // CHEROKEE SMALL LETTER SA
+    ('\u{AB9D}', CharMapAction::SubChar('Ꮝ')), // CHEROKEE SMALL LETTER S
+    ('\u{AB9E}', CharMapAction::SubChar('Ꮞ')), // CHEROKEE SMALL LETTER SE
+    ('\u{AB9F}', CharMapAction::SubChar('Ꮟ')), // CHEROKEE SMALL LETTER SI
+    ('\u{ABA0}', CharMapAction::SubChar('Ꮠ')), // CHEROKEE SMALL LETTER SO
+    ('\u{ABA1}', CharMapAction::SubChar('Ꮡ')), // CHEROKEE SMALL LETTER SU
+    ('\u{ABA2}', CharMapAction::SubChar('Ꮢ')), // CHEROKEE SMALL LETTER SV
+    ('\u{ABA3}', CharMapAction::SubChar('Ꮣ')), // CHEROKEE SMALL LETTER DA
+    ('\u{ABA4}', CharMapAction::SubChar('Ꮤ')), // CHEROKEE SMALL LETTER TA
+    ('\u{ABA5}', CharMapAction::SubChar('Ꮥ')), // CHEROKEE SMALL LETTER DE
+    ('\u{ABA6}', CharMapAction::SubChar('Ꮦ')), // CHEROKEE SMALL LETTER TE
+    ('\u{ABA7}', CharMapAction::SubChar('Ꮧ')), // CHEROKEE SMALL LETTER DI
+    ('\u{ABA8}', CharMapAction::SubChar('Ꮨ')), // CHEROKEE SMALL LETTER TI
+    ('\u{ABA9}', CharMapAction::SubChar('Ꮩ')), // CHEROKEE SMALL LETTER DO
+    ('\u{ABAA}', CharMapAction::SubChar('Ꮪ')), // CHEROKEE SMALL LETTER DU
+    ('\u{ABAB}', CharMapAction::SubChar('Ꮫ')), // CHEROKEE SMALL LETTER DV
+    ('\u{ABAC}', CharMapAction::SubChar('Ꮬ')), // CHEROKEE SMALL LETTER DLA
+    ('\u{ABAD}', CharMapAction::SubChar('Ꮭ')), // CHEROKEE SMALL LETTER TLA
+    ('\u{ABAE}', CharMapAction::SubChar('Ꮮ')), // CHEROKEE SMALL LETTER TLE
+    ('\u{ABAF}', CharMapAction::SubChar('Ꮯ')), // CHEROKEE SMALL LETTER TLI
+    ('\u{ABB0}', CharMapAction::SubChar('Ꮰ')), // CHEROKEE SMALL LETTER TLO
+    ('\u{ABB1}', CharMapAction::SubChar('Ꮱ')), // CHEROKEE SMALL LETTER TLU
+    ('\u{ABB2}', CharMapAction::SubChar('Ꮲ')), // CHEROKEE SMALL LETTER TLV
+    ('\u{ABB3}', CharMapAction::SubChar('Ꮳ')), // CHEROKEE SMALL LETTER TSA
+    ('\u{ABB4}', CharMapAction::SubChar('Ꮴ')), // CHEROKEE SMALL LETTER TSE
+    ('\u{ABB5}', CharMapAction::SubChar('Ꮵ')), // CHEROKEE SMALL LETTER TSI
+    ('\u{ABB6}', CharMapAction::SubChar('Ꮶ')), // CHEROKEE SMALL LETTER TSO
+    ('\u{ABB7}', CharMapAction::SubChar('Ꮷ')), // CHEROKEE SMALL LETTER TSU
+    ('\u{ABB8}', CharMapAction::SubChar('Ꮸ')), // CHEROKEE SMALL LETTER TSV
+    ('\u{ABB9}', CharMapAction::SubChar('Ꮹ')), // CHEROKEE SMALL LETTER WA
+    ('\u{ABBA}', CharMapAction::SubChar('Ꮺ')), // CHEROKEE SMALL LETTER WE
+    ('\u{ABBB}', CharMapAction::SubChar('Ꮻ')), // CHEROKEE SMALL LETTER WI
+    ('\u{ABBC}', CharMapAction::SubChar('Ꮼ')), // CHEROKEE SMALL LETTER WO
+    ('\u{ABBD}', CharMapAction::SubChar('Ꮽ')), // CHEROKEE SMALL LETTER WU
+    ('\u{ABBE}', CharMapAction::SubChar('Ꮾ')), // CHEROKEE SMALL LETTER WV
+    ('\u{ABBF}', CharMapAction::SubChar('Ꮿ')), // CHEROKEE SMALL LETTER YA
+    ('\u{FB00}', CharMapAction::SubStr("Ff")), // LATIN SMALL LIGATURE FF
+    ('\u{FB01}', CharMapAction::SubStr("Fi")), // LATIN SMALL LIGATURE FI
+    ('\u{FB02}', CharMapAction::SubStr("Fl")), // LATIN SMALL LIGATURE FL
+    ('\u{FB03}', CharMapAction::SubStr("Ffi")), // LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', CharMapAction::SubStr("Ffl")), // LATIN SMALL LIGATURE FFL
+    ('\u{FB05}', CharMapAction::SubStr("St")), // LATIN SMALL LIGATURE LONG S T
+    ('\u{FB06}', CharMapAction::SubStr("St")), // LATIN SMALL LIGATURE ST
+    ('\u{FB13}', CharMapAction::SubStr("Մն")), // ARMENIAN SMALL LIGATURE MEN NOW
+    ('\u{FB14}', CharMapAction::SubStr("Մե")), // ARMENIAN SMALL LIGATURE MEN ECH
+    ('\u{FB15}', CharMapAction::SubStr("Մի")), // ARMENIAN SMALL LIGATURE MEN INI
+    ('\u{FB16}', CharMapAction::SubStr("Վն")), // ARMENIAN SMALL LIGATURE VEW NOW
+    ('\u{FB17}', CharMapAction::SubStr("Մխ")), // ARMENIAN SMALL LIGATURE MEN XEH
+    ('\u{FF41}', CharMapAction::SubChar('Ａ')), // FULLWIDTH LATIN SMALL LETTER A
+    ('\u{FF42}', CharMapAction::SubChar('Ｂ')), // FULLWIDTH LATIN SMALL LETTER B
+    ('\u{FF43}', CharMapAction::SubChar('Ｃ')), // FULLWIDTH LATIN SMALL LETTER C
+    ('\u{FF44}', CharMapAction::SubChar('Ｄ')), // FULLWIDTH LATIN SMALL LETTER D
+    ('\u{FF45}', CharMapAction::SubChar('Ｅ')), // FULLWIDTH LATIN SMALL LETTER E
+    ('\u{FF46}', CharMapAction::SubChar('Ｆ')), // FULLWIDTH LATIN SMALL LETTER F
+    ('\u{FF47}', CharMapAction::SubChar('Ｇ')), // FULLWIDTH LATIN SMALL LETTER G
+    ('\u{FF48}', CharMapAction::SubChar('Ｈ')), // FULLWIDTH LATIN SMALL LETTER H
+    ('\u{FF49}', CharMapAction::SubChar('Ｉ')), // FULLWIDTH LATIN SMALL LETTER I
+    ('\u{FF4A}', CharMapAction::SubChar('Ｊ')), // FULLWIDTH LATIN SMALL LETTER J
+    ('\u{FF4B}', CharMapAction::SubChar('Ｋ')), // FULLWIDTH LATIN SMALL LETTER K
+    ('\u{FF4C}', CharMapAction::SubChar('Ｌ')), // FULLWIDTH LATIN SMALL LETTER L
+    ('\u{FF4D}', CharMapAction::SubChar('Ｍ')), // FULLWIDTH LATIN SMALL LETTER M
+    ('\u{FF4E}', CharMapAction::SubChar('Ｎ')), // FULLWIDTH LATIN SMALL LETTER N
+    ('\u{FF4F}', CharMapAction::SubChar('Ｏ')), // FULLWIDTH LATIN SMALL LETTER O
+    ('\u{FF50}', CharMapAction::SubChar('Ｐ')), // FULLWIDTH LATIN SMALL LETTER P
+    ('\u{FF51}', CharMapAction::SubChar('Ｑ')), // FULLWIDTH LATIN SMALL LETTER Q
+    ('\u{FF52}', CharMapAction::SubChar('Ｒ')), // FULLWIDTH LATIN SMALL LETTER R
+    ('\u{FF53}', CharMapAction::SubChar('Ｓ')), // FULLWIDTH LATIN SMALL LETTER S
+    ('\u{FF54}', CharMapAction::SubChar('Ｔ')), // FULLWIDTH LATIN SMALL LETTER T
+    ('\u{FF55}', CharMapAction::SubChar('Ｕ')), // FULLWIDTH LATIN SMALL LETTER U
+    ('\u{FF56}', CharMapAction::SubChar('Ｖ')), // FULLWIDTH LATIN SMALL LETTER V
+    ('\u{FF57}', CharMapAction::SubChar('Ｗ')), // FULLWIDTH LATIN SMALL LETTER W
+    ('\u{FF58}', CharMapAction::SubChar('Ｘ')), // FULLWIDTH LATIN SMALL LETTER X
+    ('\u{FF59}', CharMapAction::SubChar('Ｙ')), // FULLWIDTH LATIN SMALL LETTER Y
+    ('\u{FF5A}', CharMapAction::SubChar('Ｚ')), // FULLWIDTH LATIN SMALL LETTER Z
+    ('\u{10428}', CharMapAction::SubChar('𐐀')), // DESERET SMALL LETTER LONG I
+    ('\u{10429}', CharMapAction::SubChar('𐐁')), // DESERET SMALL LETTER LONG E
+    ('\u{1042A}', CharMapAction::SubChar('𐐂')), // DESERET SMALL LETTER LONG A
+    ('\u{1042B}', CharMapAction::SubChar('𐐃')), // DESERET SMALL LETTER LONG AH
+    ('\u{1042C}', CharMapAction::SubChar('𐐄')), // DESERET SMALL LETTER LONG O
+    ('\u{1042D}', CharMapAction::SubChar('𐐅')), // DESERET SMALL LETTER LONG OO
+    ('\u{1042E}', CharMapAction::SubChar('𐐆')), // DESERET SMALL LETTER SHORT I
+    ('\u{1042F}', CharMapAction::SubChar('𐐇')), // DESERET SMALL LETTER SHORT E
+    ('\u{10430}', CharMapAction::SubChar('𐐈')), // DESERET SMALL LETTER SHORT A
+    ('\u{10431}', CharMapAction::SubChar('𐐉')), // DESERET SMALL LETTER SHORT AH
+    ('\u{10432}', CharMapAction::SubChar('𐐊')), // DESERET SMALL LETTER SHORT O
+    ('\u{10433}', CharMapAction::SubChar('𐐋')), // DESERET SMALL LETTER SHORT OO
+    ('\u{10434}', CharMapAction::SubChar('𐐌')), // DESERET SMALL LETTER AY
+    ('\u{10435}', CharMapAction::SubChar('𐐍')), // DESERET SMALL LETTER OW
+    ('\u{10436}', CharMapAction::SubChar('𐐎')), // DESERET SMALL LETTER WU
+    ('\u{10437}', CharMapAction::SubChar('𐐏')), // DESERET SMALL LETTER YEE
+    ('\u{10438}', CharMapAction::SubChar('𐐐')), // DESERET SMALL LETTER H
+    ('\u{10439}', CharMapAction::SubChar('𐐑')), // DESERET SMALL LETTER PEE
+    ('\u{1043A}', CharMapAction::SubChar('𐐒')), // DESERET SMALL LETTER BEE
+    ('\u{1043B}', CharMapAction::SubChar('𐐓')), // DESERET SMALL LETTER TEE
+    ('\u{1043C}', CharMapAction::SubChar('𐐔')), // DESERET SMALL LETTER DEE
+    ('\u{1043D}', CharMapAction::SubChar('𐐕')), // DESERET SMALL LETTER CHEE
+    ('\u{1043E}', CharMapAction::SubChar('𐐖')), // DESERET SMALL LETTER JEE
+    ('\u{1043F}', CharMapAction::SubChar('𐐗')), // DESERET SMALL LETTER KAY
+    ('\u{10440}', CharMapAction::SubChar('𐐘')), // DESERET SMALL LETTER GAY
+    ('\u{10441}', CharMapAction::SubChar('𐐙')), // DESERET SMALL LETTER EF
+    ('\u{10442}', CharMapAction::SubChar('𐐚')), // DESERET SMALL LETTER VEE
+    ('\u{10443}', CharMapAction::SubChar('𐐛')), // DESERET SMALL LETTER ETH
+    ('\u{10444}', CharMapAction::SubChar('𐐜')), // DESERET SMALL LETTER THEE
+    ('\u{10445}', CharMapAction::SubChar('𐐝')), // DESERET SMALL LETTER ES
+    ('\u{10446}', CharMapAction::SubChar('𐐞')), // DESERET SMALL LETTER ZEE
+    ('\u{10447}', CharMapAction::SubChar('𐐟')), // DESERET SMALL LETTER ESH
+    ('\u{10448}', CharMapAction::SubChar('𐐠')), // DESERET SMALL LETTER ZHEE
+    ('\u{10449}', CharMapAction::SubChar('𐐡')), // DESERET SMALL LETTER ER
+    ('\u{1044A}', CharMapAction::SubChar('𐐢')), // DESERET SMALL LETTER EL
+    ('\u{1044B}', CharMapAction::SubChar('𐐣')), // DESERET SMALL LETTER EM
+    ('\u{1044C}', CharMapAction::SubChar('𐐤')), // DESERET SMALL LETTER EN
+    ('\u{1044D}', CharMapAction::SubChar('𐐥')), // DESERET SMALL LETTER ENG
+    ('\u{1044E}', CharMapAction::SubChar('𐐦')), // DESERET SMALL LETTER OI
+    ('\u{1044F}', CharMapAction::SubChar('𐐧')), // DESERET SMALL LETTER EW
+    ('\u{104D8}', CharMapAction::SubChar('𐒰')), // OSAGE SMALL LETTER A
+    ('\u{104D9}', CharMapAction::SubChar('𐒱')), // OSAGE SMALL LETTER AI
+    ('\u{104DA}', CharMapAction::SubChar('𐒲')), // OSAGE SMALL LETTER AIN
+    ('\u{104DB}', CharMapAction::SubChar('𐒳')), // OSAGE SMALL LETTER AH
+    ('\u{104DC}', CharMapAction::SubChar('𐒴')), // OSAGE SMALL LETTER BRA
+    ('\u{104DD}', CharMapAction::SubChar('𐒵')), // OSAGE SMALL LETTER CHA
+    ('\u{104DE}', CharMapAction::SubChar('𐒶')), // OSAGE SMALL LETTER EHCHA
+    ('\u{104DF}', CharMapAction::SubChar('𐒷')), // OSAGE SMALL LETTER E
+    ('\u{104E0}', CharMapAction::SubChar('𐒸')), // OSAGE SMALL LETTER EIN
+    ('\u{104E1}', CharMapAction::SubChar('𐒹')), // OSAGE SMALL LETTER HA
+    ('\u{104E2}', CharMapAction::SubChar('𐒺')), // OSAGE SMALL LETTER HYA
+    ('\u{104E3}', CharMapAction::SubChar('𐒻')), // OSAGE SMALL LETTER I
+    ('\u{104E4}', CharMapAction::SubChar('𐒼')), // OSAGE SMALL LETTER KA
+    ('\u{104E5}', CharMapAction::SubChar('𐒽')), // OSAGE SMALL LETTER EHKA
+    ('\u{104E6}', CharMapAction::SubChar('𐒾')), // OSAGE SMALL LETTER KYA
+    ('\u{104E7}', CharMapAction::SubChar('𐒿')), // OSAGE SMALL LETTER LA
+    ('\u{104E8}', CharMapAction::SubChar('𐓀')), // OSAGE SMALL LETTER MA
+    ('\u{104E9}', CharMapAction::SubChar('𐓁')), // OSAGE SMALL LETTER NA
+    ('\u{104EA}', CharMapAction::SubChar('𐓂')), // OSAGE SMALL LETTER O
+    ('\u{104EB}', CharMapAction::SubChar('𐓃')), // OSAGE SMALL LETTER OIN
+    ('\u{104EC}', CharMapAction::SubChar('𐓄')), // OSAGE SMALL LETTER PA
+    ('\u{104ED}', CharMapAction::SubChar('𐓅')), // OSAGE SMALL LETTER EHPA
+    ('\u{104EE}', CharMapAction::SubChar('𐓆')), // OSAGE SMALL LETTER SA
+    ('\u{104EF}', CharMapAction::SubChar('𐓇')), // OSAGE SMALL LETTER SHA
+    ('\u{104F0}', CharMapAction::SubChar('𐓈')), // OSAGE SMALL LETTER TA
+    ('\u{104F1}', CharMapAction::SubChar('𐓉')), // OSAGE SMALL LETTER EHTA
+    ('\u{104F2}', CharMapAction::SubChar('𐓊')), // OSAGE SMALL LETTER TSA
+    ('\u{104F3}', CharMapAction::SubChar('𐓋')), // OSAGE SMALL LETTER EHTSA
+    ('\u{104F4}', CharMapAction::SubChar('𐓌')), // OSAGE SMALL LETTER TSHA
+    ('\u{104F5}', CharMapAction::SubChar('𐓍')), // OSAGE SMALL LETTER DHA
+    ('\u{104F6}', CharMapAction::SubChar('𐓎')), // OSAGE SMALL LETTER U
+    ('\u{104F7}', CharMapAction::SubChar('𐓏')), // OSAGE SMALL LETTER WA
+    ('\u{104F8}', CharMapAction::SubChar('𐓐')), // OSAGE SMALL LETTER KHA
+    ('\u{104F9}', CharMapAction::SubChar('𐓑')), // OSAGE SMALL LETTER GHA
+    ('\u{104FA}', CharMapAction::SubChar('𐓒')), // OSAGE SMALL LETTER ZA
+    ('\u{104FB}', CharMapAction::SubChar('𐓓')), // OSAGE SMALL LETTER ZHA
+    ('\u{10597}', CharMapAction::SubChar('𐕰')), // VITHKUQI SMALL LETTER A
+    ('\u{10598}', CharMapAction::SubChar('𐕱')), // VITHKUQI SMALL LETTER BBE
+    ('\u{10599}', CharMapAction::SubChar('𐕲')), // VITHKUQI SMALL LETTER BE
+    ('\u{1059A}', CharMapAction::SubChar('𐕳')), // VITHKUQI SMALL LETTER CE
+    ('\u{1059B}', CharMapAction::SubChar('𐕴')), // VITHKUQI SMALL LETTER CHE
+    ('\u{1059C}', CharMapAction::SubChar('𐕵')), // VITHKUQI SMALL LETTER DE
+    ('\u{1059D}', CharMapAction::SubChar('𐕶')), // VITHKUQI SMALL LETTER DHE
+    ('\u{1059E}', CharMapAction::SubChar('𐕷')), // VITHKUQI SMALL LETTER EI
+    ('\u{1059F}', CharMapAction::SubChar('𐕸')), // VITHKUQI SMALL LETTER E
+    ('\u{105A0}', CharMapAction::SubChar('𐕹')), // VITHKUQI SMALL LETTER FE
+    ('\u{105A1}', CharMapAction::SubChar('𐕺')), // VITHKUQI SMALL LETTER GA
+    ('\u{105A3}', CharMapAction::SubChar('𐕼')), // VITHKUQI SMALL LETTER HA
+    ('\u{105A4}', CharMapAction::SubChar('𐕽')), // VITHKUQI SMALL LETTER HHA
+    ('\u{105A5}', CharMapAction::SubChar('𐕾')), // VITHKUQI SMALL LETTER I
+    ('\u{105A6}', CharMapAction::SubChar('𐕿')), // VITHKUQI SMALL LETTER IJE
+    ('\u{105A7}', CharMapAction::SubChar('𐖀')), // VITHKUQI SMALL LETTER JE
+    ('\u{105A8}', CharMapAction::SubChar('𐖁')), // VITHKUQI SMALL LETTER KA
+    ('\u{105A9}', CharMapAction::SubChar('𐖂')), // VITHKUQI SMALL LETTER LA
+    ('\u{105AA}', CharMapAction::SubChar('𐖃')), // VITHKUQI SMALL LETTER LLA
+    ('\u{105AB}', CharMapAction::SubChar('𐖄')), // VITHKUQI SMALL LETTER ME
+    ('\u{105AC}', CharMapAction::SubChar('𐖅')), // VITHKUQI SMALL LETTER NE
+    ('\u{105AD}', CharMapAction::SubChar('𐖆')), // VITHKUQI SMALL LETTER NJE
+    ('\u{105AE}', CharMapAction::SubChar('𐖇')), // VITHKUQI SMALL LETTER O
+    ('\u{105AF}', CharMapAction::SubChar('𐖈')), // VITHKUQI SMALL LETTER PE
+    ('\u{105B0}', CharMapAction::SubChar('𐖉')), // VITHKUQI SMALL LETTER QA
+    ('\u{105B1}', CharMapAction::SubChar('𐖊')), // VITHKUQI SMALL LETTER RE
+    ('\u{105B3}', CharMapAction::SubChar('𐖌')), // VITHKUQI SMALL LETTER SE
+    ('\u{105B4}', CharMapAction::SubChar('𐖍')), // VITHKUQI SMALL LETTER SHE
+    ('\u{105B5}', CharMapAction::SubChar('𐖎')), // VITHKUQI SMALL LETTER TE
+    ('\u{105B6}', CharMapAction::SubChar('𐖏')), // VITHKUQI SMALL LETTER THE
+    ('\u{105B7}', CharMapAction::SubChar('𐖐')), // VITHKUQI SMALL LETTER U
+    ('\u{105B8}', CharMapAction::SubChar('𐖑')), // VITHKUQI SMALL LETTER VE
+    ('\u{105B9}', CharMapAction::SubChar('𐖒')), // VITHKUQI SMALL LETTER XE
+    ('\u{105BB}', CharMapAction::SubChar('𐖔')), // VITHKUQI SMALL LETTER Y
+    ('\u{105BC}', CharMapAction::SubChar('𐖕')), // VITHKUQI SMALL LETTER ZE
+    ('\u{10CC0}', CharMapAction::SubChar('𐲀')), // OLD HUNGARIAN SMALL LETTER A
+    ('\u{10CC1}', CharMapAction::SubChar('𐲁')), // OLD HUNGARIAN SMALL LETTER AA
+    ('\u{10CC2}', CharMapAction::SubChar('𐲂')), // OLD HUNGARIAN SMALL LETTER EB
+    ('\u{10CC3}', CharMapAction::SubChar('𐲃')), // OLD HUNGARIAN SMALL LETTER AMB
+    ('\u{10CC4}', CharMapAction::SubChar('𐲄')), // OLD HUNGARIAN SMALL LETTER EC
+    ('\u{10CC5}', CharMapAction::SubChar('𐲅')), // OLD HUNGARIAN SMALL LETTER ENC
+    ('\u{10CC6}', CharMapAction::SubChar('𐲆')), // OLD HUNGARIAN SMALL LETTER ECS
+    ('\u{10CC7}', CharMapAction::SubChar('𐲇')), // OLD HUNGARIAN SMALL LETTER ED
+    ('\u{10CC8}', CharMapAction::SubChar('𐲈')), // OLD HUNGARIAN SMALL LETTER AND
+    ('\u{10CC9}', CharMapAction::SubChar('𐲉')), // OLD HUNGARIAN SMALL LETTER E
+    ('\u{10CCA}', CharMapAction::SubChar('𐲊')), // OLD HUNGARIAN SMALL LETTER CLOSE E
+    ('\u{10CCB}', CharMapAction::SubChar('𐲋')), // OLD HUNGARIAN SMALL LETTER EE
+    ('\u{10CCC}', CharMapAction::SubChar('𐲌')), // OLD HUNGARIAN SMALL LETTER EF
+    ('\u{10CCD}', CharMapAction::SubChar('𐲍')), // OLD HUNGARIAN SMALL LETTER EG
+    ('\u{10CCE}', CharMapAction::SubChar('𐲎')), // OLD HUNGARIAN SMALL LETTER EGY
+    ('\u{10CCF}', CharMapAction::SubChar('𐲏')), // OLD HUNGARIAN SMALL LETTER EH
+    ('\u{10CD0}', CharMapAction::SubChar('𐲐')), // OLD HUNGARIAN SMALL LETTER I
+    ('\u{10CD1}', CharMapAction::SubChar('𐲑')), // OLD HUNGARIAN SMALL LETTER II
+    ('\u{10CD2}', CharMapAction::SubChar('𐲒')), // OLD HUNGARIAN SMALL LETTER EJ
+    ('\u{10CD3}', CharMapAction::SubChar('𐲓')), // OLD HUNGARIAN SMALL LETTER EK
+    ('\u{10CD4}', CharMapAction::SubChar('𐲔')), // OLD HUNGARIAN SMALL LETTER AK
+    ('\u{10CD5}', CharMapAction::SubChar('𐲕')), // OLD HUNGARIAN SMALL LETTER UNK
+    ('\u{10CD6}', CharMapAction::SubChar('𐲖')), // OLD HUNGARIAN SMALL LETTER EL
+    ('\u{10CD7}', CharMapAction::SubChar('𐲗')), // OLD HUNGARIAN SMALL LETTER ELY
+    ('\u{10CD8}', CharMapAction::SubChar('𐲘')), // OLD HUNGARIAN SMALL LETTER EM
+    ('\u{10CD9}', CharMapAction::SubChar('𐲙')), // OLD HUNGARIAN SMALL LETTER EN
+    ('\u{10CDA}', CharMapAction::SubChar('𐲚')), // OLD HUNGARIAN SMALL LETTER ENY
+    ('\u{10CDB}', CharMapAction::SubChar('𐲛')), // OLD HUNGARIAN SMALL LETTER O
+    ('\u{10CDC}', CharMapAction::SubChar('𐲜')), // OLD HUNGARIAN SMALL LETTER OO
+    ('\u{10CDD}', CharMapAction::SubChar('𐲝')), // OLD HUNGARIAN SMALL LETTER NIKOLSBURG OE
+    ('\u{10CDE}', CharMapAction::SubChar('𐲞')), // OLD HUNGARIAN SMALL LETTER RUDIMENTA OE
+    ('\u{10CDF}', CharMapAction::SubChar('𐲟')), // OLD HUNGARIAN SMALL LETTER OEE
+    ('\u{10CE0}', CharMapAction::SubChar('𐲠')), // OLD HUNGARIAN SMALL LETTER EP
+    ('\u{10CE1}', CharMapAction::SubChar('𐲡')), // OLD HUNGARIAN SMALL LETTER EMP
+    ('\u{10CE2}', CharMapAction::SubChar('𐲢')), // OLD HUNGARIAN SMALL LETTER ER
+    ('\u{10CE3}', CharMapAction::SubChar('𐲣')), // OLD HUNGARIAN SMALL LETTER SHORT ER
+    ('\u{10CE4}', CharMapAction::SubChar('𐲤')), // OLD HUNGARIAN SMALL LETTER ES
+    ('\u{10CE5}', CharMapAction::SubChar('𐲥')), // OLD HUNGARIAN SMALL LETTER ESZ
+    ('\u{10CE6}', CharMapAction::SubChar('𐲦')), // OLD HUNGARIAN SMALL LETTER ET
+    ('\u{10CE7}', CharMapAction::SubChar('𐲧')), // OLD HUNGARIAN SMALL LETTER ENT
+    ('\u{10CE8}', CharMapAction::SubChar('𐲨')), // OLD HUNGARIAN SMALL LETTER ETY
+    ('\u{10CE9}', CharMapAction::SubChar('𐲩')), // OLD HUNGARIAN SMALL LETTER ECH
+    ('\u{10CEA}', CharMapAction::SubChar('𐲪')), // OLD HUNGARIAN SMALL LETTER U
+    ('\u{10CEB}', CharMapAction::SubChar('𐲫')), // OLD HUNGARIAN SMALL LETTER UU
+    ('\u{10CEC}', CharMapAction::SubChar('𐲬')), // OLD HUNGARIAN SMALL LETTER NIKOLSBURG UE
+    ('\u{10CED}', CharMapAction::SubChar('𐲭')), // OLD HUNGARIAN SMALL LETTER RUDIMENTA UE
+    ('\u{10CEE}', CharMapAction::SubChar('𐲮')), // OLD HUNGARIAN SMALL LETTER EV
+    ('\u{10CEF}', CharMapAction::SubChar('𐲯')), // OLD HUNGARIAN SMALL LETTER EZ
+    ('\u{10CF0}', CharMapAction::SubChar('𐲰')), // OLD HUNGARIAN SMALL LETTER EZS
+    ('\u{10CF1}', CharMapAction::SubChar('𐲱')), // OLD HUNGARIAN SMALL LETTER ENT-SHAPED SIGN
+    ('\u{10CF2}', CharMapAction::SubChar('𐲲')), // OLD HUNGARIAN SMALL LETTER US
+    ('\u{118C0}', CharMapAction::SubChar('𑢠')), // WARANG CITI SMALL LETTER NGAA
+    ('\u{118C1}', CharMapAction::SubChar('𑢡')), // WARANG CITI SMALL LETTER A
+    ('\u{118C2}', CharMapAction::SubChar('𑢢')), // WARANG CITI SMALL LETTER WI
+    ('\u{118C3}', CharMapAction::SubChar('𑢣')), // WARANG CITI SMALL LETTER YU
+    ('\u{118C4}', CharMapAction::SubChar('𑢤')), // WARANG CITI SMALL LETTER YA
+    ('\u{118C5}', CharMapAction::SubChar('𑢥')), // WARANG CITI SMALL LETTER YO
+    ('\u{118C6}', CharMapAction::SubChar('𑢦')), // WARANG CITI SMALL LETTER II
+    ('\u{118C7}', CharMapAction::SubChar('𑢧')), // WARANG CITI SMALL LETTER UU
+    ('\u{118C8}', CharMapAction::SubChar('𑢨')), // WARANG CITI SMALL LETTER E
+    ('\u{118C9}', CharMapAction::SubChar('𑢩')), // WARANG CITI SMALL LETTER O
+    ('\u{118CA}', CharMapAction::SubChar('𑢪')), // WARANG CITI SMALL LETTER ANG
+    ('\u{118CB}', CharMapAction::SubChar('𑢫')), // WARANG CITI SMALL LETTER GA
+    ('\u{118CC}', CharMapAction::SubChar('𑢬')), // WARANG CITI SMALL LETTER KO
+    ('\u{118CD}', CharMapAction::SubChar('𑢭')), // WARANG CITI SMALL LETTER ENY
+    ('\u{118CE}', CharMapAction::SubChar('𑢮')), // WARANG CITI SMALL LETTER YUJ
+    ('\u{118CF}', CharMapAction::SubChar('𑢯')), // WARANG CITI SMALL LETTER UC
+    ('\u{118D0}', CharMapAction::SubChar('𑢰')), // WARANG CITI SMALL LETTER ENN
+    ('\u{118D1}', CharMapAction::SubChar('𑢱')), // WARANG CITI SMALL LETTER ODD
+    ('\u{118D2}', CharMapAction::SubChar('𑢲')), // WARANG CITI SMALL LETTER TTE
+    ('\u{118D3}', CharMapAction::SubChar('𑢳')), // WARANG CITI SMALL LETTER NUNG
+    ('\u{118D4}', CharMapAction::SubChar('𑢴')), // WARANG CITI SMALL LETTER DA
+    ('\u{118D5}', CharMapAction::SubChar('𑢵')), // WARANG CITI SMALL LETTER AT
+    ('\u{118D6}', CharMapAction::SubChar('𑢶')), // WARANG CITI SMALL LETTER AM
+    ('\u{118D7}', CharMapAction::SubChar('𑢷')), // WARANG CITI SMALL LETTER BU
+    ('\u{118D8}', CharMapAction::SubChar('𑢸')), // WARANG CITI SMALL LETTER PU
+    ('\u{118D9}', CharMapAction::SubChar('𑢹')), // WARANG CITI SMALL LETTER HIYO
+    ('\u{118DA}', CharMapAction::SubChar('𑢺')), // WARANG CITI SMALL LETTER HOLO
+    ('\u{118DB}', CharMapAction::SubChar('𑢻')), // WARANG CITI SMALL LETTER HORR
+    ('\u{118DC}', CharMapAction::SubChar('𑢼')), // WARANG CITI SMALL LETTER HAR
+    ('\u{118DD}', CharMapAction::SubChar('𑢽')), // WARANG CITI SMALL LETTER SSUU
+    ('\u{118DE}', CharMapAction::SubChar('𑢾')), // WARANG CITI SMALL LETTER SII
+    ('\u{118DF}', CharMapAction::SubChar('𑢿')), // WARANG CITI SMALL LETTER VIYO
+    ('\u{16E60}', CharMapAction::SubChar('𖹀')), // MEDEFAIDRIN SMALL LETTER M
+    ('\u{16E61}', CharMapAction::SubChar('𖹁')), // MEDEFAIDRIN SMALL LETTER S
+    ('\u{16E62}', CharMapAction::SubChar('𖹂')), // MEDEFAIDRIN SMALL LETTER V
+    ('\u{16E63}', CharMapAction::SubChar('𖹃')), // MEDEFAIDRIN SMALL LETTER W
+    ('\u{16E64}', CharMapAction::SubChar('𖹄')), // MEDEFAIDRIN SMALL LETTER ATIU
+    ('\u{16E65}', CharMapAction::SubChar('𖹅')), // MEDEFAIDRIN SMALL LETTER Z
+    ('\u{16E66}', CharMapAction::SubChar('𖹆')), // MEDEFAIDRIN SMALL LETTER KP
+    ('\u{16E67}', CharMapAction::SubChar('𖹇')), // MEDEFAIDRIN SMALL LETTER P
+    ('\u{16E68}', CharMapAction::SubChar('𖹈')), // MEDEFAIDRIN SMALL LETTER T
+    ('\u{16E69}', CharMapAction::SubChar('𖹉')), // MEDEFAIDRIN SMALL LETTER G
+    ('\u{16E6A}', CharMapAction::SubChar('𖹊')), // MEDEFAIDRIN SMALL LETTER F
+    ('\u{16E6B}', CharMapAction::SubChar('𖹋')), // MEDEFAIDRIN SMALL LETTER I
+    ('\u{16E6C}', CharMapAction::SubChar('𖹌')), // MEDEFAIDRIN SMALL LETTER K
+    ('\u{16E6D}', CharMapAction::SubChar('𖹍')), // MEDEFAIDRIN SMALL LETTER A
+    ('\u{16E6E}', CharMapAction::SubChar('𖹎')), // MEDEFAIDRIN SMALL LETTER J
+    ('\u{16E6F}', CharMapAction::SubChar('𖹏')), // MEDEFAIDRIN SMALL LETTER E
+    ('\u{16E70}', CharMapAction::SubChar('𖹐')), // MEDEFAIDRIN SMALL LETTER B
+    ('\u{16E71}', CharMapAction::SubChar('𖹑')), // MEDEFAIDRIN SMALL LETTER C
+    ('\u{16E72}', CharMapAction::SubChar('𖹒')), // MEDEFAIDRIN SMALL LETTER U
+    ('\u{16E73}', CharMapAction::SubChar('𖹓')), // MEDEFAIDRIN SMALL LETTER YU
+    ('\u{16E74}', CharMapAction::SubChar('𖹔')), // MEDEFAIDRIN SMALL LETTER L
+    ('\u{16E75}', CharMapAction::SubChar('𖹕')), // MEDEFAIDRIN SMALL LETTER Q
+    ('\u{16E76}', CharMapAction::SubChar('𖹖')), // MEDEFAIDRIN SMALL LETTER HP
+    ('\u{16E77}', CharMapAction::SubChar('𖹗')), // MEDEFAIDRIN SMALL LETTER NY
+    ('\u{16E78}', CharMapAction::SubChar('𖹘')), // MEDEFAIDRIN SMALL LETTER X
+    ('\u{16E79}', CharMapAction::SubChar('𖹙')), // MEDEFAIDRIN SMALL LETTER D
+    ('\u{16E7A}', CharMapAction::SubChar('𖹚')), // MEDEFAIDRIN SMALL LETTER OE
+    ('\u{16E7B}', CharMapAction::SubChar('𖹛')), // MEDEFAIDRIN SMALL LETTER N
+    ('\u{16E7C}', CharMapAction::SubChar('𖹜')), // MEDEFAIDRIN SMALL LETTER R
+    ('\u{16E7D}', CharMapAction::SubChar('𖹝')), // MEDEFAIDRIN SMALL LETTER O
+    ('\u{16E7E}', CharMapAction::SubChar('𖹞')), // MEDEFAIDRIN SMALL LETTER AI
+    ('\u{16E7F}', CharMapAction::SubChar('𖹟')), // MEDEFAIDRIN SMALL LETTER Y
+    ('\u{1E922}', CharMapAction::SubChar('𞤀')), // ADLAM SMALL LETTER ALIF
+    ('\u{1E923}', CharMapAction::SubChar('𞤁')), // ADLAM SMALL LETTER DAALI
+    ('\u{1E924}', CharMapAction::SubChar('𞤂')), // ADLAM SMALL LETTER LAAM
+    ('\u{1E925}', CharMapAction::SubChar('𞤃')), // ADLAM SMALL LETTER MIIM
+    ('\u{1E926}', CharMapAction::SubChar('𞤄')), // ADLAM SMALL LETTER BA
+    ('\u{1E927}', CharMapAction::SubChar('𞤅')), // ADLAM SMALL LETTER SINNYIIYHE
+    ('\u{1E928}', CharMapAction::SubChar('𞤆')), // ADLAM SMALL LETTER PE
+    ('\u{1E929}', CharMapAction::SubChar('𞤇')), // ADLAM SMALL LETTER BHE
+    ('\u{1E92A}', CharMapAction::SubChar('𞤈')), // ADLAM SMALL LETTER RA
+    ('\u{1E92B}', CharMapAction::SubChar('𞤉')), // ADLAM SMALL LETTER E
+    ('\u{1E92C}', CharMapAction::SubChar('𞤊')), // ADLAM SMALL LETTER FA
+    ('\u{1E92D}', CharMapAction::SubChar('𞤋')), // ADLAM SMALL LETTER I
+    ('\u{1E92E}', CharMapAction::SubChar('𞤌')), // ADLAM SMALL LETTER O
+    ('\u{1E92F}', CharMapAction::SubChar('𞤍')), // ADLAM SMALL LETTER DHA
+    ('\u{1E930}', CharMapAction::SubChar('𞤎')), // ADLAM SMALL LETTER YHE
+    ('\u{1E931}', CharMapAction::SubChar('𞤏')), // ADLAM SMALL LETTER WAW
+    ('\u{1E932}', CharMapAction::SubChar('𞤐')), // ADLAM SMALL LETTER NUN
+    ('\u{1E933}', CharMapAction::SubChar('𞤑')), // ADLAM SMALL LETTER KAF
+    ('\u{1E934}', CharMapAction::SubChar('𞤒')), // ADLAM SMALL LETTER YA
+    ('\u{1E935}', CharMapAction::SubChar('𞤓')), // ADLAM SMALL LETTER U
+    ('\u{1E936}', CharMapAction::SubChar('𞤔')), // ADLAM SMALL LETTER JIIM
+    ('\u{1E937}', CharMapAction::SubChar('𞤕')), // ADLAM SMALL LETTER CHI
+    ('\u{1E938}', CharMapAction::SubChar('𞤖')), // ADLAM SMALL LETTER HA
+    ('\u{1E939}', CharMapAction::SubChar('𞤗')), // ADLAM SMALL LETTER QAAF
+    ('\u{1E93A}', CharMapAction::SubChar('𞤘')), // ADLAM SMALL LETTER GA
+    ('\u{1E93B}', CharMapAction::SubChar('𞤙')), // ADLAM SMALL LETTER NYA
+    ('\u{1E93C}', CharMapAction::SubChar('𞤚')), // ADLAM SMALL LETTER TU
+    ('\u{1E93D}', CharMapAction::SubChar('𞤛')), // ADLAM SMALL LETTER NHA
+    ('\u{1E93E}', CharMapAction::SubChar('𞤜')), // ADLAM SMALL LETTER VA
+    ('\u{1E93F}', CharMapAction::SubChar('𞤝')), // ADLAM SMALL LETTER KHA
+    ('\u{1E940}', CharMapAction::SubChar('𞤞')), // ADLAM SMALL LETTER GBE
+    ('\u{1E941}', CharMapAction::SubChar('𞤟')), // ADLAM SMALL LETTER ZAL
+    ('\u{1E942}', CharMapAction::SubChar('𞤠')), // ADLAM SMALL LETTER KPO
+    ('\u{1E943}', CharMapAction::SubChar('𞤡')), // ADLAM SMALL LETTER SHA
+];
+const _: () = assert_sorted(&TITLECASE);
+
+/// Map from characters to their full case foldings.
+#[rustfmt::skip]
+pub static CASE_FOLD: [(char, CharMapAction); 1530] = [
+    ('\u{0041}', CharMapAction::SubChar('a')), // LATIN CAPITAL LETTER A
+    ('\u{0042}', CharMapAction::SubChar('b')), // LATIN CAPITAL LETTER B
+    ('\u{0043}', CharMapAction::SubChar('c')), // LATIN CAPITAL LETTER C
+    ('\u{0044}', CharMapAction::SubChar('d')), // LATIN CAPITAL LETTER D
+    ('\u{0045}', CharMapAction::SubChar('e')), // LATIN CAPITAL LETTER E
+    ('\u{0046}', CharMapAction::SubChar('f')), // LATIN CAPITAL LETTER F
+    ('\u{0047}', CharMapAction::SubChar('g')), // LATIN CAPITAL LETTER G
+    ('\u{0048}', CharMapAction::SubChar('h')), // LATIN CAPITAL LETTER H
+    ('\u{0049}', CharMapAction::SubChar('i')), // LATIN CAPITAL LETTER I
+    ('\u{004A}', CharMapAction::SubChar('j')), // LATIN CAPITAL LETTER J
+    ('\u{004B}', CharMapAction::SubChar('k')), // LATIN CAPITAL LETTER K
+    ('\u{004C}', CharMapAction::SubChar('l')), // LATIN CAPITAL LETTER L
+    ('\u{004D}', CharMapAction::SubChar('m')), // LATIN CAPITAL LETTER M
+    ('\u{004E}', CharMapAction::SubChar('n')), // LATIN CAPITAL LETTER N
+    ('\u{004F}', CharMapAction::SubChar('o')), // LATIN CAPITAL LETTER O
+    ('\u{0050}', CharMapAction::SubChar('p')), // LATIN CAPITAL LETTER P
+    ('\u{0051}', CharMapAction::SubChar('q')), // LATIN CAPITAL LETTER Q
+    ('\u{0052}', CharMapAction::SubChar('r')), // LATIN CAPITAL LETTER R
+    ('\u{0053}', CharMapAction::SubChar('s')), // LATIN CAPITAL LETTER S
+    ('\u{0054}', CharMapAction::SubChar('t')), // LATIN CAPITAL LETTER T
+    ('\u{0055}', CharMapAction::SubChar('u')), // LATIN CAPITAL LETTER U
+    ('\u{0056}', CharMapAction::SubChar('v')), // LATIN CAPITAL LETTER V
+    ('\u{0057}', CharMapAction::SubChar('w')), // LATIN CAPITAL LETTER W
+    ('\u{0058}', CharMapAction::SubChar('x')), // LATIN CAPITAL LETTER X
+    ('\u{0059}', CharMapAction::SubChar('y')), // LATIN CAPITAL LETTER Y
+    ('\u{005A}', CharMapAction::SubChar('z')), // LATIN CAPITAL LETTER Z
+    ('\u{00B5}', CharMapAction::SubChar('μ')), // MICRO SIGN
+    ('\u{00C0}', CharMapAction::SubChar('à')), // LATIN CAPITAL LETTER A WITH GRAVE
+    ('\u{00C1}', CharMapAction::SubChar('á')), // LATIN CAPITAL LETTER A WITH ACUTE
+    ('\u{00C2}', CharMapAction::SubChar('â')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX
+    ('\u{00C3}', CharMapAction::SubChar('ã')), // LATIN CAPITAL LETTER A WITH TILDE
+    ('\u{00C4}', CharMapAction::SubChar('ä')), // LATIN CAPITAL LETTER A WITH DIAERESIS
+    ('\u{00C5}', CharMapAction::SubChar('å')), // LATIN CAPITAL LETTER A WITH RING ABOVE
+    ('\u{00C6}', CharMapAction::SubChar('æ')), // LATIN CAPITAL LETTER AE
+    ('\u{00C7}', CharMapAction::SubChar('ç')), // LATIN CAPITAL LETTER C WITH CEDILLA
+    ('\u{00C8}', CharMapAction::SubChar('è')), // LATIN CAPITAL LETTER E WITH GRAVE
+    ('\u{00C9}', CharMapAction::SubChar('é')), // LATIN CAPITAL LETTER E WITH ACUTE
+    ('\u{00CA}', CharMapAction::SubChar('ê')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX
+    ('\u{00CB}', CharMapAction::SubChar('ë')), // LATIN CAPITAL LETTER E WITH DIAERESIS
+    ('\u{00CC}', CharMapAction::SubChar('ì')), // LATIN CAPITAL LETTER I WITH GRAVE
+    ('\u{00CD}', CharMapAction::SubChar('í')), // LATIN CAPITAL LETTER I WITH ACUTE
+    ('\u{00CE}', CharMapAction::SubChar('î')), // LATIN CAPITAL LETTER I WITH CIRCUMFLEX
+    ('\u{00CF}', CharMapAction::SubChar('ï')), // LATIN CAPITAL LETTER I WITH DIAERESIS
+    ('\u{00D0}', CharMapAction::SubChar('ð')), // LATIN CAPITAL LETTER ETH
+    ('\u{00D1}', CharMapAction::SubChar('ñ')), // LATIN CAPITAL LETTER N WITH TILDE
+    ('\u{00D2}', CharMapAction::SubChar('ò')), // LATIN CAPITAL LETTER O WITH GRAVE
+    ('\u{00D3}', CharMapAction::SubChar('ó')), // LATIN CAPITAL LETTER O WITH ACUTE
+    ('\u{00D4}', CharMapAction::SubChar('ô')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX
+    ('\u{00D5}', CharMapAction::SubChar('õ')), // LATIN CAPITAL LETTER O WITH TILDE
+    ('\u{00D6}', CharMapAction::SubChar('ö')), // LATIN CAPITAL LETTER O WITH DIAERESIS
+    ('\u{00D8}', CharMapAction::SubChar('ø')), // LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00D9}', CharMapAction::SubChar('ù')), // LATIN CAPITAL LETTER U WITH GRAVE
+    ('\u{00DA}', CharMapAction::SubChar('ú')), // LATIN CAPITAL LETTER U WITH ACUTE
+    ('\u{00DB}', CharMapAction::SubChar('û')), // LATIN CAPITAL LETTER U WITH CIRCUMFLEX
+    ('\u{00DC}', CharMapAction::SubChar('ü')), // LATIN CAPITAL LETTER U WITH DIAERESIS
+    ('\u{00DD}', CharMapAction::SubChar('ý')), // LATIN CAPITAL LETTER Y WITH ACUTE
+    ('\u{00DE}', CharMapAction::SubChar('þ')), // LATIN CAPITAL LETTER THORN
+    ('\u{00DF}', CharMapAction::SubStr("ss")), // LATIN SMALL LETTER SHARP S
+    ('\u{0100}', CharMapAction::SubChar('ā')), // LATIN CAPITAL LETTER A WITH MACRON
+    ('\u{0102}', CharMapAction::SubChar('ă')), // LATIN CAPITAL LETTER A WITH BREVE
+    ('\u{0104}', CharMapAction::SubChar('ą')), // LATIN CAPITAL LETTER A WITH OGONEK
+    ('\u{0106}', CharMapAction::SubChar('ć')), // LATIN CAPITAL LETTER C WITH ACUTE
+    ('\u{0108}', CharMapAction::SubChar('ĉ')), // LATIN CAPITAL LETTER C WITH CIRCUMFLEX
+    ('\u{010A}', CharMapAction::SubChar('ċ')), // LATIN CAPITAL LETTER C WITH DOT ABOVE
+    ('\u{010C}', CharMapAction::SubChar('č')), // LATIN CAPITAL LETTER C WITH CARON
+    ('\u{010E}', CharMapAction::SubChar('ď')), // LATIN CAPITAL LETTER D WITH CARON
+    ('\u{0110}', CharMapAction::SubChar('đ')), // LATIN CAPITAL LETTER D WITH STROKE
+    ('\u{0112}', CharMapAction::SubChar('ē')), // LATIN CAPITAL LETTER E WITH MACRON
+    ('\u{0114}', CharMapAction::SubChar('ĕ')), // LATIN CAPITAL LETTER E WITH BREVE
+    ('\u{0116}', CharMapAction::SubChar('ė')), // LATIN CAPITAL LETTER E WITH DOT ABOVE
+    ('\u{0118}', CharMapAction::SubChar('ę')), // LATIN CAPITAL LETTER E WITH OGONEK
+    ('\u{011A}', CharMapAction::SubChar('ě')), // LATIN CAPITAL LETTER E WITH CARON
+    ('\u{011C}', CharMapAction::SubChar('ĝ')), // LATIN CAPITAL LETTER G WITH CIRCUMFLEX
+    ('\u{011E}', CharMapAction::SubChar('ğ')), // LATIN CAPITAL LETTER G WITH BREVE
+    ('\u{0120}', CharMapAction::SubChar('ġ')), // LATIN CAPITAL LETTER G WITH DOT ABOVE
+    ('\u{0122}', CharMapAction::SubChar('ģ')), // LATIN CAPITAL LETTER G WITH CEDILLA
+    ('\u{0124}', CharMapAction::SubChar('ĥ')), // LATIN CAPITAL LETTER H WITH CIRCUMFLEX
+    ('\u{0126}', CharMapAction::SubChar('ħ')), // LATIN CAPITAL LETTER H WITH STROKE
+    ('\u{0128}', CharMapAction::SubChar('ĩ')), // LATIN CAPITAL LETTER I WITH TILDE
+    ('\u{012A}', CharMapAction::SubChar('ī')), // LATIN CAPITAL LETTER I WITH MACRON
+    ('\u{012C}', CharMapAction::SubChar('ĭ')), // LATIN CAPITAL LETTER I WITH BREVE
+    ('\u{012E}', CharMapAction::SubChar('į')), // LATIN CAPITAL LETTER I WITH OGONEK
+    ('\u{0130}', CharMapAction::SubStr("i\u{0307}")), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+    ('\u{0132}', CharMapAction::SubChar('ĳ')), // LATIN CAPITAL LIGATURE IJ
+    ('\u{0134}', CharMapAction::SubChar('ĵ')), // LATIN CAPITAL LETTER J WITH CIRCUMFLEX
+    ('\u{0136}', CharMapAction::SubChar('ķ')), // LATIN CAPITAL LETTER K WITH CEDILLA
+    ('\u{0139}', CharMapAction::SubChar('ĺ')), // LATIN CAPITAL LETTER L WITH ACUTE
+    ('\u{013B}', CharMapAction::SubChar('ļ')), // LATIN CAPITAL LETTER L WITH CEDILLA
+    ('\u{013D}', CharMapAction::SubChar('ľ')), // LATIN CAPITAL LETTER L WITH CARON
+    ('\u{013F}', CharMapAction::SubChar('ŀ')), // LATIN CAPITAL LETTER L WITH MIDDLE DOT
+    ('\u{0141}', CharMapAction::SubChar('ł')), // LATIN CAPITAL LETTER L WITH STROKE
+    ('\u{0143}', CharMapAction::SubChar('ń')), // LATIN CAPITAL LETTER N WITH ACUTE
+    ('\u{0145}', CharMapAction::SubChar('ņ')), // LATIN CAPITAL LETTER N WITH CEDILLA
+    ('\u{0147}', CharMapAction::SubChar('ň')), // LATIN CAPITAL LETTER N WITH CARON
+    ('\u{0149}', CharMapAction::SubStr("ʼn")), // LATIN SMALL LETTER N PRECEDED BY APOSTROPHE
+    ('\u{014A}', CharMapAction::SubChar('ŋ')), // LATIN CAPITAL LETTER ENG
+    ('\u{014C}', CharMapAction::SubChar('ō')), // LATIN CAPITAL LETTER O WITH MACRON
+    ('\u{014E}', CharMapAction::SubChar('ŏ')), // LATIN CAPITAL LETTER O WITH BREVE
+    ('\u{0150}', CharMapAction::SubChar('ő')), // LATIN CAPITAL LETTER O WITH DOUBLE ACUTE
+    ('\u{0152}', CharMapAction::SubChar('œ')), // LATIN CAPITAL LIGATURE OE
+    ('\u{0154}', CharMapAction::SubChar('ŕ')), // LATIN CAPITAL LETTER R WITH ACUTE
+    ('\u{0156}', CharMapAction::SubChar('ŗ')), // LATIN CAPITAL LETTER R WITH CEDILLA
+    ('\u{0158}', CharMapAction::SubChar('ř')), // LATIN CAPITAL LETTER R WITH CARON
+    ('\u{015A}', CharMapAction::SubChar('ś')), // LATIN CAPITAL LETTER S WITH ACUTE
+    ('\u{015C}', CharMapAction::SubChar('ŝ')), // LATIN CAPITAL LETTER S WITH CIRCUMFLEX
+    ('\u{015E}', CharMapAction::SubChar('ş')), // LATIN CAPITAL LETTER S WITH CEDILLA
+    ('\u{0160}', CharMapAction::SubChar('š')), // LATIN CAPITAL LETTER S WITH CARON
+    ('\u{0162}', CharMapAction::SubChar('ţ')), // LATIN CAPITAL LETTER T WITH CEDILLA
+    ('\u{0164}', CharMapAction::SubChar('ť')), // LATIN CAPITAL LETTER T WITH CARON
+    ('\u{0166}', CharMapAction::SubChar('ŧ')), // LATIN CAPITAL LETTER T WITH STROKE
+    ('\u{0168}', CharMapAction::SubChar('ũ')), // LATIN CAPITAL LETTER U WITH TILDE
+    ('\u{016A}', CharMapAction::SubChar('ū')), // LATIN CAPITAL LETTER U WITH MACRON
+    ('\u{016C}', CharMapAction::SubChar('ŭ')), // LATIN CAPITAL LETTER U WITH BREVE
+    ('\u{016E}', CharMapAction::SubChar('ů')), // LATIN CAPITAL LETTER U WITH RING ABOVE
+    ('\u{0170}', CharMapAction::SubChar('ű')), // LATIN CAPITAL LETTER U WITH DOUBLE ACUTE
+    ('\u{0172}', CharMapAction::SubChar('ų')), // LATIN CAPITAL LETTER U WITH OGONEK
+    ('\u{0174}', CharMapAction::SubChar('ŵ')), // LATIN CAPITAL LETTER W WITH CIRCUMFLEX
+    ('\u{0176}', CharMapAction::SubChar('ŷ')), // LATIN CAPITAL LETTER Y WITH CIRCUMFLEX
+    ('\u{0178}', CharMapAction::SubChar('ÿ')), // LATIN CAPITAL LETTER Y WITH DIAERESIS
+    ('\u{0179}', CharMapAction::SubChar('ź')), // LATIN CAPITAL LETTER Z WITH ACUTE
+    ('\u{017B}', CharMapAction::SubChar('ż')), // LATIN CAPITAL LETTER Z WITH DOT ABOVE
+    ('\u{017D}', CharMapAction::SubChar('ž')), // LATIN CAPITAL LETTER Z WITH CARON
+    ('\u{017F}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER LONG S
+    ('\u{0181}', CharMapAction::SubChar('ɓ')), // LATIN CAPITAL LETTER B WITH HOOK
+    ('\u{0182}', CharMapAction::SubChar('ƃ')), // LATIN CAPITAL LETTER B WITH TOPBAR
+    ('\u{0184}', CharMapAction::SubChar('ƅ')), // LATIN CAPITAL LETTER TONE SIX
+    ('\u{0186}', CharMapAction::SubChar('ɔ')), // LATIN CAPITAL LETTER OPEN O
+    ('\u{0187}', CharMapAction::SubChar('ƈ')), // LATIN CAPITAL LETTER C WITH HOOK
+    ('\u{0189}', CharMapAction::SubChar('ɖ')), // LATIN CAPITAL LETTER AFRICAN D
+    ('\u{018A}', CharMapAction::SubChar('ɗ')), // LATIN CAPITAL LETTER D WITH HOOK
+    ('\u{018B}', CharMapAction::SubChar('ƌ')), // LATIN CAPITAL LETTER D WITH TOPBAR
+    ('\u{018E}', CharMapAction::SubChar('ǝ')), // LATIN CAPITAL LETTER REVERSED E
+    ('\u{018F}', CharMapAction::SubChar('ə')), // LATIN CAPITAL LETTER SCHWA
+    ('\u{0190}', CharMapAction::SubChar('ɛ')), // LATIN CAPITAL LETTER OPEN E
+    ('\u{0191}', CharMapAction::SubChar('ƒ')), // LATIN CAPITAL LETTER F WITH HOOK
+    ('\u{0193}', CharMapAction::SubChar('ɠ')), // LATIN CAPITAL LETTER G WITH HOOK
+    ('\u{0194}', CharMapAction::SubChar('ɣ')), // LATIN CAPITAL LETTER GAMMA
+    ('\u{0196}', CharMapAction::SubChar('ɩ')), // LATIN CAPITAL LETTER IOTA
+    ('\u{0197}', CharMapAction::SubChar('ɨ')), // LATIN CAPITAL LETTER I WITH STROKE
+    ('\u{0198}', CharMapAction::SubChar('ƙ')), // LATIN CAPITAL LETTER K WITH HOOK
+    ('\u{019C}', CharMapAction::SubChar('ɯ')), // LATIN CAPITAL LETTER TURNED M
+    ('\u{019D}', CharMapAction::SubChar('ɲ')), // LATIN CAPITAL LETTER N WITH LEFT HOOK
+    ('\u{019F}', CharMapAction::SubChar('ɵ')), // LATIN CAPITAL LETTER O WITH MIDDLE TILDE
+    ('\u{01A0}', CharMapAction::SubChar('ơ')), // LATIN CAPITAL LETTER O WITH HORN
+    ('\u{01A2}', CharMapAction::SubChar('ƣ')), // LATIN CAPITAL LETTER OI
+    ('\u{01A4}', CharMapAction::SubChar('ƥ')), // LATIN CAPITAL LETTER P WITH HOOK
+    ('\u{01A6}', CharMapAction::SubChar('ʀ')), // LATIN LETTER YR
+    ('\u{01A7}', CharMapAction::SubChar('ƨ')), // LATIN CAPITAL LETTER TONE TWO
+    ('\u{01A9}', CharMapAction::SubChar('ʃ')), // LATIN CAPITAL LETTER ESH
+    ('\u{01AC}', CharMapAction::SubChar('ƭ')), // LATIN CAPITAL LETTER T WITH HOOK
+    ('\u{01AE}', CharMapAction::SubChar('ʈ')), // LATIN CAPITAL LETTER T WITH RETROFLEX HOOK
+    ('\u{01AF}', CharMapAction::SubChar('ư')), // LATIN CAPITAL LETTER U WITH HORN
+    ('\u{01B1}', CharMapAction::SubChar('ʊ')), // LATIN CAPITAL LETTER UPSILON
+    ('\u{01B2}', CharMapAction::SubChar('ʋ')), // LATIN CAPITAL LETTER V WITH HOOK
+    ('\u{01B3}', CharMapAction::SubChar('ƴ')), // LATIN CAPITAL LETTER Y WITH HOOK
+    ('\u{01B5}', CharMapAction::SubChar('ƶ')), // LATIN CAPITAL LETTER Z WITH STROKE
+    ('\u{01B7}', CharMapAction::SubChar('ʒ')), // LATIN CAPITAL LETTER EZH
+    ('\u{01B8}', CharMapAction::SubChar('ƹ')), // LATIN CAPITAL LETTER EZH REVERSED
+    ('\u{01BC}', CharMapAction::SubChar('ƽ')), // LATIN CAPITAL LETTER TONE FIVE
+    ('\u{01C4}', CharMapAction::SubChar('ǆ')), // LATIN CAPITAL LETTER DZ WITH CARON
+    ('\u{01C5}', CharMapAction::SubChar('ǆ')), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON
+    ('\u{01C7}', CharMapAction::SubChar('ǉ')), // LATIN CAPITAL LETTER LJ
+    ('\u{01C8}', CharMapAction::SubChar('ǉ')), // LATIN CAPITAL LETTER L WITH SMALL LETTER J
+    ('\u{01CA}', CharMapAction::SubChar('ǌ')), // LATIN CAPITAL LETTER NJ
+    ('\u{01CB}', CharMapAction::SubChar('ǌ')), // LATIN CAPITAL LETTER N WITH SMALL LETTER J
+    ('\u{01CD}', CharMapAction::SubChar('ǎ')), // LATIN CAPITAL LETTER A WITH CARON
+    ('\u{01CF}', CharMapAction::SubChar('ǐ')), // LATIN CAPITAL LETTER I WITH CARON
+    ('\u{01D1}', CharMapAction::SubChar('ǒ')), // LATIN CAPITAL LETTER O WITH CARON
+    ('\u{01D3}', CharMapAction::SubChar('ǔ')), // LATIN CAPITAL LETTER U WITH CARON
+    ('\u{01D5}', CharMapAction::SubChar('ǖ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND MACRON
+    ('\u{01D7}', CharMapAction::SubChar('ǘ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND ACUTE
+    ('\u{01D9}', CharMapAction::SubChar('ǚ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND CARON
+    ('\u{01DB}', CharMapAction::SubChar('ǜ')), // LATIN CAPITAL LETTER U WITH DIAERESIS AND GRAVE
+    ('\u{01DE}', CharMapAction::SubChar('ǟ')), // LATIN CAPITAL LETTER A WITH DIAERESIS AND MACRON
+    ('\u{01E0}', CharMapAction::SubChar('ǡ')), // LATIN CAPITAL LETTER A WITH DOT ABOVE AND MACRON
+    ('\u{01E2}', CharMapAction::SubChar('ǣ')), // LATIN CAPITAL LETTER AE WITH MACRON
+    ('\u{01E4}', CharMapAction::SubChar('ǥ')), // LATIN CAPITAL LETTER G WITH STROKE
+    ('\u{01E6}', CharMapAction::SubChar('ǧ')), // LATIN CAPITAL LETTER G WITH CARON
+    ('\u{01E8}', CharMapAction::SubChar('ǩ')), // LATIN CAPITAL LETTER K WITH CARON
+    ('\u{01EA}', CharMapAction::SubChar('ǫ')), // LATIN CAPITAL LETTER O WITH OGONEK
+    ('\u{01EC}', CharMapAction::SubChar('ǭ')), // LATIN CAPITAL LETTER O WITH OGONEK AND MACRON
+    ('\u{01EE}', CharMapAction::SubChar('ǯ')), // LATIN CAPITAL LETTER EZH WITH CARON
+    ('\u{01F0}', CharMapAction::SubStr("j\u{030C}")), // LATIN SMALL LETTER J WITH CARON
+    ('\u{01F1}', CharMapAction::SubChar('ǳ')), // LATIN CAPITAL LETTER DZ
+    ('\u{01F2}', CharMapAction::SubChar('ǳ')), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z
+    ('\u{01F4}', CharMapAction::SubChar('ǵ')), // LATIN CAPITAL LETTER G WITH ACUTE
+    ('\u{01F6}', CharMapAction::SubChar('ƕ')), // LATIN CAPITAL LETTER HWAIR
+    ('\u{01F7}', CharMapAction::SubChar('ƿ')), // LATIN CAPITAL LETTER WYNN
+    ('\u{01F8}', CharMapAction::SubChar('ǹ')), // LATIN CAPITAL LETTER N WITH GRAVE
+    ('\u{01FA}', CharMapAction::SubChar('ǻ')), // LATIN CAPITAL LETTER A WITH RING ABOVE AND ACUTE
+    ('\u{01FC}', CharMapAction::SubChar('ǽ')), // LATIN CAPITAL LETTER AE WITH ACUTE
+    ('\u{01FE}', CharMapAction::SubChar('ǿ')), // LATIN CAPITAL LETTER O WITH STROKE AND ACUTE
+    ('\u{0200}', CharMapAction::SubChar('ȁ')), // LATIN CAPITAL LETTER A WITH DOUBLE GRAVE
+    ('\u{0202}', CharMapAction::SubChar('ȃ')), // LATIN CAPITAL LETTER A WITH INVERTED BREVE
+    ('\u{0204}', CharMapAction::SubChar('ȅ')), // LATIN CAPITAL LETTER E WITH DOUBLE GRAVE
+    ('\u{0206}', CharMapAction::SubChar('ȇ')), // LATIN CAPITAL LETTER E WITH INVERTED BREVE
+    ('\u{0208}', CharMapAction::SubChar('ȉ')), // LATIN CAPITAL LETTER I WITH DOUBLE GRAVE
+    ('\u{020A}', CharMapAction::SubChar('ȋ')), // LATIN CAPITAL LETTER I WITH INVERTED BREVE
+    ('\u{020C}', CharMapAction::SubChar('ȍ')), // LATIN CAPITAL LETTER O WITH DOUBLE GRAVE
+    ('\u{020E}', CharMapAction::SubChar('ȏ')), // LATIN CAPITAL LETTER O WITH INVERTED BREVE
+    ('\u{0210}', CharMapAction::SubChar('ȑ')), // LATIN CAPITAL LETTER R WITH DOUBLE GRAVE
+    ('\u{0212}', CharMapAction::SubChar('ȓ')), // LATIN CAPITAL LETTER R WITH INVERTED BREVE
+    ('\u{0214}', CharMapAction::SubChar('ȕ')), // LATIN CAPITAL LETTER U WITH DOUBLE GRAVE
+    ('\u{0216}', CharMapAction::SubChar('ȗ')), // LATIN CAPITAL LETTER U WITH INVERTED BREVE
+    ('\u{0218}', CharMapAction::SubChar('ș')), // LATIN CAPITAL LETTER S WITH COMMA BELOW
+    ('\u{021A}', CharMapAction::SubChar('ț')), // LATIN CAPITAL LETTER T WITH COMMA BELOW
+    ('\u{021C}', CharMapAction::SubChar('ȝ')), // LATIN CAPITAL LETTER YOGH
+    ('\u{021E}', CharMapAction::SubChar('ȟ')), // LATIN CAPITAL LETTER H WITH CARON
+    ('\u{0220}', CharMapAction::SubChar('ƞ')), // LATIN CAPITAL LETTER N WITH LONG RIGHT LEG
+    ('\u{0222}', CharMapAction::SubChar('ȣ')), // LATIN CAPITAL LETTER OU
+    ('\u{0224}', CharMapAction::SubChar('ȥ')), // LATIN CAPITAL LETTER Z WITH HOOK
+    ('\u{0226}', CharMapAction::SubChar('ȧ')), // LATIN CAPITAL LETTER A WITH DOT ABOVE
+    ('\u{0228}', CharMapAction::SubChar('ȩ')), // LATIN CAPITAL LETTER E WITH CEDILLA
+    ('\u{022A}', CharMapAction::SubChar('ȫ')), // LATIN CAPITAL LETTER O WITH DIAERESIS AND MACRON
+    ('\u{022C}', CharMapAction::SubChar('ȭ')), // LATIN CAPITAL LETTER O WITH TILDE AND MACRON
+    ('\u{022E}', CharMapAction::SubChar('ȯ')), // LATIN CAPITAL LETTER O WITH DOT ABOVE
+    ('\u{0230}', CharMapAction::SubChar('ȱ')), // LATIN CAPITAL LETTER O WITH DOT ABOVE AND MACRON
+    ('\u{0232}', CharMapAction::SubChar('ȳ')), // LATIN CAPITAL LETTER Y WITH MACRON
+    ('\u{023A}', CharMapAction::SubChar('ⱥ')), // LATIN CAPITAL LETTER A WITH STROKE
+    ('\u{023B}', CharMapAction::SubChar('ȼ')), // LATIN CAPITAL LETTER C WITH STROKE
+    ('\u{023D}', CharMapAction::SubChar('ƚ')), // LATIN CAPITAL LETTER L WITH BAR
+    ('\u{023E}', CharMapAction::SubChar('ⱦ')), // LATIN CAPITAL LETTER T WITH DIAGONAL STROKE
+    ('\u{0241}', CharMapAction::SubChar('ɂ')), // LATIN CAPITAL LETTER GLOTTAL STOP
+    ('\u{0243}', CharMapAction::SubChar('ƀ')), // LATIN CAPITAL LETTER B WITH STROKE
+    ('\u{0244}', CharMapAction::SubChar('ʉ')), // LATIN CAPITAL LETTER U BAR
+    ('\u{0245}', CharMapAction::SubChar('ʌ')), // LATIN CAPITAL LETTER TURNED V
+    ('\u{0246}', CharMapAction::SubChar('ɇ')), // LATIN CAPITAL LETTER E WITH STROKE
+    ('\u{0248}', CharMapAction::SubChar('ɉ')), // LATIN CAPITAL LETTER J WITH STROKE
+    ('\u{024A}', CharMapAction::SubChar('ɋ')), // LATIN CAPITAL LETTER SMALL Q WITH HOOK TAIL
+    ('\u{024C}', CharMapAction::SubChar('ɍ')), // LATIN CAPITAL LETTER R WITH STROKE
+    ('\u{024E}', CharMapAction::SubChar('ɏ')), // LATIN CAPITAL LETTER Y WITH STROKE
+    ('\u{0345}', CharMapAction::SubChar('ι')), // COMBINING GREEK YPOGEGRAMMENI
+    ('\u{0370}', CharMapAction::SubChar('ͱ')), // GREEK CAPITAL LETTER HETA
+    ('\u{0372}', CharMapAction::SubChar('ͳ')), // GREEK CAPITAL LETTER ARCHAIC SAMPI
+    ('\u{0376}', CharMapAction::SubChar('ͷ')), // GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA
+    ('\u{037F}', CharMapAction::SubChar('ϳ')), // GREEK CAPITAL LETTER YOT
+    ('\u{0386}', CharMapAction::SubChar('ά')), // GREEK CAPITAL LETTER ALPHA WITH TONOS
+    ('\u{0388}', CharMapAction::SubChar('έ')), // GREEK CAPITAL LETTER EPSILON WITH TONOS
+    ('\u{0389}', CharMapAction::SubChar('ή')), // GREEK CAPITAL LETTER ETA WITH TONOS
+    ('\u{038A}', CharMapAction::SubChar('ί')), // GREEK CAPITAL LETTER IOTA WITH TONOS
+    ('\u{038C}', CharMapAction::SubChar('ό')), // GREEK CAPITAL LETTER OMICRON WITH TONOS
+    ('\u{038E}', CharMapAction::SubChar('ύ')), // GREEK CAPITAL LETTER UPSILON WITH TONOS
+    ('\u{038F}', CharMapAction::SubChar('ώ')), // GREEK CAPITAL LETTER OMEGA WITH TONOS
+    ('\u{0390}', CharMapAction::SubStr("ι\u{0308}\u{0301}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND TONOS
+    ('\u{0391}', CharMapAction::SubChar('α')), // GREEK CAPITAL LETTER ALPHA
+    ('\u{0392}', CharMapAction::SubChar('β')), // GREEK CAPITAL LETTER BETA
+    ('\u{0393}', CharMapAction::SubChar('γ')), // GREEK CAPITAL LETTER GAMMA
+    ('\u{0394}', CharMapAction::SubChar('δ')), // GREEK CAPITAL LETTER DELTA
+    ('\u{0395}', CharMapAction::SubChar('ε')), // GREEK CAPITAL LETTER EPSILON
+    ('\u{0396}', CharMapAction::SubChar('ζ')), // GREEK CAPITAL LETTER ZETA
+    ('\u{0397}', CharMapAction::SubChar('η')), // GREEK CAPITAL LETTER ETA
+    ('\u{0398}', CharMapAction::SubChar('θ')), // GREEK CAPITAL LETTER THETA
+    ('\u{0399}', CharMapAction::SubChar('ι')), // GREEK CAPITAL LETTER IOTA
+    ('\u{039A}', CharMapAction::SubChar('κ')), // GREEK CAPITAL LETTER KAPPA
+    ('\u{039B}', CharMapAction::SubChar('λ')), // GREEK CAPITAL LETTER LAMDA
+    ('\u{039C}', CharMapAction::SubChar('μ')), // GREEK CAPITAL LETTER MU
+    ('\u{039D}', CharMapAction::SubChar('ν')), // GREEK CAPITAL LETTER NU
+    ('\u{039E}', CharMapAction::SubChar('ξ')), // GREEK CAPITAL LETTER XI
+    ('\u{039F}', CharMapAction::SubChar('ο')), // GREEK CAPITAL LETTER OMICRON
+    ('\u{03A0}', CharMapAction::SubChar('π')), // GREEK CAPITAL LETTER PI
+    ('\u{03A1}', CharMapAction::SubChar('ρ')), // GREEK CAPITAL LETTER RHO
+    ('\u{03A3}', CharMapAction::SubChar('σ')), // GREEK CAPITAL LETTER SIGMA
+    ('\u{03A4}', CharMapAction::SubChar('τ')), // GREEK CAPITAL LETTER TAU
+    ('\u{03A5}', CharMapAction::SubChar('υ')), // GREEK CAPITAL LETTER UPSILON
+    ('\u{03A6}', CharMapAction::SubChar('φ')), // GREEK CAPITAL LETTER PHI
+    ('\u{03A7}', CharMapAction::SubChar('χ')), // GREEK CAPITAL LETTER CHI
+    ('\u{03A8}', CharMapAction::SubChar('ψ')), // GREEK CAPITAL LETTER PSI
+    ('\u{03A9}', CharMapAction::SubChar('ω')), // GREEK CAPITAL LETTER OMEGA
+    ('\u{03AA}', CharMapAction::SubChar('ϊ')), // GREEK CAPITAL LETTER IOTA WITH DIALYTIKA
+    ('\u{03AB}', CharMapAction::SubChar('ϋ')), // GREEK CAPITAL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03B0}', CharMapAction::SubStr("υ\u{0308}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS
+    ('\u{03C2}', CharMapAction::SubChar('σ')), // GREEK SMALL LETTER FINAL SIGMA
+    ('\u{03CF}', CharMapAction::SubChar('ϗ')), // GREEK CAPITAL KAI SYMBOL
+    ('\u{03D0}', CharMapAction::SubChar('β')), // GREEK BETA SYMBOL
+    ('\u{03D1}', CharMapAction::SubChar('θ')), // GREEK THETA SYMBOL
+    ('\u{03D5}', CharMapAction::SubChar('φ')), // GREEK PHI SYMBOL
+    ('\u{03D6}', CharMapAction::SubChar('π')), // GREEK PI SYMBOL
+    ('\u{03D8}', CharMapAction::SubChar('ϙ')), // GREEK LETTER ARCHAIC KOPPA
+    ('\u{03DA}', CharMapAction::SubChar('ϛ')), // GREEK LETTER STIGMA
+    ('\u{03DC}', CharMapAction::SubChar('ϝ')), // GREEK LETTER DIGAMMA
+    ('\u{03DE}', CharMapAction::SubChar('ϟ')), // GREEK LETTER KOPPA
+    ('\u{03E0}', CharMapAction::SubChar('ϡ')), // GREEK LETTER SAMPI
+    ('\u{03E2}', CharMapAction::SubChar('ϣ')), // COPTIC CAPITAL LETTER SHEI
+    ('\u{03E4}', CharMapAction::SubChar('ϥ')), // COPTIC CAPITAL LETTER FEI
+    ('\u{03E6}', CharMapAction::SubChar('ϧ')), // COPTIC CAPITAL LETTER KHEI
+    ('\u{03E8}', CharMapAction::SubChar('ϩ')), // COPTIC CAPITAL LETTER HORI
+    ('\u{03EA}', CharMapAction::SubChar('ϫ')), // COPTIC CAPITAL LETTER GANGIA
+    ('\u{03EC}', CharMapAction::SubChar('ϭ')), // COPTIC CAPITAL LETTER SHIMA
+    ('\u{03EE}', CharMapAction::SubChar('ϯ')), // COPTIC CAPITAL LETTER DEI
+    ('\u{03F0}', CharMapAction::SubChar('κ')), // GREEK KAPPA SYMBOL
+    ('\u{03F1}', CharMapAction::SubChar('ρ')), // GREEK RHO SYMBOL
+    ('\u{03F4}', CharMapAction::SubChar('θ')), // GREEK CAPITAL THETA SYMBOL
+    ('\u{03F5}', CharMapAction::SubChar('ε')), // GREEK LUNATE EPSILON SYMBOL
+    ('\u{03F7}', CharMapAction::SubChar('ϸ')), // GREEK CAPITAL LETTER SHO
+    ('\u{03F9}', CharMapAction::SubChar('ϲ')), // GREEK CAPITAL LUNATE SIGMA SYMBOL
+    ('\u{03FA}', CharMapAction::SubChar('ϻ')), // GREEK CAPITAL LETTER SAN
+    ('\u{03FD}', CharMapAction::SubChar('ͻ')), // GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL
+    ('\u{03FE}', CharMapAction::SubChar('ͼ')), // GREEK CAPITAL DOTTED LUNATE SIGMA SYMBOL
+    ('\u{03FF}', CharMapAction::SubChar('ͽ')), // GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL
+    ('\u{0400}', CharMapAction::SubChar('ѐ')), // CYRILLIC CAPITAL LETTER IE WITH GRAVE
+    ('\u{0401}', CharMapAction::SubChar('ё')), // CYRILLIC CAPITAL LETTER IO
+    ('\u{0402}', CharMapAction::SubChar('ђ')), // CYRILLIC CAPITAL LETTER DJE
+    ('\u{0403}', CharMapAction::SubChar('ѓ')), // CYRILLIC CAPITAL LETTER GJE
+    ('\u{0404}', CharMapAction::SubChar('є')), // CYRILLIC CAPITAL LETTER UKRAINIAN IE
+    ('\u{0405}', CharMapAction::SubChar('ѕ')), // CYRILLIC CAPITAL LETTER DZE
+    ('\u{0406}', CharMapAction::SubChar('і')), // CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0407}', CharMapAction::SubChar('ї')), // CYRILLIC CAPITAL LETTER YI
+    ('\u{0408}', CharMapAction::SubChar('ј')), // CYRILLIC CAPITAL LETTER JE
+    ('\u{0409}', CharMapAction::SubChar('љ')), // CYRILLIC CAPITAL LETTER LJE
+    ('\u{040A}', CharMapAction::SubChar('њ')), // CYRILLIC CAPITAL LETTER NJE
+    ('\u{040B}', CharMapAction::SubChar('ћ')), // CYRILLIC CAPITAL LETTER TSHE
+    ('\u{040C}', CharMapAction::SubChar('ќ')), // CYRILLIC CAPITAL LETTER KJE
+    ('\u{040D}', CharMapAction::SubChar('ѝ')), // CYRILLIC CAPITAL LETTER I WITH GRAVE
+    ('\u{040E}', CharMapAction::SubChar('ў')), // CYRILLIC CAPITAL LETTER SHORT U
+    ('\u{040F}', CharMapAction::SubChar('џ')), // CYRILLIC CAPITAL LETTER DZHE
+    ('\u{0410}', CharMapAction::SubChar('а')), // CYRILLIC CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('б')), // CYRILLIC CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('в')), // CYRILLIC CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('г')), // CYRILLIC CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('д')), // CYRILLIC CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('е')), // CYRILLIC CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubChar('ж')), // CYRILLIC CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('з')), // CYRILLIC CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('и')), // CYRILLIC CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('й')), // CYRILLIC CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('к')), // CYRILLIC CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('л')), // CYRILLIC CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('м')), // CYRILLIC CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('н')), // CYRILLIC CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('о')), // CYRILLIC CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('п')), // CYRILLIC CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('р')), // CYRILLIC CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('с')), // CYRILLIC CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('т')), // CYRILLIC CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('у')), // CYRILLIC CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('ф')), // CYRILLIC CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('х')), // CYRILLIC CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubChar('ц')), // CYRILLIC CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubChar('ч')), // CYRILLIC CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubChar('ш')), // CYRILLIC CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubChar('щ')), // CYRILLIC CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubChar('ъ')), // CYRILLIC CAPITAL LETTER HARD SIGN
+    ('\u{042B}', CharMapAction::SubChar('ы')), // CYRILLIC CAPITAL LETTER YERU
+    ('\u{042C}', CharMapAction::SubChar('ь')), // CYRILLIC CAPITAL LETTER SOFT SIGN
+    ('\u{042D}', CharMapAction::SubChar('э')), // CYRILLIC CAPITAL LETTER E
+    ('\u{042E}', CharMapAction::SubChar('ю')), // CYRILLIC CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubChar('я')), // CYRILLIC CAPITAL LETTER YA
+    ('\u{0460}', CharMapAction::SubChar('ѡ')), // CYRILLIC CAPITAL LETTER OMEGA
+    ('\u{0462}', CharMapAction::SubChar('ѣ')), // CYRILLIC CAPITAL LETTER YAT
+    ('\u{0464}', CharMapAction::SubChar('ѥ')), // CYRILLIC CAPITAL LETTER IOTIFIED E
+    ('\u{0466}', CharMapAction::SubChar('ѧ')), // CYRILLIC CAPITAL LETTER LITTLE YUS
+    ('\u{0468}', CharMapAction::SubChar('ѩ')), // CYRILLIC CAPITAL LETTER IOTIFIED LITTLE YUS
+    ('\u{046A}', CharMapAction::SubChar('ѫ')), // CYRILLIC CAPITAL LETTER BIG YUS
+    ('\u{046C}', CharMapAction::SubChar('ѭ')), // CYRILLIC CAPITAL LETTER IOTIFIED BIG YUS
+    ('\u{046E}', CharMapAction::SubChar('ѯ')), // CYRILLIC CAPITAL LETTER KSI
+    ('\u{0470}', CharMapAction::SubChar('ѱ')), // CYRILLIC CAPITAL LETTER PSI
+    ('\u{0472}', CharMapAction::SubChar('ѳ')), // CYRILLIC CAPITAL LETTER FITA
+    ('\u{0474}', CharMapAction::SubChar('ѵ')), // CYRILLIC CAPITAL LETTER IZHITSA
+    ('\u{0476}', CharMapAction::SubChar('ѷ')), // CYRILLIC CAPITAL LETTER IZHITSA WITH DOUBLE GRAVE ACCENT
+    ('\u{0478}', CharMapAction::SubChar('ѹ')), // CYRILLIC CAPITAL LETTER UK
+    ('\u{047A}', CharMapAction::SubChar('ѻ')), // CYRILLIC CAPITAL LETTER ROUND OMEGA
+    ('\u{047C}', CharMapAction::SubChar('ѽ')), // CYRILLIC CAPITAL LETTER OMEGA WITH TITLO
+    ('\u{047E}', CharMapAction::SubChar('ѿ')), // CYRILLIC CAPITAL LETTER OT
+    ('\u{0480}', CharMapAction::SubChar('ҁ')), // CYRILLIC CAPITAL LETTER KOPPA
+    ('\u{048A}', CharMapAction::SubChar('ҋ')), // CYRILLIC CAPITAL LETTER SHORT I WITH TAIL
+    ('\u{048C}', CharMapAction::SubChar('ҍ')), // CYRILLIC CAPITAL LETTER SEMISOFT SIGN
+    ('\u{048E}', CharMapAction::SubChar('ҏ')), // CYRILLIC CAPITAL LETTER ER WITH TICK
+    ('\u{0490}', CharMapAction::SubChar('ґ')), // CYRILLIC CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0492}', CharMapAction::SubChar('ғ')), // CYRILLIC CAPITAL LETTER GHE WITH STROKE
+    ('\u{0494}', CharMapAction::SubChar('ҕ')), // CYRILLIC CAPITAL LETTER GHE WITH MIDDLE HOOK
+    ('\u{0496}', CharMapAction::SubChar('җ')), // CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER
+    ('\u{0498}', CharMapAction::SubChar('ҙ')), // CYRILLIC CAPITAL LETTER ZE WITH DESCENDER
+    ('\u{049A}', CharMapAction::SubChar('қ')), // CYRILLIC CAPITAL LETTER KA WITH DESCENDER
+    ('\u{049C}', CharMapAction::SubChar('ҝ')), // CYRILLIC CAPITAL LETTER KA WITH VERTICAL STROKE
+    ('\u{049E}', CharMapAction::SubChar('ҟ')), // CYRILLIC CAPITAL LETTER KA WITH STROKE
+    ('\u{04A0}', CharMapAction::SubChar('ҡ')), // CYRILLIC CAPITAL LETTER BASHKIR KA
+    ('\u{04A2}', CharMapAction::SubChar('ң')), // CYRILLIC CAPITAL LETTER EN WITH DESCENDER
+    ('\u{04A4}', CharMapAction::SubChar('ҥ')), // CYRILLIC CAPITAL LIGATURE EN GHE
+    ('\u{04A6}', CharMapAction::SubChar('ҧ')), // CYRILLIC CAPITAL LETTER PE WITH MIDDLE HOOK
+    ('\u{04A8}', CharMapAction::SubChar('ҩ')), // CYRILLIC CAPITAL LETTER ABKHASIAN HA
+    ('\u{04AA}', CharMapAction::SubChar('ҫ')), // CYRILLIC CAPITAL LETTER ES WITH DESCENDER
+    ('\u{04AC}', CharMapAction::SubChar('ҭ')), // CYRILLIC CAPITAL LETTER TE WITH DESCENDER
+    ('\u{04AE}', CharMapAction::SubChar('ү')), // CYRILLIC CAPITAL LETTER STRAIGHT U
+    ('\u{04B0}', CharMapAction::SubChar('ұ')), // CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B2}', CharMapAction::SubChar('ҳ')), // CYRILLIC CAPITAL LETTER HA WITH DESCENDER
+    ('\u{04B4}', CharMapAction::SubChar('ҵ')), // CYRILLIC CAPITAL LIGATURE TE TSE
+    ('\u{04B6}', CharMapAction::SubChar('ҷ')), // CYRILLIC CAPITAL LETTER CHE WITH DESCENDER
+    ('\u{04B8}', CharMapAction::SubChar('ҹ')), // CYRILLIC CAPITAL LETTER CHE WITH VERTICAL STROKE
+    ('\u{04BA}', CharMapAction::SubChar('һ')), // CYRILLIC CAPITAL LETTER SHHA
+    ('\u{04BC}', CharMapAction::SubChar('ҽ')), // CYRILLIC CAPITAL LETTER ABKHASIAN CHE
+    ('\u{04BE}', CharMapAction::SubChar('ҿ')), // CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER
+    ('\u{04C0}', CharMapAction::SubChar('ӏ')), // CYRILLIC LETTER PALOCHKA
+    ('\u{04C1}', CharMapAction::SubChar('ӂ')), // CYRILLIC CAPITAL LETTER ZHE WITH BREVE
+    ('\u{04C3}', CharMapAction::SubChar('ӄ')), // CYRILLIC CAPITAL LETTER KA WITH HOOK
+    ('\u{04C5}', CharMapAction::SubChar('ӆ')), // CYRILLIC CAPITAL LETTER EL WITH TAIL
+    ('\u{04C7}', CharMapAction::SubChar('ӈ')), // CYRILLIC CAPITAL LETTER EN WITH HOOK
+    ('\u{04C9}', CharMapAction::SubChar('ӊ')), // CYRILLIC CAPITAL LETTER EN WITH TAIL
+    ('\u{04CB}', CharMapAction::SubChar('ӌ')), // CYRILLIC CAPITAL LETTER KHAKASSIAN CHE
+    ('\u{04CD}', CharMapAction::SubChar('ӎ')), // CYRILLIC CAPITAL LETTER EM WITH TAIL
+    ('\u{04D0}', CharMapAction::SubChar('ӑ')), // CYRILLIC CAPITAL LETTER A WITH BREVE
+    ('\u{04D2}', CharMapAction::SubChar('ӓ')), // CYRILLIC CAPITAL LETTER A WITH DIAERESIS
+    ('\u{04D4}', CharMapAction::SubChar('ӕ')), // CYRILLIC CAPITAL LIGATURE A IE
+    ('\u{04D6}', CharMapAction::SubChar('ӗ')), // CYRILLIC CAPITAL LETTER IE WITH BREVE
+    ('\u{04D8}', CharMapAction::SubChar('ә')), // CYRILLIC CAPITAL LETTER SCHWA
+    ('\u{04DA}', CharMapAction::SubChar('ӛ')), // CYRILLIC CAPITAL LETTER SCHWA WITH DIAERESIS
+    ('\u{04DC}', CharMapAction::SubChar('ӝ')), // CYRILLIC CAPITAL LETTER ZHE WITH DIAERESIS
+    ('\u{04DE}', CharMapAction::SubChar('ӟ')), // CYRILLIC CAPITAL LETTER ZE WITH DIAERESIS
+    ('\u{04E0}', CharMapAction::SubChar('ӡ')), // CYRILLIC CAPITAL LETTER ABKHASIAN DZE
+    ('\u{04E2}', CharMapAction::SubChar('ӣ')), // CYRILLIC CAPITAL LETTER I WITH MACRON
+    ('\u{04E4}', CharMapAction::SubChar('ӥ')), // CYRILLIC CAPITAL LETTER I WITH DIAERESIS
+    ('\u{04E6}', CharMapAction::SubChar('ӧ')), // CYRILLIC CAPITAL LETTER O WITH DIAERESIS
+    ('\u{04E8}', CharMapAction::SubChar('ө')), // CYRILLIC CAPITAL LETTER BARRED O
+    ('\u{04EA}', CharMapAction::SubChar('ӫ')), // CYRILLIC CAPITAL LETTER BARRED O WITH DIAERESIS
+    ('\u{04EC}', CharMapAction::SubChar('ӭ')), // CYRILLIC CAPITAL LETTER E WITH DIAERESIS
+    ('\u{04EE}', CharMapAction::SubChar('ӯ')), // CYRILLIC CAPITAL LETTER U WITH MACRON
+    ('\u{04F0}', CharMapAction::SubChar('ӱ')), // CYRILLIC CAPITAL LETTER U WITH DIAERESIS
+    ('\u{04F2}', CharMapAction::SubChar('ӳ')), // CYRILLIC CAPITAL LETTER U WITH DOUBLE ACUTE
+    ('\u{04F4}', CharMapAction::SubChar('ӵ')), // CYRILLIC CAPITAL LETTER CHE WITH DIAERESIS
+    ('\u{04F6}', CharMapAction::SubChar('ӷ')), // CYRILLIC CAPITAL LETTER GHE WITH DESCENDER
+    ('\u{04F8}', CharMapAction::SubChar('ӹ')), // CYRILLIC CAPITAL LETTER YERU WITH DIAERESIS
+    ('\u{04FA}', CharMapAction::SubChar('ӻ')), // CYRILLIC CAPITAL LETTER GHE WITH STROKE AND HOOK
+    ('\u{04FC}', CharMapAction::SubChar('ӽ')), // CYRILLIC CAPITAL LETTER HA WITH HOOK
+    ('\u{04FE}', CharMapAction::SubChar('ӿ')), // CYRILLIC CAPITAL LETTER HA WITH STROKE
+    ('\u{0500}', CharMapAction::SubChar('ԁ')), // CYRILLIC CAPITAL LETTER KOMI DE
+    ('\u{0502}', CharMapAction::SubChar('ԃ')), // CYRILLIC CAPITAL LETTER KOMI DJE
+    ('\u{0504}', CharMapAction::SubChar('ԅ')), // CYRILLIC CAPITAL LETTER KOMI ZJE
+    ('\u{0506}', CharMapAction::SubChar('ԇ')), // CYRILLIC CAPITAL LETTER KOMI DZJE
+    ('\u{0508}', CharMapAction::SubChar('ԉ')), // CYRILLIC CAPITAL LETTER KOMI LJE
+    ('\u{050A}', CharMapAction::SubChar('ԋ')), // CYRILLIC CAPITAL LETTER KOMI NJE
+    ('\u{050C}', CharMapAction::SubChar('ԍ')), // CYRILLIC CAPITAL LETTER KOMI SJE
+    ('\u{050E}', CharMapAction::SubChar('ԏ')), // CYRILLIC CAPITAL LETTER KOMI TJE
+    ('\u{0510}', CharMapAction::SubChar('ԑ')), // CYRILLIC CAPITAL LETTER REVERSED ZE
+    ('\u{0512}', CharMapAction::SubChar('ԓ')), // CYRILLIC CAPITAL LETTER EL WITH HOOK
+    ('\u{0514}', CharMapAction::SubChar('ԕ')), // CYRILLIC CAPITAL LETTER LHA
+    ('\u{0516}', CharMapAction::SubChar('ԗ')), // CYRILLIC CAPITAL LETTER RHA
+    ('\u{0518}', CharMapAction::SubChar('ԙ')), // CYRILLIC CAPITAL LETTER YAE
+    ('\u{051A}', CharMapAction::SubChar('ԛ')), // CYRILLIC CAPITAL LETTER QA
+    ('\u{051C}', CharMapAction::SubChar('ԝ')), // CYRILLIC CAPITAL LETTER WE
+    ('\u{051E}', CharMapAction::SubChar('ԟ')), // CYRILLIC CAPITAL LETTER ALEUT KA
+    ('\u{0520}', CharMapAction::SubChar('ԡ')), // CYRILLIC CAPITAL LETTER EL WITH MIDDLE HOOK
+    ('\u{0522}', CharMapAction::SubChar('ԣ')), // CYRILLIC CAPITAL LETTER EN WITH MIDDLE HOOK
+    ('\u{0524}', CharMapAction::SubChar('ԥ')), // CYRILLIC CAPITAL LETTER PE WITH DESCENDER
+    ('\u{0526}', CharMapAction::SubChar('ԧ')), // CYRILLIC CAPITAL LETTER SHHA WITH DESCENDER
+    ('\u{0528}', CharMapAction::SubChar('ԩ')), // CYRILLIC CAPITAL LETTER EN WITH LEFT HOOK
+    ('\u{052A}', CharMapAction::SubChar('ԫ')), // CYRILLIC CAPITAL LETTER DZZHE
+    ('\u{052C}', CharMapAction::SubChar('ԭ')), // CYRILLIC CAPITAL LETTER DCHE
+    ('\u{052E}', CharMapAction::SubChar('ԯ')), // CYRILLIC CAPITAL LETTER EL WITH DESCENDER
+    ('\u{0531}', CharMapAction::SubChar('ա')), // ARMENIAN CAPITAL LETTER AYB
+    ('\u{0532}', CharMapAction::SubChar('բ')), // ARMENIAN CAPITAL LETTER BEN
+    ('\u{0533}', CharMapAction::SubChar('գ')), // ARMENIAN CAPITAL LETTER GIM
+    ('\u{0534}', CharMapAction::SubChar('դ')), // ARMENIAN CAPITAL LETTER DA
+    ('\u{0535}', CharMapAction::SubChar('ե')), // ARMENIAN CAPITAL LETTER ECH
+    ('\u{0536}', CharMapAction::SubChar('զ')), // ARMENIAN CAPITAL LETTER ZA
+    ('\u{0537}', CharMapAction::SubChar('է')), // ARMENIAN CAPITAL LETTER EH
+    ('\u{0538}', CharMapAction::SubChar('ը')), // ARMENIAN CAPITAL LETTER ET
+    ('\u{0539}', CharMapAction::SubChar('թ')), // ARMENIAN CAPITAL LETTER TO
+    ('\u{053A}', CharMapAction::SubChar('ժ')), // ARMENIAN CAPITAL LETTER ZHE
+    ('\u{053B}', CharMapAction::SubChar('ի')), // ARMENIAN CAPITAL LETTER INI
+    ('\u{053C}', CharMapAction::SubChar('լ')), // ARMENIAN CAPITAL LETTER LIWN
+    ('\u{053D}', CharMapAction::SubChar('խ')), // ARMENIAN CAPITAL LETTER XEH
+    ('\u{053E}', CharMapAction::SubChar('ծ')), // ARMENIAN CAPITAL LETTER CA
+    ('\u{053F}', CharMapAction::SubChar('կ')), // ARMENIAN CAPITAL LETTER KEN
+    ('\u{0540}', CharMapAction::SubChar('հ')), // ARMENIAN CAPITAL LETTER HO
+    ('\u{0541}', CharMapAction::SubChar('ձ')), // ARMENIAN CAPITAL LETTER JA
+    ('\u{0542}', CharMapAction::SubChar('ղ')), // ARMENIAN CAPITAL LETTER GHAD
+    ('\u{0543}', CharMapAction::SubChar('ճ')), // ARMENIAN CAPITAL LETTER CHEH
+    ('\u{0544}', CharMapAction::SubChar('մ')), // ARMENIAN CAPITAL LETTER MEN
+    ('\u{0545}', CharMapAction::SubChar('յ')), // ARMENIAN CAPITAL LETTER YI
+    ('\u{0546}', CharMapAction::SubChar('ն')), // ARMENIAN CAPITAL LETTER NOW
+    ('\u{0547}', CharMapAction::SubChar('շ')), // ARMENIAN CAPITAL LETTER SHA
+    ('\u{0548}', CharMapAction::SubChar('ո')), // ARMENIAN CAPITAL LETTER VO
+    ('\u{0549}', CharMapAction::SubChar('չ')), // ARMENIAN CAPITAL LETTER CHA
+    ('\u{054A}', CharMapAction::SubChar('պ')), // ARMENIAN CAPITAL LETTER PEH
+    ('\u{054B}', CharMapAction::SubChar('ջ')), // ARMENIAN CAPITAL LETTER JHEH
+    ('\u{054C}', CharMapAction::SubChar('ռ')), // ARMENIAN CAPITAL LETTER RA
+    ('\u{054D}', CharMapAction::SubChar('ս')), // ARMENIAN CAPITAL LETTER SEH
+    ('\u{054E}', CharMapAction::SubChar('վ')), // ARMENIAN CAPITAL LETTER VEW
+    ('\u{054F}', CharMapAction::SubChar('տ')), // ARMENIAN CAPITAL LETTER TIWN
+    ('\u{0550}', CharMapAction::SubChar('ր')), // ARMENIAN CAPITAL LETTER REH
+    ('\u{0551}', CharMapAction::SubChar('ց')), // ARMENIAN CAPITAL LETTER CO
+    ('\u{0552}', CharMapAction::SubChar('ւ')), // ARMENIAN CAPITAL LETTER YIWN
+    ('\u{0553}', CharMapAction::SubChar('փ')), // ARMENIAN CAPITAL LETTER PIWR
+    ('\u{0554}', CharMapAction::SubChar('ք')), // ARMENIAN CAPITAL LETTER KEH
+    ('\u{0555}', CharMapAction::SubChar('օ')), // ARMENIAN CAPITAL LETTER OH
+    ('\u{0556}', CharMapAction::SubChar('ֆ')), // ARMENIAN CAPITAL LETTER FEH
+    ('\u{0587}', CharMapAction::SubStr("եւ")), // ARMENIAN SMALL LIGATURE ECH YIWN
+    ('\u{10A0}', CharMapAction::SubChar('ⴀ')), // GEORGIAN CAPITAL LETTER AN
+    ('\u{10A1}', CharMapAction::SubChar('ⴁ')), // GEORGIAN CAPITAL LETTER BAN
+    ('\u{10A2}', CharMapAction::SubChar('ⴂ')), // GEORGIAN CAPITAL LETTER GAN
+    ('\u{10A3}', CharMapAction::SubChar('ⴃ')), // GEORGIAN CAPITAL LETTER DON
+    ('\u{10A4}', CharMapAction::SubChar('ⴄ')), // GEORGIAN CAPITAL LETTER EN
+    ('\u{10A5}', CharMapAction::SubChar('ⴅ')), // GEORGIAN CAPITAL LETTER VIN
+    ('\u{10A6}', CharMapAction::SubChar('ⴆ')), // GEORGIAN CAPITAL LETTER ZEN
+    ('\u{10A7}', CharMapAction::SubChar('ⴇ')), // GEORGIAN CAPITAL LETTER TAN
+    ('\u{10A8}', CharMapAction::SubChar('ⴈ')), // GEORGIAN CAPITAL LETTER IN
+    ('\u{10A9}', CharMapAction::SubChar('ⴉ')), // GEORGIAN CAPITAL LETTER KAN
+    ('\u{10AA}', CharMapAction::SubChar('ⴊ')), // GEORGIAN CAPITAL LETTER LAS
+    ('\u{10AB}', CharMapAction::SubChar('ⴋ')), // GEORGIAN CAPITAL LETTER MAN
+    ('\u{10AC}', CharMapAction::SubChar('ⴌ')), // GEORGIAN CAPITAL LETTER NAR
+    ('\u{10AD}', CharMapAction::SubChar('ⴍ')), // GEORGIAN CAPITAL LETTER ON
+    ('\u{10AE}', CharMapAction::SubChar('ⴎ')), // GEORGIAN CAPITAL LETTER PAR
+    ('\u{10AF}', CharMapAction::SubChar('ⴏ')), // GEORGIAN CAPITAL LETTER ZHAR
+    ('\u{10B0}', CharMapAction::SubChar('ⴐ')), // GEORGIAN CAPITAL LETTER RAE
+    ('\u{10B1}', CharMapAction::SubChar('ⴑ')), // GEORGIAN CAPITAL LETTER SAN
+    ('\u{10B2}', CharMapAction::SubChar('ⴒ')), // GEORGIAN CAPITAL LETTER TAR
+    ('\u{10B3}', CharMapAction::SubChar('ⴓ')), // GEORGIAN CAPITAL LETTER UN
+    ('\u{10B4}', CharMapAction::SubChar('ⴔ')), // GEORGIAN CAPITAL LETTER PHAR
+    ('\u{10B5}', CharMapAction::SubChar('ⴕ')), // GEORGIAN CAPITAL LETTER KHAR
+    ('\u{10B6}', CharMapAction::SubChar('ⴖ')), // GEORGIAN CAPITAL LETTER GHAN
+    ('\u{10B7}', CharMapAction::SubChar('ⴗ')), // GEORGIAN CAPITAL LETTER QAR
+    ('\u{10B8}', CharMapAction::SubChar('ⴘ')), // GEORGIAN CAPITAL LETTER SHIN
+    ('\u{10B9}', CharMapAction::SubChar('ⴙ')), // GEORGIAN CAPITAL LETTER CHIN
+    ('\u{10BA}', CharMapAction::SubChar('ⴚ')), // GEORGIAN CAPITAL LETTER CAN
+    ('\u{10BB}', CharMapAction::SubChar('ⴛ')), // GEORGIAN CAPITAL LETTER JIL
+    ('\u{10BC}', CharMapAction::SubChar('ⴜ')), // GEORGIAN CAPITAL LETTER CIL
+    ('\u{10BD}', CharMapAction::SubChar('ⴝ')), // GEORGIAN CAPITAL LETTER CHAR
+    ('\u{10BE}', CharMapAction::SubChar('ⴞ')), // GEORGIAN CAPITAL LETTER XAN
+    ('\u{10BF}', CharMapAction::SubChar('ⴟ')), // GEORGIAN CAPITAL LETTER JHAN
+    ('\u{10C0}', CharMapAction::SubChar('ⴠ')), // GEORGIAN CAPITAL LETTER HAE
+    ('\u{10C1}', CharMapAction::SubChar('ⴡ')), // GEORGIAN CAPITAL LETTER HE
+    ('\u{10C2}', CharMapAction::SubChar('ⴢ')), // GEORGIAN CAPITAL LETTER HIE
+    ('\u{10C3}', CharMapAction::SubChar('ⴣ')), // GEORGIAN CAPITAL LETTER WE
+    ('\u{10C4}', CharMapAction::SubChar('ⴤ')), // GEORGIAN CAPITAL LETTER HAR
+    ('\u{10C5}', CharMapAction::SubChar('ⴥ')), // GEORGIAN CAPITAL LETTER HOE
+    ('\u{10C7}', CharMapAction::SubChar('ⴧ')), // GEORGIAN CAPITAL LETTER YN
+    ('\u{10CD}', CharMapAction::SubChar('ⴭ')), // GEORGIAN CAPITAL LETTER AEN
+    ('\u{13F8}', CharMapAction::SubChar('Ᏸ')), // CHEROKEE SMALL LETTER YE
+    ('\u{13F9}', CharMapAction::SubChar('Ᏹ')), // CHEROKEE SMALL LETTER YI
+    ('\u{13FA}', CharMapAction::SubChar('Ᏺ')), // CHEROKEE SMALL LETTER YO
+    ('\u{13FB}', CharMapAction::SubChar('Ᏻ')), // CHEROKEE SMALL LETTER YU
+    ('\u{13FC}', CharMapAction::SubChar('Ᏼ')), // CHEROKEE SMALL LETTER YV
+    ('\u{13FD}', CharMapAction::SubChar('Ᏽ')), // CHEROKEE SMALL LETTER MV
+    ('\u{1C80}', CharMapAction::SubChar('в')), // CYRILLIC SMALL LETTER ROUNDED VE
+    ('\u{1C81}', CharMapAction::SubChar('д')), // CYRILLIC SMALL LETTER LONG-LEGGED DE
+    ('\u{1C82}', CharMapAction::SubChar('о')), // CYRILLIC SMALL LETTER NARROW O
+    ('\u{1C83}', CharMapAction::SubChar('с')), // CYRILLIC SMALL LETTER WIDE ES
+    ('\u{1C84}', CharMapAction::SubChar('т')), // CYRILLIC SMALL LETTER TALL TE
+    ('\u{1C85}', CharMapAction::SubChar('т')), // CYRILLIC SMALL LETTER THREE-LEGGED TE
+    ('\u{1C86}', CharMapAction::SubChar('ъ')), // CYRILLIC SMALL LETTER TALL HARD SIGN
+    ('\u{1C87}', CharMapAction::SubChar('ѣ')), // CYRILLIC SMALL LETTER TALL YAT
+    ('\u{1C88}', CharMapAction::SubChar('ꙋ')), // CYRILLIC SMALL LETTER UNBLENDED UK
+    ('\u{1C90}', CharMapAction::SubChar('ა')), // GEORGIAN MTAVRULI CAPITAL LETTER AN
+    ('\u{1C91}', CharMapAction::SubChar('ბ')), // GEORGIAN MTAVRULI CAPITAL LETTER BAN
+    ('\u{1C92}', CharMapAction::SubChar('გ')), // GEORGIAN MTAVRULI CAPITAL LETTER GAN
+    ('\u{1C93}', CharMapAction::SubChar('დ')), // GEORGIAN MTAVRULI CAPITAL LETTER DON
+    ('\u{1C94}', CharMapAction::SubChar('ე')), // GEORGIAN MTAVRULI CAPITAL LETTER EN
+    ('\u{1C95}', CharMapAction::SubChar('ვ')), // GEORGIAN MTAVRULI CAPITAL LETTER VIN
+    ('\u{1C96}', CharMapAction::SubChar('ზ')), // GEORGIAN MTAVRULI CAPITAL LETTER ZEN
+    ('\u{1C97}', CharMapAction::SubChar('თ')), // GEORGIAN MTAVRULI CAPITAL LETTER TAN
+    ('\u{1C98}', CharMapAction::SubChar('ი')), // GEORGIAN MTAVRULI CAPITAL LETTER IN
+    ('\u{1C99}', CharMapAction::SubChar('კ')), // GEORGIAN MTAVRULI CAPITAL LETTER KAN
+    ('\u{1C9A}', CharMapAction::SubChar('ლ')), // GEORGIAN MTAVRULI CAPITAL LETTER LAS
+    ('\u{1C9B}', CharMapAction::SubChar('მ')), // GEORGIAN MTAVRULI CAPITAL LETTER MAN
+    ('\u{1C9C}', CharMapAction::SubChar('ნ')), // GEORGIAN MTAVRULI CAPITAL LETTER NAR
+    ('\u{1C9D}', CharMapAction::SubChar('ო')), // GEORGIAN MTAVRULI CAPITAL LETTER ON
+    ('\u{1C9E}', CharMapAction::SubChar('პ')), // GEORGIAN MTAVRULI CAPITAL LETTER PAR
+    ('\u{1C9F}', CharMapAction::SubChar('ჟ')), // GEORGIAN MTAVRULI CAPITAL LETTER ZHAR
+    ('\u{1CA0}', CharMapAction::SubChar('რ')), // GEORGIAN MTAVRULI CAPITAL LETTER RAE
+    ('\u{1CA1}', CharMapAction::SubChar('ს')), // GEORGIAN MTAVRULI CAPITAL LETTER SAN
+    ('\u{1CA2}', CharMapAction::SubChar('ტ')), // GEORGIAN MTAVRULI CAPITAL LETTER TAR
+    ('\u{1CA3}', CharMapAction::SubChar('უ')), // GEORGIAN MTAVRULI CAPITAL LETTER UN
+    ('\u{1CA4}', CharMapAction::SubChar('ფ')), // GEORGIAN MTAVRULI CAPITAL LETTER PHAR
+    ('\u{1CA5}', CharMapAction::SubChar('ქ')), // GEORGIAN MTAVRULI CAPITAL LETTER KHAR
+    ('\u{1CA6}', CharMapAction::SubChar('ღ')), // GEORGIAN MTAVRULI CAPITAL LETTER GHAN
+    ('\u{1CA7}', CharMapAction::SubChar('ყ')), // GEORGIAN MTAVRULI CAPITAL LETTER QAR
+    ('\u{1CA8}', CharMapAction::SubChar('შ')), // GEORGIAN MTAVRULI CAPITAL LETTER SHIN
+    ('\u{1CA9}', CharMapAction::SubChar('ჩ')), // GEORGIAN MTAVRULI CAPITAL LETTER CHIN
+    ('\u{1CAA}', CharMapAction::SubChar('ც')), // GEORGIAN MTAVRULI CAPITAL LETTER CAN
+    ('\u{1CAB}', CharMapAction::SubChar('ძ')), // GEORGIAN MTAVRULI CAPITAL LETTER JIL
+    ('\u{1CAC}', CharMapAction::SubChar('წ')), // GEORGIAN MTAVRULI CAPITAL LETTER CIL
+    ('\u{1CAD}', CharMapAction::SubChar('ჭ')), // GEORGIAN MTAVRULI CAPITAL LETTER CHAR
+    ('\u{1CAE}', CharMapAction::SubChar('ხ')), // GEORGIAN MTAVRULI CAPITAL LETTER XAN
+    ('\u{1CAF}', CharMapAction::SubChar('ჯ')), // GEORGIAN MTAVRULI CAPITAL LETTER JHAN
+    ('\u{1CB0}', CharMapAction::SubChar('ჰ')), // GEORGIAN MTAVRULI CAPITAL LETTER HAE
+    ('\u{1CB1}', CharMapAction::SubChar('ჱ')), // GEORGIAN MTAVRULI CAPITAL LETTER HE
+    ('\u{1CB2}', CharMapAction::SubChar('ჲ')), // GEORGIAN MTAVRULI CAPITAL LETTER HIE
+    ('\u{1CB3}', CharMapAction::SubChar('ჳ')), // GEORGIAN MTAVRULI CAPITAL LETTER WE
+    ('\u{1CB4}', CharMapAction::SubChar('ჴ')), // GEORGIAN MTAVRULI CAPITAL LETTER HAR
+    ('\u{1CB5}', CharMapAction::SubChar('ჵ')), // GEORGIAN MTAVRULI CAPITAL LETTER HOE
+    ('\u{1CB6}', CharMapAction::SubChar('ჶ')), // GEORGIAN MTAVRULI CAPITAL LETTER FI
+    ('\u{1CB7}', CharMapAction::SubChar('ჷ')), // GEORGIAN MTAVRULI CAPITAL LETTER YN
+    ('\u{1CB8}', CharMapAction::SubChar('ჸ')), // GEORGIAN MTAVRULI CAPITAL LETTER ELIFI
+    ('\u{1CB9}', CharMapAction::SubChar('ჹ')), // GEORGIAN MTAVRULI CAPITAL LETTER TURNED GAN
+    ('\u{1CBA}', CharMapAction::SubChar('ჺ')), // GEORGIAN MTAVRULI CAPITAL LETTER AIN
+    ('\u{1CBD}', CharMapAction::SubChar('ჽ')), // GEORGIAN MTAVRULI CAPITAL LETTER AEN
+    ('\u{1CBE}', CharMapAction::SubChar('ჾ')), // GEORGIAN MTAVRULI CAPITAL LETTER HARD SIGN
+    ('\u{1CBF}', CharMapAction::SubChar('ჿ')), // GEORGIAN MTAVRULI CAPITAL LETTER LABIAL SIGN
+    ('\u{1E00}', CharMapAction::SubChar('ḁ')), // LATIN CAPITAL LETTER A WITH RING BELOW
+    ('\u{1E02}', CharMapAction::SubChar('ḃ')), // LATIN CAPITAL LETTER B WITH DOT ABOVE
+    ('\u{1E04}', CharMapAction::SubChar('ḅ')), // LATIN CAPITAL LETTER B WITH DOT BELOW
+    ('\u{1E06}', CharMapAction::SubChar('ḇ')), // LATIN CAPITAL LETTER B WITH LINE BELOW
+    ('\u{1E08}', CharMapAction::SubChar('ḉ')), // LATIN CAPITAL LETTER C WITH CEDILLA AND ACUTE
+    ('\u{1E0A}', CharMapAction::SubChar('ḋ')), // LATIN CAPITAL LETTER D WITH DOT ABOVE
+    ('\u{1E0C}', CharMapAction::SubChar('ḍ')), // LATIN CAPITAL LETTER D WITH DOT BELOW
+    ('\u{1E0E}', CharMapAction::SubChar('ḏ')), // LATIN CAPITAL LETTER D WITH LINE BELOW
+    ('\u{1E10}', CharMapAction::SubChar('ḑ')), // LATIN CAPITAL LETTER D WITH CEDILLA
+    ('\u{1E12}', CharMapAction::SubChar('ḓ')), // LATIN CAPITAL LETTER D WITH CIRCUMFLEX BELOW
+    ('\u{1E14}', CharMapAction::SubChar('ḕ')), // LATIN CAPITAL LETTER E WITH MACRON AND GRAVE
+    ('\u{1E16}', CharMapAction::SubChar('ḗ')), // LATIN CAPITAL LETTER E WITH MACRON AND ACUTE
+    ('\u{1E18}', CharMapAction::SubChar('ḙ')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX BELOW
+    ('\u{1E1A}', CharMapAction::SubChar('ḛ')), // LATIN CAPITAL LETTER E WITH TILDE BELOW
+    ('\u{1E1C}', CharMapAction::SubChar('ḝ')), // LATIN CAPITAL LETTER E WITH CEDILLA AND BREVE
+    ('\u{1E1E}', CharMapAction::SubChar('ḟ')), // LATIN CAPITAL LETTER F WITH DOT ABOVE
+    ('\u{1E20}', CharMapAction::SubChar('ḡ')), // LATIN CAPITAL LETTER G WITH MACRON
+    ('\u{1E22}', CharMapAction::SubChar('ḣ')), // LATIN CAPITAL LETTER H WITH DOT ABOVE
+    ('\u{1E24}', CharMapAction::SubChar('ḥ')), // LATIN CAPITAL LETTER H WITH DOT BELOW
+    ('\u{1E26}', CharMapAction::SubChar('ḧ')), // LATIN CAPITAL LETTER H WITH DIAERESIS
+    ('\u{1E28}', CharMapAction::SubChar('ḩ')), // LATIN CAPITAL LETTER H WITH CEDILLA
+    ('\u{1E2A}', CharMapAction::SubChar('ḫ')), // LATIN CAPITAL LETTER H WITH BREVE BELOW
+    ('\u{1E2C}', CharMapAction::SubChar('ḭ')), // LATIN CAPITAL LETTER I WITH TILDE BELOW
+    ('\u{1E2E}', CharMapAction::SubChar('ḯ')), // LATIN CAPITAL LETTER I WITH DIAERESIS AND ACUTE
+    ('\u{1E30}', CharMapAction::SubChar('ḱ')), // LATIN CAPITAL LETTER K WITH ACUTE
+    ('\u{1E32}', CharMapAction::SubChar('ḳ')), // LATIN CAPITAL LETTER K WITH DOT BELOW
+    ('\u{1E34}', CharMapAction::SubChar('ḵ')), // LATIN CAPITAL LETTER K WITH LINE BELOW
+    ('\u{1E36}', CharMapAction::SubChar('ḷ')), // LATIN CAPITAL LETTER L WITH DOT BELOW
+    ('\u{1E38}', CharMapAction::SubChar('ḹ')), // LATIN CAPITAL LETTER L WITH DOT BELOW AND MACRON
+    ('\u{1E3A}', CharMapAction::SubChar('ḻ')), // LATIN CAPITAL LETTER L WITH LINE BELOW
+    ('\u{1E3C}', CharMapAction::SubChar('ḽ')), // LATIN CAPITAL LETTER L WITH CIRCUMFLEX BELOW
+    ('\u{1E3E}', CharMapAction::SubChar('ḿ')), // LATIN CAPITAL LETTER M WITH ACUTE
+    ('\u{1E40}', CharMapAction::SubChar('ṁ')), // LATIN CAPITAL LETTER M WITH DOT ABOVE
+    ('\u{1E42}', CharMapAction::SubChar('ṃ')), // LATIN CAPITAL LETTER M WITH DOT BELOW
+    ('\u{1E44}', CharMapAction::SubChar('ṅ')), // LATIN CAPITAL LETTER N WITH DOT ABOVE
+    ('\u{1E46}', CharMapAction::SubChar('ṇ')), // LATIN CAPITAL LETTER N WITH DOT BELOW
+    ('\u{1E48}', CharMapAction::SubChar('ṉ')), // LATIN CAPITAL LETTER N WITH LINE BELOW
+    ('\u{1E4A}', CharMapAction::SubChar('ṋ')), // LATIN CAPITAL LETTER N WITH CIRCUMFLEX BELOW
+    ('\u{1E4C}', CharMapAction::SubChar('ṍ')), // LATIN CAPITAL LETTER O WITH TILDE AND ACUTE
+    ('\u{1E4E}', CharMapAction::SubChar('ṏ')), // LATIN CAPITAL LETTER O WITH TILDE AND DIAERESIS
+    ('\u{1E50}', CharMapAction::SubChar('ṑ')), // LATIN CAPITAL LETTER O WITH MACRON AND GRAVE
+    ('\u{1E52}', CharMapAction::SubChar('ṓ')), // LATIN CAPITAL LETTER O WITH MACRON AND ACUTE
+    ('\u{1E54}', CharMapAction::SubChar('ṕ')), // LATIN CAPITAL LETTER P WITH ACUTE
+    ('\u{1E56}', CharMapAction::SubChar('ṗ')), // LATIN CAPITAL LETTER P WITH DOT ABOVE
+    ('\u{1E58}', CharMapAction::SubChar('ṙ')), // LATIN CAPITAL LETTER R WITH DOT ABOVE
+    ('\u{1E5A}', CharMapAction::SubChar('ṛ')), // LATIN CAPITAL LETTER R WITH DOT BELOW
+    ('\u{1E5C}', CharMapAction::SubChar('ṝ')), // LATIN CAPITAL LETTER R WITH DOT BELOW AND MACRON
+    ('\u{1E5E}', CharMapAction::SubChar('ṟ')), // LATIN CAPITAL LETTER R WITH LINE BELOW
+    ('\u{1E60}', CharMapAction::SubChar('ṡ')), // LATIN CAPITAL LETTER S WITH DOT ABOVE
+    ('\u{1E62}', CharMapAction::SubChar('ṣ')), // LATIN CAPITAL LETTER S WITH DOT BELOW
+    ('\u{1E64}', CharMapAction::SubChar('ṥ')), // LATIN CAPITAL LETTER S WITH ACUTE AND DOT ABOVE
+    ('\u{1E66}', CharMapAction::SubChar('ṧ')), // LATIN CAPITAL LETTER S WITH CARON AND DOT ABOVE
+    ('\u{1E68}', CharMapAction::SubChar('ṩ')), // LATIN CAPITAL LETTER S WITH DOT BELOW AND DOT ABOVE
+    ('\u{1E6A}', CharMapAction::SubChar('ṫ')), // LATIN CAPITAL LETTER T WITH DOT ABOVE
+    ('\u{1E6C}', CharMapAction::SubChar('ṭ')), // LATIN CAPITAL LETTER T WITH DOT BELOW
+    ('\u{1E6E}', CharMapAction::SubChar('ṯ')), // LATIN CAPITAL LETTER T WITH LINE BELOW
+    ('\u{1E70}', CharMapAction::SubChar('ṱ')), // LATIN CAPITAL LETTER T WITH CIRCUMFLEX BELOW
+    ('\u{1E72}', CharMapAction::SubChar('ṳ')), // LATIN CAPITAL LETTER U WITH DIAERESIS BELOW
+    ('\u{1E74}', CharMapAction::SubChar('ṵ')), // LATIN CAPITAL LETTER U WITH TILDE BELOW
+    ('\u{1E76}', CharMapAction::SubChar('ṷ')), // LATIN CAPITAL LETTER U WITH CIRCUMFLEX BELOW
+    ('\u{1E78}', CharMapAction::SubChar('ṹ')), // LATIN CAPITAL LETTER U WITH TILDE AND ACUTE
+    ('\u{1E7A}', CharMapAction::SubChar('ṻ')), // LATIN CAPITAL LETTER U WITH MACRON AND DIAERESIS
+    ('\u{1E7C}', CharMapAction::SubChar('ṽ')), // LATIN CAPITAL LETTER V WITH TILDE
+    ('\u{1E7E}', CharMapAction::SubChar('ṿ')), // LATIN CAPITAL LETTER V WITH DOT BELOW
+    ('\u{1E80}', CharMapAction::SubChar('ẁ')), // LATIN CAPITAL LETTER W WITH GRAVE
+    ('\u{1E82}', CharMapAction::SubChar('ẃ')), // LATIN CAPITAL LETTER W WITH ACUTE
+    ('\u{1E84}', CharMapAction::SubChar('ẅ')), // LATIN CAPITAL LETTER W WITH DIAERESIS
+    ('\u{1E86}', CharMapAction::SubChar('ẇ')), // LATIN CAPITAL LETTER W WITH DOT ABOVE
+    ('\u{1E88}', CharMapAction::SubChar('ẉ')), // LATIN CAPITAL LETTER W WITH DOT BELOW
+    ('\u{1E8A}', CharMapAction::SubChar('ẋ')), // LATIN CAPITAL LETTER X WITH DOT ABOVE
+    ('\u{1E8C}', CharMapAction::SubChar('ẍ')), // LATIN CAPITAL LETTER X WITH DIAERESIS
+    ('\u{1E8E}', CharMapAction::SubChar('ẏ')), // LATIN CAPITAL LETTER Y WITH DOT ABOVE
+    ('\u{1E90}', CharMapAction::SubChar('ẑ')), // LATIN CAPITAL LETTER Z WITH CIRCUMFLEX
+    ('\u{1E92}', CharMapAction::SubChar('ẓ')), // LATIN CAPITAL LETTER Z WITH DOT BELOW
+    ('\u{1E94}', CharMapAction::SubChar('ẕ')), // LATIN CAPITAL LETTER Z WITH LINE BELOW
+    ('\u{1E96}', CharMapAction::SubStr("h\u{0331}")), // LATIN SMALL LETTER H WITH LINE BELOW
+    ('\u{1E97}', CharMapAction::SubStr("t\u{0308}")), // LATIN SMALL LETTER T WITH DIAERESIS
+    ('\u{1E98}', CharMapAction::SubStr("w\u{030A}")), // LATIN SMALL LETTER W WITH RING ABOVE
+    ('\u{1E99}', CharMapAction::SubStr("y\u{030A}")), // LATIN SMALL LETTER Y WITH RING ABOVE
+    ('\u{1E9A}', CharMapAction::SubStr("aʾ")), // LATIN SMALL LETTER A WITH RIGHT HALF RING
+    ('\u{1E9B}', CharMapAction::SubChar('ṡ')), // LATIN SMALL LETTER LONG S WITH DOT ABOVE
+    ('\u{1E9E}', CharMapAction::SubStr("ss")), // LATIN CAPITAL LETTER SHARP S
+    ('\u{1EA0}', CharMapAction::SubChar('ạ')), // LATIN CAPITAL LETTER A WITH DOT BELOW
+    ('\u{1EA2}', CharMapAction::SubChar('ả')), // LATIN CAPITAL LETTER A WITH HOOK ABOVE
+    ('\u{1EA4}', CharMapAction::SubChar('ấ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EA6}', CharMapAction::SubChar('ầ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EA8}', CharMapAction::SubChar('ẩ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EAA}', CharMapAction::SubChar('ẫ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND TILDE
+    ('\u{1EAC}', CharMapAction::SubChar('ậ')), // LATIN CAPITAL LETTER A WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EAE}', CharMapAction::SubChar('ắ')), // LATIN CAPITAL LETTER A WITH BREVE AND ACUTE
+    ('\u{1EB0}', CharMapAction::SubChar('ằ')), // LATIN CAPITAL LETTER A WITH BREVE AND GRAVE
+    ('\u{1EB2}', CharMapAction::SubChar('ẳ')), // LATIN CAPITAL LETTER A WITH BREVE AND HOOK ABOVE
+    ('\u{1EB4}', CharMapAction::SubChar('ẵ')), // LATIN CAPITAL LETTER A WITH BREVE AND TILDE
+    ('\u{1EB6}', CharMapAction::SubChar('ặ')), // LATIN CAPITAL LETTER A WITH BREVE AND DOT BELOW
+    ('\u{1EB8}', CharMapAction::SubChar('ẹ')), // LATIN CAPITAL LETTER E WITH DOT BELOW
+    ('\u{1EBA}', CharMapAction::SubChar('ẻ')), // LATIN CAPITAL LETTER E WITH HOOK ABOVE
+    ('\u{1EBC}', CharMapAction::SubChar('ẽ')), // LATIN CAPITAL LETTER E WITH TILDE
+    ('\u{1EBE}', CharMapAction::SubChar('ế')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND ACUTE
+    ('\u{1EC0}', CharMapAction::SubChar('ề')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND GRAVE
+    ('\u{1EC2}', CharMapAction::SubChar('ể')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1EC4}', CharMapAction::SubChar('ễ')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND TILDE
+    ('\u{1EC6}', CharMapAction::SubChar('ệ')), // LATIN CAPITAL LETTER E WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EC8}', CharMapAction::SubChar('ỉ')), // LATIN CAPITAL LETTER I WITH HOOK ABOVE
+    ('\u{1ECA}', CharMapAction::SubChar('ị')), // LATIN CAPITAL LETTER I WITH DOT BELOW
+    ('\u{1ECC}', CharMapAction::SubChar('ọ')), // LATIN CAPITAL LETTER O WITH DOT BELOW
+    ('\u{1ECE}', CharMapAction::SubChar('ỏ')), // LATIN CAPITAL LETTER O WITH HOOK ABOVE
+    ('\u{1ED0}', CharMapAction::SubChar('ố')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND ACUTE
+    ('\u{1ED2}', CharMapAction::SubChar('ồ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND GRAVE
+    ('\u{1ED4}', CharMapAction::SubChar('ổ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND HOOK ABOVE
+    ('\u{1ED6}', CharMapAction::SubChar('ỗ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND TILDE
+    ('\u{1ED8}', CharMapAction::SubChar('ộ')), // LATIN CAPITAL LETTER O WITH CIRCUMFLEX AND DOT BELOW
+    ('\u{1EDA}', CharMapAction::SubChar('ớ')), // LATIN CAPITAL LETTER O WITH HORN AND ACUTE
+    ('\u{1EDC}', CharMapAction::SubChar('ờ')), // LATIN CAPITAL LETTER O WITH HORN AND GRAVE
+    ('\u{1EDE}', CharMapAction::SubChar('ở')), // LATIN CAPITAL LETTER O WITH HORN AND HOOK ABOVE
+    ('\u{1EE0}', CharMapAction::SubChar('ỡ')), // LATIN CAPITAL LETTER O WITH HORN AND TILDE
+    ('\u{1EE2}', CharMapAction::SubChar('ợ')), // LATIN CAPITAL LETTER O WITH HORN AND DOT BELOW
+    ('\u{1EE4}', CharMapAction::SubChar('ụ')), // LATIN CAPITAL LETTER U WITH DOT BELOW
+    ('\u{1EE6}', CharMapAction::SubChar('ủ')), // LATIN CAPITAL LETTER U WITH HOOK ABOVE
+    ('\u{1EE8}', CharMapAction::SubChar('ứ')), // LATIN CAPITAL LETTER U WITH HORN AND ACUTE
+    ('\u{1EEA}', CharMapAction::SubChar('ừ')), // LATIN CAPITAL LETTER U WITH HORN AND GRAVE
+    ('\u{1EEC}', CharMapAction::SubChar('ử')), // LATIN CAPITAL LETTER U WITH HORN AND HOOK ABOVE
+    ('\u{1EEE}', CharMapAction::SubChar('ữ')), // LATIN CAPITAL LETTER U WITH HORN AND TILDE
+    ('\u{1EF0}', CharMapAction::SubChar('ự')), // LATIN CAPITAL LETTER U WITH HORN AND DOT BELOW
+    ('\u{1EF2}', CharMapAction::SubChar('ỳ')), // LATIN CAPITAL LETTER Y WITH GRAVE
+    ('\u{1EF4}', CharMapAction::SubChar('ỵ')), // LATIN CAPITAL LETTER Y WITH DOT BELOW
+    ('\u{1EF6}', CharMapAction::SubChar('ỷ')), // LATIN CAPITAL LETTER Y WITH HOOK ABOVE
+    ('\u{1EF8}', CharMapAction::SubChar('ỹ')), // LATIN CAPITAL LETTER Y WITH TILDE
+    ('\u{1EFA}', CharMapAction::SubChar('ỻ')), // LATIN CAPITAL LETTER MIDDLE-WELSH LL
+    ('\u{1EFC}', CharMapAction::SubChar('ỽ')), // LATIN CAPITAL LETTER MIDDLE-WELSH V
+    ('\u{1EFE}', CharMapAction::SubChar('ỿ')), // LATIN CAPITAL LETTER Y WITH LOOP
+    ('\u{1F08}', CharMapAction::SubChar('ἀ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI
+    ('\u{1F09}', CharMapAction::SubChar('ἁ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA
+    ('\u{1F0A}', CharMapAction::SubChar('ἂ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F0B}', CharMapAction::SubChar('ἃ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F0C}', CharMapAction::SubChar('ἄ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F0D}', CharMapAction::SubChar('ἅ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F0E}', CharMapAction::SubChar('ἆ')), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F0F}', CharMapAction::SubChar('ἇ')), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F18}', CharMapAction::SubChar('ἐ')), // GREEK CAPITAL LETTER EPSILON WITH PSILI
+    ('\u{1F19}', CharMapAction::SubChar('ἑ')), // GREEK CAPITAL LETTER EPSILON WITH DASIA
+    ('\u{1F1A}', CharMapAction::SubChar('ἒ')), // GREEK CAPITAL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F1B}', CharMapAction::SubChar('ἓ')), // GREEK CAPITAL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F1C}', CharMapAction::SubChar('ἔ')), // GREEK CAPITAL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F1D}', CharMapAction::SubChar('ἕ')), // GREEK CAPITAL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F28}', CharMapAction::SubChar('ἠ')), // GREEK CAPITAL LETTER ETA WITH PSILI
+    ('\u{1F29}', CharMapAction::SubChar('ἡ')), // GREEK CAPITAL LETTER ETA WITH DASIA
+    ('\u{1F2A}', CharMapAction::SubChar('ἢ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F2B}', CharMapAction::SubChar('ἣ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F2C}', CharMapAction::SubChar('ἤ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F2D}', CharMapAction::SubChar('ἥ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F2E}', CharMapAction::SubChar('ἦ')), // GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F2F}', CharMapAction::SubChar('ἧ')), // GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F38}', CharMapAction::SubChar('ἰ')), // GREEK CAPITAL LETTER IOTA WITH PSILI
+    ('\u{1F39}', CharMapAction::SubChar('ἱ')), // GREEK CAPITAL LETTER IOTA WITH DASIA
+    ('\u{1F3A}', CharMapAction::SubChar('ἲ')), // GREEK CAPITAL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F3B}', CharMapAction::SubChar('ἳ')), // GREEK CAPITAL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F3C}', CharMapAction::SubChar('ἴ')), // GREEK CAPITAL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F3D}', CharMapAction::SubChar('ἵ')), // GREEK CAPITAL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F3E}', CharMapAction::SubChar('ἶ')), // GREEK CAPITAL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F3F}', CharMapAction::SubChar('ἷ')), // GREEK CAPITAL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F48}', CharMapAction::SubChar('ὀ')), // GREEK CAPITAL LETTER OMICRON WITH PSILI
+    ('\u{1F49}', CharMapAction::SubChar('ὁ')), // GREEK CAPITAL LETTER OMICRON WITH DASIA
+    ('\u{1F4A}', CharMapAction::SubChar('ὂ')), // GREEK CAPITAL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F4B}', CharMapAction::SubChar('ὃ')), // GREEK CAPITAL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F4C}', CharMapAction::SubChar('ὄ')), // GREEK CAPITAL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F4D}', CharMapAction::SubChar('ὅ')), // GREEK CAPITAL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F50}', CharMapAction::SubStr("υ\u{0313}")), // GREEK SMALL LETTER UPSILON WITH PSILI
+    ('\u{1F52}', CharMapAction::SubStr("υ\u{0313}\u{0300}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND VARIA
+    ('\u{1F54}', CharMapAction::SubStr("υ\u{0313}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND OXIA
+    ('\u{1F56}', CharMapAction::SubStr("υ\u{0313}\u{0342}")), // GREEK SMALL LETTER UPSILON WITH PSILI AND PERISPOMENI
+    ('\u{1F59}', CharMapAction::SubChar('ὑ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA
+    ('\u{1F5B}', CharMapAction::SubChar('ὓ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F5D}', CharMapAction::SubChar('ὕ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F5F}', CharMapAction::SubChar('ὗ')), // GREEK CAPITAL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F68}', CharMapAction::SubChar('ὠ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI
+    ('\u{1F69}', CharMapAction::SubChar('ὡ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA
+    ('\u{1F6A}', CharMapAction::SubChar('ὢ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F6B}', CharMapAction::SubChar('ὣ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F6C}', CharMapAction::SubChar('ὤ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F6D}', CharMapAction::SubChar('ὥ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F6E}', CharMapAction::SubChar('ὦ')), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F6F}', CharMapAction::SubChar('ὧ')), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F80}', CharMapAction::SubStr("ἀι")), // GREEK SMALL LETTER ALPHA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F81}', CharMapAction::SubStr("ἁι")), // GREEK SMALL LETTER ALPHA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F82}', CharMapAction::SubStr("ἂι")), // GREEK SMALL LETTER ALPHA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F83}', CharMapAction::SubStr("ἃι")), // GREEK SMALL LETTER ALPHA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F84}', CharMapAction::SubStr("ἄι")), // GREEK SMALL LETTER ALPHA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F85}', CharMapAction::SubStr("ἅι")), // GREEK SMALL LETTER ALPHA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F86}', CharMapAction::SubStr("ἆι")), // GREEK SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F87}', CharMapAction::SubStr("ἇι")), // GREEK SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F88}', CharMapAction::SubStr("ἀι")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F89}', CharMapAction::SubStr("ἁι")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F8A}', CharMapAction::SubStr("ἂι")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8B}', CharMapAction::SubStr("ἃι")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8C}', CharMapAction::SubStr("ἄι")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8D}', CharMapAction::SubStr("ἅι")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8E}', CharMapAction::SubStr("ἆι")), // GREEK CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F8F}', CharMapAction::SubStr("ἇι")), // GREEK CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F90}', CharMapAction::SubStr("ἠι")), // GREEK SMALL LETTER ETA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F91}', CharMapAction::SubStr("ἡι")), // GREEK SMALL LETTER ETA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F92}', CharMapAction::SubStr("ἢι")), // GREEK SMALL LETTER ETA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F93}', CharMapAction::SubStr("ἣι")), // GREEK SMALL LETTER ETA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F94}', CharMapAction::SubStr("ἤι")), // GREEK SMALL LETTER ETA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F95}', CharMapAction::SubStr("ἥι")), // GREEK SMALL LETTER ETA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F96}', CharMapAction::SubStr("ἦι")), // GREEK SMALL LETTER ETA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F97}', CharMapAction::SubStr("ἧι")), // GREEK SMALL LETTER ETA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F98}', CharMapAction::SubStr("ἠι")), // GREEK CAPITAL LETTER ETA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F99}', CharMapAction::SubStr("ἡι")), // GREEK CAPITAL LETTER ETA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F9A}', CharMapAction::SubStr("ἢι")), // GREEK CAPITAL LETTER ETA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9B}', CharMapAction::SubStr("ἣι")), // GREEK CAPITAL LETTER ETA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9C}', CharMapAction::SubStr("ἤι")), // GREEK CAPITAL LETTER ETA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9D}', CharMapAction::SubStr("ἥι")), // GREEK CAPITAL LETTER ETA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9E}', CharMapAction::SubStr("ἦι")), // GREEK CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F9F}', CharMapAction::SubStr("ἧι")), // GREEK CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FA0}', CharMapAction::SubStr("ὠι")), // GREEK SMALL LETTER OMEGA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1FA1}', CharMapAction::SubStr("ὡι")), // GREEK SMALL LETTER OMEGA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1FA2}', CharMapAction::SubStr("ὢι")), // GREEK SMALL LETTER OMEGA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA3}', CharMapAction::SubStr("ὣι")), // GREEK SMALL LETTER OMEGA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA4}', CharMapAction::SubStr("ὤι")), // GREEK SMALL LETTER OMEGA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA5}', CharMapAction::SubStr("ὥι")), // GREEK SMALL LETTER OMEGA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA6}', CharMapAction::SubStr("ὦι")), // GREEK SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA7}', CharMapAction::SubStr("ὧι")), // GREEK SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA8}', CharMapAction::SubStr("ὠι")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1FA9}', CharMapAction::SubStr("ὡι")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1FAA}', CharMapAction::SubStr("ὢι")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAB}', CharMapAction::SubStr("ὣι")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAC}', CharMapAction::SubStr("ὤι")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAD}', CharMapAction::SubStr("ὥι")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAE}', CharMapAction::SubStr("ὦι")), // GREEK CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FAF}', CharMapAction::SubStr("ὧι")), // GREEK CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FB2}', CharMapAction::SubStr("ὰι")), // GREEK SMALL LETTER ALPHA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FB3}', CharMapAction::SubStr("αι")), // GREEK SMALL LETTER ALPHA WITH YPOGEGRAMMENI
+    ('\u{1FB4}', CharMapAction::SubStr("άι")), // GREEK SMALL LETTER ALPHA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FB6}', CharMapAction::SubStr("α\u{0342}")), // GREEK SMALL LETTER ALPHA WITH PERISPOMENI
+    ('\u{1FB7}', CharMapAction::SubStr("α\u{0342}ι")), // GREEK SMALL LETTER ALPHA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FB8}', CharMapAction::SubChar('ᾰ')), // GREEK CAPITAL LETTER ALPHA WITH VRACHY
+    ('\u{1FB9}', CharMapAction::SubChar('ᾱ')), // GREEK CAPITAL LETTER ALPHA WITH MACRON
+    ('\u{1FBA}', CharMapAction::SubChar('ὰ')), // GREEK CAPITAL LETTER ALPHA WITH VARIA
+    ('\u{1FBB}', CharMapAction::SubChar('ά')), // GREEK CAPITAL LETTER ALPHA WITH OXIA
+    ('\u{1FBC}', CharMapAction::SubStr("αι")), // GREEK CAPITAL LETTER ALPHA WITH PROSGEGRAMMENI
+    ('\u{1FBE}', CharMapAction::SubChar('ι')), // GREEK PROSGEGRAMMENI
+    ('\u{1FC2}', CharMapAction::SubStr("ὴι")), // GREEK SMALL LETTER ETA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FC3}', CharMapAction::SubStr("ηι")), // GREEK SMALL LETTER ETA WITH YPOGEGRAMMENI
+    ('\u{1FC4}', CharMapAction::SubStr("ήι")), // GREEK SMALL LETTER ETA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FC6}', CharMapAction::SubStr("η\u{0342}")), // GREEK SMALL LETTER ETA WITH PERISPOMENI
+    ('\u{1FC7}', CharMapAction::SubStr("η\u{0342}ι")), // GREEK SMALL LETTER ETA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FC8}', CharMapAction::SubChar('ὲ')), // GREEK CAPITAL LETTER EPSILON WITH VARIA
+    ('\u{1FC9}', CharMapAction::SubChar('έ')), // GREEK CAPITAL LETTER EPSILON WITH OXIA
+    ('\u{1FCA}', CharMapAction::SubChar('ὴ')), // GREEK CAPITAL LETTER ETA WITH VARIA
+    ('\u{1FCB}', CharMapAction::SubChar('ή')), // GREEK CAPITAL LETTER ETA WITH OXIA
+    ('\u{1FCC}', CharMapAction::SubStr("ηι")), // GREEK CAPITAL LETTER ETA WITH PROSGEGRAMMENI
+    ('\u{1FD2}', CharMapAction::SubStr("ι\u{0308}\u{0300}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND VARIA
+    ('\u{1FD3}', CharMapAction::SubStr("ι\u{0308}\u{0301}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND OXIA
+    ('\u{1FD6}', CharMapAction::SubStr("ι\u{0342}")), // GREEK SMALL LETTER IOTA WITH PERISPOMENI
+    ('\u{1FD7}', CharMapAction::SubStr("ι\u{0308}\u{0342}")), // GREEK SMALL LETTER IOTA WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FD8}', CharMapAction::SubChar('ῐ')), // GREEK CAPITAL LETTER IOTA WITH VRACHY
+    ('\u{1FD9}', CharMapAction::SubChar('ῑ')), // GREEK CAPITAL LETTER IOTA WITH MACRON
+    ('\u{1FDA}', CharMapAction::SubChar('ὶ')), // GREEK CAPITAL LETTER IOTA WITH VARIA
+    ('\u{1FDB}', CharMapAction::SubChar('ί')), // GREEK CAPITAL LETTER IOTA WITH OXIA
+    ('\u{1FE2}', CharMapAction::SubStr("υ\u{0308}\u{0300}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND VARIA
+    ('\u{1FE3}', CharMapAction::SubStr("υ\u{0308}\u{0301}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND OXIA
+    ('\u{1FE4}', CharMapAction::SubStr("ρ\u{0313}")), // GREEK SMALL LETTER RHO WITH PSILI
+    ('\u{1FE6}', CharMapAction::SubStr("υ\u{0342}")), // GREEK SMALL LETTER UPSILON WITH PERISPOMENI
+    ('\u{1FE7}', CharMapAction::SubStr("υ\u{0308}\u{0342}")), // GREEK SMALL LETTER UPSILON WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FE8}', CharMapAction::SubChar('ῠ')), // GREEK CAPITAL LETTER UPSILON WITH VRACHY
+    ('\u{1FE9}', CharMapAction::SubChar('ῡ')), // GREEK CAPITAL LETTER UPSILON WITH MACRON
+    ('\u{1FEA}', CharMapAction::SubChar('ὺ')), // GREEK CAPITAL LETTER UPSILON WITH VARIA
+    ('\u{1FEB}', CharMapAction::SubChar('ύ')), // GREEK CAPITAL LETTER UPSILON WITH OXIA
+    ('\u{1FEC}', CharMapAction::SubChar('ῥ')), // GREEK CAPITAL LETTER RHO WITH DASIA
+    ('\u{1FF2}', CharMapAction::SubStr("ὼι")), // GREEK SMALL LETTER OMEGA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FF3}', CharMapAction::SubStr("ωι")), // GREEK SMALL LETTER OMEGA WITH YPOGEGRAMMENI
+    ('\u{1FF4}', CharMapAction::SubStr("ώι")), // GREEK SMALL LETTER OMEGA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FF6}', CharMapAction::SubStr("ω\u{0342}")), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI
+    ('\u{1FF7}', CharMapAction::SubStr("ω\u{0342}ι")), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FF8}', CharMapAction::SubChar('ὸ')), // GREEK CAPITAL LETTER OMICRON WITH VARIA
+    ('\u{1FF9}', CharMapAction::SubChar('ό')), // GREEK CAPITAL LETTER OMICRON WITH OXIA
+    ('\u{1FFA}', CharMapAction::SubChar('ὼ')), // GREEK CAPITAL LETTER OMEGA WITH VARIA
+    ('\u{1FFB}', CharMapAction::SubChar('ώ')), // GREEK CAPITAL LETTER OMEGA WITH OXIA
+    ('\u{1FFC}', CharMapAction::SubStr("ωι")), // GREEK CAPITAL LETTER OMEGA WITH PROSGEGRAMMENI
+    ('\u{2126}', CharMapAction::SubChar('ω')), // OHM SIGN
+    ('\u{212A}', CharMapAction::SubChar('k')), // KELVIN SIGN
+    ('\u{212B}', CharMapAction::SubChar('å')), // ANGSTROM SIGN
+    ('\u{2132}', CharMapAction::SubChar('ⅎ')), // TURNED CAPITAL F
+    ('\u{2160}', CharMapAction::SubChar('ⅰ')), // ROMAN NUMERAL ONE
+    ('\u{2161}', CharMapAction::SubChar('ⅱ')), // ROMAN NUMERAL TWO
+    ('\u{2162}', CharMapAction::SubChar('ⅲ')), // ROMAN NUMERAL THREE
+    ('\u{2163}', CharMapAction::SubChar('ⅳ')), // ROMAN NUMERAL FOUR
+    ('\u{2164}', CharMapAction::SubChar('ⅴ')), // ROMAN NUMERAL FIVE
+    ('\u{2165}', CharMapAction::SubChar('ⅵ')), // ROMAN NUMERAL SIX
+    ('\u{2166}', CharMapAction::SubChar('ⅶ')), // ROMAN NUMERAL SEVEN
+    ('\u{2167}', CharMapAction::SubChar('ⅷ')), // ROMAN NUMERAL EIGHT
+    ('\u{2168}', CharMapAction::SubChar('ⅸ')), // ROMAN NUMERAL NINE
+    ('\u{2169}', CharMapAction::SubChar('ⅹ')), // ROMAN NUMERAL TEN
+    ('\u{216A}', CharMapAction::SubChar('ⅺ')), // ROMAN NUMERAL ELEVEN
+    ('\u{216B}', CharMapAction::SubChar('ⅻ')), // ROMAN NUMERAL TWELVE
+    ('\u{216C}', CharMapAction::SubChar('ⅼ')), // ROMAN NUMERAL FIFTY
+    ('\u{216D}', CharMapAction::SubChar('ⅽ')), // ROMAN NUMERAL ONE HUNDRED
+    ('\u{216E}', CharMapAction::SubChar('ⅾ')), // ROMAN NUMERAL FIVE HUNDRED
+    ('\u{216F}', CharMapAction::SubChar('ⅿ')), // ROMAN NUMERAL ONE THOUSAND
+    ('\u{2183}', CharMapAction::SubChar('ↄ')), // ROMAN NUMERAL REVERSED ONE HUNDRED
+    ('\u{24B6}', CharMapAction::SubChar('ⓐ')), // CIRCLED LATIN CAPITAL LETTER A
+    ('\u{24B7}', CharMapAction::SubChar('ⓑ')), // CIRCLED LATIN CAPITAL LETTER B
+    ('\u{24B8}', CharMapAction::SubChar('ⓒ')), // CIRCLED LATIN CAPITAL LETTER C
+    ('\u{24B9}', CharMapAction::SubChar('ⓓ')), // CIRCLED LATIN CAPITAL LETTER D
+    ('\u{24BA}', CharMapAction::SubChar('ⓔ')), // CIRCLED LATIN CAPITAL LETTER E
+    ('\u{24BB}', CharMapAction::SubChar('ⓕ')), // CIRCLED LATIN CAPITAL LETTER F
+    ('\u{24BC}', CharMapAction::SubChar('ⓖ')), // CIRCLED LATIN CAPITAL LETTER G
+    ('\u{24BD}', CharMapAction::SubChar('ⓗ')), // CIRCLED LATIN CAPITAL LETTER H
+    ('\u{24BE}', CharMapAction::SubChar('ⓘ')), // CIRCLED LATIN CAPITAL LETTER I
+    ('\u{24BF}', CharMapAction::SubChar('ⓙ')), // CIRCLED LATIN CAPITAL LETTER J
+    ('\u{24C0}', CharMapAction::SubChar('ⓚ')), // CIRCLED LATIN CAPITAL LETTER K
+    ('\u{24C1}', CharMapAction::SubChar('ⓛ')), // CIRCLED LATIN CAPITAL LETTER L
+    ('\u{24C2}', CharMapAction::SubChar('ⓜ')), // CIRCLED LATIN CAPITAL LETTER M
+    ('\u{24C3}', CharMapAction::SubChar('ⓝ')), // CIRCLED LATIN CAPITAL LETTER N
+    ('\u{24C4}', CharMapAction::SubChar('ⓞ')), // CIRCLED LATIN CAPITAL LETTER O
+    ('\u{24C5}', CharMapAction::SubChar('ⓟ')), // CIRCLED LATIN CAPITAL LETTER P
+    ('\u{24C6}', CharMapAction::SubChar('ⓠ')), // CIRCLED LATIN CAPITAL LETTER Q
+    ('\u{24C7}', CharMapAction::SubChar('ⓡ')), // CIRCLED LATIN CAPITAL LETTER R
+    ('\u{24C8}', CharMapAction::SubChar('ⓢ')), // CIRCLED LATIN CAPITAL LETTER S
+    ('\u{24C9}', CharMapAction::SubChar('ⓣ')), // CIRCLED LATIN CAPITAL LETTER T
+    ('\u{24CA}', CharMapAction::SubChar('ⓤ')), // CIRCLED LATIN CAPITAL LETTER U
+    ('\u{24CB}', CharMapAction::SubChar('ⓥ')), // CIRCLED LATIN CAPITAL LETTER V
+    ('\u{24CC}', CharMapAction::SubChar('ⓦ')), // CIRCLED LATIN CAPITAL LETTER W
+    ('\u{24CD}', CharMapAction::SubChar('ⓧ')), // CIRCLED LATIN CAPITAL LETTER X
+    ('\u{24CE}', CharMapAction::SubChar('ⓨ')), // CIRCLED LATIN CAPITAL LETTER Y
+    ('\u{24CF}', CharMapAction::SubChar('ⓩ')), // CIRCLED LATIN CAPITAL LETTER Z
+    ('\u{2C00}', CharMapAction::SubChar('ⰰ')), // GLAGOLITIC CAPITAL LETTER AZU
+    ('\u{2C01}', CharMapAction::SubChar('ⰱ')), // GLAGOLITIC CAPITAL LETTER BUKY
+    ('\u{2C02}', CharMapAction::SubChar('ⰲ')), // GLAGOLITIC CAPITAL LETTER VEDE
+    ('\u{2C03}', CharMapAction::SubChar('ⰳ')), // GLAGOLITIC CAPITAL LETTER GLAGOLI
+    ('\u{2C04}', CharMapAction::SubChar('ⰴ')), // GLAGOLITIC CAPITAL LETTER DOBRO
+    ('\u{2C05}', CharMapAction::SubChar('ⰵ')), // GLAGOLITIC CAPITAL LETTER YESTU
+    ('\u{2C06}', CharMapAction::SubChar('ⰶ')), // GLAGOLITIC CAPITAL LETTER ZHIVETE
+    ('\u{2C07}', CharMapAction::SubChar('ⰷ')), // GLAGOLITIC CAPITAL LETTER DZELO
+    ('\u{2C08}', CharMapAction::SubChar('ⰸ')), // GLAGOLITIC CAPITAL LETTER ZEMLJA
+    ('\u{2C09}', CharMapAction::SubChar('ⰹ')), // GLAGOLITIC CAPITAL LETTER IZHE
+    ('\u{2C0A}', CharMapAction::SubChar('ⰺ')), // GLAGOLITIC CAPITAL LETTER INITIAL IZHE
+    ('\u{2C0B}', CharMapAction::SubChar('ⰻ')), // GLAGOLITIC CAPITAL LETTER I
+    ('\u{2C0C}', CharMapAction::SubChar('ⰼ')), // GLAGOLITIC CAPITAL LETTER DJERVI
+    ('\u{2C0D}', CharMapAction::SubChar('ⰽ')), // GLAGOLITIC CAPITAL LETTER KAKO
+    ('\u{2C0E}', CharMapAction::SubChar('ⰾ')), // GLAGOLITIC CAPITAL LETTER LJUDIJE
+    ('\u{2C0F}', CharMapAction::SubChar('ⰿ')), // GLAGOLITIC CAPITAL LETTER MYSLITE
+    ('\u{2C10}', CharMapAction::SubChar('ⱀ')), // GLAGOLITIC CAPITAL LETTER NASHI
+    ('\u{2C11}', CharMapAction::SubChar('ⱁ')), // GLAGOLITIC CAPITAL LETTER ONU
+    ('\u{2C12}', CharMapAction::SubChar('ⱂ')), // GLAGOLITIC CAPITAL LETTER POKOJI
+    ('\u{2C13}', CharMapAction::SubChar('ⱃ')), // GLAGOLITIC CAPITAL LETTER RITSI
+    ('\u{2C14}', CharMapAction::SubChar('ⱄ')), // GLAGOLITIC CAPITAL LETTER SLOVO
+    ('\u{2C15}', CharMapAction::SubChar('ⱅ')), // GLAGOLITIC CAPITAL LETTER TVRIDO
+    ('\u{2C16}', CharMapAction::SubChar('ⱆ')), // GLAGOLITIC CAPITAL LETTER UKU
+    ('\u{2C17}', CharMapAction::SubChar('ⱇ')), // GLAGOLITIC CAPITAL LETTER FRITU
+    ('\u{2C18}', CharMapAction::SubChar('ⱈ')), // GLAGOLITIC CAPITAL LETTER HERU
+    ('\u{2C19}', CharMapAction::SubChar('ⱉ')), // GLAGOLITIC CAPITAL LETTER OTU
+    ('\u{2C1A}', CharMapAction::SubChar('ⱊ')), // GLAGOLITIC CAPITAL LETTER PE
+    ('\u{2C1B}', CharMapAction::SubChar('ⱋ')), // GLAGOLITIC CAPITAL LETTER SHTA
+    ('\u{2C1C}', CharMapAction::SubChar('ⱌ')), // GLAGOLITIC CAPITAL LETTER TSI
+    ('\u{2C1D}', CharMapAction::SubChar('ⱍ')), // GLAGOLITIC CAPITAL LETTER CHRIVI
+    ('\u{2C1E}', CharMapAction::SubChar('ⱎ')), // GLAGOLITIC CAPITAL LETTER SHA
+    ('\u{2C1F}', CharMapAction::SubChar('ⱏ')), // GLAGOLITIC CAPITAL LETTER YERU
+    ('\u{2C20}', CharMapAction::SubChar('ⱐ')), // GLAGOLITIC CAPITAL LETTER YERI
+    ('\u{2C21}', CharMapAction::SubChar('ⱑ')), // GLAGOLITIC CAPITAL LETTER YATI
+    ('\u{2C22}', CharMapAction::SubChar('ⱒ')), // GLAGOLITIC CAPITAL LETTER SPIDERY HA
+    ('\u{2C23}', CharMapAction::SubChar('ⱓ')), // GLAGOLITIC CAPITAL LETTER YU
+    ('\u{2C24}', CharMapAction::SubChar('ⱔ')), // GLAGOLITIC CAPITAL LETTER SMALL YUS
+    ('\u{2C25}', CharMapAction::SubChar('ⱕ')), // GLAGOLITIC CAPITAL LETTER SMALL YUS WITH TAIL
+    ('\u{2C26}', CharMapAction::SubChar('ⱖ')), // GLAGOLITIC CAPITAL LETTER YO
+    ('\u{2C27}', CharMapAction::SubChar('ⱗ')), // GLAGOLITIC CAPITAL LETTER IOTATED SMALL YUS
+    ('\u{2C28}', CharMapAction::SubChar('ⱘ')), // GLAGOLITIC CAPITAL LETTER BIG YUS
+    ('\u{2C29}', CharMapAction::SubChar('ⱙ')), // GLAGOLITIC CAPITAL LETTER IOTATED BIG YUS
+    ('\u{2C2A}', CharMapAction::SubChar('ⱚ')), // GLAGOLITIC CAPITAL LETTER FITA
+    ('\u{2C2B}', CharMapAction::SubChar('ⱛ')), // GLAGOLITIC CAPITAL LETTER IZHITSA
+    ('\u{2C2C}', CharMapAction::SubChar('ⱜ')), // GLAGOLITIC CAPITAL LETTER SHTAPIC
+    ('\u{2C2D}', CharMapAction::SubChar('ⱝ')), // GLAGOLITIC CAPITAL LETTER TROKUTASTI A
+    ('\u{2C2E}', CharMapAction::SubChar('ⱞ')), // GLAGOLITIC CAPITAL LETTER LATINATE MYSLITE
+    ('\u{2C2F}', CharMapAction::SubChar('ⱟ')), // GLAGOLITIC CAPITAL LETTER CAUDATE CHRIVI
+    ('\u{2C60}', CharMapAction::SubChar('ⱡ')), // LATIN CAPITAL LETTER L WITH DOUBLE BAR
+    ('\u{2C62}', CharMapAction::SubChar('ɫ')), // LATIN CAPITAL LETTER L WITH MIDDLE TILDE
+    ('\u{2C63}', CharMapAction::SubChar('ᵽ')), // LATIN CAPITAL LETTER P WITH STROKE
+    ('\u{2C64}', CharMapAction::SubChar('ɽ')), // LATIN CAPITAL LETTER R WITH TAIL
+    ('\u{2C67}', CharMapAction::SubChar('ⱨ')), // LATIN CAPITAL LETTER H WITH DESCENDER
+    ('\u{2C69}', CharMapAction::SubChar('ⱪ')), // LATIN CAPITAL LETTER K WITH DESCENDER
+    ('\u{2C6B}', CharMapAction::SubChar('ⱬ')), // LATIN CAPITAL LETTER Z WITH DESCENDER
+    ('\u{2C6D}', CharMapAction::SubChar('ɑ')), // LATIN CAPITAL LETTER ALPHA
+    ('\u{2C6E}', CharMapAction::SubChar('ɱ')), // LATIN CAPITAL LETTER M WITH HOOK
+    ('\u{2C6F}', CharMapAction::SubChar('ɐ')), // LATIN CAPITAL LETTER TURNED A
+    ('\u{2C70}', CharMapAction::SubChar('ɒ')), // LATIN CAPITAL LETTER TURNED ALPHA
+    ('\u{2C72}', CharMapAction::SubChar('ⱳ')), // LATIN CAPITAL LETTER W WITH HOOK
+    ('\u{2C75}', CharMapAction::SubChar('ⱶ')), // LATIN CAPITAL LETTER HALF H
+    ('\u{2C7E}', CharMapAction::SubChar('ȿ')), // LATIN CAPITAL LETTER S WITH SWASH TAIL
+    ('\u{2C7F}', CharMapAction::SubChar('ɀ')), // LATIN CAPITAL LETTER Z WITH SWASH TAIL
+    ('\u{2C80}', CharMapAction::SubChar('ⲁ')), // COPTIC CAPITAL LETTER ALFA
+    ('\u{2C82}', CharMapAction::SubChar('ⲃ')), // COPTIC CAPITAL LETTER VIDA
+    ('\u{2C84}', CharMapAction::SubChar('ⲅ')), // COPTIC CAPITAL LETTER GAMMA
+    ('\u{2C86}', CharMapAction::SubChar('ⲇ')), // COPTIC CAPITAL LETTER DALDA
+    ('\u{2C88}', CharMapAction::SubChar('ⲉ')), // COPTIC CAPITAL LETTER EIE
+    ('\u{2C8A}', CharMapAction::SubChar('ⲋ')), // COPTIC CAPITAL LETTER SOU
+    ('\u{2C8C}', CharMapAction::SubChar('ⲍ')), // COPTIC CAPITAL LETTER ZATA
+    ('\u{2C8E}', CharMapAction::SubChar('ⲏ')), // COPTIC CAPITAL LETTER HATE
+    ('\u{2C90}', CharMapAction::SubChar('ⲑ')), // COPTIC CAPITAL LETTER THETHE
+    ('\u{2C92}', CharMapAction::SubChar('ⲓ')), // COPTIC CAPITAL LETTER IAUDA
+    ('\u{2C94}', CharMapAction::SubChar('ⲕ')), // COPTIC CAPITAL LETTER KAPA
+    ('\u{2C96}', CharMapAction::SubChar('ⲗ')), // COPTIC CAPITAL LETTER LAULA
+    ('\u{2C98}', CharMapAction::SubChar('ⲙ')), // COPTIC CAPITAL LETTER MI
+    ('\u{2C9A}', CharMapAction::SubChar('ⲛ')), // COPTIC CAPITAL LETTER NI
+    ('\u{2C9C}', CharMapAction::SubChar('ⲝ')), // COPTIC CAPITAL LETTER KSI
+    ('\u{2C9E}', CharMapAction::SubChar('ⲟ')), // COPTIC CAPITAL LETTER O
+    ('\u{2CA0}', CharMapAction::SubChar('ⲡ')), // COPTIC CAPITAL LETTER PI
+    ('\u{2CA2}', CharMapAction::SubChar('ⲣ')), // COPTIC CAPITAL LETTER RO
+    ('\u{2CA4}', CharMapAction::SubChar('ⲥ')), // COPTIC CAPITAL LETTER SIMA
+    ('\u{2CA6}', CharMapAction::SubChar('ⲧ')), // COPTIC CAPITAL LETTER TAU
+    ('\u{2CA8}', CharMapAction::SubChar('ⲩ')), // COPTIC CAPITAL LETTER UA
+    ('\u{2CAA}', CharMapAction::SubChar('ⲫ')), // COPTIC CAPITAL LETTER FI
+    ('\u{2CAC}', CharMapAction::SubChar('ⲭ')), // COPTIC CAPITAL LETTER KHI
+    ('\u{2CAE}', CharMapAction::SubChar('ⲯ')), // COPTIC CAPITAL LETTER PSI
+    ('\u{2CB0}', CharMapAction::SubChar('ⲱ')), // COPTIC CAPITAL LETTER OOU
+    ('\u{2CB2}', CharMapAction::SubChar('ⲳ')), // COPTIC CAPITAL LETTER DIALECT-P ALEF
+    ('\u{2CB4}', CharMapAction::SubChar('ⲵ')), // COPTIC CAPITAL LETTER OLD COPTIC AIN
+    ('\u{2CB6}', CharMapAction::SubChar('ⲷ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC EIE
+    ('\u{2CB8}', CharMapAction::SubChar('ⲹ')), // COPTIC CAPITAL LETTER DIALECT-P KAPA
+    ('\u{2CBA}', CharMapAction::SubChar('ⲻ')), // COPTIC CAPITAL LETTER DIALECT-P NI
+    ('\u{2CBC}', CharMapAction::SubChar('ⲽ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC NI
+    ('\u{2CBE}', CharMapAction::SubChar('ⲿ')), // COPTIC CAPITAL LETTER OLD COPTIC OOU
+    ('\u{2CC0}', CharMapAction::SubChar('ⳁ')), // COPTIC CAPITAL LETTER SAMPI
+    ('\u{2CC2}', CharMapAction::SubChar('ⳃ')), // COPTIC CAPITAL LETTER CROSSED SHEI
+    ('\u{2CC4}', CharMapAction::SubChar('ⳅ')), // COPTIC CAPITAL LETTER OLD COPTIC SHEI
+    ('\u{2CC6}', CharMapAction::SubChar('ⳇ')), // COPTIC CAPITAL LETTER OLD COPTIC ESH
+    ('\u{2CC8}', CharMapAction::SubChar('ⳉ')), // COPTIC CAPITAL LETTER AKHMIMIC KHEI
+    ('\u{2CCA}', CharMapAction::SubChar('ⳋ')), // COPTIC CAPITAL LETTER DIALECT-P HORI
+    ('\u{2CCC}', CharMapAction::SubChar('ⳍ')), // COPTIC CAPITAL LETTER OLD COPTIC HORI
+    ('\u{2CCE}', CharMapAction::SubChar('ⳏ')), // COPTIC CAPITAL LETTER OLD COPTIC HA
+    ('\u{2CD0}', CharMapAction::SubChar('ⳑ')), // COPTIC CAPITAL LETTER L-SHAPED HA
+    ('\u{2CD2}', CharMapAction::SubChar('ⳓ')), // COPTIC CAPITAL LETTER OLD COPTIC HEI
+    ('\u{2CD4}', CharMapAction::SubChar('ⳕ')), // COPTIC CAPITAL LETTER OLD COPTIC HAT
+    ('\u{2CD6}', CharMapAction::SubChar('ⳗ')), // COPTIC CAPITAL LETTER OLD COPTIC GANGIA
+    ('\u{2CD8}', CharMapAction::SubChar('ⳙ')), // COPTIC CAPITAL LETTER OLD COPTIC DJA
+    ('\u{2CDA}', CharMapAction::SubChar('ⳛ')), // COPTIC CAPITAL LETTER OLD COPTIC SHIMA
+    ('\u{2CDC}', CharMapAction::SubChar('ⳝ')), // COPTIC CAPITAL LETTER OLD NUBIAN SHIMA
+    ('\u{2CDE}', CharMapAction::SubChar('ⳟ')), // COPTIC CAPITAL LETTER OLD NUBIAN NGI
+    ('\u{2CE0}', CharMapAction::SubChar('ⳡ')), // COPTIC CAPITAL LETTER OLD NUBIAN NYI
+    ('\u{2CE2}', CharMapAction::SubChar('ⳣ')), // COPTIC CAPITAL LETTER OLD NUBIAN WAU
+    ('\u{2CEB}', CharMapAction::SubChar('ⳬ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC SHEI
+    ('\u{2CED}', CharMapAction::SubChar('ⳮ')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC GANGIA
+    ('\u{2CF2}', CharMapAction::SubChar('ⳳ')), // COPTIC CAPITAL LETTER BOHAIRIC KHEI
+    ('\u{A640}', CharMapAction::SubChar('ꙁ')), // CYRILLIC CAPITAL LETTER ZEMLYA
+    ('\u{A642}', CharMapAction::SubChar('ꙃ')), // CYRILLIC CAPITAL LETTER DZELO
+    ('\u{A644}', CharMapAction::SubChar('ꙅ')), // CYRILLIC CAPITAL LETTER REVERSED DZE
+    ('\u{A646}', CharMapAction::SubChar('ꙇ')), // CYRILLIC CAPITAL LETTER IOTA
+    ('\u{A648}', CharMapAction::SubChar('ꙉ')), // CYRILLIC CAPITAL LETTER DJERV
+    ('\u{A64A}', CharMapAction::SubChar('ꙋ')), // CYRILLIC CAPITAL LETTER MONOGRAPH UK
+    ('\u{A64C}', CharMapAction::SubChar('ꙍ')), // CYRILLIC CAPITAL LETTER BROAD OMEGA
+    ('\u{A64E}', CharMapAction::SubChar('ꙏ')), // CYRILLIC CAPITAL LETTER NEUTRAL YER
+    ('\u{A650}', CharMapAction::SubChar('ꙑ')), // CYRILLIC CAPITAL LETTER YERU WITH BACK YER
+    ('\u{A652}', CharMapAction::SubChar('ꙓ')), // CYRILLIC CAPITAL LETTER IOTIFIED YAT
+    ('\u{A654}', CharMapAction::SubChar('ꙕ')), // CYRILLIC CAPITAL LETTER REVERSED YU
+    ('\u{A656}', CharMapAction::SubChar('ꙗ')), // CYRILLIC CAPITAL LETTER IOTIFIED A
+    ('\u{A658}', CharMapAction::SubChar('ꙙ')), // CYRILLIC CAPITAL LETTER CLOSED LITTLE YUS
+    ('\u{A65A}', CharMapAction::SubChar('ꙛ')), // CYRILLIC CAPITAL LETTER BLENDED YUS
+    ('\u{A65C}', CharMapAction::SubChar('ꙝ')), // CYRILLIC CAPITAL LETTER IOTIFIED CLOSED LITTLE YUS
+    ('\u{A65E}', CharMapAction::SubChar('ꙟ')), // CYRILLIC CAPITAL LETTER YN
+    ('\u{A660}', CharMapAction::SubChar('ꙡ')), // CYRILLIC CAPITAL LETTER REVERSED TSE
+    ('\u{A662}', CharMapAction::SubChar('ꙣ')), // CYRILLIC CAPITAL LETTER SOFT DE
+    ('\u{A664}', CharMapAction::SubChar('ꙥ')), // CYRILLIC CAPITAL LETTER SOFT EL
+    ('\u{A666}', CharMapAction::SubChar('ꙧ')), // CYRILLIC CAPITAL LETTER SOFT EM
+    ('\u{A668}', CharMapAction::SubChar('ꙩ')), // CYRILLIC CAPITAL LETTER MONOCULAR O
+    ('\u{A66A}', CharMapAction::SubChar('ꙫ')), // CYRILLIC CAPITAL LETTER BINOCULAR O
+    ('\u{A66C}', CharMapAction::SubChar('ꙭ')), // CYRILLIC CAPITAL LETTER DOUBLE MONOCULAR O
+    ('\u{A680}', CharMapAction::SubChar('ꚁ')), // CYRILLIC CAPITAL LETTER DWE
+    ('\u{A682}', CharMapAction::SubChar('ꚃ')), // CYRILLIC CAPITAL LETTER DZWE
+    ('\u{A684}', CharMapAction::SubChar('ꚅ')), // CYRILLIC CAPITAL LETTER ZHWE
+    ('\u{A686}', CharMapAction::SubChar('ꚇ')), // CYRILLIC CAPITAL LETTER CCHE
+    ('\u{A688}', CharMapAction::SubChar('ꚉ')), // CYRILLIC CAPITAL LETTER DZZE
+    ('\u{A68A}', CharMapAction::SubChar('ꚋ')), // CYRILLIC CAPITAL LETTER TE WITH MIDDLE HOOK
+    ('\u{A68C}', CharMapAction::SubChar('ꚍ')), // CYRILLIC CAPITAL LETTER TWE
+    ('\u{A68E}', CharMapAction::SubChar('ꚏ')), // CYRILLIC CAPITAL LETTER TSWE
+    ('\u{A690}', CharMapAction::SubChar('ꚑ')), // CYRILLIC CAPITAL LETTER TSSE
+    ('\u{A692}', CharMapAction::SubChar('ꚓ')), // CYRILLIC CAPITAL LETTER TCHE
+    ('\u{A694}', CharMapAction::SubChar('ꚕ')), // CYRILLIC CAPITAL LETTER HWE
+    ('\u{A696}', CharMapAction::SubChar('ꚗ')), // CYRILLIC CAPITAL LETTER SHWE
+    ('\u{A698}', CharMapAction::SubChar('ꚙ')), // CYRILLIC CAPITAL LETTER DOUBLE O
+    ('\u{A69A}', CharMapAction::SubChar('ꚛ')), // CYRILLIC CAPITAL LETTER CROSSED O
+    ('\u{A722}', CharMapAction::SubChar('ꜣ')), // LATIN CAPITAL LETTER EGYPTOLOGICAL ALEF
+    ('\u{A724}', CharMapAction::SubChar('ꜥ')), // LATIN CAPITAL LETTER EGYPTOLOGICAL AIN
+    ('\u{A726}', CharMapAction::SubChar('ꜧ')), // LATIN CAPITAL LETTER HENG
+    ('\u{A728}', CharMapAction::SubChar('ꜩ')), // LATIN CAPITAL LETTER TZ
+    ('\u{A72A}', CharMapAction::SubChar('ꜫ')), // LATIN CAPITAL LETTER TRESILLO
+    ('\u{A72C}', CharMapAction::SubChar('ꜭ')), // LATIN CAPITAL LETTER CUATRILLO
+    ('\u{A72E}', CharMapAction::SubChar('ꜯ')), // LATIN CAPITAL LETTER CUATRILLO WITH COMMA
+    ('\u{A732}', CharMapAction::SubChar('ꜳ')), // LATIN CAPITAL LETTER AA
+    ('\u{A734}', CharMapAction::SubChar('ꜵ')), // LATIN CAPITAL LETTER AO
+    ('\u{A736}', CharMapAction::SubChar('ꜷ')), // LATIN CAPITAL LETTER AU
+    ('\u{A738}', CharMapAction::SubChar('ꜹ')), // LATIN CAPITAL LETTER AV
+    ('\u{A73A}', CharMapAction::SubChar('ꜻ')), // LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73C}', CharMapAction::SubChar('ꜽ')), // LATIN CAPITAL LETTER AY
+    ('\u{A73E}', CharMapAction::SubChar('ꜿ')), // LATIN CAPITAL LETTER REVERSED C WITH DOT
+    ('\u{A740}', CharMapAction::SubChar('ꝁ')), // LATIN CAPITAL LETTER K WITH STROKE
+    ('\u{A742}', CharMapAction::SubChar('ꝃ')), // LATIN CAPITAL LETTER K WITH DIAGONAL STROKE
+    ('\u{A744}', CharMapAction::SubChar('ꝅ')), // LATIN CAPITAL LETTER K WITH STROKE AND DIAGONAL STROKE
+    ('\u{A746}', CharMapAction::SubChar('ꝇ')), // LATIN CAPITAL LETTER BROKEN L
+    ('\u{A748}', CharMapAction::SubChar('ꝉ')), // LATIN CAPITAL LETTER L WITH HIGH STROKE
+    ('\u{A74A}', CharMapAction::SubChar('ꝋ')), // LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74C}', CharMapAction::SubChar('ꝍ')), // LATIN CAPITAL LETTER O WITH LOOP
+    ('\u{A74E}', CharMapAction::SubChar('ꝏ')), // LATIN CAPITAL LETTER OO
+    ('\u{A750}', CharMapAction::SubChar('ꝑ')), // LATIN CAPITAL LETTER P WITH STROKE THROUGH DESCENDER
+    ('\u{A752}', CharMapAction::SubChar('ꝓ')), // LATIN CAPITAL LETTER P WITH FLOURISH
+    ('\u{A754}', CharMapAction::SubChar('ꝕ')), // LATIN CAPITAL LETTER P WITH SQUIRREL TAIL
+    ('\u{A756}', CharMapAction::SubChar('ꝗ')), // LATIN CAPITAL LETTER Q WITH STROKE THROUGH DESCENDER
+    ('\u{A758}', CharMapAction::SubChar('ꝙ')), // LATIN CAPITAL LETTER Q WITH DIAGONAL STROKE
+    ('\u{A75A}', CharMapAction::SubChar('ꝛ')), // LATIN CAPITAL LETTER R ROTUNDA
+    ('\u{A75C}', CharMapAction::SubChar('ꝝ')), // LATIN CAPITAL LETTER RUM ROTUNDA
+    ('\u{A75E}', CharMapAction::SubChar('ꝟ')), // LATIN CAPITAL LETTER V WITH DIAGONAL STROKE
+    ('\u{A760}', CharMapAction::SubChar('ꝡ')), // LATIN CAPITAL LETTER VY
+    ('\u{A762}', CharMapAction::SubChar('ꝣ')), // LATIN CAPITAL LETTER VISIGOTHIC Z
+    ('\u{A764}', CharMapAction::SubChar('ꝥ')), // LATIN CAPITAL LETTER THORN WITH STROKE
+    ('\u{A766}', CharMapAction::SubChar('ꝧ')), // LATIN CAPITAL LETTER THORN WITH STROKE THROUGH DESCENDER
+    ('\u{A768}', CharMapAction::SubChar('ꝩ')), // LATIN CAPITAL LETTER VEND
+    ('\u{A76A}', CharMapAction::SubChar('ꝫ')), // LATIN CAPITAL LETTER ET
+    ('\u{A76C}', CharMapAction::SubChar('ꝭ')), // LATIN CAPITAL LETTER IS
+    ('\u{A76E}', CharMapAction::SubChar('ꝯ')), // LATIN CAPITAL LETTER CON
+    ('\u{A779}', CharMapAction::SubChar('ꝺ')), // LATIN CAPITAL LETTER INSULAR D
+    ('\u{A77B}', CharMapAction::SubChar('ꝼ')), // LATIN CAPITAL LETTER INSULAR F
+    ('\u{A77D}', CharMapAction::SubChar('ᵹ')), // LATIN CAPITAL LETTER INSULAR G
+    ('\u{A77E}', CharMapAction::SubChar('ꝿ')), // LATIN CAPITAL LETTER TURNED INSULAR G
+    ('\u{A780}', CharMapAction::SubChar('ꞁ')), // LATIN CAPITAL LETTER TURNED L
+    ('\u{A782}', CharMapAction::SubChar('ꞃ')), // LATIN CAPITAL LETTER INSULAR R
+    ('\u{A784}', CharMapAction::SubChar('ꞅ')), // LATIN CAPITAL LETTER INSULAR S
+    ('\u{A786}', CharMapAction::SubChar('ꞇ')), // LATIN CAPITAL LETTER INSULAR T
+    ('\u{A78B}', CharMapAction::SubChar('ꞌ')), // LATIN CAPITAL LETTER SALTILLO
+    ('\u{A78D}', CharMapAction::SubChar('ɥ')), // LATIN CAPITAL LETTER TURNED H
+    ('\u{A790}', CharMapAction::SubChar('ꞑ')), // LATIN CAPITAL LETTER N WITH DESCENDER
+    ('\u{A792}', CharMapAction::SubChar('ꞓ')), // LATIN CAPITAL LETTER C WITH BAR
+    ('\u{A796}', CharMapAction::SubChar('ꞗ')), // LATIN CAPITAL LETTER B WITH FLOURISH
+    ('\u{A798}', CharMapAction::SubChar('ꞙ')), // LATIN CAPITAL LETTER F WITH STROKE
+    ('\u{A79A}', CharMapAction::SubChar('ꞛ')), // LATIN CAPITAL LETTER VOLAPUK AE
+    ('\u{A79C}', CharMapAction::SubChar('ꞝ')), // LATIN CAPITAL LETTER VOLAPUK OE
+    ('\u{A79E}', CharMapAction::SubChar('ꞟ')), // LATIN CAPITAL LETTER VOLAPUK UE
+    ('\u{A7A0}', CharMapAction::SubChar('ꞡ')), // LATIN CAPITAL LETTER G WITH OBLIQUE STROKE
+    ('\u{A7A2}', CharMapAction::SubChar('ꞣ')), // LATIN CAPITAL LETTER K WITH OBLIQUE STROKE
+    ('\u{A7A4}', CharMapAction::SubChar('ꞥ')), // LATIN CAPITAL LETTER N WITH OBLIQUE STROKE
+    ('\u{A7A6}', CharMapAction::SubChar('ꞧ')), // LATIN CAPITAL LETTER R WITH OBLIQUE STROKE
+    ('\u{A7A8}', CharMapAction::SubChar('ꞩ')), // LATIN CAPITAL LETTER S WITH OBLIQUE STROKE
+    ('\u{A7AA}', CharMapAction::SubChar('ɦ')), // LATIN CAPITAL LETTER H WITH HOOK
+    ('\u{A7AB}', CharMapAction::SubChar('ɜ')), // LATIN CAPITAL LETTER REVERSED OPEN E
+    ('\u{A7AC}', CharMapAction::SubChar('ɡ')), // LATIN CAPITAL LETTER SCRIPT G
+    ('\u{A7AD}', CharMapAction::SubChar('ɬ')), // LATIN CAPITAL LETTER L WITH BELT
+    ('\u{A7AE}', CharMapAction::SubChar('ɪ')), // LATIN CAPITAL LETTER SMALL CAPITAL I
+    ('\u{A7B0}', CharMapAction::SubChar('ʞ')), // LATIN CAPITAL LETTER TURNED K
+    ('\u{A7B1}', CharMapAction::SubChar('ʇ')), // LATIN CAPITAL LETTER TURNED T
+    ('\u{A7B2}', CharMapAction::SubChar('ʝ')), // LATIN CAPITAL LETTER J WITH CROSSED-TAIL
+    ('\u{A7B3}', CharMapAction::SubChar('ꭓ')), // LATIN CAPITAL LETTER CHI
+    ('\u{A7B4}', CharMapAction::SubChar('ꞵ')), // LATIN CAPITAL LETTER BETA
+    ('\u{A7B6}', CharMapAction::SubChar('ꞷ')), // LATIN CAPITAL LETTER OMEGA
+    ('\u{A7B8}', CharMapAction::SubChar('ꞹ')), // LATIN CAPITAL LETTER U WITH STROKE
+    ('\u{A7BA}', CharMapAction::SubChar('ꞻ')), // LATIN CAPITAL LETTER GLOTTAL A
+    ('\u{A7BC}', CharMapAction::SubChar('ꞽ')), // LATIN CAPITAL LETTER GLOTTAL I
+    ('\u{A7BE}', CharMapAction::SubChar('ꞿ')), // LATIN CAPITAL LETTER GLOTTAL U
+    ('\u{A7C0}', CharMapAction::SubChar('ꟁ')), // LATIN CAPITAL LETTER OLD POLISH O
+    ('\u{A7C2}', CharMapAction::SubChar('ꟃ')), // LATIN CAPITAL LETTER ANGLICANA W
+    ('\u{A7C4}', CharMapAction::SubChar('ꞔ')), // LATIN CAPITAL LETTER C WITH PALATAL HOOK
+    ('\u{A7C5}', CharMapAction::SubChar('ʂ')), // LATIN CAPITAL LETTER S WITH HOOK
+    ('\u{A7C6}', CharMapAction::SubChar('ᶎ')), // LATIN CAPITAL LETTER Z WITH PALATAL HOOK
+    ('\u{A7C7}', CharMapAction::SubChar('ꟈ')), // LATIN CAPITAL LETTER D WITH SHORT STROKE OVERLAY
+    ('\u{A7C9}', CharMapAction::SubChar('ꟊ')), // LATIN CAPITAL LETTER S WITH SHORT STROKE OVERLAY
+    ('\u{A7D0}', CharMapAction::SubChar('ꟑ')), // LATIN CAPITAL LETTER CLOSED INSULAR G
+    ('\u{A7D6}', CharMapAction::SubChar('ꟗ')), // LATIN CAPITAL LETTER MIDDLE SCOTS S
+    ('\u{A7D8}', CharMapAction::SubChar('ꟙ')), // LATIN CAPITAL LETTER SIGMOID S
+    ('\u{A7F5}', CharMapAction::SubChar('ꟶ')), // LATIN CAPITAL LETTER REVERSED HALF H
+    ('\u{AB70}', CharMapAction::SubChar('Ꭰ')), // CHEROKEE SMALL LETTER A
+    ('\u{AB71}', CharMapAction::SubChar('Ꭱ')), // CHEROKEE SMALL LETTER E
+    ('\u{AB72}', CharMapAction::SubChar('Ꭲ')), // CHEROKEE SMALL LETTER I
+    ('\u{AB73}', CharMapAction::SubChar('Ꭳ')), // CHEROKEE SMALL LETTER O
+    ('\u{AB74}', CharMapAction::SubChar('Ꭴ')), // CHEROKEE SMALL LETTER U
+    ('\u{AB75}', CharMapAction::SubChar('Ꭵ')), // CHEROKEE SMALL LETTER V
+    ('\u{AB76}', CharMapAction::SubChar('Ꭶ')), // CHEROKEE SMALL LETTER GA
+    ('\u{AB77}', CharMapAction::SubChar('Ꭷ')), // CHEROKEE SMALL LETTER KA
+    ('\u{AB78}', CharMapAction::SubChar('Ꭸ')), // CHEROKEE SMALL LETTER GE
+    ('\u{AB79}', CharMapAction::SubChar('Ꭹ')), // CHEROKEE SMALL LETTER GI
+    ('\u{AB7A}', CharMapAction::SubChar('Ꭺ')), // CHEROKEE SMALL LETTER GO
+    ('\u{AB7B}', CharMapAction::SubChar('Ꭻ')), // CHEROKEE SMALL LETTER GU
+    ('\u{AB7C}', CharMapAction::SubChar('Ꭼ')), // CHEROKEE SMALL LETTER GV
+    ('\u{AB7D}', CharMapAction::SubChar('Ꭽ')), // CHEROKEE SMALL LETTER HA
+    ('\u{AB7E}', CharMapAction::SubChar('Ꭾ')), // CHEROKEE SMALL LETTER HE
+    ('\u{AB7F}', CharMapAction::SubChar('Ꭿ')), // CHEROKEE SMALL LETTER HI
+    ('\u{AB80}', CharMapAction::SubChar('Ꮀ')), // CHEROKEE SMALL LETTER HO
+    ('\u{AB81}', CharMapAction::SubChar('Ꮁ')), // CHEROKEE SMALL LETTER HU
+    ('\u{AB82}', CharMapAction::SubChar('Ꮂ')), // CHEROKEE SMALL LETTER HV
+    ('\u{AB83}', CharMapAction::SubChar('Ꮃ')), // CHEROKEE SMALL LETTER LA
+    ('\u{AB84}', CharMapAction::SubChar('Ꮄ')), // CHEROKEE SMALL LETTER LE
+    ('\u{AB85}', CharMapAction::SubChar('Ꮅ')), // CHEROKEE SMALL LETTER LI
+    ('\u{AB86}', CharMapAction::SubChar('Ꮆ')), // CHEROKEE SMALL LETTER LO
+    ('\u{AB87}', CharMapAction::SubChar('Ꮇ')), // CHEROKEE SMALL LETTER LU
+    ('\u{AB88}', CharMapAction::SubChar('Ꮈ')), // CHEROKEE SMALL LETTER LV
+    ('\u{AB89}', CharMapAction::SubChar('Ꮉ')), // CHEROKEE SMALL LETTER MA
+    ('\u{AB8A}', CharMapAction::SubChar('Ꮊ')), // CHEROKEE SMALL LETTER ME
+    ('\u{AB8B}', CharMapAction::SubChar('Ꮋ')), // CHEROKEE SMALL LETTER MI
+    ('\u{AB8C}', CharMapAction::SubChar('Ꮌ')), // CHEROKEE SMALL LETTER MO
+    ('\u{AB8D}', CharMapAction::SubChar('Ꮍ')), // CHEROKEE SMALL LETTER MU
+    ('\u{AB8E}', CharMapAction::SubChar('Ꮎ')), // CHEROKEE SMALL LETTER NA
+    ('\u{AB8F}', CharMapAction::SubChar('Ꮏ')), // CHEROKEE SMALL LETTER HNA
+    ('\u{AB90}', CharMapAction::SubChar('Ꮐ')), // CHEROKEE SMALL LETTER NAH
+    ('\u{AB91}', CharMapAction::SubChar('Ꮑ')), // CHEROKEE SMALL LETTER NE
+    ('\u{AB92}', CharMapAction::SubChar('Ꮒ')), // CHEROKEE SMALL LETTER NI
+    ('\u{AB93}', CharMapAction::SubChar('Ꮓ')), // CHEROKEE SMALL LETTER NO
+    ('\u{AB94}', CharMapAction::SubChar('Ꮔ')), // CHEROKEE SMALL LETTER NU
+    ('\u{AB95}', CharMapAction::SubChar('Ꮕ')), // CHEROKEE SMALL LETTER NV
+    ('\u{AB96}', CharMapAction::SubChar('Ꮖ')), // CHEROKEE SMALL LETTER QUA
+    ('\u{AB97}', CharMapAction::SubChar('Ꮗ')), // CHEROKEE SMALL LETTER QUE
+    ('\u{AB98}', CharMapAction::SubChar('Ꮘ')), // CHEROKEE SMALL LETTER QUI
+    ('\u{AB99}', CharMapAction::SubChar('Ꮙ')), // CHEROKEE SMALL LETTER QUO
+    ('\u{AB9A}', CharMapAction::SubChar('Ꮚ')), // CHEROKEE SMALL LETTER QUU
+    ('\u{AB9B}', CharMapAction::SubChar('Ꮛ')), // CHEROKEE SMALL LETTER QUV
+    ('\u{AB9C}', CharMapAction::SubChar('Ꮜ')), // CHEROKEE SMALL LETTER SA
+    ('\u{AB9D}', CharMapAction::SubChar('Ꮝ')), // CHEROKEE SMALL LETTER S
+    ('\u{AB9E}', CharMapAction::SubChar('Ꮞ')), // CHEROKEE SMALL LETTER SE
+    ('\u{AB9F}', CharMapAction::SubChar('Ꮟ')), // CHEROKEE SMALL LETTER SI
+    ('\u{ABA0}', CharMapAction::SubChar('Ꮠ')), // CHEROKEE SMALL LETTER SO
+    ('\u{ABA1}', CharMapAction::SubChar('Ꮡ')), // CHEROKEE SMALL LETTER SU
+    ('\u{ABA2}', CharMapAction::SubChar('Ꮢ')), // CHEROKEE SMALL LETTER SV
+    ('\u{ABA3}', CharMapAction::SubChar('Ꮣ')), // CHEROKEE SMALL LETTER DA
+    ('\u{ABA4}', CharMapAction::SubChar('Ꮤ')), // CHEROKEE SMALL LETTER TA
+    ('\u{ABA5}', CharMapAction::SubChar('Ꮥ')), // CHEROKEE SMALL LETTER DE
+    ('\u{ABA6}', CharMapAction::SubChar('Ꮦ')), // CHEROKEE SMALL LETTER TE
+    ('\u{ABA7}', CharMapAction::SubChar('Ꮧ')), // CHEROKEE SMALL LETTER DI
+    ('\u{ABA8}', CharMapAction::SubChar('Ꮨ')), // CHEROKEE SMALL LETTER TI
+    ('\u{ABA9}', CharMapAction::SubChar('Ꮩ')), // CHEROKEE SMALL LETTER DO
+    ('\u{ABAA}', CharMapAction::SubChar('Ꮪ')), // CHEROKEE SMALL LETTER DU
+    ('\u{ABAB}', CharMapAction::SubChar('Ꮫ')), // CHEROKEE SMALL LETTER DV
+    ('\u{ABAC}', CharMapAction::SubChar('Ꮬ')), // CHEROKEE SMALL LETTER DLA
+    ('\u{ABAD}', CharMapAction::SubChar('Ꮭ')), // CHEROKEE SMALL LETTER TLA
+    ('\u{ABAE}', CharMapAction::SubChar('Ꮮ')), // CHEROKEE SMALL LETTER TLE
+    ('\u{ABAF}', CharMapAction::SubChar('Ꮯ')), // CHEROKEE SMALL LETTER TLI
+    ('\u{ABB0}', CharMapAction::SubChar('Ꮰ')), // CHEROKEE SMALL LETTER TLO
+    ('\u{ABB1}', CharMapAction::SubChar('Ꮱ')), // CHEROKEE SMALL LETTER TLU
+    ('\u{ABB2}', CharMapAction::SubChar('Ꮲ')), // CHEROKEE SMALL LETTER TLV
+    ('\u{ABB3}', CharMapAction::SubChar('Ꮳ')), // CHEROKEE SMALL LETTER TSA
+    ('\u{ABB4}', CharMapAction::SubChar('Ꮴ')), // CHEROKEE SMALL LETTER TSE
+    ('\u{ABB5}', CharMapAction::SubChar('Ꮵ')), // CHEROKEE SMALL LETTER TSI
+    ('\u{ABB6}', CharMapAction::SubChar('Ꮶ')), // CHEROKEE SMALL LETTER TSO
+    ('\u{ABB7}', CharMapAction::SubChar('Ꮷ')), // CHEROKEE SMALL LETTER TSU
+    ('\u{ABB8}', CharMapAction::SubChar('Ꮸ')), // CHEROKEE SMALL LETTER TSV
+    ('\u{ABB9}', CharMapAction::SubChar('Ꮹ')), // CHEROKEE SMALL LETTER WA
+    ('\u{ABBA}', CharMapAction::SubChar('Ꮺ')), // CHEROKEE SMALL LETTER WE
+    ('\u{ABBB}', CharMapAction::SubChar('Ꮻ')), // CHEROKEE SMALL LETTER WI
+    ('\u{ABBC}', CharMapAction::SubChar('Ꮼ')), // CHEROKEE SMALL LETTER WO
+    ('\u{ABBD}', CharMapAction::SubChar('Ꮽ')), // CHEROKEE SMALL LETTER WU
+    ('\u{ABBE}', CharMapAction::SubChar('Ꮾ')), // CHEROKEE SMALL LETTER WV
+    ('\u{ABBF}', CharMapAction::SubChar('Ꮿ')), // CHEROKEE SMALL LETTER YA
+    ('\u{FB00}', CharMapAction::SubStr("ff")), // LATIN SMALL LIGATURE FF
+    ('\u{FB01}', CharMapAction::SubStr("fi")), // LATIN SMALL LIGATURE FI
+    ('\u{FB02}', CharMapAction::SubStr("fl")), // LATIN SMALL LIGATURE FL
+    ('\u{FB03}', CharMapAction::SubStr("ffi")), // LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', CharMapAction::SubStr("ffl")), // LATIN SMALL LIGATURE FFL
+    ('\u{FB05}', CharMapAction::SubStr("st")), // LATIN SMALL LIGATURE LONG S T
+    ('\u{FB06}', CharMapAction::SubStr("st")), // LATIN SMALL LIGATURE ST
+    ('\u{FB13}', CharMapAction::SubStr("մն")), // ARMENIAN SMALL LIGATURE MEN NOW
+    ('\u{FB14}', CharMapAction::SubStr("մե")), // ARMENIAN SMALL LIGATURE MEN ECH
+    ('\u{FB15}', CharMapAction::SubStr("մի")), // ARMENIAN SMALL LIGATURE MEN INI
+    ('\u{FB16}', CharMapAction::SubStr("վն")), // ARMENIAN SMALL LIGATURE VEW NOW
+    ('\u{FB17}', CharMapAction::SubStr("մխ")), // ARMENIAN SMALL LIGATURE MEN XEH
+    ('\u{FF21}', CharMapAction::SubChar('ａ')), // FULLWIDTH LATIN CAPITAL LETTER A
+    ('\u{FF22}', CharMapAction::SubChar('ｂ')), // FULLWIDTH LATIN CAPITAL LETTER B
+    ('\u{FF23}', CharMapAction::SubChar('ｃ')), // FULLWIDTH LATIN CAPITAL LETTER C
+    ('\u{FF24}', CharMapAction::SubChar('ｄ')), // FULLWIDTH LATIN CAPITAL LETTER D
+    ('\u{FF25}', CharMapAction::SubChar('ｅ')), // FULLWIDTH LATIN CAPITAL LETTER E
+    ('\u{FF26}', CharMapAction::SubChar('ｆ')), // FULLWIDTH LATIN CAPITAL LETTER F
+    ('\u{FF27}', CharMapAction::SubChar('ｇ')), // FULLWIDTH LATIN CAPITAL LETTER G
+    ('\u{FF28}', CharMapAction::SubChar('ｈ')), // FULLWIDTH LATIN CAPITAL LETTER H
+    ('\u{FF29}', CharMapAction::SubChar('ｉ')), // FULLWIDTH LATIN CAPITAL LETTER I
+    ('\u{FF2A}', CharMapAction::SubChar('ｊ')), // FULLWIDTH LATIN CAPITAL LETTER J
+    ('\u{FF2B}', CharMapAction::SubChar('ｋ')), // FULLWIDTH LATIN CAPITAL LETTER K
+    ('\u{FF2C}', CharMapAction::SubChar('ｌ')), // FULLWIDTH LATIN CAPITAL LETTER L
+    ('\u{FF2D}', CharMapAction::SubChar('ｍ')), // FULLWIDTH LATIN CAPITAL LETTER M
+    ('\u{FF2E}', CharMapAction::SubChar('ｎ')), // FULLWIDTH LATIN CAPITAL LETTER N
+    ('\u{FF2F}', CharMapAction::SubChar('ｏ')), // FULLWIDTH LATIN CAPITAL LETTER O
+    ('\u{FF30}', CharMapAction::SubChar('ｐ')), // FULLWIDTH LATIN CAPITAL LETTER P
+    ('\u{FF31}', CharMapAction::SubChar('ｑ')), // FULLWIDTH LATIN CAPITAL LETTER Q
+    ('\u{FF32}', CharMapAction::SubChar('ｒ')), // FULLWIDTH LATIN CAPITAL LETTER R
+    ('\u{FF33}', CharMapAction::SubChar('ｓ')), // FULLWIDTH LATIN CAPITAL LETTER S
+    ('\u{FF34}', CharMapAction::SubChar('ｔ')), // FULLWIDTH LATIN CAPITAL LETTER T
+    ('\u{FF35}', CharMapAction::SubChar('ｕ')), // FULLWIDTH LATIN CAPITAL LETTER U
+    ('\u{FF36}', CharMapAction::SubChar('ｖ')), // FULLWIDTH LATIN CAPITAL LETTER V
+    ('\u{FF37}', CharMapAction::SubChar('ｗ')), // FULLWIDTH LATIN CAPITAL LETTER W
+    ('\u{FF38}', CharMapAction::SubChar('ｘ')), // FULLWIDTH LATIN CAPITAL LETTER X
+    ('\u{FF39}', CharMapAction::SubChar('ｙ')), // FULLWIDTH LATIN CAPITAL LETTER Y
+    ('\u{FF3A}', CharMapAction::SubChar('ｚ')), // FULLWIDTH LATIN CAPITAL LETTER Z
+    ('\u{10400}', CharMapAction::SubChar('𐐨')), // DESERET CAPITAL LETTER LONG I
+    ('\u{10401}', CharMapAction::SubChar('𐐩')), // DESERET CAPITAL LETTER LONG E
+    ('\u{10402}', CharMapAction::SubChar('𐐪')), // DESERET CAPITAL LETTER LONG A
+    ('\u{10403}', CharMapAction::SubChar('𐐫')), // DESERET CAPITAL LETTER LONG AH
+    ('\u{10404}', CharMapAction::SubChar('𐐬')), // DESERET CAPITAL LETTER LONG O
+    ('\u{10405}', CharMapAction::SubChar('𐐭')), // DESERET CAPITAL LETTER LONG OO
+    ('\u{10406}', CharMapAction::SubChar('𐐮')), // DESERET CAPITAL LETTER SHORT I
+    ('\u{10407}', CharMapAction::SubChar('𐐯')), // DESERET CAPITAL LETTER SHORT E
+    ('\u{10408}', CharMapAction::SubChar('𐐰')), // DESERET CAPITAL LETTER SHORT A
+    ('\u{10409}', CharMapAction::SubChar('𐐱')), // DESERET CAPITAL LETTER SHORT AH
+    ('\u{1040A}', CharMapAction::SubChar('𐐲')), // DESERET CAPITAL LETTER SHORT O
+    ('\u{1040B}', CharMapAction::SubChar('𐐳')), // DESERET CAPITAL LETTER SHORT OO
+    ('\u{1040C}', CharMapAction::SubChar('𐐴')), // DESERET CAPITAL LETTER AY
+    ('\u{1040D}', CharMapAction::SubChar('𐐵')), // DESERET CAPITAL LETTER OW
+    ('\u{1040E}', CharMapAction::SubChar('𐐶')), // DESERET CAPITAL LETTER WU
+    ('\u{1040F}', CharMapAction::SubChar('𐐷')), // DESERET CAPITAL LETTER YEE
+    ('\u{10410}', CharMapAction::SubChar('𐐸')), // DESERET CAPITAL LETTER H
+    ('\u{10411}', CharMapAction::SubChar('𐐹')), // DESERET CAPITAL LETTER PEE
+    ('\u{10412}', CharMapAction::SubChar('𐐺')), // DESERET CAPITAL LETTER BEE
+    ('\u{10413}', CharMapAction::SubChar('𐐻')), // DESERET CAPITAL LETTER TEE
+    ('\u{10414}', CharMapAction::SubChar('𐐼')), // DESERET CAPITAL LETTER DEE
+    ('\u{10415}', CharMapAction::SubChar('𐐽')), // DESERET CAPITAL LETTER CHEE
+    ('\u{10416}', CharMapAction::SubChar('𐐾')), // DESERET CAPITAL LETTER JEE
+    ('\u{10417}', CharMapAction::SubChar('𐐿')), // DESERET CAPITAL LETTER KAY
+    ('\u{10418}', CharMapAction::SubChar('𐑀')), // DESERET CAPITAL LETTER GAY
+    ('\u{10419}', CharMapAction::SubChar('𐑁')), // DESERET CAPITAL LETTER EF
+    ('\u{1041A}', CharMapAction::SubChar('𐑂')), // DESERET CAPITAL LETTER VEE
+    ('\u{1041B}', CharMapAction::SubChar('𐑃')), // DESERET CAPITAL LETTER ETH
+    ('\u{1041C}', CharMapAction::SubChar('𐑄')), // DESERET CAPITAL LETTER THEE
+    ('\u{1041D}', CharMapAction::SubChar('𐑅')), // DESERET CAPITAL LETTER ES
+    ('\u{1041E}', CharMapAction::SubChar('𐑆')), // DESERET CAPITAL LETTER ZEE
+    ('\u{1041F}', CharMapAction::SubChar('𐑇')), // DESERET CAPITAL LETTER ESH
+    ('\u{10420}', CharMapAction::SubChar('𐑈')), // DESERET CAPITAL LETTER ZHEE
+    ('\u{10421}', CharMapAction::SubChar('𐑉')), // DESERET CAPITAL LETTER ER
+    ('\u{10422}', CharMapAction::SubChar('𐑊')), // DESERET CAPITAL LETTER EL
+    ('\u{10423}', CharMapAction::SubChar('𐑋')), // DESERET CAPITAL LETTER EM
+    ('\u{10424}', CharMapAction::SubChar('𐑌')), // DESERET CAPITAL LETTER EN
+    ('\u{10425}', CharMapAction::SubChar('𐑍')), // DESERET CAPITAL LETTER ENG
+    ('\u{10426}', CharMapAction::SubChar('𐑎')), // DESERET CAPITAL LETTER OI
+    ('\u{10427}', CharMapAction::SubChar('𐑏')), // DESERET CAPITAL LETTER EW
+    ('\u{104B0}', CharMapAction::SubChar('𐓘')), // OSAGE CAPITAL LETTER A
+    ('\u{104B1}', CharMapAction::SubChar('𐓙')), // OSAGE CAPITAL LETTER AI
+    ('\u{104B2}', CharMapAction::SubChar('𐓚')), // OSAGE CAPITAL LETTER AIN
+    ('\u{104B3}', CharMapAction::SubChar('𐓛')), // OSAGE CAPITAL LETTER AH
+    ('\u{104B4}', CharMapAction::SubChar('𐓜')), // OSAGE CAPITAL LETTER BRA
+    ('\u{104B5}', CharMapAction::SubChar('𐓝')), // OSAGE CAPITAL LETTER CHA
+    ('\u{104B6}', CharMapAction::SubChar('𐓞')), // OSAGE CAPITAL LETTER EHCHA
+    ('\u{104B7}', CharMapAction::SubChar('𐓟')), // OSAGE CAPITAL LETTER E
+    ('\u{104B8}', CharMapAction::SubChar('𐓠')), // OSAGE CAPITAL LETTER EIN
+    ('\u{104B9}', CharMapAction::SubChar('𐓡')), // OSAGE CAPITAL LETTER HA
+    ('\u{104BA}', CharMapAction::SubChar('𐓢')), // OSAGE CAPITAL LETTER HYA
+    ('\u{104BB}', CharMapAction::SubChar('𐓣')), // OSAGE CAPITAL LETTER I
+    ('\u{104BC}', CharMapAction::SubChar('𐓤')), // OSAGE CAPITAL LETTER KA
+    ('\u{104BD}', CharMapAction::SubChar('𐓥')), // OSAGE CAPITAL LETTER EHKA
+    ('\u{104BE}', CharMapAction::SubChar('𐓦')), // OSAGE CAPITAL LETTER KYA
+    ('\u{104BF}', CharMapAction::SubChar('𐓧')), // OSAGE CAPITAL LETTER LA
+    ('\u{104C0}', CharMapAction::SubChar('𐓨')), // OSAGE CAPITAL LETTER MA
+    ('\u{104C1}', CharMapAction::SubChar('𐓩')), // OSAGE CAPITAL LETTER NA
+    ('\u{104C2}', CharMapAction::SubChar('𐓪')), // OSAGE CAPITAL LETTER O
+    ('\u{104C3}', CharMapAction::SubChar('𐓫')), // OSAGE CAPITAL LETTER OIN
+    ('\u{104C4}', CharMapAction::SubChar('𐓬')), // OSAGE CAPITAL LETTER PA
+    ('\u{104C5}', CharMapAction::SubChar('𐓭')), // OSAGE CAPITAL LETTER EHPA
+    ('\u{104C6}', CharMapAction::SubChar('𐓮')), // OSAGE CAPITAL LETTER SA
+    ('\u{104C7}', CharMapAction::SubChar('𐓯')), // OSAGE CAPITAL LETTER SHA
+    ('\u{104C8}', CharMapAction::SubChar('𐓰')), // OSAGE CAPITAL LETTER TA
+    ('\u{104C9}', CharMapAction::SubChar('𐓱')), // OSAGE CAPITAL LETTER EHTA
+    ('\u{104CA}', CharMapAction::SubChar('𐓲')), // OSAGE CAPITAL LETTER TSA
+    ('\u{104CB}', CharMapAction::SubChar('𐓳')), // OSAGE CAPITAL LETTER EHTSA
+    ('\u{104CC}', CharMapAction::SubChar('𐓴')), // OSAGE CAPITAL LETTER TSHA
+    ('\u{104CD}', CharMapAction::SubChar('𐓵')), // OSAGE CAPITAL LETTER DHA
+    ('\u{104CE}', CharMapAction::SubChar('𐓶')), // OSAGE CAPITAL LETTER U
+    ('\u{104CF}', CharMapAction::SubChar('𐓷')), // OSAGE CAPITAL LETTER WA
+    ('\u{104D0}', CharMapAction::SubChar('𐓸')), // OSAGE CAPITAL LETTER KHA
+    ('\u{104D1}', CharMapAction::SubChar('𐓹')), // OSAGE CAPITAL LETTER GHA
+    ('\u{104D2}', CharMapAction::SubChar('𐓺')), // OSAGE CAPITAL LETTER ZA
+    ('\u{104D3}', CharMapAction::SubChar('𐓻')), // OSAGE CAPITAL LETTER ZHA
+    ('\u{10570}', CharMapAction::SubChar('𐖗')), // VITHKUQI CAPITAL LETTER A
+    ('\u{10571}', CharMapAction::SubChar('𐖘')), // VITHKUQI CAPITAL LETTER BBE
+    ('\u{10572}', CharMapAction::SubChar('𐖙')), // VITHKUQI CAPITAL LETTER BE
+    ('\u{10573}', CharMapAction::SubChar('𐖚')), // VITHKUQI CAPITAL LETTER CE
+    ('\u{10574}', CharMapAction::SubChar('𐖛')), // VITHKUQI CAPITAL LETTER CHE
+    ('\u{10575}', CharMapAction::SubChar('𐖜')), // VITHKUQI CAPITAL LETTER DE
+    ('\u{10576}', CharMapAction::SubChar('𐖝')), // VITHKUQI CAPITAL LETTER DHE
+    ('\u{10577}', CharMapAction::SubChar('𐖞')), // VITHKUQI CAPITAL LETTER EI
+    ('\u{10578}', CharMapAction::SubChar('𐖟')), // VITHKUQI CAPITAL LETTER E
+    ('\u{10579}', CharMapAction::SubChar('𐖠')), // VITHKUQI CAPITAL LETTER FE
+    ('\u{1057A}', CharMapAction::SubChar('𐖡')), // VITHKUQI CAPITAL LETTER GA
+    ('\u{1057C}', CharMapAction::SubChar('𐖣')), // VITHKUQI CAPITAL LETTER HA
+    ('\u{1057D}', CharMapAction::SubChar('𐖤')), // VITHKUQI CAPITAL LETTER HHA
+    ('\u{1057E}', CharMapAction::SubChar('𐖥')), // VITHKUQI CAPITAL LETTER I
+    ('\u{1057F}', CharMapAction::SubChar('𐖦')), // VITHKUQI CAPITAL LETTER IJE
+    ('\u{10580}', CharMapAction::SubChar('𐖧')), // VITHKUQI CAPITAL LETTER JE
+    ('\u{10581}', CharMapAction::SubChar('𐖨')), // VITHKUQI CAPITAL LETTER KA
+    ('\u{10582}', CharMapAction::SubChar('𐖩')), // VITHKUQI CAPITAL LETTER LA
+    ('\u{10583}', CharMapAction::SubChar('𐖪')), // VITHKUQI CAPITAL LETTER LLA
+    ('\u{10584}', CharMapAction::SubChar('𐖫')), // VITHKUQI CAPITAL LETTER ME
+    ('\u{10585}', CharMapAction::SubChar('𐖬')), // VITHKUQI CAPITAL LETTER NE
+    ('\u{10586}', CharMapAction::SubChar('𐖭')), // VITHKUQI CAPITAL LETTER NJE
+    ('\u{10587}', CharMapAction::SubChar('𐖮')), // VITHKUQI CAPITAL LETTER O
+    ('\u{10588}', CharMapAction::SubChar('𐖯')), // VITHKUQI CAPITAL LETTER PE
+    ('\u{10589}', CharMapAction::SubChar('𐖰')), // VITHKUQI CAPITAL LETTER QA
+    ('\u{1058A}', CharMapAction::SubChar('𐖱')), // VITHKUQI CAPITAL LETTER RE
+    ('\u{1058C}', CharMapAction::SubChar('𐖳')), // VITHKUQI CAPITAL LETTER SE
+    ('\u{1058D}', CharMapAction::SubChar('𐖴')), // VITHKUQI CAPITAL LETTER SHE
+    ('\u{1058E}', CharMapAction::SubChar('𐖵')), // VITHKUQI CAPITAL LETTER TE
+    ('\u{1058F}', CharMapAction::SubChar('𐖶')), // VITHKUQI CAPITAL LETTER THE
+    ('\u{10590}', CharMapAction::SubChar('𐖷')), // VITHKUQI CAPITAL LETTER U
+    ('\u{10591}', CharMapAction::SubChar('𐖸')), // VITHKUQI CAPITAL LETTER VE
+    ('\u{10592}', CharMapAction::SubChar('𐖹')), // VITHKUQI CAPITAL LETTER XE
+    ('\u{10594}', CharMapAction::SubChar('𐖻')), // VITHKUQI CAPITAL LETTER Y
+    ('\u{10595}', CharMapAction::SubChar('𐖼')), // VITHKUQI CAPITAL LETTER ZE
+    ('\u{10C80}', CharMapAction::SubChar('𐳀')), // OLD HUNGARIAN CAPITAL LETTER A
+    ('\u{10C81}', CharMapAction::SubChar('𐳁')), // OLD HUNGARIAN CAPITAL LETTER AA
+    ('\u{10C82}', CharMapAction::SubChar('𐳂')), // OLD HUNGARIAN CAPITAL LETTER EB
+    ('\u{10C83}', CharMapAction::SubChar('𐳃')), // OLD HUNGARIAN CAPITAL LETTER AMB
+    ('\u{10C84}', CharMapAction::SubChar('𐳄')), // OLD HUNGARIAN CAPITAL LETTER EC
+    ('\u{10C85}', CharMapAction::SubChar('𐳅')), // OLD HUNGARIAN CAPITAL LETTER ENC
+    ('\u{10C86}', CharMapAction::SubChar('𐳆')), // OLD HUNGARIAN CAPITAL LETTER ECS
+    ('\u{10C87}', CharMapAction::SubChar('𐳇')), // OLD HUNGARIAN CAPITAL LETTER ED
+    ('\u{10C88}', CharMapAction::SubChar('𐳈')), // OLD HUNGARIAN CAPITAL LETTER AND
+    ('\u{10C89}', CharMapAction::SubChar('𐳉')), // OLD HUNGARIAN CAPITAL LETTER E
+    ('\u{10C8A}', CharMapAction::SubChar('𐳊')), // OLD HUNGARIAN CAPITAL LETTER CLOSE E
+    ('\u{10C8B}', CharMapAction::SubChar('𐳋')), // OLD HUNGARIAN CAPITAL LETTER EE
+    ('\u{10C8C}', CharMapAction::SubChar('𐳌')), // OLD HUNGARIAN CAPITAL LETTER EF
+    ('\u{10C8D}', CharMapAction::SubChar('𐳍')), // OLD HUNGARIAN CAPITAL LETTER EG
+    ('\u{10C8E}', CharMapAction::SubChar('𐳎')), // OLD HUNGARIAN CAPITAL LETTER EGY
+    ('\u{10C8F}', CharMapAction::SubChar('𐳏')), // OLD HUNGARIAN CAPITAL LETTER EH
+    ('\u{10C90}', CharMapAction::SubChar('𐳐')), // OLD HUNGARIAN CAPITAL LETTER I
+    ('\u{10C91}', CharMapAction::SubChar('𐳑')), // OLD HUNGARIAN CAPITAL LETTER II
+    ('\u{10C92}', CharMapAction::SubChar('𐳒')), // OLD HUNGARIAN CAPITAL LETTER EJ
+    ('\u{10C93}', CharMapAction::SubChar('𐳓')), // OLD HUNGARIAN CAPITAL LETTER EK
+    ('\u{10C94}', CharMapAction::SubChar('𐳔')), // OLD HUNGARIAN CAPITAL LETTER AK
+    ('\u{10C95}', CharMapAction::SubChar('𐳕')), // OLD HUNGARIAN CAPITAL LETTER UNK
+    ('\u{10C96}', CharMapAction::SubChar('𐳖')), // OLD HUNGARIAN CAPITAL LETTER EL
+    ('\u{10C97}', CharMapAction::SubChar('𐳗')), // OLD HUNGARIAN CAPITAL LETTER ELY
+    ('\u{10C98}', CharMapAction::SubChar('𐳘')), // OLD HUNGARIAN CAPITAL LETTER EM
+    ('\u{10C99}', CharMapAction::SubChar('𐳙')), // OLD HUNGARIAN CAPITAL LETTER EN
+    ('\u{10C9A}', CharMapAction::SubChar('𐳚')), // OLD HUNGARIAN CAPITAL LETTER ENY
+    ('\u{10C9B}', CharMapAction::SubChar('𐳛')), // OLD HUNGARIAN CAPITAL LETTER O
+    ('\u{10C9C}', CharMapAction::SubChar('𐳜')), // OLD HUNGARIAN CAPITAL LETTER OO
+    ('\u{10C9D}', CharMapAction::SubChar('𐳝')), // OLD HUNGARIAN CAPITAL LETTER NIKOLSBURG OE
+    ('\u{10C9E}', CharMapAction::SubChar('𐳞')), // OLD HUNGARIAN CAPITAL LETTER RUDIMENTA OE
+    ('\u{10C9F}', CharMapAction::SubChar('𐳟')), // OLD HUNGARIAN CAPITAL LETTER OEE
+    ('\u{10CA0}', CharMapAction::SubChar('𐳠')), // OLD HUNGARIAN CAPITAL LETTER EP
+    ('\u{10CA1}', CharMapAction::SubChar('𐳡')), // OLD HUNGARIAN CAPITAL LETTER EMP
+    ('\u{10CA2}', CharMapAction::SubChar('𐳢')), // OLD HUNGARIAN CAPITAL LETTER ER
+    ('\u{10CA3}', CharMapAction::SubChar('𐳣')), // OLD HUNGARIAN CAPITAL LETTER SHORT ER
+    ('\u{10CA4}', CharMapAction::SubChar('𐳤')), // OLD HUNGARIAN CAPITAL LETTER ES
+    ('\u{10CA5}', CharMapAction::SubChar('𐳥')), // OLD HUNGARIAN CAPITAL LETTER ESZ
+    ('\u{10CA6}', CharMapAction::SubChar('𐳦')), // OLD HUNGARIAN CAPITAL LETTER ET
+    ('\u{10CA7}', CharMapAction::SubChar('𐳧')), // OLD HUNGARIAN CAPITAL LETTER ENT
+    ('\u{10CA8}', CharMapAction::SubChar('𐳨')), // OLD HUNGARIAN CAPITAL LETTER ETY
+    ('\u{10CA9}', CharMapAction::SubChar('𐳩')), // OLD HUNGARIAN CAPITAL LETTER ECH
+    ('\u{10CAA}', CharMapAction::SubChar('𐳪')), // OLD HUNGARIAN CAPITAL LETTER U
+    ('\u{10CAB}', CharMapAction::SubChar('𐳫')), // OLD HUNGARIAN CAPITAL LETTER UU
+    ('\u{10CAC}', CharMapAction::SubChar('𐳬')), // OLD HUNGARIAN CAPITAL LETTER NIKOLSBURG UE
+    ('\u{10CAD}', CharMapAction::SubChar('𐳭')), // OLD HUNGARIAN CAPITAL LETTER RUDIMENTA UE
+    ('\u{10CAE}', CharMapAction::SubChar('𐳮')), // OLD HUNGARIAN CAPITAL LETTER EV
+    ('\u{10CAF}', CharMapAction::SubChar('𐳯')), // OLD HUNGARIAN CAPITAL LETTER EZ
+    ('\u{10CB0}', CharMapAction::SubChar('𐳰')), // OLD HUNGARIAN CAPITAL LETTER EZS
+    ('\u{10CB1}', CharMapAction::SubChar('𐳱')), // OLD HUNGARIAN CAPITAL LETTER ENT-SHAPED SIGN
+    ('\u{10CB2}', CharMapAction::SubChar('𐳲')), // OLD HUNGARIAN CAPITAL LETTER US
+    ('\u{118A0}', CharMapAction::SubChar('𑣀')), // WARANG CITI CAPITAL LETTER NGAA
+    ('\u{118A1}', CharMapAction::SubChar('𑣁')), // WARANG CITI CAPITAL LETTER A
+    ('\u{118A2}', CharMapAction::SubChar('𑣂')), // WARANG CITI CAPITAL LETTER WI
+    ('\u{118A3}', CharMapAction::SubChar('𑣃')), // WARANG CITI CAPITAL LETTER YU
+    ('\u{118A4}', CharMapAction::SubChar('𑣄')), // WARANG CITI CAPITAL LETTER YA
+    ('\u{118A5}', CharMapAction::SubChar('𑣅')), // WARANG CITI CAPITAL LETTER YO
+    ('\u{118A6}', CharMapAction::SubChar('𑣆')), // WARANG CITI CAPITAL LETTER II
+    ('\u{118A7}', CharMapAction::SubChar('𑣇')), // WARANG CITI CAPITAL LETTER UU
+    ('\u{118A8}', CharMapAction::SubChar('𑣈')), // WARANG CITI CAPITAL LETTER E
+    ('\u{118A9}', CharMapAction::SubChar('𑣉')), // WARANG CITI CAPITAL LETTER O
+    ('\u{118AA}', CharMapAction::SubChar('𑣊')), // WARANG CITI CAPITAL LETTER ANG
+    ('\u{118AB}', CharMapAction::SubChar('𑣋')), // WARANG CITI CAPITAL LETTER GA
+    ('\u{118AC}', CharMapAction::SubChar('𑣌')), // WARANG CITI CAPITAL LETTER KO
+    ('\u{118AD}', CharMapAction::SubChar('𑣍')), // WARANG CITI CAPITAL LETTER ENY
+    ('\u{118AE}', CharMapAction::SubChar('𑣎')), // WARANG CITI CAPITAL LETTER YUJ
+    ('\u{118AF}', CharMapAction::SubChar('𑣏')), // WARANG CITI CAPITAL LETTER UC
+    ('\u{118B0}', CharMapAction::SubChar('𑣐')), // WARANG CITI CAPITAL LETTER ENN
+    ('\u{118B1}', CharMapAction::SubChar('𑣑')), // WARANG CITI CAPITAL LETTER ODD
+    ('\u{118B2}', CharMapAction::SubChar('𑣒')), // WARANG CITI CAPITAL LETTER TTE
+    ('\u{118B3}', CharMapAction::SubChar('𑣓')), // WARANG CITI CAPITAL LETTER NUNG
+    ('\u{118B4}', CharMapAction::SubChar('𑣔')), // WARANG CITI CAPITAL LETTER DA
+    ('\u{118B5}', CharMapAction::SubChar('𑣕')), // WARANG CITI CAPITAL LETTER AT
+    ('\u{118B6}', CharMapAction::SubChar('𑣖')), // WARANG CITI CAPITAL LETTER AM
+    ('\u{118B7}', CharMapAction::SubChar('𑣗')), // WARANG CITI CAPITAL LETTER BU
+    ('\u{118B8}', CharMapAction::SubChar('𑣘')), // WARANG CITI CAPITAL LETTER PU
+    ('\u{118B9}', CharMapAction::SubChar('𑣙')), // WARANG CITI CAPITAL LETTER HIYO
+    ('\u{118BA}', CharMapAction::SubChar('𑣚')), // WARANG CITI CAPITAL LETTER HOLO
+    ('\u{118BB}', CharMapAction::SubChar('𑣛')), // WARANG CITI CAPITAL LETTER HORR
+    ('\u{118BC}', CharMapAction::SubChar('𑣜')), // WARANG CITI CAPITAL LETTER HAR
+    ('\u{118BD}', CharMapAction::SubChar('𑣝')), // WARANG CITI CAPITAL LETTER SSUU
+    ('\u{118BE}', CharMapAction::SubChar('𑣞')), // WARANG CITI CAPITAL LETTER SII
+    ('\u{118BF}', CharMapAction::SubChar('𑣟')), // WARANG CITI CAPITAL LETTER VIYO
+    ('\u{16E40}', CharMapAction::SubChar('𖹠')), // MEDEFAIDRIN CAPITAL LETTER M
+    ('\u{16E41}', CharMapAction::SubChar('𖹡')), // MEDEFAIDRIN CAPITAL LETTER S
+    ('\u{16E42}', CharMapAction::SubChar('𖹢')), // MEDEFAIDRIN CAPITAL LETTER V
+    ('\u{16E43}', CharMapAction::SubChar('𖹣')), // MEDEFAIDRIN CAPITAL LETTER W
+    ('\u{16E44}', CharMapAction::SubChar('𖹤')), // MEDEFAIDRIN CAPITAL LETTER ATIU
+    ('\u{16E45}', CharMapAction::SubChar('𖹥')), // MEDEFAIDRIN CAPITAL LETTER Z
+    ('\u{16E46}', CharMapAction::SubChar('𖹦')), // MEDEFAIDRIN CAPITAL LETTER KP
+    ('\u{16E47}', CharMapAction::SubChar('𖹧')), // MEDEFAIDRIN CAPITAL LETTER P
+    ('\u{16E48}', CharMapAction::SubChar('𖹨')), // MEDEFAIDRIN CAPITAL LETTER T
+    ('\u{16E49}', CharMapAction::SubChar('𖹩')), // MEDEFAIDRIN CAPITAL LETTER G
+    ('\u{16E4A}', CharMapAction::SubChar('𖹪')), // MEDEFAIDRIN CAPITAL LETTER F
+    ('\u{16E4B}', CharMapAction::SubChar('𖹫')), // MEDEFAIDRIN CAPITAL LETTER I
+    ('\u{16E4C}', CharMapAction::SubChar('𖹬')), // MEDEFAIDRIN CAPITAL LETTER K
+    ('\u{16E4D}', CharMapAction::SubChar('𖹭')), // MEDEFAIDRIN CAPITAL LETTER A
+    ('\u{16E4E}', CharMapAction::SubChar('𖹮')), // MEDEFAIDRIN CAPITAL LETTER J
+    ('\u{16E4F}', CharMapAction::SubChar('𖹯')), // MEDEFAIDRIN CAPITAL LETTER E
+    ('\u{16E50}', CharMapAction::SubChar('𖹰')), // MEDEFAIDRIN CAPITAL LETTER B
+    ('\u{16E51}', CharMapAction::SubChar('𖹱')), // MEDEFAIDRIN CAPITAL LETTER C
+    ('\u{16E52}', CharMapAction::SubChar('𖹲')), // MEDEFAIDRIN CAPITAL LETTER U
+    ('\u{16E53}', CharMapAction::SubChar('𖹳')), // MEDEFAIDRIN CAPITAL LETTER YU
+    ('\u{16E54}', CharMapAction::SubChar('𖹴')), // MEDEFAIDRIN CAPITAL LETTER L
+    ('\u{16E55}', CharMapAction::SubChar('𖹵')), // MEDEFAIDRIN CAPITAL LETTER Q
+    ('\u{16E56}', CharMapAction::SubChar('𖹶')), // MEDEFAIDRIN CAPITAL LETTER HP
+    ('\u{16E57}', CharMapAction::SubChar('𖹷')), // MEDEFAIDRIN CAPITAL LETTER NY
+    ('\u{16E58}', CharMapAction::SubChar('𖹸')), // MEDEFAIDRIN CAPITAL LETTER X
+    ('\u{16E59}', CharMapAction::SubChar('𖹹')), // MEDEFAIDRIN CAPITAL LETTER D
+    ('\u{16E5A}', CharMapAction::SubChar('𖹺')), // MEDEFAIDRIN CAPITAL LETTER OE
+    ('\u{16E5B}', CharMapAction::SubChar('𖹻')), // MEDEFAIDRIN CAPITAL LETTER N
+    ('\u{16E5C}', CharMapAction::SubChar('𖹼')), // MEDEFAIDRIN CAPITAL LETTER R
+    ('\u{16E5D}', CharMapAction::SubChar('𖹽')), // MEDEFAIDRIN CAPITAL LETTER O
+    ('\u{16E5E}', CharMapAction::SubChar('𖹾')), // MEDEFAIDRIN CAPITAL LETTER AI
+    ('\u{16E5F}', CharMapAction::SubChar('𖹿')), // MEDEFAIDRIN CAPITAL LETTER Y
+    ('\u{1E900}', CharMapAction::SubChar('𞤢')), // ADLAM CAPITAL LETTER ALIF
+    ('\u{1E901}', CharMapAction::SubChar('𞤣')), // ADLAM CAPITAL LETTER DAALI
+    ('\u{1E902}', CharMapAction::SubChar('𞤤')), // ADLAM CAPITAL LETTER LAAM
+    ('\u{1E903}', CharMapAction::SubChar('𞤥')), // ADLAM CAPITAL LETTER MIIM
+    ('\u{1E904}', CharMapAction::SubChar('𞤦')), // ADLAM CAPITAL LETTER BA
+    ('\u{1E905}', CharMapAction::SubChar('𞤧')), // ADLAM CAPITAL LETTER SINNYIIYHE
+    ('\u{1E906}', CharMapAction::SubChar('𞤨')), // ADLAM CAPITAL LETTER PE
+    ('\u{1E907}', CharMapAction::SubChar('𞤩')), // ADLAM CAPITAL LETTER BHE
+    ('\u{1E908}', CharMapAction::SubChar('𞤪')), // ADLAM CAPITAL LETTER RA
+    ('\u{1E909}', CharMapAction::SubChar('𞤫')), // ADLAM CAPITAL LETTER E
+    ('\u{1E90A}', CharMapAction::SubChar('𞤬')), // ADLAM CAPITAL LETTER FA
+    ('\u{1E90B}', CharMapAction::SubChar('𞤭')), // ADLAM CAPITAL LETTER I
+    ('\u{1E90C}', CharMapAction::SubChar('𞤮')), // ADLAM CAPITAL LETTER O
+    ('\u{1E90D}', CharMapAction::SubChar('𞤯')), // ADLAM CAPITAL LETTER DHA
+    ('\u{1E90E}', CharMapAction::SubChar('𞤰')), // ADLAM CAPITAL LETTER YHE
+    ('\u{1E90F}', CharMapAction::SubChar('𞤱')), // ADLAM CAPITAL LETTER WAW
+    ('\u{1E910}', CharMapAction::SubChar('𞤲')), // ADLAM CAPITAL LETTER NUN
+    ('\u{1E911}', CharMapAction::SubChar('𞤳')), // ADLAM CAPITAL LETTER KAF
+    ('\u{1E912}', CharMapAction::SubChar('𞤴')), // ADLAM CAPITAL LETTER YA
+    ('\u{1E913}', CharMapAction::SubChar('𞤵')), // ADLAM CAPITAL LETTER U
+    ('\u{1E914}', CharMapAction::SubChar('𞤶')), // ADLAM CAPITAL LETTER JIIM
+    ('\u{1E915}', CharMapAction::SubChar('𞤷')), // ADLAM CAPITAL LETTER CHI
+    ('\u{1E916}', CharMapAction::SubChar('𞤸')), // ADLAM CAPITAL LETTER HA
+    ('\u{1E917}', CharMapAction::SubChar('𞤹')), // ADLAM CAPITAL LETTER QAAF
+    ('\u{1E918}', CharMapAction::SubChar('𞤺')), // ADLAM CAPITAL LETTER GA
+    ('\u{1E919}', CharMapAction::SubChar('𞤻')), // ADLAM CAPITAL LETTER NYA
+    ('\u{1E91A}', CharMapAction::SubChar('𞤼')), // ADLAM CAPITAL LETTER TU
+    ('\u{1E91B}', CharMapAction::SubChar('𞤽')), // ADLAM CAPITAL LETTER NHA
+    ('\u{1E91C}', CharMapAction::SubChar('𞤾')), // ADLAM CAPITAL LETTER VA
+    ('\u{1E91D}', CharMapAction::SubChar('𞤿')), // ADLAM CAPITAL LETTER KHA
+    ('\u{1E91E}', CharMapAction::SubChar('𞥀')), // ADLAM CAPITAL LETTER GBE
+    ('\u{1E91F}', CharMapAction::SubChar('𞥁')), // ADLAM CAPITAL LETTER ZAL
+    ('\u{1E920}', CharMapAction::SubChar('𞥂')), // ADLAM CAPITAL LETTER KPO
+    ('\u{1E921}', CharMapAction::SubChar('𞥃')), // ADLAM CAPITAL LETTER SHA
+];
+const _: () = assert_sorted(&CASE_FOLD);
+
+/// Map tailoring [`LOWERCASE`] for Turkish and Azeri.
+pub static TURKIC_LOWERCASE: [(char, CharMapAction); 2] = [
+    ('\u{0049}', CharMapAction::SubChar('\u{0131}')), // LATIN CAPITAL LETTER I
+    ('\u{0130}', CharMapAction::SubChar('\u{0069}')), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+];
+const _: () = assert_sorted(&TURKIC_LOWERCASE);
+
+/// Map tailoring [`UPPERCASE`] and [`TITLECASE`] for Turkish and Azeri.
+pub static TURKIC_UPPERCASE: [(char, CharMapAction); 1] = [
+    ('\u{0069}', CharMapAction::SubChar('\u{0130}')), // LATIN SMALL LETTER I
+];
+
+/// Map tailoring [`CASE_FOLD`] for Turkish and Azeri.
+pub static TURKIC_CASE_FOLD: [(char, CharMapAction); 2] = [
+    ('\u{0049}', CharMapAction::SubChar('\u{0131}')), // LATIN CAPITAL LETTER I
+    ('\u{0130}', CharMapAction::SubChar('\u{0069}')), // LATIN CAPITAL LETTER I WITH DOT ABOVE
+];
+const _: () = assert_sorted(&TURKIC_CASE_FOLD);
+
+/// A case mapping.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Lowercase, e.g. `"Straße"` to `"straße"`.
+    Lower,
+    /// Uppercase, e.g. `"Straße"` to `"STRASSE"`.
+    Upper,
+    /// Titlecase, e.g. `"straße"` to `"Straße"`.
+    Title,
+    /// Case folding, e.g. `"Straße"` to `"strasse"`.
+    Fold,
+}
+
+/// An [`ActionMap`] mapping characters to a given [`Case`], optionally with
+/// Turkish and Azeri tailoring.
+///
+/// Used as an [`ActionMap`], a [`CaseMapper`] applies the maps of its case,
+/// ignoring context-dependent rules (e.g. [`Case::Title`] titlecases every
+/// character). Use [`CaseMapper::case_chars`] to apply them as well.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CaseMapper {
+    case: Case,
+    turkic: bool,
+}
+
+impl CaseMapper {
+    /// Creates a new [`CaseMapper`] for a given [`Case`] without Turkic
+    /// tailoring.
+    #[inline]
+    pub const fn new(case: Case) -> Self {
+        CaseMapper { case, turkic: false }
+    }
+
+    /// Toggles the Turkish and Azeri tailoring.
+    #[inline]
+    pub const fn turkic(mut self, enabled: bool) -> Self {
+        self.turkic = enabled;
+        self
+    }
+
+    /// Returns the [`Case`] of this [`CaseMapper`].
+    #[inline]
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    /// Returns an iterator that maps the case of characters from a given
+    /// character iterator, applying context-dependent rules as well.
+    ///
+    /// The character iterator needs to be cloneable since the final sigma
+    /// rule looks ahead past case-ignorable characters (e.g. `'` and
+    /// combining marks).
+    #[inline]
+    pub fn case_chars<I>(&self, text_chars: I) -> CasedChars<I>
+    where
+        I: Iterator<Item = char> + Clone,
+    {
+        CasedChars {
+            mapper: *self,
+            text_chars,
+            after_cased: false,
+            in_word: false,
+            out_chars: None.into_iter().chain("".chars()),
+        }
+    }
+
+    fn case_action(
+        &self,
+        case: Case,
+        c: char,
+    ) -> Option<CharMapAction<'static>> {
+        let (tailoring, actions): (&'static [_], &'static [_]) = match case {
+            Case::Lower => (&TURKIC_LOWERCASE, &LOWERCASE),
+            Case::Upper => (&TURKIC_UPPERCASE, &UPPERCASE),
+            Case::Title => (&TURKIC_UPPERCASE, &TITLECASE),
+            Case::Fold => (&TURKIC_CASE_FOLD, &CASE_FOLD),
+        };
+
+        if self.turkic {
+            if let Some(action) = <[_] as ActionMap>::map_char(tailoring, c) {
+                return Some(action);
+            }
+        }
+
+        <[_] as ActionMap>::map_char(actions, c)
+    }
+}
+
+impl ActionMap for CaseMapper {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.case_action(self.case, c)
+    }
+}
+
+/// Iterator returned by [`CaseMapper::case_chars`].
+#[derive(Clone)]
+pub struct CasedChars<I>
+where
+    I: Iterator<Item = char> + Clone,
+{
+    mapper: CaseMapper,
+    text_chars: I,
+    // Whether the last character that is not case-ignorable is cased, and
+    // whether it is alphanumeric.
+    after_cased: bool,
+    in_word: bool,
+    out_chars: iter::Chain<option::IntoIter<char>, Chars<'static>>,
+}
+
+impl<I> CasedChars<I>
+where
+    I: Iterator<Item = char> + Clone,
+{
+    fn action(&mut self, c: char) -> Option<CharMapAction<'static>> {
+        // Titlecasing only applies to the first cased letter of each word.
+        let case = match self.mapper.case {
+            Case::Title if self.in_word => Case::Lower,
+            case => case,
+        };
+
+        if case == Case::Lower {
+            if c == '\u{03A3}' && self.after_cased && !self.before_cased() {
+                return Some(CharMapAction::SubChar('\u{03C2}'));
+            }
+
+            if self.mapper.turkic
+                && c == '\u{0049}'
+                && self.text_chars.clone().next() == Some('\u{0307}')
+            {
+                self.text_chars.next();
+                return Some(CharMapAction::SubChar('\u{0069}'));
+            }
+        }
+
+        self.mapper.case_action(case, c)
+    }
+
+    // Returns whether the next character that is not case-ignorable is cased.
+    fn before_cased(&self) -> bool {
+        self.text_chars
+            .clone()
+            .find(|&c| !is_case_ignorable(c))
+            .is_some_and(is_cased)
+    }
+}
+
+impl<I> Iterator for CasedChars<I>
+where
+    I: Iterator<Item = char> + Clone,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.out_chars.next() {
+                return Some(c);
+            }
+
+            let c = self.text_chars.next()?;
+            let (d, s) = match self.action(c) {
+                None | Some(CharMapAction::Pass) => (Some(c), ""),
+                Some(CharMapAction::Delete) => (None, ""),
+                Some(CharMapAction::SubChar(d)) => (Some(d), ""),
+                Some(CharMapAction::SubStr(s)) => (None, s),
+            };
+
+            if !is_case_ignorable(c) {
+                self.after_cased = is_cased(c);
+                self.in_word = c.is_alphanumeric();
+            }
+
+            self.out_chars = d.into_iter().chain(s.chars());
+        }
+    }
+}
+
+// Returns whether a given character has the Cased property.
+#[inline]
+fn is_cased(c: char) -> bool {
+    c.is_lowercase()
+        || c.is_uppercase()
+        || matches!(
+            c,
+            '\u{01C5}'
+                | '\u{01C8}'
+                | '\u{01CB}'
+                | '\u{01F2}'
+                | '\u{1F88}'..='\u{1F8F}'
+                | '\u{1F98}'..='\u{1F9F}'
+                | '\u{1FA8}'..='\u{1FAF}'
+                | '\u{1FBC}'
+                | '\u{1FCC}'
+                | '\u{1FFC}'
+        )
+}
+
+// Returns whether a given character has the Case_Ignorable property.
+#[inline]
+fn is_case_ignorable(c: char) -> bool {
+    CASE_IGNORABLE
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// Ranges of characters with the Case_Ignorable property.
+#[rustfmt::skip]
+static CASE_IGNORABLE: [(char, char); 427] = [
+    ('\u{0027}', '\u{0027}'),
+    ('\u{002E}', '\u{002E}'),
+    ('\u{003A}', '\u{003A}'),
+    ('\u{005E}', '\u{005E}'),
+    ('\u{0060}', '\u{0060}'),
+    ('\u{00A8}', '\u{00A8}'),
+    ('\u{00AD}', '\u{00AD}'),
+    ('\u{00AF}', '\u{00AF}'),
+    ('\u{00B4}', '\u{00B4}'),
+    ('\u{00B7}', '\u{00B8}'),
+    ('\u{02B0}', '\u{036F}'),
+    ('\u{0374}', '\u{0375}'),
+    ('\u{037A}', '\u{037A}'),
+    ('\u{0384}', '\u{0385}'),
+    ('\u{0387}', '\u{0387}'),
+    ('\u{0483}', '\u{0489}'),
+    ('\u{0559}', '\u{0559}'),
+    ('\u{055F}', '\u{055F}'),
+    ('\u{0591}', '\u{05BD}'),
+    ('\u{05BF}', '\u{05BF}'),
+    ('\u{05C1}', '\u{05C2}'),
+    ('\u{05C4}', '\u{05C5}'),
+    ('\u{05C7}', '\u{05C7}'),
+    ('\u{05F4}', '\u{05F4}'),
+    ('\u{0600}', '\u{0605}'),
+    ('\u{0610}', '\u{061A}'),
+    ('\u{061C}', '\u{061C}'),
+    ('\u{0640}', '\u{0640}'),
+    ('\u{064B}', '\u{065F}'),
+    ('\u{0670}', '\u{0670}'),
+    ('\u{06D6}', '\u{06DD}'),
+    ('\u{06DF}', '\u{06E8}'),
+    ('\u{06EA}', '\u{06ED}'),
+    ('\u{070F}', '\u{070F}'),
+    ('\u{0711}', '\u{0711}'),
+    ('\u{0730}', '\u{074A}'),
+    ('\u{07A6}', '\u{07B0}'),
+    ('\u{07EB}', '\u{07F5}'),
+    ('\u{07FA}', '\u{07FA}'),
+    ('\u{07FD}', '\u{07FD}'),
+    ('\u{0816}', '\u{082D}'),
+    ('\u{0859}', '\u{085B}'),
+    ('\u{0888}', '\u{0888}'),
+    ('\u{0890}', '\u{0891}'),
+    ('\u{0898}', '\u{089F}'),
+    ('\u{08C9}', '\u{0902}'),
+    ('\u{093A}', '\u{093A}'),
+    ('\u{093C}', '\u{093C}'),
+    ('\u{0941}', '\u{0948}'),
+    ('\u{094D}', '\u{094D}'),
+    ('\u{0951}', '\u{0957}'),
+    ('\u{0962}', '\u{0963}'),
+    ('\u{0971}', '\u{0971}'),
+    ('\u{0981}', '\u{0981}'),
+    ('\u{09BC}', '\u{09BC}'),
+    ('\u{09C1}', '\u{09C4}'),
+    ('\u{09CD}', '\u{09CD}'),
+    ('\u{09E2}', '\u{09E3}'),
+    ('\u{09FE}', '\u{09FE}'),
+    ('\u{0A01}', '\u{0A02}'),
+    ('\u{0A3C}', '\u{0A3C}'),
+    ('\u{0A41}', '\u{0A42}'),
+    ('\u{0A47}', '\u{0A48}'),
+    ('\u{0A4B}', '\u{0A4D}'),
+    ('\u{0A51}', '\u{0A51}'),
+    ('\u{0A70}', '\u{0A71}'),
+    ('\u{0A75}', '\u{0A75}'),
+    ('\u{0A81}', '\u{0A82}'),
+    ('\u{0ABC}', '\u{0ABC}'),
+    ('\u{0AC1}', '\u{0AC5}'),
+    ('\u{0AC7}', '\u{0AC8}'),
+    ('\u{0ACD}', '\u{0ACD}'),
+    ('\u{0AE2}', '\u{0AE3}'),
+    ('\u{0AFA}', '\u{0AFF}'),
+    ('\u{0B01}', '\u{0B01}'),
+    ('\u{0B3C}', '\u{0B3C}'),
+    ('\u{0B3F}', '\u{0B3F}'),
+    ('\u{0B41}', '\u{0B44}'),
+    ('\u{0B4D}', '\u{0B4D}'),
+    ('\u{0B55}', '\u{0B56}'),
+    ('\u{0B62}', '\u{0B63}'),
+    ('\u{0B82}', '\u{0B82}'),
+    ('\u{0BC0}', '\u{0BC0}'),
+    ('\u{0BCD}', '\u{0BCD}'),
+    ('\u{0C00}', '\u{0C00}'),
+    ('\u{0C04}', '\u{0C04}'),
+    ('\u{0C3C}', '\u{0C3C}'),
+    ('\u{0C3E}', '\u{0C40}'),
+    ('\u{0C46}', '\u{0C48}'),
+    ('\u{0C4A}', '\u{0C4D}'),
+    ('\u{0C55}', '\u{0C56}'),
+    ('\u{0C62}', '\u{0C63}'),
+    ('\u{0C81}', '\u{0C81}'),
+    ('\u{0CBC}', '\u{0CBC}'),
+    ('\u{0CBF}', '\u{0CBF}'),
+    ('\u{0CC6}', '\u{0CC6}'),
+    ('\u{0CCC}', '\u{0CCD}'),
+    ('\u{0CE2}', '\u{0CE3}'),
+    ('\u{0D00}', '\u{0D01}'),
+    ('\u{0D3B}', '\u{0D3C}'),
+    ('\u{0D41}', '\u{0D44}'),
+    ('\u{0D4D}', '\u{0D4D}'),
+    ('\u{0D62}', '\u{0D63}'),
+    ('\u{0D81}', '\u{0D81}'),
+    ('\u{0DCA}', '\u{0DCA}'),
+    ('\u{0DD2}', '\u{0DD4}'),
+    ('\u{0DD6}', '\u{0DD6}'),
+    ('\u{0E31}', '\u{0E31}'),
+    ('\u{0E34}', '\u{0E3A}'),
+    ('\u{0E46}', '\u{0E4E}'),
+    ('\u{0EB1}', '\u{0EB1}'),
+    ('\u{0EB4}', '\u{0EBC}'),
+    ('\u{0EC6}', '\u{0EC6}'),
+    ('\u{0EC8}', '\u{0ECD}'),
+    ('\u{0F18}', '\u{0F19}'),
+    ('\u{0F35}', '\u{0F35}'),
+    ('\u{0F37}', '\u{0F37}'),
+    ('\u{0F39}', '\u{0F39}'),
+    ('\u{0F71}', '\u{0F7E}'),
+    ('\u{0F80}', '\u{0F84}'),
+    ('\u{0F86}', '\u{0F87}'),
+    ('\u{0F8D}', '\u{0F97}'),
+    ('\u{0F99}', '\u{0FBC}'),
+    ('\u{0FC6}', '\u{0FC6}'),
+    ('\u{102D}', '\u{1030}'),
+    ('\u{1032}', '\u{1037}'),
+    ('\u{1039}', '\u{103A}'),
+    ('\u{103D}', '\u{103E}'),
+    ('\u{1058}', '\u{1059}'),
+    ('\u{105E}', '\u{1060}'),
+    ('\u{1071}', '\u{1074}'),
+    ('\u{1082}', '\u{1082}'),
+    ('\u{1085}', '\u{1086}'),
+    ('\u{108D}', '\u{108D}'),
+    ('\u{109D}', '\u{109D}'),
+    ('\u{10FC}', '\u{10FC}'),
+    ('\u{135D}', '\u{135F}'),
+    ('\u{1712}', '\u{1714}'),
+    ('\u{1732}', '\u{1733}'),
+    ('\u{1752}', '\u{1753}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{17B4}', '\u{17B5}'),
+    ('\u{17B7}', '\u{17BD}'),
+    ('\u{17C6}', '\u{17C6}'),
+    ('\u{17C9}', '\u{17D3}'),
+    ('\u{17D7}', '\u{17D7}'),
+    ('\u{17DD}', '\u{17DD}'),
+    ('\u{180B}', '\u{180F}'),
+    ('\u{1843}', '\u{1843}'),
+    ('\u{1885}', '\u{1886}'),
+    ('\u{18A9}', '\u{18A9}'),
+    ('\u{1920}', '\u{1922}'),
+    ('\u{1927}', '\u{1928}'),
+    ('\u{1932}', '\u{1932}'),
+    ('\u{1939}', '\u{193B}'),
+    ('\u{1A17}', '\u{1A18}'),
+    ('\u{1A1B}', '\u{1A1B}'),
+    ('\u{1A56}', '\u{1A56}'),
+    ('\u{1A58}', '\u{1A5E}'),
+    ('\u{1A60}', '\u{1A60}'),
+    ('\u{1A62}', '\u{1A62}'),
+    ('\u{1A65}', '\u{1A6C}'),
+    ('\u{1A73}', '\u{1A7C}'),
+    ('\u{1A7F}', '\u{1A7F}'),
+    ('\u{1AA7}', '\u{1AA7}'),
+    ('\u{1AB0}', '\u{1ACE}'),
+    ('\u{1B00}', '\u{1B03}'),
+    ('\u{1B34}', '\u{1B34}'),
+    ('\u{1B36}', '\u{1B3A}'),
+    ('\u{1B3C}', '\u{1B3C}'),
+    ('\u{1B42}', '\u{1B42}'),
+    ('\u{1B6B}', '\u{1B73}'),
+    ('\u{1B80}', '\u{1B81}'),
+    ('\u{1BA2}', '\u{1BA5}'),
+    ('\u{1BA8}', '\u{1BA9}'),
+    ('\u{1BAB}', '\u{1BAD}'),
+    ('\u{1BE6}', '\u{1BE6}'),
+    ('\u{1BE8}', '\u{1BE9}'),
+    ('\u{1BED}', '\u{1BED}'),
+    ('\u{1BEF}', '\u{1BF1}'),
+    ('\u{1C2C}', '\u{1C33}'),
+    ('\u{1C36}', '\u{1C37}'),
+    ('\u{1C78}', '\u{1C7D}'),
+    ('\u{1CD0}', '\u{1CD2}'),
+    ('\u{1CD4}', '\u{1CE0}'),
+    ('\u{1CE2}', '\u{1CE8}'),
+    ('\u{1CED}', '\u{1CED}'),
+    ('\u{1CF4}', '\u{1CF4}'),
+    ('\u{1CF8}', '\u{1CF9}'),
+    ('\u{1D2C}', '\u{1D6A}'),
+    ('\u{1D78}', '\u{1D78}'),
+    ('\u{1D9B}', '\u{1DFF}'),
+    ('\u{1FBD}', '\u{1FBD}'),
+    ('\u{1FBF}', '\u{1FC1}'),
+    ('\u{1FCD}', '\u{1FCF}'),
+    ('\u{1FDD}', '\u{1FDF}'),
+    ('\u{1FED}', '\u{1FEF}'),
+    ('\u{1FFD}', '\u{1FFE}'),
+    ('\u{200B}', '\u{200F}'),
+    ('\u{2018}', '\u{2019}'),
+    ('\u{2024}', '\u{2024}'),
+    ('\u{2027}', '\u{2027}'),
+    ('\u{202A}', '\u{202E}'),
+    ('\u{2060}', '\u{2064}'),
+    ('\u{2066}', '\u{206F}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207F}', '\u{207F}'),
+    ('\u{2090}', '\u{209C}'),
+    ('\u{20D0}', '\u{20F0}'),
+    ('\u{2C7C}', '\u{2C7D}'),
+    ('\u{2CEF}', '\u{2CF1}'),
+    ('\u{2D6F}', '\u{2D6F}'),
+    ('\u{2D7F}', '\u{2D7F}'),
+    ('\u{2DE0}', '\u{2DFF}'),
+    ('\u{2E2F}', '\u{2E2F}'),
+    ('\u{3005}', '\u{3005}'),
+    ('\u{302A}', '\u{302D}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{303B}', '\u{303B}'),
+    ('\u{3099}', '\u{309E}'),
+    ('\u{30FC}', '\u{30FE}'),
+    ('\u{A015}', '\u{A015}'),
+    ('\u{A4F8}', '\u{A4FD}'),
+    ('\u{A60C}', '\u{A60C}'),
+    ('\u{A66F}', '\u{A672}'),
+    ('\u{A674}', '\u{A67D}'),
+    ('\u{A67F}', '\u{A67F}'),
+    ('\u{A69C}', '\u{A69F}'),
+    ('\u{A6F0}', '\u{A6F1}'),
+    ('\u{A700}', '\u{A721}'),
+    ('\u{A770}', '\u{A770}'),
+    ('\u{A788}', '\u{A78A}'),
+    ('\u{A7F2}', '\u{A7F4}'),
+    ('\u{A7F8}', '\u{A7F9}'),
+    ('\u{A802}', '\u{A802}'),
+    ('\u{A806}', '\u{A806}'),
+    ('\u{A80B}', '\u{A80B}'),
+    ('\u{A825}', '\u{A826}'),
+    ('\u{A82C}', '\u{A82C}'),
+    ('\u{A8C4}', '\u{A8C5}'),
+    ('\u{A8E0}', '\u{A8F1}'),
+    ('\u{A8FF}', '\u{A8FF}'),
+    ('\u{A926}', '\u{A92D}'),
+    ('\u{A947}', '\u{A951}'),
+    ('\u{A980}', '\u{A982}'),
+    ('\u{A9B3}', '\u{A9B3}'),
+    ('\u{A9B6}', '\u{A9B9}'),
+    ('\u{A9BC}', '\u{A9BD}'),
+    ('\u{A9CF}', '\u{A9CF}'),
+    ('\u{A9E5}', '\u{A9E6}'),
+    ('\u{AA29}', '\u{AA2E}'),
+    ('\u{AA31}', '\u{AA32}'),
+    ('\u{AA35}', '\u{AA36}'),
+    ('\u{AA43}', '\u{AA43}'),
+    ('\u{AA4C}', '\u{AA4C}'),
+    ('\u{AA70}', '\u{AA70}'),
+    ('\u{AA7C}', '\u{AA7C}'),
+    ('\u{AAB0}', '\u{AAB0}'),
+    ('\u{AAB2}', '\u{AAB4}'),
+    ('\u{AAB7}', '\u{AAB8}'),
+    ('\u{AABE}', '\u{AABF}'),
+    ('\u{AAC1}', '\u{AAC1}'),
+    ('\u{AADD}', '\u{AADD}'),
+    ('\u{AAEC}', '\u{AAED}'),
+    ('\u{AAF3}', '\u{AAF4}'),
+    ('\u{AAF6}', '\u{AAF6}'),
+    ('\u{AB5B}', '\u{AB5F}'),
+    ('\u{AB69}', '\u{AB6B}'),
+    ('\u{ABE5}', '\u{ABE5}'),
+    ('\u{ABE8}', '\u{ABE8}'),
+    ('\u{ABED}', '\u{ABED}'),
+    ('\u{FB1E}', '\u{FB1E}'),
+    ('\u{FBB2}', '\u{FBC2}'),
+    ('\u{FE00}', '\u{FE0F}'),
+    ('\u{FE13}', '\u{FE13}'),
+    ('\u{FE20}', '\u{FE2F}'),
+    ('\u{FE52}', '\u{FE52}'),
+    ('\u{FE55}', '\u{FE55}'),
+    ('\u{FEFF}', '\u{FEFF}'),
+    ('\u{FF07}', '\u{FF07}'),
+    ('\u{FF0E}', '\u{FF0E}'),
+    ('\u{FF1A}', '\u{FF1A}'),
+    ('\u{FF3E}', '\u{FF3E}'),
+    ('\u{FF40}', '\u{FF40}'),
+    ('\u{FF70}', '\u{FF70}'),
+    ('\u{FF9E}', '\u{FF9F}'),
+    ('\u{FFE3}', '\u{FFE3}'),
+    ('\u{FFF9}', '\u{FFFB}'),
+    ('\u{101FD}', '\u{101FD}'),
+    ('\u{102E0}', '\u{102E0}'),
+    ('\u{10376}', '\u{1037A}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107B0}'),
+    ('\u{107B2}', '\u{107BA}'),
+    ('\u{10A01}', '\u{10A03}'),
+    ('\u{10A05}', '\u{10A06}'),
+    ('\u{10A0C}', '\u{10A0F}'),
+    ('\u{10A38}', '\u{10A3A}'),
+    ('\u{10A3F}', '\u{10A3F}'),
+    ('\u{10AE5}', '\u{10AE6}'),
+    ('\u{10D24}', '\u{10D27}'),
+    ('\u{10EAB}', '\u{10EAC}'),
+    ('\u{10F46}', '\u{10F50}'),
+    ('\u{10F82}', '\u{10F85}'),
+    ('\u{11001}', '\u{11001}'),
+    ('\u{11038}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'),
+    ('\u{11073}', '\u{11074}'),
+    ('\u{1107F}', '\u{11081}'),
+    ('\u{110B3}', '\u{110B6}'),
+    ('\u{110B9}', '\u{110BA}'),
+    ('\u{110BD}', '\u{110BD}'),
+    ('\u{110C2}', '\u{110C2}'),
+    ('\u{110CD}', '\u{110CD}'),
+    ('\u{11100}', '\u{11102}'),
+    ('\u{11127}', '\u{1112B}'),
+    ('\u{1112D}', '\u{11134}'),
+    ('\u{11173}', '\u{11173}'),
+    ('\u{11180}', '\u{11181}'),
+    ('\u{111B6}', '\u{111BE}'),
+    ('\u{111C9}', '\u{111CC}'),
+    ('\u{111CF}', '\u{111CF}'),
+    ('\u{1122F}', '\u{11231}'),
+    ('\u{11234}', '\u{11234}'),
+    ('\u{11236}', '\u{11237}'),
+    ('\u{1123E}', '\u{1123E}'),
+    ('\u{112DF}', '\u{112DF}'),
+    ('\u{112E3}', '\u{112EA}'),
+    ('\u{11300}', '\u{11301}'),
+    ('\u{1133B}', '\u{1133C}'),
+    ('\u{11340}', '\u{11340}'),
+    ('\u{11366}', '\u{1136C}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11438}', '\u{1143F}'),
+    ('\u{11442}', '\u{11444}'),
+    ('\u{11446}', '\u{11446}'),
+    ('\u{1145E}', '\u{1145E}'),
+    ('\u{114B3}', '\u{114B8}'),
+    ('\u{114BA}', '\u{114BA}'),
+    ('\u{114BF}', '\u{114C0}'),
+    ('\u{114C2}', '\u{114C3}'),
+    ('\u{115B2}', '\u{115B5}'),
+    ('\u{115BC}', '\u{115BD}'),
+    ('\u{115BF}', '\u{115C0}'),
+    ('\u{115DC}', '\u{115DD}'),
+    ('\u{11633}', '\u{1163A}'),
+    ('\u{1163D}', '\u{1163D}'),
+    ('\u{1163F}', '\u{11640}'),
+    ('\u{116AB}', '\u{116AB}'),
+    ('\u{116AD}', '\u{116AD}'),
+    ('\u{116B0}', '\u{116B5}'),
+    ('\u{116B7}', '\u{116B7}'),
+    ('\u{1171D}', '\u{1171F}'),
+    ('\u{11722}', '\u{11725}'),
+    ('\u{11727}', '\u{1172B}'),
+    ('\u{1182F}', '\u{11837}'),
+    ('\u{11839}', '\u{1183A}'),
+    ('\u{1193B}', '\u{1193C}'),
+    ('\u{1193E}', '\u{1193E}'),
+    ('\u{11943}', '\u{11943}'),
+    ('\u{119D4}', '\u{119D7}'),
+    ('\u{119DA}', '\u{119DB}'),
+    ('\u{119E0}', '\u{119E0}'),
+    ('\u{11A01}', '\u{11A0A}'),
+    ('\u{11A33}', '\u{11A38}'),
+    ('\u{11A3B}', '\u{11A3E}'),
+    ('\u{11A47}', '\u{11A47}'),
+    ('\u{11A51}', '\u{11A56}'),
+    ('\u{11A59}', '\u{11A5B}'),
+    ('\u{11A8A}', '\u{11A96}'),
+    ('\u{11A98}', '\u{11A99}'),
+    ('\u{11C30}', '\u{11C36}'),
+    ('\u{11C38}', '\u{11C3D}'),
+    ('\u{11C3F}', '\u{11C3F}'),
+    ('\u{11C92}', '\u{11CA7}'),
+    ('\u{11CAA}', '\u{11CB0}'),
+    ('\u{11CB2}', '\u{11CB3}'),
+    ('\u{11CB5}', '\u{11CB6}'),
+    ('\u{11D31}', '\u{11D36}'),
+    ('\u{11D3A}', '\u{11D3A}'),
+    ('\u{11D3C}', '\u{11D3D}'),
+    ('\u{11D3F}', '\u{11D45}'),
+    ('\u{11D47}', '\u{11D47}'),
+    ('\u{11D90}', '\u{11D91}'),
+    ('\u{11D95}', '\u{11D95}'),
+    ('\u{11D97}', '\u{11D97}'),
+    ('\u{11EF3}', '\u{11EF4}'),
+    ('\u{13430}', '\u{13438}'),
+    ('\u{16AF0}', '\u{16AF4}'),
+    ('\u{16B30}', '\u{16B36}'),
+    ('\u{16B40}', '\u{16B43}'),
+    ('\u{16F4F}', '\u{16F4F}'),
+    ('\u{16F8F}', '\u{16F9F}'),
+    ('\u{16FE0}', '\u{16FE1}'),
+    ('\u{16FE3}', '\u{16FE4}'),
+    ('\u{1AFF0}', '\u{1AFF3}'),
+    ('\u{1AFF5}', '\u{1AFFB}'),
+    ('\u{1AFFD}', '\u{1AFFE}'),
+    ('\u{1BC9D}', '\u{1BC9E}'),
+    ('\u{1BCA0}', '\u{1BCA3}'),
+    ('\u{1CF00}', '\u{1CF2D}'),
+    ('\u{1CF30}', '\u{1CF46}'),
+    ('\u{1D167}', '\u{1D169}'),
+    ('\u{1D173}', '\u{1D182}'),
+    ('\u{1D185}', '\u{1D18B}'),
+    ('\u{1D1AA}', '\u{1D1AD}'),
+    ('\u{1D242}', '\u{1D244}'),
+    ('\u{1DA00}', '\u{1DA36}'),
+    ('\u{1DA3B}', '\u{1DA6C}'),
+    ('\u{1DA75}', '\u{1DA75}'),
+    ('\u{1DA84}', '\u{1DA84}'),
+    ('\u{1DA9B}', '\u{1DA9F}'),
+    ('\u{1DAA1}', '\u{1DAAF}'),
+    ('\u{1E000}', '\u{1E006}'),
+    ('\u{1E008}', '\u{1E018}'),
+    ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'),
+    ('\u{1E026}', '\u{1E02A}'),
+    ('\u{1E130}', '\u{1E13D}'),
+    ('\u{1E2AE}', '\u{1E2AE}'),
+    ('\u{1E2EC}', '\u{1E2EF}'),
+    ('\u{1E8D0}', '\u{1E8D6}'),
+    ('\u{1E944}', '\u{1E94B}'),
+    ('\u{1F3FB}', '\u{1F3FF}'),
+    ('\u{E0001}', '\u{E0001}'),
+    ('\u{E0020}', '\u{E007F}'),
+    ('\u{E0100}', '\u{E01EF}'),
+];
@@ -0,0 +1,6406 @@
+//! Confusable character (homoglyph) map from UTS #39.
+//!
+//! Maps visually confusable characters to their prototypes (e.g. Cyrillic а
+//! to Latin `a`, Greek ο to Latin `o`, and mathematical bold 𝐀 to `A`). Some
+//! characters are mapped to multiple characters (e.g. ǆ to `dž`), and some
+//! ASCII characters are mapped as well (e.g. `m` to `rn` and `1` to `l`).
+//!
+//! The map is intended to be applied to NFD text. [`skeleton`] does so and
+//! computes the UTS #39 skeleton of a string, and [`is_confusable`] compares
+//! the skeletons of two strings.
+//!
+//! ```rust
+//! use charmap::maps::confusables::{is_confusable, skeleton};
+//!
+//! assert_eq!(skeleton("gооglе"), "google");
+//! assert!(is_confusable("𝐩𝐚𝐲𝐩𝐚𝐥", "paypal"));
+//! assert!(!is_confusable("paypal", "paypai"));
+//! ```
+//!
+//! This file is generated by `scripts/gen_confusables.py` from Unicode
+//! 16.0.0 `confusables.txt`. Do not edit it manually.
+
+#[cfg(feature = "std")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+#[cfg(feature = "std")]
+use crate::charmapper::{CharMapper, MapCharsIter};
+
+/// Map from confusable characters to their prototypes.
+#[rustfmt::skip]
+pub static CONFUSABLES: [(char, CharMapAction); 6355] = [
+    ('\u{0022}', CharMapAction::SubStr("''")), // QUOTATION MARK
+    ('\u{0025}', CharMapAction::SubStr("\u{00BA}/\u{2080}")), // PERCENT SIGN
+    ('\u{0030}', CharMapAction::SubChar('O')), // DIGIT ZERO
+    ('\u{0031}', CharMapAction::SubChar('l')), // DIGIT ONE
+    ('\u{0049}', CharMapAction::SubChar('l')), // LATIN CAPITAL LETTER I
+    ('\u{0060}', CharMapAction::SubChar('\'')), // GRAVE ACCENT
+    ('\u{006D}', CharMapAction::SubStr("rn")), // LATIN SMALL LETTER M
+    ('\u{007C}', CharMapAction::SubChar('l')), // VERTICAL LINE
+    ('\u{00A0}', CharMapAction::SubChar(' ')), // NO-BREAK SPACE
+    ('\u{00A2}', CharMapAction::SubStr("c\u{0338}")), // CENT SIGN
+    ('\u{00A5}', CharMapAction::SubStr("Y\u{0335}")), // YEN SIGN
+    ('\u{00AF}', CharMapAction::SubChar('\u{02C9}')), // MACRON
+    ('\u{00B4}', CharMapAction::SubChar('\'')), // ACUTE ACCENT
+    ('\u{00B5}', CharMapAction::SubChar('\u{03BC}')), // MICRO SIGN
+    ('\u{00B8}', CharMapAction::SubChar(',')), // CEDILLA
+    ('\u{00C6}', CharMapAction::SubStr("AE")), // LATIN CAPITAL LETTER AE
+    ('\u{00C7}', CharMapAction::SubStr("C\u{0326}")), // LATIN CAPITAL LETTER C WITH CEDILLA
+    ('\u{00D0}', CharMapAction::SubStr("D\u{0335}")), // LATIN CAPITAL LETTER ETH
+    ('\u{00D7}', CharMapAction::SubChar('x')), // MULTIPLICATION SIGN
+    ('\u{00D8}', CharMapAction::SubStr("O\u{0338}")), // LATIN CAPITAL LETTER O WITH STROKE
+    ('\u{00E6}', CharMapAction::SubStr("ae")), // LATIN SMALL LETTER AE
+    ('\u{00E7}', CharMapAction::SubStr("c\u{0326}")), // LATIN SMALL LETTER C WITH CEDILLA
+    ('\u{00F0}', CharMapAction::SubStr("\u{2202}\u{0335}")), // LATIN SMALL LETTER ETH
+    ('\u{00F6}', CharMapAction::SubChar('\u{0629}')), // LATIN SMALL LETTER O WITH DIAERESIS
+    ('\u{00F8}', CharMapAction::SubStr("o\u{0338}")), // LATIN SMALL LETTER O WITH STROKE
+    ('\u{0110}', CharMapAction::SubStr("D\u{0335}")), // LATIN CAPITAL LETTER D WITH STROKE
+    ('\u{0111}', CharMapAction::SubStr("d\u{0335}")), // LATIN SMALL LETTER D WITH STROKE
+    ('\u{011A}', CharMapAction::SubChar('\u{0114}')), // LATIN CAPITAL LETTER E WITH CARON
+    ('\u{011B}', CharMapAction::SubChar('\u{0115}')), // LATIN SMALL LETTER E WITH CARON
+    ('\u{0126}', CharMapAction::SubStr("H\u{0335}")), // LATIN CAPITAL LETTER H WITH STROKE
+    ('\u{0127}', CharMapAction::SubStr("h\u{0335}")), // LATIN SMALL LETTER H WITH STROKE
+    ('\u{0131}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER DOTLESS I
+    ('\u{0132}', CharMapAction::SubStr("lJ")), // LATIN CAPITAL LIGATURE IJ
+    ('\u{0133}', CharMapAction::SubStr("ij")), // LATIN SMALL LIGATURE IJ
+    ('\u{013F}', CharMapAction::SubStr("l\u{00B7}")), // LATIN CAPITAL LETTER L WITH MIDDLE DOT
+    ('\u{0140}', CharMapAction::SubStr("l\u{00B7}")), // LATIN SMALL LETTER L WITH MIDDLE DOT
+    ('\u{0141}', CharMapAction::SubStr("L\u{0338}")), // LATIN CAPITAL LETTER L WITH STROKE
+    ('\u{0142}', CharMapAction::SubStr("l\u{0338}")), // LATIN SMALL LETTER L WITH STROKE
+    ('\u{0146}', CharMapAction::SubChar('\u{0272}')), // LATIN SMALL LETTER N WITH CEDILLA
+    ('\u{0149}', CharMapAction::SubStr("'n")), // LATIN SMALL LETTER N PRECEDED BY APOSTROPHE
+    ('\u{0150}', CharMapAction::SubChar('\u{00D6}')), // LATIN CAPITAL LETTER O WITH DOUBLE ACUTE
+    ('\u{0152}', CharMapAction::SubStr("OE")), // LATIN CAPITAL LIGATURE OE
+    ('\u{0153}', CharMapAction::SubStr("oe")), // LATIN SMALL LIGATURE OE
+    ('\u{0163}', CharMapAction::SubChar('\u{01AB}')), // LATIN SMALL LETTER T WITH CEDILLA
+    ('\u{0166}', CharMapAction::SubStr("T\u{0335}")), // LATIN CAPITAL LETTER T WITH STROKE
+    ('\u{0167}', CharMapAction::SubStr("t\u{0335}")), // LATIN SMALL LETTER T WITH STROKE
+    ('\u{017F}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER LONG S
+    ('\u{0180}', CharMapAction::SubStr("b\u{0335}")), // LATIN SMALL LETTER B WITH STROKE
+    ('\u{0181}', CharMapAction::SubStr("'B")), // LATIN CAPITAL LETTER B WITH HOOK
+    ('\u{0182}', CharMapAction::SubStr("b\u{0304}")), // LATIN CAPITAL LETTER B WITH TOPBAR
+    ('\u{0183}', CharMapAction::SubStr("b\u{0304}")), // LATIN SMALL LETTER B WITH TOPBAR
+    ('\u{0184}', CharMapAction::SubChar('b')), // LATIN CAPITAL LETTER TONE SIX
+    ('\u{0187}', CharMapAction::SubStr("C'")), // LATIN CAPITAL LETTER C WITH HOOK
+    ('\u{0189}', CharMapAction::SubStr("D\u{0335}")), // LATIN CAPITAL LETTER AFRICAN D
+    ('\u{018A}', CharMapAction::SubStr("'D")), // LATIN CAPITAL LETTER D WITH HOOK
+    ('\u{018C}', CharMapAction::SubStr("d\u{0304}")), // LATIN SMALL LETTER D WITH TOPBAR
+    ('\u{018D}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER TURNED DELTA
+    ('\u{0191}', CharMapAction::SubStr("F\u{0326}")), // LATIN CAPITAL LETTER F WITH HOOK
+    ('\u{0192}', CharMapAction::SubStr("f\u{0326}")), // LATIN SMALL LETTER F WITH HOOK
+    ('\u{0193}', CharMapAction::SubStr("G'")), // LATIN CAPITAL LETTER G WITH HOOK
+    ('\u{0196}', CharMapAction::SubChar('l')), // LATIN CAPITAL LETTER IOTA
+    ('\u{0197}', CharMapAction::SubStr("l\u{0335}")), // LATIN CAPITAL LETTER I WITH STROKE
+    ('\u{0198}', CharMapAction::SubStr("K'")), // LATIN CAPITAL LETTER K WITH HOOK
+    ('\u{0199}', CharMapAction::SubStr("k\u{0314}")), // LATIN SMALL LETTER K WITH HOOK
+    ('\u{019A}', CharMapAction::SubStr("l\u{0335}")), // LATIN SMALL LETTER L WITH BAR
+    ('\u{019B}', CharMapAction::SubStr("\u{03BB}\u{0338}")), // LATIN SMALL LETTER LAMBDA WITH STROKE
+    ('\u{019D}', CharMapAction::SubStr("N\u{0326}")), // LATIN CAPITAL LETTER N WITH LEFT HOOK
+    ('\u{019E}', CharMapAction::SubStr("n\u{0329}")), // LATIN SMALL LETTER N WITH LONG RIGHT LEG
+    ('\u{019F}', CharMapAction::SubStr("O\u{0335}")), // LATIN CAPITAL LETTER O WITH MIDDLE TILDE
+    ('\u{01A0}', CharMapAction::SubStr("O'")), // LATIN CAPITAL LETTER O WITH HORN
+    ('\u{01A1}', CharMapAction::SubStr("o'")), // LATIN SMALL LETTER O WITH HORN
+    ('\u{01A4}', CharMapAction::SubStr("'P")), // LATIN CAPITAL LETTER P WITH HOOK
+    ('\u{01A5}', CharMapAction::SubStr("p\u{0314}")), // LATIN SMALL LETTER P WITH HOOK
+    ('\u{01A6}', CharMapAction::SubChar('R')), // LATIN LETTER YR
+    ('\u{01A7}', CharMapAction::SubChar('2')), // LATIN CAPITAL LETTER TONE TWO
+    ('\u{01AC}', CharMapAction::SubStr("'T")), // LATIN CAPITAL LETTER T WITH HOOK
+    ('\u{01AD}', CharMapAction::SubStr("t\u{0314}")), // LATIN SMALL LETTER T WITH HOOK
+    ('\u{01AE}', CharMapAction::SubStr("T\u{0328}")), // LATIN CAPITAL LETTER T WITH RETROFLEX HOOK
+    ('\u{01B3}', CharMapAction::SubStr("'Y")), // LATIN CAPITAL LETTER Y WITH HOOK
+    ('\u{01B4}', CharMapAction::SubStr("y\u{0314}")), // LATIN SMALL LETTER Y WITH HOOK
+    ('\u{01B5}', CharMapAction::SubStr("Z\u{0335}")), // LATIN CAPITAL LETTER Z WITH STROKE
+    ('\u{01B6}', CharMapAction::SubStr("z\u{0335}")), // LATIN SMALL LETTER Z WITH STROKE
+    ('\u{01B7}', CharMapAction::SubChar('3')), // LATIN CAPITAL LETTER EZH
+    ('\u{01BB}', CharMapAction::SubStr("2\u{0335}")), // LATIN LETTER TWO WITH STROKE
+    ('\u{01BC}', CharMapAction::SubChar('5')), // LATIN CAPITAL LETTER TONE FIVE
+    ('\u{01BD}', CharMapAction::SubChar('s')), // LATIN SMALL LETTER TONE FIVE
+    ('\u{01BF}', CharMapAction::SubChar('\u{00FE}')), // LATIN LETTER WYNN
+    ('\u{01C0}', CharMapAction::SubChar('l')), // LATIN LETTER DENTAL CLICK
+    ('\u{01C1}', CharMapAction::SubStr("ll")), // LATIN LETTER LATERAL CLICK
+    ('\u{01C3}', CharMapAction::SubChar('!')), // LATIN LETTER RETROFLEX CLICK
+    ('\u{01C4}', CharMapAction::SubStr("D\u{017D}")), // LATIN CAPITAL LETTER DZ WITH CARON
+    ('\u{01C5}', CharMapAction::SubStr("D\u{017E}")), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z WITH CARON
+    ('\u{01C6}', CharMapAction::SubStr("d\u{017E}")), // LATIN SMALL LETTER DZ WITH CARON
+    ('\u{01C7}', CharMapAction::SubStr("LJ")), // LATIN CAPITAL LETTER LJ
+    ('\u{01C8}', CharMapAction::SubStr("Lj")), // LATIN CAPITAL LETTER L WITH SMALL LETTER J
+    ('\u{01C9}', CharMapAction::SubStr("lj")), // LATIN SMALL LETTER LJ
+    ('\u{01CA}', CharMapAction::SubStr("NJ")), // LATIN CAPITAL LETTER NJ
+    ('\u{01CB}', CharMapAction::SubStr("Nj")), // LATIN CAPITAL LETTER N WITH SMALL LETTER J
+    ('\u{01CC}', CharMapAction::SubStr("nj")), // LATIN SMALL LETTER NJ
+    ('\u{01CD}', CharMapAction::SubChar('\u{0102}')), // LATIN CAPITAL LETTER A WITH CARON
+    ('\u{01CE}', CharMapAction::SubChar('\u{0103}')), // LATIN SMALL LETTER A WITH CARON
+    ('\u{01CF}', CharMapAction::SubChar('\u{012C}')), // LATIN CAPITAL LETTER I WITH CARON
+    ('\u{01D0}', CharMapAction::SubChar('\u{012D}')), // LATIN SMALL LETTER I WITH CARON
+    ('\u{01D1}', CharMapAction::SubChar('\u{014E}')), // LATIN CAPITAL LETTER O WITH CARON
+    ('\u{01D2}', CharMapAction::SubChar('\u{014F}')), // LATIN SMALL LETTER O WITH CARON
+    ('\u{01D3}', CharMapAction::SubChar('\u{016C}')), // LATIN CAPITAL LETTER U WITH CARON
+    ('\u{01D4}', CharMapAction::SubChar('\u{016D}')), // LATIN SMALL LETTER U WITH CARON
+    ('\u{01E4}', CharMapAction::SubStr("G\u{0335}")), // LATIN CAPITAL LETTER G WITH STROKE
+    ('\u{01E5}', CharMapAction::SubStr("g\u{0335}")), // LATIN SMALL LETTER G WITH STROKE
+    ('\u{01E6}', CharMapAction::SubChar('\u{011E}')), // LATIN CAPITAL LETTER G WITH CARON
+    ('\u{01E7}', CharMapAction::SubChar('\u{011F}')), // LATIN SMALL LETTER G WITH CARON
+    ('\u{01F1}', CharMapAction::SubStr("DZ")), // LATIN CAPITAL LETTER DZ
+    ('\u{01F2}', CharMapAction::SubStr("Dz")), // LATIN CAPITAL LETTER D WITH SMALL LETTER Z
+    ('\u{01F3}', CharMapAction::SubStr("dz")), // LATIN SMALL LETTER DZ
+    ('\u{01F5}', CharMapAction::SubChar('\u{0123}')), // LATIN SMALL LETTER G WITH ACUTE
+    ('\u{01FE}', CharMapAction::SubStr("O\u{0338}\u{0301}")), // LATIN CAPITAL LETTER O WITH STROKE AND ACUTE
+    ('\u{021A}', CharMapAction::SubChar('\u{0162}')), // LATIN CAPITAL LETTER T WITH COMMA BELOW
+    ('\u{021B}', CharMapAction::SubChar('\u{01AB}')), // LATIN SMALL LETTER T WITH COMMA BELOW
+    ('\u{021C}', CharMapAction::SubChar('3')), // LATIN CAPITAL LETTER YOGH
+    ('\u{0222}', CharMapAction::SubChar('8')), // LATIN CAPITAL LETTER OU
+    ('\u{0223}', CharMapAction::SubChar('8')), // LATIN SMALL LETTER OU
+    ('\u{0224}', CharMapAction::SubStr("Z\u{0326}")), // LATIN CAPITAL LETTER Z WITH HOOK
+    ('\u{0225}', CharMapAction::SubStr("z\u{0326}")), // LATIN SMALL LETTER Z WITH HOOK
+    ('\u{0226}', CharMapAction::SubChar('\u{00C5}')), // LATIN CAPITAL LETTER A WITH DOT ABOVE
+    ('\u{0227}', CharMapAction::SubChar('\u{00E5}')), // LATIN SMALL LETTER A WITH DOT ABOVE
+    ('\u{023C}', CharMapAction::SubStr("c\u{0338}")), // LATIN SMALL LETTER C WITH STROKE
+    ('\u{023E}', CharMapAction::SubStr("T\u{0338}")), // LATIN CAPITAL LETTER T WITH DIAGONAL STROKE
+    ('\u{0241}', CharMapAction::SubChar('?')), // LATIN CAPITAL LETTER GLOTTAL STOP
+    ('\u{0244}', CharMapAction::SubStr("U\u{0335}")), // LATIN CAPITAL LETTER U BAR
+    ('\u{0246}', CharMapAction::SubStr("E\u{0338}")), // LATIN CAPITAL LETTER E WITH STROKE
+    ('\u{0247}', CharMapAction::SubStr("e\u{0338}")), // LATIN SMALL LETTER E WITH STROKE
+    ('\u{0248}', CharMapAction::SubStr("J\u{0335}")), // LATIN CAPITAL LETTER J WITH STROKE
+    ('\u{0249}', CharMapAction::SubStr("j\u{0335}")), // LATIN SMALL LETTER J WITH STROKE
+    ('\u{024D}', CharMapAction::SubStr("r\u{0335}")), // LATIN SMALL LETTER R WITH STROKE
+    ('\u{024E}', CharMapAction::SubStr("Y\u{0335}")), // LATIN CAPITAL LETTER Y WITH STROKE
+    ('\u{024F}', CharMapAction::SubStr("y\u{0335}")), // LATIN SMALL LETTER Y WITH STROKE
+    ('\u{0251}', CharMapAction::SubChar('a')), // LATIN SMALL LETTER ALPHA
+    ('\u{0253}', CharMapAction::SubStr("b\u{0314}")), // LATIN SMALL LETTER B WITH HOOK
+    ('\u{0256}', CharMapAction::SubStr("d\u{0328}")), // LATIN SMALL LETTER D WITH TAIL
+    ('\u{0257}', CharMapAction::SubStr("d\u{0314}")), // LATIN SMALL LETTER D WITH HOOK
+    ('\u{0259}', CharMapAction::SubChar('\u{01DD}')), // LATIN SMALL LETTER SCHWA
+    ('\u{025A}', CharMapAction::SubStr("\u{01DD}\u{02DE}")), // LATIN SMALL LETTER SCHWA WITH HOOK
+    ('\u{025B}', CharMapAction::SubChar('\u{A793}')), // LATIN SMALL LETTER OPEN E
+    ('\u{0260}', CharMapAction::SubStr("g\u{0314}")), // LATIN SMALL LETTER G WITH HOOK
+    ('\u{0261}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER SCRIPT G
+    ('\u{0263}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER GAMMA
+    ('\u{0266}', CharMapAction::SubStr("h\u{0314}")), // LATIN SMALL LETTER H WITH HOOK
+    ('\u{0268}', CharMapAction::SubStr("i\u{0335}")), // LATIN SMALL LETTER I WITH STROKE
+    ('\u{0269}', CharMapAction::SubChar('i')), // LATIN SMALL LETTER IOTA
+    ('\u{026A}', CharMapAction::SubChar('i')), // LATIN LETTER SMALL CAPITAL I
+    ('\u{026B}', CharMapAction::SubStr("l\u{0334}")), // LATIN SMALL LETTER L WITH MIDDLE TILDE
+    ('\u{026D}', CharMapAction::SubStr("l\u{0328}")), // LATIN SMALL LETTER L WITH RETROFLEX HOOK
+    ('\u{026E}', CharMapAction::SubStr("l\u{021D}")), // LATIN SMALL LETTER LEZH
+    ('\u{026F}', CharMapAction::SubChar('w')), // LATIN SMALL LETTER TURNED M
+    ('\u{0271}', CharMapAction::SubStr("rn\u{0326}")), // LATIN SMALL LETTER M WITH HOOK
+    ('\u{0273}', CharMapAction::SubStr("n\u{0328}")), // LATIN SMALL LETTER N WITH RETROFLEX HOOK
+    ('\u{0275}', CharMapAction::SubStr("o\u{0335}")), // LATIN SMALL LETTER BARRED O
+    ('\u{0276}', CharMapAction::SubStr("o\u{1D07}")), // LATIN LETTER SMALL CAPITAL OE
+    ('\u{027C}', CharMapAction::SubStr("r\u{0329}")), // LATIN SMALL LETTER R WITH LONG LEG
+    ('\u{027D}', CharMapAction::SubStr("r\u{0328}")), // LATIN SMALL LETTER R WITH TAIL
+    ('\u{0282}', CharMapAction::SubStr("s\u{0328}")), // LATIN SMALL LETTER S WITH HOOK
+    ('\u{028B}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER V WITH HOOK
+    ('\u{028F}', CharMapAction::SubChar('y')), // LATIN LETTER SMALL CAPITAL Y
+    ('\u{0290}', CharMapAction::SubStr("z\u{0328}")), // LATIN SMALL LETTER Z WITH RETROFLEX HOOK
+    ('\u{0292}', CharMapAction::SubChar('\u{021D}')), // LATIN SMALL LETTER EZH
+    ('\u{0294}', CharMapAction::SubChar('?')), // LATIN LETTER GLOTTAL STOP
+    ('\u{02A0}', CharMapAction::SubStr("q\u{0314}")), // LATIN SMALL LETTER Q WITH HOOK
+    ('\u{02A3}', CharMapAction::SubStr("dz")), // LATIN SMALL LETTER DZ DIGRAPH
+    ('\u{02A4}', CharMapAction::SubStr("d\u{021D}")), // LATIN SMALL LETTER DEZH DIGRAPH
+    ('\u{02A5}', CharMapAction::SubStr("d\u{0291}")), // LATIN SMALL LETTER DZ DIGRAPH WITH CURL
+    ('\u{02A6}', CharMapAction::SubStr("ts")), // LATIN SMALL LETTER TS DIGRAPH
+    ('\u{02A7}', CharMapAction::SubStr("t\u{0283}")), // LATIN SMALL LETTER TESH DIGRAPH
+    ('\u{02A8}', CharMapAction::SubStr("t\u{0255}")), // LATIN SMALL LETTER TC DIGRAPH WITH CURL
+    ('\u{02A9}', CharMapAction::SubStr("f\u{014B}")), // LATIN SMALL LETTER FENG DIGRAPH
+    ('\u{02AA}', CharMapAction::SubStr("ls")), // LATIN SMALL LETTER LS DIGRAPH
+    ('\u{02AB}', CharMapAction::SubStr("lz")), // LATIN SMALL LETTER LZ DIGRAPH
+    ('\u{02B3}', CharMapAction::SubChar('\u{18F4}')), // MODIFIER LETTER SMALL R
+    ('\u{02B9}', CharMapAction::SubChar('\'')), // MODIFIER LETTER PRIME
+    ('\u{02BA}', CharMapAction::SubStr("''")), // MODIFIER LETTER DOUBLE PRIME
+    ('\u{02BB}', CharMapAction::SubChar('\'')), // MODIFIER LETTER TURNED COMMA
+    ('\u{02BC}', CharMapAction::SubChar('\'')), // MODIFIER LETTER APOSTROPHE
+    ('\u{02BD}', CharMapAction::SubChar('\'')), // MODIFIER LETTER REVERSED COMMA
+    ('\u{02BE}', CharMapAction::SubChar('\'')), // MODIFIER LETTER RIGHT HALF RING
+    ('\u{02BF}', CharMapAction::SubChar('\u{0559}')), // MODIFIER LETTER LEFT HALF RING
+    ('\u{02C2}', CharMapAction::SubChar('<')), // MODIFIER LETTER LEFT ARROWHEAD
+    ('\u{02C3}', CharMapAction::SubChar('>')), // MODIFIER LETTER RIGHT ARROWHEAD
+    ('\u{02C4}', CharMapAction::SubChar('^')), // MODIFIER LETTER UP ARROWHEAD
+    ('\u{02C6}', CharMapAction::SubChar('^')), // MODIFIER LETTER CIRCUMFLEX ACCENT
+    ('\u{02C8}', CharMapAction::SubChar('\'')), // MODIFIER LETTER VERTICAL LINE
+    ('\u{02CA}', CharMapAction::SubChar('\'')), // MODIFIER LETTER ACUTE ACCENT
+    ('\u{02CB}', CharMapAction::SubChar('\'')), // MODIFIER LETTER GRAVE ACCENT
+    ('\u{02D0}', CharMapAction::SubChar(':')), // MODIFIER LETTER TRIANGULAR COLON
+    ('\u{02D3}', CharMapAction::SubChar('\u{0559}')), // MODIFIER LETTER CENTRED LEFT HALF RING
+    ('\u{02D7}', CharMapAction::SubChar('-')), // MODIFIER LETTER MINUS SIGN
+    ('\u{02D8}', CharMapAction::SubChar('\u{02C7}')), // BREVE
+    ('\u{02D9}', CharMapAction::SubChar('\u{0971}')), // DOT ABOVE
+    ('\u{02DA}', CharMapAction::SubChar('\u{00B0}')), // RING ABOVE
+    ('\u{02DB}', CharMapAction::SubChar('i')), // OGONEK
+    ('\u{02DC}', CharMapAction::SubChar('~')), // SMALL TILDE
+    ('\u{02DD}', CharMapAction::SubStr("''")), // DOUBLE ACUTE ACCENT
+    ('\u{02E1}', CharMapAction::SubChar('\u{18F3}')), // MODIFIER LETTER SMALL L
+    ('\u{02E2}', CharMapAction::SubChar('\u{18F5}')), // MODIFIER LETTER SMALL S
+    ('\u{02E4}', CharMapAction::SubChar('\u{02C1}')), // MODIFIER LETTER SMALL REVERSED GLOTTAL STOP
+    ('\u{02EE}', CharMapAction::SubStr("''")), // MODIFIER LETTER DOUBLE APOSTROPHE
+    ('\u{02F4}', CharMapAction::SubChar('\'')), // MODIFIER LETTER MIDDLE GRAVE ACCENT
+    ('\u{02F6}', CharMapAction::SubStr("''")), // MODIFIER LETTER MIDDLE DOUBLE ACUTE ACCENT
+    ('\u{02F8}', CharMapAction::SubChar(':')), // MODIFIER LETTER RAISED COLON
+    ('\u{02FB}', CharMapAction::SubChar('\u{02EA}')), // MODIFIER LETTER BEGIN LOW TONE
+    ('\u{0305}', CharMapAction::SubChar('\u{0304}')), // COMBINING OVERLINE
+    ('\u{030C}', CharMapAction::SubChar('\u{0306}')), // COMBINING CARON
+    ('\u{030D}', CharMapAction::SubChar('\u{0670}')), // COMBINING VERTICAL LINE ABOVE
+    ('\u{0310}', CharMapAction::SubStr("\u{0306}\u{0307}")), // COMBINING CANDRABINDU
+    ('\u{0311}', CharMapAction::SubChar('\u{0302}')), // COMBINING INVERTED BREVE
+    ('\u{0315}', CharMapAction::SubChar('\u{0313}')), // COMBINING COMMA ABOVE RIGHT
+    ('\u{0317}', CharMapAction::SubChar('\u{0650}')), // COMBINING ACUTE ACCENT BELOW
+    ('\u{0320}', CharMapAction::SubChar('\u{0331}')), // COMBINING MINUS SIGN BELOW
+    ('\u{0321}', CharMapAction::SubChar('\u{0326}')), // COMBINING PALATALIZED HOOK BELOW
+    ('\u{0322}', CharMapAction::SubChar('\u{0328}')), // COMBINING RETROFLEX HOOK BELOW
+    ('\u{0327}', CharMapAction::SubChar('\u{0326}')), // COMBINING CEDILLA
+    ('\u{0336}', CharMapAction::SubChar('\u{0335}')), // COMBINING LONG STROKE OVERLAY
+    ('\u{0337}', CharMapAction::SubChar('\u{0338}')), // COMBINING SHORT SOLIDUS OVERLAY
+    ('\u{0339}', CharMapAction::SubChar('\u{0326}')), // COMBINING RIGHT HALF RING BELOW
+    ('\u{0340}', CharMapAction::SubChar('\u{0300}')), // COMBINING GRAVE TONE MARK
+    ('\u{0341}', CharMapAction::SubChar('\u{0301}')), // COMBINING ACUTE TONE MARK
+    ('\u{0342}', CharMapAction::SubChar('\u{0303}')), // COMBINING GREEK PERISPOMENI
+    ('\u{0343}', CharMapAction::SubChar('\u{0313}')), // COMBINING GREEK KORONIS
+    ('\u{0345}', CharMapAction::SubChar('\u{0328}')), // COMBINING GREEK YPOGEGRAMMENI
+    ('\u{0347}', CharMapAction::SubChar('\u{0333}')), // COMBINING EQUALS SIGN BELOW
+    ('\u{0357}', CharMapAction::SubChar('\u{0350}')), // COMBINING RIGHT HALF RING ABOVE
+    ('\u{0358}', CharMapAction::SubChar('\u{0307}')), // COMBINING DOT ABOVE RIGHT
+    ('\u{0366}', CharMapAction::SubChar('\u{030A}')), // COMBINING LATIN SMALL LETTER O
+    ('\u{036E}', CharMapAction::SubChar('\u{0306}')), // COMBINING LATIN SMALL LETTER V
+    ('\u{0370}', CharMapAction::SubChar('\u{2C75}')), // GREEK CAPITAL LETTER HETA
+    ('\u{0374}', CharMapAction::SubChar('\'')), // GREEK NUMERAL SIGN
+    ('\u{0375}', CharMapAction::SubChar('\u{02CF}')), // GREEK LOWER NUMERAL SIGN
+    ('\u{0376}', CharMapAction::SubChar('\u{0418}')), // GREEK CAPITAL LETTER PAMPHYLIAN DIGAMMA
+    ('\u{0377}', CharMapAction::SubChar('\u{1D0E}')), // GREEK SMALL LETTER PAMPHYLIAN DIGAMMA
+    ('\u{037A}', CharMapAction::SubChar('i')), // GREEK YPOGEGRAMMENI
+    ('\u{037B}', CharMapAction::SubChar('\u{0254}')), // GREEK SMALL REVERSED LUNATE SIGMA SYMBOL
+    ('\u{037D}', CharMapAction::SubChar('\u{A73F}')), // GREEK SMALL REVERSED DOTTED LUNATE SIGMA SYMBOL
+    ('\u{037E}', CharMapAction::SubChar(';')), // GREEK QUESTION MARK
+    ('\u{037F}', CharMapAction::SubChar('J')), // GREEK CAPITAL LETTER YOT
+    ('\u{0384}', CharMapAction::SubChar('\'')), // GREEK TONOS
+    ('\u{0387}', CharMapAction::SubChar('\u{00B7}')), // GREEK ANO TELEIA
+    ('\u{0391}', CharMapAction::SubChar('A')), // GREEK CAPITAL LETTER ALPHA
+    ('\u{0392}', CharMapAction::SubChar('B')), // GREEK CAPITAL LETTER BETA
+    ('\u{0395}', CharMapAction::SubChar('E')), // GREEK CAPITAL LETTER EPSILON
+    ('\u{0396}', CharMapAction::SubChar('Z')), // GREEK CAPITAL LETTER ZETA
+    ('\u{0397}', CharMapAction::SubChar('H')), // GREEK CAPITAL LETTER ETA
+    ('\u{0398}', CharMapAction::SubStr("O\u{0335}")), // GREEK CAPITAL LETTER THETA
+    ('\u{0399}', CharMapAction::SubChar('l')), // GREEK CAPITAL LETTER IOTA
+    ('\u{039A}', CharMapAction::SubChar('K')), // GREEK CAPITAL LETTER KAPPA
+    ('\u{039B}', CharMapAction::SubChar('\u{0245}')), // GREEK CAPITAL LETTER LAMDA
+    ('\u{039C}', CharMapAction::SubChar('M')), // GREEK CAPITAL LETTER MU
+    ('\u{039D}', CharMapAction::SubChar('N')), // GREEK CAPITAL LETTER NU
+    ('\u{039F}', CharMapAction::SubChar('O')), // GREEK CAPITAL LETTER OMICRON
+    ('\u{03A1}', CharMapAction::SubChar('P')), // GREEK CAPITAL LETTER RHO
+    ('\u{03A3}', CharMapAction::SubChar('\u{01A9}')), // GREEK CAPITAL LETTER SIGMA
+    ('\u{03A4}', CharMapAction::SubChar('T')), // GREEK CAPITAL LETTER TAU
+    ('\u{03A5}', CharMapAction::SubChar('Y')), // GREEK CAPITAL LETTER UPSILON
+    ('\u{03A7}', CharMapAction::SubChar('X')), // GREEK CAPITAL LETTER CHI
+    ('\u{03B1}', CharMapAction::SubChar('a')), // GREEK SMALL LETTER ALPHA
+    ('\u{03B2}', CharMapAction::SubChar('\u{00DF}')), // GREEK SMALL LETTER BETA
+    ('\u{03B3}', CharMapAction::SubChar('y')), // GREEK SMALL LETTER GAMMA
+    ('\u{03B4}', CharMapAction::SubChar('\u{1E9F}')), // GREEK SMALL LETTER DELTA
+    ('\u{03B5}', CharMapAction::SubChar('\u{A793}')), // GREEK SMALL LETTER EPSILON
+    ('\u{03B7}', CharMapAction::SubStr("n\u{0329}")), // GREEK SMALL LETTER ETA
+    ('\u{03B8}', CharMapAction::SubStr("O\u{0335}")), // GREEK SMALL LETTER THETA
+    ('\u{03B9}', CharMapAction::SubChar('i')), // GREEK SMALL LETTER IOTA
+    ('\u{03BA}', CharMapAction::SubChar('\u{0138}')), // GREEK SMALL LETTER KAPPA
+    ('\u{03BD}', CharMapAction::SubChar('v')), // GREEK SMALL LETTER NU
+    ('\u{03BF}', CharMapAction::SubChar('o')), // GREEK SMALL LETTER OMICRON
+    ('\u{03C1}', CharMapAction::SubChar('p')), // GREEK SMALL LETTER RHO
+    ('\u{03C3}', CharMapAction::SubChar('o')), // GREEK SMALL LETTER SIGMA
+    ('\u{03C4}', CharMapAction::SubChar('\u{1D1B}')), // GREEK SMALL LETTER TAU
+    ('\u{03C5}', CharMapAction::SubChar('u')), // GREEK SMALL LETTER UPSILON
+    ('\u{03C6}', CharMapAction::SubChar('\u{0278}')), // GREEK SMALL LETTER PHI
+    ('\u{03D0}', CharMapAction::SubChar('\u{00DF}')), // GREEK BETA SYMBOL
+    ('\u{03D1}', CharMapAction::SubStr("O\u{0335}")), // GREEK THETA SYMBOL
+    ('\u{03D2}', CharMapAction::SubChar('Y')), // GREEK UPSILON WITH HOOK SYMBOL
+    ('\u{03D5}', CharMapAction::SubChar('\u{0278}')), // GREEK PHI SYMBOL
+    ('\u{03D6}', CharMapAction::SubChar('\u{03C0}')), // GREEK PI SYMBOL
+    ('\u{03DB}', CharMapAction::SubChar('\u{03C2}')), // GREEK SMALL LETTER STIGMA
+    ('\u{03DC}', CharMapAction::SubChar('F')), // GREEK LETTER DIGAMMA
+    ('\u{03E8}', CharMapAction::SubChar('2')), // COPTIC CAPITAL LETTER HORI
+    ('\u{03E9}', CharMapAction::SubChar('\u{01A8}')), // COPTIC SMALL LETTER HORI
+    ('\u{03F0}', CharMapAction::SubChar('\u{0138}')), // GREEK KAPPA SYMBOL
+    ('\u{03F1}', CharMapAction::SubChar('p')), // GREEK RHO SYMBOL
+    ('\u{03F2}', CharMapAction::SubChar('c')), // GREEK LUNATE SIGMA SYMBOL
+    ('\u{03F3}', CharMapAction::SubChar('j')), // GREEK LETTER YOT
+    ('\u{03F4}', CharMapAction::SubStr("O\u{0335}")), // GREEK CAPITAL THETA SYMBOL
+    ('\u{03F5}', CharMapAction::SubChar('\u{A793}')), // GREEK LUNATE EPSILON SYMBOL
+    ('\u{03F7}', CharMapAction::SubChar('\u{00DE}')), // GREEK CAPITAL LETTER SHO
+    ('\u{03F8}', CharMapAction::SubChar('\u{00FE}')), // GREEK SMALL LETTER SHO
+    ('\u{03F9}', CharMapAction::SubChar('C')), // GREEK CAPITAL LUNATE SIGMA SYMBOL
+    ('\u{03FA}', CharMapAction::SubChar('M')), // GREEK CAPITAL LETTER SAN
+    ('\u{03FD}', CharMapAction::SubChar('\u{0186}')), // GREEK CAPITAL REVERSED LUNATE SIGMA SYMBOL
+    ('\u{03FF}', CharMapAction::SubChar('\u{A73E}')), // GREEK CAPITAL REVERSED DOTTED LUNATE SIGMA SYMBOL
+    ('\u{0404}', CharMapAction::SubChar('\u{A792}')), // CYRILLIC CAPITAL LETTER UKRAINIAN IE
+    ('\u{0405}', CharMapAction::SubChar('S')), // CYRILLIC CAPITAL LETTER DZE
+    ('\u{0406}', CharMapAction::SubChar('l')), // CYRILLIC CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0408}', CharMapAction::SubChar('J')), // CYRILLIC CAPITAL LETTER JE
+    ('\u{0410}', CharMapAction::SubChar('A')), // CYRILLIC CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubStr("b\u{0304}")), // CYRILLIC CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('B')), // CYRILLIC CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('\u{0393}')), // CYRILLIC CAPITAL LETTER GHE
+    ('\u{0415}', CharMapAction::SubChar('E')), // CYRILLIC CAPITAL LETTER IE
+    ('\u{0417}', CharMapAction::SubChar('3')), // CYRILLIC CAPITAL LETTER ZE
+    ('\u{0419}', CharMapAction::SubChar('\u{040D}')), // CYRILLIC CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')), // CYRILLIC CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('\u{0245}')), // CYRILLIC CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')), // CYRILLIC CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('H')), // CYRILLIC CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')), // CYRILLIC CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('\u{03A0}')), // CYRILLIC CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('P')), // CYRILLIC CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('C')), // CYRILLIC CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')), // CYRILLIC CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('Y')), // CYRILLIC CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('\u{03A6}')), // CYRILLIC CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('X')), // CYRILLIC CAPITAL LETTER HA
+    ('\u{042B}', CharMapAction::SubStr("bl")), // CYRILLIC CAPITAL LETTER YERU
+    ('\u{042C}', CharMapAction::SubChar('b')), // CYRILLIC CAPITAL LETTER SOFT SIGN
+    ('\u{042E}', CharMapAction::SubStr("lO")), // CYRILLIC CAPITAL LETTER YU
+    ('\u{0430}', CharMapAction::SubChar('a')), // CYRILLIC SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('6')), // CYRILLIC SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('\u{0299}')), // CYRILLIC SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('r')), // CYRILLIC SMALL LETTER GHE
+    ('\u{0435}', CharMapAction::SubChar('e')), // CYRILLIC SMALL LETTER IE
+    ('\u{0437}', CharMapAction::SubChar('\u{025C}')), // CYRILLIC SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('\u{1D0E}')), // CYRILLIC SMALL LETTER I
+    ('\u{043A}', CharMapAction::SubChar('\u{0138}')), // CYRILLIC SMALL LETTER KA
+    ('\u{043C}', CharMapAction::SubChar('\u{028D}')), // CYRILLIC SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('\u{029C}')), // CYRILLIC SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')), // CYRILLIC SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('\u{03C0}')), // CYRILLIC SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('p')), // CYRILLIC SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('c')), // CYRILLIC SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('\u{1D1B}')), // CYRILLIC SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('y')), // CYRILLIC SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('\u{0278}')), // CYRILLIC SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('x')), // CYRILLIC SMALL LETTER HA
+    ('\u{044A}', CharMapAction::SubStr("\u{02C9}b")), // CYRILLIC SMALL LETTER HARD SIGN
+    ('\u{044B}', CharMapAction::SubStr("\u{0185}i")), // CYRILLIC SMALL LETTER YERU
+    ('\u{044C}', CharMapAction::SubChar('\u{0185}')), // CYRILLIC SMALL LETTER SOFT SIGN
+    ('\u{044F}', CharMapAction::SubChar('\u{1D19}')), // CYRILLIC SMALL LETTER YA
+    ('\u{0454}', CharMapAction::SubChar('\u{A793}')), // CYRILLIC SMALL LETTER UKRAINIAN IE
+    ('\u{0455}', CharMapAction::SubChar('s')), // CYRILLIC SMALL LETTER DZE
+    ('\u{0456}', CharMapAction::SubChar('i')), // CYRILLIC SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0458}', CharMapAction::SubChar('j')), // CYRILLIC SMALL LETTER JE
+    ('\u{045B}', CharMapAction::SubStr("h\u{0335}")), // CYRILLIC SMALL LETTER TSHE
+    ('\u{045D}', CharMapAction::SubChar('\u{0439}')), // CYRILLIC SMALL LETTER I WITH GRAVE
+    ('\u{0461}', CharMapAction::SubChar('w')), // CYRILLIC SMALL LETTER OMEGA
+    ('\u{0462}', CharMapAction::SubStr("b\u{0335}")), // CYRILLIC CAPITAL LETTER YAT
+    ('\u{0463}', CharMapAction::SubStr("b\u{0335}")), // CYRILLIC SMALL LETTER YAT
+    ('\u{0470}', CharMapAction::SubChar('\u{03A8}')), // CYRILLIC CAPITAL LETTER PSI
+    ('\u{0471}', CharMapAction::SubChar('\u{03C8}')), // CYRILLIC SMALL LETTER PSI
+    ('\u{0472}', CharMapAction::SubStr("O\u{0335}")), // CYRILLIC CAPITAL LETTER FITA
+    ('\u{0473}', CharMapAction::SubStr("o\u{0335}")), // CYRILLIC SMALL LETTER FITA
+    ('\u{0474}', CharMapAction::SubChar('V')), // CYRILLIC CAPITAL LETTER IZHITSA
+    ('\u{0475}', CharMapAction::SubChar('v')), // CYRILLIC SMALL LETTER IZHITSA
+    ('\u{047C}', CharMapAction::SubStr("\u{0460}\u{0486}\u{0487}")), // CYRILLIC CAPITAL LETTER OMEGA WITH TITLO
+    ('\u{047D}', CharMapAction::SubStr("w\u{0486}\u{0487}")), // CYRILLIC SMALL LETTER OMEGA WITH TITLO
+    ('\u{048A}', CharMapAction::SubStr("\u{040D}\u{0326}")), // CYRILLIC CAPITAL LETTER SHORT I WITH TAIL
+    ('\u{048B}', CharMapAction::SubStr("\u{0439}\u{0326}")), // CYRILLIC SMALL LETTER SHORT I WITH TAIL
+    ('\u{048C}', CharMapAction::SubStr("b\u{0335}")), // CYRILLIC CAPITAL LETTER SEMISOFT SIGN
+    ('\u{048D}', CharMapAction::SubStr("b\u{0335}")), // CYRILLIC SMALL LETTER SEMISOFT SIGN
+    ('\u{0490}', CharMapAction::SubStr("\u{0393}'")), // CYRILLIC CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0491}', CharMapAction::SubStr("r'")), // CYRILLIC SMALL LETTER GHE WITH UPTURN
+    ('\u{0492}', CharMapAction::SubStr("\u{0393}\u{0335}")), // CYRILLIC CAPITAL LETTER GHE WITH STROKE
+    ('\u{0493}', CharMapAction::SubStr("r\u{0335}")), // CYRILLIC SMALL LETTER GHE WITH STROKE
+    ('\u{0496}', CharMapAction::SubStr("\u{0416}\u{0329}")), // CYRILLIC CAPITAL LETTER ZHE WITH DESCENDER
+    ('\u{0497}', CharMapAction::SubStr("\u{0436}\u{0329}")), // CYRILLIC SMALL LETTER ZHE WITH DESCENDER
+    ('\u{0498}', CharMapAction::SubStr("3\u{0326}")), // CYRILLIC CAPITAL LETTER ZE WITH DESCENDER
+    ('\u{0499}', CharMapAction::SubStr("\u{025C}\u{0326}")), // CYRILLIC SMALL LETTER ZE WITH DESCENDER
+    ('\u{049A}', CharMapAction::SubStr("K\u{0329}")), // CYRILLIC CAPITAL LETTER KA WITH DESCENDER
+    ('\u{049B}', CharMapAction::SubStr("\u{0138}\u{0329}")), // CYRILLIC SMALL LETTER KA WITH DESCENDER
+    ('\u{049E}', CharMapAction::SubStr("K\u{0335}")), // CYRILLIC CAPITAL LETTER KA WITH STROKE
+    ('\u{049F}', CharMapAction::SubStr("\u{0138}\u{0335}")), // CYRILLIC SMALL LETTER KA WITH STROKE
+    ('\u{04A2}', CharMapAction::SubStr("H\u{0329}")), // CYRILLIC CAPITAL LETTER EN WITH DESCENDER
+    ('\u{04A3}', CharMapAction::SubStr("\u{029C}\u{0329}")), // CYRILLIC SMALL LETTER EN WITH DESCENDER
+    ('\u{04AA}', CharMapAction::SubStr("C\u{0326}")), // CYRILLIC CAPITAL LETTER ES WITH DESCENDER
+    ('\u{04AB}', CharMapAction::SubStr("c\u{0326}")), // CYRILLIC SMALL LETTER ES WITH DESCENDER
+    ('\u{04AC}', CharMapAction::SubStr("T\u{0329}")), // CYRILLIC CAPITAL LETTER TE WITH DESCENDER
+    ('\u{04AD}', CharMapAction::SubStr("\u{1D1B}\u{0329}")), // CYRILLIC SMALL LETTER TE WITH DESCENDER
+    ('\u{04AE}', CharMapAction::SubChar('Y')), // CYRILLIC CAPITAL LETTER STRAIGHT U
+    ('\u{04AF}', CharMapAction::SubChar('y')), // CYRILLIC SMALL LETTER STRAIGHT U
+    ('\u{04B0}', CharMapAction::SubStr("Y\u{0335}")), // CYRILLIC CAPITAL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B1}', CharMapAction::SubStr("y\u{0335}")), // CYRILLIC SMALL LETTER STRAIGHT U WITH STROKE
+    ('\u{04B2}', CharMapAction::SubStr("X\u{0329}")), // CYRILLIC CAPITAL LETTER HA WITH DESCENDER
+    ('\u{04BB}', CharMapAction::SubChar('h')), // CYRILLIC SMALL LETTER SHHA
+    ('\u{04BD}', CharMapAction::SubChar('e')), // CYRILLIC SMALL LETTER ABKHASIAN CHE
+    ('\u{04BE}', CharMapAction::SubStr("\u{04BC}\u{0328}")), // CYRILLIC CAPITAL LETTER ABKHASIAN CHE WITH DESCENDER
+    ('\u{04BF}', CharMapAction::SubStr("e\u{0328}")), // CYRILLIC SMALL LETTER ABKHASIAN CHE WITH DESCENDER
+    ('\u{04C0}', CharMapAction::SubChar('l')), // CYRILLIC LETTER PALOCHKA
+    ('\u{04C5}', CharMapAction::SubStr("\u{0245}\u{0326}")), // CYRILLIC CAPITAL LETTER EL WITH TAIL
+    ('\u{04C6}', CharMapAction::SubStr("\u{043B}\u{0326}")), // CYRILLIC SMALL LETTER EL WITH TAIL
+    ('\u{04C7}', CharMapAction::SubStr("H\u{0326}")), // CYRILLIC CAPITAL LETTER EN WITH HOOK
+    ('\u{04C8}', CharMapAction::SubStr("\u{029C}\u{0326}")), // CYRILLIC SMALL LETTER EN WITH HOOK
+    ('\u{04C9}', CharMapAction::SubStr("H\u{0326}")), // CYRILLIC CAPITAL LETTER EN WITH TAIL
+    ('\u{04CA}', CharMapAction::SubStr("\u{029C}\u{0326}")), // CYRILLIC SMALL LETTER EN WITH TAIL
+    ('\u{04CB}', CharMapAction::SubChar('\u{04B6}')), // CYRILLIC CAPITAL LETTER KHAKASSIAN CHE
+    ('\u{04CC}', CharMapAction::SubChar('\u{04B7}')), // CYRILLIC SMALL LETTER KHAKASSIAN CHE
+    ('\u{04CD}', CharMapAction::SubStr("M\u{0326}")), // CYRILLIC CAPITAL LETTER EM WITH TAIL
+    ('\u{04CE}', CharMapAction::SubStr("\u{028D}\u{0326}")), // CYRILLIC SMALL LETTER EM WITH TAIL
+    ('\u{04CF}', CharMapAction::SubChar('i')), // CYRILLIC SMALL LETTER PALOCHKA
+    ('\u{04D4}', CharMapAction::SubStr("AE")), // CYRILLIC CAPITAL LIGATURE A IE
+    ('\u{04D5}', CharMapAction::SubStr("ae")), // CYRILLIC SMALL LIGATURE A IE
+    ('\u{04D8}', CharMapAction::SubChar('\u{018F}')), // CYRILLIC CAPITAL LETTER SCHWA
+    ('\u{04D9}', CharMapAction::SubChar('\u{01DD}')), // CYRILLIC SMALL LETTER SCHWA
+    ('\u{04E0}', CharMapAction::SubChar('3')), // CYRILLIC CAPITAL LETTER ABKHASIAN DZE
+    ('\u{04E1}', CharMapAction::SubChar('\u{021D}')), // CYRILLIC SMALL LETTER ABKHASIAN DZE
+    ('\u{04E8}', CharMapAction::SubStr("O\u{0335}")), // CYRILLIC CAPITAL LETTER BARRED O
+    ('\u{04E9}', CharMapAction::SubStr("o\u{0335}")), // CYRILLIC SMALL LETTER BARRED O
+    ('\u{0501}', CharMapAction::SubChar('d')), // CYRILLIC SMALL LETTER KOMI DE
+    ('\u{050A}', CharMapAction::SubChar('\u{01F6}')), // CYRILLIC CAPITAL LETTER KOMI NJE
+    ('\u{050C}', CharMapAction::SubChar('G')), // CYRILLIC CAPITAL LETTER KOMI SJE
+    ('\u{050D}', CharMapAction::SubChar('\u{0262}')), // CYRILLIC SMALL LETTER KOMI SJE
+    ('\u{0510}', CharMapAction::SubChar('\u{0190}')), // CYRILLIC CAPITAL LETTER REVERSED ZE
+    ('\u{0511}', CharMapAction::SubChar('\u{A793}')), // CYRILLIC SMALL LETTER REVERSED ZE
+    ('\u{051B}', CharMapAction::SubChar('q')), // CYRILLIC SMALL LETTER QA
+    ('\u{051C}', CharMapAction::SubChar('W')), // CYRILLIC CAPITAL LETTER WE
+    ('\u{051D}', CharMapAction::SubChar('w')), // CYRILLIC SMALL LETTER WE
+    ('\u{053B}', CharMapAction::SubChar('\u{12AE}')), // ARMENIAN CAPITAL LETTER INI
+    ('\u{0544}', CharMapAction::SubChar('\u{1206}')), // ARMENIAN CAPITAL LETTER MEN
+    ('\u{054A}', CharMapAction::SubChar('\u{1323}')), // ARMENIAN CAPITAL LETTER PEH
+    ('\u{054C}', CharMapAction::SubChar('\u{1261}')), // ARMENIAN CAPITAL LETTER RA
+    ('\u{054D}', CharMapAction::SubChar('U')), // ARMENIAN CAPITAL LETTER SEH
+    ('\u{054F}', CharMapAction::SubChar('S')), // ARMENIAN CAPITAL LETTER TIWN
+    ('\u{0553}', CharMapAction::SubChar('\u{03A6}')), // ARMENIAN CAPITAL LETTER PIWR
+    ('\u{0555}', CharMapAction::SubChar('O')), // ARMENIAN CAPITAL LETTER OH
+    ('\u{055A}', CharMapAction::SubChar('\'')), // ARMENIAN APOSTROPHE
+    ('\u{055D}', CharMapAction::SubChar('\'')), // ARMENIAN COMMA
+    ('\u{0561}', CharMapAction::SubChar('w')), // ARMENIAN SMALL LETTER AYB
+    ('\u{0563}', CharMapAction::SubChar('q')), // ARMENIAN SMALL LETTER GIM
+    ('\u{0566}', CharMapAction::SubChar('q')), // ARMENIAN SMALL LETTER ZA
+    ('\u{056E}', CharMapAction::SubChar('\u{1E9F}')), // ARMENIAN SMALL LETTER CA
+    ('\u{0570}', CharMapAction::SubChar('h')), // ARMENIAN SMALL LETTER HO
+    ('\u{0575}', CharMapAction::SubChar('\u{0237}')), // ARMENIAN SMALL LETTER YI
+    ('\u{0578}', CharMapAction::SubChar('n')), // ARMENIAN SMALL LETTER VO
+    ('\u{057A}', CharMapAction::SubChar('\u{0270}')), // ARMENIAN SMALL LETTER PEH
+    ('\u{057C}', CharMapAction::SubChar('n')), // ARMENIAN SMALL LETTER RA
+    ('\u{057D}', CharMapAction::SubChar('u')), // ARMENIAN SMALL LETTER SEH
+    ('\u{0581}', CharMapAction::SubChar('g')), // ARMENIAN SMALL LETTER CO
+    ('\u{0584}', CharMapAction::SubChar('f')), // ARMENIAN SMALL LETTER KEH
+    ('\u{0585}', CharMapAction::SubChar('o')), // ARMENIAN SMALL LETTER OH
+    ('\u{0587}', CharMapAction::SubStr("\u{0565}\u{0582}")), // ARMENIAN SMALL LIGATURE ECH YIWN
+    ('\u{0589}', CharMapAction::SubChar(':')), // ARMENIAN FULL STOP
+    ('\u{059C}', CharMapAction::SubChar('\u{0301}')), // HEBREW ACCENT GERESH
+    ('\u{059D}', CharMapAction::SubChar('\u{0301}')), // HEBREW ACCENT GERESH MUQDAM
+    ('\u{05A4}', CharMapAction::SubChar('\u{059A}')), // HEBREW ACCENT MAHAPAKH
+    ('\u{05A8}', CharMapAction::SubChar('\u{0599}')), // HEBREW ACCENT QADMA
+    ('\u{05AD}', CharMapAction::SubChar('\u{0596}')), // HEBREW ACCENT DEHI
+    ('\u{05AE}', CharMapAction::SubChar('\u{0598}')), // HEBREW ACCENT ZINOR
+    ('\u{05AF}', CharMapAction::SubChar('\u{030A}')), // HEBREW MARK MASORA CIRCLE
+    ('\u{05B4}', CharMapAction::SubChar('\u{0323}')), // HEBREW POINT HIRIQ
+    ('\u{05B9}', CharMapAction::SubChar('\u{0307}')), // HEBREW POINT HOLAM
+    ('\u{05BA}', CharMapAction::SubChar('\u{0307}')), // HEBREW POINT HOLAM HASER FOR VAV
+    ('\u{05C0}', CharMapAction::SubChar('l')), // HEBREW PUNCTUATION PASEQ
+    ('\u{05C1}', CharMapAction::SubChar('\u{0307}')), // HEBREW POINT SHIN DOT
+    ('\u{05C2}', CharMapAction::SubChar('\u{0307}')), // HEBREW POINT SIN DOT
+    ('\u{05C3}', CharMapAction::SubChar(':')), // HEBREW PUNCTUATION SOF PASUQ
+    ('\u{05C4}', CharMapAction::SubChar('\u{0307}')), // HEBREW MARK UPPER DOT
+    ('\u{05C5}', CharMapAction::SubChar('\u{0323}')), // HEBREW MARK LOWER DOT
+    ('\u{05D5}', CharMapAction::SubChar('l')), // HEBREW LETTER VAV
+    ('\u{05D8}', CharMapAction::SubChar('v')), // HEBREW LETTER TET
+    ('\u{05D9}', CharMapAction::SubChar('\'')), // HEBREW LETTER YOD
+    ('\u{05DF}', CharMapAction::SubChar('l')), // HEBREW LETTER FINAL NUN
+    ('\u{05E1}', CharMapAction::SubChar('o')), // HEBREW LETTER SAMEKH
+    ('\u{05F0}', CharMapAction::SubStr("ll")), // HEBREW LIGATURE YIDDISH DOUBLE VAV
+    ('\u{05F1}', CharMapAction::SubStr("l'")), // HEBREW LIGATURE YIDDISH VAV YOD
+    ('\u{05F2}', CharMapAction::SubStr("''")), // HEBREW LIGATURE YIDDISH DOUBLE YOD
+    ('\u{05F3}', CharMapAction::SubChar('\'')), // HEBREW PUNCTUATION GERESH
+    ('\u{05F4}', CharMapAction::SubStr("''")), // HEBREW PUNCTUATION GERSHAYIM
+    ('\u{0609}', CharMapAction::SubStr("\u{00BA}/\u{2080}\u{2080}")), // ARABIC-INDIC PER MILLE SIGN
+    ('\u{060A}', CharMapAction::SubStr("\u{00BA}/\u{2080}\u{2080}\u{2080}")), // ARABIC-INDIC PER TEN THOUSAND SIGN
+    ('\u{060D}', CharMapAction::SubChar(',')), // ARABIC DATE SEPARATOR
+    ('\u{060F}', CharMapAction::SubChar('\u{0639}')), // ARABIC SIGN MISRA
+    ('\u{0618}', CharMapAction::SubChar('\u{0301}')), // ARABIC SMALL FATHA
+    ('\u{0619}', CharMapAction::SubChar('\u{0313}')), // ARABIC SMALL DAMMA
+    ('\u{061A}', CharMapAction::SubChar('\u{0650}')), // ARABIC SMALL KASRA
+    ('\u{0623}', CharMapAction::SubStr("l\u{0674}")), // ARABIC LETTER ALEF WITH HAMZA ABOVE
+    ('\u{0624}', CharMapAction::SubStr("\u{0648}\u{0674}")), // ARABIC LETTER WAW WITH HAMZA ABOVE
+    ('\u{0625}', CharMapAction::SubStr("l\u{0655}")), // ARABIC LETTER ALEF WITH HAMZA BELOW
+    ('\u{0626}', CharMapAction::SubStr("\u{0649}\u{0674}")), // ARABIC LETTER YEH WITH HAMZA ABOVE
+    ('\u{0627}', CharMapAction::SubChar('l')), // ARABIC LETTER ALEF
+    ('\u{062B}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER THEH
+    ('\u{0634}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC LETTER SHEEN
+    ('\u{063D}', CharMapAction::SubStr("\u{0649}\u{0302}")), // ARABIC LETTER FARSI YEH WITH INVERTED V
+    ('\u{063F}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER FARSI YEH WITH THREE DOTS ABOVE
+    ('\u{0647}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH
+    ('\u{064A}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH
+    ('\u{064B}', CharMapAction::SubChar('\u{030B}')), // ARABIC FATHATAN
+    ('\u{064E}', CharMapAction::SubChar('\u{0301}')), // ARABIC FATHA
+    ('\u{064F}', CharMapAction::SubChar('\u{0313}')), // ARABIC DAMMA
+    ('\u{0652}', CharMapAction::SubChar('\u{030A}')), // ARABIC SUKUN
+    ('\u{0653}', CharMapAction::SubChar('\u{0303}')), // ARABIC MADDAH ABOVE
+    ('\u{0656}', CharMapAction::SubChar('\u{0329}')), // ARABIC SUBSCRIPT ALEF
+    ('\u{0657}', CharMapAction::SubChar('\u{0312}')), // ARABIC INVERTED DAMMA
+    ('\u{0658}', CharMapAction::SubChar('\u{0306}')), // ARABIC MARK NOON GHUNNA
+    ('\u{0659}', CharMapAction::SubChar('\u{0304}')), // ARABIC ZWARAKAY
+    ('\u{065A}', CharMapAction::SubChar('\u{0306}')), // ARABIC VOWEL SIGN SMALL V ABOVE
+    ('\u{065B}', CharMapAction::SubChar('\u{0302}')), // ARABIC VOWEL SIGN INVERTED SMALL V ABOVE
+    ('\u{065C}', CharMapAction::SubChar('\u{0323}')), // ARABIC VOWEL SIGN DOT BELOW
+    ('\u{065D}', CharMapAction::SubChar('\u{0314}')), // ARABIC REVERSED DAMMA
+    ('\u{065F}', CharMapAction::SubChar('\u{0655}')), // ARABIC WAVY HAMZA BELOW
+    ('\u{0660}', CharMapAction::SubChar('.')), // ARABIC-INDIC DIGIT ZERO
+    ('\u{0661}', CharMapAction::SubChar('l')), // ARABIC-INDIC DIGIT ONE
+    ('\u{0665}', CharMapAction::SubChar('o')), // ARABIC-INDIC DIGIT FIVE
+    ('\u{0667}', CharMapAction::SubChar('V')), // ARABIC-INDIC DIGIT SEVEN
+    ('\u{0668}', CharMapAction::SubChar('\u{0245}')), // ARABIC-INDIC DIGIT EIGHT
+    ('\u{066A}', CharMapAction::SubStr("\u{00BA}/\u{2080}")), // ARABIC PERCENT SIGN
+    ('\u{066B}', CharMapAction::SubChar(',')), // ARABIC DECIMAL SEPARATOR
+    ('\u{066C}', CharMapAction::SubChar('\u{060C}')), // ARABIC THOUSANDS SEPARATOR
+    ('\u{066D}', CharMapAction::SubChar('*')), // ARABIC FIVE POINTED STAR
+    ('\u{066E}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER DOTLESS BEH
+    ('\u{066F}', CharMapAction::SubChar('\u{06A1}')), // ARABIC LETTER DOTLESS QAF
+    ('\u{0672}', CharMapAction::SubStr("l\u{0674}")), // ARABIC LETTER ALEF WITH WAVY HAMZA ABOVE
+    ('\u{0673}', CharMapAction::SubStr("l\u{0655}")), // ARABIC LETTER ALEF WITH WAVY HAMZA BELOW
+    ('\u{0675}', CharMapAction::SubStr("l\u{0674}")), // ARABIC LETTER HIGH HAMZA ALEF
+    ('\u{0676}', CharMapAction::SubStr("\u{0648}\u{0674}")), // ARABIC LETTER HIGH HAMZA WAW
+    ('\u{0677}', CharMapAction::SubStr("\u{0648}\u{0313}\u{0674}")), // ARABIC LETTER U WITH HAMZA ABOVE
+    ('\u{0678}', CharMapAction::SubStr("\u{0649}\u{0674}")), // ARABIC LETTER HIGH HAMZA YEH
+    ('\u{0679}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER TTEH
+    ('\u{067E}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER PEH
+    ('\u{0681}', CharMapAction::SubStr("\u{062D}\u{0654}")), // ARABIC LETTER HAH WITH HAMZA ABOVE
+    ('\u{0685}', CharMapAction::SubStr("\u{062D}\u{06DB}")), // ARABIC LETTER HAH WITH THREE DOTS ABOVE
+    ('\u{0688}', CharMapAction::SubStr("\u{062F}\u{0615}")), // ARABIC LETTER DDAL
+    ('\u{068B}', CharMapAction::SubStr("\u{068A}\u{0615}")), // ARABIC LETTER DAL WITH DOT BELOW AND SMALL TAH
+    ('\u{068E}', CharMapAction::SubStr("\u{062F}\u{06DB}")), // ARABIC LETTER DUL
+    ('\u{0691}', CharMapAction::SubStr("\u{0631}\u{0615}")), // ARABIC LETTER RREH
+    ('\u{0692}', CharMapAction::SubStr("\u{0631}\u{0306}")), // ARABIC LETTER REH WITH SMALL V
+    ('\u{0698}', CharMapAction::SubStr("\u{0631}\u{06DB}")), // ARABIC LETTER JEH
+    ('\u{069E}', CharMapAction::SubStr("\u{0635}\u{06DB}")), // ARABIC LETTER SAD WITH THREE DOTS ABOVE
+    ('\u{069F}', CharMapAction::SubStr("\u{0637}\u{06DB}")), // ARABIC LETTER TAH WITH THREE DOTS ABOVE
+    ('\u{06A4}', CharMapAction::SubStr("\u{06A1}\u{06DB}")), // ARABIC LETTER VEH
+    ('\u{06A7}', CharMapAction::SubChar('\u{0641}')), // ARABIC LETTER QAF WITH DOT ABOVE
+    ('\u{06A8}', CharMapAction::SubStr("\u{06A1}\u{06DB}")), // ARABIC LETTER QAF WITH THREE DOTS ABOVE
+    ('\u{06A9}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KEHEH
+    ('\u{06AA}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER SWASH KAF
+    ('\u{06AD}', CharMapAction::SubStr("\u{0643}\u{06DB}")), // ARABIC LETTER NG
+    ('\u{06B4}', CharMapAction::SubStr("\u{06AF}\u{06DB}")), // ARABIC LETTER GAF WITH THREE DOTS ABOVE
+    ('\u{06B5}', CharMapAction::SubStr("\u{0644}\u{0306}")), // ARABIC LETTER LAM WITH SMALL V
+    ('\u{06B7}', CharMapAction::SubStr("\u{0644}\u{06DB}")), // ARABIC LETTER LAM WITH THREE DOTS ABOVE
+    ('\u{06BA}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER NOON GHUNNA
+    ('\u{06BB}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER RNOON
+    ('\u{06BD}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER NOON WITH THREE DOTS ABOVE
+    ('\u{06BE}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH DOACHASHMEE
+    ('\u{06C1}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH GOAL
+    ('\u{06C2}', CharMapAction::SubChar('\u{06C0}')), // ARABIC LETTER HEH GOAL WITH HAMZA ABOVE
+    ('\u{06C3}', CharMapAction::SubChar('\u{0629}')), // ARABIC LETTER TEH MARBUTA GOAL
+    ('\u{06C6}', CharMapAction::SubStr("\u{0648}\u{0306}")), // ARABIC LETTER OE
+    ('\u{06C7}', CharMapAction::SubStr("\u{0648}\u{0313}")), // ARABIC LETTER U
+    ('\u{06C8}', CharMapAction::SubStr("\u{0648}\u{0670}")), // ARABIC LETTER YU
+    ('\u{06C9}', CharMapAction::SubStr("\u{0648}\u{0302}")), // ARABIC LETTER KIRGHIZ YU
+    ('\u{06CB}', CharMapAction::SubStr("\u{0648}\u{06DB}")), // ARABIC LETTER VE
+    ('\u{06CC}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER FARSI YEH
+    ('\u{06CE}', CharMapAction::SubStr("\u{0649}\u{0306}")), // ARABIC LETTER YEH WITH SMALL V
+    ('\u{06D0}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER E
+    ('\u{06D1}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER YEH WITH THREE DOTS BELOW
+    ('\u{06D2}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH BARREE
+    ('\u{06D4}', CharMapAction::SubChar('-')), // ARABIC FULL STOP
+    ('\u{06D5}', CharMapAction::SubChar('o')), // ARABIC LETTER AE
+    ('\u{06DF}', CharMapAction::SubChar('\u{030A}')), // ARABIC SMALL HIGH ROUNDED ZERO
+    ('\u{06E8}', CharMapAction::SubStr("\u{0306}\u{0307}")), // ARABIC SMALL HIGH NOON
+    ('\u{06EC}', CharMapAction::SubChar('\u{0307}')), // ARABIC ROUNDED HIGH STOP WITH FILLED CENTRE
+    ('\u{06EE}', CharMapAction::SubStr("\u{062F}\u{0302}")), // ARABIC LETTER DAL WITH INVERTED V
+    ('\u{06EF}', CharMapAction::SubStr("\u{0631}\u{0302}")), // ARABIC LETTER REH WITH INVERTED V
+    ('\u{06F0}', CharMapAction::SubChar('.')), // EXTENDED ARABIC-INDIC DIGIT ZERO
+    ('\u{06F1}', CharMapAction::SubChar('l')), // EXTENDED ARABIC-INDIC DIGIT ONE
+    ('\u{06F2}', CharMapAction::SubChar('\u{0662}')), // EXTENDED ARABIC-INDIC DIGIT TWO
+    ('\u{06F3}', CharMapAction::SubChar('\u{0663}')), // EXTENDED ARABIC-INDIC DIGIT THREE
+    ('\u{06F4}', CharMapAction::SubChar('\u{0664}')), // EXTENDED ARABIC-INDIC DIGIT FOUR
+    ('\u{06F5}', CharMapAction::SubChar('o')), // EXTENDED ARABIC-INDIC DIGIT FIVE
+    ('\u{06F6}', CharMapAction::SubChar('\u{0666}')), // EXTENDED ARABIC-INDIC DIGIT SIX
+    ('\u{06F7}', CharMapAction::SubChar('V')), // EXTENDED ARABIC-INDIC DIGIT SEVEN
+    ('\u{06F8}', CharMapAction::SubChar('\u{0245}')), // EXTENDED ARABIC-INDIC DIGIT EIGHT
+    ('\u{06F9}', CharMapAction::SubChar('\u{0669}')), // EXTENDED ARABIC-INDIC DIGIT NINE
+    ('\u{06FD}', CharMapAction::SubStr("\u{0621}\u{0348}")), // ARABIC SIGN SINDHI AMPERSAND
+    ('\u{06FE}', CharMapAction::SubStr("\u{0645}\u{0348}")), // ARABIC SIGN SINDHI POSTPOSITION MEN
+    ('\u{06FF}', CharMapAction::SubStr("o\u{0302}")), // ARABIC LETTER HEH WITH INVERTED V
+    ('\u{0701}', CharMapAction::SubChar('.')), // SYRIAC SUPRALINEAR FULL STOP
+    ('\u{0702}', CharMapAction::SubChar('.')), // SYRIAC SUBLINEAR FULL STOP
+    ('\u{0703}', CharMapAction::SubChar(':')), // SYRIAC SUPRALINEAR COLON
+    ('\u{0704}', CharMapAction::SubChar(':')), // SYRIAC SUBLINEAR COLON
+    ('\u{0740}', CharMapAction::SubChar('\u{0307}')), // SYRIAC FEMININE DOT
+    ('\u{0741}', CharMapAction::SubChar('\u{0307}')), // SYRIAC QUSHSHAYA
+    ('\u{0742}', CharMapAction::SubChar('\u{073C}')), // SYRIAC RUKKAKHA
+    ('\u{0747}', CharMapAction::SubChar('\u{0301}')), // SYRIAC OBLIQUE LINE ABOVE
+    ('\u{0751}', CharMapAction::SubStr("\u{0628}\u{06DB}")), // ARABIC LETTER BEH WITH DOT BELOW AND THREE DOTS ABOVE
+    ('\u{0756}', CharMapAction::SubStr("\u{0649}\u{0306}")), // ARABIC LETTER BEH WITH SMALL V
+    ('\u{0762}', CharMapAction::SubChar('\u{06AC}')), // ARABIC LETTER KEHEH WITH DOT ABOVE
+    ('\u{0763}', CharMapAction::SubStr("\u{0643}\u{06DB}")), // ARABIC LETTER KEHEH WITH THREE DOTS ABOVE
+    ('\u{0767}', CharMapAction::SubChar('\u{0754}')), // ARABIC LETTER NOON WITH TWO DOTS BELOW
+    ('\u{0768}', CharMapAction::SubStr("\u{0646}\u{0615}")), // ARABIC LETTER NOON WITH SMALL TAH
+    ('\u{0769}', CharMapAction::SubStr("\u{0646}\u{0306}")), // ARABIC LETTER NOON WITH SMALL V
+    ('\u{076C}', CharMapAction::SubStr("\u{0631}\u{0654}")), // ARABIC LETTER REH WITH HAMZA ABOVE
+    ('\u{0771}', CharMapAction::SubStr("\u{0697}\u{0615}")), // ARABIC LETTER REH WITH SMALL ARABIC LETTER TAH AND TWO DOTS
+    ('\u{0772}', CharMapAction::SubStr("\u{062D}\u{0654}")), // ARABIC LETTER HAH WITH SMALL ARABIC LETTER TAH ABOVE
+    ('\u{077E}', CharMapAction::SubStr("\u{0633}\u{0302}")), // ARABIC LETTER SEEN WITH INVERTED V
+    ('\u{07C0}', CharMapAction::SubChar('O')), // NKO DIGIT ZERO
+    ('\u{07CA}', CharMapAction::SubChar('l')), // NKO LETTER A
+    ('\u{07EB}', CharMapAction::SubChar('\u{0304}')), // NKO COMBINING SHORT HIGH TONE
+    ('\u{07ED}', CharMapAction::SubChar('\u{0307}')), // NKO COMBINING SHORT RISING TONE
+    ('\u{07EE}', CharMapAction::SubChar('\u{0302}')), // NKO COMBINING LONG DESCENDING TONE
+    ('\u{07F3}', CharMapAction::SubChar('\u{0308}')), // NKO COMBINING DOUBLE DOT ABOVE
+    ('\u{07F4}', CharMapAction::SubChar('\'')), // NKO HIGH TONE APOSTROPHE
+    ('\u{07F5}', CharMapAction::SubChar('\'')), // NKO LOW TONE APOSTROPHE
+    ('\u{07FA}', CharMapAction::SubChar('_')), // NKO LAJANYALAN
+    ('\u{08A1}', CharMapAction::SubStr("\u{0628}\u{0654}")), // ARABIC LETTER BEH WITH HAMZA ABOVE
+    ('\u{08A4}', CharMapAction::SubStr("\u{06A2}\u{06DB}")), // ARABIC LETTER FEH WITH DOT BELOW AND THREE DOTS ABOVE
+    ('\u{08A7}', CharMapAction::SubStr("\u{0645}\u{06DB}")), // ARABIC LETTER MEEM WITH THREE DOTS ABOVE
+    ('\u{08A8}', CharMapAction::SubStr("\u{0649}\u{0654}")), // ARABIC LETTER YEH WITH TWO DOTS BELOW AND HAMZA ABOVE
+    ('\u{08A9}', CharMapAction::SubChar('\u{0754}')), // ARABIC LETTER YEH WITH TWO DOTS BELOW AND DOT ABOVE
+    ('\u{08AE}', CharMapAction::SubStr("\u{062F}\u{0324}\u{0323}")), // ARABIC LETTER DAL WITH THREE DOTS BELOW
+    ('\u{08AF}', CharMapAction::SubStr("\u{0635}\u{0324}\u{0323}")), // ARABIC LETTER SAD WITH THREE DOTS BELOW
+    ('\u{08B0}', CharMapAction::SubChar('\u{06AF}')), // ARABIC LETTER GAF WITH INVERTED STROKE
+    ('\u{08B1}', CharMapAction::SubChar('\u{0648}')), // ARABIC LETTER STRAIGHT WAW
+    ('\u{08B2}', CharMapAction::SubStr("\u{0632}\u{0302}")), // ARABIC LETTER ZAIN WITH INVERTED V ABOVE
+    ('\u{08B6}', CharMapAction::SubStr("\u{0628}\u{06E2}")), // ARABIC LETTER BEH WITH SMALL MEEM ABOVE
+    ('\u{08B7}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{06E2}")), // ARABIC LETTER PEH WITH SMALL MEEM ABOVE
+    ('\u{08B9}', CharMapAction::SubStr("\u{0631}\u{0306}\u{0307}")), // ARABIC LETTER REH WITH SMALL NOON ABOVE
+    ('\u{08BA}', CharMapAction::SubStr("\u{0649}\u{0306}\u{0307}")), // ARABIC LETTER YEH WITH TWO DOTS BELOW AND SMALL NOON ABOVE
+    ('\u{08BB}', CharMapAction::SubChar('\u{06A1}')), // ARABIC LETTER AFRICAN FEH
+    ('\u{08BC}', CharMapAction::SubChar('\u{06A1}')), // ARABIC LETTER AFRICAN QAF
+    ('\u{08BD}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER AFRICAN NOON
+    ('\u{08E5}', CharMapAction::SubChar('\u{064C}')), // ARABIC CURLY DAMMA
+    ('\u{08E8}', CharMapAction::SubChar('\u{064C}')), // ARABIC CURLY DAMMATAN
+    ('\u{08EA}', CharMapAction::SubChar('\u{0307}')), // ARABIC TONE ONE DOT ABOVE
+    ('\u{08EB}', CharMapAction::SubChar('\u{0308}')), // ARABIC TONE TWO DOTS ABOVE
+    ('\u{08ED}', CharMapAction::SubChar('\u{0323}')), // ARABIC TONE ONE DOT BELOW
+    ('\u{08EE}', CharMapAction::SubChar('\u{0324}')), // ARABIC TONE TWO DOTS BELOW
+    ('\u{08F0}', CharMapAction::SubChar('\u{030B}')), // ARABIC OPEN FATHATAN
+    ('\u{08F1}', CharMapAction::SubChar('\u{064C}')), // ARABIC OPEN DAMMATAN
+    ('\u{08F2}', CharMapAction::SubChar('\u{064D}')), // ARABIC OPEN KASRATAN
+    ('\u{08F3}', CharMapAction::SubChar('\u{0313}')), // ARABIC SMALL HIGH WAW
+    ('\u{08F8}', CharMapAction::SubChar('\u{0350}')), // ARABIC RIGHT ARROWHEAD ABOVE
+    ('\u{08F9}', CharMapAction::SubChar('\u{0354}')), // ARABIC LEFT ARROWHEAD BELOW
+    ('\u{08FA}', CharMapAction::SubChar('\u{0355}')), // ARABIC RIGHT ARROWHEAD BELOW
+    ('\u{08FF}', CharMapAction::SubChar('\u{0350}')), // ARABIC MARK SIDEWAYS NOON GHUNNA
+    ('\u{0900}', CharMapAction::SubChar('\u{0352}')), // DEVANAGARI SIGN INVERTED CANDRABINDU
+    ('\u{0901}', CharMapAction::SubStr("\u{0306}\u{0307}")), // DEVANAGARI SIGN CANDRABINDU
+    ('\u{0902}', CharMapAction::SubChar('\u{0307}')), // DEVANAGARI SIGN ANUSVARA
+    ('\u{0903}', CharMapAction::SubChar(':')), // DEVANAGARI SIGN VISARGA
+    ('\u{0904}', CharMapAction::SubStr("\u{0905}\u{0946}")), // DEVANAGARI LETTER SHORT A
+    ('\u{0906}', CharMapAction::SubStr("\u{0905}\u{093E}")), // DEVANAGARI LETTER AA
+    ('\u{0908}', CharMapAction::SubStr("\u{0930}\u{094D}\u{0907}")), // DEVANAGARI LETTER II
+    ('\u{090D}', CharMapAction::SubStr("\u{090F}\u{0945}")), // DEVANAGARI LETTER CANDRA E
+    ('\u{090E}', CharMapAction::SubStr("\u{090F}\u{0946}")), // DEVANAGARI LETTER SHORT E
+    ('\u{0910}', CharMapAction::SubStr("\u{090F}\u{0947}")), // DEVANAGARI LETTER AI
+    ('\u{0911}', CharMapAction::SubStr("\u{0905}\u{0949}")), // DEVANAGARI LETTER CANDRA O
+    ('\u{0912}', CharMapAction::SubStr("\u{0905}\u{093E}\u{0946}")), // DEVANAGARI LETTER SHORT O
+    ('\u{0913}', CharMapAction::SubStr("\u{0905}\u{093E}\u{0947}")), // DEVANAGARI LETTER O
+    ('\u{0914}', CharMapAction::SubStr("\u{0905}\u{093E}\u{0948}")), // DEVANAGARI LETTER AU
+    ('\u{093C}', CharMapAction::SubChar('\u{0323}')), // DEVANAGARI SIGN NUKTA
+    ('\u{0952}', CharMapAction::SubChar('\u{0331}')), // DEVANAGARI STRESS SIGN ANUDATTA
+    ('\u{0953}', CharMapAction::SubChar('\u{0300}')), // DEVANAGARI GRAVE ACCENT
+    ('\u{0954}', CharMapAction::SubChar('\u{0301}')), // DEVANAGARI ACUTE ACCENT
+    ('\u{0965}', CharMapAction::SubStr("\u{0964}\u{0964}")), // DEVANAGARI DOUBLE DANDA
+    ('\u{0966}', CharMapAction::SubChar('o')), // DEVANAGARI DIGIT ZERO
+    ('\u{0967}', CharMapAction::SubChar('\u{0669}')), // DEVANAGARI DIGIT ONE
+    ('\u{097D}', CharMapAction::SubChar('?')), // DEVANAGARI LETTER GLOTTAL STOP
+    ('\u{0981}', CharMapAction::SubStr("\u{0306}\u{0307}")), // BENGALI SIGN CANDRABINDU
+    ('\u{0986}', CharMapAction::SubStr("\u{0985}\u{09BE}")), // BENGALI LETTER AA
+    ('\u{09BC}', CharMapAction::SubChar('\u{0323}')), // BENGALI SIGN NUKTA
+    ('\u{09E0}', CharMapAction::SubStr("\u{098B}\u{09C3}")), // BENGALI LETTER VOCALIC RR
+    ('\u{09E1}', CharMapAction::SubStr("\u{098B}\u{09C3}")), // BENGALI LETTER VOCALIC LL
+    ('\u{09E6}', CharMapAction::SubChar('O')), // BENGALI DIGIT ZERO
+    ('\u{09EA}', CharMapAction::SubChar('8')), // BENGALI DIGIT FOUR
+    ('\u{09ED}', CharMapAction::SubChar('9')), // BENGALI DIGIT SEVEN
+    ('\u{0A02}', CharMapAction::SubChar('\u{0307}')), // GURMUKHI SIGN BINDI
+    ('\u{0A03}', CharMapAction::SubChar('\u{0983}')), // GURMUKHI SIGN VISARGA
+    ('\u{0A06}', CharMapAction::SubStr("\u{0A05}\u{0A3E}")), // GURMUKHI LETTER AA
+    ('\u{0A07}', CharMapAction::SubStr("\u{0A72}\u{0A3F}")), // GURMUKHI LETTER I
+    ('\u{0A08}', CharMapAction::SubStr("\u{0A72}\u{0A40}")), // GURMUKHI LETTER II
+    ('\u{0A09}', CharMapAction::SubStr("\u{0A73}\u{0A41}")), // GURMUKHI LETTER U
+    ('\u{0A0A}', CharMapAction::SubStr("\u{0A73}\u{0A42}")), // GURMUKHI LETTER UU
+    ('\u{0A0F}', CharMapAction::SubStr("\u{0A72}\u{0A47}")), // GURMUKHI LETTER EE
+    ('\u{0A10}', CharMapAction::SubStr("\u{0A05}\u{0A48}")), // GURMUKHI LETTER AI
+    ('\u{0A14}', CharMapAction::SubStr("\u{0A05}\u{0A4C}")), // GURMUKHI LETTER AU
+    ('\u{0A3C}', CharMapAction::SubChar('\u{0323}')), // GURMUKHI SIGN NUKTA
+    ('\u{0A4B}', CharMapAction::SubChar('\u{0946}')), // GURMUKHI VOWEL SIGN OO
+    ('\u{0A4D}', CharMapAction::SubChar('\u{094D}')), // GURMUKHI SIGN VIRAMA
+    ('\u{0A66}', CharMapAction::SubChar('o')), // GURMUKHI DIGIT ZERO
+    ('\u{0A67}', CharMapAction::SubChar('9')), // GURMUKHI DIGIT ONE
+    ('\u{0A6A}', CharMapAction::SubChar('8')), // GURMUKHI DIGIT FOUR
+    ('\u{0A81}', CharMapAction::SubStr("\u{0306}\u{0307}")), // GUJARATI SIGN CANDRABINDU
+    ('\u{0A82}', CharMapAction::SubChar('\u{0307}')), // GUJARATI SIGN ANUSVARA
+    ('\u{0A83}', CharMapAction::SubChar(':')), // GUJARATI SIGN VISARGA
+    ('\u{0A86}', CharMapAction::SubStr("\u{0A85}\u{0ABE}")), // GUJARATI LETTER AA
+    ('\u{0A8D}', CharMapAction::SubStr("\u{0A85}\u{0AC5}")), // GUJARATI VOWEL CANDRA E
+    ('\u{0A8F}', CharMapAction::SubStr("\u{0A85}\u{0AC7}")), // GUJARATI LETTER E
+    ('\u{0A90}', CharMapAction::SubStr("\u{0A85}\u{0AC8}")), // GUJARATI LETTER AI
+    ('\u{0A91}', CharMapAction::SubStr("\u{0A85}\u{0ABE}\u{0AC5}")), // GUJARATI VOWEL CANDRA O
+    ('\u{0A93}', CharMapAction::SubStr("\u{0A85}\u{0ABE}\u{0AC7}")), // GUJARATI LETTER O
+    ('\u{0A94}', CharMapAction::SubStr("\u{0A85}\u{0ABE}\u{0AC8}")), // GUJARATI LETTER AU
+    ('\u{0ABC}', CharMapAction::SubChar('\u{0323}')), // GUJARATI SIGN NUKTA
+    ('\u{0ABD}', CharMapAction::SubChar('\u{093D}')), // GUJARATI SIGN AVAGRAHA
+    ('\u{0AC1}', CharMapAction::SubChar('\u{0941}')), // GUJARATI VOWEL SIGN U
+    ('\u{0AC2}', CharMapAction::SubChar('\u{0942}')), // GUJARATI VOWEL SIGN UU
+    ('\u{0ACD}', CharMapAction::SubChar('\u{094D}')), // GUJARATI SIGN VIRAMA
+    ('\u{0AE6}', CharMapAction::SubChar('o')), // GUJARATI DIGIT ZERO
+    ('\u{0AE8}', CharMapAction::SubChar('\u{0968}')), // GUJARATI DIGIT TWO
+    ('\u{0AE9}', CharMapAction::SubChar('\u{0969}')), // GUJARATI DIGIT THREE
+    ('\u{0AEA}', CharMapAction::SubChar('\u{096A}')), // GUJARATI DIGIT FOUR
+    ('\u{0AEE}', CharMapAction::SubChar('\u{096E}')), // GUJARATI DIGIT EIGHT
+    ('\u{0AF0}', CharMapAction::SubChar('\u{0970}')), // GUJARATI ABBREVIATION SIGN
+    ('\u{0B01}', CharMapAction::SubStr("\u{0306}\u{0307}")), // ORIYA SIGN CANDRABINDU
+    ('\u{0B03}', CharMapAction::SubChar('8')), // ORIYA SIGN VISARGA
+    ('\u{0B06}', CharMapAction::SubStr("\u{0B05}\u{0B3E}")), // ORIYA LETTER AA
+    ('\u{0B20}', CharMapAction::SubChar('O')), // ORIYA LETTER TTHA
+    ('\u{0B3C}', CharMapAction::SubChar('\u{0323}')), // ORIYA SIGN NUKTA
+    ('\u{0B66}', CharMapAction::SubChar('O')), // ORIYA DIGIT ZERO
+    ('\u{0B68}', CharMapAction::SubChar('9')), // ORIYA DIGIT TWO
+    ('\u{0B82}', CharMapAction::SubChar('\u{030A}')), // TAMIL SIGN ANUSVARA
+    ('\u{0B8A}', CharMapAction::SubStr("\u{0B89}\u{0BB3}")), // TAMIL LETTER UU
+    ('\u{0B9C}', CharMapAction::SubChar('\u{0B90}')), // TAMIL LETTER JA
+    ('\u{0BB0}', CharMapAction::SubChar('\u{0B88}')), // TAMIL LETTER RA
+    ('\u{0BBE}', CharMapAction::SubChar('\u{0B88}')), // TAMIL VOWEL SIGN AA
+    ('\u{0BC8}', CharMapAction::SubChar('\u{0BA9}')), // TAMIL VOWEL SIGN AI
+    ('\u{0BCA}', CharMapAction::SubStr("\u{0BC6}\u{0B88}")), // TAMIL VOWEL SIGN O
+    ('\u{0BCB}', CharMapAction::SubStr("\u{0BC7}\u{0B88}")), // TAMIL VOWEL SIGN OO
+    ('\u{0BCC}', CharMapAction::SubStr("\u{0BC6}\u{0BB3}")), // TAMIL VOWEL SIGN AU
+    ('\u{0BCD}', CharMapAction::SubChar('\u{0307}')), // TAMIL SIGN VIRAMA
+    ('\u{0BD7}', CharMapAction::SubChar('\u{0BB3}')), // TAMIL AU LENGTH MARK
+    ('\u{0BE6}', CharMapAction::SubChar('o')), // TAMIL DIGIT ZERO
+    ('\u{0BE7}', CharMapAction::SubChar('\u{0B95}')), // TAMIL DIGIT ONE
+    ('\u{0BE8}', CharMapAction::SubChar('\u{0B89}')), // TAMIL DIGIT TWO
+    ('\u{0BEA}', CharMapAction::SubChar('\u{0B9A}')), // TAMIL DIGIT FOUR
+    ('\u{0BEB}', CharMapAction::SubStr("\u{0B88}\u{0BC1}")), // TAMIL DIGIT FIVE
+    ('\u{0BEC}', CharMapAction::SubStr("\u{0B9A}\u{0BC1}")), // TAMIL DIGIT SIX
+    ('\u{0BED}', CharMapAction::SubChar('\u{0B8E}')), // TAMIL DIGIT SEVEN
+    ('\u{0BEE}', CharMapAction::SubChar('\u{0B85}')), // TAMIL DIGIT EIGHT
+    ('\u{0BF0}', CharMapAction::SubChar('\u{0BAF}')), // TAMIL NUMBER TEN
+    ('\u{0BF2}', CharMapAction::SubStr("\u{0B9A}\u{0BC2}")), // TAMIL NUMBER ONE THOUSAND
+    ('\u{0BF4}', CharMapAction::SubStr("\u{0BAE}\u{0BC0}")), // TAMIL MONTH SIGN
+    ('\u{0BF5}', CharMapAction::SubChar('\u{0BF3}')), // TAMIL YEAR SIGN
+    ('\u{0BF7}', CharMapAction::SubStr("\u{0B8E}\u{0BB5}")), // TAMIL CREDIT SIGN
+    ('\u{0BF8}', CharMapAction::SubChar('\u{0BB7}')), // TAMIL AS ABOVE SIGN
+    ('\u{0BFA}', CharMapAction::SubStr("\u{0BA8}\u{0BC0}")), // TAMIL NUMBER SIGN
+    ('\u{0C00}', CharMapAction::SubStr("\u{0306}\u{0307}")), // TELUGU SIGN COMBINING CANDRABINDU ABOVE
+    ('\u{0C02}', CharMapAction::SubChar('o')), // TELUGU SIGN ANUSVARA
+    ('\u{0C03}', CharMapAction::SubChar('\u{0983}')), // TELUGU SIGN VISARGA
+    ('\u{0C13}', CharMapAction::SubStr("\u{0C12}\u{0C55}")), // TELUGU LETTER OO
+    ('\u{0C14}', CharMapAction::SubStr("\u{0C12}\u{0C4C}")), // TELUGU LETTER AU
+    ('\u{0C20}', CharMapAction::SubStr("\u{0C30}\u{05BC}")), // TELUGU LETTER TTHA
+    ('\u{0C22}', CharMapAction::SubStr("\u{0C21}\u{0323}")), // TELUGU LETTER DDHA
+    ('\u{0C25}', CharMapAction::SubStr("\u{0C27}\u{05BC}")), // TELUGU LETTER THA
+    ('\u{0C2D}', CharMapAction::SubStr("\u{0C2C}\u{0323}")), // TELUGU LETTER BHA
+    ('\u{0C2E}', CharMapAction::SubStr("\u{0C35}\u{0C41}")), // TELUGU LETTER MA
+    ('\u{0C37}', CharMapAction::SubStr("\u{0C35}\u{0323}")), // TELUGU LETTER SSA
+    ('\u{0C39}', CharMapAction::SubStr("\u{0C35}\u{0C3E}")), // TELUGU LETTER HA
+    ('\u{0C42}', CharMapAction::SubStr("\u{0C41}\u{0C3E}")), // TELUGU VOWEL SIGN UU
+    ('\u{0C44}', CharMapAction::SubStr("\u{0C43}\u{0C3E}")), // TELUGU VOWEL SIGN VOCALIC RR
+    ('\u{0C60}', CharMapAction::SubStr("\u{0C0B}\u{0C3E}")), // TELUGU LETTER VOCALIC RR
+    ('\u{0C61}', CharMapAction::SubStr("\u{0C0C}\u{0C3E}")), // TELUGU LETTER VOCALIC LL
+    ('\u{0C66}', CharMapAction::SubChar('o')), // TELUGU DIGIT ZERO
+    ('\u{0C81}', CharMapAction::SubStr("\u{0306}\u{0307}")), // KANNADA SIGN CANDRABINDU
+    ('\u{0C82}', CharMapAction::SubChar('o')), // KANNADA SIGN ANUSVARA
+    ('\u{0C83}', CharMapAction::SubChar('\u{0983}')), // KANNADA SIGN VISARGA
+    ('\u{0C85}', CharMapAction::SubChar('\u{0C05}')), // KANNADA LETTER A
+    ('\u{0C86}', CharMapAction::SubChar('\u{0C06}')), // KANNADA LETTER AA
+    ('\u{0C87}', CharMapAction::SubChar('\u{0C07}')), // KANNADA LETTER I
+    ('\u{0C92}', CharMapAction::SubChar('\u{0C12}')), // KANNADA LETTER O
+    ('\u{0C93}', CharMapAction::SubStr("\u{0C12}\u{0C55}")), // KANNADA LETTER OO
+    ('\u{0C94}', CharMapAction::SubStr("\u{0C12}\u{0C4C}")), // KANNADA LETTER AU
+    ('\u{0C9C}', CharMapAction::SubChar('\u{0C1C}')), // KANNADA LETTER JA
+    ('\u{0C9E}', CharMapAction::SubChar('\u{0C1E}')), // KANNADA LETTER NYA
+    ('\u{0CA3}', CharMapAction::SubChar('\u{0C23}')), // KANNADA LETTER NNA
+    ('\u{0CAF}', CharMapAction::SubChar('\u{0C2F}')), // KANNADA LETTER YA
+    ('\u{0CB1}', CharMapAction::SubChar('\u{0C31}')), // KANNADA LETTER RRA
+    ('\u{0CB2}', CharMapAction::SubChar('\u{0C32}')), // KANNADA LETTER LA
+    ('\u{0CE1}', CharMapAction::SubStr("\u{0C8C}\u{0CBE}")), // KANNADA LETTER VOCALIC LL
+    ('\u{0CE6}', CharMapAction::SubChar('o')), // KANNADA DIGIT ZERO
+    ('\u{0CE7}', CharMapAction::SubChar('\u{0C67}')), // KANNADA DIGIT ONE
+    ('\u{0CE8}', CharMapAction::SubChar('\u{0C68}')), // KANNADA DIGIT TWO
+    ('\u{0CEF}', CharMapAction::SubChar('\u{0C6F}')), // KANNADA DIGIT NINE
+    ('\u{0D01}', CharMapAction::SubStr("\u{0306}\u{0307}")), // MALAYALAM SIGN CANDRABINDU
+    ('\u{0D02}', CharMapAction::SubChar('o')), // MALAYALAM SIGN ANUSVARA
+    ('\u{0D03}', CharMapAction::SubChar('\u{0983}')), // MALAYALAM SIGN VISARGA
+    ('\u{0D08}', CharMapAction::SubStr("\u{0D07}\u{0D57}")), // MALAYALAM LETTER II
+    ('\u{0D09}', CharMapAction::SubChar('\u{0B89}')), // MALAYALAM LETTER U
+    ('\u{0D0A}', CharMapAction::SubStr("\u{0B89}\u{0D57}")), // MALAYALAM LETTER UU
+    ('\u{0D0C}', CharMapAction::SubStr("\u{0D28}\u{0D41}")), // MALAYALAM LETTER VOCALIC L
+    ('\u{0D10}', CharMapAction::SubStr("\u{0D0E}\u{0D46}")), // MALAYALAM LETTER AI
+    ('\u{0D13}', CharMapAction::SubStr("\u{0D12}\u{0D3E}")), // MALAYALAM LETTER OO
+    ('\u{0D14}', CharMapAction::SubStr("\u{0D12}\u{0D57}")), // MALAYALAM LETTER AU
+    ('\u{0D19}', CharMapAction::SubStr("\u{0D28}\u{0D41}")), // MALAYALAM LETTER NGA
+    ('\u{0D1C}', CharMapAction::SubChar('\u{0B90}')), // MALAYALAM LETTER JA
+    ('\u{0D20}', CharMapAction::SubChar('o')), // MALAYALAM LETTER TTHA
+    ('\u{0D23}', CharMapAction::SubChar('\u{0BA3}')), // MALAYALAM LETTER NNA
+    ('\u{0D31}', CharMapAction::SubChar('\u{0D30}')), // MALAYALAM LETTER RRA
+    ('\u{0D34}', CharMapAction::SubChar('\u{0BB4}')), // MALAYALAM LETTER LLLA
+    ('\u{0D36}', CharMapAction::SubChar('\u{0BB6}')), // MALAYALAM LETTER SHA
+    ('\u{0D3A}', CharMapAction::SubStr("\u{0B9F}\u{0BBF}")), // MALAYALAM LETTER TTTA
+    ('\u{0D3F}', CharMapAction::SubChar('\u{0BBF}')), // MALAYALAM VOWEL SIGN I
+    ('\u{0D40}', CharMapAction::SubChar('\u{0BBF}')), // MALAYALAM VOWEL SIGN II
+    ('\u{0D42}', CharMapAction::SubChar('\u{0D41}')), // MALAYALAM VOWEL SIGN UU
+    ('\u{0D43}', CharMapAction::SubChar('\u{0D41}')), // MALAYALAM VOWEL SIGN VOCALIC R
+    ('\u{0D48}', CharMapAction::SubStr("\u{0D46}\u{0D46}")), // MALAYALAM VOWEL SIGN AI
+    ('\u{0D4E}', CharMapAction::SubChar('\u{0971}')), // MALAYALAM LETTER DOT REPH
+    ('\u{0D5A}', CharMapAction::SubStr("\u{0D28}\u{0D4D}\u{0D2E}")), // MALAYALAM FRACTION THREE EIGHTIETHS
+    ('\u{0D5F}', CharMapAction::SubStr("o\u{0D30}o")), // MALAYALAM LETTER ARCHAIC II
+    ('\u{0D61}', CharMapAction::SubChar('\u{0D1E}')), // MALAYALAM LETTER VOCALIC LL
+    ('\u{0D66}', CharMapAction::SubChar('o')), // MALAYALAM DIGIT ZERO
+    ('\u{0D6A}', CharMapAction::SubStr("\u{0D30}\u{0D4D}")), // MALAYALAM DIGIT FOUR
+    ('\u{0D6B}', CharMapAction::SubStr("\u{0D26}\u{0D4D}\u{0D30}")), // MALAYALAM DIGIT FIVE
+    ('\u{0D6C}', CharMapAction::SubStr("\u{0D28}\u{0D4D}\u{0D28}")), // MALAYALAM DIGIT SIX
+    ('\u{0D6D}', CharMapAction::SubChar('9')), // MALAYALAM DIGIT SEVEN
+    ('\u{0D6E}', CharMapAction::SubStr("\u{0D35}\u{0D4D}\u{0D30}")), // MALAYALAM DIGIT EIGHT
+    ('\u{0D6F}', CharMapAction::SubStr("\u{0D28}\u{0D4D}")), // MALAYALAM DIGIT NINE
+    ('\u{0D76}', CharMapAction::SubStr("\u{0D39}\u{0D4D}\u{0D2E}")), // MALAYALAM FRACTION ONE SIXTEENTH
+    ('\u{0D79}', CharMapAction::SubStr("\u{0D28}\u{0D41}")), // MALAYALAM DATE MARK
+    ('\u{0D7B}', CharMapAction::SubStr("\u{0D28}\u{0D4D}")), // MALAYALAM LETTER CHILLU N
+    ('\u{0D7C}', CharMapAction::SubStr("\u{0D30}\u{0D4D}")), // MALAYALAM LETTER CHILLU RR
+    ('\u{0D82}', CharMapAction::SubChar('o')), // SINHALA SIGN ANUSVARAYA
+    ('\u{0D83}', CharMapAction::SubChar('\u{0983}')), // SINHALA SIGN VISARGAYA
+    ('\u{0DE9}', CharMapAction::SubStr("\u{0DE8}\u{0DCF}")), // SINHALA LITH DIGIT THREE
+    ('\u{0DEA}', CharMapAction::SubChar('\u{0DA2}')), // SINHALA LITH DIGIT FOUR
+    ('\u{0DEB}', CharMapAction::SubChar('\u{0DAF}')), // SINHALA LITH DIGIT FIVE
+    ('\u{0DEF}', CharMapAction::SubStr("\u{0DE8}\u{0DD3}")), // SINHALA LITH DIGIT NINE
+    ('\u{0E03}', CharMapAction::SubChar('\u{0E02}')), // THAI CHARACTER KHO KHUAT
+    ('\u{0E0B}', CharMapAction::SubChar('\u{0E0A}')), // THAI CHARACTER SO SO
+    ('\u{0E0F}', CharMapAction::SubChar('\u{0E0E}')), // THAI CHARACTER TO PATAK
+    ('\u{0E14}', CharMapAction::SubChar('\u{0E04}')), // THAI CHARACTER DO DEK
+    ('\u{0E15}', CharMapAction::SubChar('\u{0E04}')), // THAI CHARACTER TO TAO
+    ('\u{0E17}', CharMapAction::SubChar('\u{0E11}')), // THAI CHARACTER THO THAHAN
+    ('\u{0E21}', CharMapAction::SubChar('\u{0E06}')), // THAI CHARACTER MO MA
+    ('\u{0E26}', CharMapAction::SubChar('\u{0E20}')), // THAI CHARACTER LU
+    ('\u{0E33}', CharMapAction::SubStr("\u{030A}\u{0E32}")), // THAI CHARACTER SARA AM
+    ('\u{0E41}', CharMapAction::SubStr("\u{0E40}\u{0E40}")), // THAI CHARACTER SARA AE
+    ('\u{0E45}', CharMapAction::SubChar('\u{0E32}')), // THAI CHARACTER LAKKHANGYAO
+    ('\u{0E4D}', CharMapAction::SubChar('\u{030A}')), // THAI CHARACTER NIKHAHIT
+    ('\u{0E50}', CharMapAction::SubChar('o')), // THAI DIGIT ZERO
+    ('\u{0E88}', CharMapAction::SubChar('\u{0E08}')), // LAO LETTER CO
+    ('\u{0E8D}', CharMapAction::SubChar('\u{0E22}')), // LAO LETTER NYO
+    ('\u{0E9A}', CharMapAction::SubChar('\u{0E1A}')), // LAO LETTER BO
+    ('\u{0E9B}', CharMapAction::SubChar('\u{0E1B}')), // LAO LETTER PO
+    ('\u{0E9D}', CharMapAction::SubChar('\u{0E1D}')), // LAO LETTER FO TAM
+    ('\u{0E9E}', CharMapAction::SubChar('\u{0E1E}')), // LAO LETTER PHO TAM
+    ('\u{0E9F}', CharMapAction::SubChar('\u{0E1F}')), // LAO LETTER FO SUNG
+    ('\u{0EB3}', CharMapAction::SubStr("\u{030A}\u{0EB2}")), // LAO VOWEL SIGN AM
+    ('\u{0EB8}', CharMapAction::SubChar('\u{0E38}')), // LAO VOWEL SIGN U
+    ('\u{0EB9}', CharMapAction::SubChar('\u{0E39}')), // LAO VOWEL SIGN UU
+    ('\u{0EC8}', CharMapAction::SubChar('\u{0E48}')), // LAO TONE MAI EK
+    ('\u{0EC9}', CharMapAction::SubChar('\u{0E49}')), // LAO TONE MAI THO
+    ('\u{0ECA}', CharMapAction::SubChar('\u{0E4A}')), // LAO TONE MAI TI
+    ('\u{0ECB}', CharMapAction::SubChar('\u{0E4B}')), // LAO TONE MAI CATAWA
+    ('\u{0ECD}', CharMapAction::SubChar('\u{030A}')), // LAO NIGGAHITA
+    ('\u{0ED0}', CharMapAction::SubChar('o')), // LAO DIGIT ZERO
+    ('\u{0EDC}', CharMapAction::SubStr("\u{0EAB}\u{0E99}")), // LAO HO NO
+    ('\u{0EDD}', CharMapAction::SubStr("\u{0EAB}\u{0EA1}")), // LAO HO MO
+    ('\u{0F00}', CharMapAction::SubStr("\u{0F68}\u{0F7C}\u{0F7E}")), // TIBETAN SYLLABLE OM
+    ('\u{0F02}', CharMapAction::SubStr("\u{0F60}\u{0F74}\u{0F82}\u{0F7F}")), // TIBETAN MARK GTER YIG MGO -UM RNAM BCAD MA
+    ('\u{0F03}', CharMapAction::SubStr("\u{0F60}\u{0F74}\u{0F82}\u{0F14}")), // TIBETAN MARK GTER YIG MGO -UM GTER TSHEG MA
+    ('\u{0F0C}', CharMapAction::SubChar('\u{0F0B}')), // TIBETAN MARK DELIMITER TSHEG BSTAR
+    ('\u{0F0E}', CharMapAction::SubStr("\u{0F0D}\u{0F0D}")), // TIBETAN MARK NYIS SHAD
+    ('\u{0F1B}', CharMapAction::SubStr("\u{0F1A}\u{0F1A}")), // TIBETAN SIGN RDEL DKAR GNYIS
+    ('\u{0F1E}', CharMapAction::SubStr("\u{0F1D}\u{0F1D}")), // TIBETAN SIGN RDEL NAG GNYIS
+    ('\u{0F1F}', CharMapAction::SubStr("\u{0F1A}\u{0F1D}")), // TIBETAN SIGN RDEL DKAR RDEL NAG
+    ('\u{0F37}', CharMapAction::SubChar('\u{0325}')), // TIBETAN MARK NGAS BZUNG SGOR RTAGS
+    ('\u{0F6A}', CharMapAction::SubChar('\u{0F62}')), // TIBETAN LETTER FIXED-FORM RA
+    ('\u{0F77}', CharMapAction::SubStr("\u{0FB2}\u{0F71}\u{0F80}")), // TIBETAN VOWEL SIGN VOCALIC RR
+    ('\u{0F79}', CharMapAction::SubStr("\u{0FB3}\u{0F71}\u{0F80}")), // TIBETAN VOWEL SIGN VOCALIC LL
+    ('\u{0FCE}', CharMapAction::SubStr("\u{0F1D}\u{0F1A}")), // TIBETAN SIGN RDEL NAG RDEL DKAR
+    ('\u{0FD5}', CharMapAction::SubChar('\u{5350}')), // RIGHT-FACING SVASTI SIGN
+    ('\u{0FD6}', CharMapAction::SubChar('\u{534D}')), // LEFT-FACING SVASTI SIGN
+    ('\u{1000}', CharMapAction::SubStr("\u{1002}\u{102C}")), // MYANMAR LETTER KA
+    ('\u{1010}', CharMapAction::SubStr("o\u{102C}")), // MYANMAR LETTER TA
+    ('\u{101D}', CharMapAction::SubChar('o')), // MYANMAR LETTER WA
+    ('\u{101F}', CharMapAction::SubStr("\u{1015}\u{102C}")), // MYANMAR LETTER HA
+    ('\u{1029}', CharMapAction::SubStr("\u{101E}\u{103C}")), // MYANMAR LETTER O
+    ('\u{102A}', CharMapAction::SubStr("\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}")), // MYANMAR LETTER AU
+    ('\u{1036}', CharMapAction::SubChar('\u{030A}')), // MYANMAR SIGN ANUSVARA
+    ('\u{1038}', CharMapAction::SubChar('\u{0983}')), // MYANMAR SIGN VISARGA
+    ('\u{1040}', CharMapAction::SubChar('o')), // MYANMAR DIGIT ZERO
+    ('\u{104B}', CharMapAction::SubStr("\u{104A}\u{104A}")), // MYANMAR SIGN SECTION
+    ('\u{1065}', CharMapAction::SubChar('\u{1041}')), // MYANMAR LETTER WESTERN PWO KAREN THA
+    ('\u{1066}', CharMapAction::SubStr("\u{1015}\u{103E}")), // MYANMAR LETTER WESTERN PWO KAREN PWA
+    ('\u{106F}', CharMapAction::SubStr("\u{1015}\u{102C}\u{103E}")), // MYANMAR LETTER EASTERN PWO KAREN YWA
+    ('\u{1070}', CharMapAction::SubStr("\u{1003}\u{103E}")), // MYANMAR LETTER EASTERN PWO KAREN GHWA
+    ('\u{107E}', CharMapAction::SubStr("\u{107D}\u{103E}")), // MYANMAR LETTER SHAN FA
+    ('\u{1081}', CharMapAction::SubStr("\u{1002}\u{103E}")), // MYANMAR LETTER SHAN HA
+    ('\u{109E}', CharMapAction::SubStr("\u{1083}\u{030A}")), // MYANMAR SYMBOL SHAN ONE
+    ('\u{10A0}', CharMapAction::SubChar('\u{A786}')), // GEORGIAN CAPITAL LETTER AN
+    ('\u{10E7}', CharMapAction::SubChar('y')), // GEORGIAN LETTER QAR
+    ('\u{10F3}', CharMapAction::SubChar('\u{021D}')), // GEORGIAN LETTER WE
+    ('\u{10FF}', CharMapAction::SubChar('o')), // GEORGIAN LETTER LABIAL SIGN
+    ('\u{1101}', CharMapAction::SubStr("\u{1100}\u{1100}")), // HANGUL CHOSEONG SSANGKIYEOK
+    ('\u{1104}', CharMapAction::SubStr("\u{1103}\u{1103}")), // HANGUL CHOSEONG SSANGTIKEUT
+    ('\u{1108}', CharMapAction::SubStr("\u{1107}\u{1107}")), // HANGUL CHOSEONG SSANGPIEUP
+    ('\u{110A}', CharMapAction::SubStr("\u{1109}\u{1109}")), // HANGUL CHOSEONG SSANGSIOS
+    ('\u{110D}', CharMapAction::SubStr("\u{110C}\u{110C}")), // HANGUL CHOSEONG SSANGCIEUC
+    ('\u{1113}', CharMapAction::SubStr("\u{1102}\u{1100}")), // HANGUL CHOSEONG NIEUN-KIYEOK
+    ('\u{1114}', CharMapAction::SubStr("\u{1102}\u{1102}")), // HANGUL CHOSEONG SSANGNIEUN
+    ('\u{1115}', CharMapAction::SubStr("\u{1102}\u{1103}")), // HANGUL CHOSEONG NIEUN-TIKEUT
+    ('\u{1116}', CharMapAction::SubStr("\u{1102}\u{1107}")), // HANGUL CHOSEONG NIEUN-PIEUP
+    ('\u{1117}', CharMapAction::SubStr("\u{1103}\u{1100}")), // HANGUL CHOSEONG TIKEUT-KIYEOK
+    ('\u{1118}', CharMapAction::SubStr("\u{1105}\u{1102}")), // HANGUL CHOSEONG RIEUL-NIEUN
+    ('\u{1119}', CharMapAction::SubStr("\u{1105}\u{1105}")), // HANGUL CHOSEONG SSANGRIEUL
+    ('\u{111A}', CharMapAction::SubStr("\u{1105}\u{1112}")), // HANGUL CHOSEONG RIEUL-HIEUH
+    ('\u{111B}', CharMapAction::SubStr("\u{1105}\u{110B}")), // HANGUL CHOSEONG KAPYEOUNRIEUL
+    ('\u{111C}', CharMapAction::SubStr("\u{1106}\u{1107}")), // HANGUL CHOSEONG MIEUM-PIEUP
+    ('\u{111D}', CharMapAction::SubStr("\u{1106}\u{110B}")), // HANGUL CHOSEONG KAPYEOUNMIEUM
+    ('\u{111E}', CharMapAction::SubStr("\u{1107}\u{1100}")), // HANGUL CHOSEONG PIEUP-KIYEOK
+    ('\u{111F}', CharMapAction::SubStr("\u{1107}\u{1102}")), // HANGUL CHOSEONG PIEUP-NIEUN
+    ('\u{1120}', CharMapAction::SubStr("\u{1107}\u{1103}")), // HANGUL CHOSEONG PIEUP-TIKEUT
+    ('\u{1121}', CharMapAction::SubStr("\u{1107}\u{1109}")), // HANGUL CHOSEONG PIEUP-SIOS
+    ('\u{1122}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1100}")), // HANGUL CHOSEONG PIEUP-SIOS-KIYEOK
+    ('\u{1123}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1103}")), // HANGUL CHOSEONG PIEUP-SIOS-TIKEUT
+    ('\u{1124}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1107}")), // HANGUL CHOSEONG PIEUP-SIOS-PIEUP
+    ('\u{1125}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1109}")), // HANGUL CHOSEONG PIEUP-SSANGSIOS
+    ('\u{1126}', CharMapAction::SubStr("\u{1107}\u{1109}\u{110C}")), // HANGUL CHOSEONG PIEUP-SIOS-CIEUC
+    ('\u{1127}', CharMapAction::SubStr("\u{1107}\u{110C}")), // HANGUL CHOSEONG PIEUP-CIEUC
+    ('\u{1128}', CharMapAction::SubStr("\u{1107}\u{110E}")), // HANGUL CHOSEONG PIEUP-CHIEUCH
+    ('\u{1129}', CharMapAction::SubStr("\u{1107}\u{1110}")), // HANGUL CHOSEONG PIEUP-THIEUTH
+    ('\u{112A}', CharMapAction::SubStr("\u{1107}\u{1111}")), // HANGUL CHOSEONG PIEUP-PHIEUPH
+    ('\u{112B}', CharMapAction::SubStr("\u{1107}\u{110B}")), // HANGUL CHOSEONG KAPYEOUNPIEUP
+    ('\u{112C}', CharMapAction::SubStr("\u{1107}\u{1107}\u{110B}")), // HANGUL CHOSEONG KAPYEOUNSSANGPIEUP
+    ('\u{112D}', CharMapAction::SubStr("\u{1109}\u{1100}")), // HANGUL CHOSEONG SIOS-KIYEOK
+    ('\u{112E}', CharMapAction::SubStr("\u{1109}\u{1102}")), // HANGUL CHOSEONG SIOS-NIEUN
+    ('\u{112F}', CharMapAction::SubStr("\u{1109}\u{1103}")), // HANGUL CHOSEONG SIOS-TIKEUT
+    ('\u{1130}', CharMapAction::SubStr("\u{1109}\u{1105}")), // HANGUL CHOSEONG SIOS-RIEUL
+    ('\u{1131}', CharMapAction::SubStr("\u{1109}\u{1106}")), // HANGUL CHOSEONG SIOS-MIEUM
+    ('\u{1132}', CharMapAction::SubStr("\u{1109}\u{1107}")), // HANGUL CHOSEONG SIOS-PIEUP
+    ('\u{1133}', CharMapAction::SubStr("\u{1109}\u{1107}\u{1100}")), // HANGUL CHOSEONG SIOS-PIEUP-KIYEOK
+    ('\u{1134}', CharMapAction::SubStr("\u{1109}\u{1109}\u{1109}")), // HANGUL CHOSEONG SIOS-SSANGSIOS
+    ('\u{1135}', CharMapAction::SubStr("\u{1109}\u{110B}")), // HANGUL CHOSEONG SIOS-IEUNG
+    ('\u{1136}', CharMapAction::SubStr("\u{1109}\u{110C}")), // HANGUL CHOSEONG SIOS-CIEUC
+    ('\u{1137}', CharMapAction::SubStr("\u{1109}\u{110E}")), // HANGUL CHOSEONG SIOS-CHIEUCH
+    ('\u{1138}', CharMapAction::SubStr("\u{1109}\u{110F}")), // HANGUL CHOSEONG SIOS-KHIEUKH
+    ('\u{1139}', CharMapAction::SubStr("\u{1109}\u{1110}")), // HANGUL CHOSEONG SIOS-THIEUTH
+    ('\u{113A}', CharMapAction::SubStr("\u{1109}\u{1111}")), // HANGUL CHOSEONG SIOS-PHIEUPH
+    ('\u{113B}', CharMapAction::SubStr("\u{1105}\u{1112}")), // HANGUL CHOSEONG SIOS-HIEUH
+    ('\u{113D}', CharMapAction::SubStr("\u{113C}\u{113C}")), // HANGUL CHOSEONG CHITUEUMSSANGSIOS
+    ('\u{113F}', CharMapAction::SubStr("\u{113E}\u{113E}")), // HANGUL CHOSEONG CEONGCHIEUMSSANGSIOS
+    ('\u{1141}', CharMapAction::SubStr("\u{110B}\u{1100}")), // HANGUL CHOSEONG IEUNG-KIYEOK
+    ('\u{1142}', CharMapAction::SubStr("\u{110B}\u{1103}")), // HANGUL CHOSEONG IEUNG-TIKEUT
+    ('\u{1143}', CharMapAction::SubStr("\u{110B}\u{1106}")), // HANGUL CHOSEONG IEUNG-MIEUM
+    ('\u{1144}', CharMapAction::SubStr("\u{110B}\u{1107}")), // HANGUL CHOSEONG IEUNG-PIEUP
+    ('\u{1145}', CharMapAction::SubStr("\u{110B}\u{1109}")), // HANGUL CHOSEONG IEUNG-SIOS
+    ('\u{1146}', CharMapAction::SubStr("\u{110B}\u{1140}")), // HANGUL CHOSEONG IEUNG-PANSIOS
+    ('\u{1147}', CharMapAction::SubStr("\u{110B}\u{110B}")), // HANGUL CHOSEONG SSANGIEUNG
+    ('\u{1148}', CharMapAction::SubStr("\u{110B}\u{110C}")), // HANGUL CHOSEONG IEUNG-CIEUC
+    ('\u{1149}', CharMapAction::SubStr("\u{110B}\u{110E}")), // HANGUL CHOSEONG IEUNG-CHIEUCH
+    ('\u{114A}', CharMapAction::SubStr("\u{110B}\u{1110}")), // HANGUL CHOSEONG IEUNG-THIEUTH
+    ('\u{114B}', CharMapAction::SubStr("\u{110B}\u{1111}")), // HANGUL CHOSEONG IEUNG-PHIEUPH
+    ('\u{114D}', CharMapAction::SubStr("\u{110C}\u{110B}")), // HANGUL CHOSEONG CIEUC-IEUNG
+    ('\u{114F}', CharMapAction::SubStr("\u{114E}\u{114E}")), // HANGUL CHOSEONG CHITUEUMSSANGCIEUC
+    ('\u{1151}', CharMapAction::SubStr("\u{1150}\u{1150}")), // HANGUL CHOSEONG CEONGCHIEUMSSANGCIEUC
+    ('\u{1152}', CharMapAction::SubStr("\u{110E}\u{110F}")), // HANGUL CHOSEONG CHIEUCH-KHIEUKH
+    ('\u{1153}', CharMapAction::SubStr("\u{110E}\u{1112}")), // HANGUL CHOSEONG CHIEUCH-HIEUH
+    ('\u{1156}', CharMapAction::SubStr("\u{1111}\u{1107}")), // HANGUL CHOSEONG PHIEUPH-PIEUP
+    ('\u{1157}', CharMapAction::SubStr("\u{1111}\u{110B}")), // HANGUL CHOSEONG KAPYEOUNPHIEUPH
+    ('\u{1158}', CharMapAction::SubStr("\u{1112}\u{1112}")), // HANGUL CHOSEONG SSANGHIEUH
+    ('\u{115A}', CharMapAction::SubStr("\u{1100}\u{1103}")), // HANGUL CHOSEONG KIYEOK-TIKEUT
+    ('\u{115B}', CharMapAction::SubStr("\u{1102}\u{1109}")), // HANGUL CHOSEONG NIEUN-SIOS
+    ('\u{115C}', CharMapAction::SubStr("\u{1102}\u{110C}")), // HANGUL CHOSEONG NIEUN-CIEUC
+    ('\u{115D}', CharMapAction::SubStr("\u{1102}\u{1112}")), // HANGUL CHOSEONG NIEUN-HIEUH
+    ('\u{115E}', CharMapAction::SubStr("\u{1103}\u{1105}")), // HANGUL CHOSEONG TIKEUT-RIEUL
+    ('\u{1162}', CharMapAction::SubStr("\u{1161}\u{4E28}")), // HANGUL JUNGSEONG AE
+    ('\u{1164}', CharMapAction::SubStr("\u{1163}\u{4E28}")), // HANGUL JUNGSEONG YAE
+    ('\u{1166}', CharMapAction::SubStr("\u{1165}\u{4E28}")), // HANGUL JUNGSEONG E
+    ('\u{1168}', CharMapAction::SubStr("\u{1167}\u{4E28}")), // HANGUL JUNGSEONG YE
+    ('\u{116A}', CharMapAction::SubStr("\u{1169}\u{1161}")), // HANGUL JUNGSEONG WA
+    ('\u{116B}', CharMapAction::SubStr("\u{1169}\u{1161}\u{4E28}")), // HANGUL JUNGSEONG WAE
+    ('\u{116C}', CharMapAction::SubStr("\u{1169}\u{4E28}")), // HANGUL JUNGSEONG OE
+    ('\u{116F}', CharMapAction::SubStr("\u{116E}\u{1165}")), // HANGUL JUNGSEONG WEO
+    ('\u{1170}', CharMapAction::SubStr("\u{116E}\u{1165}\u{4E28}")), // HANGUL JUNGSEONG WE
+    ('\u{1171}', CharMapAction::SubStr("\u{116E}\u{4E28}")), // HANGUL JUNGSEONG WI
+    ('\u{1173}', CharMapAction::SubChar('\u{30FC}')), // HANGUL JUNGSEONG EU
+    ('\u{1174}', CharMapAction::SubStr("\u{30FC}\u{4E28}")), // HANGUL JUNGSEONG YI
+    ('\u{1175}', CharMapAction::SubChar('\u{4E28}')), // HANGUL JUNGSEONG I
+    ('\u{1176}', CharMapAction::SubStr("\u{1161}\u{1169}")), // HANGUL JUNGSEONG A-O
+    ('\u{1177}', CharMapAction::SubStr("\u{1161}\u{116E}")), // HANGUL JUNGSEONG A-U
+    ('\u{1178}', CharMapAction::SubStr("\u{1163}\u{1169}")), // HANGUL JUNGSEONG YA-O
+    ('\u{1179}', CharMapAction::SubStr("\u{1163}\u{116D}")), // HANGUL JUNGSEONG YA-YO
+    ('\u{117A}', CharMapAction::SubStr("\u{1165}\u{1169}")), // HANGUL JUNGSEONG EO-O
+    ('\u{117B}', CharMapAction::SubStr("\u{1165}\u{116E}")), // HANGUL JUNGSEONG EO-U
+    ('\u{117C}', CharMapAction::SubStr("\u{1165}\u{30FC}")), // HANGUL JUNGSEONG EO-EU
+    ('\u{117D}', CharMapAction::SubStr("\u{1167}\u{1169}")), // HANGUL JUNGSEONG YEO-O
+    ('\u{117E}', CharMapAction::SubStr("\u{1167}\u{116E}")), // HANGUL JUNGSEONG YEO-U
+    ('\u{117F}', CharMapAction::SubStr("\u{1169}\u{1165}")), // HANGUL JUNGSEONG O-EO
+    ('\u{1180}', CharMapAction::SubStr("\u{1169}\u{1165}\u{4E28}")), // HANGUL JUNGSEONG O-E
+    ('\u{1181}', CharMapAction::SubStr("\u{1169}\u{1167}\u{4E28}")), // HANGUL JUNGSEONG O-YE
+    ('\u{1182}', CharMapAction::SubStr("\u{1169}\u{1169}")), // HANGUL JUNGSEONG O-O
+    ('\u{1183}', CharMapAction::SubStr("\u{1169}\u{116E}")), // HANGUL JUNGSEONG O-U
+    ('\u{1184}', CharMapAction::SubStr("\u{116D}\u{1163}")), // HANGUL JUNGSEONG YO-YA
+    ('\u{1185}', CharMapAction::SubStr("\u{116D}\u{1163}\u{4E28}")), // HANGUL JUNGSEONG YO-YAE
+    ('\u{1186}', CharMapAction::SubStr("\u{116D}\u{1163}")), // HANGUL JUNGSEONG YO-YEO
+    ('\u{1187}', CharMapAction::SubStr("\u{116D}\u{1169}")), // HANGUL JUNGSEONG YO-O
+    ('\u{1188}', CharMapAction::SubStr("\u{116D}\u{4E28}")), // HANGUL JUNGSEONG YO-I
+    ('\u{1189}', CharMapAction::SubStr("\u{116E}\u{1161}")), // HANGUL JUNGSEONG U-A
+    ('\u{118A}', CharMapAction::SubStr("\u{116E}\u{1161}\u{4E28}")), // HANGUL JUNGSEONG U-AE
+    ('\u{118B}', CharMapAction::SubStr("\u{116E}\u{1165}\u{30FC}")), // HANGUL JUNGSEONG U-EO-EU
+    ('\u{118C}', CharMapAction::SubStr("\u{116E}\u{1167}\u{4E28}")), // HANGUL JUNGSEONG U-YE
+    ('\u{118D}', CharMapAction::SubStr("\u{116E}\u{116E}")), // HANGUL JUNGSEONG U-U
+    ('\u{118E}', CharMapAction::SubStr("\u{1172}\u{1161}")), // HANGUL JUNGSEONG YU-A
+    ('\u{118F}', CharMapAction::SubStr("\u{1172}\u{1165}")), // HANGUL JUNGSEONG YU-EO
+    ('\u{1190}', CharMapAction::SubStr("\u{1172}\u{1165}\u{4E28}")), // HANGUL JUNGSEONG YU-E
+    ('\u{1191}', CharMapAction::SubStr("\u{1172}\u{1167}")), // HANGUL JUNGSEONG YU-YEO
+    ('\u{1192}', CharMapAction::SubStr("\u{1172}\u{1167}\u{4E28}")), // HANGUL JUNGSEONG YU-YE
+    ('\u{1193}', CharMapAction::SubStr("\u{1172}\u{116E}")), // HANGUL JUNGSEONG YU-U
+    ('\u{1194}', CharMapAction::SubStr("\u{1172}\u{4E28}")), // HANGUL JUNGSEONG YU-I
+    ('\u{1195}', CharMapAction::SubStr("\u{30FC}\u{116E}")), // HANGUL JUNGSEONG EU-U
+    ('\u{1196}', CharMapAction::SubStr("\u{30FC}\u{30FC}")), // HANGUL JUNGSEONG EU-EU
+    ('\u{1197}', CharMapAction::SubStr("\u{30FC}\u{4E28}\u{116E}")), // HANGUL JUNGSEONG YI-U
+    ('\u{1198}', CharMapAction::SubStr("\u{4E28}\u{1161}")), // HANGUL JUNGSEONG I-A
+    ('\u{1199}', CharMapAction::SubStr("\u{4E28}\u{1163}")), // HANGUL JUNGSEONG I-YA
+    ('\u{119A}', CharMapAction::SubStr("\u{4E28}\u{1169}")), // HANGUL JUNGSEONG I-O
+    ('\u{119B}', CharMapAction::SubStr("\u{4E28}\u{116E}")), // HANGUL JUNGSEONG I-U
+    ('\u{119C}', CharMapAction::SubStr("\u{4E28}\u{30FC}")), // HANGUL JUNGSEONG I-EU
+    ('\u{119D}', CharMapAction::SubStr("\u{4E28}\u{119E}")), // HANGUL JUNGSEONG I-ARAEA
+    ('\u{119F}', CharMapAction::SubStr("\u{119E}\u{1165}")), // HANGUL JUNGSEONG ARAEA-EO
+    ('\u{11A0}', CharMapAction::SubStr("\u{119E}\u{116E}")), // HANGUL JUNGSEONG ARAEA-U
+    ('\u{11A1}', CharMapAction::SubStr("\u{119E}\u{4E28}")), // HANGUL JUNGSEONG ARAEA-I
+    ('\u{11A2}', CharMapAction::SubStr("\u{119E}\u{119E}")), // HANGUL JUNGSEONG SSANGARAEA
+    ('\u{11A3}', CharMapAction::SubStr("\u{1161}\u{30FC}")), // HANGUL JUNGSEONG A-EU
+    ('\u{11A4}', CharMapAction::SubStr("\u{1163}\u{116E}")), // HANGUL JUNGSEONG YA-U
+    ('\u{11A5}', CharMapAction::SubStr("\u{1167}\u{1163}")), // HANGUL JUNGSEONG YEO-YA
+    ('\u{11A6}', CharMapAction::SubStr("\u{1169}\u{1163}")), // HANGUL JUNGSEONG O-YA
+    ('\u{11A7}', CharMapAction::SubStr("\u{1169}\u{1163}\u{4E28}")), // HANGUL JUNGSEONG O-YAE
+    ('\u{11A8}', CharMapAction::SubChar('\u{1100}')), // HANGUL JONGSEONG KIYEOK
+    ('\u{11A9}', CharMapAction::SubStr("\u{1100}\u{1100}")), // HANGUL JONGSEONG SSANGKIYEOK
+    ('\u{11AA}', CharMapAction::SubStr("\u{1100}\u{1109}")), // HANGUL JONGSEONG KIYEOK-SIOS
+    ('\u{11AB}', CharMapAction::SubChar('\u{1102}')), // HANGUL JONGSEONG NIEUN
+    ('\u{11AC}', CharMapAction::SubStr("\u{1102}\u{110C}")), // HANGUL JONGSEONG NIEUN-CIEUC
+    ('\u{11AD}', CharMapAction::SubStr("\u{1102}\u{1112}")), // HANGUL JONGSEONG NIEUN-HIEUH
+    ('\u{11AE}', CharMapAction::SubChar('\u{1103}')), // HANGUL JONGSEONG TIKEUT
+    ('\u{11AF}', CharMapAction::SubChar('\u{1105}')), // HANGUL JONGSEONG RIEUL
+    ('\u{11B0}', CharMapAction::SubStr("\u{1105}\u{1100}")), // HANGUL JONGSEONG RIEUL-KIYEOK
+    ('\u{11B1}', CharMapAction::SubStr("\u{1105}\u{1106}")), // HANGUL JONGSEONG RIEUL-MIEUM
+    ('\u{11B2}', CharMapAction::SubStr("\u{1105}\u{1107}")), // HANGUL JONGSEONG RIEUL-PIEUP
+    ('\u{11B3}', CharMapAction::SubStr("\u{1105}\u{1109}")), // HANGUL JONGSEONG RIEUL-SIOS
+    ('\u{11B4}', CharMapAction::SubStr("\u{1105}\u{1110}")), // HANGUL JONGSEONG RIEUL-THIEUTH
+    ('\u{11B5}', CharMapAction::SubStr("\u{1105}\u{1111}")), // HANGUL JONGSEONG RIEUL-PHIEUPH
+    ('\u{11B6}', CharMapAction::SubStr("\u{1105}\u{1112}")), // HANGUL JONGSEONG RIEUL-HIEUH
+    ('\u{11B7}', CharMapAction::SubChar('\u{1106}')), // HANGUL JONGSEONG MIEUM
+    ('\u{11B8}', CharMapAction::SubChar('\u{1107}')), // HANGUL JONGSEONG PIEUP
+    ('\u{11B9}', CharMapAction::SubStr("\u{1107}\u{1109}")), // HANGUL JONGSEONG PIEUP-SIOS
+    ('\u{11BA}', CharMapAction::SubChar('\u{1109}')), // HANGUL JONGSEONG SIOS
+    ('\u{11BB}', CharMapAction::SubStr("\u{1109}\u{1109}")), // HANGUL JONGSEONG SSANGSIOS
+    ('\u{11BC}', CharMapAction::SubChar('\u{110B}')), // HANGUL JONGSEONG IEUNG
+    ('\u{11BD}', CharMapAction::SubChar('\u{110C}')), // HANGUL JONGSEONG CIEUC
+    ('\u{11BE}', CharMapAction::SubChar('\u{110E}')), // HANGUL JONGSEONG CHIEUCH
+    ('\u{11BF}', CharMapAction::SubChar('\u{110F}')), // HANGUL JONGSEONG KHIEUKH
+    ('\u{11C0}', CharMapAction::SubChar('\u{1110}')), // HANGUL JONGSEONG THIEUTH
+    ('\u{11C1}', CharMapAction::SubChar('\u{1111}')), // HANGUL JONGSEONG PHIEUPH
+    ('\u{11C2}', CharMapAction::SubChar('\u{1112}')), // HANGUL JONGSEONG HIEUH
+    ('\u{11C3}', CharMapAction::SubStr("\u{1100}\u{1105}")), // HANGUL JONGSEONG KIYEOK-RIEUL
+    ('\u{11C4}', CharMapAction::SubStr("\u{1100}\u{1109}\u{1100}")), // HANGUL JONGSEONG KIYEOK-SIOS-KIYEOK
+    ('\u{11C5}', CharMapAction::SubStr("\u{1102}\u{1100}")), // HANGUL JONGSEONG NIEUN-KIYEOK
+    ('\u{11C6}', CharMapAction::SubStr("\u{1102}\u{1103}")), // HANGUL JONGSEONG NIEUN-TIKEUT
+    ('\u{11C7}', CharMapAction::SubStr("\u{1102}\u{1109}")), // HANGUL JONGSEONG NIEUN-SIOS
+    ('\u{11C8}', CharMapAction::SubStr("\u{1102}\u{1140}")), // HANGUL JONGSEONG NIEUN-PANSIOS
+    ('\u{11C9}', CharMapAction::SubStr("\u{1102}\u{1110}")), // HANGUL JONGSEONG NIEUN-THIEUTH
+    ('\u{11CA}', CharMapAction::SubStr("\u{1103}\u{1100}")), // HANGUL JONGSEONG TIKEUT-KIYEOK
+    ('\u{11CB}', CharMapAction::SubStr("\u{1103}\u{1105}")), // HANGUL JONGSEONG TIKEUT-RIEUL
+    ('\u{11CC}', CharMapAction::SubStr("\u{1105}\u{1100}\u{1109}")), // HANGUL JONGSEONG RIEUL-KIYEOK-SIOS
+    ('\u{11CD}', CharMapAction::SubStr("\u{1105}\u{1102}")), // HANGUL JONGSEONG RIEUL-NIEUN
+    ('\u{11CE}', CharMapAction::SubStr("\u{1105}\u{1103}")), // HANGUL JONGSEONG RIEUL-TIKEUT
+    ('\u{11CF}', CharMapAction::SubStr("\u{1105}\u{1103}\u{1112}")), // HANGUL JONGSEONG RIEUL-TIKEUT-HIEUH
+    ('\u{11D0}', CharMapAction::SubStr("\u{1105}\u{1105}")), // HANGUL JONGSEONG SSANGRIEUL
+    ('\u{11D1}', CharMapAction::SubStr("\u{1105}\u{1106}\u{1100}")), // HANGUL JONGSEONG RIEUL-MIEUM-KIYEOK
+    ('\u{11D2}', CharMapAction::SubStr("\u{1105}\u{1106}\u{1109}")), // HANGUL JONGSEONG RIEUL-MIEUM-SIOS
+    ('\u{11D3}', CharMapAction::SubStr("\u{1105}\u{1107}\u{1109}")), // HANGUL JONGSEONG RIEUL-PIEUP-SIOS
+    ('\u{11D4}', CharMapAction::SubStr("\u{1105}\u{1107}\u{1112}")), // HANGUL JONGSEONG RIEUL-PIEUP-HIEUH
+    ('\u{11D5}', CharMapAction::SubStr("\u{1105}\u{1107}\u{110B}")), // HANGUL JONGSEONG RIEUL-KAPYEOUNPIEUP
+    ('\u{11D6}', CharMapAction::SubStr("\u{1105}\u{1109}\u{1109}")), // HANGUL JONGSEONG RIEUL-SSANGSIOS
+    ('\u{11D7}', CharMapAction::SubStr("\u{1105}\u{1140}")), // HANGUL JONGSEONG RIEUL-PANSIOS
+    ('\u{11D8}', CharMapAction::SubStr("\u{1105}\u{110F}")), // HANGUL JONGSEONG RIEUL-KHIEUKH
+    ('\u{11D9}', CharMapAction::SubStr("\u{1105}\u{1159}")), // HANGUL JONGSEONG RIEUL-YEORINHIEUH
+    ('\u{11DA}', CharMapAction::SubStr("\u{1106}\u{1100}")), // HANGUL JONGSEONG MIEUM-KIYEOK
+    ('\u{11DB}', CharMapAction::SubStr("\u{1106}\u{1105}")), // HANGUL JONGSEONG MIEUM-RIEUL
+    ('\u{11DC}', CharMapAction::SubStr("\u{1106}\u{1107}")), // HANGUL JONGSEONG MIEUM-PIEUP
+    ('\u{11DD}', CharMapAction::SubStr("\u{1106}\u{1109}")), // HANGUL JONGSEONG MIEUM-SIOS
+    ('\u{11DE}', CharMapAction::SubStr("\u{1106}\u{1109}\u{1109}")), // HANGUL JONGSEONG MIEUM-SSANGSIOS
+    ('\u{11DF}', CharMapAction::SubStr("\u{1106}\u{1140}")), // HANGUL JONGSEONG MIEUM-PANSIOS
+    ('\u{11E0}', CharMapAction::SubStr("\u{1106}\u{110E}")), // HANGUL JONGSEONG MIEUM-CHIEUCH
+    ('\u{11E1}', CharMapAction::SubStr("\u{1106}\u{1112}")), // HANGUL JONGSEONG MIEUM-HIEUH
+    ('\u{11E2}', CharMapAction::SubStr("\u{1106}\u{110B}")), // HANGUL JONGSEONG KAPYEOUNMIEUM
+    ('\u{11E3}', CharMapAction::SubStr("\u{1107}\u{1105}")), // HANGUL JONGSEONG PIEUP-RIEUL
+    ('\u{11E4}', CharMapAction::SubStr("\u{1107}\u{1111}")), // HANGUL JONGSEONG PIEUP-PHIEUPH
+    ('\u{11E5}', CharMapAction::SubStr("\u{1107}\u{1112}")), // HANGUL JONGSEONG PIEUP-HIEUH
+    ('\u{11E6}', CharMapAction::SubStr("\u{1107}\u{110B}")), // HANGUL JONGSEONG KAPYEOUNPIEUP
+    ('\u{11E7}', CharMapAction::SubStr("\u{1109}\u{1100}")), // HANGUL JONGSEONG SIOS-KIYEOK
+    ('\u{11E8}', CharMapAction::SubStr("\u{1109}\u{1103}")), // HANGUL JONGSEONG SIOS-TIKEUT
+    ('\u{11E9}', CharMapAction::SubStr("\u{1109}\u{1105}")), // HANGUL JONGSEONG SIOS-RIEUL
+    ('\u{11EA}', CharMapAction::SubStr("\u{1109}\u{1107}")), // HANGUL JONGSEONG SIOS-PIEUP
+    ('\u{11EB}', CharMapAction::SubChar('\u{1140}')), // HANGUL JONGSEONG PANSIOS
+    ('\u{11EC}', CharMapAction::SubStr("\u{110B}\u{1100}")), // HANGUL JONGSEONG IEUNG-KIYEOK
+    ('\u{11ED}', CharMapAction::SubStr("\u{110B}\u{1100}\u{1100}")), // HANGUL JONGSEONG IEUNG-SSANGKIYEOK
+    ('\u{11EE}', CharMapAction::SubStr("\u{110B}\u{110B}")), // HANGUL JONGSEONG SSANGIEUNG
+    ('\u{11EF}', CharMapAction::SubStr("\u{110B}\u{110F}")), // HANGUL JONGSEONG IEUNG-KHIEUKH
+    ('\u{11F0}', CharMapAction::SubChar('\u{114C}')), // HANGUL JONGSEONG YESIEUNG
+    ('\u{11F1}', CharMapAction::SubStr("\u{110B}\u{1109}")), // HANGUL JONGSEONG YESIEUNG-SIOS
+    ('\u{11F2}', CharMapAction::SubStr("\u{110B}\u{1140}")), // HANGUL JONGSEONG YESIEUNG-PANSIOS
+    ('\u{11F3}', CharMapAction::SubStr("\u{1111}\u{1107}")), // HANGUL JONGSEONG PHIEUPH-PIEUP
+    ('\u{11F4}', CharMapAction::SubStr("\u{1111}\u{110B}")), // HANGUL JONGSEONG KAPYEOUNPHIEUPH
+    ('\u{11F5}', CharMapAction::SubStr("\u{1112}\u{1102}")), // HANGUL JONGSEONG HIEUH-NIEUN
+    ('\u{11F6}', CharMapAction::SubStr("\u{1112}\u{1105}")), // HANGUL JONGSEONG HIEUH-RIEUL
+    ('\u{11F7}', CharMapAction::SubStr("\u{1112}\u{1106}")), // HANGUL JONGSEONG HIEUH-MIEUM
+    ('\u{11F8}', CharMapAction::SubStr("\u{1112}\u{1107}")), // HANGUL JONGSEONG HIEUH-PIEUP
+    ('\u{11F9}', CharMapAction::SubChar('\u{1159}')), // HANGUL JONGSEONG YEORINHIEUH
+    ('\u{11FA}', CharMapAction::SubStr("\u{1100}\u{1102}")), // HANGUL JONGSEONG KIYEOK-NIEUN
+    ('\u{11FB}', CharMapAction::SubStr("\u{1100}\u{1107}")), // HANGUL JONGSEONG KIYEOK-PIEUP
+    ('\u{11FC}', CharMapAction::SubStr("\u{1100}\u{110E}")), // HANGUL JONGSEONG KIYEOK-CHIEUCH
+    ('\u{11FD}', CharMapAction::SubStr("\u{1100}\u{110F}")), // HANGUL JONGSEONG KIYEOK-KHIEUKH
+    ('\u{11FE}', CharMapAction::SubStr("\u{1100}\u{1112}")), // HANGUL JONGSEONG KIYEOK-HIEUH
+    ('\u{11FF}', CharMapAction::SubStr("\u{1102}\u{1102}")), // HANGUL JONGSEONG SSANGNIEUN
+    ('\u{1200}', CharMapAction::SubChar('U')), // ETHIOPIC SYLLABLE HA
+    ('\u{1223}', CharMapAction::SubChar('\u{0270}')), // ETHIOPIC SYLLABLE SZAA
+    ('\u{1240}', CharMapAction::SubChar('\u{03A6}')), // ETHIOPIC SYLLABLE QA
+    ('\u{1260}', CharMapAction::SubChar('\u{0548}')), // ETHIOPIC SYLLABLE BA
+    ('\u{1294}', CharMapAction::SubChar('\u{0571}')), // ETHIOPIC SYLLABLE NEE
+    ('\u{12D0}', CharMapAction::SubChar('O')), // ETHIOPIC SYLLABLE PHARYNGEAL A
+    ('\u{13A0}', CharMapAction::SubChar('D')), // CHEROKEE LETTER A
+    ('\u{13A1}', CharMapAction::SubChar('R')), // CHEROKEE LETTER E
+    ('\u{13A2}', CharMapAction::SubChar('T')), // CHEROKEE LETTER I
+    ('\u{13A4}', CharMapAction::SubStr("O'")), // CHEROKEE LETTER U
+    ('\u{13A5}', CharMapAction::SubChar('i')), // CHEROKEE LETTER V
+    ('\u{13A8}', CharMapAction::SubChar('\u{2C75}')), // CHEROKEE LETTER GE
+    ('\u{13A9}', CharMapAction::SubChar('Y')), // CHEROKEE LETTER GI
+    ('\u{13AA}', CharMapAction::SubChar('A')), // CHEROKEE LETTER GO
+    ('\u{13AB}', CharMapAction::SubChar('J')), // CHEROKEE LETTER GU
+    ('\u{13AC}', CharMapAction::SubChar('E')), // CHEROKEE LETTER GV
+    ('\u{13AE}', CharMapAction::SubChar('?')), // CHEROKEE LETTER HE
+    ('\u{13B0}', CharMapAction::SubChar('\u{2C75}')), // CHEROKEE LETTER HO
+    ('\u{13B1}', CharMapAction::SubChar('\u{0393}')), // CHEROKEE LETTER HU
+    ('\u{13B3}', CharMapAction::SubChar('W')), // CHEROKEE LETTER LA
+    ('\u{13B7}', CharMapAction::SubChar('M')), // CHEROKEE LETTER LU
+    ('\u{13BB}', CharMapAction::SubChar('H')), // CHEROKEE LETTER MI
+    ('\u{13BD}', CharMapAction::SubChar('Y')), // CHEROKEE LETTER MU
+    ('\u{13BE}', CharMapAction::SubStr("O\u{0335}")), // CHEROKEE LETTER NA
+    ('\u{13BF}', CharMapAction::SubChar('\u{01AB}')), // CHEROKEE LETTER HNA
+    ('\u{13C0}', CharMapAction::SubChar('G')), // CHEROKEE LETTER NAH
+    ('\u{13C2}', CharMapAction::SubChar('h')), // CHEROKEE LETTER NI
+    ('\u{13C3}', CharMapAction::SubChar('Z')), // CHEROKEE LETTER NO
+    ('\u{13C7}', CharMapAction::SubChar('\u{0460}')), // CHEROKEE LETTER QUE
+    ('\u{13CB}', CharMapAction::SubChar('\u{0190}')), // CHEROKEE LETTER QUV
+    ('\u{13CC}', CharMapAction::SubStr("U\u{0335}")), // CHEROKEE LETTER SA
+    ('\u{13CE}', CharMapAction::SubChar('4')), // CHEROKEE LETTER SE
+    ('\u{13CF}', CharMapAction::SubChar('b')), // CHEROKEE LETTER SI
+    ('\u{13D2}', CharMapAction::SubChar('R')), // CHEROKEE LETTER SV
+    ('\u{13D4}', CharMapAction::SubChar('W')), // CHEROKEE LETTER TA
+    ('\u{13D5}', CharMapAction::SubChar('S')), // CHEROKEE LETTER DE
+    ('\u{13D9}', CharMapAction::SubChar('V')), // CHEROKEE LETTER DO
+    ('\u{13DA}', CharMapAction::SubChar('S')), // CHEROKEE LETTER DU
+    ('\u{13DE}', CharMapAction::SubChar('L')), // CHEROKEE LETTER TLE
+    ('\u{13DF}', CharMapAction::SubChar('C')), // CHEROKEE LETTER TLI
+    ('\u{13E2}', CharMapAction::SubChar('P')), // CHEROKEE LETTER TLV
+    ('\u{13E6}', CharMapAction::SubChar('K')), // CHEROKEE LETTER TSO
+    ('\u{13E7}', CharMapAction::SubChar('d')), // CHEROKEE LETTER TSU
+    ('\u{13EB}', CharMapAction::SubStr("O\u{0335}")), // CHEROKEE LETTER WI
+    ('\u{13EE}', CharMapAction::SubChar('6')), // CHEROKEE LETTER WV
+    ('\u{13F0}', CharMapAction::SubChar('\u{00DF}')), // CHEROKEE LETTER YE
+    ('\u{13F2}', CharMapAction::SubStr("h\u{0314}")), // CHEROKEE LETTER YO
+    ('\u{13F3}', CharMapAction::SubChar('G')), // CHEROKEE LETTER YU
+    ('\u{13F4}', CharMapAction::SubChar('B')), // CHEROKEE LETTER YV
+    ('\u{13FB}', CharMapAction::SubChar('\u{0262}')), // CHEROKEE SMALL LETTER YU
+    ('\u{13FC}', CharMapAction::SubChar('\u{0299}')), // CHEROKEE SMALL LETTER YV
+    ('\u{1400}', CharMapAction::SubChar('=')), // CANADIAN SYLLABICS HYPHEN
+    ('\u{1403}', CharMapAction::SubChar('\u{0394}')), // CANADIAN SYLLABICS I
+    ('\u{140C}', CharMapAction::SubStr("\u{00B7}\u{1401}")), // CANADIAN SYLLABICS WE
+    ('\u{140D}', CharMapAction::SubStr("\u{1401}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WE
+    ('\u{140E}', CharMapAction::SubStr("\u{00B7}\u{0394}")), // CANADIAN SYLLABICS WI
+    ('\u{140F}', CharMapAction::SubStr("\u{0394}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WI
+    ('\u{1410}', CharMapAction::SubStr("\u{00B7}\u{1404}")), // CANADIAN SYLLABICS WII
+    ('\u{1411}', CharMapAction::SubStr("\u{1404}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WII
+    ('\u{1412}', CharMapAction::SubStr("\u{00B7}\u{1405}")), // CANADIAN SYLLABICS WO
+    ('\u{1413}', CharMapAction::SubStr("\u{1405}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WO
+    ('\u{1414}', CharMapAction::SubStr("\u{00B7}\u{1406}")), // CANADIAN SYLLABICS WOO
+    ('\u{1415}', CharMapAction::SubStr("\u{1406}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WOO
+    ('\u{1417}', CharMapAction::SubStr("\u{00B7}\u{140A}")), // CANADIAN SYLLABICS WA
+    ('\u{1418}', CharMapAction::SubStr("\u{140A}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WA
+    ('\u{1419}', CharMapAction::SubStr("\u{00B7}\u{140B}")), // CANADIAN SYLLABICS WAA
+    ('\u{141A}', CharMapAction::SubStr("\u{140B}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE WAA
+    ('\u{1427}', CharMapAction::SubChar('\u{00B7}')), // CANADIAN SYLLABICS FINAL MIDDLE DOT
+    ('\u{142B}', CharMapAction::SubStr("\u{1401}\u{1420}")), // CANADIAN SYLLABICS EN
+    ('\u{142C}', CharMapAction::SubStr("\u{0394}\u{1420}")), // CANADIAN SYLLABICS IN
+    ('\u{142D}', CharMapAction::SubStr("\u{1405}\u{1420}")), // CANADIAN SYLLABICS ON
+    ('\u{142E}', CharMapAction::SubStr("\u{140A}\u{1420}")), // CANADIAN SYLLABICS AN
+    ('\u{142F}', CharMapAction::SubChar('V')), // CANADIAN SYLLABICS PE
+    ('\u{1431}', CharMapAction::SubChar('\u{0245}')), // CANADIAN SYLLABICS PI
+    ('\u{1433}', CharMapAction::SubChar('>')), // CANADIAN SYLLABICS PO
+    ('\u{1437}', CharMapAction::SubStr("\u{00B7}>")), // CANADIAN SYLLABICS CARRIER HI
+    ('\u{1438}', CharMapAction::SubChar('<')), // CANADIAN SYLLABICS PA
+    ('\u{143A}', CharMapAction::SubStr("\u{00B7}V")), // CANADIAN SYLLABICS PWE
+    ('\u{143B}', CharMapAction::SubStr("V\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWE
+    ('\u{143C}', CharMapAction::SubStr("\u{00B7}\u{0245}")), // CANADIAN SYLLABICS PWI
+    ('\u{143D}', CharMapAction::SubStr("\u{0245}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWI
+    ('\u{143E}', CharMapAction::SubStr("\u{00B7}\u{1432}")), // CANADIAN SYLLABICS PWII
+    ('\u{143F}', CharMapAction::SubStr("\u{1432}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWII
+    ('\u{1440}', CharMapAction::SubStr("\u{00B7}>")), // CANADIAN SYLLABICS PWO
+    ('\u{1441}', CharMapAction::SubStr(">\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWO
+    ('\u{1442}', CharMapAction::SubStr("\u{00B7}\u{1434}")), // CANADIAN SYLLABICS PWOO
+    ('\u{1443}', CharMapAction::SubStr("\u{1434}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWOO
+    ('\u{1444}', CharMapAction::SubStr("\u{00B7}<")), // CANADIAN SYLLABICS PWA
+    ('\u{1445}', CharMapAction::SubStr("<\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWA
+    ('\u{1446}', CharMapAction::SubStr("\u{00B7}\u{1439}")), // CANADIAN SYLLABICS PWAA
+    ('\u{1447}', CharMapAction::SubStr("\u{1439}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE PWAA
+    ('\u{144A}', CharMapAction::SubChar('\'')), // CANADIAN SYLLABICS WEST-CREE P
+    ('\u{144C}', CharMapAction::SubChar('U')), // CANADIAN SYLLABICS TE
+    ('\u{144E}', CharMapAction::SubChar('\u{0548}')), // CANADIAN SYLLABICS TI
+    ('\u{1454}', CharMapAction::SubStr("\u{00B7}\u{1450}")), // CANADIAN SYLLABICS CARRIER DI
+    ('\u{1457}', CharMapAction::SubStr("\u{00B7}U")), // CANADIAN SYLLABICS TWE
+    ('\u{1458}', CharMapAction::SubStr("U\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWE
+    ('\u{1459}', CharMapAction::SubStr("\u{00B7}\u{0548}")), // CANADIAN SYLLABICS TWI
+    ('\u{145A}', CharMapAction::SubStr("\u{0548}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWI
+    ('\u{145B}', CharMapAction::SubStr("\u{00B7}\u{144F}")), // CANADIAN SYLLABICS TWII
+    ('\u{145C}', CharMapAction::SubStr("\u{144F}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWII
+    ('\u{145D}', CharMapAction::SubStr("\u{00B7}\u{1450}")), // CANADIAN SYLLABICS TWO
+    ('\u{145E}', CharMapAction::SubStr("\u{1450}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWO
+    ('\u{145F}', CharMapAction::SubStr("\u{00B7}\u{1451}")), // CANADIAN SYLLABICS TWOO
+    ('\u{1460}', CharMapAction::SubStr("\u{1451}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWOO
+    ('\u{1461}', CharMapAction::SubStr("\u{00B7}\u{1455}")), // CANADIAN SYLLABICS TWA
+    ('\u{1462}', CharMapAction::SubStr("\u{1455}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWA
+    ('\u{1463}', CharMapAction::SubStr("\u{00B7}\u{1456}")), // CANADIAN SYLLABICS TWAA
+    ('\u{1464}', CharMapAction::SubStr("\u{1456}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE TWAA
+    ('\u{1467}', CharMapAction::SubStr("U'")), // CANADIAN SYLLABICS TTE
+    ('\u{1468}', CharMapAction::SubStr("\u{0548}'")), // CANADIAN SYLLABICS TTI
+    ('\u{1469}', CharMapAction::SubStr("\u{1450}'")), // CANADIAN SYLLABICS TTO
+    ('\u{146A}', CharMapAction::SubStr("\u{1455}'")), // CANADIAN SYLLABICS TTA
+    ('\u{146D}', CharMapAction::SubChar('P')), // CANADIAN SYLLABICS KI
+    ('\u{146F}', CharMapAction::SubChar('d')), // CANADIAN SYLLABICS KO
+    ('\u{1472}', CharMapAction::SubChar('b')), // CANADIAN SYLLABICS KA
+    ('\u{1473}', CharMapAction::SubStr("b\u{0307}")), // CANADIAN SYLLABICS KAA
+    ('\u{1474}', CharMapAction::SubStr("\u{00B7}\u{146B}")), // CANADIAN SYLLABICS KWE
+    ('\u{1475}', CharMapAction::SubStr("\u{146B}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWE
+    ('\u{1476}', CharMapAction::SubStr("\u{00B7}P")), // CANADIAN SYLLABICS KWI
+    ('\u{1477}', CharMapAction::SubStr("p\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWI
+    ('\u{1478}', CharMapAction::SubStr("\u{00B7}\u{146E}")), // CANADIAN SYLLABICS KWII
+    ('\u{1479}', CharMapAction::SubStr("\u{146E}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWII
+    ('\u{147A}', CharMapAction::SubStr("\u{00B7}d")), // CANADIAN SYLLABICS KWO
+    ('\u{147B}', CharMapAction::SubStr("d\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWO
+    ('\u{147C}', CharMapAction::SubStr("\u{00B7}\u{1470}")), // CANADIAN SYLLABICS KWOO
+    ('\u{147D}', CharMapAction::SubStr("\u{1470}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWOO
+    ('\u{147E}', CharMapAction::SubStr("\u{00B7}b")), // CANADIAN SYLLABICS KWA
+    ('\u{147F}', CharMapAction::SubStr("b\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWA
+    ('\u{1480}', CharMapAction::SubStr("\u{00B7}b\u{0307}")), // CANADIAN SYLLABICS KWAA
+    ('\u{1481}', CharMapAction::SubStr("b\u{0307}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE KWAA
+    ('\u{1485}', CharMapAction::SubStr("\u{146B}'")), // CANADIAN SYLLABICS SOUTH-SLAVEY KEH
+    ('\u{1486}', CharMapAction::SubStr("P'")), // CANADIAN SYLLABICS SOUTH-SLAVEY KIH
+    ('\u{1487}', CharMapAction::SubStr("d'")), // CANADIAN SYLLABICS SOUTH-SLAVEY KOH
+    ('\u{1488}', CharMapAction::SubStr("b'")), // CANADIAN SYLLABICS SOUTH-SLAVEY KAH
+    ('\u{148D}', CharMapAction::SubChar('J')), // CANADIAN SYLLABICS CO
+    ('\u{1492}', CharMapAction::SubStr("\u{00B7}\u{1489}")), // CANADIAN SYLLABICS CWE
+    ('\u{1493}', CharMapAction::SubStr("\u{1489}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWE
+    ('\u{1494}', CharMapAction::SubStr("\u{00B7}\u{148B}")), // CANADIAN SYLLABICS CWI
+    ('\u{1495}', CharMapAction::SubStr("\u{148B}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWI
+    ('\u{1496}', CharMapAction::SubStr("\u{00B7}\u{148C}")), // CANADIAN SYLLABICS CWII
+    ('\u{1497}', CharMapAction::SubStr("\u{148C}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWII
+    ('\u{1498}', CharMapAction::SubStr("\u{00B7}J")), // CANADIAN SYLLABICS CWO
+    ('\u{1499}', CharMapAction::SubStr("J\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWO
+    ('\u{149A}', CharMapAction::SubStr("\u{00B7}\u{148E}")), // CANADIAN SYLLABICS CWOO
+    ('\u{149B}', CharMapAction::SubStr("\u{148E}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWOO
+    ('\u{149C}', CharMapAction::SubStr("\u{00B7}\u{1490}")), // CANADIAN SYLLABICS CWA
+    ('\u{149D}', CharMapAction::SubStr("\u{1490}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWA
+    ('\u{149E}', CharMapAction::SubStr("\u{00B7}\u{1491}")), // CANADIAN SYLLABICS CWAA
+    ('\u{149F}', CharMapAction::SubStr("\u{1491}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE CWAA
+    ('\u{14A5}', CharMapAction::SubChar('\u{0393}')), // CANADIAN SYLLABICS MI
+    ('\u{14AA}', CharMapAction::SubChar('L')), // CANADIAN SYLLABICS MA
+    ('\u{14AC}', CharMapAction::SubStr("\u{00B7}\u{14A3}")), // CANADIAN SYLLABICS MWE
+    ('\u{14AD}', CharMapAction::SubStr("\u{14A3}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWE
+    ('\u{14AE}', CharMapAction::SubStr("\u{00B7}\u{0393}")), // CANADIAN SYLLABICS MWI
+    ('\u{14AF}', CharMapAction::SubStr("\u{0393}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWI
+    ('\u{14B0}', CharMapAction::SubStr("\u{00B7}\u{14A6}")), // CANADIAN SYLLABICS MWII
+    ('\u{14B1}', CharMapAction::SubStr("\u{14A6}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWII
+    ('\u{14B2}', CharMapAction::SubStr("\u{00B7}\u{14A7}")), // CANADIAN SYLLABICS MWO
+    ('\u{14B3}', CharMapAction::SubStr("\u{14A7}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWO
+    ('\u{14B4}', CharMapAction::SubStr("\u{00B7}\u{14A8}")), // CANADIAN SYLLABICS MWOO
+    ('\u{14B5}', CharMapAction::SubStr("\u{14A8}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWOO
+    ('\u{14B6}', CharMapAction::SubStr("\u{00B7}L")), // CANADIAN SYLLABICS MWA
+    ('\u{14B7}', CharMapAction::SubStr("l\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWA
+    ('\u{14B8}', CharMapAction::SubStr("\u{00B7}\u{14AB}")), // CANADIAN SYLLABICS MWAA
+    ('\u{14B9}', CharMapAction::SubStr("\u{14AB}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE MWAA
+    ('\u{14BF}', CharMapAction::SubChar('2')), // CANADIAN SYLLABICS SAYISI M
+    ('\u{14C9}', CharMapAction::SubStr("\u{00B7}\u{14C0}")), // CANADIAN SYLLABICS NWE
+    ('\u{14CA}', CharMapAction::SubStr("\u{14C0}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE NWE
+    ('\u{14CB}', CharMapAction::SubStr("\u{00B7}\u{14C7}")), // CANADIAN SYLLABICS NWA
+    ('\u{14CC}', CharMapAction::SubStr("\u{14C7}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE NWA
+    ('\u{14CD}', CharMapAction::SubStr("\u{00B7}\u{14C8}")), // CANADIAN SYLLABICS NWAA
+    ('\u{14CE}', CharMapAction::SubStr("\u{14C8}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE NWAA
+    ('\u{14D1}', CharMapAction::SubChar('\u{1421}')), // CANADIAN SYLLABICS CARRIER NG
+    ('\u{14DC}', CharMapAction::SubStr("\u{00B7}\u{14D3}")), // CANADIAN SYLLABICS LWE
+    ('\u{14DD}', CharMapAction::SubStr("\u{14D3}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWE
+    ('\u{14DE}', CharMapAction::SubStr("\u{00B7}\u{14D5}")), // CANADIAN SYLLABICS LWI
+    ('\u{14DF}', CharMapAction::SubStr("\u{14D5}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWI
+    ('\u{14E0}', CharMapAction::SubStr("\u{00B7}\u{14D6}")), // CANADIAN SYLLABICS LWII
+    ('\u{14E1}', CharMapAction::SubStr("\u{14D6}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWII
+    ('\u{14E2}', CharMapAction::SubStr("\u{00B7}\u{14D7}")), // CANADIAN SYLLABICS LWO
+    ('\u{14E3}', CharMapAction::SubStr("\u{14D7}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWO
+    ('\u{14E4}', CharMapAction::SubStr("\u{00B7}\u{14D8}")), // CANADIAN SYLLABICS LWOO
+    ('\u{14E5}', CharMapAction::SubStr("\u{14D8}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWOO
+    ('\u{14E6}', CharMapAction::SubStr("\u{00B7}\u{14DA}")), // CANADIAN SYLLABICS LWA
+    ('\u{14E7}', CharMapAction::SubStr("\u{14DA}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWA
+    ('\u{14E8}', CharMapAction::SubStr("\u{00B7}\u{14DB}")), // CANADIAN SYLLABICS LWAA
+    ('\u{14E9}', CharMapAction::SubStr("\u{14DB}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE LWAA
+    ('\u{14F6}', CharMapAction::SubStr("\u{00B7}\u{14ED}")), // CANADIAN SYLLABICS SWE
+    ('\u{14F7}', CharMapAction::SubStr("\u{14ED}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWE
+    ('\u{14F8}', CharMapAction::SubStr("\u{00B7}\u{14EF}")), // CANADIAN SYLLABICS SWI
+    ('\u{14F9}', CharMapAction::SubStr("\u{14EF}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWI
+    ('\u{14FA}', CharMapAction::SubStr("\u{00B7}\u{14F0}")), // CANADIAN SYLLABICS SWII
+    ('\u{14FB}', CharMapAction::SubStr("\u{14F0}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWII
+    ('\u{14FC}', CharMapAction::SubStr("\u{00B7}\u{14F1}")), // CANADIAN SYLLABICS SWO
+    ('\u{14FD}', CharMapAction::SubStr("\u{14F1}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWO
+    ('\u{14FE}', CharMapAction::SubStr("\u{00B7}\u{14F2}")), // CANADIAN SYLLABICS SWOO
+    ('\u{14FF}', CharMapAction::SubStr("\u{14F2}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWOO
+    ('\u{1500}', CharMapAction::SubStr("\u{00B7}\u{14F4}")), // CANADIAN SYLLABICS SWA
+    ('\u{1501}', CharMapAction::SubStr("\u{14F4}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWA
+    ('\u{1502}', CharMapAction::SubStr("\u{00B7}\u{14F5}")), // CANADIAN SYLLABICS SWAA
+    ('\u{1503}', CharMapAction::SubStr("\u{14F5}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SWAA
+    ('\u{150C}', CharMapAction::SubStr("\u{150B}<")), // CANADIAN SYLLABICS NASKAPI SPWA
+    ('\u{150D}', CharMapAction::SubStr("\u{150B}\u{1455}")), // CANADIAN SYLLABICS NASKAPI STWA
+    ('\u{150E}', CharMapAction::SubStr("\u{150B}b")), // CANADIAN SYLLABICS NASKAPI SKWA
+    ('\u{150F}', CharMapAction::SubStr("\u{150B}\u{1490}")), // CANADIAN SYLLABICS NASKAPI SCWA
+    ('\u{1517}', CharMapAction::SubStr("\u{00B7}\u{1510}")), // CANADIAN SYLLABICS SHWE
+    ('\u{1518}', CharMapAction::SubStr("\u{1510}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWE
+    ('\u{1519}', CharMapAction::SubStr("\u{00B7}\u{1511}")), // CANADIAN SYLLABICS SHWI
+    ('\u{151A}', CharMapAction::SubStr("\u{1511}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWI
+    ('\u{151B}', CharMapAction::SubStr("\u{00B7}\u{1512}")), // CANADIAN SYLLABICS SHWII
+    ('\u{151C}', CharMapAction::SubStr("\u{1512}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWII
+    ('\u{151D}', CharMapAction::SubStr("\u{00B7}\u{1513}")), // CANADIAN SYLLABICS SHWO
+    ('\u{151E}', CharMapAction::SubStr("\u{1513}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWO
+    ('\u{151F}', CharMapAction::SubStr("\u{00B7}\u{1514}")), // CANADIAN SYLLABICS SHWOO
+    ('\u{1520}', CharMapAction::SubStr("\u{1514}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWOO
+    ('\u{1521}', CharMapAction::SubStr("\u{00B7}\u{1515}")), // CANADIAN SYLLABICS SHWA
+    ('\u{1522}', CharMapAction::SubStr("\u{1515}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWA
+    ('\u{1523}', CharMapAction::SubStr("\u{00B7}\u{1516}")), // CANADIAN SYLLABICS SHWAA
+    ('\u{1524}', CharMapAction::SubStr("\u{1516}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE SHWAA
+    ('\u{152F}', CharMapAction::SubStr("\u{00B7}4")), // CANADIAN SYLLABICS YWE
+    ('\u{1530}', CharMapAction::SubStr("4\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWE
+    ('\u{1531}', CharMapAction::SubStr("\u{00B7}\u{1528}")), // CANADIAN SYLLABICS YWI
+    ('\u{1532}', CharMapAction::SubStr("\u{1528}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWI
+    ('\u{1533}', CharMapAction::SubStr("\u{00B7}\u{1529}")), // CANADIAN SYLLABICS YWII
+    ('\u{1534}', CharMapAction::SubStr("\u{1529}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWII
+    ('\u{1535}', CharMapAction::SubStr("\u{00B7}\u{152A}")), // CANADIAN SYLLABICS YWO
+    ('\u{1536}', CharMapAction::SubStr("\u{152A}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWO
+    ('\u{1537}', CharMapAction::SubStr("\u{00B7}\u{152B}")), // CANADIAN SYLLABICS YWOO
+    ('\u{1538}', CharMapAction::SubStr("\u{152B}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWOO
+    ('\u{1539}', CharMapAction::SubStr("\u{00B7}\u{152D}")), // CANADIAN SYLLABICS YWA
+    ('\u{153A}', CharMapAction::SubStr("\u{152D}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWA
+    ('\u{153B}', CharMapAction::SubStr("\u{00B7}\u{152E}")), // CANADIAN SYLLABICS YWAA
+    ('\u{153C}', CharMapAction::SubStr("\u{152E}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE YWAA
+    ('\u{1540}', CharMapAction::SubChar('\u{1429}')), // CANADIAN SYLLABICS WEST-CREE Y
+    ('\u{1541}', CharMapAction::SubChar('x')), // CANADIAN SYLLABICS SAYISI YI
+    ('\u{154E}', CharMapAction::SubStr("\u{00B7}\u{154C}")), // CANADIAN SYLLABICS RWAA
+    ('\u{154F}', CharMapAction::SubStr("\u{154C}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE RWAA
+    ('\u{155B}', CharMapAction::SubStr("\u{00B7}\u{155A}")), // CANADIAN SYLLABICS FWAA
+    ('\u{155C}', CharMapAction::SubStr("\u{155A}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE FWAA
+    ('\u{1568}', CharMapAction::SubStr("\u{00B7}\u{1567}")), // CANADIAN SYLLABICS THWAA
+    ('\u{1569}', CharMapAction::SubStr("\u{1567}\u{00B7}")), // CANADIAN SYLLABICS WEST-CREE THWAA
+    ('\u{1577}', CharMapAction::SubChar('\u{1E9F}')), // CANADIAN SYLLABICS NUNAVIK HO
+    ('\u{157C}', CharMapAction::SubChar('H')), // CANADIAN SYLLABICS NUNAVUT H
+    ('\u{157D}', CharMapAction::SubChar('x')), // CANADIAN SYLLABICS HK
+    ('\u{157E}', CharMapAction::SubStr("\u{1550}\u{146C}")), // CANADIAN SYLLABICS QAAI
+    ('\u{157F}', CharMapAction::SubStr("\u{1550}P")), // CANADIAN SYLLABICS QI
+    ('\u{1580}', CharMapAction::SubStr("\u{1550}\u{146E}")), // CANADIAN SYLLABICS QII
+    ('\u{1581}', CharMapAction::SubStr("\u{1550}d")), // CANADIAN SYLLABICS QO
+    ('\u{1582}', CharMapAction::SubStr("\u{1550}\u{1470}")), // CANADIAN SYLLABICS QOO
+    ('\u{1583}', CharMapAction::SubStr("\u{1550}b")), // CANADIAN SYLLABICS QA
+    ('\u{1584}', CharMapAction::SubStr("\u{1550}b\u{0307}")), // CANADIAN SYLLABICS QAA
+    ('\u{1585}', CharMapAction::SubStr("\u{1550}\u{1483}")), // CANADIAN SYLLABICS Q
+    ('\u{1587}', CharMapAction::SubChar('R')), // CANADIAN SYLLABICS TLHI
+    ('\u{158E}', CharMapAction::SubStr("\u{1595}\u{148A}")), // CANADIAN SYLLABICS NGAAI
+    ('\u{158F}', CharMapAction::SubStr("\u{1595}\u{148B}")), // CANADIAN SYLLABICS NGI
+    ('\u{1590}', CharMapAction::SubStr("\u{1595}\u{148C}")), // CANADIAN SYLLABICS NGII
+    ('\u{1591}', CharMapAction::SubStr("\u{1595}J")), // CANADIAN SYLLABICS NGO
+    ('\u{1592}', CharMapAction::SubStr("\u{1595}\u{148E}")), // CANADIAN SYLLABICS NGOO
+    ('\u{1593}', CharMapAction::SubStr("\u{1595}\u{1490}")), // CANADIAN SYLLABICS NGA
+    ('\u{1594}', CharMapAction::SubStr("\u{1595}\u{1491}")), // CANADIAN SYLLABICS NGAA
+    ('\u{15AF}', CharMapAction::SubChar('b')), // CANADIAN SYLLABICS AIVILIK B
+    ('\u{15B4}', CharMapAction::SubChar('F')), // CANADIAN SYLLABICS BLACKFOOT WE
+    ('\u{15B5}', CharMapAction::SubChar('\u{2132}')), // CANADIAN SYLLABICS BLACKFOOT WI
+    ('\u{15B7}', CharMapAction::SubChar('\u{A7FB}')), // CANADIAN SYLLABICS BLACKFOOT WA
+    ('\u{15C4}', CharMapAction::SubChar('\u{2C6F}')), // CANADIAN SYLLABICS CARRIER GHU
+    ('\u{15C5}', CharMapAction::SubChar('A')), // CANADIAN SYLLABICS CARRIER GHO
+    ('\u{15DE}', CharMapAction::SubChar('D')), // CANADIAN SYLLABICS CARRIER THE
+    ('\u{15EA}', CharMapAction::SubChar('D')), // CANADIAN SYLLABICS CARRIER PE
+    ('\u{15EF}', CharMapAction::SubChar('\u{0460}')), // CANADIAN SYLLABICS CARRIER GU
+    ('\u{15F0}', CharMapAction::SubChar('M')), // CANADIAN SYLLABICS CARRIER GO
+    ('\u{15F7}', CharMapAction::SubChar('B')), // CANADIAN SYLLABICS CARRIER KHE
+    ('\u{1602}', CharMapAction::SubChar('\u{1490}')), // CANADIAN SYLLABICS CARRIER NU
+    ('\u{1603}', CharMapAction::SubChar('\u{1489}')), // CANADIAN SYLLABICS CARRIER NO
+    ('\u{1604}', CharMapAction::SubChar('\u{14D3}')), // CANADIAN SYLLABICS CARRIER NE
+    ('\u{1607}', CharMapAction::SubChar('\u{14DA}')), // CANADIAN SYLLABICS CARRIER NA
+    ('\u{1622}', CharMapAction::SubChar('\u{1543}')), // CANADIAN SYLLABICS CARRIER LU
+    ('\u{1623}', CharMapAction::SubChar('\u{1546}')), // CANADIAN SYLLABICS CARRIER LO
+    ('\u{1624}', CharMapAction::SubChar('\u{154A}')), // CANADIAN SYLLABICS CARRIER LE
+    ('\u{162E}', CharMapAction::SubChar('\u{01B1}')), // CANADIAN SYLLABICS CARRIER LHU
+    ('\u{162F}', CharMapAction::SubChar('\u{03A9}')), // CANADIAN SYLLABICS CARRIER LHO
+    ('\u{1634}', CharMapAction::SubChar('\u{01B1}')), // CANADIAN SYLLABICS CARRIER TLHU
+    ('\u{1635}', CharMapAction::SubChar('\u{03A9}')), // CANADIAN SYLLABICS CARRIER TLHO
+    ('\u{166D}', CharMapAction::SubChar('X')), // CANADIAN SYLLABICS CHI SIGN
+    ('\u{166E}', CharMapAction::SubChar('x')), // CANADIAN SYLLABICS FULL STOP
+    ('\u{166F}', CharMapAction::SubStr("\u{1550}\u{146B}")), // CANADIAN SYLLABICS QAI
+    ('\u{1670}', CharMapAction::SubStr("\u{1595}\u{1489}")), // CANADIAN SYLLABICS NGAI
+    ('\u{1671}', CharMapAction::SubStr("\u{1596}\u{148B}")), // CANADIAN SYLLABICS NNGI
+    ('\u{1672}', CharMapAction::SubStr("\u{1596}\u{148C}")), // CANADIAN SYLLABICS NNGII
+    ('\u{1673}', CharMapAction::SubStr("\u{1596}J")), // CANADIAN SYLLABICS NNGO
+    ('\u{1674}', CharMapAction::SubStr("\u{1596}\u{148E}")), // CANADIAN SYLLABICS NNGOO
+    ('\u{1675}', CharMapAction::SubStr("\u{1596}\u{1490}")), // CANADIAN SYLLABICS NNGA
+    ('\u{1676}', CharMapAction::SubStr("\u{1596}\u{1491}")), // CANADIAN SYLLABICS NNGAA
+    ('\u{1677}', CharMapAction::SubStr("\u{15A7}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWEE
+    ('\u{1678}', CharMapAction::SubStr("\u{15A8}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWI
+    ('\u{1679}', CharMapAction::SubStr("\u{15A9}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWII
+    ('\u{167A}', CharMapAction::SubStr("\u{15AA}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWO
+    ('\u{167B}', CharMapAction::SubStr("\u{15AB}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWOO
+    ('\u{167C}', CharMapAction::SubStr("\u{15AC}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWA
+    ('\u{167D}', CharMapAction::SubStr("\u{15AD}\u{00B7}")), // CANADIAN SYLLABICS WOODS-CREE THWAA
+    ('\u{1680}', CharMapAction::SubChar(' ')), // OGHAM SPACE MARK
+    ('\u{16B2}', CharMapAction::SubChar('<')), // RUNIC LETTER KAUNA
+    ('\u{16B7}', CharMapAction::SubChar('X')), // RUNIC LETTER GEBO GYFU G
+    ('\u{16C1}', CharMapAction::SubChar('l')), // RUNIC LETTER ISAZ IS ISS I
+    ('\u{16C2}', CharMapAction::SubChar('\u{16BD}')), // RUNIC LETTER E
+    ('\u{16CC}', CharMapAction::SubChar('\'')), // RUNIC LETTER SHORT-TWIG-SOL S
+    ('\u{16D5}', CharMapAction::SubChar('K')), // RUNIC LETTER OPEN-P
+    ('\u{16D6}', CharMapAction::SubChar('M')), // RUNIC LETTER EHWAZ EH E
+    ('\u{16D8}', CharMapAction::SubChar('\u{03A8}')), // RUNIC LETTER LONG-BRANCH-MADR M
+    ('\u{16E1}', CharMapAction::SubChar('\u{16BC}')), // RUNIC LETTER IOR
+    ('\u{16EB}', CharMapAction::SubChar('\u{00B7}')), // RUNIC SINGLE PUNCTUATION
+    ('\u{16EC}', CharMapAction::SubChar(':')), // RUNIC MULTIPLE PUNCTUATION
+    ('\u{16ED}', CharMapAction::SubChar('+')), // RUNIC CROSS PUNCTUATION
+    ('\u{16F0}', CharMapAction::SubChar('\u{03A6}')), // RUNIC BELGTHOR SYMBOL
+    ('\u{1734}', CharMapAction::SubChar('\u{1715}')), // HANUNOO SIGN PAMUDPOD
+    ('\u{1735}', CharMapAction::SubChar('/')), // PHILIPPINE SINGLE PUNCTUATION
+    ('\u{17A3}', CharMapAction::SubChar('\u{17A2}')), // KHMER INDEPENDENT VOWEL QAQ
+    ('\u{17B7}', CharMapAction::SubChar('\u{0E34}')), // KHMER VOWEL SIGN I
+    ('\u{17B8}', CharMapAction::SubChar('\u{0E35}')), // KHMER VOWEL SIGN II
+    ('\u{17B9}', CharMapAction::SubChar('\u{0E36}')), // KHMER VOWEL SIGN Y
+    ('\u{17BA}', CharMapAction::SubChar('\u{0E37}')), // KHMER VOWEL SIGN YY
+    ('\u{17C6}', CharMapAction::SubChar('\u{030A}')), // KHMER SIGN NIKAHIT
+    ('\u{17CB}', CharMapAction::SubChar('\u{0E48}')), // KHMER SIGN BANTOC
+    ('\u{17D3}', CharMapAction::SubChar('\u{030A}')), // KHMER SIGN BATHAMASAT
+    ('\u{17D4}', CharMapAction::SubChar('\u{0E2F}')), // KHMER SIGN KHAN
+    ('\u{17D5}', CharMapAction::SubChar('\u{0E5A}')), // KHMER SIGN BARIYOOSAN
+    ('\u{17D9}', CharMapAction::SubChar('\u{0E4F}')), // KHMER SIGN PHNAEK MUAN
+    ('\u{17DA}', CharMapAction::SubChar('\u{0E5B}')), // KHMER SIGN KOOMUUT
+    ('\u{1803}', CharMapAction::SubChar(':')), // MONGOLIAN FULL STOP
+    ('\u{1809}', CharMapAction::SubChar(':')), // MONGOLIAN MANCHU FULL STOP
+    ('\u{1855}', CharMapAction::SubChar('\u{1835}')), // MONGOLIAN LETTER TODO YA
+    ('\u{1896}', CharMapAction::SubChar('\u{185C}')), // MONGOLIAN LETTER ALI GALI ZA
+    ('\u{18B3}', CharMapAction::SubStr("\u{00B7}\u{18B1}")), // CANADIAN SYLLABICS WAY
+    ('\u{18B6}', CharMapAction::SubStr("\u{00B7}\u{18B4}")), // CANADIAN SYLLABICS PWOY
+    ('\u{18B9}', CharMapAction::SubStr("\u{00B7}\u{18B8}")), // CANADIAN SYLLABICS KWAY
+    ('\u{18C2}', CharMapAction::SubStr("\u{00B7}\u{18C0}")), // CANADIAN SYLLABICS SHWOY
+    ('\u{18C6}', CharMapAction::SubStr("\u{00B7}\u{14C2}")), // CANADIAN SYLLABICS NWI
+    ('\u{18C7}', CharMapAction::SubStr("\u{14C2}\u{00B7}")), // CANADIAN SYLLABICS OJIBWAY NWI
+    ('\u{18C8}', CharMapAction::SubStr("\u{00B7}\u{14C3}")), // CANADIAN SYLLABICS NWII
+    ('\u{18C9}', CharMapAction::SubStr("\u{14C3}\u{00B7}")), // CANADIAN SYLLABICS OJIBWAY NWII
+    ('\u{18CA}', CharMapAction::SubStr("\u{00B7}\u{14C4}")), // CANADIAN SYLLABICS NWO
+    ('\u{18CB}', CharMapAction::SubStr("\u{14C4}\u{00B7}")), // CANADIAN SYLLABICS OJIBWAY NWO
+    ('\u{18CC}', CharMapAction::SubStr("\u{00B7}\u{14C5}")), // CANADIAN SYLLABICS NWOO
+    ('\u{18CD}', CharMapAction::SubStr("\u{14C5}\u{00B7}")), // CANADIAN SYLLABICS OJIBWAY NWOO
+    ('\u{18CE}', CharMapAction::SubStr("\u{00B7}\u{1543}")), // CANADIAN SYLLABICS RWEE
+    ('\u{18CF}', CharMapAction::SubStr("\u{00B7}\u{1546}")), // CANADIAN SYLLABICS RWI
+    ('\u{18D0}', CharMapAction::SubStr("\u{00B7}\u{1547}")), // CANADIAN SYLLABICS RWII
+    ('\u{18D1}', CharMapAction::SubStr("\u{00B7}\u{1548}")), // CANADIAN SYLLABICS RWO
+    ('\u{18D2}', CharMapAction::SubStr("\u{00B7}\u{1549}")), // CANADIAN SYLLABICS RWOO
+    ('\u{18D3}', CharMapAction::SubStr("\u{00B7}\u{154B}")), // CANADIAN SYLLABICS RWA
+    ('\u{18DB}', CharMapAction::SubChar('\u{18F5}')), // CANADIAN SYLLABICS OJIBWAY SH
+    ('\u{18DC}', CharMapAction::SubStr("\u{18DF}\u{141E}")), // CANADIAN SYLLABICS EASTERN W
+    ('\u{18DD}', CharMapAction::SubStr("\u{141E}\u{18DF}")), // CANADIAN SYLLABICS WESTERN W
+    ('\u{18E0}', CharMapAction::SubStr("\u{1543}\u{00B7}")), // CANADIAN SYLLABICS R-CREE RWE
+    ('\u{18E3}', CharMapAction::SubStr("\u{155E}\u{00B7}")), // CANADIAN SYLLABICS THWE
+    ('\u{18E4}', CharMapAction::SubStr("\u{1566}\u{00B7}")), // CANADIAN SYLLABICS THWA
+    ('\u{18E5}', CharMapAction::SubStr("\u{156B}\u{00B7}")), // CANADIAN SYLLABICS TTHWE
+    ('\u{18E8}', CharMapAction::SubStr("\u{1586}\u{00B7}")), // CANADIAN SYLLABICS TLHWE
+    ('\u{18EA}', CharMapAction::SubStr("\u{1597}\u{00B7}")), // CANADIAN SYLLABICS SAYISI SHWE
+    ('\u{18ED}', CharMapAction::SubStr("\u{0460}\u{00B7}")), // CANADIAN SYLLABICS CARRIER GWU
+    ('\u{18F0}', CharMapAction::SubStr("\u{15F4}\u{00B7}")), // CANADIAN SYLLABICS CARRIER GWA
+    ('\u{18F2}', CharMapAction::SubStr("\u{161B}\u{00B7}")), // CANADIAN SYLLABICS CARRIER JWA
+    ('\u{19D0}', CharMapAction::SubChar('\u{199E}')), // NEW TAI LUE DIGIT ZERO
+    ('\u{19D1}', CharMapAction::SubChar('\u{19B1}')), // NEW TAI LUE DIGIT ONE
+    ('\u{1A80}', CharMapAction::SubChar('\u{1A45}')), // TAI THAM HORA DIGIT ZERO
+    ('\u{1A90}', CharMapAction::SubChar('\u{1A45}')), // TAI THAM THAM DIGIT ZERO
+    ('\u{1AA9}', CharMapAction::SubStr("\u{1AA8}\u{1AA8}")), // TAI THAM SIGN KAANKUU
+    ('\u{1AAB}', CharMapAction::SubStr("\u{1AAA}\u{1AA8}")), // TAI THAM SIGN SATKAANKUU
+    ('\u{1AB4}', CharMapAction::SubChar('\u{06DB}')), // COMBINING TRIPLE DOT
+    ('\u{1AB7}', CharMapAction::SubChar('\u{0328}')), // COMBINING OPEN MARK BELOW
+    ('\u{1B52}', CharMapAction::SubChar('\u{1B0D}')), // BALINESE DIGIT TWO
+    ('\u{1B53}', CharMapAction::SubChar('\u{1B11}')), // BALINESE DIGIT THREE
+    ('\u{1B58}', CharMapAction::SubChar('\u{1B28}')), // BALINESE DIGIT EIGHT
+    ('\u{1B5C}', CharMapAction::SubChar('\u{1B50}')), // BALINESE WINDU
+    ('\u{1B5F}', CharMapAction::SubStr("\u{1B5E}\u{1B5E}")), // BALINESE CARIK PAREREN
+    ('\u{1C3C}', CharMapAction::SubStr("\u{1C3B}\u{1C3B}")), // LEPCHA PUNCTUATION NYET THYOOM TA-ROL
+    ('\u{1C7F}', CharMapAction::SubStr("\u{1C7E}\u{1C7E}")), // OL CHIKI PUNCTUATION DOUBLE MUCAAD
+    ('\u{1CD0}', CharMapAction::SubChar('\u{0302}')), // VEDIC TONE KARSHANA
+    ('\u{1CD2}', CharMapAction::SubChar('\u{0304}')), // VEDIC TONE PRENKHA
+    ('\u{1CD3}', CharMapAction::SubStr("''")), // VEDIC SIGN NIHSHVASA
+    ('\u{1CD5}', CharMapAction::SubChar('\u{032B}')), // VEDIC TONE YAJURVEDIC AGGRAVATED INDEPENDENT SVARITA
+    ('\u{1CD8}', CharMapAction::SubChar('\u{032E}')), // VEDIC TONE CANDRA BELOW
+    ('\u{1CD9}', CharMapAction::SubChar('\u{032D}')), // VEDIC TONE YAJURVEDIC KATHAKA INDEPENDENT SVARITA SCHROEDER
+    ('\u{1CDA}', CharMapAction::SubChar('\u{030E}')), // VEDIC TONE DOUBLE SVARITA
+    ('\u{1CDC}', CharMapAction::SubChar('\u{0329}')), // VEDIC TONE KATHAKA ANUDATTA
+    ('\u{1CDD}', CharMapAction::SubChar('\u{0323}')), // VEDIC TONE DOT BELOW
+    ('\u{1CDE}', CharMapAction::SubChar('\u{0324}')), // VEDIC TONE TWO DOTS BELOW
+    ('\u{1CED}', CharMapAction::SubChar('\u{0316}')), // VEDIC SIGN TIRYAK
+    ('\u{1D04}', CharMapAction::SubChar('c')), // LATIN LETTER SMALL CAPITAL C
+    ('\u{1D08}', CharMapAction::SubChar('\u{025C}')), // LATIN SMALL LETTER TURNED OPEN E
+    ('\u{1D0B}', CharMapAction::SubChar('\u{0138}')), // LATIN LETTER SMALL CAPITAL K
+    ('\u{1D0D}', CharMapAction::SubChar('\u{028D}')), // LATIN LETTER SMALL CAPITAL M
+    ('\u{1D0F}', CharMapAction::SubChar('o')), // LATIN LETTER SMALL CAPITAL O
+    ('\u{1D10}', CharMapAction::SubChar('\u{0254}')), // LATIN LETTER SMALL CAPITAL OPEN O
+    ('\u{1D11}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER SIDEWAYS O
+    ('\u{1D14}', CharMapAction::SubStr("\u{01DD}o")), // LATIN SMALL LETTER TURNED OE
+    ('\u{1D1C}', CharMapAction::SubChar('u')), // LATIN LETTER SMALL CAPITAL U
+    ('\u{1D20}', CharMapAction::SubChar('v')), // LATIN LETTER SMALL CAPITAL V
+    ('\u{1D21}', CharMapAction::SubChar('w')), // LATIN LETTER SMALL CAPITAL W
+    ('\u{1D22}', CharMapAction::SubChar('z')), // LATIN LETTER SMALL CAPITAL Z
+    ('\u{1D24}', CharMapAction::SubChar('\u{01A8}')), // LATIN LETTER VOICED LARYNGEAL SPIRANT
+    ('\u{1D26}', CharMapAction::SubChar('r')), // GREEK LETTER SMALL CAPITAL GAMMA
+    ('\u{1D27}', CharMapAction::SubChar('\u{028C}')), // GREEK LETTER SMALL CAPITAL LAMDA
+    ('\u{1D28}', CharMapAction::SubChar('\u{03C0}')), // GREEK LETTER SMALL CAPITAL PI
+    ('\u{1D29}', CharMapAction::SubChar('\u{1D18}')), // GREEK LETTER SMALL CAPITAL RHO
+    ('\u{1D2B}', CharMapAction::SubChar('\u{043B}')), // CYRILLIC LETTER SMALL CAPITAL EL
+    ('\u{1D3E}', CharMapAction::SubChar('\u{18D6}')), // MODIFIER LETTER CAPITAL P
+    ('\u{1D52}', CharMapAction::SubChar('\u{00BA}')), // MODIFIER LETTER SMALL O
+    ('\u{1D6B}', CharMapAction::SubStr("ue")), // LATIN SMALL LETTER UE
+    ('\u{1D6E}', CharMapAction::SubStr("f\u{0334}")), // LATIN SMALL LETTER F WITH MIDDLE TILDE
+    ('\u{1D6F}', CharMapAction::SubStr("rn\u{0334}")), // LATIN SMALL LETTER M WITH MIDDLE TILDE
+    ('\u{1D70}', CharMapAction::SubStr("n\u{0334}")), // LATIN SMALL LETTER N WITH MIDDLE TILDE
+    ('\u{1D72}', CharMapAction::SubStr("r\u{0334}")), // LATIN SMALL LETTER R WITH MIDDLE TILDE
+    ('\u{1D73}', CharMapAction::SubStr("\u{027E}\u{0334}")), // LATIN SMALL LETTER R WITH FISHHOOK AND MIDDLE TILDE
+    ('\u{1D74}', CharMapAction::SubStr("s\u{0334}")), // LATIN SMALL LETTER S WITH MIDDLE TILDE
+    ('\u{1D75}', CharMapAction::SubStr("t\u{0334}")), // LATIN SMALL LETTER T WITH MIDDLE TILDE
+    ('\u{1D76}', CharMapAction::SubStr("z\u{0334}")), // LATIN SMALL LETTER Z WITH MIDDLE TILDE
+    ('\u{1D78}', CharMapAction::SubChar('\u{1D34}')), // MODIFIER LETTER CYRILLIC EN
+    ('\u{1D7B}', CharMapAction::SubStr("i\u{0335}")), // LATIN SMALL CAPITAL LETTER I WITH STROKE
+    ('\u{1D7C}', CharMapAction::SubStr("i\u{0335}")), // LATIN SMALL LETTER IOTA WITH STROKE
+    ('\u{1D7D}', CharMapAction::SubStr("p\u{0335}")), // LATIN SMALL LETTER P WITH STROKE
+    ('\u{1D7E}', CharMapAction::SubStr("u\u{0335}")), // LATIN SMALL CAPITAL LETTER U WITH STROKE
+    ('\u{1D7F}', CharMapAction::SubStr("\u{028A}\u{0335}")), // LATIN SMALL LETTER UPSILON WITH STROKE
+    ('\u{1D83}', CharMapAction::SubChar('g')), // LATIN SMALL LETTER G WITH PALATAL HOOK
+    ('\u{1D8C}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER V WITH PALATAL HOOK
+    ('\u{1D90}', CharMapAction::SubChar('\u{024B}')), // LATIN SMALL LETTER ALPHA WITH RETROFLEX HOOK
+    ('\u{1D9F}', CharMapAction::SubChar('\u{1D4B}')), // MODIFIER LETTER SMALL REVERSED OPEN E
+    ('\u{1DA2}', CharMapAction::SubChar('\u{1D4D}')), // MODIFIER LETTER SMALL SCRIPT G
+    ('\u{1DBA}', CharMapAction::SubChar('\u{18D4}')), // MODIFIER LETTER SMALL TURNED V
+    ('\u{1DBB}', CharMapAction::SubChar('\u{1646}')), // MODIFIER LETTER SMALL Z
+    ('\u{1DEE}', CharMapAction::SubChar('\u{2DEC}')), // COMBINING LATIN SMALL LETTER P
+    ('\u{1E43}', CharMapAction::SubChar('\u{AB51}')), // LATIN SMALL LETTER M WITH DOT BELOW
+    ('\u{1E9A}', CharMapAction::SubChar('\u{1EA3}')), // LATIN SMALL LETTER A WITH RIGHT HALF RING
+    ('\u{1E9D}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER LONG S WITH HIGH STROKE
+    ('\u{1E9E}', CharMapAction::SubChar('\u{00DF}')), // LATIN CAPITAL LETTER SHARP S
+    ('\u{1EFF}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH LOOP
+    ('\u{1F7D}', CharMapAction::SubChar('\u{1FF4}')), // GREEK SMALL LETTER OMEGA WITH OXIA
+    ('\u{1FBD}', CharMapAction::SubChar('\'')), // GREEK KORONIS
+    ('\u{1FBE}', CharMapAction::SubChar('i')), // GREEK PROSGEGRAMMENI
+    ('\u{1FBF}', CharMapAction::SubChar('\'')), // GREEK PSILI
+    ('\u{1FC0}', CharMapAction::SubChar('~')), // GREEK PERISPOMENI
+    ('\u{1FEF}', CharMapAction::SubChar('\'')), // GREEK VARIA
+    ('\u{1FF6}', CharMapAction::SubChar('\u{13EF}')), // GREEK SMALL LETTER OMEGA WITH PERISPOMENI
+    ('\u{1FFD}', CharMapAction::SubChar('\'')), // GREEK OXIA
+    ('\u{1FFE}', CharMapAction::SubChar('\'')), // GREEK DASIA
+    ('\u{2000}', CharMapAction::SubChar(' ')), // EN QUAD
+    ('\u{2001}', CharMapAction::SubChar(' ')), // EM QUAD
+    ('\u{2002}', CharMapAction::SubChar(' ')), // EN SPACE
+    ('\u{2003}', CharMapAction::SubChar(' ')), // EM SPACE
+    ('\u{2004}', CharMapAction::SubChar(' ')), // THREE-PER-EM SPACE
+    ('\u{2005}', CharMapAction::SubChar(' ')), // FOUR-PER-EM SPACE
+    ('\u{2006}', CharMapAction::SubChar(' ')), // SIX-PER-EM SPACE
+    ('\u{2007}', CharMapAction::SubChar(' ')), // FIGURE SPACE
+    ('\u{2008}', CharMapAction::SubChar(' ')), // PUNCTUATION SPACE
+    ('\u{2009}', CharMapAction::SubChar(' ')), // THIN SPACE
+    ('\u{200A}', CharMapAction::SubChar(' ')), // HAIR SPACE
+    ('\u{2010}', CharMapAction::SubChar('-')), // HYPHEN
+    ('\u{2011}', CharMapAction::SubChar('-')), // NON-BREAKING HYPHEN
+    ('\u{2012}', CharMapAction::SubChar('-')), // FIGURE DASH
+    ('\u{2013}', CharMapAction::SubChar('-')), // EN DASH
+    ('\u{2014}', CharMapAction::SubChar('\u{30FC}')), // EM DASH
+    ('\u{2015}', CharMapAction::SubChar('\u{30FC}')), // HORIZONTAL BAR
+    ('\u{2016}', CharMapAction::SubStr("ll")), // DOUBLE VERTICAL LINE
+    ('\u{2018}', CharMapAction::SubChar('\'')), // LEFT SINGLE QUOTATION MARK
+    ('\u{2019}', CharMapAction::SubChar('\'')), // RIGHT SINGLE QUOTATION MARK
+    ('\u{201A}', CharMapAction::SubChar(',')), // SINGLE LOW-9 QUOTATION MARK
+    ('\u{201B}', CharMapAction::SubChar('\'')), // SINGLE HIGH-REVERSED-9 QUOTATION MARK
+    ('\u{201C}', CharMapAction::SubStr("''")), // LEFT DOUBLE QUOTATION MARK
+    ('\u{201D}', CharMapAction::SubStr("''")), // RIGHT DOUBLE QUOTATION MARK
+    ('\u{201F}', CharMapAction::SubStr("''")), // DOUBLE HIGH-REVERSED-9 QUOTATION MARK
+    ('\u{2022}', CharMapAction::SubChar('\u{00B7}')), // BULLET
+    ('\u{2024}', CharMapAction::SubChar('.')), // ONE DOT LEADER
+    ('\u{2025}', CharMapAction::SubStr("..")), // TWO DOT LEADER
+    ('\u{2026}', CharMapAction::SubStr("...")), // HORIZONTAL ELLIPSIS
+    ('\u{2027}', CharMapAction::SubChar('\u{00B7}')), // HYPHENATION POINT
+    ('\u{2028}', CharMapAction::SubChar(' ')), // LINE SEPARATOR
+    ('\u{2029}', CharMapAction::SubChar(' ')), // PARAGRAPH SEPARATOR
+    ('\u{202F}', CharMapAction::SubChar(' ')), // NARROW NO-BREAK SPACE
+    ('\u{2030}', CharMapAction::SubStr("\u{00BA}/\u{2080}\u{2080}")), // PER MILLE SIGN
+    ('\u{2031}', CharMapAction::SubStr("\u{00BA}/\u{2080}\u{2080}\u{2080}")), // PER TEN THOUSAND SIGN
+    ('\u{2032}', CharMapAction::SubChar('\'')), // PRIME
+    ('\u{2033}', CharMapAction::SubStr("''")), // DOUBLE PRIME
+    ('\u{2034}', CharMapAction::SubStr("'''")), // TRIPLE PRIME
+    ('\u{2035}', CharMapAction::SubChar('\'')), // REVERSED PRIME
+    ('\u{2036}', CharMapAction::SubStr("''")), // REVERSED DOUBLE PRIME
+    ('\u{2037}', CharMapAction::SubStr("'''")), // REVERSED TRIPLE PRIME
+    ('\u{2039}', CharMapAction::SubChar('<')), // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+    ('\u{203A}', CharMapAction::SubChar('>')), // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+    ('\u{203C}', CharMapAction::SubStr("!!")), // DOUBLE EXCLAMATION MARK
+    ('\u{203E}', CharMapAction::SubChar('\u{02C9}')), // OVERLINE
+    ('\u{2041}', CharMapAction::SubChar('/')), // CARET INSERTION POINT
+    ('\u{2043}', CharMapAction::SubChar('-')), // HYPHEN BULLET
+    ('\u{2044}', CharMapAction::SubChar('/')), // FRACTION SLASH
+    ('\u{2047}', CharMapAction::SubStr("??")), // DOUBLE QUESTION MARK
+    ('\u{2048}', CharMapAction::SubStr("?!")), // QUESTION EXCLAMATION MARK
+    ('\u{2049}', CharMapAction::SubStr("!?")), // EXCLAMATION QUESTION MARK
+    ('\u{204E}', CharMapAction::SubChar('*')), // LOW ASTERISK
+    ('\u{2052}', CharMapAction::SubStr("\u{00BA}/\u{2080}")), // COMMERCIAL MINUS SIGN
+    ('\u{2053}', CharMapAction::SubChar('~')), // SWUNG DASH
+    ('\u{2057}', CharMapAction::SubStr("''''")), // QUADRUPLE PRIME
+    ('\u{205A}', CharMapAction::SubChar(':')), // TWO DOT PUNCTUATION
+    ('\u{205D}', CharMapAction::SubChar('\u{2D57}')), // TRICOLON
+    ('\u{205E}', CharMapAction::SubChar('\u{2D42}')), // VERTICAL FOUR DOTS
+    ('\u{205F}', CharMapAction::SubChar(' ')), // MEDIUM MATHEMATICAL SPACE
+    ('\u{2070}', CharMapAction::SubChar('\u{00BA}')), // SUPERSCRIPT ZERO
+    ('\u{2079}', CharMapAction::SubChar('\u{A770}')), // SUPERSCRIPT NINE
+    ('\u{20A1}', CharMapAction::SubStr("C\u{20EB}")), // COLON SIGN
+    ('\u{20A4}', CharMapAction::SubChar('\u{00A3}')), // LIRA SIGN
+    ('\u{20A5}', CharMapAction::SubStr("rn\u{0338}")), // MILL SIGN
+    ('\u{20A8}', CharMapAction::SubStr("Rs")), // RUPEE SIGN
+    ('\u{20A9}', CharMapAction::SubStr("W\u{0335}")), // WON SIGN
+    ('\u{20AB}', CharMapAction::SubStr("d\u{0335}\u{0331}")), // DONG SIGN
+    ('\u{20AC}', CharMapAction::SubChar('\u{A792}')), // EURO SIGN
+    ('\u{20AD}', CharMapAction::SubStr("K\u{0335}")), // KIP SIGN
+    ('\u{20AE}', CharMapAction::SubStr("T\u{20EB}")), // TUGRIK SIGN
+    ('\u{20B6}', CharMapAction::SubStr("lt")), // LIVRE TOURNOIS SIGN
+    ('\u{20BD}', CharMapAction::SubChar('\u{0554}')), // RUBLE SIGN
+    ('\u{20DB}', CharMapAction::SubChar('\u{06DB}')), // COMBINING THREE DOTS ABOVE
+    ('\u{2100}', CharMapAction::SubStr("a/c")), // ACCOUNT OF
+    ('\u{2101}', CharMapAction::SubStr("a/s")), // ADDRESSED TO THE SUBJECT
+    ('\u{2102}', CharMapAction::SubChar('C')), // DOUBLE-STRUCK CAPITAL C
+    ('\u{2103}', CharMapAction::SubStr("\u{00B0}C")), // DEGREE CELSIUS
+    ('\u{2105}', CharMapAction::SubStr("c/o")), // CARE OF
+    ('\u{2106}', CharMapAction::SubStr("c/u")), // CADA UNA
+    ('\u{2107}', CharMapAction::SubChar('\u{0190}')), // EULER CONSTANT
+    ('\u{2108}', CharMapAction::SubChar('\u{042D}')), // SCRUPLE
+    ('\u{2109}', CharMapAction::SubStr("\u{00B0}F")), // DEGREE FAHRENHEIT
+    ('\u{210A}', CharMapAction::SubChar('g')), // SCRIPT SMALL G
+    ('\u{210B}', CharMapAction::SubChar('H')), // SCRIPT CAPITAL H
+    ('\u{210C}', CharMapAction::SubChar('H')), // BLACK-LETTER CAPITAL H
+    ('\u{210D}', CharMapAction::SubChar('H')), // DOUBLE-STRUCK CAPITAL H
+    ('\u{210E}', CharMapAction::SubChar('h')), // PLANCK CONSTANT
+    ('\u{210F}', CharMapAction::SubStr("h\u{0335}")), // PLANCK CONSTANT OVER TWO PI
+    ('\u{2110}', CharMapAction::SubChar('l')), // SCRIPT CAPITAL I
+    ('\u{2111}', CharMapAction::SubChar('l')), // BLACK-LETTER CAPITAL I
+    ('\u{2112}', CharMapAction::SubChar('L')), // SCRIPT CAPITAL L
+    ('\u{2113}', CharMapAction::SubChar('l')), // SCRIPT SMALL L
+    ('\u{2115}', CharMapAction::SubChar('N')), // DOUBLE-STRUCK CAPITAL N
+    ('\u{2116}', CharMapAction::SubStr("No")), // NUMERO SIGN
+    ('\u{2119}', CharMapAction::SubChar('P')), // DOUBLE-STRUCK CAPITAL P
+    ('\u{211A}', CharMapAction::SubChar('Q')), // DOUBLE-STRUCK CAPITAL Q
+    ('\u{211B}', CharMapAction::SubChar('R')), // SCRIPT CAPITAL R
+    ('\u{211C}', CharMapAction::SubChar('R')), // BLACK-LETTER CAPITAL R
+    ('\u{211D}', CharMapAction::SubChar('R')), // DOUBLE-STRUCK CAPITAL R
+    ('\u{2121}', CharMapAction::SubStr("TEL")), // TELEPHONE SIGN
+    ('\u{2124}', CharMapAction::SubChar('Z')), // DOUBLE-STRUCK CAPITAL Z
+    ('\u{2126}', CharMapAction::SubChar('\u{03A9}')), // OHM SIGN
+    ('\u{2127}', CharMapAction::SubChar('\u{01B1}')), // INVERTED OHM SIGN
+    ('\u{2128}', CharMapAction::SubChar('Z')), // BLACK-LETTER CAPITAL Z
+    ('\u{2129}', CharMapAction::SubChar('\u{027F}')), // TURNED GREEK SMALL LETTER IOTA
+    ('\u{212A}', CharMapAction::SubChar('K')), // KELVIN SIGN
+    ('\u{212C}', CharMapAction::SubChar('B')), // SCRIPT CAPITAL B
+    ('\u{212D}', CharMapAction::SubChar('C')), // BLACK-LETTER CAPITAL C
+    ('\u{212E}', CharMapAction::SubChar('e')), // ESTIMATED SYMBOL
+    ('\u{212F}', CharMapAction::SubChar('e')), // SCRIPT SMALL E
+    ('\u{2130}', CharMapAction::SubChar('E')), // SCRIPT CAPITAL E
+    ('\u{2131}', CharMapAction::SubChar('F')), // SCRIPT CAPITAL F
+    ('\u{2133}', CharMapAction::SubChar('M')), // SCRIPT CAPITAL M
+    ('\u{2134}', CharMapAction::SubChar('o')), // SCRIPT SMALL O
+    ('\u{2135}', CharMapAction::SubChar('\u{05D0}')), // ALEF SYMBOL
+    ('\u{2136}', CharMapAction::SubChar('\u{05D1}')), // BET SYMBOL
+    ('\u{2137}', CharMapAction::SubChar('\u{05D2}')), // GIMEL SYMBOL
+    ('\u{2138}', CharMapAction::SubChar('\u{05D3}')), // DALET SYMBOL
+    ('\u{2139}', CharMapAction::SubChar('i')), // INFORMATION SOURCE
+    ('\u{213B}', CharMapAction::SubStr("FAX")), // FACSIMILE SIGN
+    ('\u{213C}', CharMapAction::SubChar('\u{03C0}')), // DOUBLE-STRUCK SMALL PI
+    ('\u{213D}', CharMapAction::SubChar('y')), // DOUBLE-STRUCK SMALL GAMMA
+    ('\u{213E}', CharMapAction::SubChar('\u{0393}')), // DOUBLE-STRUCK CAPITAL GAMMA
+    ('\u{213F}', CharMapAction::SubChar('\u{03A0}')), // DOUBLE-STRUCK CAPITAL PI
+    ('\u{2140}', CharMapAction::SubChar('\u{01A9}')), // DOUBLE-STRUCK N-ARY SUMMATION
+    ('\u{2141}', CharMapAction::SubChar('\u{A4E8}')), // TURNED SANS-SERIF CAPITAL G
+    ('\u{2142}', CharMapAction::SubChar('\u{A4F6}')), // TURNED SANS-SERIF CAPITAL L
+    ('\u{2143}', CharMapAction::SubChar('\u{16F00}')), // REVERSED SANS-SERIF CAPITAL L
+    ('\u{2145}', CharMapAction::SubChar('D')), // DOUBLE-STRUCK ITALIC CAPITAL D
+    ('\u{2146}', CharMapAction::SubChar('d')), // DOUBLE-STRUCK ITALIC SMALL D
+    ('\u{2147}', CharMapAction::SubChar('e')), // DOUBLE-STRUCK ITALIC SMALL E
+    ('\u{2148}', CharMapAction::SubChar('i')), // DOUBLE-STRUCK ITALIC SMALL I
+    ('\u{2149}', CharMapAction::SubChar('j')), // DOUBLE-STRUCK ITALIC SMALL J
+    ('\u{2160}', CharMapAction::SubChar('l')), // ROMAN NUMERAL ONE
+    ('\u{2161}', CharMapAction::SubStr("ll")), // ROMAN NUMERAL TWO
+    ('\u{2162}', CharMapAction::SubStr("lll")), // ROMAN NUMERAL THREE
+    ('\u{2163}', CharMapAction::SubStr("lV")), // ROMAN NUMERAL FOUR
+    ('\u{2164}', CharMapAction::SubChar('V')), // ROMAN NUMERAL FIVE
+    ('\u{2165}', CharMapAction::SubStr("Vl")), // ROMAN NUMERAL SIX
+    ('\u{2166}', CharMapAction::SubStr("Vll")), // ROMAN NUMERAL SEVEN
+    ('\u{2167}', CharMapAction::SubStr("Vlll")), // ROMAN NUMERAL EIGHT
+    ('\u{2168}', CharMapAction::SubStr("lX")), // ROMAN NUMERAL NINE
+    ('\u{2169}', CharMapAction::SubChar('X')), // ROMAN NUMERAL TEN
+    ('\u{216A}', CharMapAction::SubStr("Xl")), // ROMAN NUMERAL ELEVEN
+    ('\u{216B}', CharMapAction::SubStr("Xll")), // ROMAN NUMERAL TWELVE
+    ('\u{216C}', CharMapAction::SubChar('L')), // ROMAN NUMERAL FIFTY
+    ('\u{216D}', CharMapAction::SubChar('C')), // ROMAN NUMERAL ONE HUNDRED
+    ('\u{216E}', CharMapAction::SubChar('D')), // ROMAN NUMERAL FIVE HUNDRED
+    ('\u{216F}', CharMapAction::SubChar('M')), // ROMAN NUMERAL ONE THOUSAND
+    ('\u{2170}', CharMapAction::SubChar('i')), // SMALL ROMAN NUMERAL ONE
+    ('\u{2171}', CharMapAction::SubStr("ii")), // SMALL ROMAN NUMERAL TWO
+    ('\u{2172}', CharMapAction::SubStr("iii")), // SMALL ROMAN NUMERAL THREE
+    ('\u{2173}', CharMapAction::SubStr("iv")), // SMALL ROMAN NUMERAL FOUR
+    ('\u{2174}', CharMapAction::SubChar('v')), // SMALL ROMAN NUMERAL FIVE
+    ('\u{2175}', CharMapAction::SubStr("vi")), // SMALL ROMAN NUMERAL SIX
+    ('\u{2176}', CharMapAction::SubStr("vii")), // SMALL ROMAN NUMERAL SEVEN
+    ('\u{2177}', CharMapAction::SubStr("viii")), // SMALL ROMAN NUMERAL EIGHT
+    ('\u{2178}', CharMapAction::SubStr("ix")), // SMALL ROMAN NUMERAL NINE
+    ('\u{2179}', CharMapAction::SubChar('x')), // SMALL ROMAN NUMERAL TEN
+    ('\u{217A}', CharMapAction::SubStr("xi")), // SMALL ROMAN NUMERAL ELEVEN
+    ('\u{217B}', CharMapAction::SubStr("xii")), // SMALL ROMAN NUMERAL TWELVE
+    ('\u{217C}', CharMapAction::SubChar('l')), // SMALL ROMAN NUMERAL FIFTY
+    ('\u{217D}', CharMapAction::SubChar('c')), // SMALL ROMAN NUMERAL ONE HUNDRED
+    ('\u{217E}', CharMapAction::SubChar('d')), // SMALL ROMAN NUMERAL FIVE HUNDRED
+    ('\u{217F}', CharMapAction::SubStr("rn")), // SMALL ROMAN NUMERAL ONE THOUSAND
+    ('\u{2183}', CharMapAction::SubChar('\u{0186}')), // ROMAN NUMERAL REVERSED ONE HUNDRED
+    ('\u{2184}', CharMapAction::SubChar('\u{0254}')), // LATIN SMALL LETTER REVERSED C
+    ('\u{2191}', CharMapAction::SubChar('\u{16CF}')), // UPWARDS ARROW
+    ('\u{2195}', CharMapAction::SubChar('\u{16E8}')), // UP DOWN ARROW
+    ('\u{21B5}', CharMapAction::SubChar('\u{21B2}')), // DOWNWARDS ARROW WITH CORNER LEFTWARDS
+    ('\u{21BA}', CharMapAction::SubChar('\u{1F10E}')), // ANTICLOCKWISE OPEN CIRCLE ARROW
+    ('\u{21BE}', CharMapAction::SubChar('\u{16DA}')), // UPWARDS HARPOON WITH BARB RIGHTWARDS
+    ('\u{21BF}', CharMapAction::SubChar('\u{16D0}')), // UPWARDS HARPOON WITH BARB LEFTWARDS
+    ('\u{2200}', CharMapAction::SubChar('\u{2C6F}')), // FOR ALL
+    ('\u{2203}', CharMapAction::SubChar('\u{018E}')), // THERE EXISTS
+    ('\u{2206}', CharMapAction::SubChar('\u{0394}')), // INCREMENT
+    ('\u{220F}', CharMapAction::SubChar('\u{03A0}')), // N-ARY PRODUCT
+    ('\u{2211}', CharMapAction::SubChar('\u{01A9}')), // N-ARY SUMMATION
+    ('\u{2212}', CharMapAction::SubChar('-')), // MINUS SIGN
+    ('\u{2214}', CharMapAction::SubStr("+\u{0307}")), // DOT PLUS
+    ('\u{2215}', CharMapAction::SubChar('/')), // DIVISION SLASH
+    ('\u{2216}', CharMapAction::SubChar('\\')), // SET MINUS
+    ('\u{2217}', CharMapAction::SubChar('*')), // ASTERISK OPERATOR
+    ('\u{2218}', CharMapAction::SubChar('\u{00B0}')), // RING OPERATOR
+    ('\u{2219}', CharMapAction::SubChar('\u{00B7}')), // BULLET OPERATOR
+    ('\u{221E}', CharMapAction::SubStr("oo")), // INFINITY
+    ('\u{2223}', CharMapAction::SubChar('l')), // DIVIDES
+    ('\u{2225}', CharMapAction::SubStr("ll")), // PARALLEL TO
+    ('\u{2228}', CharMapAction::SubChar('v')), // LOGICAL OR
+    ('\u{2229}', CharMapAction::SubChar('\u{0548}')), // INTERSECTION
+    ('\u{222A}', CharMapAction::SubChar('U')), // UNION
+    ('\u{222B}', CharMapAction::SubChar('\u{0283}')), // INTEGRAL
+    ('\u{222C}', CharMapAction::SubStr("\u{0283}\u{0283}")), // DOUBLE INTEGRAL
+    ('\u{222D}', CharMapAction::SubStr("\u{0283}\u{0283}\u{0283}")), // TRIPLE INTEGRAL
+    ('\u{222F}', CharMapAction::SubStr("\u{222E}\u{222E}")), // SURFACE INTEGRAL
+    ('\u{2230}', CharMapAction::SubStr("\u{222E}\u{222E}\u{222E}")), // VOLUME INTEGRAL
+    ('\u{2236}', CharMapAction::SubChar(':')), // RATIO
+    ('\u{2238}', CharMapAction::SubStr("-\u{0307}")), // DOT MINUS
+    ('\u{223C}', CharMapAction::SubChar('~')), // TILDE OPERATOR
+    ('\u{2250}', CharMapAction::SubStr("=\u{0307}")), // APPROACHES THE LIMIT
+    ('\u{2251}', CharMapAction::SubStr("=\u{0307}\u{0323}")), // GEOMETRICALLY EQUAL TO
+    ('\u{2257}', CharMapAction::SubStr("=\u{030A}")), // RING EQUAL TO
+    ('\u{2259}', CharMapAction::SubStr("=\u{0302}")), // ESTIMATES
+    ('\u{225A}', CharMapAction::SubStr("=\u{0306}")), // EQUIANGULAR TO
+    ('\u{225E}', CharMapAction::SubStr("=\u{036B}")), // MEASURED BY
+    ('\u{2263}', CharMapAction::SubChar('\u{2261}')), // STRICTLY EQUIVALENT TO
+    ('\u{226A}', CharMapAction::SubStr("<<")), // MUCH LESS-THAN
+    ('\u{226B}', CharMapAction::SubStr(">>")), // MUCH GREATER-THAN
+    ('\u{2282}', CharMapAction::SubChar('\u{1455}')), // SUBSET OF
+    ('\u{2283}', CharMapAction::SubChar('\u{1450}')), // SUPERSET OF
+    ('\u{2295}', CharMapAction::SubChar('\u{102A8}')), // CIRCLED PLUS
+    ('\u{2296}', CharMapAction::SubStr("O\u{0335}")), // CIRCLED MINUS
+    ('\u{2299}', CharMapAction::SubChar('\u{0298}')), // CIRCLED DOT OPERATOR
+    ('\u{229D}', CharMapAction::SubStr("O\u{0335}")), // CIRCLED DASH
+    ('\u{22A4}', CharMapAction::SubChar('T')), // DOWN TACK
+    ('\u{22A5}', CharMapAction::SubChar('\u{A4D5}')), // UP TACK
+    ('\u{22C0}', CharMapAction::SubChar('\u{2227}')), // N-ARY LOGICAL AND
+    ('\u{22C1}', CharMapAction::SubChar('v')), // N-ARY LOGICAL OR
+    ('\u{22C2}', CharMapAction::SubChar('\u{0548}')), // N-ARY INTERSECTION
+    ('\u{22C3}', CharMapAction::SubChar('U')), // N-ARY UNION
+    ('\u{22C4}', CharMapAction::SubChar('\u{16DC}')), // DIAMOND OPERATOR
+    ('\u{22C5}', CharMapAction::SubChar('\u{00B7}')), // DOT OPERATOR
+    ('\u{22C8}', CharMapAction::SubChar('\u{16DE}')), // BOWTIE
+    ('\u{22D6}', CharMapAction::SubStr("<\u{00B7}")), // LESS-THAN WITH DOT
+    ('\u{22D7}', CharMapAction::SubStr("\u{00B7}>")), // GREATER-THAN WITH DOT
+    ('\u{22D8}', CharMapAction::SubStr("<<<")), // VERY MUCH LESS-THAN
+    ('\u{22D9}', CharMapAction::SubStr(">>>")), // VERY MUCH GREATER-THAN
+    ('\u{22EE}', CharMapAction::SubChar('\u{2D57}')), // VERTICAL ELLIPSIS
+    ('\u{22EF}', CharMapAction::SubStr("\u{00B7}\u{00B7}\u{00B7}")), // MIDLINE HORIZONTAL ELLIPSIS
+    ('\u{22F4}', CharMapAction::SubChar('\u{A793}')), // SMALL ELEMENT OF WITH VERTICAL BAR AT END OF HORIZONTAL STROKE
+    ('\u{22FF}', CharMapAction::SubChar('E')), // Z NOTATION BAG MEMBERSHIP
+    ('\u{2300}', CharMapAction::SubChar('\u{2205}')), // DIAMETER SIGN
+    ('\u{2325}', CharMapAction::SubChar('\u{2324}')), // OPTION KEY
+    ('\u{2329}', CharMapAction::SubChar('\u{276C}')), // LEFT-POINTING ANGLE BRACKET
+    ('\u{232A}', CharMapAction::SubChar('\u{276D}')), // RIGHT-POINTING ANGLE BRACKET
+    ('\u{2341}', CharMapAction::SubChar('\u{303C}')), // APL FUNCTIONAL SYMBOL QUAD SLASH
+    ('\u{2359}', CharMapAction::SubStr("\u{0394}\u{0332}")), // APL FUNCTIONAL SYMBOL DELTA UNDERBAR
+    ('\u{235A}', CharMapAction::SubStr("\u{16DC}\u{0332}")), // APL FUNCTIONAL SYMBOL DIAMOND UNDERBAR
+    ('\u{235C}', CharMapAction::SubStr("\u{00B0}\u{0332}")), // APL FUNCTIONAL SYMBOL CIRCLE UNDERBAR
+    ('\u{235F}', CharMapAction::SubChar('\u{229B}')), // APL FUNCTIONAL SYMBOL CIRCLE STAR
+    ('\u{2361}', CharMapAction::SubStr("T\u{0308}")), // APL FUNCTIONAL SYMBOL UP TACK DIAERESIS
+    ('\u{2362}', CharMapAction::SubStr("\u{2207}\u{0308}")), // APL FUNCTIONAL SYMBOL DEL DIAERESIS
+    ('\u{2363}', CharMapAction::SubStr("\u{22C6}\u{0308}")), // APL FUNCTIONAL SYMBOL STAR DIAERESIS
+    ('\u{2364}', CharMapAction::SubStr("\u{00B0}\u{0308}")), // APL FUNCTIONAL SYMBOL JOT DIAERESIS
+    ('\u{2365}', CharMapAction::SubChar('\u{0629}')), // APL FUNCTIONAL SYMBOL CIRCLE DIAERESIS
+    ('\u{2368}', CharMapAction::SubStr("~\u{0308}")), // APL FUNCTIONAL SYMBOL TILDE DIAERESIS
+    ('\u{2369}', CharMapAction::SubChar('\u{1435}')), // APL FUNCTIONAL SYMBOL GREATER-THAN DIAERESIS
+    ('\u{236B}', CharMapAction::SubStr("\u{2207}\u{0334}")), // APL FUNCTIONAL SYMBOL DEL TILDE
+    ('\u{236C}', CharMapAction::SubStr("O\u{0335}")), // APL FUNCTIONAL SYMBOL ZILDE
+    ('\u{2373}', CharMapAction::SubChar('i')), // APL FUNCTIONAL SYMBOL IOTA
+    ('\u{2374}', CharMapAction::SubChar('p')), // APL FUNCTIONAL SYMBOL RHO
+    ('\u{2375}', CharMapAction::SubChar('\u{03C9}')), // APL FUNCTIONAL SYMBOL OMEGA
+    ('\u{2376}', CharMapAction::SubStr("a\u{0332}")), // APL FUNCTIONAL SYMBOL ALPHA UNDERBAR
+    ('\u{2377}', CharMapAction::SubStr("\u{A793}\u{0332}")), // APL FUNCTIONAL SYMBOL EPSILON UNDERBAR
+    ('\u{2378}', CharMapAction::SubStr("i\u{0332}")), // APL FUNCTIONAL SYMBOL IOTA UNDERBAR
+    ('\u{2379}', CharMapAction::SubStr("\u{03C9}\u{0332}")), // APL FUNCTIONAL SYMBOL OMEGA UNDERBAR
+    ('\u{237A}', CharMapAction::SubChar('a')), // APL FUNCTIONAL SYMBOL ALPHA
+    ('\u{237F}', CharMapAction::SubChar('\u{16BD}')), // VERTICAL LINE WITH MIDDLE DOT
+    ('\u{239C}', CharMapAction::SubChar('\u{4E28}')), // LEFT PARENTHESIS EXTENSION
+    ('\u{239F}', CharMapAction::SubChar('\u{4E28}')), // RIGHT PARENTHESIS EXTENSION
+    ('\u{23A2}', CharMapAction::SubChar('\u{4E28}')), // LEFT SQUARE BRACKET EXTENSION
+    ('\u{23A5}', CharMapAction::SubChar('\u{4E28}')), // RIGHT SQUARE BRACKET EXTENSION
+    ('\u{23AA}', CharMapAction::SubChar('\u{4E28}')), // CURLY BRACKET EXTENSION
+    ('\u{23AE}', CharMapAction::SubChar('\u{4E28}')), // INTEGRAL EXTENSION
+    ('\u{23C1}', CharMapAction::SubChar('\u{2355}')), // DENTISTRY SYMBOL LIGHT DOWN AND HORIZONTAL WITH CIRCLE
+    ('\u{23C2}', CharMapAction::SubChar('\u{234E}')), // DENTISTRY SYMBOL LIGHT UP AND HORIZONTAL WITH CIRCLE
+    ('\u{23C3}', CharMapAction::SubChar('\u{234B}')), // DENTISTRY SYMBOL LIGHT VERTICAL WITH TRIANGLE
+    ('\u{23C6}', CharMapAction::SubChar('\u{236D}')), // DENTISTRY SYMBOL LIGHT VERTICAL AND WAVE
+    ('\u{23E8}', CharMapAction::SubStr("\u{2081}\u{2080}")), // DECIMAL EXPONENT SYMBOL
+    ('\u{23FC}', CharMapAction::SubChar('\u{23FB}')), // POWER ON-OFF SYMBOL
+    ('\u{23FD}', CharMapAction::SubChar('l')), // POWER ON SYMBOL
+    ('\u{23FE}', CharMapAction::SubChar('\u{263E}')), // POWER SLEEP SYMBOL
+    ('\u{244A}', CharMapAction::SubStr("\\\\")), // OCR DOUBLE BACKSLASH
+    ('\u{2460}', CharMapAction::SubChar('\u{2780}')), // CIRCLED DIGIT ONE
+    ('\u{2461}', CharMapAction::SubChar('\u{2781}')), // CIRCLED DIGIT TWO
+    ('\u{2462}', CharMapAction::SubChar('\u{2782}')), // CIRCLED DIGIT THREE
+    ('\u{2463}', CharMapAction::SubChar('\u{2783}')), // CIRCLED DIGIT FOUR
+    ('\u{2464}', CharMapAction::SubChar('\u{2784}')), // CIRCLED DIGIT FIVE
+    ('\u{2465}', CharMapAction::SubChar('\u{2785}')), // CIRCLED DIGIT SIX
+    ('\u{2466}', CharMapAction::SubChar('\u{2786}')), // CIRCLED DIGIT SEVEN
+    ('\u{2467}', CharMapAction::SubChar('\u{2787}')), // CIRCLED DIGIT EIGHT
+    ('\u{2468}', CharMapAction::SubChar('\u{2788}')), // CIRCLED DIGIT NINE
+    ('\u{2469}', CharMapAction::SubChar('\u{2789}')), // CIRCLED NUMBER TEN
+    ('\u{2474}', CharMapAction::SubStr("(l)")), // PARENTHESIZED DIGIT ONE
+    ('\u{2475}', CharMapAction::SubStr("(2)")), // PARENTHESIZED DIGIT TWO
+    ('\u{2476}', CharMapAction::SubStr("(3)")), // PARENTHESIZED DIGIT THREE
+    ('\u{2477}', CharMapAction::SubStr("(4)")), // PARENTHESIZED DIGIT FOUR
+    ('\u{2478}', CharMapAction::SubStr("(5)")), // PARENTHESIZED DIGIT FIVE
+    ('\u{2479}', CharMapAction::SubStr("(6)")), // PARENTHESIZED DIGIT SIX
+    ('\u{247A}', CharMapAction::SubStr("(7)")), // PARENTHESIZED DIGIT SEVEN
+    ('\u{247B}', CharMapAction::SubStr("(8)")), // PARENTHESIZED DIGIT EIGHT
+    ('\u{247C}', CharMapAction::SubStr("(9)")), // PARENTHESIZED DIGIT NINE
+    ('\u{247D}', CharMapAction::SubStr("(lO)")), // PARENTHESIZED NUMBER TEN
+    ('\u{247E}', CharMapAction::SubStr("(ll)")), // PARENTHESIZED NUMBER ELEVEN
+    ('\u{247F}', CharMapAction::SubStr("(l2)")), // PARENTHESIZED NUMBER TWELVE
+    ('\u{2480}', CharMapAction::SubStr("(l3)")), // PARENTHESIZED NUMBER THIRTEEN
+    ('\u{2481}', CharMapAction::SubStr("(l4)")), // PARENTHESIZED NUMBER FOURTEEN
+    ('\u{2482}', CharMapAction::SubStr("(l5)")), // PARENTHESIZED NUMBER FIFTEEN
+    ('\u{2483}', CharMapAction::SubStr("(l6)")), // PARENTHESIZED NUMBER SIXTEEN
+    ('\u{2484}', CharMapAction::SubStr("(l7)")), // PARENTHESIZED NUMBER SEVENTEEN
+    ('\u{2485}', CharMapAction::SubStr("(l8)")), // PARENTHESIZED NUMBER EIGHTEEN
+    ('\u{2486}', CharMapAction::SubStr("(l9)")), // PARENTHESIZED NUMBER NINETEEN
+    ('\u{2487}', CharMapAction::SubStr("(2O)")), // PARENTHESIZED NUMBER TWENTY
+    ('\u{2488}', CharMapAction::SubStr("l.")), // DIGIT ONE FULL STOP
+    ('\u{2489}', CharMapAction::SubStr("2.")), // DIGIT TWO FULL STOP
+    ('\u{248A}', CharMapAction::SubStr("3.")), // DIGIT THREE FULL STOP
+    ('\u{248B}', CharMapAction::SubStr("4.")), // DIGIT FOUR FULL STOP
+    ('\u{248C}', CharMapAction::SubStr("5.")), // DIGIT FIVE FULL STOP
+    ('\u{248D}', CharMapAction::SubStr("6.")), // DIGIT SIX FULL STOP
+    ('\u{248E}', CharMapAction::SubStr("7.")), // DIGIT SEVEN FULL STOP
+    ('\u{248F}', CharMapAction::SubStr("8.")), // DIGIT EIGHT FULL STOP
+    ('\u{2490}', CharMapAction::SubStr("9.")), // DIGIT NINE FULL STOP
+    ('\u{2491}', CharMapAction::SubStr("lO.")), // NUMBER TEN FULL STOP
+    ('\u{2492}', CharMapAction::SubStr("ll.")), // NUMBER ELEVEN FULL STOP
+    ('\u{2493}', CharMapAction::SubStr("l2.")), // NUMBER TWELVE FULL STOP
+    ('\u{2494}', CharMapAction::SubStr("l3.")), // NUMBER THIRTEEN FULL STOP
+    ('\u{2495}', CharMapAction::SubStr("l4.")), // NUMBER FOURTEEN FULL STOP
+    ('\u{2496}', CharMapAction::SubStr("l5.")), // NUMBER FIFTEEN FULL STOP
+    ('\u{2497}', CharMapAction::SubStr("l6.")), // NUMBER SIXTEEN FULL STOP
+    ('\u{2498}', CharMapAction::SubStr("l7.")), // NUMBER SEVENTEEN FULL STOP
+    ('\u{2499}', CharMapAction::SubStr("l8.")), // NUMBER EIGHTEEN FULL STOP
+    ('\u{249A}', CharMapAction::SubStr("l9.")), // NUMBER NINETEEN FULL STOP
+    ('\u{249B}', CharMapAction::SubStr("2O.")), // NUMBER TWENTY FULL STOP
+    ('\u{249C}', CharMapAction::SubStr("(a)")), // PARENTHESIZED LATIN SMALL LETTER A
+    ('\u{249D}', CharMapAction::SubStr("(b)")), // PARENTHESIZED LATIN SMALL LETTER B
+    ('\u{249E}', CharMapAction::SubStr("(c)")), // PARENTHESIZED LATIN SMALL LETTER C
+    ('\u{249F}', CharMapAction::SubStr("(d)")), // PARENTHESIZED LATIN SMALL LETTER D
+    ('\u{24A0}', CharMapAction::SubStr("(e)")), // PARENTHESIZED LATIN SMALL LETTER E
+    ('\u{24A1}', CharMapAction::SubStr("(f)")), // PARENTHESIZED LATIN SMALL LETTER F
+    ('\u{24A2}', CharMapAction::SubStr("(g)")), // PARENTHESIZED LATIN SMALL LETTER G
+    ('\u{24A3}', CharMapAction::SubStr("(h)")), // PARENTHESIZED LATIN SMALL LETTER H
+    ('\u{24A4}', CharMapAction::SubStr("(i)")), // PARENTHESIZED LATIN SMALL LETTER I
+    ('\u{24A5}', CharMapAction::SubStr("(j)")), // PARENTHESIZED LATIN SMALL LETTER J
+    ('\u{24A6}', CharMapAction::SubStr("(k)")), // PARENTHESIZED LATIN SMALL LETTER K
+    ('\u{24A7}', CharMapAction::SubStr("(l)")), // PARENTHESIZED LATIN SMALL LETTER L
+    ('\u{24A8}', CharMapAction::SubStr("(rn)")), // PARENTHESIZED LATIN SMALL LETTER M
+    ('\u{24A9}', CharMapAction::SubStr("(n)")), // PARENTHESIZED LATIN SMALL LETTER N
+    ('\u{24AA}', CharMapAction::SubStr("(o)")), // PARENTHESIZED LATIN SMALL LETTER O
+    ('\u{24AB}', CharMapAction::SubStr("(p)")), // PARENTHESIZED LATIN SMALL LETTER P
+    ('\u{24AC}', CharMapAction::SubStr("(q)")), // PARENTHESIZED LATIN SMALL LETTER Q
+    ('\u{24AD}', CharMapAction::SubStr("(r)")), // PARENTHESIZED LATIN SMALL LETTER R
+    ('\u{24AE}', CharMapAction::SubStr("(s)")), // PARENTHESIZED LATIN SMALL LETTER S
+    ('\u{24AF}', CharMapAction::SubStr("(t)")), // PARENTHESIZED LATIN SMALL LETTER T
+    ('\u{24B0}', CharMapAction::SubStr("(u)")), // PARENTHESIZED LATIN SMALL LETTER U
+    ('\u{24B1}', CharMapAction::SubStr("(v)")), // PARENTHESIZED LATIN SMALL LETTER V
+    ('\u{24B2}', CharMapAction::SubStr("(w)")), // PARENTHESIZED LATIN SMALL LETTER W
+    ('\u{24B3}', CharMapAction::SubStr("(x)")), // PARENTHESIZED LATIN SMALL LETTER X
+    ('\u{24B4}', CharMapAction::SubStr("(y)")), // PARENTHESIZED LATIN SMALL LETTER Y
+    ('\u{24B5}', CharMapAction::SubStr("(z)")), // PARENTHESIZED LATIN SMALL LETTER Z
+    ('\u{24B8}', CharMapAction::SubChar('\u{00A9}')), // CIRCLED LATIN CAPITAL LETTER C
+    ('\u{24C5}', CharMapAction::SubChar('\u{2117}')), // CIRCLED LATIN CAPITAL LETTER P
+    ('\u{24C7}', CharMapAction::SubChar('\u{00AE}')), // CIRCLED LATIN CAPITAL LETTER R
+    ('\u{24DB}', CharMapAction::SubChar('\u{24BE}')), // CIRCLED LATIN SMALL LETTER L
+    ('\u{24EA}', CharMapAction::SubChar('\u{1F10D}')), // CIRCLED DIGIT ZERO
+    ('\u{2500}', CharMapAction::SubChar('\u{30FC}')), // BOX DRAWINGS LIGHT HORIZONTAL
+    ('\u{2501}', CharMapAction::SubChar('\u{30FC}')), // BOX DRAWINGS HEAVY HORIZONTAL
+    ('\u{2503}', CharMapAction::SubChar('\u{2502}')), // BOX DRAWINGS HEAVY VERTICAL
+    ('\u{250F}', CharMapAction::SubChar('\u{250C}')), // BOX DRAWINGS HEAVY DOWN AND RIGHT
+    ('\u{2523}', CharMapAction::SubChar('\u{251C}')), // BOX DRAWINGS HEAVY VERTICAL AND RIGHT
+    ('\u{2571}', CharMapAction::SubChar('/')), // BOX DRAWINGS LIGHT DIAGONAL UPPER RIGHT TO LOWER LEFT
+    ('\u{2573}', CharMapAction::SubChar('X')), // BOX DRAWINGS LIGHT DIAGONAL CROSS
+    ('\u{2588}', CharMapAction::SubChar('\u{220E}')), // FULL BLOCK
+    ('\u{2590}', CharMapAction::SubChar('\u{258C}')), // RIGHT HALF BLOCK
+    ('\u{2594}', CharMapAction::SubChar('\u{02C9}')), // UPPER ONE EIGHTH BLOCK
+    ('\u{2597}', CharMapAction::SubChar('\u{2596}')), // QUADRANT LOWER RIGHT
+    ('\u{259D}', CharMapAction::SubChar('\u{2598}')), // QUADRANT UPPER RIGHT
+    ('\u{25A0}', CharMapAction::SubChar('\u{220E}')), // BLACK SQUARE
+    ('\u{25B1}', CharMapAction::SubChar('\u{23E5}')), // WHITE PARALLELOGRAM
+    ('\u{25B3}', CharMapAction::SubChar('\u{0394}')), // WHITE UP-POINTING TRIANGLE
+    ('\u{25B7}', CharMapAction::SubChar('\u{22B3}')), // WHITE RIGHT-POINTING TRIANGLE
+    ('\u{25B8}', CharMapAction::SubChar('\u{25B6}')), // BLACK RIGHT-POINTING SMALL TRIANGLE
+    ('\u{25BA}', CharMapAction::SubChar('\u{25B6}')), // BLACK RIGHT-POINTING POINTER
+    ('\u{25BD}', CharMapAction::SubChar('\u{102BC}')), // WHITE DOWN-POINTING TRIANGLE
+    ('\u{25C1}', CharMapAction::SubChar('\u{22B2}')), // WHITE LEFT-POINTING TRIANGLE
+    ('\u{25C7}', CharMapAction::SubChar('\u{16DC}')), // WHITE DIAMOND
+    ('\u{25CA}', CharMapAction::SubChar('\u{16DC}')), // LOZENGE
+    ('\u{25CB}', CharMapAction::SubChar('\u{00B0}')), // WHITE CIRCLE
+    ('\u{25CE}', CharMapAction::SubChar('\u{233E}')), // BULLSEYE
+    ('\u{25E0}', CharMapAction::SubChar('\u{2312}')), // UPPER HALF CIRCLE
+    ('\u{25E6}', CharMapAction::SubChar('\u{00B0}')), // WHITE BULLET
+    ('\u{2609}', CharMapAction::SubChar('\u{0298}')), // SUN
+    ('\u{2610}', CharMapAction::SubChar('\u{25A1}')), // BALLOT BOX
+    ('\u{2625}', CharMapAction::SubChar('\u{1099E}')), // ANKH
+    ('\u{2630}', CharMapAction::SubChar('\u{2CB6}')), // TRIGRAM FOR HEAVEN
+    ('\u{2638}', CharMapAction::SubChar('\u{2388}')), // WHEEL OF DHARMA
+    ('\u{264E}', CharMapAction::SubChar('\u{224F}')), // LIBRA
+    ('\u{2662}', CharMapAction::SubChar('\u{16DC}')), // WHITE DIAMOND SUIT
+    ('\u{2669}', CharMapAction::SubStr("\u{1D158}\u{1D165}")), // QUARTER NOTE
+    ('\u{266A}', CharMapAction::SubStr("\u{1D158}\u{1D165}\u{1D16E}")), // EIGHTH NOTE
+    ('\u{26AC}', CharMapAction::SubChar('\u{0970}')), // MEDIUM SMALL WHITE CIRCLE
+    ('\u{2768}', CharMapAction::SubChar('(')), // MEDIUM LEFT PARENTHESIS ORNAMENT
+    ('\u{2769}', CharMapAction::SubChar(')')), // MEDIUM RIGHT PARENTHESIS ORNAMENT
+    ('\u{276E}', CharMapAction::SubChar('<')), // HEAVY LEFT-POINTING ANGLE QUOTATION MARK ORNAMENT
+    ('\u{276F}', CharMapAction::SubChar('>')), // HEAVY RIGHT-POINTING ANGLE QUOTATION MARK ORNAMENT
+    ('\u{2772}', CharMapAction::SubChar('(')), // LIGHT LEFT TORTOISE SHELL BRACKET ORNAMENT
+    ('\u{2773}', CharMapAction::SubChar(')')), // LIGHT RIGHT TORTOISE SHELL BRACKET ORNAMENT
+    ('\u{2774}', CharMapAction::SubChar('{')), // MEDIUM LEFT CURLY BRACKET ORNAMENT
+    ('\u{2775}', CharMapAction::SubChar('}')), // MEDIUM RIGHT CURLY BRACKET ORNAMENT
+    ('\u{2795}', CharMapAction::SubChar('+')), // HEAVY PLUS SIGN
+    ('\u{2796}', CharMapAction::SubChar('-')), // HEAVY MINUS SIGN
+    ('\u{2797}', CharMapAction::SubChar('\u{00F7}')), // HEAVY DIVISION SIGN
+    ('\u{27C2}', CharMapAction::SubChar('\u{A4D5}')), // PERPENDICULAR
+    ('\u{27C8}', CharMapAction::SubStr("\\\u{1455}")), // REVERSE SOLIDUS PRECEDING SUBSET
+    ('\u{27C9}', CharMapAction::SubStr("\u{1450}/")), // SUPERSET PRECEDING SOLIDUS
+    ('\u{27CB}', CharMapAction::SubChar('/')), // MATHEMATICAL RISING DIAGONAL
+    ('\u{27CD}', CharMapAction::SubChar('\\')), // MATHEMATICAL FALLING DIAGONAL
+    ('\u{27D9}', CharMapAction::SubChar('T')), // LARGE DOWN TACK
+    ('\u{27E8}', CharMapAction::SubChar('\u{276C}')), // MATHEMATICAL LEFT ANGLE BRACKET
+    ('\u{27E9}', CharMapAction::SubChar('\u{276D}')), // MATHEMATICAL RIGHT ANGLE BRACKET
+    ('\u{292B}', CharMapAction::SubChar('x')), // RISING DIAGONAL CROSSING FALLING DIAGONAL
+    ('\u{292C}', CharMapAction::SubChar('x')), // FALLING DIAGONAL CROSSING RISING DIAGONAL
+    ('\u{2963}', CharMapAction::SubStr("\u{16D0}\u{16DA}")), // UPWARDS HARPOON WITH BARB LEFT BESIDE UPWARDS HARPOON WITH BARB RIGHT
+    ('\u{2965}', CharMapAction::SubStr("\u{21C3}\u{21C2}")), // DOWNWARDS HARPOON WITH BARB LEFT BESIDE DOWNWARDS HARPOON WITH BARB RIGHT
+    ('\u{296E}', CharMapAction::SubStr("\u{16D0}\u{21C2}")), // UPWARDS HARPOON WITH BARB LEFT BESIDE DOWNWARDS HARPOON WITH BARB RIGHT
+    ('\u{296F}', CharMapAction::SubStr("\u{21C3}\u{16DA}")), // DOWNWARDS HARPOON WITH BARB LEFT BESIDE UPWARDS HARPOON WITH BARB RIGHT
+    ('\u{2999}', CharMapAction::SubChar('\u{2D42}')), // DOTTED FENCE
+    ('\u{29B0}', CharMapAction::SubChar('\u{2349}')), // REVERSED EMPTY SET
+    ('\u{29BE}', CharMapAction::SubChar('\u{233E}')), // CIRCLED WHITE BULLET
+    ('\u{29C4}', CharMapAction::SubChar('\u{303C}')), // SQUARED RISING DIAGONAL SLASH
+    ('\u{29C5}', CharMapAction::SubChar('\u{2342}')), // SQUARED FALLING DIAGONAL SLASH
+    ('\u{29C7}', CharMapAction::SubChar('\u{233B}')), // SQUARED SMALL CIRCLE
+    ('\u{29D6}', CharMapAction::SubChar('\u{102C0}')), // WHITE HOURGLASS
+    ('\u{29D9}', CharMapAction::SubChar('\u{299A}')), // RIGHT WIGGLY FENCE
+    ('\u{29F4}', CharMapAction::SubStr(":\u{2192}")), // RULE-DELAYED
+    ('\u{29F5}', CharMapAction::SubChar('\\')), // REVERSE SOLIDUS OPERATOR
+    ('\u{29F6}', CharMapAction::SubStr("/\u{0304}")), // SOLIDUS WITH OVERBAR
+    ('\u{29F8}', CharMapAction::SubChar('/')), // BIG SOLIDUS
+    ('\u{29F9}', CharMapAction::SubChar('\\')), // BIG REVERSE SOLIDUS
+    ('\u{2A00}', CharMapAction::SubChar('\u{0298}')), // N-ARY CIRCLED DOT OPERATOR
+    ('\u{2A01}', CharMapAction::SubChar('\u{102A8}')), // N-ARY CIRCLED PLUS OPERATOR
+    ('\u{2A02}', CharMapAction::SubChar('\u{2297}')), // N-ARY CIRCLED TIMES OPERATOR
+    ('\u{2A03}', CharMapAction::SubChar('\u{228D}')), // N-ARY UNION OPERATOR WITH DOT
+    ('\u{2A04}', CharMapAction::SubChar('\u{228E}')), // N-ARY UNION OPERATOR WITH PLUS
+    ('\u{2A05}', CharMapAction::SubChar('\u{2293}')), // N-ARY SQUARE INTERSECTION OPERATOR
+    ('\u{2A06}', CharMapAction::SubChar('\u{2294}')), // N-ARY SQUARE UNION OPERATOR
+    ('\u{2A0C}', CharMapAction::SubStr("\u{0283}\u{0283}\u{0283}\u{0283}")), // QUADRUPLE INTEGRAL OPERATOR
+    ('\u{2A1D}', CharMapAction::SubChar('\u{16DE}')), // JOIN
+    ('\u{2A20}', CharMapAction::SubStr(">>")), // Z NOTATION SCHEMA PIPING
+    ('\u{2A21}', CharMapAction::SubChar('\u{16DA}')), // Z NOTATION SCHEMA PROJECTION
+    ('\u{2A22}', CharMapAction::SubStr("+\u{030A}")), // PLUS SIGN WITH SMALL CIRCLE ABOVE
+    ('\u{2A23}', CharMapAction::SubStr("+\u{0302}")), // PLUS SIGN WITH CIRCUMFLEX ACCENT ABOVE
+    ('\u{2A24}', CharMapAction::SubStr("+\u{0303}")), // PLUS SIGN WITH TILDE ABOVE
+    ('\u{2A25}', CharMapAction::SubStr("+\u{0323}")), // PLUS SIGN WITH DOT BELOW
+    ('\u{2A26}', CharMapAction::SubStr("+\u{0330}")), // PLUS SIGN WITH TILDE BELOW
+    ('\u{2A27}', CharMapAction::SubStr("+\u{2082}")), // PLUS SIGN WITH SUBSCRIPT TWO
+    ('\u{2A29}', CharMapAction::SubStr("-\u{0313}")), // MINUS SIGN WITH COMMA ABOVE
+    ('\u{2A2A}', CharMapAction::SubStr("-\u{0323}")), // MINUS SIGN WITH DOT BELOW
+    ('\u{2A2F}', CharMapAction::SubChar('x')), // VECTOR OR CROSS PRODUCT
+    ('\u{2A30}', CharMapAction::SubStr("x\u{0307}")), // MULTIPLICATION SIGN WITH DOT ABOVE
+    ('\u{2A3D}', CharMapAction::SubChar('\u{2319}')), // RIGHTHAND INTERIOR PRODUCT
+    ('\u{2A3E}', CharMapAction::SubChar('\u{2A1F}')), // Z NOTATION RELATIONAL COMPOSITION
+    ('\u{2A3F}', CharMapAction::SubChar('\u{2210}')), // AMALGAMATION OR COPRODUCT
+    ('\u{2A6A}', CharMapAction::SubStr("~\u{0307}")), // TILDE OPERATOR WITH DOT ABOVE
+    ('\u{2A6E}', CharMapAction::SubStr("=\u{20F0}")), // EQUALS WITH ASTERISK
+    ('\u{2A74}', CharMapAction::SubStr("::=")), // DOUBLE COLON EQUAL
+    ('\u{2A75}', CharMapAction::SubStr("==")), // TWO CONSECUTIVE EQUALS SIGNS
+    ('\u{2A76}', CharMapAction::SubStr("===")), // THREE CONSECUTIVE EQUALS SIGNS
+    ('\u{2AA5}', CharMapAction::SubStr("><")), // GREATER-THAN BESIDE LESS-THAN
+    ('\u{2AAA}', CharMapAction::SubChar('\u{15D5}')), // SMALLER THAN
+    ('\u{2AAB}', CharMapAction::SubChar('\u{15D2}')), // LARGER THAN
+    ('\u{2AD7}', CharMapAction::SubStr("\u{1450}\u{1455}")), // SUPERSET BESIDE SUBSET
+    ('\u{2AFB}', CharMapAction::SubStr("///")), // TRIPLE SOLIDUS BINARY RELATION
+    ('\u{2AFD}', CharMapAction::SubStr("//")), // DOUBLE SOLIDUS OPERATOR
+    ('\u{2BEC}', CharMapAction::SubChar('\u{219E}')), // LEFTWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS
+    ('\u{2BED}', CharMapAction::SubChar('\u{219F}')), // UPWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS
+    ('\u{2BEE}', CharMapAction::SubChar('\u{21A0}')), // RIGHTWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS
+    ('\u{2BEF}', CharMapAction::SubChar('\u{21A1}')), // DOWNWARDS TWO-HEADED ARROW WITH TRIANGLE ARROWHEADS
+    ('\u{2C67}', CharMapAction::SubStr("H\u{0329}")), // LATIN CAPITAL LETTER H WITH DESCENDER
+    ('\u{2C69}', CharMapAction::SubStr("K\u{0329}")), // LATIN CAPITAL LETTER K WITH DESCENDER
+    ('\u{2C84}', CharMapAction::SubChar('\u{0393}')), // COPTIC CAPITAL LETTER GAMMA
+    ('\u{2C85}', CharMapAction::SubChar('r')), // COPTIC SMALL LETTER GAMMA
+    ('\u{2C86}', CharMapAction::SubChar('\u{0394}')), // COPTIC CAPITAL LETTER DALDA
+    ('\u{2C88}', CharMapAction::SubChar('\u{A792}')), // COPTIC CAPITAL LETTER EIE
+    ('\u{2C89}', CharMapAction::SubChar('\u{A793}')), // COPTIC SMALL LETTER EIE
+    ('\u{2C8E}', CharMapAction::SubChar('H')), // COPTIC CAPITAL LETTER HATE
+    ('\u{2C92}', CharMapAction::SubChar('l')), // COPTIC CAPITAL LETTER IAUDA
+    ('\u{2C94}', CharMapAction::SubChar('K')), // COPTIC CAPITAL LETTER KAPA
+    ('\u{2C95}', CharMapAction::SubChar('\u{0138}')), // COPTIC SMALL LETTER KAPA
+    ('\u{2C96}', CharMapAction::SubChar('\u{03BB}')), // COPTIC CAPITAL LETTER LAULA
+    ('\u{2C98}', CharMapAction::SubChar('M')), // COPTIC CAPITAL LETTER MI
+    ('\u{2C9A}', CharMapAction::SubChar('N')), // COPTIC CAPITAL LETTER NI
+    ('\u{2C9E}', CharMapAction::SubChar('O')), // COPTIC CAPITAL LETTER O
+    ('\u{2C9F}', CharMapAction::SubChar('o')), // COPTIC SMALL LETTER O
+    ('\u{2CA0}', CharMapAction::SubChar('\u{03A0}')), // COPTIC CAPITAL LETTER PI
+    ('\u{2CA2}', CharMapAction::SubChar('P')), // COPTIC CAPITAL LETTER RO
+    ('\u{2CA3}', CharMapAction::SubChar('p')), // COPTIC SMALL LETTER RO
+    ('\u{2CA4}', CharMapAction::SubChar('C')), // COPTIC CAPITAL LETTER SIMA
+    ('\u{2CA5}', CharMapAction::SubChar('c')), // COPTIC SMALL LETTER SIMA
+    ('\u{2CA6}', CharMapAction::SubChar('T')), // COPTIC CAPITAL LETTER TAU
+    ('\u{2CA8}', CharMapAction::SubChar('Y')), // COPTIC CAPITAL LETTER UA
+    ('\u{2CAA}', CharMapAction::SubChar('\u{03A6}')), // COPTIC CAPITAL LETTER FI
+    ('\u{2CAB}', CharMapAction::SubChar('\u{0278}')), // COPTIC SMALL LETTER FI
+    ('\u{2CAC}', CharMapAction::SubChar('X')), // COPTIC CAPITAL LETTER KHI
+    ('\u{2CAD}', CharMapAction::SubChar('\u{03C7}')), // COPTIC SMALL LETTER KHI
+    ('\u{2CAE}', CharMapAction::SubChar('\u{03A8}')), // COPTIC CAPITAL LETTER PSI
+    ('\u{2CB1}', CharMapAction::SubChar('\u{03C9}')), // COPTIC SMALL LETTER OOU
+    ('\u{2CB4}', CharMapAction::SubStr("<\u{00B7}")), // COPTIC CAPITAL LETTER OLD COPTIC AIN
+    ('\u{2CBA}', CharMapAction::SubChar('-')), // COPTIC CAPITAL LETTER DIALECT-P NI
+    ('\u{2CBC}', CharMapAction::SubChar('\u{0428}')), // COPTIC CAPITAL LETTER CRYPTOGRAMMIC NI
+    ('\u{2CBD}', CharMapAction::SubChar('\u{0448}')), // COPTIC SMALL LETTER CRYPTOGRAMMIC NI
+    ('\u{2CC6}', CharMapAction::SubChar('/')), // COPTIC CAPITAL LETTER OLD COPTIC ESH
+    ('\u{2CCA}', CharMapAction::SubChar('9')), // COPTIC CAPITAL LETTER DIALECT-P HORI
+    ('\u{2CCC}', CharMapAction::SubChar('3')), // COPTIC CAPITAL LETTER OLD COPTIC HORI
+    ('\u{2CCD}', CharMapAction::SubChar('\u{021D}')), // COPTIC SMALL LETTER OLD COPTIC HORI
+    ('\u{2CD0}', CharMapAction::SubChar('L')), // COPTIC CAPITAL LETTER L-SHAPED HA
+    ('\u{2CD1}', CharMapAction::SubChar('\u{029F}')), // COPTIC SMALL LETTER L-SHAPED HA
+    ('\u{2CD2}', CharMapAction::SubChar('6')), // COPTIC CAPITAL LETTER OLD COPTIC HEI
+    ('\u{2CDC}', CharMapAction::SubChar('\u{03EC}')), // COPTIC CAPITAL LETTER OLD NUBIAN SHIMA
+    ('\u{2CE4}', CharMapAction::SubChar('\u{03D7}')), // COPTIC SYMBOL KAI
+    ('\u{2CE9}', CharMapAction::SubChar('\u{2627}')), // COPTIC SYMBOL KHI RO
+    ('\u{2CF9}', CharMapAction::SubStr("\\\\")), // COPTIC OLD NUBIAN FULL STOP
+    ('\u{2D31}', CharMapAction::SubStr("O\u{0335}")), // TIFINAGH LETTER YAB
+    ('\u{2D37}', CharMapAction::SubChar('\u{0245}')), // TIFINAGH LETTER YAD
+    ('\u{2D38}', CharMapAction::SubChar('V')), // TIFINAGH LETTER YADH
+    ('\u{2D39}', CharMapAction::SubChar('E')), // TIFINAGH LETTER YADD
+    ('\u{2D3A}', CharMapAction::SubChar('\u{018E}')), // TIFINAGH LETTER YADDH
+    ('\u{2D41}', CharMapAction::SubStr("O\u{0338}")), // TIFINAGH LETTER BERBER ACADEMY YAH
+    ('\u{2D48}', CharMapAction::SubStr("\u{00B7}\u{00B7}\u{00B7}")), // TIFINAGH LETTER TUAREG YAQ
+    ('\u{2D49}', CharMapAction::SubChar('\u{01A9}')), // TIFINAGH LETTER YI
+    ('\u{2D4F}', CharMapAction::SubChar('l')), // TIFINAGH LETTER YAN
+    ('\u{2D51}', CharMapAction::SubChar('!')), // TIFINAGH LETTER TUAREG YANG
+    ('\u{2D54}', CharMapAction::SubChar('O')), // TIFINAGH LETTER YAR
+    ('\u{2D55}', CharMapAction::SubChar('Q')), // TIFINAGH LETTER YARR
+    ('\u{2D59}', CharMapAction::SubChar('\u{0298}')), // TIFINAGH LETTER YAS
+    ('\u{2D5D}', CharMapAction::SubChar('X')), // TIFINAGH LETTER YATH
+    ('\u{2D60}', CharMapAction::SubChar('\u{0394}')), // TIFINAGH LETTER YAV
+    ('\u{2D63}', CharMapAction::SubChar('\u{16EF}')), // TIFINAGH LETTER YAZ
+    ('\u{2DE8}', CharMapAction::SubChar('\u{1DDF}')), // COMBINING CYRILLIC LETTER EM
+    ('\u{2DEA}', CharMapAction::SubChar('\u{030A}')), // COMBINING CYRILLIC LETTER O
+    ('\u{2DED}', CharMapAction::SubChar('\u{0368}')), // COMBINING CYRILLIC LETTER ES
+    ('\u{2DEF}', CharMapAction::SubChar('\u{036F}')), // COMBINING CYRILLIC LETTER HA
+    ('\u{2DF6}', CharMapAction::SubChar('\u{0363}')), // COMBINING CYRILLIC LETTER A
+    ('\u{2DF7}', CharMapAction::SubChar('\u{0364}')), // COMBINING CYRILLIC LETTER IE
+    ('\u{2E1A}', CharMapAction::SubStr("-\u{0308}")), // HYPHEN WITH DIAERESIS
+    ('\u{2E1E}', CharMapAction::SubStr("~\u{0307}")), // TILDE WITH DOT ABOVE
+    ('\u{2E1F}', CharMapAction::SubStr("~\u{0323}")), // TILDE WITH DOT BELOW
+    ('\u{2E26}', CharMapAction::SubChar('\u{1455}')), // LEFT SIDEWAYS U BRACKET
+    ('\u{2E27}', CharMapAction::SubChar('\u{1450}')), // RIGHT SIDEWAYS U BRACKET
+    ('\u{2E28}', CharMapAction::SubStr("((")), // LEFT DOUBLE PARENTHESIS
+    ('\u{2E29}', CharMapAction::SubStr("))")), // RIGHT DOUBLE PARENTHESIS
+    ('\u{2E2A}', CharMapAction::SubChar('\u{2235}')), // TWO DOTS OVER ONE DOT PUNCTUATION
+    ('\u{2E2B}', CharMapAction::SubChar('\u{2234}')), // ONE DOT OVER TWO DOTS PUNCTUATION
+    ('\u{2E2C}', CharMapAction::SubChar('\u{2237}')), // SQUARED FOUR DOT PUNCTUATION
+    ('\u{2E2E}', CharMapAction::SubChar('\u{061F}')), // REVERSED QUESTION MARK
+    ('\u{2E30}', CharMapAction::SubChar('\u{00B0}')), // RING POINT
+    ('\u{2E31}', CharMapAction::SubChar('\u{00B7}')), // WORD SEPARATOR MIDDLE DOT
+    ('\u{2E32}', CharMapAction::SubChar('\u{060C}')), // TURNED COMMA
+    ('\u{2E35}', CharMapAction::SubChar('\u{061B}')), // TURNED SEMICOLON
+    ('\u{2E39}', CharMapAction::SubChar('\u{1E9F}')), // TOP HALF SECTION SIGN
+    ('\u{2E3D}', CharMapAction::SubChar('\u{2D42}')), // VERTICAL SIX DOTS
+    ('\u{2E3F}', CharMapAction::SubChar('\u{00B6}')), // CAPITULUM
+    ('\u{2E40}', CharMapAction::SubChar('=')), // DOUBLE HYPHEN
+    ('\u{2E82}', CharMapAction::SubChar('\u{4E5B}')), // CJK RADICAL SECOND ONE
+    ('\u{2E83}', CharMapAction::SubChar('\u{4E5A}')), // CJK RADICAL SECOND TWO
+    ('\u{2E85}', CharMapAction::SubChar('\u{4EBB}')), // CJK RADICAL PERSON
+    ('\u{2E89}', CharMapAction::SubChar('\u{5202}')), // CJK RADICAL KNIFE TWO
+    ('\u{2E8B}', CharMapAction::SubChar('\u{353E}')), // CJK RADICAL SEAL
+    ('\u{2E8E}', CharMapAction::SubChar('\u{5140}')), // CJK RADICAL LAME ONE
+    ('\u{2E8F}', CharMapAction::SubChar('\u{5C23}')), // CJK RADICAL LAME TWO
+    ('\u{2E90}', CharMapAction::SubChar('\u{5C22}')), // CJK RADICAL LAME THREE
+    ('\u{2E92}', CharMapAction::SubChar('\u{5DF3}')), // CJK RADICAL SNAKE
+    ('\u{2E93}', CharMapAction::SubChar('\u{5E7A}')), // CJK RADICAL THREAD
+    ('\u{2E94}', CharMapAction::SubChar('\u{5F51}')), // CJK RADICAL SNOUT ONE
+    ('\u{2E96}', CharMapAction::SubChar('\u{5FC4}')), // CJK RADICAL HEART ONE
+    ('\u{2E97}', CharMapAction::SubChar('\u{38FA}')), // CJK RADICAL HEART TWO
+    ('\u{2E98}', CharMapAction::SubChar('\u{624C}')), // CJK RADICAL HAND
+    ('\u{2E99}', CharMapAction::SubChar('\u{6535}')), // CJK RADICAL RAP
+    ('\u{2E9B}', CharMapAction::SubChar('\u{65E1}')), // CJK RADICAL CHOKE
+    ('\u{2E9E}', CharMapAction::SubChar('\u{6B7A}')), // CJK RADICAL DEATH
+    ('\u{2E9F}', CharMapAction::SubChar('\u{6BCD}')), // CJK RADICAL MOTHER
+    ('\u{2EA0}', CharMapAction::SubChar('\u{6C11}')), // CJK RADICAL CIVILIAN
+    ('\u{2EA1}', CharMapAction::SubChar('\u{6C35}')), // CJK RADICAL WATER ONE
+    ('\u{2EA2}', CharMapAction::SubChar('\u{6C3A}')), // CJK RADICAL WATER TWO
+    ('\u{2EA3}', CharMapAction::SubChar('\u{706C}')), // CJK RADICAL FIRE
+    ('\u{2EA4}', CharMapAction::SubChar('\u{722B}')), // CJK RADICAL PAW ONE
+    ('\u{2EA6}', CharMapAction::SubChar('\u{4E2C}')), // CJK RADICAL SIMPLIFIED HALF TREE TRUNK
+    ('\u{2EA8}', CharMapAction::SubChar('\u{72AD}')), // CJK RADICAL DOG
+    ('\u{2EAB}', CharMapAction::SubChar('\u{7F52}')), // CJK RADICAL EYE
+    ('\u{2EAD}', CharMapAction::SubChar('\u{793B}')), // CJK RADICAL SPIRIT TWO
+    ('\u{2EAF}', CharMapAction::SubChar('\u{7CF9}')), // CJK RADICAL SILK
+    ('\u{2EB1}', CharMapAction::SubChar('\u{7F53}')), // CJK RADICAL NET ONE
+    ('\u{2EB2}', CharMapAction::SubChar('\u{7F52}')), // CJK RADICAL NET TWO
+    ('\u{2EB9}', CharMapAction::SubChar('\u{8002}')), // CJK RADICAL OLD
+    ('\u{2EBA}', CharMapAction::SubChar('\u{8080}')), // CJK RADICAL BRUSH ONE
+    ('\u{2EBE}', CharMapAction::SubChar('\u{8279}')), // CJK RADICAL GRASS ONE
+    ('\u{2EBF}', CharMapAction::SubChar('\u{8279}')), // CJK RADICAL GRASS TWO
+    ('\u{2EC0}', CharMapAction::SubChar('\u{8279}')), // CJK RADICAL GRASS THREE
+    ('\u{2EC1}', CharMapAction::SubChar('\u{864E}')), // CJK RADICAL TIGER
+    ('\u{2EC2}', CharMapAction::SubChar('\u{8864}')), // CJK RADICAL CLOTHES
+    ('\u{2EC3}', CharMapAction::SubChar('\u{8980}')), // CJK RADICAL WEST ONE
+    ('\u{2EC4}', CharMapAction::SubChar('\u{897F}')), // CJK RADICAL WEST TWO
+    ('\u{2EC5}', CharMapAction::SubChar('\u{89C1}')), // CJK RADICAL C-SIMPLIFIED SEE
+    ('\u{2EC8}', CharMapAction::SubChar('\u{8BA0}')), // CJK RADICAL C-SIMPLIFIED SPEECH
+    ('\u{2EC9}', CharMapAction::SubChar('\u{8D1D}')), // CJK RADICAL C-SIMPLIFIED SHELL
+    ('\u{2ECB}', CharMapAction::SubChar('\u{8F66}')), // CJK RADICAL C-SIMPLIFIED CART
+    ('\u{2ECC}', CharMapAction::SubChar('\u{8FB6}')), // CJK RADICAL SIMPLIFIED WALK
+    ('\u{2ECD}', CharMapAction::SubChar('\u{8FB6}')), // CJK RADICAL WALK ONE
+    ('\u{2ECF}', CharMapAction::SubChar('\u{961D}')), // CJK RADICAL CITY
+    ('\u{2ED0}', CharMapAction::SubChar('\u{9485}')), // CJK RADICAL C-SIMPLIFIED GOLD
+    ('\u{2ED1}', CharMapAction::SubChar('\u{9577}')), // CJK RADICAL LONG ONE
+    ('\u{2ED2}', CharMapAction::SubChar('\u{9578}')), // CJK RADICAL LONG TWO
+    ('\u{2ED3}', CharMapAction::SubChar('\u{957F}')), // CJK RADICAL C-SIMPLIFIED LONG
+    ('\u{2ED4}', CharMapAction::SubChar('\u{95E8}')), // CJK RADICAL C-SIMPLIFIED GATE
+    ('\u{2ED6}', CharMapAction::SubChar('\u{961D}')), // CJK RADICAL MOUND TWO
+    ('\u{2ED8}', CharMapAction::SubChar('\u{9752}')), // CJK RADICAL BLUE
+    ('\u{2ED9}', CharMapAction::SubChar('\u{97E6}')), // CJK RADICAL C-SIMPLIFIED TANNED LEATHER
+    ('\u{2EDA}', CharMapAction::SubChar('\u{9875}')), // CJK RADICAL C-SIMPLIFIED LEAF
+    ('\u{2EDB}', CharMapAction::SubChar('\u{98CE}')), // CJK RADICAL C-SIMPLIFIED WIND
+    ('\u{2EDC}', CharMapAction::SubChar('\u{98DE}')), // CJK RADICAL C-SIMPLIFIED FLY
+    ('\u{2EDD}', CharMapAction::SubChar('\u{98DF}')), // CJK RADICAL EAT ONE
+    ('\u{2EDF}', CharMapAction::SubChar('\u{98E0}')), // CJK RADICAL EAT THREE
+    ('\u{2EE0}', CharMapAction::SubChar('\u{9963}')), // CJK RADICAL C-SIMPLIFIED EAT
+    ('\u{2EE2}', CharMapAction::SubChar('\u{9A6C}')), // CJK RADICAL C-SIMPLIFIED HORSE
+    ('\u{2EE4}', CharMapAction::SubChar('\u{9B3C}')), // CJK RADICAL GHOST
+    ('\u{2EE5}', CharMapAction::SubChar('\u{9C7C}')), // CJK RADICAL C-SIMPLIFIED FISH
+    ('\u{2EE8}', CharMapAction::SubChar('\u{9EA6}')), // CJK RADICAL SIMPLIFIED WHEAT
+    ('\u{2EE9}', CharMapAction::SubChar('\u{9EC4}')), // CJK RADICAL SIMPLIFIED YELLOW
+    ('\u{2EEB}', CharMapAction::SubChar('\u{6589}')), // CJK RADICAL J-SIMPLIFIED EVEN
+    ('\u{2EEC}', CharMapAction::SubChar('\u{9F50}')), // CJK RADICAL C-SIMPLIFIED EVEN
+    ('\u{2EED}', CharMapAction::SubChar('\u{6B6F}')), // CJK RADICAL J-SIMPLIFIED TOOTH
+    ('\u{2EEE}', CharMapAction::SubChar('\u{9F7F}')), // CJK RADICAL C-SIMPLIFIED TOOTH
+    ('\u{2EEF}', CharMapAction::SubChar('\u{7ADC}')), // CJK RADICAL J-SIMPLIFIED DRAGON
+    ('\u{2EF0}', CharMapAction::SubChar('\u{9F99}')), // CJK RADICAL C-SIMPLIFIED DRAGON
+    ('\u{2EF2}', CharMapAction::SubChar('\u{4E80}')), // CJK RADICAL J-SIMPLIFIED TURTLE
+    ('\u{2EF3}', CharMapAction::SubChar('\u{9F9F}')), // CJK RADICAL C-SIMPLIFIED TURTLE
+    ('\u{2F00}', CharMapAction::SubChar('\u{30FC}')), // KANGXI RADICAL ONE
+    ('\u{2F01}', CharMapAction::SubChar('\u{4E28}')), // KANGXI RADICAL LINE
+    ('\u{2F02}', CharMapAction::SubChar('\\')), // KANGXI RADICAL DOT
+    ('\u{2F03}', CharMapAction::SubChar('/')), // KANGXI RADICAL SLASH
+    ('\u{2F04}', CharMapAction::SubChar('\u{4E59}')), // KANGXI RADICAL SECOND
+    ('\u{2F05}', CharMapAction::SubChar('\u{4E85}')), // KANGXI RADICAL HOOK
+    ('\u{2F06}', CharMapAction::SubChar('\u{4E8C}')), // KANGXI RADICAL TWO
+    ('\u{2F07}', CharMapAction::SubChar('\u{4EA0}')), // KANGXI RADICAL LID
+    ('\u{2F08}', CharMapAction::SubChar('\u{4EBA}')), // KANGXI RADICAL MAN
+    ('\u{2F09}', CharMapAction::SubChar('\u{513F}')), // KANGXI RADICAL LEGS
+    ('\u{2F0A}', CharMapAction::SubChar('\u{5165}')), // KANGXI RADICAL ENTER
+    ('\u{2F0B}', CharMapAction::SubChar('\u{516B}')), // KANGXI RADICAL EIGHT
+    ('\u{2F0C}', CharMapAction::SubChar('\u{5182}')), // KANGXI RADICAL DOWN BOX
+    ('\u{2F0D}', CharMapAction::SubChar('\u{5196}')), // KANGXI RADICAL COVER
+    ('\u{2F0E}', CharMapAction::SubChar('\u{51AB}')), // KANGXI RADICAL ICE
+    ('\u{2F0F}', CharMapAction::SubChar('\u{51E0}')), // KANGXI RADICAL TABLE
+    ('\u{2F10}', CharMapAction::SubChar('\u{51F5}')), // KANGXI RADICAL OPEN BOX
+    ('\u{2F11}', CharMapAction::SubChar('\u{5200}')), // KANGXI RADICAL KNIFE
+    ('\u{2F12}', CharMapAction::SubChar('\u{529B}')), // KANGXI RADICAL POWER
+    ('\u{2F13}', CharMapAction::SubChar('\u{52F9}')), // KANGXI RADICAL WRAP
+    ('\u{2F14}', CharMapAction::SubChar('\u{5315}')), // KANGXI RADICAL SPOON
+    ('\u{2F15}', CharMapAction::SubChar('\u{531A}')), // KANGXI RADICAL RIGHT OPEN BOX
+    ('\u{2F16}', CharMapAction::SubChar('\u{5338}')), // KANGXI RADICAL HIDING ENCLOSURE
+    ('\u{2F17}', CharMapAction::SubChar('\u{5341}')), // KANGXI RADICAL TEN
+    ('\u{2F18}', CharMapAction::SubChar('\u{535C}')), // KANGXI RADICAL DIVINATION
+    ('\u{2F19}', CharMapAction::SubChar('\u{5369}')), // KANGXI RADICAL SEAL
+    ('\u{2F1A}', CharMapAction::SubChar('\u{5382}')), // KANGXI RADICAL CLIFF
+    ('\u{2F1B}', CharMapAction::SubChar('\u{53B6}')), // KANGXI RADICAL PRIVATE
+    ('\u{2F1C}', CharMapAction::SubChar('\u{53C8}')), // KANGXI RADICAL AGAIN
+    ('\u{2F1D}', CharMapAction::SubChar('\u{53E3}')), // KANGXI RADICAL MOUTH
+    ('\u{2F1E}', CharMapAction::SubChar('\u{53E3}')), // KANGXI RADICAL ENCLOSURE
+    ('\u{2F1F}', CharMapAction::SubChar('\u{571F}')), // KANGXI RADICAL EARTH
+    ('\u{2F20}', CharMapAction::SubChar('\u{571F}')), // KANGXI RADICAL SCHOLAR
+    ('\u{2F21}', CharMapAction::SubChar('\u{5902}')), // KANGXI RADICAL GO
+    ('\u{2F22}', CharMapAction::SubChar('\u{590A}')), // KANGXI RADICAL GO SLOWLY
+    ('\u{2F23}', CharMapAction::SubChar('\u{5915}')), // KANGXI RADICAL EVENING
+    ('\u{2F24}', CharMapAction::SubChar('\u{5927}')), // KANGXI RADICAL BIG
+    ('\u{2F25}', CharMapAction::SubChar('\u{5973}')), // KANGXI RADICAL WOMAN
+    ('\u{2F26}', CharMapAction::SubChar('\u{5B50}')), // KANGXI RADICAL CHILD
+    ('\u{2F27}', CharMapAction::SubChar('\u{5B80}')), // KANGXI RADICAL ROOF
+    ('\u{2F28}', CharMapAction::SubChar('\u{5BF8}')), // KANGXI RADICAL INCH
+    ('\u{2F29}', CharMapAction::SubChar('\u{5C0F}')), // KANGXI RADICAL SMALL
+    ('\u{2F2A}', CharMapAction::SubChar('\u{5C22}')), // KANGXI RADICAL LAME
+    ('\u{2F2B}', CharMapAction::SubChar('\u{5C38}')), // KANGXI RADICAL CORPSE
+    ('\u{2F2C}', CharMapAction::SubChar('\u{5C6E}')), // KANGXI RADICAL SPROUT
+    ('\u{2F2D}', CharMapAction::SubChar('\u{5C71}')), // KANGXI RADICAL MOUNTAIN
+    ('\u{2F2E}', CharMapAction::SubChar('\u{5DDB}')), // KANGXI RADICAL RIVER
+    ('\u{2F2F}', CharMapAction::SubChar('\u{5DE5}')), // KANGXI RADICAL WORK
+    ('\u{2F30}', CharMapAction::SubChar('\u{5DF1}')), // KANGXI RADICAL ONESELF
+    ('\u{2F31}', CharMapAction::SubChar('\u{5DFE}')), // KANGXI RADICAL TURBAN
+    ('\u{2F32}', CharMapAction::SubChar('\u{5E72}')), // KANGXI RADICAL DRY
+    ('\u{2F33}', CharMapAction::SubChar('\u{5E7A}')), // KANGXI RADICAL SHORT THREAD
+    ('\u{2F34}', CharMapAction::SubChar('\u{5E7F}')), // KANGXI RADICAL DOTTED CLIFF
+    ('\u{2F35}', CharMapAction::SubChar('\u{5EF4}')), // KANGXI RADICAL LONG STRIDE
+    ('\u{2F36}', CharMapAction::SubChar('\u{5EFE}')), // KANGXI RADICAL TWO HANDS
+    ('\u{2F37}', CharMapAction::SubChar('\u{5F0B}')), // KANGXI RADICAL SHOOT
+    ('\u{2F38}', CharMapAction::SubChar('\u{5F13}')), // KANGXI RADICAL BOW
+    ('\u{2F39}', CharMapAction::SubChar('\u{5F50}')), // KANGXI RADICAL SNOUT
+    ('\u{2F3A}', CharMapAction::SubChar('\u{5F61}')), // KANGXI RADICAL BRISTLE
+    ('\u{2F3B}', CharMapAction::SubChar('\u{5F73}')), // KANGXI RADICAL STEP
+    ('\u{2F3C}', CharMapAction::SubChar('\u{5FC3}')), // KANGXI RADICAL HEART
+    ('\u{2F3D}', CharMapAction::SubChar('\u{6208}')), // KANGXI RADICAL HALBERD
+    ('\u{2F3E}', CharMapAction::SubChar('\u{6236}')), // KANGXI RADICAL DOOR
+    ('\u{2F3F}', CharMapAction::SubChar('\u{624B}')), // KANGXI RADICAL HAND
+    ('\u{2F40}', CharMapAction::SubChar('\u{652F}')), // KANGXI RADICAL BRANCH
+    ('\u{2F41}', CharMapAction::SubChar('\u{6534}')), // KANGXI RADICAL RAP
+    ('\u{2F42}', CharMapAction::SubChar('\u{6587}')), // KANGXI RADICAL SCRIPT
+    ('\u{2F43}', CharMapAction::SubChar('\u{6597}')), // KANGXI RADICAL DIPPER
+    ('\u{2F44}', CharMapAction::SubChar('\u{65A4}')), // KANGXI RADICAL AXE
+    ('\u{2F45}', CharMapAction::SubChar('\u{65B9}')), // KANGXI RADICAL SQUARE
+    ('\u{2F46}', CharMapAction::SubChar('\u{65E0}')), // KANGXI RADICAL NOT
+    ('\u{2F47}', CharMapAction::SubChar('\u{65E5}')), // KANGXI RADICAL SUN
+    ('\u{2F48}', CharMapAction::SubChar('\u{66F0}')), // KANGXI RADICAL SAY
+    ('\u{2F49}', CharMapAction::SubChar('\u{6708}')), // KANGXI RADICAL MOON
+    ('\u{2F4A}', CharMapAction::SubChar('\u{6728}')), // KANGXI RADICAL TREE
+    ('\u{2F4B}', CharMapAction::SubChar('\u{6B20}')), // KANGXI RADICAL LACK
+    ('\u{2F4C}', CharMapAction::SubChar('\u{6B62}')), // KANGXI RADICAL STOP
+    ('\u{2F4D}', CharMapAction::SubChar('\u{6B79}')), // KANGXI RADICAL DEATH
+    ('\u{2F4E}', CharMapAction::SubChar('\u{6BB3}')), // KANGXI RADICAL WEAPON
+    ('\u{2F4F}', CharMapAction::SubChar('\u{6BCB}')), // KANGXI RADICAL DO NOT
+    ('\u{2F50}', CharMapAction::SubChar('\u{6BD4}')), // KANGXI RADICAL COMPARE
+    ('\u{2F51}', CharMapAction::SubChar('\u{6BDB}')), // KANGXI RADICAL FUR
+    ('\u{2F52}', CharMapAction::SubChar('\u{6C0F}')), // KANGXI RADICAL CLAN
+    ('\u{2F53}', CharMapAction::SubChar('\u{6C14}')), // KANGXI RADICAL STEAM
+    ('\u{2F54}', CharMapAction::SubChar('\u{6C34}')), // KANGXI RADICAL WATER
+    ('\u{2F55}', CharMapAction::SubChar('\u{706B}')), // KANGXI RADICAL FIRE
+    ('\u{2F56}', CharMapAction::SubChar('\u{722A}')), // KANGXI RADICAL CLAW
+    ('\u{2F57}', CharMapAction::SubChar('\u{7236}')), // KANGXI RADICAL FATHER
+    ('\u{2F58}', CharMapAction::SubChar('\u{723B}')), // KANGXI RADICAL DOUBLE X
+    ('\u{2F59}', CharMapAction::SubChar('\u{723F}')), // KANGXI RADICAL HALF TREE TRUNK
+    ('\u{2F5A}', CharMapAction::SubChar('\u{7247}')), // KANGXI RADICAL SLICE
+    ('\u{2F5B}', CharMapAction::SubChar('\u{7259}')), // KANGXI RADICAL FANG
+    ('\u{2F5C}', CharMapAction::SubChar('\u{725B}')), // KANGXI RADICAL COW
+    ('\u{2F5D}', CharMapAction::SubChar('\u{72AC}')), // KANGXI RADICAL DOG
+    ('\u{2F5E}', CharMapAction::SubChar('\u{7384}')), // KANGXI RADICAL PROFOUND
+    ('\u{2F5F}', CharMapAction::SubChar('\u{7389}')), // KANGXI RADICAL JADE
+    ('\u{2F60}', CharMapAction::SubChar('\u{74DC}')), // KANGXI RADICAL MELON
+    ('\u{2F61}', CharMapAction::SubChar('\u{74E6}')), // KANGXI RADICAL TILE
+    ('\u{2F62}', CharMapAction::SubChar('\u{7518}')), // KANGXI RADICAL SWEET
+    ('\u{2F63}', CharMapAction::SubChar('\u{751F}')), // KANGXI RADICAL LIFE
+    ('\u{2F64}', CharMapAction::SubChar('\u{7528}')), // KANGXI RADICAL USE
+    ('\u{2F65}', CharMapAction::SubChar('\u{7530}')), // KANGXI RADICAL FIELD
+    ('\u{2F66}', CharMapAction::SubChar('\u{758B}')), // KANGXI RADICAL BOLT OF CLOTH
+    ('\u{2F67}', CharMapAction::SubChar('\u{7592}')), // KANGXI RADICAL SICKNESS
+    ('\u{2F68}', CharMapAction::SubChar('\u{7676}')), // KANGXI RADICAL DOTTED TENT
+    ('\u{2F69}', CharMapAction::SubChar('\u{767D}')), // KANGXI RADICAL WHITE
+    ('\u{2F6A}', CharMapAction::SubChar('\u{76AE}')), // KANGXI RADICAL SKIN
+    ('\u{2F6B}', CharMapAction::SubChar('\u{76BF}')), // KANGXI RADICAL DISH
+    ('\u{2F6C}', CharMapAction::SubChar('\u{76EE}')), // KANGXI RADICAL EYE
+    ('\u{2F6D}', CharMapAction::SubChar('\u{77DB}')), // KANGXI RADICAL SPEAR
+    ('\u{2F6E}', CharMapAction::SubChar('\u{77E2}')), // KANGXI RADICAL ARROW
+    ('\u{2F6F}', CharMapAction::SubChar('\u{77F3}')), // KANGXI RADICAL STONE
+    ('\u{2F70}', CharMapAction::SubChar('\u{793A}')), // KANGXI RADICAL SPIRIT
+    ('\u{2F71}', CharMapAction::SubChar('\u{79B8}')), // KANGXI RADICAL TRACK
+    ('\u{2F72}', CharMapAction::SubChar('\u{79BE}')), // KANGXI RADICAL GRAIN
+    ('\u{2F73}', CharMapAction::SubChar('\u{7A74}')), // KANGXI RADICAL CAVE
+    ('\u{2F74}', CharMapAction::SubChar('\u{7ACB}')), // KANGXI RADICAL STAND
+    ('\u{2F75}', CharMapAction::SubChar('\u{7AF9}')), // KANGXI RADICAL BAMBOO
+    ('\u{2F76}', CharMapAction::SubChar('\u{7C73}')), // KANGXI RADICAL RICE
+    ('\u{2F77}', CharMapAction::SubChar('\u{7CF8}')), // KANGXI RADICAL SILK
+    ('\u{2F78}', CharMapAction::SubChar('\u{7F36}')), // KANGXI RADICAL JAR
+    ('\u{2F79}', CharMapAction::SubChar('\u{7F51}')), // KANGXI RADICAL NET
+    ('\u{2F7A}', CharMapAction::SubChar('\u{7F8A}')), // KANGXI RADICAL SHEEP
+    ('\u{2F7B}', CharMapAction::SubChar('\u{7FBD}')), // KANGXI RADICAL FEATHER
+    ('\u{2F7C}', CharMapAction::SubChar('\u{8001}')), // KANGXI RADICAL OLD
+    ('\u{2F7D}', CharMapAction::SubChar('\u{800C}')), // KANGXI RADICAL AND
+    ('\u{2F7E}', CharMapAction::SubChar('\u{8012}')), // KANGXI RADICAL PLOW
+    ('\u{2F7F}', CharMapAction::SubChar('\u{8033}')), // KANGXI RADICAL EAR
+    ('\u{2F80}', CharMapAction::SubChar('\u{807F}')), // KANGXI RADICAL BRUSH
+    ('\u{2F81}', CharMapAction::SubChar('\u{8089}')), // KANGXI RADICAL MEAT
+    ('\u{2F82}', CharMapAction::SubChar('\u{81E3}')), // KANGXI RADICAL MINISTER
+    ('\u{2F83}', CharMapAction::SubChar('\u{81EA}')), // KANGXI RADICAL SELF
+    ('\u{2F84}', CharMapAction::SubChar('\u{81F3}')), // KANGXI RADICAL ARRIVE
+    ('\u{2F85}', CharMapAction::SubChar('\u{81FC}')), // KANGXI RADICAL MORTAR
+    ('\u{2F86}', CharMapAction::SubChar('\u{820C}')), // KANGXI RADICAL TONGUE
+    ('\u{2F87}', CharMapAction::SubChar('\u{821B}')), // KANGXI RADICAL OPPOSE
+    ('\u{2F88}', CharMapAction::SubChar('\u{821F}')), // KANGXI RADICAL BOAT
+    ('\u{2F89}', CharMapAction::SubChar('\u{826E}')), // KANGXI RADICAL STOPPING
+    ('\u{2F8A}', CharMapAction::SubChar('\u{8272}')), // KANGXI RADICAL COLOR
+    ('\u{2F8B}', CharMapAction::SubChar('\u{8278}')), // KANGXI RADICAL GRASS
+    ('\u{2F8C}', CharMapAction::SubChar('\u{864D}')), // KANGXI RADICAL TIGER
+    ('\u{2F8D}', CharMapAction::SubChar('\u{866B}')), // KANGXI RADICAL INSECT
+    ('\u{2F8E}', CharMapAction::SubChar('\u{8840}')), // KANGXI RADICAL BLOOD
+    ('\u{2F8F}', CharMapAction::SubChar('\u{884C}')), // KANGXI RADICAL WALK ENCLOSURE
+    ('\u{2F90}', CharMapAction::SubChar('\u{8863}')), // KANGXI RADICAL CLOTHES
+    ('\u{2F91}', CharMapAction::SubChar('\u{897E}')), // KANGXI RADICAL WEST
+    ('\u{2F92}', CharMapAction::SubChar('\u{898B}')), // KANGXI RADICAL SEE
+    ('\u{2F93}', CharMapAction::SubChar('\u{89D2}')), // KANGXI RADICAL HORN
+    ('\u{2F94}', CharMapAction::SubChar('\u{8A00}')), // KANGXI RADICAL SPEECH
+    ('\u{2F95}', CharMapAction::SubChar('\u{8C37}')), // KANGXI RADICAL VALLEY
+    ('\u{2F96}', CharMapAction::SubChar('\u{8C46}')), // KANGXI RADICAL BEAN
+    ('\u{2F97}', CharMapAction::SubChar('\u{8C55}')), // KANGXI RADICAL PIG
+    ('\u{2F98}', CharMapAction::SubChar('\u{8C78}')), // KANGXI RADICAL BADGER
+    ('\u{2F99}', CharMapAction::SubChar('\u{8C9D}')), // KANGXI RADICAL SHELL
+    ('\u{2F9A}', CharMapAction::SubChar('\u{8D64}')), // KANGXI RADICAL RED
+    ('\u{2F9B}', CharMapAction::SubChar('\u{8D70}')), // KANGXI RADICAL RUN
+    ('\u{2F9C}', CharMapAction::SubChar('\u{8DB3}')), // KANGXI RADICAL FOOT
+    ('\u{2F9D}', CharMapAction::SubChar('\u{8EAB}')), // KANGXI RADICAL BODY
+    ('\u{2F9E}', CharMapAction::SubChar('\u{8ECA}')), // KANGXI RADICAL CART
+    ('\u{2F9F}', CharMapAction::SubChar('\u{8F9B}')), // KANGXI RADICAL BITTER
+    ('\u{2FA0}', CharMapAction::SubChar('\u{8FB0}')), // KANGXI RADICAL MORNING
+    ('\u{2FA1}', CharMapAction::SubChar('\u{8FB5}')), // KANGXI RADICAL WALK
+    ('\u{2FA2}', CharMapAction::SubChar('\u{9091}')), // KANGXI RADICAL CITY
+    ('\u{2FA3}', CharMapAction::SubChar('\u{9149}')), // KANGXI RADICAL WINE
+    ('\u{2FA4}', CharMapAction::SubChar('\u{91C6}')), // KANGXI RADICAL DISTINGUISH
+    ('\u{2FA5}', CharMapAction::SubChar('\u{91CC}')), // KANGXI RADICAL VILLAGE
+    ('\u{2FA6}', CharMapAction::SubChar('\u{91D1}')), // KANGXI RADICAL GOLD
+    ('\u{2FA7}', CharMapAction::SubChar('\u{9577}')), // KANGXI RADICAL LONG
+    ('\u{2FA8}', CharMapAction::SubChar('\u{9580}')), // KANGXI RADICAL GATE
+    ('\u{2FA9}', CharMapAction::SubChar('\u{961C}')), // KANGXI RADICAL MOUND
+    ('\u{2FAA}', CharMapAction::SubChar('\u{96B6}')), // KANGXI RADICAL SLAVE
+    ('\u{2FAB}', CharMapAction::SubChar('\u{96B9}')), // KANGXI RADICAL SHORT TAILED BIRD
+    ('\u{2FAC}', CharMapAction::SubChar('\u{96E8}')), // KANGXI RADICAL RAIN
+    ('\u{2FAD}', CharMapAction::SubChar('\u{9751}')), // KANGXI RADICAL BLUE
+    ('\u{2FAE}', CharMapAction::SubChar('\u{975E}')), // KANGXI RADICAL WRONG
+    ('\u{2FAF}', CharMapAction::SubChar('\u{9762}')), // KANGXI RADICAL FACE
+    ('\u{2FB0}', CharMapAction::SubChar('\u{9769}')), // KANGXI RADICAL LEATHER
+    ('\u{2FB1}', CharMapAction::SubChar('\u{97CB}')), // KANGXI RADICAL TANNED LEATHER
+    ('\u{2FB2}', CharMapAction::SubChar('\u{97ED}')), // KANGXI RADICAL LEEK
+    ('\u{2FB3}', CharMapAction::SubChar('\u{97F3}')), // KANGXI RADICAL SOUND
+    ('\u{2FB4}', CharMapAction::SubChar('\u{9801}')), // KANGXI RADICAL LEAF
+    ('\u{2FB5}', CharMapAction::SubChar('\u{98A8}')), // KANGXI RADICAL WIND
+    ('\u{2FB6}', CharMapAction::SubChar('\u{98DB}')), // KANGXI RADICAL FLY
+    ('\u{2FB7}', CharMapAction::SubChar('\u{98DF}')), // KANGXI RADICAL EAT
+    ('\u{2FB8}', CharMapAction::SubChar('\u{9996}')), // KANGXI RADICAL HEAD
+    ('\u{2FB9}', CharMapAction::SubChar('\u{9999}')), // KANGXI RADICAL FRAGRANT
+    ('\u{2FBA}', CharMapAction::SubChar('\u{99AC}')), // KANGXI RADICAL HORSE
+    ('\u{2FBB}', CharMapAction::SubChar('\u{9AA8}')), // KANGXI RADICAL BONE
+    ('\u{2FBC}', CharMapAction::SubChar('\u{9AD8}')), // KANGXI RADICAL TALL
+    ('\u{2FBD}', CharMapAction::SubChar('\u{9ADF}')), // KANGXI RADICAL HAIR
+    ('\u{2FBE}', CharMapAction::SubChar('\u{9B25}')), // KANGXI RADICAL FIGHT
+    ('\u{2FBF}', CharMapAction::SubChar('\u{9B2F}')), // KANGXI RADICAL SACRIFICIAL WINE
+    ('\u{2FC0}', CharMapAction::SubChar('\u{9B32}')), // KANGXI RADICAL CAULDRON
+    ('\u{2FC1}', CharMapAction::SubChar('\u{9B3C}')), // KANGXI RADICAL GHOST
+    ('\u{2FC2}', CharMapAction::SubChar('\u{9B5A}')), // KANGXI RADICAL FISH
+    ('\u{2FC3}', CharMapAction::SubChar('\u{9CE5}')), // KANGXI RADICAL BIRD
+    ('\u{2FC4}', CharMapAction::SubChar('\u{9E75}')), // KANGXI RADICAL SALT
+    ('\u{2FC5}', CharMapAction::SubChar('\u{9E7F}')), // KANGXI RADICAL DEER
+    ('\u{2FC6}', CharMapAction::SubChar('\u{9EA5}')), // KANGXI RADICAL WHEAT
+    ('\u{2FC7}', CharMapAction::SubChar('\u{9EBB}')), // KANGXI RADICAL HEMP
+    ('\u{2FC8}', CharMapAction::SubChar('\u{9EC3}')), // KANGXI RADICAL YELLOW
+    ('\u{2FC9}', CharMapAction::SubChar('\u{9ECD}')), // KANGXI RADICAL MILLET
+    ('\u{2FCA}', CharMapAction::SubChar('\u{9ED1}')), // KANGXI RADICAL BLACK
+    ('\u{2FCB}', CharMapAction::SubChar('\u{9EF9}')), // KANGXI RADICAL EMBROIDERY
+    ('\u{2FCC}', CharMapAction::SubChar('\u{9EFD}')), // KANGXI RADICAL FROG
+    ('\u{2FCD}', CharMapAction::SubChar('\u{9F0E}')), // KANGXI RADICAL TRIPOD
+    ('\u{2FCE}', CharMapAction::SubChar('\u{9F13}')), // KANGXI RADICAL DRUM
+    ('\u{2FCF}', CharMapAction::SubChar('\u{9F20}')), // KANGXI RADICAL RAT
+    ('\u{2FD0}', CharMapAction::SubChar('\u{9F3B}')), // KANGXI RADICAL NOSE
+    ('\u{2FD1}', CharMapAction::SubChar('\u{9F4A}')), // KANGXI RADICAL EVEN
+    ('\u{2FD2}', CharMapAction::SubChar('\u{9F52}')), // KANGXI RADICAL TOOTH
+    ('\u{2FD3}', CharMapAction::SubChar('\u{9F8D}')), // KANGXI RADICAL DRAGON
+    ('\u{2FD4}', CharMapAction::SubChar('\u{9F9C}')), // KANGXI RADICAL TURTLE
+    ('\u{2FD5}', CharMapAction::SubChar('\u{9FA0}')), // KANGXI RADICAL FLUTE
+    ('\u{3002}', CharMapAction::SubChar('\u{02F3}')), // IDEOGRAPHIC FULL STOP
+    ('\u{3003}', CharMapAction::SubStr("''")), // DITTO MARK
+    ('\u{3007}', CharMapAction::SubChar('O')), // IDEOGRAPHIC NUMBER ZERO
+    ('\u{3008}', CharMapAction::SubChar('\u{276C}')), // LEFT ANGLE BRACKET
+    ('\u{3009}', CharMapAction::SubChar('\u{276D}')), // RIGHT ANGLE BRACKET
+    ('\u{3012}', CharMapAction::SubChar('\u{20B8}')), // POSTAL MARK
+    ('\u{3014}', CharMapAction::SubChar('(')), // LEFT TORTOISE SHELL BRACKET
+    ('\u{3015}', CharMapAction::SubChar(')')), // RIGHT TORTOISE SHELL BRACKET
+    ('\u{301A}', CharMapAction::SubChar('\u{27E6}')), // LEFT WHITE SQUARE BRACKET
+    ('\u{301B}', CharMapAction::SubChar('\u{27E7}')), // RIGHT WHITE SQUARE BRACKET
+    ('\u{302C}', CharMapAction::SubChar('\u{0309}')), // IDEOGRAPHIC DEPARTING TONE MARK
+    ('\u{302D}', CharMapAction::SubChar('\u{0325}')), // IDEOGRAPHIC ENTERING TONE MARK
+    ('\u{3033}', CharMapAction::SubChar('/')), // VERTICAL KANA REPEAT MARK UPPER HALF
+    ('\u{3036}', CharMapAction::SubChar('\u{20B8}')), // CIRCLED POSTAL MARK
+    ('\u{3038}', CharMapAction::SubChar('\u{5341}')), // HANGZHOU NUMERAL TEN
+    ('\u{3039}', CharMapAction::SubChar('\u{5344}')), // HANGZHOU NUMERAL TWENTY
+    ('\u{303A}', CharMapAction::SubChar('\u{5345}')), // HANGZHOU NUMERAL THIRTY
+    ('\u{304F}', CharMapAction::SubChar('\u{276C}')), // HIRAGANA LETTER KU
+    ('\u{309A}', CharMapAction::SubChar('\u{030A}')), // COMBINING KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK
+    ('\u{309B}', CharMapAction::SubChar('\u{FF9E}')), // KATAKANA-HIRAGANA VOICED SOUND MARK
+    ('\u{309C}', CharMapAction::SubChar('\u{FF9F}')), // KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK
+    ('\u{30A0}', CharMapAction::SubChar('=')), // KATAKANA-HIRAGANA DOUBLE HYPHEN
+    ('\u{30A4}', CharMapAction::SubChar('\u{4EBB}')), // KATAKANA LETTER I
+    ('\u{30A8}', CharMapAction::SubChar('\u{5DE5}')), // KATAKANA LETTER E
+    ('\u{30AB}', CharMapAction::SubChar('\u{529B}')), // KATAKANA LETTER KA
+    ('\u{30BF}', CharMapAction::SubChar('\u{5915}')), // KATAKANA LETTER TA
+    ('\u{30C8}', CharMapAction::SubChar('\u{535C}')), // KATAKANA LETTER TO
+    ('\u{30CB}', CharMapAction::SubChar('\u{4E8C}')), // KATAKANA LETTER NI
+    ('\u{30CE}', CharMapAction::SubChar('/')), // KATAKANA LETTER NO
+    ('\u{30CF}', CharMapAction::SubChar('\u{516B}')), // KATAKANA LETTER HA
+    ('\u{30D8}', CharMapAction::SubChar('\u{3078}')), // KATAKANA LETTER HE
+    ('\u{30ED}', CharMapAction::SubChar('\u{53E3}')), // KATAKANA LETTER RO
+    ('\u{30FB}', CharMapAction::SubChar('\u{00B7}')), // KATAKANA MIDDLE DOT
+    ('\u{3126}', CharMapAction::SubChar('\u{513F}')), // BOPOMOFO LETTER ER
+    ('\u{3131}', CharMapAction::SubChar('\u{1100}')), // HANGUL LETTER KIYEOK
+    ('\u{3132}', CharMapAction::SubStr("\u{1100}\u{1100}")), // HANGUL LETTER SSANGKIYEOK
+    ('\u{3133}', CharMapAction::SubStr("\u{1100}\u{1109}")), // HANGUL LETTER KIYEOK-SIOS
+    ('\u{3134}', CharMapAction::SubChar('\u{1102}')), // HANGUL LETTER NIEUN
+    ('\u{3135}', CharMapAction::SubStr("\u{1102}\u{110C}")), // HANGUL LETTER NIEUN-CIEUC
+    ('\u{3136}', CharMapAction::SubStr("\u{1102}\u{1112}")), // HANGUL LETTER NIEUN-HIEUH
+    ('\u{3137}', CharMapAction::SubChar('\u{1103}')), // HANGUL LETTER TIKEUT
+    ('\u{3138}', CharMapAction::SubStr("\u{1103}\u{1103}")), // HANGUL LETTER SSANGTIKEUT
+    ('\u{3139}', CharMapAction::SubChar('\u{1105}')), // HANGUL LETTER RIEUL
+    ('\u{313A}', CharMapAction::SubStr("\u{1105}\u{1100}")), // HANGUL LETTER RIEUL-KIYEOK
+    ('\u{313B}', CharMapAction::SubStr("\u{1105}\u{1106}")), // HANGUL LETTER RIEUL-MIEUM
+    ('\u{313C}', CharMapAction::SubStr("\u{1105}\u{1107}")), // HANGUL LETTER RIEUL-PIEUP
+    ('\u{313D}', CharMapAction::SubStr("\u{1105}\u{1109}")), // HANGUL LETTER RIEUL-SIOS
+    ('\u{313E}', CharMapAction::SubStr("\u{1105}\u{1110}")), // HANGUL LETTER RIEUL-THIEUTH
+    ('\u{313F}', CharMapAction::SubStr("\u{1105}\u{1111}")), // HANGUL LETTER RIEUL-PHIEUPH
+    ('\u{3140}', CharMapAction::SubStr("\u{1105}\u{1112}")), // HANGUL LETTER RIEUL-HIEUH
+    ('\u{3141}', CharMapAction::SubChar('\u{1106}')), // HANGUL LETTER MIEUM
+    ('\u{3142}', CharMapAction::SubChar('\u{1107}')), // HANGUL LETTER PIEUP
+    ('\u{3143}', CharMapAction::SubStr("\u{1107}\u{1107}")), // HANGUL LETTER SSANGPIEUP
+    ('\u{3144}', CharMapAction::SubStr("\u{1107}\u{1109}")), // HANGUL LETTER PIEUP-SIOS
+    ('\u{3145}', CharMapAction::SubChar('\u{1109}')), // HANGUL LETTER SIOS
+    ('\u{3146}', CharMapAction::SubStr("\u{1109}\u{1109}")), // HANGUL LETTER SSANGSIOS
+    ('\u{3147}', CharMapAction::SubChar('\u{110B}')), // HANGUL LETTER IEUNG
+    ('\u{3148}', CharMapAction::SubChar('\u{110C}')), // HANGUL LETTER CIEUC
+    ('\u{3149}', CharMapAction::SubStr("\u{110C}\u{110C}")), // HANGUL LETTER SSANGCIEUC
+    ('\u{314A}', CharMapAction::SubChar('\u{110E}')), // HANGUL LETTER CHIEUCH
+    ('\u{314B}', CharMapAction::SubChar('\u{110F}')), // HANGUL LETTER KHIEUKH
+    ('\u{314C}', CharMapAction::SubChar('\u{1110}')), // HANGUL LETTER THIEUTH
+    ('\u{314D}', CharMapAction::SubChar('\u{1111}')), // HANGUL LETTER PHIEUPH
+    ('\u{314E}', CharMapAction::SubChar('\u{1112}')), // HANGUL LETTER HIEUH
+    ('\u{314F}', CharMapAction::SubChar('\u{1161}')), // HANGUL LETTER A
+    ('\u{3150}', CharMapAction::SubStr("\u{1161}\u{4E28}")), // HANGUL LETTER AE
+    ('\u{3151}', CharMapAction::SubChar('\u{1163}')), // HANGUL LETTER YA
+    ('\u{3152}', CharMapAction::SubStr("\u{1163}\u{4E28}")), // HANGUL LETTER YAE
+    ('\u{3153}', CharMapAction::SubChar('\u{1165}')), // HANGUL LETTER EO
+    ('\u{3154}', CharMapAction::SubStr("\u{1165}\u{4E28}")), // HANGUL LETTER E
+    ('\u{3155}', CharMapAction::SubChar('\u{1167}')), // HANGUL LETTER YEO
+    ('\u{3156}', CharMapAction::SubStr("\u{1167}\u{4E28}")), // HANGUL LETTER YE
+    ('\u{3157}', CharMapAction::SubChar('\u{1169}')), // HANGUL LETTER O
+    ('\u{3158}', CharMapAction::SubStr("\u{1169}\u{1161}")), // HANGUL LETTER WA
+    ('\u{3159}', CharMapAction::SubStr("\u{1169}\u{1161}\u{4E28}")), // HANGUL LETTER WAE
+    ('\u{315A}', CharMapAction::SubStr("\u{1169}\u{4E28}")), // HANGUL LETTER OE
+    ('\u{315B}', CharMapAction::SubChar('\u{116D}')), // HANGUL LETTER YO
+    ('\u{315C}', CharMapAction::SubChar('\u{116E}')), // HANGUL LETTER U
+    ('\u{315D}', CharMapAction::SubStr("\u{116E}\u{1165}")), // HANGUL LETTER WEO
+    ('\u{315E}', CharMapAction::SubStr("\u{116E}\u{1165}\u{4E28}")), // HANGUL LETTER WE
+    ('\u{315F}', CharMapAction::SubStr("\u{116E}\u{4E28}")), // HANGUL LETTER WI
+    ('\u{3160}', CharMapAction::SubChar('\u{1172}')), // HANGUL LETTER YU
+    ('\u{3161}', CharMapAction::SubChar('\u{30FC}')), // HANGUL LETTER EU
+    ('\u{3162}', CharMapAction::SubStr("\u{30FC}\u{4E28}")), // HANGUL LETTER YI
+    ('\u{3163}', CharMapAction::SubChar('\u{4E28}')), // HANGUL LETTER I
+    ('\u{3164}', CharMapAction::SubChar('\u{1160}')), // HANGUL FILLER
+    ('\u{3165}', CharMapAction::SubStr("\u{1102}\u{1102}")), // HANGUL LETTER SSANGNIEUN
+    ('\u{3166}', CharMapAction::SubStr("\u{1102}\u{1103}")), // HANGUL LETTER NIEUN-TIKEUT
+    ('\u{3167}', CharMapAction::SubStr("\u{1102}\u{1109}")), // HANGUL LETTER NIEUN-SIOS
+    ('\u{3168}', CharMapAction::SubStr("\u{1102}\u{1140}")), // HANGUL LETTER NIEUN-PANSIOS
+    ('\u{3169}', CharMapAction::SubStr("\u{1105}\u{1100}\u{1109}")), // HANGUL LETTER RIEUL-KIYEOK-SIOS
+    ('\u{316A}', CharMapAction::SubStr("\u{1105}\u{1103}")), // HANGUL LETTER RIEUL-TIKEUT
+    ('\u{316B}', CharMapAction::SubStr("\u{1105}\u{1107}\u{1109}")), // HANGUL LETTER RIEUL-PIEUP-SIOS
+    ('\u{316C}', CharMapAction::SubStr("\u{1105}\u{1140}")), // HANGUL LETTER RIEUL-PANSIOS
+    ('\u{316D}', CharMapAction::SubStr("\u{1105}\u{1159}")), // HANGUL LETTER RIEUL-YEORINHIEUH
+    ('\u{316E}', CharMapAction::SubStr("\u{1106}\u{1107}")), // HANGUL LETTER MIEUM-PIEUP
+    ('\u{316F}', CharMapAction::SubStr("\u{1106}\u{1109}")), // HANGUL LETTER MIEUM-SIOS
+    ('\u{3170}', CharMapAction::SubStr("\u{1106}\u{1140}")), // HANGUL LETTER MIEUM-PANSIOS
+    ('\u{3171}', CharMapAction::SubStr("\u{1106}\u{110B}")), // HANGUL LETTER KAPYEOUNMIEUM
+    ('\u{3172}', CharMapAction::SubStr("\u{1107}\u{1100}")), // HANGUL LETTER PIEUP-KIYEOK
+    ('\u{3173}', CharMapAction::SubStr("\u{1107}\u{1103}")), // HANGUL LETTER PIEUP-TIKEUT
+    ('\u{3174}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1100}")), // HANGUL LETTER PIEUP-SIOS-KIYEOK
+    ('\u{3175}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1103}")), // HANGUL LETTER PIEUP-SIOS-TIKEUT
+    ('\u{3176}', CharMapAction::SubStr("\u{1107}\u{110C}")), // HANGUL LETTER PIEUP-CIEUC
+    ('\u{3177}', CharMapAction::SubStr("\u{1107}\u{1110}")), // HANGUL LETTER PIEUP-THIEUTH
+    ('\u{3178}', CharMapAction::SubStr("\u{1107}\u{110B}")), // HANGUL LETTER KAPYEOUNPIEUP
+    ('\u{3179}', CharMapAction::SubStr("\u{1107}\u{1107}\u{110B}")), // HANGUL LETTER KAPYEOUNSSANGPIEUP
+    ('\u{317A}', CharMapAction::SubStr("\u{1109}\u{1100}")), // HANGUL LETTER SIOS-KIYEOK
+    ('\u{317B}', CharMapAction::SubStr("\u{1109}\u{1102}")), // HANGUL LETTER SIOS-NIEUN
+    ('\u{317C}', CharMapAction::SubStr("\u{1109}\u{1103}")), // HANGUL LETTER SIOS-TIKEUT
+    ('\u{317D}', CharMapAction::SubStr("\u{1109}\u{1107}")), // HANGUL LETTER SIOS-PIEUP
+    ('\u{317E}', CharMapAction::SubStr("\u{1109}\u{110C}")), // HANGUL LETTER SIOS-CIEUC
+    ('\u{317F}', CharMapAction::SubChar('\u{1140}')), // HANGUL LETTER PANSIOS
+    ('\u{3180}', CharMapAction::SubStr("\u{110B}\u{110B}")), // HANGUL LETTER SSANGIEUNG
+    ('\u{3181}', CharMapAction::SubChar('\u{114C}')), // HANGUL LETTER YESIEUNG
+    ('\u{3182}', CharMapAction::SubStr("\u{110B}\u{1109}")), // HANGUL LETTER YESIEUNG-SIOS
+    ('\u{3183}', CharMapAction::SubStr("\u{110B}\u{1140}")), // HANGUL LETTER YESIEUNG-PANSIOS
+    ('\u{3184}', CharMapAction::SubStr("\u{1111}\u{110B}")), // HANGUL LETTER KAPYEOUNPHIEUPH
+    ('\u{3185}', CharMapAction::SubStr("\u{1112}\u{1112}")), // HANGUL LETTER SSANGHIEUH
+    ('\u{3186}', CharMapAction::SubChar('\u{1159}')), // HANGUL LETTER YEORINHIEUH
+    ('\u{3187}', CharMapAction::SubStr("\u{116D}\u{1163}")), // HANGUL LETTER YO-YA
+    ('\u{3188}', CharMapAction::SubStr("\u{116D}\u{1163}\u{4E28}")), // HANGUL LETTER YO-YAE
+    ('\u{3189}', CharMapAction::SubStr("\u{116D}\u{4E28}")), // HANGUL LETTER YO-I
+    ('\u{318A}', CharMapAction::SubStr("\u{1172}\u{1167}")), // HANGUL LETTER YU-YEO
+    ('\u{318B}', CharMapAction::SubStr("\u{1172}\u{1167}\u{4E28}")), // HANGUL LETTER YU-YE
+    ('\u{318C}', CharMapAction::SubStr("\u{1172}\u{4E28}")), // HANGUL LETTER YU-I
+    ('\u{318D}', CharMapAction::SubChar('\u{119E}')), // HANGUL LETTER ARAEA
+    ('\u{318E}', CharMapAction::SubStr("\u{119E}\u{4E28}")), // HANGUL LETTER ARAEAE
+    ('\u{31D0}', CharMapAction::SubChar('\u{30FC}')), // CJK STROKE H
+    ('\u{31D1}', CharMapAction::SubChar('\u{4E28}')), // CJK STROKE S
+    ('\u{31D3}', CharMapAction::SubChar('/')), // CJK STROKE SP
+    ('\u{31D4}', CharMapAction::SubChar('\\')), // CJK STROKE D
+    ('\u{31D6}', CharMapAction::SubChar('\u{4E5B}')), // CJK STROKE HG
+    ('\u{31DA}', CharMapAction::SubChar('\u{4E85}')), // CJK STROKE SG
+    ('\u{31DB}', CharMapAction::SubChar('\u{276C}')), // CJK STROKE PD
+    ('\u{31DF}', CharMapAction::SubChar('\u{4E5A}')), // CJK STROKE SWG
+    ('\u{31E0}', CharMapAction::SubChar('\u{4E59}')), // CJK STROKE HXWG
+    ('\u{3200}', CharMapAction::SubStr("(\u{1100})")), // PARENTHESIZED HANGUL KIYEOK
+    ('\u{3201}', CharMapAction::SubStr("(\u{1102})")), // PARENTHESIZED HANGUL NIEUN
+    ('\u{3202}', CharMapAction::SubStr("(\u{1103})")), // PARENTHESIZED HANGUL TIKEUT
+    ('\u{3203}', CharMapAction::SubStr("(\u{1105})")), // PARENTHESIZED HANGUL RIEUL
+    ('\u{3204}', CharMapAction::SubStr("(\u{1106})")), // PARENTHESIZED HANGUL MIEUM
+    ('\u{3205}', CharMapAction::SubStr("(\u{1107})")), // PARENTHESIZED HANGUL PIEUP
+    ('\u{3206}', CharMapAction::SubStr("(\u{1109})")), // PARENTHESIZED HANGUL SIOS
+    ('\u{3207}', CharMapAction::SubStr("(\u{110B})")), // PARENTHESIZED HANGUL IEUNG
+    ('\u{3208}', CharMapAction::SubStr("(\u{110C})")), // PARENTHESIZED HANGUL CIEUC
+    ('\u{3209}', CharMapAction::SubStr("(\u{110E})")), // PARENTHESIZED HANGUL CHIEUCH
+    ('\u{320A}', CharMapAction::SubStr("(\u{110F})")), // PARENTHESIZED HANGUL KHIEUKH
+    ('\u{320B}', CharMapAction::SubStr("(\u{1110})")), // PARENTHESIZED HANGUL THIEUTH
+    ('\u{320C}', CharMapAction::SubStr("(\u{1111})")), // PARENTHESIZED HANGUL PHIEUPH
+    ('\u{320D}', CharMapAction::SubStr("(\u{1112})")), // PARENTHESIZED HANGUL HIEUH
+    ('\u{320E}', CharMapAction::SubStr("(\u{AC00})")), // PARENTHESIZED HANGUL KIYEOK A
+    ('\u{320F}', CharMapAction::SubStr("(\u{B098})")), // PARENTHESIZED HANGUL NIEUN A
+    ('\u{3210}', CharMapAction::SubStr("(\u{B2E4})")), // PARENTHESIZED HANGUL TIKEUT A
+    ('\u{3211}', CharMapAction::SubStr("(\u{B77C})")), // PARENTHESIZED HANGUL RIEUL A
+    ('\u{3212}', CharMapAction::SubStr("(\u{B9C8})")), // PARENTHESIZED HANGUL MIEUM A
+    ('\u{3213}', CharMapAction::SubStr("(\u{BC14})")), // PARENTHESIZED HANGUL PIEUP A
+    ('\u{3214}', CharMapAction::SubStr("(\u{C0AC})")), // PARENTHESIZED HANGUL SIOS A
+    ('\u{3215}', CharMapAction::SubStr("(\u{C544})")), // PARENTHESIZED HANGUL IEUNG A
+    ('\u{3216}', CharMapAction::SubStr("(\u{C790})")), // PARENTHESIZED HANGUL CIEUC A
+    ('\u{3217}', CharMapAction::SubStr("(\u{CC28})")), // PARENTHESIZED HANGUL CHIEUCH A
+    ('\u{3218}', CharMapAction::SubStr("(\u{CE74})")), // PARENTHESIZED HANGUL KHIEUKH A
+    ('\u{3219}', CharMapAction::SubStr("(\u{D0C0})")), // PARENTHESIZED HANGUL THIEUTH A
+    ('\u{321A}', CharMapAction::SubStr("(\u{D30C})")), // PARENTHESIZED HANGUL PHIEUPH A
+    ('\u{321B}', CharMapAction::SubStr("(\u{D558})")), // PARENTHESIZED HANGUL HIEUH A
+    ('\u{321C}', CharMapAction::SubStr("(\u{C8FC})")), // PARENTHESIZED HANGUL CIEUC U
+    ('\u{321D}', CharMapAction::SubStr("(\u{C624}\u{C804})")), // PARENTHESIZED KOREAN CHARACTER OJEON
+    ('\u{321E}', CharMapAction::SubStr("(\u{C624}\u{D6C4})")), // PARENTHESIZED KOREAN CHARACTER O HU
+    ('\u{3220}', CharMapAction::SubStr("(\u{30FC})")), // PARENTHESIZED IDEOGRAPH ONE
+    ('\u{3221}', CharMapAction::SubStr("(\u{4E8C})")), // PARENTHESIZED IDEOGRAPH TWO
+    ('\u{3222}', CharMapAction::SubStr("(\u{4E09})")), // PARENTHESIZED IDEOGRAPH THREE
+    ('\u{3223}', CharMapAction::SubStr("(\u{56DB})")), // PARENTHESIZED IDEOGRAPH FOUR
+    ('\u{3224}', CharMapAction::SubStr("(\u{4E94})")), // PARENTHESIZED IDEOGRAPH FIVE
+    ('\u{3225}', CharMapAction::SubStr("(\u{516D})")), // PARENTHESIZED IDEOGRAPH SIX
+    ('\u{3226}', CharMapAction::SubStr("(\u{4E03})")), // PARENTHESIZED IDEOGRAPH SEVEN
+    ('\u{3227}', CharMapAction::SubStr("(\u{516B})")), // PARENTHESIZED IDEOGRAPH EIGHT
+    ('\u{3228}', CharMapAction::SubStr("(\u{4E5D})")), // PARENTHESIZED IDEOGRAPH NINE
+    ('\u{3229}', CharMapAction::SubStr("(\u{5341})")), // PARENTHESIZED IDEOGRAPH TEN
+    ('\u{322A}', CharMapAction::SubStr("(\u{6708})")), // PARENTHESIZED IDEOGRAPH MOON
+    ('\u{322B}', CharMapAction::SubStr("(\u{706B})")), // PARENTHESIZED IDEOGRAPH FIRE
+    ('\u{322C}', CharMapAction::SubStr("(\u{6C34})")), // PARENTHESIZED IDEOGRAPH WATER
+    ('\u{322D}', CharMapAction::SubStr("(\u{6728})")), // PARENTHESIZED IDEOGRAPH WOOD
+    ('\u{322E}', CharMapAction::SubStr("(\u{91D1})")), // PARENTHESIZED IDEOGRAPH METAL
+    ('\u{322F}', CharMapAction::SubStr("(\u{571F})")), // PARENTHESIZED IDEOGRAPH EARTH
+    ('\u{3230}', CharMapAction::SubStr("(\u{65E5})")), // PARENTHESIZED IDEOGRAPH SUN
+    ('\u{3231}', CharMapAction::SubStr("(\u{682A})")), // PARENTHESIZED IDEOGRAPH STOCK
+    ('\u{3232}', CharMapAction::SubStr("(\u{6709})")), // PARENTHESIZED IDEOGRAPH HAVE
+    ('\u{3233}', CharMapAction::SubStr("(\u{793E})")), // PARENTHESIZED IDEOGRAPH SOCIETY
+    ('\u{3234}', CharMapAction::SubStr("(\u{540D})")), // PARENTHESIZED IDEOGRAPH NAME
+    ('\u{3235}', CharMapAction::SubStr("(\u{7279})")), // PARENTHESIZED IDEOGRAPH SPECIAL
+    ('\u{3236}', CharMapAction::SubStr("(\u{8CA1})")), // PARENTHESIZED IDEOGRAPH FINANCIAL
+    ('\u{3237}', CharMapAction::SubStr("(\u{795D})")), // PARENTHESIZED IDEOGRAPH CONGRATULATION
+    ('\u{3238}', CharMapAction::SubStr("(\u{52B4})")), // PARENTHESIZED IDEOGRAPH LABOR
+    ('\u{3239}', CharMapAction::SubStr("(\u{4EE3})")), // PARENTHESIZED IDEOGRAPH REPRESENT
+    ('\u{323A}', CharMapAction::SubStr("(\u{547C})")), // PARENTHESIZED IDEOGRAPH CALL
+    ('\u{323B}', CharMapAction::SubStr("(\u{5B66})")), // PARENTHESIZED IDEOGRAPH STUDY
+    ('\u{323C}', CharMapAction::SubStr("(\u{76E3})")), // PARENTHESIZED IDEOGRAPH SUPERVISE
+    ('\u{323D}', CharMapAction::SubStr("(\u{4F01})")), // PARENTHESIZED IDEOGRAPH ENTERPRISE
+    ('\u{323E}', CharMapAction::SubStr("(\u{8CC7})")), // PARENTHESIZED IDEOGRAPH RESOURCE
+    ('\u{323F}', CharMapAction::SubStr("(\u{5354})")), // PARENTHESIZED IDEOGRAPH ALLIANCE
+    ('\u{3240}', CharMapAction::SubStr("(\u{796D})")), // PARENTHESIZED IDEOGRAPH FESTIVAL
+    ('\u{3241}', CharMapAction::SubStr("(\u{4F11})")), // PARENTHESIZED IDEOGRAPH REST
+    ('\u{3242}', CharMapAction::SubStr("(\u{81EA})")), // PARENTHESIZED IDEOGRAPH SELF
+    ('\u{3243}', CharMapAction::SubStr("(\u{81F3})")), // PARENTHESIZED IDEOGRAPH REACH
+    ('\u{32C0}', CharMapAction::SubStr("l\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR JANUARY
+    ('\u{32C1}', CharMapAction::SubStr("2\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR FEBRUARY
+    ('\u{32C2}', CharMapAction::SubStr("3\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR MARCH
+    ('\u{32C3}', CharMapAction::SubStr("4\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR APRIL
+    ('\u{32C4}', CharMapAction::SubStr("5\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR MAY
+    ('\u{32C5}', CharMapAction::SubStr("6\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR JUNE
+    ('\u{32C6}', CharMapAction::SubStr("7\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR JULY
+    ('\u{32C7}', CharMapAction::SubStr("8\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR AUGUST
+    ('\u{32C8}', CharMapAction::SubStr("9\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR SEPTEMBER
+    ('\u{32C9}', CharMapAction::SubStr("lO\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR OCTOBER
+    ('\u{32CA}', CharMapAction::SubStr("ll\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR NOVEMBER
+    ('\u{32CB}', CharMapAction::SubStr("l2\u{6708}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DECEMBER
+    ('\u{3358}', CharMapAction::SubStr("O\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR ZERO
+    ('\u{3359}', CharMapAction::SubStr("l\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR ONE
+    ('\u{335A}', CharMapAction::SubStr("2\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWO
+    ('\u{335B}', CharMapAction::SubStr("3\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR THREE
+    ('\u{335C}', CharMapAction::SubStr("4\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FOUR
+    ('\u{335D}', CharMapAction::SubStr("5\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FIVE
+    ('\u{335E}', CharMapAction::SubStr("6\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SIX
+    ('\u{335F}', CharMapAction::SubStr("7\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SEVEN
+    ('\u{3360}', CharMapAction::SubStr("8\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR EIGHT
+    ('\u{3361}', CharMapAction::SubStr("9\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR NINE
+    ('\u{3362}', CharMapAction::SubStr("lO\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TEN
+    ('\u{3363}', CharMapAction::SubStr("ll\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR ELEVEN
+    ('\u{3364}', CharMapAction::SubStr("l2\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWELVE
+    ('\u{3365}', CharMapAction::SubStr("l3\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR THIRTEEN
+    ('\u{3366}', CharMapAction::SubStr("l4\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FOURTEEN
+    ('\u{3367}', CharMapAction::SubStr("l5\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FIFTEEN
+    ('\u{3368}', CharMapAction::SubStr("l6\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SIXTEEN
+    ('\u{3369}', CharMapAction::SubStr("l7\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SEVENTEEN
+    ('\u{336A}', CharMapAction::SubStr("l8\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR EIGHTEEN
+    ('\u{336B}', CharMapAction::SubStr("l9\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR NINETEEN
+    ('\u{336C}', CharMapAction::SubStr("2O\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY
+    ('\u{336D}', CharMapAction::SubStr("2l\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-ONE
+    ('\u{336E}', CharMapAction::SubStr("22\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-TWO
+    ('\u{336F}', CharMapAction::SubStr("23\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-THREE
+    ('\u{3370}', CharMapAction::SubStr("24\u{70B9}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-FOUR
+    ('\u{33E0}', CharMapAction::SubStr("l\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY ONE
+    ('\u{33E1}', CharMapAction::SubStr("2\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWO
+    ('\u{33E2}', CharMapAction::SubStr("3\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THREE
+    ('\u{33E3}', CharMapAction::SubStr("4\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FOUR
+    ('\u{33E4}', CharMapAction::SubStr("5\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FIVE
+    ('\u{33E5}', CharMapAction::SubStr("6\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SIX
+    ('\u{33E6}', CharMapAction::SubStr("7\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SEVEN
+    ('\u{33E7}', CharMapAction::SubStr("8\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY EIGHT
+    ('\u{33E8}', CharMapAction::SubStr("9\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY NINE
+    ('\u{33E9}', CharMapAction::SubStr("lO\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TEN
+    ('\u{33EA}', CharMapAction::SubStr("ll\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY ELEVEN
+    ('\u{33EB}', CharMapAction::SubStr("l2\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWELVE
+    ('\u{33EC}', CharMapAction::SubStr("l3\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THIRTEEN
+    ('\u{33ED}', CharMapAction::SubStr("l4\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FOURTEEN
+    ('\u{33EE}', CharMapAction::SubStr("l5\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FIFTEEN
+    ('\u{33EF}', CharMapAction::SubStr("l6\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SIXTEEN
+    ('\u{33F0}', CharMapAction::SubStr("l7\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SEVENTEEN
+    ('\u{33F1}', CharMapAction::SubStr("l8\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY EIGHTEEN
+    ('\u{33F2}', CharMapAction::SubStr("l9\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY NINETEEN
+    ('\u{33F3}', CharMapAction::SubStr("2O\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY
+    ('\u{33F4}', CharMapAction::SubStr("2l\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-ONE
+    ('\u{33F5}', CharMapAction::SubStr("22\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-TWO
+    ('\u{33F6}', CharMapAction::SubStr("23\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-THREE
+    ('\u{33F7}', CharMapAction::SubStr("24\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-FOUR
+    ('\u{33F8}', CharMapAction::SubStr("25\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-FIVE
+    ('\u{33F9}', CharMapAction::SubStr("26\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-SIX
+    ('\u{33FA}', CharMapAction::SubStr("27\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-SEVEN
+    ('\u{33FB}', CharMapAction::SubStr("28\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-EIGHT
+    ('\u{33FC}', CharMapAction::SubStr("29\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-NINE
+    ('\u{33FD}', CharMapAction::SubStr("3O\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THIRTY
+    ('\u{33FE}', CharMapAction::SubStr("3l\u{65E5}")), // IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THIRTY-ONE
+    ('\u{39B3}', CharMapAction::SubChar('\u{363D}')), // CJK UNIFIED IDEOGRAPH-39B3
+    ('\u{439B}', CharMapAction::SubChar('\u{3588}')), // CJK UNIFIED IDEOGRAPH-439B
+    ('\u{4420}', CharMapAction::SubChar('\u{3B3B}')), // CJK UNIFIED IDEOGRAPH-4420
+    ('\u{4E00}', CharMapAction::SubChar('\u{30FC}')), // CJK UNIFIED IDEOGRAPH-4E00
+    ('\u{4E36}', CharMapAction::SubChar('\\')), // CJK UNIFIED IDEOGRAPH-4E36
+    ('\u{4E3F}', CharMapAction::SubChar('/')), // CJK UNIFIED IDEOGRAPH-4E3F
+    ('\u{5002}', CharMapAction::SubChar('\u{4F75}')), // CJK UNIFIED IDEOGRAPH-5002
+    ('\u{503C}', CharMapAction::SubChar('\u{5024}')), // CJK UNIFIED IDEOGRAPH-503C
+    ('\u{555F}', CharMapAction::SubChar('\u{5553}')), // CJK UNIFIED IDEOGRAPH-555F
+    ('\u{56D7}', CharMapAction::SubChar('\u{53E3}')), // CJK UNIFIED IDEOGRAPH-56D7
+    ('\u{586B}', CharMapAction::SubChar('\u{5861}')), // CJK UNIFIED IDEOGRAPH-586B
+    ('\u{58EB}', CharMapAction::SubChar('\u{571F}')), // CJK UNIFIED IDEOGRAPH-58EB
+    ('\u{58FF}', CharMapAction::SubChar('\u{58AB}')), // CJK UNIFIED IDEOGRAPH-58FF
+    ('\u{5B00}', CharMapAction::SubChar('\u{5AAF}')), // CJK UNIFIED IDEOGRAPH-5B00
+    ('\u{5E32}', CharMapAction::SubChar('\u{5E21}')), // CJK UNIFIED IDEOGRAPH-5E32
+    ('\u{5E50}', CharMapAction::SubChar('\u{3B3A}')), // CJK UNIFIED IDEOGRAPH-5E50
+    ('\u{6238}', CharMapAction::SubChar('\u{6236}')), // CJK UNIFIED IDEOGRAPH-6238
+    ('\u{6409}', CharMapAction::SubChar('\u{3A41}')), // CJK UNIFIED IDEOGRAPH-6409
+    ('\u{6663}', CharMapAction::SubChar('\u{403F}')), // CJK UNIFIED IDEOGRAPH-6663
+    ('\u{6669}', CharMapAction::SubChar('\u{665A}')), // CJK UNIFIED IDEOGRAPH-6669
+    ('\u{66F6}', CharMapAction::SubChar('\u{3ADA}')), // CJK UNIFIED IDEOGRAPH-66F6
+    ('\u{6726}', CharMapAction::SubChar('\u{4443}')), // CJK UNIFIED IDEOGRAPH-6726
+    ('\u{67FF}', CharMapAction::SubChar('\u{676E}')), // CJK UNIFIED IDEOGRAPH-67FF
+    ('\u{69E9}', CharMapAction::SubChar('\u{3BA3}')), // CJK UNIFIED IDEOGRAPH-69E9
+    ('\u{6A27}', CharMapAction::SubChar('\u{699D}')), // CJK UNIFIED IDEOGRAPH-6A27
+    ('\u{6F59}', CharMapAction::SubChar('\u{6E88}')), // CJK UNIFIED IDEOGRAPH-6F59
+    ('\u{784F}', CharMapAction::SubChar('\u{7814}')), // CJK UNIFIED IDEOGRAPH-784F
+    ('\u{7D76}', CharMapAction::SubChar('\u{7D55}')), // CJK UNIFIED IDEOGRAPH-7D76
+    ('\u{80A6}', CharMapAction::SubChar('\u{670C}')), // CJK UNIFIED IDEOGRAPH-80A6
+    ('\u{80CA}', CharMapAction::SubChar('\u{6710}')), // CJK UNIFIED IDEOGRAPH-80CA
+    ('\u{80D0}', CharMapAction::SubChar('\u{670F}')), // CJK UNIFIED IDEOGRAPH-80D0
+    ('\u{80F6}', CharMapAction::SubChar('\u{3B35}')), // CJK UNIFIED IDEOGRAPH-80F6
+    ('\u{8101}', CharMapAction::SubChar('\u{6713}')), // CJK UNIFIED IDEOGRAPH-8101
+    ('\u{8127}', CharMapAction::SubChar('\u{6718}')), // CJK UNIFIED IDEOGRAPH-8127
+    ('\u{8141}', CharMapAction::SubChar('\u{80FC}')), // CJK UNIFIED IDEOGRAPH-8141
+    ('\u{81A7}', CharMapAction::SubChar('\u{6723}')), // CJK UNIFIED IDEOGRAPH-81A7
+    ('\u{853F}', CharMapAction::SubChar('\u{848D}')), // CJK UNIFIED IDEOGRAPH-853F
+    ('\u{8641}', CharMapAction::SubChar('\u{8637}')), // CJK UNIFIED IDEOGRAPH-8641
+    ('\u{8A1E}', CharMapAction::SubChar('\u{46B6}')), // CJK UNIFIED IDEOGRAPH-8A1E
+    ('\u{8A7D}', CharMapAction::SubChar('\u{8A2E}')), // CJK UNIFIED IDEOGRAPH-8A7D
+    ('\u{8B8F}', CharMapAction::SubChar('\u{8B86}')), // CJK UNIFIED IDEOGRAPH-8B8F
+    ('\u{8C63}', CharMapAction::SubChar('\u{8C5C}')), // CJK UNIFIED IDEOGRAPH-8C63
+    ('\u{8D86}', CharMapAction::SubChar('\u{8D7F}')), // CJK UNIFIED IDEOGRAPH-8D86
+    ('\u{8DFA}', CharMapAction::SubChar('\u{8DE5}')), // CJK UNIFIED IDEOGRAPH-8DFA
+    ('\u{8E9B}', CharMapAction::SubChar('\u{8E97}')), // CJK UNIFIED IDEOGRAPH-8E9B
+    ('\u{8F27}', CharMapAction::SubChar('\u{8EFF}')), // CJK UNIFIED IDEOGRAPH-8F27
+    ('\u{90DE}', CharMapAction::SubChar('\u{90CE}')), // CJK UNIFIED IDEOGRAPH-90DE
+    ('\u{93AE}', CharMapAction::SubChar('\u{93AD}')), // CJK UNIFIED IDEOGRAPH-93AE
+    ('\u{96B8}', CharMapAction::SubChar('\u{96B7}')), // CJK UNIFIED IDEOGRAPH-96B8
+    ('\u{9E43}', CharMapAction::SubChar('\u{9E42}')), // CJK UNIFIED IDEOGRAPH-9E43
+    ('\u{9ED2}', CharMapAction::SubChar('\u{9ED1}')), // CJK UNIFIED IDEOGRAPH-9ED2
+    ('\u{9FC3}', CharMapAction::SubChar('\u{4039}')), // CJK UNIFIED IDEOGRAPH-9FC3
+    ('\u{A494}', CharMapAction::SubChar('\u{A2CD}')), // YI RADICAL CYP
+    ('\u{A49C}', CharMapAction::SubChar('\u{A0C0}')), // YI RADICAL MOP
+    ('\u{A49E}', CharMapAction::SubChar('\u{A04A}')), // YI RADICAL PUT
+    ('\u{A4A7}', CharMapAction::SubChar('\u{A458}')), // YI RADICAL NYOP
+    ('\u{A4A8}', CharMapAction::SubChar('\u{A132}')), // YI RADICAL TU
+    ('\u{A4AC}', CharMapAction::SubChar('\u{A050}')), // YI RADICAL PYT
+    ('\u{A4B0}', CharMapAction::SubChar('\u{A3C2}')), // YI RADICAL SHY
+    ('\u{A4BA}', CharMapAction::SubChar('\u{A3BF}')), // YI RADICAL SHUR
+    ('\u{A4BE}', CharMapAction::SubChar('\u{A2B1}')), // YI RADICAL CIP
+    ('\u{A4BF}', CharMapAction::SubChar('\u{A259}')), // YI RADICAL HXOP
+    ('\u{A4C0}', CharMapAction::SubChar('\u{A3AB}')), // YI RADICAL SHAT
+    ('\u{A4C2}', CharMapAction::SubChar('\u{A3B5}')), // YI RADICAL SHOP
+    ('\u{A4D0}', CharMapAction::SubChar('B')), // LISU LETTER BA
+    ('\u{A4D1}', CharMapAction::SubChar('P')), // LISU LETTER PA
+    ('\u{A4D2}', CharMapAction::SubChar('d')), // LISU LETTER PHA
+    ('\u{A4D3}', CharMapAction::SubChar('D')), // LISU LETTER DA
+    ('\u{A4D4}', CharMapAction::SubChar('T')), // LISU LETTER TA
+    ('\u{A4D6}', CharMapAction::SubChar('G')), // LISU LETTER GA
+    ('\u{A4D7}', CharMapAction::SubChar('K')), // LISU LETTER KA
+    ('\u{A4D9}', CharMapAction::SubChar('J')), // LISU LETTER JA
+    ('\u{A4DA}', CharMapAction::SubChar('C')), // LISU LETTER CA
+    ('\u{A4DB}', CharMapAction::SubChar('\u{0186}')), // LISU LETTER CHA
+    ('\u{A4DC}', CharMapAction::SubChar('Z')), // LISU LETTER DZA
+    ('\u{A4DD}', CharMapAction::SubChar('F')), // LISU LETTER TSA
+    ('\u{A4DE}', CharMapAction::SubChar('\u{2132}')), // LISU LETTER TSHA
+    ('\u{A4DF}', CharMapAction::SubChar('M')), // LISU LETTER MA
+    ('\u{A4E0}', CharMapAction::SubChar('N')), // LISU LETTER NA
+    ('\u{A4E1}', CharMapAction::SubChar('L')), // LISU LETTER LA
+    ('\u{A4E2}', CharMapAction::SubChar('S')), // LISU LETTER SA
+    ('\u{A4E3}', CharMapAction::SubChar('R')), // LISU LETTER ZHA
+    ('\u{A4E5}', CharMapAction::SubChar('\u{0245}')), // LISU LETTER NGA
+    ('\u{A4E6}', CharMapAction::SubChar('V')), // LISU LETTER HA
+    ('\u{A4E7}', CharMapAction::SubChar('H')), // LISU LETTER XA
+    ('\u{A4EA}', CharMapAction::SubChar('W')), // LISU LETTER WA
+    ('\u{A4EB}', CharMapAction::SubChar('X')), // LISU LETTER SHA
+    ('\u{A4EC}', CharMapAction::SubChar('Y')), // LISU LETTER YA
+    ('\u{A4ED}', CharMapAction::SubChar('\u{1660}')), // LISU LETTER GHA
+    ('\u{A4EE}', CharMapAction::SubChar('A')), // LISU LETTER A
+    ('\u{A4EF}', CharMapAction::SubChar('\u{2C6F}')), // LISU LETTER AE
+    ('\u{A4F0}', CharMapAction::SubChar('E')), // LISU LETTER E
+    ('\u{A4F1}', CharMapAction::SubChar('\u{018E}')), // LISU LETTER EU
+    ('\u{A4F2}', CharMapAction::SubChar('l')), // LISU LETTER I
+    ('\u{A4F3}', CharMapAction::SubChar('O')), // LISU LETTER O
+    ('\u{A4F4}', CharMapAction::SubChar('U')), // LISU LETTER U
+    ('\u{A4F5}', CharMapAction::SubChar('\u{0548}')), // LISU LETTER UE
+    ('\u{A4F7}', CharMapAction::SubChar('\u{15E1}')), // LISU LETTER OE
+    ('\u{A4F8}', CharMapAction::SubChar('.')), // LISU LETTER TONE MYA TI
+    ('\u{A4F9}', CharMapAction::SubChar(',')), // LISU LETTER TONE NA PO
+    ('\u{A4FA}', CharMapAction::SubStr("..")), // LISU LETTER TONE MYA CYA
+    ('\u{A4FB}', CharMapAction::SubStr(".,")), // LISU LETTER TONE MYA BO
+    ('\u{A4FD}', CharMapAction::SubChar(':')), // LISU LETTER TONE MYA JEU
+    ('\u{A4FE}', CharMapAction::SubStr("-.")), // LISU PUNCTUATION COMMA
+    ('\u{A4FF}', CharMapAction::SubChar('=')), // LISU PUNCTUATION FULL STOP
+    ('\u{A60E}', CharMapAction::SubChar('.')), // VAI FULL STOP
+    ('\u{A644}', CharMapAction::SubChar('2')), // CYRILLIC CAPITAL LETTER REVERSED DZE
+    ('\u{A645}', CharMapAction::SubChar('\u{01A8}')), // CYRILLIC SMALL LETTER REVERSED DZE
+    ('\u{A647}', CharMapAction::SubChar('i')), // CYRILLIC SMALL LETTER IOTA
+    ('\u{A64D}', CharMapAction::SubChar('\u{03C9}')), // CYRILLIC SMALL LETTER BROAD OMEGA
+    ('\u{A650}', CharMapAction::SubStr("\u{042A}l")), // CYRILLIC CAPITAL LETTER YERU WITH BACK YER
+    ('\u{A651}', CharMapAction::SubStr("\u{02C9}bi")), // CYRILLIC SMALL LETTER YERU WITH BACK YER
+    ('\u{A668}', CharMapAction::SubChar('\u{0298}')), // CYRILLIC CAPITAL LETTER MONOCULAR O
+    ('\u{A66F}', CharMapAction::SubChar('\u{20E9}')), // COMBINING CYRILLIC VZMET
+    ('\u{A67C}', CharMapAction::SubChar('\u{0306}')), // COMBINING CYRILLIC KAVYKA
+    ('\u{A67E}', CharMapAction::SubChar('\u{02C7}')), // CYRILLIC KAVYKA
+    ('\u{A695}', CharMapAction::SubStr("h\u{0314}")), // CYRILLIC SMALL LETTER HWE
+    ('\u{A698}', CharMapAction::SubStr("OO")), // CYRILLIC CAPITAL LETTER DOUBLE O
+    ('\u{A699}', CharMapAction::SubStr("oo")), // CYRILLIC SMALL LETTER DOUBLE O
+    ('\u{A69A}', CharMapAction::SubChar('\u{102A8}')), // CYRILLIC CAPITAL LETTER CROSSED O
+    ('\u{A6A1}', CharMapAction::SubChar('\u{0418}')), // BAMUM LETTER KA
+    ('\u{A6B0}', CharMapAction::SubChar('\u{16B9}')), // BAMUM LETTER TAA
+    ('\u{A6B1}', CharMapAction::SubChar('\u{2C75}')), // BAMUM LETTER NDAA
+    ('\u{A6CD}', CharMapAction::SubChar('\u{02A1}')), // BAMUM LETTER LU
+    ('\u{A6CE}', CharMapAction::SubChar('\u{0245}')), // BAMUM LETTER MI
+    ('\u{A6DB}', CharMapAction::SubChar('\u{03A0}')), // BAMUM LETTER NA
+    ('\u{A6DF}', CharMapAction::SubChar('V')), // BAMUM LETTER KO
+    ('\u{A6EB}', CharMapAction::SubChar('?')), // BAMUM LETTER NTUU
+    ('\u{A6EF}', CharMapAction::SubChar('2')), // BAMUM LETTER KOGHOM
+    ('\u{A6F0}', CharMapAction::SubChar('\u{0302}')), // BAMUM COMBINING MARK KOQNDON
+    ('\u{A6F1}', CharMapAction::SubChar('\u{0304}')), // BAMUM COMBINING MARK TUKWENTIS
+    ('\u{A6F4}', CharMapAction::SubStr("\u{A6F3}\u{A6F3}")), // BAMUM COLON
+    ('\u{A714}', CharMapAction::SubChar('\u{02EB}')), // MODIFIER LETTER MID LEFT-STEM TONE BAR
+    ('\u{A716}', CharMapAction::SubChar('\u{02EA}')), // MODIFIER LETTER EXTRA-LOW LEFT-STEM TONE BAR
+    ('\u{A728}', CharMapAction::SubStr("T3")), // LATIN CAPITAL LETTER TZ
+    ('\u{A729}', CharMapAction::SubStr("t\u{021D}")), // LATIN SMALL LETTER TZ
+    ('\u{A731}', CharMapAction::SubChar('s')), // LATIN LETTER SMALL CAPITAL S
+    ('\u{A732}', CharMapAction::SubStr("AA")), // LATIN CAPITAL LETTER AA
+    ('\u{A733}', CharMapAction::SubStr("aa")), // LATIN SMALL LETTER AA
+    ('\u{A734}', CharMapAction::SubStr("AO")), // LATIN CAPITAL LETTER AO
+    ('\u{A735}', CharMapAction::SubStr("ao")), // LATIN SMALL LETTER AO
+    ('\u{A736}', CharMapAction::SubStr("AU")), // LATIN CAPITAL LETTER AU
+    ('\u{A737}', CharMapAction::SubStr("au")), // LATIN SMALL LETTER AU
+    ('\u{A738}', CharMapAction::SubStr("AV")), // LATIN CAPITAL LETTER AV
+    ('\u{A739}', CharMapAction::SubStr("av")), // LATIN SMALL LETTER AV
+    ('\u{A73A}', CharMapAction::SubStr("AV")), // LATIN CAPITAL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73B}', CharMapAction::SubStr("av")), // LATIN SMALL LETTER AV WITH HORIZONTAL BAR
+    ('\u{A73C}', CharMapAction::SubStr("AY")), // LATIN CAPITAL LETTER AY
+    ('\u{A73D}', CharMapAction::SubStr("ay")), // LATIN SMALL LETTER AY
+    ('\u{A740}', CharMapAction::SubStr("K\u{0335}")), // LATIN CAPITAL LETTER K WITH STROKE
+    ('\u{A74A}', CharMapAction::SubStr("O\u{0335}")), // LATIN CAPITAL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74B}', CharMapAction::SubStr("o\u{0335}")), // LATIN SMALL LETTER O WITH LONG STROKE OVERLAY
+    ('\u{A74E}', CharMapAction::SubStr("OO")), // LATIN CAPITAL LETTER OO
+    ('\u{A74F}', CharMapAction::SubStr("oo")), // LATIN SMALL LETTER OO
+    ('\u{A75A}', CharMapAction::SubChar('2')), // LATIN CAPITAL LETTER R ROTUNDA
+    ('\u{A761}', CharMapAction::SubStr("w\u{0326}")), // LATIN SMALL LETTER VY
+    ('\u{A76A}', CharMapAction::SubChar('3')), // LATIN CAPITAL LETTER ET
+    ('\u{A76B}', CharMapAction::SubChar('\u{021D}')), // LATIN SMALL LETTER ET
+    ('\u{A76E}', CharMapAction::SubChar('9')), // LATIN CAPITAL LETTER CON
+    ('\u{A777}', CharMapAction::SubStr("tf")), // LATIN SMALL LETTER TUM
+    ('\u{A778}', CharMapAction::SubChar('&')), // LATIN SMALL LETTER UM
+    ('\u{A77A}', CharMapAction::SubChar('\u{A779}')), // LATIN SMALL LETTER INSULAR D
+    ('\u{A789}', CharMapAction::SubChar(':')), // MODIFIER LETTER COLON
+    ('\u{A78C}', CharMapAction::SubChar('\'')), // LATIN SMALL LETTER SALTILLO
+    ('\u{A78F}', CharMapAction::SubChar('\u{00B7}')), // LATIN LETTER SINOLOGICAL DOT
+    ('\u{A795}', CharMapAction::SubChar('\u{A727}')), // LATIN SMALL LETTER H WITH PALATAL HOOK
+    ('\u{A798}', CharMapAction::SubChar('F')), // LATIN CAPITAL LETTER F WITH STROKE
+    ('\u{A799}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER F WITH STROKE
+    ('\u{A79A}', CharMapAction::SubChar('\u{10412}')), // LATIN CAPITAL LETTER VOLAPUK AE
+    ('\u{A79B}', CharMapAction::SubChar('\u{1043A}')), // LATIN SMALL LETTER VOLAPUK AE
+    ('\u{A79D}', CharMapAction::SubChar('\u{029A}')), // LATIN SMALL LETTER VOLAPUK OE
+    ('\u{A79E}', CharMapAction::SubChar('\u{A4E4}')), // LATIN CAPITAL LETTER VOLAPUK UE
+    ('\u{A79F}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER VOLAPUK UE
+    ('\u{A7AB}', CharMapAction::SubChar('3')), // LATIN CAPITAL LETTER REVERSED OPEN E
+    ('\u{A7B1}', CharMapAction::SubChar('\u{A4D5}')), // LATIN CAPITAL LETTER TURNED T
+    ('\u{A7B2}', CharMapAction::SubChar('J')), // LATIN CAPITAL LETTER J WITH CROSSED-TAIL
+    ('\u{A7B3}', CharMapAction::SubChar('X')), // LATIN CAPITAL LETTER CHI
+    ('\u{A7B4}', CharMapAction::SubChar('B')), // LATIN CAPITAL LETTER BETA
+    ('\u{A7B5}', CharMapAction::SubChar('\u{00DF}')), // LATIN SMALL LETTER BETA
+    ('\u{A7B6}', CharMapAction::SubChar('\u{A64C}')), // LATIN CAPITAL LETTER OMEGA
+    ('\u{A7B7}', CharMapAction::SubChar('\u{03C9}')), // LATIN SMALL LETTER OMEGA
+    ('\u{A7D6}', CharMapAction::SubChar('\u{00DF}')), // LATIN CAPITAL LETTER MIDDLE SCOTS S
+    ('\u{A7DA}', CharMapAction::SubChar('\u{0245}')),
+    ('\u{A7DB}', CharMapAction::SubChar('\u{03BB}')),
+    ('\u{A7DC}', CharMapAction::SubStr("\u{0245}\u{0338}")),
+    ('\u{A7F7}', CharMapAction::SubChar('\u{30FC}')), // LATIN EPIGRAPHIC LETTER SIDEWAYS I
+    ('\u{A830}', CharMapAction::SubChar('\u{0964}')), // NORTH INDIC FRACTION ONE QUARTER
+    ('\u{A960}', CharMapAction::SubStr("\u{1103}\u{1106}")), // HANGUL CHOSEONG TIKEUT-MIEUM
+    ('\u{A961}', CharMapAction::SubStr("\u{1103}\u{1107}")), // HANGUL CHOSEONG TIKEUT-PIEUP
+    ('\u{A962}', CharMapAction::SubStr("\u{1103}\u{1109}")), // HANGUL CHOSEONG TIKEUT-SIOS
+    ('\u{A963}', CharMapAction::SubStr("\u{1103}\u{110C}")), // HANGUL CHOSEONG TIKEUT-CIEUC
+    ('\u{A964}', CharMapAction::SubStr("\u{1105}\u{1100}")), // HANGUL CHOSEONG RIEUL-KIYEOK
+    ('\u{A965}', CharMapAction::SubStr("\u{1105}\u{1100}\u{1100}")), // HANGUL CHOSEONG RIEUL-SSANGKIYEOK
+    ('\u{A966}', CharMapAction::SubStr("\u{1105}\u{1103}")), // HANGUL CHOSEONG RIEUL-TIKEUT
+    ('\u{A967}', CharMapAction::SubStr("\u{1105}\u{1103}\u{1103}")), // HANGUL CHOSEONG RIEUL-SSANGTIKEUT
+    ('\u{A968}', CharMapAction::SubStr("\u{1105}\u{1106}")), // HANGUL CHOSEONG RIEUL-MIEUM
+    ('\u{A969}', CharMapAction::SubStr("\u{1105}\u{1107}")), // HANGUL CHOSEONG RIEUL-PIEUP
+    ('\u{A96A}', CharMapAction::SubStr("\u{1105}\u{1107}\u{1107}")), // HANGUL CHOSEONG RIEUL-SSANGPIEUP
+    ('\u{A96B}', CharMapAction::SubStr("\u{1105}\u{1107}\u{110B}")), // HANGUL CHOSEONG RIEUL-KAPYEOUNPIEUP
+    ('\u{A96C}', CharMapAction::SubStr("\u{1105}\u{1109}")), // HANGUL CHOSEONG RIEUL-SIOS
+    ('\u{A96D}', CharMapAction::SubStr("\u{1105}\u{110C}")), // HANGUL CHOSEONG RIEUL-CIEUC
+    ('\u{A96E}', CharMapAction::SubStr("\u{1105}\u{110F}")), // HANGUL CHOSEONG RIEUL-KHIEUKH
+    ('\u{A96F}', CharMapAction::SubStr("\u{1106}\u{1100}")), // HANGUL CHOSEONG MIEUM-KIYEOK
+    ('\u{A970}', CharMapAction::SubStr("\u{1106}\u{1103}")), // HANGUL CHOSEONG MIEUM-TIKEUT
+    ('\u{A971}', CharMapAction::SubStr("\u{1106}\u{1109}")), // HANGUL CHOSEONG MIEUM-SIOS
+    ('\u{A972}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1110}")), // HANGUL CHOSEONG PIEUP-SIOS-THIEUTH
+    ('\u{A973}', CharMapAction::SubStr("\u{1107}\u{110F}")), // HANGUL CHOSEONG PIEUP-KHIEUKH
+    ('\u{A974}', CharMapAction::SubStr("\u{1107}\u{1112}")), // HANGUL CHOSEONG PIEUP-HIEUH
+    ('\u{A975}', CharMapAction::SubStr("\u{1109}\u{1109}\u{1107}")), // HANGUL CHOSEONG SSANGSIOS-PIEUP
+    ('\u{A976}', CharMapAction::SubStr("\u{110B}\u{1105}")), // HANGUL CHOSEONG IEUNG-RIEUL
+    ('\u{A977}', CharMapAction::SubStr("\u{110B}\u{1112}")), // HANGUL CHOSEONG IEUNG-HIEUH
+    ('\u{A978}', CharMapAction::SubStr("\u{110C}\u{110C}\u{1112}")), // HANGUL CHOSEONG SSANGCIEUC-HIEUH
+    ('\u{A979}', CharMapAction::SubStr("\u{1110}\u{1110}")), // HANGUL CHOSEONG SSANGTHIEUTH
+    ('\u{A97A}', CharMapAction::SubStr("\u{1111}\u{1112}")), // HANGUL CHOSEONG PHIEUPH-HIEUH
+    ('\u{A97B}', CharMapAction::SubStr("\u{1112}\u{1109}")), // HANGUL CHOSEONG HIEUH-SIOS
+    ('\u{A97C}', CharMapAction::SubStr("\u{1159}\u{1159}")), // HANGUL CHOSEONG SSANGYEORINHIEUH
+    ('\u{A992}', CharMapAction::SubChar('\u{2C3F}')), // JAVANESE LETTER GA
+    ('\u{A9A3}', CharMapAction::SubChar('\u{A99D}')), // JAVANESE LETTER DA MAHAPRANA
+    ('\u{A9C6}', CharMapAction::SubChar('\u{A9D0}')), // JAVANESE PADA WINDU
+    ('\u{A9CF}', CharMapAction::SubChar('\u{0662}')), // JAVANESE PANGRANGKEP
+    ('\u{AA53}', CharMapAction::SubChar('\u{AA01}')), // CHAM DIGIT THREE
+    ('\u{AA56}', CharMapAction::SubChar('\u{AA23}')), // CHAM DIGIT SIX
+    ('\u{AB32}', CharMapAction::SubChar('e')), // LATIN SMALL LETTER BLACKLETTER E
+    ('\u{AB35}', CharMapAction::SubChar('f')), // LATIN SMALL LETTER LENIS F
+    ('\u{AB3D}', CharMapAction::SubChar('o')), // LATIN SMALL LETTER BLACKLETTER O
+    ('\u{AB3E}', CharMapAction::SubStr("o\u{0338}")), // LATIN SMALL LETTER BLACKLETTER O WITH STROKE
+    ('\u{AB3F}', CharMapAction::SubStr("\u{0254}\u{0338}")), // LATIN SMALL LETTER OPEN O WITH STROKE
+    ('\u{AB41}', CharMapAction::SubStr("\u{01DD}o\u{0338}")), // LATIN SMALL LETTER TURNED OE WITH STROKE
+    ('\u{AB42}', CharMapAction::SubStr("\u{01DD}o\u{0335}")), // LATIN SMALL LETTER TURNED OE WITH HORIZONTAL STROKE
+    ('\u{AB47}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER R WITHOUT HANDLE
+    ('\u{AB48}', CharMapAction::SubChar('r')), // LATIN SMALL LETTER DOUBLE R
+    ('\u{AB4D}', CharMapAction::SubChar('\u{0283}')), // LATIN SMALL LETTER BASELINE ESH
+    ('\u{AB4E}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH SHORT RIGHT LEG
+    ('\u{AB52}', CharMapAction::SubChar('u')), // LATIN SMALL LETTER U WITH LEFT HOOK
+    ('\u{AB53}', CharMapAction::SubChar('\u{03C7}')), // LATIN SMALL LETTER CHI
+    ('\u{AB55}', CharMapAction::SubChar('\u{03C7}')), // LATIN SMALL LETTER CHI WITH LOW LEFT SERIF
+    ('\u{AB5A}', CharMapAction::SubChar('y')), // LATIN SMALL LETTER Y WITH SHORT RIGHT LEG
+    ('\u{AB60}', CharMapAction::SubChar('\u{0459}')), // LATIN SMALL LETTER SAKHA YAT
+    ('\u{AB62}', CharMapAction::SubStr("\u{0254}e")), // LATIN SMALL LETTER OPEN OE
+    ('\u{AB63}', CharMapAction::SubStr("uo")), // LATIN SMALL LETTER UO
+    ('\u{AB70}', CharMapAction::SubChar('\u{1D05}')), // CHEROKEE SMALL LETTER A
+    ('\u{AB71}', CharMapAction::SubChar('\u{0280}')), // CHEROKEE SMALL LETTER E
+    ('\u{AB72}', CharMapAction::SubChar('\u{1D1B}')), // CHEROKEE SMALL LETTER I
+    ('\u{AB74}', CharMapAction::SubStr("o\u{031B}")), // CHEROKEE SMALL LETTER U
+    ('\u{AB75}', CharMapAction::SubChar('i')), // CHEROKEE SMALL LETTER V
+    ('\u{AB7A}', CharMapAction::SubChar('\u{1D00}')), // CHEROKEE SMALL LETTER GO
+    ('\u{AB7B}', CharMapAction::SubChar('\u{1D0A}')), // CHEROKEE SMALL LETTER GU
+    ('\u{AB7C}', CharMapAction::SubChar('\u{1D07}')), // CHEROKEE SMALL LETTER GV
+    ('\u{AB7E}', CharMapAction::SubChar('\u{0242}')), // CHEROKEE SMALL LETTER HE
+    ('\u{AB80}', CharMapAction::SubChar('\u{2C76}')), // CHEROKEE SMALL LETTER HO
+    ('\u{AB81}', CharMapAction::SubChar('r')), // CHEROKEE SMALL LETTER HU
+    ('\u{AB83}', CharMapAction::SubChar('w')), // CHEROKEE SMALL LETTER LA
+    ('\u{AB87}', CharMapAction::SubChar('\u{028D}')), // CHEROKEE SMALL LETTER LU
+    ('\u{AB8B}', CharMapAction::SubChar('\u{029C}')), // CHEROKEE SMALL LETTER MI
+    ('\u{AB8E}', CharMapAction::SubStr("o\u{0335}")), // CHEROKEE SMALL LETTER NA
+    ('\u{AB90}', CharMapAction::SubChar('\u{0262}')), // CHEROKEE SMALL LETTER NAH
+    ('\u{AB93}', CharMapAction::SubChar('z')), // CHEROKEE SMALL LETTER NO
+    ('\u{AB9B}', CharMapAction::SubChar('\u{A793}')), // CHEROKEE SMALL LETTER QUV
+    ('\u{AB9C}', CharMapAction::SubStr("u\u{0335}")), // CHEROKEE SMALL LETTER SA
+    ('\u{AB9F}', CharMapAction::SubChar('\u{0185}')), // CHEROKEE SMALL LETTER SI
+    ('\u{ABA2}', CharMapAction::SubChar('\u{0280}')), // CHEROKEE SMALL LETTER SV
+    ('\u{ABA9}', CharMapAction::SubChar('v')), // CHEROKEE SMALL LETTER DO
+    ('\u{ABAA}', CharMapAction::SubChar('s')), // CHEROKEE SMALL LETTER DU
+    ('\u{ABAE}', CharMapAction::SubChar('\u{029F}')), // CHEROKEE SMALL LETTER TLE
+    ('\u{ABAF}', CharMapAction::SubChar('c')), // CHEROKEE SMALL LETTER TLI
+    ('\u{ABB2}', CharMapAction::SubChar('\u{1D18}')), // CHEROKEE SMALL LETTER TLV
+    ('\u{ABB6}', CharMapAction::SubChar('\u{0138}')), // CHEROKEE SMALL LETTER TSO
+    ('\u{ABBB}', CharMapAction::SubStr("o\u{0335}")), // CHEROKEE SMALL LETTER WI
+    ('\u{D7B0}', CharMapAction::SubStr("\u{1169}\u{1167}")), // HANGUL JUNGSEONG O-YEO
+    ('\u{D7B1}', CharMapAction::SubStr("\u{1169}\u{1169}\u{4E28}")), // HANGUL JUNGSEONG O-O-I
+    ('\u{D7B2}', CharMapAction::SubStr("\u{116D}\u{1161}")), // HANGUL JUNGSEONG YO-A
+    ('\u{D7B3}', CharMapAction::SubStr("\u{116D}\u{1161}\u{4E28}")), // HANGUL JUNGSEONG YO-AE
+    ('\u{D7B4}', CharMapAction::SubStr("\u{116D}\u{1165}")), // HANGUL JUNGSEONG YO-EO
+    ('\u{D7B5}', CharMapAction::SubStr("\u{116E}\u{1167}")), // HANGUL JUNGSEONG U-YEO
+    ('\u{D7B6}', CharMapAction::SubStr("\u{116E}\u{4E28}\u{4E28}")), // HANGUL JUNGSEONG U-I-I
+    ('\u{D7B7}', CharMapAction::SubStr("\u{1172}\u{1161}\u{4E28}")), // HANGUL JUNGSEONG YU-AE
+    ('\u{D7B8}', CharMapAction::SubStr("\u{1172}\u{1169}")), // HANGUL JUNGSEONG YU-O
+    ('\u{D7B9}', CharMapAction::SubStr("\u{30FC}\u{1161}")), // HANGUL JUNGSEONG EU-A
+    ('\u{D7BA}', CharMapAction::SubStr("\u{30FC}\u{1165}")), // HANGUL JUNGSEONG EU-EO
+    ('\u{D7BB}', CharMapAction::SubStr("\u{30FC}\u{1165}\u{4E28}")), // HANGUL JUNGSEONG EU-E
+    ('\u{D7BC}', CharMapAction::SubStr("\u{30FC}\u{1169}")), // HANGUL JUNGSEONG EU-O
+    ('\u{D7BD}', CharMapAction::SubStr("\u{4E28}\u{1163}\u{1169}")), // HANGUL JUNGSEONG I-YA-O
+    ('\u{D7BE}', CharMapAction::SubStr("\u{4E28}\u{1163}\u{4E28}")), // HANGUL JUNGSEONG I-YAE
+    ('\u{D7BF}', CharMapAction::SubStr("\u{4E28}\u{1167}")), // HANGUL JUNGSEONG I-YEO
+    ('\u{D7C0}', CharMapAction::SubStr("\u{4E28}\u{1167}\u{4E28}")), // HANGUL JUNGSEONG I-YE
+    ('\u{D7C1}', CharMapAction::SubStr("\u{4E28}\u{1169}\u{4E28}")), // HANGUL JUNGSEONG I-O-I
+    ('\u{D7C2}', CharMapAction::SubStr("\u{4E28}\u{116D}")), // HANGUL JUNGSEONG I-YO
+    ('\u{D7C3}', CharMapAction::SubStr("\u{4E28}\u{1172}")), // HANGUL JUNGSEONG I-YU
+    ('\u{D7C4}', CharMapAction::SubStr("\u{4E28}\u{4E28}")), // HANGUL JUNGSEONG I-I
+    ('\u{D7C5}', CharMapAction::SubStr("\u{119E}\u{1161}")), // HANGUL JUNGSEONG ARAEA-A
+    ('\u{D7C6}', CharMapAction::SubStr("\u{119E}\u{1165}\u{4E28}")), // HANGUL JUNGSEONG ARAEA-E
+    ('\u{D7CB}', CharMapAction::SubStr("\u{1102}\u{1105}")), // HANGUL JONGSEONG NIEUN-RIEUL
+    ('\u{D7CC}', CharMapAction::SubStr("\u{1102}\u{110E}")), // HANGUL JONGSEONG NIEUN-CHIEUCH
+    ('\u{D7CD}', CharMapAction::SubStr("\u{1103}\u{1103}")), // HANGUL JONGSEONG SSANGTIKEUT
+    ('\u{D7CE}', CharMapAction::SubStr("\u{1103}\u{1103}\u{1107}")), // HANGUL JONGSEONG SSANGTIKEUT-PIEUP
+    ('\u{D7CF}', CharMapAction::SubStr("\u{1103}\u{1107}")), // HANGUL JONGSEONG TIKEUT-PIEUP
+    ('\u{D7D0}', CharMapAction::SubStr("\u{1103}\u{1109}")), // HANGUL JONGSEONG TIKEUT-SIOS
+    ('\u{D7D1}', CharMapAction::SubStr("\u{1103}\u{1109}\u{1100}")), // HANGUL JONGSEONG TIKEUT-SIOS-KIYEOK
+    ('\u{D7D2}', CharMapAction::SubStr("\u{1103}\u{110C}")), // HANGUL JONGSEONG TIKEUT-CIEUC
+    ('\u{D7D3}', CharMapAction::SubStr("\u{1103}\u{110E}")), // HANGUL JONGSEONG TIKEUT-CHIEUCH
+    ('\u{D7D4}', CharMapAction::SubStr("\u{1103}\u{1110}")), // HANGUL JONGSEONG TIKEUT-THIEUTH
+    ('\u{D7D5}', CharMapAction::SubStr("\u{1105}\u{1100}\u{1100}")), // HANGUL JONGSEONG RIEUL-SSANGKIYEOK
+    ('\u{D7D6}', CharMapAction::SubStr("\u{1105}\u{1100}\u{1112}")), // HANGUL JONGSEONG RIEUL-KIYEOK-HIEUH
+    ('\u{D7D7}', CharMapAction::SubStr("\u{1105}\u{1105}\u{110F}")), // HANGUL JONGSEONG SSANGRIEUL-KHIEUKH
+    ('\u{D7D8}', CharMapAction::SubStr("\u{1105}\u{1106}\u{1112}")), // HANGUL JONGSEONG RIEUL-MIEUM-HIEUH
+    ('\u{D7D9}', CharMapAction::SubStr("\u{1105}\u{1107}\u{1103}")), // HANGUL JONGSEONG RIEUL-PIEUP-TIKEUT
+    ('\u{D7DA}', CharMapAction::SubStr("\u{1105}\u{1107}\u{1111}")), // HANGUL JONGSEONG RIEUL-PIEUP-PHIEUPH
+    ('\u{D7DB}', CharMapAction::SubStr("\u{1105}\u{114C}")), // HANGUL JONGSEONG RIEUL-YESIEUNG
+    ('\u{D7DC}', CharMapAction::SubStr("\u{1105}\u{1159}\u{1112}")), // HANGUL JONGSEONG RIEUL-YEORINHIEUH-HIEUH
+    ('\u{D7DD}', CharMapAction::SubStr("\u{1105}\u{110B}")), // HANGUL JONGSEONG KAPYEOUNRIEUL
+    ('\u{D7DE}', CharMapAction::SubStr("\u{1106}\u{1102}")), // HANGUL JONGSEONG MIEUM-NIEUN
+    ('\u{D7DF}', CharMapAction::SubStr("\u{1106}\u{1102}\u{1102}")), // HANGUL JONGSEONG MIEUM-SSANGNIEUN
+    ('\u{D7E0}', CharMapAction::SubStr("\u{1106}\u{1106}")), // HANGUL JONGSEONG SSANGMIEUM
+    ('\u{D7E1}', CharMapAction::SubStr("\u{1106}\u{1107}\u{1109}")), // HANGUL JONGSEONG MIEUM-PIEUP-SIOS
+    ('\u{D7E2}', CharMapAction::SubStr("\u{1106}\u{110C}")), // HANGUL JONGSEONG MIEUM-CIEUC
+    ('\u{D7E3}', CharMapAction::SubStr("\u{1107}\u{1103}")), // HANGUL JONGSEONG PIEUP-TIKEUT
+    ('\u{D7E4}', CharMapAction::SubStr("\u{1107}\u{1105}\u{1111}")), // HANGUL JONGSEONG PIEUP-RIEUL-PHIEUPH
+    ('\u{D7E5}', CharMapAction::SubStr("\u{1107}\u{1106}")), // HANGUL JONGSEONG PIEUP-MIEUM
+    ('\u{D7E6}', CharMapAction::SubStr("\u{1107}\u{1107}")), // HANGUL JONGSEONG SSANGPIEUP
+    ('\u{D7E7}', CharMapAction::SubStr("\u{1107}\u{1109}\u{1103}")), // HANGUL JONGSEONG PIEUP-SIOS-TIKEUT
+    ('\u{D7E8}', CharMapAction::SubStr("\u{1107}\u{110C}")), // HANGUL JONGSEONG PIEUP-CIEUC
+    ('\u{D7E9}', CharMapAction::SubStr("\u{1107}\u{110E}")), // HANGUL JONGSEONG PIEUP-CHIEUCH
+    ('\u{D7EA}', CharMapAction::SubStr("\u{1109}\u{1106}")), // HANGUL JONGSEONG SIOS-MIEUM
+    ('\u{D7EB}', CharMapAction::SubStr("\u{1109}\u{1107}\u{110B}")), // HANGUL JONGSEONG SIOS-KAPYEOUNPIEUP
+    ('\u{D7EC}', CharMapAction::SubStr("\u{1109}\u{1109}\u{1100}")), // HANGUL JONGSEONG SSANGSIOS-KIYEOK
+    ('\u{D7ED}', CharMapAction::SubStr("\u{1109}\u{1109}\u{1103}")), // HANGUL JONGSEONG SSANGSIOS-TIKEUT
+    ('\u{D7EE}', CharMapAction::SubStr("\u{1109}\u{1140}")), // HANGUL JONGSEONG SIOS-PANSIOS
+    ('\u{D7EF}', CharMapAction::SubStr("\u{1109}\u{110C}")), // HANGUL JONGSEONG SIOS-CIEUC
+    ('\u{D7F0}', CharMapAction::SubStr("\u{1109}\u{110E}")), // HANGUL JONGSEONG SIOS-CHIEUCH
+    ('\u{D7F1}', CharMapAction::SubStr("\u{1109}\u{1110}")), // HANGUL JONGSEONG SIOS-THIEUTH
+    ('\u{D7F2}', CharMapAction::SubStr("\u{1105}\u{1112}")), // HANGUL JONGSEONG SIOS-HIEUH
+    ('\u{D7F3}', CharMapAction::SubStr("\u{1140}\u{1107}")), // HANGUL JONGSEONG PANSIOS-PIEUP
+    ('\u{D7F4}', CharMapAction::SubStr("\u{1140}\u{1107}\u{110B}")), // HANGUL JONGSEONG PANSIOS-KAPYEOUNPIEUP
+    ('\u{D7F5}', CharMapAction::SubStr("\u{114C}\u{1106}")), // HANGUL JONGSEONG YESIEUNG-MIEUM
+    ('\u{D7F6}', CharMapAction::SubStr("\u{114C}\u{1112}")), // HANGUL JONGSEONG YESIEUNG-HIEUH
+    ('\u{D7F7}', CharMapAction::SubStr("\u{110C}\u{1107}")), // HANGUL JONGSEONG CIEUC-PIEUP
+    ('\u{D7F8}', CharMapAction::SubStr("\u{110C}\u{1107}\u{1107}")), // HANGUL JONGSEONG CIEUC-SSANGPIEUP
+    ('\u{D7F9}', CharMapAction::SubStr("\u{110C}\u{110C}")), // HANGUL JONGSEONG SSANGCIEUC
+    ('\u{D7FA}', CharMapAction::SubStr("\u{1111}\u{1109}")), // HANGUL JONGSEONG PHIEUPH-SIOS
+    ('\u{D7FB}', CharMapAction::SubStr("\u{1111}\u{1110}")), // HANGUL JONGSEONG PHIEUPH-THIEUTH
+    ('\u{F900}', CharMapAction::SubChar('\u{8C48}')), // CJK COMPATIBILITY IDEOGRAPH-F900
+    ('\u{F901}', CharMapAction::SubChar('\u{66F4}')), // CJK COMPATIBILITY IDEOGRAPH-F901
+    ('\u{F902}', CharMapAction::SubChar('\u{8ECA}')), // CJK COMPATIBILITY IDEOGRAPH-F902
+    ('\u{F903}', CharMapAction::SubChar('\u{8CC8}')), // CJK COMPATIBILITY IDEOGRAPH-F903
+    ('\u{F904}', CharMapAction::SubChar('\u{6ED1}')), // CJK COMPATIBILITY IDEOGRAPH-F904
+    ('\u{F905}', CharMapAction::SubChar('\u{4E32}')), // CJK COMPATIBILITY IDEOGRAPH-F905
+    ('\u{F906}', CharMapAction::SubChar('\u{53E5}')), // CJK COMPATIBILITY IDEOGRAPH-F906
+    ('\u{F907}', CharMapAction::SubChar('\u{9F9C}')), // CJK COMPATIBILITY IDEOGRAPH-F907
+    ('\u{F908}', CharMapAction::SubChar('\u{9F9C}')), // CJK COMPATIBILITY IDEOGRAPH-F908
+    ('\u{F909}', CharMapAction::SubChar('\u{5951}')), // CJK COMPATIBILITY IDEOGRAPH-F909
+    ('\u{F90A}', CharMapAction::SubChar('\u{91D1}')), // CJK COMPATIBILITY IDEOGRAPH-F90A
+    ('\u{F90B}', CharMapAction::SubChar('\u{5587}')), // CJK COMPATIBILITY IDEOGRAPH-F90B
+    ('\u{F90C}', CharMapAction::SubChar('\u{5948}')), // CJK COMPATIBILITY IDEOGRAPH-F90C
+    ('\u{F90D}', CharMapAction::SubChar('\u{61F6}')), // CJK COMPATIBILITY IDEOGRAPH-F90D
+    ('\u{F90E}', CharMapAction::SubChar('\u{7669}')), // CJK COMPATIBILITY IDEOGRAPH-F90E
+    ('\u{F90F}', CharMapAction::SubChar('\u{7F85}')), // CJK COMPATIBILITY IDEOGRAPH-F90F
+    ('\u{F910}', CharMapAction::SubChar('\u{863F}')), // CJK COMPATIBILITY IDEOGRAPH-F910
+    ('\u{F911}', CharMapAction::SubChar('\u{87BA}')), // CJK COMPATIBILITY IDEOGRAPH-F911
+    ('\u{F912}', CharMapAction::SubChar('\u{88F8}')), // CJK COMPATIBILITY IDEOGRAPH-F912
+    ('\u{F913}', CharMapAction::SubChar('\u{908F}')), // CJK COMPATIBILITY IDEOGRAPH-F913
+    ('\u{F914}', CharMapAction::SubChar('\u{6A02}')), // CJK COMPATIBILITY IDEOGRAPH-F914
+    ('\u{F915}', CharMapAction::SubChar('\u{6D1B}')), // CJK COMPATIBILITY IDEOGRAPH-F915
+    ('\u{F916}', CharMapAction::SubChar('\u{70D9}')), // CJK COMPATIBILITY IDEOGRAPH-F916
+    ('\u{F917}', CharMapAction::SubChar('\u{73DE}')), // CJK COMPATIBILITY IDEOGRAPH-F917
+    ('\u{F918}', CharMapAction::SubChar('\u{843D}')), // CJK COMPATIBILITY IDEOGRAPH-F918
+    ('\u{F919}', CharMapAction::SubChar('\u{916A}')), // CJK COMPATIBILITY IDEOGRAPH-F919
+    ('\u{F91A}', CharMapAction::SubChar('\u{99F1}')), // CJK COMPATIBILITY IDEOGRAPH-F91A
+    ('\u{F91B}', CharMapAction::SubChar('\u{4E82}')), // CJK COMPATIBILITY IDEOGRAPH-F91B
+    ('\u{F91C}', CharMapAction::SubChar('\u{5375}')), // CJK COMPATIBILITY IDEOGRAPH-F91C
+    ('\u{F91D}', CharMapAction::SubChar('\u{6B04}')), // CJK COMPATIBILITY IDEOGRAPH-F91D
+    ('\u{F91E}', CharMapAction::SubChar('\u{721B}')), // CJK COMPATIBILITY IDEOGRAPH-F91E
+    ('\u{F91F}', CharMapAction::SubChar('\u{862D}')), // CJK COMPATIBILITY IDEOGRAPH-F91F
+    ('\u{F920}', CharMapAction::SubChar('\u{9E1E}')), // CJK COMPATIBILITY IDEOGRAPH-F920
+    ('\u{F921}', CharMapAction::SubChar('\u{5D50}')), // CJK COMPATIBILITY IDEOGRAPH-F921
+    ('\u{F922}', CharMapAction::SubChar('\u{6FEB}')), // CJK COMPATIBILITY IDEOGRAPH-F922
+    ('\u{F923}', CharMapAction::SubChar('\u{85CD}')), // CJK COMPATIBILITY IDEOGRAPH-F923
+    ('\u{F924}', CharMapAction::SubChar('\u{8964}')), // CJK COMPATIBILITY IDEOGRAPH-F924
+    ('\u{F925}', CharMapAction::SubChar('\u{62C9}')), // CJK COMPATIBILITY IDEOGRAPH-F925
+    ('\u{F926}', CharMapAction::SubChar('\u{81D8}')), // CJK COMPATIBILITY IDEOGRAPH-F926
+    ('\u{F927}', CharMapAction::SubChar('\u{881F}')), // CJK COMPATIBILITY IDEOGRAPH-F927
+    ('\u{F928}', CharMapAction::SubChar('\u{5ECA}')), // CJK COMPATIBILITY IDEOGRAPH-F928
+    ('\u{F929}', CharMapAction::SubChar('\u{6717}')), // CJK COMPATIBILITY IDEOGRAPH-F929
+    ('\u{F92A}', CharMapAction::SubChar('\u{6D6A}')), // CJK COMPATIBILITY IDEOGRAPH-F92A
+    ('\u{F92B}', CharMapAction::SubChar('\u{72FC}')), // CJK COMPATIBILITY IDEOGRAPH-F92B
+    ('\u{F92C}', CharMapAction::SubChar('\u{90CE}')), // CJK COMPATIBILITY IDEOGRAPH-F92C
+    ('\u{F92D}', CharMapAction::SubChar('\u{4F86}')), // CJK COMPATIBILITY IDEOGRAPH-F92D
+    ('\u{F92E}', CharMapAction::SubChar('\u{51B7}')), // CJK COMPATIBILITY IDEOGRAPH-F92E
+    ('\u{F92F}', CharMapAction::SubChar('\u{52DE}')), // CJK COMPATIBILITY IDEOGRAPH-F92F
+    ('\u{F930}', CharMapAction::SubChar('\u{64C4}')), // CJK COMPATIBILITY IDEOGRAPH-F930
+    ('\u{F931}', CharMapAction::SubChar('\u{6AD3}')), // CJK COMPATIBILITY IDEOGRAPH-F931
+    ('\u{F932}', CharMapAction::SubChar('\u{7210}')), // CJK COMPATIBILITY IDEOGRAPH-F932
+    ('\u{F933}', CharMapAction::SubChar('\u{76E7}')), // CJK COMPATIBILITY IDEOGRAPH-F933
+    ('\u{F934}', CharMapAction::SubChar('\u{8001}')), // CJK COMPATIBILITY IDEOGRAPH-F934
+    ('\u{F935}', CharMapAction::SubChar('\u{8606}')), // CJK COMPATIBILITY IDEOGRAPH-F935
+    ('\u{F936}', CharMapAction::SubChar('\u{865C}')), // CJK COMPATIBILITY IDEOGRAPH-F936
+    ('\u{F937}', CharMapAction::SubChar('\u{8DEF}')), // CJK COMPATIBILITY IDEOGRAPH-F937
+    ('\u{F938}', CharMapAction::SubChar('\u{9732}')), // CJK COMPATIBILITY IDEOGRAPH-F938
+    ('\u{F939}', CharMapAction::SubChar('\u{9B6F}')), // CJK COMPATIBILITY IDEOGRAPH-F939
+    ('\u{F93A}', CharMapAction::SubChar('\u{9DFA}')), // CJK COMPATIBILITY IDEOGRAPH-F93A
+    ('\u{F93B}', CharMapAction::SubChar('\u{788C}')), // CJK COMPATIBILITY IDEOGRAPH-F93B
+    ('\u{F93C}', CharMapAction::SubChar('\u{797F}')), // CJK COMPATIBILITY IDEOGRAPH-F93C
+    ('\u{F93D}', CharMapAction::SubChar('\u{7DA0}')), // CJK COMPATIBILITY IDEOGRAPH-F93D
+    ('\u{F93E}', CharMapAction::SubChar('\u{83C9}')), // CJK COMPATIBILITY IDEOGRAPH-F93E
+    ('\u{F93F}', CharMapAction::SubChar('\u{9304}')), // CJK COMPATIBILITY IDEOGRAPH-F93F
+    ('\u{F940}', CharMapAction::SubChar('\u{9E7F}')), // CJK COMPATIBILITY IDEOGRAPH-F940
+    ('\u{F941}', CharMapAction::SubChar('\u{8AD6}')), // CJK COMPATIBILITY IDEOGRAPH-F941
+    ('\u{F942}', CharMapAction::SubChar('\u{58DF}')), // CJK COMPATIBILITY IDEOGRAPH-F942
+    ('\u{F943}', CharMapAction::SubChar('\u{5F04}')), // CJK COMPATIBILITY IDEOGRAPH-F943
+    ('\u{F944}', CharMapAction::SubChar('\u{7C60}')), // CJK COMPATIBILITY IDEOGRAPH-F944
+    ('\u{F945}', CharMapAction::SubChar('\u{807E}')), // CJK COMPATIBILITY IDEOGRAPH-F945
+    ('\u{F946}', CharMapAction::SubChar('\u{7262}')), // CJK COMPATIBILITY IDEOGRAPH-F946
+    ('\u{F947}', CharMapAction::SubChar('\u{78CA}')), // CJK COMPATIBILITY IDEOGRAPH-F947
+    ('\u{F948}', CharMapAction::SubChar('\u{8CC2}')), // CJK COMPATIBILITY IDEOGRAPH-F948
+    ('\u{F949}', CharMapAction::SubChar('\u{96F7}')), // CJK COMPATIBILITY IDEOGRAPH-F949
+    ('\u{F94A}', CharMapAction::SubChar('\u{58D8}')), // CJK COMPATIBILITY IDEOGRAPH-F94A
+    ('\u{F94B}', CharMapAction::SubChar('\u{5C62}')), // CJK COMPATIBILITY IDEOGRAPH-F94B
+    ('\u{F94C}', CharMapAction::SubChar('\u{6A13}')), // CJK COMPATIBILITY IDEOGRAPH-F94C
+    ('\u{F94D}', CharMapAction::SubChar('\u{6DDA}')), // CJK COMPATIBILITY IDEOGRAPH-F94D
+    ('\u{F94E}', CharMapAction::SubChar('\u{6F0F}')), // CJK COMPATIBILITY IDEOGRAPH-F94E
+    ('\u{F94F}', CharMapAction::SubChar('\u{7D2F}')), // CJK COMPATIBILITY IDEOGRAPH-F94F
+    ('\u{F950}', CharMapAction::SubChar('\u{7E37}')), // CJK COMPATIBILITY IDEOGRAPH-F950
+    ('\u{F951}', CharMapAction::SubChar('\u{964B}')), // CJK COMPATIBILITY IDEOGRAPH-F951
+    ('\u{F952}', CharMapAction::SubChar('\u{52D2}')), // CJK COMPATIBILITY IDEOGRAPH-F952
+    ('\u{F953}', CharMapAction::SubChar('\u{808B}')), // CJK COMPATIBILITY IDEOGRAPH-F953
+    ('\u{F954}', CharMapAction::SubChar('\u{51DC}')), // CJK COMPATIBILITY IDEOGRAPH-F954
+    ('\u{F955}', CharMapAction::SubChar('\u{51CC}')), // CJK COMPATIBILITY IDEOGRAPH-F955
+    ('\u{F956}', CharMapAction::SubChar('\u{7A1C}')), // CJK COMPATIBILITY IDEOGRAPH-F956
+    ('\u{F957}', CharMapAction::SubChar('\u{7DBE}')), // CJK COMPATIBILITY IDEOGRAPH-F957
+    ('\u{F958}', CharMapAction::SubChar('\u{83F1}')), // CJK COMPATIBILITY IDEOGRAPH-F958
+    ('\u{F959}', CharMapAction::SubChar('\u{9675}')), // CJK COMPATIBILITY IDEOGRAPH-F959
+    ('\u{F95A}', CharMapAction::SubChar('\u{8B80}')), // CJK COMPATIBILITY IDEOGRAPH-F95A
+    ('\u{F95B}', CharMapAction::SubChar('\u{62CF}')), // CJK COMPATIBILITY IDEOGRAPH-F95B
+    ('\u{F95C}', CharMapAction::SubChar('\u{6A02}')), // CJK COMPATIBILITY IDEOGRAPH-F95C
+    ('\u{F95D}', CharMapAction::SubChar('\u{8AFE}')), // CJK COMPATIBILITY IDEOGRAPH-F95D
+    ('\u{F95E}', CharMapAction::SubChar('\u{4E39}')), // CJK COMPATIBILITY IDEOGRAPH-F95E
+    ('\u{F95F}', CharMapAction::SubChar('\u{5BE7}')), // CJK COMPATIBILITY IDEOGRAPH-F95F
+    ('\u{F960}', CharMapAction::SubChar('\u{6012}')), // CJK COMPATIBILITY IDEOGRAPH-F960
+    ('\u{F961}', CharMapAction::SubChar('\u{7387}')), // CJK COMPATIBILITY IDEOGRAPH-F961
+    ('\u{F962}', CharMapAction::SubChar('\u{7570}')), // CJK COMPATIBILITY IDEOGRAPH-F962
+    ('\u{F963}', CharMapAction::SubChar('\u{5317}')), // CJK COMPATIBILITY IDEOGRAPH-F963
+    ('\u{F964}', CharMapAction::SubChar('\u{78FB}')), // CJK COMPATIBILITY IDEOGRAPH-F964
+    ('\u{F965}', CharMapAction::SubChar('\u{4FBF}')), // CJK COMPATIBILITY IDEOGRAPH-F965
+    ('\u{F966}', CharMapAction::SubChar('\u{5FA9}')), // CJK COMPATIBILITY IDEOGRAPH-F966
+    ('\u{F967}', CharMapAction::SubChar('\u{4E0D}')), // CJK COMPATIBILITY IDEOGRAPH-F967
+    ('\u{F968}', CharMapAction::SubChar('\u{6CCC}')), // CJK COMPATIBILITY IDEOGRAPH-F968
+    ('\u{F969}', CharMapAction::SubChar('\u{6578}')), // CJK COMPATIBILITY IDEOGRAPH-F969
+    ('\u{F96A}', CharMapAction::SubChar('\u{7D22}')), // CJK COMPATIBILITY IDEOGRAPH-F96A
+    ('\u{F96B}', CharMapAction::SubChar('\u{53C3}')), // CJK COMPATIBILITY IDEOGRAPH-F96B
+    ('\u{F96C}', CharMapAction::SubChar('\u{585E}')), // CJK COMPATIBILITY IDEOGRAPH-F96C
+    ('\u{F96D}', CharMapAction::SubChar('\u{7701}')), // CJK COMPATIBILITY IDEOGRAPH-F96D
+    ('\u{F96E}', CharMapAction::SubChar('\u{8449}')), // CJK COMPATIBILITY IDEOGRAPH-F96E
+    ('\u{F96F}', CharMapAction::SubChar('\u{8AAA}')), // CJK COMPATIBILITY IDEOGRAPH-F96F
+    ('\u{F970}', CharMapAction::SubChar('\u{6BBA}')), // CJK COMPATIBILITY IDEOGRAPH-F970
+    ('\u{F971}', CharMapAction::SubChar('\u{8FB0}')), // CJK COMPATIBILITY IDEOGRAPH-F971
+    ('\u{F972}', CharMapAction::SubChar('\u{6C88}')), // CJK COMPATIBILITY IDEOGRAPH-F972
+    ('\u{F973}', CharMapAction::SubChar('\u{62FE}')), // CJK COMPATIBILITY IDEOGRAPH-F973
+    ('\u{F974}', CharMapAction::SubChar('\u{82E5}')), // CJK COMPATIBILITY IDEOGRAPH-F974
+    ('\u{F975}', CharMapAction::SubChar('\u{63A0}')), // CJK COMPATIBILITY IDEOGRAPH-F975
+    ('\u{F976}', CharMapAction::SubChar('\u{7565}')), // CJK COMPATIBILITY IDEOGRAPH-F976
+    ('\u{F977}', CharMapAction::SubChar('\u{4EAE}')), // CJK COMPATIBILITY IDEOGRAPH-F977
+    ('\u{F978}', CharMapAction::SubChar('\u{5169}')), // CJK COMPATIBILITY IDEOGRAPH-F978
+    ('\u{F979}', CharMapAction::SubChar('\u{51C9}')), // CJK COMPATIBILITY IDEOGRAPH-F979
+    ('\u{F97A}', CharMapAction::SubChar('\u{6881}')), // CJK COMPATIBILITY IDEOGRAPH-F97A
+    ('\u{F97B}', CharMapAction::SubChar('\u{7CE7}')), // CJK COMPATIBILITY IDEOGRAPH-F97B
+    ('\u{F97C}', CharMapAction::SubChar('\u{826F}')), // CJK COMPATIBILITY IDEOGRAPH-F97C
+    ('\u{F97D}', CharMapAction::SubChar('\u{8AD2}')), // CJK COMPATIBILITY IDEOGRAPH-F97D
+    ('\u{F97E}', CharMapAction::SubChar('\u{91CF}')), // CJK COMPATIBILITY IDEOGRAPH-F97E
+    ('\u{F97F}', CharMapAction::SubChar('\u{52F5}')), // CJK COMPATIBILITY IDEOGRAPH-F97F
+    ('\u{F980}', CharMapAction::SubChar('\u{5442}')), // CJK COMPATIBILITY IDEOGRAPH-F980
+    ('\u{F981}', CharMapAction::SubChar('\u{5973}')), // CJK COMPATIBILITY IDEOGRAPH-F981
+    ('\u{F982}', CharMapAction::SubChar('\u{5EEC}')), // CJK COMPATIBILITY IDEOGRAPH-F982
+    ('\u{F983}', CharMapAction::SubChar('\u{65C5}')), // CJK COMPATIBILITY IDEOGRAPH-F983
+    ('\u{F984}', CharMapAction::SubChar('\u{6FFE}')), // CJK COMPATIBILITY IDEOGRAPH-F984
+    ('\u{F985}', CharMapAction::SubChar('\u{792A}')), // CJK COMPATIBILITY IDEOGRAPH-F985
+    ('\u{F986}', CharMapAction::SubChar('\u{95AD}')), // CJK COMPATIBILITY IDEOGRAPH-F986
+    ('\u{F987}', CharMapAction::SubChar('\u{9A6A}')), // CJK COMPATIBILITY IDEOGRAPH-F987
+    ('\u{F988}', CharMapAction::SubChar('\u{9E97}')), // CJK COMPATIBILITY IDEOGRAPH-F988
+    ('\u{F989}', CharMapAction::SubChar('\u{9ECE}')), // CJK COMPATIBILITY IDEOGRAPH-F989
+    ('\u{F98A}', CharMapAction::SubChar('\u{529B}')), // CJK COMPATIBILITY IDEOGRAPH-F98A
+    ('\u{F98B}', CharMapAction::SubChar('\u{66C6}')), // CJK COMPATIBILITY IDEOGRAPH-F98B
+    ('\u{F98C}', CharMapAction::SubChar('\u{6B77}')), // CJK COMPATIBILITY IDEOGRAPH-F98C
+    ('\u{F98D}', CharMapAction::SubChar('\u{8F62}')), // CJK COMPATIBILITY IDEOGRAPH-F98D
+    ('\u{F98E}', CharMapAction::SubChar('\u{5E74}')), // CJK COMPATIBILITY IDEOGRAPH-F98E
+    ('\u{F98F}', CharMapAction::SubChar('\u{6190}')), // CJK COMPATIBILITY IDEOGRAPH-F98F
+    ('\u{F990}', CharMapAction::SubChar('\u{6200}')), // CJK COMPATIBILITY IDEOGRAPH-F990
+    ('\u{F991}', CharMapAction::SubChar('\u{649A}')), // CJK COMPATIBILITY IDEOGRAPH-F991
+    ('\u{F992}', CharMapAction::SubChar('\u{6F23}')), // CJK COMPATIBILITY IDEOGRAPH-F992
+    ('\u{F993}', CharMapAction::SubChar('\u{7149}')), // CJK COMPATIBILITY IDEOGRAPH-F993
+    ('\u{F994}', CharMapAction::SubChar('\u{7489}')), // CJK COMPATIBILITY IDEOGRAPH-F994
+    ('\u{F995}', CharMapAction::SubChar('\u{79CA}')), // CJK COMPATIBILITY IDEOGRAPH-F995
+    ('\u{F996}', CharMapAction::SubChar('\u{7DF4}')), // CJK COMPATIBILITY IDEOGRAPH-F996
+    ('\u{F997}', CharMapAction::SubChar('\u{806F}')), // CJK COMPATIBILITY IDEOGRAPH-F997
+    ('\u{F998}', CharMapAction::SubChar('\u{8F26}')), // CJK COMPATIBILITY IDEOGRAPH-F998
+    ('\u{F999}', CharMapAction::SubChar('\u{84EE}')), // CJK COMPATIBILITY IDEOGRAPH-F999
+    ('\u{F99A}', CharMapAction::SubChar('\u{9023}')), // CJK COMPATIBILITY IDEOGRAPH-F99A
+    ('\u{F99B}', CharMapAction::SubChar('\u{934A}')), // CJK COMPATIBILITY IDEOGRAPH-F99B
+    ('\u{F99C}', CharMapAction::SubChar('\u{5217}')), // CJK COMPATIBILITY IDEOGRAPH-F99C
+    ('\u{F99D}', CharMapAction::SubChar('\u{52A3}')), // CJK COMPATIBILITY IDEOGRAPH-F99D
+    ('\u{F99E}', CharMapAction::SubChar('\u{54BD}')), // CJK COMPATIBILITY IDEOGRAPH-F99E
+    ('\u{F99F}', CharMapAction::SubChar('\u{70C8}')), // CJK COMPATIBILITY IDEOGRAPH-F99F
+    ('\u{F9A0}', CharMapAction::SubChar('\u{88C2}')), // CJK COMPATIBILITY IDEOGRAPH-F9A0
+    ('\u{F9A1}', CharMapAction::SubChar('\u{8AAA}')), // CJK COMPATIBILITY IDEOGRAPH-F9A1
+    ('\u{F9A2}', CharMapAction::SubChar('\u{5EC9}')), // CJK COMPATIBILITY IDEOGRAPH-F9A2
+    ('\u{F9A3}', CharMapAction::SubChar('\u{5FF5}')), // CJK COMPATIBILITY IDEOGRAPH-F9A3
+    ('\u{F9A4}', CharMapAction::SubChar('\u{637B}')), // CJK COMPATIBILITY IDEOGRAPH-F9A4
+    ('\u{F9A5}', CharMapAction::SubChar('\u{6BAE}')), // CJK COMPATIBILITY IDEOGRAPH-F9A5
+    ('\u{F9A6}', CharMapAction::SubChar('\u{7C3E}')), // CJK COMPATIBILITY IDEOGRAPH-F9A6
+    ('\u{F9A7}', CharMapAction::SubChar('\u{7375}')), // CJK COMPATIBILITY IDEOGRAPH-F9A7
+    ('\u{F9A8}', CharMapAction::SubChar('\u{4EE4}')), // CJK COMPATIBILITY IDEOGRAPH-F9A8
+    ('\u{F9A9}', CharMapAction::SubChar('\u{56F9}')), // CJK COMPATIBILITY IDEOGRAPH-F9A9
+    ('\u{F9AA}', CharMapAction::SubChar('\u{5BE7}')), // CJK COMPATIBILITY IDEOGRAPH-F9AA
+    ('\u{F9AB}', CharMapAction::SubChar('\u{5DBA}')), // CJK COMPATIBILITY IDEOGRAPH-F9AB
+    ('\u{F9AC}', CharMapAction::SubChar('\u{601C}')), // CJK COMPATIBILITY IDEOGRAPH-F9AC
+    ('\u{F9AD}', CharMapAction::SubChar('\u{73B2}')), // CJK COMPATIBILITY IDEOGRAPH-F9AD
+    ('\u{F9AE}', CharMapAction::SubChar('\u{7469}')), // CJK COMPATIBILITY IDEOGRAPH-F9AE
+    ('\u{F9AF}', CharMapAction::SubChar('\u{7F9A}')), // CJK COMPATIBILITY IDEOGRAPH-F9AF
+    ('\u{F9B0}', CharMapAction::SubChar('\u{8046}')), // CJK COMPATIBILITY IDEOGRAPH-F9B0
+    ('\u{F9B1}', CharMapAction::SubChar('\u{9234}')), // CJK COMPATIBILITY IDEOGRAPH-F9B1
+    ('\u{F9B2}', CharMapAction::SubChar('\u{96F6}')), // CJK COMPATIBILITY IDEOGRAPH-F9B2
+    ('\u{F9B3}', CharMapAction::SubChar('\u{9748}')), // CJK COMPATIBILITY IDEOGRAPH-F9B3
+    ('\u{F9B4}', CharMapAction::SubChar('\u{9818}')), // CJK COMPATIBILITY IDEOGRAPH-F9B4
+    ('\u{F9B5}', CharMapAction::SubChar('\u{4F8B}')), // CJK COMPATIBILITY IDEOGRAPH-F9B5
+    ('\u{F9B6}', CharMapAction::SubChar('\u{79AE}')), // CJK COMPATIBILITY IDEOGRAPH-F9B6
+    ('\u{F9B7}', CharMapAction::SubChar('\u{91B4}')), // CJK COMPATIBILITY IDEOGRAPH-F9B7
+    ('\u{F9B8}', CharMapAction::SubChar('\u{96B7}')), // CJK COMPATIBILITY IDEOGRAPH-F9B8
+    ('\u{F9B9}', CharMapAction::SubChar('\u{60E1}')), // CJK COMPATIBILITY IDEOGRAPH-F9B9
+    ('\u{F9BA}', CharMapAction::SubChar('\u{4E86}')), // CJK COMPATIBILITY IDEOGRAPH-F9BA
+    ('\u{F9BB}', CharMapAction::SubChar('\u{50DA}')), // CJK COMPATIBILITY IDEOGRAPH-F9BB
+    ('\u{F9BC}', CharMapAction::SubChar('\u{5BEE}')), // CJK COMPATIBILITY IDEOGRAPH-F9BC
+    ('\u{F9BD}', CharMapAction::SubChar('\u{5C3F}')), // CJK COMPATIBILITY IDEOGRAPH-F9BD
+    ('\u{F9BE}', CharMapAction::SubChar('\u{6599}')), // CJK COMPATIBILITY IDEOGRAPH-F9BE
+    ('\u{F9BF}', CharMapAction::SubChar('\u{6A02}')), // CJK COMPATIBILITY IDEOGRAPH-F9BF
+    ('\u{F9C0}', CharMapAction::SubChar('\u{71CE}')), // CJK COMPATIBILITY IDEOGRAPH-F9C0
+    ('\u{F9C1}', CharMapAction::SubChar('\u{7642}')), // CJK COMPATIBILITY IDEOGRAPH-F9C1
+    ('\u{F9C2}', CharMapAction::SubChar('\u{84FC}')), // CJK COMPATIBILITY IDEOGRAPH-F9C2
+    ('\u{F9C3}', CharMapAction::SubChar('\u{907C}')), // CJK COMPATIBILITY IDEOGRAPH-F9C3
+    ('\u{F9C4}', CharMapAction::SubChar('\u{9F8D}')), // CJK COMPATIBILITY IDEOGRAPH-F9C4
+    ('\u{F9C5}', CharMapAction::SubChar('\u{6688}')), // CJK COMPATIBILITY IDEOGRAPH-F9C5
+    ('\u{F9C6}', CharMapAction::SubChar('\u{962E}')), // CJK COMPATIBILITY IDEOGRAPH-F9C6
+    ('\u{F9C7}', CharMapAction::SubChar('\u{5289}')), // CJK COMPATIBILITY IDEOGRAPH-F9C7
+    ('\u{F9C8}', CharMapAction::SubChar('\u{677B}')), // CJK COMPATIBILITY IDEOGRAPH-F9C8
+    ('\u{F9C9}', CharMapAction::SubChar('\u{67F3}')), // CJK COMPATIBILITY IDEOGRAPH-F9C9
+    ('\u{F9CA}', CharMapAction::SubChar('\u{6D41}')), // CJK COMPATIBILITY IDEOGRAPH-F9CA
+    ('\u{F9CB}', CharMapAction::SubChar('\u{6E9C}')), // CJK COMPATIBILITY IDEOGRAPH-F9CB
+    ('\u{F9CC}', CharMapAction::SubChar('\u{7409}')), // CJK COMPATIBILITY IDEOGRAPH-F9CC
+    ('\u{F9CD}', CharMapAction::SubChar('\u{7559}')), // CJK COMPATIBILITY IDEOGRAPH-F9CD
+    ('\u{F9CE}', CharMapAction::SubChar('\u{786B}')), // CJK COMPATIBILITY IDEOGRAPH-F9CE
+    ('\u{F9CF}', CharMapAction::SubChar('\u{7D10}')), // CJK COMPATIBILITY IDEOGRAPH-F9CF
+    ('\u{F9D0}', CharMapAction::SubChar('\u{985E}')), // CJK COMPATIBILITY IDEOGRAPH-F9D0
+    ('\u{F9D1}', CharMapAction::SubChar('\u{516D}')), // CJK COMPATIBILITY IDEOGRAPH-F9D1
+    ('\u{F9D2}', CharMapAction::SubChar('\u{622E}')), // CJK COMPATIBILITY IDEOGRAPH-F9D2
+    ('\u{F9D3}', CharMapAction::SubChar('\u{9678}')), // CJK COMPATIBILITY IDEOGRAPH-F9D3
+    ('\u{F9D4}', CharMapAction::SubChar('\u{502B}')), // CJK COMPATIBILITY IDEOGRAPH-F9D4
+    ('\u{F9D5}', CharMapAction::SubChar('\u{5D19}')), // CJK COMPATIBILITY IDEOGRAPH-F9D5
+    ('\u{F9D6}', CharMapAction::SubChar('\u{6DEA}')), // CJK COMPATIBILITY IDEOGRAPH-F9D6
+    ('\u{F9D7}', CharMapAction::SubChar('\u{8F2A}')), // CJK COMPATIBILITY IDEOGRAPH-F9D7
+    ('\u{F9D8}', CharMapAction::SubChar('\u{5F8B}')), // CJK COMPATIBILITY IDEOGRAPH-F9D8
+    ('\u{F9D9}', CharMapAction::SubChar('\u{6144}')), // CJK COMPATIBILITY IDEOGRAPH-F9D9
+    ('\u{F9DA}', CharMapAction::SubChar('\u{6817}')), // CJK COMPATIBILITY IDEOGRAPH-F9DA
+    ('\u{F9DB}', CharMapAction::SubChar('\u{7387}')), // CJK COMPATIBILITY IDEOGRAPH-F9DB
+    ('\u{F9DC}', CharMapAction::SubChar('\u{9686}')), // CJK COMPATIBILITY IDEOGRAPH-F9DC
+    ('\u{F9DD}', CharMapAction::SubChar('\u{5229}')), // CJK COMPATIBILITY IDEOGRAPH-F9DD
+    ('\u{F9DE}', CharMapAction::SubChar('\u{540F}')), // CJK COMPATIBILITY IDEOGRAPH-F9DE
+    ('\u{F9DF}', CharMapAction::SubChar('\u{5C65}')), // CJK COMPATIBILITY IDEOGRAPH-F9DF
+    ('\u{F9E0}', CharMapAction::SubChar('\u{6613}')), // CJK COMPATIBILITY IDEOGRAPH-F9E0
+    ('\u{F9E1}', CharMapAction::SubChar('\u{674E}')), // CJK COMPATIBILITY IDEOGRAPH-F9E1
+    ('\u{F9E2}', CharMapAction::SubChar('\u{68A8}')), // CJK COMPATIBILITY IDEOGRAPH-F9E2
+    ('\u{F9E3}', CharMapAction::SubChar('\u{6CE5}')), // CJK COMPATIBILITY IDEOGRAPH-F9E3
+    ('\u{F9E4}', CharMapAction::SubChar('\u{7406}')), // CJK COMPATIBILITY IDEOGRAPH-F9E4
+    ('\u{F9E5}', CharMapAction::SubChar('\u{75E2}')), // CJK COMPATIBILITY IDEOGRAPH-F9E5
+    ('\u{F9E6}', CharMapAction::SubChar('\u{7F79}')), // CJK COMPATIBILITY IDEOGRAPH-F9E6
+    ('\u{F9E7}', CharMapAction::SubChar('\u{88CF}')), // CJK COMPATIBILITY IDEOGRAPH-F9E7
+    ('\u{F9E8}', CharMapAction::SubChar('\u{88E1}')), // CJK COMPATIBILITY IDEOGRAPH-F9E8
+    ('\u{F9E9}', CharMapAction::SubChar('\u{91CC}')), // CJK COMPATIBILITY IDEOGRAPH-F9E9
+    ('\u{F9EA}', CharMapAction::SubChar('\u{96E2}')), // CJK COMPATIBILITY IDEOGRAPH-F9EA
+    ('\u{F9EB}', CharMapAction::SubChar('\u{533F}')), // CJK COMPATIBILITY IDEOGRAPH-F9EB
+    ('\u{F9EC}', CharMapAction::SubChar('\u{6EBA}')), // CJK COMPATIBILITY IDEOGRAPH-F9EC
+    ('\u{F9ED}', CharMapAction::SubChar('\u{541D}')), // CJK COMPATIBILITY IDEOGRAPH-F9ED
+    ('\u{F9EE}', CharMapAction::SubChar('\u{71D0}')), // CJK COMPATIBILITY IDEOGRAPH-F9EE
+    ('\u{F9EF}', CharMapAction::SubChar('\u{7498}')), // CJK COMPATIBILITY IDEOGRAPH-F9EF
+    ('\u{F9F0}', CharMapAction::SubChar('\u{85FA}')), // CJK COMPATIBILITY IDEOGRAPH-F9F0
+    ('\u{F9F1}', CharMapAction::SubChar('\u{96A3}')), // CJK COMPATIBILITY IDEOGRAPH-F9F1
+    ('\u{F9F2}', CharMapAction::SubChar('\u{9C57}')), // CJK COMPATIBILITY IDEOGRAPH-F9F2
+    ('\u{F9F3}', CharMapAction::SubChar('\u{9E9F}')), // CJK COMPATIBILITY IDEOGRAPH-F9F3
+    ('\u{F9F4}', CharMapAction::SubChar('\u{6797}')), // CJK COMPATIBILITY IDEOGRAPH-F9F4
+    ('\u{F9F5}', CharMapAction::SubChar('\u{6DCB}')), // CJK COMPATIBILITY IDEOGRAPH-F9F5
+    ('\u{F9F6}', CharMapAction::SubChar('\u{81E8}')), // CJK COMPATIBILITY IDEOGRAPH-F9F6
+    ('\u{F9F7}', CharMapAction::SubChar('\u{7ACB}')), // CJK COMPATIBILITY IDEOGRAPH-F9F7
+    ('\u{F9F8}', CharMapAction::SubChar('\u{7B20}')), // CJK COMPATIBILITY IDEOGRAPH-F9F8
+    ('\u{F9F9}', CharMapAction::SubChar('\u{7C92}')), // CJK COMPATIBILITY IDEOGRAPH-F9F9
+    ('\u{F9FA}', CharMapAction::SubChar('\u{72C0}')), // CJK COMPATIBILITY IDEOGRAPH-F9FA
+    ('\u{F9FB}', CharMapAction::SubChar('\u{7099}')), // CJK COMPATIBILITY IDEOGRAPH-F9FB
+    ('\u{F9FC}', CharMapAction::SubChar('\u{8B58}')), // CJK COMPATIBILITY IDEOGRAPH-F9FC
+    ('\u{F9FD}', CharMapAction::SubChar('\u{4EC0}')), // CJK COMPATIBILITY IDEOGRAPH-F9FD
+    ('\u{F9FE}', CharMapAction::SubChar('\u{8336}')), // CJK COMPATIBILITY IDEOGRAPH-F9FE
+    ('\u{F9FF}', CharMapAction::SubChar('\u{523A}')), // CJK COMPATIBILITY IDEOGRAPH-F9FF
+    ('\u{FA00}', CharMapAction::SubChar('\u{5207}')), // CJK COMPATIBILITY IDEOGRAPH-FA00
+    ('\u{FA01}', CharMapAction::SubChar('\u{5EA6}')), // CJK COMPATIBILITY IDEOGRAPH-FA01
+    ('\u{FA02}', CharMapAction::SubChar('\u{62D3}')), // CJK COMPATIBILITY IDEOGRAPH-FA02
+    ('\u{FA03}', CharMapAction::SubChar('\u{7CD6}')), // CJK COMPATIBILITY IDEOGRAPH-FA03
+    ('\u{FA04}', CharMapAction::SubChar('\u{5B85}')), // CJK COMPATIBILITY IDEOGRAPH-FA04
+    ('\u{FA05}', CharMapAction::SubChar('\u{6D1E}')), // CJK COMPATIBILITY IDEOGRAPH-FA05
+    ('\u{FA06}', CharMapAction::SubChar('\u{66B4}')), // CJK COMPATIBILITY IDEOGRAPH-FA06
+    ('\u{FA07}', CharMapAction::SubChar('\u{8F3B}')), // CJK COMPATIBILITY IDEOGRAPH-FA07
+    ('\u{FA08}', CharMapAction::SubChar('\u{884C}')), // CJK COMPATIBILITY IDEOGRAPH-FA08
+    ('\u{FA09}', CharMapAction::SubChar('\u{964D}')), // CJK COMPATIBILITY IDEOGRAPH-FA09
+    ('\u{FA0A}', CharMapAction::SubChar('\u{898B}')), // CJK COMPATIBILITY IDEOGRAPH-FA0A
+    ('\u{FA0B}', CharMapAction::SubChar('\u{5ED3}')), // CJK COMPATIBILITY IDEOGRAPH-FA0B
+    ('\u{FA0C}', CharMapAction::SubChar('\u{5140}')), // CJK COMPATIBILITY IDEOGRAPH-FA0C
+    ('\u{FA0D}', CharMapAction::SubChar('\u{55C0}')), // CJK COMPATIBILITY IDEOGRAPH-FA0D
+    ('\u{FA10}', CharMapAction::SubChar('\u{585A}')), // CJK COMPATIBILITY IDEOGRAPH-FA10
+    ('\u{FA12}', CharMapAction::SubChar('\u{6674}')), // CJK COMPATIBILITY IDEOGRAPH-FA12
+    ('\u{FA15}', CharMapAction::SubChar('\u{51DE}')), // CJK COMPATIBILITY IDEOGRAPH-FA15
+    ('\u{FA16}', CharMapAction::SubChar('\u{732A}')), // CJK COMPATIBILITY IDEOGRAPH-FA16
+    ('\u{FA17}', CharMapAction::SubChar('\u{76CA}')), // CJK COMPATIBILITY IDEOGRAPH-FA17
+    ('\u{FA18}', CharMapAction::SubChar('\u{793C}')), // CJK COMPATIBILITY IDEOGRAPH-FA18
+    ('\u{FA19}', CharMapAction::SubChar('\u{795E}')), // CJK COMPATIBILITY IDEOGRAPH-FA19
+    ('\u{FA1A}', CharMapAction::SubChar('\u{7965}')), // CJK COMPATIBILITY IDEOGRAPH-FA1A
+    ('\u{FA1B}', CharMapAction::SubChar('\u{798F}')), // CJK COMPATIBILITY IDEOGRAPH-FA1B
+    ('\u{FA1C}', CharMapAction::SubChar('\u{9756}')), // CJK COMPATIBILITY IDEOGRAPH-FA1C
+    ('\u{FA1D}', CharMapAction::SubChar('\u{7CBE}')), // CJK COMPATIBILITY IDEOGRAPH-FA1D
+    ('\u{FA1E}', CharMapAction::SubChar('\u{7FBD}')), // CJK COMPATIBILITY IDEOGRAPH-FA1E
+    ('\u{FA20}', CharMapAction::SubChar('\u{8612}')), // CJK COMPATIBILITY IDEOGRAPH-FA20
+    ('\u{FA22}', CharMapAction::SubChar('\u{8AF8}')), // CJK COMPATIBILITY IDEOGRAPH-FA22
+    ('\u{FA25}', CharMapAction::SubChar('\u{9038}')), // CJK COMPATIBILITY IDEOGRAPH-FA25
+    ('\u{FA26}', CharMapAction::SubChar('\u{90FD}')), // CJK COMPATIBILITY IDEOGRAPH-FA26
+    ('\u{FA2A}', CharMapAction::SubChar('\u{98EF}')), // CJK COMPATIBILITY IDEOGRAPH-FA2A
+    ('\u{FA2B}', CharMapAction::SubChar('\u{98FC}')), // CJK COMPATIBILITY IDEOGRAPH-FA2B
+    ('\u{FA2C}', CharMapAction::SubChar('\u{9928}')), // CJK COMPATIBILITY IDEOGRAPH-FA2C
+    ('\u{FA2D}', CharMapAction::SubChar('\u{9DB4}')), // CJK COMPATIBILITY IDEOGRAPH-FA2D
+    ('\u{FA2E}', CharMapAction::SubChar('\u{90CE}')), // CJK COMPATIBILITY IDEOGRAPH-FA2E
+    ('\u{FA2F}', CharMapAction::SubChar('\u{96B7}')), // CJK COMPATIBILITY IDEOGRAPH-FA2F
+    ('\u{FA30}', CharMapAction::SubChar('\u{4FAE}')), // CJK COMPATIBILITY IDEOGRAPH-FA30
+    ('\u{FA31}', CharMapAction::SubChar('\u{50E7}')), // CJK COMPATIBILITY IDEOGRAPH-FA31
+    ('\u{FA32}', CharMapAction::SubChar('\u{514D}')), // CJK COMPATIBILITY IDEOGRAPH-FA32
+    ('\u{FA33}', CharMapAction::SubChar('\u{52C9}')), // CJK COMPATIBILITY IDEOGRAPH-FA33
+    ('\u{FA34}', CharMapAction::SubChar('\u{52E4}')), // CJK COMPATIBILITY IDEOGRAPH-FA34
+    ('\u{FA35}', CharMapAction::SubChar('\u{5351}')), // CJK COMPATIBILITY IDEOGRAPH-FA35
+    ('\u{FA36}', CharMapAction::SubChar('\u{559D}')), // CJK COMPATIBILITY IDEOGRAPH-FA36
+    ('\u{FA37}', CharMapAction::SubChar('\u{5606}')), // CJK COMPATIBILITY IDEOGRAPH-FA37
+    ('\u{FA38}', CharMapAction::SubChar('\u{5668}')), // CJK COMPATIBILITY IDEOGRAPH-FA38
+    ('\u{FA39}', CharMapAction::SubChar('\u{5840}')), // CJK COMPATIBILITY IDEOGRAPH-FA39
+    ('\u{FA3A}', CharMapAction::SubChar('\u{58A8}')), // CJK COMPATIBILITY IDEOGRAPH-FA3A
+    ('\u{FA3B}', CharMapAction::SubChar('\u{5C64}')), // CJK COMPATIBILITY IDEOGRAPH-FA3B
+    ('\u{FA3C}', CharMapAction::SubChar('\u{5C6E}')), // CJK COMPATIBILITY IDEOGRAPH-FA3C
+    ('\u{FA3D}', CharMapAction::SubChar('\u{6094}')), // CJK COMPATIBILITY IDEOGRAPH-FA3D
+    ('\u{FA3E}', CharMapAction::SubChar('\u{6168}')), // CJK COMPATIBILITY IDEOGRAPH-FA3E
+    ('\u{FA3F}', CharMapAction::SubChar('\u{618E}')), // CJK COMPATIBILITY IDEOGRAPH-FA3F
+    ('\u{FA40}', CharMapAction::SubChar('\u{61F2}')), // CJK COMPATIBILITY IDEOGRAPH-FA40
+    ('\u{FA41}', CharMapAction::SubChar('\u{654F}')), // CJK COMPATIBILITY IDEOGRAPH-FA41
+    ('\u{FA42}', CharMapAction::SubChar('\u{65E2}')), // CJK COMPATIBILITY IDEOGRAPH-FA42
+    ('\u{FA43}', CharMapAction::SubChar('\u{6691}')), // CJK COMPATIBILITY IDEOGRAPH-FA43
+    ('\u{FA44}', CharMapAction::SubChar('\u{6885}')), // CJK COMPATIBILITY IDEOGRAPH-FA44
+    ('\u{FA45}', CharMapAction::SubChar('\u{6D77}')), // CJK COMPATIBILITY IDEOGRAPH-FA45
+    ('\u{FA46}', CharMapAction::SubChar('\u{6E1A}')), // CJK COMPATIBILITY IDEOGRAPH-FA46
+    ('\u{FA47}', CharMapAction::SubChar('\u{6F22}')), // CJK COMPATIBILITY IDEOGRAPH-FA47
+    ('\u{FA48}', CharMapAction::SubChar('\u{716E}')), // CJK COMPATIBILITY IDEOGRAPH-FA48
+    ('\u{FA49}', CharMapAction::SubChar('\u{722B}')), // CJK COMPATIBILITY IDEOGRAPH-FA49
+    ('\u{FA4A}', CharMapAction::SubChar('\u{7422}')), // CJK COMPATIBILITY IDEOGRAPH-FA4A
+    ('\u{FA4B}', CharMapAction::SubChar('\u{7891}')), // CJK COMPATIBILITY IDEOGRAPH-FA4B
+    ('\u{FA4C}', CharMapAction::SubChar('\u{793E}')), // CJK COMPATIBILITY IDEOGRAPH-FA4C
+    ('\u{FA4D}', CharMapAction::SubChar('\u{7949}')), // CJK COMPATIBILITY IDEOGRAPH-FA4D
+    ('\u{FA4E}', CharMapAction::SubChar('\u{7948}')), // CJK COMPATIBILITY IDEOGRAPH-FA4E
+    ('\u{FA4F}', CharMapAction::SubChar('\u{7950}')), // CJK COMPATIBILITY IDEOGRAPH-FA4F
+    ('\u{FA50}', CharMapAction::SubChar('\u{7956}')), // CJK COMPATIBILITY IDEOGRAPH-FA50
+    ('\u{FA51}', CharMapAction::SubChar('\u{795D}')), // CJK COMPATIBILITY IDEOGRAPH-FA51
+    ('\u{FA52}', CharMapAction::SubChar('\u{798D}')), // CJK COMPATIBILITY IDEOGRAPH-FA52
+    ('\u{FA53}', CharMapAction::SubChar('\u{798E}')), // CJK COMPATIBILITY IDEOGRAPH-FA53
+    ('\u{FA54}', CharMapAction::SubChar('\u{7A40}')), // CJK COMPATIBILITY IDEOGRAPH-FA54
+    ('\u{FA55}', CharMapAction::SubChar('\u{7A81}')), // CJK COMPATIBILITY IDEOGRAPH-FA55
+    ('\u{FA56}', CharMapAction::SubChar('\u{7BC0}')), // CJK COMPATIBILITY IDEOGRAPH-FA56
+    ('\u{FA57}', CharMapAction::SubChar('\u{7DF4}')), // CJK COMPATIBILITY IDEOGRAPH-FA57
+    ('\u{FA58}', CharMapAction::SubChar('\u{7E09}')), // CJK COMPATIBILITY IDEOGRAPH-FA58
+    ('\u{FA59}', CharMapAction::SubChar('\u{7E41}')), // CJK COMPATIBILITY IDEOGRAPH-FA59
+    ('\u{FA5A}', CharMapAction::SubChar('\u{7F72}')), // CJK COMPATIBILITY IDEOGRAPH-FA5A
+    ('\u{FA5B}', CharMapAction::SubChar('\u{8005}')), // CJK COMPATIBILITY IDEOGRAPH-FA5B
+    ('\u{FA5C}', CharMapAction::SubChar('\u{81ED}')), // CJK COMPATIBILITY IDEOGRAPH-FA5C
+    ('\u{FA5D}', CharMapAction::SubChar('\u{8279}')), // CJK COMPATIBILITY IDEOGRAPH-FA5D
+    ('\u{FA5E}', CharMapAction::SubChar('\u{8279}')), // CJK COMPATIBILITY IDEOGRAPH-FA5E
+    ('\u{FA5F}', CharMapAction::SubChar('\u{8457}')), // CJK COMPATIBILITY IDEOGRAPH-FA5F
+    ('\u{FA60}', CharMapAction::SubChar('\u{8910}')), // CJK COMPATIBILITY IDEOGRAPH-FA60
+    ('\u{FA61}', CharMapAction::SubChar('\u{8996}')), // CJK COMPATIBILITY IDEOGRAPH-FA61
+    ('\u{FA62}', CharMapAction::SubChar('\u{8B01}')), // CJK COMPATIBILITY IDEOGRAPH-FA62
+    ('\u{FA63}', CharMapAction::SubChar('\u{8B39}')), // CJK COMPATIBILITY IDEOGRAPH-FA63
+    ('\u{FA64}', CharMapAction::SubChar('\u{8CD3}')), // CJK COMPATIBILITY IDEOGRAPH-FA64
+    ('\u{FA65}', CharMapAction::SubChar('\u{8D08}')), // CJK COMPATIBILITY IDEOGRAPH-FA65
+    ('\u{FA66}', CharMapAction::SubChar('\u{8FB6}')), // CJK COMPATIBILITY IDEOGRAPH-FA66
+    ('\u{FA67}', CharMapAction::SubChar('\u{9038}')), // CJK COMPATIBILITY IDEOGRAPH-FA67
+    ('\u{FA68}', CharMapAction::SubChar('\u{96E3}')), // CJK COMPATIBILITY IDEOGRAPH-FA68
+    ('\u{FA69}', CharMapAction::SubChar('\u{97FF}')), // CJK COMPATIBILITY IDEOGRAPH-FA69
+    ('\u{FA6A}', CharMapAction::SubChar('\u{983B}')), // CJK COMPATIBILITY IDEOGRAPH-FA6A
+    ('\u{FA6B}', CharMapAction::SubChar('\u{6075}')), // CJK COMPATIBILITY IDEOGRAPH-FA6B
+    ('\u{FA6C}', CharMapAction::SubChar('\u{242EE}')), // CJK COMPATIBILITY IDEOGRAPH-FA6C
+    ('\u{FA6D}', CharMapAction::SubChar('\u{8218}')), // CJK COMPATIBILITY IDEOGRAPH-FA6D
+    ('\u{FA70}', CharMapAction::SubChar('\u{4E26}')), // CJK COMPATIBILITY IDEOGRAPH-FA70
+    ('\u{FA71}', CharMapAction::SubChar('\u{51B5}')), // CJK COMPATIBILITY IDEOGRAPH-FA71
+    ('\u{FA72}', CharMapAction::SubChar('\u{5168}')), // CJK COMPATIBILITY IDEOGRAPH-FA72
+    ('\u{FA73}', CharMapAction::SubChar('\u{4F80}')), // CJK COMPATIBILITY IDEOGRAPH-FA73
+    ('\u{FA74}', CharMapAction::SubChar('\u{5145}')), // CJK COMPATIBILITY IDEOGRAPH-FA74
+    ('\u{FA75}', CharMapAction::SubChar('\u{5180}')), // CJK COMPATIBILITY IDEOGRAPH-FA75
+    ('\u{FA76}', CharMapAction::SubChar('\u{52C7}')), // CJK COMPATIBILITY IDEOGRAPH-FA76
+    ('\u{FA77}', CharMapAction::SubChar('\u{52FA}')), // CJK COMPATIBILITY IDEOGRAPH-FA77
+    ('\u{FA78}', CharMapAction::SubChar('\u{559D}')), // CJK COMPATIBILITY IDEOGRAPH-FA78
+    ('\u{FA79}', CharMapAction::SubChar('\u{5555}')), // CJK COMPATIBILITY IDEOGRAPH-FA79
+    ('\u{FA7A}', CharMapAction::SubChar('\u{5599}')), // CJK COMPATIBILITY IDEOGRAPH-FA7A
+    ('\u{FA7B}', CharMapAction::SubChar('\u{55E2}')), // CJK COMPATIBILITY IDEOGRAPH-FA7B
+    ('\u{FA7C}', CharMapAction::SubChar('\u{585A}')), // CJK COMPATIBILITY IDEOGRAPH-FA7C
+    ('\u{FA7D}', CharMapAction::SubChar('\u{58B3}')), // CJK COMPATIBILITY IDEOGRAPH-FA7D
+    ('\u{FA7E}', CharMapAction::SubChar('\u{5944}')), // CJK COMPATIBILITY IDEOGRAPH-FA7E
+    ('\u{FA7F}', CharMapAction::SubChar('\u{5954}')), // CJK COMPATIBILITY IDEOGRAPH-FA7F
+    ('\u{FA80}', CharMapAction::SubChar('\u{5A62}')), // CJK COMPATIBILITY IDEOGRAPH-FA80
+    ('\u{FA81}', CharMapAction::SubChar('\u{5B28}')), // CJK COMPATIBILITY IDEOGRAPH-FA81
+    ('\u{FA82}', CharMapAction::SubChar('\u{5ED2}')), // CJK COMPATIBILITY IDEOGRAPH-FA82
+    ('\u{FA83}', CharMapAction::SubChar('\u{5ED9}')), // CJK COMPATIBILITY IDEOGRAPH-FA83
+    ('\u{FA84}', CharMapAction::SubChar('\u{5F69}')), // CJK COMPATIBILITY IDEOGRAPH-FA84
+    ('\u{FA85}', CharMapAction::SubChar('\u{5FAD}')), // CJK COMPATIBILITY IDEOGRAPH-FA85
+    ('\u{FA86}', CharMapAction::SubChar('\u{60D8}')), // CJK COMPATIBILITY IDEOGRAPH-FA86
+    ('\u{FA87}', CharMapAction::SubChar('\u{614E}')), // CJK COMPATIBILITY IDEOGRAPH-FA87
+    ('\u{FA88}', CharMapAction::SubChar('\u{6108}')), // CJK COMPATIBILITY IDEOGRAPH-FA88
+    ('\u{FA89}', CharMapAction::SubChar('\u{618E}')), // CJK COMPATIBILITY IDEOGRAPH-FA89
+    ('\u{FA8A}', CharMapAction::SubChar('\u{6160}')), // CJK COMPATIBILITY IDEOGRAPH-FA8A
+    ('\u{FA8B}', CharMapAction::SubChar('\u{61F2}')), // CJK COMPATIBILITY IDEOGRAPH-FA8B
+    ('\u{FA8C}', CharMapAction::SubChar('\u{6234}')), // CJK COMPATIBILITY IDEOGRAPH-FA8C
+    ('\u{FA8D}', CharMapAction::SubChar('\u{63C4}')), // CJK COMPATIBILITY IDEOGRAPH-FA8D
+    ('\u{FA8E}', CharMapAction::SubChar('\u{641C}')), // CJK COMPATIBILITY IDEOGRAPH-FA8E
+    ('\u{FA8F}', CharMapAction::SubChar('\u{6452}')), // CJK COMPATIBILITY IDEOGRAPH-FA8F
+    ('\u{FA90}', CharMapAction::SubChar('\u{6556}')), // CJK COMPATIBILITY IDEOGRAPH-FA90
+    ('\u{FA91}', CharMapAction::SubChar('\u{6674}')), // CJK COMPATIBILITY IDEOGRAPH-FA91
+    ('\u{FA92}', CharMapAction::SubChar('\u{6717}')), // CJK COMPATIBILITY IDEOGRAPH-FA92
+    ('\u{FA93}', CharMapAction::SubChar('\u{671B}')), // CJK COMPATIBILITY IDEOGRAPH-FA93
+    ('\u{FA94}', CharMapAction::SubChar('\u{6756}')), // CJK COMPATIBILITY IDEOGRAPH-FA94
+    ('\u{FA95}', CharMapAction::SubChar('\u{6B79}')), // CJK COMPATIBILITY IDEOGRAPH-FA95
+    ('\u{FA96}', CharMapAction::SubChar('\u{6BBA}')), // CJK COMPATIBILITY IDEOGRAPH-FA96
+    ('\u{FA97}', CharMapAction::SubChar('\u{6D41}')), // CJK COMPATIBILITY IDEOGRAPH-FA97
+    ('\u{FA98}', CharMapAction::SubChar('\u{6EDB}')), // CJK COMPATIBILITY IDEOGRAPH-FA98
+    ('\u{FA99}', CharMapAction::SubChar('\u{6ECB}')), // CJK COMPATIBILITY IDEOGRAPH-FA99
+    ('\u{FA9A}', CharMapAction::SubChar('\u{6F22}')), // CJK COMPATIBILITY IDEOGRAPH-FA9A
+    ('\u{FA9B}', CharMapAction::SubChar('\u{701E}')), // CJK COMPATIBILITY IDEOGRAPH-FA9B
+    ('\u{FA9C}', CharMapAction::SubChar('\u{716E}')), // CJK COMPATIBILITY IDEOGRAPH-FA9C
+    ('\u{FA9D}', CharMapAction::SubChar('\u{77A7}')), // CJK COMPATIBILITY IDEOGRAPH-FA9D
+    ('\u{FA9E}', CharMapAction::SubChar('\u{7235}')), // CJK COMPATIBILITY IDEOGRAPH-FA9E
+    ('\u{FA9F}', CharMapAction::SubChar('\u{72AF}')), // CJK COMPATIBILITY IDEOGRAPH-FA9F
+    ('\u{FAA0}', CharMapAction::SubChar('\u{732A}')), // CJK COMPATIBILITY IDEOGRAPH-FAA0
+    ('\u{FAA1}', CharMapAction::SubChar('\u{7471}')), // CJK COMPATIBILITY IDEOGRAPH-FAA1
+    ('\u{FAA2}', CharMapAction::SubChar('\u{7506}')), // CJK COMPATIBILITY IDEOGRAPH-FAA2
+    ('\u{FAA3}', CharMapAction::SubChar('\u{753B}')), // CJK COMPATIBILITY IDEOGRAPH-FAA3
+    ('\u{FAA4}', CharMapAction::SubChar('\u{761D}')), // CJK COMPATIBILITY IDEOGRAPH-FAA4
+    ('\u{FAA5}', CharMapAction::SubChar('\u{761F}')), // CJK COMPATIBILITY IDEOGRAPH-FAA5
+    ('\u{FAA6}', CharMapAction::SubChar('\u{76CA}')), // CJK COMPATIBILITY IDEOGRAPH-FAA6
+    ('\u{FAA7}', CharMapAction::SubChar('\u{76DB}')), // CJK COMPATIBILITY IDEOGRAPH-FAA7
+    ('\u{FAA8}', CharMapAction::SubChar('\u{76F4}')), // CJK COMPATIBILITY IDEOGRAPH-FAA8
+    ('\u{FAA9}', CharMapAction::SubChar('\u{774A}')), // CJK COMPATIBILITY IDEOGRAPH-FAA9
+    ('\u{FAAA}', CharMapAction::SubChar('\u{7740}')), // CJK COMPATIBILITY IDEOGRAPH-FAAA
+    ('\u{FAAB}', CharMapAction::SubChar('\u{78CC}')), // CJK COMPATIBILITY IDEOGRAPH-FAAB
+    ('\u{FAAC}', CharMapAction::SubChar('\u{7AB1}')), // CJK COMPATIBILITY IDEOGRAPH-FAAC
+    ('\u{FAAD}', CharMapAction::SubChar('\u{7BC0}')), // CJK COMPATIBILITY IDEOGRAPH-FAAD
+    ('\u{FAAE}', CharMapAction::SubChar('\u{7C7B}')), // CJK COMPATIBILITY IDEOGRAPH-FAAE
+    ('\u{FAAF}', CharMapAction::SubChar('\u{7D5B}')), // CJK COMPATIBILITY IDEOGRAPH-FAAF
+    ('\u{FAB0}', CharMapAction::SubChar('\u{7DF4}')), // CJK COMPATIBILITY IDEOGRAPH-FAB0
+    ('\u{FAB1}', CharMapAction::SubChar('\u{7F3E}')), // CJK COMPATIBILITY IDEOGRAPH-FAB1
+    ('\u{FAB2}', CharMapAction::SubChar('\u{8005}')), // CJK COMPATIBILITY IDEOGRAPH-FAB2
+    ('\u{FAB3}', CharMapAction::SubChar('\u{8352}')), // CJK COMPATIBILITY IDEOGRAPH-FAB3
+    ('\u{FAB4}', CharMapAction::SubChar('\u{83EF}')), // CJK COMPATIBILITY IDEOGRAPH-FAB4
+    ('\u{FAB5}', CharMapAction::SubChar('\u{8779}')), // CJK COMPATIBILITY IDEOGRAPH-FAB5
+    ('\u{FAB6}', CharMapAction::SubChar('\u{8941}')), // CJK COMPATIBILITY IDEOGRAPH-FAB6
+    ('\u{FAB7}', CharMapAction::SubChar('\u{8986}')), // CJK COMPATIBILITY IDEOGRAPH-FAB7
+    ('\u{FAB8}', CharMapAction::SubChar('\u{8996}')), // CJK COMPATIBILITY IDEOGRAPH-FAB8
+    ('\u{FAB9}', CharMapAction::SubChar('\u{8ABF}')), // CJK COMPATIBILITY IDEOGRAPH-FAB9
+    ('\u{FABA}', CharMapAction::SubChar('\u{8AF8}')), // CJK COMPATIBILITY IDEOGRAPH-FABA
+    ('\u{FABB}', CharMapAction::SubChar('\u{8ACB}')), // CJK COMPATIBILITY IDEOGRAPH-FABB
+    ('\u{FABC}', CharMapAction::SubChar('\u{8B01}')), // CJK COMPATIBILITY IDEOGRAPH-FABC
+    ('\u{FABD}', CharMapAction::SubChar('\u{8AFE}')), // CJK COMPATIBILITY IDEOGRAPH-FABD
+    ('\u{FABE}', CharMapAction::SubChar('\u{8AED}')), // CJK COMPATIBILITY IDEOGRAPH-FABE
+    ('\u{FABF}', CharMapAction::SubChar('\u{8B39}')), // CJK COMPATIBILITY IDEOGRAPH-FABF
+    ('\u{FAC0}', CharMapAction::SubChar('\u{8B8A}')), // CJK COMPATIBILITY IDEOGRAPH-FAC0
+    ('\u{FAC1}', CharMapAction::SubChar('\u{8D08}')), // CJK COMPATIBILITY IDEOGRAPH-FAC1
+    ('\u{FAC2}', CharMapAction::SubChar('\u{8F38}')), // CJK COMPATIBILITY IDEOGRAPH-FAC2
+    ('\u{FAC3}', CharMapAction::SubChar('\u{9072}')), // CJK COMPATIBILITY IDEOGRAPH-FAC3
+    ('\u{FAC4}', CharMapAction::SubChar('\u{9199}')), // CJK COMPATIBILITY IDEOGRAPH-FAC4
+    ('\u{FAC5}', CharMapAction::SubChar('\u{9276}')), // CJK COMPATIBILITY IDEOGRAPH-FAC5
+    ('\u{FAC6}', CharMapAction::SubChar('\u{967C}')), // CJK COMPATIBILITY IDEOGRAPH-FAC6
+    ('\u{FAC7}', CharMapAction::SubChar('\u{96E3}')), // CJK COMPATIBILITY IDEOGRAPH-FAC7
+    ('\u{FAC8}', CharMapAction::SubChar('\u{9756}')), // CJK COMPATIBILITY IDEOGRAPH-FAC8
+    ('\u{FAC9}', CharMapAction::SubChar('\u{97DB}')), // CJK COMPATIBILITY IDEOGRAPH-FAC9
+    ('\u{FACA}', CharMapAction::SubChar('\u{97FF}')), // CJK COMPATIBILITY IDEOGRAPH-FACA
+    ('\u{FACB}', CharMapAction::SubChar('\u{980B}')), // CJK COMPATIBILITY IDEOGRAPH-FACB
+    ('\u{FACC}', CharMapAction::SubChar('\u{983B}')), // CJK COMPATIBILITY IDEOGRAPH-FACC
+    ('\u{FACD}', CharMapAction::SubChar('\u{9B12}')), // CJK COMPATIBILITY IDEOGRAPH-FACD
+    ('\u{FACE}', CharMapAction::SubChar('\u{9F9C}')), // CJK COMPATIBILITY IDEOGRAPH-FACE
+    ('\u{FACF}', CharMapAction::SubChar('\u{2284A}')), // CJK COMPATIBILITY IDEOGRAPH-FACF
+    ('\u{FAD0}', CharMapAction::SubChar('\u{22844}')), // CJK COMPATIBILITY IDEOGRAPH-FAD0
+    ('\u{FAD1}', CharMapAction::SubChar('\u{233D5}')), // CJK COMPATIBILITY IDEOGRAPH-FAD1
+    ('\u{FAD2}', CharMapAction::SubChar('\u{3B9D}')), // CJK COMPATIBILITY IDEOGRAPH-FAD2
+    ('\u{FAD3}', CharMapAction::SubChar('\u{4018}')), // CJK COMPATIBILITY IDEOGRAPH-FAD3
+    ('\u{FAD4}', CharMapAction::SubChar('\u{4039}')), // CJK COMPATIBILITY IDEOGRAPH-FAD4
+    ('\u{FAD5}', CharMapAction::SubChar('\u{25249}')), // CJK COMPATIBILITY IDEOGRAPH-FAD5
+    ('\u{FAD6}', CharMapAction::SubChar('\u{25CD0}')), // CJK COMPATIBILITY IDEOGRAPH-FAD6
+    ('\u{FAD7}', CharMapAction::SubChar('\u{27ED3}')), // CJK COMPATIBILITY IDEOGRAPH-FAD7
+    ('\u{FAD8}', CharMapAction::SubChar('\u{9F43}')), // CJK COMPATIBILITY IDEOGRAPH-FAD8
+    ('\u{FAD9}', CharMapAction::SubChar('\u{9F8E}')), // CJK COMPATIBILITY IDEOGRAPH-FAD9
+    ('\u{FB00}', CharMapAction::SubStr("ff")), // LATIN SMALL LIGATURE FF
+    ('\u{FB01}', CharMapAction::SubStr("fi")), // LATIN SMALL LIGATURE FI
+    ('\u{FB02}', CharMapAction::SubStr("fl")), // LATIN SMALL LIGATURE FL
+    ('\u{FB03}', CharMapAction::SubStr("ffi")), // LATIN SMALL LIGATURE FFI
+    ('\u{FB04}', CharMapAction::SubStr("ffl")), // LATIN SMALL LIGATURE FFL
+    ('\u{FB06}', CharMapAction::SubStr("st")), // LATIN SMALL LIGATURE ST
+    ('\u{FB13}', CharMapAction::SubStr("\u{0574}\u{0576}")), // ARMENIAN SMALL LIGATURE MEN NOW
+    ('\u{FB14}', CharMapAction::SubStr("\u{0574}\u{0565}")), // ARMENIAN SMALL LIGATURE MEN ECH
+    ('\u{FB15}', CharMapAction::SubStr("\u{0574}\u{056B}")), // ARMENIAN SMALL LIGATURE MEN INI
+    ('\u{FB16}', CharMapAction::SubStr("\u{057E}\u{0576}")), // ARMENIAN SMALL LIGATURE VEW NOW
+    ('\u{FB17}', CharMapAction::SubStr("\u{0574}\u{056D}")), // ARMENIAN SMALL LIGATURE MEN XEH
+    ('\u{FB20}', CharMapAction::SubChar('\u{05E2}')), // HEBREW LETTER ALTERNATIVE AYIN
+    ('\u{FB21}', CharMapAction::SubChar('\u{05D0}')), // HEBREW LETTER WIDE ALEF
+    ('\u{FB22}', CharMapAction::SubChar('\u{05D3}')), // HEBREW LETTER WIDE DALET
+    ('\u{FB23}', CharMapAction::SubChar('\u{05D4}')), // HEBREW LETTER WIDE HE
+    ('\u{FB24}', CharMapAction::SubChar('\u{05DB}')), // HEBREW LETTER WIDE KAF
+    ('\u{FB25}', CharMapAction::SubChar('\u{05DC}')), // HEBREW LETTER WIDE LAMED
+    ('\u{FB26}', CharMapAction::SubChar('\u{05DD}')), // HEBREW LETTER WIDE FINAL MEM
+    ('\u{FB27}', CharMapAction::SubChar('\u{05E8}')), // HEBREW LETTER WIDE RESH
+    ('\u{FB28}', CharMapAction::SubChar('\u{05EA}')), // HEBREW LETTER WIDE TAV
+    ('\u{FB29}', CharMapAction::SubStr("-\u{0307}")), // HEBREW LETTER ALTERNATIVE PLUS SIGN
+    ('\u{FB2B}', CharMapAction::SubChar('\u{FB2A}')), // HEBREW LETTER SHIN WITH SIN DOT
+    ('\u{FB2D}', CharMapAction::SubChar('\u{FB2C}')), // HEBREW LETTER SHIN WITH DAGESH AND SIN DOT
+    ('\u{FB2F}', CharMapAction::SubChar('\u{FB2E}')), // HEBREW LETTER ALEF WITH QAMATS
+    ('\u{FB30}', CharMapAction::SubChar('\u{FB2E}')), // HEBREW LETTER ALEF WITH MAPIQ
+    ('\u{FB39}', CharMapAction::SubChar('\u{FB1D}')), // HEBREW LETTER YOD WITH DAGESH
+    ('\u{FB49}', CharMapAction::SubChar('\u{FB2A}')), // HEBREW LETTER SHIN WITH DAGESH
+    ('\u{FB4F}', CharMapAction::SubStr("\u{05D0}\u{05DC}")), // HEBREW LIGATURE ALEF LAMED
+    ('\u{FB50}', CharMapAction::SubChar('\u{0671}')), // ARABIC LETTER ALEF WASLA ISOLATED FORM
+    ('\u{FB51}', CharMapAction::SubChar('\u{0671}')), // ARABIC LETTER ALEF WASLA FINAL FORM
+    ('\u{FB52}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER BEEH ISOLATED FORM
+    ('\u{FB53}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER BEEH FINAL FORM
+    ('\u{FB54}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER BEEH INITIAL FORM
+    ('\u{FB55}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER BEEH MEDIAL FORM
+    ('\u{FB56}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER PEH ISOLATED FORM
+    ('\u{FB57}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER PEH FINAL FORM
+    ('\u{FB58}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER PEH INITIAL FORM
+    ('\u{FB59}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER PEH MEDIAL FORM
+    ('\u{FB5A}', CharMapAction::SubChar('\u{0680}')), // ARABIC LETTER BEHEH ISOLATED FORM
+    ('\u{FB5B}', CharMapAction::SubChar('\u{0680}')), // ARABIC LETTER BEHEH FINAL FORM
+    ('\u{FB5C}', CharMapAction::SubChar('\u{0680}')), // ARABIC LETTER BEHEH INITIAL FORM
+    ('\u{FB5D}', CharMapAction::SubChar('\u{0680}')), // ARABIC LETTER BEHEH MEDIAL FORM
+    ('\u{FB5E}', CharMapAction::SubChar('\u{067A}')), // ARABIC LETTER TTEHEH ISOLATED FORM
+    ('\u{FB5F}', CharMapAction::SubChar('\u{067A}')), // ARABIC LETTER TTEHEH FINAL FORM
+    ('\u{FB60}', CharMapAction::SubChar('\u{067A}')), // ARABIC LETTER TTEHEH INITIAL FORM
+    ('\u{FB61}', CharMapAction::SubChar('\u{067A}')), // ARABIC LETTER TTEHEH MEDIAL FORM
+    ('\u{FB62}', CharMapAction::SubChar('\u{067F}')), // ARABIC LETTER TEHEH ISOLATED FORM
+    ('\u{FB63}', CharMapAction::SubChar('\u{067F}')), // ARABIC LETTER TEHEH FINAL FORM
+    ('\u{FB64}', CharMapAction::SubChar('\u{067F}')), // ARABIC LETTER TEHEH INITIAL FORM
+    ('\u{FB65}', CharMapAction::SubChar('\u{067F}')), // ARABIC LETTER TEHEH MEDIAL FORM
+    ('\u{FB66}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER TTEH ISOLATED FORM
+    ('\u{FB67}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER TTEH FINAL FORM
+    ('\u{FB68}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER TTEH INITIAL FORM
+    ('\u{FB69}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER TTEH MEDIAL FORM
+    ('\u{FB6A}', CharMapAction::SubStr("\u{06A1}\u{06DB}")), // ARABIC LETTER VEH ISOLATED FORM
+    ('\u{FB6B}', CharMapAction::SubStr("\u{06A1}\u{06DB}")), // ARABIC LETTER VEH FINAL FORM
+    ('\u{FB6C}', CharMapAction::SubStr("\u{06A1}\u{06DB}")), // ARABIC LETTER VEH INITIAL FORM
+    ('\u{FB6D}', CharMapAction::SubStr("\u{06A1}\u{06DB}")), // ARABIC LETTER VEH MEDIAL FORM
+    ('\u{FB6E}', CharMapAction::SubChar('\u{06A6}')), // ARABIC LETTER PEHEH ISOLATED FORM
+    ('\u{FB6F}', CharMapAction::SubChar('\u{06A6}')), // ARABIC LETTER PEHEH FINAL FORM
+    ('\u{FB70}', CharMapAction::SubChar('\u{06A6}')), // ARABIC LETTER PEHEH INITIAL FORM
+    ('\u{FB71}', CharMapAction::SubChar('\u{06A6}')), // ARABIC LETTER PEHEH MEDIAL FORM
+    ('\u{FB72}', CharMapAction::SubChar('\u{0684}')), // ARABIC LETTER DYEH ISOLATED FORM
+    ('\u{FB73}', CharMapAction::SubChar('\u{0684}')), // ARABIC LETTER DYEH FINAL FORM
+    ('\u{FB74}', CharMapAction::SubChar('\u{0684}')), // ARABIC LETTER DYEH INITIAL FORM
+    ('\u{FB75}', CharMapAction::SubChar('\u{0684}')), // ARABIC LETTER DYEH MEDIAL FORM
+    ('\u{FB76}', CharMapAction::SubChar('\u{0683}')), // ARABIC LETTER NYEH ISOLATED FORM
+    ('\u{FB77}', CharMapAction::SubChar('\u{0683}')), // ARABIC LETTER NYEH FINAL FORM
+    ('\u{FB78}', CharMapAction::SubChar('\u{0683}')), // ARABIC LETTER NYEH INITIAL FORM
+    ('\u{FB79}', CharMapAction::SubChar('\u{0683}')), // ARABIC LETTER NYEH MEDIAL FORM
+    ('\u{FB7A}', CharMapAction::SubChar('\u{0686}')), // ARABIC LETTER TCHEH ISOLATED FORM
+    ('\u{FB7B}', CharMapAction::SubChar('\u{0686}')), // ARABIC LETTER TCHEH FINAL FORM
+    ('\u{FB7C}', CharMapAction::SubChar('\u{0686}')), // ARABIC LETTER TCHEH INITIAL FORM
+    ('\u{FB7D}', CharMapAction::SubChar('\u{0686}')), // ARABIC LETTER TCHEH MEDIAL FORM
+    ('\u{FB7E}', CharMapAction::SubChar('\u{0687}')), // ARABIC LETTER TCHEHEH ISOLATED FORM
+    ('\u{FB7F}', CharMapAction::SubChar('\u{0687}')), // ARABIC LETTER TCHEHEH FINAL FORM
+    ('\u{FB80}', CharMapAction::SubChar('\u{0687}')), // ARABIC LETTER TCHEHEH INITIAL FORM
+    ('\u{FB81}', CharMapAction::SubChar('\u{0687}')), // ARABIC LETTER TCHEHEH MEDIAL FORM
+    ('\u{FB82}', CharMapAction::SubChar('\u{068D}')), // ARABIC LETTER DDAHAL ISOLATED FORM
+    ('\u{FB83}', CharMapAction::SubChar('\u{068D}')), // ARABIC LETTER DDAHAL FINAL FORM
+    ('\u{FB84}', CharMapAction::SubChar('\u{068C}')), // ARABIC LETTER DAHAL ISOLATED FORM
+    ('\u{FB85}', CharMapAction::SubChar('\u{068C}')), // ARABIC LETTER DAHAL FINAL FORM
+    ('\u{FB86}', CharMapAction::SubStr("\u{062F}\u{06DB}")), // ARABIC LETTER DUL ISOLATED FORM
+    ('\u{FB87}', CharMapAction::SubStr("\u{062F}\u{06DB}")), // ARABIC LETTER DUL FINAL FORM
+    ('\u{FB88}', CharMapAction::SubStr("\u{062F}\u{0615}")), // ARABIC LETTER DDAL ISOLATED FORM
+    ('\u{FB89}', CharMapAction::SubStr("\u{062F}\u{0615}")), // ARABIC LETTER DDAL FINAL FORM
+    ('\u{FB8A}', CharMapAction::SubStr("\u{0631}\u{06DB}")), // ARABIC LETTER JEH ISOLATED FORM
+    ('\u{FB8B}', CharMapAction::SubStr("\u{0631}\u{06DB}")), // ARABIC LETTER JEH FINAL FORM
+    ('\u{FB8C}', CharMapAction::SubStr("\u{0631}\u{0615}")), // ARABIC LETTER RREH ISOLATED FORM
+    ('\u{FB8D}', CharMapAction::SubStr("\u{0631}\u{0615}")), // ARABIC LETTER RREH FINAL FORM
+    ('\u{FB8E}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KEHEH ISOLATED FORM
+    ('\u{FB8F}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KEHEH FINAL FORM
+    ('\u{FB90}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KEHEH INITIAL FORM
+    ('\u{FB91}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KEHEH MEDIAL FORM
+    ('\u{FB92}', CharMapAction::SubChar('\u{06AF}')), // ARABIC LETTER GAF ISOLATED FORM
+    ('\u{FB93}', CharMapAction::SubChar('\u{06AF}')), // ARABIC LETTER GAF FINAL FORM
+    ('\u{FB94}', CharMapAction::SubChar('\u{06AF}')), // ARABIC LETTER GAF INITIAL FORM
+    ('\u{FB95}', CharMapAction::SubChar('\u{06AF}')), // ARABIC LETTER GAF MEDIAL FORM
+    ('\u{FB96}', CharMapAction::SubChar('\u{06B3}')), // ARABIC LETTER GUEH ISOLATED FORM
+    ('\u{FB97}', CharMapAction::SubChar('\u{06B3}')), // ARABIC LETTER GUEH FINAL FORM
+    ('\u{FB98}', CharMapAction::SubChar('\u{06B3}')), // ARABIC LETTER GUEH INITIAL FORM
+    ('\u{FB99}', CharMapAction::SubChar('\u{06B3}')), // ARABIC LETTER GUEH MEDIAL FORM
+    ('\u{FB9A}', CharMapAction::SubChar('\u{06B1}')), // ARABIC LETTER NGOEH ISOLATED FORM
+    ('\u{FB9B}', CharMapAction::SubChar('\u{06B1}')), // ARABIC LETTER NGOEH FINAL FORM
+    ('\u{FB9C}', CharMapAction::SubChar('\u{06B1}')), // ARABIC LETTER NGOEH INITIAL FORM
+    ('\u{FB9D}', CharMapAction::SubChar('\u{06B1}')), // ARABIC LETTER NGOEH MEDIAL FORM
+    ('\u{FB9E}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER NOON GHUNNA ISOLATED FORM
+    ('\u{FB9F}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER NOON GHUNNA FINAL FORM
+    ('\u{FBA0}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER RNOON ISOLATED FORM
+    ('\u{FBA1}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER RNOON FINAL FORM
+    ('\u{FBA2}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER RNOON INITIAL FORM
+    ('\u{FBA3}', CharMapAction::SubStr("\u{0649}\u{0615}")), // ARABIC LETTER RNOON MEDIAL FORM
+    ('\u{FBA4}', CharMapAction::SubChar('\u{06C0}')), // ARABIC LETTER HEH WITH YEH ABOVE ISOLATED FORM
+    ('\u{FBA5}', CharMapAction::SubChar('\u{06C0}')), // ARABIC LETTER HEH WITH YEH ABOVE FINAL FORM
+    ('\u{FBA6}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH GOAL ISOLATED FORM
+    ('\u{FBA7}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH GOAL FINAL FORM
+    ('\u{FBA8}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH GOAL INITIAL FORM
+    ('\u{FBA9}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH GOAL MEDIAL FORM
+    ('\u{FBAA}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH DOACHASHMEE ISOLATED FORM
+    ('\u{FBAB}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH DOACHASHMEE FINAL FORM
+    ('\u{FBAC}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH DOACHASHMEE INITIAL FORM
+    ('\u{FBAD}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH DOACHASHMEE MEDIAL FORM
+    ('\u{FBAE}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH BARREE ISOLATED FORM
+    ('\u{FBAF}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH BARREE FINAL FORM
+    ('\u{FBB0}', CharMapAction::SubChar('\u{06D3}')), // ARABIC LETTER YEH BARREE WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FBB1}', CharMapAction::SubChar('\u{06D3}')), // ARABIC LETTER YEH BARREE WITH HAMZA ABOVE FINAL FORM
+    ('\u{FBD3}', CharMapAction::SubStr("\u{0643}\u{06DB}")), // ARABIC LETTER NG ISOLATED FORM
+    ('\u{FBD4}', CharMapAction::SubStr("\u{0643}\u{06DB}")), // ARABIC LETTER NG FINAL FORM
+    ('\u{FBD5}', CharMapAction::SubStr("\u{0643}\u{06DB}")), // ARABIC LETTER NG INITIAL FORM
+    ('\u{FBD6}', CharMapAction::SubStr("\u{0643}\u{06DB}")), // ARABIC LETTER NG MEDIAL FORM
+    ('\u{FBD7}', CharMapAction::SubStr("\u{0648}\u{0313}")), // ARABIC LETTER U ISOLATED FORM
+    ('\u{FBD8}', CharMapAction::SubStr("\u{0648}\u{0313}")), // ARABIC LETTER U FINAL FORM
+    ('\u{FBD9}', CharMapAction::SubStr("\u{0648}\u{0306}")), // ARABIC LETTER OE ISOLATED FORM
+    ('\u{FBDA}', CharMapAction::SubStr("\u{0648}\u{0306}")), // ARABIC LETTER OE FINAL FORM
+    ('\u{FBDB}', CharMapAction::SubStr("\u{0648}\u{0670}")), // ARABIC LETTER YU ISOLATED FORM
+    ('\u{FBDC}', CharMapAction::SubStr("\u{0648}\u{0670}")), // ARABIC LETTER YU FINAL FORM
+    ('\u{FBDD}', CharMapAction::SubStr("\u{0648}\u{0313}\u{0674}")), // ARABIC LETTER U WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FBDE}', CharMapAction::SubStr("\u{0648}\u{06DB}")), // ARABIC LETTER VE ISOLATED FORM
+    ('\u{FBDF}', CharMapAction::SubStr("\u{0648}\u{06DB}")), // ARABIC LETTER VE FINAL FORM
+    ('\u{FBE0}', CharMapAction::SubChar('\u{06C5}')), // ARABIC LETTER KIRGHIZ OE ISOLATED FORM
+    ('\u{FBE1}', CharMapAction::SubChar('\u{06C5}')), // ARABIC LETTER KIRGHIZ OE FINAL FORM
+    ('\u{FBE2}', CharMapAction::SubStr("\u{0648}\u{0302}")), // ARABIC LETTER KIRGHIZ YU ISOLATED FORM
+    ('\u{FBE3}', CharMapAction::SubStr("\u{0648}\u{0302}")), // ARABIC LETTER KIRGHIZ YU FINAL FORM
+    ('\u{FBE4}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER E ISOLATED FORM
+    ('\u{FBE5}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER E FINAL FORM
+    ('\u{FBE6}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER E INITIAL FORM
+    ('\u{FBE7}', CharMapAction::SubChar('\u{067B}')), // ARABIC LETTER E MEDIAL FORM
+    ('\u{FBE8}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER UIGHUR KAZAKH KIRGHIZ ALEF MAKSURA INITIAL FORM
+    ('\u{FBE9}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER UIGHUR KAZAKH KIRGHIZ ALEF MAKSURA MEDIAL FORM
+    ('\u{FBEA}', CharMapAction::SubStr("\u{0649}\u{0674}l")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH ALEF ISOLATED FORM
+    ('\u{FBEB}', CharMapAction::SubStr("\u{0649}\u{0674}l")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH ALEF FINAL FORM
+    ('\u{FBEC}', CharMapAction::SubStr("\u{0649}\u{0674}o")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH AE ISOLATED FORM
+    ('\u{FBED}', CharMapAction::SubStr("\u{0649}\u{0674}o")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH AE FINAL FORM
+    ('\u{FBEE}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH WAW ISOLATED FORM
+    ('\u{FBEF}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH WAW FINAL FORM
+    ('\u{FBF0}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}\u{0313}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH U ISOLATED FORM
+    ('\u{FBF1}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}\u{0313}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH U FINAL FORM
+    ('\u{FBF2}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}\u{0306}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH OE ISOLATED FORM
+    ('\u{FBF3}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}\u{0306}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH OE FINAL FORM
+    ('\u{FBF4}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}\u{0670}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH YU ISOLATED FORM
+    ('\u{FBF5}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0648}\u{0670}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH YU FINAL FORM
+    ('\u{FBF6}', CharMapAction::SubStr("\u{0649}\u{0674}\u{067B}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH E ISOLATED FORM
+    ('\u{FBF7}', CharMapAction::SubStr("\u{0649}\u{0674}\u{067B}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH E FINAL FORM
+    ('\u{FBF8}', CharMapAction::SubStr("\u{0649}\u{0674}\u{067B}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH E INITIAL FORM
+    ('\u{FBF9}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FBFA}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA FINAL FORM
+    ('\u{FBFB}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE UIGHUR KIRGHIZ YEH WITH HAMZA ABOVE WITH ALEF MAKSURA INITIAL FORM
+    ('\u{FBFC}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER FARSI YEH ISOLATED FORM
+    ('\u{FBFD}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER FARSI YEH FINAL FORM
+    ('\u{FBFE}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER FARSI YEH INITIAL FORM
+    ('\u{FBFF}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER FARSI YEH MEDIAL FORM
+    ('\u{FC00}', CharMapAction::SubStr("\u{0649}\u{0674}\u{062C}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH JEEM ISOLATED FORM
+    ('\u{FC01}', CharMapAction::SubStr("\u{0649}\u{0674}\u{062D}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH HAH ISOLATED FORM
+    ('\u{FC02}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0645}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH MEEM ISOLATED FORM
+    ('\u{FC03}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC04}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH YEH ISOLATED FORM
+    ('\u{FC05}', CharMapAction::SubStr("\u{0628}\u{062C}")), // ARABIC LIGATURE BEH WITH JEEM ISOLATED FORM
+    ('\u{FC06}', CharMapAction::SubStr("\u{0628}\u{062D}")), // ARABIC LIGATURE BEH WITH HAH ISOLATED FORM
+    ('\u{FC07}', CharMapAction::SubStr("\u{0628}\u{062E}")), // ARABIC LIGATURE BEH WITH KHAH ISOLATED FORM
+    ('\u{FC08}', CharMapAction::SubStr("\u{0628}\u{0645}")), // ARABIC LIGATURE BEH WITH MEEM ISOLATED FORM
+    ('\u{FC09}', CharMapAction::SubStr("\u{0628}\u{0649}")), // ARABIC LIGATURE BEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC0A}', CharMapAction::SubStr("\u{0628}\u{0649}")), // ARABIC LIGATURE BEH WITH YEH ISOLATED FORM
+    ('\u{FC0B}', CharMapAction::SubStr("\u{062A}\u{062C}")), // ARABIC LIGATURE TEH WITH JEEM ISOLATED FORM
+    ('\u{FC0C}', CharMapAction::SubStr("\u{062A}\u{062D}")), // ARABIC LIGATURE TEH WITH HAH ISOLATED FORM
+    ('\u{FC0D}', CharMapAction::SubStr("\u{062A}\u{062E}")), // ARABIC LIGATURE TEH WITH KHAH ISOLATED FORM
+    ('\u{FC0E}', CharMapAction::SubStr("\u{062A}\u{0645}")), // ARABIC LIGATURE TEH WITH MEEM ISOLATED FORM
+    ('\u{FC0F}', CharMapAction::SubStr("\u{062A}\u{0649}")), // ARABIC LIGATURE TEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC10}', CharMapAction::SubStr("\u{062A}\u{0649}")), // ARABIC LIGATURE TEH WITH YEH ISOLATED FORM
+    ('\u{FC11}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{062C}")), // ARABIC LIGATURE THEH WITH JEEM ISOLATED FORM
+    ('\u{FC12}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0645}")), // ARABIC LIGATURE THEH WITH MEEM ISOLATED FORM
+    ('\u{FC13}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0649}")), // ARABIC LIGATURE THEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC14}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0649}")), // ARABIC LIGATURE THEH WITH YEH ISOLATED FORM
+    ('\u{FC15}', CharMapAction::SubStr("\u{062C}\u{062D}")), // ARABIC LIGATURE JEEM WITH HAH ISOLATED FORM
+    ('\u{FC16}', CharMapAction::SubStr("\u{062C}\u{0645}")), // ARABIC LIGATURE JEEM WITH MEEM ISOLATED FORM
+    ('\u{FC17}', CharMapAction::SubStr("\u{062D}\u{062C}")), // ARABIC LIGATURE HAH WITH JEEM ISOLATED FORM
+    ('\u{FC18}', CharMapAction::SubStr("\u{062D}\u{0645}")), // ARABIC LIGATURE HAH WITH MEEM ISOLATED FORM
+    ('\u{FC19}', CharMapAction::SubStr("\u{062E}\u{062C}")), // ARABIC LIGATURE KHAH WITH JEEM ISOLATED FORM
+    ('\u{FC1A}', CharMapAction::SubStr("\u{062E}\u{062D}")), // ARABIC LIGATURE KHAH WITH HAH ISOLATED FORM
+    ('\u{FC1B}', CharMapAction::SubStr("\u{062E}\u{0645}")), // ARABIC LIGATURE KHAH WITH MEEM ISOLATED FORM
+    ('\u{FC1C}', CharMapAction::SubStr("\u{0633}\u{062C}")), // ARABIC LIGATURE SEEN WITH JEEM ISOLATED FORM
+    ('\u{FC1D}', CharMapAction::SubStr("\u{0633}\u{062D}")), // ARABIC LIGATURE SEEN WITH HAH ISOLATED FORM
+    ('\u{FC1E}', CharMapAction::SubStr("\u{0633}\u{062E}")), // ARABIC LIGATURE SEEN WITH KHAH ISOLATED FORM
+    ('\u{FC1F}', CharMapAction::SubStr("\u{0633}\u{0645}")), // ARABIC LIGATURE SEEN WITH MEEM ISOLATED FORM
+    ('\u{FC20}', CharMapAction::SubStr("\u{0635}\u{062D}")), // ARABIC LIGATURE SAD WITH HAH ISOLATED FORM
+    ('\u{FC21}', CharMapAction::SubStr("\u{0635}\u{0645}")), // ARABIC LIGATURE SAD WITH MEEM ISOLATED FORM
+    ('\u{FC22}', CharMapAction::SubStr("\u{0636}\u{062C}")), // ARABIC LIGATURE DAD WITH JEEM ISOLATED FORM
+    ('\u{FC23}', CharMapAction::SubStr("\u{0636}\u{062D}")), // ARABIC LIGATURE DAD WITH HAH ISOLATED FORM
+    ('\u{FC24}', CharMapAction::SubStr("\u{0636}\u{062E}")), // ARABIC LIGATURE DAD WITH KHAH ISOLATED FORM
+    ('\u{FC25}', CharMapAction::SubStr("\u{0636}\u{0645}")), // ARABIC LIGATURE DAD WITH MEEM ISOLATED FORM
+    ('\u{FC26}', CharMapAction::SubStr("\u{0637}\u{062D}")), // ARABIC LIGATURE TAH WITH HAH ISOLATED FORM
+    ('\u{FC27}', CharMapAction::SubStr("\u{0637}\u{0645}")), // ARABIC LIGATURE TAH WITH MEEM ISOLATED FORM
+    ('\u{FC28}', CharMapAction::SubStr("\u{0638}\u{0645}")), // ARABIC LIGATURE ZAH WITH MEEM ISOLATED FORM
+    ('\u{FC29}', CharMapAction::SubStr("\u{0639}\u{062C}")), // ARABIC LIGATURE AIN WITH JEEM ISOLATED FORM
+    ('\u{FC2A}', CharMapAction::SubStr("\u{0639}\u{0645}")), // ARABIC LIGATURE AIN WITH MEEM ISOLATED FORM
+    ('\u{FC2B}', CharMapAction::SubStr("\u{063A}\u{062C}")), // ARABIC LIGATURE GHAIN WITH JEEM ISOLATED FORM
+    ('\u{FC2C}', CharMapAction::SubStr("\u{063A}\u{0645}")), // ARABIC LIGATURE GHAIN WITH MEEM ISOLATED FORM
+    ('\u{FC2D}', CharMapAction::SubStr("\u{0641}\u{062C}")), // ARABIC LIGATURE FEH WITH JEEM ISOLATED FORM
+    ('\u{FC2E}', CharMapAction::SubStr("\u{0641}\u{062D}")), // ARABIC LIGATURE FEH WITH HAH ISOLATED FORM
+    ('\u{FC2F}', CharMapAction::SubStr("\u{0641}\u{062E}")), // ARABIC LIGATURE FEH WITH KHAH ISOLATED FORM
+    ('\u{FC30}', CharMapAction::SubStr("\u{0641}\u{0645}")), // ARABIC LIGATURE FEH WITH MEEM ISOLATED FORM
+    ('\u{FC31}', CharMapAction::SubStr("\u{0641}\u{0649}")), // ARABIC LIGATURE FEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC32}', CharMapAction::SubStr("\u{0641}\u{0649}")), // ARABIC LIGATURE FEH WITH YEH ISOLATED FORM
+    ('\u{FC33}', CharMapAction::SubStr("\u{0642}\u{062D}")), // ARABIC LIGATURE QAF WITH HAH ISOLATED FORM
+    ('\u{FC34}', CharMapAction::SubStr("\u{0642}\u{0645}")), // ARABIC LIGATURE QAF WITH MEEM ISOLATED FORM
+    ('\u{FC35}', CharMapAction::SubStr("\u{0642}\u{0649}")), // ARABIC LIGATURE QAF WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC36}', CharMapAction::SubStr("\u{0642}\u{0649}")), // ARABIC LIGATURE QAF WITH YEH ISOLATED FORM
+    ('\u{FC37}', CharMapAction::SubStr("\u{0643}l")), // ARABIC LIGATURE KAF WITH ALEF ISOLATED FORM
+    ('\u{FC38}', CharMapAction::SubStr("\u{0643}\u{062C}")), // ARABIC LIGATURE KAF WITH JEEM ISOLATED FORM
+    ('\u{FC39}', CharMapAction::SubStr("\u{0643}\u{062D}")), // ARABIC LIGATURE KAF WITH HAH ISOLATED FORM
+    ('\u{FC3A}', CharMapAction::SubStr("\u{0643}\u{062E}")), // ARABIC LIGATURE KAF WITH KHAH ISOLATED FORM
+    ('\u{FC3B}', CharMapAction::SubStr("\u{0643}\u{0644}")), // ARABIC LIGATURE KAF WITH LAM ISOLATED FORM
+    ('\u{FC3C}', CharMapAction::SubStr("\u{0643}\u{0645}")), // ARABIC LIGATURE KAF WITH MEEM ISOLATED FORM
+    ('\u{FC3D}', CharMapAction::SubStr("\u{0643}\u{0649}")), // ARABIC LIGATURE KAF WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC3E}', CharMapAction::SubStr("\u{0643}\u{0649}")), // ARABIC LIGATURE KAF WITH YEH ISOLATED FORM
+    ('\u{FC3F}', CharMapAction::SubStr("\u{0644}\u{062C}")), // ARABIC LIGATURE LAM WITH JEEM ISOLATED FORM
+    ('\u{FC40}', CharMapAction::SubStr("\u{0644}\u{062D}")), // ARABIC LIGATURE LAM WITH HAH ISOLATED FORM
+    ('\u{FC41}', CharMapAction::SubStr("\u{0644}\u{062E}")), // ARABIC LIGATURE LAM WITH KHAH ISOLATED FORM
+    ('\u{FC42}', CharMapAction::SubStr("\u{0644}\u{0645}")), // ARABIC LIGATURE LAM WITH MEEM ISOLATED FORM
+    ('\u{FC43}', CharMapAction::SubStr("\u{0644}\u{0649}")), // ARABIC LIGATURE LAM WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC44}', CharMapAction::SubStr("\u{0644}\u{0649}")), // ARABIC LIGATURE LAM WITH YEH ISOLATED FORM
+    ('\u{FC45}', CharMapAction::SubStr("\u{0645}\u{062C}")), // ARABIC LIGATURE MEEM WITH JEEM ISOLATED FORM
+    ('\u{FC46}', CharMapAction::SubStr("\u{0645}\u{062D}")), // ARABIC LIGATURE MEEM WITH HAH ISOLATED FORM
+    ('\u{FC47}', CharMapAction::SubStr("\u{0645}\u{062E}")), // ARABIC LIGATURE MEEM WITH KHAH ISOLATED FORM
+    ('\u{FC48}', CharMapAction::SubStr("\u{0645}\u{0645}")), // ARABIC LIGATURE MEEM WITH MEEM ISOLATED FORM
+    ('\u{FC49}', CharMapAction::SubStr("\u{0645}\u{0649}")), // ARABIC LIGATURE MEEM WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC4A}', CharMapAction::SubStr("\u{0645}\u{0649}")), // ARABIC LIGATURE MEEM WITH YEH ISOLATED FORM
+    ('\u{FC4B}', CharMapAction::SubStr("\u{0628}\u{062E}")), // ARABIC LIGATURE NOON WITH JEEM ISOLATED FORM
+    ('\u{FC4C}', CharMapAction::SubStr("\u{0646}\u{062D}")), // ARABIC LIGATURE NOON WITH HAH ISOLATED FORM
+    ('\u{FC4D}', CharMapAction::SubStr("\u{0646}\u{062E}")), // ARABIC LIGATURE NOON WITH KHAH ISOLATED FORM
+    ('\u{FC4E}', CharMapAction::SubStr("\u{0646}\u{0645}")), // ARABIC LIGATURE NOON WITH MEEM ISOLATED FORM
+    ('\u{FC4F}', CharMapAction::SubStr("\u{0646}\u{0649}")), // ARABIC LIGATURE NOON WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC50}', CharMapAction::SubStr("\u{0646}\u{0649}")), // ARABIC LIGATURE NOON WITH YEH ISOLATED FORM
+    ('\u{FC51}', CharMapAction::SubStr("o\u{062C}")), // ARABIC LIGATURE HEH WITH JEEM ISOLATED FORM
+    ('\u{FC52}', CharMapAction::SubStr("o\u{0645}")), // ARABIC LIGATURE HEH WITH MEEM ISOLATED FORM
+    ('\u{FC53}', CharMapAction::SubStr("o\u{0649}")), // ARABIC LIGATURE HEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC54}', CharMapAction::SubStr("o\u{0649}")), // ARABIC LIGATURE HEH WITH YEH ISOLATED FORM
+    ('\u{FC55}', CharMapAction::SubStr("\u{0649}\u{062C}")), // ARABIC LIGATURE YEH WITH JEEM ISOLATED FORM
+    ('\u{FC56}', CharMapAction::SubStr("\u{0649}\u{062D}")), // ARABIC LIGATURE YEH WITH HAH ISOLATED FORM
+    ('\u{FC57}', CharMapAction::SubStr("\u{0649}\u{062E}")), // ARABIC LIGATURE YEH WITH KHAH ISOLATED FORM
+    ('\u{FC58}', CharMapAction::SubStr("\u{0649}\u{0645}")), // ARABIC LIGATURE YEH WITH MEEM ISOLATED FORM
+    ('\u{FC59}', CharMapAction::SubStr("\u{0649}\u{0649}")), // ARABIC LIGATURE YEH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FC5A}', CharMapAction::SubStr("\u{0649}\u{0649}")), // ARABIC LIGATURE YEH WITH YEH ISOLATED FORM
+    ('\u{FC5B}', CharMapAction::SubStr("\u{0630}\u{0670}")), // ARABIC LIGATURE THAL WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC5C}', CharMapAction::SubStr("\u{0631}\u{0670}")), // ARABIC LIGATURE REH WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC5D}', CharMapAction::SubStr("\u{0649}\u{0670}")), // ARABIC LIGATURE ALEF MAKSURA WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC5E}', CharMapAction::SubStr("\u{FE72}\u{0651}")), // ARABIC LIGATURE SHADDA WITH DAMMATAN ISOLATED FORM
+    ('\u{FC5F}', CharMapAction::SubStr("\u{FE74}\u{0651}")), // ARABIC LIGATURE SHADDA WITH KASRATAN ISOLATED FORM
+    ('\u{FC60}', CharMapAction::SubStr("\u{FE76}\u{0651}")), // ARABIC LIGATURE SHADDA WITH FATHA ISOLATED FORM
+    ('\u{FC61}', CharMapAction::SubStr("\u{FE78}\u{0651}")), // ARABIC LIGATURE SHADDA WITH DAMMA ISOLATED FORM
+    ('\u{FC62}', CharMapAction::SubStr("\u{FE7A}\u{0651}")), // ARABIC LIGATURE SHADDA WITH KASRA ISOLATED FORM
+    ('\u{FC63}', CharMapAction::SubStr("\u{FE7C}\u{0670}")), // ARABIC LIGATURE SHADDA WITH SUPERSCRIPT ALEF ISOLATED FORM
+    ('\u{FC64}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0631}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH REH FINAL FORM
+    ('\u{FC65}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0632}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH ZAIN FINAL FORM
+    ('\u{FC66}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0645}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH MEEM FINAL FORM
+    ('\u{FC67}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0646}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH NOON FINAL FORM
+    ('\u{FC68}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC69}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0649}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH YEH FINAL FORM
+    ('\u{FC6A}', CharMapAction::SubStr("\u{0628}\u{0631}")), // ARABIC LIGATURE BEH WITH REH FINAL FORM
+    ('\u{FC6B}', CharMapAction::SubStr("\u{0628}\u{0632}")), // ARABIC LIGATURE BEH WITH ZAIN FINAL FORM
+    ('\u{FC6C}', CharMapAction::SubStr("\u{0628}\u{0645}")), // ARABIC LIGATURE BEH WITH MEEM FINAL FORM
+    ('\u{FC6D}', CharMapAction::SubStr("\u{0628}\u{0646}")), // ARABIC LIGATURE BEH WITH NOON FINAL FORM
+    ('\u{FC6E}', CharMapAction::SubStr("\u{0628}\u{0649}")), // ARABIC LIGATURE BEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC6F}', CharMapAction::SubStr("\u{0628}\u{0649}")), // ARABIC LIGATURE BEH WITH YEH FINAL FORM
+    ('\u{FC70}', CharMapAction::SubStr("\u{062A}\u{0631}")), // ARABIC LIGATURE TEH WITH REH FINAL FORM
+    ('\u{FC71}', CharMapAction::SubStr("\u{062A}\u{0632}")), // ARABIC LIGATURE TEH WITH ZAIN FINAL FORM
+    ('\u{FC72}', CharMapAction::SubStr("\u{062A}\u{0645}")), // ARABIC LIGATURE TEH WITH MEEM FINAL FORM
+    ('\u{FC73}', CharMapAction::SubStr("\u{062A}\u{0646}")), // ARABIC LIGATURE TEH WITH NOON FINAL FORM
+    ('\u{FC74}', CharMapAction::SubStr("\u{062A}\u{0649}")), // ARABIC LIGATURE TEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC75}', CharMapAction::SubStr("\u{062A}\u{0649}")), // ARABIC LIGATURE TEH WITH YEH FINAL FORM
+    ('\u{FC76}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0631}")), // ARABIC LIGATURE THEH WITH REH FINAL FORM
+    ('\u{FC77}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0632}")), // ARABIC LIGATURE THEH WITH ZAIN FINAL FORM
+    ('\u{FC78}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0645}")), // ARABIC LIGATURE THEH WITH MEEM FINAL FORM
+    ('\u{FC79}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0646}")), // ARABIC LIGATURE THEH WITH NOON FINAL FORM
+    ('\u{FC7A}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0649}")), // ARABIC LIGATURE THEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC7B}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0649}")), // ARABIC LIGATURE THEH WITH YEH FINAL FORM
+    ('\u{FC7C}', CharMapAction::SubStr("\u{0641}\u{0649}")), // ARABIC LIGATURE FEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC7D}', CharMapAction::SubStr("\u{0641}\u{0649}")), // ARABIC LIGATURE FEH WITH YEH FINAL FORM
+    ('\u{FC7E}', CharMapAction::SubStr("\u{0642}\u{0649}")), // ARABIC LIGATURE QAF WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC7F}', CharMapAction::SubStr("\u{0642}\u{0649}")), // ARABIC LIGATURE QAF WITH YEH FINAL FORM
+    ('\u{FC80}', CharMapAction::SubStr("\u{0643}l")), // ARABIC LIGATURE KAF WITH ALEF FINAL FORM
+    ('\u{FC81}', CharMapAction::SubStr("\u{0643}\u{0644}")), // ARABIC LIGATURE KAF WITH LAM FINAL FORM
+    ('\u{FC82}', CharMapAction::SubStr("\u{0643}\u{0645}")), // ARABIC LIGATURE KAF WITH MEEM FINAL FORM
+    ('\u{FC83}', CharMapAction::SubStr("\u{0643}\u{0649}")), // ARABIC LIGATURE KAF WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC84}', CharMapAction::SubStr("\u{0643}\u{0649}")), // ARABIC LIGATURE KAF WITH YEH FINAL FORM
+    ('\u{FC85}', CharMapAction::SubStr("\u{0644}\u{0645}")), // ARABIC LIGATURE LAM WITH MEEM FINAL FORM
+    ('\u{FC86}', CharMapAction::SubStr("\u{0644}\u{0649}")), // ARABIC LIGATURE LAM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC87}', CharMapAction::SubStr("\u{0644}\u{0649}")), // ARABIC LIGATURE LAM WITH YEH FINAL FORM
+    ('\u{FC88}', CharMapAction::SubStr("\u{0645}l")), // ARABIC LIGATURE MEEM WITH ALEF FINAL FORM
+    ('\u{FC89}', CharMapAction::SubStr("\u{0645}\u{0645}")), // ARABIC LIGATURE MEEM WITH MEEM FINAL FORM
+    ('\u{FC8A}', CharMapAction::SubStr("\u{0646}\u{0631}")), // ARABIC LIGATURE NOON WITH REH FINAL FORM
+    ('\u{FC8B}', CharMapAction::SubStr("\u{0646}\u{0632}")), // ARABIC LIGATURE NOON WITH ZAIN FINAL FORM
+    ('\u{FC8C}', CharMapAction::SubStr("\u{0646}\u{0645}")), // ARABIC LIGATURE NOON WITH MEEM FINAL FORM
+    ('\u{FC8D}', CharMapAction::SubStr("\u{0646}\u{0646}")), // ARABIC LIGATURE NOON WITH NOON FINAL FORM
+    ('\u{FC8E}', CharMapAction::SubStr("\u{0646}\u{0649}")), // ARABIC LIGATURE NOON WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC8F}', CharMapAction::SubStr("\u{0646}\u{0649}")), // ARABIC LIGATURE NOON WITH YEH FINAL FORM
+    ('\u{FC90}', CharMapAction::SubStr("\u{0649}\u{0670}")), // ARABIC LIGATURE ALEF MAKSURA WITH SUPERSCRIPT ALEF FINAL FORM
+    ('\u{FC91}', CharMapAction::SubStr("\u{0649}\u{0631}")), // ARABIC LIGATURE YEH WITH REH FINAL FORM
+    ('\u{FC92}', CharMapAction::SubStr("\u{0649}\u{0632}")), // ARABIC LIGATURE YEH WITH ZAIN FINAL FORM
+    ('\u{FC93}', CharMapAction::SubStr("\u{0649}\u{0645}")), // ARABIC LIGATURE YEH WITH MEEM FINAL FORM
+    ('\u{FC94}', CharMapAction::SubStr("\u{0649}\u{0646}")), // ARABIC LIGATURE YEH WITH NOON FINAL FORM
+    ('\u{FC95}', CharMapAction::SubStr("\u{0649}\u{0649}")), // ARABIC LIGATURE YEH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FC96}', CharMapAction::SubStr("\u{0649}\u{0649}")), // ARABIC LIGATURE YEH WITH YEH FINAL FORM
+    ('\u{FC97}', CharMapAction::SubStr("\u{0649}\u{0674}\u{062C}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH JEEM INITIAL FORM
+    ('\u{FC98}', CharMapAction::SubStr("\u{0649}\u{0674}\u{062D}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH HAH INITIAL FORM
+    ('\u{FC99}', CharMapAction::SubStr("\u{0649}\u{0674}\u{062E}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH KHAH INITIAL FORM
+    ('\u{FC9A}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0645}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH MEEM INITIAL FORM
+    ('\u{FC9B}', CharMapAction::SubStr("\u{0649}\u{0674}o")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH HEH INITIAL FORM
+    ('\u{FC9C}', CharMapAction::SubStr("\u{0628}\u{062C}")), // ARABIC LIGATURE BEH WITH JEEM INITIAL FORM
+    ('\u{FC9D}', CharMapAction::SubStr("\u{0628}\u{062D}")), // ARABIC LIGATURE BEH WITH HAH INITIAL FORM
+    ('\u{FC9E}', CharMapAction::SubStr("\u{0628}\u{062E}")), // ARABIC LIGATURE BEH WITH KHAH INITIAL FORM
+    ('\u{FC9F}', CharMapAction::SubStr("\u{0628}\u{0645}")), // ARABIC LIGATURE BEH WITH MEEM INITIAL FORM
+    ('\u{FCA0}', CharMapAction::SubStr("\u{0628}o")), // ARABIC LIGATURE BEH WITH HEH INITIAL FORM
+    ('\u{FCA1}', CharMapAction::SubStr("\u{062A}\u{062C}")), // ARABIC LIGATURE TEH WITH JEEM INITIAL FORM
+    ('\u{FCA2}', CharMapAction::SubStr("\u{062A}\u{062D}")), // ARABIC LIGATURE TEH WITH HAH INITIAL FORM
+    ('\u{FCA3}', CharMapAction::SubStr("\u{062A}\u{062E}")), // ARABIC LIGATURE TEH WITH KHAH INITIAL FORM
+    ('\u{FCA4}', CharMapAction::SubStr("\u{062A}\u{0645}")), // ARABIC LIGATURE TEH WITH MEEM INITIAL FORM
+    ('\u{FCA5}', CharMapAction::SubStr("\u{062A}o")), // ARABIC LIGATURE TEH WITH HEH INITIAL FORM
+    ('\u{FCA6}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0645}")), // ARABIC LIGATURE THEH WITH MEEM INITIAL FORM
+    ('\u{FCA7}', CharMapAction::SubStr("\u{062C}\u{062D}")), // ARABIC LIGATURE JEEM WITH HAH INITIAL FORM
+    ('\u{FCA8}', CharMapAction::SubStr("\u{062C}\u{0645}")), // ARABIC LIGATURE JEEM WITH MEEM INITIAL FORM
+    ('\u{FCA9}', CharMapAction::SubStr("\u{062D}\u{062C}")), // ARABIC LIGATURE HAH WITH JEEM INITIAL FORM
+    ('\u{FCAA}', CharMapAction::SubStr("\u{062D}\u{0645}")), // ARABIC LIGATURE HAH WITH MEEM INITIAL FORM
+    ('\u{FCAB}', CharMapAction::SubStr("\u{062E}\u{062C}")), // ARABIC LIGATURE KHAH WITH JEEM INITIAL FORM
+    ('\u{FCAC}', CharMapAction::SubStr("\u{062E}\u{0645}")), // ARABIC LIGATURE KHAH WITH MEEM INITIAL FORM
+    ('\u{FCAD}', CharMapAction::SubStr("\u{0633}\u{062C}")), // ARABIC LIGATURE SEEN WITH JEEM INITIAL FORM
+    ('\u{FCAE}', CharMapAction::SubStr("\u{0633}\u{062D}")), // ARABIC LIGATURE SEEN WITH HAH INITIAL FORM
+    ('\u{FCAF}', CharMapAction::SubStr("\u{0633}\u{062E}")), // ARABIC LIGATURE SEEN WITH KHAH INITIAL FORM
+    ('\u{FCB0}', CharMapAction::SubStr("\u{0633}\u{0645}")), // ARABIC LIGATURE SEEN WITH MEEM INITIAL FORM
+    ('\u{FCB1}', CharMapAction::SubStr("\u{0635}\u{062D}")), // ARABIC LIGATURE SAD WITH HAH INITIAL FORM
+    ('\u{FCB2}', CharMapAction::SubStr("\u{0635}\u{062E}")), // ARABIC LIGATURE SAD WITH KHAH INITIAL FORM
+    ('\u{FCB3}', CharMapAction::SubStr("\u{0635}\u{0645}")), // ARABIC LIGATURE SAD WITH MEEM INITIAL FORM
+    ('\u{FCB4}', CharMapAction::SubStr("\u{0636}\u{062C}")), // ARABIC LIGATURE DAD WITH JEEM INITIAL FORM
+    ('\u{FCB5}', CharMapAction::SubStr("\u{0636}\u{062D}")), // ARABIC LIGATURE DAD WITH HAH INITIAL FORM
+    ('\u{FCB6}', CharMapAction::SubStr("\u{0636}\u{062E}")), // ARABIC LIGATURE DAD WITH KHAH INITIAL FORM
+    ('\u{FCB7}', CharMapAction::SubStr("\u{0636}\u{0645}")), // ARABIC LIGATURE DAD WITH MEEM INITIAL FORM
+    ('\u{FCB8}', CharMapAction::SubStr("\u{0637}\u{062D}")), // ARABIC LIGATURE TAH WITH HAH INITIAL FORM
+    ('\u{FCB9}', CharMapAction::SubStr("\u{0638}\u{0645}")), // ARABIC LIGATURE ZAH WITH MEEM INITIAL FORM
+    ('\u{FCBA}', CharMapAction::SubStr("\u{0639}\u{062C}")), // ARABIC LIGATURE AIN WITH JEEM INITIAL FORM
+    ('\u{FCBB}', CharMapAction::SubStr("\u{0639}\u{0645}")), // ARABIC LIGATURE AIN WITH MEEM INITIAL FORM
+    ('\u{FCBC}', CharMapAction::SubStr("\u{063A}\u{062C}")), // ARABIC LIGATURE GHAIN WITH JEEM INITIAL FORM
+    ('\u{FCBD}', CharMapAction::SubStr("\u{063A}\u{0645}")), // ARABIC LIGATURE GHAIN WITH MEEM INITIAL FORM
+    ('\u{FCBE}', CharMapAction::SubStr("\u{0641}\u{062C}")), // ARABIC LIGATURE FEH WITH JEEM INITIAL FORM
+    ('\u{FCBF}', CharMapAction::SubStr("\u{0641}\u{062D}")), // ARABIC LIGATURE FEH WITH HAH INITIAL FORM
+    ('\u{FCC0}', CharMapAction::SubStr("\u{0641}\u{062E}")), // ARABIC LIGATURE FEH WITH KHAH INITIAL FORM
+    ('\u{FCC1}', CharMapAction::SubStr("\u{0641}\u{0645}")), // ARABIC LIGATURE FEH WITH MEEM INITIAL FORM
+    ('\u{FCC2}', CharMapAction::SubStr("\u{0642}\u{062D}")), // ARABIC LIGATURE QAF WITH HAH INITIAL FORM
+    ('\u{FCC3}', CharMapAction::SubStr("\u{0642}\u{0645}")), // ARABIC LIGATURE QAF WITH MEEM INITIAL FORM
+    ('\u{FCC4}', CharMapAction::SubStr("\u{0643}\u{062C}")), // ARABIC LIGATURE KAF WITH JEEM INITIAL FORM
+    ('\u{FCC5}', CharMapAction::SubStr("\u{0643}\u{062D}")), // ARABIC LIGATURE KAF WITH HAH INITIAL FORM
+    ('\u{FCC6}', CharMapAction::SubStr("\u{0643}\u{062E}")), // ARABIC LIGATURE KAF WITH KHAH INITIAL FORM
+    ('\u{FCC7}', CharMapAction::SubStr("\u{0643}\u{0644}")), // ARABIC LIGATURE KAF WITH LAM INITIAL FORM
+    ('\u{FCC8}', CharMapAction::SubStr("\u{0643}\u{0645}")), // ARABIC LIGATURE KAF WITH MEEM INITIAL FORM
+    ('\u{FCC9}', CharMapAction::SubStr("\u{0644}\u{062C}")), // ARABIC LIGATURE LAM WITH JEEM INITIAL FORM
+    ('\u{FCCA}', CharMapAction::SubStr("\u{0644}\u{062D}")), // ARABIC LIGATURE LAM WITH HAH INITIAL FORM
+    ('\u{FCCB}', CharMapAction::SubStr("\u{0644}\u{062E}")), // ARABIC LIGATURE LAM WITH KHAH INITIAL FORM
+    ('\u{FCCC}', CharMapAction::SubStr("\u{0644}\u{0645}")), // ARABIC LIGATURE LAM WITH MEEM INITIAL FORM
+    ('\u{FCCD}', CharMapAction::SubStr("\u{0644}o")), // ARABIC LIGATURE LAM WITH HEH INITIAL FORM
+    ('\u{FCCE}', CharMapAction::SubStr("\u{0645}\u{062C}")), // ARABIC LIGATURE MEEM WITH JEEM INITIAL FORM
+    ('\u{FCCF}', CharMapAction::SubStr("\u{0645}\u{062D}")), // ARABIC LIGATURE MEEM WITH HAH INITIAL FORM
+    ('\u{FCD0}', CharMapAction::SubStr("\u{0645}\u{062E}")), // ARABIC LIGATURE MEEM WITH KHAH INITIAL FORM
+    ('\u{FCD1}', CharMapAction::SubStr("\u{0645}\u{0645}")), // ARABIC LIGATURE MEEM WITH MEEM INITIAL FORM
+    ('\u{FCD2}', CharMapAction::SubStr("\u{0628}\u{062E}")), // ARABIC LIGATURE NOON WITH JEEM INITIAL FORM
+    ('\u{FCD3}', CharMapAction::SubStr("\u{0646}\u{062D}")), // ARABIC LIGATURE NOON WITH HAH INITIAL FORM
+    ('\u{FCD4}', CharMapAction::SubStr("\u{0646}\u{062E}")), // ARABIC LIGATURE NOON WITH KHAH INITIAL FORM
+    ('\u{FCD5}', CharMapAction::SubStr("\u{0646}\u{0645}")), // ARABIC LIGATURE NOON WITH MEEM INITIAL FORM
+    ('\u{FCD6}', CharMapAction::SubStr("\u{0646}o")), // ARABIC LIGATURE NOON WITH HEH INITIAL FORM
+    ('\u{FCD7}', CharMapAction::SubStr("o\u{062C}")), // ARABIC LIGATURE HEH WITH JEEM INITIAL FORM
+    ('\u{FCD8}', CharMapAction::SubStr("o\u{0645}")), // ARABIC LIGATURE HEH WITH MEEM INITIAL FORM
+    ('\u{FCD9}', CharMapAction::SubStr("o\u{0670}")), // ARABIC LIGATURE HEH WITH SUPERSCRIPT ALEF INITIAL FORM
+    ('\u{FCDA}', CharMapAction::SubStr("\u{0649}\u{062C}")), // ARABIC LIGATURE YEH WITH JEEM INITIAL FORM
+    ('\u{FCDB}', CharMapAction::SubStr("\u{0649}\u{062D}")), // ARABIC LIGATURE YEH WITH HAH INITIAL FORM
+    ('\u{FCDC}', CharMapAction::SubStr("\u{0649}\u{062E}")), // ARABIC LIGATURE YEH WITH KHAH INITIAL FORM
+    ('\u{FCDD}', CharMapAction::SubStr("\u{0649}\u{0645}")), // ARABIC LIGATURE YEH WITH MEEM INITIAL FORM
+    ('\u{FCDE}', CharMapAction::SubStr("\u{0649}o")), // ARABIC LIGATURE YEH WITH HEH INITIAL FORM
+    ('\u{FCDF}', CharMapAction::SubStr("\u{0649}\u{0674}\u{0645}")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH MEEM MEDIAL FORM
+    ('\u{FCE0}', CharMapAction::SubStr("\u{0649}\u{0674}o")), // ARABIC LIGATURE YEH WITH HAMZA ABOVE WITH HEH MEDIAL FORM
+    ('\u{FCE1}', CharMapAction::SubStr("\u{0628}\u{0645}")), // ARABIC LIGATURE BEH WITH MEEM MEDIAL FORM
+    ('\u{FCE2}', CharMapAction::SubStr("\u{0628}o")), // ARABIC LIGATURE BEH WITH HEH MEDIAL FORM
+    ('\u{FCE3}', CharMapAction::SubStr("\u{062A}\u{0645}")), // ARABIC LIGATURE TEH WITH MEEM MEDIAL FORM
+    ('\u{FCE4}', CharMapAction::SubStr("\u{062A}o")), // ARABIC LIGATURE TEH WITH HEH MEDIAL FORM
+    ('\u{FCE5}', CharMapAction::SubStr("\u{0649}\u{06DB}\u{0645}")), // ARABIC LIGATURE THEH WITH MEEM MEDIAL FORM
+    ('\u{FCE6}', CharMapAction::SubStr("\u{0649}\u{06DB}o")), // ARABIC LIGATURE THEH WITH HEH MEDIAL FORM
+    ('\u{FCE7}', CharMapAction::SubStr("\u{0633}\u{0645}")), // ARABIC LIGATURE SEEN WITH MEEM MEDIAL FORM
+    ('\u{FCE8}', CharMapAction::SubStr("\u{0633}o")), // ARABIC LIGATURE SEEN WITH HEH MEDIAL FORM
+    ('\u{FCE9}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}")), // ARABIC LIGATURE SHEEN WITH MEEM MEDIAL FORM
+    ('\u{FCEA}', CharMapAction::SubStr("\u{0633}\u{06DB}o")), // ARABIC LIGATURE SHEEN WITH HEH MEDIAL FORM
+    ('\u{FCEB}', CharMapAction::SubStr("\u{0643}\u{0644}")), // ARABIC LIGATURE KAF WITH LAM MEDIAL FORM
+    ('\u{FCEC}', CharMapAction::SubStr("\u{0643}\u{0645}")), // ARABIC LIGATURE KAF WITH MEEM MEDIAL FORM
+    ('\u{FCED}', CharMapAction::SubStr("\u{0644}\u{0645}")), // ARABIC LIGATURE LAM WITH MEEM MEDIAL FORM
+    ('\u{FCEE}', CharMapAction::SubStr("\u{0646}\u{0645}")), // ARABIC LIGATURE NOON WITH MEEM MEDIAL FORM
+    ('\u{FCEF}', CharMapAction::SubStr("\u{0646}o")), // ARABIC LIGATURE NOON WITH HEH MEDIAL FORM
+    ('\u{FCF0}', CharMapAction::SubStr("\u{0649}\u{0645}")), // ARABIC LIGATURE YEH WITH MEEM MEDIAL FORM
+    ('\u{FCF1}', CharMapAction::SubStr("\u{0649}o")), // ARABIC LIGATURE YEH WITH HEH MEDIAL FORM
+    ('\u{FCF2}', CharMapAction::SubStr("\u{FE77}\u{0651}")), // ARABIC LIGATURE SHADDA WITH FATHA MEDIAL FORM
+    ('\u{FCF3}', CharMapAction::SubStr("\u{FE79}\u{0651}")), // ARABIC LIGATURE SHADDA WITH DAMMA MEDIAL FORM
+    ('\u{FCF4}', CharMapAction::SubStr("\u{FE7B}\u{0651}")), // ARABIC LIGATURE SHADDA WITH KASRA MEDIAL FORM
+    ('\u{FCF5}', CharMapAction::SubStr("\u{0637}\u{0649}")), // ARABIC LIGATURE TAH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCF6}', CharMapAction::SubStr("\u{0637}\u{0649}")), // ARABIC LIGATURE TAH WITH YEH ISOLATED FORM
+    ('\u{FCF7}', CharMapAction::SubStr("\u{0639}\u{0649}")), // ARABIC LIGATURE AIN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCF8}', CharMapAction::SubStr("\u{0639}\u{0649}")), // ARABIC LIGATURE AIN WITH YEH ISOLATED FORM
+    ('\u{FCF9}', CharMapAction::SubStr("\u{063A}\u{0649}")), // ARABIC LIGATURE GHAIN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCFA}', CharMapAction::SubStr("\u{063A}\u{0649}")), // ARABIC LIGATURE GHAIN WITH YEH ISOLATED FORM
+    ('\u{FCFB}', CharMapAction::SubStr("\u{0633}\u{0649}")), // ARABIC LIGATURE SEEN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCFC}', CharMapAction::SubStr("\u{0633}\u{0649}")), // ARABIC LIGATURE SEEN WITH YEH ISOLATED FORM
+    ('\u{FCFD}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0649}")), // ARABIC LIGATURE SHEEN WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FCFE}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0649}")), // ARABIC LIGATURE SHEEN WITH YEH ISOLATED FORM
+    ('\u{FCFF}', CharMapAction::SubStr("\u{062D}\u{0649}")), // ARABIC LIGATURE HAH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD00}', CharMapAction::SubStr("\u{062D}\u{0649}")), // ARABIC LIGATURE HAH WITH YEH ISOLATED FORM
+    ('\u{FD01}', CharMapAction::SubStr("\u{062C}\u{0649}")), // ARABIC LIGATURE JEEM WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD02}', CharMapAction::SubStr("\u{062C}\u{0649}")), // ARABIC LIGATURE JEEM WITH YEH ISOLATED FORM
+    ('\u{FD03}', CharMapAction::SubStr("\u{062E}\u{0649}")), // ARABIC LIGATURE KHAH WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD04}', CharMapAction::SubStr("\u{062E}\u{0649}")), // ARABIC LIGATURE KHAH WITH YEH ISOLATED FORM
+    ('\u{FD05}', CharMapAction::SubStr("\u{0635}\u{0649}")), // ARABIC LIGATURE SAD WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD06}', CharMapAction::SubStr("\u{0635}\u{0649}")), // ARABIC LIGATURE SAD WITH YEH ISOLATED FORM
+    ('\u{FD07}', CharMapAction::SubStr("\u{0636}\u{0649}")), // ARABIC LIGATURE DAD WITH ALEF MAKSURA ISOLATED FORM
+    ('\u{FD08}', CharMapAction::SubStr("\u{0636}\u{0649}")), // ARABIC LIGATURE DAD WITH YEH ISOLATED FORM
+    ('\u{FD09}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062C}")), // ARABIC LIGATURE SHEEN WITH JEEM ISOLATED FORM
+    ('\u{FD0A}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}")), // ARABIC LIGATURE SHEEN WITH HAH ISOLATED FORM
+    ('\u{FD0B}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062E}")), // ARABIC LIGATURE SHEEN WITH KHAH ISOLATED FORM
+    ('\u{FD0C}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}")), // ARABIC LIGATURE SHEEN WITH MEEM ISOLATED FORM
+    ('\u{FD0D}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0631}")), // ARABIC LIGATURE SHEEN WITH REH ISOLATED FORM
+    ('\u{FD0E}', CharMapAction::SubStr("\u{0633}\u{0631}")), // ARABIC LIGATURE SEEN WITH REH ISOLATED FORM
+    ('\u{FD0F}', CharMapAction::SubStr("\u{0635}\u{0631}")), // ARABIC LIGATURE SAD WITH REH ISOLATED FORM
+    ('\u{FD10}', CharMapAction::SubStr("\u{0636}\u{0631}")), // ARABIC LIGATURE DAD WITH REH ISOLATED FORM
+    ('\u{FD11}', CharMapAction::SubStr("\u{0637}\u{0649}")), // ARABIC LIGATURE TAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD12}', CharMapAction::SubStr("\u{0637}\u{0649}")), // ARABIC LIGATURE TAH WITH YEH FINAL FORM
+    ('\u{FD13}', CharMapAction::SubStr("\u{0639}\u{0649}")), // ARABIC LIGATURE AIN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD14}', CharMapAction::SubStr("\u{0639}\u{0649}")), // ARABIC LIGATURE AIN WITH YEH FINAL FORM
+    ('\u{FD15}', CharMapAction::SubStr("\u{063A}\u{0649}")), // ARABIC LIGATURE GHAIN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD16}', CharMapAction::SubStr("\u{063A}\u{0649}")), // ARABIC LIGATURE GHAIN WITH YEH FINAL FORM
+    ('\u{FD17}', CharMapAction::SubStr("\u{0633}\u{0649}")), // ARABIC LIGATURE SEEN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD18}', CharMapAction::SubStr("\u{0633}\u{0649}")), // ARABIC LIGATURE SEEN WITH YEH FINAL FORM
+    ('\u{FD19}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0649}")), // ARABIC LIGATURE SHEEN WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD1A}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0649}")), // ARABIC LIGATURE SHEEN WITH YEH FINAL FORM
+    ('\u{FD1B}', CharMapAction::SubStr("\u{062D}\u{0649}")), // ARABIC LIGATURE HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD1C}', CharMapAction::SubStr("\u{062D}\u{0649}")), // ARABIC LIGATURE HAH WITH YEH FINAL FORM
+    ('\u{FD1D}', CharMapAction::SubStr("\u{062C}\u{0649}")), // ARABIC LIGATURE JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD1E}', CharMapAction::SubStr("\u{062C}\u{0649}")), // ARABIC LIGATURE JEEM WITH YEH FINAL FORM
+    ('\u{FD1F}', CharMapAction::SubStr("\u{062E}\u{0649}")), // ARABIC LIGATURE KHAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD20}', CharMapAction::SubStr("\u{062E}\u{0649}")), // ARABIC LIGATURE KHAH WITH YEH FINAL FORM
+    ('\u{FD21}', CharMapAction::SubStr("\u{0635}\u{0649}")), // ARABIC LIGATURE SAD WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD22}', CharMapAction::SubStr("\u{0635}\u{0649}")), // ARABIC LIGATURE SAD WITH YEH FINAL FORM
+    ('\u{FD23}', CharMapAction::SubStr("\u{0636}\u{0649}")), // ARABIC LIGATURE DAD WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD24}', CharMapAction::SubStr("\u{0636}\u{0649}")), // ARABIC LIGATURE DAD WITH YEH FINAL FORM
+    ('\u{FD25}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062C}")), // ARABIC LIGATURE SHEEN WITH JEEM FINAL FORM
+    ('\u{FD26}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}")), // ARABIC LIGATURE SHEEN WITH HAH FINAL FORM
+    ('\u{FD27}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062E}")), // ARABIC LIGATURE SHEEN WITH KHAH FINAL FORM
+    ('\u{FD28}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}")), // ARABIC LIGATURE SHEEN WITH MEEM FINAL FORM
+    ('\u{FD29}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0631}")), // ARABIC LIGATURE SHEEN WITH REH FINAL FORM
+    ('\u{FD2A}', CharMapAction::SubStr("\u{0633}\u{0631}")), // ARABIC LIGATURE SEEN WITH REH FINAL FORM
+    ('\u{FD2B}', CharMapAction::SubStr("\u{0635}\u{0631}")), // ARABIC LIGATURE SAD WITH REH FINAL FORM
+    ('\u{FD2C}', CharMapAction::SubStr("\u{0636}\u{0631}")), // ARABIC LIGATURE DAD WITH REH FINAL FORM
+    ('\u{FD2D}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062C}")), // ARABIC LIGATURE SHEEN WITH JEEM INITIAL FORM
+    ('\u{FD2E}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}")), // ARABIC LIGATURE SHEEN WITH HAH INITIAL FORM
+    ('\u{FD2F}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062E}")), // ARABIC LIGATURE SHEEN WITH KHAH INITIAL FORM
+    ('\u{FD30}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}")), // ARABIC LIGATURE SHEEN WITH MEEM INITIAL FORM
+    ('\u{FD31}', CharMapAction::SubStr("\u{0633}o")), // ARABIC LIGATURE SEEN WITH HEH INITIAL FORM
+    ('\u{FD32}', CharMapAction::SubStr("\u{0633}\u{06DB}o")), // ARABIC LIGATURE SHEEN WITH HEH INITIAL FORM
+    ('\u{FD33}', CharMapAction::SubStr("\u{0637}\u{0645}")), // ARABIC LIGATURE TAH WITH MEEM INITIAL FORM
+    ('\u{FD34}', CharMapAction::SubStr("\u{0633}\u{062C}")), // ARABIC LIGATURE SEEN WITH JEEM MEDIAL FORM
+    ('\u{FD35}', CharMapAction::SubStr("\u{0633}\u{062D}")), // ARABIC LIGATURE SEEN WITH HAH MEDIAL FORM
+    ('\u{FD36}', CharMapAction::SubStr("\u{0633}\u{062E}")), // ARABIC LIGATURE SEEN WITH KHAH MEDIAL FORM
+    ('\u{FD37}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062C}")), // ARABIC LIGATURE SHEEN WITH JEEM MEDIAL FORM
+    ('\u{FD38}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}")), // ARABIC LIGATURE SHEEN WITH HAH MEDIAL FORM
+    ('\u{FD39}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062E}")), // ARABIC LIGATURE SHEEN WITH KHAH MEDIAL FORM
+    ('\u{FD3A}', CharMapAction::SubStr("\u{0637}\u{0645}")), // ARABIC LIGATURE TAH WITH MEEM MEDIAL FORM
+    ('\u{FD3B}', CharMapAction::SubStr("\u{0638}\u{0645}")), // ARABIC LIGATURE ZAH WITH MEEM MEDIAL FORM
+    ('\u{FD3C}', CharMapAction::SubStr("l\u{030B}")), // ARABIC LIGATURE ALEF WITH FATHATAN FINAL FORM
+    ('\u{FD3D}', CharMapAction::SubStr("l\u{030B}")), // ARABIC LIGATURE ALEF WITH FATHATAN ISOLATED FORM
+    ('\u{FD3E}', CharMapAction::SubChar('(')), // ORNATE LEFT PARENTHESIS
+    ('\u{FD3F}', CharMapAction::SubChar(')')), // ORNATE RIGHT PARENTHESIS
+    ('\u{FD50}', CharMapAction::SubStr("\u{062A}\u{062C}\u{0645}")), // ARABIC LIGATURE TEH WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FD51}', CharMapAction::SubStr("\u{062A}\u{062D}\u{062C}")), // ARABIC LIGATURE TEH WITH HAH WITH JEEM FINAL FORM
+    ('\u{FD52}', CharMapAction::SubStr("\u{062A}\u{062D}\u{062C}")), // ARABIC LIGATURE TEH WITH HAH WITH JEEM INITIAL FORM
+    ('\u{FD53}', CharMapAction::SubStr("\u{062A}\u{062D}\u{0645}")), // ARABIC LIGATURE TEH WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD54}', CharMapAction::SubStr("\u{062A}\u{062E}\u{0645}")), // ARABIC LIGATURE TEH WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD55}', CharMapAction::SubStr("\u{062A}\u{0645}\u{062C}")), // ARABIC LIGATURE TEH WITH MEEM WITH JEEM INITIAL FORM
+    ('\u{FD56}', CharMapAction::SubStr("\u{062A}\u{0645}\u{062D}")), // ARABIC LIGATURE TEH WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD57}', CharMapAction::SubStr("\u{062A}\u{0645}\u{062E}")), // ARABIC LIGATURE TEH WITH MEEM WITH KHAH INITIAL FORM
+    ('\u{FD58}', CharMapAction::SubStr("\u{062C}\u{0645}\u{062D}")), // ARABIC LIGATURE JEEM WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD59}', CharMapAction::SubStr("\u{062C}\u{0645}\u{062D}")), // ARABIC LIGATURE JEEM WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD5A}', CharMapAction::SubStr("\u{062D}\u{0645}\u{0649}")), // ARABIC LIGATURE HAH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD5B}', CharMapAction::SubStr("\u{062D}\u{0645}\u{0649}")), // ARABIC LIGATURE HAH WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD5C}', CharMapAction::SubStr("\u{0633}\u{062D}\u{062C}")), // ARABIC LIGATURE SEEN WITH HAH WITH JEEM INITIAL FORM
+    ('\u{FD5D}', CharMapAction::SubStr("\u{0633}\u{062C}\u{062D}")), // ARABIC LIGATURE SEEN WITH JEEM WITH HAH INITIAL FORM
+    ('\u{FD5E}', CharMapAction::SubStr("\u{0633}\u{062C}\u{0649}")), // ARABIC LIGATURE SEEN WITH JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD5F}', CharMapAction::SubStr("\u{0633}\u{0645}\u{062D}")), // ARABIC LIGATURE SEEN WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD60}', CharMapAction::SubStr("\u{0633}\u{0645}\u{062D}")), // ARABIC LIGATURE SEEN WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD61}', CharMapAction::SubStr("\u{0633}\u{0645}\u{062C}")), // ARABIC LIGATURE SEEN WITH MEEM WITH JEEM INITIAL FORM
+    ('\u{FD62}', CharMapAction::SubStr("\u{0633}\u{0645}\u{0645}")), // ARABIC LIGATURE SEEN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD63}', CharMapAction::SubStr("\u{0633}\u{0645}\u{0645}")), // ARABIC LIGATURE SEEN WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD64}', CharMapAction::SubStr("\u{0635}\u{062D}\u{062D}")), // ARABIC LIGATURE SAD WITH HAH WITH HAH FINAL FORM
+    ('\u{FD65}', CharMapAction::SubStr("\u{0635}\u{062D}\u{062D}")), // ARABIC LIGATURE SAD WITH HAH WITH HAH INITIAL FORM
+    ('\u{FD66}', CharMapAction::SubStr("\u{0635}\u{0645}\u{0645}")), // ARABIC LIGATURE SAD WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD67}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}\u{0645}")), // ARABIC LIGATURE SHEEN WITH HAH WITH MEEM FINAL FORM
+    ('\u{FD68}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}\u{0645}")), // ARABIC LIGATURE SHEEN WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD69}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062C}\u{0649}")), // ARABIC LIGATURE SHEEN WITH JEEM WITH YEH FINAL FORM
+    ('\u{FD6A}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}\u{062E}")), // ARABIC LIGATURE SHEEN WITH MEEM WITH KHAH FINAL FORM
+    ('\u{FD6B}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}\u{062E}")), // ARABIC LIGATURE SHEEN WITH MEEM WITH KHAH INITIAL FORM
+    ('\u{FD6C}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}\u{0645}")), // ARABIC LIGATURE SHEEN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD6D}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{0645}\u{0645}")), // ARABIC LIGATURE SHEEN WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD6E}', CharMapAction::SubStr("\u{0636}\u{062D}\u{0649}")), // ARABIC LIGATURE DAD WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD6F}', CharMapAction::SubStr("\u{0636}\u{062E}\u{0645}")), // ARABIC LIGATURE DAD WITH KHAH WITH MEEM FINAL FORM
+    ('\u{FD70}', CharMapAction::SubStr("\u{0636}\u{062E}\u{0645}")), // ARABIC LIGATURE DAD WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD71}', CharMapAction::SubStr("\u{0637}\u{0645}\u{062D}")), // ARABIC LIGATURE TAH WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD72}', CharMapAction::SubStr("\u{0637}\u{0645}\u{062D}")), // ARABIC LIGATURE TAH WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD73}', CharMapAction::SubStr("\u{0637}\u{0645}\u{0645}")), // ARABIC LIGATURE TAH WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD74}', CharMapAction::SubStr("\u{0637}\u{0645}\u{0649}")), // ARABIC LIGATURE TAH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD75}', CharMapAction::SubStr("\u{0639}\u{062C}\u{0645}")), // ARABIC LIGATURE AIN WITH JEEM WITH MEEM FINAL FORM
+    ('\u{FD76}', CharMapAction::SubStr("\u{0639}\u{0645}\u{0645}")), // ARABIC LIGATURE AIN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD77}', CharMapAction::SubStr("\u{0639}\u{0645}\u{0645}")), // ARABIC LIGATURE AIN WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD78}', CharMapAction::SubStr("\u{0639}\u{0645}\u{0649}")), // ARABIC LIGATURE AIN WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD79}', CharMapAction::SubStr("\u{063A}\u{0645}\u{0645}")), // ARABIC LIGATURE GHAIN WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD7A}', CharMapAction::SubStr("\u{063A}\u{0645}\u{0649}")), // ARABIC LIGATURE GHAIN WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD7B}', CharMapAction::SubStr("\u{063A}\u{0645}\u{0649}")), // ARABIC LIGATURE GHAIN WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD7C}', CharMapAction::SubStr("\u{0641}\u{062E}\u{0645}")), // ARABIC LIGATURE FEH WITH KHAH WITH MEEM FINAL FORM
+    ('\u{FD7D}', CharMapAction::SubStr("\u{0641}\u{062E}\u{0645}")), // ARABIC LIGATURE FEH WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD7E}', CharMapAction::SubStr("\u{0642}\u{0645}\u{062D}")), // ARABIC LIGATURE QAF WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD7F}', CharMapAction::SubStr("\u{0642}\u{0645}\u{0645}")), // ARABIC LIGATURE QAF WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD80}', CharMapAction::SubStr("\u{0644}\u{062D}\u{0645}")), // ARABIC LIGATURE LAM WITH HAH WITH MEEM FINAL FORM
+    ('\u{FD81}', CharMapAction::SubStr("\u{0644}\u{062D}\u{0649}")), // ARABIC LIGATURE LAM WITH HAH WITH YEH FINAL FORM
+    ('\u{FD82}', CharMapAction::SubStr("\u{0644}\u{062D}\u{0649}")), // ARABIC LIGATURE LAM WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD83}', CharMapAction::SubStr("\u{0644}\u{062C}\u{062C}")), // ARABIC LIGATURE LAM WITH JEEM WITH JEEM INITIAL FORM
+    ('\u{FD84}', CharMapAction::SubStr("\u{0644}\u{062C}\u{062C}")), // ARABIC LIGATURE LAM WITH JEEM WITH JEEM FINAL FORM
+    ('\u{FD85}', CharMapAction::SubStr("\u{0644}\u{062E}\u{0645}")), // ARABIC LIGATURE LAM WITH KHAH WITH MEEM FINAL FORM
+    ('\u{FD86}', CharMapAction::SubStr("\u{0644}\u{062E}\u{0645}")), // ARABIC LIGATURE LAM WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD87}', CharMapAction::SubStr("\u{0644}\u{0645}\u{062D}")), // ARABIC LIGATURE LAM WITH MEEM WITH HAH FINAL FORM
+    ('\u{FD88}', CharMapAction::SubStr("\u{0644}\u{0645}\u{062D}")), // ARABIC LIGATURE LAM WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FD89}', CharMapAction::SubStr("\u{0645}\u{062D}\u{062C}")), // ARABIC LIGATURE MEEM WITH HAH WITH JEEM INITIAL FORM
+    ('\u{FD8A}', CharMapAction::SubStr("\u{0645}\u{062D}\u{0645}")), // ARABIC LIGATURE MEEM WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD8B}', CharMapAction::SubStr("\u{0645}\u{062D}\u{0649}")), // ARABIC LIGATURE MEEM WITH HAH WITH YEH FINAL FORM
+    ('\u{FD8C}', CharMapAction::SubStr("\u{0645}\u{062C}\u{062D}")), // ARABIC LIGATURE MEEM WITH JEEM WITH HAH INITIAL FORM
+    ('\u{FD8D}', CharMapAction::SubStr("\u{0645}\u{062C}\u{0645}")), // ARABIC LIGATURE MEEM WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FD8E}', CharMapAction::SubStr("\u{0645}\u{062E}\u{062C}")), // ARABIC LIGATURE MEEM WITH KHAH WITH JEEM INITIAL FORM
+    ('\u{FD8F}', CharMapAction::SubStr("\u{0645}\u{062E}\u{0645}")), // ARABIC LIGATURE MEEM WITH KHAH WITH MEEM INITIAL FORM
+    ('\u{FD92}', CharMapAction::SubStr("\u{0645}\u{062C}\u{062E}")), // ARABIC LIGATURE MEEM WITH JEEM WITH KHAH INITIAL FORM
+    ('\u{FD93}', CharMapAction::SubStr("o\u{0645}\u{062C}")), // ARABIC LIGATURE HEH WITH MEEM WITH JEEM INITIAL FORM
+    ('\u{FD94}', CharMapAction::SubStr("o\u{0645}\u{0645}")), // ARABIC LIGATURE HEH WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD95}', CharMapAction::SubStr("\u{0646}\u{062D}\u{0645}")), // ARABIC LIGATURE NOON WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FD96}', CharMapAction::SubStr("\u{0646}\u{062D}\u{0649}")), // ARABIC LIGATURE NOON WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD97}', CharMapAction::SubStr("\u{0646}\u{062C}\u{0645}")), // ARABIC LIGATURE NOON WITH JEEM WITH MEEM FINAL FORM
+    ('\u{FD98}', CharMapAction::SubStr("\u{0646}\u{062C}\u{0645}")), // ARABIC LIGATURE NOON WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FD99}', CharMapAction::SubStr("\u{0646}\u{062C}\u{0649}")), // ARABIC LIGATURE NOON WITH JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD9A}', CharMapAction::SubStr("\u{0646}\u{0645}\u{0649}")), // ARABIC LIGATURE NOON WITH MEEM WITH YEH FINAL FORM
+    ('\u{FD9B}', CharMapAction::SubStr("\u{0646}\u{0645}\u{0649}")), // ARABIC LIGATURE NOON WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FD9C}', CharMapAction::SubStr("\u{0649}\u{0645}\u{0645}")), // ARABIC LIGATURE YEH WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FD9D}', CharMapAction::SubStr("\u{0649}\u{0645}\u{0645}")), // ARABIC LIGATURE YEH WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FD9E}', CharMapAction::SubStr("\u{0628}\u{062E}\u{0649}")), // ARABIC LIGATURE BEH WITH KHAH WITH YEH FINAL FORM
+    ('\u{FD9F}', CharMapAction::SubStr("\u{062A}\u{062C}\u{0649}")), // ARABIC LIGATURE TEH WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDA0}', CharMapAction::SubStr("\u{062A}\u{062C}\u{0649}")), // ARABIC LIGATURE TEH WITH JEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA1}', CharMapAction::SubStr("\u{062A}\u{062E}\u{0649}")), // ARABIC LIGATURE TEH WITH KHAH WITH YEH FINAL FORM
+    ('\u{FDA2}', CharMapAction::SubStr("\u{062A}\u{062E}\u{0649}")), // ARABIC LIGATURE TEH WITH KHAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA3}', CharMapAction::SubStr("\u{062A}\u{0645}\u{0649}")), // ARABIC LIGATURE TEH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDA4}', CharMapAction::SubStr("\u{062A}\u{0645}\u{0649}")), // ARABIC LIGATURE TEH WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA5}', CharMapAction::SubStr("\u{062C}\u{0645}\u{0649}")), // ARABIC LIGATURE JEEM WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDA6}', CharMapAction::SubStr("\u{062C}\u{062D}\u{0649}")), // ARABIC LIGATURE JEEM WITH HAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA7}', CharMapAction::SubStr("\u{062C}\u{0645}\u{0649}")), // ARABIC LIGATURE JEEM WITH MEEM WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA8}', CharMapAction::SubStr("\u{0633}\u{062E}\u{0649}")), // ARABIC LIGATURE SEEN WITH KHAH WITH ALEF MAKSURA FINAL FORM
+    ('\u{FDA9}', CharMapAction::SubStr("\u{0635}\u{062D}\u{0649}")), // ARABIC LIGATURE SAD WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAA}', CharMapAction::SubStr("\u{0633}\u{06DB}\u{062D}\u{0649}")), // ARABIC LIGATURE SHEEN WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAB}', CharMapAction::SubStr("\u{0636}\u{062D}\u{0649}")), // ARABIC LIGATURE DAD WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAC}', CharMapAction::SubStr("\u{0644}\u{062C}\u{0649}")), // ARABIC LIGATURE LAM WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDAD}', CharMapAction::SubStr("\u{0644}\u{0645}\u{0649}")), // ARABIC LIGATURE LAM WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDAE}', CharMapAction::SubStr("\u{0649}\u{062D}\u{0649}")), // ARABIC LIGATURE YEH WITH HAH WITH YEH FINAL FORM
+    ('\u{FDAF}', CharMapAction::SubStr("\u{0649}\u{062C}\u{0649}")), // ARABIC LIGATURE YEH WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDB0}', CharMapAction::SubStr("\u{0649}\u{0645}\u{0649}")), // ARABIC LIGATURE YEH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB1}', CharMapAction::SubStr("\u{0645}\u{0645}\u{0649}")), // ARABIC LIGATURE MEEM WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB2}', CharMapAction::SubStr("\u{0642}\u{0645}\u{0649}")), // ARABIC LIGATURE QAF WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB3}', CharMapAction::SubStr("\u{0646}\u{062D}\u{0649}")), // ARABIC LIGATURE NOON WITH HAH WITH YEH FINAL FORM
+    ('\u{FDB4}', CharMapAction::SubStr("\u{0642}\u{0645}\u{062D}")), // ARABIC LIGATURE QAF WITH MEEM WITH HAH INITIAL FORM
+    ('\u{FDB5}', CharMapAction::SubStr("\u{0644}\u{062D}\u{0645}")), // ARABIC LIGATURE LAM WITH HAH WITH MEEM INITIAL FORM
+    ('\u{FDB6}', CharMapAction::SubStr("\u{0639}\u{0645}\u{0649}")), // ARABIC LIGATURE AIN WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB7}', CharMapAction::SubStr("\u{0643}\u{0645}\u{0649}")), // ARABIC LIGATURE KAF WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDB8}', CharMapAction::SubStr("\u{0646}\u{062C}\u{062D}")), // ARABIC LIGATURE NOON WITH JEEM WITH HAH INITIAL FORM
+    ('\u{FDB9}', CharMapAction::SubStr("\u{0645}\u{062E}\u{0649}")), // ARABIC LIGATURE MEEM WITH KHAH WITH YEH FINAL FORM
+    ('\u{FDBA}', CharMapAction::SubStr("\u{0644}\u{062C}\u{0645}")), // ARABIC LIGATURE LAM WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FDBB}', CharMapAction::SubStr("\u{0643}\u{0645}\u{0645}")), // ARABIC LIGATURE KAF WITH MEEM WITH MEEM FINAL FORM
+    ('\u{FDBC}', CharMapAction::SubStr("\u{0644}\u{062C}\u{0645}")), // ARABIC LIGATURE LAM WITH JEEM WITH MEEM FINAL FORM
+    ('\u{FDBD}', CharMapAction::SubStr("\u{0646}\u{062C}\u{062D}")), // ARABIC LIGATURE NOON WITH JEEM WITH HAH FINAL FORM
+    ('\u{FDBE}', CharMapAction::SubStr("\u{062C}\u{062D}\u{0649}")), // ARABIC LIGATURE JEEM WITH HAH WITH YEH FINAL FORM
+    ('\u{FDBF}', CharMapAction::SubStr("\u{062D}\u{062C}\u{0649}")), // ARABIC LIGATURE HAH WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDC0}', CharMapAction::SubStr("\u{0645}\u{062C}\u{0649}")), // ARABIC LIGATURE MEEM WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDC1}', CharMapAction::SubStr("\u{0641}\u{0645}\u{0649}")), // ARABIC LIGATURE FEH WITH MEEM WITH YEH FINAL FORM
+    ('\u{FDC2}', CharMapAction::SubStr("\u{0628}\u{062D}\u{0649}")), // ARABIC LIGATURE BEH WITH HAH WITH YEH FINAL FORM
+    ('\u{FDC3}', CharMapAction::SubStr("\u{0643}\u{0645}\u{0645}")), // ARABIC LIGATURE KAF WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FDC4}', CharMapAction::SubStr("\u{0639}\u{062C}\u{0645}")), // ARABIC LIGATURE AIN WITH JEEM WITH MEEM INITIAL FORM
+    ('\u{FDC5}', CharMapAction::SubStr("\u{0635}\u{0645}\u{0645}")), // ARABIC LIGATURE SAD WITH MEEM WITH MEEM INITIAL FORM
+    ('\u{FDC6}', CharMapAction::SubStr("\u{0633}\u{062E}\u{0649}")), // ARABIC LIGATURE SEEN WITH KHAH WITH YEH FINAL FORM
+    ('\u{FDC7}', CharMapAction::SubStr("\u{0646}\u{062C}\u{0649}")), // ARABIC LIGATURE NOON WITH JEEM WITH YEH FINAL FORM
+    ('\u{FDF0}', CharMapAction::SubStr("\u{0635}\u{0644}\u{0649}")), // ARABIC LIGATURE SALLA USED AS KORANIC STOP SIGN ISOLATED FORM
+    ('\u{FDF1}', CharMapAction::SubStr("\u{0642}\u{0644}\u{0649}")), // ARABIC LIGATURE QALA USED AS KORANIC STOP SIGN ISOLATED FORM
+    ('\u{FDF2}', CharMapAction::SubStr("l\u{0644}\u{0644}\u{0651}\u{0670}o")), // ARABIC LIGATURE ALLAH ISOLATED FORM
+    ('\u{FDF3}', CharMapAction::SubStr("l\u{0643}\u{0628}\u{0631}")), // ARABIC LIGATURE AKBAR ISOLATED FORM
+    ('\u{FDF4}', CharMapAction::SubStr("\u{0645}\u{062D}\u{0645}\u{062F}")), // ARABIC LIGATURE MOHAMMAD ISOLATED FORM
+    ('\u{FDF5}', CharMapAction::SubStr("\u{0635}\u{0644}\u{0639}\u{0645}")), // ARABIC LIGATURE SALAM ISOLATED FORM
+    ('\u{FDF6}', CharMapAction::SubStr("\u{0631}\u{0633}\u{0648}\u{0644}")), // ARABIC LIGATURE RASOUL ISOLATED FORM
+    ('\u{FDF7}', CharMapAction::SubStr("\u{0639}\u{0644}\u{0649}o")), // ARABIC LIGATURE ALAYHE ISOLATED FORM
+    ('\u{FDF8}', CharMapAction::SubStr("\u{0648}\u{0633}\u{0644}\u{0645}")), // ARABIC LIGATURE WASALLAM ISOLATED FORM
+    ('\u{FDF9}', CharMapAction::SubStr("\u{0635}\u{0644}\u{0649}")), // ARABIC LIGATURE SALLA ISOLATED FORM
+    ('\u{FDFA}', CharMapAction::SubStr("\u{0635}\u{0644}\u{0649} l\u{0644}\u{0644}o \u{0639}\u{0644}\u{0649}o \u{0648}\u{0633}\u{0644}\u{0645}")), // ARABIC LIGATURE SALLALLAHOU ALAYHE WASALLAM
+    ('\u{FDFB}', CharMapAction::SubStr("\u{062C}\u{0644} \u{062C}\u{0644}l\u{0644}o")), // ARABIC LIGATURE JALLAJALALOUHOU
+    ('\u{FDFC}', CharMapAction::SubStr("\u{0631}\u{0649}l\u{0644}")), // RIAL SIGN
+    ('\u{FE19}', CharMapAction::SubChar('\u{2D57}')), // PRESENTATION FORM FOR VERTICAL HORIZONTAL ELLIPSIS
+    ('\u{FE30}', CharMapAction::SubChar(':')), // PRESENTATION FORM FOR VERTICAL TWO DOT LEADER
+    ('\u{FE31}', CharMapAction::SubChar('\u{2502}')), // PRESENTATION FORM FOR VERTICAL EM DASH
+    ('\u{FE34}', CharMapAction::SubChar('\u{2307}')), // PRESENTATION FORM FOR VERTICAL WAVY LOW LINE
+    ('\u{FE35}', CharMapAction::SubChar('\u{23DC}')), // PRESENTATION FORM FOR VERTICAL LEFT PARENTHESIS
+    ('\u{FE36}', CharMapAction::SubChar('\u{23DD}')), // PRESENTATION FORM FOR VERTICAL RIGHT PARENTHESIS
+    ('\u{FE37}', CharMapAction::SubChar('\u{23DE}')), // PRESENTATION FORM FOR VERTICAL LEFT CURLY BRACKET
+    ('\u{FE38}', CharMapAction::SubChar('\u{23DF}')), // PRESENTATION FORM FOR VERTICAL RIGHT CURLY BRACKET
+    ('\u{FE39}', CharMapAction::SubChar('\u{23E0}')), // PRESENTATION FORM FOR VERTICAL LEFT TORTOISE SHELL BRACKET
+    ('\u{FE3A}', CharMapAction::SubChar('\u{23E1}')), // PRESENTATION FORM FOR VERTICAL RIGHT TORTOISE SHELL BRACKET
+    ('\u{FE49}', CharMapAction::SubChar('\u{02C9}')), // DASHED OVERLINE
+    ('\u{FE4A}', CharMapAction::SubChar('\u{02C9}')), // CENTRELINE OVERLINE
+    ('\u{FE4B}', CharMapAction::SubChar('\u{02C9}')), // WAVY OVERLINE
+    ('\u{FE4C}', CharMapAction::SubChar('\u{02C9}')), // DOUBLE WAVY OVERLINE
+    ('\u{FE4D}', CharMapAction::SubChar('_')), // DASHED LOW LINE
+    ('\u{FE4E}', CharMapAction::SubChar('_')), // CENTRELINE LOW LINE
+    ('\u{FE4F}', CharMapAction::SubChar('_')), // WAVY LOW LINE
+    ('\u{FE58}', CharMapAction::SubChar('-')), // SMALL EM DASH
+    ('\u{FE68}', CharMapAction::SubChar('\\')), // SMALL REVERSE SOLIDUS
+    ('\u{FE80}', CharMapAction::SubChar('\u{0621}')), // ARABIC LETTER HAMZA ISOLATED FORM
+    ('\u{FE81}', CharMapAction::SubChar('\u{0622}')), // ARABIC LETTER ALEF WITH MADDA ABOVE ISOLATED FORM
+    ('\u{FE82}', CharMapAction::SubChar('\u{0622}')), // ARABIC LETTER ALEF WITH MADDA ABOVE FINAL FORM
+    ('\u{FE83}', CharMapAction::SubStr("l\u{0674}")), // ARABIC LETTER ALEF WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FE84}', CharMapAction::SubStr("l\u{0674}")), // ARABIC LETTER ALEF WITH HAMZA ABOVE FINAL FORM
+    ('\u{FE85}', CharMapAction::SubStr("\u{0648}\u{0674}")), // ARABIC LETTER WAW WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FE86}', CharMapAction::SubStr("\u{0648}\u{0674}")), // ARABIC LETTER WAW WITH HAMZA ABOVE FINAL FORM
+    ('\u{FE87}', CharMapAction::SubStr("l\u{0655}")), // ARABIC LETTER ALEF WITH HAMZA BELOW ISOLATED FORM
+    ('\u{FE88}', CharMapAction::SubStr("l\u{0655}")), // ARABIC LETTER ALEF WITH HAMZA BELOW FINAL FORM
+    ('\u{FE89}', CharMapAction::SubStr("\u{0649}\u{0674}")), // ARABIC LETTER YEH WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FE8A}', CharMapAction::SubStr("\u{0649}\u{0674}")), // ARABIC LETTER YEH WITH HAMZA ABOVE FINAL FORM
+    ('\u{FE8B}', CharMapAction::SubStr("\u{0649}\u{0674}")), // ARABIC LETTER YEH WITH HAMZA ABOVE INITIAL FORM
+    ('\u{FE8C}', CharMapAction::SubStr("\u{0649}\u{0674}")), // ARABIC LETTER YEH WITH HAMZA ABOVE MEDIAL FORM
+    ('\u{FE8D}', CharMapAction::SubChar('l')), // ARABIC LETTER ALEF ISOLATED FORM
+    ('\u{FE8E}', CharMapAction::SubChar('l')), // ARABIC LETTER ALEF FINAL FORM
+    ('\u{FE8F}', CharMapAction::SubChar('\u{0628}')), // ARABIC LETTER BEH ISOLATED FORM
+    ('\u{FE90}', CharMapAction::SubChar('\u{0628}')), // ARABIC LETTER BEH FINAL FORM
+    ('\u{FE91}', CharMapAction::SubChar('\u{0628}')), // ARABIC LETTER BEH INITIAL FORM
+    ('\u{FE92}', CharMapAction::SubChar('\u{0628}')), // ARABIC LETTER BEH MEDIAL FORM
+    ('\u{FE93}', CharMapAction::SubChar('\u{0629}')), // ARABIC LETTER TEH MARBUTA ISOLATED FORM
+    ('\u{FE94}', CharMapAction::SubChar('\u{0629}')), // ARABIC LETTER TEH MARBUTA FINAL FORM
+    ('\u{FE95}', CharMapAction::SubChar('\u{062A}')), // ARABIC LETTER TEH ISOLATED FORM
+    ('\u{FE96}', CharMapAction::SubChar('\u{062A}')), // ARABIC LETTER TEH FINAL FORM
+    ('\u{FE97}', CharMapAction::SubChar('\u{062A}')), // ARABIC LETTER TEH INITIAL FORM
+    ('\u{FE98}', CharMapAction::SubChar('\u{062A}')), // ARABIC LETTER TEH MEDIAL FORM
+    ('\u{FE99}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER THEH ISOLATED FORM
+    ('\u{FE9A}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER THEH FINAL FORM
+    ('\u{FE9B}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER THEH INITIAL FORM
+    ('\u{FE9C}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC LETTER THEH MEDIAL FORM
+    ('\u{FE9D}', CharMapAction::SubChar('\u{062C}')), // ARABIC LETTER JEEM ISOLATED FORM
+    ('\u{FE9E}', CharMapAction::SubChar('\u{062C}')), // ARABIC LETTER JEEM FINAL FORM
+    ('\u{FE9F}', CharMapAction::SubChar('\u{062C}')), // ARABIC LETTER JEEM INITIAL FORM
+    ('\u{FEA0}', CharMapAction::SubChar('\u{062C}')), // ARABIC LETTER JEEM MEDIAL FORM
+    ('\u{FEA1}', CharMapAction::SubChar('\u{062D}')), // ARABIC LETTER HAH ISOLATED FORM
+    ('\u{FEA2}', CharMapAction::SubChar('\u{062D}')), // ARABIC LETTER HAH FINAL FORM
+    ('\u{FEA3}', CharMapAction::SubChar('\u{062D}')), // ARABIC LETTER HAH INITIAL FORM
+    ('\u{FEA4}', CharMapAction::SubChar('\u{062D}')), // ARABIC LETTER HAH MEDIAL FORM
+    ('\u{FEA5}', CharMapAction::SubChar('\u{062E}')), // ARABIC LETTER KHAH ISOLATED FORM
+    ('\u{FEA6}', CharMapAction::SubChar('\u{062E}')), // ARABIC LETTER KHAH FINAL FORM
+    ('\u{FEA7}', CharMapAction::SubChar('\u{062E}')), // ARABIC LETTER KHAH INITIAL FORM
+    ('\u{FEA8}', CharMapAction::SubChar('\u{062E}')), // ARABIC LETTER KHAH MEDIAL FORM
+    ('\u{FEA9}', CharMapAction::SubChar('\u{062F}')), // ARABIC LETTER DAL ISOLATED FORM
+    ('\u{FEAA}', CharMapAction::SubChar('\u{062F}')), // ARABIC LETTER DAL FINAL FORM
+    ('\u{FEAB}', CharMapAction::SubChar('\u{0630}')), // ARABIC LETTER THAL ISOLATED FORM
+    ('\u{FEAC}', CharMapAction::SubChar('\u{0630}')), // ARABIC LETTER THAL FINAL FORM
+    ('\u{FEAD}', CharMapAction::SubChar('\u{0631}')), // ARABIC LETTER REH ISOLATED FORM
+    ('\u{FEAE}', CharMapAction::SubChar('\u{0631}')), // ARABIC LETTER REH FINAL FORM
+    ('\u{FEAF}', CharMapAction::SubChar('\u{0632}')), // ARABIC LETTER ZAIN ISOLATED FORM
+    ('\u{FEB0}', CharMapAction::SubChar('\u{0632}')), // ARABIC LETTER ZAIN FINAL FORM
+    ('\u{FEB1}', CharMapAction::SubChar('\u{0633}')), // ARABIC LETTER SEEN ISOLATED FORM
+    ('\u{FEB2}', CharMapAction::SubChar('\u{0633}')), // ARABIC LETTER SEEN FINAL FORM
+    ('\u{FEB3}', CharMapAction::SubChar('\u{0633}')), // ARABIC LETTER SEEN INITIAL FORM
+    ('\u{FEB4}', CharMapAction::SubChar('\u{0633}')), // ARABIC LETTER SEEN MEDIAL FORM
+    ('\u{FEB5}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC LETTER SHEEN ISOLATED FORM
+    ('\u{FEB6}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC LETTER SHEEN FINAL FORM
+    ('\u{FEB7}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC LETTER SHEEN INITIAL FORM
+    ('\u{FEB8}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC LETTER SHEEN MEDIAL FORM
+    ('\u{FEB9}', CharMapAction::SubChar('\u{0635}')), // ARABIC LETTER SAD ISOLATED FORM
+    ('\u{FEBA}', CharMapAction::SubChar('\u{0635}')), // ARABIC LETTER SAD FINAL FORM
+    ('\u{FEBB}', CharMapAction::SubChar('\u{0635}')), // ARABIC LETTER SAD INITIAL FORM
+    ('\u{FEBC}', CharMapAction::SubChar('\u{0635}')), // ARABIC LETTER SAD MEDIAL FORM
+    ('\u{FEBD}', CharMapAction::SubChar('\u{0636}')), // ARABIC LETTER DAD ISOLATED FORM
+    ('\u{FEBE}', CharMapAction::SubChar('\u{0636}')), // ARABIC LETTER DAD FINAL FORM
+    ('\u{FEBF}', CharMapAction::SubChar('\u{0636}')), // ARABIC LETTER DAD INITIAL FORM
+    ('\u{FEC0}', CharMapAction::SubChar('\u{0636}')), // ARABIC LETTER DAD MEDIAL FORM
+    ('\u{FEC1}', CharMapAction::SubChar('\u{0637}')), // ARABIC LETTER TAH ISOLATED FORM
+    ('\u{FEC2}', CharMapAction::SubChar('\u{0637}')), // ARABIC LETTER TAH FINAL FORM
+    ('\u{FEC3}', CharMapAction::SubChar('\u{0637}')), // ARABIC LETTER TAH INITIAL FORM
+    ('\u{FEC4}', CharMapAction::SubChar('\u{0637}')), // ARABIC LETTER TAH MEDIAL FORM
+    ('\u{FEC5}', CharMapAction::SubChar('\u{0638}')), // ARABIC LETTER ZAH ISOLATED FORM
+    ('\u{FEC6}', CharMapAction::SubChar('\u{0638}')), // ARABIC LETTER ZAH FINAL FORM
+    ('\u{FEC7}', CharMapAction::SubChar('\u{0638}')), // ARABIC LETTER ZAH INITIAL FORM
+    ('\u{FEC8}', CharMapAction::SubChar('\u{0638}')), // ARABIC LETTER ZAH MEDIAL FORM
+    ('\u{FEC9}', CharMapAction::SubChar('\u{0639}')), // ARABIC LETTER AIN ISOLATED FORM
+    ('\u{FECA}', CharMapAction::SubChar('\u{0639}')), // ARABIC LETTER AIN FINAL FORM
+    ('\u{FECB}', CharMapAction::SubChar('\u{0639}')), // ARABIC LETTER AIN INITIAL FORM
+    ('\u{FECC}', CharMapAction::SubChar('\u{0639}')), // ARABIC LETTER AIN MEDIAL FORM
+    ('\u{FECD}', CharMapAction::SubChar('\u{063A}')), // ARABIC LETTER GHAIN ISOLATED FORM
+    ('\u{FECE}', CharMapAction::SubChar('\u{063A}')), // ARABIC LETTER GHAIN FINAL FORM
+    ('\u{FECF}', CharMapAction::SubChar('\u{063A}')), // ARABIC LETTER GHAIN INITIAL FORM
+    ('\u{FED0}', CharMapAction::SubChar('\u{063A}')), // ARABIC LETTER GHAIN MEDIAL FORM
+    ('\u{FED1}', CharMapAction::SubChar('\u{0641}')), // ARABIC LETTER FEH ISOLATED FORM
+    ('\u{FED2}', CharMapAction::SubChar('\u{0641}')), // ARABIC LETTER FEH FINAL FORM
+    ('\u{FED3}', CharMapAction::SubChar('\u{0641}')), // ARABIC LETTER FEH INITIAL FORM
+    ('\u{FED4}', CharMapAction::SubChar('\u{0641}')), // ARABIC LETTER FEH MEDIAL FORM
+    ('\u{FED5}', CharMapAction::SubChar('\u{0642}')), // ARABIC LETTER QAF ISOLATED FORM
+    ('\u{FED6}', CharMapAction::SubChar('\u{0642}')), // ARABIC LETTER QAF FINAL FORM
+    ('\u{FED7}', CharMapAction::SubChar('\u{0642}')), // ARABIC LETTER QAF INITIAL FORM
+    ('\u{FED8}', CharMapAction::SubChar('\u{0642}')), // ARABIC LETTER QAF MEDIAL FORM
+    ('\u{FED9}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KAF ISOLATED FORM
+    ('\u{FEDA}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KAF FINAL FORM
+    ('\u{FEDB}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KAF INITIAL FORM
+    ('\u{FEDC}', CharMapAction::SubChar('\u{0643}')), // ARABIC LETTER KAF MEDIAL FORM
+    ('\u{FEDD}', CharMapAction::SubChar('\u{0644}')), // ARABIC LETTER LAM ISOLATED FORM
+    ('\u{FEDE}', CharMapAction::SubChar('\u{0644}')), // ARABIC LETTER LAM FINAL FORM
+    ('\u{FEDF}', CharMapAction::SubChar('\u{0644}')), // ARABIC LETTER LAM INITIAL FORM
+    ('\u{FEE0}', CharMapAction::SubChar('\u{0644}')), // ARABIC LETTER LAM MEDIAL FORM
+    ('\u{FEE1}', CharMapAction::SubChar('\u{0645}')), // ARABIC LETTER MEEM ISOLATED FORM
+    ('\u{FEE2}', CharMapAction::SubChar('\u{0645}')), // ARABIC LETTER MEEM FINAL FORM
+    ('\u{FEE3}', CharMapAction::SubChar('\u{0645}')), // ARABIC LETTER MEEM INITIAL FORM
+    ('\u{FEE4}', CharMapAction::SubChar('\u{0645}')), // ARABIC LETTER MEEM MEDIAL FORM
+    ('\u{FEE5}', CharMapAction::SubChar('\u{0646}')), // ARABIC LETTER NOON ISOLATED FORM
+    ('\u{FEE6}', CharMapAction::SubChar('\u{0646}')), // ARABIC LETTER NOON FINAL FORM
+    ('\u{FEE7}', CharMapAction::SubChar('\u{0646}')), // ARABIC LETTER NOON INITIAL FORM
+    ('\u{FEE8}', CharMapAction::SubChar('\u{0646}')), // ARABIC LETTER NOON MEDIAL FORM
+    ('\u{FEE9}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH ISOLATED FORM
+    ('\u{FEEA}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH FINAL FORM
+    ('\u{FEEB}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH INITIAL FORM
+    ('\u{FEEC}', CharMapAction::SubChar('o')), // ARABIC LETTER HEH MEDIAL FORM
+    ('\u{FEED}', CharMapAction::SubChar('\u{0648}')), // ARABIC LETTER WAW ISOLATED FORM
+    ('\u{FEEE}', CharMapAction::SubChar('\u{0648}')), // ARABIC LETTER WAW FINAL FORM
+    ('\u{FEEF}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER ALEF MAKSURA ISOLATED FORM
+    ('\u{FEF0}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER ALEF MAKSURA FINAL FORM
+    ('\u{FEF1}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH ISOLATED FORM
+    ('\u{FEF2}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH FINAL FORM
+    ('\u{FEF3}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH INITIAL FORM
+    ('\u{FEF4}', CharMapAction::SubChar('\u{0649}')), // ARABIC LETTER YEH MEDIAL FORM
+    ('\u{FEF5}', CharMapAction::SubStr("\u{0644}\u{0622}")), // ARABIC LIGATURE LAM WITH ALEF WITH MADDA ABOVE ISOLATED FORM
+    ('\u{FEF6}', CharMapAction::SubStr("\u{0644}\u{0622}")), // ARABIC LIGATURE LAM WITH ALEF WITH MADDA ABOVE FINAL FORM
+    ('\u{FEF7}', CharMapAction::SubStr("\u{0644}l\u{0674}")), // ARABIC LIGATURE LAM WITH ALEF WITH HAMZA ABOVE ISOLATED FORM
+    ('\u{FEF8}', CharMapAction::SubStr("\u{0644}l\u{0674}")), // ARABIC LIGATURE LAM WITH ALEF WITH HAMZA ABOVE FINAL FORM
+    ('\u{FEF9}', CharMapAction::SubStr("\u{0644}l\u{0655}")), // ARABIC LIGATURE LAM WITH ALEF WITH HAMZA BELOW ISOLATED FORM
+    ('\u{FEFA}', CharMapAction::SubStr("\u{0644}l\u{0655}")), // ARABIC LIGATURE LAM WITH ALEF WITH HAMZA BELOW FINAL FORM
+    ('\u{FEFB}', CharMapAction::SubStr("\u{0644}l")), // ARABIC LIGATURE LAM WITH ALEF ISOLATED FORM
+    ('\u{FEFC}', CharMapAction::SubStr("\u{0644}l")), // ARABIC LIGATURE LAM WITH ALEF FINAL FORM
+    ('\u{FF01}', CharMapAction::SubChar('!')), // FULLWIDTH EXCLAMATION MARK
+    ('\u{FF02}', CharMapAction::SubStr("''")), // FULLWIDTH QUOTATION MARK
+    ('\u{FF07}', CharMapAction::SubChar('\'')), // FULLWIDTH APOSTROPHE
+    ('\u{FF0D}', CharMapAction::SubChar('\u{30FC}')), // FULLWIDTH HYPHEN-MINUS
+    ('\u{FF1A}', CharMapAction::SubChar(':')), // FULLWIDTH COLON
+    ('\u{FF21}', CharMapAction::SubChar('A')), // FULLWIDTH LATIN CAPITAL LETTER A
+    ('\u{FF22}', CharMapAction::SubChar('B')), // FULLWIDTH LATIN CAPITAL LETTER B
+    ('\u{FF23}', CharMapAction::SubChar('C')), // FULLWIDTH LATIN CAPITAL LETTER C
+    ('\u{FF25}', CharMapAction::SubChar('E')), // FULLWIDTH LATIN CAPITAL LETTER E
+    ('\u{FF28}', CharMapAction::SubChar('H')), // FULLWIDTH LATIN CAPITAL LETTER H
+    ('\u{FF29}', CharMapAction::SubChar('l')), // FULLWIDTH LATIN CAPITAL LETTER I
+    ('\u{FF2A}', CharMapAction::SubChar('J')), // FULLWIDTH LATIN CAPITAL LETTER J
+    ('\u{FF2B}', CharMapAction::SubChar('K')), // FULLWIDTH LATIN CAPITAL LETTER K
+    ('\u{FF2D}', CharMapAction::SubChar('M')), // FULLWIDTH LATIN CAPITAL LETTER M
+    ('\u{FF2E}', CharMapAction::SubChar('N')), // FULLWIDTH LATIN CAPITAL LETTER N
+    ('\u{FF2F}', CharMapAction::SubChar('O')), // FULLWIDTH LATIN CAPITAL LETTER O
+    ('\u{FF30}', CharMapAction::SubChar('P')), // FULLWIDTH LATIN CAPITAL LETTER P
+    ('\u{FF33}', CharMapAction::SubChar('S')), // FULLWIDTH LATIN CAPITAL LETTER S
+    ('\u{FF34}', CharMapAction::SubChar('T')), // FULLWIDTH LATIN CAPITAL LETTER T
+    ('\u{FF38}', CharMapAction::SubChar('X')), // FULLWIDTH LATIN CAPITAL LETTER X
+    ('\u{FF39}', CharMapAction::SubChar('Y')), // FULLWIDTH LATIN CAPITAL LETTER Y
+    ('\u{FF3A}', CharMapAction::SubChar('Z')), // FULLWIDTH LATIN CAPITAL LETTER Z
+    ('\u{FF3B}', CharMapAction::SubChar('(')), // FULLWIDTH LEFT SQUARE BRACKET
+    ('\u{FF3C}', CharMapAction::SubChar('\\')), // FULLWIDTH REVERSE SOLIDUS
+    ('\u{FF3D}', CharMapAction::SubChar(')')), // FULLWIDTH RIGHT SQUARE BRACKET
+    ('\u{FF3E}', CharMapAction::SubChar('\u{FE3F}')), // FULLWIDTH CIRCUMFLEX ACCENT
+    ('\u{FF40}', CharMapAction::SubChar('\'')), // FULLWIDTH GRAVE ACCENT
+    ('\u{FF41}', CharMapAction::SubChar('a')), // FULLWIDTH LATIN SMALL LETTER A
+    ('\u{FF43}', CharMapAction::SubChar('c')), // FULLWIDTH LATIN SMALL LETTER C
+    ('\u{FF45}', CharMapAction::SubChar('e')), // FULLWIDTH LATIN SMALL LETTER E
+    ('\u{FF47}', CharMapAction::SubChar('g')), // FULLWIDTH LATIN SMALL LETTER G
+    ('\u{FF48}', CharMapAction::SubChar('h')), // FULLWIDTH LATIN SMALL LETTER H
+    ('\u{FF49}', CharMapAction::SubChar('i')), // FULLWIDTH LATIN SMALL LETTER I
+    ('\u{FF4A}', CharMapAction::SubChar('j')), // FULLWIDTH LATIN SMALL LETTER J
+    ('\u{FF4C}', CharMapAction::SubChar('l')), // FULLWIDTH LATIN SMALL LETTER L
+    ('\u{FF4F}', CharMapAction::SubChar('o')), // FULLWIDTH LATIN SMALL LETTER O
+    ('\u{FF50}', CharMapAction::SubChar('p')), // FULLWIDTH LATIN SMALL LETTER P
+    ('\u{FF53}', CharMapAction::SubChar('s')), // FULLWIDTH LATIN SMALL LETTER S
+    ('\u{FF56}', CharMapAction::SubChar('v')), // FULLWIDTH LATIN SMALL LETTER V
+    ('\u{FF58}', CharMapAction::SubChar('x')), // FULLWIDTH LATIN SMALL LETTER X
+    ('\u{FF59}', CharMapAction::SubChar('y')), // FULLWIDTH LATIN SMALL LETTER Y
+    ('\u{FF5C}', CharMapAction::SubChar('\u{2502}')), // FULLWIDTH VERTICAL LINE
+    ('\u{FF5E}', CharMapAction::SubChar('\u{301C}')), // FULLWIDTH TILDE
+    ('\u{FF65}', CharMapAction::SubChar('\u{00B7}')), // HALFWIDTH KATAKANA MIDDLE DOT
+    ('\u{FFE3}', CharMapAction::SubChar('\u{02C9}')), // FULLWIDTH MACRON
+    ('\u{FFE8}', CharMapAction::SubChar('l')), // HALFWIDTH FORMS LIGHT VERTICAL
+    ('\u{FFED}', CharMapAction::SubChar('\u{25AA}')), // HALFWIDTH BLACK SQUARE
+    ('\u{10101}', CharMapAction::SubChar('\u{00B7}')), // AEGEAN WORD SEPARATOR DOT
+    ('\u{1018E}', CharMapAction::SubStr("N\u{030A}")), // NOMISMA SIGN
+    ('\u{10196}', CharMapAction::SubStr("X\u{0335}")), // ROMAN DENARIUS SIGN
+    ('\u{10197}', CharMapAction::SubStr("V\u{0335}")), // ROMAN QUINARIUS SIGN
+    ('\u{10198}', CharMapAction::SubStr("l\u{0335}l\u{0335}S\u{0335}")), // ROMAN SESTERTIUS SIGN
+    ('\u{10199}', CharMapAction::SubStr("l\u{0335}l\u{0335}")), // ROMAN DUPONDIUS SIGN
+    ('\u{101A0}', CharMapAction::SubChar('\u{2CE8}')), // GREEK SYMBOL TAU RHO
+    ('\u{10282}', CharMapAction::SubChar('B')), // LYCIAN LETTER B
+    ('\u{10285}', CharMapAction::SubChar('\u{0394}')), // LYCIAN LETTER D
+    ('\u{10286}', CharMapAction::SubChar('E')), // LYCIAN LETTER I
+    ('\u{10287}', CharMapAction::SubChar('F')), // LYCIAN LETTER W
+    ('\u{1028A}', CharMapAction::SubChar('l')), // LYCIAN LETTER J
+    ('\u{1028D}', CharMapAction::SubChar('\u{0245}')), // LYCIAN LETTER L
+    ('\u{10290}', CharMapAction::SubChar('X')), // LYCIAN LETTER MM
+    ('\u{10292}', CharMapAction::SubChar('O')), // LYCIAN LETTER U
+    ('\u{10294}', CharMapAction::SubChar('\u{16DC}')), // LYCIAN LETTER KK
+    ('\u{10295}', CharMapAction::SubChar('P')), // LYCIAN LETTER R
+    ('\u{10296}', CharMapAction::SubChar('S')), // LYCIAN LETTER S
+    ('\u{10297}', CharMapAction::SubChar('T')), // LYCIAN LETTER T
+    ('\u{1029B}', CharMapAction::SubChar('+')), // LYCIAN LETTER H
+    ('\u{102A0}', CharMapAction::SubChar('A')), // CARIAN LETTER A
+    ('\u{102A1}', CharMapAction::SubChar('B')), // CARIAN LETTER P2
+    ('\u{102A2}', CharMapAction::SubChar('C')), // CARIAN LETTER D
+    ('\u{102A3}', CharMapAction::SubChar('\u{0394}')), // CARIAN LETTER L
+    ('\u{102A5}', CharMapAction::SubChar('F')), // CARIAN LETTER R
+    ('\u{102AB}', CharMapAction::SubChar('O')), // CARIAN LETTER O
+    ('\u{102AD}', CharMapAction::SubChar('\u{03D8}')), // CARIAN LETTER T
+    ('\u{102B0}', CharMapAction::SubChar('M')), // CARIAN LETTER S
+    ('\u{102B1}', CharMapAction::SubChar('T')), // CARIAN LETTER C-18
+    ('\u{102B2}', CharMapAction::SubChar('Y')), // CARIAN LETTER U
+    ('\u{102B3}', CharMapAction::SubChar('\u{03A6}')), // CARIAN LETTER NN
+    ('\u{102B4}', CharMapAction::SubChar('X')), // CARIAN LETTER X
+    ('\u{102B5}', CharMapAction::SubChar('\u{03A8}')), // CARIAN LETTER N
+    ('\u{102B6}', CharMapAction::SubChar('\u{03A9}')), // CARIAN LETTER TT2
+    ('\u{102B8}', CharMapAction::SubChar('\u{2D40}')), // CARIAN LETTER SS
+    ('\u{102CF}', CharMapAction::SubChar('H')), // CARIAN LETTER E2
+    ('\u{102E1}', CharMapAction::SubChar('\u{062F}')), // COPTIC EPACT DIGIT ONE
+    ('\u{102E4}', CharMapAction::SubChar('\u{0648}')), // COPTIC EPACT DIGIT FOUR
+    ('\u{102E8}', CharMapAction::SubChar('\u{0637}')), // COPTIC EPACT DIGIT EIGHT
+    ('\u{102F2}', CharMapAction::SubChar('\u{0635}')), // COPTIC EPACT NUMBER NINETY
+    ('\u{102F5}', CharMapAction::SubChar('Z')), // COPTIC EPACT NUMBER THREE HUNDRED
+    ('\u{10301}', CharMapAction::SubChar('B')), // OLD ITALIC LETTER BE
+    ('\u{10302}', CharMapAction::SubChar('C')), // OLD ITALIC LETTER KE
+    ('\u{10309}', CharMapAction::SubChar('l')), // OLD ITALIC LETTER I
+    ('\u{10311}', CharMapAction::SubChar('M')), // OLD ITALIC LETTER SHE
+    ('\u{10312}', CharMapAction::SubChar('\u{03D8}')), // OLD ITALIC LETTER KU
+    ('\u{10315}', CharMapAction::SubChar('T')), // OLD ITALIC LETTER TE
+    ('\u{10317}', CharMapAction::SubChar('X')), // OLD ITALIC LETTER EKS
+    ('\u{1031A}', CharMapAction::SubChar('8')), // OLD ITALIC LETTER EF
+    ('\u{1031F}', CharMapAction::SubChar('*')), // OLD ITALIC LETTER ESS
+    ('\u{10320}', CharMapAction::SubChar('l')), // OLD ITALIC NUMERAL ONE
+    ('\u{10322}', CharMapAction::SubChar('X')), // OLD ITALIC NUMERAL TEN
+    ('\u{103D1}', CharMapAction::SubChar('\u{10382}')), // OLD PERSIAN NUMBER ONE
+    ('\u{103D3}', CharMapAction::SubChar('\u{10393}')), // OLD PERSIAN NUMBER TEN
+    ('\u{10401}', CharMapAction::SubChar('\u{0190}')), // DESERET CAPITAL LETTER LONG E
+    ('\u{10404}', CharMapAction::SubChar('O')), // DESERET CAPITAL LETTER LONG O
+    ('\u{10411}', CharMapAction::SubChar('\u{A4F6}')), // DESERET CAPITAL LETTER PEE
+    ('\u{10415}', CharMapAction::SubChar('C')), // DESERET CAPITAL LETTER CHEE
+    ('\u{1041B}', CharMapAction::SubChar('L')), // DESERET CAPITAL LETTER ETH
+    ('\u{1041F}', CharMapAction::SubChar('\u{2C70}')), // DESERET CAPITAL LETTER ESH
+    ('\u{10420}', CharMapAction::SubChar('S')), // DESERET CAPITAL LETTER ZHEE
+    ('\u{10423}', CharMapAction::SubChar('\u{0186}')), // DESERET CAPITAL LETTER EM
+    ('\u{10425}', CharMapAction::SubChar('\u{0418}')), // DESERET CAPITAL LETTER ENG
+    ('\u{10429}', CharMapAction::SubChar('\u{A793}')), // DESERET SMALL LETTER LONG E
+    ('\u{1042A}', CharMapAction::SubChar('\u{029A}')), // DESERET SMALL LETTER LONG A
+    ('\u{1042C}', CharMapAction::SubChar('o')), // DESERET SMALL LETTER LONG O
+    ('\u{1043D}', CharMapAction::SubChar('c')), // DESERET SMALL LETTER CHEE
+    ('\u{1043F}', CharMapAction::SubChar('\u{0277}')), // DESERET SMALL LETTER KAY
+    ('\u{10442}', CharMapAction::SubChar('\u{025E}')), // DESERET SMALL LETTER VEE
+    ('\u{10443}', CharMapAction::SubChar('\u{029F}')), // DESERET SMALL LETTER ETH
+    ('\u{10448}', CharMapAction::SubChar('s')), // DESERET SMALL LETTER ZHEE
+    ('\u{1044B}', CharMapAction::SubChar('\u{0254}')), // DESERET SMALL LETTER EM
+    ('\u{1044D}', CharMapAction::SubChar('\u{1D0E}')), // DESERET SMALL LETTER ENG
+    ('\u{104A0}', CharMapAction::SubChar('\u{10486}')), // OSMANYA DIGIT ZERO
+    ('\u{104B0}', CharMapAction::SubChar('\u{0245}')), // OSAGE CAPITAL LETTER A
+    ('\u{104B4}', CharMapAction::SubChar('R')), // OSAGE CAPITAL LETTER BRA
+    ('\u{104BC}', CharMapAction::SubChar('\u{04C3}')), // OSAGE CAPITAL LETTER KA
+    ('\u{104C2}', CharMapAction::SubChar('O')), // OSAGE CAPITAL LETTER O
+    ('\u{104C3}', CharMapAction::SubChar('\u{0298}')), // OSAGE CAPITAL LETTER OIN
+    ('\u{104C4}', CharMapAction::SubChar('\u{00DE}')), // OSAGE CAPITAL LETTER PA
+    ('\u{104CD}', CharMapAction::SubChar('\u{040B}')), // OSAGE CAPITAL LETTER DHA
+    ('\u{104CE}', CharMapAction::SubChar('U')), // OSAGE CAPITAL LETTER U
+    ('\u{104D0}', CharMapAction::SubChar('\u{16E6}')), // OSAGE CAPITAL LETTER KHA
+    ('\u{104D1}', CharMapAction::SubChar('\u{03A8}')), // OSAGE CAPITAL LETTER GHA
+    ('\u{104D2}', CharMapAction::SubChar('7')), // OSAGE CAPITAL LETTER ZA
+    ('\u{104D8}', CharMapAction::SubChar('\u{028C}')), // OSAGE SMALL LETTER A
+    ('\u{104DB}', CharMapAction::SubChar('\u{03BB}')), // OSAGE SMALL LETTER AH
+    ('\u{104EA}', CharMapAction::SubChar('o')), // OSAGE SMALL LETTER O
+    ('\u{104EB}', CharMapAction::SubChar('\u{A669}')), // OSAGE SMALL LETTER OIN
+    ('\u{104F6}', CharMapAction::SubChar('u')), // OSAGE SMALL LETTER U
+    ('\u{104F9}', CharMapAction::SubChar('\u{03C8}')), // OSAGE SMALL LETTER GHA
+    ('\u{10513}', CharMapAction::SubChar('N')), // ELBASAN LETTER NE
+    ('\u{10516}', CharMapAction::SubChar('O')), // ELBASAN LETTER O
+    ('\u{10518}', CharMapAction::SubChar('K')), // ELBASAN LETTER QE
+    ('\u{1051C}', CharMapAction::SubChar('C')), // ELBASAN LETTER SHE
+    ('\u{1051D}', CharMapAction::SubChar('V')), // ELBASAN LETTER TE
+    ('\u{10525}', CharMapAction::SubChar('F')), // ELBASAN LETTER GHE
+    ('\u{10526}', CharMapAction::SubChar('L')), // ELBASAN LETTER GHAMMA
+    ('\u{10527}', CharMapAction::SubChar('X')), // ELBASAN LETTER KHE
+    ('\u{10A3A}', CharMapAction::SubChar('\u{0323}')), // KHAROSHTHI SIGN DOT BELOW
+    ('\u{10A50}', CharMapAction::SubChar('.')), // KHAROSHTHI PUNCTUATION DOT
+    ('\u{10A57}', CharMapAction::SubStr("\u{10A56}\u{10A56}")), // KHAROSHTHI PUNCTUATION DOUBLE DANDA
+    ('\u{10CFA}', CharMapAction::SubChar('\u{10CA5}')), // OLD HUNGARIAN NUMBER ONE
+    ('\u{10CFC}', CharMapAction::SubChar('\u{10C82}')), // OLD HUNGARIAN NUMBER TEN
+    ('\u{110BB}', CharMapAction::SubChar('\u{0970}')), // KAITHI ABBREVIATION SIGN
+    ('\u{111C7}', CharMapAction::SubChar('\u{0970}')), // SHARADA ABBREVIATION SIGN
+    ('\u{111CA}', CharMapAction::SubChar('\u{0323}')), // SHARADA SIGN NUKTA
+    ('\u{111CB}', CharMapAction::SubChar('\u{093A}')), // SHARADA VOWEL MODIFIER MARK
+    ('\u{111DB}', CharMapAction::SubChar('\u{A8FC}')), // SHARADA SIGN SIDDHAM
+    ('\u{111DC}', CharMapAction::SubChar('\u{A8FB}')), // SHARADA HEADSTROKE
+    ('\u{111DE}', CharMapAction::SubChar('\u{2248}')), // SHARADA SECTION MARK-1
+    ('\u{11300}', CharMapAction::SubChar('\u{030A}')), // GRANTHA SIGN COMBINING ANUSVARA ABOVE
+    ('\u{11413}', CharMapAction::SubStr("\u{11434}\u{11442}\u{11412}")), // NEWA LETTER NGHA
+    ('\u{11419}', CharMapAction::SubStr("\u{11434}\u{11442}\u{11418}")), // NEWA LETTER NYHA
+    ('\u{11424}', CharMapAction::SubStr("\u{11434}\u{11442}\u{11423}")), // NEWA LETTER NHA
+    ('\u{1142A}', CharMapAction::SubStr("\u{11434}\u{11442}\u{11429}")), // NEWA LETTER MHA
+    ('\u{1142D}', CharMapAction::SubStr("\u{11434}\u{11442}\u{1142C}")), // NEWA LETTER RHA
+    ('\u{1142F}', CharMapAction::SubStr("\u{11434}\u{11442}\u{1142E}")), // NEWA LETTER LHA
+    ('\u{1144C}', CharMapAction::SubStr("\u{1144B}\u{1144B}")), // NEWA DOUBLE DANDA
+    ('\u{11492}', CharMapAction::SubChar('\u{0998}')), // TIRHUTA LETTER GHA
+    ('\u{11494}', CharMapAction::SubChar('\u{099A}')), // TIRHUTA LETTER CA
+    ('\u{11496}', CharMapAction::SubChar('\u{099C}')), // TIRHUTA LETTER JA
+    ('\u{11498}', CharMapAction::SubChar('\u{099E}')), // TIRHUTA LETTER NYA
+    ('\u{11499}', CharMapAction::SubChar('\u{099F}')), // TIRHUTA LETTER TTA
+    ('\u{1149B}', CharMapAction::SubChar('\u{09A1}')), // TIRHUTA LETTER DDA
+    ('\u{1149D}', CharMapAction::SubChar('\u{09B2}')), // TIRHUTA LETTER NNA
+    ('\u{1149E}', CharMapAction::SubChar('\u{09A4}')), // TIRHUTA LETTER TA
+    ('\u{1149F}', CharMapAction::SubChar('\u{09A5}')), // TIRHUTA LETTER THA
+    ('\u{114A0}', CharMapAction::SubChar('\u{09A6}')), // TIRHUTA LETTER DA
+    ('\u{114A1}', CharMapAction::SubChar('\u{09A7}')), // TIRHUTA LETTER DHA
+    ('\u{114A2}', CharMapAction::SubChar('\u{09A8}')), // TIRHUTA LETTER NA
+    ('\u{114A3}', CharMapAction::SubChar('\u{09AA}')), // TIRHUTA LETTER PA
+    ('\u{114A7}', CharMapAction::SubChar('\u{09AE}')), // TIRHUTA LETTER MA
+    ('\u{114A8}', CharMapAction::SubChar('\u{09AF}')), // TIRHUTA LETTER YA
+    ('\u{114A9}', CharMapAction::SubChar('\u{09AC}')), // TIRHUTA LETTER RA
+    ('\u{114AA}', CharMapAction::SubChar('\u{09A3}')), // TIRHUTA LETTER LA
+    ('\u{114AB}', CharMapAction::SubChar('\u{09B0}')), // TIRHUTA LETTER VA
+    ('\u{114AD}', CharMapAction::SubChar('\u{09B7}')), // TIRHUTA LETTER SSA
+    ('\u{114AE}', CharMapAction::SubChar('\u{09B8}')), // TIRHUTA LETTER SA
+    ('\u{114B0}', CharMapAction::SubChar('\u{09BE}')), // TIRHUTA VOWEL SIGN AA
+    ('\u{114B1}', CharMapAction::SubChar('\u{09BF}')), // TIRHUTA VOWEL SIGN I
+    ('\u{114B9}', CharMapAction::SubChar('\u{09C7}')), // TIRHUTA VOWEL SIGN E
+    ('\u{114BC}', CharMapAction::SubChar('\u{09CB}')), // TIRHUTA VOWEL SIGN O
+    ('\u{114BD}', CharMapAction::SubChar('\u{09D7}')), // TIRHUTA VOWEL SIGN SHORT O
+    ('\u{114BE}', CharMapAction::SubChar('\u{09CC}')), // TIRHUTA VOWEL SIGN AU
+    ('\u{114BF}', CharMapAction::SubStr("\u{0306}\u{0307}")), // TIRHUTA SIGN CANDRABINDU
+    ('\u{114C1}', CharMapAction::SubChar('\u{0983}')), // TIRHUTA SIGN VISARGA
+    ('\u{114C2}', CharMapAction::SubChar('\u{09CD}')), // TIRHUTA SIGN VIRAMA
+    ('\u{114C3}', CharMapAction::SubChar('\u{0323}')), // TIRHUTA SIGN NUKTA
+    ('\u{114C4}', CharMapAction::SubChar('\u{09BD}')), // TIRHUTA SIGN AVAGRAHA
+    ('\u{114C5}', CharMapAction::SubStr("w\u{0307}")), // TIRHUTA GVANG
+    ('\u{114D0}', CharMapAction::SubChar('O')), // TIRHUTA DIGIT ZERO
+    ('\u{114D1}', CharMapAction::SubChar('\u{09E7}')), // TIRHUTA DIGIT ONE
+    ('\u{114D2}', CharMapAction::SubChar('\u{09E8}')), // TIRHUTA DIGIT TWO
+    ('\u{114D6}', CharMapAction::SubChar('\u{09EC}')), // TIRHUTA DIGIT SIX
+    ('\u{115D8}', CharMapAction::SubChar('\u{11582}')), // SIDDHAM LETTER THREE-CIRCLE ALTERNATE I
+    ('\u{115D9}', CharMapAction::SubChar('\u{11582}')), // SIDDHAM LETTER TWO-CIRCLE ALTERNATE I
+    ('\u{115DA}', CharMapAction::SubChar('\u{11583}')), // SIDDHAM LETTER TWO-CIRCLE ALTERNATE II
+    ('\u{115DB}', CharMapAction::SubChar('\u{11584}')), // SIDDHAM LETTER ALTERNATE U
+    ('\u{115DC}', CharMapAction::SubChar('\u{115B2}')), // SIDDHAM VOWEL SIGN ALTERNATE U
+    ('\u{115DD}', CharMapAction::SubChar('\u{115B3}')), // SIDDHAM VOWEL SIGN ALTERNATE UU
+    ('\u{11642}', CharMapAction::SubStr("\u{11641}\u{11641}")), // MODI DOUBLE DANDA
+    ('\u{11700}', CharMapAction::SubStr("rn")), // AHOM LETTER KA
+    ('\u{11706}', CharMapAction::SubChar('v')), // AHOM LETTER PA
+    ('\u{1170A}', CharMapAction::SubChar('w')), // AHOM LETTER JA
+    ('\u{1170E}', CharMapAction::SubChar('w')), // AHOM LETTER LA
+    ('\u{1170F}', CharMapAction::SubChar('w')), // AHOM LETTER SA
+    ('\u{118A0}', CharMapAction::SubChar('V')), // WARANG CITI CAPITAL LETTER NGAA
+    ('\u{118A2}', CharMapAction::SubChar('F')), // WARANG CITI CAPITAL LETTER WI
+    ('\u{118A3}', CharMapAction::SubChar('L')), // WARANG CITI CAPITAL LETTER YU
+    ('\u{118A4}', CharMapAction::SubChar('Y')), // WARANG CITI CAPITAL LETTER YA
+    ('\u{118A6}', CharMapAction::SubChar('E')), // WARANG CITI CAPITAL LETTER II
+    ('\u{118A8}', CharMapAction::SubChar('\u{2207}')), // WARANG CITI CAPITAL LETTER E
+    ('\u{118A9}', CharMapAction::SubChar('Z')), // WARANG CITI CAPITAL LETTER O
+    ('\u{118AC}', CharMapAction::SubChar('9')), // WARANG CITI CAPITAL LETTER KO
+    ('\u{118AE}', CharMapAction::SubChar('E')), // WARANG CITI CAPITAL LETTER YUJ
+    ('\u{118AF}', CharMapAction::SubChar('4')), // WARANG CITI CAPITAL LETTER UC
+    ('\u{118B2}', CharMapAction::SubChar('L')), // WARANG CITI CAPITAL LETTER TTE
+    ('\u{118B5}', CharMapAction::SubChar('O')), // WARANG CITI CAPITAL LETTER AT
+    ('\u{118B7}', CharMapAction::SubChar('\u{16DC}')), // WARANG CITI CAPITAL LETTER BU
+    ('\u{118B8}', CharMapAction::SubChar('U')), // WARANG CITI CAPITAL LETTER PU
+    ('\u{118BB}', CharMapAction::SubChar('5')), // WARANG CITI CAPITAL LETTER HORR
+    ('\u{118BC}', CharMapAction::SubChar('T')), // WARANG CITI CAPITAL LETTER HAR
+    ('\u{118C0}', CharMapAction::SubChar('v')), // WARANG CITI SMALL LETTER NGAA
+    ('\u{118C1}', CharMapAction::SubChar('s')), // WARANG CITI SMALL LETTER A
+    ('\u{118C2}', CharMapAction::SubChar('F')), // WARANG CITI SMALL LETTER WI
+    ('\u{118C3}', CharMapAction::SubChar('i')), // WARANG CITI SMALL LETTER YU
+    ('\u{118C4}', CharMapAction::SubChar('z')), // WARANG CITI SMALL LETTER YA
+    ('\u{118C6}', CharMapAction::SubChar('7')), // WARANG CITI SMALL LETTER II
+    ('\u{118C8}', CharMapAction::SubChar('o')), // WARANG CITI SMALL LETTER E
+    ('\u{118CA}', CharMapAction::SubChar('3')), // WARANG CITI SMALL LETTER ANG
+    ('\u{118CC}', CharMapAction::SubChar('9')), // WARANG CITI SMALL LETTER KO
+    ('\u{118CE}', CharMapAction::SubChar('\u{A793}')), // WARANG CITI SMALL LETTER YUJ
+    ('\u{118D5}', CharMapAction::SubChar('6')), // WARANG CITI SMALL LETTER AT
+    ('\u{118D6}', CharMapAction::SubChar('9')), // WARANG CITI SMALL LETTER AM
+    ('\u{118D7}', CharMapAction::SubChar('o')), // WARANG CITI SMALL LETTER BU
+    ('\u{118D8}', CharMapAction::SubChar('u')), // WARANG CITI SMALL LETTER PU
+    ('\u{118DC}', CharMapAction::SubChar('y')), // WARANG CITI SMALL LETTER HAR
+    ('\u{118E0}', CharMapAction::SubChar('O')), // WARANG CITI DIGIT ZERO
+    ('\u{118E3}', CharMapAction::SubStr("rn")), // WARANG CITI DIGIT THREE
+    ('\u{118E4}', CharMapAction::SubChar('\u{0669}')), // WARANG CITI DIGIT FOUR
+    ('\u{118E5}', CharMapAction::SubChar('Z')), // WARANG CITI DIGIT FIVE
+    ('\u{118E6}', CharMapAction::SubChar('W')), // WARANG CITI DIGIT SIX
+    ('\u{118E9}', CharMapAction::SubChar('C')), // WARANG CITI DIGIT NINE
+    ('\u{118EC}', CharMapAction::SubChar('X')), // WARANG CITI NUMBER THIRTY
+    ('\u{118EF}', CharMapAction::SubChar('W')), // WARANG CITI NUMBER SIXTY
+    ('\u{118F2}', CharMapAction::SubChar('C')), // WARANG CITI NUMBER NINETY
+    ('\u{11AE6}', CharMapAction::SubStr("\u{11AE5}\u{11AEF}")), // PAU CIN HAU RISING TONE
+    ('\u{11AE7}', CharMapAction::SubStr("\u{11AE5}\u{11AF0}")), // PAU CIN HAU SANDHI GLOTTAL STOP
+    ('\u{11AE8}', CharMapAction::SubStr("\u{11AE5}\u{11AE5}")), // PAU CIN HAU RISING TONE LONG FINAL
+    ('\u{11AE9}', CharMapAction::SubStr("\u{11AE5}\u{11AE5}\u{11AEF}")), // PAU CIN HAU RISING TONE FINAL
+    ('\u{11AEA}', CharMapAction::SubStr("\u{11AE5}\u{11AE5}\u{11AF0}")), // PAU CIN HAU SANDHI GLOTTAL STOP FINAL
+    ('\u{11AEC}', CharMapAction::SubStr("\u{11AEB}\u{11AEF}")), // PAU CIN HAU SANDHI TONE
+    ('\u{11AED}', CharMapAction::SubStr("\u{11AEB}\u{11AEB}")), // PAU CIN HAU SANDHI TONE LONG FINAL
+    ('\u{11AEE}', CharMapAction::SubStr("\u{11AEB}\u{11AEB}\u{11AEF}")), // PAU CIN HAU SANDHI TONE FINAL
+    ('\u{11AF4}', CharMapAction::SubStr("\u{11AF3}\u{11AEF}")), // PAU CIN HAU LOW-FALLING TONE
+    ('\u{11AF5}', CharMapAction::SubStr("\u{11AF3}\u{11AF0}")), // PAU CIN HAU GLOTTAL STOP
+    ('\u{11AF6}', CharMapAction::SubStr("\u{11AF3}\u{11AF3}")), // PAU CIN HAU LOW-FALLING TONE LONG FINAL
+    ('\u{11AF7}', CharMapAction::SubStr("\u{11AF3}\u{11AF3}\u{11AEF}")), // PAU CIN HAU LOW-FALLING TONE FINAL
+    ('\u{11AF8}', CharMapAction::SubStr("\u{11AF3}\u{11AF3}\u{11AF0}")), // PAU CIN HAU GLOTTAL STOP FINAL
+    ('\u{11C42}', CharMapAction::SubStr("\u{11C41}\u{11C41}")), // BHAIKSUKI DOUBLE DANDA
+    ('\u{11CB2}', CharMapAction::SubChar('\u{11CAA}')), // MARCHEN VOWEL SIGN U
+    ('\u{12038}', CharMapAction::SubChar('\u{1039A}')), // CUNEIFORM SIGN ASH
+    ('\u{132F9}', CharMapAction::SubChar('\u{1099E}')), // EGYPTIAN HIEROGLYPH S034
+    ('\u{16F07}', CharMapAction::SubChar('\u{0393}')), // MIAO LETTER FA
+    ('\u{16F08}', CharMapAction::SubChar('V')), // MIAO LETTER VA
+    ('\u{16F0A}', CharMapAction::SubChar('T')), // MIAO LETTER TA
+    ('\u{16F16}', CharMapAction::SubChar('L')), // MIAO LETTER LA
+    ('\u{16F1A}', CharMapAction::SubChar('\u{0394}')), // MIAO LETTER TLHA
+    ('\u{16F1C}', CharMapAction::SubChar('\u{A658}')), // MIAO LETTER TLHYA
+    ('\u{16F26}', CharMapAction::SubChar('\u{A4F6}')), // MIAO LETTER HA
+    ('\u{16F28}', CharMapAction::SubChar('l')), // MIAO LETTER GHA
+    ('\u{16F2D}', CharMapAction::SubChar('\u{0190}')), // MIAO LETTER NYHA
+    ('\u{16F35}', CharMapAction::SubChar('R')), // MIAO LETTER ZHA
+    ('\u{16F3A}', CharMapAction::SubChar('S')), // MIAO LETTER SA
+    ('\u{16F3B}', CharMapAction::SubChar('3')), // MIAO LETTER ZA
+    ('\u{16F3D}', CharMapAction::SubChar('\u{0245}')), // MIAO LETTER ZZA
+    ('\u{16F3F}', CharMapAction::SubChar('>')), // MIAO LETTER ARCHAIC ZZA
+    ('\u{16F40}', CharMapAction::SubChar('A')), // MIAO LETTER ZZYA
+    ('\u{16F42}', CharMapAction::SubChar('U')), // MIAO LETTER WA
+    ('\u{16F43}', CharMapAction::SubChar('Y')), // MIAO LETTER AH
+    ('\u{16F51}', CharMapAction::SubChar('\'')), // MIAO SIGN ASPIRATION
+    ('\u{16F52}', CharMapAction::SubChar('\'')), // MIAO SIGN REFORMED VOICING
+    ('\u{1CCD6}', CharMapAction::SubChar('A')),
+    ('\u{1CCD7}', CharMapAction::SubChar('B')),
+    ('\u{1CCD8}', CharMapAction::SubChar('C')),
+    ('\u{1CCD9}', CharMapAction::SubChar('D')),
+    ('\u{1CCDA}', CharMapAction::SubChar('E')),
+    ('\u{1CCDB}', CharMapAction::SubChar('F')),
+    ('\u{1CCDC}', CharMapAction::SubChar('G')),
+    ('\u{1CCDD}', CharMapAction::SubChar('H')),
+    ('\u{1CCDE}', CharMapAction::SubChar('l')),
+    ('\u{1CCDF}', CharMapAction::SubChar('J')),
+    ('\u{1CCE0}', CharMapAction::SubChar('K')),
+    ('\u{1CCE1}', CharMapAction::SubChar('L')),
+    ('\u{1CCE2}', CharMapAction::SubChar('M')),
+    ('\u{1CCE3}', CharMapAction::SubChar('N')),
+    ('\u{1CCE4}', CharMapAction::SubChar('O')),
+    ('\u{1CCE5}', CharMapAction::SubChar('P')),
+    ('\u{1CCE6}', CharMapAction::SubChar('Q')),
+    ('\u{1CCE7}', CharMapAction::SubChar('R')),
+    ('\u{1CCE8}', CharMapAction::SubChar('S')),
+    ('\u{1CCE9}', CharMapAction::SubChar('T')),
+    ('\u{1CCEA}', CharMapAction::SubChar('U')),
+    ('\u{1CCEB}', CharMapAction::SubChar('V')),
+    ('\u{1CCEC}', CharMapAction::SubChar('W')),
+    ('\u{1CCED}', CharMapAction::SubChar('X')),
+    ('\u{1CCEE}', CharMapAction::SubChar('Y')),
+    ('\u{1CCEF}', CharMapAction::SubChar('Z')),
+    ('\u{1CCF0}', CharMapAction::SubChar('O')),
+    ('\u{1CCF1}', CharMapAction::SubChar('l')),
+    ('\u{1CCF2}', CharMapAction::SubChar('2')),
+    ('\u{1CCF3}', CharMapAction::SubChar('3')),
+    ('\u{1CCF4}', CharMapAction::SubChar('4')),
+    ('\u{1CCF5}', CharMapAction::SubChar('5')),
+    ('\u{1CCF6}', CharMapAction::SubChar('6')),
+    ('\u{1CCF7}', CharMapAction::SubChar('7')),
+    ('\u{1CCF8}', CharMapAction::SubChar('8')),
+    ('\u{1CCF9}', CharMapAction::SubChar('9')),
+    ('\u{1D114}', CharMapAction::SubChar('{')), // MUSICAL SYMBOL BRACE
+    ('\u{1D16D}', CharMapAction::SubChar('.')), // MUSICAL SYMBOL COMBINING AUGMENTATION DOT
+    ('\u{1D202}', CharMapAction::SubChar('\u{04FE}')), // GREEK VOCAL NOTATION SYMBOL-3
+    ('\u{1D206}', CharMapAction::SubChar('3')), // GREEK VOCAL NOTATION SYMBOL-7
+    ('\u{1D20B}', CharMapAction::SubChar('\u{0418}')), // GREEK VOCAL NOTATION SYMBOL-12
+    ('\u{1D20D}', CharMapAction::SubChar('V')), // GREEK VOCAL NOTATION SYMBOL-14
+    ('\u{1D20F}', CharMapAction::SubChar('\\')), // GREEK VOCAL NOTATION SYMBOL-16
+    ('\u{1D212}', CharMapAction::SubChar('7')), // GREEK VOCAL NOTATION SYMBOL-19
+    ('\u{1D213}', CharMapAction::SubChar('F')), // GREEK VOCAL NOTATION SYMBOL-20
+    ('\u{1D214}', CharMapAction::SubChar('\u{102BC}')), // GREEK VOCAL NOTATION SYMBOL-21
+    ('\u{1D215}', CharMapAction::SubChar('\u{A4F6}')), // GREEK VOCAL NOTATION SYMBOL-22
+    ('\u{1D216}', CharMapAction::SubChar('R')), // GREEK VOCAL NOTATION SYMBOL-23
+    ('\u{1D217}', CharMapAction::SubChar('\u{2C6F}')), // GREEK VOCAL NOTATION SYMBOL-24
+    ('\u{1D21A}', CharMapAction::SubStr("O\u{0335}")), // GREEK VOCAL NOTATION SYMBOL-52
+    ('\u{1D21B}', CharMapAction::SubChar('\u{2144}')), // GREEK VOCAL NOTATION SYMBOL-53
+    ('\u{1D21C}', CharMapAction::SubChar('\u{A4D5}')), // GREEK VOCAL NOTATION SYMBOL-54
+    ('\u{1D221}', CharMapAction::SubChar('\u{0190}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-7
+    ('\u{1D222}', CharMapAction::SubChar('\u{0460}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-8
+    ('\u{1D22A}', CharMapAction::SubChar('L')), // GREEK INSTRUMENTAL NOTATION SYMBOL-23
+    ('\u{1D22B}', CharMapAction::SubChar('\u{A4F6}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-24
+    ('\u{1D230}', CharMapAction::SubChar('\u{A7FB}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-30
+    ('\u{1D236}', CharMapAction::SubChar('<')), // GREEK INSTRUMENTAL NOTATION SYMBOL-40
+    ('\u{1D237}', CharMapAction::SubChar('>')), // GREEK INSTRUMENTAL NOTATION SYMBOL-42
+    ('\u{1D238}', CharMapAction::SubChar('\u{228F}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-43
+    ('\u{1D239}', CharMapAction::SubChar('\u{2290}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-45
+    ('\u{1D23A}', CharMapAction::SubChar('/')), // GREEK INSTRUMENTAL NOTATION SYMBOL-47
+    ('\u{1D23B}', CharMapAction::SubChar('\\')), // GREEK INSTRUMENTAL NOTATION SYMBOL-48
+    ('\u{1D23F}', CharMapAction::SubChar('\u{16CB}')), // GREEK INSTRUMENTAL NOTATION SYMBOL-52
+    ('\u{1D245}', CharMapAction::SubChar('\u{0548}')), // GREEK MUSICAL LEIMMA
+    ('\u{1D400}', CharMapAction::SubChar('A')), // MATHEMATICAL BOLD CAPITAL A
+    ('\u{1D401}', CharMapAction::SubChar('B')), // MATHEMATICAL BOLD CAPITAL B
+    ('\u{1D402}', CharMapAction::SubChar('C')), // MATHEMATICAL BOLD CAPITAL C
+    ('\u{1D403}', CharMapAction::SubChar('D')), // MATHEMATICAL BOLD CAPITAL D
+    ('\u{1D404}', CharMapAction::SubChar('E')), // MATHEMATICAL BOLD CAPITAL E
+    ('\u{1D405}', CharMapAction::SubChar('F')), // MATHEMATICAL BOLD CAPITAL F
+    ('\u{1D406}', CharMapAction::SubChar('G')), // MATHEMATICAL BOLD CAPITAL G
+    ('\u{1D407}', CharMapAction::SubChar('H')), // MATHEMATICAL BOLD CAPITAL H
+    ('\u{1D408}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD CAPITAL I
+    ('\u{1D409}', CharMapAction::SubChar('J')), // MATHEMATICAL BOLD CAPITAL J
+    ('\u{1D40A}', CharMapAction::SubChar('K')), // MATHEMATICAL BOLD CAPITAL K
+    ('\u{1D40B}', CharMapAction::SubChar('L')), // MATHEMATICAL BOLD CAPITAL L
+    ('\u{1D40C}', CharMapAction::SubChar('M')), // MATHEMATICAL BOLD CAPITAL M
+    ('\u{1D40D}', CharMapAction::SubChar('N')), // MATHEMATICAL BOLD CAPITAL N
+    ('\u{1D40E}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD CAPITAL O
+    ('\u{1D40F}', CharMapAction::SubChar('P')), // MATHEMATICAL BOLD CAPITAL P
+    ('\u{1D410}', CharMapAction::SubChar('Q')), // MATHEMATICAL BOLD CAPITAL Q
+    ('\u{1D411}', CharMapAction::SubChar('R')), // MATHEMATICAL BOLD CAPITAL R
+    ('\u{1D412}', CharMapAction::SubChar('S')), // MATHEMATICAL BOLD CAPITAL S
+    ('\u{1D413}', CharMapAction::SubChar('T')), // MATHEMATICAL BOLD CAPITAL T
+    ('\u{1D414}', CharMapAction::SubChar('U')), // MATHEMATICAL BOLD CAPITAL U
+    ('\u{1D415}', CharMapAction::SubChar('V')), // MATHEMATICAL BOLD CAPITAL V
+    ('\u{1D416}', CharMapAction::SubChar('W')), // MATHEMATICAL BOLD CAPITAL W
+    ('\u{1D417}', CharMapAction::SubChar('X')), // MATHEMATICAL BOLD CAPITAL X
+    ('\u{1D418}', CharMapAction::SubChar('Y')), // MATHEMATICAL BOLD CAPITAL Y
+    ('\u{1D419}', CharMapAction::SubChar('Z')), // MATHEMATICAL BOLD CAPITAL Z
+    ('\u{1D41A}', CharMapAction::SubChar('a')), // MATHEMATICAL BOLD SMALL A
+    ('\u{1D41B}', CharMapAction::SubChar('b')), // MATHEMATICAL BOLD SMALL B
+    ('\u{1D41C}', CharMapAction::SubChar('c')), // MATHEMATICAL BOLD SMALL C
+    ('\u{1D41D}', CharMapAction::SubChar('d')), // MATHEMATICAL BOLD SMALL D
+    ('\u{1D41E}', CharMapAction::SubChar('e')), // MATHEMATICAL BOLD SMALL E
+    ('\u{1D41F}', CharMapAction::SubChar('f')), // MATHEMATICAL BOLD SMALL F
+    ('\u{1D420}', CharMapAction::SubChar('g')), // MATHEMATICAL BOLD SMALL G
+    ('\u{1D421}', CharMapAction::SubChar('h')), // MATHEMATICAL BOLD SMALL H
+    ('\u{1D422}', CharMapAction::SubChar('i')), // MATHEMATICAL BOLD SMALL I
+    ('\u{1D423}', CharMapAction::SubChar('j')), // MATHEMATICAL BOLD SMALL J
+    ('\u{1D424}', CharMapAction::SubChar('k')), // MATHEMATICAL BOLD SMALL K
+    ('\u{1D425}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD SMALL L
+    ('\u{1D426}', CharMapAction::SubStr("rn")), // MATHEMATICAL BOLD SMALL M
+    ('\u{1D427}', CharMapAction::SubChar('n')), // MATHEMATICAL BOLD SMALL N
+    ('\u{1D428}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD SMALL O
+    ('\u{1D429}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD SMALL P
+    ('\u{1D42A}', CharMapAction::SubChar('q')), // MATHEMATICAL BOLD SMALL Q
+    ('\u{1D42B}', CharMapAction::SubChar('r')), // MATHEMATICAL BOLD SMALL R
+    ('\u{1D42C}', CharMapAction::SubChar('s')), // MATHEMATICAL BOLD SMALL S
+    ('\u{1D42D}', CharMapAction::SubChar('t')), // MATHEMATICAL BOLD SMALL T
+    ('\u{1D42E}', CharMapAction::SubChar('u')), // MATHEMATICAL BOLD SMALL U
+    ('\u{1D42F}', CharMapAction::SubChar('v')), // MATHEMATICAL BOLD SMALL V
+    ('\u{1D430}', CharMapAction::SubChar('w')), // MATHEMATICAL BOLD SMALL W
+    ('\u{1D431}', CharMapAction::SubChar('x')), // MATHEMATICAL BOLD SMALL X
+    ('\u{1D432}', CharMapAction::SubChar('y')), // MATHEMATICAL BOLD SMALL Y
+    ('\u{1D433}', CharMapAction::SubChar('z')), // MATHEMATICAL BOLD SMALL Z
+    ('\u{1D434}', CharMapAction::SubChar('A')), // MATHEMATICAL ITALIC CAPITAL A
+    ('\u{1D435}', CharMapAction::SubChar('B')), // MATHEMATICAL ITALIC CAPITAL B
+    ('\u{1D436}', CharMapAction::SubChar('C')), // MATHEMATICAL ITALIC CAPITAL C
+    ('\u{1D437}', CharMapAction::SubChar('D')), // MATHEMATICAL ITALIC CAPITAL D
+    ('\u{1D438}', CharMapAction::SubChar('E')), // MATHEMATICAL ITALIC CAPITAL E
+    ('\u{1D439}', CharMapAction::SubChar('F')), // MATHEMATICAL ITALIC CAPITAL F
+    ('\u{1D43A}', CharMapAction::SubChar('G')), // MATHEMATICAL ITALIC CAPITAL G
+    ('\u{1D43B}', CharMapAction::SubChar('H')), // MATHEMATICAL ITALIC CAPITAL H
+    ('\u{1D43C}', CharMapAction::SubChar('l')), // MATHEMATICAL ITALIC CAPITAL I
+    ('\u{1D43D}', CharMapAction::SubChar('J')), // MATHEMATICAL ITALIC CAPITAL J
+    ('\u{1D43E}', CharMapAction::SubChar('K')), // MATHEMATICAL ITALIC CAPITAL K
+    ('\u{1D43F}', CharMapAction::SubChar('L')), // MATHEMATICAL ITALIC CAPITAL L
+    ('\u{1D440}', CharMapAction::SubChar('M')), // MATHEMATICAL ITALIC CAPITAL M
+    ('\u{1D441}', CharMapAction::SubChar('N')), // MATHEMATICAL ITALIC CAPITAL N
+    ('\u{1D442}', CharMapAction::SubChar('O')), // MATHEMATICAL ITALIC CAPITAL O
+    ('\u{1D443}', CharMapAction::SubChar('P')), // MATHEMATICAL ITALIC CAPITAL P
+    ('\u{1D444}', CharMapAction::SubChar('Q')), // MATHEMATICAL ITALIC CAPITAL Q
+    ('\u{1D445}', CharMapAction::SubChar('R')), // MATHEMATICAL ITALIC CAPITAL R
+    ('\u{1D446}', CharMapAction::SubChar('S')), // MATHEMATICAL ITALIC CAPITAL S
+    ('\u{1D447}', CharMapAction::SubChar('T')), // MATHEMATICAL ITALIC CAPITAL T
+    ('\u{1D448}', CharMapAction::SubChar('U')), // MATHEMATICAL ITALIC CAPITAL U
+    ('\u{1D449}', CharMapAction::SubChar('V')), // MATHEMATICAL ITALIC CAPITAL V
+    ('\u{1D44A}', CharMapAction::SubChar('W')), // MATHEMATICAL ITALIC CAPITAL W
+    ('\u{1D44B}', CharMapAction::SubChar('X')), // MATHEMATICAL ITALIC CAPITAL X
+    ('\u{1D44C}', CharMapAction::SubChar('Y')), // MATHEMATICAL ITALIC CAPITAL Y
+    ('\u{1D44D}', CharMapAction::SubChar('Z')), // MATHEMATICAL ITALIC CAPITAL Z
+    ('\u{1D44E}', CharMapAction::SubChar('a')), // MATHEMATICAL ITALIC SMALL A
+    ('\u{1D44F}', CharMapAction::SubChar('b')), // MATHEMATICAL ITALIC SMALL B
+    ('\u{1D450}', CharMapAction::SubChar('c')), // MATHEMATICAL ITALIC SMALL C
+    ('\u{1D451}', CharMapAction::SubChar('d')), // MATHEMATICAL ITALIC SMALL D
+    ('\u{1D452}', CharMapAction::SubChar('e')), // MATHEMATICAL ITALIC SMALL E
+    ('\u{1D453}', CharMapAction::SubChar('f')), // MATHEMATICAL ITALIC SMALL F
+    ('\u{1D454}', CharMapAction::SubChar('g')), // MATHEMATICAL ITALIC SMALL G
+    ('\u{1D456}', CharMapAction::SubChar('i')), // MATHEMATICAL ITALIC SMALL I
+    ('\u{1D457}', CharMapAction::SubChar('j')), // MATHEMATICAL ITALIC SMALL J
+    ('\u{1D458}', CharMapAction::SubChar('k')), // MATHEMATICAL ITALIC SMALL K
+    ('\u{1D459}', CharMapAction::SubChar('l')), // MATHEMATICAL ITALIC SMALL L
+    ('\u{1D45A}', CharMapAction::SubStr("rn")), // MATHEMATICAL ITALIC SMALL M
+    ('\u{1D45B}', CharMapAction::SubChar('n')), // MATHEMATICAL ITALIC SMALL N
+    ('\u{1D45C}', CharMapAction::SubChar('o')), // MATHEMATICAL ITALIC SMALL O
+    ('\u{1D45D}', CharMapAction::SubChar('p')), // MATHEMATICAL ITALIC SMALL P
+    ('\u{1D45E}', CharMapAction::SubChar('q')), // MATHEMATICAL ITALIC SMALL Q
+    ('\u{1D45F}', CharMapAction::SubChar('r')), // MATHEMATICAL ITALIC SMALL R
+    ('\u{1D460}', CharMapAction::SubChar('s')), // MATHEMATICAL ITALIC SMALL S
+    ('\u{1D461}', CharMapAction::SubChar('t')), // MATHEMATICAL ITALIC SMALL T
+    ('\u{1D462}', CharMapAction::SubChar('u')), // MATHEMATICAL ITALIC SMALL U
+    ('\u{1D463}', CharMapAction::SubChar('v')), // MATHEMATICAL ITALIC SMALL V
+    ('\u{1D464}', CharMapAction::SubChar('w')), // MATHEMATICAL ITALIC SMALL W
+    ('\u{1D465}', CharMapAction::SubChar('x')), // MATHEMATICAL ITALIC SMALL X
+    ('\u{1D466}', CharMapAction::SubChar('y')), // MATHEMATICAL ITALIC SMALL Y
+    ('\u{1D467}', CharMapAction::SubChar('z')), // MATHEMATICAL ITALIC SMALL Z
+    ('\u{1D468}', CharMapAction::SubChar('A')), // MATHEMATICAL BOLD ITALIC CAPITAL A
+    ('\u{1D469}', CharMapAction::SubChar('B')), // MATHEMATICAL BOLD ITALIC CAPITAL B
+    ('\u{1D46A}', CharMapAction::SubChar('C')), // MATHEMATICAL BOLD ITALIC CAPITAL C
+    ('\u{1D46B}', CharMapAction::SubChar('D')), // MATHEMATICAL BOLD ITALIC CAPITAL D
+    ('\u{1D46C}', CharMapAction::SubChar('E')), // MATHEMATICAL BOLD ITALIC CAPITAL E
+    ('\u{1D46D}', CharMapAction::SubChar('F')), // MATHEMATICAL BOLD ITALIC CAPITAL F
+    ('\u{1D46E}', CharMapAction::SubChar('G')), // MATHEMATICAL BOLD ITALIC CAPITAL G
+    ('\u{1D46F}', CharMapAction::SubChar('H')), // MATHEMATICAL BOLD ITALIC CAPITAL H
+    ('\u{1D470}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD ITALIC CAPITAL I
+    ('\u{1D471}', CharMapAction::SubChar('J')), // MATHEMATICAL BOLD ITALIC CAPITAL J
+    ('\u{1D472}', CharMapAction::SubChar('K')), // MATHEMATICAL BOLD ITALIC CAPITAL K
+    ('\u{1D473}', CharMapAction::SubChar('L')), // MATHEMATICAL BOLD ITALIC CAPITAL L
+    ('\u{1D474}', CharMapAction::SubChar('M')), // MATHEMATICAL BOLD ITALIC CAPITAL M
+    ('\u{1D475}', CharMapAction::SubChar('N')), // MATHEMATICAL BOLD ITALIC CAPITAL N
+    ('\u{1D476}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD ITALIC CAPITAL O
+    ('\u{1D477}', CharMapAction::SubChar('P')), // MATHEMATICAL BOLD ITALIC CAPITAL P
+    ('\u{1D478}', CharMapAction::SubChar('Q')), // MATHEMATICAL BOLD ITALIC CAPITAL Q
+    ('\u{1D479}', CharMapAction::SubChar('R')), // MATHEMATICAL BOLD ITALIC CAPITAL R
+    ('\u{1D47A}', CharMapAction::SubChar('S')), // MATHEMATICAL BOLD ITALIC CAPITAL S
+    ('\u{1D47B}', CharMapAction::SubChar('T')), // MATHEMATICAL BOLD ITALIC CAPITAL T
+    ('\u{1D47C}', CharMapAction::SubChar('U')), // MATHEMATICAL BOLD ITALIC CAPITAL U
+    ('\u{1D47D}', CharMapAction::SubChar('V')), // MATHEMATICAL BOLD ITALIC CAPITAL V
+    ('\u{1D47E}', CharMapAction::SubChar('W')), // MATHEMATICAL BOLD ITALIC CAPITAL W
+    ('\u{1D47F}', CharMapAction::SubChar('X')), // MATHEMATICAL BOLD ITALIC CAPITAL X
+    ('\u{1D480}', CharMapAction::SubChar('Y')), // MATHEMATICAL BOLD ITALIC CAPITAL Y
+    ('\u{1D481}', CharMapAction::SubChar('Z')), // MATHEMATICAL BOLD ITALIC CAPITAL Z
+    ('\u{1D482}', CharMapAction::SubChar('a')), // MATHEMATICAL BOLD ITALIC SMALL A
+    ('\u{1D483}', CharMapAction::SubChar('b')), // MATHEMATICAL BOLD ITALIC SMALL B
+    ('\u{1D484}', CharMapAction::SubChar('c')), // MATHEMATICAL BOLD ITALIC SMALL C
+    ('\u{1D485}', CharMapAction::SubChar('d')), // MATHEMATICAL BOLD ITALIC SMALL D
+    ('\u{1D486}', CharMapAction::SubChar('e')), // MATHEMATICAL BOLD ITALIC SMALL E
+    ('\u{1D487}', CharMapAction::SubChar('f')), // MATHEMATICAL BOLD ITALIC SMALL F
+    ('\u{1D488}', CharMapAction::SubChar('g')), // MATHEMATICAL BOLD ITALIC SMALL G
+    ('\u{1D489}', CharMapAction::SubChar('h')), // MATHEMATICAL BOLD ITALIC SMALL H
+    ('\u{1D48A}', CharMapAction::SubChar('i')), // MATHEMATICAL BOLD ITALIC SMALL I
+    ('\u{1D48B}', CharMapAction::SubChar('j')), // MATHEMATICAL BOLD ITALIC SMALL J
+    ('\u{1D48C}', CharMapAction::SubChar('k')), // MATHEMATICAL BOLD ITALIC SMALL K
+    ('\u{1D48D}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD ITALIC SMALL L
+    ('\u{1D48E}', CharMapAction::SubStr("rn")), // MATHEMATICAL BOLD ITALIC SMALL M
+    ('\u{1D48F}', CharMapAction::SubChar('n')), // MATHEMATICAL BOLD ITALIC SMALL N
+    ('\u{1D490}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD ITALIC SMALL O
+    ('\u{1D491}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD ITALIC SMALL P
+    ('\u{1D492}', CharMapAction::SubChar('q')), // MATHEMATICAL BOLD ITALIC SMALL Q
+    ('\u{1D493}', CharMapAction::SubChar('r')), // MATHEMATICAL BOLD ITALIC SMALL R
+    ('\u{1D494}', CharMapAction::SubChar('s')), // MATHEMATICAL BOLD ITALIC SMALL S
+    ('\u{1D495}', CharMapAction::SubChar('t')), // MATHEMATICAL BOLD ITALIC SMALL T
+    ('\u{1D496}', CharMapAction::SubChar('u')), // MATHEMATICAL BOLD ITALIC SMALL U
+    ('\u{1D497}', CharMapAction::SubChar('v')), // MATHEMATICAL BOLD ITALIC SMALL V
+    ('\u{1D498}', CharMapAction::SubChar('w')), // MATHEMATICAL BOLD ITALIC SMALL W
+    ('\u{1D499}', CharMapAction::SubChar('x')), // MATHEMATICAL BOLD ITALIC SMALL X
+    ('\u{1D49A}', CharMapAction::SubChar('y')), // MATHEMATICAL BOLD ITALIC SMALL Y
+    ('\u{1D49B}', CharMapAction::SubChar('z')), // MATHEMATICAL BOLD ITALIC SMALL Z
+    ('\u{1D49C}', CharMapAction::SubChar('A')), // MATHEMATICAL SCRIPT CAPITAL A
+    ('\u{1D49E}', CharMapAction::SubChar('C')), // MATHEMATICAL SCRIPT CAPITAL C
+    ('\u{1D49F}', CharMapAction::SubChar('D')), // MATHEMATICAL SCRIPT CAPITAL D
+    ('\u{1D4A2}', CharMapAction::SubChar('G')), // MATHEMATICAL SCRIPT CAPITAL G
+    ('\u{1D4A5}', CharMapAction::SubChar('J')), // MATHEMATICAL SCRIPT CAPITAL J
+    ('\u{1D4A6}', CharMapAction::SubChar('K')), // MATHEMATICAL SCRIPT CAPITAL K
+    ('\u{1D4A9}', CharMapAction::SubChar('N')), // MATHEMATICAL SCRIPT CAPITAL N
+    ('\u{1D4AA}', CharMapAction::SubChar('O')), // MATHEMATICAL SCRIPT CAPITAL O
+    ('\u{1D4AB}', CharMapAction::SubChar('P')), // MATHEMATICAL SCRIPT CAPITAL P
+    ('\u{1D4AC}', CharMapAction::SubChar('Q')), // MATHEMATICAL SCRIPT CAPITAL Q
+    ('\u{1D4AE}', CharMapAction::SubChar('S')), // MATHEMATICAL SCRIPT CAPITAL S
+    ('\u{1D4AF}', CharMapAction::SubChar('T')), // MATHEMATICAL SCRIPT CAPITAL T
+    ('\u{1D4B0}', CharMapAction::SubChar('U')), // MATHEMATICAL SCRIPT CAPITAL U
+    ('\u{1D4B1}', CharMapAction::SubChar('V')), // MATHEMATICAL SCRIPT CAPITAL V
+    ('\u{1D4B2}', CharMapAction::SubChar('W')), // MATHEMATICAL SCRIPT CAPITAL W
+    ('\u{1D4B3}', CharMapAction::SubChar('X')), // MATHEMATICAL SCRIPT CAPITAL X
+    ('\u{1D4B4}', CharMapAction::SubChar('Y')), // MATHEMATICAL SCRIPT CAPITAL Y
+    ('\u{1D4B5}', CharMapAction::SubChar('Z')), // MATHEMATICAL SCRIPT CAPITAL Z
+    ('\u{1D4B6}', CharMapAction::SubChar('a')), // MATHEMATICAL SCRIPT SMALL A
+    ('\u{1D4B7}', CharMapAction::SubChar('b')), // MATHEMATICAL SCRIPT SMALL B
+    ('\u{1D4B8}', CharMapAction::SubChar('c')), // MATHEMATICAL SCRIPT SMALL C
+    ('\u{1D4B9}', CharMapAction::SubChar('d')), // MATHEMATICAL SCRIPT SMALL D
+    ('\u{1D4BB}', CharMapAction::SubChar('f')), // MATHEMATICAL SCRIPT SMALL F
+    ('\u{1D4BD}', CharMapAction::SubChar('h')), // MATHEMATICAL SCRIPT SMALL H
+    ('\u{1D4BE}', CharMapAction::SubChar('i')), // MATHEMATICAL SCRIPT SMALL I
+    ('\u{1D4BF}', CharMapAction::SubChar('j')), // MATHEMATICAL SCRIPT SMALL J
+    ('\u{1D4C0}', CharMapAction::SubChar('k')), // MATHEMATICAL SCRIPT SMALL K
+    ('\u{1D4C1}', CharMapAction::SubChar('l')), // MATHEMATICAL SCRIPT SMALL L
+    ('\u{1D4C2}', CharMapAction::SubStr("rn")), // MATHEMATICAL SCRIPT SMALL M
+    ('\u{1D4C3}', CharMapAction::SubChar('n')), // MATHEMATICAL SCRIPT SMALL N
+    ('\u{1D4C5}', CharMapAction::SubChar('p')), // MATHEMATICAL SCRIPT SMALL P
+    ('\u{1D4C6}', CharMapAction::SubChar('q')), // MATHEMATICAL SCRIPT SMALL Q
+    ('\u{1D4C7}', CharMapAction::SubChar('r')), // MATHEMATICAL SCRIPT SMALL R
+    ('\u{1D4C8}', CharMapAction::SubChar('s')), // MATHEMATICAL SCRIPT SMALL S
+    ('\u{1D4C9}', CharMapAction::SubChar('t')), // MATHEMATICAL SCRIPT SMALL T
+    ('\u{1D4CA}', CharMapAction::SubChar('u')), // MATHEMATICAL SCRIPT SMALL U
+    ('\u{1D4CB}', CharMapAction::SubChar('v')), // MATHEMATICAL SCRIPT SMALL V
+    ('\u{1D4CC}', CharMapAction::SubChar('w')), // MATHEMATICAL SCRIPT SMALL W
+    ('\u{1D4CD}', CharMapAction::SubChar('x')), // MATHEMATICAL SCRIPT SMALL X
+    ('\u{1D4CE}', CharMapAction::SubChar('y')), // MATHEMATICAL SCRIPT SMALL Y
+    ('\u{1D4CF}', CharMapAction::SubChar('z')), // MATHEMATICAL SCRIPT SMALL Z
+    ('\u{1D4D0}', CharMapAction::SubChar('A')), // MATHEMATICAL BOLD SCRIPT CAPITAL A
+    ('\u{1D4D1}', CharMapAction::SubChar('B')), // MATHEMATICAL BOLD SCRIPT CAPITAL B
+    ('\u{1D4D2}', CharMapAction::SubChar('C')), // MATHEMATICAL BOLD SCRIPT CAPITAL C
+    ('\u{1D4D3}', CharMapAction::SubChar('D')), // MATHEMATICAL BOLD SCRIPT CAPITAL D
+    ('\u{1D4D4}', CharMapAction::SubChar('E')), // MATHEMATICAL BOLD SCRIPT CAPITAL E
+    ('\u{1D4D5}', CharMapAction::SubChar('F')), // MATHEMATICAL BOLD SCRIPT CAPITAL F
+    ('\u{1D4D6}', CharMapAction::SubChar('G')), // MATHEMATICAL BOLD SCRIPT CAPITAL G
+    ('\u{1D4D7}', CharMapAction::SubChar('H')), // MATHEMATICAL BOLD SCRIPT CAPITAL H
+    ('\u{1D4D8}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD SCRIPT CAPITAL I
+    ('\u{1D4D9}', CharMapAction::SubChar('J')), // MATHEMATICAL BOLD SCRIPT CAPITAL J
+    ('\u{1D4DA}', CharMapAction::SubChar('K')), // MATHEMATICAL BOLD SCRIPT CAPITAL K
+    ('\u{1D4DB}', CharMapAction::SubChar('L')), // MATHEMATICAL BOLD SCRIPT CAPITAL L
+    ('\u{1D4DC}', CharMapAction::SubChar('M')), // MATHEMATICAL BOLD SCRIPT CAPITAL M
+    ('\u{1D4DD}', CharMapAction::SubChar('N')), // MATHEMATICAL BOLD SCRIPT CAPITAL N
+    ('\u{1D4DE}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD SCRIPT CAPITAL O
+    ('\u{1D4DF}', CharMapAction::SubChar('P')), // MATHEMATICAL BOLD SCRIPT CAPITAL P
+    ('\u{1D4E0}', CharMapAction::SubChar('Q')), // MATHEMATICAL BOLD SCRIPT CAPITAL Q
+    ('\u{1D4E1}', CharMapAction::SubChar('R')), // MATHEMATICAL BOLD SCRIPT CAPITAL R
+    ('\u{1D4E2}', CharMapAction::SubChar('S')), // MATHEMATICAL BOLD SCRIPT CAPITAL S
+    ('\u{1D4E3}', CharMapAction::SubChar('T')), // MATHEMATICAL BOLD SCRIPT CAPITAL T
+    ('\u{1D4E4}', CharMapAction::SubChar('U')), // MATHEMATICAL BOLD SCRIPT CAPITAL U
+    ('\u{1D4E5}', CharMapAction::SubChar('V')), // MATHEMATICAL BOLD SCRIPT CAPITAL V
+    ('\u{1D4E6}', CharMapAction::SubChar('W')), // MATHEMATICAL BOLD SCRIPT CAPITAL W
+    ('\u{1D4E7}', CharMapAction::SubChar('X')), // MATHEMATICAL BOLD SCRIPT CAPITAL X
+    ('\u{1D4E8}', CharMapAction::SubChar('Y')), // MATHEMATICAL BOLD SCRIPT CAPITAL Y
+    ('\u{1D4E9}', CharMapAction::SubChar('Z')), // MATHEMATICAL BOLD SCRIPT CAPITAL Z
+    ('\u{1D4EA}', CharMapAction::SubChar('a')), // MATHEMATICAL BOLD SCRIPT SMALL A
+    ('\u{1D4EB}', CharMapAction::SubChar('b')), // MATHEMATICAL BOLD SCRIPT SMALL B
+    ('\u{1D4EC}', CharMapAction::SubChar('c')), // MATHEMATICAL BOLD SCRIPT SMALL C
+    ('\u{1D4ED}', CharMapAction::SubChar('d')), // MATHEMATICAL BOLD SCRIPT SMALL D
+    ('\u{1D4EE}', CharMapAction::SubChar('e')), // MATHEMATICAL BOLD SCRIPT SMALL E
+    ('\u{1D4EF}', CharMapAction::SubChar('f')), // MATHEMATICAL BOLD SCRIPT SMALL F
+    ('\u{1D4F0}', CharMapAction::SubChar('g')), // MATHEMATICAL BOLD SCRIPT SMALL G
+    ('\u{1D4F1}', CharMapAction::SubChar('h')), // MATHEMATICAL BOLD SCRIPT SMALL H
+    ('\u{1D4F2}', CharMapAction::SubChar('i')), // MATHEMATICAL BOLD SCRIPT SMALL I
+    ('\u{1D4F3}', CharMapAction::SubChar('j')), // MATHEMATICAL BOLD SCRIPT SMALL J
+    ('\u{1D4F4}', CharMapAction::SubChar('k')), // MATHEMATICAL BOLD SCRIPT SMALL K
+    ('\u{1D4F5}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD SCRIPT SMALL L
+    ('\u{1D4F6}', CharMapAction::SubStr("rn")), // MATHEMATICAL BOLD SCRIPT SMALL M
+    ('\u{1D4F7}', CharMapAction::SubChar('n')), // MATHEMATICAL BOLD SCRIPT SMALL N
+    ('\u{1D4F8}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD SCRIPT SMALL O
+    ('\u{1D4F9}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD SCRIPT SMALL P
+    ('\u{1D4FA}', CharMapAction::SubChar('q')), // MATHEMATICAL BOLD SCRIPT SMALL Q
+    ('\u{1D4FB}', CharMapAction::SubChar('r')), // MATHEMATICAL BOLD SCRIPT SMALL R
+    ('\u{1D4FC}', CharMapAction::SubChar('s')), // MATHEMATICAL BOLD SCRIPT SMALL S
+    ('\u{1D4FD}', CharMapAction::SubChar('t')), // MATHEMATICAL BOLD SCRIPT SMALL T
+    ('\u{1D4FE}', CharMapAction::SubChar('u')), // MATHEMATICAL BOLD SCRIPT SMALL U
+    ('\u{1D4FF}', CharMapAction::SubChar('v')), // MATHEMATICAL BOLD SCRIPT SMALL V
+    ('\u{1D500}', CharMapAction::SubChar('w')), // MATHEMATICAL BOLD SCRIPT SMALL W
+    ('\u{1D501}', CharMapAction::SubChar('x')), // MATHEMATICAL BOLD SCRIPT SMALL X
+    ('\u{1D502}', CharMapAction::SubChar('y')), // MATHEMATICAL BOLD SCRIPT SMALL Y
+    ('\u{1D503}', CharMapAction::SubChar('z')), // MATHEMATICAL BOLD SCRIPT SMALL Z
+    ('\u{1D504}', CharMapAction::SubChar('A')), // MATHEMATICAL FRAKTUR CAPITAL A
+    ('\u{1D505}', CharMapAction::SubChar('B')), // MATHEMATICAL FRAKTUR CAPITAL B
+    ('\u{1D507}', CharMapAction::SubChar('D')), // MATHEMATICAL FRAKTUR CAPITAL D
+    ('\u{1D508}', CharMapAction::SubChar('E')), // MATHEMATICAL FRAKTUR CAPITAL E
+    ('\u{1D509}', CharMapAction::SubChar('F')), // MATHEMATICAL FRAKTUR CAPITAL F
+    ('\u{1D50A}', CharMapAction::SubChar('G')), // MATHEMATICAL FRAKTUR CAPITAL G
+    ('\u{1D50D}', CharMapAction::SubChar('J')), // MATHEMATICAL FRAKTUR CAPITAL J
+    ('\u{1D50E}', CharMapAction::SubChar('K')), // MATHEMATICAL FRAKTUR CAPITAL K
+    ('\u{1D50F}', CharMapAction::SubChar('L')), // MATHEMATICAL FRAKTUR CAPITAL L
+    ('\u{1D510}', CharMapAction::SubChar('M')), // MATHEMATICAL FRAKTUR CAPITAL M
+    ('\u{1D511}', CharMapAction::SubChar('N')), // MATHEMATICAL FRAKTUR CAPITAL N
+    ('\u{1D512}', CharMapAction::SubChar('O')), // MATHEMATICAL FRAKTUR CAPITAL O
+    ('\u{1D513}', CharMapAction::SubChar('P')), // MATHEMATICAL FRAKTUR CAPITAL P
+    ('\u{1D514}', CharMapAction::SubChar('Q')), // MATHEMATICAL FRAKTUR CAPITAL Q
+    ('\u{1D516}', CharMapAction::SubChar('S')), // MATHEMATICAL FRAKTUR CAPITAL S
+    ('\u{1D517}', CharMapAction::SubChar('T')), // MATHEMATICAL FRAKTUR CAPITAL T
+    ('\u{1D518}', CharMapAction::SubChar('U')), // MATHEMATICAL FRAKTUR CAPITAL U
+    ('\u{1D519}', CharMapAction::SubChar('V')), // MATHEMATICAL FRAKTUR CAPITAL V
+    ('\u{1D51A}', CharMapAction::SubChar('W')), // MATHEMATICAL FRAKTUR CAPITAL W
+    ('\u{1D51B}', CharMapAction::SubChar('X')), // MATHEMATICAL FRAKTUR CAPITAL X
+    ('\u{1D51C}', CharMapAction::SubChar('Y')), // MATHEMATICAL FRAKTUR CAPITAL Y
+    ('\u{1D51E}', CharMapAction::SubChar('a')), // MATHEMATICAL FRAKTUR SMALL A
+    ('\u{1D51F}', CharMapAction::SubChar('b')), // MATHEMATICAL FRAKTUR SMALL B
+    ('\u{1D520}', CharMapAction::SubChar('c')), // MATHEMATICAL FRAKTUR SMALL C
+    ('\u{1D521}', CharMapAction::SubChar('d')), // MATHEMATICAL FRAKTUR SMALL D
+    ('\u{1D522}', CharMapAction::SubChar('e')), // MATHEMATICAL FRAKTUR SMALL E
+    ('\u{1D523}', CharMapAction::SubChar('f')), // MATHEMATICAL FRAKTUR SMALL F
+    ('\u{1D524}', CharMapAction::SubChar('g')), // MATHEMATICAL FRAKTUR SMALL G
+    ('\u{1D525}', CharMapAction::SubChar('h')), // MATHEMATICAL FRAKTUR SMALL H
+    ('\u{1D526}', CharMapAction::SubChar('i')), // MATHEMATICAL FRAKTUR SMALL I
+    ('\u{1D527}', CharMapAction::SubChar('j')), // MATHEMATICAL FRAKTUR SMALL J
+    ('\u{1D528}', CharMapAction::SubChar('k')), // MATHEMATICAL FRAKTUR SMALL K
+    ('\u{1D529}', CharMapAction::SubChar('l')), // MATHEMATICAL FRAKTUR SMALL L
+    ('\u{1D52A}', CharMapAction::SubStr("rn")), // MATHEMATICAL FRAKTUR SMALL M
+    ('\u{1D52B}', CharMapAction::SubChar('n')), // MATHEMATICAL FRAKTUR SMALL N
+    ('\u{1D52C}', CharMapAction::SubChar('o')), // MATHEMATICAL FRAKTUR SMALL O
+    ('\u{1D52D}', CharMapAction::SubChar('p')), // MATHEMATICAL FRAKTUR SMALL P
+    ('\u{1D52E}', CharMapAction::SubChar('q')), // MATHEMATICAL FRAKTUR SMALL Q
+    ('\u{1D52F}', CharMapAction::SubChar('r')), // MATHEMATICAL FRAKTUR SMALL R
+    ('\u{1D530}', CharMapAction::SubChar('s')), // MATHEMATICAL FRAKTUR SMALL S
+    ('\u{1D531}', CharMapAction::SubChar('t')), // MATHEMATICAL FRAKTUR SMALL T
+    ('\u{1D532}', CharMapAction::SubChar('u')), // MATHEMATICAL FRAKTUR SMALL U
+    ('\u{1D533}', CharMapAction::SubChar('v')), // MATHEMATICAL FRAKTUR SMALL V
+    ('\u{1D534}', CharMapAction::SubChar('w')), // MATHEMATICAL FRAKTUR SMALL W
+    ('\u{1D535}', CharMapAction::SubChar('x')), // MATHEMATICAL FRAKTUR SMALL X
+    ('\u{1D536}', CharMapAction::SubChar('y')), // MATHEMATICAL FRAKTUR SMALL Y
+    ('\u{1D537}', CharMapAction::SubChar('z')), // MATHEMATICAL FRAKTUR SMALL Z
+    ('\u{1D538}', CharMapAction::SubChar('A')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL A
+    ('\u{1D539}', CharMapAction::SubChar('B')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL B
+    ('\u{1D53B}', CharMapAction::SubChar('D')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL D
+    ('\u{1D53C}', CharMapAction::SubChar('E')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL E
+    ('\u{1D53D}', CharMapAction::SubChar('F')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL F
+    ('\u{1D53E}', CharMapAction::SubChar('G')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL G
+    ('\u{1D540}', CharMapAction::SubChar('l')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL I
+    ('\u{1D541}', CharMapAction::SubChar('J')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL J
+    ('\u{1D542}', CharMapAction::SubChar('K')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL K
+    ('\u{1D543}', CharMapAction::SubChar('L')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL L
+    ('\u{1D544}', CharMapAction::SubChar('M')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL M
+    ('\u{1D546}', CharMapAction::SubChar('O')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL O
+    ('\u{1D54A}', CharMapAction::SubChar('S')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL S
+    ('\u{1D54B}', CharMapAction::SubChar('T')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL T
+    ('\u{1D54C}', CharMapAction::SubChar('U')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL U
+    ('\u{1D54D}', CharMapAction::SubChar('V')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL V
+    ('\u{1D54E}', CharMapAction::SubChar('W')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL W
+    ('\u{1D54F}', CharMapAction::SubChar('X')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL X
+    ('\u{1D550}', CharMapAction::SubChar('Y')), // MATHEMATICAL DOUBLE-STRUCK CAPITAL Y
+    ('\u{1D552}', CharMapAction::SubChar('a')), // MATHEMATICAL DOUBLE-STRUCK SMALL A
+    ('\u{1D553}', CharMapAction::SubChar('b')), // MATHEMATICAL DOUBLE-STRUCK SMALL B
+    ('\u{1D554}', CharMapAction::SubChar('c')), // MATHEMATICAL DOUBLE-STRUCK SMALL C
+    ('\u{1D555}', CharMapAction::SubChar('d')), // MATHEMATICAL DOUBLE-STRUCK SMALL D
+    ('\u{1D556}', CharMapAction::SubChar('e')), // MATHEMATICAL DOUBLE-STRUCK SMALL E
+    ('\u{1D557}', CharMapAction::SubChar('f')), // MATHEMATICAL DOUBLE-STRUCK SMALL F
+    ('\u{1D558}', CharMapAction::SubChar('g')), // MATHEMATICAL DOUBLE-STRUCK SMALL G
+    ('\u{1D559}', CharMapAction::SubChar('h')), // MATHEMATICAL DOUBLE-STRUCK SMALL H
+    ('\u{1D55A}', CharMapAction::SubChar('i')), // MATHEMATICAL DOUBLE-STRUCK SMALL I
+    ('\u{1D55B}', CharMapAction::SubChar('j')), // MATHEMATICAL DOUBLE-STRUCK SMALL J
+    ('\u{1D55C}', CharMapAction::SubChar('k')), // MATHEMATICAL DOUBLE-STRUCK SMALL K
+    ('\u{1D55D}', CharMapAction::SubChar('l')), // MATHEMATICAL DOUBLE-STRUCK SMALL L
+    ('\u{1D55E}', CharMapAction::SubStr("rn")), // MATHEMATICAL DOUBLE-STRUCK SMALL M
+    ('\u{1D55F}', CharMapAction::SubChar('n')), // MATHEMATICAL DOUBLE-STRUCK SMALL N
+    ('\u{1D560}', CharMapAction::SubChar('o')), // MATHEMATICAL DOUBLE-STRUCK SMALL O
+    ('\u{1D561}', CharMapAction::SubChar('p')), // MATHEMATICAL DOUBLE-STRUCK SMALL P
+    ('\u{1D562}', CharMapAction::SubChar('q')), // MATHEMATICAL DOUBLE-STRUCK SMALL Q
+    ('\u{1D563}', CharMapAction::SubChar('r')), // MATHEMATICAL DOUBLE-STRUCK SMALL R
+    ('\u{1D564}', CharMapAction::SubChar('s')), // MATHEMATICAL DOUBLE-STRUCK SMALL S
+    ('\u{1D565}', CharMapAction::SubChar('t')), // MATHEMATICAL DOUBLE-STRUCK SMALL T
+    ('\u{1D566}', CharMapAction::SubChar('u')), // MATHEMATICAL DOUBLE-STRUCK SMALL U
+    ('\u{1D567}', CharMapAction::SubChar('v')), // MATHEMATICAL DOUBLE-STRUCK SMALL V
+    ('\u{1D568}', CharMapAction::SubChar('w')), // MATHEMATICAL DOUBLE-STRUCK SMALL W
+    ('\u{1D569}', CharMapAction::SubChar('x')), // MATHEMATICAL DOUBLE-STRUCK SMALL X
+    ('\u{1D56A}', CharMapAction::SubChar('y')), // MATHEMATICAL DOUBLE-STRUCK SMALL Y
+    ('\u{1D56B}', CharMapAction::SubChar('z')), // MATHEMATICAL DOUBLE-STRUCK SMALL Z
+    ('\u{1D56C}', CharMapAction::SubChar('A')), // MATHEMATICAL BOLD FRAKTUR CAPITAL A
+    ('\u{1D56D}', CharMapAction::SubChar('B')), // MATHEMATICAL BOLD FRAKTUR CAPITAL B
+    ('\u{1D56E}', CharMapAction::SubChar('C')), // MATHEMATICAL BOLD FRAKTUR CAPITAL C
+    ('\u{1D56F}', CharMapAction::SubChar('D')), // MATHEMATICAL BOLD FRAKTUR CAPITAL D
+    ('\u{1D570}', CharMapAction::SubChar('E')), // MATHEMATICAL BOLD FRAKTUR CAPITAL E
+    ('\u{1D571}', CharMapAction::SubChar('F')), // MATHEMATICAL BOLD FRAKTUR CAPITAL F
+    ('\u{1D572}', CharMapAction::SubChar('G')), // MATHEMATICAL BOLD FRAKTUR CAPITAL G
+    ('\u{1D573}', CharMapAction::SubChar('H')), // MATHEMATICAL BOLD FRAKTUR CAPITAL H
+    ('\u{1D574}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD FRAKTUR CAPITAL I
+    ('\u{1D575}', CharMapAction::SubChar('J')), // MATHEMATICAL BOLD FRAKTUR CAPITAL J
+    ('\u{1D576}', CharMapAction::SubChar('K')), // MATHEMATICAL BOLD FRAKTUR CAPITAL K
+    ('\u{1D577}', CharMapAction::SubChar('L')), // MATHEMATICAL BOLD FRAKTUR CAPITAL L
+    ('\u{1D578}', CharMapAction::SubChar('M')), // MATHEMATICAL BOLD FRAKTUR CAPITAL M
+    ('\u{1D579}', CharMapAction::SubChar('N')), // MATHEMATICAL BOLD FRAKTUR CAPITAL N
+    ('\u{1D57A}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD FRAKTUR CAPITAL O
+    ('\u{1D57B}', CharMapAction::SubChar('P')), // MATHEMATICAL BOLD FRAKTUR CAPITAL P
+    ('\u{1D57C}', CharMapAction::SubChar('Q')), // MATHEMATICAL BOLD FRAKTUR CAPITAL Q
+    ('\u{1D57D}', CharMapAction::SubChar('R')), // MATHEMATICAL BOLD FRAKTUR CAPITAL R
+    ('\u{1D57E}', CharMapAction::SubChar('S')), // MATHEMATICAL BOLD FRAKTUR CAPITAL S
+    ('\u{1D57F}', CharMapAction::SubChar('T')), // MATHEMATICAL BOLD FRAKTUR CAPITAL T
+    ('\u{1D580}', CharMapAction::SubChar('U')), // MATHEMATICAL BOLD FRAKTUR CAPITAL U
+    ('\u{1D581}', CharMapAction::SubChar('V')), // MATHEMATICAL BOLD FRAKTUR CAPITAL V
+    ('\u{1D582}', CharMapAction::SubChar('W')), // MATHEMATICAL BOLD FRAKTUR CAPITAL W
+    ('\u{1D583}', CharMapAction::SubChar('X')), // MATHEMATICAL BOLD FRAKTUR CAPITAL X
+    ('\u{1D584}', CharMapAction::SubChar('Y')), // MATHEMATICAL BOLD FRAKTUR CAPITAL Y
+    ('\u{1D585}', CharMapAction::SubChar('Z')), // MATHEMATICAL BOLD FRAKTUR CAPITAL Z
+    ('\u{1D586}', CharMapAction::SubChar('a')), // MATHEMATICAL BOLD FRAKTUR SMALL A
+    ('\u{1D587}', CharMapAction::SubChar('b')), // MATHEMATICAL BOLD FRAKTUR SMALL B
+    ('\u{1D588}', CharMapAction::SubChar('c')), // MATHEMATICAL BOLD FRAKTUR SMALL C
+    ('\u{1D589}', CharMapAction::SubChar('d')), // MATHEMATICAL BOLD FRAKTUR SMALL D
+    ('\u{1D58A}', CharMapAction::SubChar('e')), // MATHEMATICAL BOLD FRAKTUR SMALL E
+    ('\u{1D58B}', CharMapAction::SubChar('f')), // MATHEMATICAL BOLD FRAKTUR SMALL F
+    ('\u{1D58C}', CharMapAction::SubChar('g')), // MATHEMATICAL BOLD FRAKTUR SMALL G
+    ('\u{1D58D}', CharMapAction::SubChar('h')), // MATHEMATICAL BOLD FRAKTUR SMALL H
+    ('\u{1D58E}', CharMapAction::SubChar('i')), // MATHEMATICAL BOLD FRAKTUR SMALL I
+    ('\u{1D58F}', CharMapAction::SubChar('j')), // MATHEMATICAL BOLD FRAKTUR SMALL J
+    ('\u{1D590}', CharMapAction::SubChar('k')), // MATHEMATICAL BOLD FRAKTUR SMALL K
+    ('\u{1D591}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD FRAKTUR SMALL L
+    ('\u{1D592}', CharMapAction::SubStr("rn")), // MATHEMATICAL BOLD FRAKTUR SMALL M
+    ('\u{1D593}', CharMapAction::SubChar('n')), // MATHEMATICAL BOLD FRAKTUR SMALL N
+    ('\u{1D594}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD FRAKTUR SMALL O
+    ('\u{1D595}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD FRAKTUR SMALL P
+    ('\u{1D596}', CharMapAction::SubChar('q')), // MATHEMATICAL BOLD FRAKTUR SMALL Q
+    ('\u{1D597}', CharMapAction::SubChar('r')), // MATHEMATICAL BOLD FRAKTUR SMALL R
+    ('\u{1D598}', CharMapAction::SubChar('s')), // MATHEMATICAL BOLD FRAKTUR SMALL S
+    ('\u{1D599}', CharMapAction::SubChar('t')), // MATHEMATICAL BOLD FRAKTUR SMALL T
+    ('\u{1D59A}', CharMapAction::SubChar('u')), // MATHEMATICAL BOLD FRAKTUR SMALL U
+    ('\u{1D59B}', CharMapAction::SubChar('v')), // MATHEMATICAL BOLD FRAKTUR SMALL V
+    ('\u{1D59C}', CharMapAction::SubChar('w')), // MATHEMATICAL BOLD FRAKTUR SMALL W
+    ('\u{1D59D}', CharMapAction::SubChar('x')), // MATHEMATICAL BOLD FRAKTUR SMALL X
+    ('\u{1D59E}', CharMapAction::SubChar('y')), // MATHEMATICAL BOLD FRAKTUR SMALL Y
+    ('\u{1D59F}', CharMapAction::SubChar('z')), // MATHEMATICAL BOLD FRAKTUR SMALL Z
+    ('\u{1D5A0}', CharMapAction::SubChar('A')), // MATHEMATICAL SANS-SERIF CAPITAL A
+    ('\u{1D5A1}', CharMapAction::SubChar('B')), // MATHEMATICAL SANS-SERIF CAPITAL B
+    ('\u{1D5A2}', CharMapAction::SubChar('C')), // MATHEMATICAL SANS-SERIF CAPITAL C
+    ('\u{1D5A3}', CharMapAction::SubChar('D')), // MATHEMATICAL SANS-SERIF CAPITAL D
+    ('\u{1D5A4}', CharMapAction::SubChar('E')), // MATHEMATICAL SANS-SERIF CAPITAL E
+    ('\u{1D5A5}', CharMapAction::SubChar('F')), // MATHEMATICAL SANS-SERIF CAPITAL F
+    ('\u{1D5A6}', CharMapAction::SubChar('G')), // MATHEMATICAL SANS-SERIF CAPITAL G
+    ('\u{1D5A7}', CharMapAction::SubChar('H')), // MATHEMATICAL SANS-SERIF CAPITAL H
+    ('\u{1D5A8}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF CAPITAL I
+    ('\u{1D5A9}', CharMapAction::SubChar('J')), // MATHEMATICAL SANS-SERIF CAPITAL J
+    ('\u{1D5AA}', CharMapAction::SubChar('K')), // MATHEMATICAL SANS-SERIF CAPITAL K
+    ('\u{1D5AB}', CharMapAction::SubChar('L')), // MATHEMATICAL SANS-SERIF CAPITAL L
+    ('\u{1D5AC}', CharMapAction::SubChar('M')), // MATHEMATICAL SANS-SERIF CAPITAL M
+    ('\u{1D5AD}', CharMapAction::SubChar('N')), // MATHEMATICAL SANS-SERIF CAPITAL N
+    ('\u{1D5AE}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF CAPITAL O
+    ('\u{1D5AF}', CharMapAction::SubChar('P')), // MATHEMATICAL SANS-SERIF CAPITAL P
+    ('\u{1D5B0}', CharMapAction::SubChar('Q')), // MATHEMATICAL SANS-SERIF CAPITAL Q
+    ('\u{1D5B1}', CharMapAction::SubChar('R')), // MATHEMATICAL SANS-SERIF CAPITAL R
+    ('\u{1D5B2}', CharMapAction::SubChar('S')), // MATHEMATICAL SANS-SERIF CAPITAL S
+    ('\u{1D5B3}', CharMapAction::SubChar('T')), // MATHEMATICAL SANS-SERIF CAPITAL T
+    ('\u{1D5B4}', CharMapAction::SubChar('U')), // MATHEMATICAL SANS-SERIF CAPITAL U
+    ('\u{1D5B5}', CharMapAction::SubChar('V')), // MATHEMATICAL SANS-SERIF CAPITAL V
+    ('\u{1D5B6}', CharMapAction::SubChar('W')), // MATHEMATICAL SANS-SERIF CAPITAL W
+    ('\u{1D5B7}', CharMapAction::SubChar('X')), // MATHEMATICAL SANS-SERIF CAPITAL X
+    ('\u{1D5B8}', CharMapAction::SubChar('Y')), // MATHEMATICAL SANS-SERIF CAPITAL Y
+    ('\u{1D5B9}', CharMapAction::SubChar('Z')), // MATHEMATICAL SANS-SERIF CAPITAL Z
+    ('\u{1D5BA}', CharMapAction::SubChar('a')), // MATHEMATICAL SANS-SERIF SMALL A
+    ('\u{1D5BB}', CharMapAction::SubChar('b')), // MATHEMATICAL SANS-SERIF SMALL B
+    ('\u{1D5BC}', CharMapAction::SubChar('c')), // MATHEMATICAL SANS-SERIF SMALL C
+    ('\u{1D5BD}', CharMapAction::SubChar('d')), // MATHEMATICAL SANS-SERIF SMALL D
+    ('\u{1D5BE}', CharMapAction::SubChar('e')), // MATHEMATICAL SANS-SERIF SMALL E
+    ('\u{1D5BF}', CharMapAction::SubChar('f')), // MATHEMATICAL SANS-SERIF SMALL F
+    ('\u{1D5C0}', CharMapAction::SubChar('g')), // MATHEMATICAL SANS-SERIF SMALL G
+    ('\u{1D5C1}', CharMapAction::SubChar('h')), // MATHEMATICAL SANS-SERIF SMALL H
+    ('\u{1D5C2}', CharMapAction::SubChar('i')), // MATHEMATICAL SANS-SERIF SMALL I
+    ('\u{1D5C3}', CharMapAction::SubChar('j')), // MATHEMATICAL SANS-SERIF SMALL J
+    ('\u{1D5C4}', CharMapAction::SubChar('k')), // MATHEMATICAL SANS-SERIF SMALL K
+    ('\u{1D5C5}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF SMALL L
+    ('\u{1D5C6}', CharMapAction::SubStr("rn")), // MATHEMATICAL SANS-SERIF SMALL M
+    ('\u{1D5C7}', CharMapAction::SubChar('n')), // MATHEMATICAL SANS-SERIF SMALL N
+    ('\u{1D5C8}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF SMALL O
+    ('\u{1D5C9}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF SMALL P
+    ('\u{1D5CA}', CharMapAction::SubChar('q')), // MATHEMATICAL SANS-SERIF SMALL Q
+    ('\u{1D5CB}', CharMapAction::SubChar('r')), // MATHEMATICAL SANS-SERIF SMALL R
+    ('\u{1D5CC}', CharMapAction::SubChar('s')), // MATHEMATICAL SANS-SERIF SMALL S
+    ('\u{1D5CD}', CharMapAction::SubChar('t')), // MATHEMATICAL SANS-SERIF SMALL T
+    ('\u{1D5CE}', CharMapAction::SubChar('u')), // MATHEMATICAL SANS-SERIF SMALL U
+    ('\u{1D5CF}', CharMapAction::SubChar('v')), // MATHEMATICAL SANS-SERIF SMALL V
+    ('\u{1D5D0}', CharMapAction::SubChar('w')), // MATHEMATICAL SANS-SERIF SMALL W
+    ('\u{1D5D1}', CharMapAction::SubChar('x')), // MATHEMATICAL SANS-SERIF SMALL X
+    ('\u{1D5D2}', CharMapAction::SubChar('y')), // MATHEMATICAL SANS-SERIF SMALL Y
+    ('\u{1D5D3}', CharMapAction::SubChar('z')), // MATHEMATICAL SANS-SERIF SMALL Z
+    ('\u{1D5D4}', CharMapAction::SubChar('A')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL A
+    ('\u{1D5D5}', CharMapAction::SubChar('B')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL B
+    ('\u{1D5D6}', CharMapAction::SubChar('C')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL C
+    ('\u{1D5D7}', CharMapAction::SubChar('D')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL D
+    ('\u{1D5D8}', CharMapAction::SubChar('E')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL E
+    ('\u{1D5D9}', CharMapAction::SubChar('F')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL F
+    ('\u{1D5DA}', CharMapAction::SubChar('G')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL G
+    ('\u{1D5DB}', CharMapAction::SubChar('H')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL H
+    ('\u{1D5DC}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL I
+    ('\u{1D5DD}', CharMapAction::SubChar('J')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL J
+    ('\u{1D5DE}', CharMapAction::SubChar('K')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL K
+    ('\u{1D5DF}', CharMapAction::SubChar('L')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL L
+    ('\u{1D5E0}', CharMapAction::SubChar('M')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL M
+    ('\u{1D5E1}', CharMapAction::SubChar('N')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL N
+    ('\u{1D5E2}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL O
+    ('\u{1D5E3}', CharMapAction::SubChar('P')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL P
+    ('\u{1D5E4}', CharMapAction::SubChar('Q')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL Q
+    ('\u{1D5E5}', CharMapAction::SubChar('R')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL R
+    ('\u{1D5E6}', CharMapAction::SubChar('S')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL S
+    ('\u{1D5E7}', CharMapAction::SubChar('T')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL T
+    ('\u{1D5E8}', CharMapAction::SubChar('U')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL U
+    ('\u{1D5E9}', CharMapAction::SubChar('V')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL V
+    ('\u{1D5EA}', CharMapAction::SubChar('W')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL W
+    ('\u{1D5EB}', CharMapAction::SubChar('X')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL X
+    ('\u{1D5EC}', CharMapAction::SubChar('Y')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL Y
+    ('\u{1D5ED}', CharMapAction::SubChar('Z')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL Z
+    ('\u{1D5EE}', CharMapAction::SubChar('a')), // MATHEMATICAL SANS-SERIF BOLD SMALL A
+    ('\u{1D5EF}', CharMapAction::SubChar('b')), // MATHEMATICAL SANS-SERIF BOLD SMALL B
+    ('\u{1D5F0}', CharMapAction::SubChar('c')), // MATHEMATICAL SANS-SERIF BOLD SMALL C
+    ('\u{1D5F1}', CharMapAction::SubChar('d')), // MATHEMATICAL SANS-SERIF BOLD SMALL D
+    ('\u{1D5F2}', CharMapAction::SubChar('e')), // MATHEMATICAL SANS-SERIF BOLD SMALL E
+    ('\u{1D5F3}', CharMapAction::SubChar('f')), // MATHEMATICAL SANS-SERIF BOLD SMALL F
+    ('\u{1D5F4}', CharMapAction::SubChar('g')), // MATHEMATICAL SANS-SERIF BOLD SMALL G
+    ('\u{1D5F5}', CharMapAction::SubChar('h')), // MATHEMATICAL SANS-SERIF BOLD SMALL H
+    ('\u{1D5F6}', CharMapAction::SubChar('i')), // MATHEMATICAL SANS-SERIF BOLD SMALL I
+    ('\u{1D5F7}', CharMapAction::SubChar('j')), // MATHEMATICAL SANS-SERIF BOLD SMALL J
+    ('\u{1D5F8}', CharMapAction::SubChar('k')), // MATHEMATICAL SANS-SERIF BOLD SMALL K
+    ('\u{1D5F9}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD SMALL L
+    ('\u{1D5FA}', CharMapAction::SubStr("rn")), // MATHEMATICAL SANS-SERIF BOLD SMALL M
+    ('\u{1D5FB}', CharMapAction::SubChar('n')), // MATHEMATICAL SANS-SERIF BOLD SMALL N
+    ('\u{1D5FC}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF BOLD SMALL O
+    ('\u{1D5FD}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF BOLD SMALL P
+    ('\u{1D5FE}', CharMapAction::SubChar('q')), // MATHEMATICAL SANS-SERIF BOLD SMALL Q
+    ('\u{1D5FF}', CharMapAction::SubChar('r')), // MATHEMATICAL SANS-SERIF BOLD SMALL R
+    ('\u{1D600}', CharMapAction::SubChar('s')), // MATHEMATICAL SANS-SERIF BOLD SMALL S
+    ('\u{1D601}', CharMapAction::SubChar('t')), // MATHEMATICAL SANS-SERIF BOLD SMALL T
+    ('\u{1D602}', CharMapAction::SubChar('u')), // MATHEMATICAL SANS-SERIF BOLD SMALL U
+    ('\u{1D603}', CharMapAction::SubChar('v')), // MATHEMATICAL SANS-SERIF BOLD SMALL V
+    ('\u{1D604}', CharMapAction::SubChar('w')), // MATHEMATICAL SANS-SERIF BOLD SMALL W
+    ('\u{1D605}', CharMapAction::SubChar('x')), // MATHEMATICAL SANS-SERIF BOLD SMALL X
+    ('\u{1D606}', CharMapAction::SubChar('y')), // MATHEMATICAL SANS-SERIF BOLD SMALL Y
+    ('\u{1D607}', CharMapAction::SubChar('z')), // MATHEMATICAL SANS-SERIF BOLD SMALL Z
+    ('\u{1D608}', CharMapAction::SubChar('A')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL A
+    ('\u{1D609}', CharMapAction::SubChar('B')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL B
+    ('\u{1D60A}', CharMapAction::SubChar('C')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL C
+    ('\u{1D60B}', CharMapAction::SubChar('D')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL D
+    ('\u{1D60C}', CharMapAction::SubChar('E')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL E
+    ('\u{1D60D}', CharMapAction::SubChar('F')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL F
+    ('\u{1D60E}', CharMapAction::SubChar('G')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL G
+    ('\u{1D60F}', CharMapAction::SubChar('H')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL H
+    ('\u{1D610}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL I
+    ('\u{1D611}', CharMapAction::SubChar('J')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL J
+    ('\u{1D612}', CharMapAction::SubChar('K')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL K
+    ('\u{1D613}', CharMapAction::SubChar('L')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL L
+    ('\u{1D614}', CharMapAction::SubChar('M')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL M
+    ('\u{1D615}', CharMapAction::SubChar('N')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL N
+    ('\u{1D616}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL O
+    ('\u{1D617}', CharMapAction::SubChar('P')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL P
+    ('\u{1D618}', CharMapAction::SubChar('Q')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL Q
+    ('\u{1D619}', CharMapAction::SubChar('R')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL R
+    ('\u{1D61A}', CharMapAction::SubChar('S')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL S
+    ('\u{1D61B}', CharMapAction::SubChar('T')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL T
+    ('\u{1D61C}', CharMapAction::SubChar('U')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL U
+    ('\u{1D61D}', CharMapAction::SubChar('V')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL V
+    ('\u{1D61E}', CharMapAction::SubChar('W')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL W
+    ('\u{1D61F}', CharMapAction::SubChar('X')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL X
+    ('\u{1D620}', CharMapAction::SubChar('Y')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL Y
+    ('\u{1D621}', CharMapAction::SubChar('Z')), // MATHEMATICAL SANS-SERIF ITALIC CAPITAL Z
+    ('\u{1D622}', CharMapAction::SubChar('a')), // MATHEMATICAL SANS-SERIF ITALIC SMALL A
+    ('\u{1D623}', CharMapAction::SubChar('b')), // MATHEMATICAL SANS-SERIF ITALIC SMALL B
+    ('\u{1D624}', CharMapAction::SubChar('c')), // MATHEMATICAL SANS-SERIF ITALIC SMALL C
+    ('\u{1D625}', CharMapAction::SubChar('d')), // MATHEMATICAL SANS-SERIF ITALIC SMALL D
+    ('\u{1D626}', CharMapAction::SubChar('e')), // MATHEMATICAL SANS-SERIF ITALIC SMALL E
+    ('\u{1D627}', CharMapAction::SubChar('f')), // MATHEMATICAL SANS-SERIF ITALIC SMALL F
+    ('\u{1D628}', CharMapAction::SubChar('g')), // MATHEMATICAL SANS-SERIF ITALIC SMALL G
+    ('\u{1D629}', CharMapAction::SubChar('h')), // MATHEMATICAL SANS-SERIF ITALIC SMALL H
+    ('\u{1D62A}', CharMapAction::SubChar('i')), // MATHEMATICAL SANS-SERIF ITALIC SMALL I
+    ('\u{1D62B}', CharMapAction::SubChar('j')), // MATHEMATICAL SANS-SERIF ITALIC SMALL J
+    ('\u{1D62C}', CharMapAction::SubChar('k')), // MATHEMATICAL SANS-SERIF ITALIC SMALL K
+    ('\u{1D62D}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF ITALIC SMALL L
+    ('\u{1D62E}', CharMapAction::SubStr("rn")), // MATHEMATICAL SANS-SERIF ITALIC SMALL M
+    ('\u{1D62F}', CharMapAction::SubChar('n')), // MATHEMATICAL SANS-SERIF ITALIC SMALL N
+    ('\u{1D630}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF ITALIC SMALL O
+    ('\u{1D631}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF ITALIC SMALL P
+    ('\u{1D632}', CharMapAction::SubChar('q')), // MATHEMATICAL SANS-SERIF ITALIC SMALL Q
+    ('\u{1D633}', CharMapAction::SubChar('r')), // MATHEMATICAL SANS-SERIF ITALIC SMALL R
+    ('\u{1D634}', CharMapAction::SubChar('s')), // MATHEMATICAL SANS-SERIF ITALIC SMALL S
+    ('\u{1D635}', CharMapAction::SubChar('t')), // MATHEMATICAL SANS-SERIF ITALIC SMALL T
+    ('\u{1D636}', CharMapAction::SubChar('u')), // MATHEMATICAL SANS-SERIF ITALIC SMALL U
+    ('\u{1D637}', CharMapAction::SubChar('v')), // MATHEMATICAL SANS-SERIF ITALIC SMALL V
+    ('\u{1D638}', CharMapAction::SubChar('w')), // MATHEMATICAL SANS-SERIF ITALIC SMALL W
+    ('\u{1D639}', CharMapAction::SubChar('x')), // MATHEMATICAL SANS-SERIF ITALIC SMALL X
+    ('\u{1D63A}', CharMapAction::SubChar('y')), // MATHEMATICAL SANS-SERIF ITALIC SMALL Y
+    ('\u{1D63B}', CharMapAction::SubChar('z')), // MATHEMATICAL SANS-SERIF ITALIC SMALL Z
+    ('\u{1D63C}', CharMapAction::SubChar('A')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL A
+    ('\u{1D63D}', CharMapAction::SubChar('B')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL B
+    ('\u{1D63E}', CharMapAction::SubChar('C')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL C
+    ('\u{1D63F}', CharMapAction::SubChar('D')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL D
+    ('\u{1D640}', CharMapAction::SubChar('E')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL E
+    ('\u{1D641}', CharMapAction::SubChar('F')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL F
+    ('\u{1D642}', CharMapAction::SubChar('G')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL G
+    ('\u{1D643}', CharMapAction::SubChar('H')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL H
+    ('\u{1D644}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL I
+    ('\u{1D645}', CharMapAction::SubChar('J')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL J
+    ('\u{1D646}', CharMapAction::SubChar('K')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL K
+    ('\u{1D647}', CharMapAction::SubChar('L')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL L
+    ('\u{1D648}', CharMapAction::SubChar('M')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL M
+    ('\u{1D649}', CharMapAction::SubChar('N')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL N
+    ('\u{1D64A}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL O
+    ('\u{1D64B}', CharMapAction::SubChar('P')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL P
+    ('\u{1D64C}', CharMapAction::SubChar('Q')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Q
+    ('\u{1D64D}', CharMapAction::SubChar('R')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL R
+    ('\u{1D64E}', CharMapAction::SubChar('S')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL S
+    ('\u{1D64F}', CharMapAction::SubChar('T')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL T
+    ('\u{1D650}', CharMapAction::SubChar('U')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL U
+    ('\u{1D651}', CharMapAction::SubChar('V')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL V
+    ('\u{1D652}', CharMapAction::SubChar('W')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL W
+    ('\u{1D653}', CharMapAction::SubChar('X')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL X
+    ('\u{1D654}', CharMapAction::SubChar('Y')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Y
+    ('\u{1D655}', CharMapAction::SubChar('Z')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL Z
+    ('\u{1D656}', CharMapAction::SubChar('a')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL A
+    ('\u{1D657}', CharMapAction::SubChar('b')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL B
+    ('\u{1D658}', CharMapAction::SubChar('c')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL C
+    ('\u{1D659}', CharMapAction::SubChar('d')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL D
+    ('\u{1D65A}', CharMapAction::SubChar('e')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL E
+    ('\u{1D65B}', CharMapAction::SubChar('f')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL F
+    ('\u{1D65C}', CharMapAction::SubChar('g')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL G
+    ('\u{1D65D}', CharMapAction::SubChar('h')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL H
+    ('\u{1D65E}', CharMapAction::SubChar('i')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL I
+    ('\u{1D65F}', CharMapAction::SubChar('j')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL J
+    ('\u{1D660}', CharMapAction::SubChar('k')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL K
+    ('\u{1D661}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL L
+    ('\u{1D662}', CharMapAction::SubStr("rn")), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL M
+    ('\u{1D663}', CharMapAction::SubChar('n')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL N
+    ('\u{1D664}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL O
+    ('\u{1D665}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL P
+    ('\u{1D666}', CharMapAction::SubChar('q')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Q
+    ('\u{1D667}', CharMapAction::SubChar('r')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL R
+    ('\u{1D668}', CharMapAction::SubChar('s')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL S
+    ('\u{1D669}', CharMapAction::SubChar('t')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL T
+    ('\u{1D66A}', CharMapAction::SubChar('u')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL U
+    ('\u{1D66B}', CharMapAction::SubChar('v')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL V
+    ('\u{1D66C}', CharMapAction::SubChar('w')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL W
+    ('\u{1D66D}', CharMapAction::SubChar('x')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL X
+    ('\u{1D66E}', CharMapAction::SubChar('y')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Y
+    ('\u{1D66F}', CharMapAction::SubChar('z')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL Z
+    ('\u{1D670}', CharMapAction::SubChar('A')), // MATHEMATICAL MONOSPACE CAPITAL A
+    ('\u{1D671}', CharMapAction::SubChar('B')), // MATHEMATICAL MONOSPACE CAPITAL B
+    ('\u{1D672}', CharMapAction::SubChar('C')), // MATHEMATICAL MONOSPACE CAPITAL C
+    ('\u{1D673}', CharMapAction::SubChar('D')), // MATHEMATICAL MONOSPACE CAPITAL D
+    ('\u{1D674}', CharMapAction::SubChar('E')), // MATHEMATICAL MONOSPACE CAPITAL E
+    ('\u{1D675}', CharMapAction::SubChar('F')), // MATHEMATICAL MONOSPACE CAPITAL F
+    ('\u{1D676}', CharMapAction::SubChar('G')), // MATHEMATICAL MONOSPACE CAPITAL G
+    ('\u{1D677}', CharMapAction::SubChar('H')), // MATHEMATICAL MONOSPACE CAPITAL H
+    ('\u{1D678}', CharMapAction::SubChar('l')), // MATHEMATICAL MONOSPACE CAPITAL I
+    ('\u{1D679}', CharMapAction::SubChar('J')), // MATHEMATICAL MONOSPACE CAPITAL J
+    ('\u{1D67A}', CharMapAction::SubChar('K')), // MATHEMATICAL MONOSPACE CAPITAL K
+    ('\u{1D67B}', CharMapAction::SubChar('L')), // MATHEMATICAL MONOSPACE CAPITAL L
+    ('\u{1D67C}', CharMapAction::SubChar('M')), // MATHEMATICAL MONOSPACE CAPITAL M
+    ('\u{1D67D}', CharMapAction::SubChar('N')), // MATHEMATICAL MONOSPACE CAPITAL N
+    ('\u{1D67E}', CharMapAction::SubChar('O')), // MATHEMATICAL MONOSPACE CAPITAL O
+    ('\u{1D67F}', CharMapAction::SubChar('P')), // MATHEMATICAL MONOSPACE CAPITAL P
+    ('\u{1D680}', CharMapAction::SubChar('Q')), // MATHEMATICAL MONOSPACE CAPITAL Q
+    ('\u{1D681}', CharMapAction::SubChar('R')), // MATHEMATICAL MONOSPACE CAPITAL R
+    ('\u{1D682}', CharMapAction::SubChar('S')), // MATHEMATICAL MONOSPACE CAPITAL S
+    ('\u{1D683}', CharMapAction::SubChar('T')), // MATHEMATICAL MONOSPACE CAPITAL T
+    ('\u{1D684}', CharMapAction::SubChar('U')), // MATHEMATICAL MONOSPACE CAPITAL U
+    ('\u{1D685}', CharMapAction::SubChar('V')), // MATHEMATICAL MONOSPACE CAPITAL V
+    ('\u{1D686}', CharMapAction::SubChar('W')), // MATHEMATICAL MONOSPACE CAPITAL W
+    ('\u{1D687}', CharMapAction::SubChar('X')), // MATHEMATICAL MONOSPACE CAPITAL X
+    ('\u{1D688}', CharMapAction::SubChar('Y')), // MATHEMATICAL MONOSPACE CAPITAL Y
+    ('\u{1D689}', CharMapAction::SubChar('Z')), // MATHEMATICAL MONOSPACE CAPITAL Z
+    ('\u{1D68A}', CharMapAction::SubChar('a')), // MATHEMATICAL MONOSPACE SMALL A
+    ('\u{1D68B}', CharMapAction::SubChar('b')), // MATHEMATICAL MONOSPACE SMALL B
+    ('\u{1D68C}', CharMapAction::SubChar('c')), // MATHEMATICAL MONOSPACE SMALL C
+    ('\u{1D68D}', CharMapAction::SubChar('d')), // MATHEMATICAL MONOSPACE SMALL D
+    ('\u{1D68E}', CharMapAction::SubChar('e')), // MATHEMATICAL MONOSPACE SMALL E
+    ('\u{1D68F}', CharMapAction::SubChar('f')), // MATHEMATICAL MONOSPACE SMALL F
+    ('\u{1D690}', CharMapAction::SubChar('g')), // MATHEMATICAL MONOSPACE SMALL G
+    ('\u{1D691}', CharMapAction::SubChar('h')), // MATHEMATICAL MONOSPACE SMALL H
+    ('\u{1D692}', CharMapAction::SubChar('i')), // MATHEMATICAL MONOSPACE SMALL I
+    ('\u{1D693}', CharMapAction::SubChar('j')), // MATHEMATICAL MONOSPACE SMALL J
+    ('\u{1D694}', CharMapAction::SubChar('k')), // MATHEMATICAL MONOSPACE SMALL K
+    ('\u{1D695}', CharMapAction::SubChar('l')), // MATHEMATICAL MONOSPACE SMALL L
+    ('\u{1D696}', CharMapAction::SubStr("rn")), // MATHEMATICAL MONOSPACE SMALL M
+    ('\u{1D697}', CharMapAction::SubChar('n')), // MATHEMATICAL MONOSPACE SMALL N
+    ('\u{1D698}', CharMapAction::SubChar('o')), // MATHEMATICAL MONOSPACE SMALL O
+    ('\u{1D699}', CharMapAction::SubChar('p')), // MATHEMATICAL MONOSPACE SMALL P
+    ('\u{1D69A}', CharMapAction::SubChar('q')), // MATHEMATICAL MONOSPACE SMALL Q
+    ('\u{1D69B}', CharMapAction::SubChar('r')), // MATHEMATICAL MONOSPACE SMALL R
+    ('\u{1D69C}', CharMapAction::SubChar('s')), // MATHEMATICAL MONOSPACE SMALL S
+    ('\u{1D69D}', CharMapAction::SubChar('t')), // MATHEMATICAL MONOSPACE SMALL T
+    ('\u{1D69E}', CharMapAction::SubChar('u')), // MATHEMATICAL MONOSPACE SMALL U
+    ('\u{1D69F}', CharMapAction::SubChar('v')), // MATHEMATICAL MONOSPACE SMALL V
+    ('\u{1D6A0}', CharMapAction::SubChar('w')), // MATHEMATICAL MONOSPACE SMALL W
+    ('\u{1D6A1}', CharMapAction::SubChar('x')), // MATHEMATICAL MONOSPACE SMALL X
+    ('\u{1D6A2}', CharMapAction::SubChar('y')), // MATHEMATICAL MONOSPACE SMALL Y
+    ('\u{1D6A3}', CharMapAction::SubChar('z')), // MATHEMATICAL MONOSPACE SMALL Z
+    ('\u{1D6A4}', CharMapAction::SubChar('i')), // MATHEMATICAL ITALIC SMALL DOTLESS I
+    ('\u{1D6A5}', CharMapAction::SubChar('\u{0237}')), // MATHEMATICAL ITALIC SMALL DOTLESS J
+    ('\u{1D6A8}', CharMapAction::SubChar('A')), // MATHEMATICAL BOLD CAPITAL ALPHA
+    ('\u{1D6A9}', CharMapAction::SubChar('B')), // MATHEMATICAL BOLD CAPITAL BETA
+    ('\u{1D6AA}', CharMapAction::SubChar('\u{0393}')), // MATHEMATICAL BOLD CAPITAL GAMMA
+    ('\u{1D6AB}', CharMapAction::SubChar('\u{0394}')), // MATHEMATICAL BOLD CAPITAL DELTA
+    ('\u{1D6AC}', CharMapAction::SubChar('E')), // MATHEMATICAL BOLD CAPITAL EPSILON
+    ('\u{1D6AD}', CharMapAction::SubChar('Z')), // MATHEMATICAL BOLD CAPITAL ZETA
+    ('\u{1D6AE}', CharMapAction::SubChar('H')), // MATHEMATICAL BOLD CAPITAL ETA
+    ('\u{1D6AF}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD CAPITAL THETA
+    ('\u{1D6B0}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD CAPITAL IOTA
+    ('\u{1D6B1}', CharMapAction::SubChar('K')), // MATHEMATICAL BOLD CAPITAL KAPPA
+    ('\u{1D6B2}', CharMapAction::SubChar('\u{0245}')), // MATHEMATICAL BOLD CAPITAL LAMDA
+    ('\u{1D6B3}', CharMapAction::SubChar('M')), // MATHEMATICAL BOLD CAPITAL MU
+    ('\u{1D6B4}', CharMapAction::SubChar('N')), // MATHEMATICAL BOLD CAPITAL NU
+    ('\u{1D6B5}', CharMapAction::SubChar('\u{039E}')), // MATHEMATICAL BOLD CAPITAL XI
+    ('\u{1D6B6}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD CAPITAL OMICRON
+    ('\u{1D6B7}', CharMapAction::SubChar('\u{03A0}')), // MATHEMATICAL BOLD CAPITAL PI
+    ('\u{1D6B8}', CharMapAction::SubChar('P')), // MATHEMATICAL BOLD CAPITAL RHO
+    ('\u{1D6B9}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD CAPITAL THETA SYMBOL
+    ('\u{1D6BA}', CharMapAction::SubChar('\u{01A9}')), // MATHEMATICAL BOLD CAPITAL SIGMA
+    ('\u{1D6BB}', CharMapAction::SubChar('T')), // MATHEMATICAL BOLD CAPITAL TAU
+    ('\u{1D6BC}', CharMapAction::SubChar('Y')), // MATHEMATICAL BOLD CAPITAL UPSILON
+    ('\u{1D6BD}', CharMapAction::SubChar('\u{03A6}')), // MATHEMATICAL BOLD CAPITAL PHI
+    ('\u{1D6BE}', CharMapAction::SubChar('X')), // MATHEMATICAL BOLD CAPITAL CHI
+    ('\u{1D6BF}', CharMapAction::SubChar('\u{03A8}')), // MATHEMATICAL BOLD CAPITAL PSI
+    ('\u{1D6C0}', CharMapAction::SubChar('\u{03A9}')), // MATHEMATICAL BOLD CAPITAL OMEGA
+    ('\u{1D6C1}', CharMapAction::SubChar('\u{2207}')), // MATHEMATICAL BOLD NABLA
+    ('\u{1D6C2}', CharMapAction::SubChar('a')), // MATHEMATICAL BOLD SMALL ALPHA
+    ('\u{1D6C3}', CharMapAction::SubChar('\u{00DF}')), // MATHEMATICAL BOLD SMALL BETA
+    ('\u{1D6C4}', CharMapAction::SubChar('y')), // MATHEMATICAL BOLD SMALL GAMMA
+    ('\u{1D6C5}', CharMapAction::SubChar('\u{1E9F}')), // MATHEMATICAL BOLD SMALL DELTA
+    ('\u{1D6C6}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL BOLD SMALL EPSILON
+    ('\u{1D6C7}', CharMapAction::SubChar('\u{03B6}')), // MATHEMATICAL BOLD SMALL ZETA
+    ('\u{1D6C8}', CharMapAction::SubStr("n\u{0329}")), // MATHEMATICAL BOLD SMALL ETA
+    ('\u{1D6C9}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD SMALL THETA
+    ('\u{1D6CA}', CharMapAction::SubChar('i')), // MATHEMATICAL BOLD SMALL IOTA
+    ('\u{1D6CB}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL BOLD SMALL KAPPA
+    ('\u{1D6CC}', CharMapAction::SubChar('\u{03BB}')), // MATHEMATICAL BOLD SMALL LAMDA
+    ('\u{1D6CD}', CharMapAction::SubChar('\u{03BC}')), // MATHEMATICAL BOLD SMALL MU
+    ('\u{1D6CE}', CharMapAction::SubChar('v')), // MATHEMATICAL BOLD SMALL NU
+    ('\u{1D6CF}', CharMapAction::SubChar('\u{03BE}')), // MATHEMATICAL BOLD SMALL XI
+    ('\u{1D6D0}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD SMALL OMICRON
+    ('\u{1D6D1}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL BOLD SMALL PI
+    ('\u{1D6D2}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD SMALL RHO
+    ('\u{1D6D3}', CharMapAction::SubChar('\u{03C2}')), // MATHEMATICAL BOLD SMALL FINAL SIGMA
+    ('\u{1D6D4}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD SMALL SIGMA
+    ('\u{1D6D5}', CharMapAction::SubChar('\u{1D1B}')), // MATHEMATICAL BOLD SMALL TAU
+    ('\u{1D6D6}', CharMapAction::SubChar('u')), // MATHEMATICAL BOLD SMALL UPSILON
+    ('\u{1D6D7}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL BOLD SMALL PHI
+    ('\u{1D6D8}', CharMapAction::SubChar('\u{03C7}')), // MATHEMATICAL BOLD SMALL CHI
+    ('\u{1D6D9}', CharMapAction::SubChar('\u{03C8}')), // MATHEMATICAL BOLD SMALL PSI
+    ('\u{1D6DA}', CharMapAction::SubChar('\u{03C9}')), // MATHEMATICAL BOLD SMALL OMEGA
+    ('\u{1D6DB}', CharMapAction::SubChar('\u{2202}')), // MATHEMATICAL BOLD PARTIAL DIFFERENTIAL
+    ('\u{1D6DC}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL BOLD EPSILON SYMBOL
+    ('\u{1D6DD}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD THETA SYMBOL
+    ('\u{1D6DE}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL BOLD KAPPA SYMBOL
+    ('\u{1D6DF}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL BOLD PHI SYMBOL
+    ('\u{1D6E0}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD RHO SYMBOL
+    ('\u{1D6E1}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL BOLD PI SYMBOL
+    ('\u{1D6E2}', CharMapAction::SubChar('A')), // MATHEMATICAL ITALIC CAPITAL ALPHA
+    ('\u{1D6E3}', CharMapAction::SubChar('B')), // MATHEMATICAL ITALIC CAPITAL BETA
+    ('\u{1D6E4}', CharMapAction::SubChar('\u{0393}')), // MATHEMATICAL ITALIC CAPITAL GAMMA
+    ('\u{1D6E5}', CharMapAction::SubChar('\u{0394}')), // MATHEMATICAL ITALIC CAPITAL DELTA
+    ('\u{1D6E6}', CharMapAction::SubChar('E')), // MATHEMATICAL ITALIC CAPITAL EPSILON
+    ('\u{1D6E7}', CharMapAction::SubChar('Z')), // MATHEMATICAL ITALIC CAPITAL ZETA
+    ('\u{1D6E8}', CharMapAction::SubChar('H')), // MATHEMATICAL ITALIC CAPITAL ETA
+    ('\u{1D6E9}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL ITALIC CAPITAL THETA
+    ('\u{1D6EA}', CharMapAction::SubChar('l')), // MATHEMATICAL ITALIC CAPITAL IOTA
+    ('\u{1D6EB}', CharMapAction::SubChar('K')), // MATHEMATICAL ITALIC CAPITAL KAPPA
+    ('\u{1D6EC}', CharMapAction::SubChar('\u{0245}')), // MATHEMATICAL ITALIC CAPITAL LAMDA
+    ('\u{1D6ED}', CharMapAction::SubChar('M')), // MATHEMATICAL ITALIC CAPITAL MU
+    ('\u{1D6EE}', CharMapAction::SubChar('N')), // MATHEMATICAL ITALIC CAPITAL NU
+    ('\u{1D6EF}', CharMapAction::SubChar('\u{039E}')), // MATHEMATICAL ITALIC CAPITAL XI
+    ('\u{1D6F0}', CharMapAction::SubChar('O')), // MATHEMATICAL ITALIC CAPITAL OMICRON
+    ('\u{1D6F1}', CharMapAction::SubChar('\u{03A0}')), // MATHEMATICAL ITALIC CAPITAL PI
+    ('\u{1D6F2}', CharMapAction::SubChar('P')), // MATHEMATICAL ITALIC CAPITAL RHO
+    ('\u{1D6F3}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL ITALIC CAPITAL THETA SYMBOL
+    ('\u{1D6F4}', CharMapAction::SubChar('\u{01A9}')), // MATHEMATICAL ITALIC CAPITAL SIGMA
+    ('\u{1D6F5}', CharMapAction::SubChar('T')), // MATHEMATICAL ITALIC CAPITAL TAU
+    ('\u{1D6F6}', CharMapAction::SubChar('Y')), // MATHEMATICAL ITALIC CAPITAL UPSILON
+    ('\u{1D6F7}', CharMapAction::SubChar('\u{03A6}')), // MATHEMATICAL ITALIC CAPITAL PHI
+    ('\u{1D6F8}', CharMapAction::SubChar('X')), // MATHEMATICAL ITALIC CAPITAL CHI
+    ('\u{1D6F9}', CharMapAction::SubChar('\u{03A8}')), // MATHEMATICAL ITALIC CAPITAL PSI
+    ('\u{1D6FA}', CharMapAction::SubChar('\u{03A9}')), // MATHEMATICAL ITALIC CAPITAL OMEGA
+    ('\u{1D6FB}', CharMapAction::SubChar('\u{2207}')), // MATHEMATICAL ITALIC NABLA
+    ('\u{1D6FC}', CharMapAction::SubChar('a')), // MATHEMATICAL ITALIC SMALL ALPHA
+    ('\u{1D6FD}', CharMapAction::SubChar('\u{00DF}')), // MATHEMATICAL ITALIC SMALL BETA
+    ('\u{1D6FE}', CharMapAction::SubChar('y')), // MATHEMATICAL ITALIC SMALL GAMMA
+    ('\u{1D6FF}', CharMapAction::SubChar('\u{1E9F}')), // MATHEMATICAL ITALIC SMALL DELTA
+    ('\u{1D700}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL ITALIC SMALL EPSILON
+    ('\u{1D701}', CharMapAction::SubChar('\u{03B6}')), // MATHEMATICAL ITALIC SMALL ZETA
+    ('\u{1D702}', CharMapAction::SubStr("n\u{0329}")), // MATHEMATICAL ITALIC SMALL ETA
+    ('\u{1D703}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL ITALIC SMALL THETA
+    ('\u{1D704}', CharMapAction::SubChar('i')), // MATHEMATICAL ITALIC SMALL IOTA
+    ('\u{1D705}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL ITALIC SMALL KAPPA
+    ('\u{1D706}', CharMapAction::SubChar('\u{03BB}')), // MATHEMATICAL ITALIC SMALL LAMDA
+    ('\u{1D707}', CharMapAction::SubChar('\u{03BC}')), // MATHEMATICAL ITALIC SMALL MU
+    ('\u{1D708}', CharMapAction::SubChar('v')), // MATHEMATICAL ITALIC SMALL NU
+    ('\u{1D709}', CharMapAction::SubChar('\u{03BE}')), // MATHEMATICAL ITALIC SMALL XI
+    ('\u{1D70A}', CharMapAction::SubChar('o')), // MATHEMATICAL ITALIC SMALL OMICRON
+    ('\u{1D70B}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL ITALIC SMALL PI
+    ('\u{1D70C}', CharMapAction::SubChar('p')), // MATHEMATICAL ITALIC SMALL RHO
+    ('\u{1D70D}', CharMapAction::SubChar('\u{03C2}')), // MATHEMATICAL ITALIC SMALL FINAL SIGMA
+    ('\u{1D70E}', CharMapAction::SubChar('o')), // MATHEMATICAL ITALIC SMALL SIGMA
+    ('\u{1D70F}', CharMapAction::SubChar('\u{1D1B}')), // MATHEMATICAL ITALIC SMALL TAU
+    ('\u{1D710}', CharMapAction::SubChar('u')), // MATHEMATICAL ITALIC SMALL UPSILON
+    ('\u{1D711}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL ITALIC SMALL PHI
+    ('\u{1D712}', CharMapAction::SubChar('\u{03C7}')), // MATHEMATICAL ITALIC SMALL CHI
+    ('\u{1D713}', CharMapAction::SubChar('\u{03C8}')), // MATHEMATICAL ITALIC SMALL PSI
+    ('\u{1D714}', CharMapAction::SubChar('\u{03C9}')), // MATHEMATICAL ITALIC SMALL OMEGA
+    ('\u{1D715}', CharMapAction::SubChar('\u{2202}')), // MATHEMATICAL ITALIC PARTIAL DIFFERENTIAL
+    ('\u{1D716}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL ITALIC EPSILON SYMBOL
+    ('\u{1D717}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL ITALIC THETA SYMBOL
+    ('\u{1D718}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL ITALIC KAPPA SYMBOL
+    ('\u{1D719}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL ITALIC PHI SYMBOL
+    ('\u{1D71A}', CharMapAction::SubChar('p')), // MATHEMATICAL ITALIC RHO SYMBOL
+    ('\u{1D71B}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL ITALIC PI SYMBOL
+    ('\u{1D71C}', CharMapAction::SubChar('A')), // MATHEMATICAL BOLD ITALIC CAPITAL ALPHA
+    ('\u{1D71D}', CharMapAction::SubChar('B')), // MATHEMATICAL BOLD ITALIC CAPITAL BETA
+    ('\u{1D71E}', CharMapAction::SubChar('\u{0393}')), // MATHEMATICAL BOLD ITALIC CAPITAL GAMMA
+    ('\u{1D71F}', CharMapAction::SubChar('\u{0394}')), // MATHEMATICAL BOLD ITALIC CAPITAL DELTA
+    ('\u{1D720}', CharMapAction::SubChar('E')), // MATHEMATICAL BOLD ITALIC CAPITAL EPSILON
+    ('\u{1D721}', CharMapAction::SubChar('Z')), // MATHEMATICAL BOLD ITALIC CAPITAL ZETA
+    ('\u{1D722}', CharMapAction::SubChar('H')), // MATHEMATICAL BOLD ITALIC CAPITAL ETA
+    ('\u{1D723}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD ITALIC CAPITAL THETA
+    ('\u{1D724}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD ITALIC CAPITAL IOTA
+    ('\u{1D725}', CharMapAction::SubChar('K')), // MATHEMATICAL BOLD ITALIC CAPITAL KAPPA
+    ('\u{1D726}', CharMapAction::SubChar('\u{0245}')), // MATHEMATICAL BOLD ITALIC CAPITAL LAMDA
+    ('\u{1D727}', CharMapAction::SubChar('M')), // MATHEMATICAL BOLD ITALIC CAPITAL MU
+    ('\u{1D728}', CharMapAction::SubChar('N')), // MATHEMATICAL BOLD ITALIC CAPITAL NU
+    ('\u{1D729}', CharMapAction::SubChar('\u{039E}')), // MATHEMATICAL BOLD ITALIC CAPITAL XI
+    ('\u{1D72A}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD ITALIC CAPITAL OMICRON
+    ('\u{1D72B}', CharMapAction::SubChar('\u{03A0}')), // MATHEMATICAL BOLD ITALIC CAPITAL PI
+    ('\u{1D72C}', CharMapAction::SubChar('P')), // MATHEMATICAL BOLD ITALIC CAPITAL RHO
+    ('\u{1D72D}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD ITALIC CAPITAL THETA SYMBOL
+    ('\u{1D72E}', CharMapAction::SubChar('\u{01A9}')), // MATHEMATICAL BOLD ITALIC CAPITAL SIGMA
+    ('\u{1D72F}', CharMapAction::SubChar('T')), // MATHEMATICAL BOLD ITALIC CAPITAL TAU
+    ('\u{1D730}', CharMapAction::SubChar('Y')), // MATHEMATICAL BOLD ITALIC CAPITAL UPSILON
+    ('\u{1D731}', CharMapAction::SubChar('\u{03A6}')), // MATHEMATICAL BOLD ITALIC CAPITAL PHI
+    ('\u{1D732}', CharMapAction::SubChar('X')), // MATHEMATICAL BOLD ITALIC CAPITAL CHI
+    ('\u{1D733}', CharMapAction::SubChar('\u{03A8}')), // MATHEMATICAL BOLD ITALIC CAPITAL PSI
+    ('\u{1D734}', CharMapAction::SubChar('\u{03A9}')), // MATHEMATICAL BOLD ITALIC CAPITAL OMEGA
+    ('\u{1D735}', CharMapAction::SubChar('\u{2207}')), // MATHEMATICAL BOLD ITALIC NABLA
+    ('\u{1D736}', CharMapAction::SubChar('a')), // MATHEMATICAL BOLD ITALIC SMALL ALPHA
+    ('\u{1D737}', CharMapAction::SubChar('\u{00DF}')), // MATHEMATICAL BOLD ITALIC SMALL BETA
+    ('\u{1D738}', CharMapAction::SubChar('y')), // MATHEMATICAL BOLD ITALIC SMALL GAMMA
+    ('\u{1D739}', CharMapAction::SubChar('\u{1E9F}')), // MATHEMATICAL BOLD ITALIC SMALL DELTA
+    ('\u{1D73A}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL BOLD ITALIC SMALL EPSILON
+    ('\u{1D73B}', CharMapAction::SubChar('\u{03B6}')), // MATHEMATICAL BOLD ITALIC SMALL ZETA
+    ('\u{1D73C}', CharMapAction::SubStr("n\u{0329}")), // MATHEMATICAL BOLD ITALIC SMALL ETA
+    ('\u{1D73D}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD ITALIC SMALL THETA
+    ('\u{1D73E}', CharMapAction::SubChar('i')), // MATHEMATICAL BOLD ITALIC SMALL IOTA
+    ('\u{1D73F}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL BOLD ITALIC SMALL KAPPA
+    ('\u{1D740}', CharMapAction::SubChar('\u{03BB}')), // MATHEMATICAL BOLD ITALIC SMALL LAMDA
+    ('\u{1D741}', CharMapAction::SubChar('\u{03BC}')), // MATHEMATICAL BOLD ITALIC SMALL MU
+    ('\u{1D742}', CharMapAction::SubChar('v')), // MATHEMATICAL BOLD ITALIC SMALL NU
+    ('\u{1D743}', CharMapAction::SubChar('\u{03BE}')), // MATHEMATICAL BOLD ITALIC SMALL XI
+    ('\u{1D744}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD ITALIC SMALL OMICRON
+    ('\u{1D745}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL BOLD ITALIC SMALL PI
+    ('\u{1D746}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD ITALIC SMALL RHO
+    ('\u{1D747}', CharMapAction::SubChar('\u{03C2}')), // MATHEMATICAL BOLD ITALIC SMALL FINAL SIGMA
+    ('\u{1D748}', CharMapAction::SubChar('o')), // MATHEMATICAL BOLD ITALIC SMALL SIGMA
+    ('\u{1D749}', CharMapAction::SubChar('\u{1D1B}')), // MATHEMATICAL BOLD ITALIC SMALL TAU
+    ('\u{1D74A}', CharMapAction::SubChar('u')), // MATHEMATICAL BOLD ITALIC SMALL UPSILON
+    ('\u{1D74B}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL BOLD ITALIC SMALL PHI
+    ('\u{1D74C}', CharMapAction::SubChar('\u{03C7}')), // MATHEMATICAL BOLD ITALIC SMALL CHI
+    ('\u{1D74D}', CharMapAction::SubChar('\u{03C8}')), // MATHEMATICAL BOLD ITALIC SMALL PSI
+    ('\u{1D74E}', CharMapAction::SubChar('\u{03C9}')), // MATHEMATICAL BOLD ITALIC SMALL OMEGA
+    ('\u{1D74F}', CharMapAction::SubChar('\u{2202}')), // MATHEMATICAL BOLD ITALIC PARTIAL DIFFERENTIAL
+    ('\u{1D750}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL BOLD ITALIC EPSILON SYMBOL
+    ('\u{1D751}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL BOLD ITALIC THETA SYMBOL
+    ('\u{1D752}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL BOLD ITALIC KAPPA SYMBOL
+    ('\u{1D753}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL BOLD ITALIC PHI SYMBOL
+    ('\u{1D754}', CharMapAction::SubChar('p')), // MATHEMATICAL BOLD ITALIC RHO SYMBOL
+    ('\u{1D755}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL BOLD ITALIC PI SYMBOL
+    ('\u{1D756}', CharMapAction::SubChar('A')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL ALPHA
+    ('\u{1D757}', CharMapAction::SubChar('B')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL BETA
+    ('\u{1D758}', CharMapAction::SubChar('\u{0393}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL GAMMA
+    ('\u{1D759}', CharMapAction::SubChar('\u{0394}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL DELTA
+    ('\u{1D75A}', CharMapAction::SubChar('E')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL EPSILON
+    ('\u{1D75B}', CharMapAction::SubChar('Z')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL ZETA
+    ('\u{1D75C}', CharMapAction::SubChar('H')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL ETA
+    ('\u{1D75D}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD CAPITAL THETA
+    ('\u{1D75E}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL IOTA
+    ('\u{1D75F}', CharMapAction::SubChar('K')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL KAPPA
+    ('\u{1D760}', CharMapAction::SubChar('\u{0245}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL LAMDA
+    ('\u{1D761}', CharMapAction::SubChar('M')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL MU
+    ('\u{1D762}', CharMapAction::SubChar('N')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL NU
+    ('\u{1D763}', CharMapAction::SubChar('\u{039E}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL XI
+    ('\u{1D764}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL OMICRON
+    ('\u{1D765}', CharMapAction::SubChar('\u{03A0}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL PI
+    ('\u{1D766}', CharMapAction::SubChar('P')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL RHO
+    ('\u{1D767}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD CAPITAL THETA SYMBOL
+    ('\u{1D768}', CharMapAction::SubChar('\u{01A9}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL SIGMA
+    ('\u{1D769}', CharMapAction::SubChar('T')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL TAU
+    ('\u{1D76A}', CharMapAction::SubChar('Y')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL UPSILON
+    ('\u{1D76B}', CharMapAction::SubChar('\u{03A6}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL PHI
+    ('\u{1D76C}', CharMapAction::SubChar('X')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL CHI
+    ('\u{1D76D}', CharMapAction::SubChar('\u{03A8}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL PSI
+    ('\u{1D76E}', CharMapAction::SubChar('\u{03A9}')), // MATHEMATICAL SANS-SERIF BOLD CAPITAL OMEGA
+    ('\u{1D76F}', CharMapAction::SubChar('\u{2207}')), // MATHEMATICAL SANS-SERIF BOLD NABLA
+    ('\u{1D770}', CharMapAction::SubChar('a')), // MATHEMATICAL SANS-SERIF BOLD SMALL ALPHA
+    ('\u{1D771}', CharMapAction::SubChar('\u{00DF}')), // MATHEMATICAL SANS-SERIF BOLD SMALL BETA
+    ('\u{1D772}', CharMapAction::SubChar('y')), // MATHEMATICAL SANS-SERIF BOLD SMALL GAMMA
+    ('\u{1D773}', CharMapAction::SubChar('\u{1E9F}')), // MATHEMATICAL SANS-SERIF BOLD SMALL DELTA
+    ('\u{1D774}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL SANS-SERIF BOLD SMALL EPSILON
+    ('\u{1D775}', CharMapAction::SubChar('\u{03B6}')), // MATHEMATICAL SANS-SERIF BOLD SMALL ZETA
+    ('\u{1D776}', CharMapAction::SubStr("n\u{0329}")), // MATHEMATICAL SANS-SERIF BOLD SMALL ETA
+    ('\u{1D777}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD SMALL THETA
+    ('\u{1D778}', CharMapAction::SubChar('i')), // MATHEMATICAL SANS-SERIF BOLD SMALL IOTA
+    ('\u{1D779}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL SANS-SERIF BOLD SMALL KAPPA
+    ('\u{1D77A}', CharMapAction::SubChar('\u{03BB}')), // MATHEMATICAL SANS-SERIF BOLD SMALL LAMDA
+    ('\u{1D77B}', CharMapAction::SubChar('\u{03BC}')), // MATHEMATICAL SANS-SERIF BOLD SMALL MU
+    ('\u{1D77C}', CharMapAction::SubChar('v')), // MATHEMATICAL SANS-SERIF BOLD SMALL NU
+    ('\u{1D77D}', CharMapAction::SubChar('\u{03BE}')), // MATHEMATICAL SANS-SERIF BOLD SMALL XI
+    ('\u{1D77E}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF BOLD SMALL OMICRON
+    ('\u{1D77F}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL SANS-SERIF BOLD SMALL PI
+    ('\u{1D780}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF BOLD SMALL RHO
+    ('\u{1D781}', CharMapAction::SubChar('\u{03C2}')), // MATHEMATICAL SANS-SERIF BOLD SMALL FINAL SIGMA
+    ('\u{1D782}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF BOLD SMALL SIGMA
+    ('\u{1D783}', CharMapAction::SubChar('\u{1D1B}')), // MATHEMATICAL SANS-SERIF BOLD SMALL TAU
+    ('\u{1D784}', CharMapAction::SubChar('u')), // MATHEMATICAL SANS-SERIF BOLD SMALL UPSILON
+    ('\u{1D785}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL SANS-SERIF BOLD SMALL PHI
+    ('\u{1D786}', CharMapAction::SubChar('\u{03C7}')), // MATHEMATICAL SANS-SERIF BOLD SMALL CHI
+    ('\u{1D787}', CharMapAction::SubChar('\u{03C8}')), // MATHEMATICAL SANS-SERIF BOLD SMALL PSI
+    ('\u{1D788}', CharMapAction::SubChar('\u{03C9}')), // MATHEMATICAL SANS-SERIF BOLD SMALL OMEGA
+    ('\u{1D789}', CharMapAction::SubChar('\u{2202}')), // MATHEMATICAL SANS-SERIF BOLD PARTIAL DIFFERENTIAL
+    ('\u{1D78A}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL SANS-SERIF BOLD EPSILON SYMBOL
+    ('\u{1D78B}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD THETA SYMBOL
+    ('\u{1D78C}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL SANS-SERIF BOLD KAPPA SYMBOL
+    ('\u{1D78D}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL SANS-SERIF BOLD PHI SYMBOL
+    ('\u{1D78E}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF BOLD RHO SYMBOL
+    ('\u{1D78F}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL SANS-SERIF BOLD PI SYMBOL
+    ('\u{1D790}', CharMapAction::SubChar('A')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL ALPHA
+    ('\u{1D791}', CharMapAction::SubChar('B')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL BETA
+    ('\u{1D792}', CharMapAction::SubChar('\u{0393}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL GAMMA
+    ('\u{1D793}', CharMapAction::SubChar('\u{0394}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL DELTA
+    ('\u{1D794}', CharMapAction::SubChar('E')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL EPSILON
+    ('\u{1D795}', CharMapAction::SubChar('Z')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL ZETA
+    ('\u{1D796}', CharMapAction::SubChar('H')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL ETA
+    ('\u{1D797}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL THETA
+    ('\u{1D798}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL IOTA
+    ('\u{1D799}', CharMapAction::SubChar('K')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL KAPPA
+    ('\u{1D79A}', CharMapAction::SubChar('\u{0245}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL LAMDA
+    ('\u{1D79B}', CharMapAction::SubChar('M')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL MU
+    ('\u{1D79C}', CharMapAction::SubChar('N')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL NU
+    ('\u{1D79D}', CharMapAction::SubChar('\u{039E}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL XI
+    ('\u{1D79E}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL OMICRON
+    ('\u{1D79F}', CharMapAction::SubChar('\u{03A0}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL PI
+    ('\u{1D7A0}', CharMapAction::SubChar('P')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL RHO
+    ('\u{1D7A1}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL THETA SYMBOL
+    ('\u{1D7A2}', CharMapAction::SubChar('\u{01A9}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL SIGMA
+    ('\u{1D7A3}', CharMapAction::SubChar('T')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL TAU
+    ('\u{1D7A4}', CharMapAction::SubChar('Y')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL UPSILON
+    ('\u{1D7A5}', CharMapAction::SubChar('\u{03A6}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL PHI
+    ('\u{1D7A6}', CharMapAction::SubChar('X')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL CHI
+    ('\u{1D7A7}', CharMapAction::SubChar('\u{03A8}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL PSI
+    ('\u{1D7A8}', CharMapAction::SubChar('\u{03A9}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC CAPITAL OMEGA
+    ('\u{1D7A9}', CharMapAction::SubChar('\u{2207}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC NABLA
+    ('\u{1D7AA}', CharMapAction::SubChar('a')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL ALPHA
+    ('\u{1D7AB}', CharMapAction::SubChar('\u{00DF}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL BETA
+    ('\u{1D7AC}', CharMapAction::SubChar('y')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL GAMMA
+    ('\u{1D7AD}', CharMapAction::SubChar('\u{1E9F}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL DELTA
+    ('\u{1D7AE}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL EPSILON
+    ('\u{1D7AF}', CharMapAction::SubChar('\u{03B6}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL ZETA
+    ('\u{1D7B0}', CharMapAction::SubStr("n\u{0329}")), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL ETA
+    ('\u{1D7B1}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL THETA
+    ('\u{1D7B2}', CharMapAction::SubChar('i')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL IOTA
+    ('\u{1D7B3}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL KAPPA
+    ('\u{1D7B4}', CharMapAction::SubChar('\u{03BB}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL LAMDA
+    ('\u{1D7B5}', CharMapAction::SubChar('\u{03BC}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL MU
+    ('\u{1D7B6}', CharMapAction::SubChar('v')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL NU
+    ('\u{1D7B7}', CharMapAction::SubChar('\u{03BE}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL XI
+    ('\u{1D7B8}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL OMICRON
+    ('\u{1D7B9}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL PI
+    ('\u{1D7BA}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL RHO
+    ('\u{1D7BB}', CharMapAction::SubChar('\u{03C2}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL FINAL SIGMA
+    ('\u{1D7BC}', CharMapAction::SubChar('o')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL SIGMA
+    ('\u{1D7BD}', CharMapAction::SubChar('\u{1D1B}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL TAU
+    ('\u{1D7BE}', CharMapAction::SubChar('u')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL UPSILON
+    ('\u{1D7BF}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL PHI
+    ('\u{1D7C0}', CharMapAction::SubChar('\u{03C7}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL CHI
+    ('\u{1D7C1}', CharMapAction::SubChar('\u{03C8}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL PSI
+    ('\u{1D7C2}', CharMapAction::SubChar('\u{03C9}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC SMALL OMEGA
+    ('\u{1D7C3}', CharMapAction::SubChar('\u{2202}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC PARTIAL DIFFERENTIAL
+    ('\u{1D7C4}', CharMapAction::SubChar('\u{A793}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC EPSILON SYMBOL
+    ('\u{1D7C5}', CharMapAction::SubStr("O\u{0335}")), // MATHEMATICAL SANS-SERIF BOLD ITALIC THETA SYMBOL
+    ('\u{1D7C6}', CharMapAction::SubChar('\u{0138}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC KAPPA SYMBOL
+    ('\u{1D7C7}', CharMapAction::SubChar('\u{0278}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC PHI SYMBOL
+    ('\u{1D7C8}', CharMapAction::SubChar('p')), // MATHEMATICAL SANS-SERIF BOLD ITALIC RHO SYMBOL
+    ('\u{1D7C9}', CharMapAction::SubChar('\u{03C0}')), // MATHEMATICAL SANS-SERIF BOLD ITALIC PI SYMBOL
+    ('\u{1D7CA}', CharMapAction::SubChar('F')), // MATHEMATICAL BOLD CAPITAL DIGAMMA
+    ('\u{1D7CB}', CharMapAction::SubChar('\u{03DD}')), // MATHEMATICAL BOLD SMALL DIGAMMA
+    ('\u{1D7CE}', CharMapAction::SubChar('O')), // MATHEMATICAL BOLD DIGIT ZERO
+    ('\u{1D7CF}', CharMapAction::SubChar('l')), // MATHEMATICAL BOLD DIGIT ONE
+    ('\u{1D7D0}', CharMapAction::SubChar('2')), // MATHEMATICAL BOLD DIGIT TWO
+    ('\u{1D7D1}', CharMapAction::SubChar('3')), // MATHEMATICAL BOLD DIGIT THREE
+    ('\u{1D7D2}', CharMapAction::SubChar('4')), // MATHEMATICAL BOLD DIGIT FOUR
+    ('\u{1D7D3}', CharMapAction::SubChar('5')), // MATHEMATICAL BOLD DIGIT FIVE
+    ('\u{1D7D4}', CharMapAction::SubChar('6')), // MATHEMATICAL BOLD DIGIT SIX
+    ('\u{1D7D5}', CharMapAction::SubChar('7')), // MATHEMATICAL BOLD DIGIT SEVEN
+    ('\u{1D7D6}', CharMapAction::SubChar('8')), // MATHEMATICAL BOLD DIGIT EIGHT
+    ('\u{1D7D7}', CharMapAction::SubChar('9')), // MATHEMATICAL BOLD DIGIT NINE
+    ('\u{1D7D8}', CharMapAction::SubChar('O')), // MATHEMATICAL DOUBLE-STRUCK DIGIT ZERO
+    ('\u{1D7D9}', CharMapAction::SubChar('l')), // MATHEMATICAL DOUBLE-STRUCK DIGIT ONE
+    ('\u{1D7DA}', CharMapAction::SubChar('2')), // MATHEMATICAL DOUBLE-STRUCK DIGIT TWO
+    ('\u{1D7DB}', CharMapAction::SubChar('3')), // MATHEMATICAL DOUBLE-STRUCK DIGIT THREE
+    ('\u{1D7DC}', CharMapAction::SubChar('4')), // MATHEMATICAL DOUBLE-STRUCK DIGIT FOUR
+    ('\u{1D7DD}', CharMapAction::SubChar('5')), // MATHEMATICAL DOUBLE-STRUCK DIGIT FIVE
+    ('\u{1D7DE}', CharMapAction::SubChar('6')), // MATHEMATICAL DOUBLE-STRUCK DIGIT SIX
+    ('\u{1D7DF}', CharMapAction::SubChar('7')), // MATHEMATICAL DOUBLE-STRUCK DIGIT SEVEN
+    ('\u{1D7E0}', CharMapAction::SubChar('8')), // MATHEMATICAL DOUBLE-STRUCK DIGIT EIGHT
+    ('\u{1D7E1}', CharMapAction::SubChar('9')), // MATHEMATICAL DOUBLE-STRUCK DIGIT NINE
+    ('\u{1D7E2}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF DIGIT ZERO
+    ('\u{1D7E3}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF DIGIT ONE
+    ('\u{1D7E4}', CharMapAction::SubChar('2')), // MATHEMATICAL SANS-SERIF DIGIT TWO
+    ('\u{1D7E5}', CharMapAction::SubChar('3')), // MATHEMATICAL SANS-SERIF DIGIT THREE
+    ('\u{1D7E6}', CharMapAction::SubChar('4')), // MATHEMATICAL SANS-SERIF DIGIT FOUR
+    ('\u{1D7E7}', CharMapAction::SubChar('5')), // MATHEMATICAL SANS-SERIF DIGIT FIVE
+    ('\u{1D7E8}', CharMapAction::SubChar('6')), // MATHEMATICAL SANS-SERIF DIGIT SIX
+    ('\u{1D7E9}', CharMapAction::SubChar('7')), // MATHEMATICAL SANS-SERIF DIGIT SEVEN
+    ('\u{1D7EA}', CharMapAction::SubChar('8')), // MATHEMATICAL SANS-SERIF DIGIT EIGHT
+    ('\u{1D7EB}', CharMapAction::SubChar('9')), // MATHEMATICAL SANS-SERIF DIGIT NINE
+    ('\u{1D7EC}', CharMapAction::SubChar('O')), // MATHEMATICAL SANS-SERIF BOLD DIGIT ZERO
+    ('\u{1D7ED}', CharMapAction::SubChar('l')), // MATHEMATICAL SANS-SERIF BOLD DIGIT ONE
+    ('\u{1D7EE}', CharMapAction::SubChar('2')), // MATHEMATICAL SANS-SERIF BOLD DIGIT TWO
+    ('\u{1D7EF}', CharMapAction::SubChar('3')), // MATHEMATICAL SANS-SERIF BOLD DIGIT THREE
+    ('\u{1D7F0}', CharMapAction::SubChar('4')), // MATHEMATICAL SANS-SERIF BOLD DIGIT FOUR
+    ('\u{1D7F1}', CharMapAction::SubChar('5')), // MATHEMATICAL SANS-SERIF BOLD DIGIT FIVE
+    ('\u{1D7F2}', CharMapAction::SubChar('6')), // MATHEMATICAL SANS-SERIF BOLD DIGIT SIX
+    ('\u{1D7F3}', CharMapAction::SubChar('7')), // MATHEMATICAL SANS-SERIF BOLD DIGIT SEVEN
+    ('\u{1D7F4}', CharMapAction::SubChar('8')), // MATHEMATICAL SANS-SERIF BOLD DIGIT EIGHT
+    ('\u{1D7F5}', CharMapAction::SubChar('9')), // MATHEMATICAL SANS-SERIF BOLD DIGIT NINE
+    ('\u{1D7F6}', CharMapAction::SubChar('O')), // MATHEMATICAL MONOSPACE DIGIT ZERO
+    ('\u{1D7F7}', CharMapAction::SubChar('l')), // MATHEMATICAL MONOSPACE DIGIT ONE
+    ('\u{1D7F8}', CharMapAction::SubChar('2')), // MATHEMATICAL MONOSPACE DIGIT TWO
+    ('\u{1D7F9}', CharMapAction::SubChar('3')), // MATHEMATICAL MONOSPACE DIGIT THREE
+    ('\u{1D7FA}', CharMapAction::SubChar('4')), // MATHEMATICAL MONOSPACE DIGIT FOUR
+    ('\u{1D7FB}', CharMapAction::SubChar('5')), // MATHEMATICAL MONOSPACE DIGIT FIVE
+    ('\u{1D7FC}', CharMapAction::SubChar('6')), // MATHEMATICAL MONOSPACE DIGIT SIX
+    ('\u{1D7FD}', CharMapAction::SubChar('7')), // MATHEMATICAL MONOSPACE DIGIT SEVEN
+    ('\u{1D7FE}', CharMapAction::SubChar('8')), // MATHEMATICAL MONOSPACE DIGIT EIGHT
+    ('\u{1D7FF}', CharMapAction::SubChar('9')), // MATHEMATICAL MONOSPACE DIGIT NINE
+    ('\u{1E8C7}', CharMapAction::SubChar('l')), // MENDE KIKAKUI DIGIT ONE
+    ('\u{1E8C8}', CharMapAction::SubChar('\u{2220}')), // MENDE KIKAKUI DIGIT TWO
+    ('\u{1E8C9}', CharMapAction::SubChar('\u{0663}')), // MENDE KIKAKUI DIGIT THREE
+    ('\u{1E8CB}', CharMapAction::SubChar('8')), // MENDE KIKAKUI DIGIT FIVE
+    ('\u{1E8CC}', CharMapAction::SubChar('\u{2202}')), // MENDE KIKAKUI DIGIT SIX
+    ('\u{1E8CD}', CharMapAction::SubStr("\u{2202}\u{0335}")), // MENDE KIKAKUI DIGIT SEVEN
+    ('\u{1EE00}', CharMapAction::SubChar('l')), // ARABIC MATHEMATICAL ALEF
+    ('\u{1EE01}', CharMapAction::SubChar('\u{0628}')), // ARABIC MATHEMATICAL BEH
+    ('\u{1EE02}', CharMapAction::SubChar('\u{062C}')), // ARABIC MATHEMATICAL JEEM
+    ('\u{1EE03}', CharMapAction::SubChar('\u{062F}')), // ARABIC MATHEMATICAL DAL
+    ('\u{1EE05}', CharMapAction::SubChar('\u{0648}')), // ARABIC MATHEMATICAL WAW
+    ('\u{1EE06}', CharMapAction::SubChar('\u{0632}')), // ARABIC MATHEMATICAL ZAIN
+    ('\u{1EE07}', CharMapAction::SubChar('\u{062D}')), // ARABIC MATHEMATICAL HAH
+    ('\u{1EE08}', CharMapAction::SubChar('\u{0637}')), // ARABIC MATHEMATICAL TAH
+    ('\u{1EE09}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL YEH
+    ('\u{1EE0A}', CharMapAction::SubChar('\u{0643}')), // ARABIC MATHEMATICAL KAF
+    ('\u{1EE0B}', CharMapAction::SubChar('\u{0644}')), // ARABIC MATHEMATICAL LAM
+    ('\u{1EE0C}', CharMapAction::SubChar('\u{0645}')), // ARABIC MATHEMATICAL MEEM
+    ('\u{1EE0D}', CharMapAction::SubChar('\u{0646}')), // ARABIC MATHEMATICAL NOON
+    ('\u{1EE0E}', CharMapAction::SubChar('\u{0633}')), // ARABIC MATHEMATICAL SEEN
+    ('\u{1EE0F}', CharMapAction::SubChar('\u{0639}')), // ARABIC MATHEMATICAL AIN
+    ('\u{1EE10}', CharMapAction::SubChar('\u{0641}')), // ARABIC MATHEMATICAL FEH
+    ('\u{1EE11}', CharMapAction::SubChar('\u{0635}')), // ARABIC MATHEMATICAL SAD
+    ('\u{1EE12}', CharMapAction::SubChar('\u{0642}')), // ARABIC MATHEMATICAL QAF
+    ('\u{1EE13}', CharMapAction::SubChar('\u{0631}')), // ARABIC MATHEMATICAL REH
+    ('\u{1EE14}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC MATHEMATICAL SHEEN
+    ('\u{1EE15}', CharMapAction::SubChar('\u{062A}')), // ARABIC MATHEMATICAL TEH
+    ('\u{1EE16}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC MATHEMATICAL THEH
+    ('\u{1EE17}', CharMapAction::SubChar('\u{062E}')), // ARABIC MATHEMATICAL KHAH
+    ('\u{1EE18}', CharMapAction::SubChar('\u{0630}')), // ARABIC MATHEMATICAL THAL
+    ('\u{1EE19}', CharMapAction::SubChar('\u{0636}')), // ARABIC MATHEMATICAL DAD
+    ('\u{1EE1A}', CharMapAction::SubChar('\u{0638}')), // ARABIC MATHEMATICAL ZAH
+    ('\u{1EE1B}', CharMapAction::SubChar('\u{063A}')), // ARABIC MATHEMATICAL GHAIN
+    ('\u{1EE1C}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL DOTLESS BEH
+    ('\u{1EE1D}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL DOTLESS NOON
+    ('\u{1EE1E}', CharMapAction::SubChar('\u{06A1}')), // ARABIC MATHEMATICAL DOTLESS FEH
+    ('\u{1EE1F}', CharMapAction::SubChar('\u{06A1}')), // ARABIC MATHEMATICAL DOTLESS QAF
+    ('\u{1EE21}', CharMapAction::SubChar('\u{0628}')), // ARABIC MATHEMATICAL INITIAL BEH
+    ('\u{1EE22}', CharMapAction::SubChar('\u{062C}')), // ARABIC MATHEMATICAL INITIAL JEEM
+    ('\u{1EE24}', CharMapAction::SubChar('o')), // ARABIC MATHEMATICAL INITIAL HEH
+    ('\u{1EE27}', CharMapAction::SubChar('\u{062D}')), // ARABIC MATHEMATICAL INITIAL HAH
+    ('\u{1EE29}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL INITIAL YEH
+    ('\u{1EE2A}', CharMapAction::SubChar('\u{0643}')), // ARABIC MATHEMATICAL INITIAL KAF
+    ('\u{1EE2B}', CharMapAction::SubChar('\u{0644}')), // ARABIC MATHEMATICAL INITIAL LAM
+    ('\u{1EE2C}', CharMapAction::SubChar('\u{0645}')), // ARABIC MATHEMATICAL INITIAL MEEM
+    ('\u{1EE2D}', CharMapAction::SubChar('\u{0646}')), // ARABIC MATHEMATICAL INITIAL NOON
+    ('\u{1EE2E}', CharMapAction::SubChar('\u{0633}')), // ARABIC MATHEMATICAL INITIAL SEEN
+    ('\u{1EE2F}', CharMapAction::SubChar('\u{0639}')), // ARABIC MATHEMATICAL INITIAL AIN
+    ('\u{1EE30}', CharMapAction::SubChar('\u{0641}')), // ARABIC MATHEMATICAL INITIAL FEH
+    ('\u{1EE31}', CharMapAction::SubChar('\u{0635}')), // ARABIC MATHEMATICAL INITIAL SAD
+    ('\u{1EE32}', CharMapAction::SubChar('\u{0642}')), // ARABIC MATHEMATICAL INITIAL QAF
+    ('\u{1EE34}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC MATHEMATICAL INITIAL SHEEN
+    ('\u{1EE35}', CharMapAction::SubChar('\u{062A}')), // ARABIC MATHEMATICAL INITIAL TEH
+    ('\u{1EE36}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC MATHEMATICAL INITIAL THEH
+    ('\u{1EE37}', CharMapAction::SubChar('\u{062E}')), // ARABIC MATHEMATICAL INITIAL KHAH
+    ('\u{1EE39}', CharMapAction::SubChar('\u{0636}')), // ARABIC MATHEMATICAL INITIAL DAD
+    ('\u{1EE3B}', CharMapAction::SubChar('\u{063A}')), // ARABIC MATHEMATICAL INITIAL GHAIN
+    ('\u{1EE42}', CharMapAction::SubChar('\u{062C}')), // ARABIC MATHEMATICAL TAILED JEEM
+    ('\u{1EE47}', CharMapAction::SubChar('\u{062D}')), // ARABIC MATHEMATICAL TAILED HAH
+    ('\u{1EE49}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL TAILED YEH
+    ('\u{1EE4B}', CharMapAction::SubChar('\u{0644}')), // ARABIC MATHEMATICAL TAILED LAM
+    ('\u{1EE4D}', CharMapAction::SubChar('\u{0646}')), // ARABIC MATHEMATICAL TAILED NOON
+    ('\u{1EE4E}', CharMapAction::SubChar('\u{0633}')), // ARABIC MATHEMATICAL TAILED SEEN
+    ('\u{1EE4F}', CharMapAction::SubChar('\u{0639}')), // ARABIC MATHEMATICAL TAILED AIN
+    ('\u{1EE51}', CharMapAction::SubChar('\u{0635}')), // ARABIC MATHEMATICAL TAILED SAD
+    ('\u{1EE52}', CharMapAction::SubChar('\u{0642}')), // ARABIC MATHEMATICAL TAILED QAF
+    ('\u{1EE54}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC MATHEMATICAL TAILED SHEEN
+    ('\u{1EE57}', CharMapAction::SubChar('\u{062E}')), // ARABIC MATHEMATICAL TAILED KHAH
+    ('\u{1EE59}', CharMapAction::SubChar('\u{0636}')), // ARABIC MATHEMATICAL TAILED DAD
+    ('\u{1EE5B}', CharMapAction::SubChar('\u{063A}')), // ARABIC MATHEMATICAL TAILED GHAIN
+    ('\u{1EE5D}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL TAILED DOTLESS NOON
+    ('\u{1EE5F}', CharMapAction::SubChar('\u{06A1}')), // ARABIC MATHEMATICAL TAILED DOTLESS QAF
+    ('\u{1EE61}', CharMapAction::SubChar('\u{0628}')), // ARABIC MATHEMATICAL STRETCHED BEH
+    ('\u{1EE62}', CharMapAction::SubChar('\u{062C}')), // ARABIC MATHEMATICAL STRETCHED JEEM
+    ('\u{1EE64}', CharMapAction::SubChar('o')), // ARABIC MATHEMATICAL STRETCHED HEH
+    ('\u{1EE67}', CharMapAction::SubChar('\u{062D}')), // ARABIC MATHEMATICAL STRETCHED HAH
+    ('\u{1EE68}', CharMapAction::SubChar('\u{0637}')), // ARABIC MATHEMATICAL STRETCHED TAH
+    ('\u{1EE69}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL STRETCHED YEH
+    ('\u{1EE6A}', CharMapAction::SubChar('\u{0643}')), // ARABIC MATHEMATICAL STRETCHED KAF
+    ('\u{1EE6C}', CharMapAction::SubChar('\u{0645}')), // ARABIC MATHEMATICAL STRETCHED MEEM
+    ('\u{1EE6D}', CharMapAction::SubChar('\u{0646}')), // ARABIC MATHEMATICAL STRETCHED NOON
+    ('\u{1EE6E}', CharMapAction::SubChar('\u{0633}')), // ARABIC MATHEMATICAL STRETCHED SEEN
+    ('\u{1EE6F}', CharMapAction::SubChar('\u{0639}')), // ARABIC MATHEMATICAL STRETCHED AIN
+    ('\u{1EE70}', CharMapAction::SubChar('\u{0641}')), // ARABIC MATHEMATICAL STRETCHED FEH
+    ('\u{1EE71}', CharMapAction::SubChar('\u{0635}')), // ARABIC MATHEMATICAL STRETCHED SAD
+    ('\u{1EE72}', CharMapAction::SubChar('\u{0642}')), // ARABIC MATHEMATICAL STRETCHED QAF
+    ('\u{1EE74}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC MATHEMATICAL STRETCHED SHEEN
+    ('\u{1EE75}', CharMapAction::SubChar('\u{062A}')), // ARABIC MATHEMATICAL STRETCHED TEH
+    ('\u{1EE76}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC MATHEMATICAL STRETCHED THEH
+    ('\u{1EE77}', CharMapAction::SubChar('\u{062E}')), // ARABIC MATHEMATICAL STRETCHED KHAH
+    ('\u{1EE79}', CharMapAction::SubChar('\u{0636}')), // ARABIC MATHEMATICAL STRETCHED DAD
+    ('\u{1EE7A}', CharMapAction::SubChar('\u{0638}')), // ARABIC MATHEMATICAL STRETCHED ZAH
+    ('\u{1EE7B}', CharMapAction::SubChar('\u{063A}')), // ARABIC MATHEMATICAL STRETCHED GHAIN
+    ('\u{1EE7C}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL STRETCHED DOTLESS BEH
+    ('\u{1EE7E}', CharMapAction::SubChar('\u{06A1}')), // ARABIC MATHEMATICAL STRETCHED DOTLESS FEH
+    ('\u{1EE80}', CharMapAction::SubChar('l')), // ARABIC MATHEMATICAL LOOPED ALEF
+    ('\u{1EE81}', CharMapAction::SubChar('\u{0628}')), // ARABIC MATHEMATICAL LOOPED BEH
+    ('\u{1EE82}', CharMapAction::SubChar('\u{062C}')), // ARABIC MATHEMATICAL LOOPED JEEM
+    ('\u{1EE83}', CharMapAction::SubChar('\u{062F}')), // ARABIC MATHEMATICAL LOOPED DAL
+    ('\u{1EE84}', CharMapAction::SubChar('o')), // ARABIC MATHEMATICAL LOOPED HEH
+    ('\u{1EE85}', CharMapAction::SubChar('\u{0648}')), // ARABIC MATHEMATICAL LOOPED WAW
+    ('\u{1EE86}', CharMapAction::SubChar('\u{0632}')), // ARABIC MATHEMATICAL LOOPED ZAIN
+    ('\u{1EE87}', CharMapAction::SubChar('\u{062D}')), // ARABIC MATHEMATICAL LOOPED HAH
+    ('\u{1EE88}', CharMapAction::SubChar('\u{0637}')), // ARABIC MATHEMATICAL LOOPED TAH
+    ('\u{1EE89}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL LOOPED YEH
+    ('\u{1EE8B}', CharMapAction::SubChar('\u{0644}')), // ARABIC MATHEMATICAL LOOPED LAM
+    ('\u{1EE8C}', CharMapAction::SubChar('\u{0645}')), // ARABIC MATHEMATICAL LOOPED MEEM
+    ('\u{1EE8D}', CharMapAction::SubChar('\u{0646}')), // ARABIC MATHEMATICAL LOOPED NOON
+    ('\u{1EE8E}', CharMapAction::SubChar('\u{0633}')), // ARABIC MATHEMATICAL LOOPED SEEN
+    ('\u{1EE8F}', CharMapAction::SubChar('\u{0639}')), // ARABIC MATHEMATICAL LOOPED AIN
+    ('\u{1EE90}', CharMapAction::SubChar('\u{0641}')), // ARABIC MATHEMATICAL LOOPED FEH
+    ('\u{1EE91}', CharMapAction::SubChar('\u{0635}')), // ARABIC MATHEMATICAL LOOPED SAD
+    ('\u{1EE92}', CharMapAction::SubChar('\u{0642}')), // ARABIC MATHEMATICAL LOOPED QAF
+    ('\u{1EE93}', CharMapAction::SubChar('\u{0631}')), // ARABIC MATHEMATICAL LOOPED REH
+    ('\u{1EE94}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC MATHEMATICAL LOOPED SHEEN
+    ('\u{1EE95}', CharMapAction::SubChar('\u{062A}')), // ARABIC MATHEMATICAL LOOPED TEH
+    ('\u{1EE96}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC MATHEMATICAL LOOPED THEH
+    ('\u{1EE97}', CharMapAction::SubChar('\u{062E}')), // ARABIC MATHEMATICAL LOOPED KHAH
+    ('\u{1EE98}', CharMapAction::SubChar('\u{0630}')), // ARABIC MATHEMATICAL LOOPED THAL
+    ('\u{1EE99}', CharMapAction::SubChar('\u{0636}')), // ARABIC MATHEMATICAL LOOPED DAD
+    ('\u{1EE9A}', CharMapAction::SubChar('\u{0638}')), // ARABIC MATHEMATICAL LOOPED ZAH
+    ('\u{1EE9B}', CharMapAction::SubChar('\u{063A}')), // ARABIC MATHEMATICAL LOOPED GHAIN
+    ('\u{1EEA1}', CharMapAction::SubChar('\u{0628}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK BEH
+    ('\u{1EEA2}', CharMapAction::SubChar('\u{062C}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK JEEM
+    ('\u{1EEA3}', CharMapAction::SubChar('\u{062F}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK DAL
+    ('\u{1EEA5}', CharMapAction::SubChar('\u{0648}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK WAW
+    ('\u{1EEA6}', CharMapAction::SubChar('\u{0632}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK ZAIN
+    ('\u{1EEA7}', CharMapAction::SubChar('\u{062D}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK HAH
+    ('\u{1EEA8}', CharMapAction::SubChar('\u{0637}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK TAH
+    ('\u{1EEA9}', CharMapAction::SubChar('\u{0649}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK YEH
+    ('\u{1EEAB}', CharMapAction::SubChar('\u{0644}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK LAM
+    ('\u{1EEAC}', CharMapAction::SubChar('\u{0645}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK MEEM
+    ('\u{1EEAD}', CharMapAction::SubChar('\u{0646}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK NOON
+    ('\u{1EEAE}', CharMapAction::SubChar('\u{0633}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK SEEN
+    ('\u{1EEAF}', CharMapAction::SubChar('\u{0639}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK AIN
+    ('\u{1EEB0}', CharMapAction::SubChar('\u{0641}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK FEH
+    ('\u{1EEB1}', CharMapAction::SubChar('\u{0635}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK SAD
+    ('\u{1EEB2}', CharMapAction::SubChar('\u{0642}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK QAF
+    ('\u{1EEB3}', CharMapAction::SubChar('\u{0631}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK REH
+    ('\u{1EEB4}', CharMapAction::SubStr("\u{0633}\u{06DB}")), // ARABIC MATHEMATICAL DOUBLE-STRUCK SHEEN
+    ('\u{1EEB5}', CharMapAction::SubChar('\u{062A}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK TEH
+    ('\u{1EEB6}', CharMapAction::SubStr("\u{0649}\u{06DB}")), // ARABIC MATHEMATICAL DOUBLE-STRUCK THEH
+    ('\u{1EEB7}', CharMapAction::SubChar('\u{062E}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK KHAH
+    ('\u{1EEB8}', CharMapAction::SubChar('\u{0630}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK THAL
+    ('\u{1EEB9}', CharMapAction::SubChar('\u{0636}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK DAD
+    ('\u{1EEBA}', CharMapAction::SubChar('\u{0638}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK ZAH
+    ('\u{1EEBB}', CharMapAction::SubChar('\u{063A}')), // ARABIC MATHEMATICAL DOUBLE-STRUCK GHAIN
+    ('\u{1F100}', CharMapAction::SubStr("O.")), // DIGIT ZERO FULL STOP
+    ('\u{1F101}', CharMapAction::SubStr("O,")), // DIGIT ZERO COMMA
+    ('\u{1F102}', CharMapAction::SubStr("l,")), // DIGIT ONE COMMA
+    ('\u{1F103}', CharMapAction::SubStr("2,")), // DIGIT TWO COMMA
+    ('\u{1F104}', CharMapAction::SubStr("3,")), // DIGIT THREE COMMA
+    ('\u{1F105}', CharMapAction::SubStr("4,")), // DIGIT FOUR COMMA
+    ('\u{1F106}', CharMapAction::SubStr("5,")), // DIGIT FIVE COMMA
+    ('\u{1F107}', CharMapAction::SubStr("6,")), // DIGIT SIX COMMA
+    ('\u{1F108}', CharMapAction::SubStr("7,")), // DIGIT SEVEN COMMA
+    ('\u{1F109}', CharMapAction::SubStr("8,")), // DIGIT EIGHT COMMA
+    ('\u{1F10A}', CharMapAction::SubStr("9,")), // DIGIT NINE COMMA
+    ('\u{1F10F}', CharMapAction::SubStr("$\u{20E0}")), // CIRCLED DOLLAR SIGN WITH OVERLAID BACKSLASH
+    ('\u{1F110}', CharMapAction::SubStr("(A)")), // PARENTHESIZED LATIN CAPITAL LETTER A
+    ('\u{1F111}', CharMapAction::SubStr("(B)")), // PARENTHESIZED LATIN CAPITAL LETTER B
+    ('\u{1F112}', CharMapAction::SubStr("(C)")), // PARENTHESIZED LATIN CAPITAL LETTER C
+    ('\u{1F113}', CharMapAction::SubStr("(D)")), // PARENTHESIZED LATIN CAPITAL LETTER D
+    ('\u{1F114}', CharMapAction::SubStr("(E)")), // PARENTHESIZED LATIN CAPITAL LETTER E
+    ('\u{1F115}', CharMapAction::SubStr("(F)")), // PARENTHESIZED LATIN CAPITAL LETTER F
+    ('\u{1F116}', CharMapAction::SubStr("(G)")), // PARENTHESIZED LATIN CAPITAL LETTER G
+    ('\u{1F117}', CharMapAction::SubStr("(H)")), // PARENTHESIZED LATIN CAPITAL LETTER H
+    ('\u{1F118}', CharMapAction::SubStr("(l)")), // PARENTHESIZED LATIN CAPITAL LETTER I
+    ('\u{1F119}', CharMapAction::SubStr("(J)")), // PARENTHESIZED LATIN CAPITAL LETTER J
+    ('\u{1F11A}', CharMapAction::SubStr("(K)")), // PARENTHESIZED LATIN CAPITAL LETTER K
+    ('\u{1F11B}', CharMapAction::SubStr("(L)")), // PARENTHESIZED LATIN CAPITAL LETTER L
+    ('\u{1F11C}', CharMapAction::SubStr("(M)")), // PARENTHESIZED LATIN CAPITAL LETTER M
+    ('\u{1F11D}', CharMapAction::SubStr("(N)")), // PARENTHESIZED LATIN CAPITAL LETTER N
+    ('\u{1F11E}', CharMapAction::SubStr("(O)")), // PARENTHESIZED LATIN CAPITAL LETTER O
+    ('\u{1F11F}', CharMapAction::SubStr("(P)")), // PARENTHESIZED LATIN CAPITAL LETTER P
+    ('\u{1F120}', CharMapAction::SubStr("(Q)")), // PARENTHESIZED LATIN CAPITAL LETTER Q
+    ('\u{1F121}', CharMapAction::SubStr("(R)")), // PARENTHESIZED LATIN CAPITAL LETTER R
+    ('\u{1F122}', CharMapAction::SubStr("(S)")), // PARENTHESIZED LATIN CAPITAL LETTER S
+    ('\u{1F123}', CharMapAction::SubStr("(T)")), // PARENTHESIZED LATIN CAPITAL LETTER T
+    ('\u{1F124}', CharMapAction::SubStr("(U)")), // PARENTHESIZED LATIN CAPITAL LETTER U
+    ('\u{1F125}', CharMapAction::SubStr("(V)")), // PARENTHESIZED LATIN CAPITAL LETTER V
+    ('\u{1F126}', CharMapAction::SubStr("(W)")), // PARENTHESIZED LATIN CAPITAL LETTER W
+    ('\u{1F127}', CharMapAction::SubStr("(X)")), // PARENTHESIZED LATIN CAPITAL LETTER X
+    ('\u{1F128}', CharMapAction::SubStr("(Y)")), // PARENTHESIZED LATIN CAPITAL LETTER Y
+    ('\u{1F129}', CharMapAction::SubStr("(Z)")), // PARENTHESIZED LATIN CAPITAL LETTER Z
+    ('\u{1F12A}', CharMapAction::SubStr("(S)")), // TORTOISE SHELL BRACKETED LATIN CAPITAL LETTER S
+    ('\u{1F16D}', CharMapAction::SubStr("\u{33C4}\u{0009}\u{20DD}")), // CIRCLED CC
+    ('\u{1F16E}', CharMapAction::SubStr("C\u{20E0}")), // CIRCLED C WITH OVERLAID BACKSLASH
+    ('\u{1F240}', CharMapAction::SubStr("(\u{672C})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-672C
+    ('\u{1F241}', CharMapAction::SubStr("(\u{4E09})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-4E09
+    ('\u{1F242}', CharMapAction::SubStr("(\u{4E8C})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-4E8C
+    ('\u{1F243}', CharMapAction::SubStr("(\u{5B89})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-5B89
+    ('\u{1F244}', CharMapAction::SubStr("(\u{70B9})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-70B9
+    ('\u{1F245}', CharMapAction::SubStr("(\u{6253})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-6253
+    ('\u{1F246}', CharMapAction::SubStr("(\u{76D7})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-76D7
+    ('\u{1F247}', CharMapAction::SubStr("(\u{52DD})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-52DD
+    ('\u{1F248}', CharMapAction::SubStr("(\u{6557})")), // TORTOISE SHELL BRACKETED CJK UNIFIED IDEOGRAPH-6557
+    ('\u{1F312}', CharMapAction::SubChar('\u{263D}')), // WAXING CRESCENT MOON SYMBOL
+    ('\u{1F318}', CharMapAction::SubChar('\u{263E}')), // WANING CRESCENT MOON SYMBOL
+    ('\u{1F319}', CharMapAction::SubChar('\u{263D}')), // CRESCENT MOON
+    ('\u{1F700}', CharMapAction::SubStr("QE")), // ALCHEMICAL SYMBOL FOR QUINTESSENCE
+    ('\u{1F701}', CharMapAction::SubChar('\u{A658}')), // ALCHEMICAL SYMBOL FOR AIR
+    ('\u{1F702}', CharMapAction::SubChar('\u{0394}')), // ALCHEMICAL SYMBOL FOR FIRE
+    ('\u{1F704}', CharMapAction::SubChar('\u{102BC}')), // ALCHEMICAL SYMBOL FOR WATER
+    ('\u{1F707}', CharMapAction::SubStr("AR")), // ALCHEMICAL SYMBOL FOR AQUA REGIA-2
+    ('\u{1F708}', CharMapAction::SubStr("V\u{1DE4}")), // ALCHEMICAL SYMBOL FOR AQUA VITAE
+    ('\u{1F70A}', CharMapAction::SubChar('\u{2629}')), // ALCHEMICAL SYMBOL FOR VINEGAR
+    ('\u{1F714}', CharMapAction::SubStr("O\u{0335}")), // ALCHEMICAL SYMBOL FOR SALT
+    ('\u{1F728}', CharMapAction::SubChar('\u{102A8}')), // ALCHEMICAL SYMBOL FOR VERDIGRIS
+    ('\u{1F73A}', CharMapAction::SubChar('\u{29DF}')), // ALCHEMICAL SYMBOL FOR ARSENIC
+    ('\u{1F74C}', CharMapAction::SubChar('C')), // ALCHEMICAL SYMBOL FOR CALX
+    ('\u{1F754}', CharMapAction::SubChar('\u{16DC}')), // ALCHEMICAL SYMBOL FOR SOAP
+    ('\u{1F755}', CharMapAction::SubChar('\u{22A1}')), // ALCHEMICAL SYMBOL FOR URINE
+    ('\u{1F75C}', CharMapAction::SubStr("sss")), // ALCHEMICAL SYMBOL FOR STRATUM SUPER STRATUM
+    ('\u{1F75E}', CharMapAction::SubChar('\u{224F}')), // ALCHEMICAL SYMBOL FOR SUBLIMATION
+    ('\u{1F768}', CharMapAction::SubChar('T')), // ALCHEMICAL SYMBOL FOR CRUCIBLE-4
+    ('\u{1F76B}', CharMapAction::SubStr("MB")), // ALCHEMICAL SYMBOL FOR BATH OF MARY
+    ('\u{1F76C}', CharMapAction::SubStr("VB")), // ALCHEMICAL SYMBOL FOR BATH OF VAPOURS
+    ('\u{1F771}', CharMapAction::SubChar('\u{22A0}')), // ALCHEMICAL SYMBOL FOR MONTH
+    ('\u{1FBF0}', CharMapAction::SubChar('O')), // SEGMENTED DIGIT ZERO
+    ('\u{1FBF1}', CharMapAction::SubChar('l')), // SEGMENTED DIGIT ONE
+    ('\u{1FBF2}', CharMapAction::SubChar('2')), // SEGMENTED DIGIT TWO
+    ('\u{1FBF3}', CharMapAction::SubChar('3')), // SEGMENTED DIGIT THREE
+    ('\u{1FBF4}', CharMapAction::SubChar('4')), // SEGMENTED DIGIT FOUR
+    ('\u{1FBF5}', CharMapAction::SubChar('5')), // SEGMENTED DIGIT FIVE
+    ('\u{1FBF6}', CharMapAction::SubChar('6')), // SEGMENTED DIGIT SIX
+    ('\u{1FBF7}', CharMapAction::SubChar('7')), // SEGMENTED DIGIT SEVEN
+    ('\u{1FBF8}', CharMapAction::SubChar('8')), // SEGMENTED DIGIT EIGHT
+    ('\u{1FBF9}', CharMapAction::SubChar('9')), // SEGMENTED DIGIT NINE
+    ('\u{21FE8}', CharMapAction::SubChar('\u{276C}')), // CJK UNIFIED IDEOGRAPH-21FE8
+    ('\u{2F800}', CharMapAction::SubChar('\u{4E3D}')), // CJK COMPATIBILITY IDEOGRAPH-2F800
+    ('\u{2F801}', CharMapAction::SubChar('\u{4E38}')), // CJK COMPATIBILITY IDEOGRAPH-2F801
+    ('\u{2F802}', CharMapAction::SubChar('\u{4E41}')), // CJK COMPATIBILITY IDEOGRAPH-2F802
+    ('\u{2F803}', CharMapAction::SubChar('\u{20122}')), // CJK COMPATIBILITY IDEOGRAPH-2F803
+    ('\u{2F804}', CharMapAction::SubChar('\u{4F60}')), // CJK COMPATIBILITY IDEOGRAPH-2F804
+    ('\u{2F805}', CharMapAction::SubChar('\u{4FAE}')), // CJK COMPATIBILITY IDEOGRAPH-2F805
+    ('\u{2F806}', CharMapAction::SubChar('\u{4FBB}')), // CJK COMPATIBILITY IDEOGRAPH-2F806
+    ('\u{2F807}', CharMapAction::SubChar('\u{4F75}')), // CJK COMPATIBILITY IDEOGRAPH-2F807
+    ('\u{2F808}', CharMapAction::SubChar('\u{507A}')), // CJK COMPATIBILITY IDEOGRAPH-2F808
+    ('\u{2F809}', CharMapAction::SubChar('\u{5099}')), // CJK COMPATIBILITY IDEOGRAPH-2F809
+    ('\u{2F80A}', CharMapAction::SubChar('\u{50E7}')), // CJK COMPATIBILITY IDEOGRAPH-2F80A
+    ('\u{2F80B}', CharMapAction::SubChar('\u{50CF}')), // CJK COMPATIBILITY IDEOGRAPH-2F80B
+    ('\u{2F80C}', CharMapAction::SubChar('\u{349E}')), // CJK COMPATIBILITY IDEOGRAPH-2F80C
+    ('\u{2F80D}', CharMapAction::SubChar('\u{2063A}')), // CJK COMPATIBILITY IDEOGRAPH-2F80D
+    ('\u{2F80E}', CharMapAction::SubChar('\u{514D}')), // CJK COMPATIBILITY IDEOGRAPH-2F80E
+    ('\u{2F80F}', CharMapAction::SubChar('\u{5154}')), // CJK COMPATIBILITY IDEOGRAPH-2F80F
+    ('\u{2F810}', CharMapAction::SubChar('\u{5164}')), // CJK COMPATIBILITY IDEOGRAPH-2F810
+    ('\u{2F811}', CharMapAction::SubChar('\u{5177}')), // CJK COMPATIBILITY IDEOGRAPH-2F811
+    ('\u{2F812}', CharMapAction::SubChar('\u{2051C}')), // CJK COMPATIBILITY IDEOGRAPH-2F812
+    ('\u{2F813}', CharMapAction::SubChar('\u{34B9}')), // CJK COMPATIBILITY IDEOGRAPH-2F813
+    ('\u{2F814}', CharMapAction::SubChar('\u{5167}')), // CJK COMPATIBILITY IDEOGRAPH-2F814
+    ('\u{2F815}', CharMapAction::SubChar('\u{518D}')), // CJK COMPATIBILITY IDEOGRAPH-2F815
+    ('\u{2F816}', CharMapAction::SubChar('\u{2054B}')), // CJK COMPATIBILITY IDEOGRAPH-2F816
+    ('\u{2F817}', CharMapAction::SubChar('\u{5197}')), // CJK COMPATIBILITY IDEOGRAPH-2F817
+    ('\u{2F818}', CharMapAction::SubChar('\u{51A4}')), // CJK COMPATIBILITY IDEOGRAPH-2F818
+    ('\u{2F819}', CharMapAction::SubChar('\u{4ECC}')), // CJK COMPATIBILITY IDEOGRAPH-2F819
+    ('\u{2F81A}', CharMapAction::SubChar('\u{51AC}')), // CJK COMPATIBILITY IDEOGRAPH-2F81A
+    ('\u{2F81B}', CharMapAction::SubChar('\u{51B5}')), // CJK COMPATIBILITY IDEOGRAPH-2F81B
+    ('\u{2F81C}', CharMapAction::SubChar('\u{291DF}')), // CJK COMPATIBILITY IDEOGRAPH-2F81C
+    ('\u{2F81D}', CharMapAction::SubChar('\u{51F5}')), // CJK COMPATIBILITY IDEOGRAPH-2F81D
+    ('\u{2F81E}', CharMapAction::SubChar('\u{5203}')), // CJK COMPATIBILITY IDEOGRAPH-2F81E
+    ('\u{2F81F}', CharMapAction::SubChar('\u{34DF}')), // CJK COMPATIBILITY IDEOGRAPH-2F81F
+    ('\u{2F820}', CharMapAction::SubChar('\u{523B}')), // CJK COMPATIBILITY IDEOGRAPH-2F820
+    ('\u{2F821}', CharMapAction::SubChar('\u{5246}')), // CJK COMPATIBILITY IDEOGRAPH-2F821
+    ('\u{2F822}', CharMapAction::SubChar('\u{5272}')), // CJK COMPATIBILITY IDEOGRAPH-2F822
+    ('\u{2F823}', CharMapAction::SubChar('\u{5277}')), // CJK COMPATIBILITY IDEOGRAPH-2F823
+    ('\u{2F824}', CharMapAction::SubChar('\u{3515}')), // CJK COMPATIBILITY IDEOGRAPH-2F824
+    ('\u{2F825}', CharMapAction::SubChar('\u{52C7}')), // CJK COMPATIBILITY IDEOGRAPH-2F825
+    ('\u{2F826}', CharMapAction::SubChar('\u{52C9}')), // CJK COMPATIBILITY IDEOGRAPH-2F826
+    ('\u{2F827}', CharMapAction::SubChar('\u{52E4}')), // CJK COMPATIBILITY IDEOGRAPH-2F827
+    ('\u{2F828}', CharMapAction::SubChar('\u{52FA}')), // CJK COMPATIBILITY IDEOGRAPH-2F828
+    ('\u{2F829}', CharMapAction::SubChar('\u{5305}')), // CJK COMPATIBILITY IDEOGRAPH-2F829
+    ('\u{2F82A}', CharMapAction::SubChar('\u{5306}')), // CJK COMPATIBILITY IDEOGRAPH-2F82A
+    ('\u{2F82B}', CharMapAction::SubChar('\u{5317}')), // CJK COMPATIBILITY IDEOGRAPH-2F82B
+    ('\u{2F82C}', CharMapAction::SubChar('\u{5349}')), // CJK COMPATIBILITY IDEOGRAPH-2F82C
+    ('\u{2F82D}', CharMapAction::SubChar('\u{5351}')), // CJK COMPATIBILITY IDEOGRAPH-2F82D
+    ('\u{2F82E}', CharMapAction::SubChar('\u{535A}')), // CJK COMPATIBILITY IDEOGRAPH-2F82E
+    ('\u{2F82F}', CharMapAction::SubChar('\u{5373}')), // CJK COMPATIBILITY IDEOGRAPH-2F82F
+    ('\u{2F830}', CharMapAction::SubChar('\u{537D}')), // CJK COMPATIBILITY IDEOGRAPH-2F830
+    ('\u{2F831}', CharMapAction::SubChar('\u{537F}')), // CJK COMPATIBILITY IDEOGRAPH-2F831
+    ('\u{2F832}', CharMapAction::SubChar('\u{537F}')), // CJK COMPATIBILITY IDEOGRAPH-2F832
+    ('\u{2F833}', CharMapAction::SubChar('\u{537F}')), // CJK COMPATIBILITY IDEOGRAPH-2F833
+    ('\u{2F834}', CharMapAction::SubChar('\u{20A2C}')), // CJK COMPATIBILITY IDEOGRAPH-2F834
+    ('\u{2F835}', CharMapAction::SubChar('\u{7070}')), // CJK COMPATIBILITY IDEOGRAPH-2F835
+    ('\u{2F836}', CharMapAction::SubChar('\u{53CA}')), // CJK COMPATIBILITY IDEOGRAPH-2F836
+    ('\u{2F837}', CharMapAction::SubChar('\u{53DF}')), // CJK COMPATIBILITY IDEOGRAPH-2F837
+    ('\u{2F838}', CharMapAction::SubChar('\u{20B63}')), // CJK COMPATIBILITY IDEOGRAPH-2F838
+    ('\u{2F839}', CharMapAction::SubChar('\u{53EB}')), // CJK COMPATIBILITY IDEOGRAPH-2F839
+    ('\u{2F83A}', CharMapAction::SubChar('\u{53F1}')), // CJK COMPATIBILITY IDEOGRAPH-2F83A
+    ('\u{2F83B}', CharMapAction::SubChar('\u{5406}')), // CJK COMPATIBILITY IDEOGRAPH-2F83B
+    ('\u{2F83C}', CharMapAction::SubChar('\u{549E}')), // CJK COMPATIBILITY IDEOGRAPH-2F83C
+    ('\u{2F83D}', CharMapAction::SubChar('\u{5438}')), // CJK COMPATIBILITY IDEOGRAPH-2F83D
+    ('\u{2F83E}', CharMapAction::SubChar('\u{5448}')), // CJK COMPATIBILITY IDEOGRAPH-2F83E
+    ('\u{2F83F}', CharMapAction::SubChar('\u{5468}')), // CJK COMPATIBILITY IDEOGRAPH-2F83F
+    ('\u{2F840}', CharMapAction::SubChar('\u{54A2}')), // CJK COMPATIBILITY IDEOGRAPH-2F840
+    ('\u{2F841}', CharMapAction::SubChar('\u{54F6}')), // CJK COMPATIBILITY IDEOGRAPH-2F841
+    ('\u{2F842}', CharMapAction::SubChar('\u{5510}')), // CJK COMPATIBILITY IDEOGRAPH-2F842
+    ('\u{2F843}', CharMapAction::SubChar('\u{5553}')), // CJK COMPATIBILITY IDEOGRAPH-2F843
+    ('\u{2F844}', CharMapAction::SubChar('\u{5563}')), // CJK COMPATIBILITY IDEOGRAPH-2F844
+    ('\u{2F845}', CharMapAction::SubChar('\u{5584}')), // CJK COMPATIBILITY IDEOGRAPH-2F845
+    ('\u{2F846}', CharMapAction::SubChar('\u{5584}')), // CJK COMPATIBILITY IDEOGRAPH-2F846
+    ('\u{2F847}', CharMapAction::SubChar('\u{5599}')), // CJK COMPATIBILITY IDEOGRAPH-2F847
+    ('\u{2F848}', CharMapAction::SubChar('\u{55AB}')), // CJK COMPATIBILITY IDEOGRAPH-2F848
+    ('\u{2F849}', CharMapAction::SubChar('\u{55B3}')), // CJK COMPATIBILITY IDEOGRAPH-2F849
+    ('\u{2F84A}', CharMapAction::SubChar('\u{55C2}')), // CJK COMPATIBILITY IDEOGRAPH-2F84A
+    ('\u{2F84B}', CharMapAction::SubChar('\u{5716}')), // CJK COMPATIBILITY IDEOGRAPH-2F84B
+    ('\u{2F84C}', CharMapAction::SubChar('\u{5606}')), // CJK COMPATIBILITY IDEOGRAPH-2F84C
+    ('\u{2F84D}', CharMapAction::SubChar('\u{5717}')), // CJK COMPATIBILITY IDEOGRAPH-2F84D
+    ('\u{2F84E}', CharMapAction::SubChar('\u{5651}')), // CJK COMPATIBILITY IDEOGRAPH-2F84E
+    ('\u{2F84F}', CharMapAction::SubChar('\u{5674}')), // CJK COMPATIBILITY IDEOGRAPH-2F84F
+    ('\u{2F850}', CharMapAction::SubChar('\u{5207}')), // CJK COMPATIBILITY IDEOGRAPH-2F850
+    ('\u{2F851}', CharMapAction::SubChar('\u{58EE}')), // CJK COMPATIBILITY IDEOGRAPH-2F851
+    ('\u{2F852}', CharMapAction::SubChar('\u{57CE}')), // CJK COMPATIBILITY IDEOGRAPH-2F852
+    ('\u{2F853}', CharMapAction::SubChar('\u{57F4}')), // CJK COMPATIBILITY IDEOGRAPH-2F853
+    ('\u{2F854}', CharMapAction::SubChar('\u{580D}')), // CJK COMPATIBILITY IDEOGRAPH-2F854
+    ('\u{2F855}', CharMapAction::SubChar('\u{578B}')), // CJK COMPATIBILITY IDEOGRAPH-2F855
+    ('\u{2F856}', CharMapAction::SubChar('\u{5832}')), // CJK COMPATIBILITY IDEOGRAPH-2F856
+    ('\u{2F857}', CharMapAction::SubChar('\u{5831}')), // CJK COMPATIBILITY IDEOGRAPH-2F857
+    ('\u{2F858}', CharMapAction::SubChar('\u{58AC}')), // CJK COMPATIBILITY IDEOGRAPH-2F858
+    ('\u{2F859}', CharMapAction::SubChar('\u{214E4}')), // CJK COMPATIBILITY IDEOGRAPH-2F859
+    ('\u{2F85A}', CharMapAction::SubChar('\u{58F2}')), // CJK COMPATIBILITY IDEOGRAPH-2F85A
+    ('\u{2F85B}', CharMapAction::SubChar('\u{58F7}')), // CJK COMPATIBILITY IDEOGRAPH-2F85B
+    ('\u{2F85C}', CharMapAction::SubChar('\u{5906}')), // CJK COMPATIBILITY IDEOGRAPH-2F85C
+    ('\u{2F85D}', CharMapAction::SubChar('\u{591A}')), // CJK COMPATIBILITY IDEOGRAPH-2F85D
+    ('\u{2F85E}', CharMapAction::SubChar('\u{5922}')), // CJK COMPATIBILITY IDEOGRAPH-2F85E
+    ('\u{2F85F}', CharMapAction::SubChar('\u{5962}')), // CJK COMPATIBILITY IDEOGRAPH-2F85F
+    ('\u{2F860}', CharMapAction::SubChar('\u{216A8}')), // CJK COMPATIBILITY IDEOGRAPH-2F860
+    ('\u{2F861}', CharMapAction::SubChar('\u{216EA}')), // CJK COMPATIBILITY IDEOGRAPH-2F861
+    ('\u{2F862}', CharMapAction::SubChar('\u{59EC}')), // CJK COMPATIBILITY IDEOGRAPH-2F862
+    ('\u{2F863}', CharMapAction::SubChar('\u{5A1B}')), // CJK COMPATIBILITY IDEOGRAPH-2F863
+    ('\u{2F864}', CharMapAction::SubChar('\u{5A27}')), // CJK COMPATIBILITY IDEOGRAPH-2F864
+    ('\u{2F865}', CharMapAction::SubChar('\u{59D8}')), // CJK COMPATIBILITY IDEOGRAPH-2F865
+    ('\u{2F866}', CharMapAction::SubChar('\u{5A66}')), // CJK COMPATIBILITY IDEOGRAPH-2F866
+    ('\u{2F867}', CharMapAction::SubChar('\u{36EE}')), // CJK COMPATIBILITY IDEOGRAPH-2F867
+    ('\u{2F868}', CharMapAction::SubChar('\u{36FC}')), // CJK COMPATIBILITY IDEOGRAPH-2F868
+    ('\u{2F869}', CharMapAction::SubChar('\u{5B08}')), // CJK COMPATIBILITY IDEOGRAPH-2F869
+    ('\u{2F86A}', CharMapAction::SubChar('\u{5B3E}')), // CJK COMPATIBILITY IDEOGRAPH-2F86A
+    ('\u{2F86B}', CharMapAction::SubChar('\u{5B3E}')), // CJK COMPATIBILITY IDEOGRAPH-2F86B
+    ('\u{2F86C}', CharMapAction::SubChar('\u{219C8}')), // CJK COMPATIBILITY IDEOGRAPH-2F86C
+    ('\u{2F86D}', CharMapAction::SubChar('\u{5BC3}')), // CJK COMPATIBILITY IDEOGRAPH-2F86D
+    ('\u{2F86E}', CharMapAction::SubChar('\u{5BD8}')), // CJK COMPATIBILITY IDEOGRAPH-2F86E
+    ('\u{2F86F}', CharMapAction::SubChar('\u{5BE7}')), // CJK COMPATIBILITY IDEOGRAPH-2F86F
+    ('\u{2F870}', CharMapAction::SubChar('\u{5BF3}')), // CJK COMPATIBILITY IDEOGRAPH-2F870
+    ('\u{2F871}', CharMapAction::SubChar('\u{21B18}')), // CJK COMPATIBILITY IDEOGRAPH-2F871
+    ('\u{2F872}', CharMapAction::SubChar('\u{5BFF}')), // CJK COMPATIBILITY IDEOGRAPH-2F872
+    ('\u{2F873}', CharMapAction::SubChar('\u{5C06}')), // CJK COMPATIBILITY IDEOGRAPH-2F873
+    ('\u{2F874}', CharMapAction::SubChar('\u{5F53}')), // CJK COMPATIBILITY IDEOGRAPH-2F874
+    ('\u{2F875}', CharMapAction::SubChar('\u{5C22}')), // CJK COMPATIBILITY IDEOGRAPH-2F875
+    ('\u{2F876}', CharMapAction::SubChar('\u{3781}')), // CJK COMPATIBILITY IDEOGRAPH-2F876
+    ('\u{2F877}', CharMapAction::SubChar('\u{5C60}')), // CJK COMPATIBILITY IDEOGRAPH-2F877
+    ('\u{2F878}', CharMapAction::SubChar('\u{5C6E}')), // CJK COMPATIBILITY IDEOGRAPH-2F878
+    ('\u{2F879}', CharMapAction::SubChar('\u{5CC0}')), // CJK COMPATIBILITY IDEOGRAPH-2F879
+    ('\u{2F87A}', CharMapAction::SubChar('\u{5C8D}')), // CJK COMPATIBILITY IDEOGRAPH-2F87A
+    ('\u{2F87B}', CharMapAction::SubChar('\u{21DE4}')), // CJK COMPATIBILITY IDEOGRAPH-2F87B
+    ('\u{2F87C}', CharMapAction::SubChar('\u{5D43}')), // CJK COMPATIBILITY IDEOGRAPH-2F87C
+    ('\u{2F87D}', CharMapAction::SubChar('\u{21DE6}')), // CJK COMPATIBILITY IDEOGRAPH-2F87D
+    ('\u{2F87E}', CharMapAction::SubChar('\u{5D6E}')), // CJK COMPATIBILITY IDEOGRAPH-2F87E
+    ('\u{2F87F}', CharMapAction::SubChar('\u{5D6B}')), // CJK COMPATIBILITY IDEOGRAPH-2F87F
+    ('\u{2F880}', CharMapAction::SubChar('\u{5D7C}')), // CJK COMPATIBILITY IDEOGRAPH-2F880
+    ('\u{2F881}', CharMapAction::SubChar('\u{5DE1}')), // CJK COMPATIBILITY IDEOGRAPH-2F881
+    ('\u{2F882}', CharMapAction::SubChar('\u{5DE2}')), // CJK COMPATIBILITY IDEOGRAPH-2F882
+    ('\u{2F883}', CharMapAction::SubChar('\u{382F}')), // CJK COMPATIBILITY IDEOGRAPH-2F883
+    ('\u{2F884}', CharMapAction::SubChar('\u{5DFD}')), // CJK COMPATIBILITY IDEOGRAPH-2F884
+    ('\u{2F885}', CharMapAction::SubChar('\u{5E28}')), // CJK COMPATIBILITY IDEOGRAPH-2F885
+    ('\u{2F886}', CharMapAction::SubChar('\u{5E3D}')), // CJK COMPATIBILITY IDEOGRAPH-2F886
+    ('\u{2F887}', CharMapAction::SubChar('\u{5E69}')), // CJK COMPATIBILITY IDEOGRAPH-2F887
+    ('\u{2F888}', CharMapAction::SubChar('\u{3862}')), // CJK COMPATIBILITY IDEOGRAPH-2F888
+    ('\u{2F889}', CharMapAction::SubChar('\u{22183}')), // CJK COMPATIBILITY IDEOGRAPH-2F889
+    ('\u{2F88A}', CharMapAction::SubChar('\u{387C}')), // CJK COMPATIBILITY IDEOGRAPH-2F88A
+    ('\u{2F88B}', CharMapAction::SubChar('\u{5EB0}')), // CJK COMPATIBILITY IDEOGRAPH-2F88B
+    ('\u{2F88C}', CharMapAction::SubChar('\u{5EB3}')), // CJK COMPATIBILITY IDEOGRAPH-2F88C
+    ('\u{2F88D}', CharMapAction::SubChar('\u{5EB6}')), // CJK COMPATIBILITY IDEOGRAPH-2F88D
+    ('\u{2F88E}', CharMapAction::SubChar('\u{5ECA}')), // CJK COMPATIBILITY IDEOGRAPH-2F88E
+    ('\u{2F88F}', CharMapAction::SubChar('\u{2A392}')), // CJK COMPATIBILITY IDEOGRAPH-2F88F
+    ('\u{2F890}', CharMapAction::SubChar('\u{5EFE}')), // CJK COMPATIBILITY IDEOGRAPH-2F890
+    ('\u{2F891}', CharMapAction::SubChar('\u{22331}')), // CJK COMPATIBILITY IDEOGRAPH-2F891
+    ('\u{2F892}', CharMapAction::SubChar('\u{22331}')), // CJK COMPATIBILITY IDEOGRAPH-2F892
+    ('\u{2F893}', CharMapAction::SubChar('\u{8201}')), // CJK COMPATIBILITY IDEOGRAPH-2F893
+    ('\u{2F894}', CharMapAction::SubChar('\u{5F22}')), // CJK COMPATIBILITY IDEOGRAPH-2F894
+    ('\u{2F895}', CharMapAction::SubChar('\u{5F22}')), // CJK COMPATIBILITY IDEOGRAPH-2F895
+    ('\u{2F896}', CharMapAction::SubChar('\u{38C7}')), // CJK COMPATIBILITY IDEOGRAPH-2F896
+    ('\u{2F897}', CharMapAction::SubChar('\u{232B8}')), // CJK COMPATIBILITY IDEOGRAPH-2F897
+    ('\u{2F898}', CharMapAction::SubChar('\u{261DA}')), // CJK COMPATIBILITY IDEOGRAPH-2F898
+    ('\u{2F899}', CharMapAction::SubChar('\u{5F62}')), // CJK COMPATIBILITY IDEOGRAPH-2F899
+    ('\u{2F89A}', CharMapAction::SubChar('\u{5F6B}')), // CJK COMPATIBILITY IDEOGRAPH-2F89A
+    ('\u{2F89B}', CharMapAction::SubChar('\u{38E3}')), // CJK COMPATIBILITY IDEOGRAPH-2F89B
+    ('\u{2F89C}', CharMapAction::SubChar('\u{5F9A}')), // CJK COMPATIBILITY IDEOGRAPH-2F89C
+    ('\u{2F89D}', CharMapAction::SubChar('\u{5FCD}')), // CJK COMPATIBILITY IDEOGRAPH-2F89D
+    ('\u{2F89E}', CharMapAction::SubChar('\u{5FD7}')), // CJK COMPATIBILITY IDEOGRAPH-2F89E
+    ('\u{2F89F}', CharMapAction::SubChar('\u{5FF9}')), // CJK COMPATIBILITY IDEOGRAPH-2F89F
+    ('\u{2F8A0}', CharMapAction::SubChar('\u{6081}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A0
+    ('\u{2F8A1}', CharMapAction::SubChar('\u{393A}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A1
+    ('\u{2F8A2}', CharMapAction::SubChar('\u{391C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A2
+    ('\u{2F8A3}', CharMapAction::SubChar('\u{6094}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A3
+    ('\u{2F8A4}', CharMapAction::SubChar('\u{226D4}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A4
+    ('\u{2F8A5}', CharMapAction::SubChar('\u{60C7}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A5
+    ('\u{2F8A6}', CharMapAction::SubChar('\u{6148}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A6
+    ('\u{2F8A7}', CharMapAction::SubChar('\u{614C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A7
+    ('\u{2F8A8}', CharMapAction::SubChar('\u{614E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A8
+    ('\u{2F8A9}', CharMapAction::SubChar('\u{614C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8A9
+    ('\u{2F8AA}', CharMapAction::SubChar('\u{617A}')), // CJK COMPATIBILITY IDEOGRAPH-2F8AA
+    ('\u{2F8AB}', CharMapAction::SubChar('\u{618E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8AB
+    ('\u{2F8AC}', CharMapAction::SubChar('\u{61B2}')), // CJK COMPATIBILITY IDEOGRAPH-2F8AC
+    ('\u{2F8AD}', CharMapAction::SubChar('\u{61A4}')), // CJK COMPATIBILITY IDEOGRAPH-2F8AD
+    ('\u{2F8AE}', CharMapAction::SubChar('\u{61AF}')), // CJK COMPATIBILITY IDEOGRAPH-2F8AE
+    ('\u{2F8AF}', CharMapAction::SubChar('\u{61DE}')), // CJK COMPATIBILITY IDEOGRAPH-2F8AF
+    ('\u{2F8B0}', CharMapAction::SubChar('\u{61F2}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B0
+    ('\u{2F8B1}', CharMapAction::SubChar('\u{61F6}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B1
+    ('\u{2F8B2}', CharMapAction::SubChar('\u{6210}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B2
+    ('\u{2F8B3}', CharMapAction::SubChar('\u{621B}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B3
+    ('\u{2F8B4}', CharMapAction::SubChar('\u{625D}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B4
+    ('\u{2F8B5}', CharMapAction::SubChar('\u{62B1}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B5
+    ('\u{2F8B6}', CharMapAction::SubChar('\u{62D4}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B6
+    ('\u{2F8B7}', CharMapAction::SubChar('\u{6350}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B7
+    ('\u{2F8B8}', CharMapAction::SubChar('\u{22B0C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B8
+    ('\u{2F8B9}', CharMapAction::SubChar('\u{633D}')), // CJK COMPATIBILITY IDEOGRAPH-2F8B9
+    ('\u{2F8BA}', CharMapAction::SubChar('\u{62FC}')), // CJK COMPATIBILITY IDEOGRAPH-2F8BA
+    ('\u{2F8BB}', CharMapAction::SubChar('\u{6368}')), // CJK COMPATIBILITY IDEOGRAPH-2F8BB
+    ('\u{2F8BC}', CharMapAction::SubChar('\u{6383}')), // CJK COMPATIBILITY IDEOGRAPH-2F8BC
+    ('\u{2F8BD}', CharMapAction::SubChar('\u{63E4}')), // CJK COMPATIBILITY IDEOGRAPH-2F8BD
+    ('\u{2F8BE}', CharMapAction::SubChar('\u{22BF1}')), // CJK COMPATIBILITY IDEOGRAPH-2F8BE
+    ('\u{2F8BF}', CharMapAction::SubChar('\u{6422}')), // CJK COMPATIBILITY IDEOGRAPH-2F8BF
+    ('\u{2F8C0}', CharMapAction::SubChar('\u{63C5}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C0
+    ('\u{2F8C1}', CharMapAction::SubChar('\u{63A9}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C1
+    ('\u{2F8C2}', CharMapAction::SubChar('\u{3A2E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C2
+    ('\u{2F8C3}', CharMapAction::SubChar('\u{6469}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C3
+    ('\u{2F8C4}', CharMapAction::SubChar('\u{647E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C4
+    ('\u{2F8C5}', CharMapAction::SubChar('\u{649D}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C5
+    ('\u{2F8C6}', CharMapAction::SubChar('\u{6477}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C6
+    ('\u{2F8C7}', CharMapAction::SubChar('\u{3A6C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C7
+    ('\u{2F8C8}', CharMapAction::SubChar('\u{654F}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C8
+    ('\u{2F8C9}', CharMapAction::SubChar('\u{656C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8C9
+    ('\u{2F8CA}', CharMapAction::SubChar('\u{2300A}')), // CJK COMPATIBILITY IDEOGRAPH-2F8CA
+    ('\u{2F8CB}', CharMapAction::SubChar('\u{65E3}')), // CJK COMPATIBILITY IDEOGRAPH-2F8CB
+    ('\u{2F8CC}', CharMapAction::SubChar('\u{66F8}')), // CJK COMPATIBILITY IDEOGRAPH-2F8CC
+    ('\u{2F8CD}', CharMapAction::SubChar('\u{6649}')), // CJK COMPATIBILITY IDEOGRAPH-2F8CD
+    ('\u{2F8CE}', CharMapAction::SubChar('\u{3B19}')), // CJK COMPATIBILITY IDEOGRAPH-2F8CE
+    ('\u{2F8CF}', CharMapAction::SubChar('\u{6691}')), // CJK COMPATIBILITY IDEOGRAPH-2F8CF
+    ('\u{2F8D0}', CharMapAction::SubChar('\u{3B08}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D0
+    ('\u{2F8D1}', CharMapAction::SubChar('\u{3AE4}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D1
+    ('\u{2F8D2}', CharMapAction::SubChar('\u{5192}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D2
+    ('\u{2F8D3}', CharMapAction::SubChar('\u{5195}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D3
+    ('\u{2F8D4}', CharMapAction::SubChar('\u{6700}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D4
+    ('\u{2F8D5}', CharMapAction::SubChar('\u{669C}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D5
+    ('\u{2F8D6}', CharMapAction::SubChar('\u{80AD}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D6
+    ('\u{2F8D7}', CharMapAction::SubChar('\u{43D9}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D7
+    ('\u{2F8D8}', CharMapAction::SubChar('\u{6717}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D8
+    ('\u{2F8D9}', CharMapAction::SubChar('\u{671B}')), // CJK COMPATIBILITY IDEOGRAPH-2F8D9
+    ('\u{2F8DA}', CharMapAction::SubChar('\u{6721}')), // CJK COMPATIBILITY IDEOGRAPH-2F8DA
+    ('\u{2F8DB}', CharMapAction::SubChar('\u{675E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8DB
+    ('\u{2F8DC}', CharMapAction::SubChar('\u{6753}')), // CJK COMPATIBILITY IDEOGRAPH-2F8DC
+    ('\u{2F8DD}', CharMapAction::SubChar('\u{233C3}')), // CJK COMPATIBILITY IDEOGRAPH-2F8DD
+    ('\u{2F8DE}', CharMapAction::SubChar('\u{3B49}')), // CJK COMPATIBILITY IDEOGRAPH-2F8DE
+    ('\u{2F8DF}', CharMapAction::SubChar('\u{67FA}')), // CJK COMPATIBILITY IDEOGRAPH-2F8DF
+    ('\u{2F8E0}', CharMapAction::SubChar('\u{6785}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E0
+    ('\u{2F8E1}', CharMapAction::SubChar('\u{6852}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E1
+    ('\u{2F8E2}', CharMapAction::SubChar('\u{6885}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E2
+    ('\u{2F8E3}', CharMapAction::SubChar('\u{2346D}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E3
+    ('\u{2F8E4}', CharMapAction::SubChar('\u{688E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E4
+    ('\u{2F8E5}', CharMapAction::SubChar('\u{681F}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E5
+    ('\u{2F8E6}', CharMapAction::SubChar('\u{6914}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E6
+    ('\u{2F8E7}', CharMapAction::SubChar('\u{3B9D}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E7
+    ('\u{2F8E8}', CharMapAction::SubChar('\u{6942}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E8
+    ('\u{2F8E9}', CharMapAction::SubChar('\u{69A3}')), // CJK COMPATIBILITY IDEOGRAPH-2F8E9
+    ('\u{2F8EA}', CharMapAction::SubChar('\u{69EA}')), // CJK COMPATIBILITY IDEOGRAPH-2F8EA
+    ('\u{2F8EB}', CharMapAction::SubChar('\u{6AA8}')), // CJK COMPATIBILITY IDEOGRAPH-2F8EB
+    ('\u{2F8EC}', CharMapAction::SubChar('\u{236A3}')), // CJK COMPATIBILITY IDEOGRAPH-2F8EC
+    ('\u{2F8ED}', CharMapAction::SubChar('\u{6ADB}')), // CJK COMPATIBILITY IDEOGRAPH-2F8ED
+    ('\u{2F8EE}', CharMapAction::SubChar('\u{3C18}')), // CJK COMPATIBILITY IDEOGRAPH-2F8EE
+    ('\u{2F8EF}', CharMapAction::SubChar('\u{6B21}')), // CJK COMPATIBILITY IDEOGRAPH-2F8EF
+    ('\u{2F8F0}', CharMapAction::SubChar('\u{238A7}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F0
+    ('\u{2F8F1}', CharMapAction::SubChar('\u{6B54}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F1
+    ('\u{2F8F2}', CharMapAction::SubChar('\u{3C4E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F2
+    ('\u{2F8F3}', CharMapAction::SubChar('\u{6B72}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F3
+    ('\u{2F8F4}', CharMapAction::SubChar('\u{6B9F}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F4
+    ('\u{2F8F5}', CharMapAction::SubChar('\u{6BBA}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F5
+    ('\u{2F8F6}', CharMapAction::SubChar('\u{6BBB}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F6
+    ('\u{2F8F7}', CharMapAction::SubChar('\u{23A8D}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F7
+    ('\u{2F8F8}', CharMapAction::SubChar('\u{21D0B}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F8
+    ('\u{2F8F9}', CharMapAction::SubChar('\u{23AFA}')), // CJK COMPATIBILITY IDEOGRAPH-2F8F9
+    ('\u{2F8FA}', CharMapAction::SubChar('\u{6C4E}')), // CJK COMPATIBILITY IDEOGRAPH-2F8FA
+    ('\u{2F8FB}', CharMapAction::SubChar('\u{23CBC}')), // CJK COMPATIBILITY IDEOGRAPH-2F8FB
+    ('\u{2F8FC}', CharMapAction::SubChar('\u{6CBF}')), // CJK COMPATIBILITY IDEOGRAPH-2F8FC
+    ('\u{2F8FD}', CharMapAction::SubChar('\u{6CCD}')), // CJK COMPATIBILITY IDEOGRAPH-2F8FD
+    ('\u{2F8FE}', CharMapAction::SubChar('\u{6C67}')), // CJK COMPATIBILITY IDEOGRAPH-2F8FE
+    ('\u{2F8FF}', CharMapAction::SubChar('\u{6D16}')), // CJK COMPATIBILITY IDEOGRAPH-2F8FF
+    ('\u{2F900}', CharMapAction::SubChar('\u{6D3E}')), // CJK COMPATIBILITY IDEOGRAPH-2F900
+    ('\u{2F901}', CharMapAction::SubChar('\u{6D77}')), // CJK COMPATIBILITY IDEOGRAPH-2F901
+    ('\u{2F902}', CharMapAction::SubChar('\u{6D41}')), // CJK COMPATIBILITY IDEOGRAPH-2F902
+    ('\u{2F903}', CharMapAction::SubChar('\u{6D69}')), // CJK COMPATIBILITY IDEOGRAPH-2F903
+    ('\u{2F904}', CharMapAction::SubChar('\u{6D78}')), // CJK COMPATIBILITY IDEOGRAPH-2F904
+    ('\u{2F905}', CharMapAction::SubChar('\u{6D85}')), // CJK COMPATIBILITY IDEOGRAPH-2F905
+    ('\u{2F906}', CharMapAction::SubChar('\u{23D1E}')), // CJK COMPATIBILITY IDEOGRAPH-2F906
+    ('\u{2F907}', CharMapAction::SubChar('\u{6D34}')), // CJK COMPATIBILITY IDEOGRAPH-2F907
+    ('\u{2F908}', CharMapAction::SubChar('\u{6E2F}')), // CJK COMPATIBILITY IDEOGRAPH-2F908
+    ('\u{2F909}', CharMapAction::SubChar('\u{6E6E}')), // CJK COMPATIBILITY IDEOGRAPH-2F909
+    ('\u{2F90A}', CharMapAction::SubChar('\u{3D33}')), // CJK COMPATIBILITY IDEOGRAPH-2F90A
+    ('\u{2F90B}', CharMapAction::SubChar('\u{6ECB}')), // CJK COMPATIBILITY IDEOGRAPH-2F90B
+    ('\u{2F90C}', CharMapAction::SubChar('\u{6EC7}')), // CJK COMPATIBILITY IDEOGRAPH-2F90C
+    ('\u{2F90D}', CharMapAction::SubChar('\u{23ED1}')), // CJK COMPATIBILITY IDEOGRAPH-2F90D
+    ('\u{2F90E}', CharMapAction::SubChar('\u{6DF9}')), // CJK COMPATIBILITY IDEOGRAPH-2F90E
+    ('\u{2F90F}', CharMapAction::SubChar('\u{6F6E}')), // CJK COMPATIBILITY IDEOGRAPH-2F90F
+    ('\u{2F910}', CharMapAction::SubChar('\u{23F5E}')), // CJK COMPATIBILITY IDEOGRAPH-2F910
+    ('\u{2F911}', CharMapAction::SubChar('\u{23F8E}')), // CJK COMPATIBILITY IDEOGRAPH-2F911
+    ('\u{2F912}', CharMapAction::SubChar('\u{6FC6}')), // CJK COMPATIBILITY IDEOGRAPH-2F912
+    ('\u{2F913}', CharMapAction::SubChar('\u{7039}')), // CJK COMPATIBILITY IDEOGRAPH-2F913
+    ('\u{2F914}', CharMapAction::SubChar('\u{701E}')), // CJK COMPATIBILITY IDEOGRAPH-2F914
+    ('\u{2F915}', CharMapAction::SubChar('\u{701B}')), // CJK COMPATIBILITY IDEOGRAPH-2F915
+    ('\u{2F916}', CharMapAction::SubChar('\u{3D96}')), // CJK COMPATIBILITY IDEOGRAPH-2F916
+    ('\u{2F917}', CharMapAction::SubChar('\u{704A}')), // CJK COMPATIBILITY IDEOGRAPH-2F917
+    ('\u{2F918}', CharMapAction::SubChar('\u{707D}')), // CJK COMPATIBILITY IDEOGRAPH-2F918
+    ('\u{2F919}', CharMapAction::SubChar('\u{7077}')), // CJK COMPATIBILITY IDEOGRAPH-2F919
+    ('\u{2F91A}', CharMapAction::SubChar('\u{70AD}')), // CJK COMPATIBILITY IDEOGRAPH-2F91A
+    ('\u{2F91B}', CharMapAction::SubChar('\u{20525}')), // CJK COMPATIBILITY IDEOGRAPH-2F91B
+    ('\u{2F91C}', CharMapAction::SubChar('\u{7145}')), // CJK COMPATIBILITY IDEOGRAPH-2F91C
+    ('\u{2F91D}', CharMapAction::SubChar('\u{24263}')), // CJK COMPATIBILITY IDEOGRAPH-2F91D
+    ('\u{2F91E}', CharMapAction::SubChar('\u{719C}')), // CJK COMPATIBILITY IDEOGRAPH-2F91E
+    ('\u{2F91F}', CharMapAction::SubChar('\u{243AB}')), // CJK COMPATIBILITY IDEOGRAPH-2F91F
+    ('\u{2F920}', CharMapAction::SubChar('\u{7228}')), // CJK COMPATIBILITY IDEOGRAPH-2F920
+    ('\u{2F921}', CharMapAction::SubChar('\u{7235}')), // CJK COMPATIBILITY IDEOGRAPH-2F921
+    ('\u{2F922}', CharMapAction::SubChar('\u{7250}')), // CJK COMPATIBILITY IDEOGRAPH-2F922
+    ('\u{2F923}', CharMapAction::SubChar('\u{24608}')), // CJK COMPATIBILITY IDEOGRAPH-2F923
+    ('\u{2F924}', CharMapAction::SubChar('\u{7280}')), // CJK COMPATIBILITY IDEOGRAPH-2F924
+    ('\u{2F925}', CharMapAction::SubChar('\u{7295}')), // CJK COMPATIBILITY IDEOGRAPH-2F925
+    ('\u{2F926}', CharMapAction::SubChar('\u{24735}')), // CJK COMPATIBILITY IDEOGRAPH-2F926
+    ('\u{2F927}', CharMapAction::SubChar('\u{24814}')), // CJK COMPATIBILITY IDEOGRAPH-2F927
+    ('\u{2F928}', CharMapAction::SubChar('\u{737A}')), // CJK COMPATIBILITY IDEOGRAPH-2F928
+    ('\u{2F929}', CharMapAction::SubChar('\u{738B}')), // CJK COMPATIBILITY IDEOGRAPH-2F929
+    ('\u{2F92A}', CharMapAction::SubChar('\u{3EAC}')), // CJK COMPATIBILITY IDEOGRAPH-2F92A
+    ('\u{2F92B}', CharMapAction::SubChar('\u{73A5}')), // CJK COMPATIBILITY IDEOGRAPH-2F92B
+    ('\u{2F92C}', CharMapAction::SubChar('\u{3EB8}')), // CJK COMPATIBILITY IDEOGRAPH-2F92C
+    ('\u{2F92D}', CharMapAction::SubChar('\u{3EB8}')), // CJK COMPATIBILITY IDEOGRAPH-2F92D
+    ('\u{2F92E}', CharMapAction::SubChar('\u{7447}')), // CJK COMPATIBILITY IDEOGRAPH-2F92E
+    ('\u{2F92F}', CharMapAction::SubChar('\u{745C}')), // CJK COMPATIBILITY IDEOGRAPH-2F92F
+    ('\u{2F930}', CharMapAction::SubChar('\u{7471}')), // CJK COMPATIBILITY IDEOGRAPH-2F930
+    ('\u{2F931}', CharMapAction::SubChar('\u{7485}')), // CJK COMPATIBILITY IDEOGRAPH-2F931
+    ('\u{2F932}', CharMapAction::SubChar('\u{74CA}')), // CJK COMPATIBILITY IDEOGRAPH-2F932
+    ('\u{2F933}', CharMapAction::SubChar('\u{3F1B}')), // CJK COMPATIBILITY IDEOGRAPH-2F933
+    ('\u{2F934}', CharMapAction::SubChar('\u{7524}')), // CJK COMPATIBILITY IDEOGRAPH-2F934
+    ('\u{2F935}', CharMapAction::SubChar('\u{24C36}')), // CJK COMPATIBILITY IDEOGRAPH-2F935
+    ('\u{2F936}', CharMapAction::SubChar('\u{753E}')), // CJK COMPATIBILITY IDEOGRAPH-2F936
+    ('\u{2F937}', CharMapAction::SubChar('\u{24C92}')), // CJK COMPATIBILITY IDEOGRAPH-2F937
+    ('\u{2F938}', CharMapAction::SubChar('\u{7570}')), // CJK COMPATIBILITY IDEOGRAPH-2F938
+    ('\u{2F939}', CharMapAction::SubChar('\u{2219F}')), // CJK COMPATIBILITY IDEOGRAPH-2F939
+    ('\u{2F93A}', CharMapAction::SubChar('\u{7610}')), // CJK COMPATIBILITY IDEOGRAPH-2F93A
+    ('\u{2F93B}', CharMapAction::SubChar('\u{24FA1}')), // CJK COMPATIBILITY IDEOGRAPH-2F93B
+    ('\u{2F93C}', CharMapAction::SubChar('\u{24FB8}')), // CJK COMPATIBILITY IDEOGRAPH-2F93C
+    ('\u{2F93D}', CharMapAction::SubChar('\u{25044}')), // CJK COMPATIBILITY IDEOGRAPH-2F93D
+    ('\u{2F93E}', CharMapAction::SubChar('\u{3FFC}')), // CJK COMPATIBILITY IDEOGRAPH-2F93E
+    ('\u{2F93F}', CharMapAction::SubChar('\u{4008}')), // CJK COMPATIBILITY IDEOGRAPH-2F93F
+    ('\u{2F940}', CharMapAction::SubChar('\u{76F4}')), // CJK COMPATIBILITY IDEOGRAPH-2F940
+    ('\u{2F941}', CharMapAction::SubChar('\u{250F3}')), // CJK COMPATIBILITY IDEOGRAPH-2F941
+    ('\u{2F942}', CharMapAction::SubChar('\u{250F2}')), // CJK COMPATIBILITY IDEOGRAPH-2F942
+    ('\u{2F943}', CharMapAction::SubChar('\u{25119}')), // CJK COMPATIBILITY IDEOGRAPH-2F943
+    ('\u{2F944}', CharMapAction::SubChar('\u{25133}')), // CJK COMPATIBILITY IDEOGRAPH-2F944
+    ('\u{2F945}', CharMapAction::SubChar('\u{771E}')), // CJK COMPATIBILITY IDEOGRAPH-2F945
+    ('\u{2F946}', CharMapAction::SubChar('\u{771F}')), // CJK COMPATIBILITY IDEOGRAPH-2F946
+    ('\u{2F947}', CharMapAction::SubChar('\u{771F}')), // CJK COMPATIBILITY IDEOGRAPH-2F947
+    ('\u{2F948}', CharMapAction::SubChar('\u{774A}')), // CJK COMPATIBILITY IDEOGRAPH-2F948
+    ('\u{2F949}', CharMapAction::SubChar('\u{4039}')), // CJK COMPATIBILITY IDEOGRAPH-2F949
+    ('\u{2F94A}', CharMapAction::SubChar('\u{778B}')), // CJK COMPATIBILITY IDEOGRAPH-2F94A
+    ('\u{2F94B}', CharMapAction::SubChar('\u{4046}')), // CJK COMPATIBILITY IDEOGRAPH-2F94B
+    ('\u{2F94C}', CharMapAction::SubChar('\u{4096}')), // CJK COMPATIBILITY IDEOGRAPH-2F94C
+    ('\u{2F94D}', CharMapAction::SubChar('\u{2541D}')), // CJK COMPATIBILITY IDEOGRAPH-2F94D
+    ('\u{2F94E}', CharMapAction::SubChar('\u{784E}')), // CJK COMPATIBILITY IDEOGRAPH-2F94E
+    ('\u{2F94F}', CharMapAction::SubChar('\u{788C}')), // CJK COMPATIBILITY IDEOGRAPH-2F94F
+    ('\u{2F950}', CharMapAction::SubChar('\u{78CC}')), // CJK COMPATIBILITY IDEOGRAPH-2F950
+    ('\u{2F951}', CharMapAction::SubChar('\u{40E3}')), // CJK COMPATIBILITY IDEOGRAPH-2F951
+    ('\u{2F952}', CharMapAction::SubChar('\u{25626}')), // CJK COMPATIBILITY IDEOGRAPH-2F952
+    ('\u{2F953}', CharMapAction::SubChar('\u{7956}')), // CJK COMPATIBILITY IDEOGRAPH-2F953
+    ('\u{2F954}', CharMapAction::SubChar('\u{2569A}')), // CJK COMPATIBILITY IDEOGRAPH-2F954
+    ('\u{2F955}', CharMapAction::SubChar('\u{256C5}')), // CJK COMPATIBILITY IDEOGRAPH-2F955
+    ('\u{2F956}', CharMapAction::SubChar('\u{798F}')), // CJK COMPATIBILITY IDEOGRAPH-2F956
+    ('\u{2F957}', CharMapAction::SubChar('\u{79EB}')), // CJK COMPATIBILITY IDEOGRAPH-2F957
+    ('\u{2F958}', CharMapAction::SubChar('\u{412F}')), // CJK COMPATIBILITY IDEOGRAPH-2F958
+    ('\u{2F959}', CharMapAction::SubChar('\u{7A40}')), // CJK COMPATIBILITY IDEOGRAPH-2F959
+    ('\u{2F95A}', CharMapAction::SubChar('\u{7A4A}')), // CJK COMPATIBILITY IDEOGRAPH-2F95A
+    ('\u{2F95B}', CharMapAction::SubChar('\u{7A4F}')), // CJK COMPATIBILITY IDEOGRAPH-2F95B
+    ('\u{2F95C}', CharMapAction::SubChar('\u{2597C}')), // CJK COMPATIBILITY IDEOGRAPH-2F95C
+    ('\u{2F95D}', CharMapAction::SubChar('\u{25AA7}')), // CJK COMPATIBILITY IDEOGRAPH-2F95D
+    ('\u{2F95E}', CharMapAction::SubChar('\u{25AA7}')), // CJK COMPATIBILITY IDEOGRAPH-2F95E
+    ('\u{2F95F}', CharMapAction::SubChar('\u{7AEE}')), // CJK COMPATIBILITY IDEOGRAPH-2F95F
+    ('\u{2F960}', CharMapAction::SubChar('\u{4202}')), // CJK COMPATIBILITY IDEOGRAPH-2F960
+    ('\u{2F961}', CharMapAction::SubChar('\u{25BAB}')), // CJK COMPATIBILITY IDEOGRAPH-2F961
+    ('\u{2F962}', CharMapAction::SubChar('\u{7BC6}')), // CJK COMPATIBILITY IDEOGRAPH-2F962
+    ('\u{2F963}', CharMapAction::SubChar('\u{7BC9}')), // CJK COMPATIBILITY IDEOGRAPH-2F963
+    ('\u{2F964}', CharMapAction::SubChar('\u{4227}')), // CJK COMPATIBILITY IDEOGRAPH-2F964
+    ('\u{2F965}', CharMapAction::SubChar('\u{25C80}')), // CJK COMPATIBILITY IDEOGRAPH-2F965
+    ('\u{2F966}', CharMapAction::SubChar('\u{7CD2}')), // CJK COMPATIBILITY IDEOGRAPH-2F966
+    ('\u{2F967}', CharMapAction::SubChar('\u{42A0}')), // CJK COMPATIBILITY IDEOGRAPH-2F967
+    ('\u{2F968}', CharMapAction::SubChar('\u{7CE8}')), // CJK COMPATIBILITY IDEOGRAPH-2F968
+    ('\u{2F969}', CharMapAction::SubChar('\u{7CE3}')), // CJK COMPATIBILITY IDEOGRAPH-2F969
+    ('\u{2F96A}', CharMapAction::SubChar('\u{7D00}')), // CJK COMPATIBILITY IDEOGRAPH-2F96A
+    ('\u{2F96B}', CharMapAction::SubChar('\u{25F86}')), // CJK COMPATIBILITY IDEOGRAPH-2F96B
+    ('\u{2F96C}', CharMapAction::SubChar('\u{7D63}')), // CJK COMPATIBILITY IDEOGRAPH-2F96C
+    ('\u{2F96D}', CharMapAction::SubChar('\u{4301}')), // CJK COMPATIBILITY IDEOGRAPH-2F96D
+    ('\u{2F96E}', CharMapAction::SubChar('\u{7DC7}')), // CJK COMPATIBILITY IDEOGRAPH-2F96E
+    ('\u{2F96F}', CharMapAction::SubChar('\u{7E02}')), // CJK COMPATIBILITY IDEOGRAPH-2F96F
+    ('\u{2F970}', CharMapAction::SubChar('\u{7E45}')), // CJK COMPATIBILITY IDEOGRAPH-2F970
+    ('\u{2F971}', CharMapAction::SubChar('\u{4334}')), // CJK COMPATIBILITY IDEOGRAPH-2F971
+    ('\u{2F972}', CharMapAction::SubChar('\u{26228}')), // CJK COMPATIBILITY IDEOGRAPH-2F972
+    ('\u{2F973}', CharMapAction::SubChar('\u{26247}')), // CJK COMPATIBILITY IDEOGRAPH-2F973
+    ('\u{2F974}', CharMapAction::SubChar('\u{4359}')), // CJK COMPATIBILITY IDEOGRAPH-2F974
+    ('\u{2F975}', CharMapAction::SubChar('\u{262D9}')), // CJK COMPATIBILITY IDEOGRAPH-2F975
+    ('\u{2F976}', CharMapAction::SubChar('\u{7F7A}')), // CJK COMPATIBILITY IDEOGRAPH-2F976
+    ('\u{2F977}', CharMapAction::SubChar('\u{2633E}')), // CJK COMPATIBILITY IDEOGRAPH-2F977
+    ('\u{2F978}', CharMapAction::SubChar('\u{7F95}')), // CJK COMPATIBILITY IDEOGRAPH-2F978
+    ('\u{2F979}', CharMapAction::SubChar('\u{7FFA}')), // CJK COMPATIBILITY IDEOGRAPH-2F979
+    ('\u{2F97A}', CharMapAction::SubChar('\u{8005}')), // CJK COMPATIBILITY IDEOGRAPH-2F97A
+    ('\u{2F97B}', CharMapAction::SubChar('\u{264DA}')), // CJK COMPATIBILITY IDEOGRAPH-2F97B
+    ('\u{2F97C}', CharMapAction::SubChar('\u{26523}')), // CJK COMPATIBILITY IDEOGRAPH-2F97C
+    ('\u{2F97D}', CharMapAction::SubChar('\u{8060}')), // CJK COMPATIBILITY IDEOGRAPH-2F97D
+    ('\u{2F97E}', CharMapAction::SubChar('\u{265A8}')), // CJK COMPATIBILITY IDEOGRAPH-2F97E
+    ('\u{2F97F}', CharMapAction::SubChar('\u{8070}')), // CJK COMPATIBILITY IDEOGRAPH-2F97F
+    ('\u{2F980}', CharMapAction::SubChar('\u{2335F}')), // CJK COMPATIBILITY IDEOGRAPH-2F980
+    ('\u{2F981}', CharMapAction::SubChar('\u{43D5}')), // CJK COMPATIBILITY IDEOGRAPH-2F981
+    ('\u{2F982}', CharMapAction::SubChar('\u{80B2}')), // CJK COMPATIBILITY IDEOGRAPH-2F982
+    ('\u{2F983}', CharMapAction::SubChar('\u{8103}')), // CJK COMPATIBILITY IDEOGRAPH-2F983
+    ('\u{2F984}', CharMapAction::SubChar('\u{440B}')), // CJK COMPATIBILITY IDEOGRAPH-2F984
+    ('\u{2F985}', CharMapAction::SubChar('\u{813E}')), // CJK COMPATIBILITY IDEOGRAPH-2F985
+    ('\u{2F986}', CharMapAction::SubChar('\u{5AB5}')), // CJK COMPATIBILITY IDEOGRAPH-2F986
+    ('\u{2F987}', CharMapAction::SubChar('\u{267A7}')), // CJK COMPATIBILITY IDEOGRAPH-2F987
+    ('\u{2F988}', CharMapAction::SubChar('\u{267B5}')), // CJK COMPATIBILITY IDEOGRAPH-2F988
+    ('\u{2F989}', CharMapAction::SubChar('\u{23393}')), // CJK COMPATIBILITY IDEOGRAPH-2F989
+    ('\u{2F98A}', CharMapAction::SubChar('\u{2339C}')), // CJK COMPATIBILITY IDEOGRAPH-2F98A
+    ('\u{2F98B}', CharMapAction::SubChar('\u{8201}')), // CJK COMPATIBILITY IDEOGRAPH-2F98B
+    ('\u{2F98C}', CharMapAction::SubChar('\u{8204}')), // CJK COMPATIBILITY IDEOGRAPH-2F98C
+    ('\u{2F98D}', CharMapAction::SubChar('\u{8F9E}')), // CJK COMPATIBILITY IDEOGRAPH-2F98D
+    ('\u{2F98E}', CharMapAction::SubChar('\u{446B}')), // CJK COMPATIBILITY IDEOGRAPH-2F98E
+    ('\u{2F98F}', CharMapAction::SubChar('\u{8291}')), // CJK COMPATIBILITY IDEOGRAPH-2F98F
+    ('\u{2F990}', CharMapAction::SubChar('\u{828B}')), // CJK COMPATIBILITY IDEOGRAPH-2F990
+    ('\u{2F991}', CharMapAction::SubChar('\u{829D}')), // CJK COMPATIBILITY IDEOGRAPH-2F991
+    ('\u{2F992}', CharMapAction::SubChar('\u{52B3}')), // CJK COMPATIBILITY IDEOGRAPH-2F992
+    ('\u{2F993}', CharMapAction::SubChar('\u{82B1}')), // CJK COMPATIBILITY IDEOGRAPH-2F993
+    ('\u{2F994}', CharMapAction::SubChar('\u{82B3}')), // CJK COMPATIBILITY IDEOGRAPH-2F994
+    ('\u{2F995}', CharMapAction::SubChar('\u{82BD}')), // CJK COMPATIBILITY IDEOGRAPH-2F995
+    ('\u{2F996}', CharMapAction::SubChar('\u{82E6}')), // CJK COMPATIBILITY IDEOGRAPH-2F996
+    ('\u{2F997}', CharMapAction::SubChar('\u{26B3C}')), // CJK COMPATIBILITY IDEOGRAPH-2F997
+    ('\u{2F998}', CharMapAction::SubChar('\u{82E5}')), // CJK COMPATIBILITY IDEOGRAPH-2F998
+    ('\u{2F999}', CharMapAction::SubChar('\u{831D}')), // CJK COMPATIBILITY IDEOGRAPH-2F999
+    ('\u{2F99A}', CharMapAction::SubChar('\u{8363}')), // CJK COMPATIBILITY IDEOGRAPH-2F99A
+    ('\u{2F99B}', CharMapAction::SubChar('\u{83AD}')), // CJK COMPATIBILITY IDEOGRAPH-2F99B
+    ('\u{2F99C}', CharMapAction::SubChar('\u{8323}')), // CJK COMPATIBILITY IDEOGRAPH-2F99C
+    ('\u{2F99D}', CharMapAction::SubChar('\u{83BD}')), // CJK COMPATIBILITY IDEOGRAPH-2F99D
+    ('\u{2F99E}', CharMapAction::SubChar('\u{83E7}')), // CJK COMPATIBILITY IDEOGRAPH-2F99E
+    ('\u{2F99F}', CharMapAction::SubChar('\u{8457}')), // CJK COMPATIBILITY IDEOGRAPH-2F99F
+    ('\u{2F9A0}', CharMapAction::SubChar('\u{8353}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A0
+    ('\u{2F9A1}', CharMapAction::SubChar('\u{83CA}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A1
+    ('\u{2F9A2}', CharMapAction::SubChar('\u{83CC}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A2
+    ('\u{2F9A3}', CharMapAction::SubChar('\u{83DC}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A3
+    ('\u{2F9A4}', CharMapAction::SubChar('\u{26C36}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A4
+    ('\u{2F9A5}', CharMapAction::SubChar('\u{26D6B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A5
+    ('\u{2F9A6}', CharMapAction::SubChar('\u{26CD5}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A6
+    ('\u{2F9A7}', CharMapAction::SubChar('\u{452B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A7
+    ('\u{2F9A8}', CharMapAction::SubChar('\u{84F1}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A8
+    ('\u{2F9A9}', CharMapAction::SubChar('\u{84F3}')), // CJK COMPATIBILITY IDEOGRAPH-2F9A9
+    ('\u{2F9AA}', CharMapAction::SubChar('\u{8516}')), // CJK COMPATIBILITY IDEOGRAPH-2F9AA
+    ('\u{2F9AB}', CharMapAction::SubChar('\u{273CA}')), // CJK COMPATIBILITY IDEOGRAPH-2F9AB
+    ('\u{2F9AC}', CharMapAction::SubChar('\u{8564}')), // CJK COMPATIBILITY IDEOGRAPH-2F9AC
+    ('\u{2F9AD}', CharMapAction::SubChar('\u{26F2C}')), // CJK COMPATIBILITY IDEOGRAPH-2F9AD
+    ('\u{2F9AE}', CharMapAction::SubChar('\u{455D}')), // CJK COMPATIBILITY IDEOGRAPH-2F9AE
+    ('\u{2F9AF}', CharMapAction::SubChar('\u{4561}')), // CJK COMPATIBILITY IDEOGRAPH-2F9AF
+    ('\u{2F9B0}', CharMapAction::SubChar('\u{26FB1}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B0
+    ('\u{2F9B1}', CharMapAction::SubChar('\u{270D2}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B1
+    ('\u{2F9B2}', CharMapAction::SubChar('\u{456B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B2
+    ('\u{2F9B3}', CharMapAction::SubChar('\u{8650}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B3
+    ('\u{2F9B4}', CharMapAction::SubChar('\u{865C}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B4
+    ('\u{2F9B5}', CharMapAction::SubChar('\u{8667}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B5
+    ('\u{2F9B6}', CharMapAction::SubChar('\u{8669}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B6
+    ('\u{2F9B7}', CharMapAction::SubChar('\u{86A9}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B7
+    ('\u{2F9B8}', CharMapAction::SubChar('\u{8688}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B8
+    ('\u{2F9B9}', CharMapAction::SubChar('\u{870E}')), // CJK COMPATIBILITY IDEOGRAPH-2F9B9
+    ('\u{2F9BA}', CharMapAction::SubChar('\u{86E2}')), // CJK COMPATIBILITY IDEOGRAPH-2F9BA
+    ('\u{2F9BB}', CharMapAction::SubChar('\u{8779}')), // CJK COMPATIBILITY IDEOGRAPH-2F9BB
+    ('\u{2F9BC}', CharMapAction::SubChar('\u{8728}')), // CJK COMPATIBILITY IDEOGRAPH-2F9BC
+    ('\u{2F9BD}', CharMapAction::SubChar('\u{876B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9BD
+    ('\u{2F9BE}', CharMapAction::SubChar('\u{8786}')), // CJK COMPATIBILITY IDEOGRAPH-2F9BE
+    ('\u{2F9BF}', CharMapAction::SubChar('\u{45D7}')), // CJK COMPATIBILITY IDEOGRAPH-2F9BF
+    ('\u{2F9C0}', CharMapAction::SubChar('\u{87E1}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C0
+    ('\u{2F9C1}', CharMapAction::SubChar('\u{8801}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C1
+    ('\u{2F9C2}', CharMapAction::SubChar('\u{45F9}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C2
+    ('\u{2F9C3}', CharMapAction::SubChar('\u{8860}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C3
+    ('\u{2F9C4}', CharMapAction::SubChar('\u{8863}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C4
+    ('\u{2F9C5}', CharMapAction::SubChar('\u{27667}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C5
+    ('\u{2F9C6}', CharMapAction::SubChar('\u{88D7}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C6
+    ('\u{2F9C7}', CharMapAction::SubChar('\u{88DE}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C7
+    ('\u{2F9C8}', CharMapAction::SubChar('\u{4635}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C8
+    ('\u{2F9C9}', CharMapAction::SubChar('\u{88FA}')), // CJK COMPATIBILITY IDEOGRAPH-2F9C9
+    ('\u{2F9CA}', CharMapAction::SubChar('\u{34BB}')), // CJK COMPATIBILITY IDEOGRAPH-2F9CA
+    ('\u{2F9CB}', CharMapAction::SubChar('\u{278AE}')), // CJK COMPATIBILITY IDEOGRAPH-2F9CB
+    ('\u{2F9CC}', CharMapAction::SubChar('\u{27966}')), // CJK COMPATIBILITY IDEOGRAPH-2F9CC
+    ('\u{2F9CD}', CharMapAction::SubChar('\u{46BE}')), // CJK COMPATIBILITY IDEOGRAPH-2F9CD
+    ('\u{2F9CE}', CharMapAction::SubChar('\u{46C7}')), // CJK COMPATIBILITY IDEOGRAPH-2F9CE
+    ('\u{2F9CF}', CharMapAction::SubChar('\u{8AA0}')), // CJK COMPATIBILITY IDEOGRAPH-2F9CF
+    ('\u{2F9D0}', CharMapAction::SubChar('\u{8AED}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D0
+    ('\u{2F9D1}', CharMapAction::SubChar('\u{8B8A}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D1
+    ('\u{2F9D2}', CharMapAction::SubChar('\u{8C55}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D2
+    ('\u{2F9D3}', CharMapAction::SubChar('\u{27CA8}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D3
+    ('\u{2F9D4}', CharMapAction::SubChar('\u{8CAB}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D4
+    ('\u{2F9D5}', CharMapAction::SubChar('\u{8CC1}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D5
+    ('\u{2F9D6}', CharMapAction::SubChar('\u{8D1B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D6
+    ('\u{2F9D7}', CharMapAction::SubChar('\u{8D77}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D7
+    ('\u{2F9D8}', CharMapAction::SubChar('\u{27F2F}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D8
+    ('\u{2F9D9}', CharMapAction::SubChar('\u{20804}')), // CJK COMPATIBILITY IDEOGRAPH-2F9D9
+    ('\u{2F9DA}', CharMapAction::SubChar('\u{8DCB}')), // CJK COMPATIBILITY IDEOGRAPH-2F9DA
+    ('\u{2F9DB}', CharMapAction::SubChar('\u{8DBC}')), // CJK COMPATIBILITY IDEOGRAPH-2F9DB
+    ('\u{2F9DC}', CharMapAction::SubChar('\u{8DF0}')), // CJK COMPATIBILITY IDEOGRAPH-2F9DC
+    ('\u{2F9DD}', CharMapAction::SubChar('\u{208DE}')), // CJK COMPATIBILITY IDEOGRAPH-2F9DD
+    ('\u{2F9DE}', CharMapAction::SubChar('\u{8ED4}')), // CJK COMPATIBILITY IDEOGRAPH-2F9DE
+    ('\u{2F9DF}', CharMapAction::SubChar('\u{8F38}')), // CJK COMPATIBILITY IDEOGRAPH-2F9DF
+    ('\u{2F9E0}', CharMapAction::SubChar('\u{285D2}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E0
+    ('\u{2F9E1}', CharMapAction::SubChar('\u{285ED}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E1
+    ('\u{2F9E2}', CharMapAction::SubChar('\u{9094}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E2
+    ('\u{2F9E3}', CharMapAction::SubChar('\u{90F1}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E3
+    ('\u{2F9E4}', CharMapAction::SubChar('\u{9111}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E4
+    ('\u{2F9E5}', CharMapAction::SubChar('\u{2872E}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E5
+    ('\u{2F9E6}', CharMapAction::SubChar('\u{911B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E6
+    ('\u{2F9E7}', CharMapAction::SubChar('\u{9238}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E7
+    ('\u{2F9E8}', CharMapAction::SubChar('\u{92D7}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E8
+    ('\u{2F9E9}', CharMapAction::SubChar('\u{92D8}')), // CJK COMPATIBILITY IDEOGRAPH-2F9E9
+    ('\u{2F9EA}', CharMapAction::SubChar('\u{927C}')), // CJK COMPATIBILITY IDEOGRAPH-2F9EA
+    ('\u{2F9EB}', CharMapAction::SubChar('\u{93F9}')), // CJK COMPATIBILITY IDEOGRAPH-2F9EB
+    ('\u{2F9EC}', CharMapAction::SubChar('\u{9415}')), // CJK COMPATIBILITY IDEOGRAPH-2F9EC
+    ('\u{2F9ED}', CharMapAction::SubChar('\u{28BFA}')), // CJK COMPATIBILITY IDEOGRAPH-2F9ED
+    ('\u{2F9EE}', CharMapAction::SubChar('\u{958B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9EE
+    ('\u{2F9EF}', CharMapAction::SubChar('\u{4995}')), // CJK COMPATIBILITY IDEOGRAPH-2F9EF
+    ('\u{2F9F0}', CharMapAction::SubChar('\u{95B7}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F0
+    ('\u{2F9F1}', CharMapAction::SubChar('\u{28D77}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F1
+    ('\u{2F9F2}', CharMapAction::SubChar('\u{49E6}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F2
+    ('\u{2F9F3}', CharMapAction::SubChar('\u{96C3}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F3
+    ('\u{2F9F4}', CharMapAction::SubChar('\u{5DB2}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F4
+    ('\u{2F9F5}', CharMapAction::SubChar('\u{9723}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F5
+    ('\u{2F9F6}', CharMapAction::SubChar('\u{29145}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F6
+    ('\u{2F9F7}', CharMapAction::SubChar('\u{2921A}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F7
+    ('\u{2F9F8}', CharMapAction::SubChar('\u{4A6E}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F8
+    ('\u{2F9F9}', CharMapAction::SubChar('\u{4A76}')), // CJK COMPATIBILITY IDEOGRAPH-2F9F9
+    ('\u{2F9FA}', CharMapAction::SubChar('\u{97E0}')), // CJK COMPATIBILITY IDEOGRAPH-2F9FA
+    ('\u{2F9FB}', CharMapAction::SubChar('\u{2940A}')), // CJK COMPATIBILITY IDEOGRAPH-2F9FB
+    ('\u{2F9FC}', CharMapAction::SubChar('\u{4AB2}')), // CJK COMPATIBILITY IDEOGRAPH-2F9FC
+    ('\u{2F9FD}', CharMapAction::SubChar('\u{29496}')), // CJK COMPATIBILITY IDEOGRAPH-2F9FD
+    ('\u{2F9FE}', CharMapAction::SubChar('\u{980B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9FE
+    ('\u{2F9FF}', CharMapAction::SubChar('\u{980B}')), // CJK COMPATIBILITY IDEOGRAPH-2F9FF
+    ('\u{2FA00}', CharMapAction::SubChar('\u{9829}')), // CJK COMPATIBILITY IDEOGRAPH-2FA00
+    ('\u{2FA01}', CharMapAction::SubChar('\u{295B6}')), // CJK COMPATIBILITY IDEOGRAPH-2FA01
+    ('\u{2FA02}', CharMapAction::SubChar('\u{98E2}')), // CJK COMPATIBILITY IDEOGRAPH-2FA02
+    ('\u{2FA03}', CharMapAction::SubChar('\u{4B33}')), // CJK COMPATIBILITY IDEOGRAPH-2FA03
+    ('\u{2FA04}', CharMapAction::SubChar('\u{9929}')), // CJK COMPATIBILITY IDEOGRAPH-2FA04
+    ('\u{2FA05}', CharMapAction::SubChar('\u{99A7}')), // CJK COMPATIBILITY IDEOGRAPH-2FA05
+    ('\u{2FA06}', CharMapAction::SubChar('\u{99C2}')), // CJK COMPATIBILITY IDEOGRAPH-2FA06
+    ('\u{2FA07}', CharMapAction::SubChar('\u{99FE}')), // CJK COMPATIBILITY IDEOGRAPH-2FA07
+    ('\u{2FA08}', CharMapAction::SubChar('\u{4BCE}')), // CJK COMPATIBILITY IDEOGRAPH-2FA08
+    ('\u{2FA09}', CharMapAction::SubChar('\u{29B30}')), // CJK COMPATIBILITY IDEOGRAPH-2FA09
+    ('\u{2FA0A}', CharMapAction::SubChar('\u{9B12}')), // CJK COMPATIBILITY IDEOGRAPH-2FA0A
+    ('\u{2FA0B}', CharMapAction::SubChar('\u{9C40}')), // CJK COMPATIBILITY IDEOGRAPH-2FA0B
+    ('\u{2FA0C}', CharMapAction::SubChar('\u{9CFD}')), // CJK COMPATIBILITY IDEOGRAPH-2FA0C
+    ('\u{2FA0D}', CharMapAction::SubChar('\u{4CCE}')), // CJK COMPATIBILITY IDEOGRAPH-2FA0D
+    ('\u{2FA0E}', CharMapAction::SubChar('\u{4CED}')), // CJK COMPATIBILITY IDEOGRAPH-2FA0E
+    ('\u{2FA0F}', CharMapAction::SubChar('\u{9D67}')), // CJK COMPATIBILITY IDEOGRAPH-2FA0F
+    ('\u{2FA10}', CharMapAction::SubChar('\u{2A0CE}')), // CJK COMPATIBILITY IDEOGRAPH-2FA10
+    ('\u{2FA11}', CharMapAction::SubChar('\u{4CF8}')), // CJK COMPATIBILITY IDEOGRAPH-2FA11
+    ('\u{2FA12}', CharMapAction::SubChar('\u{2A105}')), // CJK COMPATIBILITY IDEOGRAPH-2FA12
+    ('\u{2FA13}', CharMapAction::SubChar('\u{2A20E}')), // CJK COMPATIBILITY IDEOGRAPH-2FA13
+    ('\u{2FA14}', CharMapAction::SubChar('\u{2A291}')), // CJK COMPATIBILITY IDEOGRAPH-2FA14
+    ('\u{2FA15}', CharMapAction::SubChar('\u{9EBB}')), // CJK COMPATIBILITY IDEOGRAPH-2FA15
+    ('\u{2FA16}', CharMapAction::SubChar('\u{4D56}')), // CJK COMPATIBILITY IDEOGRAPH-2FA16
+    ('\u{2FA17}', CharMapAction::SubChar('\u{9EF9}')), // CJK COMPATIBILITY IDEOGRAPH-2FA17
+    ('\u{2FA18}', CharMapAction::SubChar('\u{9EFE}')), // CJK COMPATIBILITY IDEOGRAPH-2FA18
+    ('\u{2FA19}', CharMapAction::SubChar('\u{9F05}')), // CJK COMPATIBILITY IDEOGRAPH-2FA19
+    ('\u{2FA1A}', CharMapAction::SubChar('\u{9F0F}')), // CJK COMPATIBILITY IDEOGRAPH-2FA1A
+    ('\u{2FA1B}', CharMapAction::SubChar('\u{9F16}')), // CJK COMPATIBILITY IDEOGRAPH-2FA1B
+    ('\u{2FA1C}', CharMapAction::SubChar('\u{9F3B}')), // CJK COMPATIBILITY IDEOGRAPH-2FA1C
+    ('\u{2FA1D}', CharMapAction::SubChar('\u{2A600}')), // CJK COMPATIBILITY IDEOGRAPH-2FA1D
+];
+const _: () = assert_sorted(&CONFUSABLES);
+
+/// Returns the UTS #39 skeleton of a given string, i.e. its NFD form with
+/// every confusable character replaced by its prototype, normalized to NFD
+/// again.
+#[cfg(feature = "std")]
+pub fn skeleton(s: &str) -> String {
+    let mapper = CharMapper::new(&CONFUSABLES, CharMapAction::Pass);
+
+    s.nfd().map_chars(&mapper).nfd().collect()
+}
+
+/// Returns whether two given strings are confusable, i.e. whether they have
+/// the same [`skeleton`].
+#[cfg(feature = "std")]
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    skeleton(a) == skeleton(b)
+}
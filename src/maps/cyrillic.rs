@@ -0,0 +1,766 @@
+//! Cyrillic romanization maps for the ISO 9, GOST 7.79, and BGN/PCGN schemes.
+//!
+//! The following schemes are provided:
+//!
+//! * [`ISO_9`]: ISO 9:1995, a language-independent one-to-one transliteration
+//!   using Latin letters with diacritics, e.g. ж `ž`, щ `ŝ`, and я `â`.
+//! * GOST 7.79-2000 system B ([`GOST_7_79_RUSSIAN`], [`GOST_7_79_UKRAINIAN`],
+//!   and [`GOST_7_79_BULGARIAN`]): an ASCII-only transliteration, e.g. ж
+//!   `zh`, щ `shh` (or `sth` in Bulgarian), and ы `y'`.
+//! * BGN/PCGN ([`BGN_PCGN_RUSSIAN`], [`BGN_PCGN_UKRAINIAN`], and
+//!   [`BGN_PCGN_BULGARIAN`]): the romanization systems used for geographic
+//!   names, e.g. х `kh`, щ `shch` (or `sht` in Bulgarian), and Ukrainian г
+//!   `h`.
+//!
+//! Some rules of these schemes depend on the surrounding characters, which a
+//! single [`CharMapAction`] cannot express:
+//!
+//! * GOST 7.79: ц is `c` before `i`, `e`, `y`, and `j`, and `cz` elsewhere.
+//! * BGN/PCGN Russian: е and ё are `ye` and `yë` at the start of a word and
+//!   after vowels, й, ъ, and ь.
+//! * BGN/PCGN Ukrainian: є, ї, й, ю, and я are `ye`, `yi`, `y`, `yu`, and
+//!   `ya` at the start of a word, and зг is `zgh`.
+//! * BGN/PCGN Bulgarian: ия at the end of a word is `ia`.
+//!
+//! The maps contain the context-free form of each letter (e.g. `cz`), so they
+//! can be used with a [`CharMapper`](crate::CharMapper) when an approximate
+//! romanization is good enough. [`Romanizer`] applies the context-dependent
+//! rules as well, and also fully capitalizes multi-letter romanizations of
+//! capital letters in all-caps words (e.g. ЩИ `SHCHI` instead of `ShchI`).
+//!
+//! ```rust
+//! use charmap::maps::cyrillic::{Language, Romanizer, Scheme};
+//!
+//! let romanizer = Romanizer::new(Scheme::BgnPcgn, Language::Russian);
+//! let romanized: String = romanizer.romanize_chars("Елена Щербакова".chars()).collect();
+//! assert_eq!(romanized, "Yelena Shcherbakova");
+//!
+//! let romanizer = Romanizer::new(Scheme::Gost779, Language::Ukrainian);
+//! let romanized: String = romanizer.romanize_chars("Запоріжжя".chars()).collect();
+//! assert_eq!(romanized, "Zaporizhzhya");
+//!
+//! let romanizer = Romanizer::new(Scheme::BgnPcgn, Language::Bulgarian);
+//! let romanized: String = romanizer.romanize_chars("ЩАСТЛИВ".chars()).collect();
+//! assert_eq!(romanized, "SHTASTLIV");
+//! ```
+
+use crate::actionmap::{assert_sorted, ActionMap, CharMapAction};
+
+use super::romanize::{ContextRomanized, ContextRules};
+
+/// ISO 9:1995 Cyrillic to Latin transliteration map.
+pub static ISO_9: [(char, CharMapAction); 94] = [
+    ('\u{0401}', CharMapAction::SubChar('\u{00CB}')), // CAPITAL LETTER IO
+    ('\u{0402}', CharMapAction::SubChar('\u{0110}')), // CAPITAL LETTER DJE
+    ('\u{0403}', CharMapAction::SubChar('\u{01F4}')), // CAPITAL LETTER GJE
+    ('\u{0404}', CharMapAction::SubChar('\u{00CA}')), // CAPITAL LETTER UKRAINIAN IE
+    ('\u{0405}', CharMapAction::SubChar('\u{1E90}')), // CAPITAL LETTER DZE
+    ('\u{0406}', CharMapAction::SubChar('\u{00CC}')), // CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0407}', CharMapAction::SubChar('\u{00CF}')), // CAPITAL LETTER YI
+    ('\u{0408}', CharMapAction::SubStr("J\u{030C}")), // CAPITAL LETTER JE
+    ('\u{0409}', CharMapAction::SubStr("L\u{0302}")), // CAPITAL LETTER LJE
+    ('\u{040A}', CharMapAction::SubStr("N\u{0302}")), // CAPITAL LETTER NJE
+    ('\u{040B}', CharMapAction::SubChar('\u{0106}')), // CAPITAL LETTER TSHE
+    ('\u{040C}', CharMapAction::SubChar('\u{1E30}')), // CAPITAL LETTER KJE
+    ('\u{040E}', CharMapAction::SubChar('\u{01D3}')), // CAPITAL LETTER SHORT U
+    ('\u{040F}', CharMapAction::SubStr("D\u{0302}")), // CAPITAL LETTER DZHE
+    ('\u{0410}', CharMapAction::SubChar('A')),        // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')),        // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')),        // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('G')),        // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')),        // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')),        // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubChar('\u{017D}')), // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')),        // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('I')),        // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('J')),        // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')),        // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')),        // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')),        // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')),        // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')),        // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')),        // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')),        // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')),        // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')),        // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')),        // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')),        // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('H')),        // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubChar('C')),        // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubChar('\u{010C}')), // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubChar('\u{0160}')), // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubChar('\u{015C}')), // CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubChar('\u{02BA}')), // CAPITAL LETTER HARD SIGN
+    ('\u{042B}', CharMapAction::SubChar('Y')),        // CAPITAL LETTER YERU
+    ('\u{042C}', CharMapAction::SubChar('\u{02B9}')), // CAPITAL LETTER SOFT SIGN
+    ('\u{042D}', CharMapAction::SubChar('\u{00C8}')), // CAPITAL LETTER E
+    ('\u{042E}', CharMapAction::SubChar('\u{00DB}')), // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubChar('\u{00C2}')), // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')),        // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')),        // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')),        // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('g')),        // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')),        // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')),        // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubChar('\u{017E}')), // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')),        // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('i')),        // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('j')),        // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')),        // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')),        // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')),        // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')),        // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')),        // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')),        // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')),        // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')),        // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')),        // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')),        // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')),        // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('h')),        // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubChar('c')),        // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubChar('\u{010D}')), // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubChar('\u{0161}')), // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubChar('\u{015D}')), // SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubChar('\u{02BA}')), // SMALL LETTER HARD SIGN
+    ('\u{044B}', CharMapAction::SubChar('y')),        // SMALL LETTER YERU
+    ('\u{044C}', CharMapAction::SubChar('\u{02B9}')), // SMALL LETTER SOFT SIGN
+    ('\u{044D}', CharMapAction::SubChar('\u{00E8}')), // SMALL LETTER E
+    ('\u{044E}', CharMapAction::SubChar('\u{00FB}')), // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubChar('\u{00E2}')), // SMALL LETTER YA
+    ('\u{0451}', CharMapAction::SubChar('\u{00EB}')), // SMALL LETTER IO
+    ('\u{0452}', CharMapAction::SubChar('\u{0111}')), // SMALL LETTER DJE
+    ('\u{0453}', CharMapAction::SubChar('\u{01F5}')), // SMALL LETTER GJE
+    ('\u{0454}', CharMapAction::SubChar('\u{00EA}')), // SMALL LETTER UKRAINIAN IE
+    ('\u{0455}', CharMapAction::SubChar('\u{1E91}')), // SMALL LETTER DZE
+    ('\u{0456}', CharMapAction::SubChar('\u{00EC}')), // SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0457}', CharMapAction::SubChar('\u{00EF}')), // SMALL LETTER YI
+    ('\u{0458}', CharMapAction::SubStr("j\u{030C}")), // SMALL LETTER JE
+    ('\u{0459}', CharMapAction::SubStr("l\u{0302}")), // SMALL LETTER LJE
+    ('\u{045A}', CharMapAction::SubStr("n\u{0302}")), // SMALL LETTER NJE
+    ('\u{045B}', CharMapAction::SubChar('\u{0107}')), // SMALL LETTER TSHE
+    ('\u{045C}', CharMapAction::SubChar('\u{1E31}')), // SMALL LETTER KJE
+    ('\u{045E}', CharMapAction::SubChar('\u{01D4}')), // SMALL LETTER SHORT U
+    ('\u{045F}', CharMapAction::SubStr("d\u{0302}")), // SMALL LETTER DZHE
+    ('\u{0490}', CharMapAction::SubStr("G\u{0300}")), // CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0491}', CharMapAction::SubStr("g\u{0300}")), // SMALL LETTER GHE WITH UPTURN
+];
+const _: () = assert_sorted(&ISO_9);
+
+/// GOST 7.79-2000 (system B) Russian to Latin transliteration map.
+pub static GOST_7_79_RUSSIAN: [(char, CharMapAction); 66] = [
+    ('\u{0401}', CharMapAction::SubStr("Yo")), // CAPITAL LETTER IO
+    ('\u{0410}', CharMapAction::SubChar('A')), // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')), // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')), // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('G')), // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')), // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')), // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubStr("Zh")), // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')), // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('I')), // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('J')), // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')), // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')), // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')), // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')), // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')), // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')), // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')), // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')), // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')), // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')), // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')), // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('X')), // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubStr("Cz")), // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubStr("Ch")), // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubStr("Sh")), // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubStr("Shh")), // CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubStr("``")), // CAPITAL LETTER HARD SIGN
+    ('\u{042B}', CharMapAction::SubStr("Y'")), // CAPITAL LETTER YERU
+    ('\u{042C}', CharMapAction::SubChar('`')), // CAPITAL LETTER SOFT SIGN
+    ('\u{042D}', CharMapAction::SubStr("E`")), // CAPITAL LETTER E
+    ('\u{042E}', CharMapAction::SubStr("Yu")), // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubStr("Ya")), // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')), // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')), // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')), // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('g')), // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')), // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')), // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubStr("zh")), // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')), // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('i')), // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('j')), // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')), // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')), // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')), // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')), // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')), // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')), // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')), // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')), // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')), // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')), // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')), // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('x')), // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubStr("cz")), // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubStr("ch")), // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubStr("sh")), // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubStr("shh")), // SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubStr("``")), // SMALL LETTER HARD SIGN
+    ('\u{044B}', CharMapAction::SubStr("y'")), // SMALL LETTER YERU
+    ('\u{044C}', CharMapAction::SubChar('`')), // SMALL LETTER SOFT SIGN
+    ('\u{044D}', CharMapAction::SubStr("e`")), // SMALL LETTER E
+    ('\u{044E}', CharMapAction::SubStr("yu")), // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubStr("ya")), // SMALL LETTER YA
+    ('\u{0451}', CharMapAction::SubStr("yo")), // SMALL LETTER IO
+];
+const _: () = assert_sorted(&GOST_7_79_RUSSIAN);
+
+/// GOST 7.79-2000 (system B) Ukrainian to Latin transliteration map.
+pub static GOST_7_79_UKRAINIAN: [(char, CharMapAction); 68] = [
+    ('\u{02BC}', CharMapAction::SubChar('\'')), // MODIFIER LETTER APOSTROPHE
+    ('\u{0404}', CharMapAction::SubStr("Ye")),  // CAPITAL LETTER UKRAINIAN IE
+    ('\u{0406}', CharMapAction::SubChar('I')), // CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0407}', CharMapAction::SubStr("Yi")), // CAPITAL LETTER YI
+    ('\u{0410}', CharMapAction::SubChar('A')), // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')), // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')), // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('G')), // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')), // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')), // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubStr("Zh")), // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')), // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubStr("Y'")), // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('J')), // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')), // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')), // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')), // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')), // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')), // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')), // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')), // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')), // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')), // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')), // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')), // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('X')), // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubStr("Cz")), // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubStr("Ch")), // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubStr("Sh")), // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubStr("Shh")), // CAPITAL LETTER SHCHA
+    ('\u{042C}', CharMapAction::SubChar('`')), // CAPITAL LETTER SOFT SIGN
+    ('\u{042E}', CharMapAction::SubStr("Yu")), // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubStr("Ya")), // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')), // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')), // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')), // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('g')), // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')), // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')), // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubStr("zh")), // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')), // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubStr("y'")), // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('j')), // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')), // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')), // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')), // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')), // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')), // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')), // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')), // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')), // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')), // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')), // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')), // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('x')), // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubStr("cz")), // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubStr("ch")), // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubStr("sh")), // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubStr("shh")), // SMALL LETTER SHCHA
+    ('\u{044C}', CharMapAction::SubChar('`')), // SMALL LETTER SOFT SIGN
+    ('\u{044E}', CharMapAction::SubStr("yu")), // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubStr("ya")), // SMALL LETTER YA
+    ('\u{0454}', CharMapAction::SubStr("ye")), // SMALL LETTER UKRAINIAN IE
+    ('\u{0456}', CharMapAction::SubChar('i')), // SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0457}', CharMapAction::SubStr("yi")), // SMALL LETTER YI
+    ('\u{0490}', CharMapAction::SubStr("G`")), // CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0491}', CharMapAction::SubStr("g`")), // SMALL LETTER GHE WITH UPTURN
+    ('\u{2019}', CharMapAction::SubChar('\'')), // RIGHT SINGLE QUOTATION MARK
+];
+const _: () = assert_sorted(&GOST_7_79_UKRAINIAN);
+
+/// GOST 7.79-2000 (system B) Bulgarian to Latin transliteration map.
+pub static GOST_7_79_BULGARIAN: [(char, CharMapAction); 60] = [
+    ('\u{0410}', CharMapAction::SubChar('A')), // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')), // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')), // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('G')), // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')), // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')), // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubStr("Zh")), // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')), // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('I')), // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('J')), // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')), // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')), // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')), // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')), // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')), // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')), // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')), // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')), // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')), // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')), // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')), // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('X')), // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubStr("Cz")), // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubStr("Ch")), // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubStr("Sh")), // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubStr("Sth")), // CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubStr("A`")), // CAPITAL LETTER HARD SIGN
+    ('\u{042C}', CharMapAction::SubChar('`')), // CAPITAL LETTER SOFT SIGN
+    ('\u{042E}', CharMapAction::SubStr("Yu")), // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubStr("Ya")), // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')), // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')), // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')), // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('g')), // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')), // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')), // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubStr("zh")), // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')), // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('i')), // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('j')), // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')), // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')), // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')), // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')), // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')), // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')), // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')), // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')), // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')), // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')), // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')), // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('x')), // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubStr("cz")), // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubStr("ch")), // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubStr("sh")), // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubStr("sth")), // SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubStr("a`")), // SMALL LETTER HARD SIGN
+    ('\u{044C}', CharMapAction::SubChar('`')), // SMALL LETTER SOFT SIGN
+    ('\u{044E}', CharMapAction::SubStr("yu")), // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubStr("ya")), // SMALL LETTER YA
+];
+const _: () = assert_sorted(&GOST_7_79_BULGARIAN);
+
+/// BGN/PCGN (1947) Russian to Latin romanization map.
+pub static BGN_PCGN_RUSSIAN: [(char, CharMapAction); 66] = [
+    ('\u{0401}', CharMapAction::SubChar('\u{00CB}')), // CAPITAL LETTER IO
+    ('\u{0410}', CharMapAction::SubChar('A')),        // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')),        // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')),        // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('G')),        // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')),        // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')),        // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubStr("Zh")),        // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')),        // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('I')),        // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('Y')),        // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')),        // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')),        // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')),        // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')),        // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')),        // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')),        // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')),        // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')),        // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')),        // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')),        // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')),        // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubStr("Kh")),        // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubStr("Ts")),        // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubStr("Ch")),        // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubStr("Sh")),        // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubStr("Shch")),      // CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubChar('\u{201D}')), // CAPITAL LETTER HARD SIGN
+    ('\u{042B}', CharMapAction::SubChar('Y')),        // CAPITAL LETTER YERU
+    ('\u{042C}', CharMapAction::SubChar('\u{2019}')), // CAPITAL LETTER SOFT SIGN
+    ('\u{042D}', CharMapAction::SubChar('E')),        // CAPITAL LETTER E
+    ('\u{042E}', CharMapAction::SubStr("Yu")),        // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubStr("Ya")),        // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')),        // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')),        // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')),        // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('g')),        // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')),        // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')),        // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubStr("zh")),        // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')),        // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('i')),        // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('y')),        // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')),        // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')),        // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')),        // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')),        // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')),        // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')),        // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')),        // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')),        // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')),        // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')),        // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')),        // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubStr("kh")),        // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubStr("ts")),        // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubStr("ch")),        // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubStr("sh")),        // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubStr("shch")),      // SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubChar('\u{201D}')), // SMALL LETTER HARD SIGN
+    ('\u{044B}', CharMapAction::SubChar('y')),        // SMALL LETTER YERU
+    ('\u{044C}', CharMapAction::SubChar('\u{2019}')), // SMALL LETTER SOFT SIGN
+    ('\u{044D}', CharMapAction::SubChar('e')),        // SMALL LETTER E
+    ('\u{044E}', CharMapAction::SubStr("yu")),        // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubStr("ya")),        // SMALL LETTER YA
+    ('\u{0451}', CharMapAction::SubChar('\u{00EB}')), // SMALL LETTER IO
+];
+const _: () = assert_sorted(&BGN_PCGN_RUSSIAN);
+
+/// BGN/PCGN (2019) Ukrainian to Latin romanization map.
+pub static BGN_PCGN_UKRAINIAN: [(char, CharMapAction); 68] = [
+    ('\u{02BC}', CharMapAction::Delete), // MODIFIER LETTER APOSTROPHE
+    ('\u{0404}', CharMapAction::SubStr("Ie")), // CAPITAL LETTER UKRAINIAN IE
+    ('\u{0406}', CharMapAction::SubChar('I')), // CAPITAL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0407}', CharMapAction::SubChar('I')), // CAPITAL LETTER YI
+    ('\u{0410}', CharMapAction::SubChar('A')), // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')), // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')), // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('H')), // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')), // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')), // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubStr("Zh")), // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')), // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('Y')), // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('I')), // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')), // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')), // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')), // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')), // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')), // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')), // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')), // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')), // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')), // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')), // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')), // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubStr("Kh")), // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubStr("Ts")), // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubStr("Ch")), // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubStr("Sh")), // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubStr("Shch")), // CAPITAL LETTER SHCHA
+    ('\u{042C}', CharMapAction::Delete),       // CAPITAL LETTER SOFT SIGN
+    ('\u{042E}', CharMapAction::SubStr("Iu")), // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubStr("Ia")), // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')), // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')), // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')), // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('h')), // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')), // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')), // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubStr("zh")), // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')), // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('y')), // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('i')), // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')), // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')), // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')), // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')), // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')), // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')), // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')), // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')), // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')), // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')), // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')), // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubStr("kh")), // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubStr("ts")), // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubStr("ch")), // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubStr("sh")), // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubStr("shch")), // SMALL LETTER SHCHA
+    ('\u{044C}', CharMapAction::Delete),       // SMALL LETTER SOFT SIGN
+    ('\u{044E}', CharMapAction::SubStr("iu")), // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubStr("ia")), // SMALL LETTER YA
+    ('\u{0454}', CharMapAction::SubStr("ie")), // SMALL LETTER UKRAINIAN IE
+    ('\u{0456}', CharMapAction::SubChar('i')), // SMALL LETTER BYELORUSSIAN-UKRAINIAN I
+    ('\u{0457}', CharMapAction::SubChar('i')), // SMALL LETTER YI
+    ('\u{0490}', CharMapAction::SubChar('G')), // CAPITAL LETTER GHE WITH UPTURN
+    ('\u{0491}', CharMapAction::SubChar('g')), // SMALL LETTER GHE WITH UPTURN
+    ('\u{2019}', CharMapAction::Delete),       // RIGHT SINGLE QUOTATION MARK
+];
+const _: () = assert_sorted(&BGN_PCGN_UKRAINIAN);
+
+/// BGN/PCGN (2013) Bulgarian to Latin romanization map.
+pub static BGN_PCGN_BULGARIAN: [(char, CharMapAction); 60] = [
+    ('\u{0410}', CharMapAction::SubChar('A')), // CAPITAL LETTER A
+    ('\u{0411}', CharMapAction::SubChar('B')), // CAPITAL LETTER BE
+    ('\u{0412}', CharMapAction::SubChar('V')), // CAPITAL LETTER VE
+    ('\u{0413}', CharMapAction::SubChar('G')), // CAPITAL LETTER GHE
+    ('\u{0414}', CharMapAction::SubChar('D')), // CAPITAL LETTER DE
+    ('\u{0415}', CharMapAction::SubChar('E')), // CAPITAL LETTER IE
+    ('\u{0416}', CharMapAction::SubStr("Zh")), // CAPITAL LETTER ZHE
+    ('\u{0417}', CharMapAction::SubChar('Z')), // CAPITAL LETTER ZE
+    ('\u{0418}', CharMapAction::SubChar('I')), // CAPITAL LETTER I
+    ('\u{0419}', CharMapAction::SubChar('Y')), // CAPITAL LETTER SHORT I
+    ('\u{041A}', CharMapAction::SubChar('K')), // CAPITAL LETTER KA
+    ('\u{041B}', CharMapAction::SubChar('L')), // CAPITAL LETTER EL
+    ('\u{041C}', CharMapAction::SubChar('M')), // CAPITAL LETTER EM
+    ('\u{041D}', CharMapAction::SubChar('N')), // CAPITAL LETTER EN
+    ('\u{041E}', CharMapAction::SubChar('O')), // CAPITAL LETTER O
+    ('\u{041F}', CharMapAction::SubChar('P')), // CAPITAL LETTER PE
+    ('\u{0420}', CharMapAction::SubChar('R')), // CAPITAL LETTER ER
+    ('\u{0421}', CharMapAction::SubChar('S')), // CAPITAL LETTER ES
+    ('\u{0422}', CharMapAction::SubChar('T')), // CAPITAL LETTER TE
+    ('\u{0423}', CharMapAction::SubChar('U')), // CAPITAL LETTER U
+    ('\u{0424}', CharMapAction::SubChar('F')), // CAPITAL LETTER EF
+    ('\u{0425}', CharMapAction::SubChar('H')), // CAPITAL LETTER HA
+    ('\u{0426}', CharMapAction::SubStr("Ts")), // CAPITAL LETTER TSE
+    ('\u{0427}', CharMapAction::SubStr("Ch")), // CAPITAL LETTER CHE
+    ('\u{0428}', CharMapAction::SubStr("Sh")), // CAPITAL LETTER SHA
+    ('\u{0429}', CharMapAction::SubStr("Sht")), // CAPITAL LETTER SHCHA
+    ('\u{042A}', CharMapAction::SubChar('A')), // CAPITAL LETTER HARD SIGN
+    ('\u{042C}', CharMapAction::SubChar('Y')), // CAPITAL LETTER SOFT SIGN
+    ('\u{042E}', CharMapAction::SubStr("Yu")), // CAPITAL LETTER YU
+    ('\u{042F}', CharMapAction::SubStr("Ya")), // CAPITAL LETTER YA
+    ('\u{0430}', CharMapAction::SubChar('a')), // SMALL LETTER A
+    ('\u{0431}', CharMapAction::SubChar('b')), // SMALL LETTER BE
+    ('\u{0432}', CharMapAction::SubChar('v')), // SMALL LETTER VE
+    ('\u{0433}', CharMapAction::SubChar('g')), // SMALL LETTER GHE
+    ('\u{0434}', CharMapAction::SubChar('d')), // SMALL LETTER DE
+    ('\u{0435}', CharMapAction::SubChar('e')), // SMALL LETTER IE
+    ('\u{0436}', CharMapAction::SubStr("zh")), // SMALL LETTER ZHE
+    ('\u{0437}', CharMapAction::SubChar('z')), // SMALL LETTER ZE
+    ('\u{0438}', CharMapAction::SubChar('i')), // SMALL LETTER I
+    ('\u{0439}', CharMapAction::SubChar('y')), // SMALL LETTER SHORT I
+    ('\u{043A}', CharMapAction::SubChar('k')), // SMALL LETTER KA
+    ('\u{043B}', CharMapAction::SubChar('l')), // SMALL LETTER EL
+    ('\u{043C}', CharMapAction::SubChar('m')), // SMALL LETTER EM
+    ('\u{043D}', CharMapAction::SubChar('n')), // SMALL LETTER EN
+    ('\u{043E}', CharMapAction::SubChar('o')), // SMALL LETTER O
+    ('\u{043F}', CharMapAction::SubChar('p')), // SMALL LETTER PE
+    ('\u{0440}', CharMapAction::SubChar('r')), // SMALL LETTER ER
+    ('\u{0441}', CharMapAction::SubChar('s')), // SMALL LETTER ES
+    ('\u{0442}', CharMapAction::SubChar('t')), // SMALL LETTER TE
+    ('\u{0443}', CharMapAction::SubChar('u')), // SMALL LETTER U
+    ('\u{0444}', CharMapAction::SubChar('f')), // SMALL LETTER EF
+    ('\u{0445}', CharMapAction::SubChar('h')), // SMALL LETTER HA
+    ('\u{0446}', CharMapAction::SubStr("ts")), // SMALL LETTER TSE
+    ('\u{0447}', CharMapAction::SubStr("ch")), // SMALL LETTER CHE
+    ('\u{0448}', CharMapAction::SubStr("sh")), // SMALL LETTER SHA
+    ('\u{0449}', CharMapAction::SubStr("sht")), // SMALL LETTER SHCHA
+    ('\u{044A}', CharMapAction::SubChar('a')), // SMALL LETTER HARD SIGN
+    ('\u{044C}', CharMapAction::SubChar('y')), // SMALL LETTER SOFT SIGN
+    ('\u{044E}', CharMapAction::SubStr("yu")), // SMALL LETTER YU
+    ('\u{044F}', CharMapAction::SubStr("ya")), // SMALL LETTER YA
+];
+const _: () = assert_sorted(&BGN_PCGN_BULGARIAN);
+
+/// A Cyrillic romanization scheme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    /// ISO 9:1995 (language-independent).
+    Iso9,
+    /// GOST 7.79-2000 system B.
+    Gost779,
+    /// BGN/PCGN.
+    BgnPcgn,
+}
+
+/// The language of the text to romanize.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Russian.
+    Russian,
+    /// Ukrainian.
+    Ukrainian,
+    /// Bulgarian.
+    Bulgarian,
+}
+
+/// A Cyrillic romanizer for a given scheme and language.
+///
+/// Used as an [`ActionMap`], a [`Romanizer`] applies the map of its scheme and
+/// language, ignoring context-dependent rules. Use
+/// [`Romanizer::romanize_chars`] to apply them as well.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Romanizer {
+    scheme: Scheme,
+    language: Language,
+}
+
+impl Romanizer {
+    /// Creates a new [`Romanizer`] for a given scheme and language. The
+    /// language is ignored for [`Scheme::Iso9`].
+    #[inline]
+    pub const fn new(scheme: Scheme, language: Language) -> Self {
+        Romanizer { scheme, language }
+    }
+
+    /// Returns the scheme of this romanizer.
+    #[inline]
+    pub const fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Returns the language of this romanizer.
+    #[inline]
+    pub const fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Returns the context-free map of this romanizer's scheme and language.
+    #[inline]
+    pub fn table(&self) -> &'static [(char, CharMapAction<'static>)] {
+        match (self.scheme, self.language) {
+            (Scheme::Iso9, _) => &ISO_9,
+            (Scheme::Gost779, Language::Russian) => &GOST_7_79_RUSSIAN,
+            (Scheme::Gost779, Language::Ukrainian) => &GOST_7_79_UKRAINIAN,
+            (Scheme::Gost779, Language::Bulgarian) => &GOST_7_79_BULGARIAN,
+            (Scheme::BgnPcgn, Language::Russian) => &BGN_PCGN_RUSSIAN,
+            (Scheme::BgnPcgn, Language::Ukrainian) => &BGN_PCGN_UKRAINIAN,
+            (Scheme::BgnPcgn, Language::Bulgarian) => &BGN_PCGN_BULGARIAN,
+        }
+    }
+
+    /// Returns an iterator that romanizes characters from a given character
+    /// iterator, applying context-dependent rules. Characters not in the map
+    /// are output as they are.
+    #[inline]
+    pub fn romanize_chars<I>(&self, text_chars: I) -> RomanizedChars<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        RomanizedChars(ContextRomanized::new(*self, text_chars))
+    }
+
+    #[inline]
+    fn table_action(&self, c: char) -> Option<CharMapAction<'static>> {
+        <[_] as ActionMap>::map_char(self.table(), c)
+    }
+}
+
+impl ActionMap for Romanizer {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.table_action(c)
+    }
+}
+
+impl ContextRules for Romanizer {
+    fn action(
+        &self,
+        prev: Option<char>,
+        c: char,
+        next: Option<char>,
+    ) -> (CharMapAction<'static>, bool) {
+        let is_upper = c.is_uppercase();
+        let word_start = !prev.is_some_and(is_word_char);
+        let word_end = !next.is_some_and(is_word_char);
+        let lower_prev = prev.map(to_lower);
+        let cased = |lower, upper| {
+            CharMapAction::SubStr(if is_upper { upper } else { lower })
+        };
+
+        let action = match (self.scheme, self.language, to_lower(c)) {
+            (Scheme::Gost779, _, 'ц') => {
+                let next_action = next.and_then(|n| self.table_action(n));
+                let before_ieyj = match next_action {
+                    Some(CharMapAction::SubChar(d)) => is_ieyj(d),
+                    Some(CharMapAction::SubStr(s)) => {
+                        s.chars().next().is_some_and(is_ieyj)
+                    }
+                    _ => false,
+                };
+
+                if before_ieyj {
+                    CharMapAction::SubChar(if is_upper { 'C' } else { 'c' })
+                } else {
+                    cased("cz", "Cz")
+                }
+            }
+            (Scheme::BgnPcgn, Language::Russian, lower @ ('е' | 'ё'))
+                if word_start
+                    || lower_prev
+                        .is_some_and(|p| "аеёиоуыэюяйъь".contains(p)) =>
+            {
+                if lower == 'е' {
+                    cased("ye", "Ye")
+                } else {
+                    cased("y\u{00EB}", "Y\u{00EB}")
+                }
+            }
+            (
+                Scheme::BgnPcgn,
+                Language::Ukrainian,
+                lower @ ('є' | 'ї' | 'й' | 'ю' | 'я'),
+            ) if word_start => match lower {
+                'є' => cased("ye", "Ye"),
+                'ї' => cased("yi", "Yi"),
+                'й' => {
+                    CharMapAction::SubChar(if is_upper { 'Y' } else { 'y' })
+                }
+                'ю' => cased("yu", "Yu"),
+                _ => cased("ya", "Ya"),
+            },
+            (Scheme::BgnPcgn, Language::Ukrainian, 'г')
+                if lower_prev == Some('з') =>
+            {
+                cased("gh", "Gh")
+            }
+            (Scheme::BgnPcgn, Language::Bulgarian, 'я')
+                if lower_prev == Some('и') && word_end =>
+            {
+                CharMapAction::SubChar(if is_upper { 'A' } else { 'a' })
+            }
+            _ => self.table_action(c).unwrap_or(CharMapAction::Pass),
+        };
+
+        (action, false)
+    }
+}
+
+/// Iterator returned by [`Romanizer::romanize_chars`].
+#[derive(Clone)]
+pub struct RomanizedChars<I>(ContextRomanized<Romanizer, I>)
+where
+    I: Iterator<Item = char>;
+
+impl<I> Iterator for RomanizedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+// Returns whether a character is part of a word, including apostrophes used
+// within Ukrainian words.
+#[inline]
+fn is_word_char(c: char) -> bool {
+    c.is_alphabetic() || matches!(c, '\'' | '\u{2019}' | '\u{02BC}')
+}
+
+#[inline]
+fn is_ieyj(c: char) -> bool {
+    matches!(c, 'i' | 'e' | 'y' | 'j' | 'I' | 'E' | 'Y' | 'J')
+}
+
+// Lowercases a Cyrillic letter (which always lowercases to a single char).
+#[inline]
+fn to_lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
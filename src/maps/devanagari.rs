@@ -0,0 +1,846 @@
+//! Devanagari transliteration to and from IAST and ISO 15919.
+//!
+//! Unlike most other maps in this crate, transliterating Devanagari can't be
+//! done one character at a time: consonants carry an inherent vowel `a` that
+//! is replaced by a following vowel sign (matra) or suppressed by a virama,
+//! and the Latin schemes use multi-letter sequences such as `kh` and `ai`.
+//! [`Transliterator`] handles these sequences and can be used through the
+//! [`TransliterateChars`] trait, in the same way [`CharMapper`]s are used
+//! through [`MapCharsIter`]. Since it works on any character iterator, it can
+//! also be chained with the output of a [`CharMapper`], e.g. to remove
+//! zero-width characters first.
+//!
+//! [`CharMapper`]: crate::CharMapper
+//! [`MapCharsIter`]: crate::MapCharsIter
+//!
+//! ```rust
+//! use charmap::maps::devanagari::{Scheme, TransliterateChars, Transliterator};
+//! use charmap::*;
+//!
+//! let to_iast = Transliterator::to_latin(Scheme::Iast);
+//! let iast: String = "संस्कृतम्".transliterate(&to_iast).collect();
+//! assert_eq!(iast, "saṃskṛtam");
+//!
+//! let from_iast = Transliterator::from_latin(Scheme::Iast);
+//! let devanagari: String = iast.chars().transliterate(&from_iast).collect();
+//! assert_eq!(devanagari, "संस्कृतम्");
+//!
+//! let remove_zwnj = [('\u{200C}', CharMapAction::Delete)];
+//! let mapper = CharMapper::new(&remove_zwnj, CharMapAction::Pass);
+//! let iast: String = "नम\u{200C}स्ते"
+//!     .map_chars(&mapper)
+//!     .transliterate(&to_iast)
+//!     .collect();
+//! assert_eq!(iast, "namaste");
+//! ```
+//!
+//! When transliterating to ISO 15919, a colon is inserted where the Latin
+//! sequence would otherwise be ambiguous (e.g. क्ह `k:h` and अइ `a:i`), and
+//! removed again when transliterating from ISO 15919. Digits, dandas, and
+//! avagrahas are only converted when transliterating to Latin. Latin input is
+//! expected to be in NFC and may be in any case.
+
+use core::iter::Peekable;
+use core::{option, str::Chars};
+
+use crate::actionmap::{assert_sorted, ActionMap, CharMapAction};
+
+/// A Latin transliteration scheme for Devanagari.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    /// The International Alphabet of Sanskrit Transliteration.
+    Iast,
+    /// ISO 15919.
+    Iso15919,
+}
+
+/// A transliterator between Devanagari and a Latin [`Scheme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Transliterator {
+    scheme: Scheme,
+    to_latin: bool,
+}
+
+impl Transliterator {
+    /// Creates a new [`Transliterator`] from Devanagari to a given scheme.
+    #[inline]
+    pub const fn to_latin(scheme: Scheme) -> Self {
+        Transliterator { scheme, to_latin: true }
+    }
+
+    /// Creates a new [`Transliterator`] from a given scheme to Devanagari.
+    #[inline]
+    pub const fn from_latin(scheme: Scheme) -> Self {
+        Transliterator { scheme, to_latin: false }
+    }
+
+    /// Returns the Latin scheme of this transliterator.
+    #[inline]
+    pub const fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    /// Returns an iterator that transliterates characters from a given
+    /// character iterator. Characters that are not part of the source script
+    /// are output as they are.
+    #[inline]
+    pub fn transliterate_chars<I>(
+        &self,
+        text_chars: I,
+    ) -> TransliteratedChars<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        let state = if self.to_latin {
+            let table: &'static [_] = match self.scheme {
+                Scheme::Iast => &DEVANAGARI_TO_IAST,
+                Scheme::Iso15919 => &DEVANAGARI_TO_ISO_15919,
+            };
+
+            State::ToLatin {
+                text_chars: text_chars.peekable(),
+                table,
+                separate: self.scheme == Scheme::Iso15919,
+                prev: Prev::Other,
+            }
+        } else {
+            let tokens: &'static [_] = match self.scheme {
+                Scheme::Iast => &IAST_TOKENS,
+                Scheme::Iso15919 => &ISO_15919_TOKENS,
+            };
+
+            State::FromLatin {
+                text_chars,
+                tokens,
+                ahead: ['\0'; MAX_TOKEN_LEN],
+                n_ahead: 0,
+                after_consonant: false,
+                after_letter: false,
+            }
+        };
+
+        TransliteratedChars {
+            state,
+            queue: [CharMapAction::Delete; 3],
+            queue_len: 0,
+            queue_pos: 0,
+            sub_chars: "".chars(),
+        }
+    }
+}
+
+/// Character iterator returned by [`Transliterator::transliterate_chars`] and
+/// [`TransliterateChars::transliterate`].
+#[derive(Clone)]
+pub struct TransliteratedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    state: State<I>,
+    // Actions to output for the current input, which are never `Pass`.
+    queue: [CharMapAction<'static>; 3],
+    queue_len: usize,
+    queue_pos: usize,
+    sub_chars: Chars<'static>,
+}
+
+#[derive(Clone)]
+enum State<I>
+where
+    I: Iterator<Item = char>,
+{
+    ToLatin {
+        text_chars: Peekable<I>,
+        table: &'static [(char, CharMapAction<'static>)],
+        separate: bool,
+        prev: Prev,
+    },
+    FromLatin {
+        text_chars: I,
+        tokens: &'static [(&'static str, Token)],
+        ahead: [char; MAX_TOKEN_LEN],
+        n_ahead: usize,
+        after_consonant: bool,
+        after_letter: bool,
+    },
+}
+
+// What was output for the previous Devanagari character, as far as ISO 15919
+// separators are concerned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Prev {
+    Other,
+    // A consonant with a virama which would form an aspirate with `h`.
+    Unaspirated,
+    // An inherent or independent `a`, which would form a diphthong with `i`
+    // or `u`.
+    A,
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Consonant(&'static str),
+    // An independent vowel along with its vowel sign, if any.
+    Vowel(char, Option<char>),
+    Sign(char),
+    Separator,
+}
+
+const VIRAMA: char = '\u{094D}';
+const NUKTA: char = '\u{093C}';
+
+// Romanized consonants that form an aspirate when followed by `h`.
+const UNASPIRATED: &str = "bcdgjkpt\u{1E0D}\u{1E5B}\u{1E6D}";
+
+// Maximum length of a Latin token in characters.
+const MAX_TOKEN_LEN: usize = 3;
+
+impl<I> TransliteratedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    #[inline]
+    fn push(&mut self, action: CharMapAction<'static>) {
+        self.queue[self.queue_len] = action;
+        self.queue_len += 1;
+    }
+
+    // Reads the next Devanagari character (along with its nukta and vowel
+    // sign or virama) and queues its romanization.
+    fn fill_to_latin(&mut self) -> Option<()> {
+        let State::ToLatin { text_chars, table, separate, prev } =
+            &mut self.state
+        else {
+            unreachable!()
+        };
+        let (table, separate) = (*table, *separate);
+        let c = text_chars.next()?;
+        let lookup = |c| <[_] as ActionMap>::map_char(table, c);
+        let mut actions = [CharMapAction::Delete; 3];
+        let mut n = 0;
+
+        if is_consonant(c) {
+            let mut action = lookup(c).unwrap_or(CharMapAction::SubChar(c));
+
+            if text_chars.next_if_eq(&NUKTA).is_some() {
+                if let Some(a) = NUKTA_CONSONANTS.map_char(c) {
+                    action = a;
+                }
+            }
+
+            if separate && *prev == Prev::Unaspirated && c == '\u{0939}' {
+                actions[n] = CharMapAction::SubChar(':');
+                n += 1;
+            }
+            actions[n] = action;
+            n += 1;
+
+            *prev = Prev::Other;
+
+            match text_chars.peek() {
+                Some(&VIRAMA) => {
+                    text_chars.next();
+                    if is_unaspirated(action) {
+                        *prev = Prev::Unaspirated;
+                    }
+                }
+                Some(&m) if is_vowel_sign(m) => {
+                    text_chars.next();
+                    if let Some(a) = lookup(m) {
+                        actions[n] = a;
+                        n += 1;
+                    }
+                }
+                _ => {
+                    actions[n] = CharMapAction::SubChar('a');
+                    n += 1;
+                    *prev = Prev::A;
+                }
+            }
+        } else {
+            if separate
+                && *prev == Prev::A
+                && matches!(c, '\u{0907}' | '\u{0909}')
+            {
+                actions[n] = CharMapAction::SubChar(':');
+                n += 1;
+            }
+
+            actions[n] = match lookup(c) {
+                Some(CharMapAction::Pass) | None => CharMapAction::SubChar(c),
+                Some(action) => action,
+            };
+            n += 1;
+
+            *prev = if c == '\u{0905}' { Prev::A } else { Prev::Other };
+        }
+
+        for action in &actions[..n] {
+            self.push(*action);
+        }
+
+        Some(())
+    }
+
+    // Reads the next Latin token and queues its Devanagari equivalent.
+    fn fill_from_latin(&mut self) -> Option<()> {
+        let State::FromLatin {
+            text_chars,
+            tokens,
+            ahead,
+            n_ahead,
+            after_consonant,
+            after_letter,
+        } = &mut self.state
+        else {
+            unreachable!()
+        };
+
+        while *n_ahead < MAX_TOKEN_LEN {
+            match text_chars.next() {
+                Some(c) => {
+                    ahead[*n_ahead] = c;
+                    *n_ahead += 1;
+                }
+                None => break,
+            }
+        }
+
+        if *n_ahead == 0 {
+            if *after_consonant {
+                *after_consonant = false;
+                self.push(CharMapAction::SubChar(VIRAMA));
+                return Some(());
+            }
+            return None;
+        }
+
+        let mut actions = [CharMapAction::Delete; 2];
+        let mut n = 0;
+        let token = (1..=*n_ahead).rev().find_map(|len| {
+            let lowered = ahead[..len].iter().map(|c| to_lower(*c));
+            tokens
+                .binary_search_by(|(s, _)| s.chars().cmp(lowered.clone()))
+                .ok()
+                .map(|i| (len, tokens[i].1))
+        });
+        // Separators are only removed between letters.
+        let token = match token {
+            Some((_, Token::Separator))
+                if !*after_letter
+                    || *n_ahead < 2
+                    || !ahead[1].is_alphabetic() =>
+            {
+                None
+            }
+            token => token,
+        };
+        *after_letter =
+            matches!(token, Some((_, Token::Consonant(_) | Token::Vowel(..))));
+        let len = match token {
+            Some((len, Token::Consonant(s))) => {
+                if *after_consonant {
+                    actions[n] = CharMapAction::SubChar(VIRAMA);
+                    n += 1;
+                }
+                actions[n] = CharMapAction::SubStr(s);
+                n += 1;
+                *after_consonant = true;
+                len
+            }
+            Some((len, Token::Vowel(independent, sign))) => {
+                if !*after_consonant {
+                    actions[n] = CharMapAction::SubChar(independent);
+                    n += 1;
+                } else if let Some(sign) = sign {
+                    actions[n] = CharMapAction::SubChar(sign);
+                    n += 1;
+                }
+                *after_consonant = false;
+                len
+            }
+            Some((len, Token::Sign(sign))) => {
+                actions[n] = CharMapAction::SubChar(sign);
+                n += 1;
+                *after_consonant = false;
+                len
+            }
+            Some((len, Token::Separator)) => len,
+            None => {
+                if *after_consonant {
+                    actions[n] = CharMapAction::SubChar(VIRAMA);
+                    n += 1;
+                }
+                actions[n] = CharMapAction::SubChar(ahead[0]);
+                n += 1;
+                *after_consonant = false;
+                1
+            }
+        };
+
+        ahead.copy_within(len..*n_ahead, 0);
+        *n_ahead -= len;
+
+        for action in &actions[..n] {
+            self.push(*action);
+        }
+
+        Some(())
+    }
+}
+
+impl<I> Iterator for TransliteratedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.sub_chars.next() {
+                return Some(c);
+            }
+
+            if self.queue_pos < self.queue_len {
+                let action = self.queue[self.queue_pos];
+                self.queue_pos += 1;
+
+                match action {
+                    CharMapAction::SubChar(c) => return Some(c),
+                    CharMapAction::SubStr(s) => self.sub_chars = s.chars(),
+                    CharMapAction::Pass | CharMapAction::Delete => {}
+                }
+                continue;
+            }
+
+            self.queue_len = 0;
+            self.queue_pos = 0;
+
+            match self.state {
+                State::ToLatin { .. } => self.fill_to_latin()?,
+                State::FromLatin { .. } => self.fill_from_latin()?,
+            }
+        }
+    }
+}
+
+/// A trait providing a convenience method for [`Iterators`](Iterator) of
+/// [`char`] to transliterate their output.
+pub trait TransliterateChars<I: Iterator<Item = char>> {
+    fn transliterate(
+        self,
+        transliterator: &Transliterator,
+    ) -> TransliteratedChars<I>;
+}
+
+impl<'a> TransliterateChars<Chars<'a>> for &'a str {
+    #[inline]
+    fn transliterate(
+        self,
+        transliterator: &Transliterator,
+    ) -> TransliteratedChars<Chars<'a>> {
+        transliterator.transliterate_chars(self.chars())
+    }
+}
+
+impl TransliterateChars<option::IntoIter<char>> for char {
+    #[inline]
+    fn transliterate(
+        self,
+        transliterator: &Transliterator,
+    ) -> TransliteratedChars<option::IntoIter<char>> {
+        transliterator.transliterate_chars(Some(self).into_iter())
+    }
+}
+
+impl<I: Iterator<Item = char>> TransliterateChars<I> for I {
+    #[inline]
+    fn transliterate(
+        self,
+        transliterator: &Transliterator,
+    ) -> TransliteratedChars<I> {
+        transliterator.transliterate_chars(self)
+    }
+}
+
+#[inline]
+fn is_consonant(c: char) -> bool {
+    matches!(c, '\u{0915}'..='\u{0939}' | '\u{0958}'..='\u{095F}')
+}
+
+#[inline]
+fn is_vowel_sign(c: char) -> bool {
+    matches!(c, '\u{093E}'..='\u{094C}' | '\u{0962}' | '\u{0963}')
+}
+
+// Returns whether a romanized consonant would form an aspirate with `h`.
+#[inline]
+fn is_unaspirated(action: CharMapAction) -> bool {
+    match action {
+        CharMapAction::SubChar(c) => UNASPIRATED.contains(c),
+        _ => false,
+    }
+}
+
+#[inline]
+fn to_lower(c: char) -> char {
+    let mut lower = c.to_lowercase();
+
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+// Devanagari to IAST map. Consonants map to their bare forms without the
+// inherent vowel.
+static DEVANAGARI_TO_IAST: [(char, CharMapAction); 92] = [
+    ('\u{0901}', CharMapAction::SubStr("m\u{0310}")), // SIGN CANDRABINDU
+    ('\u{0902}', CharMapAction::SubChar('\u{1E43}')), // SIGN ANUSVARA
+    ('\u{0903}', CharMapAction::SubChar('\u{1E25}')), // SIGN VISARGA
+    ('\u{0905}', CharMapAction::SubChar('a')),        // LETTER A
+    ('\u{0906}', CharMapAction::SubChar('\u{0101}')), // LETTER AA
+    ('\u{0907}', CharMapAction::SubChar('i')),        // LETTER I
+    ('\u{0908}', CharMapAction::SubChar('\u{012B}')), // LETTER II
+    ('\u{0909}', CharMapAction::SubChar('u')),        // LETTER U
+    ('\u{090A}', CharMapAction::SubChar('\u{016B}')), // LETTER UU
+    ('\u{090B}', CharMapAction::SubChar('\u{1E5B}')), // LETTER VOCALIC R
+    ('\u{090C}', CharMapAction::SubChar('\u{1E37}')), // LETTER VOCALIC L
+    ('\u{090D}', CharMapAction::SubChar('\u{00EA}')), // LETTER CANDRA E
+    ('\u{090F}', CharMapAction::SubChar('e')),        // LETTER E
+    ('\u{0910}', CharMapAction::SubStr("ai")),        // LETTER AI
+    ('\u{0911}', CharMapAction::SubChar('\u{00F4}')), // LETTER CANDRA O
+    ('\u{0913}', CharMapAction::SubChar('o')),        // LETTER O
+    ('\u{0914}', CharMapAction::SubStr("au")),        // LETTER AU
+    ('\u{0915}', CharMapAction::SubChar('k')),        // LETTER KA
+    ('\u{0916}', CharMapAction::SubStr("kh")),        // LETTER KHA
+    ('\u{0917}', CharMapAction::SubChar('g')),        // LETTER GA
+    ('\u{0918}', CharMapAction::SubStr("gh")),        // LETTER GHA
+    ('\u{0919}', CharMapAction::SubChar('\u{1E45}')), // LETTER NGA
+    ('\u{091A}', CharMapAction::SubChar('c')),        // LETTER CA
+    ('\u{091B}', CharMapAction::SubStr("ch")),        // LETTER CHA
+    ('\u{091C}', CharMapAction::SubChar('j')),        // LETTER JA
+    ('\u{091D}', CharMapAction::SubStr("jh")),        // LETTER JHA
+    ('\u{091E}', CharMapAction::SubChar('\u{00F1}')), // LETTER NYA
+    ('\u{091F}', CharMapAction::SubChar('\u{1E6D}')), // LETTER TTA
+    ('\u{0920}', CharMapAction::SubStr("\u{1E6D}h")), // LETTER TTHA
+    ('\u{0921}', CharMapAction::SubChar('\u{1E0D}')), // LETTER DDA
+    ('\u{0922}', CharMapAction::SubStr("\u{1E0D}h")), // LETTER DDHA
+    ('\u{0923}', CharMapAction::SubChar('\u{1E47}')), // LETTER NNA
+    ('\u{0924}', CharMapAction::SubChar('t')),        // LETTER TA
+    ('\u{0925}', CharMapAction::SubStr("th")),        // LETTER THA
+    ('\u{0926}', CharMapAction::SubChar('d')),        // LETTER DA
+    ('\u{0927}', CharMapAction::SubStr("dh")),        // LETTER DHA
+    ('\u{0928}', CharMapAction::SubChar('n')),        // LETTER NA
+    ('\u{092A}', CharMapAction::SubChar('p')),        // LETTER PA
+    ('\u{092B}', CharMapAction::SubStr("ph")),        // LETTER PHA
+    ('\u{092C}', CharMapAction::SubChar('b')),        // LETTER BA
+    ('\u{092D}', CharMapAction::SubStr("bh")),        // LETTER BHA
+    ('\u{092E}', CharMapAction::SubChar('m')),        // LETTER MA
+    ('\u{092F}', CharMapAction::SubChar('y')),        // LETTER YA
+    ('\u{0930}', CharMapAction::SubChar('r')),        // LETTER RA
+    ('\u{0932}', CharMapAction::SubChar('l')),        // LETTER LA
+    ('\u{0933}', CharMapAction::SubChar('\u{1E3B}')), // LETTER LLA
+    ('\u{0935}', CharMapAction::SubChar('v')),        // LETTER VA
+    ('\u{0936}', CharMapAction::SubChar('\u{015B}')), // LETTER SHA
+    ('\u{0937}', CharMapAction::SubChar('\u{1E63}')), // LETTER SSA
+    ('\u{0938}', CharMapAction::SubChar('s')),        // LETTER SA
+    ('\u{0939}', CharMapAction::SubChar('h')),        // LETTER HA
+    ('\u{093C}', CharMapAction::Delete),              // SIGN NUKTA
+    ('\u{093D}', CharMapAction::SubChar('\'')),       // SIGN AVAGRAHA
+    ('\u{093E}', CharMapAction::SubChar('\u{0101}')), // VOWEL SIGN AA
+    ('\u{093F}', CharMapAction::SubChar('i')),        // VOWEL SIGN I
+    ('\u{0940}', CharMapAction::SubChar('\u{012B}')), // VOWEL SIGN II
+    ('\u{0941}', CharMapAction::SubChar('u')),        // VOWEL SIGN U
+    ('\u{0942}', CharMapAction::SubChar('\u{016B}')), // VOWEL SIGN UU
+    ('\u{0943}', CharMapAction::SubChar('\u{1E5B}')), // VOWEL SIGN VOCALIC R
+    ('\u{0944}', CharMapAction::SubChar('\u{1E5D}')), // VOWEL SIGN VOCALIC RR
+    ('\u{0945}', CharMapAction::SubChar('\u{00EA}')), // VOWEL SIGN CANDRA E
+    ('\u{0947}', CharMapAction::SubChar('e')),        // VOWEL SIGN E
+    ('\u{0948}', CharMapAction::SubStr("ai")),        // VOWEL SIGN AI
+    ('\u{0949}', CharMapAction::SubChar('\u{00F4}')), // VOWEL SIGN CANDRA O
+    ('\u{094B}', CharMapAction::SubChar('o')),        // VOWEL SIGN O
+    ('\u{094C}', CharMapAction::SubStr("au")),        // VOWEL SIGN AU
+    ('\u{094D}', CharMapAction::Delete),              // SIGN VIRAMA
+    ('\u{0950}', CharMapAction::SubStr("o\u{1E43}")), // OM
+    ('\u{0958}', CharMapAction::SubChar('q')),        // LETTER QA
+    ('\u{0959}', CharMapAction::SubStr("k\u{035F}h")), // LETTER KHHA
+    ('\u{095A}', CharMapAction::SubChar('\u{0121}')), // LETTER GHHA
+    ('\u{095B}', CharMapAction::SubChar('z')),        // LETTER ZA
+    ('\u{095C}', CharMapAction::SubChar('\u{1E5B}')), // LETTER DDDHA
+    ('\u{095D}', CharMapAction::SubStr("\u{1E5B}h")), // LETTER RHA
+    ('\u{095E}', CharMapAction::SubChar('f')),        // LETTER FA
+    ('\u{095F}', CharMapAction::SubChar('\u{1E8F}')), // LETTER YYA
+    ('\u{0960}', CharMapAction::SubChar('\u{1E5D}')), // LETTER VOCALIC RR
+    ('\u{0961}', CharMapAction::SubChar('\u{1E39}')), // LETTER VOCALIC LL
+    ('\u{0962}', CharMapAction::SubChar('\u{1E37}')), // VOWEL SIGN VOCALIC L
+    ('\u{0963}', CharMapAction::SubChar('\u{1E39}')), // VOWEL SIGN VOCALIC LL
+    ('\u{0964}', CharMapAction::SubChar('.')),        // DANDA
+    ('\u{0965}', CharMapAction::SubStr("..")),        // DOUBLE DANDA
+    ('\u{0966}', CharMapAction::SubChar('0')),        // DIGIT ZERO
+    ('\u{0967}', CharMapAction::SubChar('1')),        // DIGIT ONE
+    ('\u{0968}', CharMapAction::SubChar('2')),        // DIGIT TWO
+    ('\u{0969}', CharMapAction::SubChar('3')),        // DIGIT THREE
+    ('\u{096A}', CharMapAction::SubChar('4')),        // DIGIT FOUR
+    ('\u{096B}', CharMapAction::SubChar('5')),        // DIGIT FIVE
+    ('\u{096C}', CharMapAction::SubChar('6')),        // DIGIT SIX
+    ('\u{096D}', CharMapAction::SubChar('7')),        // DIGIT SEVEN
+    ('\u{096E}', CharMapAction::SubChar('8')),        // DIGIT EIGHT
+    ('\u{096F}', CharMapAction::SubChar('9')),        // DIGIT NINE
+];
+const _: () = assert_sorted(&DEVANAGARI_TO_IAST);
+
+// Devanagari to ISO 15919 map. Consonants map to their bare forms without
+// the inherent vowel.
+static DEVANAGARI_TO_ISO_15919: [(char, CharMapAction); 99] = [
+    ('\u{0901}', CharMapAction::SubStr("m\u{0310}")), // SIGN CANDRABINDU
+    ('\u{0902}', CharMapAction::SubChar('\u{1E41}')), // SIGN ANUSVARA
+    ('\u{0903}', CharMapAction::SubChar('\u{1E25}')), // SIGN VISARGA
+    ('\u{0905}', CharMapAction::SubChar('a')),        // LETTER A
+    ('\u{0906}', CharMapAction::SubChar('\u{0101}')), // LETTER AA
+    ('\u{0907}', CharMapAction::SubChar('i')),        // LETTER I
+    ('\u{0908}', CharMapAction::SubChar('\u{012B}')), // LETTER II
+    ('\u{0909}', CharMapAction::SubChar('u')),        // LETTER U
+    ('\u{090A}', CharMapAction::SubChar('\u{016B}')), // LETTER UU
+    ('\u{090B}', CharMapAction::SubStr("r\u{0325}")), // LETTER VOCALIC R
+    ('\u{090C}', CharMapAction::SubStr("l\u{0325}")), // LETTER VOCALIC L
+    ('\u{090D}', CharMapAction::SubChar('\u{00EA}')), // LETTER CANDRA E
+    ('\u{090E}', CharMapAction::SubChar('e')),        // LETTER SHORT E
+    ('\u{090F}', CharMapAction::SubChar('\u{0113}')), // LETTER E
+    ('\u{0910}', CharMapAction::SubStr("ai")),        // LETTER AI
+    ('\u{0911}', CharMapAction::SubChar('\u{00F4}')), // LETTER CANDRA O
+    ('\u{0912}', CharMapAction::SubChar('o')),        // LETTER SHORT O
+    ('\u{0913}', CharMapAction::SubChar('\u{014D}')), // LETTER O
+    ('\u{0914}', CharMapAction::SubStr("au")),        // LETTER AU
+    ('\u{0915}', CharMapAction::SubChar('k')),        // LETTER KA
+    ('\u{0916}', CharMapAction::SubStr("kh")),        // LETTER KHA
+    ('\u{0917}', CharMapAction::SubChar('g')),        // LETTER GA
+    ('\u{0918}', CharMapAction::SubStr("gh")),        // LETTER GHA
+    ('\u{0919}', CharMapAction::SubChar('\u{1E45}')), // LETTER NGA
+    ('\u{091A}', CharMapAction::SubChar('c')),        // LETTER CA
+    ('\u{091B}', CharMapAction::SubStr("ch")),        // LETTER CHA
+    ('\u{091C}', CharMapAction::SubChar('j')),        // LETTER JA
+    ('\u{091D}', CharMapAction::SubStr("jh")),        // LETTER JHA
+    ('\u{091E}', CharMapAction::SubChar('\u{00F1}')), // LETTER NYA
+    ('\u{091F}', CharMapAction::SubChar('\u{1E6D}')), // LETTER TTA
+    ('\u{0920}', CharMapAction::SubStr("\u{1E6D}h")), // LETTER TTHA
+    ('\u{0921}', CharMapAction::SubChar('\u{1E0D}')), // LETTER DDA
+    ('\u{0922}', CharMapAction::SubStr("\u{1E0D}h")), // LETTER DDHA
+    ('\u{0923}', CharMapAction::SubChar('\u{1E47}')), // LETTER NNA
+    ('\u{0924}', CharMapAction::SubChar('t')),        // LETTER TA
+    ('\u{0925}', CharMapAction::SubStr("th")),        // LETTER THA
+    ('\u{0926}', CharMapAction::SubChar('d')),        // LETTER DA
+    ('\u{0927}', CharMapAction::SubStr("dh")),        // LETTER DHA
+    ('\u{0928}', CharMapAction::SubChar('n')),        // LETTER NA
+    ('\u{0929}', CharMapAction::SubChar('\u{1E49}')), // LETTER NNNA
+    ('\u{092A}', CharMapAction::SubChar('p')),        // LETTER PA
+    ('\u{092B}', CharMapAction::SubStr("ph")),        // LETTER PHA
+    ('\u{092C}', CharMapAction::SubChar('b')),        // LETTER BA
+    ('\u{092D}', CharMapAction::SubStr("bh")),        // LETTER BHA
+    ('\u{092E}', CharMapAction::SubChar('m')),        // LETTER MA
+    ('\u{092F}', CharMapAction::SubChar('y')),        // LETTER YA
+    ('\u{0930}', CharMapAction::SubChar('r')),        // LETTER RA
+    ('\u{0931}', CharMapAction::SubChar('\u{1E5F}')), // LETTER RRA
+    ('\u{0932}', CharMapAction::SubChar('l')),        // LETTER LA
+    ('\u{0933}', CharMapAction::SubChar('\u{1E37}')), // LETTER LLA
+    ('\u{0934}', CharMapAction::SubChar('\u{1E3B}')), // LETTER LLLA
+    ('\u{0935}', CharMapAction::SubChar('v')),        // LETTER VA
+    ('\u{0936}', CharMapAction::SubChar('\u{015B}')), // LETTER SHA
+    ('\u{0937}', CharMapAction::SubChar('\u{1E63}')), // LETTER SSA
+    ('\u{0938}', CharMapAction::SubChar('s')),        // LETTER SA
+    ('\u{0939}', CharMapAction::SubChar('h')),        // LETTER HA
+    ('\u{093C}', CharMapAction::Delete),              // SIGN NUKTA
+    ('\u{093D}', CharMapAction::SubChar('\u{2019}')), // SIGN AVAGRAHA
+    ('\u{093E}', CharMapAction::SubChar('\u{0101}')), // VOWEL SIGN AA
+    ('\u{093F}', CharMapAction::SubChar('i')),        // VOWEL SIGN I
+    ('\u{0940}', CharMapAction::SubChar('\u{012B}')), // VOWEL SIGN II
+    ('\u{0941}', CharMapAction::SubChar('u')),        // VOWEL SIGN U
+    ('\u{0942}', CharMapAction::SubChar('\u{016B}')), // VOWEL SIGN UU
+    ('\u{0943}', CharMapAction::SubStr("r\u{0325}")), // VOWEL SIGN VOCALIC R
+    ('\u{0944}', CharMapAction::SubStr("r\u{0325}\u{0304}")), // VOWEL SIGN VOCALIC RR
+    ('\u{0945}', CharMapAction::SubChar('\u{00EA}')), // VOWEL SIGN CANDRA E
+    ('\u{0946}', CharMapAction::SubChar('e')),        // VOWEL SIGN SHORT E
+    ('\u{0947}', CharMapAction::SubChar('\u{0113}')), // VOWEL SIGN E
+    ('\u{0948}', CharMapAction::SubStr("ai")),        // VOWEL SIGN AI
+    ('\u{0949}', CharMapAction::SubChar('\u{00F4}')), // VOWEL SIGN CANDRA O
+    ('\u{094A}', CharMapAction::SubChar('o')),        // VOWEL SIGN SHORT O
+    ('\u{094B}', CharMapAction::SubChar('\u{014D}')), // VOWEL SIGN O
+    ('\u{094C}', CharMapAction::SubStr("au")),        // VOWEL SIGN AU
+    ('\u{094D}', CharMapAction::Delete),              // SIGN VIRAMA
+    ('\u{0950}', CharMapAction::SubStr("\u{014D}\u{1E41}")), // OM
+    ('\u{0958}', CharMapAction::SubChar('q')),        // LETTER QA
+    ('\u{0959}', CharMapAction::SubStr("k\u{035F}h")), // LETTER KHHA
+    ('\u{095A}', CharMapAction::SubChar('\u{0121}')), // LETTER GHHA
+    ('\u{095B}', CharMapAction::SubChar('z')),        // LETTER ZA
+    ('\u{095C}', CharMapAction::SubChar('\u{1E5B}')), // LETTER DDDHA
+    ('\u{095D}', CharMapAction::SubStr("\u{1E5B}h")), // LETTER RHA
+    ('\u{095E}', CharMapAction::SubChar('f')),        // LETTER FA
+    ('\u{095F}', CharMapAction::SubChar('\u{1E8F}')), // LETTER YYA
+    ('\u{0960}', CharMapAction::SubStr("r\u{0325}\u{0304}")), // LETTER VOCALIC RR
+    ('\u{0961}', CharMapAction::SubStr("l\u{0325}\u{0304}")), // LETTER VOCALIC LL
+    ('\u{0962}', CharMapAction::SubStr("l\u{0325}")), // VOWEL SIGN VOCALIC L
+    ('\u{0963}', CharMapAction::SubStr("l\u{0325}\u{0304}")), // VOWEL SIGN VOCALIC LL
+    ('\u{0964}', CharMapAction::SubChar('.')),                // DANDA
+    ('\u{0965}', CharMapAction::SubStr("..")),                // DOUBLE DANDA
+    ('\u{0966}', CharMapAction::SubChar('0')),                // DIGIT ZERO
+    ('\u{0967}', CharMapAction::SubChar('1')),                // DIGIT ONE
+    ('\u{0968}', CharMapAction::SubChar('2')),                // DIGIT TWO
+    ('\u{0969}', CharMapAction::SubChar('3')),                // DIGIT THREE
+    ('\u{096A}', CharMapAction::SubChar('4')),                // DIGIT FOUR
+    ('\u{096B}', CharMapAction::SubChar('5')),                // DIGIT FIVE
+    ('\u{096C}', CharMapAction::SubChar('6')),                // DIGIT SIX
+    ('\u{096D}', CharMapAction::SubChar('7')),                // DIGIT SEVEN
+    ('\u{096E}', CharMapAction::SubChar('8')),                // DIGIT EIGHT
+    ('\u{096F}', CharMapAction::SubChar('9')),                // DIGIT NINE
+];
+const _: () = assert_sorted(&DEVANAGARI_TO_ISO_15919);
+
+// Romanizations of consonants followed by a nukta.
+static NUKTA_CONSONANTS: [(char, CharMapAction); 8] = [
+    ('\u{0915}', CharMapAction::SubChar('q')), // LETTER KA
+    ('\u{0916}', CharMapAction::SubStr("k\u{035F}h")), // LETTER KHA
+    ('\u{0917}', CharMapAction::SubChar('\u{0121}')), // LETTER GA
+    ('\u{091C}', CharMapAction::SubChar('z')), // LETTER JA
+    ('\u{0921}', CharMapAction::SubChar('\u{1E5B}')), // LETTER DDA
+    ('\u{0922}', CharMapAction::SubStr("\u{1E5B}h")), // LETTER DDHA
+    ('\u{092B}', CharMapAction::SubChar('f')), // LETTER PHA
+    ('\u{092F}', CharMapAction::SubChar('\u{1E8F}')), // LETTER YA
+];
+const _: () = assert_sorted(&NUKTA_CONSONANTS);
+
+// IAST tokens and their Devanagari equivalents.
+static IAST_TOKENS: [(&str, Token); 60] = [
+    ("a", Token::Vowel('\u{0905}', None)), // LETTER A
+    ("ai", Token::Vowel('\u{0910}', Some('\u{0948}'))), // LETTER AI
+    ("au", Token::Vowel('\u{0914}', Some('\u{094C}'))), // LETTER AU
+    ("b", Token::Consonant("\u{092C}")),   // LETTER BA
+    ("bh", Token::Consonant("\u{092D}")),  // LETTER BHA
+    ("c", Token::Consonant("\u{091A}")),   // LETTER CA
+    ("ch", Token::Consonant("\u{091B}")),  // LETTER CHA
+    ("d", Token::Consonant("\u{0926}")),   // LETTER DA
+    ("dh", Token::Consonant("\u{0927}")),  // LETTER DHA
+    ("e", Token::Vowel('\u{090F}', Some('\u{0947}'))), // LETTER E
+    ("f", Token::Consonant("\u{092B}\u{093C}")), // LETTER PHA, SIGN NUKTA
+    ("g", Token::Consonant("\u{0917}")),   // LETTER GA
+    ("gh", Token::Consonant("\u{0918}")),  // LETTER GHA
+    ("h", Token::Consonant("\u{0939}")),   // LETTER HA
+    ("i", Token::Vowel('\u{0907}', Some('\u{093F}'))), // LETTER I
+    ("j", Token::Consonant("\u{091C}")),   // LETTER JA
+    ("jh", Token::Consonant("\u{091D}")),  // LETTER JHA
+    ("k", Token::Consonant("\u{0915}")),   // LETTER KA
+    ("kh", Token::Consonant("\u{0916}")),  // LETTER KHA
+    ("k\u{035F}h", Token::Consonant("\u{0916}\u{093C}")), // LETTER KHA, SIGN NUKTA
+    ("l", Token::Consonant("\u{0932}")),                  // LETTER LA
+    ("m", Token::Consonant("\u{092E}")),                  // LETTER MA
+    ("m\u{0310}", Token::Sign('\u{0901}')),               // SIGN CANDRABINDU
+    ("n", Token::Consonant("\u{0928}")),                  // LETTER NA
+    ("o", Token::Vowel('\u{0913}', Some('\u{094B}'))),    // LETTER O
+    ("p", Token::Consonant("\u{092A}")),                  // LETTER PA
+    ("ph", Token::Consonant("\u{092B}")),                 // LETTER PHA
+    ("q", Token::Consonant("\u{0915}\u{093C}")), // LETTER KA, SIGN NUKTA
+    ("r", Token::Consonant("\u{0930}")),         // LETTER RA
+    ("s", Token::Consonant("\u{0938}")),         // LETTER SA
+    ("t", Token::Consonant("\u{0924}")),         // LETTER TA
+    ("th", Token::Consonant("\u{0925}")),        // LETTER THA
+    ("u", Token::Vowel('\u{0909}', Some('\u{0941}'))), // LETTER U
+    ("v", Token::Consonant("\u{0935}")),         // LETTER VA
+    ("y", Token::Consonant("\u{092F}")),         // LETTER YA
+    ("z", Token::Consonant("\u{091C}\u{093C}")), // LETTER JA, SIGN NUKTA
+    ("\u{00EA}", Token::Vowel('\u{090D}', Some('\u{0945}'))), // LETTER CANDRA E
+    ("\u{00F1}", Token::Consonant("\u{091E}")),               // LETTER NYA
+    ("\u{00F4}", Token::Vowel('\u{0911}', Some('\u{0949}'))), // LETTER CANDRA O
+    ("\u{0101}", Token::Vowel('\u{0906}', Some('\u{093E}'))), // LETTER AA
+    ("\u{0121}", Token::Consonant("\u{0917}\u{093C}")), // LETTER GA, SIGN NUKTA
+    ("\u{012B}", Token::Vowel('\u{0908}', Some('\u{0940}'))), // LETTER II
+    ("\u{015B}", Token::Consonant("\u{0936}")),         // LETTER SHA
+    ("\u{016B}", Token::Vowel('\u{090A}', Some('\u{0942}'))), // LETTER UU
+    ("\u{1E0D}", Token::Consonant("\u{0921}")),         // LETTER DDA
+    ("\u{1E0D}h", Token::Consonant("\u{0922}")),        // LETTER DDHA
+    ("\u{1E25}", Token::Sign('\u{0903}')),              // SIGN VISARGA
+    ("\u{1E37}", Token::Vowel('\u{090C}', Some('\u{0962}'))), // LETTER VOCALIC L
+    ("\u{1E39}", Token::Vowel('\u{0961}', Some('\u{0963}'))), // LETTER VOCALIC LL
+    ("\u{1E3B}", Token::Consonant("\u{0933}")),               // LETTER LLA
+    ("\u{1E41}", Token::Sign('\u{0902}')),                    // SIGN ANUSVARA
+    ("\u{1E43}", Token::Sign('\u{0902}')),                    // SIGN ANUSVARA
+    ("\u{1E45}", Token::Consonant("\u{0919}")),               // LETTER NGA
+    ("\u{1E47}", Token::Consonant("\u{0923}")),               // LETTER NNA
+    ("\u{1E5B}", Token::Vowel('\u{090B}', Some('\u{0943}'))), // LETTER VOCALIC R
+    ("\u{1E5D}", Token::Vowel('\u{0960}', Some('\u{0944}'))), // LETTER VOCALIC RR
+    ("\u{1E63}", Token::Consonant("\u{0937}")),               // LETTER SSA
+    ("\u{1E6D}", Token::Consonant("\u{091F}")),               // LETTER TTA
+    ("\u{1E6D}h", Token::Consonant("\u{0920}")),              // LETTER TTHA
+    ("\u{1E8F}", Token::Consonant("\u{092F}\u{093C}")), // LETTER YA, SIGN NUKTA
+];
+
+// ISO 15919 tokens and their Devanagari equivalents.
+static ISO_15919_TOKENS: [(&str, Token); 68] = [
+    (":", Token::Separator),
+    ("a", Token::Vowel('\u{0905}', None)), // LETTER A
+    ("ai", Token::Vowel('\u{0910}', Some('\u{0948}'))), // LETTER AI
+    ("au", Token::Vowel('\u{0914}', Some('\u{094C}'))), // LETTER AU
+    ("b", Token::Consonant("\u{092C}")),   // LETTER BA
+    ("bh", Token::Consonant("\u{092D}")),  // LETTER BHA
+    ("c", Token::Consonant("\u{091A}")),   // LETTER CA
+    ("ch", Token::Consonant("\u{091B}")),  // LETTER CHA
+    ("d", Token::Consonant("\u{0926}")),   // LETTER DA
+    ("dh", Token::Consonant("\u{0927}")),  // LETTER DHA
+    ("e", Token::Vowel('\u{090E}', Some('\u{0946}'))), // LETTER SHORT E
+    ("f", Token::Consonant("\u{092B}\u{093C}")), // LETTER PHA, SIGN NUKTA
+    ("g", Token::Consonant("\u{0917}")),   // LETTER GA
+    ("gh", Token::Consonant("\u{0918}")),  // LETTER GHA
+    ("h", Token::Consonant("\u{0939}")),   // LETTER HA
+    ("i", Token::Vowel('\u{0907}', Some('\u{093F}'))), // LETTER I
+    ("j", Token::Consonant("\u{091C}")),   // LETTER JA
+    ("jh", Token::Consonant("\u{091D}")),  // LETTER JHA
+    ("k", Token::Consonant("\u{0915}")),   // LETTER KA
+    ("kh", Token::Consonant("\u{0916}")),  // LETTER KHA
+    ("k\u{035F}h", Token::Consonant("\u{0916}\u{093C}")), // LETTER KHA, SIGN NUKTA
+    ("l", Token::Consonant("\u{0932}")),                  // LETTER LA
+    ("l\u{0325}", Token::Vowel('\u{090C}', Some('\u{0962}'))), // LETTER VOCALIC L
+    ("l\u{0325}\u{0304}", Token::Vowel('\u{0961}', Some('\u{0963}'))), // LETTER VOCALIC LL
+    ("m", Token::Consonant("\u{092E}")), // LETTER MA
+    ("m\u{0310}", Token::Sign('\u{0901}')), // SIGN CANDRABINDU
+    ("n", Token::Consonant("\u{0928}")), // LETTER NA
+    ("o", Token::Vowel('\u{0912}', Some('\u{094A}'))), // LETTER SHORT O
+    ("p", Token::Consonant("\u{092A}")), // LETTER PA
+    ("ph", Token::Consonant("\u{092B}")), // LETTER PHA
+    ("q", Token::Consonant("\u{0915}\u{093C}")), // LETTER KA, SIGN NUKTA
+    ("r", Token::Consonant("\u{0930}")), // LETTER RA
+    ("r\u{0325}", Token::Vowel('\u{090B}', Some('\u{0943}'))), // LETTER VOCALIC R
+    ("r\u{0325}\u{0304}", Token::Vowel('\u{0960}', Some('\u{0944}'))), // LETTER VOCALIC RR
+    ("s", Token::Consonant("\u{0938}")), // LETTER SA
+    ("t", Token::Consonant("\u{0924}")), // LETTER TA
+    ("th", Token::Consonant("\u{0925}")), // LETTER THA
+    ("u", Token::Vowel('\u{0909}', Some('\u{0941}'))), // LETTER U
+    ("v", Token::Consonant("\u{0935}")), // LETTER VA
+    ("y", Token::Consonant("\u{092F}")), // LETTER YA
+    ("z", Token::Consonant("\u{091C}\u{093C}")), // LETTER JA, SIGN NUKTA
+    ("\u{00EA}", Token::Vowel('\u{090D}', Some('\u{0945}'))), // LETTER CANDRA E
+    ("\u{00F1}", Token::Consonant("\u{091E}")),               // LETTER NYA
+    ("\u{00F4}", Token::Vowel('\u{0911}', Some('\u{0949}'))), // LETTER CANDRA O
+    ("\u{0101}", Token::Vowel('\u{0906}', Some('\u{093E}'))), // LETTER AA
+    ("\u{0113}", Token::Vowel('\u{090F}', Some('\u{0947}'))), // LETTER E
+    ("\u{0121}", Token::Consonant("\u{0917}\u{093C}")), // LETTER GA, SIGN NUKTA
+    ("\u{012B}", Token::Vowel('\u{0908}', Some('\u{0940}'))), // LETTER II
+    ("\u{014D}", Token::Vowel('\u{0913}', Some('\u{094B}'))), // LETTER O
+    ("\u{015B}", Token::Consonant("\u{0936}")),         // LETTER SHA
+    ("\u{016B}", Token::Vowel('\u{090A}', Some('\u{0942}'))), // LETTER UU
+    ("\u{1E0D}", Token::Consonant("\u{0921}")),         // LETTER DDA
+    ("\u{1E0D}h", Token::Consonant("\u{0922}")),        // LETTER DDHA
+    ("\u{1E25}", Token::Sign('\u{0903}')),              // SIGN VISARGA
+    ("\u{1E37}", Token::Consonant("\u{0933}")),         // LETTER LLA
+    ("\u{1E3B}", Token::Consonant("\u{0934}")),         // LETTER LLLA
+    ("\u{1E41}", Token::Sign('\u{0902}')),              // SIGN ANUSVARA
+    ("\u{1E43}", Token::Sign('\u{0902}')),              // SIGN ANUSVARA
+    ("\u{1E45}", Token::Consonant("\u{0919}")),         // LETTER NGA
+    ("\u{1E47}", Token::Consonant("\u{0923}")),         // LETTER NNA
+    ("\u{1E49}", Token::Consonant("\u{0929}")),         // LETTER NNNA
+    ("\u{1E5B}", Token::Consonant("\u{0921}\u{093C}")), // LETTER DDA, SIGN NUKTA
+    ("\u{1E5B}h", Token::Consonant("\u{0922}\u{093C}")), // LETTER DDHA, SIGN NUKTA
+    ("\u{1E5F}", Token::Consonant("\u{0931}")),          // LETTER RRA
+    ("\u{1E63}", Token::Consonant("\u{0937}")),          // LETTER SSA
+    ("\u{1E6D}", Token::Consonant("\u{091F}")),          // LETTER TTA
+    ("\u{1E6D}h", Token::Consonant("\u{0920}")),         // LETTER TTHA
+    ("\u{1E8F}", Token::Consonant("\u{092F}\u{093C}")), // LETTER YA, SIGN NUKTA
+];
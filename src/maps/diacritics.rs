@@ -0,0 +1,155 @@
+//! Diacritic stripping map based on canonical decomposition.
+//!
+//! Instead of listing every accented letter, [`Stripper`] decomposes each
+//! character (as in NFD), removes its diacritics, and recomposes the rest (as
+//! in NFC). This works for any script whose accented letters have canonical
+//! decompositions, including Latin, Greek (e.g. ᾄ `α`), Cyrillic (e.g. ё
+//! `е`), and Vietnamese letters with stacked tone marks (e.g. ệ `e`).
+//!
+//! Diacritics are combining marks with a non-zero canonical combining class
+//! (see [`is_diacritic`]). Nuktas, kana voicing marks, and viramas are not
+//! considered diacritics since they are part of the spelling of their
+//! scripts, and neither are the vowel signs and tone marks of Telugu, Thai,
+//! Lao, and Tibetan (e.g. Thai สุข and ไม่ and Tibetan བོད are left as is).
+//! Letters without a canonical decomposition (e.g. ø, ł, and đ) are left as
+//! is.
+//!
+//! Some diacritics distinguish letters in some languages (e.g. the breve in
+//! Russian й or the horn in Vietnamese ư), so marks can be kept using
+//! [`Stripper::keep`]:
+//!
+//! ```rust
+//! use charmap::maps::diacritics::Stripper;
+//! use charmap::*;
+//!
+//! let stripper = Stripper::new();
+//! let mapper = CharMapper::new(&stripper, CharMapAction::Pass);
+//!
+//! let stripped: String = "Crème brûlée, Ἀθῆναι".map_chars(&mapper).collect();
+//! assert_eq!(stripped, "Creme brulee, Αθηναι");
+//!
+//! let stripped: String = "Tiếng Việt, Йошкар-Ола".map_chars(&mapper).collect();
+//! assert_eq!(stripped, "Tieng Viet, Иошкар-Ола");
+//!
+//! // Keep the circumflex, breve, and horn, stripping only Vietnamese tones,
+//! // which also keeps Russian й.
+//! let stripper = Stripper::new().keep(&['\u{0302}', '\u{0306}', '\u{031B}']);
+//! let mapper = CharMapper::new(&stripper, CharMapAction::Pass);
+//!
+//! let stripped: String = "Tiếng Việt, Йошкар-Ола".map_chars(&mapper).collect();
+//! assert_eq!(stripped, "Tiêng Viêt, Йошкар-Ола");
+//! ```
+//!
+//! A precomposed character is only stripped if the remaining characters
+//! recompose into a single character, which is almost always the case. For
+//! exact results, the map can be applied to decomposed text, in which case it
+//! simply removes diacritics:
+//!
+//! ```rust
+//! use charmap::maps::diacritics::Stripper;
+//! use charmap::*;
+//!
+//! let stripper = Stripper::new();
+//! let mapper = CharMapper::new(&stripper, CharMapAction::Pass);
+//!
+//! let stripped: String = "Ελληνικά"
+//!     .normalize(NormalForm::Nfd)
+//!     .map_chars(&mapper)
+//!     .normalize(NormalForm::Nfc)
+//!     .collect();
+//! assert_eq!(stripped, "Ελληνικα");
+//! ```
+
+use unicode_normalization::char::{
+    canonical_combining_class, compose, decompose_canonical,
+};
+
+use crate::actionmap::{ActionMap, CharMapAction};
+
+/// Returns whether a given character is a diacritic, i.e. a combining mark
+/// with a non-zero canonical combining class other than nukta (7), kana
+/// voicing (8), virama (9), and the fixed position classes of Telugu, Thai,
+/// Lao, and Tibetan vowel signs and tone marks (84 to 132).
+#[inline]
+pub fn is_diacritic(c: char) -> bool {
+    !matches!(canonical_combining_class(c), 0 | 7..=9 | 84..=132)
+}
+
+/// An [`ActionMap`] stripping diacritics from characters, except for a given
+/// set of marks to keep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Stripper<'a> {
+    keep: &'a [char],
+}
+
+impl<'a> Stripper<'a> {
+    /// Creates a new [`Stripper`] stripping all diacritics.
+    #[inline]
+    pub const fn new() -> Self {
+        Stripper { keep: &[] }
+    }
+
+    /// Sets the diacritics to keep (e.g. `'\u{0308}'` to keep diaereses).
+    #[inline]
+    pub const fn keep(mut self, marks: &'a [char]) -> Self {
+        self.keep = marks;
+        self
+    }
+
+    /// Returns the diacritics kept by this [`Stripper`].
+    #[inline]
+    pub fn kept(&self) -> &'a [char] {
+        self.keep
+    }
+
+    #[inline]
+    fn strips(&self, c: char) -> bool {
+        is_diacritic(c) && !self.keep.contains(&c)
+    }
+}
+
+impl Default for Stripper<'_> {
+    #[inline]
+    fn default() -> Self {
+        Stripper::new()
+    }
+}
+
+impl ActionMap for Stripper<'_> {
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        if self.strips(c) {
+            return Some(CharMapAction::Delete);
+        }
+
+        // Decompose the character, skipping stripped diacritics, and
+        // recompose the rest as we go.
+        let mut stripped = false;
+        let mut composed: Option<char> = None;
+        let mut recomposable = true;
+
+        decompose_canonical(c, |d| {
+            if self.strips(d) {
+                stripped = true;
+                return;
+            }
+
+            composed = match composed {
+                None => Some(d),
+                Some(a) => match compose(a, d) {
+                    Some(b) => Some(b),
+                    None => {
+                        recomposable = false;
+                        Some(a)
+                    }
+                },
+            };
+        });
+
+        match composed {
+            Some(d) if stripped && recomposable => {
+                Some(CharMapAction::SubChar(d))
+            }
+            _ => None,
+        }
+    }
+}
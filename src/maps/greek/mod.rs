@@ -0,0 +1,218 @@
+//! Greek accent stripping, polytonic folding, and ELOT 743 transliteration
+//! maps.
+//!
+//! * [`STRIP_ACCENTS`] removes tonos and dialytika from monotonic Greek
+//!   letters (e.g. ά `α` and ϊ `ι`).
+//! * [`FOLD_POLYTONIC`] folds polytonic letters in the Greek Extended block
+//!   (U+1F00-U+1FFF) to their monotonic base letters (e.g. ἄ `α` and ῷ `ω`)
+//!   and removes polytonic diacritics.
+//! * [`Romanizer`] transliterates Greek to Latin according to ELOT 743 (e.g.
+//!   θ `th`, χ `ch`, and ψ `ps`), dropping all accents and breathings.
+//!
+//! The maps can be combined using pairs, e.g. `(&STRIP_ACCENTS,
+//! &FOLD_POLYTONIC)` to strip all accents from both monotonic and polytonic
+//! text.
+//!
+//! Some rules of ELOT 743 depend on the surrounding letters, which a single
+//! [`CharMapAction`] cannot express:
+//!
+//! * ου is `ou`.
+//! * αυ, ευ, and ηυ are `av`, `ev`, and `iv` before vowels and voiced
+//!   consonants (β, γ, δ, ζ, λ, μ, ν, and ρ), and `af`, `ef`, and `if`
+//!   elsewhere. A dialytika on υ (e.g. αϋ) marks separate vowels.
+//! * γ is `n` before γ, ξ, and χ, and before κ except at the start of a word
+//!   (e.g. γγ `ng`, γκ `nk` or `gk`, γξ `nx`, and γχ `nch`).
+//! * μπ is `b` at the start of a word and `mp` elsewhere, while ντ is always
+//!   `nt`.
+//!
+//! [`ELOT_743`] contains the context-free form of each letter (e.g. υ `y`), so
+//! it can be used with a [`CharMapper`](crate::CharMapper) when an
+//! approximate transliteration is good enough. [`Romanizer::romanize_chars`]
+//! applies the context-dependent rules as well, and also fully capitalizes
+//! multi-letter transliterations of capital letters in all-caps words (e.g.
+//! ΘΕΑ `THEA` instead of `ThEA`). It expects precomposed (NFC) text.
+//!
+//! Note that [`STRIP_ACCENTS`] and [`FOLD_POLYTONIC`] delete combining marks
+//! which are not specific to Greek (e.g. U+0301 COMBINING ACUTE ACCENT), so
+//! they should only be applied to Greek text if it is decomposed.
+//!
+//! ```rust
+//! use charmap::maps::greek::{Romanizer, FOLD_POLYTONIC, STRIP_ACCENTS};
+//! use charmap::*;
+//!
+//! let strip = (&STRIP_ACCENTS, &FOLD_POLYTONIC);
+//! let mapper = CharMapper::new(&strip, CharMapAction::Pass);
+//!
+//! let stripped: String = "Ἀθῆναι, Αθήνα".map_chars(&mapper).collect();
+//! assert_eq!(stripped, "Αθηναι, Αθηνα");
+//!
+//! let romanizer = Romanizer::new();
+//! let romanize = |s: &str| -> String {
+//!     romanizer.romanize_chars(s.chars()).collect()
+//! };
+//!
+//! assert_eq!(romanize("Μπουζούκι"), "Bouzouki");
+//! assert_eq!(romanize("ΕΥΑΓΓΕΛΟΣ"), "EVANGELOS");
+//! ```
+//!
+//! The tables are generated by `scripts/gen_greek.py` from Unicode data.
+
+mod tables;
+
+pub use self::tables::{ELOT_743, FOLD_POLYTONIC, STRIP_ACCENTS};
+
+use crate::actionmap::{ActionMap, CharMapAction};
+
+use super::romanize::{ContextRomanized, ContextRules};
+
+/// A Greek to Latin ELOT 743 romanizer.
+///
+/// Used as an [`ActionMap`], a [`Romanizer`] applies [`ELOT_743`], ignoring
+/// context-dependent rules. Use [`Romanizer::romanize_chars`] to apply them as
+/// well.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Romanizer;
+
+impl Romanizer {
+    /// Creates a new [`Romanizer`].
+    #[inline]
+    pub const fn new() -> Self {
+        Romanizer
+    }
+
+    /// Returns an iterator that romanizes characters from a given character
+    /// iterator, applying context-dependent rules. Characters not in the map
+    /// are output as they are.
+    #[inline]
+    pub fn romanize_chars<I>(&self, text_chars: I) -> RomanizedChars<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        RomanizedChars(ContextRomanized::new(*self, text_chars))
+    }
+}
+
+impl ActionMap for Romanizer {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        table_action(c)
+    }
+}
+
+impl ContextRules for Romanizer {
+    fn action(
+        &self,
+        prev: Option<char>,
+        c: char,
+        next: Option<char>,
+    ) -> (CharMapAction<'static>, bool) {
+        let is_upper = c.is_uppercase();
+        let cased = |lower, upper| {
+            CharMapAction::SubChar(if is_upper { upper } else { lower })
+        };
+        let word_start = !prev.is_some_and(char::is_alphabetic);
+        let base_prev = prev.map(base_letter);
+        let base_next = next.map(base_letter);
+
+        let action = match base_letter(c) {
+            'υ' if !has_dialytika(c) => match base_prev {
+                Some('ο') => cased('u', 'U'),
+                Some('α' | 'ε' | 'η') => {
+                    if base_next.is_some_and(is_voiced) {
+                        cased('v', 'V')
+                    } else {
+                        cased('f', 'F')
+                    }
+                }
+                _ => cased('y', 'Y'),
+            },
+            'γ' => match base_next {
+                Some('γ' | 'ξ' | 'χ') => cased('n', 'N'),
+                Some('κ') if !word_start => cased('n', 'N'),
+                _ => cased('g', 'G'),
+            },
+            'μ' if word_start && base_next == Some('π') => {
+                return (cased('b', 'B'), true);
+            }
+            _ => table_action(c).unwrap_or(CharMapAction::Pass),
+        };
+
+        (action, false)
+    }
+}
+
+/// Iterator returned by [`Romanizer::romanize_chars`].
+#[derive(Clone)]
+pub struct RomanizedChars<I>(ContextRomanized<Romanizer, I>)
+where
+    I: Iterator<Item = char>;
+
+impl<I> Iterator for RomanizedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[inline]
+fn table_action(c: char) -> Option<CharMapAction<'static>> {
+    <[_] as ActionMap>::map_char(&ELOT_743, c)
+}
+
+// Returns the lowercase base letter of a Greek letter (e.g. ά `α` and Ἄ
+// `α`).
+#[inline]
+fn base_letter(c: char) -> char {
+    let c = c.to_lowercase().next().unwrap_or(c);
+    let actions = (&STRIP_ACCENTS, &FOLD_POLYTONIC);
+
+    match actions.map_char(c) {
+        Some(CharMapAction::SubChar(d)) => d,
+        _ => c,
+    }
+}
+
+#[inline]
+fn has_dialytika(c: char) -> bool {
+    matches!(
+        c,
+        'ϊ' | 'ϋ'
+            | 'ΐ'
+            | 'ΰ'
+            | 'Ϊ'
+            | 'Ϋ'
+            | '\u{1FD2}'
+            | '\u{1FD3}'
+            | '\u{1FD7}'
+            | '\u{1FE2}'
+            | '\u{1FE3}'
+            | '\u{1FE7}'
+    )
+}
+
+// Returns whether a lowercase Greek letter is a vowel or a voiced consonant.
+#[inline]
+fn is_voiced(c: char) -> bool {
+    matches!(
+        c,
+        'α' | 'ε'
+            | 'η'
+            | 'ι'
+            | 'ο'
+            | 'υ'
+            | 'ω'
+            | 'β'
+            | 'γ'
+            | 'δ'
+            | 'ζ'
+            | 'λ'
+            | 'μ'
+            | 'ν'
+            | 'ρ'
+    )
+}
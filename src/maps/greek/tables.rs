@@ -0,0 +1,603 @@
+// This file is generated by `scripts/gen_greek.py` from Unicode 14.0.0
+// data. Do not edit it manually.
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+
+/// Map removing tonos and dialytika from Greek letters.
+#[rustfmt::skip]
+pub static STRIP_ACCENTS: [(char, CharMapAction); 27] = [
+    ('\u{0301}', CharMapAction::Delete), // COMBINING ACUTE ACCENT
+    ('\u{0308}', CharMapAction::Delete), // COMBINING DIAERESIS
+    ('\u{0344}', CharMapAction::Delete), // COMBINING DIALYTIKA TONOS
+    ('\u{0384}', CharMapAction::Delete), // TONOS
+    ('\u{0385}', CharMapAction::Delete), // DIALYTIKA TONOS
+    ('\u{0386}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH TONOS
+    ('\u{0388}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH TONOS
+    ('\u{0389}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH TONOS
+    ('\u{038A}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH TONOS
+    ('\u{038C}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH TONOS
+    ('\u{038E}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH TONOS
+    ('\u{038F}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH TONOS
+    ('\u{0390}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DIALYTIKA AND TONOS
+    ('\u{03AA}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH DIALYTIKA
+    ('\u{03AB}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03AC}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH TONOS
+    ('\u{03AD}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH TONOS
+    ('\u{03AE}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH TONOS
+    ('\u{03AF}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH TONOS
+    ('\u{03B0}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS
+    ('\u{03CA}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DIALYTIKA
+    ('\u{03CB}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03CC}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH TONOS
+    ('\u{03CD}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH TONOS
+    ('\u{03CE}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH TONOS
+    ('\u{03D3}', CharMapAction::SubChar('ϒ')), // UPSILON WITH ACUTE AND HOOK SYMBOL
+    ('\u{03D4}', CharMapAction::SubChar('ϒ')), // UPSILON WITH DIAERESIS AND HOOK SYMBOL
+];
+const _: () = assert_sorted(&STRIP_ACCENTS);
+
+/// Map folding polytonic Greek letters to their base letters.
+#[rustfmt::skip]
+pub static FOLD_POLYTONIC: [(char, CharMapAction); 239] = [
+    ('\u{0300}', CharMapAction::Delete), // COMBINING GRAVE ACCENT
+    ('\u{0313}', CharMapAction::Delete), // COMBINING COMMA ABOVE
+    ('\u{0314}', CharMapAction::Delete), // COMBINING REVERSED COMMA ABOVE
+    ('\u{0342}', CharMapAction::Delete), // COMBINING PERISPOMENI
+    ('\u{0343}', CharMapAction::Delete), // COMBINING KORONIS
+    ('\u{0345}', CharMapAction::Delete), // COMBINING YPOGEGRAMMENI
+    ('\u{1F00}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI
+    ('\u{1F01}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA
+    ('\u{1F02}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F03}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F04}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F05}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F06}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F07}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F08}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI
+    ('\u{1F09}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA
+    ('\u{1F0A}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F0B}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F0C}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F0D}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F0E}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F0F}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F10}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH PSILI
+    ('\u{1F11}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH DASIA
+    ('\u{1F12}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F13}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F14}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F15}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F18}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH PSILI
+    ('\u{1F19}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH DASIA
+    ('\u{1F1A}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F1B}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F1C}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F1D}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F20}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI
+    ('\u{1F21}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA
+    ('\u{1F22}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F23}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F24}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F25}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F26}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F27}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F28}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI
+    ('\u{1F29}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA
+    ('\u{1F2A}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F2B}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F2C}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F2D}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F2E}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F2F}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F30}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH PSILI
+    ('\u{1F31}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DASIA
+    ('\u{1F32}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F33}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F34}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F35}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F36}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F37}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F38}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH PSILI
+    ('\u{1F39}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH DASIA
+    ('\u{1F3A}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F3B}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F3C}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F3D}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F3E}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F3F}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F40}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH PSILI
+    ('\u{1F41}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH DASIA
+    ('\u{1F42}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F43}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F44}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F45}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F48}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH PSILI
+    ('\u{1F49}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH DASIA
+    ('\u{1F4A}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F4B}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F4C}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F4D}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F50}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH PSILI
+    ('\u{1F51}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DASIA
+    ('\u{1F52}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH PSILI AND VARIA
+    ('\u{1F53}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F54}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH PSILI AND OXIA
+    ('\u{1F55}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F56}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH PSILI AND PERISPOMENI
+    ('\u{1F57}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F59}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH DASIA
+    ('\u{1F5B}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F5D}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F5F}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F60}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI
+    ('\u{1F61}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA
+    ('\u{1F62}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F63}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F64}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F65}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F66}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F67}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F68}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI
+    ('\u{1F69}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA
+    ('\u{1F6A}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F6B}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F6C}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F6D}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F6E}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F6F}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F70}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH VARIA
+    ('\u{1F71}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH OXIA
+    ('\u{1F72}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH VARIA
+    ('\u{1F73}', CharMapAction::SubChar('ε')), // SMALL LETTER EPSILON WITH OXIA
+    ('\u{1F74}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH VARIA
+    ('\u{1F75}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH OXIA
+    ('\u{1F76}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH VARIA
+    ('\u{1F77}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH OXIA
+    ('\u{1F78}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH VARIA
+    ('\u{1F79}', CharMapAction::SubChar('ο')), // SMALL LETTER OMICRON WITH OXIA
+    ('\u{1F7A}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH VARIA
+    ('\u{1F7B}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH OXIA
+    ('\u{1F7C}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH VARIA
+    ('\u{1F7D}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH OXIA
+    ('\u{1F80}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F81}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F82}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F83}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F84}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F85}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F86}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F87}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F88}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F89}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F8A}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8B}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8C}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8D}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8E}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F8F}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F90}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F91}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F92}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F93}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F94}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F95}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F96}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F97}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F98}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F99}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F9A}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9B}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9C}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9D}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9E}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F9F}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FA0}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1FA1}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1FA2}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA3}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA4}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA5}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA6}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA7}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA8}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1FA9}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1FAA}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAB}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAC}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAD}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAE}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FAF}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FB0}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH VRACHY
+    ('\u{1FB1}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH MACRON
+    ('\u{1FB2}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FB3}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH YPOGEGRAMMENI
+    ('\u{1FB4}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FB6}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PERISPOMENI
+    ('\u{1FB7}', CharMapAction::SubChar('α')), // SMALL LETTER ALPHA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FB8}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH VRACHY
+    ('\u{1FB9}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH MACRON
+    ('\u{1FBA}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH VARIA
+    ('\u{1FBB}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH OXIA
+    ('\u{1FBC}', CharMapAction::SubChar('Α')), // CAPITAL LETTER ALPHA WITH PROSGEGRAMMENI
+    ('\u{1FBD}', CharMapAction::Delete), // KORONIS
+    ('\u{1FBE}', CharMapAction::SubChar('ι')), // PROSGEGRAMMENI
+    ('\u{1FBF}', CharMapAction::Delete), // PSILI
+    ('\u{1FC0}', CharMapAction::Delete), // PERISPOMENI
+    ('\u{1FC1}', CharMapAction::Delete), // DIALYTIKA AND PERISPOMENI
+    ('\u{1FC2}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FC3}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH YPOGEGRAMMENI
+    ('\u{1FC4}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FC6}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PERISPOMENI
+    ('\u{1FC7}', CharMapAction::SubChar('η')), // SMALL LETTER ETA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FC8}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH VARIA
+    ('\u{1FC9}', CharMapAction::SubChar('Ε')), // CAPITAL LETTER EPSILON WITH OXIA
+    ('\u{1FCA}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH VARIA
+    ('\u{1FCB}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH OXIA
+    ('\u{1FCC}', CharMapAction::SubChar('Η')), // CAPITAL LETTER ETA WITH PROSGEGRAMMENI
+    ('\u{1FCD}', CharMapAction::Delete), // PSILI AND VARIA
+    ('\u{1FCE}', CharMapAction::Delete), // PSILI AND OXIA
+    ('\u{1FCF}', CharMapAction::Delete), // PSILI AND PERISPOMENI
+    ('\u{1FD0}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH VRACHY
+    ('\u{1FD1}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH MACRON
+    ('\u{1FD2}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DIALYTIKA AND VARIA
+    ('\u{1FD3}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DIALYTIKA AND OXIA
+    ('\u{1FD6}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH PERISPOMENI
+    ('\u{1FD7}', CharMapAction::SubChar('ι')), // SMALL LETTER IOTA WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FD8}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH VRACHY
+    ('\u{1FD9}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH MACRON
+    ('\u{1FDA}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH VARIA
+    ('\u{1FDB}', CharMapAction::SubChar('Ι')), // CAPITAL LETTER IOTA WITH OXIA
+    ('\u{1FDD}', CharMapAction::Delete), // DASIA AND VARIA
+    ('\u{1FDE}', CharMapAction::Delete), // DASIA AND OXIA
+    ('\u{1FDF}', CharMapAction::Delete), // DASIA AND PERISPOMENI
+    ('\u{1FE0}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH VRACHY
+    ('\u{1FE1}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH MACRON
+    ('\u{1FE2}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DIALYTIKA AND VARIA
+    ('\u{1FE3}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DIALYTIKA AND OXIA
+    ('\u{1FE4}', CharMapAction::SubChar('ρ')), // SMALL LETTER RHO WITH PSILI
+    ('\u{1FE5}', CharMapAction::SubChar('ρ')), // SMALL LETTER RHO WITH DASIA
+    ('\u{1FE6}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH PERISPOMENI
+    ('\u{1FE7}', CharMapAction::SubChar('υ')), // SMALL LETTER UPSILON WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FE8}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH VRACHY
+    ('\u{1FE9}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH MACRON
+    ('\u{1FEA}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH VARIA
+    ('\u{1FEB}', CharMapAction::SubChar('Υ')), // CAPITAL LETTER UPSILON WITH OXIA
+    ('\u{1FEC}', CharMapAction::SubChar('Ρ')), // CAPITAL LETTER RHO WITH DASIA
+    ('\u{1FED}', CharMapAction::Delete), // DIALYTIKA AND VARIA
+    ('\u{1FEE}', CharMapAction::Delete), // DIALYTIKA AND OXIA
+    ('\u{1FEF}', CharMapAction::Delete), // VARIA
+    ('\u{1FF2}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FF3}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH YPOGEGRAMMENI
+    ('\u{1FF4}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FF6}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PERISPOMENI
+    ('\u{1FF7}', CharMapAction::SubChar('ω')), // SMALL LETTER OMEGA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FF8}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH VARIA
+    ('\u{1FF9}', CharMapAction::SubChar('Ο')), // CAPITAL LETTER OMICRON WITH OXIA
+    ('\u{1FFA}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH VARIA
+    ('\u{1FFB}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH OXIA
+    ('\u{1FFC}', CharMapAction::SubChar('Ω')), // CAPITAL LETTER OMEGA WITH PROSGEGRAMMENI
+    ('\u{1FFD}', CharMapAction::Delete), // OXIA
+    ('\u{1FFE}', CharMapAction::Delete), // DASIA
+];
+const _: () = assert_sorted(&FOLD_POLYTONIC);
+
+/// Greek to Latin ELOT 743 transliteration map (context-free form).
+#[rustfmt::skip]
+pub static ELOT_743: [(char, CharMapAction); 315] = [
+    ('\u{0300}', CharMapAction::Delete), // COMBINING GRAVE ACCENT
+    ('\u{0301}', CharMapAction::Delete), // COMBINING ACUTE ACCENT
+    ('\u{0308}', CharMapAction::Delete), // COMBINING DIAERESIS
+    ('\u{0313}', CharMapAction::Delete), // COMBINING COMMA ABOVE
+    ('\u{0314}', CharMapAction::Delete), // COMBINING REVERSED COMMA ABOVE
+    ('\u{0342}', CharMapAction::Delete), // COMBINING PERISPOMENI
+    ('\u{0343}', CharMapAction::Delete), // COMBINING KORONIS
+    ('\u{0344}', CharMapAction::Delete), // COMBINING DIALYTIKA TONOS
+    ('\u{0345}', CharMapAction::Delete), // COMBINING YPOGEGRAMMENI
+    ('\u{037E}', CharMapAction::SubChar('?')), // QUESTION MARK
+    ('\u{0384}', CharMapAction::Delete), // TONOS
+    ('\u{0385}', CharMapAction::Delete), // DIALYTIKA TONOS
+    ('\u{0386}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH TONOS
+    ('\u{0387}', CharMapAction::SubChar(';')), // ANO TELEIA
+    ('\u{0388}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH TONOS
+    ('\u{0389}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH TONOS
+    ('\u{038A}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH TONOS
+    ('\u{038C}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH TONOS
+    ('\u{038E}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH TONOS
+    ('\u{038F}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH TONOS
+    ('\u{0390}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DIALYTIKA AND TONOS
+    ('\u{0391}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA
+    ('\u{0392}', CharMapAction::SubChar('V')), // CAPITAL LETTER BETA
+    ('\u{0393}', CharMapAction::SubChar('G')), // CAPITAL LETTER GAMMA
+    ('\u{0394}', CharMapAction::SubChar('D')), // CAPITAL LETTER DELTA
+    ('\u{0395}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON
+    ('\u{0396}', CharMapAction::SubChar('Z')), // CAPITAL LETTER ZETA
+    ('\u{0397}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA
+    ('\u{0398}', CharMapAction::SubStr("Th")), // CAPITAL LETTER THETA
+    ('\u{0399}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA
+    ('\u{039A}', CharMapAction::SubChar('K')), // CAPITAL LETTER KAPPA
+    ('\u{039B}', CharMapAction::SubChar('L')), // CAPITAL LETTER LAMDA
+    ('\u{039C}', CharMapAction::SubChar('M')), // CAPITAL LETTER MU
+    ('\u{039D}', CharMapAction::SubChar('N')), // CAPITAL LETTER NU
+    ('\u{039E}', CharMapAction::SubChar('X')), // CAPITAL LETTER XI
+    ('\u{039F}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON
+    ('\u{03A0}', CharMapAction::SubChar('P')), // CAPITAL LETTER PI
+    ('\u{03A1}', CharMapAction::SubChar('R')), // CAPITAL LETTER RHO
+    ('\u{03A3}', CharMapAction::SubChar('S')), // CAPITAL LETTER SIGMA
+    ('\u{03A4}', CharMapAction::SubChar('T')), // CAPITAL LETTER TAU
+    ('\u{03A5}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON
+    ('\u{03A6}', CharMapAction::SubChar('F')), // CAPITAL LETTER PHI
+    ('\u{03A7}', CharMapAction::SubStr("Ch")), // CAPITAL LETTER CHI
+    ('\u{03A8}', CharMapAction::SubStr("Ps")), // CAPITAL LETTER PSI
+    ('\u{03A9}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA
+    ('\u{03AA}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH DIALYTIKA
+    ('\u{03AB}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03AC}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH TONOS
+    ('\u{03AD}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH TONOS
+    ('\u{03AE}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH TONOS
+    ('\u{03AF}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH TONOS
+    ('\u{03B0}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DIALYTIKA AND TONOS
+    ('\u{03B1}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA
+    ('\u{03B2}', CharMapAction::SubChar('v')), // SMALL LETTER BETA
+    ('\u{03B3}', CharMapAction::SubChar('g')), // SMALL LETTER GAMMA
+    ('\u{03B4}', CharMapAction::SubChar('d')), // SMALL LETTER DELTA
+    ('\u{03B5}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON
+    ('\u{03B6}', CharMapAction::SubChar('z')), // SMALL LETTER ZETA
+    ('\u{03B7}', CharMapAction::SubChar('i')), // SMALL LETTER ETA
+    ('\u{03B8}', CharMapAction::SubStr("th")), // SMALL LETTER THETA
+    ('\u{03B9}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA
+    ('\u{03BA}', CharMapAction::SubChar('k')), // SMALL LETTER KAPPA
+    ('\u{03BB}', CharMapAction::SubChar('l')), // SMALL LETTER LAMDA
+    ('\u{03BC}', CharMapAction::SubChar('m')), // SMALL LETTER MU
+    ('\u{03BD}', CharMapAction::SubChar('n')), // SMALL LETTER NU
+    ('\u{03BE}', CharMapAction::SubChar('x')), // SMALL LETTER XI
+    ('\u{03BF}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON
+    ('\u{03C0}', CharMapAction::SubChar('p')), // SMALL LETTER PI
+    ('\u{03C1}', CharMapAction::SubChar('r')), // SMALL LETTER RHO
+    ('\u{03C2}', CharMapAction::SubChar('s')), // SMALL LETTER FINAL SIGMA
+    ('\u{03C3}', CharMapAction::SubChar('s')), // SMALL LETTER SIGMA
+    ('\u{03C4}', CharMapAction::SubChar('t')), // SMALL LETTER TAU
+    ('\u{03C5}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON
+    ('\u{03C6}', CharMapAction::SubChar('f')), // SMALL LETTER PHI
+    ('\u{03C7}', CharMapAction::SubStr("ch")), // SMALL LETTER CHI
+    ('\u{03C8}', CharMapAction::SubStr("ps")), // SMALL LETTER PSI
+    ('\u{03C9}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA
+    ('\u{03CA}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DIALYTIKA
+    ('\u{03CB}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DIALYTIKA
+    ('\u{03CC}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH TONOS
+    ('\u{03CD}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH TONOS
+    ('\u{03CE}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH TONOS
+    ('\u{1F00}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI
+    ('\u{1F01}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA
+    ('\u{1F02}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F03}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F04}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F05}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F06}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F07}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F08}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI
+    ('\u{1F09}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA
+    ('\u{1F0A}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND VARIA
+    ('\u{1F0B}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND VARIA
+    ('\u{1F0C}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND OXIA
+    ('\u{1F0D}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND OXIA
+    ('\u{1F0E}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI
+    ('\u{1F0F}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI
+    ('\u{1F10}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH PSILI
+    ('\u{1F11}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH DASIA
+    ('\u{1F12}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F13}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F14}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F15}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F18}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH PSILI
+    ('\u{1F19}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH DASIA
+    ('\u{1F1A}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH PSILI AND VARIA
+    ('\u{1F1B}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH DASIA AND VARIA
+    ('\u{1F1C}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH PSILI AND OXIA
+    ('\u{1F1D}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH DASIA AND OXIA
+    ('\u{1F20}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI
+    ('\u{1F21}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA
+    ('\u{1F22}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F23}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F24}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F25}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F26}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F27}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F28}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI
+    ('\u{1F29}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA
+    ('\u{1F2A}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND VARIA
+    ('\u{1F2B}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND VARIA
+    ('\u{1F2C}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND OXIA
+    ('\u{1F2D}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND OXIA
+    ('\u{1F2E}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI
+    ('\u{1F2F}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI
+    ('\u{1F30}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH PSILI
+    ('\u{1F31}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DASIA
+    ('\u{1F32}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F33}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F34}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F35}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F36}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F37}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F38}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH PSILI
+    ('\u{1F39}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH DASIA
+    ('\u{1F3A}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH PSILI AND VARIA
+    ('\u{1F3B}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH DASIA AND VARIA
+    ('\u{1F3C}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH PSILI AND OXIA
+    ('\u{1F3D}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH DASIA AND OXIA
+    ('\u{1F3E}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH PSILI AND PERISPOMENI
+    ('\u{1F3F}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH DASIA AND PERISPOMENI
+    ('\u{1F40}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH PSILI
+    ('\u{1F41}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH DASIA
+    ('\u{1F42}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F43}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F44}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F45}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F48}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH PSILI
+    ('\u{1F49}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH DASIA
+    ('\u{1F4A}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH PSILI AND VARIA
+    ('\u{1F4B}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH DASIA AND VARIA
+    ('\u{1F4C}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH PSILI AND OXIA
+    ('\u{1F4D}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH DASIA AND OXIA
+    ('\u{1F50}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH PSILI
+    ('\u{1F51}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DASIA
+    ('\u{1F52}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH PSILI AND VARIA
+    ('\u{1F53}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F54}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH PSILI AND OXIA
+    ('\u{1F55}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F56}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH PSILI AND PERISPOMENI
+    ('\u{1F57}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F59}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH DASIA
+    ('\u{1F5B}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH DASIA AND VARIA
+    ('\u{1F5D}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH DASIA AND OXIA
+    ('\u{1F5F}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH DASIA AND PERISPOMENI
+    ('\u{1F60}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI
+    ('\u{1F61}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA
+    ('\u{1F62}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F63}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F64}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F65}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F66}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F67}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F68}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI
+    ('\u{1F69}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA
+    ('\u{1F6A}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND VARIA
+    ('\u{1F6B}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND VARIA
+    ('\u{1F6C}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND OXIA
+    ('\u{1F6D}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND OXIA
+    ('\u{1F6E}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI
+    ('\u{1F6F}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI
+    ('\u{1F70}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH VARIA
+    ('\u{1F71}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH OXIA
+    ('\u{1F72}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH VARIA
+    ('\u{1F73}', CharMapAction::SubChar('e')), // SMALL LETTER EPSILON WITH OXIA
+    ('\u{1F74}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH VARIA
+    ('\u{1F75}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH OXIA
+    ('\u{1F76}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH VARIA
+    ('\u{1F77}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH OXIA
+    ('\u{1F78}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH VARIA
+    ('\u{1F79}', CharMapAction::SubChar('o')), // SMALL LETTER OMICRON WITH OXIA
+    ('\u{1F7A}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH VARIA
+    ('\u{1F7B}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH OXIA
+    ('\u{1F7C}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH VARIA
+    ('\u{1F7D}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH OXIA
+    ('\u{1F80}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F81}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F82}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F83}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F84}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F85}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F86}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F87}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F88}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F89}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F8A}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8B}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F8C}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8D}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F8E}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F8F}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F90}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1F91}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1F92}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F93}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1F94}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F95}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1F96}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F97}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1F98}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1F99}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1F9A}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9B}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1F9C}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9D}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1F9E}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1F9F}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FA0}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND YPOGEGRAMMENI
+    ('\u{1FA1}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND YPOGEGRAMMENI
+    ('\u{1FA2}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA3}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND VARIA AND YPOGEGRAMMENI
+    ('\u{1FA4}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA5}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND OXIA AND YPOGEGRAMMENI
+    ('\u{1FA6}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PSILI AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA7}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH DASIA AND PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FA8}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND PROSGEGRAMMENI
+    ('\u{1FA9}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND PROSGEGRAMMENI
+    ('\u{1FAA}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAB}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND VARIA AND PROSGEGRAMMENI
+    ('\u{1FAC}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAD}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND OXIA AND PROSGEGRAMMENI
+    ('\u{1FAE}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PSILI AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FAF}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH DASIA AND PERISPOMENI AND PROSGEGRAMMENI
+    ('\u{1FB0}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH VRACHY
+    ('\u{1FB1}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH MACRON
+    ('\u{1FB2}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FB3}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH YPOGEGRAMMENI
+    ('\u{1FB4}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FB6}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PERISPOMENI
+    ('\u{1FB7}', CharMapAction::SubChar('a')), // SMALL LETTER ALPHA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FB8}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH VRACHY
+    ('\u{1FB9}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH MACRON
+    ('\u{1FBA}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH VARIA
+    ('\u{1FBB}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH OXIA
+    ('\u{1FBC}', CharMapAction::SubChar('A')), // CAPITAL LETTER ALPHA WITH PROSGEGRAMMENI
+    ('\u{1FBD}', CharMapAction::Delete), // KORONIS
+    ('\u{1FBE}', CharMapAction::SubChar('i')), // PROSGEGRAMMENI
+    ('\u{1FBF}', CharMapAction::Delete), // PSILI
+    ('\u{1FC0}', CharMapAction::Delete), // PERISPOMENI
+    ('\u{1FC1}', CharMapAction::Delete), // DIALYTIKA AND PERISPOMENI
+    ('\u{1FC2}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FC3}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH YPOGEGRAMMENI
+    ('\u{1FC4}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FC6}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PERISPOMENI
+    ('\u{1FC7}', CharMapAction::SubChar('i')), // SMALL LETTER ETA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FC8}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH VARIA
+    ('\u{1FC9}', CharMapAction::SubChar('E')), // CAPITAL LETTER EPSILON WITH OXIA
+    ('\u{1FCA}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH VARIA
+    ('\u{1FCB}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH OXIA
+    ('\u{1FCC}', CharMapAction::SubChar('I')), // CAPITAL LETTER ETA WITH PROSGEGRAMMENI
+    ('\u{1FCD}', CharMapAction::Delete), // PSILI AND VARIA
+    ('\u{1FCE}', CharMapAction::Delete), // PSILI AND OXIA
+    ('\u{1FCF}', CharMapAction::Delete), // PSILI AND PERISPOMENI
+    ('\u{1FD0}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH VRACHY
+    ('\u{1FD1}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH MACRON
+    ('\u{1FD2}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DIALYTIKA AND VARIA
+    ('\u{1FD3}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DIALYTIKA AND OXIA
+    ('\u{1FD6}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH PERISPOMENI
+    ('\u{1FD7}', CharMapAction::SubChar('i')), // SMALL LETTER IOTA WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FD8}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH VRACHY
+    ('\u{1FD9}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH MACRON
+    ('\u{1FDA}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH VARIA
+    ('\u{1FDB}', CharMapAction::SubChar('I')), // CAPITAL LETTER IOTA WITH OXIA
+    ('\u{1FDD}', CharMapAction::Delete), // DASIA AND VARIA
+    ('\u{1FDE}', CharMapAction::Delete), // DASIA AND OXIA
+    ('\u{1FDF}', CharMapAction::Delete), // DASIA AND PERISPOMENI
+    ('\u{1FE0}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH VRACHY
+    ('\u{1FE1}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH MACRON
+    ('\u{1FE2}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DIALYTIKA AND VARIA
+    ('\u{1FE3}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DIALYTIKA AND OXIA
+    ('\u{1FE4}', CharMapAction::SubChar('r')), // SMALL LETTER RHO WITH PSILI
+    ('\u{1FE5}', CharMapAction::SubChar('r')), // SMALL LETTER RHO WITH DASIA
+    ('\u{1FE6}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH PERISPOMENI
+    ('\u{1FE7}', CharMapAction::SubChar('y')), // SMALL LETTER UPSILON WITH DIALYTIKA AND PERISPOMENI
+    ('\u{1FE8}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH VRACHY
+    ('\u{1FE9}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH MACRON
+    ('\u{1FEA}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH VARIA
+    ('\u{1FEB}', CharMapAction::SubChar('Y')), // CAPITAL LETTER UPSILON WITH OXIA
+    ('\u{1FEC}', CharMapAction::SubChar('R')), // CAPITAL LETTER RHO WITH DASIA
+    ('\u{1FED}', CharMapAction::Delete), // DIALYTIKA AND VARIA
+    ('\u{1FEE}', CharMapAction::Delete), // DIALYTIKA AND OXIA
+    ('\u{1FEF}', CharMapAction::Delete), // VARIA
+    ('\u{1FF2}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH VARIA AND YPOGEGRAMMENI
+    ('\u{1FF3}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH YPOGEGRAMMENI
+    ('\u{1FF4}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH OXIA AND YPOGEGRAMMENI
+    ('\u{1FF6}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PERISPOMENI
+    ('\u{1FF7}', CharMapAction::SubChar('o')), // SMALL LETTER OMEGA WITH PERISPOMENI AND YPOGEGRAMMENI
+    ('\u{1FF8}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH VARIA
+    ('\u{1FF9}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMICRON WITH OXIA
+    ('\u{1FFA}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH VARIA
+    ('\u{1FFB}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH OXIA
+    ('\u{1FFC}', CharMapAction::SubChar('O')), // CAPITAL LETTER OMEGA WITH PROSGEGRAMMENI
+    ('\u{1FFD}', CharMapAction::Delete), // OXIA
+    ('\u{1FFE}', CharMapAction::Delete), // DASIA
+];
+const _: () = assert_sorted(&ELOT_743);
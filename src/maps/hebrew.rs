@@ -0,0 +1,165 @@
+//! Hebrew niqqud, cantillation, and final form normalization maps.
+//!
+//! Vowel points, cantillation marks, and final letter forms each have a map
+//! of their own. Pointed and cantillated text such as the Hebrew Bible
+//! usually calls for removing the first two (e.g. with
+//! `(&REMOVE_NIQQUD, &REMOVE_CANTILLATION)`), which is also what the default
+//! [`Normalizer`] does. Final forms are distinct letters in ordinary text, so
+//! folding them is left to [`Normalizer::final_forms`].
+//!
+//! Only combining marks are removed. Precomposed letters from the Alphabetic
+//! Presentation Forms block (e.g. U+FB2A שׁ) are left as they are.
+//!
+//! ```rust
+//! use charmap::maps::hebrew::Normalizer;
+//! use charmap::*;
+//!
+//! let normalizer = Normalizer::default();
+//! let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+//!
+//! let normalized: String = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים".map_chars(&mapper).collect();
+//! assert_eq!(normalized, "בראשית ברא אלהים");
+//! ```
+
+#[cfg(feature = "std")]
+use crate::actionmap::Keys;
+use crate::actionmap::{
+    assert_sorted, ActionMap, ActionTable, CharMapAction, Toggles,
+};
+
+/// Map removing niqqud (vowel points, dagesh, meteg, rafe, and shin and sin
+/// dots).
+pub static REMOVE_NIQQUD: [(char, CharMapAction); 18] = [
+    ('\u{05B0}', CharMapAction::Delete), // POINT SHEVA
+    ('\u{05B1}', CharMapAction::Delete), // POINT HATAF SEGOL
+    ('\u{05B2}', CharMapAction::Delete), // POINT HATAF PATAH
+    ('\u{05B3}', CharMapAction::Delete), // POINT HATAF QAMATS
+    ('\u{05B4}', CharMapAction::Delete), // POINT HIRIQ
+    ('\u{05B5}', CharMapAction::Delete), // POINT TSERE
+    ('\u{05B6}', CharMapAction::Delete), // POINT SEGOL
+    ('\u{05B7}', CharMapAction::Delete), // POINT PATAH
+    ('\u{05B8}', CharMapAction::Delete), // POINT QAMATS
+    ('\u{05B9}', CharMapAction::Delete), // POINT HOLAM
+    ('\u{05BA}', CharMapAction::Delete), // POINT HOLAM HASER FOR VAV
+    ('\u{05BB}', CharMapAction::Delete), // POINT QUBUTS
+    ('\u{05BC}', CharMapAction::Delete), // POINT DAGESH OR MAPIQ
+    ('\u{05BD}', CharMapAction::Delete), // POINT METEG
+    ('\u{05BF}', CharMapAction::Delete), // POINT RAFE
+    ('\u{05C1}', CharMapAction::Delete), // POINT SHIN DOT
+    ('\u{05C2}', CharMapAction::Delete), // POINT SIN DOT
+    ('\u{05C7}', CharMapAction::Delete), // POINT QAMATS QATAN
+];
+const _: () = assert_sorted(&REMOVE_NIQQUD);
+
+/// Map removing cantillation marks (te'amim), including the Masora circle and
+/// the upper and lower dots.
+pub static REMOVE_CANTILLATION: [(char, CharMapAction); 33] = [
+    ('\u{0591}', CharMapAction::Delete), // ACCENT ETNAHTA
+    ('\u{0592}', CharMapAction::Delete), // ACCENT SEGOL
+    ('\u{0593}', CharMapAction::Delete), // ACCENT SHALSHELET
+    ('\u{0594}', CharMapAction::Delete), // ACCENT ZAQEF QATAN
+    ('\u{0595}', CharMapAction::Delete), // ACCENT ZAQEF GADOL
+    ('\u{0596}', CharMapAction::Delete), // ACCENT TIPEHA
+    ('\u{0597}', CharMapAction::Delete), // ACCENT REVIA
+    ('\u{0598}', CharMapAction::Delete), // ACCENT ZARQA
+    ('\u{0599}', CharMapAction::Delete), // ACCENT PASHTA
+    ('\u{059A}', CharMapAction::Delete), // ACCENT YETIV
+    ('\u{059B}', CharMapAction::Delete), // ACCENT TEVIR
+    ('\u{059C}', CharMapAction::Delete), // ACCENT GERESH
+    ('\u{059D}', CharMapAction::Delete), // ACCENT GERESH MUQDAM
+    ('\u{059E}', CharMapAction::Delete), // ACCENT GERSHAYIM
+    ('\u{059F}', CharMapAction::Delete), // ACCENT QARNEY PARA
+    ('\u{05A0}', CharMapAction::Delete), // ACCENT TELISHA GEDOLA
+    ('\u{05A1}', CharMapAction::Delete), // ACCENT PAZER
+    ('\u{05A2}', CharMapAction::Delete), // ACCENT ATNAH HAFUKH
+    ('\u{05A3}', CharMapAction::Delete), // ACCENT MUNAH
+    ('\u{05A4}', CharMapAction::Delete), // ACCENT MAHAPAKH
+    ('\u{05A5}', CharMapAction::Delete), // ACCENT MERKHA
+    ('\u{05A6}', CharMapAction::Delete), // ACCENT MERKHA KEFULA
+    ('\u{05A7}', CharMapAction::Delete), // ACCENT DARGA
+    ('\u{05A8}', CharMapAction::Delete), // ACCENT QADMA
+    ('\u{05A9}', CharMapAction::Delete), // ACCENT TELISHA QETANA
+    ('\u{05AA}', CharMapAction::Delete), // ACCENT YERAH BEN YOMO
+    ('\u{05AB}', CharMapAction::Delete), // ACCENT OLE
+    ('\u{05AC}', CharMapAction::Delete), // ACCENT ILUY
+    ('\u{05AD}', CharMapAction::Delete), // ACCENT DEHI
+    ('\u{05AE}', CharMapAction::Delete), // ACCENT ZINOR
+    ('\u{05AF}', CharMapAction::Delete), // MARK MASORA CIRCLE
+    ('\u{05C4}', CharMapAction::Delete), // MARK UPPER DOT
+    ('\u{05C5}', CharMapAction::Delete), // MARK LOWER DOT
+];
+const _: () = assert_sorted(&REMOVE_CANTILLATION);
+
+/// Map normalizing final form letters (ך ם ן ף ץ) to their regular forms (כ מ
+/// נ פ צ).
+pub static NORMALIZE_FINAL_FORMS: [(char, CharMapAction); 5] = [
+    ('\u{05DA}', CharMapAction::SubChar('\u{05DB}')), // LETTER FINAL KAF
+    ('\u{05DD}', CharMapAction::SubChar('\u{05DE}')), // LETTER FINAL MEM
+    ('\u{05DF}', CharMapAction::SubChar('\u{05E0}')), // LETTER FINAL NUN
+    ('\u{05E3}', CharMapAction::SubChar('\u{05E4}')), // LETTER FINAL PE
+    ('\u{05E5}', CharMapAction::SubChar('\u{05E6}')), // LETTER FINAL TSADI
+];
+const _: () = assert_sorted(&NORMALIZE_FINAL_FORMS);
+
+// Tables of the rules applied by `Normalizer`, indexed by the constants below.
+static RULES: [ActionTable; 3] =
+    [&REMOVE_NIQQUD, &REMOVE_CANTILLATION, &NORMALIZE_FINAL_FORMS];
+
+const NIQQUD: usize = 0;
+const CANTILLATION: usize = 1;
+const FINAL_FORMS: usize = 2;
+
+/// An [`ActionMap`] applying a configurable set of Hebrew normalization rules.
+///
+/// The default [`Normalizer`] enables all rules except
+/// [`NORMALIZE_FINAL_FORMS`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Normalizer {
+    rules: Toggles<3>,
+}
+
+impl Normalizer {
+    /// A [`Normalizer`] with all rules enabled.
+    pub const ALL: Normalizer = Normalizer { rules: Toggles::ALL };
+
+    /// A [`Normalizer`] with all rules disabled.
+    pub const NONE: Normalizer = Normalizer { rules: Toggles::NONE };
+
+    /// Toggles [`REMOVE_NIQQUD`].
+    #[inline]
+    pub const fn niqqud(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(NIQQUD, enabled) }
+    }
+
+    /// Toggles [`REMOVE_CANTILLATION`].
+    #[inline]
+    pub const fn cantillation(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(CANTILLATION, enabled) }
+    }
+
+    /// Toggles [`NORMALIZE_FINAL_FORMS`].
+    #[inline]
+    pub const fn final_forms(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(FINAL_FORMS, enabled) }
+    }
+}
+
+impl Default for Normalizer {
+    #[inline]
+    fn default() -> Self {
+        Normalizer::ALL.final_forms(false)
+    }
+}
+
+impl ActionMap for Normalizer {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.rules.map_char(&RULES, c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(self.rules.keys(&RULES))
+    }
+}
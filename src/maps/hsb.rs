@@ -0,0 +1,147 @@
+//! Arabic transliteration maps for the Habash-Soudi-Buckwalter (HSB)
+//! transliteration scheme.
+//!
+//! HSB is a one-to-one transliteration using non-ASCII Latin (and some Greek)
+//! letters. The maps cover the following:
+//!
+//! * All basic letters, e.g. ث `θ`, ذ `ð`, ش `š`, ظ `Ď`, ع `ς`, and غ `γ`.
+//! * Hamza variants: ء `ʼ`, أ `Â`, إ `Ǎ`, آ `Ā`, ؤ `ŵ`, and ئ `ŷ`, as well as
+//!   alef wasla ٱ `Ä`.
+//! * Ta marbuta ة `ħ` and alef maksura ى `ý`.
+//! * Tanween: fathatan `ã`, dammatan `ũ`, and kasratan `ĩ`.
+//! * Other diacritics: fatha `a`, damma `u`, kasra `i`, shadda `~`, sukun `.`,
+//!   and dagger alef `á`.
+//! * Tatweel ـ `_`.
+//! * Extended letters: پ `p`, چ `č`, ڤ `v`, and گ `g`.
+//!
+//! Note that sukun is transliterated as `.`, so [`HSB_TO_ARABIC`] maps all
+//! periods to sukun. Transliterating Arabic to HSB and back is lossless for
+//! text containing no other characters in HSB's inventory.
+//!
+//! ```rust
+//! use charmap::maps::hsb::{ARABIC_TO_HSB, HSB_TO_ARABIC};
+//! use charmap::*;
+//!
+//! let to_hsb = CharMapper::new(&ARABIC_TO_HSB, CharMapAction::Pass);
+//! let from_hsb = CharMapper::new(&HSB_TO_ARABIC, CharMapAction::Pass);
+//!
+//! let hsb: String = "مَدْرَسَة".map_chars(&to_hsb).collect();
+//! assert_eq!(hsb, "mad.rasaħ");
+//!
+//! let arabic: String = hsb.chars().map_chars(&from_hsb).collect();
+//! assert_eq!(arabic, "مَدْرَسَة");
+//! ```
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+
+/// Arabic to Habash-Soudi-Buckwalter transliteration map.
+pub static ARABIC_TO_HSB: [(char, CharMapAction); 51] = [
+    ('\u{0621}', CharMapAction::SubChar('\u{02BC}')), // HAMZA
+    ('\u{0622}', CharMapAction::SubChar('\u{0100}')), // ALEF WITH MADDA ABOVE
+    ('\u{0623}', CharMapAction::SubChar('\u{00C2}')), // ALEF WITH HAMZA ABOVE
+    ('\u{0624}', CharMapAction::SubChar('\u{0175}')), // WAW WITH HAMZA ABOVE
+    ('\u{0625}', CharMapAction::SubChar('\u{01CD}')), // ALEF WITH HAMZA BELOW
+    ('\u{0626}', CharMapAction::SubChar('\u{0177}')), // YEH WITH HAMZA ABOVE
+    ('\u{0627}', CharMapAction::SubChar('A')),        // ALEF
+    ('\u{0628}', CharMapAction::SubChar('b')),        // BEH
+    ('\u{0629}', CharMapAction::SubChar('\u{0127}')), // TEH MARBUTA
+    ('\u{062A}', CharMapAction::SubChar('t')),        // TEH
+    ('\u{062B}', CharMapAction::SubChar('\u{03B8}')), // THEH
+    ('\u{062C}', CharMapAction::SubChar('j')),        // JEEM
+    ('\u{062D}', CharMapAction::SubChar('H')),        // HAH
+    ('\u{062E}', CharMapAction::SubChar('x')),        // KHAH
+    ('\u{062F}', CharMapAction::SubChar('d')),        // DAL
+    ('\u{0630}', CharMapAction::SubChar('\u{00F0}')), // THAL
+    ('\u{0631}', CharMapAction::SubChar('r')),        // REH
+    ('\u{0632}', CharMapAction::SubChar('z')),        // ZAIN
+    ('\u{0633}', CharMapAction::SubChar('s')),        // SEEN
+    ('\u{0634}', CharMapAction::SubChar('\u{0161}')), // SHEEN
+    ('\u{0635}', CharMapAction::SubChar('S')),        // SAD
+    ('\u{0636}', CharMapAction::SubChar('D')),        // DAD
+    ('\u{0637}', CharMapAction::SubChar('T')),        // TAH
+    ('\u{0638}', CharMapAction::SubChar('\u{010E}')), // ZAH
+    ('\u{0639}', CharMapAction::SubChar('\u{03C2}')), // AIN
+    ('\u{063A}', CharMapAction::SubChar('\u{03B3}')), // GHAIN
+    ('\u{0640}', CharMapAction::SubChar('_')),        // TATWEEL
+    ('\u{0641}', CharMapAction::SubChar('f')),        // FEH
+    ('\u{0642}', CharMapAction::SubChar('q')),        // QAF
+    ('\u{0643}', CharMapAction::SubChar('k')),        // KAF
+    ('\u{0644}', CharMapAction::SubChar('l')),        // LAM
+    ('\u{0645}', CharMapAction::SubChar('m')),        // MEEM
+    ('\u{0646}', CharMapAction::SubChar('n')),        // NOON
+    ('\u{0647}', CharMapAction::SubChar('h')),        // HEH
+    ('\u{0648}', CharMapAction::SubChar('w')),        // WAW
+    ('\u{0649}', CharMapAction::SubChar('\u{00FD}')), // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('y')),        // YEH
+    ('\u{064B}', CharMapAction::SubChar('\u{00E3}')), // FATHATAN
+    ('\u{064C}', CharMapAction::SubChar('\u{0169}')), // DAMMATAN
+    ('\u{064D}', CharMapAction::SubChar('\u{0129}')), // KASRATAN
+    ('\u{064E}', CharMapAction::SubChar('a')),        // FATHA
+    ('\u{064F}', CharMapAction::SubChar('u')),        // DAMMA
+    ('\u{0650}', CharMapAction::SubChar('i')),        // KASRA
+    ('\u{0651}', CharMapAction::SubChar('~')),        // SHADDA
+    ('\u{0652}', CharMapAction::SubChar('.')),        // SUKUN
+    ('\u{0670}', CharMapAction::SubChar('\u{00E1}')), // SUPERSCRIPT ALEF
+    ('\u{0671}', CharMapAction::SubChar('\u{00C4}')), // ALEF WASLA
+    ('\u{067E}', CharMapAction::SubChar('p')),        // PEH
+    ('\u{0686}', CharMapAction::SubChar('\u{010D}')), // TCHEH
+    ('\u{06A4}', CharMapAction::SubChar('v')),        // VEH
+    ('\u{06AF}', CharMapAction::SubChar('g')),        // GAF
+];
+const _: () = assert_sorted(&ARABIC_TO_HSB);
+
+/// Habash-Soudi-Buckwalter to Arabic transliteration map.
+pub static HSB_TO_ARABIC: [(char, CharMapAction); 51] = [
+    ('.', CharMapAction::SubChar('\u{0652}')), // SUKUN
+    ('A', CharMapAction::SubChar('\u{0627}')), // ALEF
+    ('D', CharMapAction::SubChar('\u{0636}')), // DAD
+    ('H', CharMapAction::SubChar('\u{062D}')), // HAH
+    ('S', CharMapAction::SubChar('\u{0635}')), // SAD
+    ('T', CharMapAction::SubChar('\u{0637}')), // TAH
+    ('_', CharMapAction::SubChar('\u{0640}')), // TATWEEL
+    ('a', CharMapAction::SubChar('\u{064E}')), // FATHA
+    ('b', CharMapAction::SubChar('\u{0628}')), // BEH
+    ('d', CharMapAction::SubChar('\u{062F}')), // DAL
+    ('f', CharMapAction::SubChar('\u{0641}')), // FEH
+    ('g', CharMapAction::SubChar('\u{06AF}')), // GAF
+    ('h', CharMapAction::SubChar('\u{0647}')), // HEH
+    ('i', CharMapAction::SubChar('\u{0650}')), // KASRA
+    ('j', CharMapAction::SubChar('\u{062C}')), // JEEM
+    ('k', CharMapAction::SubChar('\u{0643}')), // KAF
+    ('l', CharMapAction::SubChar('\u{0644}')), // LAM
+    ('m', CharMapAction::SubChar('\u{0645}')), // MEEM
+    ('n', CharMapAction::SubChar('\u{0646}')), // NOON
+    ('p', CharMapAction::SubChar('\u{067E}')), // PEH
+    ('q', CharMapAction::SubChar('\u{0642}')), // QAF
+    ('r', CharMapAction::SubChar('\u{0631}')), // REH
+    ('s', CharMapAction::SubChar('\u{0633}')), // SEEN
+    ('t', CharMapAction::SubChar('\u{062A}')), // TEH
+    ('u', CharMapAction::SubChar('\u{064F}')), // DAMMA
+    ('v', CharMapAction::SubChar('\u{06A4}')), // VEH
+    ('w', CharMapAction::SubChar('\u{0648}')), // WAW
+    ('x', CharMapAction::SubChar('\u{062E}')), // KHAH
+    ('y', CharMapAction::SubChar('\u{064A}')), // YEH
+    ('z', CharMapAction::SubChar('\u{0632}')), // ZAIN
+    ('~', CharMapAction::SubChar('\u{0651}')), // SHADDA
+    ('\u{00C2}', CharMapAction::SubChar('\u{0623}')), // ALEF WITH HAMZA ABOVE
+    ('\u{00C4}', CharMapAction::SubChar('\u{0671}')), // ALEF WASLA
+    ('\u{00E1}', CharMapAction::SubChar('\u{0670}')), // SUPERSCRIPT ALEF
+    ('\u{00E3}', CharMapAction::SubChar('\u{064B}')), // FATHATAN
+    ('\u{00F0}', CharMapAction::SubChar('\u{0630}')), // THAL
+    ('\u{00FD}', CharMapAction::SubChar('\u{0649}')), // ALEF MAKSURA
+    ('\u{0100}', CharMapAction::SubChar('\u{0622}')), // ALEF WITH MADDA ABOVE
+    ('\u{010D}', CharMapAction::SubChar('\u{0686}')), // TCHEH
+    ('\u{010E}', CharMapAction::SubChar('\u{0638}')), // ZAH
+    ('\u{0127}', CharMapAction::SubChar('\u{0629}')), // TEH MARBUTA
+    ('\u{0129}', CharMapAction::SubChar('\u{064D}')), // KASRATAN
+    ('\u{0161}', CharMapAction::SubChar('\u{0634}')), // SHEEN
+    ('\u{0169}', CharMapAction::SubChar('\u{064C}')), // DAMMATAN
+    ('\u{0175}', CharMapAction::SubChar('\u{0624}')), // WAW WITH HAMZA ABOVE
+    ('\u{0177}', CharMapAction::SubChar('\u{0626}')), // YEH WITH HAMZA ABOVE
+    ('\u{01CD}', CharMapAction::SubChar('\u{0625}')), // ALEF WITH HAMZA BELOW
+    ('\u{02BC}', CharMapAction::SubChar('\u{0621}')), // HAMZA
+    ('\u{03B3}', CharMapAction::SubChar('\u{063A}')), // GHAIN
+    ('\u{03B8}', CharMapAction::SubChar('\u{062B}')), // THEH
+    ('\u{03C2}', CharMapAction::SubChar('\u{0639}')), // AIN
+];
+const _: () = assert_sorted(&HSB_TO_ARABIC);
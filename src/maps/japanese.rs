@@ -0,0 +1,603 @@
+//! Japanese kana and character width conversion maps.
+//!
+//! * [`HIRAGANA_TO_KATAKANA`] and [`KATAKANA_TO_HIRAGANA`] convert between
+//!   the two kana syllabaries (e.g. か `カ`), which is useful for
+//!   kana-insensitive matching. Katakana without a precomposed hiragana
+//!   equivalent (ヷ, ヸ, ヹ, and ヺ) are converted to a hiragana letter followed
+//!   by U+3099 COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK.
+//! * [`FULLWIDTH_TO_ASCII`] and [`ASCII_TO_FULLWIDTH`] convert between
+//!   fullwidth ASCII variants (U+FF01-U+FF5E) and ASCII (e.g. Ａ `A`), as well
+//!   as between U+3000 IDEOGRAPHIC SPACE and space.
+//! * [`HALFWIDTH_TO_FULLWIDTH_KATAKANA`] converts halfwidth katakana and
+//!   punctuation (U+FF61-U+FF9F) to their fullwidth forms (e.g. ｶ `カ`).
+//!
+//! Halfwidth katakana are voiced using separate voiced sound marks (e.g. ｶﾞ
+//! for ガ). [`HALFWIDTH_TO_FULLWIDTH_KATAKANA`] maps these marks to the
+//! combining marks U+3099 and U+309A, which is canonically equivalent to the
+//! precomposed voiced katakana. Use [`widen_katakana_chars`] to compose them
+//! with the preceding katakana instead.
+//!
+//! ```rust
+//! use charmap::maps::japanese::{
+//!     widen_katakana_chars, FULLWIDTH_TO_ASCII, HIRAGANA_TO_KATAKANA,
+//! };
+//! use charmap::*;
+//!
+//! let mapper = CharMapper::new(&FULLWIDTH_TO_ASCII, CharMapAction::Pass);
+//! let narrowed: String = "ＡＢＣ　１２３".map_chars(&mapper).collect();
+//! assert_eq!(narrowed, "ABC 123");
+//!
+//! let mapper = CharMapper::new(&HIRAGANA_TO_KATAKANA, CharMapAction::Pass);
+//! let katakana: String = "ひらがな".map_chars(&mapper).collect();
+//! assert_eq!(katakana, "ヒラガナ");
+//!
+//! let widened: String = widen_katakana_chars("ｶﾞｲﾄﾞﾌﾞｯｸ".chars()).collect();
+//! assert_eq!(widened, "ガイドブック");
+//! ```
+
+use core::iter::Peekable;
+
+use crate::actionmap::{assert_sorted, ActionMap, CharMapAction};
+
+/// Hiragana to katakana conversion map.
+pub static HIRAGANA_TO_KATAKANA: [(char, CharMapAction); 88] = [
+    ('\u{3041}', CharMapAction::SubChar('\u{30A1}')), // HIRAGANA LETTER SMALL A
+    ('\u{3042}', CharMapAction::SubChar('\u{30A2}')), // HIRAGANA LETTER A
+    ('\u{3043}', CharMapAction::SubChar('\u{30A3}')), // HIRAGANA LETTER SMALL I
+    ('\u{3044}', CharMapAction::SubChar('\u{30A4}')), // HIRAGANA LETTER I
+    ('\u{3045}', CharMapAction::SubChar('\u{30A5}')), // HIRAGANA LETTER SMALL U
+    ('\u{3046}', CharMapAction::SubChar('\u{30A6}')), // HIRAGANA LETTER U
+    ('\u{3047}', CharMapAction::SubChar('\u{30A7}')), // HIRAGANA LETTER SMALL E
+    ('\u{3048}', CharMapAction::SubChar('\u{30A8}')), // HIRAGANA LETTER E
+    ('\u{3049}', CharMapAction::SubChar('\u{30A9}')), // HIRAGANA LETTER SMALL O
+    ('\u{304A}', CharMapAction::SubChar('\u{30AA}')), // HIRAGANA LETTER O
+    ('\u{304B}', CharMapAction::SubChar('\u{30AB}')), // HIRAGANA LETTER KA
+    ('\u{304C}', CharMapAction::SubChar('\u{30AC}')), // HIRAGANA LETTER GA
+    ('\u{304D}', CharMapAction::SubChar('\u{30AD}')), // HIRAGANA LETTER KI
+    ('\u{304E}', CharMapAction::SubChar('\u{30AE}')), // HIRAGANA LETTER GI
+    ('\u{304F}', CharMapAction::SubChar('\u{30AF}')), // HIRAGANA LETTER KU
+    ('\u{3050}', CharMapAction::SubChar('\u{30B0}')), // HIRAGANA LETTER GU
+    ('\u{3051}', CharMapAction::SubChar('\u{30B1}')), // HIRAGANA LETTER KE
+    ('\u{3052}', CharMapAction::SubChar('\u{30B2}')), // HIRAGANA LETTER GE
+    ('\u{3053}', CharMapAction::SubChar('\u{30B3}')), // HIRAGANA LETTER KO
+    ('\u{3054}', CharMapAction::SubChar('\u{30B4}')), // HIRAGANA LETTER GO
+    ('\u{3055}', CharMapAction::SubChar('\u{30B5}')), // HIRAGANA LETTER SA
+    ('\u{3056}', CharMapAction::SubChar('\u{30B6}')), // HIRAGANA LETTER ZA
+    ('\u{3057}', CharMapAction::SubChar('\u{30B7}')), // HIRAGANA LETTER SI
+    ('\u{3058}', CharMapAction::SubChar('\u{30B8}')), // HIRAGANA LETTER ZI
+    ('\u{3059}', CharMapAction::SubChar('\u{30B9}')), // HIRAGANA LETTER SU
+    ('\u{305A}', CharMapAction::SubChar('\u{30BA}')), // HIRAGANA LETTER ZU
+    ('\u{305B}', CharMapAction::SubChar('\u{30BB}')), // HIRAGANA LETTER SE
+    ('\u{305C}', CharMapAction::SubChar('\u{30BC}')), // HIRAGANA LETTER ZE
+    ('\u{305D}', CharMapAction::SubChar('\u{30BD}')), // HIRAGANA LETTER SO
+    ('\u{305E}', CharMapAction::SubChar('\u{30BE}')), // HIRAGANA LETTER ZO
+    ('\u{305F}', CharMapAction::SubChar('\u{30BF}')), // HIRAGANA LETTER TA
+    ('\u{3060}', CharMapAction::SubChar('\u{30C0}')), // HIRAGANA LETTER DA
+    ('\u{3061}', CharMapAction::SubChar('\u{30C1}')), // HIRAGANA LETTER TI
+    ('\u{3062}', CharMapAction::SubChar('\u{30C2}')), // HIRAGANA LETTER DI
+    ('\u{3063}', CharMapAction::SubChar('\u{30C3}')), // HIRAGANA LETTER SMALL TU
+    ('\u{3064}', CharMapAction::SubChar('\u{30C4}')), // HIRAGANA LETTER TU
+    ('\u{3065}', CharMapAction::SubChar('\u{30C5}')), // HIRAGANA LETTER DU
+    ('\u{3066}', CharMapAction::SubChar('\u{30C6}')), // HIRAGANA LETTER TE
+    ('\u{3067}', CharMapAction::SubChar('\u{30C7}')), // HIRAGANA LETTER DE
+    ('\u{3068}', CharMapAction::SubChar('\u{30C8}')), // HIRAGANA LETTER TO
+    ('\u{3069}', CharMapAction::SubChar('\u{30C9}')), // HIRAGANA LETTER DO
+    ('\u{306A}', CharMapAction::SubChar('\u{30CA}')), // HIRAGANA LETTER NA
+    ('\u{306B}', CharMapAction::SubChar('\u{30CB}')), // HIRAGANA LETTER NI
+    ('\u{306C}', CharMapAction::SubChar('\u{30CC}')), // HIRAGANA LETTER NU
+    ('\u{306D}', CharMapAction::SubChar('\u{30CD}')), // HIRAGANA LETTER NE
+    ('\u{306E}', CharMapAction::SubChar('\u{30CE}')), // HIRAGANA LETTER NO
+    ('\u{306F}', CharMapAction::SubChar('\u{30CF}')), // HIRAGANA LETTER HA
+    ('\u{3070}', CharMapAction::SubChar('\u{30D0}')), // HIRAGANA LETTER BA
+    ('\u{3071}', CharMapAction::SubChar('\u{30D1}')), // HIRAGANA LETTER PA
+    ('\u{3072}', CharMapAction::SubChar('\u{30D2}')), // HIRAGANA LETTER HI
+    ('\u{3073}', CharMapAction::SubChar('\u{30D3}')), // HIRAGANA LETTER BI
+    ('\u{3074}', CharMapAction::SubChar('\u{30D4}')), // HIRAGANA LETTER PI
+    ('\u{3075}', CharMapAction::SubChar('\u{30D5}')), // HIRAGANA LETTER HU
+    ('\u{3076}', CharMapAction::SubChar('\u{30D6}')), // HIRAGANA LETTER BU
+    ('\u{3077}', CharMapAction::SubChar('\u{30D7}')), // HIRAGANA LETTER PU
+    ('\u{3078}', CharMapAction::SubChar('\u{30D8}')), // HIRAGANA LETTER HE
+    ('\u{3079}', CharMapAction::SubChar('\u{30D9}')), // HIRAGANA LETTER BE
+    ('\u{307A}', CharMapAction::SubChar('\u{30DA}')), // HIRAGANA LETTER PE
+    ('\u{307B}', CharMapAction::SubChar('\u{30DB}')), // HIRAGANA LETTER HO
+    ('\u{307C}', CharMapAction::SubChar('\u{30DC}')), // HIRAGANA LETTER BO
+    ('\u{307D}', CharMapAction::SubChar('\u{30DD}')), // HIRAGANA LETTER PO
+    ('\u{307E}', CharMapAction::SubChar('\u{30DE}')), // HIRAGANA LETTER MA
+    ('\u{307F}', CharMapAction::SubChar('\u{30DF}')), // HIRAGANA LETTER MI
+    ('\u{3080}', CharMapAction::SubChar('\u{30E0}')), // HIRAGANA LETTER MU
+    ('\u{3081}', CharMapAction::SubChar('\u{30E1}')), // HIRAGANA LETTER ME
+    ('\u{3082}', CharMapAction::SubChar('\u{30E2}')), // HIRAGANA LETTER MO
+    ('\u{3083}', CharMapAction::SubChar('\u{30E3}')), // HIRAGANA LETTER SMALL YA
+    ('\u{3084}', CharMapAction::SubChar('\u{30E4}')), // HIRAGANA LETTER YA
+    ('\u{3085}', CharMapAction::SubChar('\u{30E5}')), // HIRAGANA LETTER SMALL YU
+    ('\u{3086}', CharMapAction::SubChar('\u{30E6}')), // HIRAGANA LETTER YU
+    ('\u{3087}', CharMapAction::SubChar('\u{30E7}')), // HIRAGANA LETTER SMALL YO
+    ('\u{3088}', CharMapAction::SubChar('\u{30E8}')), // HIRAGANA LETTER YO
+    ('\u{3089}', CharMapAction::SubChar('\u{30E9}')), // HIRAGANA LETTER RA
+    ('\u{308A}', CharMapAction::SubChar('\u{30EA}')), // HIRAGANA LETTER RI
+    ('\u{308B}', CharMapAction::SubChar('\u{30EB}')), // HIRAGANA LETTER RU
+    ('\u{308C}', CharMapAction::SubChar('\u{30EC}')), // HIRAGANA LETTER RE
+    ('\u{308D}', CharMapAction::SubChar('\u{30ED}')), // HIRAGANA LETTER RO
+    ('\u{308E}', CharMapAction::SubChar('\u{30EE}')), // HIRAGANA LETTER SMALL WA
+    ('\u{308F}', CharMapAction::SubChar('\u{30EF}')), // HIRAGANA LETTER WA
+    ('\u{3090}', CharMapAction::SubChar('\u{30F0}')), // HIRAGANA LETTER WI
+    ('\u{3091}', CharMapAction::SubChar('\u{30F1}')), // HIRAGANA LETTER WE
+    ('\u{3092}', CharMapAction::SubChar('\u{30F2}')), // HIRAGANA LETTER WO
+    ('\u{3093}', CharMapAction::SubChar('\u{30F3}')), // HIRAGANA LETTER N
+    ('\u{3094}', CharMapAction::SubChar('\u{30F4}')), // HIRAGANA LETTER VU
+    ('\u{3095}', CharMapAction::SubChar('\u{30F5}')), // HIRAGANA LETTER SMALL KA
+    ('\u{3096}', CharMapAction::SubChar('\u{30F6}')), // HIRAGANA LETTER SMALL KE
+    ('\u{309D}', CharMapAction::SubChar('\u{30FD}')), // HIRAGANA ITERATION MARK
+    ('\u{309E}', CharMapAction::SubChar('\u{30FE}')), // HIRAGANA VOICED ITERATION MARK
+];
+const _: () = assert_sorted(&HIRAGANA_TO_KATAKANA);
+
+/// Katakana to hiragana conversion map.
+pub static KATAKANA_TO_HIRAGANA: [(char, CharMapAction); 92] = [
+    ('\u{30A1}', CharMapAction::SubChar('\u{3041}')), // KATAKANA LETTER SMALL A
+    ('\u{30A2}', CharMapAction::SubChar('\u{3042}')), // KATAKANA LETTER A
+    ('\u{30A3}', CharMapAction::SubChar('\u{3043}')), // KATAKANA LETTER SMALL I
+    ('\u{30A4}', CharMapAction::SubChar('\u{3044}')), // KATAKANA LETTER I
+    ('\u{30A5}', CharMapAction::SubChar('\u{3045}')), // KATAKANA LETTER SMALL U
+    ('\u{30A6}', CharMapAction::SubChar('\u{3046}')), // KATAKANA LETTER U
+    ('\u{30A7}', CharMapAction::SubChar('\u{3047}')), // KATAKANA LETTER SMALL E
+    ('\u{30A8}', CharMapAction::SubChar('\u{3048}')), // KATAKANA LETTER E
+    ('\u{30A9}', CharMapAction::SubChar('\u{3049}')), // KATAKANA LETTER SMALL O
+    ('\u{30AA}', CharMapAction::SubChar('\u{304A}')), // KATAKANA LETTER O
+    ('\u{30AB}', CharMapAction::SubChar('\u{304B}')), // KATAKANA LETTER KA
+    ('\u{30AC}', CharMapAction::SubChar('\u{304C}')), // KATAKANA LETTER GA
+    ('\u{30AD}', CharMapAction::SubChar('\u{304D}')), // KATAKANA LETTER KI
+    ('\u{30AE}', CharMapAction::SubChar('\u{304E}')), // KATAKANA LETTER GI
+    ('\u{30AF}', CharMapAction::SubChar('\u{304F}')), // KATAKANA LETTER KU
+    ('\u{30B0}', CharMapAction::SubChar('\u{3050}')), // KATAKANA LETTER GU
+    ('\u{30B1}', CharMapAction::SubChar('\u{3051}')), // KATAKANA LETTER KE
+    ('\u{30B2}', CharMapAction::SubChar('\u{3052}')), // KATAKANA LETTER GE
+    ('\u{30B3}', CharMapAction::SubChar('\u{3053}')), // KATAKANA LETTER KO
+    ('\u{30B4}', CharMapAction::SubChar('\u{3054}')), // KATAKANA LETTER GO
+    ('\u{30B5}', CharMapAction::SubChar('\u{3055}')), // KATAKANA LETTER SA
+    ('\u{30B6}', CharMapAction::SubChar('\u{3056}')), // KATAKANA LETTER ZA
+    ('\u{30B7}', CharMapAction::SubChar('\u{3057}')), // KATAKANA LETTER SI
+    ('\u{30B8}', CharMapAction::SubChar('\u{3058}')), // KATAKANA LETTER ZI
+    ('\u{30B9}', CharMapAction::SubChar('\u{3059}')), // KATAKANA LETTER SU
+    ('\u{30BA}', CharMapAction::SubChar('\u{305A}')), // KATAKANA LETTER ZU
+    ('\u{30BB}', CharMapAction::SubChar('\u{305B}')), // KATAKANA LETTER SE
+    ('\u{30BC}', CharMapAction::SubChar('\u{305C}')), // KATAKANA LETTER ZE
+    ('\u{30BD}', CharMapAction::SubChar('\u{305D}')), // KATAKANA LETTER SO
+    ('\u{30BE}', CharMapAction::SubChar('\u{305E}')), // KATAKANA LETTER ZO
+    ('\u{30BF}', CharMapAction::SubChar('\u{305F}')), // KATAKANA LETTER TA
+    ('\u{30C0}', CharMapAction::SubChar('\u{3060}')), // KATAKANA LETTER DA
+    ('\u{30C1}', CharMapAction::SubChar('\u{3061}')), // KATAKANA LETTER TI
+    ('\u{30C2}', CharMapAction::SubChar('\u{3062}')), // KATAKANA LETTER DI
+    ('\u{30C3}', CharMapAction::SubChar('\u{3063}')), // KATAKANA LETTER SMALL TU
+    ('\u{30C4}', CharMapAction::SubChar('\u{3064}')), // KATAKANA LETTER TU
+    ('\u{30C5}', CharMapAction::SubChar('\u{3065}')), // KATAKANA LETTER DU
+    ('\u{30C6}', CharMapAction::SubChar('\u{3066}')), // KATAKANA LETTER TE
+    ('\u{30C7}', CharMapAction::SubChar('\u{3067}')), // KATAKANA LETTER DE
+    ('\u{30C8}', CharMapAction::SubChar('\u{3068}')), // KATAKANA LETTER TO
+    ('\u{30C9}', CharMapAction::SubChar('\u{3069}')), // KATAKANA LETTER DO
+    ('\u{30CA}', CharMapAction::SubChar('\u{306A}')), // KATAKANA LETTER NA
+    ('\u{30CB}', CharMapAction::SubChar('\u{306B}')), // KATAKANA LETTER NI
+    ('\u{30CC}', CharMapAction::SubChar('\u{306C}')), // KATAKANA LETTER NU
+    ('\u{30CD}', CharMapAction::SubChar('\u{306D}')), // KATAKANA LETTER NE
+    ('\u{30CE}', CharMapAction::SubChar('\u{306E}')), // KATAKANA LETTER NO
+    ('\u{30CF}', CharMapAction::SubChar('\u{306F}')), // KATAKANA LETTER HA
+    ('\u{30D0}', CharMapAction::SubChar('\u{3070}')), // KATAKANA LETTER BA
+    ('\u{30D1}', CharMapAction::SubChar('\u{3071}')), // KATAKANA LETTER PA
+    ('\u{30D2}', CharMapAction::SubChar('\u{3072}')), // KATAKANA LETTER HI
+    ('\u{30D3}', CharMapAction::SubChar('\u{3073}')), // KATAKANA LETTER BI
+    ('\u{30D4}', CharMapAction::SubChar('\u{3074}')), // KATAKANA LETTER PI
+    ('\u{30D5}', CharMapAction::SubChar('\u{3075}')), // KATAKANA LETTER HU
+    ('\u{30D6}', CharMapAction::SubChar('\u{3076}')), // KATAKANA LETTER BU
+    ('\u{30D7}', CharMapAction::SubChar('\u{3077}')), // KATAKANA LETTER PU
+    ('\u{30D8}', CharMapAction::SubChar('\u{3078}')), // KATAKANA LETTER HE
+    ('\u{30D9}', CharMapAction::SubChar('\u{3079}')), // KATAKANA LETTER BE
+    ('\u{30DA}', CharMapAction::SubChar('\u{307A}')), // KATAKANA LETTER PE
+    ('\u{30DB}', CharMapAction::SubChar('\u{307B}')), // KATAKANA LETTER HO
+    ('\u{30DC}', CharMapAction::SubChar('\u{307C}')), // KATAKANA LETTER BO
+    ('\u{30DD}', CharMapAction::SubChar('\u{307D}')), // KATAKANA LETTER PO
+    ('\u{30DE}', CharMapAction::SubChar('\u{307E}')), // KATAKANA LETTER MA
+    ('\u{30DF}', CharMapAction::SubChar('\u{307F}')), // KATAKANA LETTER MI
+    ('\u{30E0}', CharMapAction::SubChar('\u{3080}')), // KATAKANA LETTER MU
+    ('\u{30E1}', CharMapAction::SubChar('\u{3081}')), // KATAKANA LETTER ME
+    ('\u{30E2}', CharMapAction::SubChar('\u{3082}')), // KATAKANA LETTER MO
+    ('\u{30E3}', CharMapAction::SubChar('\u{3083}')), // KATAKANA LETTER SMALL YA
+    ('\u{30E4}', CharMapAction::SubChar('\u{3084}')), // KATAKANA LETTER YA
+    ('\u{30E5}', CharMapAction::SubChar('\u{3085}')), // KATAKANA LETTER SMALL YU
+    ('\u{30E6}', CharMapAction::SubChar('\u{3086}')), // KATAKANA LETTER YU
+    ('\u{30E7}', CharMapAction::SubChar('\u{3087}')), // KATAKANA LETTER SMALL YO
+    ('\u{30E8}', CharMapAction::SubChar('\u{3088}')), // KATAKANA LETTER YO
+    ('\u{30E9}', CharMapAction::SubChar('\u{3089}')), // KATAKANA LETTER RA
+    ('\u{30EA}', CharMapAction::SubChar('\u{308A}')), // KATAKANA LETTER RI
+    ('\u{30EB}', CharMapAction::SubChar('\u{308B}')), // KATAKANA LETTER RU
+    ('\u{30EC}', CharMapAction::SubChar('\u{308C}')), // KATAKANA LETTER RE
+    ('\u{30ED}', CharMapAction::SubChar('\u{308D}')), // KATAKANA LETTER RO
+    ('\u{30EE}', CharMapAction::SubChar('\u{308E}')), // KATAKANA LETTER SMALL WA
+    ('\u{30EF}', CharMapAction::SubChar('\u{308F}')), // KATAKANA LETTER WA
+    ('\u{30F0}', CharMapAction::SubChar('\u{3090}')), // KATAKANA LETTER WI
+    ('\u{30F1}', CharMapAction::SubChar('\u{3091}')), // KATAKANA LETTER WE
+    ('\u{30F2}', CharMapAction::SubChar('\u{3092}')), // KATAKANA LETTER WO
+    ('\u{30F3}', CharMapAction::SubChar('\u{3093}')), // KATAKANA LETTER N
+    ('\u{30F4}', CharMapAction::SubChar('\u{3094}')), // KATAKANA LETTER VU
+    ('\u{30F5}', CharMapAction::SubChar('\u{3095}')), // KATAKANA LETTER SMALL KA
+    ('\u{30F6}', CharMapAction::SubChar('\u{3096}')), // KATAKANA LETTER SMALL KE
+    ('\u{30F7}', CharMapAction::SubStr("\u{308F}\u{3099}")), // KATAKANA LETTER VA
+    ('\u{30F8}', CharMapAction::SubStr("\u{3090}\u{3099}")), // KATAKANA LETTER VI
+    ('\u{30F9}', CharMapAction::SubStr("\u{3091}\u{3099}")), // KATAKANA LETTER VE
+    ('\u{30FA}', CharMapAction::SubStr("\u{3092}\u{3099}")), // KATAKANA LETTER VO
+    ('\u{30FD}', CharMapAction::SubChar('\u{309D}')), // KATAKANA ITERATION MARK
+    ('\u{30FE}', CharMapAction::SubChar('\u{309E}')), // KATAKANA VOICED ITERATION MARK
+];
+const _: () = assert_sorted(&KATAKANA_TO_HIRAGANA);
+
+/// Fullwidth ASCII variants to ASCII conversion map.
+pub static FULLWIDTH_TO_ASCII: [(char, CharMapAction); 95] = [
+    ('\u{3000}', CharMapAction::SubChar(' ')), // IDEOGRAPHIC SPACE
+    ('\u{FF01}', CharMapAction::SubChar('!')), // FULLWIDTH EXCLAMATION MARK
+    ('\u{FF02}', CharMapAction::SubChar('"')), // FULLWIDTH QUOTATION MARK
+    ('\u{FF03}', CharMapAction::SubChar('#')), // FULLWIDTH NUMBER SIGN
+    ('\u{FF04}', CharMapAction::SubChar('$')), // FULLWIDTH DOLLAR SIGN
+    ('\u{FF05}', CharMapAction::SubChar('%')), // FULLWIDTH PERCENT SIGN
+    ('\u{FF06}', CharMapAction::SubChar('&')), // FULLWIDTH AMPERSAND
+    ('\u{FF07}', CharMapAction::SubChar('\'')), // FULLWIDTH APOSTROPHE
+    ('\u{FF08}', CharMapAction::SubChar('(')), // FULLWIDTH LEFT PARENTHESIS
+    ('\u{FF09}', CharMapAction::SubChar(')')), // FULLWIDTH RIGHT PARENTHESIS
+    ('\u{FF0A}', CharMapAction::SubChar('*')), // FULLWIDTH ASTERISK
+    ('\u{FF0B}', CharMapAction::SubChar('+')), // FULLWIDTH PLUS SIGN
+    ('\u{FF0C}', CharMapAction::SubChar(',')), // FULLWIDTH COMMA
+    ('\u{FF0D}', CharMapAction::SubChar('-')), // FULLWIDTH HYPHEN-MINUS
+    ('\u{FF0E}', CharMapAction::SubChar('.')), // FULLWIDTH FULL STOP
+    ('\u{FF0F}', CharMapAction::SubChar('/')), // FULLWIDTH SOLIDUS
+    ('\u{FF10}', CharMapAction::SubChar('0')), // FULLWIDTH DIGIT ZERO
+    ('\u{FF11}', CharMapAction::SubChar('1')), // FULLWIDTH DIGIT ONE
+    ('\u{FF12}', CharMapAction::SubChar('2')), // FULLWIDTH DIGIT TWO
+    ('\u{FF13}', CharMapAction::SubChar('3')), // FULLWIDTH DIGIT THREE
+    ('\u{FF14}', CharMapAction::SubChar('4')), // FULLWIDTH DIGIT FOUR
+    ('\u{FF15}', CharMapAction::SubChar('5')), // FULLWIDTH DIGIT FIVE
+    ('\u{FF16}', CharMapAction::SubChar('6')), // FULLWIDTH DIGIT SIX
+    ('\u{FF17}', CharMapAction::SubChar('7')), // FULLWIDTH DIGIT SEVEN
+    ('\u{FF18}', CharMapAction::SubChar('8')), // FULLWIDTH DIGIT EIGHT
+    ('\u{FF19}', CharMapAction::SubChar('9')), // FULLWIDTH DIGIT NINE
+    ('\u{FF1A}', CharMapAction::SubChar(':')), // FULLWIDTH COLON
+    ('\u{FF1B}', CharMapAction::SubChar(';')), // FULLWIDTH SEMICOLON
+    ('\u{FF1C}', CharMapAction::SubChar('<')), // FULLWIDTH LESS-THAN SIGN
+    ('\u{FF1D}', CharMapAction::SubChar('=')), // FULLWIDTH EQUALS SIGN
+    ('\u{FF1E}', CharMapAction::SubChar('>')), // FULLWIDTH GREATER-THAN SIGN
+    ('\u{FF1F}', CharMapAction::SubChar('?')), // FULLWIDTH QUESTION MARK
+    ('\u{FF20}', CharMapAction::SubChar('@')), // FULLWIDTH COMMERCIAL AT
+    ('\u{FF21}', CharMapAction::SubChar('A')), // FULLWIDTH LATIN CAPITAL LETTER A
+    ('\u{FF22}', CharMapAction::SubChar('B')), // FULLWIDTH LATIN CAPITAL LETTER B
+    ('\u{FF23}', CharMapAction::SubChar('C')), // FULLWIDTH LATIN CAPITAL LETTER C
+    ('\u{FF24}', CharMapAction::SubChar('D')), // FULLWIDTH LATIN CAPITAL LETTER D
+    ('\u{FF25}', CharMapAction::SubChar('E')), // FULLWIDTH LATIN CAPITAL LETTER E
+    ('\u{FF26}', CharMapAction::SubChar('F')), // FULLWIDTH LATIN CAPITAL LETTER F
+    ('\u{FF27}', CharMapAction::SubChar('G')), // FULLWIDTH LATIN CAPITAL LETTER G
+    ('\u{FF28}', CharMapAction::SubChar('H')), // FULLWIDTH LATIN CAPITAL LETTER H
+    ('\u{FF29}', CharMapAction::SubChar('I')), // FULLWIDTH LATIN CAPITAL LETTER I
+    ('\u{FF2A}', CharMapAction::SubChar('J')), // FULLWIDTH LATIN CAPITAL LETTER J
+    ('\u{FF2B}', CharMapAction::SubChar('K')), // FULLWIDTH LATIN CAPITAL LETTER K
+    ('\u{FF2C}', CharMapAction::SubChar('L')), // FULLWIDTH LATIN CAPITAL LETTER L
+    ('\u{FF2D}', CharMapAction::SubChar('M')), // FULLWIDTH LATIN CAPITAL LETTER M
+    ('\u{FF2E}', CharMapAction::SubChar('N')), // FULLWIDTH LATIN CAPITAL LETTER N
+    ('\u{FF2F}', CharMapAction::SubChar('O')), // FULLWIDTH LATIN CAPITAL LETTER O
+    ('\u{FF30}', CharMapAction::SubChar('P')), // FULLWIDTH LATIN CAPITAL LETTER P
+    ('\u{FF31}', CharMapAction::SubChar('Q')), // FULLWIDTH LATIN CAPITAL LETTER Q
+    ('\u{FF32}', CharMapAction::SubChar('R')), // FULLWIDTH LATIN CAPITAL LETTER R
+    ('\u{FF33}', CharMapAction::SubChar('S')), // FULLWIDTH LATIN CAPITAL LETTER S
+    ('\u{FF34}', CharMapAction::SubChar('T')), // FULLWIDTH LATIN CAPITAL LETTER T
+    ('\u{FF35}', CharMapAction::SubChar('U')), // FULLWIDTH LATIN CAPITAL LETTER U
+    ('\u{FF36}', CharMapAction::SubChar('V')), // FULLWIDTH LATIN CAPITAL LETTER V
+    ('\u{FF37}', CharMapAction::SubChar('W')), // FULLWIDTH LATIN CAPITAL LETTER W
+    ('\u{FF38}', CharMapAction::SubChar('X')), // FULLWIDTH LATIN CAPITAL LETTER X
+    ('\u{FF39}', CharMapAction::SubChar('Y')), // FULLWIDTH LATIN CAPITAL LETTER Y
+    ('\u{FF3A}', CharMapAction::SubChar('Z')), // FULLWIDTH LATIN CAPITAL LETTER Z
+    ('\u{FF3B}', CharMapAction::SubChar('[')), // FULLWIDTH LEFT SQUARE BRACKET
+    ('\u{FF3C}', CharMapAction::SubChar('\\')), // FULLWIDTH REVERSE SOLIDUS
+    ('\u{FF3D}', CharMapAction::SubChar(']')), // FULLWIDTH RIGHT SQUARE BRACKET
+    ('\u{FF3E}', CharMapAction::SubChar('^')), // FULLWIDTH CIRCUMFLEX ACCENT
+    ('\u{FF3F}', CharMapAction::SubChar('_')), // FULLWIDTH LOW LINE
+    ('\u{FF40}', CharMapAction::SubChar('`')), // FULLWIDTH GRAVE ACCENT
+    ('\u{FF41}', CharMapAction::SubChar('a')), // FULLWIDTH LATIN SMALL LETTER A
+    ('\u{FF42}', CharMapAction::SubChar('b')), // FULLWIDTH LATIN SMALL LETTER B
+    ('\u{FF43}', CharMapAction::SubChar('c')), // FULLWIDTH LATIN SMALL LETTER C
+    ('\u{FF44}', CharMapAction::SubChar('d')), // FULLWIDTH LATIN SMALL LETTER D
+    ('\u{FF45}', CharMapAction::SubChar('e')), // FULLWIDTH LATIN SMALL LETTER E
+    ('\u{FF46}', CharMapAction::SubChar('f')), // FULLWIDTH LATIN SMALL LETTER F
+    ('\u{FF47}', CharMapAction::SubChar('g')), // FULLWIDTH LATIN SMALL LETTER G
+    ('\u{FF48}', CharMapAction::SubChar('h')), // FULLWIDTH LATIN SMALL LETTER H
+    ('\u{FF49}', CharMapAction::SubChar('i')), // FULLWIDTH LATIN SMALL LETTER I
+    ('\u{FF4A}', CharMapAction::SubChar('j')), // FULLWIDTH LATIN SMALL LETTER J
+    ('\u{FF4B}', CharMapAction::SubChar('k')), // FULLWIDTH LATIN SMALL LETTER K
+    ('\u{FF4C}', CharMapAction::SubChar('l')), // FULLWIDTH LATIN SMALL LETTER L
+    ('\u{FF4D}', CharMapAction::SubChar('m')), // FULLWIDTH LATIN SMALL LETTER M
+    ('\u{FF4E}', CharMapAction::SubChar('n')), // FULLWIDTH LATIN SMALL LETTER N
+    ('\u{FF4F}', CharMapAction::SubChar('o')), // FULLWIDTH LATIN SMALL LETTER O
+    ('\u{FF50}', CharMapAction::SubChar('p')), // FULLWIDTH LATIN SMALL LETTER P
+    ('\u{FF51}', CharMapAction::SubChar('q')), // FULLWIDTH LATIN SMALL LETTER Q
+    ('\u{FF52}', CharMapAction::SubChar('r')), // FULLWIDTH LATIN SMALL LETTER R
+    ('\u{FF53}', CharMapAction::SubChar('s')), // FULLWIDTH LATIN SMALL LETTER S
+    ('\u{FF54}', CharMapAction::SubChar('t')), // FULLWIDTH LATIN SMALL LETTER T
+    ('\u{FF55}', CharMapAction::SubChar('u')), // FULLWIDTH LATIN SMALL LETTER U
+    ('\u{FF56}', CharMapAction::SubChar('v')), // FULLWIDTH LATIN SMALL LETTER V
+    ('\u{FF57}', CharMapAction::SubChar('w')), // FULLWIDTH LATIN SMALL LETTER W
+    ('\u{FF58}', CharMapAction::SubChar('x')), // FULLWIDTH LATIN SMALL LETTER X
+    ('\u{FF59}', CharMapAction::SubChar('y')), // FULLWIDTH LATIN SMALL LETTER Y
+    ('\u{FF5A}', CharMapAction::SubChar('z')), // FULLWIDTH LATIN SMALL LETTER Z
+    ('\u{FF5B}', CharMapAction::SubChar('{')), // FULLWIDTH LEFT CURLY BRACKET
+    ('\u{FF5C}', CharMapAction::SubChar('|')), // FULLWIDTH VERTICAL LINE
+    ('\u{FF5D}', CharMapAction::SubChar('}')), // FULLWIDTH RIGHT CURLY BRACKET
+    ('\u{FF5E}', CharMapAction::SubChar('~')), // FULLWIDTH TILDE
+];
+const _: () = assert_sorted(&FULLWIDTH_TO_ASCII);
+
+/// ASCII to fullwidth ASCII variants conversion map.
+pub static ASCII_TO_FULLWIDTH: [(char, CharMapAction); 95] = [
+    (' ', CharMapAction::SubChar('\u{3000}')), // SPACE
+    ('!', CharMapAction::SubChar('\u{FF01}')), // EXCLAMATION MARK
+    ('"', CharMapAction::SubChar('\u{FF02}')), // QUOTATION MARK
+    ('#', CharMapAction::SubChar('\u{FF03}')), // NUMBER SIGN
+    ('$', CharMapAction::SubChar('\u{FF04}')), // DOLLAR SIGN
+    ('%', CharMapAction::SubChar('\u{FF05}')), // PERCENT SIGN
+    ('&', CharMapAction::SubChar('\u{FF06}')), // AMPERSAND
+    ('\'', CharMapAction::SubChar('\u{FF07}')), // APOSTROPHE
+    ('(', CharMapAction::SubChar('\u{FF08}')), // LEFT PARENTHESIS
+    (')', CharMapAction::SubChar('\u{FF09}')), // RIGHT PARENTHESIS
+    ('*', CharMapAction::SubChar('\u{FF0A}')), // ASTERISK
+    ('+', CharMapAction::SubChar('\u{FF0B}')), // PLUS SIGN
+    (',', CharMapAction::SubChar('\u{FF0C}')), // COMMA
+    ('-', CharMapAction::SubChar('\u{FF0D}')), // HYPHEN-MINUS
+    ('.', CharMapAction::SubChar('\u{FF0E}')), // FULL STOP
+    ('/', CharMapAction::SubChar('\u{FF0F}')), // SOLIDUS
+    ('0', CharMapAction::SubChar('\u{FF10}')), // DIGIT ZERO
+    ('1', CharMapAction::SubChar('\u{FF11}')), // DIGIT ONE
+    ('2', CharMapAction::SubChar('\u{FF12}')), // DIGIT TWO
+    ('3', CharMapAction::SubChar('\u{FF13}')), // DIGIT THREE
+    ('4', CharMapAction::SubChar('\u{FF14}')), // DIGIT FOUR
+    ('5', CharMapAction::SubChar('\u{FF15}')), // DIGIT FIVE
+    ('6', CharMapAction::SubChar('\u{FF16}')), // DIGIT SIX
+    ('7', CharMapAction::SubChar('\u{FF17}')), // DIGIT SEVEN
+    ('8', CharMapAction::SubChar('\u{FF18}')), // DIGIT EIGHT
+    ('9', CharMapAction::SubChar('\u{FF19}')), // DIGIT NINE
+    (':', CharMapAction::SubChar('\u{FF1A}')), // COLON
+    (';', CharMapAction::SubChar('\u{FF1B}')), // SEMICOLON
+    ('<', CharMapAction::SubChar('\u{FF1C}')), // LESS-THAN SIGN
+    ('=', CharMapAction::SubChar('\u{FF1D}')), // EQUALS SIGN
+    ('>', CharMapAction::SubChar('\u{FF1E}')), // GREATER-THAN SIGN
+    ('?', CharMapAction::SubChar('\u{FF1F}')), // QUESTION MARK
+    ('@', CharMapAction::SubChar('\u{FF20}')), // COMMERCIAL AT
+    ('A', CharMapAction::SubChar('\u{FF21}')), // LATIN CAPITAL LETTER A
+    ('B', CharMapAction::SubChar('\u{FF22}')), // LATIN CAPITAL LETTER B
+    ('C', CharMapAction::SubChar('\u{FF23}')), // LATIN CAPITAL LETTER C
+    ('D', CharMapAction::SubChar('\u{FF24}')), // LATIN CAPITAL LETTER D
+    ('E', CharMapAction::SubChar('\u{FF25}')), // LATIN CAPITAL LETTER E
+    ('F', CharMapAction::SubChar('\u{FF26}')), // LATIN CAPITAL LETTER F
+    ('G', CharMapAction::SubChar('\u{FF27}')), // LATIN CAPITAL LETTER G
+    ('H', CharMapAction::SubChar('\u{FF28}')), // LATIN CAPITAL LETTER H
+    ('I', CharMapAction::SubChar('\u{FF29}')), // LATIN CAPITAL LETTER I
+    ('J', CharMapAction::SubChar('\u{FF2A}')), // LATIN CAPITAL LETTER J
+    ('K', CharMapAction::SubChar('\u{FF2B}')), // LATIN CAPITAL LETTER K
+    ('L', CharMapAction::SubChar('\u{FF2C}')), // LATIN CAPITAL LETTER L
+    ('M', CharMapAction::SubChar('\u{FF2D}')), // LATIN CAPITAL LETTER M
+    ('N', CharMapAction::SubChar('\u{FF2E}')), // LATIN CAPITAL LETTER N
+    ('O', CharMapAction::SubChar('\u{FF2F}')), // LATIN CAPITAL LETTER O
+    ('P', CharMapAction::SubChar('\u{FF30}')), // LATIN CAPITAL LETTER P
+    ('Q', CharMapAction::SubChar('\u{FF31}')), // LATIN CAPITAL LETTER Q
+    ('R', CharMapAction::SubChar('\u{FF32}')), // LATIN CAPITAL LETTER R
+    ('S', CharMapAction::SubChar('\u{FF33}')), // LATIN CAPITAL LETTER S
+    ('T', CharMapAction::SubChar('\u{FF34}')), // LATIN CAPITAL LETTER T
+    ('U', CharMapAction::SubChar('\u{FF35}')), // LATIN CAPITAL LETTER U
+    ('V', CharMapAction::SubChar('\u{FF36}')), // LATIN CAPITAL LETTER V
+    ('W', CharMapAction::SubChar('\u{FF37}')), // LATIN CAPITAL LETTER W
+    ('X', CharMapAction::SubChar('\u{FF38}')), // LATIN CAPITAL LETTER X
+    ('Y', CharMapAction::SubChar('\u{FF39}')), // LATIN CAPITAL LETTER Y
+    ('Z', CharMapAction::SubChar('\u{FF3A}')), // LATIN CAPITAL LETTER Z
+    ('[', CharMapAction::SubChar('\u{FF3B}')), // LEFT SQUARE BRACKET
+    ('\\', CharMapAction::SubChar('\u{FF3C}')), // REVERSE SOLIDUS
+    (']', CharMapAction::SubChar('\u{FF3D}')), // RIGHT SQUARE BRACKET
+    ('^', CharMapAction::SubChar('\u{FF3E}')), // CIRCUMFLEX ACCENT
+    ('_', CharMapAction::SubChar('\u{FF3F}')), // LOW LINE
+    ('`', CharMapAction::SubChar('\u{FF40}')), // GRAVE ACCENT
+    ('a', CharMapAction::SubChar('\u{FF41}')), // LATIN SMALL LETTER A
+    ('b', CharMapAction::SubChar('\u{FF42}')), // LATIN SMALL LETTER B
+    ('c', CharMapAction::SubChar('\u{FF43}')), // LATIN SMALL LETTER C
+    ('d', CharMapAction::SubChar('\u{FF44}')), // LATIN SMALL LETTER D
+    ('e', CharMapAction::SubChar('\u{FF45}')), // LATIN SMALL LETTER E
+    ('f', CharMapAction::SubChar('\u{FF46}')), // LATIN SMALL LETTER F
+    ('g', CharMapAction::SubChar('\u{FF47}')), // LATIN SMALL LETTER G
+    ('h', CharMapAction::SubChar('\u{FF48}')), // LATIN SMALL LETTER H
+    ('i', CharMapAction::SubChar('\u{FF49}')), // LATIN SMALL LETTER I
+    ('j', CharMapAction::SubChar('\u{FF4A}')), // LATIN SMALL LETTER J
+    ('k', CharMapAction::SubChar('\u{FF4B}')), // LATIN SMALL LETTER K
+    ('l', CharMapAction::SubChar('\u{FF4C}')), // LATIN SMALL LETTER L
+    ('m', CharMapAction::SubChar('\u{FF4D}')), // LATIN SMALL LETTER M
+    ('n', CharMapAction::SubChar('\u{FF4E}')), // LATIN SMALL LETTER N
+    ('o', CharMapAction::SubChar('\u{FF4F}')), // LATIN SMALL LETTER O
+    ('p', CharMapAction::SubChar('\u{FF50}')), // LATIN SMALL LETTER P
+    ('q', CharMapAction::SubChar('\u{FF51}')), // LATIN SMALL LETTER Q
+    ('r', CharMapAction::SubChar('\u{FF52}')), // LATIN SMALL LETTER R
+    ('s', CharMapAction::SubChar('\u{FF53}')), // LATIN SMALL LETTER S
+    ('t', CharMapAction::SubChar('\u{FF54}')), // LATIN SMALL LETTER T
+    ('u', CharMapAction::SubChar('\u{FF55}')), // LATIN SMALL LETTER U
+    ('v', CharMapAction::SubChar('\u{FF56}')), // LATIN SMALL LETTER V
+    ('w', CharMapAction::SubChar('\u{FF57}')), // LATIN SMALL LETTER W
+    ('x', CharMapAction::SubChar('\u{FF58}')), // LATIN SMALL LETTER X
+    ('y', CharMapAction::SubChar('\u{FF59}')), // LATIN SMALL LETTER Y
+    ('z', CharMapAction::SubChar('\u{FF5A}')), // LATIN SMALL LETTER Z
+    ('{', CharMapAction::SubChar('\u{FF5B}')), // LEFT CURLY BRACKET
+    ('|', CharMapAction::SubChar('\u{FF5C}')), // VERTICAL LINE
+    ('}', CharMapAction::SubChar('\u{FF5D}')), // RIGHT CURLY BRACKET
+    ('~', CharMapAction::SubChar('\u{FF5E}')), // TILDE
+];
+const _: () = assert_sorted(&ASCII_TO_FULLWIDTH);
+
+/// Halfwidth katakana to fullwidth katakana conversion map.
+pub static HALFWIDTH_TO_FULLWIDTH_KATAKANA: [(char, CharMapAction); 63] = [
+    ('\u{FF61}', CharMapAction::SubChar('\u{3002}')), // HALFWIDTH IDEOGRAPHIC FULL STOP
+    ('\u{FF62}', CharMapAction::SubChar('\u{300C}')), // HALFWIDTH LEFT CORNER BRACKET
+    ('\u{FF63}', CharMapAction::SubChar('\u{300D}')), // HALFWIDTH RIGHT CORNER BRACKET
+    ('\u{FF64}', CharMapAction::SubChar('\u{3001}')), // HALFWIDTH IDEOGRAPHIC COMMA
+    ('\u{FF65}', CharMapAction::SubChar('\u{30FB}')), // HALFWIDTH KATAKANA MIDDLE DOT
+    ('\u{FF66}', CharMapAction::SubChar('\u{30F2}')), // HALFWIDTH KATAKANA LETTER WO
+    ('\u{FF67}', CharMapAction::SubChar('\u{30A1}')), // HALFWIDTH KATAKANA LETTER SMALL A
+    ('\u{FF68}', CharMapAction::SubChar('\u{30A3}')), // HALFWIDTH KATAKANA LETTER SMALL I
+    ('\u{FF69}', CharMapAction::SubChar('\u{30A5}')), // HALFWIDTH KATAKANA LETTER SMALL U
+    ('\u{FF6A}', CharMapAction::SubChar('\u{30A7}')), // HALFWIDTH KATAKANA LETTER SMALL E
+    ('\u{FF6B}', CharMapAction::SubChar('\u{30A9}')), // HALFWIDTH KATAKANA LETTER SMALL O
+    ('\u{FF6C}', CharMapAction::SubChar('\u{30E3}')), // HALFWIDTH KATAKANA LETTER SMALL YA
+    ('\u{FF6D}', CharMapAction::SubChar('\u{30E5}')), // HALFWIDTH KATAKANA LETTER SMALL YU
+    ('\u{FF6E}', CharMapAction::SubChar('\u{30E7}')), // HALFWIDTH KATAKANA LETTER SMALL YO
+    ('\u{FF6F}', CharMapAction::SubChar('\u{30C3}')), // HALFWIDTH KATAKANA LETTER SMALL TU
+    ('\u{FF70}', CharMapAction::SubChar('\u{30FC}')), // HALFWIDTH KATAKANA-HIRAGANA PROLONGED SOUND MARK
+    ('\u{FF71}', CharMapAction::SubChar('\u{30A2}')), // HALFWIDTH KATAKANA LETTER A
+    ('\u{FF72}', CharMapAction::SubChar('\u{30A4}')), // HALFWIDTH KATAKANA LETTER I
+    ('\u{FF73}', CharMapAction::SubChar('\u{30A6}')), // HALFWIDTH KATAKANA LETTER U
+    ('\u{FF74}', CharMapAction::SubChar('\u{30A8}')), // HALFWIDTH KATAKANA LETTER E
+    ('\u{FF75}', CharMapAction::SubChar('\u{30AA}')), // HALFWIDTH KATAKANA LETTER O
+    ('\u{FF76}', CharMapAction::SubChar('\u{30AB}')), // HALFWIDTH KATAKANA LETTER KA
+    ('\u{FF77}', CharMapAction::SubChar('\u{30AD}')), // HALFWIDTH KATAKANA LETTER KI
+    ('\u{FF78}', CharMapAction::SubChar('\u{30AF}')), // HALFWIDTH KATAKANA LETTER KU
+    ('\u{FF79}', CharMapAction::SubChar('\u{30B1}')), // HALFWIDTH KATAKANA LETTER KE
+    ('\u{FF7A}', CharMapAction::SubChar('\u{30B3}')), // HALFWIDTH KATAKANA LETTER KO
+    ('\u{FF7B}', CharMapAction::SubChar('\u{30B5}')), // HALFWIDTH KATAKANA LETTER SA
+    ('\u{FF7C}', CharMapAction::SubChar('\u{30B7}')), // HALFWIDTH KATAKANA LETTER SI
+    ('\u{FF7D}', CharMapAction::SubChar('\u{30B9}')), // HALFWIDTH KATAKANA LETTER SU
+    ('\u{FF7E}', CharMapAction::SubChar('\u{30BB}')), // HALFWIDTH KATAKANA LETTER SE
+    ('\u{FF7F}', CharMapAction::SubChar('\u{30BD}')), // HALFWIDTH KATAKANA LETTER SO
+    ('\u{FF80}', CharMapAction::SubChar('\u{30BF}')), // HALFWIDTH KATAKANA LETTER TA
+    ('\u{FF81}', CharMapAction::SubChar('\u{30C1}')), // HALFWIDTH KATAKANA LETTER TI
+    ('\u{FF82}', CharMapAction::SubChar('\u{30C4}')), // HALFWIDTH KATAKANA LETTER TU
+    ('\u{FF83}', CharMapAction::SubChar('\u{30C6}')), // HALFWIDTH KATAKANA LETTER TE
+    ('\u{FF84}', CharMapAction::SubChar('\u{30C8}')), // HALFWIDTH KATAKANA LETTER TO
+    ('\u{FF85}', CharMapAction::SubChar('\u{30CA}')), // HALFWIDTH KATAKANA LETTER NA
+    ('\u{FF86}', CharMapAction::SubChar('\u{30CB}')), // HALFWIDTH KATAKANA LETTER NI
+    ('\u{FF87}', CharMapAction::SubChar('\u{30CC}')), // HALFWIDTH KATAKANA LETTER NU
+    ('\u{FF88}', CharMapAction::SubChar('\u{30CD}')), // HALFWIDTH KATAKANA LETTER NE
+    ('\u{FF89}', CharMapAction::SubChar('\u{30CE}')), // HALFWIDTH KATAKANA LETTER NO
+    ('\u{FF8A}', CharMapAction::SubChar('\u{30CF}')), // HALFWIDTH KATAKANA LETTER HA
+    ('\u{FF8B}', CharMapAction::SubChar('\u{30D2}')), // HALFWIDTH KATAKANA LETTER HI
+    ('\u{FF8C}', CharMapAction::SubChar('\u{30D5}')), // HALFWIDTH KATAKANA LETTER HU
+    ('\u{FF8D}', CharMapAction::SubChar('\u{30D8}')), // HALFWIDTH KATAKANA LETTER HE
+    ('\u{FF8E}', CharMapAction::SubChar('\u{30DB}')), // HALFWIDTH KATAKANA LETTER HO
+    ('\u{FF8F}', CharMapAction::SubChar('\u{30DE}')), // HALFWIDTH KATAKANA LETTER MA
+    ('\u{FF90}', CharMapAction::SubChar('\u{30DF}')), // HALFWIDTH KATAKANA LETTER MI
+    ('\u{FF91}', CharMapAction::SubChar('\u{30E0}')), // HALFWIDTH KATAKANA LETTER MU
+    ('\u{FF92}', CharMapAction::SubChar('\u{30E1}')), // HALFWIDTH KATAKANA LETTER ME
+    ('\u{FF93}', CharMapAction::SubChar('\u{30E2}')), // HALFWIDTH KATAKANA LETTER MO
+    ('\u{FF94}', CharMapAction::SubChar('\u{30E4}')), // HALFWIDTH KATAKANA LETTER YA
+    ('\u{FF95}', CharMapAction::SubChar('\u{30E6}')), // HALFWIDTH KATAKANA LETTER YU
+    ('\u{FF96}', CharMapAction::SubChar('\u{30E8}')), // HALFWIDTH KATAKANA LETTER YO
+    ('\u{FF97}', CharMapAction::SubChar('\u{30E9}')), // HALFWIDTH KATAKANA LETTER RA
+    ('\u{FF98}', CharMapAction::SubChar('\u{30EA}')), // HALFWIDTH KATAKANA LETTER RI
+    ('\u{FF99}', CharMapAction::SubChar('\u{30EB}')), // HALFWIDTH KATAKANA LETTER RU
+    ('\u{FF9A}', CharMapAction::SubChar('\u{30EC}')), // HALFWIDTH KATAKANA LETTER RE
+    ('\u{FF9B}', CharMapAction::SubChar('\u{30ED}')), // HALFWIDTH KATAKANA LETTER RO
+    ('\u{FF9C}', CharMapAction::SubChar('\u{30EF}')), // HALFWIDTH KATAKANA LETTER WA
+    ('\u{FF9D}', CharMapAction::SubChar('\u{30F3}')), // HALFWIDTH KATAKANA LETTER N
+    ('\u{FF9E}', CharMapAction::SubChar('\u{3099}')), // HALFWIDTH KATAKANA VOICED SOUND MARK
+    ('\u{FF9F}', CharMapAction::SubChar('\u{309A}')), // HALFWIDTH KATAKANA SEMI-VOICED SOUND MARK
+];
+const _: () = assert_sorted(&HALFWIDTH_TO_FULLWIDTH_KATAKANA);
+
+// Katakana that can be voiced with U+3099 COMBINING KATAKANA-HIRAGANA VOICED
+// SOUND MARK and their voiced forms.
+const VOICED: [(char, char); 26] = [
+    ('\u{30A6}', '\u{30F4}'), // U
+    ('\u{30AB}', '\u{30AC}'), // KA
+    ('\u{30AD}', '\u{30AE}'), // KI
+    ('\u{30AF}', '\u{30B0}'), // KU
+    ('\u{30B1}', '\u{30B2}'), // KE
+    ('\u{30B3}', '\u{30B4}'), // KO
+    ('\u{30B5}', '\u{30B6}'), // SA
+    ('\u{30B7}', '\u{30B8}'), // SI
+    ('\u{30B9}', '\u{30BA}'), // SU
+    ('\u{30BB}', '\u{30BC}'), // SE
+    ('\u{30BD}', '\u{30BE}'), // SO
+    ('\u{30BF}', '\u{30C0}'), // TA
+    ('\u{30C1}', '\u{30C2}'), // TI
+    ('\u{30C4}', '\u{30C5}'), // TU
+    ('\u{30C6}', '\u{30C7}'), // TE
+    ('\u{30C8}', '\u{30C9}'), // TO
+    ('\u{30CF}', '\u{30D0}'), // HA
+    ('\u{30D2}', '\u{30D3}'), // HI
+    ('\u{30D5}', '\u{30D6}'), // HU
+    ('\u{30D8}', '\u{30D9}'), // HE
+    ('\u{30DB}', '\u{30DC}'), // HO
+    ('\u{30EF}', '\u{30F7}'), // WA
+    ('\u{30F0}', '\u{30F8}'), // WI
+    ('\u{30F1}', '\u{30F9}'), // WE
+    ('\u{30F2}', '\u{30FA}'), // WO
+    ('\u{30FD}', '\u{30FE}'), // ITERATION MARK
+];
+
+// Katakana that can be voiced with U+309A COMBINING KATAKANA-HIRAGANA
+// SEMI-VOICED SOUND MARK and their semi-voiced forms.
+const SEMI_VOICED: [(char, char); 5] = [
+    ('\u{30CF}', '\u{30D1}'), // HA
+    ('\u{30D2}', '\u{30D4}'), // HI
+    ('\u{30D5}', '\u{30D7}'), // HU
+    ('\u{30D8}', '\u{30DA}'), // HE
+    ('\u{30DB}', '\u{30DD}'), // HO
+];
+
+/// Returns an iterator that converts halfwidth katakana from a given
+/// character iterator to fullwidth katakana like
+/// [`HALFWIDTH_TO_FULLWIDTH_KATAKANA`], while composing halfwidth voiced
+/// sound marks with the preceding katakana (e.g. ｶﾞ `ガ` and ﾊﾟ `パ`).
+///
+/// Voiced sound marks that cannot be composed with the preceding character
+/// are converted to combining marks. All other characters are output as they
+/// are.
+#[inline]
+pub fn widen_katakana_chars<I>(text_chars: I) -> WidenedKatakanaChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    WidenedKatakanaChars { text_chars: text_chars.peekable() }
+}
+
+/// Iterator returned by [`widen_katakana_chars`].
+#[derive(Clone)]
+pub struct WidenedKatakanaChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    text_chars: Peekable<I>,
+}
+
+impl<I> Iterator for WidenedKatakanaChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = widen(self.text_chars.next()?);
+        let marks: &[(char, char)] = match self.text_chars.peek() {
+            Some('\u{FF9E}') => &VOICED,
+            Some('\u{FF9F}') => &SEMI_VOICED,
+            _ => return Some(c),
+        };
+
+        match marks.binary_search_by_key(&c, |(base, _)| *base) {
+            Ok(i) => {
+                self.text_chars.next();
+                Some(marks[i].1)
+            }
+            Err(_) => Some(c),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.text_chars.size_hint();
+        (lower.div_ceil(2), upper)
+    }
+}
+
+#[inline]
+fn widen(c: char) -> char {
+    match HALFWIDTH_TO_FULLWIDTH_KATAKANA.map_char(c) {
+        Some(CharMapAction::SubChar(d)) => d,
+        _ => c,
+    }
+}
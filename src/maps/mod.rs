@@ -0,0 +1,41 @@
+//! Ready-made action maps.
+//!
+//! Maps are provided as sorted tables of `(char, CharMapAction)` pairs (or as
+//! configurable [`ActionMap`](crate::ActionMap)s built on top of them) that
+//! can be used directly with [`CharMapper::new`](crate::CharMapper::new).
+//! Each module is enabled by the feature of the same name.
+
+#[cfg(feature = "arabic")]
+pub mod arabic;
+#[cfg(feature = "arabic_presentation")]
+pub mod arabic_presentation;
+#[cfg(feature = "ascii_folding")]
+pub mod ascii_folding;
+#[cfg(feature = "buckwalter")]
+pub mod buckwalter;
+#[cfg(feature = "case")]
+pub mod case;
+#[cfg(feature = "confusables")]
+pub mod confusables;
+#[cfg(feature = "cyrillic")]
+pub mod cyrillic;
+#[cfg(feature = "devanagari")]
+pub mod devanagari;
+#[cfg(feature = "diacritics")]
+pub mod diacritics;
+#[cfg(feature = "greek")]
+pub mod greek;
+#[cfg(feature = "hebrew")]
+pub mod hebrew;
+#[cfg(feature = "hsb")]
+pub mod hsb;
+#[cfg(feature = "japanese")]
+pub mod japanese;
+#[cfg(feature = "perso_arabic")]
+pub mod perso_arabic;
+#[cfg(feature = "punctuation")]
+pub mod punctuation;
+#[cfg(any(feature = "cyrillic", feature = "greek"))]
+mod romanize;
+#[cfg(feature = "sanitize")]
+pub mod sanitize;
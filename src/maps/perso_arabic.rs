@@ -0,0 +1,146 @@
+//! Persian, Urdu, and Kurdish letter and digit unification maps.
+//!
+//! The `*_TO_ARABIC` maps unify language-specific letter variants (e.g. Farsi
+//! yeh ی and keheh ک) with their Arabic counterparts (ي and ك), while the
+//! `ARABIC_TO_*` maps convert Arabic letters to the variants used by each
+//! language. Language-specific letters with no Arabic counterpart (e.g. پ, ڕ,
+//! or ۆ) are not mapped and are thus preserved.
+//!
+//! Digits are handled by separate maps converting between Arabic-Indic digits
+//! (٠-٩), Extended Arabic-Indic digits (۰-۹, used by Persian and Urdu), and
+//! ASCII digits.
+//!
+//! ```rust
+//! use charmap::maps::perso_arabic::{
+//!     EXTENDED_ARABIC_INDIC_DIGITS_TO_ASCII, PERSIAN_TO_ARABIC,
+//! };
+//! use charmap::*;
+//!
+//! let actions = (&PERSIAN_TO_ARABIC, &EXTENDED_ARABIC_INDIC_DIGITS_TO_ASCII);
+//! let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+//!
+//! let unified: String = "کتاب ۱۴۰۲ یک".map_chars(&mapper).collect();
+//! assert_eq!(unified, "كتاب 1402 يك");
+//! ```
+
+use crate::actionmap::{assert_sorted, CharMapAction};
+
+/// Map unifying Persian letter variants with their Arabic counterparts.
+pub static PERSIAN_TO_ARABIC: [(char, CharMapAction); 3] = [
+    ('\u{06A9}', CharMapAction::SubChar('\u{0643}')), // KEHEH
+    ('\u{06C0}', CharMapAction::SubStr("\u{0647}\u{0654}")), // HEH WITH YEH ABOVE
+    ('\u{06CC}', CharMapAction::SubChar('\u{064A}')),        // FARSI YEH
+];
+const _: () = assert_sorted(&PERSIAN_TO_ARABIC);
+
+/// Map converting Arabic letters to their Persian variants.
+pub static ARABIC_TO_PERSIAN: [(char, CharMapAction); 3] = [
+    ('\u{0643}', CharMapAction::SubChar('\u{06A9}')), // KAF
+    ('\u{0649}', CharMapAction::SubChar('\u{06CC}')), // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('\u{06CC}')), // YEH
+];
+const _: () = assert_sorted(&ARABIC_TO_PERSIAN);
+
+/// Map unifying Urdu letter variants with their Arabic counterparts.
+pub static URDU_TO_ARABIC: [(char, CharMapAction); 8] = [
+    ('\u{06A9}', CharMapAction::SubChar('\u{0643}')), // KEHEH
+    ('\u{06BE}', CharMapAction::SubChar('\u{0647}')), // HEH DOACHASHMEE
+    ('\u{06C1}', CharMapAction::SubChar('\u{0647}')), // HEH GOAL
+    ('\u{06C2}', CharMapAction::SubStr("\u{0647}\u{0654}")), // HEH GOAL WITH HAMZA ABOVE
+    ('\u{06C3}', CharMapAction::SubChar('\u{0629}')), // TEH MARBUTA GOAL
+    ('\u{06CC}', CharMapAction::SubChar('\u{064A}')), // FARSI YEH
+    ('\u{06D2}', CharMapAction::SubChar('\u{064A}')), // YEH BARREE
+    ('\u{06D3}', CharMapAction::SubChar('\u{0626}')), // YEH BARREE WITH HAMZA ABOVE
+];
+const _: () = assert_sorted(&URDU_TO_ARABIC);
+
+/// Map converting Arabic letters to their Urdu variants.
+pub static ARABIC_TO_URDU: [(char, CharMapAction); 5] = [
+    ('\u{0629}', CharMapAction::SubChar('\u{06C3}')), // TEH MARBUTA
+    ('\u{0643}', CharMapAction::SubChar('\u{06A9}')), // KAF
+    ('\u{0647}', CharMapAction::SubChar('\u{06C1}')), // HEH
+    ('\u{0649}', CharMapAction::SubChar('\u{06CC}')), // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('\u{06CC}')), // YEH
+];
+const _: () = assert_sorted(&ARABIC_TO_URDU);
+
+/// Map unifying (Sorani) Kurdish letter variants with their Arabic
+/// counterparts.
+pub static KURDISH_TO_ARABIC: [(char, CharMapAction); 4] = [
+    ('\u{06A9}', CharMapAction::SubChar('\u{0643}')), // KEHEH
+    ('\u{06BE}', CharMapAction::SubChar('\u{0647}')), // HEH DOACHASHMEE
+    ('\u{06CC}', CharMapAction::SubChar('\u{064A}')), // FARSI YEH
+    ('\u{06D5}', CharMapAction::SubChar('\u{0647}')), // AE
+];
+const _: () = assert_sorted(&KURDISH_TO_ARABIC);
+
+/// Map converting Arabic letters to their (Sorani) Kurdish variants.
+pub static ARABIC_TO_KURDISH: [(char, CharMapAction); 3] = [
+    ('\u{0643}', CharMapAction::SubChar('\u{06A9}')), // KAF
+    ('\u{0649}', CharMapAction::SubChar('\u{06CC}')), // ALEF MAKSURA
+    ('\u{064A}', CharMapAction::SubChar('\u{06CC}')), // YEH
+];
+const _: () = assert_sorted(&ARABIC_TO_KURDISH);
+
+/// Map converting Extended Arabic-Indic (Persian and Urdu) digits to ASCII
+/// digits.
+pub static EXTENDED_ARABIC_INDIC_DIGITS_TO_ASCII: [(char, CharMapAction); 10] = [
+    ('\u{06F0}', CharMapAction::SubChar('0')), // DIGIT ZERO
+    ('\u{06F1}', CharMapAction::SubChar('1')), // DIGIT ONE
+    ('\u{06F2}', CharMapAction::SubChar('2')), // DIGIT TWO
+    ('\u{06F3}', CharMapAction::SubChar('3')), // DIGIT THREE
+    ('\u{06F4}', CharMapAction::SubChar('4')), // DIGIT FOUR
+    ('\u{06F5}', CharMapAction::SubChar('5')), // DIGIT FIVE
+    ('\u{06F6}', CharMapAction::SubChar('6')), // DIGIT SIX
+    ('\u{06F7}', CharMapAction::SubChar('7')), // DIGIT SEVEN
+    ('\u{06F8}', CharMapAction::SubChar('8')), // DIGIT EIGHT
+    ('\u{06F9}', CharMapAction::SubChar('9')), // DIGIT NINE
+];
+const _: () = assert_sorted(&EXTENDED_ARABIC_INDIC_DIGITS_TO_ASCII);
+
+/// Map converting Arabic-Indic digits to ASCII digits.
+pub static ARABIC_INDIC_DIGITS_TO_ASCII: [(char, CharMapAction); 10] = [
+    ('\u{0660}', CharMapAction::SubChar('0')), // DIGIT ZERO
+    ('\u{0661}', CharMapAction::SubChar('1')), // DIGIT ONE
+    ('\u{0662}', CharMapAction::SubChar('2')), // DIGIT TWO
+    ('\u{0663}', CharMapAction::SubChar('3')), // DIGIT THREE
+    ('\u{0664}', CharMapAction::SubChar('4')), // DIGIT FOUR
+    ('\u{0665}', CharMapAction::SubChar('5')), // DIGIT FIVE
+    ('\u{0666}', CharMapAction::SubChar('6')), // DIGIT SIX
+    ('\u{0667}', CharMapAction::SubChar('7')), // DIGIT SEVEN
+    ('\u{0668}', CharMapAction::SubChar('8')), // DIGIT EIGHT
+    ('\u{0669}', CharMapAction::SubChar('9')), // DIGIT NINE
+];
+const _: () = assert_sorted(&ARABIC_INDIC_DIGITS_TO_ASCII);
+
+/// Map converting Extended Arabic-Indic (Persian and Urdu) digits to
+/// Arabic-Indic digits.
+pub static EXTENDED_TO_ARABIC_INDIC_DIGITS: [(char, CharMapAction); 10] = [
+    ('\u{06F0}', CharMapAction::SubChar('\u{0660}')), // DIGIT ZERO
+    ('\u{06F1}', CharMapAction::SubChar('\u{0661}')), // DIGIT ONE
+    ('\u{06F2}', CharMapAction::SubChar('\u{0662}')), // DIGIT TWO
+    ('\u{06F3}', CharMapAction::SubChar('\u{0663}')), // DIGIT THREE
+    ('\u{06F4}', CharMapAction::SubChar('\u{0664}')), // DIGIT FOUR
+    ('\u{06F5}', CharMapAction::SubChar('\u{0665}')), // DIGIT FIVE
+    ('\u{06F6}', CharMapAction::SubChar('\u{0666}')), // DIGIT SIX
+    ('\u{06F7}', CharMapAction::SubChar('\u{0667}')), // DIGIT SEVEN
+    ('\u{06F8}', CharMapAction::SubChar('\u{0668}')), // DIGIT EIGHT
+    ('\u{06F9}', CharMapAction::SubChar('\u{0669}')), // DIGIT NINE
+];
+const _: () = assert_sorted(&EXTENDED_TO_ARABIC_INDIC_DIGITS);
+
+/// Map converting Arabic-Indic digits to Extended Arabic-Indic (Persian and
+/// Urdu) digits.
+pub static ARABIC_INDIC_TO_EXTENDED_DIGITS: [(char, CharMapAction); 10] = [
+    ('\u{0660}', CharMapAction::SubChar('\u{06F0}')), // DIGIT ZERO
+    ('\u{0661}', CharMapAction::SubChar('\u{06F1}')), // DIGIT ONE
+    ('\u{0662}', CharMapAction::SubChar('\u{06F2}')), // DIGIT TWO
+    ('\u{0663}', CharMapAction::SubChar('\u{06F3}')), // DIGIT THREE
+    ('\u{0664}', CharMapAction::SubChar('\u{06F4}')), // DIGIT FOUR
+    ('\u{0665}', CharMapAction::SubChar('\u{06F5}')), // DIGIT FIVE
+    ('\u{0666}', CharMapAction::SubChar('\u{06F6}')), // DIGIT SIX
+    ('\u{0667}', CharMapAction::SubChar('\u{06F7}')), // DIGIT SEVEN
+    ('\u{0668}', CharMapAction::SubChar('\u{06F8}')), // DIGIT EIGHT
+    ('\u{0669}', CharMapAction::SubChar('\u{06F9}')), // DIGIT NINE
+];
+const _: () = assert_sorted(&ARABIC_INDIC_TO_EXTENDED_DIGITS);
@@ -0,0 +1,247 @@
+//! Punctuation and quotation mark normalization maps.
+//!
+//! Typographic punctuation is grouped into maps by kind (quotes, dashes,
+//! ellipses, and CJK and fullwidth punctuation), so that text can be folded
+//! to ASCII as far as needed, e.g. with
+//! `(&NORMALIZE_QUOTES, &NORMALIZE_DASHES)`. [`Normalizer`] has a toggle for
+//! each group and comes with two presets:
+//!
+//! * [`Normalizer::ALL`] folds all supported punctuation to ASCII, including
+//!   em dashes (`—` to `-`) and ellipses (`…` to `...`).
+//! * [`Normalizer::CONSERVATIVE`] only folds quotation marks, hyphens and en
+//!   dashes, and fullwidth punctuation, which have a direct ASCII equivalent.
+//!   Em dashes, ellipses, and CJK punctuation are kept.
+//!
+//! ```rust
+//! use charmap::maps::punctuation::Normalizer;
+//! use charmap::*;
+//!
+//! let text = "«Well…» — she said, “it’s 9–5！”";
+//!
+//! let mapper = CharMapper::new(&Normalizer::ALL, CharMapAction::Pass);
+//! let normalized: String = text.map_chars(&mapper).collect();
+//! assert_eq!(normalized, "\"Well...\" - she said, \"it's 9-5!\"");
+//!
+//! let mapper =
+//!     CharMapper::new(&Normalizer::CONSERVATIVE, CharMapAction::Pass);
+//! let normalized: String = text.map_chars(&mapper).collect();
+//! assert_eq!(normalized, "\"Well…\" — she said, \"it's 9-5!\"");
+//! ```
+
+#[cfg(feature = "std")]
+use crate::actionmap::Keys;
+use crate::actionmap::{
+    assert_sorted, ActionMap, ActionTable, CharMapAction, Toggles,
+};
+
+/// Map normalizing curly quotation marks and guillemets to ASCII quotation
+/// marks (`"` and `'`).
+pub static NORMALIZE_QUOTES: [(char, CharMapAction); 12] = [
+    ('\u{00AB}', CharMapAction::SubChar('"')), // LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{00BB}', CharMapAction::SubChar('"')), // RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+    ('\u{2018}', CharMapAction::SubChar('\'')), // LEFT SINGLE QUOTATION MARK
+    ('\u{2019}', CharMapAction::SubChar('\'')), // RIGHT SINGLE QUOTATION MARK
+    ('\u{201A}', CharMapAction::SubChar('\'')), // SINGLE LOW-9 QUOTATION MARK
+    ('\u{201B}', CharMapAction::SubChar('\'')), // SINGLE HIGH-REVERSED-9 QUOTATION MARK
+    ('\u{201C}', CharMapAction::SubChar('"')),  // LEFT DOUBLE QUOTATION MARK
+    ('\u{201D}', CharMapAction::SubChar('"')),  // RIGHT DOUBLE QUOTATION MARK
+    ('\u{201E}', CharMapAction::SubChar('"')),  // DOUBLE LOW-9 QUOTATION MARK
+    ('\u{201F}', CharMapAction::SubChar('"')), // DOUBLE HIGH-REVERSED-9 QUOTATION MARK
+    ('\u{2039}', CharMapAction::SubChar('\'')), // SINGLE LEFT-POINTING ANGLE QUOTATION MARK
+    ('\u{203A}', CharMapAction::SubChar('\'')), // SINGLE RIGHT-POINTING ANGLE QUOTATION MARK
+];
+const _: () = assert_sorted(&NORMALIZE_QUOTES);
+
+/// Map normalizing hyphens and en dashes (‐ ‑ ‒ –) to hyphen-minus (`-`).
+pub static NORMALIZE_DASHES: [(char, CharMapAction); 6] = [
+    ('\u{2010}', CharMapAction::SubChar('-')), // HYPHEN
+    ('\u{2011}', CharMapAction::SubChar('-')), // NON-BREAKING HYPHEN
+    ('\u{2012}', CharMapAction::SubChar('-')), // FIGURE DASH
+    ('\u{2013}', CharMapAction::SubChar('-')), // EN DASH
+    ('\u{FE32}', CharMapAction::SubChar('-')), // PRESENTATION FORM FOR VERTICAL EN DASH
+    ('\u{FE63}', CharMapAction::SubChar('-')), // SMALL HYPHEN-MINUS
+];
+const _: () = assert_sorted(&NORMALIZE_DASHES);
+
+/// Map folding em dashes and horizontal bars (— ―) to hyphen-minus (`-`).
+pub static FOLD_EM_DASHES: [(char, CharMapAction); 6] = [
+    ('\u{2014}', CharMapAction::SubChar('-')), // EM DASH
+    ('\u{2015}', CharMapAction::SubChar('-')), // HORIZONTAL BAR
+    ('\u{2E3A}', CharMapAction::SubChar('-')), // TWO-EM DASH
+    ('\u{2E3B}', CharMapAction::SubChar('-')), // THREE-EM DASH
+    ('\u{FE31}', CharMapAction::SubChar('-')), // PRESENTATION FORM FOR VERTICAL EM DASH
+    ('\u{FE58}', CharMapAction::SubChar('-')), // SMALL EM DASH
+];
+const _: () = assert_sorted(&FOLD_EM_DASHES);
+
+/// Map expanding ellipses and dot leaders (… ‥ ․) to full stops (`...`).
+pub static EXPAND_ELLIPSIS: [(char, CharMapAction); 3] = [
+    ('\u{2024}', CharMapAction::SubChar('.')), // ONE DOT LEADER
+    ('\u{2025}', CharMapAction::SubStr("..")), // TWO DOT LEADER
+    ('\u{2026}', CharMapAction::SubStr("...")), // HORIZONTAL ELLIPSIS
+];
+const _: () = assert_sorted(&EXPAND_ELLIPSIS);
+
+/// Map normalizing CJK brackets, quotation marks, commas, and full stops to
+/// ASCII punctuation.
+pub static NORMALIZE_CJK_PUNCTUATION: [(char, CharMapAction); 27] = [
+    ('\u{3001}', CharMapAction::SubChar(',')), // IDEOGRAPHIC COMMA
+    ('\u{3002}', CharMapAction::SubChar('.')), // IDEOGRAPHIC FULL STOP
+    ('\u{3008}', CharMapAction::SubChar('<')), // LEFT ANGLE BRACKET
+    ('\u{3009}', CharMapAction::SubChar('>')), // RIGHT ANGLE BRACKET
+    ('\u{300A}', CharMapAction::SubChar('<')), // LEFT DOUBLE ANGLE BRACKET
+    ('\u{300B}', CharMapAction::SubChar('>')), // RIGHT DOUBLE ANGLE BRACKET
+    ('\u{300C}', CharMapAction::SubChar('"')), // LEFT CORNER BRACKET
+    ('\u{300D}', CharMapAction::SubChar('"')), // RIGHT CORNER BRACKET
+    ('\u{300E}', CharMapAction::SubChar('"')), // LEFT WHITE CORNER BRACKET
+    ('\u{300F}', CharMapAction::SubChar('"')), // RIGHT WHITE CORNER BRACKET
+    ('\u{3010}', CharMapAction::SubChar('[')), // LEFT BLACK LENTICULAR BRACKET
+    ('\u{3011}', CharMapAction::SubChar(']')), // RIGHT BLACK LENTICULAR BRACKET
+    ('\u{3014}', CharMapAction::SubChar('[')), // LEFT TORTOISE SHELL BRACKET
+    ('\u{3015}', CharMapAction::SubChar(']')), // RIGHT TORTOISE SHELL BRACKET
+    ('\u{3016}', CharMapAction::SubChar('[')), // LEFT WHITE LENTICULAR BRACKET
+    ('\u{3017}', CharMapAction::SubChar(']')), // RIGHT WHITE LENTICULAR BRACKET
+    ('\u{3018}', CharMapAction::SubChar('[')), // LEFT WHITE TORTOISE SHELL BRACKET
+    ('\u{3019}', CharMapAction::SubChar(']')), // RIGHT WHITE TORTOISE SHELL BRACKET
+    ('\u{301A}', CharMapAction::SubChar('[')), // LEFT WHITE SQUARE BRACKET
+    ('\u{301B}', CharMapAction::SubChar(']')), // RIGHT WHITE SQUARE BRACKET
+    ('\u{301D}', CharMapAction::SubChar('"')), // REVERSED DOUBLE PRIME QUOTATION MARK
+    ('\u{301E}', CharMapAction::SubChar('"')), // DOUBLE PRIME QUOTATION MARK
+    ('\u{301F}', CharMapAction::SubChar('"')), // LOW DOUBLE PRIME QUOTATION MARK
+    ('\u{FF61}', CharMapAction::SubChar('.')), // HALFWIDTH IDEOGRAPHIC FULL STOP
+    ('\u{FF62}', CharMapAction::SubChar('"')), // HALFWIDTH LEFT CORNER BRACKET
+    ('\u{FF63}', CharMapAction::SubChar('"')), // HALFWIDTH RIGHT CORNER BRACKET
+    ('\u{FF64}', CharMapAction::SubChar(',')), // HALFWIDTH IDEOGRAPHIC COMMA
+];
+const _: () = assert_sorted(&NORMALIZE_CJK_PUNCTUATION);
+
+/// Map normalizing fullwidth punctuation (U+FF01-U+FF5E, excluding letters
+/// and digits) to ASCII punctuation.
+pub static NORMALIZE_FULLWIDTH_PUNCTUATION: [(char, CharMapAction); 32] = [
+    ('\u{FF01}', CharMapAction::SubChar('!')), // FULLWIDTH EXCLAMATION MARK
+    ('\u{FF02}', CharMapAction::SubChar('"')), // FULLWIDTH QUOTATION MARK
+    ('\u{FF03}', CharMapAction::SubChar('#')), // FULLWIDTH NUMBER SIGN
+    ('\u{FF04}', CharMapAction::SubChar('$')), // FULLWIDTH DOLLAR SIGN
+    ('\u{FF05}', CharMapAction::SubChar('%')), // FULLWIDTH PERCENT SIGN
+    ('\u{FF06}', CharMapAction::SubChar('&')), // FULLWIDTH AMPERSAND
+    ('\u{FF07}', CharMapAction::SubChar('\'')), // FULLWIDTH APOSTROPHE
+    ('\u{FF08}', CharMapAction::SubChar('(')), // FULLWIDTH LEFT PARENTHESIS
+    ('\u{FF09}', CharMapAction::SubChar(')')), // FULLWIDTH RIGHT PARENTHESIS
+    ('\u{FF0A}', CharMapAction::SubChar('*')), // FULLWIDTH ASTERISK
+    ('\u{FF0B}', CharMapAction::SubChar('+')), // FULLWIDTH PLUS SIGN
+    ('\u{FF0C}', CharMapAction::SubChar(',')), // FULLWIDTH COMMA
+    ('\u{FF0D}', CharMapAction::SubChar('-')), // FULLWIDTH HYPHEN-MINUS
+    ('\u{FF0E}', CharMapAction::SubChar('.')), // FULLWIDTH FULL STOP
+    ('\u{FF0F}', CharMapAction::SubChar('/')), // FULLWIDTH SOLIDUS
+    ('\u{FF1A}', CharMapAction::SubChar(':')), // FULLWIDTH COLON
+    ('\u{FF1B}', CharMapAction::SubChar(';')), // FULLWIDTH SEMICOLON
+    ('\u{FF1C}', CharMapAction::SubChar('<')), // FULLWIDTH LESS-THAN SIGN
+    ('\u{FF1D}', CharMapAction::SubChar('=')), // FULLWIDTH EQUALS SIGN
+    ('\u{FF1E}', CharMapAction::SubChar('>')), // FULLWIDTH GREATER-THAN SIGN
+    ('\u{FF1F}', CharMapAction::SubChar('?')), // FULLWIDTH QUESTION MARK
+    ('\u{FF20}', CharMapAction::SubChar('@')), // FULLWIDTH COMMERCIAL AT
+    ('\u{FF3B}', CharMapAction::SubChar('[')), // FULLWIDTH LEFT SQUARE BRACKET
+    ('\u{FF3C}', CharMapAction::SubChar('\\')), // FULLWIDTH REVERSE SOLIDUS
+    ('\u{FF3D}', CharMapAction::SubChar(']')), // FULLWIDTH RIGHT SQUARE BRACKET
+    ('\u{FF3E}', CharMapAction::SubChar('^')), // FULLWIDTH CIRCUMFLEX ACCENT
+    ('\u{FF3F}', CharMapAction::SubChar('_')), // FULLWIDTH LOW LINE
+    ('\u{FF40}', CharMapAction::SubChar('`')), // FULLWIDTH GRAVE ACCENT
+    ('\u{FF5B}', CharMapAction::SubChar('{')), // FULLWIDTH LEFT CURLY BRACKET
+    ('\u{FF5C}', CharMapAction::SubChar('|')), // FULLWIDTH VERTICAL LINE
+    ('\u{FF5D}', CharMapAction::SubChar('}')), // FULLWIDTH RIGHT CURLY BRACKET
+    ('\u{FF5E}', CharMapAction::SubChar('~')), // FULLWIDTH TILDE
+];
+const _: () = assert_sorted(&NORMALIZE_FULLWIDTH_PUNCTUATION);
+
+// Tables of the rules applied by `Normalizer`, indexed by the constants below.
+static RULES: [ActionTable; 6] = [
+    &NORMALIZE_QUOTES,
+    &NORMALIZE_DASHES,
+    &FOLD_EM_DASHES,
+    &EXPAND_ELLIPSIS,
+    &NORMALIZE_CJK_PUNCTUATION,
+    &NORMALIZE_FULLWIDTH_PUNCTUATION,
+];
+
+const QUOTES: usize = 0;
+const DASHES: usize = 1;
+const EM_DASHES: usize = 2;
+const ELLIPSIS: usize = 3;
+const CJK: usize = 4;
+const FULLWIDTH: usize = 5;
+
+/// An [`ActionMap`] applying a configurable set of punctuation normalization
+/// rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Normalizer {
+    rules: Toggles<6>,
+}
+
+impl Normalizer {
+    /// A [`Normalizer`] with all rules enabled.
+    pub const ALL: Normalizer = Normalizer { rules: Toggles::ALL };
+
+    /// A [`Normalizer`] with only the rules that have a direct ASCII
+    /// equivalent enabled.
+    pub const CONSERVATIVE: Normalizer =
+        Normalizer::NONE.quotes(true).dashes(true).fullwidth(true);
+
+    /// A [`Normalizer`] with all rules disabled.
+    pub const NONE: Normalizer = Normalizer { rules: Toggles::NONE };
+
+    /// Toggles [`NORMALIZE_QUOTES`].
+    #[inline]
+    pub const fn quotes(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(QUOTES, enabled) }
+    }
+
+    /// Toggles [`NORMALIZE_DASHES`].
+    #[inline]
+    pub const fn dashes(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(DASHES, enabled) }
+    }
+
+    /// Toggles [`FOLD_EM_DASHES`].
+    #[inline]
+    pub const fn em_dashes(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(EM_DASHES, enabled) }
+    }
+
+    /// Toggles [`EXPAND_ELLIPSIS`].
+    #[inline]
+    pub const fn ellipsis(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(ELLIPSIS, enabled) }
+    }
+
+    /// Toggles [`NORMALIZE_CJK_PUNCTUATION`].
+    #[inline]
+    pub const fn cjk(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(CJK, enabled) }
+    }
+
+    /// Toggles [`NORMALIZE_FULLWIDTH_PUNCTUATION`].
+    #[inline]
+    pub const fn fullwidth(self, enabled: bool) -> Self {
+        Normalizer { rules: self.rules.set(FULLWIDTH, enabled) }
+    }
+}
+
+impl Default for Normalizer {
+    #[inline]
+    fn default() -> Self {
+        Normalizer::ALL
+    }
+}
+
+impl ActionMap for Normalizer {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.rules.map_char(&RULES, c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(self.rules.keys(&RULES))
+    }
+}
@@ -0,0 +1,101 @@
+use core::char::ToUppercase;
+use core::iter::{self, Peekable};
+use core::{option, str::Chars};
+
+use crate::actionmap::CharMapAction;
+
+// A romanization scheme with rules that depend on the surrounding characters.
+pub(crate) trait ContextRules {
+    // Returns the action to apply to a character given its neighbours, and
+    // whether the next character is part of the same digraph (and thus
+    // romanized along with it).
+    fn action(
+        &self,
+        prev: Option<char>,
+        c: char,
+        next: Option<char>,
+    ) -> (CharMapAction<'static>, bool);
+}
+
+// Characters from a character iterator romanized using given context rules.
+// Multi-letter romanizations of capital letters are fully capitalized inside
+// all-caps words.
+#[derive(Clone)]
+pub(crate) struct ContextRomanized<R, I>
+where
+    R: ContextRules,
+    I: Iterator<Item = char>,
+{
+    rules: R,
+    text_chars: Peekable<I>,
+    prev: Option<char>,
+    out_chars: iter::Chain<option::IntoIter<char>, Chars<'static>>,
+    upper_chars: Option<ToUppercase>,
+    upper: bool,
+}
+
+impl<R, I> ContextRomanized<R, I>
+where
+    R: ContextRules,
+    I: Iterator<Item = char>,
+{
+    #[inline]
+    pub(crate) fn new(rules: R, text_chars: I) -> Self {
+        ContextRomanized {
+            rules,
+            text_chars: text_chars.peekable(),
+            prev: None,
+            out_chars: None.into_iter().chain("".chars()),
+            upper_chars: None,
+            upper: false,
+        }
+    }
+}
+
+impl<R, I> Iterator for ContextRomanized<R, I>
+where
+    R: ContextRules,
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.upper_chars.as_mut().and_then(Iterator::next)
+            {
+                return Some(c);
+            }
+
+            if let Some(c) = self.out_chars.next() {
+                if self.upper {
+                    self.upper_chars = Some(c.to_uppercase());
+                    continue;
+                }
+                return Some(c);
+            }
+
+            let c = self.text_chars.next()?;
+            let next = self.text_chars.peek().copied();
+            let (action, digraph) = self.rules.action(self.prev, c, next);
+
+            self.upper = c.is_uppercase()
+                && match next {
+                    Some(n) if n.is_alphabetic() => n.is_uppercase(),
+                    _ => self.prev.is_some_and(char::is_uppercase),
+                };
+            let (d, s) = match action {
+                CharMapAction::Pass => (Some(c), ""),
+                CharMapAction::Delete => (None, ""),
+                CharMapAction::SubChar(d) => (Some(d), ""),
+                CharMapAction::SubStr(s) => (None, s),
+            };
+            self.out_chars = d.into_iter().chain(s.chars());
+            self.prev = Some(c);
+
+            if digraph {
+                self.prev = self.text_chars.next();
+            }
+        }
+    }
+}
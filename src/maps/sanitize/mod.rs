@@ -0,0 +1,432 @@
+//! Invisible and control character sanitization maps.
+//!
+//! Characters are split into classes by how much harm they can do and how
+//! often legitimate text needs them, with one map per class (e.g.
+//! [`REMOVE_BIDI_CONTROLS`] or [`REMOVE_SOFT_HYPHEN`]). Since most callers want
+//! some mix of these, [`Sanitizer`] has a switch per class and two presets:
+//!
+//! * [`Sanitizer::DISPLAY`] removes characters that are never needed to
+//!   display text correctly and may be used to corrupt or spoof it: C0 and C1
+//!   controls (except tab, line feed, and carriage return), bidirectional
+//!   embedding, override, and isolate controls, and zero-width spaces, word
+//!   joiners, and byte order marks. Characters that affect the rendering of
+//!   legitimate text (ZWJ, ZWNJ, bidirectional marks, and soft hyphens) are
+//!   kept.
+//! * [`Sanitizer::AGGRESSIVE`] removes all of the above as well as ZWJ, ZWNJ,
+//!   bidirectional marks, soft hyphens, and other invisible format characters.
+//!
+//! Control characters that separate text are replaced rather than removed, so
+//! that the text around them stays apart: line tabulation and form feed become
+//! spaces, and next line becomes a line feed.
+//!
+//! ZWJ is used to join emoji into a single emoji (e.g. 👩‍💻), so removing it
+//! splits such sequences into their parts. Since a single character map
+//! can't tell whether a ZWJ is part of an emoji sequence, used as an
+//! [`ActionMap`] a [`Sanitizer`] removes either all ZWJs or none.
+//! [`Sanitizer::sanitize_chars`] looks at the surrounding characters instead,
+//! and keeps ZWJs between two Extended_Pictographic characters (ignoring
+//! variation selectors, emoji modifiers, and tags after the first one) even
+//! if ZWJ removal is enabled.
+//!
+//! ```rust
+//! use charmap::maps::sanitize::Sanitizer;
+//! use charmap::*;
+//!
+//! let text = "\u{FEFF}pay\u{200B}pal\u{202E}gpj.exe\u{202C} \u{1F469}\u{200D}\u{1F4BB}";
+//!
+//! let mapper = CharMapper::new(&Sanitizer::DISPLAY, CharMapAction::Pass);
+//! let sanitized: String = text.map_chars(&mapper).collect();
+//! assert_eq!(sanitized, "paypalgpj.exe \u{1F469}\u{200D}\u{1F4BB}");
+//!
+//! let mapper = CharMapper::new(&Sanitizer::AGGRESSIVE, CharMapAction::Pass);
+//! let sanitized: String = text.map_chars(&mapper).collect();
+//! assert_eq!(sanitized, "paypalgpj.exe \u{1F469}\u{1F4BB}");
+//!
+//! let text = "a\u{200D}b \u{1F469}\u{200D}\u{1F4BB}";
+//! let sanitized: String = Sanitizer::AGGRESSIVE.sanitize_chars(text.chars()).collect();
+//! assert_eq!(sanitized, "ab \u{1F469}\u{200D}\u{1F4BB}");
+//! ```
+//!
+//! The Extended_Pictographic table is generated by `scripts/gen_sanitize.py`
+//! from Unicode data.
+
+mod tables;
+
+use core::cmp::Ordering;
+use core::iter::Peekable;
+use core::str::Chars;
+
+#[cfg(feature = "std")]
+use crate::actionmap::Keys;
+use crate::actionmap::{
+    assert_sorted, ActionMap, ActionTable, CharMapAction, Toggles,
+};
+
+use self::tables::EXTENDED_PICTOGRAPHIC;
+
+/// Map removing C0 control characters and DEL, except for tab, line feed, and
+/// carriage return. Line tabulation and form feed are replaced with spaces.
+pub static REMOVE_C0_CONTROLS: [(char, CharMapAction); 30] = [
+    ('\u{0000}', CharMapAction::Delete), // NULL
+    ('\u{0001}', CharMapAction::Delete), // START OF HEADING
+    ('\u{0002}', CharMapAction::Delete), // START OF TEXT
+    ('\u{0003}', CharMapAction::Delete), // END OF TEXT
+    ('\u{0004}', CharMapAction::Delete), // END OF TRANSMISSION
+    ('\u{0005}', CharMapAction::Delete), // ENQUIRY
+    ('\u{0006}', CharMapAction::Delete), // ACKNOWLEDGE
+    ('\u{0007}', CharMapAction::Delete), // BELL
+    ('\u{0008}', CharMapAction::Delete), // BACKSPACE
+    ('\u{000B}', CharMapAction::SubChar(' ')), // LINE TABULATION
+    ('\u{000C}', CharMapAction::SubChar(' ')), // FORM FEED
+    ('\u{000E}', CharMapAction::Delete), // SHIFT OUT
+    ('\u{000F}', CharMapAction::Delete), // SHIFT IN
+    ('\u{0010}', CharMapAction::Delete), // DATA LINK ESCAPE
+    ('\u{0011}', CharMapAction::Delete), // DEVICE CONTROL ONE
+    ('\u{0012}', CharMapAction::Delete), // DEVICE CONTROL TWO
+    ('\u{0013}', CharMapAction::Delete), // DEVICE CONTROL THREE
+    ('\u{0014}', CharMapAction::Delete), // DEVICE CONTROL FOUR
+    ('\u{0015}', CharMapAction::Delete), // NEGATIVE ACKNOWLEDGE
+    ('\u{0016}', CharMapAction::Delete), // SYNCHRONOUS IDLE
+    ('\u{0017}', CharMapAction::Delete), // END OF TRANSMISSION BLOCK
+    ('\u{0018}', CharMapAction::Delete), // CANCEL
+    ('\u{0019}', CharMapAction::Delete), // END OF MEDIUM
+    ('\u{001A}', CharMapAction::Delete), // SUBSTITUTE
+    ('\u{001B}', CharMapAction::Delete), // ESCAPE
+    ('\u{001C}', CharMapAction::Delete), // INFORMATION SEPARATOR FOUR
+    ('\u{001D}', CharMapAction::Delete), // INFORMATION SEPARATOR THREE
+    ('\u{001E}', CharMapAction::Delete), // INFORMATION SEPARATOR TWO
+    ('\u{001F}', CharMapAction::Delete), // INFORMATION SEPARATOR ONE
+    ('\u{007F}', CharMapAction::Delete), // DELETE
+];
+const _: () = assert_sorted(&REMOVE_C0_CONTROLS);
+
+/// Map removing C1 control characters (U+0080-U+009F). Next line is replaced
+/// with a line feed.
+pub static REMOVE_C1_CONTROLS: [(char, CharMapAction); 32] = [
+    ('\u{0080}', CharMapAction::Delete), // PADDING CHARACTER
+    ('\u{0081}', CharMapAction::Delete), // HIGH OCTET PRESET
+    ('\u{0082}', CharMapAction::Delete), // BREAK PERMITTED HERE
+    ('\u{0083}', CharMapAction::Delete), // NO BREAK HERE
+    ('\u{0084}', CharMapAction::Delete), // INDEX
+    ('\u{0085}', CharMapAction::SubChar('\n')), // NEXT LINE
+    ('\u{0086}', CharMapAction::Delete), // START OF SELECTED AREA
+    ('\u{0087}', CharMapAction::Delete), // END OF SELECTED AREA
+    ('\u{0088}', CharMapAction::Delete), // CHARACTER TABULATION SET
+    ('\u{0089}', CharMapAction::Delete), // CHARACTER TABULATION WITH JUSTIFICATION
+    ('\u{008A}', CharMapAction::Delete), // LINE TABULATION SET
+    ('\u{008B}', CharMapAction::Delete), // PARTIAL LINE FORWARD
+    ('\u{008C}', CharMapAction::Delete), // PARTIAL LINE BACKWARD
+    ('\u{008D}', CharMapAction::Delete), // REVERSE LINE FEED
+    ('\u{008E}', CharMapAction::Delete), // SINGLE SHIFT TWO
+    ('\u{008F}', CharMapAction::Delete), // SINGLE SHIFT THREE
+    ('\u{0090}', CharMapAction::Delete), // DEVICE CONTROL STRING
+    ('\u{0091}', CharMapAction::Delete), // PRIVATE USE ONE
+    ('\u{0092}', CharMapAction::Delete), // PRIVATE USE TWO
+    ('\u{0093}', CharMapAction::Delete), // SET TRANSMIT STATE
+    ('\u{0094}', CharMapAction::Delete), // CANCEL CHARACTER
+    ('\u{0095}', CharMapAction::Delete), // MESSAGE WAITING
+    ('\u{0096}', CharMapAction::Delete), // START OF GUARDED AREA
+    ('\u{0097}', CharMapAction::Delete), // END OF GUARDED AREA
+    ('\u{0098}', CharMapAction::Delete), // START OF STRING
+    ('\u{0099}', CharMapAction::Delete), // SINGLE GRAPHIC CHARACTER INTRODUCER
+    ('\u{009A}', CharMapAction::Delete), // SINGLE CHARACTER INTRODUCER
+    ('\u{009B}', CharMapAction::Delete), // CONTROL SEQUENCE INTRODUCER
+    ('\u{009C}', CharMapAction::Delete), // STRING TERMINATOR
+    ('\u{009D}', CharMapAction::Delete), // OPERATING SYSTEM COMMAND
+    ('\u{009E}', CharMapAction::Delete), // PRIVACY MESSAGE
+    ('\u{009F}', CharMapAction::Delete), // APPLICATION PROGRAM COMMAND
+];
+const _: () = assert_sorted(&REMOVE_C1_CONTROLS);
+
+/// Map removing bidirectional embedding, override, and isolate controls
+/// (U+202A-U+202E and U+2066-U+2069).
+pub static REMOVE_BIDI_CONTROLS: [(char, CharMapAction); 9] = [
+    ('\u{202A}', CharMapAction::Delete), // LEFT-TO-RIGHT EMBEDDING
+    ('\u{202B}', CharMapAction::Delete), // RIGHT-TO-LEFT EMBEDDING
+    ('\u{202C}', CharMapAction::Delete), // POP DIRECTIONAL FORMATTING
+    ('\u{202D}', CharMapAction::Delete), // LEFT-TO-RIGHT OVERRIDE
+    ('\u{202E}', CharMapAction::Delete), // RIGHT-TO-LEFT OVERRIDE
+    ('\u{2066}', CharMapAction::Delete), // LEFT-TO-RIGHT ISOLATE
+    ('\u{2067}', CharMapAction::Delete), // RIGHT-TO-LEFT ISOLATE
+    ('\u{2068}', CharMapAction::Delete), // FIRST STRONG ISOLATE
+    ('\u{2069}', CharMapAction::Delete), // POP DIRECTIONAL ISOLATE
+];
+const _: () = assert_sorted(&REMOVE_BIDI_CONTROLS);
+
+/// Map removing bidirectional marks (ALM, LRM, and RLM).
+pub static REMOVE_BIDI_MARKS: [(char, CharMapAction); 3] = [
+    ('\u{061C}', CharMapAction::Delete), // ARABIC LETTER MARK
+    ('\u{200E}', CharMapAction::Delete), // LEFT-TO-RIGHT MARK
+    ('\u{200F}', CharMapAction::Delete), // RIGHT-TO-LEFT MARK
+];
+const _: () = assert_sorted(&REMOVE_BIDI_MARKS);
+
+/// Map removing zero-width spaces, word joiners, and byte order marks.
+pub static REMOVE_ZERO_WIDTH: [(char, CharMapAction); 3] = [
+    ('\u{200B}', CharMapAction::Delete), // ZERO WIDTH SPACE
+    ('\u{2060}', CharMapAction::Delete), // WORD JOINER
+    ('\u{FEFF}', CharMapAction::Delete), // ZERO WIDTH NO-BREAK SPACE
+];
+const _: () = assert_sorted(&REMOVE_ZERO_WIDTH);
+
+/// Map removing zero-width non-joiners (ZWNJ).
+pub static REMOVE_ZWNJ: [(char, CharMapAction); 1] = [
+    ('\u{200C}', CharMapAction::Delete), // ZERO WIDTH NON-JOINER
+];
+
+/// Map removing zero-width joiners (ZWJ).
+///
+/// This also splits emoji ZWJ sequences, see [`Sanitizer::sanitize_chars`]
+/// for removing the other ZWJs only.
+pub static REMOVE_ZWJ: [(char, CharMapAction); 1] = [
+    ('\u{200D}', CharMapAction::Delete), // ZERO WIDTH JOINER
+];
+
+/// Map removing soft hyphens.
+pub static REMOVE_SOFT_HYPHEN: [(char, CharMapAction); 1] = [
+    ('\u{00AD}', CharMapAction::Delete), // SOFT HYPHEN
+];
+
+/// Map removing other invisible format characters (combining grapheme
+/// joiner, invisible mathematical operators, deprecated format characters,
+/// and interlinear annotation characters).
+pub static REMOVE_INVISIBLE_FORMAT: [(char, CharMapAction); 14] = [
+    ('\u{034F}', CharMapAction::Delete), // COMBINING GRAPHEME JOINER
+    ('\u{2061}', CharMapAction::Delete), // FUNCTION APPLICATION
+    ('\u{2062}', CharMapAction::Delete), // INVISIBLE TIMES
+    ('\u{2063}', CharMapAction::Delete), // INVISIBLE SEPARATOR
+    ('\u{2064}', CharMapAction::Delete), // INVISIBLE PLUS
+    ('\u{206A}', CharMapAction::Delete), // INHIBIT SYMMETRIC SWAPPING
+    ('\u{206B}', CharMapAction::Delete), // ACTIVATE SYMMETRIC SWAPPING
+    ('\u{206C}', CharMapAction::Delete), // INHIBIT ARABIC FORM SHAPING
+    ('\u{206D}', CharMapAction::Delete), // ACTIVATE ARABIC FORM SHAPING
+    ('\u{206E}', CharMapAction::Delete), // NATIONAL DIGIT SHAPES
+    ('\u{206F}', CharMapAction::Delete), // NOMINAL DIGIT SHAPES
+    ('\u{FFF9}', CharMapAction::Delete), // INTERLINEAR ANNOTATION ANCHOR
+    ('\u{FFFA}', CharMapAction::Delete), // INTERLINEAR ANNOTATION SEPARATOR
+    ('\u{FFFB}', CharMapAction::Delete), // INTERLINEAR ANNOTATION TERMINATOR
+];
+const _: () = assert_sorted(&REMOVE_INVISIBLE_FORMAT);
+
+// Tables of the classes removed by `Sanitizer`, indexed by the constants
+// below.
+static RULES: [ActionTable; 9] = [
+    &REMOVE_C0_CONTROLS,
+    &REMOVE_C1_CONTROLS,
+    &REMOVE_BIDI_CONTROLS,
+    &REMOVE_BIDI_MARKS,
+    &REMOVE_ZERO_WIDTH,
+    &REMOVE_ZWNJ,
+    &REMOVE_ZWJ,
+    &REMOVE_SOFT_HYPHEN,
+    &REMOVE_INVISIBLE_FORMAT,
+];
+
+const C0_CONTROLS: usize = 0;
+const C1_CONTROLS: usize = 1;
+const BIDI_CONTROLS: usize = 2;
+const BIDI_MARKS: usize = 3;
+const ZERO_WIDTH: usize = 4;
+const ZWNJ: usize = 5;
+const ZWJ: usize = 6;
+const SOFT_HYPHEN: usize = 7;
+const INVISIBLE_FORMAT: usize = 8;
+
+/// An [`ActionMap`] removing a configurable set of invisible and control
+/// characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sanitizer {
+    rules: Toggles<9>,
+}
+
+impl Sanitizer {
+    /// A [`Sanitizer`] removing characters that are unsafe for display.
+    pub const DISPLAY: Sanitizer = Sanitizer::NONE
+        .c0_controls(true)
+        .c1_controls(true)
+        .bidi_controls(true)
+        .zero_width(true);
+
+    /// A [`Sanitizer`] removing all invisible and control characters.
+    pub const AGGRESSIVE: Sanitizer = Sanitizer { rules: Toggles::ALL };
+
+    /// A [`Sanitizer`] removing nothing.
+    pub const NONE: Sanitizer = Sanitizer { rules: Toggles::NONE };
+
+    /// Toggles [`REMOVE_C0_CONTROLS`].
+    #[inline]
+    pub const fn c0_controls(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(C0_CONTROLS, enabled) }
+    }
+
+    /// Toggles [`REMOVE_C1_CONTROLS`].
+    #[inline]
+    pub const fn c1_controls(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(C1_CONTROLS, enabled) }
+    }
+
+    /// Toggles [`REMOVE_BIDI_CONTROLS`].
+    #[inline]
+    pub const fn bidi_controls(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(BIDI_CONTROLS, enabled) }
+    }
+
+    /// Toggles [`REMOVE_BIDI_MARKS`].
+    #[inline]
+    pub const fn bidi_marks(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(BIDI_MARKS, enabled) }
+    }
+
+    /// Toggles [`REMOVE_ZERO_WIDTH`].
+    #[inline]
+    pub const fn zero_width(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(ZERO_WIDTH, enabled) }
+    }
+
+    /// Toggles [`REMOVE_ZWNJ`].
+    #[inline]
+    pub const fn zwnj(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(ZWNJ, enabled) }
+    }
+
+    /// Toggles [`REMOVE_ZWJ`].
+    #[inline]
+    pub const fn zwj(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(ZWJ, enabled) }
+    }
+
+    /// Toggles [`REMOVE_SOFT_HYPHEN`].
+    #[inline]
+    pub const fn soft_hyphen(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(SOFT_HYPHEN, enabled) }
+    }
+
+    /// Toggles [`REMOVE_INVISIBLE_FORMAT`].
+    #[inline]
+    pub const fn invisible_format(self, enabled: bool) -> Self {
+        Sanitizer { rules: self.rules.set(INVISIBLE_FORMAT, enabled) }
+    }
+
+    /// Returns an iterator that sanitizes characters from a given character
+    /// iterator. Unlike mapping characters with this [`Sanitizer`], ZWJs
+    /// joining two Extended_Pictographic characters (i.e. in emoji ZWJ
+    /// sequences) are kept even if ZWJ removal is enabled.
+    #[inline]
+    pub fn sanitize_chars<I>(&self, text_chars: I) -> SanitizedChars<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        SanitizedChars {
+            sanitizer: *self,
+            text_chars: text_chars.peekable(),
+            sub_chars: "".chars(),
+            pictographic: false,
+        }
+    }
+
+    #[inline]
+    fn action(&self, c: char) -> Option<CharMapAction<'static>> {
+        self.rules.map_char(&RULES, c)
+    }
+}
+
+impl Default for Sanitizer {
+    #[inline]
+    fn default() -> Self {
+        Sanitizer::DISPLAY
+    }
+}
+
+impl ActionMap for Sanitizer {
+    #[inline]
+    fn map_char(&'_ self, c: char) -> Option<CharMapAction<'_>> {
+        self.action(c)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn keys(&self) -> Option<Keys<'_>> {
+        Some(self.rules.keys(&RULES))
+    }
+}
+
+/// Iterator returned by [`Sanitizer::sanitize_chars`].
+#[derive(Clone)]
+pub struct SanitizedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    sanitizer: Sanitizer,
+    text_chars: Peekable<I>,
+    sub_chars: Chars<'static>,
+    // Whether the last character other than an emoji extender was
+    // Extended_Pictographic.
+    pictographic: bool,
+}
+
+impl<I> Iterator for SanitizedChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(c) = self.sub_chars.next() {
+                return Some(c);
+            }
+
+            let c = self.text_chars.next()?;
+
+            if c == ZERO_WIDTH_JOINER
+                && self.pictographic
+                && self.text_chars.peek().is_some_and(|&n| is_pictographic(n))
+            {
+                return Some(c);
+            }
+            if !is_emoji_extender(c) {
+                self.pictographic = is_pictographic(c);
+            }
+
+            match self.sanitizer.action(c) {
+                None | Some(CharMapAction::Pass) => return Some(c),
+                Some(CharMapAction::Delete) => {}
+                Some(CharMapAction::SubChar(d)) => return Some(d),
+                Some(CharMapAction::SubStr(s)) => self.sub_chars = s.chars(),
+            }
+        }
+    }
+}
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+// Returns whether a character may follow an Extended_Pictographic character
+// within an emoji (variation selectors, emoji modifiers, the combining
+// enclosing keycap, and tags).
+#[inline]
+fn is_emoji_extender(c: char) -> bool {
+    matches!(
+        c,
+        '\u{20E3}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+// Returns whether a given character has the Extended_Pictographic property.
+#[inline]
+fn is_pictographic(c: char) -> bool {
+    EXTENDED_PICTOGRAPHIC
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
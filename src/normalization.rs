@@ -0,0 +1,186 @@
+use core::str::Chars;
+
+use unicode_normalization::{
+    Decompositions, Recompositions, UnicodeNormalization,
+};
+
+use super::actionmap::ActionMap;
+use super::charmapper::CharMapper;
+
+/// A Unicode normalization form (see
+/// [UAX #15](https://www.unicode.org/reports/tr15/)).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormalForm {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalForm {
+    /// Returns an iterator that normalizes characters from a given character
+    /// iterator to this normal form.
+    #[inline]
+    pub fn normalize_chars_iter<I>(self, text_chars: I) -> Normalized<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        let inner = match self {
+            NormalForm::Nfc => NormalizedInner::Composed(text_chars.nfc()),
+            NormalForm::Nfd => NormalizedInner::Decomposed(text_chars.nfd()),
+            NormalForm::Nfkc => NormalizedInner::Composed(text_chars.nfkc()),
+            NormalForm::Nfkd => NormalizedInner::Decomposed(text_chars.nfkd()),
+        };
+
+        Normalized { inner }
+    }
+}
+
+/// Character iterator returned by [`NormalForm::normalize_chars_iter`] and
+/// [`NormalizeCharsIter::normalize`].
+#[derive(Clone)]
+pub struct Normalized<I>
+where
+    I: Iterator<Item = char>,
+{
+    inner: NormalizedInner<I>,
+}
+
+#[derive(Clone)]
+enum NormalizedInner<I>
+where
+    I: Iterator<Item = char>,
+{
+    Composed(Recompositions<I>),
+    Decomposed(Decompositions<I>),
+}
+
+impl<I> Iterator for Normalized<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            NormalizedInner::Composed(chars) => chars.next(),
+            NormalizedInner::Decomposed(chars) => chars.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            NormalizedInner::Composed(chars) => chars.size_hint(),
+            NormalizedInner::Decomposed(chars) => chars.size_hint(),
+        }
+    }
+}
+
+/// A trait providing a convenience method for [`Iterators`](Iterator) of
+/// [`char`] to normalize their output.
+pub trait NormalizeCharsIter<I: Iterator<Item = char>> {
+    fn normalize(self, form: NormalForm) -> Normalized<I>;
+}
+
+impl<'a> NormalizeCharsIter<Chars<'a>> for &'a str {
+    #[inline]
+    fn normalize(self, form: NormalForm) -> Normalized<Chars<'a>> {
+        form.normalize_chars_iter(self.chars())
+    }
+}
+
+impl<I: Iterator<Item = char>> NormalizeCharsIter<I> for I {
+    #[inline]
+    fn normalize(self, form: NormalForm) -> Normalized<I> {
+        form.normalize_chars_iter(self)
+    }
+}
+
+// Characters from a character iterator, normalized to a given normal form if
+// there is one.
+#[derive(Clone)]
+pub(crate) enum MaybeNormalized<I>
+where
+    I: Iterator<Item = char>,
+{
+    Plain(I),
+    Normalized(Normalized<I>),
+}
+
+impl<I> MaybeNormalized<I>
+where
+    I: Iterator<Item = char>,
+{
+    #[inline]
+    pub(crate) fn new(text_chars: I, form: Option<NormalForm>) -> Self {
+        match form {
+            None => MaybeNormalized::Plain(text_chars),
+            Some(form) => MaybeNormalized::Normalized(
+                form.normalize_chars_iter(text_chars),
+            ),
+        }
+    }
+}
+
+impl<I> Iterator for MaybeNormalized<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            MaybeNormalized::Plain(chars) => chars.next(),
+            MaybeNormalized::Normalized(chars) => chars.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            MaybeNormalized::Plain(chars) => chars.size_hint(),
+            MaybeNormalized::Normalized(chars) => chars.size_hint(),
+        }
+    }
+}
+
+impl<M> CharMapper<'_, M>
+where
+    M: ActionMap + ?Sized,
+{
+    /// Normalizes text to a given normal form when mapping.
+    ///
+    /// The input is normalized before mapping, so the [`ActionMap`] only sees
+    /// characters in the given normal form, and the output is normalized
+    /// again, since mapped characters may not be normalized (e.g. when a base
+    /// letter is substituted in front of a combining mark).
+    ///
+    /// ```rust
+    /// use charmap::*;
+    ///
+    /// // A rule written against precomposed (NFC) characters.
+    /// let actions = [('é', CharMapAction::SubChar('e'))];
+    /// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+    ///
+    /// // Decomposed input slips past the rule...
+    /// let mapped: String = "cafe\u{0301}".map_chars(&mapper).collect();
+    /// assert_eq!(mapped, "cafe\u{0301}");
+    ///
+    /// // ...unless it is normalized to NFC first.
+    /// let mapper = mapper.normalize(NormalForm::Nfc);
+    /// let mapped: String = "cafe\u{0301}".map_chars(&mapper).collect();
+    /// assert_eq!(mapped, "cafe");
+    /// ```
+    #[inline]
+    pub fn normalize(mut self, form: NormalForm) -> Self {
+        self.normal_form = Some(form);
+        self
+    }
+}
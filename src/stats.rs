@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use super::actionmap::{ActionMap, CharMapAction};
+use super::charmapper::{CharMapper, Mapping, MaybeNormalized, Recorder};
+use super::trace::{ActionSource, TracedChar};
+
+/// Statistics collected while mapping characters.
+///
+/// Statistics from different runs (e.g. from different threads) can be
+/// aggregated using [`Stats::merge`].
+///
+/// ```rust
+/// use charmap::*;
+///
+/// let actions = [('e', CharMapAction::SubStr("ee")), ('l', CharMapAction::Delete)];
+/// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+/// let mut stats = Stats::new();
+///
+/// let mapped_str: String =
+///     mapper.map_chars_iter_with_stats("Hello".chars(), &mut stats).collect();
+///
+/// assert_eq!(mapped_str, "Heeo");
+/// assert_eq!(stats.deleted, 2);
+/// assert_eq!(stats.default_hits, 2);
+/// assert_eq!(stats.expansion_ratio(), Some(0.8));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of input characters.
+    pub input_chars: u64,
+    /// Number of characters output by the mapping. If the [`CharMapper`]
+    /// normalizes text, these are counted before output normalization.
+    pub mapped_chars: u64,
+    /// Number of characters to which [`CharMapAction::Pass`] was applied.
+    pub passed: u64,
+    /// Number of characters to which [`CharMapAction::Delete`] was applied.
+    pub deleted: u64,
+    /// Number of characters to which [`CharMapAction::SubChar`] was applied.
+    pub sub_chars: u64,
+    /// Number of characters to which [`CharMapAction::SubStr`] was applied.
+    pub sub_strs: u64,
+    /// Number of characters that were not in the [`ActionMap`] and had the
+    /// default action applied.
+    pub default_hits: u64,
+    /// Number of times each character was found in the [`ActionMap`].
+    pub map_char_hits: HashMap<char, u64>,
+    /// Number of times each character not in the [`ActionMap`] was seen.
+    pub default_char_hits: HashMap<char, u64>,
+}
+
+impl Stats {
+    /// Creates new empty [`Stats`].
+    #[inline]
+    pub fn new() -> Self {
+        Stats::default()
+    }
+
+    /// Records a single traced character.
+    pub fn record(&mut self, traced: &TracedChar<'_>) {
+        self.record_input(traced);
+        self.mapped_chars += traced.output().count() as u64;
+    }
+
+    // Records a traced character without its output.
+    fn record_input(&mut self, traced: &TracedChar<'_>) {
+        self.input_chars += 1;
+
+        match traced.action {
+            CharMapAction::Pass => self.passed += 1,
+            CharMapAction::Delete => self.deleted += 1,
+            CharMapAction::SubChar(_) => self.sub_chars += 1,
+            CharMapAction::SubStr(_) => self.sub_strs += 1,
+        }
+
+        match traced.source {
+            ActionSource::Map => {
+                *self.map_char_hits.entry(traced.c).or_insert(0) += 1;
+            }
+            ActionSource::Default => {
+                self.default_hits += 1;
+                *self.default_char_hits.entry(traced.c).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Adds the statistics of another [`Stats`] to this one.
+    pub fn merge(&mut self, other: &Stats) {
+        self.input_chars += other.input_chars;
+        self.mapped_chars += other.mapped_chars;
+        self.passed += other.passed;
+        self.deleted += other.deleted;
+        self.sub_chars += other.sub_chars;
+        self.sub_strs += other.sub_strs;
+        self.default_hits += other.default_hits;
+
+        for (c, n) in other.map_char_hits.iter() {
+            *self.map_char_hits.entry(*c).or_insert(0) += n;
+        }
+
+        for (c, n) in other.default_char_hits.iter() {
+            *self.default_char_hits.entry(*c).or_insert(0) += n;
+        }
+    }
+
+    /// Returns the ratio of mapped characters to input characters, or `None`
+    /// if no characters were recorded.
+    #[inline]
+    pub fn expansion_ratio(&self) -> Option<f64> {
+        if self.input_chars == 0 {
+            None
+        } else {
+            Some(self.mapped_chars as f64 / self.input_chars as f64)
+        }
+    }
+
+    /// Returns the keys of a given [`ActionMap`] that were never hit, or
+    /// `None` if the map cannot list its keys (see [`ActionMap::keys`]).
+    pub fn unhit_keys<'s, M>(
+        &'s self,
+        actions: &'s M,
+    ) -> Option<impl Iterator<Item = char> + 's>
+    where
+        M: ActionMap + ?Sized,
+    {
+        let keys = actions.keys()?;
+
+        Some(keys.filter(|c| !self.map_char_hits.contains_key(c)))
+    }
+}
+
+impl Recorder for &mut Stats {
+    #[inline]
+    fn record_input(&mut self, traced: &TracedChar<'_>) {
+        Stats::record_input(self, traced);
+    }
+
+    #[inline]
+    fn record_output(&mut self, _c: char) {
+        self.mapped_chars += 1;
+    }
+}
+
+impl<'a, M> CharMapper<'a, M>
+where
+    M: ActionMap + ?Sized,
+{
+    /// Returns an iterator that maps characters from a given character
+    /// iterator while recording statistics in `stats`.
+    ///
+    /// The returned iterator outputs the same characters as
+    /// [`CharMapper::map_chars_iter`]. If this [`CharMapper`] normalizes
+    /// text, input characters are counted after input normalization and
+    /// mapped characters before output normalization.
+    #[inline]
+    pub fn map_chars_iter_with_stats<'s, I>(
+        &'a self,
+        text_chars: I,
+        stats: &'s mut Stats,
+    ) -> StatsChars<'a, 's, M, I>
+    where
+        I: Iterator<Item = char>,
+    {
+        let text_chars = self.normalize_chars(text_chars);
+        let mapping = Mapping::new(self, text_chars, stats);
+
+        StatsChars { mapping: self.normalize_chars(mapping) }
+    }
+}
+
+/// Character iterator returned by
+/// [`CharMapper::map_chars_iter_with_stats`](super::CharMapper::map_chars_iter_with_stats).
+pub struct StatsChars<'a, 's, M, I>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+{
+    mapping:
+        MaybeNormalized<Mapping<'a, M, MaybeNormalized<I>, &'s mut Stats>>,
+}
+
+impl<M, I> Iterator for StatsChars<'_, '_, M, I>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.mapping.next()
+    }
+}
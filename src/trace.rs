@@ -0,0 +1,343 @@
+use core::fmt::{self, Write};
+use core::{iter, option, str::Chars};
+
+use super::actionmap::{ActionMap, CharMapAction};
+use super::charmapper::{CharMapper, MaxRepeats, MaybeNormalized};
+
+/// The origin of an action applied by a [`CharMapper`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ActionSource {
+    /// The action was found in the [`ActionMap`].
+    Map,
+    /// The character was not in the [`ActionMap`] so the default action of
+    /// the [`CharMapper`] was applied.
+    Default,
+}
+
+impl fmt::Display for ActionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionSource::Map => f.pad("map"),
+            ActionSource::Default => f.pad("default"),
+        }
+    }
+}
+
+/// A record of how a single input character was mapped.
+#[derive(Clone, Copy)]
+pub struct TracedChar<'a> {
+    /// The position of the character in the input (in characters).
+    pub pos: usize,
+    /// The input character.
+    pub c: char,
+    /// Where the applied action came from.
+    pub source: ActionSource,
+    /// The applied action.
+    pub action: CharMapAction<'a>,
+    // The run of output characters before this character and the limit on
+    // its length, used to apply `CharMapper::max_repeats` to the output.
+    run: Run,
+    max_repeats: MaxRepeats<'a>,
+}
+
+// A run of the same output character.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Run {
+    last_char: Option<char>,
+    repeats: usize,
+}
+
+impl<'a> TracedChar<'a> {
+    /// Returns an iterator over the characters output for the input character.
+    ///
+    /// If the [`CharMapper`] limits repeated characters, output characters
+    /// that exceed the limit (given the output of the previous characters) are
+    /// left out.
+    #[inline]
+    pub fn output(&self) -> TracedOutput<'a> {
+        let (c, s) = match self.action {
+            CharMapAction::Pass => (Some(self.c), ""),
+            CharMapAction::Delete => (None, ""),
+            CharMapAction::SubChar(d) => (Some(d), ""),
+            CharMapAction::SubStr(s) => (None, s),
+        };
+
+        TracedOutput::new(c, s, self.run, self.max_repeats)
+    }
+}
+
+impl fmt::Debug for TracedChar<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TracedChar")
+            .field("pos", &self.pos)
+            .field("c", &self.c)
+            .field("source", &self.source)
+            .field("action", &self.action)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for TracedChar<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pos == other.pos
+            && self.c == other.c
+            && self.source == other.source
+            && self.action == other.action
+            && self.output().eq(other.output())
+    }
+}
+
+impl Eq for TracedChar<'_> {}
+
+/// Iterator returned by [`TracedChar::output`].
+#[derive(Clone)]
+pub struct TracedOutput<'a> {
+    chars: iter::Chain<option::IntoIter<char>, Chars<'a>>,
+    run: Run,
+    max_repeats: MaxRepeats<'a>,
+}
+
+impl<'a> TracedOutput<'a> {
+    // Returns the output of an optional character followed by a string, which
+    // follows a given run of output characters.
+    #[inline]
+    pub(crate) fn new(
+        c: Option<char>,
+        s: &'a str,
+        run: Run,
+        max_repeats: MaxRepeats<'a>,
+    ) -> Self {
+        TracedOutput {
+            chars: c.into_iter().chain(s.chars()),
+            run,
+            max_repeats,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn empty(max_repeats: MaxRepeats<'a>) -> Self {
+        TracedOutput::new(None, "", Run::default(), max_repeats)
+    }
+
+    // Returns the run of output characters so far.
+    #[inline]
+    pub(crate) fn run(&self) -> Run {
+        self.run
+    }
+
+    // Returns whether an output character is within the maximum number of
+    // repeats.
+    #[inline]
+    fn admit(&mut self, c: char) -> bool {
+        if let MaxRepeats::Unlimited = self.max_repeats {
+            return true;
+        }
+
+        if self.run.last_char == Some(c) {
+            self.run.repeats += 1;
+        } else {
+            self.run = Run { last_char: Some(c), repeats: 1 };
+        }
+
+        match self.max_repeats.of(c) {
+            Some(max) => self.run.repeats <= max,
+            None => true,
+        }
+    }
+}
+
+impl Iterator for TracedOutput<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let c = self.chars.next()?;
+
+            if self.admit(c) {
+                return Some(c);
+            }
+        }
+    }
+}
+
+impl<'a, M> CharMapper<'a, M>
+where
+    M: ActionMap + ?Sized,
+{
+    /// Returns an iterator that traces how each character of a given
+    /// character iterator is mapped.
+    ///
+    /// If this [`CharMapper`] normalizes text, the input is normalized before
+    /// tracing, but the output of each [`TracedChar`] is not normalized again.
+    #[inline]
+    pub fn trace_chars_iter<I>(
+        &'a self,
+        text_chars: I,
+    ) -> TracedChars<'a, M, I>
+    where
+        I: Iterator<Item = char>,
+    {
+        TracedChars {
+            charmapper: self,
+            text_chars: self.normalize_chars(text_chars),
+            pos: 0,
+            run: Run::default(),
+        }
+    }
+
+    // Returns the trace of mapping a given character at a given position,
+    // following a given run of output characters.
+    #[inline]
+    pub(crate) fn trace_char(
+        &self,
+        pos: usize,
+        c: char,
+        run: Run,
+    ) -> TracedChar<'a> {
+        let (source, action) = match self.actionmap().map_char(c) {
+            Some(action) => (ActionSource::Map, action),
+            None => (ActionSource::Default, self.default_action()),
+        };
+
+        TracedChar {
+            pos,
+            c,
+            source,
+            action,
+            run,
+            max_repeats: self.max_repeats,
+        }
+    }
+}
+
+/// Iterator returned by
+/// [`CharMapper::trace_chars_iter`](super::CharMapper::trace_chars_iter).
+#[derive(Clone)]
+pub struct TracedChars<'a, M, I>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+{
+    charmapper: &'a CharMapper<'a, M>,
+    text_chars: MaybeNormalized<I>,
+    pos: usize,
+    run: Run,
+}
+
+impl<'a, M, I> Iterator for TracedChars<'a, M, I>
+where
+    M: ActionMap + ?Sized,
+    I: Iterator<Item = char>,
+{
+    type Item = TracedChar<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.text_chars.next()?;
+        let traced = self.charmapper.trace_char(self.pos, c, self.run);
+
+        self.pos += 1;
+        if !matches!(self.charmapper.max_repeats, MaxRepeats::Unlimited) {
+            let mut out_chars = traced.output();
+            out_chars.by_ref().for_each(drop);
+            self.run = out_chars.run();
+        }
+
+        Some(traced)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.text_chars.size_hint()
+    }
+}
+
+/// Renders traced characters as a human-readable table when displayed.
+///
+/// ```rust
+/// use charmap::*;
+///
+/// let actions = [('e', CharMapAction::SubStr("ee")), ('l', CharMapAction::Delete)];
+/// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+///
+/// println!("{}", TraceTable::new(mapper.trace_chars_iter("Hel".chars())));
+/// //   pos | char     | code     | source  | action           | output
+/// // ------+----------+----------+---------+------------------+--------
+/// //     0 | 'H'      | U+0048   | default | Pass             | "H"
+/// //     1 | 'e'      | U+0065   | map     | SubStr("ee")     | "ee"
+/// //     2 | 'l'      | U+006C   | map     | Delete           | ""
+/// ```
+#[derive(Clone, Debug)]
+pub struct TraceTable<T> {
+    traces: T,
+}
+
+impl<'a, T> TraceTable<T>
+where
+    T: IntoIterator<Item = TracedChar<'a>> + Clone,
+{
+    /// Creates a new [`TraceTable`] from a (cloneable) collection of traced
+    /// characters, such as a [`TracedChars`] iterator.
+    #[inline]
+    pub fn new(traces: T) -> Self {
+        TraceTable { traces }
+    }
+}
+
+impl<'a, T> fmt::Display for TraceTable<T>
+where
+    T: IntoIterator<Item = TracedChar<'a>> + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5} | {:<8} | {:<8} | {:<7} | {:<16} | output",
+            "pos", "char", "code", "source", "action"
+        )?;
+        writeln!(f, "------+----------+----------+---------+------------------+--------")?;
+
+        for traced in self.traces.clone() {
+            write!(f, "{:>5} | ", traced.pos)?;
+            write_padded(f, 8, format_args!("{:?}", traced.c))?;
+            f.write_str(" | ")?;
+            write_padded(f, 8, format_args!("U+{:04X}", traced.c as u32))?;
+            write!(f, " | {:<7} | ", traced.source)?;
+            write_padded(f, 16, format_args!("{:?}", traced.action))?;
+            f.write_str(" | \"")?;
+            for c in traced.output() {
+                write!(f, "{}", c.escape_debug())?;
+            }
+            f.write_str("\"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Writes formatted arguments padded with spaces to a given width. This is
+// needed since the Debug implementations of chars and strings ignore padding.
+fn write_padded(
+    f: &mut fmt::Formatter<'_>,
+    width: usize,
+    args: fmt::Arguments<'_>,
+) -> fmt::Result {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    counter.write_fmt(args)?;
+    f.write_fmt(args)?;
+
+    for _ in counter.0..width {
+        f.write_char(' ')?;
+    }
+
+    Ok(())
+}
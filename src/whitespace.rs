@@ -0,0 +1,248 @@
+use core::str::Chars;
+
+/// The set of characters treated as whitespace by a [`WhitespaceNormalizer`].
+#[derive(Clone, Copy, Debug)]
+pub enum Spaces {
+    /// ASCII whitespace (see [`char::is_ascii_whitespace`]).
+    Ascii,
+    /// Unicode whitespace, i.e. characters with the `White_Space` property
+    /// (see [`char::is_whitespace`]).
+    Unicode,
+    /// Characters for which a given function returns `true`.
+    Custom(fn(char) -> bool),
+}
+
+impl Spaces {
+    /// Returns whether a given character is whitespace.
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            Spaces::Ascii => c.is_ascii_whitespace(),
+            Spaces::Unicode => c.is_whitespace(),
+            Spaces::Custom(f) => f(c),
+        }
+    }
+}
+
+/// A stateful whitespace normalization stage.
+///
+/// Unlike [`CharMapper`](super::CharMapper), which maps each character
+/// independently, a [`WhitespaceNormalizer`] operates on runs of whitespace:
+///
+/// * When collapsing, each run of whitespace is replaced by a single space
+///   (`' '`). If newlines are preserved, runs containing line breaks are
+///   replaced by their line breaks instead (e.g. `"a \r\n\n b"` becomes
+///   `"a\r\n\nb"`). Otherwise, whitespace is output as is.
+/// * When trimming, leading and trailing whitespace is removed.
+///
+/// Line breaks are `\n`, `\r`, vertical tab, form feed, U+0085 NEXT LINE,
+/// U+2028 LINE SEPARATOR, and U+2029 PARAGRAPH SEPARATOR, and are only
+/// recognized if they are whitespace according to the configured [`Spaces`].
+///
+/// It can be applied to the output of a [`CharMapper`](super::CharMapper)
+/// using [`NormalizeWhitespaceIter::normalize_whitespace`]:
+///
+/// ```rust
+/// use charmap::*;
+///
+/// let actions = [('_', CharMapAction::SubChar(' '))];
+/// let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+/// let normalizer = WhitespaceNormalizer::new();
+///
+/// let normalized: String = "  snake__case\u{00A0} \tname\n"
+///     .map_chars(&mapper)
+///     .normalize_whitespace(&normalizer)
+///     .collect();
+/// assert_eq!(normalized, "snake case name");
+///
+/// let normalizer = normalizer.preserve_newlines(true);
+/// let normalized: String =
+///     " one  line \n\n two ".normalize_whitespace(&normalizer).collect();
+/// assert_eq!(normalized, "one line\n\ntwo");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WhitespaceNormalizer {
+    spaces: Spaces,
+    collapse: bool,
+    trim: bool,
+    preserve_newlines: bool,
+}
+
+impl WhitespaceNormalizer {
+    /// Creates a new [`WhitespaceNormalizer`] collapsing and trimming Unicode
+    /// whitespace without preserving newlines.
+    #[inline]
+    pub const fn new() -> Self {
+        WhitespaceNormalizer {
+            spaces: Spaces::Unicode,
+            collapse: true,
+            trim: true,
+            preserve_newlines: false,
+        }
+    }
+
+    /// Sets the characters treated as whitespace.
+    #[inline]
+    pub const fn spaces(mut self, spaces: Spaces) -> Self {
+        self.spaces = spaces;
+        self
+    }
+
+    /// Toggles collapsing runs of whitespace.
+    #[inline]
+    pub const fn collapse(mut self, enabled: bool) -> Self {
+        self.collapse = enabled;
+        self
+    }
+
+    /// Toggles trimming leading and trailing whitespace.
+    #[inline]
+    pub const fn trim(mut self, enabled: bool) -> Self {
+        self.trim = enabled;
+        self
+    }
+
+    /// Toggles preserving line breaks when collapsing runs of whitespace.
+    #[inline]
+    pub const fn preserve_newlines(mut self, enabled: bool) -> Self {
+        self.preserve_newlines = enabled;
+        self
+    }
+
+    /// Returns an iterator that normalizes whitespace in a given character
+    /// iterator.
+    #[inline]
+    pub fn normalize_chars_iter<I>(
+        &self,
+        text_chars: I,
+    ) -> NormalizedWhitespace<I>
+    where
+        I: Iterator<Item = char>,
+    {
+        NormalizedWhitespace {
+            normalizer: *self,
+            text_chars,
+            run: String::new(),
+            run_pos: 0,
+            after_run: None,
+            at_start: true,
+        }
+    }
+}
+
+impl Default for WhitespaceNormalizer {
+    #[inline]
+    fn default() -> Self {
+        WhitespaceNormalizer::new()
+    }
+}
+
+#[inline]
+fn is_line_break(c: char) -> bool {
+    matches!(c, '\n'..='\r' | '\u{0085}' | '\u{2028}' | '\u{2029}')
+}
+
+/// Character iterator returned by
+/// [`WhitespaceNormalizer::normalize_chars_iter`] and
+/// [`NormalizeWhitespaceIter::normalize_whitespace`].
+#[derive(Clone, Debug)]
+pub struct NormalizedWhitespace<I>
+where
+    I: Iterator<Item = char>,
+{
+    normalizer: WhitespaceNormalizer,
+    text_chars: I,
+    // The (normalized) whitespace run currently being output, and the byte
+    // position of the next character to output from it.
+    run: String,
+    run_pos: usize,
+    // The character that ended the current whitespace run.
+    after_run: Option<char>,
+    at_start: bool,
+}
+
+impl<I> Iterator for NormalizedWhitespace<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.run[self.run_pos..].chars().next() {
+            self.run_pos += c.len_utf8();
+            return Some(c);
+        }
+
+        if let Some(c) = self.after_run.take() {
+            return Some(c);
+        }
+
+        let c = self.text_chars.next()?;
+        let spaces = self.normalizer.spaces;
+
+        if !spaces.contains(c) {
+            self.at_start = false;
+            return Some(c);
+        }
+
+        // We need to read the whole whitespace run before outputting anything
+        // since it could be trailing whitespace.
+        self.run.clear();
+        self.run_pos = 0;
+        self.run.push(c);
+        self.after_run = loop {
+            match self.text_chars.next() {
+                Some(d) if spaces.contains(d) => self.run.push(d),
+                next => break next,
+            }
+        };
+
+        let trimmed = self.at_start || self.after_run.is_none();
+        self.at_start = false;
+
+        if self.normalizer.trim && trimmed {
+            self.run.clear();
+        } else if self.normalizer.collapse {
+            if self.normalizer.preserve_newlines {
+                self.run.retain(is_line_break);
+            } else {
+                self.run.clear();
+            }
+
+            if self.run.is_empty() {
+                self.run.push(' ');
+            }
+        }
+
+        self.next()
+    }
+}
+
+/// A trait providing a convenience method for [`Iterators`](Iterator) of
+/// [`char`] to normalize their whitespace.
+pub trait NormalizeWhitespaceIter<I: Iterator<Item = char>> {
+    fn normalize_whitespace(
+        self,
+        normalizer: &WhitespaceNormalizer,
+    ) -> NormalizedWhitespace<I>;
+}
+
+impl<'a> NormalizeWhitespaceIter<Chars<'a>> for &'a str {
+    #[inline]
+    fn normalize_whitespace(
+        self,
+        normalizer: &WhitespaceNormalizer,
+    ) -> NormalizedWhitespace<Chars<'a>> {
+        normalizer.normalize_chars_iter(self.chars())
+    }
+}
+
+impl<I: Iterator<Item = char>> NormalizeWhitespaceIter<I> for I {
+    #[inline]
+    fn normalize_whitespace(
+        self,
+        normalizer: &WhitespaceNormalizer,
+    ) -> NormalizedWhitespace<I> {
+        normalizer.normalize_chars_iter(self)
+    }
+}
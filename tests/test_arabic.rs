@@ -0,0 +1,118 @@
+#[cfg(feature = "arabic")]
+mod test {
+    use charmap::maps::arabic::{
+        Normalizer, NORMALIZE_ALEF, NORMALIZE_ALEF_MAKSURA,
+        NORMALIZE_TEH_MARBUTA, REMOVE_COMBINING_HAMZA, REMOVE_DIACRITICS,
+        REMOVE_TATWEEL,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    const INPUT: &str = "أَحْمَدُ وَإِبْرَاهِيمُ فِي ٱلْمَكْتَبَـــةِ عَلَى آخِرِ مَرَّةٍ";
+
+    #[test]
+    fn arabic_individual_rules() {
+        let mapper = CharMapper::new(&NORMALIZE_ALEF, CharMapAction::Pass);
+        let mapped: String = "أ إ آ ٱ ا".map_chars(&mapper).collect();
+        assert_eq!(mapped, "ا ا ا ا ا");
+
+        let mapper =
+            CharMapper::new(&NORMALIZE_ALEF_MAKSURA, CharMapAction::Pass);
+        let mapped: String = "على".map_chars(&mapper).collect();
+        assert_eq!(mapped, "علي");
+
+        let mapper =
+            CharMapper::new(&NORMALIZE_TEH_MARBUTA, CharMapAction::Pass);
+        let mapped: String = "مكتبة".map_chars(&mapper).collect();
+        assert_eq!(mapped, "مكتبه");
+
+        let mapper = CharMapper::new(&REMOVE_TATWEEL, CharMapAction::Pass);
+        let mapped: String = "مكتبـــة".map_chars(&mapper).collect();
+        assert_eq!(mapped, "مكتبة");
+
+        let mapper = CharMapper::new(&REMOVE_DIACRITICS, CharMapAction::Pass);
+        let mapped: String = "مَرَّةٍ عَلَىٰ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "مرة على");
+
+        // Decomposed alef with madda, waw with hamza, and alef with hamza
+        // below keep their marks, as do their precomposed forms.
+        let decomposed = "\u{0627}\u{0653}\u{062E}\u{0650}\u{0631} \
+                          \u{0645}\u{0648}\u{0654}\u{0645}\u{0650}\u{0646} \
+                          \u{0627}\u{0655}\u{0650}\u{0646} \u{0622}\u{0624}";
+        let mapped: String = decomposed.map_chars(&mapper).collect();
+        assert_eq!(
+            mapped,
+            "\u{0627}\u{0653}\u{062E}\u{0631} \
+             \u{0645}\u{0648}\u{0654}\u{0645}\u{0646} \
+             \u{0627}\u{0655}\u{0646} \u{0622}\u{0624}"
+        );
+
+        let mapper =
+            CharMapper::new(&REMOVE_COMBINING_HAMZA, CharMapAction::Pass);
+        let mapped: String = decomposed.map_chars(&mapper).collect();
+        assert_eq!(
+            mapped,
+            "\u{0627}\u{062E}\u{0650}\u{0631} \
+             \u{0645}\u{0648}\u{0645}\u{0650}\u{0646} \
+             \u{0627}\u{0650}\u{0646} \u{0622}\u{0624}"
+        );
+    }
+
+    #[test]
+    fn arabic_normalizer_all() {
+        let mapper = CharMapper::new(&Normalizer::ALL, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, "احمد وابراهيم في المكتبه علي اخر مره");
+
+        let normalizer = Normalizer::default();
+        let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, "احمد وابراهيم في المكتبه علي اخر مره");
+    }
+
+    #[test]
+    fn arabic_normalizer_none() {
+        let mapper = CharMapper::new(&Normalizer::NONE, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, INPUT);
+    }
+
+    #[test]
+    fn arabic_normalizer_toggles() {
+        let normalizer = Normalizer::NONE.diacritics(true).tatweel(true);
+        let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, "أحمد وإبراهيم في ٱلمكتبة على آخر مرة");
+
+        let normalizer = Normalizer::ALL.alef(false).alef_maksura(false);
+        let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, "أحمد وإبراهيم في ٱلمكتبه على آخر مره");
+    }
+
+    #[test]
+    fn arabic_normalizer_combining_hamza() {
+        let decomposed =
+            "\u{0627}\u{0653}\u{062E}\u{0650}\u{0631} \u{0648}\u{0654}";
+
+        let normalizer = Normalizer::default();
+        let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+        let mapped: String = decomposed.map_chars(&mapper).collect();
+        assert_eq!(
+            mapped,
+            "\u{0627}\u{0653}\u{062E}\u{0631} \u{0648}\u{0654}"
+        );
+
+        let mapper = CharMapper::new(&Normalizer::ALL, CharMapAction::Pass);
+        let mapped: String = decomposed.map_chars(&mapper).collect();
+        assert_eq!(mapped, "اخر و");
+    }
+
+    #[test]
+    fn arabic_combined_rules() {
+        let actions =
+            ((&NORMALIZE_ALEF, &NORMALIZE_TEH_MARBUTA), &REMOVE_TATWEEL);
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let mapped: String = "أُسْرَةٌ مُـمْتَـازَةٌ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "اُسْرَهٌ مُمْتَازَهٌ");
+    }
+}
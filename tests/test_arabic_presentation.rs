@@ -0,0 +1,61 @@
+#[cfg(feature = "arabic_presentation")]
+mod test {
+    use charmap::maps::arabic_presentation::DECOMPOSE_PRESENTATION_FORMS;
+    use charmap::{ActionMap, CharMapAction, CharMapper, MapCharsIter};
+
+    fn is_presentation_form(c: char) -> bool {
+        ('\u{FB50}'..='\u{FDFF}').contains(&c)
+            || ('\u{FE70}'..='\u{FEFF}').contains(&c)
+    }
+
+    #[test]
+    fn presentation_forms_decomposed() {
+        let mapper = CharMapper::new(
+            &DECOMPOSE_PRESENTATION_FORMS,
+            CharMapAction::Pass,
+        );
+
+        for (c, action) in DECOMPOSE_PRESENTATION_FORMS {
+            assert!(is_presentation_form(c));
+
+            let mapped: String = c.map_chars(&mapper).collect();
+            assert!(!mapped.is_empty());
+            assert!(!mapped.chars().any(is_presentation_form));
+            assert!(matches!(
+                action,
+                CharMapAction::SubChar(_) | CharMapAction::SubStr(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn presentation_forms_ligatures() {
+        let mapper = CharMapper::new(
+            &DECOMPOSE_PRESENTATION_FORMS,
+            CharMapAction::Pass,
+        );
+        let inputs = [
+            ("ﻻ", "لا"),
+            ("ﻷ", "لأ"),
+            ("ﷲ", "الله"),
+            ("ﷺ", "صلى الله عليه وسلم"),
+            ("ﲅ", "لم"),
+            ("ﳲ", "ـَّ"),
+            ("ﺍﻟﺴﻼﻡ ﻋﻠﻴﻜﻢ", "السلام عليكم"),
+        ];
+
+        for (input, expected) in inputs {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn presentation_forms_unmapped() {
+        // Characters in the presentation form blocks without a compatibility
+        // decomposition, and base letters, are left unmapped.
+        for c in ['\u{FDFD}', '\u{FEFF}', '\u{FD3E}', 'ب', 'a'] {
+            assert_eq!(DECOMPOSE_PRESENTATION_FORMS.map_char(c), None);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#[path = "data/ascii_folding.rs"]
+mod ascii_folding_data;
+
+#[cfg(feature = "ascii_folding")]
+mod test {
+    use super::ascii_folding_data::ASCII_FOLDINGS;
+    use charmap::maps::ascii_folding::ASCII_FOLDING;
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn latin1_accents() {
+        let mapper = CharMapper::new(&ASCII_FOLDING, CharMapAction::Pass);
+
+        let input = "Des mot clés À LA CHAÎNE À Á Â Ã Ä Å Æ Ç È É Ê Ë Ì Í Î \
+                     Ï Ĳ Ð Ñ Ò Ó Ô Õ Ö Ø Œ Þ Ù Ú Û Ü Ý Ÿ à á â ã ä å æ ç è é \
+                     ê ë ì í î ï ĳ ð ñ ò ó ô õ ö ø œ ß þ ù ú û ü ý ÿ ﬁ ﬂ";
+        let expected =
+            "Des mot cles A LA CHAINE A A A A A A AE C E E E E I I \
+                        I I IJ D N O O O O O O OE TH U U U U Y Y a a a a a a \
+                        ae c e e e e i i i i ij d n o o o o o o oe ss th u u \
+                        u u y y fi fl";
+
+        let folded: String = input.map_chars(&mapper).collect();
+        assert_eq!(folded, expected);
+    }
+
+    #[test]
+    fn unfoldable_chars() {
+        let mapper = CharMapper::new(&ASCII_FOLDING, CharMapAction::Pass);
+
+        for s in ["§ ¦ ¤ END", "Hello, world!", "مرحبا", "你好"] {
+            let folded: String = s.map_chars(&mapper).collect();
+            assert_eq!(folded, s);
+        }
+    }
+
+    #[test]
+    fn all_foldings() {
+        let mapper = CharMapper::new(&ASCII_FOLDING, CharMapAction::Pass);
+        let mut n_chars = 0;
+
+        for (chars, folded) in ASCII_FOLDINGS {
+            for c in chars.chars() {
+                let mapped: String =
+                    c.to_string().map_chars(&mapper).collect();
+                assert_eq!(mapped, folded, "{:?} (U+{:04X})", c, c as u32);
+                n_chars += 1;
+            }
+        }
+
+        assert_eq!(n_chars, ASCII_FOLDING.len());
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(feature = "buckwalter")]
+mod test {
+    use charmap::maps::buckwalter::{
+        ARABIC_TO_BW, ARABIC_TO_SAFE_BW, ARABIC_TO_XML_BW, BW_TO_ARABIC,
+        SAFE_BW_TO_ARABIC, XML_BW_TO_ARABIC,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    // All Arabic letters and diacritics covered by the Buckwalter scheme.
+    const ARABIC_INVENTORY: &str = concat!(
+        "ءآأؤإئابةتثجحخدذرزسشصضطظعغـفقكلمنهوىي",
+        "\u{064B}\u{064C}\u{064D}\u{064E}\u{064F}\u{0650}\u{0651}\u{0652}",
+        "\u{0670}ٱپچڤگ",
+    );
+
+    const BW_INVENTORY: &str =
+        "'|>&<}AbptvjHxd*rzs$SDTZEg_fqklmnhwYyFNKaui~o`{PJVG";
+
+    const SAFE_BW_INVENTORY: &str =
+        "CMOWIQAbptvjHxdVrzscSDTZEg_fqklmnhwYyFNKaui~oeLPJBG";
+
+    const XML_BW_INVENTORY: &str =
+        "'|OWI}AbptvjHxd*rzs$SDTZEg_fqklmnhwYyFNKaui~o`{PJVG";
+
+    #[test]
+    fn buckwalter_inventory() {
+        let to_bw = CharMapper::new(&ARABIC_TO_BW, CharMapAction::Pass);
+        let to_safe_bw =
+            CharMapper::new(&ARABIC_TO_SAFE_BW, CharMapAction::Pass);
+        let to_xml_bw =
+            CharMapper::new(&ARABIC_TO_XML_BW, CharMapAction::Pass);
+
+        let mapped: String = ARABIC_INVENTORY.map_chars(&to_bw).collect();
+        assert_eq!(mapped, BW_INVENTORY);
+
+        let mapped: String = ARABIC_INVENTORY.map_chars(&to_safe_bw).collect();
+        assert_eq!(mapped, SAFE_BW_INVENTORY);
+
+        let mapped: String = ARABIC_INVENTORY.map_chars(&to_xml_bw).collect();
+        assert_eq!(mapped, XML_BW_INVENTORY);
+    }
+
+    #[test]
+    fn buckwalter_round_trip() {
+        let tables = [
+            (&ARABIC_TO_BW, &BW_TO_ARABIC),
+            (&ARABIC_TO_SAFE_BW, &SAFE_BW_TO_ARABIC),
+            (&ARABIC_TO_XML_BW, &XML_BW_TO_ARABIC),
+        ];
+
+        for (forward, backward) in tables {
+            let to_bw = CharMapper::new(forward, CharMapAction::Pass);
+            let from_bw = CharMapper::new(backward, CharMapAction::Pass);
+
+            assert_eq!(forward.len(), backward.len());
+
+            let mapped: String = ARABIC_INVENTORY.map_chars(&to_bw).collect();
+            assert_eq!(
+                mapped.chars().count(),
+                ARABIC_INVENTORY.chars().count()
+            );
+
+            let unmapped: String = mapped.map_chars(&from_bw).collect();
+            assert_eq!(unmapped, ARABIC_INVENTORY);
+        }
+    }
+
+    #[test]
+    fn buckwalter_sentence() {
+        let to_bw = CharMapper::new(&ARABIC_TO_BW, CharMapAction::Pass);
+        let to_safe_bw =
+            CharMapper::new(&ARABIC_TO_SAFE_BW, CharMapAction::Pass);
+        let from_bw = CharMapper::new(&BW_TO_ARABIC, CharMapAction::Pass);
+        let input = "ذَهَبَ الطَّالِبُ إِلَى المَدْرَسَةِ.";
+
+        let mapped: String = input.map_chars(&to_bw).collect();
+        assert_eq!(mapped, "*ahaba AlTa~Alibu <ilaY Almadorasapi.");
+
+        let mapped: String = input.map_chars(&to_safe_bw).collect();
+        assert_eq!(mapped, "Vahaba AlTa~Alibu IilaY Almadorasapi.");
+
+        let unmapped: String = "*ahaba AlTa~Alibu <ilaY Almadorasapi."
+            .map_chars(&from_bw)
+            .collect();
+        assert_eq!(unmapped, input);
+    }
+}
@@ -0,0 +1,243 @@
+mod data;
+
+#[cfg(feature = "std")]
+mod test {
+    use super::data::{
+        TEST_CHARS_DELETE, TEST_CHARS_PASS, TEST_CHARS_SUB_X, TEST_MAPPING,
+        TEST_STRINGS_DELETE, TEST_STRINGS_PASS, TEST_STRINGS_SUB_X,
+    };
+    use charmap::{
+        ActionMap, CharMap, CharMapAction, CharMapBuilder, ConflictError,
+        MapCharsIter,
+    };
+
+    fn test_charmap(default: CharMapAction<'static>) -> CharMap<'static> {
+        TEST_MAPPING
+            .iter()
+            .fold(CharMapBuilder::new(), |b, (c, action)| {
+                b.action(*c, *action)
+            })
+            .default(default)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn builder_default_pass() {
+        let charmap = test_charmap(CharMapAction::Pass);
+        let mapper = charmap.mapper();
+
+        for (input, expected) in TEST_STRINGS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn builder_default_delete() {
+        let charmap = test_charmap(CharMapAction::Delete);
+        let mapper = charmap.mapper();
+
+        for (input, expected) in TEST_STRINGS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn builder_default_sub_x() {
+        let charmap = test_charmap(CharMapAction::SubStr("x"));
+        let mapper = charmap.mapper();
+
+        for (input, expected) in TEST_STRINGS_SUB_X {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_SUB_X {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn builder_sparse_chars() {
+        let charmap = CharMapBuilder::new()
+            .sub_char('a', 'A')
+            .sub_str('ß', "ss")
+            .delete(['\u{200B}', '\u{FEFF}'])
+            .sub_char('😀', ':')
+            .build()
+            .unwrap();
+
+        assert_eq!(charmap.map_char('a'), Some(CharMapAction::SubChar('A')));
+        assert_eq!(charmap.map_char('ß'), Some(CharMapAction::SubStr("ss")));
+        assert_eq!(charmap.map_char('\u{FEFF}'), Some(CharMapAction::Delete));
+        assert_eq!(charmap.map_char('😀'), Some(CharMapAction::SubChar(':')));
+        assert_eq!(charmap.map_char('b'), None);
+
+        let mapped: String =
+            "a\u{200B}ß😀\u{FEFF}b".map_chars(&charmap.mapper()).collect();
+        assert_eq!(mapped, "Ass:b");
+    }
+
+    #[test]
+    fn builder_wide_ranges() {
+        let charmap = CharMapBuilder::new()
+            .delete_range('\u{0000}'..='\u{001F}')
+            .pass_range('a'..='z')
+            .pass_range('\u{0600}'..='\u{06FF}')
+            .delete_range('\u{1F600}'..='\u{1F64F}')
+            .default(CharMapAction::SubChar('?'))
+            .build()
+            .unwrap();
+
+        assert_eq!(charmap.map_char('\u{0000}'), Some(CharMapAction::Delete));
+        assert_eq!(charmap.map_char('\u{001F}'), Some(CharMapAction::Delete));
+        assert_eq!(charmap.map_char(' '), None);
+        assert_eq!(charmap.map_char('\u{0650}'), Some(CharMapAction::Pass));
+        assert_eq!(charmap.map_char('\u{0700}'), None);
+        assert_eq!(charmap.map_char('\u{1F600}'), Some(CharMapAction::Delete));
+        assert_eq!(charmap.map_char('\u{1F650}'), None);
+
+        let mapped: String =
+            "ab\tc D\u{0628}\u{1F600}".map_chars(&charmap.mapper()).collect();
+        assert_eq!(mapped, "abc??\u{0628}");
+    }
+
+    #[test]
+    fn builder_matching() {
+        let charmap = CharMapBuilder::new()
+            .pass_matching(char::is_alphanumeric)
+            .sub_char(' ', '_')
+            .default(CharMapAction::Delete)
+            .build()
+            .unwrap();
+
+        let mapped: String =
+            "Héllo, wörld! ١٢٣".map_chars(&charmap.mapper()).collect();
+        assert_eq!(mapped, "Héllo_wörld_١٢٣");
+    }
+
+    #[test]
+    fn builder_empty() {
+        let charmap = CharMapBuilder::new().build().unwrap();
+
+        assert_eq!(charmap.map_char('a'), None);
+        assert_eq!(charmap.default_action(), CharMapAction::Pass);
+    }
+
+    #[test]
+    fn builder_overlap_same_action() {
+        let charmap = CharMapBuilder::new()
+            .delete_range('a'..='m')
+            .delete_range('g'..='z')
+            .delete(['k'])
+            .build()
+            .unwrap();
+
+        let mapped: String =
+            "abc XYZ xyz".map_chars(&charmap.mapper()).collect();
+        assert_eq!(mapped, " XYZ ");
+    }
+
+    #[test]
+    fn builder_conflict() {
+        let err = CharMapBuilder::new()
+            .delete_range('a'..='z')
+            .sub_char('q', 'Q')
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            ConflictError {
+                c: 'q',
+                first: CharMapAction::Delete,
+                second: CharMapAction::SubChar('Q'),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "conflicting actions for 'q' (U+0071): Delete and SubChar('Q')"
+        );
+    }
+
+    #[test]
+    fn builder_conflict_merged() {
+        let err = CharMapBuilder::new()
+            .pass_range('b'..='d')
+            .delete(['c'])
+            .pass_range('a'..='b')
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.c, 'c');
+        assert_eq!(err.first, CharMapAction::Pass);
+        assert_eq!(err.second, CharMapAction::Delete);
+    }
+
+    #[test]
+    fn builder_conflict_matching() {
+        let err = CharMapBuilder::new()
+            .sub_str('\u{00A0}', " ")
+            .delete_matching(char::is_whitespace)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.c, '\u{00A0}');
+        assert_eq!(err.first, CharMapAction::SubStr(" "));
+        assert_eq!(err.second, CharMapAction::Delete);
+    }
+
+    #[cfg(feature = "general_category")]
+    #[test]
+    fn builder_category() {
+        use charmap::{GeneralCategory, GeneralCategoryGroup};
+
+        let charmap = CharMapBuilder::new()
+            .pass_category_group(GeneralCategoryGroup::Letter)
+            .pass_category(GeneralCategory::DecimalNumber)
+            .delete_category_group(GeneralCategoryGroup::Mark)
+            .action_category(
+                GeneralCategory::SpaceSeparator,
+                CharMapAction::SubChar('_'),
+            )
+            .default(CharMapAction::Delete)
+            .build()
+            .unwrap();
+
+        // Letter numbers (e.g. Ⅻ) and other numbers (e.g. ²) are not decimal
+        // numbers.
+        let mapped: String = "Hé\u{0301}llo, wörld! ١٢٣ Ⅻ²\u{00A0}ǅ"
+            .map_chars(&charmap.mapper())
+            .collect();
+        assert_eq!(mapped, "Héllo_wörld_١٢٣__ǅ");
+    }
+
+    #[cfg(feature = "general_category")]
+    #[test]
+    fn builder_conflict_category() {
+        use charmap::GeneralCategory;
+
+        let err = CharMapBuilder::new()
+            .sub_str('\u{00A0}', " ")
+            .delete_category(GeneralCategory::SpaceSeparator)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.c, '\u{00A0}');
+        assert_eq!(err.first, CharMapAction::SubStr(" "));
+        assert_eq!(err.second, CharMapAction::Delete);
+    }
+}
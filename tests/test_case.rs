@@ -0,0 +1,101 @@
+#[cfg(feature = "case")]
+mod test {
+    use charmap::maps::case::{
+        Case, CaseMapper, CASE_FOLD, LOWERCASE, TITLECASE, TURKIC_LOWERCASE,
+        UPPERCASE,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn case_maps() {
+        let cases = [
+            (&LOWERCASE[..], "ÀÉÎ ΑΒΓ АБВ İ Ǆǅ", "àéî αβγ абв i\u{0307} ǆǆ"),
+            (
+                &UPPERCASE,
+                "straße ﬁx ŉ ΐ ǆǅ",
+                "STRASSE FIX ʼN \u{0399}\u{0308}\u{0301} ǄǄ",
+            ),
+            (&TITLECASE, "ß ﬁ ǆ Ǆ a", "Ss Fi ǅ ǅ A"),
+            (&CASE_FOLD, "Straße ΣΑΣ ς ẞ", "strasse σασ σ ss"),
+        ];
+
+        for (actions, input, expected) in cases {
+            let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        let actions = (&TURKIC_LOWERCASE, &LOWERCASE);
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let mapped: String = "IĞDIR İZMİR".map_chars(&mapper).collect();
+        assert_eq!(mapped, "ığdır izmir");
+    }
+
+    #[test]
+    fn case_mapper_matches_std() {
+        let input = "Hello, Wörld! Straße ǅ İstanbul ΑΘΗΝΑ Москва ﬁ";
+        let upper = CaseMapper::new(Case::Upper);
+        let lower = CaseMapper::new(Case::Lower);
+
+        let mapper = CharMapper::new(&upper, CharMapAction::Pass);
+        let mapped: String = input.map_chars(&mapper).collect();
+        assert_eq!(mapped, input.to_uppercase());
+
+        let mapped: String = upper.case_chars(input.chars()).collect();
+        assert_eq!(mapped, input.to_uppercase());
+
+        let mapped: String = lower.case_chars(input.chars()).collect();
+        assert_eq!(mapped, input.to_lowercase());
+    }
+
+    #[test]
+    fn case_mapper_turkic() {
+        let cases = [
+            (Case::Lower, "KIRIKKALE İĞNE I\u{0307}", "kırıkkale iğne i"),
+            (Case::Upper, "istanbul ılık", "İSTANBUL ILIK"),
+            (Case::Title, "istanbul ırmak", "İstanbul Irmak"),
+            (Case::Fold, "DİYARBAKIR", "diyarbakır"),
+        ];
+
+        for (case, input, expected) in cases {
+            let mapper = CaseMapper::new(case).turkic(true);
+            let mapped: String = mapper.case_chars(input.chars()).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        let mapper = CaseMapper::new(Case::Lower);
+        let mapped: String = mapper.case_chars("I\u{0307}I".chars()).collect();
+        assert_eq!(mapped, "i\u{0307}i");
+    }
+
+    #[test]
+    fn case_mapper_context() {
+        let cases = [
+            (
+                Case::Lower,
+                "ΟΔΟΣ ΟΔΟΣ. ΣΑΣ Σ Α'Σ' ΑΣ'Α",
+                "οδος οδος. σας σ α'ς' ασ'α",
+            ),
+            (Case::Title, "the QUICK brown ΟΔΟΣ", "The Quick Brown Οδος"),
+            (
+                Case::Title,
+                "they're o'neil 1st ǆemal",
+                "They're O'neil 1st ǅemal",
+            ),
+            (Case::Title, "ﬁsh ßa", "Fish Ssa"),
+            (Case::Fold, "ΟΔΟΣ", "οδοσ"),
+        ];
+
+        for (case, input, expected) in cases {
+            let mapper = CaseMapper::new(case);
+            let mapped: String = mapper.case_chars(input.chars()).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        // Without context, every character is titlecased.
+        let title = CaseMapper::new(Case::Title);
+        let mapper = CharMapper::new(&title, CharMapAction::Pass);
+        let mapped: String = "the ǆ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "THE ǅ");
+    }
+}
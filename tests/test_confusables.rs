@@ -0,0 +1,59 @@
+#[cfg(feature = "confusables")]
+mod test {
+    use charmap::maps::confusables::{is_confusable, skeleton, CONFUSABLES};
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn confusables_map() {
+        let mapper = CharMapper::new(&CONFUSABLES, CharMapAction::Pass);
+
+        // Cyrillic, Greek, and mathematical letters.
+        let mapped: String = "а ο 𝐀 𝟏 ℌ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "a o A l H");
+
+        // Multi-character prototypes.
+        let mapped: String = "ǆ ﬀ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "dž ff");
+
+        // Prototypes are stable.
+        for (_, action) in CONFUSABLES {
+            let prototype: String = match action {
+                CharMapAction::SubChar(c) => c.to_string(),
+                CharMapAction::SubStr(s) => s.to_string(),
+                _ => panic!("{:?}", action),
+            };
+            let mapped: String =
+                prototype.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, prototype);
+        }
+    }
+
+    #[test]
+    fn skeletons() {
+        let cases = [
+            ("gооglе", "google"),
+            ("раураӏ", "paypai"),
+            ("ΡΑΥΡΑΙ", "PAYPAl"),
+            ("𝐠𝐨𝐨𝐠𝐥𝐞", "google"),
+            ("modern", "rnodern"),
+            ("", ""),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(skeleton(input), expected);
+        }
+
+        // Precomposed and decomposed characters have the same skeleton.
+        assert_eq!(skeleton("caf\u{E9}"), skeleton("cafe\u{301}"));
+    }
+
+    #[test]
+    fn confusable_strings() {
+        assert!(is_confusable("gооglе", "google"));
+        assert!(is_confusable("ѕсоре", "scope"));
+        assert!(is_confusable("I0O", "l00"));
+        assert!(is_confusable("rn", "m"));
+        assert!(!is_confusable("paypal", "paypa1l"));
+        assert!(!is_confusable("apple", "appIe1"));
+    }
+}
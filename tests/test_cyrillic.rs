@@ -0,0 +1,161 @@
+#[cfg(feature = "cyrillic")]
+mod test {
+    use charmap::maps::cyrillic::{
+        Language, Romanizer, Scheme, BGN_PCGN_BULGARIAN, BGN_PCGN_RUSSIAN,
+        BGN_PCGN_UKRAINIAN, GOST_7_79_BULGARIAN, GOST_7_79_RUSSIAN,
+        GOST_7_79_UKRAINIAN, ISO_9,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    fn romanize(scheme: Scheme, language: Language, text: &str) -> String {
+        Romanizer::new(scheme, language).romanize_chars(text.chars()).collect()
+    }
+
+    #[test]
+    fn iso_9() {
+        let mapper = CharMapper::new(&ISO_9, CharMapAction::Pass);
+
+        let mapped: String = "Щёлково, Жуковский".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Ŝëlkovo, Žukovskij");
+
+        let mapped: String = "Київ, Ґалаґан".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Kiïv, G\u{300}alag\u{300}an");
+
+        let mapped: String = "Љубљана, Ђаковица".map_chars(&mapper).collect();
+        assert_eq!(mapped, "L\u{302}ubl\u{302}ana, Đakovica");
+
+        // ISO 9 is language independent.
+        for language in
+            [Language::Russian, Language::Ukrainian, Language::Bulgarian]
+        {
+            assert_eq!(
+                romanize(Scheme::Iso9, language, "Объём ЩЁЛКОВО"),
+                "Obʺëm ŜËLKOVO"
+            );
+        }
+    }
+
+    #[test]
+    fn gost_7_79() {
+        assert_eq!(
+            romanize(Scheme::Gost779, Language::Russian, "Щёлково, Цимлянск"),
+            "Shhyolkovo, Cimlyansk"
+        );
+        assert_eq!(
+            romanize(Scheme::Gost779, Language::Russian, "Царицын, цыплёнок"),
+            "Czaricy'n, cy'plyonok"
+        );
+        assert_eq!(
+            romanize(Scheme::Gost779, Language::Russian, "объект, Эльбрус"),
+            "ob``ekt, E`l`brus"
+        );
+        assert_eq!(
+            romanize(Scheme::Gost779, Language::Ukrainian, "Київ, Гнівань"),
+            "Ky'yiv, Gnivan`"
+        );
+        assert_eq!(
+            romanize(Scheme::Gost779, Language::Ukrainian, "м’ята, Ґудзь"),
+            "m'yata, G`udz`"
+        );
+        assert_eq!(
+            romanize(Scheme::Gost779, Language::Bulgarian, "Щастие, България"),
+            "Sthastie, Ba`lgariya"
+        );
+    }
+
+    #[test]
+    fn bgn_pcgn_russian() {
+        let cases = [
+            ("Елена", "Yelena"),
+            ("Ёлкино", "Yëlkino"),
+            ("Белёв", "Belëv"),
+            ("Заельцовский", "Zayel’tsovskiy"),
+            ("подъезд", "pod”yezd"),
+            ("Воробьёво", "Vorob’yëvo"),
+            ("Хабаровск", "Khabarovsk"),
+            ("ЩЕРБАКОВ", "SHCHERBAKOV"),
+            ("Щербаков", "Shcherbakov"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                romanize(Scheme::BgnPcgn, Language::Russian, input),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn bgn_pcgn_ukrainian() {
+        let cases = [
+            ("Київ", "Kyiv"),
+            ("Їжакевич", "Yizhakevych"),
+            ("Йосипівка", "Yosypivka"),
+            ("Стрий", "Stryi"),
+            ("Юрій", "Yurii"),
+            ("Знам’янка", "Znamianka"),
+            ("Згурівка", "Zghurivka"),
+            ("Україна", "Ukraina"),
+            ("Харків", "Kharkiv"),
+            ("Львів", "Lviv"),
+            ("ЗГУРІВКА", "ZGHURIVKA"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                romanize(Scheme::BgnPcgn, Language::Ukrainian, input),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn bgn_pcgn_bulgarian() {
+        let cases = [
+            ("София", "Sofia"),
+            ("Благоевград", "Blagoevgrad"),
+            ("Шумен", "Shumen"),
+            ("Щастие", "Shtastie"),
+            ("Търговище", "Targovishte"),
+            ("Ямбол", "Yambol"),
+            ("Кияк", "Kiyak"),
+            ("БЪЛГАРИЯ", "BALGARIA"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                romanize(Scheme::BgnPcgn, Language::Bulgarian, input),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn context_free_maps() {
+        let romanizer = Romanizer::new(Scheme::Gost779, Language::Bulgarian);
+        assert_eq!(romanizer.table(), &GOST_7_79_BULGARIAN[..]);
+
+        // Used as an ActionMap, a Romanizer ignores context-dependent rules.
+        let mapper = CharMapper::new(&romanizer, CharMapAction::Pass);
+        let mapped: String = "Цигулка".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Czigulka");
+
+        let tables: [&[(char, CharMapAction)]; 7] = [
+            &ISO_9,
+            &GOST_7_79_RUSSIAN,
+            &GOST_7_79_UKRAINIAN,
+            &GOST_7_79_BULGARIAN,
+            &BGN_PCGN_RUSSIAN,
+            &BGN_PCGN_UKRAINIAN,
+            &BGN_PCGN_BULGARIAN,
+        ];
+
+        // All letters of the basic Russian alphabet are covered by every
+        // Russian map.
+        for table in [tables[0], tables[1], tables[4]] {
+            for c in ('А'..='я').chain(['Ё', 'ё']) {
+                assert!(table.binary_search_by_key(&c, |(k, _)| *k).is_ok());
+            }
+        }
+    }
+}
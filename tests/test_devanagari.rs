@@ -0,0 +1,127 @@
+#[cfg(feature = "devanagari")]
+mod test {
+    use charmap::maps::devanagari::{
+        Scheme, TransliterateChars, Transliterator,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    const IAST_CASES: [(&str, &str); 10] = [
+        ("संस्कृतम्", "saṃskṛtam"),
+        ("नमस्ते", "namaste"),
+        ("भगवद्गीता", "bhagavadgītā"),
+        ("महाभारत", "mahābhārata"),
+        ("ऋग्वेद", "ṛgveda"),
+        ("कृष्ण", "kṛṣṇa"),
+        ("ज्ञान", "jñāna"),
+        ("ऐश्वर्य", "aiśvarya"),
+        ("औषधि", "auṣadhi"),
+        ("दुःख", "duḥkha"),
+    ];
+
+    const ISO_15919_CASES: [(&str, &str); 8] = [
+        ("संस्कृतम्", "saṁskr̥tam"),
+        ("हिन्दी", "hindī"),
+        ("देवनागरी", "dēvanāgarī"),
+        ("मोक्ष", "mōkṣa"),
+        ("ज़िंदगी", "ziṁdagī"),
+        ("पढ़ना", "paṛhanā"),
+        // Separators for ambiguous sequences.
+        ("अइ", "a:i"),
+        ("क्ह", "k:ha"),
+    ];
+
+    #[test]
+    fn devanagari_to_iast() {
+        let to_iast = Transliterator::to_latin(Scheme::Iast);
+
+        for (devanagari, iast) in IAST_CASES {
+            let mapped: String = devanagari.transliterate(&to_iast).collect();
+            assert_eq!(mapped, iast);
+        }
+
+        let mapped: String =
+            "ॐ नमः शिवाय। १२३".transliterate(&to_iast).collect();
+        assert_eq!(mapped, "oṃ namaḥ śivāya. 123");
+    }
+
+    #[test]
+    fn iast_to_devanagari() {
+        let from_iast = Transliterator::from_latin(Scheme::Iast);
+
+        for (devanagari, iast) in IAST_CASES {
+            let mapped: String = iast.transliterate(&from_iast).collect();
+            assert_eq!(mapped, devanagari);
+        }
+
+        // Input may be in any case, and unknown characters are kept.
+        let mapped: String =
+            "Kṛṣṇa, Arjuna!".transliterate(&from_iast).collect();
+        assert_eq!(mapped, "कृष्ण, अर्जुन!");
+    }
+
+    #[test]
+    fn iso_15919_roundtrip() {
+        let to_iso = Transliterator::to_latin(Scheme::Iso15919);
+        let from_iso = Transliterator::from_latin(Scheme::Iso15919);
+
+        for (devanagari, iso) in ISO_15919_CASES {
+            let mapped: String = devanagari.transliterate(&to_iso).collect();
+            assert_eq!(mapped, iso);
+
+            let mapped: String = iso.transliterate(&from_iso).collect();
+            assert_eq!(mapped, devanagari);
+        }
+
+        // Precomposed nukta consonants are transliterated as well, but are
+        // always output decomposed.
+        let mapped: String = "\u{95B}\u{940}".transliterate(&to_iso).collect();
+        assert_eq!(mapped, "zī");
+
+        let mapped: String = mapped.transliterate(&from_iso).collect();
+        assert_eq!(mapped, "\u{91C}\u{93C}\u{940}");
+
+        // Colons that do not separate letters are kept.
+        let mapped: String = "nōṭa: dēkhō".transliterate(&from_iso).collect();
+        assert_eq!(mapped, "नोट: देखो");
+    }
+
+    #[test]
+    fn single_chars() {
+        let to_iast = Transliterator::to_latin(Scheme::Iast);
+        let from_iast = Transliterator::from_latin(Scheme::Iast);
+
+        assert_eq!('क'.transliterate(&to_iast).collect::<String>(), "ka");
+        assert_eq!('k'.transliterate(&from_iast).collect::<String>(), "क्");
+        assert_eq!("ā".transliterate(&from_iast).collect::<String>(), "आ");
+    }
+
+    #[test]
+    fn pipeline() {
+        let to_iast = Transliterator::to_latin(Scheme::Iast);
+        let from_iast = Transliterator::from_latin(Scheme::Iast);
+
+        // Zero-width non-joiners are removed before transliterating.
+        let mapper = CharMapper::new(
+            &[('\u{200C}', CharMapAction::Delete)],
+            CharMapAction::Pass,
+        );
+        let mapped: String = "नम\u{200C}स्ते"
+            .map_chars(&mapper)
+            .transliterate(&to_iast)
+            .collect();
+        assert_eq!(mapped, "namaste");
+
+        // Transliterated output can be mapped further.
+        let mapper = CharMapper::new(
+            &[('ā', CharMapAction::SubChar('a'))],
+            CharMapAction::Pass,
+        );
+        let mapped: String =
+            "महाभारत".transliterate(&to_iast).map_chars(&mapper).collect();
+        assert_eq!(mapped, "mahabharata");
+
+        let mapped: String =
+            "नमस्ते".transliterate(&to_iast).transliterate(&from_iast).collect();
+        assert_eq!(mapped, "नमस्ते");
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(feature = "diacritics")]
+mod test {
+    use charmap::maps::diacritics::{is_diacritic, Stripper};
+    use charmap::{
+        CharMapAction, CharMapper, MapCharsIter, NormalForm,
+        NormalizeCharsIter,
+    };
+
+    const CASES: [(&str, &str); 9] = [
+        ("àáâãäåāăą çćĉċč ñ ÿ", "aaaaaaaaa ccccc n y"),
+        ("Ærøskøbing Łódź Đà Nẵng", "Ærøskøbing Łodz Đa Nang"),
+        ("Ἀθῆναι ᾄδω Ελληνικά ΐ", "Αθηναι αδω Ελληνικα ι"),
+        ("ёлка Йошкар-Ола ї ў", "елка Иошкар-Ола і у"),
+        ("Tiếng Việt ở ữ ặ", "Tieng Viet o u a"),
+        ("ḍ ṣ ṁ ś ā", "d s m s a"),
+        ("क़ ग़ が ぱ", "क़ ग़ が ぱ"),
+        ("שָׁלוֹם", "שלום"),
+        // Vowel signs and tone marks of abugidas are not diacritics.
+        ("สุข ไม่ བོད ພໍ່", "สุข ไม่ བོད ພໍ່"),
+    ];
+
+    #[test]
+    fn diacritics_strip_composed() {
+        let stripper = Stripper::new();
+        let mapper = CharMapper::new(&stripper, CharMapAction::Pass);
+
+        for (input, expected) in CASES {
+            let stripped: String =
+                input.normalize(NormalForm::Nfc).map_chars(&mapper).collect();
+            assert_eq!(stripped, nfc(expected));
+        }
+    }
+
+    #[test]
+    fn diacritics_strip_decomposed() {
+        let stripper = Stripper::default();
+        let mapper = CharMapper::new(&stripper, CharMapAction::Pass);
+
+        for (input, expected) in CASES {
+            let stripped: String = input
+                .normalize(NormalForm::Nfd)
+                .map_chars(&mapper)
+                .normalize(NormalForm::Nfc)
+                .collect();
+            assert_eq!(stripped, nfc(expected));
+        }
+    }
+
+    #[test]
+    fn diacritics_keep() {
+        let cases = [
+            (&['\u{0306}'][..], "Йошкар-Ола ёлка", "Йошкар-Ола елка"),
+            (
+                &['\u{0302}', '\u{0306}', '\u{031B}'],
+                "Việt Nẵng ở ữ",
+                "Viêt Năng ơ ư",
+            ),
+            (&['\u{0301}', '\u{0323}'], "ệ ấ ặ", "ẹ á ạ"),
+            (&['\u{0308}'], "ä ǘ ΐ", "ä ü ϊ"),
+            (&['\u{0345}'], "ᾄ ῷ", "ᾳ ῳ"),
+        ];
+
+        for (keep, input, expected) in cases {
+            let stripper = Stripper::new().keep(keep);
+            assert_eq!(stripper.kept(), keep);
+
+            let mapper = CharMapper::new(&stripper, CharMapAction::Pass);
+
+            let stripped: String = input.map_chars(&mapper).collect();
+            assert_eq!(stripped, expected);
+
+            let stripped: String = input
+                .normalize(NormalForm::Nfd)
+                .map_chars(&mapper)
+                .normalize(NormalForm::Nfc)
+                .collect();
+            assert_eq!(stripped, expected);
+        }
+    }
+
+    #[test]
+    fn diacritics_is_diacritic() {
+        for c in ['\u{0300}', '\u{0308}', '\u{0323}', '\u{0345}', '\u{05B8}'] {
+            assert!(is_diacritic(c));
+        }
+
+        for c in ['a', 'ä', '\u{093C}', '\u{094D}', '\u{3099}', '\u{0903}'] {
+            assert!(!is_diacritic(c));
+        }
+
+        // Thai sara u and mai ek, Lao mai ek, Tibetan vowel sign o, and
+        // Telugu length mark.
+        for c in ['\u{0E38}', '\u{0E48}', '\u{0EC8}', '\u{0F7C}', '\u{0C56}'] {
+            assert!(!is_diacritic(c));
+        }
+    }
+
+    // Some expected strings are not in NFC (e.g. क़ is excluded from
+    // composition).
+    fn nfc(s: &str) -> String {
+        s.normalize(NormalForm::Nfc).collect()
+    }
+}
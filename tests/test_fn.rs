@@ -0,0 +1,110 @@
+mod data;
+
+mod test {
+    use super::data::{
+        TEST_CHARS_DELETE, TEST_CHARS_PASS, TEST_CHARS_SUB_X, TEST_MAPPING,
+        TEST_STRINGS_DELETE, TEST_STRINGS_PASS, TEST_STRINGS_SUB_X,
+    };
+    use charmap::{CharMapAction, CharMapper, FnMap, MapCharsIter};
+
+    fn fn_charmap(c: char) -> Option<CharMapAction<'static>> {
+        TEST_MAPPING.iter().find(|(k, _)| *k == c).map(|(_, action)| *action)
+    }
+
+    #[test]
+    fn fn_default_pass() {
+        let mapper = CharMapper::new(&FnMap(fn_charmap), CharMapAction::Pass);
+
+        for (input, expected) in TEST_STRINGS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn fn_default_delete() {
+        let mapper =
+            CharMapper::new(&FnMap(fn_charmap), CharMapAction::Delete);
+
+        for (input, expected) in TEST_STRINGS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn fn_default_sub_x() {
+        let mapper =
+            CharMapper::new(&FnMap(fn_charmap), CharMapAction::SubStr("x"));
+
+        for (input, expected) in TEST_STRINGS_SUB_X {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_SUB_X {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn closure_default_pass() {
+        let closure = FnMap(|c: char| match c {
+            'd' => Some(CharMapAction::SubChar('m')),
+            'e' => Some(CharMapAction::SubStr("eeee")),
+            'l' => Some(CharMapAction::Delete),
+            'o' => Some(CharMapAction::Pass),
+            _ => None,
+        });
+        let mapper = CharMapper::new(&closure, CharMapAction::Pass);
+
+        for (input, expected) in TEST_STRINGS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn closure_capturing_default_pass() {
+        let digit_sub = "#";
+        let closure = FnMap(move |c: char| {
+            if c.is_ascii_digit() {
+                Some(CharMapAction::SubStr(digit_sub))
+            } else {
+                None
+            }
+        });
+        let mapper = CharMapper::new(&closure, CharMapAction::Pass);
+
+        let mapped: String = "a1b22c".map_chars(&mapper).collect();
+        assert_eq!(mapped, "a#b##c");
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "graphemes")]
+mod test {
+    use charmap::{
+        CharMapAction, CharMapper, ClusterActionMap, MapCharsIter, MarkPolicy,
+    };
+
+    const ACTIONS: [(char, CharMapAction); 5] = [
+        ('\r', CharMapAction::Delete),
+        ('a', CharMapAction::Delete),
+        ('e', CharMapAction::SubChar('E')),
+        ('o', CharMapAction::SubStr("oo")),
+        ('\u{0308}', CharMapAction::SubChar('\u{0307}')),
+    ];
+
+    const INPUT: &str = "a\u{0308} e\u{0301}\u{0323} o\u{0308} u\u{0308} \r\n";
+
+    #[test]
+    fn graphemes_policies() {
+        let mapper = CharMapper::new(&ACTIONS, CharMapAction::Pass);
+        let cases = [
+            (
+                MarkPolicy::Keep,
+                "\u{0307} E\u{0301}\u{0323} oo\u{0307} u\u{0307} \n",
+            ),
+            (
+                MarkPolicy::DeleteWithBase,
+                " E\u{0301}\u{0323} oo\u{0307} u\u{0307} \n",
+            ),
+            (MarkPolicy::Drop, " E oo u\u{0307} \n"),
+        ];
+
+        for (policy, expected) in cases {
+            let mapped: String =
+                mapper.map_graphemes(INPUT, &[], policy).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        // Keeping attached characters is equivalent to mapping characters.
+        let mapped: String =
+            mapper.map_graphemes(INPUT, &[], MarkPolicy::Keep).collect();
+        assert_eq!(mapped, INPUT.map_chars(&mapper).collect::<String>());
+    }
+
+    #[test]
+    fn graphemes_clusters() {
+        let mapper = CharMapper::new(&ACTIONS, CharMapAction::Pass);
+        let clusters = [
+            ("a\u{0308}", CharMapAction::SubStr("ae")),
+            ("e\u{0301}\u{0323}", CharMapAction::Pass),
+            ("u\u{0308}", CharMapAction::Delete),
+        ];
+
+        let mapped: String =
+            mapper.map_graphemes(INPUT, &clusters, MarkPolicy::Drop).collect();
+        assert_eq!(mapped, "ae e\u{0301}\u{0323} oo  \n");
+
+        // Closures and pairs of maps can be used as cluster maps as well.
+        let closure = |cluster: &str| {
+            if cluster.chars().count() > 1 {
+                Some(CharMapAction::SubChar('*'))
+            } else {
+                None
+            }
+        };
+        let combined = (clusters, closure);
+
+        let mapped: String =
+            mapper.map_graphemes(INPUT, &combined, MarkPolicy::Drop).collect();
+        assert_eq!(mapped, "ae e\u{0301}\u{0323} *  *");
+
+        assert_eq!(
+            clusters.map_cluster("u\u{0308}"),
+            Some(CharMapAction::Delete)
+        );
+        assert_eq!(clusters.map_cluster("u"), None);
+    }
+
+    #[test]
+    fn graphemes_extended_clusters() {
+        let actions = [
+            ('\u{1100}', CharMapAction::SubChar('G')),
+            ('\u{1F1EF}', CharMapAction::SubStr("[flag]")),
+            ('\u{1F469}', CharMapAction::SubStr(":woman:")),
+        ];
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+
+        // Hangul jamo syllables, emoji ZWJ sequences, and flags are single
+        // clusters.
+        let input = "\u{1100}\u{1161}\u{11A8} \u{1F469}\u{200D}\u{1F4BB} \u{1F1EF}\u{1F1F5}";
+        let cases = [
+            (
+                MarkPolicy::Keep,
+                "G\u{1161}\u{11A8} :woman:\u{200D}\u{1F4BB} [flag]\u{1F1F5}",
+            ),
+            (MarkPolicy::Drop, "G :woman: [flag]"),
+        ];
+
+        for (policy, expected) in cases {
+            let mapped: String =
+                mapper.map_graphemes(input, &[], policy).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn graphemes_max_repeats() {
+        let mapper =
+            CharMapper::new(&ACTIONS, CharMapAction::Pass).max_repeats(2);
+        let clusters = [
+            ("!\u{0301}", CharMapAction::SubStr("!!!")),
+            ("a\u{0308}", CharMapAction::SubStr("ooo")),
+        ];
+
+        // Runs continue across clusters and attached characters.
+        let cases = [
+            ("ooo!!!!", MarkPolicy::Keep, "oo!!"),
+            ("a\u{0308}o\u{0301}!\u{0301}!", MarkPolicy::Drop, "oo!!"),
+            (
+                "e\u{0308}\u{0308}\u{0308}E",
+                MarkPolicy::Keep,
+                "E\u{0307}\u{0307}E",
+            ),
+            ("eEEe", MarkPolicy::DeleteWithBase, "EE"),
+        ];
+
+        for (input, policy, expected) in cases {
+            let mapped: String =
+                mapper.map_graphemes(input, &clusters, policy).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        // Keeping attached characters is still equivalent to mapping
+        // characters.
+        let mapped: String =
+            mapper.map_graphemes(INPUT, &[], MarkPolicy::Keep).collect();
+        assert_eq!(mapped, INPUT.map_chars(&mapper).collect::<String>());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn graphemes_std_maps() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let mapper = CharMapper::new(&ACTIONS, CharMapAction::Pass);
+        let clusters = [("o\u{0308}", CharMapAction::SubChar('ö'))];
+        let hash_map = HashMap::from(clusters);
+        let btree_map = BTreeMap::from(clusters);
+
+        let mapped: String =
+            mapper.map_graphemes(INPUT, &hash_map, MarkPolicy::Drop).collect();
+        assert_eq!(mapped, " E ö u\u{0307} \n");
+
+        let mapped: String = mapper
+            .map_graphemes(INPUT, &btree_map, MarkPolicy::Drop)
+            .collect();
+        assert_eq!(mapped, " E ö u\u{0307} \n");
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(feature = "greek")]
+mod test {
+    use charmap::maps::greek::{
+        Romanizer, ELOT_743, FOLD_POLYTONIC, STRIP_ACCENTS,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn strip_accents() {
+        let mapper = CharMapper::new(&STRIP_ACCENTS, CharMapAction::Pass);
+
+        let mapped: String =
+            "Άέήίόύώ ΐΰϊϋ Ϊ Ϋ καλημέρα".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Αεηιουω ιυιυ Ι Υ καλημερα");
+
+        // Decomposed tonos and dialytika are removed as well.
+        let mapped: String =
+            "α\u{301} ι\u{308} ι\u{344}".map_chars(&mapper).collect();
+        assert_eq!(mapped, "α ι ι");
+
+        // Polytonic letters are left alone.
+        let mapped: String = "ἀρχή".map_chars(&mapper).collect();
+        assert_eq!(mapped, "ἀρχη");
+    }
+
+    #[test]
+    fn fold_polytonic() {
+        let mapper = CharMapper::new(&FOLD_POLYTONIC, CharMapAction::Pass);
+
+        let mapped: String = "Ἐν ἀρχῇ ἦν ὁ λόγος".map_chars(&mapper).collect();
+        // Oxia is canonically equivalent to tonos, so NFC text contains
+        // monotonic letters such as ό that are left alone.
+        assert_eq!(mapped, "Εν αρχη ην ο λόγος");
+
+        let mapped: String = "ᾼ ᾯ ῥ Ῥ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Α Ω ρ Ρ");
+
+        // Decomposed polytonic diacritics are removed as well.
+        let mapped: String =
+            "α\u{313}\u{342}\u{345}".map_chars(&mapper).collect();
+        assert_eq!(mapped, "α");
+
+        // Every Greek Extended letter is folded to a single letter.
+        for (c, action) in FOLD_POLYTONIC {
+            if ('\u{1F00}'..='\u{1FFF}').contains(&c) && c.is_alphabetic() {
+                assert!(
+                    matches!(action, CharMapAction::SubChar(d) if d < '\u{0400}'),
+                    "{:?}",
+                    c
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn strip_all_accents() {
+        let actions = (&STRIP_ACCENTS, &FOLD_POLYTONIC);
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+
+        let mapped: String =
+            "Ὦ ξεῖν᾽, ἀγγέλλειν Λακεδαιμονίοις".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Ω ξειν, αγγελλειν Λακεδαιμονιοις");
+    }
+
+    #[test]
+    fn elot_743() {
+        let mapper = CharMapper::new(&ELOT_743, CharMapAction::Pass);
+
+        let cases = [
+            ("Θεσσαλονίκη", "Thessaloniki"),
+            ("Χανιά", "Chania"),
+            ("Ψαρά", "Psara"),
+            ("Ξάνθη", "Xanthi"),
+            ("Ηράκλειο", "Irakleio"),
+            ("Ζάκυνθος", "Zakynthos"),
+            ("Ἀθῆναι", "Athinai"),
+            ("Τι κάνεις\u{37E}", "Ti kaneis?"),
+        ];
+
+        for (input, expected) in cases {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn romanizer_digraphs() {
+        let romanizer = Romanizer::new();
+
+        let cases = [
+            // ου
+            ("Ουρανός", "Ouranos"),
+            ("μουσική", "mousiki"),
+            // αυ, ευ, and ηυ before voiced and voiceless letters
+            ("αύριο", "avrio"),
+            ("Αυτοκίνητο", "Aftokinito"),
+            ("Ευαγγελία", "Evangelia"),
+            ("ευχαριστώ", "efcharisto"),
+            ("ηύρα", "ivra"),
+            ("Ζευς", "Zefs"),
+            ("ευ", "ef"),
+            // A dialytika separates the vowels.
+            ("Ταΰγετος", "Taygetos"),
+            // γγ, γκ, γξ, and γχ
+            ("Άγγελος", "Angelos"),
+            ("άγκυρα", "ankyra"),
+            ("γκρίζος", "gkrizos"),
+            ("σφίγξ", "sfinx"),
+            ("μελαγχολία", "melancholia"),
+            // μπ and ντ
+            ("Μπαλτάς", "Baltas"),
+            ("Λάμπρος", "Lampros"),
+            ("ντομάτα", "ntomata"),
+            ("Πέντε", "Pente"),
+            // All-caps words
+            ("ΕΥΑΓΓΕΛΟΣ", "EVANGELOS"),
+            ("ΜΠΟΥΖΟΥΚΙ ΘΕΑ", "BOUZOUKI THEA"),
+            ("Θ. Χ.", "Th. Ch."),
+        ];
+
+        for (input, expected) in cases {
+            let romanized: String =
+                romanizer.romanize_chars(input.chars()).collect();
+            assert_eq!(romanized, expected);
+        }
+
+        // As an action map, a romanizer applies the context-free form.
+        let mapper = CharMapper::new(&romanizer, CharMapAction::Pass);
+        let mapped: String = "Ουρανός".map_chars(&mapper).collect();
+        assert_eq!(mapped, "Oyranos");
+    }
+}
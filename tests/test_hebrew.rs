@@ -0,0 +1,62 @@
+#[cfg(feature = "hebrew")]
+mod test {
+    use charmap::maps::hebrew::{
+        Normalizer, NORMALIZE_FINAL_FORMS, REMOVE_CANTILLATION, REMOVE_NIQQUD,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    const INPUT: &str = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים אֵ֥ת הַשָּׁמַ֖יִם וְאֵ֥ת הָאָֽרֶץ׃";
+
+    #[test]
+    fn hebrew_individual_rules() {
+        let mapper = CharMapper::new(&REMOVE_NIQQUD, CharMapAction::Pass);
+        let mapped: String = "שָׁלוֹם".map_chars(&mapper).collect();
+        assert_eq!(mapped, "שלום");
+
+        let mapper =
+            CharMapper::new(&REMOVE_CANTILLATION, CharMapAction::Pass);
+        let mapped: String = "בָּרָ֣א".map_chars(&mapper).collect();
+        assert_eq!(mapped, "בָּרָא");
+
+        let mapper =
+            CharMapper::new(&NORMALIZE_FINAL_FORMS, CharMapAction::Pass);
+        let mapped: String = "ך ם ן ף ץ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "כ מ נ פ צ");
+    }
+
+    #[test]
+    fn hebrew_normalizer() {
+        let cases = [
+            (Normalizer::ALL, "בראשית ברא אלהימ את השמימ ואת הארצ׃"),
+            (
+                Normalizer::ALL.final_forms(false),
+                "בראשית ברא אלהים את השמים ואת הארץ׃",
+            ),
+            (
+                Normalizer::NONE.cantillation(true),
+                "בְּרֵאשִׁית בָּרָא אֱלֹהִים אֵת הַשָּׁמַיִם וְאֵת הָאָֽרֶץ׃",
+            ),
+            (Normalizer::NONE, INPUT),
+        ];
+
+        for (normalizer, expected) in cases {
+            let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+            let mapped: String = INPUT.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        assert_eq!(Normalizer::default(), Normalizer::ALL.final_forms(false));
+        let normalizer = Normalizer::default();
+        let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, "בראשית ברא אלהים את השמים ואת הארץ׃");
+    }
+
+    #[test]
+    fn hebrew_combined_rules() {
+        let actions = (&REMOVE_NIQQUD, &REMOVE_CANTILLATION);
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let mapped: String = INPUT.map_chars(&mapper).collect();
+        assert_eq!(mapped, "בראשית ברא אלהים את השמים ואת הארץ׃");
+    }
+}
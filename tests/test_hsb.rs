@@ -0,0 +1,59 @@
+#[cfg(feature = "hsb")]
+mod test {
+    use charmap::maps::hsb::{ARABIC_TO_HSB, HSB_TO_ARABIC};
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    // All Arabic letters and diacritics covered by the HSB scheme.
+    const ARABIC_INVENTORY: &str = concat!(
+        "ءآأؤإئابةتثجحخدذرزسشصضطظعغـفقكلمنهوىي",
+        "\u{064B}\u{064C}\u{064D}\u{064E}\u{064F}\u{0650}\u{0651}\u{0652}",
+        "\u{0670}ٱپچڤگ",
+    );
+
+    const HSB_INVENTORY: &str =
+        "ʼĀÂŵǍŷAbħtθjHxdðrzsšSDTĎςγ_fqklmnhwýyãũĩaui~.áÄpčvg";
+
+    #[test]
+    fn hsb_inventory() {
+        let to_hsb = CharMapper::new(&ARABIC_TO_HSB, CharMapAction::Pass);
+        let from_hsb = CharMapper::new(&HSB_TO_ARABIC, CharMapAction::Pass);
+
+        let mapped: String = ARABIC_INVENTORY.map_chars(&to_hsb).collect();
+        assert_eq!(mapped, HSB_INVENTORY);
+
+        let unmapped: String = HSB_INVENTORY.map_chars(&from_hsb).collect();
+        assert_eq!(unmapped, ARABIC_INVENTORY);
+    }
+
+    #[test]
+    fn hsb_round_trip() {
+        let to_hsb = CharMapper::new(&ARABIC_TO_HSB, CharMapAction::Pass);
+        let from_hsb = CharMapper::new(&HSB_TO_ARABIC, CharMapAction::Pass);
+        let inputs =
+            ["سُؤَالٌ", "قِرَاءَةً", "إِنْشَاءٍ", "آدَابُ ٱلْمُعَلِّمِ", "شَاطِئٌ ظَرِيفٌ", "عَلَىٰ"];
+
+        for input in inputs {
+            let mapped: String = input.map_chars(&to_hsb).collect();
+            assert!(mapped
+                .chars()
+                .all(|c| !('\u{0600}'..='\u{06FF}').contains(&c)));
+
+            let unmapped: String = mapped.map_chars(&from_hsb).collect();
+            assert_eq!(unmapped, input);
+        }
+    }
+
+    #[test]
+    fn hsb_tanween_and_hamza() {
+        let to_hsb = CharMapper::new(&ARABIC_TO_HSB, CharMapAction::Pass);
+
+        let mapped: String = "سُؤَالٌ".map_chars(&to_hsb).collect();
+        assert_eq!(mapped, "suŵaAlũ");
+
+        let mapped: String = "قِرَاءَةً".map_chars(&to_hsb).collect();
+        assert_eq!(mapped, "qiraAʼaħã");
+
+        let mapped: String = "شَاطِئٌ".map_chars(&to_hsb).collect();
+        assert_eq!(mapped, "šaATiŷũ");
+    }
+}
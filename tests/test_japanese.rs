@@ -0,0 +1,102 @@
+#[cfg(feature = "japanese")]
+mod test {
+    use charmap::maps::japanese::{
+        widen_katakana_chars, ASCII_TO_FULLWIDTH, FULLWIDTH_TO_ASCII,
+        HALFWIDTH_TO_FULLWIDTH_KATAKANA, HIRAGANA_TO_KATAKANA,
+        KATAKANA_TO_HIRAGANA,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn kana_conversion() {
+        let to_katakana =
+            CharMapper::new(&HIRAGANA_TO_KATAKANA, CharMapAction::Pass);
+        let to_hiragana =
+            CharMapper::new(&KATAKANA_TO_HIRAGANA, CharMapAction::Pass);
+
+        let katakana: String = "きょうは いい てんき ですね、ゞ"
+            .map_chars(&to_katakana)
+            .collect();
+        assert_eq!(katakana, "キョウハ イイ テンキ デスネ、ヾ");
+
+        let hiragana: String =
+            katakana.chars().map_chars(&to_hiragana).collect();
+        assert_eq!(hiragana, "きょうは いい てんき ですね、ゞ");
+
+        // Long vowel marks and kanji are shared by both syllabaries.
+        let hiragana: String =
+            "コーヒー 漢字".map_chars(&to_hiragana).collect();
+        assert_eq!(hiragana, "こーひー 漢字");
+
+        let hiragana: String = "ヷ".map_chars(&to_hiragana).collect();
+        assert_eq!(hiragana, "わ\u{3099}");
+    }
+
+    #[test]
+    fn kana_roundtrip() {
+        for (c, action) in HIRAGANA_TO_KATAKANA {
+            let mapper =
+                CharMapper::new(&KATAKANA_TO_HIRAGANA, CharMapAction::Pass);
+            let CharMapAction::SubChar(k) = action else {
+                panic!("{:?}", c);
+            };
+            assert_eq!(k.map_chars(&mapper).next(), Some(c));
+        }
+    }
+
+    #[test]
+    fn width_conversion() {
+        let narrow = CharMapper::new(&FULLWIDTH_TO_ASCII, CharMapAction::Pass);
+        let wide = CharMapper::new(&ASCII_TO_FULLWIDTH, CharMapAction::Pass);
+
+        let ascii: String =
+            "ＡＢＣ　ｘｙｚ　０１２！？（～）".map_chars(&narrow).collect();
+        assert_eq!(ascii, "ABC xyz 012!?(~)");
+
+        let fullwidth: String = ascii.chars().map_chars(&wide).collect();
+        assert_eq!(fullwidth, "ＡＢＣ　ｘｙｚ　０１２！？（～）");
+
+        // Every printable ASCII character roundtrips.
+        let ascii: String = (' '..='~').collect();
+        let roundtrip: String =
+            ascii.chars().map_chars(&wide).map_chars(&narrow).collect();
+        assert_eq!(roundtrip, ascii);
+    }
+
+    #[test]
+    fn halfwidth_katakana() {
+        let mapper = CharMapper::new(
+            &HALFWIDTH_TO_FULLWIDTH_KATAKANA,
+            CharMapAction::Pass,
+        );
+
+        let mapped: String = "｢ｶﾀｶﾅ｣､ｰ｡".map_chars(&mapper).collect();
+        assert_eq!(mapped, "「カタカナ」、ー。");
+
+        // Voiced sound marks become combining marks.
+        let mapped: String = "ｶﾞﾊﾟ".map_chars(&mapper).collect();
+        assert_eq!(mapped, "カ\u{3099}ハ\u{309A}");
+    }
+
+    #[test]
+    fn widen_katakana() {
+        let cases = [
+            ("ｶﾞｲﾄﾞﾌﾞｯｸ", "ガイドブック"),
+            ("ﾊﾟﾋﾟﾌﾟﾍﾟﾎﾟ", "パピプペポ"),
+            ("ｳﾞｧｲｵﾘﾝ", "ヴァイオリン"),
+            ("ﾜﾞｦﾞ", "ヷヺ"),
+            // Fullwidth katakana followed by halfwidth marks are composed too.
+            ("カﾞ", "ガ"),
+            // Marks that cannot be composed become combining marks.
+            ("ｱﾞ ﾟ", "ア\u{3099} \u{309A}"),
+            ("ﾀﾟ", "タ\u{309A}"),
+            ("abc ｶﾅ", "abc カナ"),
+        ];
+
+        for (input, expected) in cases {
+            let widened: String =
+                widen_katakana_chars(input.chars()).collect();
+            assert_eq!(widened, expected);
+        }
+    }
+}
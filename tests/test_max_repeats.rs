@@ -0,0 +1,105 @@
+mod test {
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn max_repeats_all() {
+        let actions = [
+            ('-', CharMapAction::Delete),
+            ('O', CharMapAction::SubChar('o')),
+            ('ø', CharMapAction::SubStr("oo")),
+        ];
+        let cases = [
+            ("", ""),
+            ("so", "so"),
+            ("soooooo", "soo"),
+            ("sOoOoOo", "soo"),
+            ("so-o-o-o", "soo"),
+            ("søø", "soo"),
+            ("!!!!!!", "!!"),
+            ("aaa bbb aaa", "aa bb aa"),
+        ];
+
+        let mapper =
+            CharMapper::new(&actions, CharMapAction::Pass).max_repeats(2);
+
+        for (input, expected) in cases {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        let unlimited = CharMapper::new(&actions, CharMapAction::Pass);
+
+        for max in [1, 2, 3] {
+            let mapper = CharMapper::new(&actions, CharMapAction::Pass)
+                .max_repeats(max);
+
+            for (input, _) in cases {
+                let mapped: String = input.map_chars(&mapper).collect();
+                let expected: String = input.map_chars(&unlimited).collect();
+                assert_eq!(mapped, collapse(&expected, max));
+            }
+        }
+
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let mapped: String = "soo-oO".map_chars(&mapper).collect();
+        assert_eq!(mapped, "soooo");
+
+        let mapper = mapper.max_repeats(0);
+        let mapped: String = "soo-oO".map_chars(&mapper).collect();
+        assert_eq!(mapped, "");
+    }
+
+    #[test]
+    fn max_repeats_by() {
+        let max = |c: char| match c {
+            '!' | '?' => Some(1),
+            '\u{0647}' => Some(3),
+            c if c.is_alphabetic() => Some(2),
+            _ => None,
+        };
+        let actions = [('؟', CharMapAction::SubChar('?'))];
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass)
+            .max_repeats_by(&max);
+
+        let cases = [
+            ("Nooooo!!!!!", "Noo!"),
+            ("ههههههه", "ههه"),
+            ("1000000...", "1000000..."),
+            ("ماذا؟؟؟?", "ماذا?"),
+        ];
+
+        for (input, expected) in cases {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        // A maximum of 0 removes the character.
+        let max = |c: char| if c == '~' { Some(0) } else { Some(1) };
+        let mapper =
+            CharMapper::new(&[], CharMapAction::Pass).max_repeats_by(&max);
+        let mapped: String = "~a~~aab~".map_chars(&mapper).collect();
+        assert_eq!(mapped, "aab");
+    }
+
+    // Reference implementation collapsing runs of the same character.
+    fn collapse(s: &str, max: usize) -> String {
+        let mut out = String::new();
+        let mut last = None;
+        let mut repeats = 0;
+
+        for c in s.chars() {
+            if last == Some(c) {
+                repeats += 1;
+            } else {
+                last = Some(c);
+                repeats = 1;
+            }
+
+            if repeats <= max {
+                out.push(c);
+            }
+        }
+
+        out
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(feature = "normalization")]
+mod test {
+    use charmap::{
+        CharMapAction, CharMapper, MapCharsIter, NormalForm,
+        NormalizeCharsIter,
+    };
+
+    #[test]
+    fn normalization_forms() {
+        let input = "Å ﬁ e\u{0301} ①";
+        let cases = [
+            (NormalForm::Nfc, "Å ﬁ é ①"),
+            (NormalForm::Nfd, "A\u{030A} ﬁ e\u{0301} ①"),
+            (NormalForm::Nfkc, "Å fi é 1"),
+            (NormalForm::Nfkd, "A\u{030A} fi e\u{0301} 1"),
+        ];
+
+        for (form, expected) in cases {
+            let normalized: String = input.normalize(form).collect();
+            assert_eq!(normalized, expected);
+
+            let normalized: String =
+                form.normalize_chars_iter(input.chars()).collect();
+            assert_eq!(normalized, expected);
+        }
+    }
+
+    #[test]
+    fn normalization_stages() {
+        let actions = [
+            ('é', CharMapAction::SubChar('3')),
+            ('\u{0301}', CharMapAction::Delete),
+        ];
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let input = "caf\u{00E9} cafe\u{0301}";
+
+        let mapped: String = input.map_chars(&mapper).collect();
+        assert_eq!(mapped, "caf3 cafe");
+
+        let mapped: String =
+            input.normalize(NormalForm::Nfd).map_chars(&mapper).collect();
+        assert_eq!(mapped, "cafe cafe");
+
+        let mapped: String =
+            input.normalize(NormalForm::Nfc).map_chars(&mapper).collect();
+        assert_eq!(mapped, "caf3 caf3");
+    }
+
+    #[test]
+    fn normalization_mapped_chars() {
+        // Mapped characters may no longer be normalized, e.g. when a base
+        // letter is substituted in front of a combining mark.
+        let cases = [
+            (NormalForm::Nfc, 'e', "x\u{0301}", "e\u{0301}", "\u{00E9}"),
+            (NormalForm::Nfd, '\u{00E9}', "x", "\u{00E9}", "e\u{0301}"),
+            (NormalForm::Nfkc, 'e', "x\u{0301}", "e\u{0301}", "\u{00E9}"),
+        ];
+
+        for (form, x, input, unnormalized, expected) in cases {
+            let actions = [('x', CharMapAction::SubChar(x))];
+            let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+
+            let mapped: String =
+                input.normalize(form).map_chars(&mapper).collect();
+            assert_eq!(mapped, unnormalized);
+
+            let mapper = mapper.normalize(form);
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String =
+                mapper.map_chars_iter(input.chars()).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn normalization_charmapper() {
+        let actions = [
+            ('o', CharMapAction::SubStr("o\u{0308}")),
+            ('é', CharMapAction::SubChar('3')),
+        ];
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass)
+            .normalize(NormalForm::Nfc)
+            .max_repeats(2);
+        let input = "caf\u{00E9} cafe\u{0301} cooool";
+
+        let mapped: String = input.map_chars(&mapper).collect();
+        assert_eq!(mapped, "caf3 caf3 c\u{00F6}\u{00F6}\u{00F6}\u{00F6}l");
+
+        // Traces are recorded for the normalized input, but their output is
+        // not normalized again.
+        let traced: Vec<(char, String)> = mapper
+            .trace_chars_iter("e\u{0301}o".chars())
+            .map(|traced| (traced.c, traced.output().collect()))
+            .collect();
+        assert_eq!(
+            traced,
+            [('é', "3".to_string()), ('o', "o\u{0308}".to_string())]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn normalization_stats() {
+        use charmap::Stats;
+
+        let actions = [('é', CharMapAction::SubChar('3'))];
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass)
+            .normalize(NormalForm::Nfc);
+
+        // Statistics are recorded for the normalized input.
+        let mut stats = Stats::new();
+        let mapped: String = mapper
+            .map_chars_iter_with_stats(
+                "caf\u{00E9} cafe\u{0301}".chars(),
+                &mut stats,
+            )
+            .collect();
+        assert_eq!(mapped, "caf3 caf3");
+        assert_eq!(stats.input_chars, 9);
+        assert_eq!(stats.mapped_chars, 9);
+        assert_eq!(stats.sub_chars, 2);
+    }
+}
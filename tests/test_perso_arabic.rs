@@ -0,0 +1,91 @@
+#[cfg(feature = "perso_arabic")]
+mod test {
+    use charmap::maps::perso_arabic::{
+        ARABIC_INDIC_DIGITS_TO_ASCII, ARABIC_INDIC_TO_EXTENDED_DIGITS,
+        ARABIC_TO_KURDISH, ARABIC_TO_PERSIAN, ARABIC_TO_URDU,
+        EXTENDED_ARABIC_INDIC_DIGITS_TO_ASCII,
+        EXTENDED_TO_ARABIC_INDIC_DIGITS, KURDISH_TO_ARABIC, PERSIAN_TO_ARABIC,
+        URDU_TO_ARABIC,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    #[test]
+    fn persian_unification() {
+        let to_arabic =
+            CharMapper::new(&PERSIAN_TO_ARABIC, CharMapAction::Pass);
+        let to_persian =
+            CharMapper::new(&ARABIC_TO_PERSIAN, CharMapAction::Pass);
+
+        let mapped: String =
+            "یک کتاب خانهٔ پدری".map_chars(&to_arabic).collect();
+        assert_eq!(mapped, "يك كتاب خانهٔ پدري");
+
+        let mapped: String = "خانۀ".map_chars(&to_arabic).collect();
+        assert_eq!(mapped, "خانهٔ");
+
+        let mapped: String = "يك كتاب على".map_chars(&to_persian).collect();
+        assert_eq!(mapped, "یک کتاب علی");
+    }
+
+    #[test]
+    fn urdu_unification() {
+        let to_arabic = CharMapper::new(&URDU_TO_ARABIC, CharMapAction::Pass);
+        let to_urdu = CharMapper::new(&ARABIC_TO_URDU, CharMapAction::Pass);
+
+        let mapped: String = "یہ کھانا ہے".map_chars(&to_arabic).collect();
+        assert_eq!(mapped, "يه كهانا هي");
+
+        let mapped: String = "يه كتاب هي".map_chars(&to_urdu).collect();
+        assert_eq!(mapped, "یہ کتاب ہی");
+    }
+
+    #[test]
+    fn kurdish_unification() {
+        let to_arabic =
+            CharMapper::new(&KURDISH_TO_ARABIC, CharMapAction::Pass);
+        let to_kurdish =
+            CharMapper::new(&ARABIC_TO_KURDISH, CharMapAction::Pass);
+
+        // Kurdish-specific letters (ڕ, ۆ, ێ, ڵ) are preserved.
+        let mapped: String =
+            "کوردی ڕۆژ ئێوە باڵ".map_chars(&to_arabic).collect();
+        assert_eq!(mapped, "كوردي ڕۆژ ئێوه باڵ");
+
+        let mapped: String = "كوردي".map_chars(&to_kurdish).collect();
+        assert_eq!(mapped, "کوردی");
+    }
+
+    #[test]
+    fn digits() {
+        let extended = "۰۱۲۳۴۵۶۷۸۹";
+        let arabic = "٠١٢٣٤٥٦٧٨٩";
+
+        let mapper = CharMapper::new(
+            &EXTENDED_ARABIC_INDIC_DIGITS_TO_ASCII,
+            CharMapAction::Pass,
+        );
+        let mapped: String = extended.map_chars(&mapper).collect();
+        assert_eq!(mapped, "0123456789");
+
+        let mapper = CharMapper::new(
+            &ARABIC_INDIC_DIGITS_TO_ASCII,
+            CharMapAction::Pass,
+        );
+        let mapped: String = arabic.map_chars(&mapper).collect();
+        assert_eq!(mapped, "0123456789");
+
+        let mapper = CharMapper::new(
+            &EXTENDED_TO_ARABIC_INDIC_DIGITS,
+            CharMapAction::Pass,
+        );
+        let mapped: String = extended.map_chars(&mapper).collect();
+        assert_eq!(mapped, arabic);
+
+        let mapper = CharMapper::new(
+            &ARABIC_INDIC_TO_EXTENDED_DIGITS,
+            CharMapAction::Pass,
+        );
+        let mapped: String = arabic.map_chars(&mapper).collect();
+        assert_eq!(mapped, extended);
+    }
+}
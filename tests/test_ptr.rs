@@ -0,0 +1,142 @@
+mod data;
+
+#[cfg(feature = "std")]
+mod test {
+    use super::data::{
+        TEST_CHARS_PASS, TEST_MAPPING, TEST_STRINGS_PASS, TEST_STRINGS_SUB_X,
+    };
+    use charmap::{ActionMap, CharMapAction, CharMapper, MapCharsIter};
+    use std::collections::{BTreeMap, HashMap};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    fn check_default_pass<M: ActionMap + ?Sized>(actionmap: &M) {
+        let mapper = CharMapper::new(actionmap, CharMapAction::Pass);
+
+        for (input, expected) in TEST_STRINGS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn dyn_ref_default_pass() {
+        let hashmap = HashMap::from(TEST_MAPPING);
+        let actionmap: &dyn ActionMap = &hashmap;
+
+        check_default_pass(actionmap);
+        check_default_pass(&actionmap);
+    }
+
+    #[test]
+    fn dyn_box_default_pass() {
+        let actionmap: Box<dyn ActionMap> =
+            Box::new(BTreeMap::from(TEST_MAPPING));
+
+        check_default_pass(&*actionmap);
+        check_default_pass(&actionmap);
+    }
+
+    #[test]
+    fn generic_ptr_default_pass() {
+        let hashmap = HashMap::from(TEST_MAPPING);
+        let actionmap = &hashmap;
+        check_default_pass(&actionmap);
+        check_default_pass(&&actionmap);
+
+        let actionmap = Box::new(BTreeMap::from(TEST_MAPPING));
+        check_default_pass(&actionmap);
+        check_default_pass(&Rc::new(actionmap));
+    }
+
+    #[test]
+    fn dyn_send_sync_default_pass() {
+        let hashmap = HashMap::from(TEST_MAPPING);
+        let actionmap: &(dyn ActionMap + Send) = &hashmap;
+        check_default_pass(&actionmap);
+        let actionmap: &(dyn ActionMap + Sync) = &hashmap;
+        check_default_pass(&actionmap);
+        let actionmap: &(dyn ActionMap + Send + Sync) = &hashmap;
+        check_default_pass(&actionmap);
+
+        let actionmap: Box<dyn ActionMap + Send> =
+            Box::new(HashMap::from(TEST_MAPPING));
+        check_default_pass(&actionmap);
+        let actionmap: Box<dyn ActionMap + Sync> =
+            Box::new(HashMap::from(TEST_MAPPING));
+        check_default_pass(&actionmap);
+        let actionmap: Box<dyn ActionMap + Send + Sync> =
+            Box::new(HashMap::from(TEST_MAPPING));
+        check_default_pass(&actionmap);
+    }
+
+    #[test]
+    fn dyn_box_threads() {
+        let actionmap: Box<dyn ActionMap + Send + Sync> =
+            Box::new(BTreeMap::from(TEST_MAPPING));
+        let actionmap = Arc::new(actionmap);
+
+        let handles: Vec<_> = TEST_STRINGS_SUB_X
+            .into_iter()
+            .map(|(input, expected)| {
+                let actionmap = Arc::clone(&actionmap);
+                std::thread::spawn(move || {
+                    let mapper = CharMapper::new(
+                        &actionmap,
+                        CharMapAction::SubStr("x"),
+                    );
+                    let mapped: String = input.map_chars(&mapper).collect();
+                    assert_eq!(mapped, expected);
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn rc_default_pass() {
+        check_default_pass(&Rc::new(HashMap::from(TEST_MAPPING)));
+
+        let actionmap: Rc<dyn ActionMap> =
+            Rc::new(HashMap::from(TEST_MAPPING));
+        check_default_pass(&actionmap);
+    }
+
+    #[test]
+    fn arc_default_pass() {
+        check_default_pass(&Arc::new(HashMap::from(TEST_MAPPING)));
+
+        let actionmap: Arc<dyn ActionMap> =
+            Arc::new(HashMap::from(TEST_MAPPING));
+        check_default_pass(&actionmap);
+    }
+
+    #[test]
+    fn dyn_vec_default_sub_x() {
+        let hashmap = HashMap::from(TEST_MAPPING);
+        let btreemap = BTreeMap::from(TEST_MAPPING);
+        let actionmaps: [Box<dyn ActionMap>; 2] =
+            [Box::new(hashmap), Box::new(btreemap)];
+
+        for actionmap in actionmaps.iter() {
+            let mapper =
+                CharMapper::new(actionmap, CharMapAction::SubStr("x"));
+
+            for (input, expected) in TEST_STRINGS_SUB_X {
+                let mapped: String = input.map_chars(&mapper).collect();
+                assert_eq!(mapped, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(feature = "punctuation")]
+mod test {
+    use charmap::maps::punctuation::{
+        Normalizer, EXPAND_ELLIPSIS, FOLD_EM_DASHES,
+        NORMALIZE_CJK_PUNCTUATION, NORMALIZE_DASHES,
+        NORMALIZE_FULLWIDTH_PUNCTUATION, NORMALIZE_QUOTES,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    const INPUT: &str =
+        "„Ja“ ‹non› ‘it’s’ 1‒2 a‐b – — ― …「東京」【注】、。！？（）";
+
+    #[test]
+    fn punctuation_individual_rules() {
+        let cases = [
+            (
+                &NORMALIZE_QUOTES[..],
+                "«a» ‹b› “c” ‘d’ „e‟ ‚f‛",
+                "\"a\" 'b' \"c\" 'd' \"e\" 'f'",
+            ),
+            (&NORMALIZE_DASHES, "‐ ‑ ‒ – — ―", "- - - - — ―"),
+            (&FOLD_EM_DASHES, "– — ― ⸺ ⸻", "– - - - -"),
+            (&EXPAND_ELLIPSIS, "a…b‥c․", "a...b..c."),
+            (
+                &NORMALIZE_CJK_PUNCTUATION,
+                "「a」『b』《c》【d】〔e〕、。",
+                "\"a\"\"b\"<c>[d][e],.",
+            ),
+            (&NORMALIZE_CJK_PUNCTUATION, "｢a｣､｡", "\"a\",."),
+            (
+                &NORMALIZE_FULLWIDTH_PUNCTUATION,
+                "！＂＃（ｘ）［＼］｛～｝１",
+                "!\"#(ｘ)[\\]{~}１",
+            ),
+        ];
+
+        for (actions, input, expected) in cases {
+            let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn punctuation_normalizer() {
+        let cases = [
+            (
+                Normalizer::ALL,
+                "\"Ja\" 'non' 'it's' 1-2 a-b - - - ...\"東京\"[注],.!?()",
+            ),
+            (
+                Normalizer::CONSERVATIVE,
+                "\"Ja\" 'non' 'it's' 1-2 a-b - — ― …「東京」【注】、。!?()",
+            ),
+            (
+                Normalizer::CONSERVATIVE.em_dashes(true).ellipsis(true),
+                "\"Ja\" 'non' 'it's' 1-2 a-b - - - ...「東京」【注】、。!?()",
+            ),
+            (
+                Normalizer::ALL.quotes(false).cjk(false),
+                "„Ja“ ‹non› ‘it’s’ 1-2 a-b - - - ...「東京」【注】、。!?()",
+            ),
+            (
+                Normalizer::NONE.fullwidth(true),
+                "„Ja“ ‹non› ‘it’s’ 1‒2 a‐b – — ― …「東京」【注】、。!?()",
+            ),
+            (Normalizer::NONE, INPUT),
+        ];
+
+        for (normalizer, expected) in cases {
+            let mapper = CharMapper::new(&normalizer, CharMapAction::Pass);
+            let mapped: String = INPUT.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        assert_eq!(Normalizer::default(), Normalizer::ALL);
+    }
+
+    #[test]
+    fn punctuation_combined_rules() {
+        let actions =
+            (&NORMALIZE_QUOTES, (&NORMALIZE_DASHES, &EXPAND_ELLIPSIS));
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let mapped: String =
+            "“Wait…” – she — left".map_chars(&mapper).collect();
+        assert_eq!(mapped, "\"Wait...\" - she — left");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn punctuation_normalizer_keys() {
+        use charmap::ActionMap;
+
+        let keys: Vec<char> =
+            Normalizer::NONE.quotes(true).keys().unwrap().collect();
+        let expected: Vec<char> =
+            NORMALIZE_QUOTES.iter().map(|(c, _)| *c).collect();
+        assert_eq!(keys, expected);
+
+        // Every key is listed once and maps to an action.
+        let mut keys: Vec<char> = Normalizer::ALL.keys().unwrap().collect();
+        assert!(keys.iter().all(|c| Normalizer::ALL.map_char(*c).is_some()));
+        let n_keys = keys.len();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), n_keys);
+
+        assert_eq!(Normalizer::NONE.keys().unwrap().count(), 0);
+    }
+}
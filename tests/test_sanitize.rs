@@ -0,0 +1,151 @@
+#[cfg(feature = "sanitize")]
+mod test {
+    use charmap::maps::sanitize::{
+        Sanitizer, REMOVE_BIDI_CONTROLS, REMOVE_BIDI_MARKS,
+        REMOVE_C0_CONTROLS, REMOVE_C1_CONTROLS, REMOVE_INVISIBLE_FORMAT,
+        REMOVE_SOFT_HYPHEN, REMOVE_ZERO_WIDTH, REMOVE_ZWJ, REMOVE_ZWNJ,
+    };
+    use charmap::{CharMapAction, CharMapper, MapCharsIter};
+
+    const INPUT: &str = concat!(
+        "\u{FEFF}a\u{0000}b\u{0007}\tc\r\n",
+        "d\u{0085}e\u{009B}f ",
+        "\u{202E}gpj.exe\u{202C} \u{2067}x\u{2069} ",
+        "\u{200F}\u{05E9}\u{061C} ",
+        "zero\u{200B}width\u{2060}join ",
+        "\u{0645}\u{06CC}\u{200C}\u{0634}\u{0648}\u{062F} ",
+        "\u{1F469}\u{200D}\u{1F4BB} ",
+        "hy\u{00AD}phen ",
+        "f\u{2061}(x)\u{2062}y\u{034F}",
+    );
+
+    #[test]
+    fn sanitize_individual_rules() {
+        let cases = [
+            (&REMOVE_C0_CONTROLS[..], "\u{0000}a\u{001B}[0m\u{007F}", "a[0m"),
+            (&REMOVE_C0_CONTROLS, "a\tb\nc\r\n", "a\tb\nc\r\n"),
+            (&REMOVE_C0_CONTROLS, "a\u{000B}b\u{000C}c", "a b c"),
+            (&REMOVE_C1_CONTROLS, "a\u{0080}b\u{009F}c", "abc"),
+            (&REMOVE_C1_CONTROLS, "a\u{0085}b", "a\nb"),
+            (&REMOVE_BIDI_CONTROLS, "\u{202A}a\u{202E}b\u{2066}c", "abc"),
+            (&REMOVE_BIDI_MARKS, "a\u{200E}b\u{200F}c\u{061C}", "abc"),
+            (&REMOVE_ZERO_WIDTH, "\u{FEFF}a\u{200B}b\u{2060}c", "abc"),
+            (&REMOVE_ZWNJ, "a\u{200C}b\u{200D}c", "ab\u{200D}c"),
+            (&REMOVE_ZWJ, "a\u{200C}b\u{200D}c", "a\u{200C}bc"),
+            (&REMOVE_SOFT_HYPHEN, "hy\u{00AD}phen", "hyphen"),
+            (&REMOVE_INVISIBLE_FORMAT, "\u{206A}a\u{FFF9}b\u{FFFB}", "ab"),
+        ];
+
+        for (actions, input, expected) in cases {
+            let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn sanitize_sanitizer() {
+        let cases = [
+            (
+                Sanitizer::DISPLAY,
+                concat!(
+                    "ab\tc\r\nd\nef gpj.exe x ",
+                    "\u{200F}\u{05E9}\u{061C} zerowidthjoin ",
+                    "\u{0645}\u{06CC}\u{200C}\u{0634}\u{0648}\u{062F} ",
+                    "\u{1F469}\u{200D}\u{1F4BB} ",
+                    "hy\u{00AD}phen ",
+                    "f\u{2061}(x)\u{2062}y\u{034F}",
+                ),
+            ),
+            (
+                Sanitizer::AGGRESSIVE,
+                concat!(
+                    "ab\tc\r\nd\nef gpj.exe x \u{05E9} zerowidthjoin ",
+                    "\u{0645}\u{06CC}\u{0634}\u{0648}\u{062F} ",
+                    "\u{1F469}\u{1F4BB} hyphen f(x)y",
+                ),
+            ),
+            (
+                Sanitizer::AGGRESSIVE.zwj(false),
+                concat!(
+                    "ab\tc\r\nd\nef gpj.exe x \u{05E9} zerowidthjoin ",
+                    "\u{0645}\u{06CC}\u{0634}\u{0648}\u{062F} ",
+                    "\u{1F469}\u{200D}\u{1F4BB} hyphen f(x)y",
+                ),
+            ),
+            (Sanitizer::NONE, INPUT),
+        ];
+
+        for (sanitizer, expected) in cases {
+            let mapper = CharMapper::new(&sanitizer, CharMapAction::Pass);
+            let mapped: String = INPUT.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        assert_eq!(Sanitizer::default(), Sanitizer::DISPLAY);
+    }
+
+    #[test]
+    fn sanitize_presets_are_toggles() {
+        let display = Sanitizer::NONE
+            .c0_controls(true)
+            .c1_controls(true)
+            .bidi_controls(true)
+            .zero_width(true);
+        assert_eq!(display, Sanitizer::DISPLAY);
+
+        let aggressive = Sanitizer::DISPLAY
+            .bidi_marks(true)
+            .zwnj(true)
+            .zwj(true)
+            .soft_hyphen(true)
+            .invisible_format(true);
+        assert_eq!(aggressive, Sanitizer::AGGRESSIVE);
+    }
+
+    #[test]
+    fn sanitize_chars_zwj() {
+        let cases = [
+            // Emoji ZWJ sequences, including with variation selectors and
+            // emoji modifiers.
+            ("\u{1F469}\u{200D}\u{1F4BB}", "\u{1F469}\u{200D}\u{1F4BB}"),
+            (
+                "\u{2764}\u{FE0F}\u{200D}\u{1F525}",
+                "\u{2764}\u{FE0F}\u{200D}\u{1F525}",
+            ),
+            (
+                "\u{1F469}\u{1F3FD}\u{200D}\u{1F680}",
+                "\u{1F469}\u{1F3FD}\u{200D}\u{1F680}",
+            ),
+            // Extended_Pictographic characters that aren't emoji (yet), such
+            // as mahjong tiles and unassigned code points reserved for emoji.
+            ("\u{1F000}\u{200D}\u{1F003}", "\u{1F000}\u{200D}\u{1F003}"),
+            ("\u{1FC00}\u{200D}\u{1F469}", "\u{1FC00}\u{200D}\u{1F469}"),
+            // ZWJs outside of emoji sequences are removed.
+            ("a\u{200D}b", "ab"),
+            ("\u{1F469}\u{200D}a\u{200D}\u{1F4BB}", "\u{1F469}a\u{1F4BB}"),
+            ("\u{200D}\u{1F469}\u{200D}", "\u{1F469}"),
+            (
+                concat!(
+                    "\u{0645}\u{06CC}\u{200D}\u{0634} ",
+                    "\u{0915}\u{094D}\u{200D}\u{0937}",
+                ),
+                "\u{0645}\u{06CC}\u{0634} \u{0915}\u{094D}\u{0937}",
+            ),
+            // Other characters are sanitized as with a character map.
+            ("\u{FEFF}a\u{0085}b\u{000C}c", "a\nb c"),
+        ];
+
+        for (input, expected) in cases {
+            let sanitized: String =
+                Sanitizer::AGGRESSIVE.sanitize_chars(input.chars()).collect();
+            assert_eq!(sanitized, expected);
+        }
+
+        // ZWJs are kept as they are when ZWJ removal is disabled.
+        let sanitized: String = Sanitizer::DISPLAY
+            .sanitize_chars("a\u{200D}b \u{1F469}\u{200D}\u{1F4BB}".chars())
+            .collect();
+        assert_eq!(sanitized, "a\u{200D}b \u{1F469}\u{200D}\u{1F4BB}");
+    }
+}
@@ -0,0 +1,105 @@
+mod data;
+
+mod test {
+    use super::data::{
+        TEST_CHARS_DELETE, TEST_CHARS_PASS, TEST_CHARS_SUB_X, TEST_MAPPING,
+        TEST_STRINGS_DELETE, TEST_STRINGS_PASS, TEST_STRINGS_SUB_X,
+    };
+    use charmap::{assert_sorted, CharMapAction, CharMapper, MapCharsIter};
+
+    const _: () = assert_sorted(&TEST_MAPPING);
+
+    static SLICE_CHARMAP: &[(char, CharMapAction)] = &TEST_MAPPING;
+
+    #[test]
+    fn array_default_pass() {
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Pass);
+
+        for (input, expected) in TEST_STRINGS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_PASS {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn array_default_delete() {
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Delete);
+
+        for (input, expected) in TEST_STRINGS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn slice_default_sub_x() {
+        let mapper =
+            CharMapper::new(SLICE_CHARMAP, CharMapAction::SubStr("x"));
+
+        for (input, expected) in TEST_STRINGS_SUB_X {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_SUB_X {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    fn slice_ref_default_sub_empty() {
+        let mapper =
+            CharMapper::new(&SLICE_CHARMAP, CharMapAction::SubStr(""));
+
+        for (input, expected) in TEST_STRINGS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+
+            let mapped: String = input.chars().map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+
+        for (input, expected) in TEST_CHARS_DELETE {
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(mapped, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not sorted")]
+    fn assert_sorted_unsorted() {
+        assert_sorted(&[
+            ('b', CharMapAction::Pass),
+            ('a', CharMapAction::Pass),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate")]
+    fn assert_sorted_duplicate() {
+        assert_sorted(&[
+            ('a', CharMapAction::Pass),
+            ('a', CharMapAction::Delete),
+        ]);
+    }
+}
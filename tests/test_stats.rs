@@ -0,0 +1,166 @@
+mod data;
+
+#[cfg(feature = "std")]
+mod test {
+    use super::data::{TEST_MAPPING, TEST_STRINGS_PASS};
+    use charmap::{
+        CharMapAction, CharMapBuilder, CharMapper, FnMap, MapCharsIter, Stats,
+    };
+    use std::collections::HashMap;
+    use std::thread;
+
+    #[test]
+    fn stats_mapped_output() {
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Pass);
+
+        for (input, expected) in TEST_STRINGS_PASS {
+            let mut stats = Stats::new();
+            let mapped: String = mapper
+                .map_chars_iter_with_stats(input.chars(), &mut stats)
+                .collect();
+            assert_eq!(mapped, expected);
+            assert_eq!(mapped, input.map_chars(&mapper).collect::<String>());
+
+            assert_eq!(stats.input_chars, input.chars().count() as u64);
+            assert_eq!(stats.mapped_chars, expected.chars().count() as u64);
+        }
+    }
+
+    #[test]
+    fn stats_max_repeats() {
+        let mapper =
+            CharMapper::new(&TEST_MAPPING, CharMapAction::Pass).max_repeats(2);
+
+        for (input, _) in TEST_STRINGS_PASS {
+            let mut stats = Stats::new();
+            let mapped: String = mapper
+                .map_chars_iter_with_stats(input.chars(), &mut stats)
+                .collect();
+            assert_eq!(mapped, input.map_chars(&mapper).collect::<String>());
+
+            assert_eq!(stats.input_chars, input.chars().count() as u64);
+            assert_eq!(stats.mapped_chars, mapped.chars().count() as u64);
+
+            // Recording traces counts the same output.
+            let mut recorded = Stats::new();
+            for traced in mapper.trace_chars_iter(input.chars()) {
+                recorded.record(&traced);
+            }
+            assert_eq!(recorded.mapped_chars, stats.mapped_chars);
+        }
+
+        let mut stats = Stats::new();
+        let mapped: String = mapper
+            .map_chars_iter_with_stats("teehee!!!".chars(), &mut stats)
+            .collect();
+
+        assert_eq!(mapped, "teehee!!");
+        assert_eq!(stats.input_chars, 9);
+        assert_eq!(stats.mapped_chars, 8);
+        assert_eq!(stats.sub_strs, 4);
+    }
+
+    #[test]
+    fn stats_counts() {
+        let mapper =
+            CharMapper::new(&TEST_MAPPING, CharMapAction::SubChar('x'));
+        let mut stats = Stats::new();
+        let _: String = mapper
+            .map_chars_iter_with_stats("Hello, world!".chars(), &mut stats)
+            .collect();
+
+        assert_eq!(stats.input_chars, 13);
+        assert_eq!(stats.mapped_chars, 13);
+        assert_eq!(stats.passed, 2);
+        assert_eq!(stats.deleted, 3);
+        assert_eq!(stats.sub_chars, 7);
+        assert_eq!(stats.sub_strs, 1);
+        assert_eq!(stats.default_hits, 6);
+        assert_eq!(stats.expansion_ratio(), Some(1.0));
+        assert_eq!(
+            stats.map_char_hits,
+            HashMap::from([('d', 1), ('e', 1), ('l', 3), ('o', 2)])
+        );
+        assert_eq!(stats.default_char_hits.len(), 6);
+        assert_eq!(stats.default_char_hits[&'H'], 1);
+        assert_eq!(stats.default_char_hits[&' '], 1);
+    }
+
+    #[test]
+    fn stats_unhit_keys() {
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Pass);
+        let mut stats = Stats::new();
+        let _: String = mapper
+            .map_chars_iter_with_stats("led".chars(), &mut stats)
+            .collect();
+
+        let unhit: Vec<char> =
+            stats.unhit_keys(&TEST_MAPPING).unwrap().collect();
+        assert_eq!(unhit, ['o']);
+
+        let hashmap = HashMap::from(TEST_MAPPING);
+        let mut unhit: Vec<char> =
+            stats.unhit_keys(&hashmap).unwrap().collect();
+        unhit.sort();
+        assert_eq!(unhit, ['o']);
+
+        // Keys shadowed by the first map of a pair are only listed once.
+        let pair = (
+            [('o', CharMapAction::Delete), ('x', CharMapAction::Pass)],
+            TEST_MAPPING,
+        );
+        let unhit: Vec<char> = stats.unhit_keys(&pair).unwrap().collect();
+        assert_eq!(unhit, ['o', 'x']);
+
+        let charmap = CharMapBuilder::new()
+            .delete_range('a'..='f')
+            .pass(['l', 'z'])
+            .build()
+            .unwrap();
+        let unhit: Vec<char> = stats.unhit_keys(&charmap).unwrap().collect();
+        assert_eq!(unhit, ['a', 'b', 'c', 'f', 'z']);
+
+        let closure = FnMap(|_| None);
+        assert!(stats.unhit_keys(&closure).is_none());
+    }
+
+    #[test]
+    fn stats_empty() {
+        let stats = Stats::new();
+
+        assert_eq!(stats.expansion_ratio(), None);
+    }
+
+    #[test]
+    fn stats_merge_threads() {
+        let inputs = ["Hello", "world", "teehee", ""];
+
+        let handles: Vec<_> = inputs
+            .into_iter()
+            .map(|input| {
+                thread::spawn(move || {
+                    let mapper =
+                        CharMapper::new(&TEST_MAPPING, CharMapAction::Pass);
+                    let mut stats = Stats::new();
+                    let _: String = mapper
+                        .map_chars_iter_with_stats(input.chars(), &mut stats)
+                        .collect();
+                    stats
+                })
+            })
+            .collect();
+
+        let mut merged = Stats::new();
+        for handle in handles {
+            merged.merge(&handle.join().unwrap());
+        }
+
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Pass);
+        let mut expected = Stats::new();
+        let _: String = mapper
+            .map_chars_iter_with_stats(inputs.concat().chars(), &mut expected)
+            .collect();
+
+        assert_eq!(merged, expected);
+    }
+}
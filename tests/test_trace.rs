@@ -0,0 +1,100 @@
+mod data;
+
+mod test {
+    use super::data::{TEST_MAPPING, TEST_STRINGS_SUB_X};
+    use charmap::{
+        ActionSource, CharMapAction, CharMapper, MapCharsIter, TraceTable,
+        TracedChar,
+    };
+
+    #[test]
+    fn trace_sources() {
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Delete);
+        let traces: Vec<_> = mapper
+            .trace_chars_iter("dex".chars())
+            .map(|t| (t.pos, t.c, t.source, t.action))
+            .collect();
+
+        assert_eq!(
+            traces,
+            [
+                (0, 'd', ActionSource::Map, CharMapAction::SubChar('m')),
+                (1, 'e', ActionSource::Map, CharMapAction::SubStr("eeee")),
+                (2, 'x', ActionSource::Default, CharMapAction::Delete),
+            ]
+        );
+    }
+
+    #[test]
+    fn trace_output_matches_mapped() {
+        let mapper =
+            CharMapper::new(&TEST_MAPPING, CharMapAction::SubStr("x"));
+
+        for (input, expected) in TEST_STRINGS_SUB_X {
+            let traced: String = mapper
+                .trace_chars_iter(input.chars())
+                .flat_map(|traced| traced.output())
+                .collect();
+            assert_eq!(traced, expected);
+
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(traced, mapped);
+        }
+    }
+
+    #[test]
+    fn trace_table() {
+        let mapper = CharMapper::new(&TEST_MAPPING, CharMapAction::Pass);
+        let table = TraceTable::new(mapper.trace_chars_iter("Hel\n".chars()));
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "  pos | char     | code     | source  | action           | output\n",
+                "------+----------+----------+---------+------------------+--------\n",
+                "    0 | 'H'      | U+0048   | default | Pass             | \"H\"\n",
+                "    1 | 'e'      | U+0065   | map     | SubStr(\"eeee\")   | \"eeee\"\n",
+                "    2 | 'l'      | U+006C   | map     | Delete           | \"\"\n",
+                "    3 | '\\n'     | U+000A   | default | Pass             | \"\\n\"\n",
+            )
+        );
+    }
+
+    #[test]
+    fn trace_max_repeats() {
+        let mapper =
+            CharMapper::new(&TEST_MAPPING, CharMapAction::Pass).max_repeats(2);
+
+        for (input, _) in TEST_STRINGS_SUB_X {
+            let traced: String = mapper
+                .trace_chars_iter(input.chars())
+                .flat_map(|traced| traced.output())
+                .collect();
+            let mapped: String = input.map_chars(&mapper).collect();
+            assert_eq!(traced, mapped);
+        }
+
+        let traces: Vec<TracedChar> =
+            mapper.trace_chars_iter("eel".chars()).collect();
+        let outputs: Vec<String> =
+            traces.iter().map(|traced| traced.output().collect()).collect();
+        assert_eq!(outputs, ["ee", "", ""]);
+
+        // Traces with the same action but a different output are not equal.
+        assert_eq!(traces[1].action, traces[0].action);
+        let mut first = traces[0];
+        first.pos = 1;
+        assert_ne!(traces[1], first);
+
+        let table = TraceTable::new(mapper.trace_chars_iter("He".chars()));
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "  pos | char     | code     | source  | action           | output\n",
+                "------+----------+----------+---------+------------------+--------\n",
+                "    0 | 'H'      | U+0048   | default | Pass             | \"H\"\n",
+                "    1 | 'e'      | U+0065   | map     | SubStr(\"eeee\")   | \"ee\"\n",
+            )
+        );
+    }
+}
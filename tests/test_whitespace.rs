@@ -0,0 +1,91 @@
+#[cfg(feature = "std")]
+mod test {
+    use charmap::{
+        CharMapAction, CharMapper, MapCharsIter, NormalizeWhitespaceIter,
+        Spaces, WhitespaceNormalizer,
+    };
+
+    const INPUT: &str =
+        " \t Hello,\u{00A0}\u{3000} world! \r\n\n  Bye\u{2028} \n";
+
+    #[test]
+    fn whitespace_options() {
+        let normalizer = WhitespaceNormalizer::new();
+        let cases = [
+            (normalizer, "Hello, world! Bye"),
+            (normalizer.trim(false), " Hello, world! Bye "),
+            (
+                normalizer.collapse(false),
+                "Hello,\u{00A0}\u{3000} world! \r\n\n  Bye",
+            ),
+            (normalizer.collapse(false).trim(false), INPUT),
+            (normalizer.preserve_newlines(true), "Hello, world!\r\n\nBye"),
+            (
+                normalizer.preserve_newlines(true).trim(false),
+                " Hello, world!\r\n\nBye\u{2028}\n",
+            ),
+            (
+                normalizer.spaces(Spaces::Ascii),
+                "Hello,\u{00A0}\u{3000} world! Bye\u{2028}",
+            ),
+            (
+                normalizer.spaces(Spaces::Custom(|c| c == ' ')),
+                "\t Hello,\u{00A0}\u{3000} world! \r\n\n Bye\u{2028} \n",
+            ),
+        ];
+
+        for (normalizer, expected) in cases {
+            let normalized: String =
+                INPUT.normalize_whitespace(&normalizer).collect();
+            assert_eq!(normalized, expected);
+        }
+    }
+
+    #[test]
+    fn whitespace_edge_cases() {
+        let normalizer = WhitespaceNormalizer::default();
+        let cases = [
+            ("", "", ""),
+            ("   ", "", " "),
+            ("\n", "", "\n"),
+            ("a", "a", "a"),
+            (" a ", "a", " a "),
+            ("a  b  c", "a b c", "a b c"),
+            ("a \n b \n\n c", "a b c", "a\nb\n\nc"),
+        ];
+
+        for (input, expected, expected_untrimmed) in cases {
+            let normalized: String =
+                input.normalize_whitespace(&normalizer).collect();
+            assert_eq!(normalized, expected);
+
+            let normalizer = normalizer.trim(false).preserve_newlines(true);
+            let normalized: String =
+                input.normalize_whitespace(&normalizer).collect();
+            assert_eq!(normalized, expected_untrimmed);
+        }
+    }
+
+    #[test]
+    fn whitespace_after_mapping() {
+        let actions = [
+            ('-', CharMapAction::SubChar(' ')),
+            ('_', CharMapAction::SubStr(" _ ")),
+            ('|', CharMapAction::Delete),
+        ];
+        let mapper = CharMapper::new(&actions, CharMapAction::Pass);
+        let normalizer = WhitespaceNormalizer::new();
+
+        let normalized: String = "--a-|-b_c|-"
+            .map_chars(&mapper)
+            .normalize_whitespace(&normalizer)
+            .collect();
+        assert_eq!(normalized, "a b _ c");
+
+        let normalized: String = normalizer
+            .normalize_chars_iter("x  y".chars())
+            .map_chars(&mapper)
+            .collect();
+        assert_eq!(normalized, "x y");
+    }
+}